@@ -44,6 +44,8 @@ export type SortGroupItemConfig = NewlinesBetweenMarker | string | string[];
 export type SortOrderConfig = "asc" | "desc";
 export type SortPackageJsonUserConfig = boolean | SortPackageJsonConfig;
 export type SortTailwindcssUserConfig = boolean | SortTailwindcssConfig;
+export type SqlUserConfig = boolean | SqlConfig;
+export type KeywordCaseConfig = "upper" | "lower" | "preserve";
 export type SvelteUserConfig = boolean | SvelteConfig;
 export type TrailingCommaConfig = "all" | "es5" | "none";
 
@@ -249,6 +251,16 @@ export interface Oxfmtrc {
    * - Default: Disabled
    */
   sortTailwindcss?: SortTailwindcssUserConfig;
+  /**
+   * Format embedded SQL, e.g. `` sql`...` `` tagged templates, templates tagged with a `sql` comment
+   * and `sql` code blocks.
+   *
+   * Pass `true` or an object to enable with defaults, or omit/set `false` to disable.
+   *
+   * - Languages: JS, JSX, TS, TSX, Markdown, MDX
+   * - Default: Disabled
+   */
+  sql?: SqlUserConfig;
   /**
    * Options for `prettier-plugin-svelte`.
    *
@@ -571,6 +583,16 @@ export interface FormatConfig {
    * - Default: Disabled
    */
   sortTailwindcss?: SortTailwindcssUserConfig;
+  /**
+   * Format embedded SQL, e.g. `` sql`...` `` tagged templates, templates tagged with a `sql` comment
+   * and `sql` code blocks.
+   *
+   * Pass `true` or an object to enable with defaults, or omit/set `false` to disable.
+   *
+   * - Languages: JS, JSX, TS, TSX, Markdown, MDX
+   * - Default: Disabled
+   */
+  sql?: SqlUserConfig;
   /**
    * Options for `prettier-plugin-svelte`.
    *
@@ -843,6 +865,16 @@ export interface SortTailwindcssConfig {
   stylesheet?: string;
   [k: string]: unknown;
 }
+export interface SqlConfig {
+  /**
+   * How to case SQL keywords (`select` -> `SELECT`).
+   * Identifiers, function names and quoted names keep their casing.
+   *
+   * - Default: `"upper"`
+   */
+  keywordCase?: KeywordCaseConfig;
+  [k: string]: unknown;
+}
 export interface SvelteConfig {
   /**
    * Whether to allow attribute shorthand if attribute name and expression are same.
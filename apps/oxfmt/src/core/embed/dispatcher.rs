@@ -8,7 +8,7 @@ use std::sync::{Arc, OnceLock};
 
 use tracing::{debug, debug_span};
 
-use oxc_formatter::CssInJsTemplate;
+use oxc_formatter::{CssInJsTemplate, SqlInJsTemplate};
 use oxc_formatter_core::{
     CoreFormatOptions, DispatchRequest, DispatchResponse, EmbeddedIr, FormatDispatcher,
     FormatSession,
@@ -17,12 +17,13 @@ use oxc_formatter_core::{FormatOptions, PrinterOptions};
 use oxc_formatter_css::{CssFormatOptions, CssVariant};
 use oxc_formatter_graphql::GraphqlFormatOptions;
 use oxc_formatter_json::{JsonFormatOptions, JsonVariant};
+use oxc_formatter_sql::SqlFormatOptions;
 use oxc_formatter_yaml::YamlFormatOptions;
 
 use crate::core::{
     options::{
         to_oxc_formatter_css, to_oxc_formatter_graphql, to_oxc_formatter_json,
-        to_oxc_formatter_yaml,
+        to_oxc_formatter_sql, to_oxc_formatter_yaml,
     },
     oxfmtrc::FormatConfig,
 };
@@ -36,6 +37,7 @@ pub enum NativeLanguage {
     Css(CssVariant),
     Yaml,
     Json(JsonVariant),
+    Sql,
 }
 
 /// Languages Prettier still formats for us (no Rust formatter yet).
@@ -98,6 +100,7 @@ pub fn route(language: &str) -> Route {
         "json" => Route::Native(NativeLanguage::Json(JsonVariant::Json)),
         "jsonc" => Route::Native(NativeLanguage::Json(JsonVariant::Jsonc)),
         "json5" => Route::Native(NativeLanguage::Json(JsonVariant::Json5)),
+        "sql" => Route::Native(NativeLanguage::Sql),
         "html" => Route::Prettier(PrettierLanguage::Html),
         "angular" => Route::Prettier(PrettierLanguage::Angular),
         "markdown" | "md" => Route::Prettier(PrettierLanguage::Markdown),
@@ -125,6 +128,7 @@ pub struct ResolvedDispatchConfig {
     yaml: OnceLock<YamlFormatOptions>,
     /// One cell per fence-reachable [`JsonVariant`] (json / jsonc / json5; `JsonStringify` is `package.json`-only).
     json: [OnceLock<JsonFormatOptions>; 3],
+    sql: OnceLock<SqlFormatOptions>,
     /// The options handed to Prettier; see [`PrettierOptions`].
     #[cfg(feature = "napi")]
     prettier: PrettierOptions,
@@ -158,6 +162,7 @@ impl ResolvedDispatchConfig {
             css: [OnceLock::new(), OnceLock::new(), OnceLock::new()],
             yaml: OnceLock::new(),
             json: [OnceLock::new(), OnceLock::new(), OnceLock::new()],
+            sql: OnceLock::new(),
             #[cfg(feature = "napi")]
             prettier: PrettierOptions::default(),
         }
@@ -227,6 +232,15 @@ impl ResolvedDispatchConfig {
         *cell.get_or_init(|| to_oxc_formatter_json(&self.config, self.core, variant))
     }
 
+    /// Embedded SQL is opt-in (`sql`), unlike the other native languages.
+    pub fn is_sql_enabled(&self) -> bool {
+        self.config.is_sql_enabled()
+    }
+
+    pub fn sql_options(&self) -> SqlFormatOptions {
+        *self.sql.get_or_init(|| to_oxc_formatter_sql(&self.config, self.core))
+    }
+
     /// Printer options from the shared resolved core bundle;
     /// the fence adapter ([`super::jsdoc_fence`]) derives its per-fence options from these
     /// (width overridden to the fence's effective width).
@@ -325,6 +339,25 @@ pub fn build_dispatcher(
                     dispatch_config.json_options(variant),
                 )
             })),
+            Route::Native(NativeLanguage::Sql) if !dispatch_config.is_sql_enabled() => {
+                debug!("SQL formatting is not enabled, part stays as-is");
+                Ok(DispatchResponse::PreserveOriginal)
+            }
+            Route::Native(NativeLanguage::Sql) => {
+                // sql-in-js (typed `SqlInJsTemplate` context) carries `${}` placeholder markers;
+                // a fenced block reads backticks as MySQL quoted identifiers.
+                let template_placeholders = request
+                    .parent_context
+                    .is_some_and(|c| c.downcast_ref::<SqlInJsTemplate>().is_some());
+                Ok(format_native("sql", || {
+                    oxc_formatter_sql::format_to_ir(
+                        session,
+                        text,
+                        dispatch_config.sql_options(),
+                        template_placeholders,
+                    )
+                }))
+            }
 
             // Prettier-served languages: Doc→IR fallback when available (napi),
             // deliberate skip otherwise (pure build).
@@ -376,11 +409,14 @@ mod tests {
     };
 
     use super::{ResolvedDispatchConfig, build_dispatcher};
-    use crate::core::oxfmtrc::FormatConfig;
+    use crate::core::oxfmtrc::{FormatConfig, SqlUserConfig};
 
     fn dispatch_config() -> Arc<ResolvedDispatchConfig> {
         Arc::new(ResolvedDispatchConfig::new(
-            Arc::new(FormatConfig::default()),
+            Arc::new(FormatConfig {
+                sql: Some(SqlUserConfig::Bool(true)),
+                ..FormatConfig::default()
+            }),
             CoreFormatOptions::default(),
         ))
     }
@@ -400,14 +436,15 @@ mod tests {
             },
         );
 
-        for language in
-            ["graphql", "gql", "css", "scss", "less", "yaml", "yml", "json", "jsonc", "json5"]
-        {
+        for language in [
+            "graphql", "gql", "css", "scss", "less", "yaml", "yml", "json", "jsonc", "json5", "sql",
+        ] {
             let text = match language {
                 "graphql" | "gql" => "{ a }",
                 "css" | "scss" | "less" => "a { color: red }",
                 "yaml" | "yml" => "a: 1",
                 "json" | "jsonc" | "json5" => "{ \"a\": 1 }",
+                "sql" => "select a from t",
                 other => panic!("no sample input for native language '{other}'"),
             };
             let response = session.dispatch(DispatchRequest {
@@ -423,6 +460,32 @@ mod tests {
         }
     }
 
+    /// Embedded SQL is opt-in: without `sql` in the config, it stays as-is.
+    #[test]
+    fn sql_is_preserved_unless_enabled() {
+        let allocator = Allocator::default();
+        let dispatch_config = Arc::new(ResolvedDispatchConfig::new(
+            Arc::new(FormatConfig::default()),
+            CoreFormatOptions::default(),
+        ));
+        let session = FormatSession::with_services(
+            &allocator,
+            InputKind::PhysicalFile,
+            SessionServices {
+                dispatcher: Some(build_dispatcher(dispatch_config, None)),
+                ..SessionServices::default()
+            },
+        );
+
+        let response = session.dispatch(DispatchRequest {
+            language: "sql",
+            text: "select a from t",
+            input_kind: InputKind::Fragment,
+            parent_context: None,
+        });
+        assert!(matches!(response, Ok(DispatchResponse::PreserveOriginal)));
+    }
+
     /// Pure-build criterion: the native registry dispatches YAML with no fallback installed.
     #[test]
     fn native_yaml_dispatch_works_without_fallback() {
@@ -0,0 +1,53 @@
+use oxc_formatter_core::{CoreFormatOptions, FormatOptions};
+use oxc_formatter_sql::{KeywordCase, SqlFormatOptions};
+
+use super::super::oxfmtrc::{FormatConfig, KeywordCaseConfig, SqlUserConfig};
+
+/// Convert `FormatConfig` into `SqlFormatOptions` for `oxc_formatter_sql`.
+///
+/// Prettier has no SQL language, so only the shared layout options apply,
+/// plus `sql.keywordCase`.
+///
+/// NOTE: Pure field translation:
+/// `core` comes pre-validated from the config-resolution gate (`validate()`), so this cannot fail.
+pub fn to_oxc_formatter_sql(
+    config: &FormatConfig,
+    core_options: CoreFormatOptions,
+) -> SqlFormatOptions {
+    let mut options = SqlFormatOptions::default();
+    options.apply_core(core_options);
+    let sql_config = config.sql.clone().and_then(SqlUserConfig::into_config).unwrap_or_default();
+    if let Some(keyword_case) = sql_config.keyword_case {
+        options.keyword_case = match keyword_case {
+            KeywordCaseConfig::Upper => KeywordCase::Upper,
+            KeywordCaseConfig::Lower => KeywordCase::Lower,
+            KeywordCaseConfig::Preserve => KeywordCase::Preserve,
+        };
+    }
+    options
+}
+
+#[cfg(test)]
+mod tests {
+    use oxc_formatter_core::CoreFormatOptions;
+    use oxc_formatter_sql::KeywordCase;
+
+    use super::to_oxc_formatter_sql;
+    use crate::core::oxfmtrc::FormatConfig;
+
+    fn keyword_case(json: &str) -> KeywordCase {
+        let config: FormatConfig = serde_json::from_str(json).unwrap();
+        to_oxc_formatter_sql(&config, CoreFormatOptions::default()).keyword_case
+    }
+
+    #[test]
+    fn reads_keyword_case() {
+        assert_eq!(keyword_case("{}"), KeywordCase::Upper);
+        assert_eq!(keyword_case(r#"{ "sql": true }"#), KeywordCase::Upper);
+        assert_eq!(keyword_case(r#"{ "sql": { "keywordCase": "lower" } }"#), KeywordCase::Lower);
+        assert_eq!(
+            keyword_case(r#"{ "sql": { "keywordCase": "preserve" } }"#),
+            KeywordCase::Preserve
+        );
+    }
+}
@@ -418,7 +418,8 @@ mod tests_to_prettier {
                 "sortImports": true,
                 "sortPackageJson": true,
                 "sortTailwindcss": true,
-                "jsdoc": true
+                "jsdoc": true,
+                "sql": true
             }"#,
         );
         let value = to_prettier(&config);
@@ -433,6 +434,7 @@ mod tests_to_prettier {
             "sortTailwindcss",
             "experimentalTailwindcss",
             "jsdoc",
+            "sql",
             "overrides",
             "ignorePatterns",
             "experimentalTernaries",
@@ -273,6 +273,16 @@ pub struct FormatConfig {
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub jsdoc: Option<JsdocUserConfig>,
 
+    /// Format embedded SQL, e.g. `` sql`...` `` tagged templates, templates tagged with a `sql` comment
+    /// and `sql` code blocks.
+    ///
+    /// Pass `true` or an object to enable with defaults, or omit/set `false` to disable.
+    ///
+    /// - Languages: JS, JSX, TS, TSX, Markdown, MDX
+    /// - Default: Disabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sql: Option<SqlUserConfig>,
+
     /// Options for `prettier-plugin-svelte`.
     ///
     /// Pass `true` or an object to enable `.svelte` file formatting,
@@ -298,6 +308,14 @@ impl FormatConfig {
         !matches!(self.embedded_language_formatting, Some(EmbeddedLanguageFormattingConfig::Off))
     }
 
+    /// Whether embedded SQL formatting is enabled by this config.
+    ///
+    /// Enabled when `sql` is set to `true` or an object;
+    /// disabled when unset or `false`.
+    pub fn is_sql_enabled(&self) -> bool {
+        matches!(self.sql, Some(SqlUserConfig::Bool(true) | SqlUserConfig::Object(_)))
+    }
+
     /// Whether `prettier-plugin-svelte` is enabled by this config.
     ///
     /// Enabled when `svelte` is set to `true` or an object;
@@ -903,6 +921,44 @@ pub enum CommentLineStrategyConfig {
 
 // ---
 
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(untagged)]
+pub enum SqlUserConfig {
+    Bool(bool),
+    Object(SqlConfig),
+}
+
+impl SqlUserConfig {
+    pub fn into_config(self) -> Option<SqlConfig> {
+        match self {
+            Self::Bool(true) => Some(SqlConfig::default()),
+            Self::Bool(false) => None,
+            Self::Object(config) => Some(config),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SqlConfig {
+    /// How to case SQL keywords (`select` -> `SELECT`).
+    /// Identifiers, function names and quoted names keep their casing.
+    ///
+    /// - Default: `"upper"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keyword_case: Option<KeywordCaseConfig>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum KeywordCaseConfig {
+    Upper,
+    Lower,
+    Preserve,
+}
+
+// ---
+
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(untagged)]
 pub enum SvelteUserConfig {
@@ -1,20 +1,30 @@
 use std::{
+    borrow::Cow,
     path::{Path, PathBuf},
     sync::{Arc, RwLock},
 };
 
 use ignore::gitignore::{Gitignore, GitignoreBuilder};
-use tower_lsp_server::ls_types::{Pattern, Range, ServerCapabilities, TextEdit, Uri};
+use tower_lsp_server::ls_types::{
+    CodeAction, CodeActionKind, CodeActionOptions, CodeActionOrCommand,
+    CodeActionProviderCapability, Pattern, Range, ServerCapabilities, TextEdit, Uri,
+    WorkDoneProgressOptions, WorkspaceEdit,
+};
 use tracing::{debug, error, warn};
 
+use oxc_allocator::Allocator;
+use oxc_ast::ast::Statement;
+use oxc_formatter::{SortImportsOptions, parse_for_format};
 use oxc_language_server::{
-    Capabilities, ClientMessage, LanguageId, TextDocument, Tool, ToolBuildResult, ToolBuilder,
-    ToolRestartChanges, offset_to_position, utils::normalize_user_config_path_to_watch_pattern,
+    Capabilities, ClientMessage, CodeActionParams, LanguageId, TextDocument, Tool, ToolBuildResult,
+    ToolBuilder, ToolRestartChanges, offset_to_position,
+    utils::normalize_user_config_path_to_watch_pattern,
 };
+use oxc_span::{GetSpan, SourceType};
 
 use crate::core::{
-    ConfigResolver, ExternalServices, FormatResult, JsConfigLoaderCb, NestedConfigCtx,
-    ResolveOutcome, SourceFormatter, classify_file_kind, config_discovery,
+    ConfigResolver, ExternalServices, FormatResult, FormatStrategy, JsConfigLoaderCb,
+    NestedConfigCtx, ResolveOutcome, SourceFormatter, classify_file_kind, config_discovery,
     resolve_editorconfig_path, resolve_file_scope_config, utils,
 };
 use crate::lsp::create_fake_file_path_from_language_id;
@@ -104,6 +114,12 @@ impl ToolBuilder for ServerFormatterBuilder {
     ) {
         capabilities.document_formatting_provider =
             Some(tower_lsp_server::ls_types::OneOf::Left(true));
+        capabilities.code_action_provider =
+            Some(CodeActionProviderCapability::Options(CodeActionOptions {
+                code_action_kinds: Some(vec![CodeActionKind::SOURCE_ORGANIZE_IMPORTS]),
+                work_done_progress_options: WorkDoneProgressOptions::default(),
+                resolve_provider: None,
+            }));
     }
 
     fn build(&self, root_uri: &Uri, options: serde_json::Value) -> ToolBuildResult {
@@ -291,6 +307,66 @@ impl Tool for ServerFormatter {
             }
         }
     }
+
+    /// Provide the `source.organizeImports` code action, which sorts the imports with `sortImports` enabled.
+    /// Only the import block is edited, the rest of the file is left as-is.
+    /// Source actions apply to the whole file, so it is only provided when it is explicitly requested.
+    fn get_code_actions_or_commands(&self, params: &CodeActionParams) -> Vec<CodeActionOrCommand> {
+        let organize_imports = CodeActionKind::SOURCE_ORGANIZE_IMPORTS;
+        let is_requested = params.context.only.as_ref().is_some_and(|only| {
+            only.iter().any(|kind| {
+                organize_imports.as_str() == kind.as_str()
+                    || organize_imports
+                        .as_str()
+                        .strip_prefix(kind.as_str())
+                        .is_some_and(|rest| rest.starts_with('.'))
+            })
+        });
+        if !is_requested {
+            return vec![];
+        }
+
+        let Some(path) = params.uri.to_file_path() else {
+            return vec![];
+        };
+        let source_text = match params.text.as_deref() {
+            Some(text) => Cow::Borrowed(text),
+            None => match utils::read_to_string(&path) {
+                Ok(text) => Cow::Owned(text),
+                Err(err) => {
+                    debug!("Failed to read file for organizing imports: {err}");
+                    return vec![];
+                }
+            },
+        };
+
+        let Some(code) = self.organize_imports(&path, &source_text) else {
+            return vec![];
+        };
+
+        let (start, end, replacement) = compute_minimal_text_edit(&source_text, &code);
+        let text_edit = TextEdit::new(
+            Range::new(
+                offset_to_position(&source_text, start),
+                offset_to_position(&source_text, end),
+            ),
+            replacement.to_string(),
+        );
+
+        vec![CodeActionOrCommand::CodeAction(CodeAction {
+            title: "Organize imports".to_string(),
+            kind: Some(organize_imports),
+            edit: Some(WorkspaceEdit {
+                #[expect(clippy::disallowed_types)]
+                changes: Some(std::collections::HashMap::from([(
+                    params.uri.clone(),
+                    vec![text_edit],
+                )])),
+                ..WorkspaceEdit::default()
+            }),
+            ..CodeAction::default()
+        })]
+    }
 }
 
 impl ServerFormatter {
@@ -378,6 +454,13 @@ impl ServerFormatter {
     /// Resolve config and format a file at the given path.
     /// Returns `None` if the file is unsupported or ignored.
     fn resolve_and_format(&self, path: &Path, source_text: &str) -> Option<FormatResult> {
+        let strategy = self.resolve_strategy(path)?;
+        Some(tokio::task::block_in_place(|| self.source_formatter.format(source_text, strategy)))
+    }
+
+    /// Resolve the config of a file at the given path into its format strategy.
+    /// Returns `None` if the file is unsupported or ignored.
+    fn resolve_strategy(&self, path: &Path) -> Option<FormatStrategy> {
         // Snapshot the current state.
         // In-flight reads survive a concurrent rebuild because the old `Arc` keeps the previous snapshot alive.
         let state = Arc::clone(&self.state.read().expect("state rwlock poisoned"));
@@ -420,18 +503,46 @@ impl ServerFormatter {
         };
         debug!("strategy = {strategy:?}");
 
-        Some(tokio::task::block_in_place(|| self.source_formatter.format(source_text, strategy)))
+        Some(strategy)
     }
 
     fn format_file(&self, path: &Path, source_text: &str) -> Option<FormatResult> {
-        if self.prettierignore_glob.as_ref().is_some_and(|glob| {
+        if self.is_prettier_ignored(path) {
+            return None;
+        }
+        self.resolve_and_format(path, source_text)
+    }
+
+    /// Sort the imports of a JS/TS file at the given path with `sortImports` enabled, even if the config does not enable it.
+    /// Returns the source text with only its import block replaced by the formatted one,
+    /// or `None` if the file is unsupported, ignored, or its imports are already sorted.
+    fn organize_imports(&self, path: &Path, source_text: &str) -> Option<String> {
+        if self.is_prettier_ignored(path) {
+            return None;
+        }
+        let mut strategy = self.resolve_strategy(path)?;
+        let FormatStrategy::OxcFormatter { format_options, source_type, .. } = &mut strategy else {
+            return None;
+        };
+        let source_type = *source_type;
+        format_options.sort_imports.get_or_insert_with(SortImportsOptions::default);
+        let FormatResult::Success { code, is_changed: true } =
+            tokio::task::block_in_place(|| self.source_formatter.format(source_text, strategy))
+        else {
+            return None;
+        };
+        replace_import_block(source_text, &code, source_type)
+    }
+
+    fn is_prettier_ignored(&self, path: &Path) -> bool {
+        let is_ignored = self.prettierignore_glob.as_ref().is_some_and(|glob| {
             path.starts_with(glob.path())
                 && glob.matched_path_or_any_parents(path, path.is_dir()).is_ignore()
-        }) {
+        });
+        if is_ignored {
             debug!("File is ignored by .prettierignore: {}", path.display());
-            return None;
         }
-        self.resolve_and_format(path, source_text)
+        is_ignored
     }
 
     fn format_in_memory(
@@ -464,6 +575,65 @@ fn deserialize_lsp_options(value: serde_json::Value) -> LSPFormatOptions {
     }
 }
 
+/// Replace the import block of `source_text` with the import block of `formatted_text`,
+/// leaving the code around it unformatted.
+/// Returns `None` if either has no imports, or if nothing changes.
+fn replace_import_block(
+    source_text: &str,
+    formatted_text: &str,
+    source_type: SourceType,
+) -> Option<String> {
+    let allocator = Allocator::default();
+    let (start, end) = import_block_range(&allocator, source_text, source_type)?;
+    let (formatted_start, formatted_end) =
+        import_block_range(&allocator, formatted_text, source_type)?;
+    let text = format!(
+        "{}{}{}",
+        &source_text[..start],
+        &formatted_text[formatted_start..formatted_end],
+        &source_text[end..]
+    );
+    (text != source_text).then_some(text)
+}
+
+/// Byte range of the import block: from the end of the code preceding the first top-level import
+/// to the end of the last one, including comments which trail it on the same line.
+/// Sorting moves imports and their comments only within this range.
+fn import_block_range(
+    allocator: &Allocator,
+    source_text: &str,
+    source_type: SourceType,
+) -> Option<(usize, usize)> {
+    let ret = parse_for_format(allocator, source_text, source_type);
+    if !ret.diagnostics.is_empty() {
+        return None;
+    }
+    let program = &ret.program;
+    let is_import = |statement: &Statement| matches!(statement, Statement::ImportDeclaration(_));
+    let first = program.body.iter().position(is_import)?;
+    let last = program.body.iter().rposition(is_import)?;
+
+    let start = match first.checked_sub(1) {
+        Some(previous) => program.body[previous].span().end,
+        None => program
+            .directives
+            .last()
+            .map(|directive| directive.span.end)
+            .or_else(|| program.hashbang.as_ref().map(|hashbang| hashbang.span.end))
+            .unwrap_or(0),
+    };
+    let last_end = program.body[last].span().end;
+    let mut end = last_end;
+    for comment in program.comments.iter().skip_while(|comment| comment.span.start < last_end) {
+        let gap = &source_text[end as usize..comment.span.start as usize];
+        if !gap.bytes().all(|b| b == b' ' || b == b'\t') {
+            break;
+        }
+        end = comment.span.end;
+    }
+    Some((start as usize, end as usize))
+}
+
 /// Returns the minimal text edit (start, end, replacement) to transform `source_text` into `formatted_text`
 ///
 /// Respects EOL characters as a whole edit. Appending `\r` before `\n` is invalid and should be treated as a single edit replacing `\n` with `\r\n`.
@@ -543,7 +713,9 @@ mod tests_builder {
 
     #[test]
     fn test_server_capabilities() {
-        use tower_lsp_server::ls_types::{OneOf, ServerCapabilities};
+        use tower_lsp_server::ls_types::{
+            CodeActionKind, CodeActionProviderCapability, OneOf, ServerCapabilities,
+        };
 
         let builder = ServerFormatterBuilder::dummy();
         let mut capabilities = ServerCapabilities::default();
@@ -551,6 +723,92 @@ mod tests_builder {
         builder.server_capabilities(&mut capabilities, &mut Capabilities::default());
 
         assert_eq!(capabilities.document_formatting_provider, Some(OneOf::Left(true)));
+        let Some(CodeActionProviderCapability::Options(options)) =
+            capabilities.code_action_provider
+        else {
+            panic!("Expected code action options");
+        };
+        assert_eq!(options.code_action_kinds, Some(vec![CodeActionKind::SOURCE_ORGANIZE_IMPORTS]));
+    }
+}
+
+#[cfg(test)]
+mod tests_code_actions {
+    use std::sync::Arc;
+
+    use tower_lsp_server::ls_types::{
+        CodeActionContext, CodeActionKind, CodeActionOrCommand, Position, Range, Uri,
+    };
+
+    use oxc_language_server::{CodeActionParams, Tool};
+
+    use crate::lsp::server_formatter::ServerFormatterBuilder;
+
+    fn organize_imports(only: Option<Vec<CodeActionKind>>, text: &str) -> Vec<CodeActionOrCommand> {
+        let root = std::env::current_dir().unwrap();
+        let root_uri = Uri::from_file_path(&root).unwrap();
+        let (formatter, _) =
+            ServerFormatterBuilder::dummy().build(&root_uri, serde_json::json!({}));
+
+        formatter.get_code_actions_or_commands(&CodeActionParams {
+            uri: Uri::from_file_path(root.join("organize_imports.ts")).unwrap(),
+            range: Range::default(),
+            context: CodeActionContext { only, ..CodeActionContext::default() },
+            is_open_document: true,
+            text: Some(Arc::from(text)),
+        })
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_organize_imports() {
+        let actions = organize_imports(
+            Some(vec![CodeActionKind::SOURCE]),
+            "import { b } from \"b\";\nimport { a } from \"a\";\n\nexport { a, b };\n",
+        );
+        assert_eq!(actions.len(), 1);
+        let CodeActionOrCommand::CodeAction(action) = &actions[0] else {
+            panic!("Expected a code action");
+        };
+        assert_eq!(action.kind, Some(CodeActionKind::SOURCE_ORGANIZE_IMPORTS));
+        let changes = action.edit.as_ref().unwrap().changes.as_ref().unwrap();
+        let edits = changes.values().next().unwrap();
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].new_text, "a } from \"a\";\nimport { b } from \"b");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_organize_imports_only_edits_imports() {
+        let actions = organize_imports(
+            Some(vec![CodeActionKind::SOURCE_ORGANIZE_IMPORTS]),
+            "\"use client\"\nconst  x  =  1\nimport { b } from \"b\" // b\nimport { a } from \"a\" // a\nexport   { a, b, x }\n",
+        );
+        assert_eq!(actions.len(), 1);
+        let CodeActionOrCommand::CodeAction(action) = &actions[0] else {
+            panic!("Expected a code action");
+        };
+        let changes = action.edit.as_ref().unwrap().changes.as_ref().unwrap();
+        let edits = changes.values().next().unwrap();
+        assert_eq!(edits.len(), 1);
+        // Formatted imports, without reformatting `const  x  =  1` or `export   { a, b, x }`
+        assert_eq!(edits[0].range.start, Position::new(2, 9));
+        assert_eq!(edits[0].range.end, Position::new(3, 26));
+        assert_eq!(edits[0].new_text, "a } from \"a\"; // a\nimport { b } from \"b\"; // b");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_organize_imports_only_when_requested() {
+        let text = "import { b } from \"b\";\nimport { a } from \"a\";\n";
+        assert!(organize_imports(None, text).is_empty());
+        assert!(organize_imports(Some(vec![CodeActionKind::QUICKFIX]), text).is_empty());
+        assert!(organize_imports(Some(vec![CodeActionKind::SOURCE_FIX_ALL]), text).is_empty());
+        // Already sorted
+        assert!(
+            organize_imports(
+                Some(vec![CodeActionKind::SOURCE_ORGANIZE_IMPORTS]),
+                "import { a } from \"a\";\nimport { b } from \"b\";\n"
+            )
+            .is_empty()
+        );
     }
 }
 
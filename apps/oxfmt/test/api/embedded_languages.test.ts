@@ -263,6 +263,54 @@ describe("Embedded languages", () => {
       expect(result.code).toMatchSnapshot();
     });
 
+    it("should format sql tags and /* sql */ comments, keeping ${} interpolations", async () => {
+      const input = `\
+const sqlQuery = sql\`select * from users where id = 1\`;
+
+const users = await db.query(/* sql */ \`select id, name from users where org_id = \${orgId} and name like '%\${term}%' order by name\`);
+`;
+      const result = await format("sql.js", input, { sql: true });
+      expect(result.errors).toStrictEqual([]);
+      expect(result.code).toBe(`\
+const sqlQuery = sql\`
+  SELECT *
+  FROM users
+  WHERE id = 1
+\`;
+
+const users = await db.query(
+  /* sql */ \`
+    SELECT id, name
+    FROM users
+    WHERE org_id = \${orgId} AND name LIKE '%\${term}%'
+    ORDER BY name
+  \`,
+);
+`);
+    });
+
+    it("should not format sql unless enabled", async () => {
+      const input = "const sqlQuery = sql`select * from users where id = 1`;\n";
+      for (const options of [undefined, { sql: false }]) {
+        const result = await format("sql.js", input, options);
+        expect(result.errors).toStrictEqual([]);
+        expect(result.code).toBe(input);
+      }
+    });
+
+    it("should apply sql.keywordCase", async () => {
+      const input = "const sqlQuery = sql`SELECT * FROM users WHERE id = 1`;\n";
+      const result = await format("sql.js", input, { sql: { keywordCase: "lower" } });
+      expect(result.errors).toStrictEqual([]);
+      expect(result.code).toBe(`\
+const sqlQuery = sql\`
+  select *
+  from users
+  where id = 1
+\`;
+`);
+    });
+
     // (css-in-html)-in-js: For now, HTML is handled by Prettier, so CSS is also handled by Prettier too.
     // The CSS `Doc` carries a trailing space after `prop: ` that only materializes when the value fits flat;
     // when the value breaks, it must not leak (the Doc→IR conversion maps it to a pending space, dropped at the break).
@@ -0,0 +1,2 @@
+var x = 'Hello world';
+console.log(x);
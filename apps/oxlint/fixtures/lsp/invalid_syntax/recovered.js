@@ -0,0 +1,7 @@
+debugger;
+const a = ;
+function f() {
+  if (a) {
+    debugger;
+  }
+}
@@ -0,0 +1,4 @@
+export function f() {
+  var c = 1;
+  return c;
+}
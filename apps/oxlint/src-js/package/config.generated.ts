@@ -56,7 +56,15 @@ export type LintPluginOptionsSchema =
   | "react-perf"
   | "promise"
   | "node"
-  | "vue";
+  | "vue"
+  | "stylelint"
+  | "json"
+  | "compat"
+  | "svelte"
+  | "angular"
+  | "testing-library"
+  | "playwright"
+  | "security";
 export type LintPlugins = LintPluginOptionsSchema[];
 export type RuleNoConfig = AllowWarnDeny | [AllowWarnDeny];
 export type Mode2 = "as-needed" | "always" | "never";
@@ -414,6 +422,7 @@ export type NextTickOption = "promise" | "callback";
 export type CaseType2 = "camelCase" | "snake_case";
 export type AllowYoda = "never" | "always";
 export type OxlintOverrides = OxlintOverride[];
+export type CompatTargets = string | string[];
 export type JestVersionSchema = number | string;
 export type TagNamePreference =
   | (
@@ -685,6 +694,31 @@ export interface Oxlintrc {
    * rules.
    */
   rules?: DummyRuleMap;
+  /**
+   * Rust plugins, custom rules written in Rust and compiled to a dynamic library.
+   *
+   * Each entry is a path to the library, relative to the config file. As with `jsPlugins`,
+   * an entry can be an object with a `name` to load the plugin under a different name.
+   *
+   * A plugin must be built against the same version of `oxc_linter` as Oxlint, with the same
+   * Rust compiler. Plugins which were not are rejected when loading them.
+   *
+   * Note: Rust plugins are experimental and not subject to semver.
+   *
+   * Example:
+   *
+   * ```json
+   * {
+   *   "rustPlugins": [
+   *     "./target/release/libacme_oxlint_plugin.so"
+   *   ],
+   *   "rules": {
+   *     "acme/no-foo": "error"
+   *   }
+   * }
+   * ```
+   */
+  rustPlugins?: null | ExternalPluginEntry[];
   /**
    * Plugin-specific configuration for both built-in and custom plugins.
    * This includes settings for built-in plugins such as `react` and `jsdoc`
@@ -815,6 +849,20 @@ export interface OxlintOptions {
    * Equivalent to passing `--max-warnings` on the CLI.
    */
   maxWarnings?: number;
+  /**
+   * Type-aware rules to run natively, on Oxc's own type inference, rather than in `tsgolint`.
+   *
+   * Native rules run in-process and do not require `typeAware` or the `oxlint-tsgolint`
+   * package. They only use type information available in the file being linted, so may
+   * report fewer problems than `tsgolint`.
+   *
+   * Supported rules: `typescript/await-thenable`, `typescript/no-floating-promises`,
+   * `typescript/no-misused-promises` and `typescript/restrict-template-expressions`.
+   * Only supported in the root configuration file.
+   *
+   * Note: Native type-aware rules are experimental.
+   */
+  nativeTypeAwareRules?: string[];
   /**
    * Report unused disable directives (e.g. `// oxlint-disable-line` or `// eslint-disable-line`).
    *
@@ -895,11 +943,18 @@ export interface OxlintOverride {
  */
 export interface DummyRuleMap {
   "accessor-pairs"?: RuleNoConfig | [AllowWarnDeny, AccessorPairsConfig];
+  "angular/no-input-rename"?: RuleNoConfig;
+  "angular/no-output-on-prefix"?: RuleNoConfig;
+  "angular/template-banana-in-box"?: RuleNoConfig;
+  "angular/template-eqeqeq"?: RuleNoConfig;
+  "angular/use-lifecycle-interface"?: RuleNoConfig;
   "array-callback-return"?: RuleNoConfig | [AllowWarnDeny, ArrayCallbackReturn];
   "arrow-body-style"?: RuleNoConfig | [AllowWarnDeny, Mode2] | [AllowWarnDeny, Mode2, ArrowBodyStyleConfig];
   "block-scoped-var"?: RuleNoConfig;
   "capitalized-comments"?: RuleNoConfig | [AllowWarnDeny, AlwaysNever] | [AllowWarnDeny, AlwaysNever, OptionsJsonEnum];
   "class-methods-use-this"?: RuleNoConfig | [AllowWarnDeny, ClassMethodsUseThisConfig];
+  "compat/compat"?: RuleNoConfig | [AllowWarnDeny, Compat];
+  "compat/no-unsupported-syntax"?: RuleNoConfig;
   complexity?: RuleNoConfig | [AllowWarnDeny, ComplexityConfigEnum];
   "constructor-super"?: RuleNoConfig;
   curly?: RuleNoConfig | [AllowWarnDeny, CurlyType] | [AllowWarnDeny, CurlyType, CurlyConsistent];
@@ -1053,6 +1108,11 @@ export interface DummyRuleMap {
   "jsdoc/require-yields"?: RuleNoConfig | [AllowWarnDeny, RequireYieldsConfig];
   "jsdoc/require-yields-description"?: RuleNoConfig;
   "jsdoc/require-yields-type"?: RuleNoConfig;
+  "json/consistent-dependency-versions"?: RuleNoConfig;
+  "json/no-duplicate-keys"?: RuleNoConfig;
+  "json/sort-dependencies"?: RuleNoConfig;
+  "json/valid-package-json"?: RuleNoConfig;
+  "json/valid-tsconfig"?: RuleNoConfig;
   "jsx-a11y/alt-text"?: RuleNoConfig | [AllowWarnDeny, AltTextConfigSchema];
   "jsx-a11y/anchor-ambiguous-text"?: RuleNoConfig | [AllowWarnDeny, AnchorAmbiguousTextConfig];
   "jsx-a11y/anchor-has-content"?: RuleNoConfig | [AllowWarnDeny, AnchorHasContentConfig];
@@ -1255,13 +1315,21 @@ export interface DummyRuleMap {
     RuleNoConfig | [AllowWarnDeny, ExportsStyleMode] | [AllowWarnDeny, ExportsStyleMode, ExportsStyleOptions];
   "node/global-require"?: RuleNoConfig;
   "node/handle-callback-err"?: RuleNoConfig | [AllowWarnDeny, HandleCallbackErrConfig];
+  "node/hashbang"?: RuleNoConfig | [AllowWarnDeny, HashbangConfig];
+  "node/no-deprecated-api"?: RuleNoConfig | [AllowWarnDeny, NoDeprecatedApiConfig];
   "node/no-exports-assign"?: RuleNoConfig;
+  "node/no-missing-import"?: RuleNoConfig | [AllowWarnDeny, NoMissingImportConfig];
+  "node/no-missing-require"?: RuleNoConfig | [AllowWarnDeny, NoMissingRequireConfig];
   "node/no-mixed-requires"?: RuleNoConfig | [AllowWarnDeny, NoMixedRequiresConfig];
   "node/no-new-require"?: RuleNoConfig;
   "node/no-path-concat"?: RuleNoConfig;
   "node/no-process-env"?: RuleNoConfig | [AllowWarnDeny, NoProcessEnvConfig];
   "node/no-sync"?: RuleNoConfig | [AllowWarnDeny, NoSyncConfig];
   "node/no-top-level-await"?: RuleNoConfig | [AllowWarnDeny, NoTopLevelAwaitConfig];
+  "node/no-unpublished-import"?: RuleNoConfig | [AllowWarnDeny, NoUnpublishedImportConfig];
+  "node/no-unsupported-features-node-builtins"?:
+    RuleNoConfig | [AllowWarnDeny, NoUnsupportedFeaturesNodeBuiltinsConfig];
+  "node/prefer-node-protocol"?: RuleNoConfig | [AllowWarnDeny, PreferNodeProtocolConfig];
   "object-shorthand"?:
     RuleNoConfig | [AllowWarnDeny, ShorthandType] | [AllowWarnDeny, ShorthandType, ObjectShorthandOptions];
   "one-var"?: RuleNoConfig | [AllowWarnDeny, OneVar];
@@ -1293,6 +1361,10 @@ export interface DummyRuleMap {
   "oxc/number-arg-out-of-range"?: RuleNoConfig;
   "oxc/only-used-in-recursion"?: RuleNoConfig;
   "oxc/uninvoked-array-callback"?: RuleNoConfig;
+  "playwright/missing-playwright-await"?: RuleNoConfig | [AllowWarnDeny, MissingPlaywrightAwaitConfig];
+  "playwright/no-force-option"?: RuleNoConfig;
+  "playwright/no-wait-for-timeout"?: RuleNoConfig;
+  "playwright/prefer-web-first-assertions"?: RuleNoConfig;
   "prefer-arrow-callback"?: RuleNoConfig | [AllowWarnDeny, PreferArrowCallbackConfig];
   "prefer-const"?: RuleNoConfig | [AllowWarnDeny, PreferConstConfig];
   "prefer-destructuring"?:
@@ -1428,10 +1500,34 @@ export interface DummyRuleMap {
   "require-await"?: RuleNoConfig;
   "require-unicode-regexp"?: RuleNoConfig | [AllowWarnDeny, RequireUnicodeRegexpConfig];
   "require-yield"?: RuleNoConfig;
+  "security/detect-child-process"?: RuleNoConfig;
+  "security/detect-eval-with-expression"?: RuleNoConfig;
+  "security/detect-non-literal-fs-filename"?: RuleNoConfig;
+  "security/detect-non-literal-regexp"?: RuleNoConfig;
+  "security/detect-non-literal-require"?: RuleNoConfig;
+  "security/detect-possible-timing-attacks"?: RuleNoConfig;
+  "security/no-tainted-code"?: RuleNoConfig;
+  "security/no-tainted-command"?: RuleNoConfig;
+  "security/no-tainted-html"?: RuleNoConfig;
+  "security/no-tainted-path"?: RuleNoConfig;
+  "security/no-unsanitized-method"?: RuleNoConfig | [AllowWarnDeny, NoUnsanitizedMethodConfig];
+  "security/no-unsanitized-property"?: RuleNoConfig | [AllowWarnDeny, NoUnsanitizedPropertyConfig];
   "sort-imports"?: RuleNoConfig | [AllowWarnDeny, SortImportsOptions];
   "sort-keys"?: RuleNoConfig | [AllowWarnDeny, SortOrder] | [AllowWarnDeny, SortOrder, SortKeysOptions];
   "sort-vars"?: RuleNoConfig | [AllowWarnDeny, SortVars];
+  "stylelint/color-no-invalid-hex"?: RuleNoConfig;
+  "stylelint/declaration-block-no-duplicate-properties"?: RuleNoConfig;
+  "stylelint/no-descending-specificity"?: RuleNoConfig;
+  "stylelint/no-duplicate-selectors"?: RuleNoConfig;
+  "stylelint/unit-no-unknown"?: RuleNoConfig;
+  "svelte/no-at-html-tags"?: RuleNoConfig;
+  "svelte/no-reactive-destructuring"?: RuleNoConfig;
+  "svelte/valid-rune-placement"?: RuleNoConfig;
   "symbol-description"?: RuleNoConfig;
+  "testing-library/await-async-queries"?: RuleNoConfig;
+  "testing-library/no-node-access"?: RuleNoConfig;
+  "testing-library/prefer-screen-queries"?: RuleNoConfig;
+  "testing-library/prefer-user-event"?: RuleNoConfig | [AllowWarnDeny, PreferUserEventConfig];
   "typescript/adjacent-overload-signatures"?: RuleNoConfig;
   "typescript/array-type"?: RuleNoConfig | [AllowWarnDeny, ArrayTypeConfig];
   "typescript/await-thenable"?: RuleNoConfig;
@@ -1881,6 +1977,15 @@ export interface ClassMethodsUseThisConfig {
    */
   ignoreOverrideMethods?: boolean;
 }
+export interface Compat {
+  /**
+   * Also check prototype methods called on values of unknown type, such as `items.at(-1)`.
+   *
+   * By default, prototype methods are only checked on array, string and template literals,
+   * since the type of other receivers is not known.
+   */
+  aggressive?: boolean;
+}
 export interface ComplexityConfig {
   /**
    * Maximum amount of cyclomatic complexity
@@ -4229,6 +4334,49 @@ export interface ExportsStyleOptions {
    */
   allowBatchAssign?: boolean;
 }
+export interface HashbangConfig {
+  /**
+   * Glob patterns, relative to `package.json`, of files which are executables although
+   * they are not in the `bin` field.
+   */
+  additionalExecutables?: string[];
+  /**
+   * The executable to run files with, by file extension, such as
+   * `{ ".ts": "tsx" }`. Files with other extensions are run with `node`.
+   */
+  executableMap?: {
+    [k: string]: string | undefined;
+  };
+  /**
+   * Whether to ignore files which are not published, such as scripts which are not
+   * in the `bin` field.
+   */
+  ignoreUnpublished?: boolean;
+}
+export interface NoDeprecatedApiConfig {
+  /**
+   * Deprecated globals and members of globals to allow, such as `"Buffer()"`.
+   */
+  ignoreGlobalItems?: string[];
+  /**
+   * Deprecated modules and members of modules to allow, such as `"url.parse"`.
+   */
+  ignoreModuleItems?: string[];
+}
+export interface NoMissingImportConfig {
+  /**
+   * Packages which are allowed to be missing, such as packages provided by the environment
+   * the code runs in.
+   */
+  allowModules?: string[];
+}
+export interface NoMissingRequireConfig {
+  /**
+   * Packages which are allowed to be missing, such as packages provided by the environment
+   * the code runs in.
+   */
+  allowModules?: string[];
+}
 export interface NoMixedRequiresOptions {
   allowCall?: boolean;
   grouping?: boolean;
@@ -4257,6 +4405,34 @@ export interface NoTopLevelAwaitConfig {
    */
   ignoreBin?: boolean;
 }
+export interface NoUnpublishedImportConfig {
+  /**
+   * Packages which are allowed to be imported although they are not published.
+   */
+  allowModules?: string[];
+  /**
+   * Whether to ignore type-only imports, which are removed when compiling TypeScript.
+   */
+  ignoreTypeImport?: boolean;
+}
+export interface NoUnsupportedFeaturesNodeBuiltinsConfig {
+  /**
+   * APIs to allow although they are not supported, such as `"fs.cp"` or `"fetch"`.
+   */
+  ignores?: string[];
+  /**
+   * The range of Node.js versions to support, such as `">=18.0.0"`. Defaults to the
+   * `engines.node` field of the nearest `package.json`, then `">=16.0.0"`.
+   */
+  version?: string;
+}
+export interface PreferNodeProtocolConfig {
+  /**
+   * The range of Node.js versions to support, such as `">=18.0.0"`. Defaults to the
+   * `engines.node` field of the nearest `package.json`, then `">=16.0.0"`.
+   */
+  version?: string;
+}
 export interface ObjectShorthandOptions {
   avoidExplicitReturnArrows?: boolean;
   avoidQuotes?: boolean;
@@ -4435,6 +4611,12 @@ export interface PreserveCaughtErrorOptions {
    */
   requireCatchParameter?: boolean;
 }
+export interface MissingPlaywrightAwaitConfig {
+  /**
+   * Custom asynchronous matchers, added with `expect.extend`, which must be awaited as well.
+   */
+  customMatchers?: string[];
+}
 export interface AlwaysReturnConfig {
   /**
    * You can pass an `{ ignoreAssignmentVariable: [] }` as an option to this rule
@@ -5188,6 +5370,20 @@ export interface RequireUnicodeRegexpConfig {
    */
   requireFlag?: RequireFlag;
 }
+export interface NoUnsanitizedMethodConfig {
+  /**
+   * Functions and template tags whose result is sanitized HTML, such as
+   * `"DOMPurify.sanitize"`.
+   */
+  escape?: string[];
+}
+export interface NoUnsanitizedPropertyConfig {
+  /**
+   * Functions and template tags whose result is sanitized HTML, such as
+   * `"DOMPurify.sanitize"`.
+   */
+  escape?: string[];
+}
 export interface SortImportsOptions {
   /**
    * When `true`, the rule allows import groups separated by blank lines to be treated independently.
@@ -5235,6 +5431,12 @@ export interface SortVars {
    */
   ignoreCase?: boolean;
 }
+export interface PreferUserEventConfig {
+  /**
+   * Events of `fireEvent` which are allowed, such as `["blur", "focus"]`.
+   */
+  allowedMethods?: string[];
+}
 export interface ArrayTypeConfig {
   /**
    * The array type expected for mutable cases.
@@ -6835,6 +7037,7 @@ export interface YodaOptions {
  * ```
  */
 export interface OxlintPluginSettings {
+  compat?: CompatPluginSettings;
   jest?: JestPluginSettings;
   jsdoc?: JSDocPluginSettings;
   "jsx-a11y"?: JSXA11YPluginSettings;
@@ -6843,6 +7046,42 @@ export interface OxlintPluginSettings {
   vitest?: VitestPluginSettings;
   [k: string]: unknown | undefined;
 }
+/**
+ * Configure Compat plugin rules.
+ *
+ * See [eslint-plugin-compat](https://github.com/amilajack/eslint-plugin-compat)'s
+ * configuration for a full reference.
+ */
+export interface CompatPluginSettings {
+  /**
+   * APIs which are polyfilled, and therefore not reported, such as `structuredClone` or
+   * `Array.prototype.at`.
+   */
+  polyfills?: string[];
+  /**
+   * The environments to check the code against.
+   *
+   * Either a [browserslist](https://github.com/browserslist/browserslist) query, or a list of
+   * targets in the format of the transformer's `target` option, such as `es2020` or `chrome90`.
+   *
+   * When not set, the `.browserslistrc` or `browserslist` field of `package.json` nearest to the
+   * config file is used, falling back to the `defaults` query.
+   *
+   * Example:
+   *
+   * ```json
+   * {
+   *   "settings": {
+   *     "compat": {
+   *       "targets": ["chrome100", "safari15"]
+   *     }
+   *   }
+   * }
+   * ```
+   */
+  targets?: CompatTargets;
+  [k: string]: unknown | undefined;
+}
 /**
  * Configure Jest plugin rules.
  *
@@ -82,6 +82,10 @@ pub struct SuppressionOptions {
     /// Remove entries for violations that no longer exist
     #[bpaf(switch, hide)]
     pub prune_suppressions: bool,
+
+    /// Print the remaining suppressed violations per rule and per directory
+    #[bpaf(switch, hide)]
+    pub suppressions_summary: bool,
 }
 
 impl LintCommand {
@@ -277,7 +281,7 @@ pub struct WarningOptions {
 #[derive(Debug, Clone, Bpaf)]
 pub struct OutputOptions {
     /// Use a specific output format. Possible values:
-    /// `checkstyle`, `default`, `agent`, `github`, `gitlab`, `json`, `junit`, `sarif`, `stylish`, `unix`
+    /// `checkstyle`, `codeclimate`, `default`, `agent`, `github`, `gitlab`, `html`, `json`, `junit`, `sarif`, `stylish`, `unix`
     #[bpaf(long, short, fallback_with(default_output_format), hide_usage)]
     pub format: OutputFormat,
 
@@ -451,6 +455,38 @@ pub struct EnablePlugins {
     /// Enable the vue plugin and detect vue usage problems
     #[bpaf(flag(OverrideToggle::Enable, OverrideToggle::NotSet), hide_usage)]
     pub vue_plugin: OverrideToggle,
+
+    /// Enable the stylelint plugin and detect CSS problems
+    #[bpaf(flag(OverrideToggle::Enable, OverrideToggle::NotSet), hide_usage)]
+    pub stylelint_plugin: OverrideToggle,
+
+    /// Enable the json plugin and detect problems in JSON files such as package.json and tsconfig.json
+    #[bpaf(flag(OverrideToggle::Enable, OverrideToggle::NotSet), hide_usage)]
+    pub json_plugin: OverrideToggle,
+
+    /// Enable the compat plugin and detect APIs and syntax unsupported by the browserslist targets
+    #[bpaf(flag(OverrideToggle::Enable, OverrideToggle::NotSet), hide_usage)]
+    pub compat_plugin: OverrideToggle,
+
+    /// Enable the svelte plugin and detect problems in Svelte components and runes
+    #[bpaf(flag(OverrideToggle::Enable, OverrideToggle::NotSet), hide_usage)]
+    pub svelte_plugin: OverrideToggle,
+
+    /// Enable the angular plugin and detect problems in Angular classes and inline templates
+    #[bpaf(flag(OverrideToggle::Enable, OverrideToggle::NotSet), hide_usage)]
+    pub angular_plugin: OverrideToggle,
+
+    /// Enable the testing-library plugin and detect problems in Testing Library queries and events
+    #[bpaf(flag(OverrideToggle::Enable, OverrideToggle::NotSet), hide_usage)]
+    pub testing_library_plugin: OverrideToggle,
+
+    /// Enable the playwright plugin and detect problems in Playwright end-to-end tests
+    #[bpaf(flag(OverrideToggle::Enable, OverrideToggle::NotSet), hide_usage)]
+    pub playwright_plugin: OverrideToggle,
+
+    /// Enable the security plugin and detect code which may be vulnerable to injection attacks
+    #[bpaf(flag(OverrideToggle::Enable, OverrideToggle::NotSet), hide_usage)]
+    pub security_plugin: OverrideToggle,
 }
 
 /// Enables or disables a boolean option, or leaves it unset.
@@ -526,6 +562,14 @@ impl EnablePlugins {
         self.promise_plugin.inspect(|yes| plugins.set(LintPlugins::PROMISE, yes));
         self.node_plugin.inspect(|yes| plugins.set(LintPlugins::NODE, yes));
         self.vue_plugin.inspect(|yes| plugins.set(LintPlugins::VUE, yes));
+        self.stylelint_plugin.inspect(|yes| plugins.set(LintPlugins::STYLELINT, yes));
+        self.json_plugin.inspect(|yes| plugins.set(LintPlugins::JSON, yes));
+        self.compat_plugin.inspect(|yes| plugins.set(LintPlugins::COMPAT, yes));
+        self.svelte_plugin.inspect(|yes| plugins.set(LintPlugins::SVELTE, yes));
+        self.angular_plugin.inspect(|yes| plugins.set(LintPlugins::ANGULAR, yes));
+        self.testing_library_plugin.inspect(|yes| plugins.set(LintPlugins::TESTING_LIBRARY, yes));
+        self.playwright_plugin.inspect(|yes| plugins.set(LintPlugins::PLAYWRIGHT, yes));
+        self.security_plugin.inspect(|yes| plugins.set(LintPlugins::SECURITY, yes));
     }
 }
 
@@ -811,6 +855,13 @@ mod lint_options {
         assert!(!options.suppression_options.suppress_all);
     }
 
+    #[test]
+    fn suppressions_summary() {
+        let options = get_lint_options("--suppressions-summary");
+        assert!(options.suppression_options.suppressions_summary);
+        assert!(!options.suppression_options.suppress_all);
+    }
+
     #[test]
     fn suppress_and_prune() {
         let options = get_lint_options("--suppress-all --prune-suppressions");
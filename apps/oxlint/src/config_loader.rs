@@ -11,7 +11,8 @@ use oxc_config::{
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_linter::{
-    Config, ConfigStoreBuilder, ExternalLinter, ExternalPluginStore, LintFilter, Oxlintrc,
+    Config, ConfigStoreBuilder, ExternalLinter, ExternalPluginStore, LINT_JSON_EXTENSIONS,
+    LintFilter, Oxlintrc,
 };
 use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
 
@@ -107,6 +108,18 @@ pub fn discover_configs_in_ancestors<P: AsRef<Path>>(
     (config_paths, conflicts)
 }
 
+/// Find the closest config file in the ancestor directories of `file`,
+/// which is the config applied to the file when nested configs are enabled.
+///
+/// Returns `None` if there is no config, or the closest directory has conflicting config files.
+pub fn find_config_in_ancestors(file: &Path) -> Option<DiscoveredConfigFile> {
+    let discovery = config_discovery();
+    file.ancestors()
+        .skip(1)
+        .find_map(|dir| discovery.find_unique_config_by_readdir(dir, true).transpose())?
+        .ok()
+}
+
 /// Discover config files by walking DOWN from a root directory.
 /// Will skip the base config file (e.g., root oxlintrc) to avoid duplicate loading.
 /// In Vite+ mode, only `vite.config.ts` is discovered.
@@ -435,6 +448,12 @@ impl<'a> ConfigLoader<'a> {
                         .push(ConfigLoadError::Diagnostic(nested_type_check_not_supported(&path)));
                     continue;
                 }
+                if options.native_type_aware_rules.is_some() {
+                    errors.push(ConfigLoadError::Diagnostic(
+                        nested_native_type_aware_rules_not_supported(&path),
+                    ));
+                    continue;
+                }
                 if options.deny_warnings.is_some() {
                     errors.push(ConfigLoadError::Diagnostic(nested_deny_warnings_not_supported(
                         &path,
@@ -651,9 +670,19 @@ impl<'a> ConfigLoader<'a> {
             });
         }
 
-        // Discover config files by walking up from each file's directory
-        let config_paths: Vec<_> =
-            paths.iter().map(|p| Path::new(p.as_ref()).to_path_buf()).collect();
+        // Discover config files by walking up from each file's directory.
+        // JSON files are skipped: a directory with only JSON files is usually a config directory
+        // (`config/`, `.vscode/`), whose `.oxlintrc.json` is meant to be extended, not applied.
+        let config_paths: Vec<_> = paths
+            .iter()
+            .map(|p| Path::new(p.as_ref()))
+            .filter(|path| {
+                path.extension()
+                    .and_then(OsStr::to_str)
+                    .is_none_or(|ext| !LINT_JSON_EXTENSIONS.contains(&ext))
+            })
+            .map(Path::to_path_buf)
+            .collect();
         let (discovered_configs, conflicts) =
             discover_configs_in_ancestors(&config_paths, &oxlintrc.path);
 
@@ -730,6 +759,14 @@ fn nested_type_check_not_supported(path: &Path) -> OxcDiagnostic {
     .with_help("Move `options.typeCheck` to the root configuration file.")
 }
 
+fn nested_native_type_aware_rules_not_supported(path: &Path) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!(
+        "The `options.nativeTypeAwareRules` option is only supported in the root config, but it was found in {}.",
+        path.display()
+    ))
+    .with_help("Move `options.nativeTypeAwareRules` to the root configuration file.")
+}
+
 fn nested_deny_warnings_not_supported(path: &Path) -> OxcDiagnostic {
     OxcDiagnostic::error(format!(
         "The `options.denyWarnings` option is only supported in the root config, but it was found in {}.",
@@ -896,6 +933,27 @@ mod test {
         assert!(matches!(errors[0], ConfigLoadError::Diagnostic(_)));
     }
 
+    #[test]
+    fn test_nested_json_config_rejects_native_type_aware_rules() {
+        let root_dir = tempfile::tempdir().unwrap();
+        let nested_path = root_dir.path().join("nested/.oxlintrc.json");
+        std::fs::create_dir_all(nested_path.parent().unwrap()).unwrap();
+        std::fs::write(
+            &nested_path,
+            r#"{ "options": { "nativeTypeAwareRules": ["typescript/await-thenable"] } }"#,
+        )
+        .unwrap();
+
+        let mut external_plugin_store = ExternalPluginStore::new(false);
+        let mut loader = ConfigLoader::new(None, &mut external_plugin_store, &[], None);
+        let (_configs, errors) = loader.load_discovered_with_root_dir(
+            root_dir.path(),
+            [DiscoveredConfigFile::Json(nested_path)],
+        );
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(errors[0], ConfigLoadError::Diagnostic(_)));
+    }
+
     #[test]
     fn test_nested_json_config_rejects_deny_warnings() {
         let root_dir = tempfile::tempdir().unwrap();
@@ -372,19 +372,6 @@ impl CliRunner {
             nested_ignore_patterns,
         );
 
-        let files_to_lint = paths
-            .into_iter()
-            .filter(|path| !ignore_matcher.should_ignore(Path::new(path)))
-            .collect::<Vec<Arc<OsStr>>>();
-
-        if debug_files {
-            return crate::mode::run_debug_files(
-                files_to_lint.iter().map(|path| Path::new(path.as_ref())),
-                &self.cwd,
-                stdout,
-            );
-        }
-
         // If no external rules, discard `ExternalLinter`
         let mut external_linter = self.external_linter;
         if external_plugin_store.is_empty() {
@@ -406,6 +393,21 @@ impl CliRunner {
         );
 
         let config_store = ConfigStore::new(lint_config, nested_configs, external_plugin_store);
+
+        // Stylesheets and JSON files are only linted when their plugin is enabled.
+        let files_to_lint = paths
+            .into_iter()
+            .filter(|path| !ignore_matcher.should_ignore(Path::new(path)))
+            .filter(|path| config_store.lints_path(Path::new(path)))
+            .collect::<Vec<Arc<OsStr>>>();
+
+        if debug_files {
+            return crate::mode::run_debug_files(
+                files_to_lint.iter().map(|path| Path::new(path.as_ref())),
+                &self.cwd,
+                stdout,
+            );
+        }
         let type_check_only = self.options.type_check_only;
         let type_aware =
             type_check_only || self.options.type_aware || config_store.type_aware_enabled();
@@ -564,12 +566,20 @@ impl CliRunner {
         } else {
             suppression_manager.finalize(diff_manager, &tx_error, &cwd)
         };
-        let suppress_all_succeeded = suppression_options.suppress_all && result.is_ok();
+        let suppress_all_succeeded = suppression_options.suppress_all
+            && result.is_ok()
+            && !suppression_manager.exceeds_debt_limits();
 
         drop(tx_error);
 
         let diagnostic_result = diagnostic_service.run(stdout);
 
+        if suppression_options.suppressions_summary
+            && let Some(summary) = suppression_manager.debt_summary()
+        {
+            print_and_flush_stdout(stdout, &summary.to_string());
+        }
+
         let oxlint_suppression_file_action = if let Err(report_suppression_error) = result {
             OxlintSuppressionFileAction::UnableToPerformFsOperation(report_suppression_error)
         } else {
@@ -1274,6 +1284,17 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_fix_multi_pass() {
+        // `no-var` turns `var` into `let`, then `prefer-const` turns `let` into `const`.
+        let tester = Tester::new().with_cwd("fixtures/cli/fix_multi_pass".into());
+        tester.test_fix(
+            "fix.js",
+            "var x = 'Hello world';\nconsole.log(x);\n",
+            "const x = 'Hello world';\nconsole.log(x);\n",
+        );
+    }
+
     #[test]
     fn test_fix_skip_suggestion() {
         let tester = Tester::new().with_cwd("fixtures/cli/fix_argument".into());
@@ -2091,6 +2112,20 @@ mod suppression {
             .test_and_snapshot(args);
     }
 
+    #[test]
+    fn test_rule_budget_exceeded() {
+        let args = &[];
+        Tester::new()
+            .with_cwd("fixtures/suppression/rule_budget_exceeded".into())
+            .test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_expired_suppressions_and_debt_summary() {
+        let args = &["--suppressions-summary"];
+        Tester::new().with_cwd("fixtures/suppression/debt_summary".into()).test_and_snapshot(args);
+    }
+
     #[test]
     fn test_decreased_violations_are_reported() {
         let args = &[];
@@ -2416,4 +2451,14 @@ mod suppression {
             .with_backup_file(false)
             .test(&["--suppress-all", "--type-check"]);
     }
+
+    #[test]
+    fn test_suppress_all_keeps_debt_annotations() {
+        SuppressionTester::new()
+            .with_cwd("suppress_all_keeps_debt_annotations")
+            .with_setup_file(true)
+            .with_expected_file(true)
+            .with_backup_file(true)
+            .test(&["--suppress-all"]);
+    }
 }
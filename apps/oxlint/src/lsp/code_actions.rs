@@ -1,9 +1,12 @@
 use oxc_diagnostics::OxcCode;
 use oxc_linter::FixKind;
-use tower_lsp_server::ls_types::{CodeAction, CodeActionKind, TextEdit, Uri, WorkspaceEdit};
+use tower_lsp_server::ls_types::{
+    CodeAction, CodeActionKind, Command, TextEdit, Uri, WorkspaceEdit,
+};
 use tracing::debug;
 
 use crate::lsp::{
+    commands::FIX_ALL_RULE_COMMAND_ID,
     error_with_position::{FixedContent, FixedContentKind, LinterCodeAction},
     options::RulesCustomization,
     utils::{get_full_rule_name, range_overlaps},
@@ -18,6 +21,10 @@ pub const CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC: CodeActionKind =
 pub const CODE_ACTION_KIND_SOURCE_FIX_ALL_DANGEROUS_OXC: CodeActionKind =
     CodeActionKind::new("source.fixAllDangerous.oxc");
 
+/// The prefix of the code action kinds which fix all problems of a single rule,
+/// e.g. `source.fixAll.oxc.no-debugger`.
+pub const CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC_RULE_PREFIX: &str = "source.fixAll.oxc.";
+
 fn fix_content_to_code_action(
     fixed_content: FixedContent,
     uri: Uri,
@@ -149,6 +156,93 @@ pub fn apply_dangerous_fix_code_action(
     })
 }
 
+/// The full name of the rule which reported the diagnostic of the code action.
+pub fn code_action_rule_name(action: &LinterCodeAction) -> Option<String> {
+    match &action.fixed_content.first()?.lsp_kind {
+        FixedContentKind::LintRule(code) => get_full_rule_name(code).map(String::from),
+        FixedContentKind::UnusedDirective => None,
+    }
+}
+
+/// Collect the safe text edits of all problems reported by `rule`.
+pub fn fix_rule_text_edits(
+    actions: impl Iterator<Item = LinterCodeAction>,
+    rule: &str,
+    rules_customization: Option<&RulesCustomization>,
+) -> Vec<TextEdit> {
+    fix_all_text_edit(
+        filter_disabled_by_customization(actions, rules_customization)
+            .filter(|action| code_action_rule_name(action).is_some_and(|name| name == rule)),
+    )
+}
+
+/// Fix all problems of `rule` in the file at once.
+pub fn apply_rule_fix_code_action(
+    text_edits: Vec<TextEdit>,
+    uri: Uri,
+    rule: &str,
+    kind: CodeActionKind,
+) -> CodeAction {
+    CodeAction {
+        title: format!("Fix all {rule} problems in this file"),
+        kind: Some(kind),
+        is_preferred: Some(false),
+        edit: Some(WorkspaceEdit {
+            #[expect(clippy::disallowed_types)]
+            changes: Some(std::collections::HashMap::from([(uri, text_edits)])),
+            ..WorkspaceEdit::default()
+        }),
+        disabled: None,
+        data: None,
+        diagnostics: None,
+        command: None,
+    }
+}
+
+/// Fix all problems of `rule` in the workspace.
+/// Linting the whole workspace is too heavy for a code action request,
+/// so the edits are computed when the command is executed and applied with `workspace/applyEdit`.
+pub fn apply_rule_fix_in_workspace_code_action(rule: &str) -> CodeAction {
+    let title = format!("Fix all {rule} problems in the workspace");
+    CodeAction {
+        title: title.clone(),
+        kind: Some(CodeActionKind::QUICKFIX),
+        is_preferred: Some(false),
+        edit: None,
+        disabled: None,
+        data: None,
+        diagnostics: None,
+        command: Some(Command {
+            title,
+            command: FIX_ALL_RULE_COMMAND_ID.to_string(),
+            arguments: Some(vec![serde_json::json!({ "rule": rule })]),
+        }),
+    }
+}
+
+/// Turn `rule` off in the config file at `config_uri`.
+pub fn disable_rule_in_config_code_action(
+    rule: &str,
+    config_uri: Uri,
+    config_name: &str,
+    text_edit: TextEdit,
+) -> CodeAction {
+    CodeAction {
+        title: format!("Disable {rule} in {config_name}"),
+        kind: Some(CodeActionKind::QUICKFIX),
+        is_preferred: Some(false),
+        edit: Some(WorkspaceEdit {
+            #[expect(clippy::disallowed_types)]
+            changes: Some(std::collections::HashMap::from([(config_uri, vec![text_edit])])),
+            ..WorkspaceEdit::default()
+        }),
+        disabled: None,
+        data: None,
+        diagnostics: None,
+        command: None,
+    }
+}
+
 /// Collect safe text edits from the provided diagnostic reports, which can be applied at once.
 pub fn fix_all_text_edit(actions: impl Iterator<Item = LinterCodeAction>) -> Vec<TextEdit> {
     let mut text_edits: Vec<TextEdit> = vec![];
@@ -1,7 +1,10 @@
-use serde::Deserialize;
+use serde::{Deserialize, de::DeserializeOwned};
 
 pub const FIX_ALL_COMMAND_ID: &str = "oxc.fixAll";
 
+/// Fixes all problems of a single rule in every linted file of the workspace.
+pub const FIX_ALL_RULE_COMMAND_ID: &str = "oxc.fixAllRule";
+
 #[derive(Deserialize)]
 pub struct FixAllCommandArgs {
     pub uri: String,
@@ -11,11 +14,32 @@ impl TryFrom<Vec<serde_json::Value>> for FixAllCommandArgs {
     type Error = &'static str;
 
     fn try_from(value: Vec<serde_json::Value>) -> Result<Self, Self::Error> {
-        if value.len() != 1 {
-            return Err("Expected exactly one argument for FixAllCommandArgs");
-        }
+        parse_single_argument(value)
+            .ok_or("Expected exactly one argument for FixAllCommandArgs")?
+            .map_err(|_| "Failed to parse FixAllCommandArgs")
+    }
+}
+
+#[derive(Deserialize)]
+pub struct FixAllRuleCommandArgs {
+    /// The full rule name, e.g. `no-debugger` or `typescript/no-explicit-any`.
+    pub rule: String,
+}
 
-        let first_value = value.into_iter().next().ok_or("Missing argument")?;
-        serde_json::from_value(first_value).map_err(|_| "Failed to parse FixAllCommandArgs")
+impl TryFrom<Vec<serde_json::Value>> for FixAllRuleCommandArgs {
+    type Error = &'static str;
+
+    fn try_from(value: Vec<serde_json::Value>) -> Result<Self, Self::Error> {
+        parse_single_argument(value)
+            .ok_or("Expected exactly one argument for FixAllRuleCommandArgs")?
+            .map_err(|_| "Failed to parse FixAllRuleCommandArgs")
     }
 }
+
+/// Returns `None` when there is not exactly one argument.
+fn parse_single_argument<T: DeserializeOwned>(
+    value: Vec<serde_json::Value>,
+) -> Option<serde_json::Result<T>> {
+    let [argument] = <[serde_json::Value; 1]>::try_from(value).ok()?;
+    Some(serde_json::from_value(argument))
+}
@@ -0,0 +1,158 @@
+use tower_lsp_server::ls_types::{Range, TextEdit};
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{Expression, ObjectExpression, ObjectProperty, ObjectPropertyKind};
+use oxc_language_server::offset_to_position;
+use oxc_linter::{Oxlintrc, normalize_rule_name};
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType, Span};
+
+/// Create a text edit which turns `rule` off in the `rules` of the JSON config `source_text`.
+///
+/// The config is validated the same way as it is loaded, so comments and trailing commas of JSONC
+/// files are supported, and the rest of the file is kept untouched.
+/// Returns `None` if the config is not a valid oxlint config.
+pub fn disable_rule_text_edit(source_text: &str, rule: &str) -> Option<TextEdit> {
+    let mut json = source_text.to_string();
+    json_strip_comments::strip(&mut json).ok()?;
+    Oxlintrc::from_string(&json).ok()?;
+
+    // Valid JSON is a valid JavaScript expression, and the parser gives us the spans to edit.
+    let allocator = Allocator::default();
+    let Ok(Expression::ObjectExpression(config)) =
+        Parser::new(&allocator, source_text, SourceType::default()).parse_expression()
+    else {
+        return None;
+    };
+
+    let rule_entry = format!("{}: \"off\"", serde_json::Value::from(rule));
+    let (span, new_text) = match find_property(&config, |key| key == "rules") {
+        Some(rules) => match rules.value.without_parentheses() {
+            Expression::ObjectExpression(rules) => {
+                match find_property(rules, |key| is_rule_key(key, rule)) {
+                    Some(entry) => (entry.value.span(), "\"off\"".to_string()),
+                    None => insert_property(source_text, rules, &rule_entry),
+                }
+            }
+            value => (value.span(), format!("{{ {rule_entry} }}")),
+        },
+        None => insert_property(source_text, &config, &format!("\"rules\": {{ {rule_entry} }}")),
+    };
+
+    Some(TextEdit {
+        range: Range::new(
+            offset_to_position(source_text, span.start),
+            offset_to_position(source_text, span.end),
+        ),
+        new_text,
+    })
+}
+
+fn find_property<'o, 'a>(
+    object: &'o ObjectExpression<'a>,
+    predicate: impl Fn(&str) -> bool,
+) -> Option<&'o ObjectProperty<'a>> {
+    object.properties.iter().find_map(|property| match property {
+        ObjectPropertyKind::ObjectProperty(property)
+            if property.key.static_name().is_some_and(|key| predicate(&key)) =>
+        {
+            Some(property.as_ref())
+        }
+        _ => None,
+    })
+}
+
+/// Rules can be configured with plugin aliases (e.g. `@typescript-eslint/`, `eslint-plugin-react/`),
+/// and rules of the `eslint` plugin with and without the plugin prefix.
+fn is_rule_key(key: &str, rule: &str) -> bool {
+    key == rule || normalize_rule_name(key) == normalize_rule_name(rule)
+}
+
+/// Insert `property` before the first property of `object`, keeping its indentation.
+fn insert_property(source_text: &str, object: &ObjectExpression, property: &str) -> (Span, String) {
+    let Some(first) = object.properties.first() else {
+        return (object.span, format!("{{ {property} }}"));
+    };
+
+    let start = first.span().start;
+    let line_prefix = source_text[..start as usize].rsplit(['\n', '\r']).next().unwrap_or_default();
+    let separator =
+        if line_prefix.trim().is_empty() { format!("\n{line_prefix}") } else { " ".to_string() };
+    (Span::empty(start), format!("{property},{separator}"))
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_language_server::position_to_offset;
+
+    use super::disable_rule_text_edit;
+
+    fn disable(source_text: &str, rule: &str) -> String {
+        let edit = disable_rule_text_edit(source_text, rule).unwrap();
+        let start = position_to_offset(source_text, edit.range.start) as usize;
+        let end = position_to_offset(source_text, edit.range.end) as usize;
+        format!("{}{}{}", &source_text[..start], edit.new_text, &source_text[end..])
+    }
+
+    #[test]
+    fn test_existing_rule() {
+        assert_eq!(
+            disable(
+                "{\n  \"rules\": {\n    \"eslint/no-debugger\": [\"error\"]\n  }\n}",
+                "no-debugger"
+            ),
+            "{\n  \"rules\": {\n    \"eslint/no-debugger\": \"off\"\n  }\n}"
+        );
+    }
+
+    #[test]
+    fn test_aliased_rule() {
+        assert_eq!(
+            disable(
+                "{ \"rules\": { \"@typescript-eslint/no-explicit-any\": \"error\" } }",
+                "typescript/no-explicit-any"
+            ),
+            "{ \"rules\": { \"@typescript-eslint/no-explicit-any\": \"off\" } }"
+        );
+        assert_eq!(
+            disable(
+                "{ \"rules\": { \"eslint-plugin-react/jsx-key\": \"error\" } }",
+                "react/jsx-key"
+            ),
+            "{ \"rules\": { \"eslint-plugin-react/jsx-key\": \"off\" } }"
+        );
+    }
+
+    #[test]
+    fn test_new_rule() {
+        assert_eq!(
+            disable(
+                "{\n  // comment\n  \"rules\": {\n    \"no-console\": \"warn\",\n  },\n}",
+                "typescript/no-explicit-any"
+            ),
+            "{\n  // comment\n  \"rules\": {\n    \"typescript/no-explicit-any\": \"off\",\n    \"no-console\": \"warn\",\n  },\n}"
+        );
+        assert_eq!(
+            disable("{ \"rules\": {} }", "no-debugger"),
+            "{ \"rules\": { \"no-debugger\": \"off\" } }"
+        );
+    }
+
+    #[test]
+    fn test_missing_rules() {
+        assert_eq!(
+            disable("{\n  \"plugins\": [\"import\"]\n}", "import/no-cycle"),
+            "{\n  \"rules\": { \"import/no-cycle\": \"off\" },\n  \"plugins\": [\"import\"]\n}"
+        );
+        assert_eq!(disable("{}", "no-debugger"), "{ \"rules\": { \"no-debugger\": \"off\" } }");
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        assert!(disable_rule_text_edit("[]", "no-debugger").is_none());
+        assert!(disable_rule_text_edit("{", "no-debugger").is_none());
+        // Not JSON, although it is a valid JavaScript object.
+        assert!(disable_rule_text_edit("{ rules: {} }", "no-debugger").is_none());
+        assert!(disable_rule_text_edit("{ \"rules\": [] }", "no-debugger").is_none());
+    }
+}
@@ -0,0 +1,224 @@
+use std::cmp::Reverse;
+
+use tower_lsp_server::ls_types::{FoldingRange, FoldingRangeKind};
+
+use oxc_ast::{AstKind, ast::Statement};
+use oxc_semantic::Semantic;
+use oxc_span::{GetSpan, Span};
+
+/// Compute the folding ranges of multi-line blocks, literals, JSX elements, comments,
+/// `// #region` markers and groups of import declarations.
+pub fn folding_ranges(semantic: &Semantic) -> Vec<FoldingRange> {
+    let source_text = semantic.source_text();
+    let line_starts = line_starts(source_text);
+    let line = |offset: u32| line_of(&line_starts, offset);
+
+    let folding_range = |span: Span, kind: Option<FoldingRangeKind>| {
+        let start_line = line(span.start);
+        let mut end_line = line(span.end);
+        // Keep the line with the closing bracket or tag visible.
+        if span.source_text(source_text).ends_with(['}', ']', ')', '>', '`']) {
+            end_line = end_line.saturating_sub(1);
+        }
+        (end_line > start_line).then(|| FoldingRange {
+            start_line,
+            end_line,
+            kind,
+            ..FoldingRange::default()
+        })
+    };
+
+    let mut ranges = Vec::new();
+
+    for node in semantic.nodes().iter() {
+        match node.kind() {
+            AstKind::BlockStatement(_)
+            | AstKind::FunctionBody(_)
+            | AstKind::ClassBody(_)
+            | AstKind::StaticBlock(_)
+            | AstKind::SwitchStatement(_)
+            | AstKind::ObjectExpression(_)
+            | AstKind::ArrayExpression(_)
+            | AstKind::ObjectPattern(_)
+            | AstKind::ArrayPattern(_)
+            | AstKind::TemplateLiteral(_)
+            | AstKind::JSXElement(_)
+            | AstKind::JSXFragment(_)
+            | AstKind::TSInterfaceBody(_)
+            | AstKind::TSEnumBody(_)
+            | AstKind::TSModuleBlock(_)
+            | AstKind::TSTypeLiteral(_) => ranges.extend(folding_range(node.kind().span(), None)),
+            _ => {}
+        }
+    }
+
+    // Consecutive import declarations.
+    let mut imports: Option<Span> = None;
+    for statement in &semantic.nodes().program().body {
+        if let Statement::ImportDeclaration(import) = statement {
+            imports = Some(imports.map_or(import.span, |span| span.merge(import.span)));
+        } else if let Some(span) = imports.take() {
+            ranges.extend(folding_range(span, Some(FoldingRangeKind::Imports)));
+        }
+    }
+    if let Some(span) = imports {
+        ranges.extend(folding_range(span, Some(FoldingRangeKind::Imports)));
+    }
+
+    // Block comments, consecutive line comments and regions.
+    let mut line_comments: Option<Span> = None;
+    let mut regions = Vec::new();
+    for comment in semantic.comments() {
+        if comment.is_block() {
+            ranges.extend(folding_range(comment.span, Some(FoldingRangeKind::Comment)));
+            continue;
+        }
+
+        let content = comment.content_span().source_text(source_text).trim_start();
+        if content.starts_with("#region") {
+            regions.push(line(comment.span.start));
+        } else if content.starts_with("#endregion") {
+            if let Some(start_line) = regions.pop() {
+                ranges.push(FoldingRange {
+                    start_line,
+                    end_line: line(comment.span.start),
+                    kind: Some(FoldingRangeKind::Region),
+                    ..FoldingRange::default()
+                });
+            }
+        } else {
+            line_comments = match line_comments {
+                Some(span) if line(span.end) + 1 == line(comment.span.start) => {
+                    Some(span.merge(comment.span))
+                }
+                previous => {
+                    if let Some(span) = previous {
+                        ranges.extend(folding_range(span, Some(FoldingRangeKind::Comment)));
+                    }
+                    Some(comment.span)
+                }
+            };
+            continue;
+        }
+        // Region markers end a group of line comments.
+        if let Some(span) = line_comments.take() {
+            ranges.extend(folding_range(span, Some(FoldingRangeKind::Comment)));
+        }
+    }
+    if let Some(span) = line_comments {
+        ranges.extend(folding_range(span, Some(FoldingRangeKind::Comment)));
+    }
+
+    // Editors only support one range per start line, keep the outermost one.
+    ranges.sort_by_key(|range| (range.start_line, Reverse(range.end_line)));
+    ranges.dedup_by_key(|range| range.start_line);
+    ranges
+}
+
+/// Offsets of the start of each line. The protocol only treats CR, LF and CRLF as line breaks.
+fn line_starts(source_text: &str) -> Vec<u32> {
+    let bytes = source_text.as_bytes();
+    let mut line_starts = vec![0];
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\r' if bytes.get(i + 1) == Some(&b'\n') => i += 2,
+            b'\r' | b'\n' => i += 1,
+            _ => {
+                i += 1;
+                continue;
+            }
+        }
+        line_starts.push(u32::try_from(i).expect("offset must fit in u32"));
+    }
+    line_starts
+}
+
+fn line_of(line_starts: &[u32], offset: u32) -> u32 {
+    let line = line_starts.partition_point(|&start| start <= offset) - 1;
+    u32::try_from(line).expect("line must fit in u32")
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use tower_lsp_server::ls_types::FoldingRangeKind;
+
+    use crate::lsp::utils::with_semantic;
+
+    use super::folding_ranges;
+
+    fn ranges(path: &str, source_text: &str) -> Vec<(u32, u32, Option<FoldingRangeKind>)> {
+        with_semantic(Path::new(path), source_text, folding_ranges)
+            .unwrap()
+            .into_iter()
+            .map(|range| (range.start_line, range.end_line, range.kind))
+            .collect()
+    }
+
+    #[test]
+    fn test_blocks() {
+        let source_text = "function f() {
+  if (a) {
+    return [
+      1,
+    ];
+  }
+}
+const o = { a: 1 };
+const s = `
+a
+`;";
+        assert_eq!(
+            ranges("test.js", source_text),
+            vec![(0, 5, None), (1, 4, None), (2, 3, None), (8, 9, None)]
+        );
+    }
+
+    #[test]
+    fn test_jsx_and_types() {
+        let source_text = "interface I {
+  a: string;
+}
+const e = <div>
+  <span />
+</div>;";
+        assert_eq!(ranges("test.tsx", source_text), vec![(0, 1, None), (3, 4, None)]);
+    }
+
+    #[test]
+    fn test_imports() {
+        let source_text = "import a from 'a';
+import b from 'b';
+import {
+  c,
+} from 'c';
+
+a;
+import d from 'd';";
+        assert_eq!(ranges("test.js", source_text), vec![(0, 4, Some(FoldingRangeKind::Imports))]);
+    }
+
+    #[test]
+    fn test_comments_and_regions() {
+        let source_text = "// a
+// b
+/**
+ * c
+ */
+// #region name
+// d
+let x;
+// #endregion
+// e";
+        assert_eq!(
+            ranges("test.js", source_text),
+            vec![
+                (0, 1, Some(FoldingRangeKind::Comment)),
+                (2, 4, Some(FoldingRangeKind::Comment)),
+                (5, 8, Some(FoldingRangeKind::Region)),
+            ]
+        );
+    }
+}
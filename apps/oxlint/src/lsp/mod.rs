@@ -8,8 +8,12 @@ use crate::js_config::JsConfigLoaderCb;
 
 mod code_actions;
 mod commands;
+mod config_edit;
 mod error_with_position;
+mod folding_ranges;
 mod lsp_file_system;
+mod selection_ranges;
+mod semantic_tokens;
 mod server_linter;
 #[cfg(test)]
 mod tester;
@@ -0,0 +1,112 @@
+use tower_lsp_server::ls_types::{Position, Range, SelectionRange};
+
+use oxc_language_server::{offset_to_position, position_to_offset};
+use oxc_semantic::{AstNode, Semantic};
+use oxc_span::{GetSpan, Span};
+
+/// Compute the selection ranges for each position, expanding from the innermost AST node
+/// at the position to the whole program.
+pub fn selection_ranges(semantic: &Semantic, positions: &[Position]) -> Vec<SelectionRange> {
+    let source_text = semantic.source_text();
+    let nodes = semantic.nodes();
+
+    positions
+        .iter()
+        .map(|&position| {
+            let offset = position_to_offset(source_text, position);
+
+            // Children are created after their parents, so the last of the smallest nodes is the innermost.
+            let mut innermost: Option<&AstNode> = None;
+            for node in nodes.iter() {
+                let span = node.kind().span();
+                if span.start <= offset
+                    && offset <= span.end
+                    && innermost.is_none_or(|inner| span.size() <= inner.kind().span().size())
+                {
+                    innermost = Some(node);
+                }
+            }
+
+            let mut spans = vec![innermost.map_or(nodes.program().span, |node| node.kind().span())];
+            if let Some(node) = innermost {
+                for kind in nodes.ancestor_kinds(node.id()) {
+                    let span = kind.span();
+                    if spans.last() != Some(&span) {
+                        spans.push(span);
+                    }
+                }
+            }
+
+            spans
+                .into_iter()
+                .rev()
+                .fold(None, |parent, span| {
+                    Some(SelectionRange {
+                        range: span_to_range(source_text, span),
+                        parent: parent.map(Box::new),
+                    })
+                })
+                .expect("there is always at least one span")
+        })
+        .collect()
+}
+
+fn span_to_range(source_text: &str, span: Span) -> Range {
+    Range::new(
+        offset_to_position(source_text, span.start),
+        offset_to_position(source_text, span.end),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use tower_lsp_server::ls_types::{Position, SelectionRange};
+
+    use crate::lsp::utils::with_semantic;
+
+    use super::selection_ranges;
+
+    /// The selected texts, from the innermost to the outermost range.
+    fn selections(source_text: &str, position: Position) -> Vec<String> {
+        let ranges = with_semantic(Path::new("test.js"), source_text, |semantic| {
+            selection_ranges(semantic, &[position])
+        })
+        .unwrap();
+        assert_eq!(ranges.len(), 1);
+
+        let lines: Vec<&str> = source_text.lines().collect();
+        let mut texts = Vec::new();
+        let mut range: Option<&SelectionRange> = ranges.first();
+        while let Some(selection) = range {
+            let (start, end) = (selection.range.start, selection.range.end);
+            assert_eq!(start.line, end.line, "test ranges stay on one line");
+            let line = lines[start.line as usize];
+            texts.push(line[start.character as usize..end.character as usize].to_string());
+            range = selection.parent.as_deref();
+        }
+        texts
+    }
+
+    #[test]
+    fn test_expands_to_program() {
+        assert_eq!(
+            selections("foo(a + bar.baz);", Position::new(0, 13)),
+            vec!["baz", "bar.baz", "a + bar.baz", "foo(a + bar.baz)", "foo(a + bar.baz);"]
+        );
+    }
+
+    #[test]
+    fn test_position_at_identifier_end() {
+        assert_eq!(
+            selections("let x = [a, b];", Position::new(0, 10)),
+            vec!["a", "[a, b]", "x = [a, b]", "let x = [a, b];"]
+        );
+    }
+
+    #[test]
+    fn test_position_in_whitespace() {
+        assert_eq!(selections("  a;  ", Position::new(0, 0)), vec!["  a;  "]);
+    }
+}
@@ -0,0 +1,309 @@
+use tower_lsp_server::ls_types::{
+    SemanticToken, SemanticTokenModifier, SemanticTokenType, SemanticTokensLegend,
+};
+
+use oxc_ast::{AstKind, ast::Expression};
+use oxc_semantic::{Semantic, SymbolFlags, SymbolId};
+use oxc_span::Span;
+
+/// Token types reported by the server, in the order of the legend.
+#[derive(Debug, Clone, Copy)]
+#[repr(u32)]
+enum TokenType {
+    Namespace,
+    Type,
+    Class,
+    Enum,
+    Interface,
+    TypeParameter,
+    Parameter,
+    Variable,
+    EnumMember,
+    Function,
+}
+
+/// Token modifiers reported by the server, as bits in the order of the legend.
+mod token_modifier {
+    pub const DECLARATION: u32 = 1 << 0;
+    pub const READONLY: u32 = 1 << 1;
+}
+
+/// The legend for the tokens returned by [`semantic_tokens`].
+pub fn legend() -> SemanticTokensLegend {
+    SemanticTokensLegend {
+        token_types: vec![
+            SemanticTokenType::NAMESPACE,
+            SemanticTokenType::TYPE,
+            SemanticTokenType::CLASS,
+            SemanticTokenType::ENUM,
+            SemanticTokenType::INTERFACE,
+            SemanticTokenType::TYPE_PARAMETER,
+            SemanticTokenType::PARAMETER,
+            SemanticTokenType::VARIABLE,
+            SemanticTokenType::ENUM_MEMBER,
+            SemanticTokenType::FUNCTION,
+        ],
+        token_modifiers: vec![SemanticTokenModifier::DECLARATION, SemanticTokenModifier::READONLY],
+    }
+}
+
+/// Compute the semantic tokens of all declarations and resolved references in the program.
+///
+/// The token type is derived from the symbol, so a reference to a JSX component is highlighted
+/// the same way as its function or class declaration.
+pub fn semantic_tokens(semantic: &Semantic) -> Vec<SemanticToken> {
+    let scoping = semantic.scoping();
+    let nodes = semantic.nodes();
+
+    let mut tokens = Vec::new();
+    for symbol_id in scoping.symbol_ids() {
+        let (token_type, modifiers) = classify_symbol(semantic, symbol_id);
+        let declaration_modifiers = modifiers | token_modifier::DECLARATION;
+
+        tokens.push((scoping.symbol_span(symbol_id), token_type, declaration_modifiers));
+        for redeclaration in scoping.symbol_redeclarations(symbol_id) {
+            tokens.push((redeclaration.span, token_type, declaration_modifiers));
+        }
+        for reference in scoping.get_resolved_references(symbol_id) {
+            let AstKind::IdentifierReference(ident) = nodes.kind(reference.node_id()) else {
+                continue;
+            };
+            tokens.push((ident.span, token_type, modifiers));
+        }
+    }
+
+    tokens.sort_unstable_by_key(|(span, _, _)| span.start);
+    tokens.dedup_by_key(|(span, _, _)| span.start);
+
+    encode_tokens(semantic.source_text(), &tokens)
+}
+
+fn classify_symbol(semantic: &Semantic, symbol_id: SymbolId) -> (TokenType, u32) {
+    let flags = semantic.scoping().symbol_flags(symbol_id);
+    let declaration = semantic.nodes().kind(semantic.scoping().symbol_declaration(symbol_id));
+
+    let token_type = if flags.contains(SymbolFlags::Class) {
+        TokenType::Class
+    } else if flags.intersects(SymbolFlags::Enum) {
+        TokenType::Enum
+    } else if flags.contains(SymbolFlags::Interface) {
+        TokenType::Interface
+    } else if flags.contains(SymbolFlags::TypeAlias) {
+        TokenType::Type
+    } else if flags.contains(SymbolFlags::TypeParameter) {
+        TokenType::TypeParameter
+    } else if flags.contains(SymbolFlags::EnumMember) {
+        TokenType::EnumMember
+    } else if flags.intersects(SymbolFlags::NamespaceModule | SymbolFlags::ValueModule) {
+        TokenType::Namespace
+    } else if flags.contains(SymbolFlags::Function) {
+        TokenType::Function
+    } else {
+        match declaration {
+            AstKind::ImportNamespaceSpecifier(_) => TokenType::Namespace,
+            _ if flags.contains(SymbolFlags::TypeImport) => TokenType::Type,
+            AstKind::FormalParameter(_) | AstKind::FormalParameterRest(_) => TokenType::Parameter,
+            AstKind::VariableDeclarator(declarator)
+                if declarator.init.as_ref().is_some_and(|init| {
+                    matches!(
+                        init.without_parentheses(),
+                        Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_)
+                    )
+                }) =>
+            {
+                TokenType::Function
+            }
+            _ => TokenType::Variable,
+        }
+    };
+
+    let modifiers = if flags
+        .intersects(SymbolFlags::ConstVariable | SymbolFlags::Import | SymbolFlags::EnumMember)
+    {
+        token_modifier::READONLY
+    } else {
+        0
+    };
+
+    (token_type, modifiers)
+}
+
+/// Encode the tokens, sorted by their start offset, relative to each other.
+fn encode_tokens(source_text: &str, tokens: &[(Span, TokenType, u32)]) -> Vec<SemanticToken> {
+    let mut encoded = Vec::with_capacity(tokens.len());
+
+    // Position of `offset`, updated while walking through the source text.
+    let mut offset = 0;
+    let mut line = 0;
+    let mut character = 0;
+    let mut previous_line = 0;
+    let mut previous_character = 0;
+
+    for &(span, token_type, modifiers) in tokens {
+        let mut chars = source_text[offset..span.start as usize].chars().peekable();
+        while let Some(ch) = chars.next() {
+            match ch {
+                '\r' => {
+                    chars.next_if_eq(&'\n');
+                    line += 1;
+                    character = 0;
+                }
+                '\n' => {
+                    line += 1;
+                    character = 0;
+                }
+                _ => character += utf16_len(ch.len_utf16()),
+            }
+        }
+        offset = span.start as usize;
+
+        let delta_line = line - previous_line;
+        encoded.push(SemanticToken {
+            delta_line,
+            delta_start: if delta_line == 0 { character - previous_character } else { character },
+            length: utf16_len(span.source_text(source_text).encode_utf16().count()),
+            token_type: token_type as u32,
+            token_modifiers_bitset: modifiers,
+        });
+        previous_line = line;
+        previous_character = character;
+    }
+
+    encoded
+}
+
+fn utf16_len(len: usize) -> u32 {
+    u32::try_from(len).expect("UTF-16 length must fit in u32")
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use crate::lsp::utils::with_semantic;
+
+    use super::semantic_tokens;
+
+    /// Decode the tokens into `(text, token type, modifiers)` tuples.
+    fn tokens(path: &str, source_text: &str) -> Vec<(String, String, Vec<String>)> {
+        let legend = super::legend();
+        let tokens = with_semantic(Path::new(path), source_text, semantic_tokens).unwrap();
+        let lines: Vec<&str> = source_text.lines().collect();
+
+        let mut line = 0;
+        let mut character = 0;
+        tokens
+            .into_iter()
+            .map(|token| {
+                if token.delta_line == 0 {
+                    character += token.delta_start;
+                } else {
+                    line += token.delta_line;
+                    character = token.delta_start;
+                }
+                let utf16: Vec<u16> = lines[line as usize].encode_utf16().collect();
+                let text = String::from_utf16(
+                    &utf16[character as usize..(character + token.length) as usize],
+                )
+                .unwrap();
+                let token_type = legend.token_types[token.token_type as usize].as_str().to_string();
+                let modifiers = legend
+                    .token_modifiers
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| token.token_modifiers_bitset & (1 << index) != 0)
+                    .map(|(_, modifier)| modifier.as_str().to_string())
+                    .collect();
+                (text, token_type, modifiers)
+            })
+            .collect()
+    }
+
+    fn token(text: &str, token_type: &str, modifiers: &[&str]) -> (String, String, Vec<String>) {
+        (
+            text.to_string(),
+            token_type.to_string(),
+            modifiers.iter().map(ToString::to_string).collect(),
+        )
+    }
+
+    #[test]
+    fn test_declarations_and_references() {
+        assert_eq!(
+            tokens(
+                "test.js",
+                "import { x } from 'mod';\nconst a = 1;\nlet b = (c) => c + a;\nfunction f(...d) { return d; }\nf(b, x, unresolved);"
+            ),
+            vec![
+                token("x", "variable", &["declaration", "readonly"]),
+                token("a", "variable", &["declaration", "readonly"]),
+                token("b", "function", &["declaration"]),
+                token("c", "parameter", &["declaration"]),
+                token("c", "parameter", &[]),
+                token("a", "variable", &["readonly"]),
+                token("f", "function", &["declaration"]),
+                token("d", "parameter", &["declaration"]),
+                token("d", "parameter", &[]),
+                token("f", "function", &[]),
+                token("b", "function", &[]),
+                token("x", "variable", &["readonly"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_types_and_namespaces() {
+        assert_eq!(
+            tokens(
+                "test.ts",
+                "import type { T } from 'mod';\nimport * as ns from 'mod';\ninterface I<P> { p: P }\ntype U = T;\nenum E { A }\nnamespace N {}\nclass C implements I<U> {}"
+            ),
+            vec![
+                token("T", "type", &["declaration"]),
+                token("ns", "namespace", &["declaration", "readonly"]),
+                token("I", "interface", &["declaration"]),
+                token("P", "typeParameter", &["declaration"]),
+                token("P", "typeParameter", &[]),
+                token("U", "type", &["declaration"]),
+                token("T", "type", &[]),
+                token("E", "enum", &["declaration"]),
+                token("A", "enumMember", &["declaration", "readonly"]),
+                token("N", "namespace", &["declaration"]),
+                token("C", "class", &["declaration"]),
+                token("I", "interface", &[]),
+                token("U", "type", &[]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_jsx_components() {
+        assert_eq!(
+            tokens(
+                "test.jsx",
+                "class Foo {}\nconst Bar = () => <Foo>\n  <div />\n</Foo>;\n<Bar />;"
+            ),
+            vec![
+                token("Foo", "class", &["declaration"]),
+                token("Bar", "function", &["declaration", "readonly"]),
+                token("Foo", "class", &[]),
+                token("Foo", "class", &[]),
+                token("Bar", "function", &["readonly"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_utf16_positions() {
+        assert_eq!(
+            tokens("test.js", "let s = '🍄£';\nlet a = '🍄', b = s; a;"),
+            vec![
+                token("s", "variable", &["declaration"]),
+                token("a", "variable", &["declaration"]),
+                token("b", "variable", &["declaration"]),
+                token("s", "variable", &[]),
+                token("a", "variable", &[]),
+            ]
+        );
+    }
+}
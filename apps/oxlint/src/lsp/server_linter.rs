@@ -1,11 +1,16 @@
+use std::borrow::Cow;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, OnceLock};
 
 use ignore::gitignore::Gitignore;
 use oxc_language_server::{ClientMessage, ToolBuildResult};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use rustc_hash::{FxHashMap, FxHashSet};
 use tower_lsp_server::ls_types::{
-    CodeActionTriggerKind, DiagnosticOptions, DiagnosticServerCapabilities,
+    CodeAction, CodeActionTriggerKind, DiagnosticOptions, DiagnosticServerCapabilities,
+    FoldingRange, FoldingRangeProviderCapability, Position, SelectionRange,
+    SelectionRangeProviderCapability, SemanticToken, SemanticTokensFullOptions,
+    SemanticTokensOptions, SemanticTokensServerCapabilities,
 };
 use tower_lsp_server::{
     jsonrpc::ErrorCode,
@@ -22,6 +27,7 @@ use oxc_linter::{
     FixKind, LINTABLE_EXTENSIONS, LintIgnoreMatcher, LintOptions, LintRunner, LintRunnerBuilder,
     LintServiceOptions, Linter, Oxlintrc, read_to_string,
 };
+use oxc_semantic::Semantic;
 
 use oxc_language_server::{
     Capabilities, CodeActionParams, ConcurrentHashMap, DiagnosticMode, DiagnosticResult,
@@ -32,24 +38,32 @@ use oxc_language_server::{
 use crate::{
     config_loader::{
         ConfigLoader, build_nested_configs, config_file_names, discover_configs_in_tree,
-        materialize_default_plugins,
+        find_config_in_ancestors, materialize_default_plugins,
     },
     lsp::{
         code_actions::{
             CODE_ACTION_KIND_SOURCE_FIX_ALL_DANGEROUS_OXC, CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC,
-            apply_all_fix_code_action, apply_dangerous_fix_code_action, apply_fix_code_actions,
-            fix_all_text_edit,
+            CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC_RULE_PREFIX, apply_all_fix_code_action,
+            apply_dangerous_fix_code_action, apply_fix_code_actions, apply_rule_fix_code_action,
+            apply_rule_fix_in_workspace_code_action, code_action_rule_name,
+            disable_rule_in_config_code_action, fix_all_text_edit, fix_rule_text_edits,
         },
-        commands::{FIX_ALL_COMMAND_ID, FixAllCommandArgs},
+        commands::{
+            FIX_ALL_COMMAND_ID, FIX_ALL_RULE_COMMAND_ID, FixAllCommandArgs, FixAllRuleCommandArgs,
+        },
+        config_edit::disable_rule_text_edit,
         error_with_position::{
             DiagnosticReport, LinterCodeAction, create_unused_directives_report,
             generate_inverted_diagnostics, message_to_lsp_diagnostic,
         },
+        folding_ranges::folding_ranges,
         lsp_file_system::LspFileSystem,
         options::{
             LintOptions as LSPLintOptions, RulesCustomization, Run, UnusedDisableDirectives,
         },
-        utils::range_overlaps,
+        selection_ranges::selection_ranges,
+        semantic_tokens::{self, semantic_tokens},
+        utils::{range_overlaps, with_semantic},
     },
     utils::normalize_path,
 };
@@ -124,6 +138,8 @@ impl ServerLinterBuilder {
             }
         };
         materialize_default_plugins(&mut oxlintrc);
+        let root_config_path =
+            (!oxlintrc.path.as_os_str().is_empty()).then(|| oxlintrc.path.clone());
 
         let mut nested_ignore_patterns = Vec::new();
         let mut extended_paths = FxHashSet::default();
@@ -210,8 +226,10 @@ impl ServerLinterBuilder {
 
         let linter = Linter::new(lint_options, config_store, external_linter.cloned())
             .with_workspace_uri(Some(root_uri.as_str()));
-        let mut lint_service_options =
-            LintServiceOptions::new(root_path.clone()).with_cross_module(use_cross_module);
+        // Keep linting files while they are edited, even if they have syntax errors.
+        let mut lint_service_options = LintServiceOptions::new(root_path.clone())
+            .with_cross_module(use_cross_module)
+            .with_recover_from_errors(true);
 
         if let Some(ts_path) = options.ts_config_path.as_ref() {
             let ts_path = Path::new(ts_path).to_path_buf();
@@ -253,6 +271,8 @@ impl ServerLinterBuilder {
                 fix_kind,
                 lint_options.report_unused_directive,
                 options.rules_customization,
+                use_nested_config,
+                root_config_path,
             ),
             Vec::new(),
         )
@@ -278,7 +298,7 @@ impl ToolBuilder for ServerLinterBuilder {
             }));
 
         capabilities.execute_command_provider = Some(ExecuteCommandOptions {
-            commands: vec![FIX_ALL_COMMAND_ID.to_string()],
+            commands: vec![FIX_ALL_COMMAND_ID.to_string(), FIX_ALL_RULE_COMMAND_ID.to_string()],
             work_done_progress_options: WorkDoneProgressOptions::default(),
         });
 
@@ -298,6 +318,18 @@ impl ToolBuilder for ServerLinterBuilder {
             } else {
                 None
             };
+
+        // syntax-aware highlighting and editing, computed from the AST and semantic model
+        capabilities.semantic_tokens_provider =
+            Some(SemanticTokensServerCapabilities::SemanticTokensOptions(SemanticTokensOptions {
+                work_done_progress_options: WorkDoneProgressOptions::default(),
+                legend: semantic_tokens::legend(),
+                range: None,
+                full: Some(SemanticTokensFullOptions::Delta { delta: Some(true) }),
+            }));
+        capabilities.folding_range_provider = Some(FoldingRangeProviderCapability::Simple(true));
+        capabilities.selection_range_provider =
+            Some(SelectionRangeProviderCapability::Simple(true));
     }
 
     fn build(&self, root_uri: &Uri, options: serde_json::Value) -> ToolBuildResult {
@@ -412,6 +444,9 @@ pub struct ServerLinter {
     fix_kind: FixKind,
     unused_directives_severity: Option<AllowWarnDeny>,
     rules_customization: Option<RulesCustomization>,
+    use_nested_config: bool,
+    /// The config file found for the workspace root, or passed with `configPath`.
+    root_config_path: Option<PathBuf>,
 }
 
 impl Tool for ServerLinter {
@@ -540,42 +575,11 @@ impl Tool for ServerLinter {
         command: &str,
         arguments: Vec<serde_json::Value>,
     ) -> Result<Option<WorkspaceEdit>, ErrorCode> {
-        if command != FIX_ALL_COMMAND_ID {
-            return Err(ErrorCode::InvalidParams);
-        }
-
-        let args = FixAllCommandArgs::try_from(arguments).map_err(|_| ErrorCode::InvalidParams)?;
-        let uri: Uri = args.uri.parse().map_err(|_| ErrorCode::InvalidParams)?;
-
-        if !self.is_responsible_for_uri(&uri) {
-            return Ok(None);
-        }
-
-        // We only run the lint process when the code action is explicitly invoked and the file is not open in the editor.
-        let is_open = None;
-        let actions =
-            self.get_code_actions_for_uri(&uri, Some(CodeActionTriggerKind::INVOKED), is_open);
-
-        let Some(actions) = actions else {
-            return Ok(None);
-        };
-
-        if actions.is_empty() {
-            return Ok(None);
-        }
-
-        let text_edits = fix_all_text_edit(actions.into_iter());
-
-        if text_edits.is_empty() {
-            return Ok(None);
+        match command {
+            FIX_ALL_COMMAND_ID => self.fix_all_command(arguments),
+            FIX_ALL_RULE_COMMAND_ID => self.fix_all_rule_command(arguments),
+            _ => Err(ErrorCode::InvalidParams),
         }
-
-        Ok(Some(WorkspaceEdit {
-            #[expect(clippy::disallowed_types)]
-            changes: Some(std::collections::HashMap::from([(uri, text_edits)])),
-            document_changes: None,
-            change_annotations: None,
-        }))
     }
 
     fn get_code_actions_or_commands(&self, params: &CodeActionParams) -> Vec<CodeActionOrCommand> {
@@ -593,7 +597,7 @@ impl Tool for ServerLinter {
             return vec![];
         }
 
-        let actions = actions.into_iter().filter(|r| range_overlaps(params.range, r.range));
+        let in_range = actions.iter().filter(|r| range_overlaps(params.range, r.range)).cloned();
 
         // `context.only` is a special case here. ESLint behavior is if `source.fixAll` is the first element in `context.only`,
         // then only return fix all code action, and ignore other code actions, even if they are requested.
@@ -635,7 +639,7 @@ impl Tool for ServerLinter {
             // `CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC` was filtered out by `applying_kinds`, so we don't need to check it here.
             if kind == CodeActionKind::SOURCE_FIX_ALL {
                 let Some(fix_all) = apply_all_fix_code_action(
-                    actions.clone(),
+                    in_range.clone(),
                     params.uri.clone(),
                     self.rules_customization.as_ref(),
                 ) else {
@@ -650,7 +654,7 @@ impl Tool for ServerLinter {
                     continue;
                 }
                 let Some(fix_all) = apply_dangerous_fix_code_action(
-                    actions.clone(),
+                    in_range.clone(),
                     params.uri.clone(),
                     self.rules_customization.as_ref(),
                 ) else {
@@ -658,11 +662,41 @@ impl Tool for ServerLinter {
                 };
                 code_actions_vec.push(CodeActionOrCommand::CodeAction(fix_all));
             } else if kind == CodeActionKind::QUICKFIX {
-                for action in actions.clone() {
+                let mut rules: Vec<String> = vec![];
+                for action in in_range.clone() {
+                    if let Some(rule) = code_action_rule_name(&action)
+                        && !rules.contains(&rule)
+                    {
+                        rules.push(rule);
+                    }
                     let fix_actions = apply_fix_code_actions(action, &params.uri);
                     code_actions_vec
                         .extend(fix_actions.into_iter().map(CodeActionOrCommand::CodeAction));
                 }
+                code_actions_vec.extend(
+                    self.rule_code_actions(&actions, &rules, &params.uri)
+                        .into_iter()
+                        .map(CodeActionOrCommand::CodeAction),
+                );
+            } else if let Some(rule) =
+                kind.as_str().strip_prefix(CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC_RULE_PREFIX)
+            {
+                // Fixes for a single rule are requested with `source.fixAll.oxc.<rule>`,
+                // e.g. as a code action on save.
+                let text_edits = fix_rule_text_edits(
+                    actions.iter().cloned(),
+                    rule,
+                    self.rules_customization.as_ref(),
+                );
+                if text_edits.is_empty() {
+                    continue;
+                }
+                code_actions_vec.push(CodeActionOrCommand::CodeAction(apply_rule_fix_code_action(
+                    text_edits,
+                    params.uri.clone(),
+                    rule,
+                    kind.clone(),
+                )));
             }
         }
 
@@ -698,6 +732,23 @@ impl Tool for ServerLinter {
     fn remove_uri_cache(&self, uri: &Uri) {
         self.code_actions.pin().remove(uri);
     }
+
+    fn semantic_tokens(&self, document: &TextDocument) -> Option<Vec<SemanticToken>> {
+        Self::with_document_semantic(document, semantic_tokens)
+    }
+
+    fn folding_ranges(&self, document: &TextDocument) -> Vec<FoldingRange> {
+        Self::with_document_semantic(document, folding_ranges).unwrap_or_default()
+    }
+
+    fn selection_ranges(
+        &self,
+        document: &TextDocument,
+        positions: &[Position],
+    ) -> Vec<SelectionRange> {
+        Self::with_document_semantic(document, |semantic| selection_ranges(semantic, positions))
+            .unwrap_or_default()
+    }
 }
 
 impl ServerLinter {
@@ -713,6 +764,8 @@ impl ServerLinter {
         fix_kind: FixKind,
         unused_directives_severity: Option<AllowWarnDeny>,
         rules_customization: Option<RulesCustomization>,
+        use_nested_config: bool,
+        root_config_path: Option<PathBuf>,
     ) -> Self {
         Self {
             run,
@@ -725,6 +778,8 @@ impl ServerLinter {
             fix_kind,
             unused_directives_severity,
             rules_customization,
+            use_nested_config,
+            root_config_path,
         }
     }
 
@@ -750,6 +805,186 @@ impl ServerLinter {
         }
     }
 
+    fn fix_all_command(
+        &self,
+        arguments: Vec<serde_json::Value>,
+    ) -> Result<Option<WorkspaceEdit>, ErrorCode> {
+        let args = FixAllCommandArgs::try_from(arguments).map_err(|_| ErrorCode::InvalidParams)?;
+        let uri: Uri = args.uri.parse().map_err(|_| ErrorCode::InvalidParams)?;
+
+        if !self.is_responsible_for_uri(&uri) {
+            return Ok(None);
+        }
+
+        // We only run the lint process when the code action is explicitly invoked and the file is not open in the editor.
+        let is_open = None;
+        let actions =
+            self.get_code_actions_for_uri(&uri, Some(CodeActionTriggerKind::INVOKED), is_open);
+
+        let Some(actions) = actions else {
+            return Ok(None);
+        };
+
+        if actions.is_empty() {
+            return Ok(None);
+        }
+
+        let text_edits = fix_all_text_edit(actions.into_iter());
+
+        if text_edits.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(WorkspaceEdit {
+            #[expect(clippy::disallowed_types)]
+            changes: Some(std::collections::HashMap::from([(uri, text_edits)])),
+            document_changes: None,
+            change_annotations: None,
+        }))
+    }
+
+    /// Fix all problems of a single rule in every linted file of the workspace.
+    /// The files are linted in parallel, and the edits of multiple files are applied at once by the client.
+    fn fix_all_rule_command(
+        &self,
+        arguments: Vec<serde_json::Value>,
+    ) -> Result<Option<WorkspaceEdit>, ErrorCode> {
+        let args =
+            FixAllRuleCommandArgs::try_from(arguments).map_err(|_| ErrorCode::InvalidParams)?;
+
+        #[expect(clippy::disallowed_types)]
+        let changes: std::collections::HashMap<_, _> = self
+            .workspace_files()
+            .into_par_iter()
+            .filter_map(|path| {
+                let uri = Uri::from_file_path(&path)?;
+
+                // Open documents are linted on change, so their cached code actions match the content of the editor,
+                // which can differ from the file on disk.
+                let actions = match self.code_actions.pin().get(&uri) {
+                    Some(Some(actions)) => actions.clone(),
+                    _ => match self.lint_path(&path, &uri, None) {
+                        Ok(reports) => {
+                            reports.into_iter().filter_map(|report| report.code_action).collect()
+                        }
+                        Err(err) => {
+                            warn!("Failed to lint {}: {err}", path.display());
+                            return None;
+                        }
+                    },
+                };
+
+                let text_edits = fix_rule_text_edits(
+                    actions.into_iter(),
+                    &args.rule,
+                    self.rules_customization.as_ref(),
+                );
+                (!text_edits.is_empty()).then_some((uri, text_edits))
+            })
+            .collect();
+
+        if changes.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(WorkspaceEdit { changes: Some(changes), ..WorkspaceEdit::default() }))
+    }
+
+    /// All files of the workspace which are linted, skipping the ones ignored by `.gitignore` files
+    /// and the ignore patterns.
+    #[expect(clippy::filetype_is_file)]
+    fn workspace_files(&self) -> Vec<PathBuf> {
+        ignore::WalkBuilder::new(&self.cwd)
+            .git_global(false)
+            .build()
+            .flatten()
+            .filter(|entry| entry.file_type().is_some_and(|file_type| file_type.is_file()))
+            .map(ignore::DirEntry::into_path)
+            .filter(|path| !self.is_ignored(path))
+            .collect()
+    }
+
+    /// Code actions for all problems of the given rules in the file:
+    /// fixing them in the file or in the workspace, and disabling the rules in the config file.
+    fn rule_code_actions(
+        &self,
+        actions: &[LinterCodeAction],
+        rules: &[String],
+        uri: &Uri,
+    ) -> Vec<CodeAction> {
+        if rules.is_empty() {
+            return vec![];
+        }
+
+        let config = uri.to_file_path().and_then(|path| {
+            let config_path = self.json_config_path(&path)?;
+            let source_text = read_to_string(&config_path).ok()?;
+            Some((Uri::from_file_path(&config_path)?, config_path, source_text))
+        });
+
+        let mut code_actions = vec![];
+        for rule in rules {
+            let text_edits = fix_rule_text_edits(
+                actions.iter().cloned(),
+                rule,
+                self.rules_customization.as_ref(),
+            );
+            if !text_edits.is_empty() {
+                // A single problem is already fixed by its own quick fix.
+                if text_edits.len() > 1 {
+                    code_actions.push(apply_rule_fix_code_action(
+                        text_edits,
+                        uri.clone(),
+                        rule,
+                        CodeActionKind::QUICKFIX,
+                    ));
+                }
+                code_actions.push(apply_rule_fix_in_workspace_code_action(rule));
+            }
+
+            if let Some((config_uri, config_path, source_text)) = &config
+                && let Some(text_edit) = disable_rule_text_edit(source_text, rule)
+            {
+                let config_name = config_path.file_name().unwrap_or_default().to_string_lossy();
+                code_actions.push(disable_rule_in_config_code_action(
+                    rule,
+                    config_uri.clone(),
+                    &config_name,
+                    text_edit,
+                ));
+            }
+        }
+        code_actions
+    }
+
+    /// The JSON config file which configures the rules of the file at `path`.
+    /// JS configs are not returned, because they can not be edited safely.
+    fn json_config_path(&self, path: &Path) -> Option<PathBuf> {
+        let config_path = if self.use_nested_config {
+            find_config_in_ancestors(path).map(|config| config.path().to_path_buf())
+        } else {
+            self.root_config_path.clone()
+        }?;
+        config_path
+            .extension()
+            .is_some_and(|extension| extension == "json" || extension == "jsonc")
+            .then_some(config_path)
+    }
+
+    /// Parse the document, reading it from disk when it is not open, and call `f` with its semantic model.
+    /// Ignored files are parsed too, because they are still highlighted in the editor.
+    fn with_document_semantic<T>(
+        document: &TextDocument,
+        f: impl FnOnce(&Semantic) -> T,
+    ) -> Option<T> {
+        let path = document.uri.to_file_path()?;
+        let source_text = match document.text.as_deref() {
+            Some(text) => Cow::Borrowed(text),
+            None => Cow::Owned(read_to_string(&path).ok()?),
+        };
+        with_semantic(&path, &source_text, f)
+    }
+
     fn is_lintable_extension(path: &Path) -> bool {
         static WANTED_EXTENSIONS: OnceLock<FxHashSet<&'static str>> = OnceLock::new();
         let wanted_exts =
@@ -890,7 +1125,9 @@ impl ServerLinter {
 #[cfg(test)]
 mod tests_builder {
     use tower_lsp_server::ls_types::{
-        CodeActionKind, CodeActionProviderCapability, ServerCapabilities,
+        CodeActionKind, CodeActionProviderCapability, FoldingRangeProviderCapability,
+        SelectionRangeProviderCapability, SemanticTokensFullOptions,
+        SemanticTokensServerCapabilities, ServerCapabilities,
     };
 
     use oxc_language_server::{Capabilities, DiagnosticMode, ToolBuilder};
@@ -899,7 +1136,7 @@ mod tests_builder {
         code_actions::{
             CODE_ACTION_KIND_SOURCE_FIX_ALL_DANGEROUS_OXC, CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC,
         },
-        commands::FIX_ALL_COMMAND_ID,
+        commands::{FIX_ALL_COMMAND_ID, FIX_ALL_RULE_COMMAND_ID},
         server_linter::ServerLinterBuilder,
     };
 
@@ -926,7 +1163,28 @@ mod tests_builder {
         // Should set execute command provider with fix all command
         let execute_command_provider = capabilities.execute_command_provider.as_ref().unwrap();
         assert!(execute_command_provider.commands.contains(&FIX_ALL_COMMAND_ID.to_string()));
-        assert_eq!(execute_command_provider.commands.len(), 1);
+        assert!(execute_command_provider.commands.contains(&FIX_ALL_RULE_COMMAND_ID.to_string()));
+        assert_eq!(execute_command_provider.commands.len(), 2);
+
+        // Should provide full and delta semantic tokens, folding and selection ranges
+        match &capabilities.semantic_tokens_provider {
+            Some(SemanticTokensServerCapabilities::SemanticTokensOptions(options)) => {
+                assert_eq!(
+                    options.full,
+                    Some(SemanticTokensFullOptions::Delta { delta: Some(true) })
+                );
+                assert_eq!(options.legend.token_types.len(), 10);
+            }
+            _ => panic!("Expected semantic tokens provider options"),
+        }
+        assert_eq!(
+            capabilities.folding_range_provider,
+            Some(FoldingRangeProviderCapability::Simple(true))
+        );
+        assert_eq!(
+            capabilities.selection_range_provider,
+            Some(SelectionRangeProviderCapability::Simple(true))
+        );
     }
 
     #[test]
@@ -1134,13 +1392,15 @@ mod test {
     use rustc_hash::FxHashSet;
     use serde_json::json;
     use tower_lsp_server::ls_types::{
-        CodeActionContext, CodeActionKind, CodeActionTriggerKind, Position, Range,
+        CodeActionContext, CodeActionKind, CodeActionOrCommand, CodeActionTriggerKind, Position,
+        Range,
     };
 
     use crate::lsp::{
         code_actions::{
             CODE_ACTION_KIND_SOURCE_FIX_ALL_DANGEROUS_OXC, CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC,
         },
+        commands::FIX_ALL_RULE_COMMAND_ID,
         server_linter::ServerLinterBuilder,
         tester::{Tester, get_file_path},
     };
@@ -1150,7 +1410,7 @@ mod test {
         range: Range,
         context: CodeActionContext,
     ) -> CodeActionParams {
-        CodeActionParams { uri: uri.clone(), range, context, is_open_document: false }
+        CodeActionParams { uri: uri.clone(), range, context, is_open_document: false, text: None }
     }
 
     #[test]
@@ -1244,8 +1504,8 @@ mod test {
         ));
         assert_eq!(
             code_actions.len(),
-            3,
-            "Default Context: Should return 3 code actions: 1 rule fix + 2 ignore actions"
+            4,
+            "Default Context: Should return 4 code actions: 1 rule fix + 2 ignore actions + 1 workspace rule fix"
         );
 
         let code_actions = linter.get_code_actions_or_commands(&code_action_params(
@@ -1256,8 +1516,8 @@ mod test {
 
         assert_eq!(
             code_actions.len(),
-            3,
-            "Quickfix Context: Should return 3 code actions: 1 rule fix + 2 ignore actions"
+            4,
+            "Quickfix Context: Should return 4 code actions: 1 rule fix + 2 ignore actions + 1 workspace rule fix"
         );
 
         let code_actions = linter.get_code_actions_or_commands(&code_action_params(
@@ -1271,8 +1531,8 @@ mod test {
 
         assert_eq!(
             code_actions.len(),
-            4,
-            "Quickfix & FixAll Context: Should return 4 code actions: 1 rule fix + 2 ignore actions, and 1 fix all action"
+            5,
+            "Quickfix & FixAll Context: Should return 5 code actions: 1 rule fix + 2 ignore actions + 1 workspace rule fix, and 1 fix all action"
         );
 
         let code_actions = linter.get_code_actions_or_commands(&code_action_params(
@@ -1290,8 +1550,8 @@ mod test {
 
         assert_eq!(
             code_actions.len(),
-            4,
-            "Quickfix & FixAll Context: Should return 4 code actions even if both `source.fixAll` and `source.fixAll.oxc` are requested,
+            5,
+            "Quickfix & FixAll Context: Should return 5 code actions even if both `source.fixAll` and `source.fixAll.oxc` are requested,
             because they are the same action, we should filter out duplicates."
         );
     }
@@ -1349,6 +1609,98 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_rule_code_actions() {
+        let tester = Tester::new("fixtures/lsp/rule_code_actions", json!({}));
+        let linter = tester.create_linter();
+        let range = Range::new(Position::new(0, 0), Position::new(0, 9));
+        let uri = tester.get_file_uri("first.js");
+        let _ = linter.run_file(&uri, None).unwrap();
+
+        let code_actions = linter.get_code_actions_or_commands(&code_action_params(
+            &uri,
+            range,
+            CodeActionContext::default(),
+        ));
+        let titles: Vec<_> = code_actions
+            .iter()
+            .map(|action| match action {
+                CodeActionOrCommand::CodeAction(action) => action.title.as_str(),
+                CodeActionOrCommand::Command(command) => command.title.as_str(),
+            })
+            .collect();
+        assert_eq!(
+            titles,
+            vec![
+                "Replace `var a = 1;` with `const a = 1;`.",
+                "Disable no-var for this line",
+                "Disable no-var for this whole file",
+                "Fix all no-var problems in this file",
+                "Fix all no-var problems in the workspace",
+                "Disable no-var in .oxlintrc.json",
+            ]
+        );
+
+        let CodeActionOrCommand::CodeAction(fix_in_file) = &code_actions[3] else {
+            panic!("Expected a code action");
+        };
+        let changes = fix_in_file.edit.as_ref().unwrap().changes.as_ref().unwrap();
+        assert_eq!(changes[&uri].len(), 2);
+
+        let CodeActionOrCommand::CodeAction(disable_in_config) = &code_actions[5] else {
+            panic!("Expected a code action");
+        };
+        let changes = disable_in_config.edit.as_ref().unwrap().changes.as_ref().unwrap();
+        let edits = &changes[&tester.get_file_uri(".oxlintrc.json")];
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].new_text, "\"off\"");
+
+        // A single rule can be fixed with `source.fixAll.oxc.<rule>`
+        let kind = CodeActionKind::from("source.fixAll.oxc.no-var".to_string());
+        let code_actions = linter.get_code_actions_or_commands(&code_action_params(
+            &uri,
+            range,
+            CodeActionContext { only: Some(vec![kind.clone()]), ..Default::default() },
+        ));
+        assert_eq!(code_actions.len(), 1);
+        let CodeActionOrCommand::CodeAction(fix_rule) = &code_actions[0] else {
+            panic!("Expected a code action");
+        };
+        assert_eq!(fix_rule.kind, Some(kind));
+
+        let code_actions = linter.get_code_actions_or_commands(&code_action_params(
+            &uri,
+            range,
+            CodeActionContext {
+                only: Some(vec![CodeActionKind::from("source.fixAll.oxc.no-debugger".to_string())]),
+                ..Default::default()
+            },
+        ));
+        assert!(code_actions.is_empty());
+    }
+
+    #[test]
+    fn test_fix_all_rule_command() {
+        let tester = Tester::new("fixtures/lsp/rule_code_actions", json!({}));
+        let linter = tester.create_linter();
+
+        let edit = linter
+            .execute_command(FIX_ALL_RULE_COMMAND_ID, vec![json!({ "rule": "no-var" })])
+            .unwrap()
+            .unwrap();
+        let changes = edit.changes.unwrap();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[&tester.get_file_uri("first.js")].len(), 2);
+        assert_eq!(changes[&tester.get_file_uri("second.js")].len(), 1);
+
+        let edit = linter
+            .execute_command(FIX_ALL_RULE_COMMAND_ID, vec![json!({ "rule": "no-debugger" })])
+            .unwrap();
+        assert!(edit.is_none());
+
+        assert!(linter.execute_command(FIX_ALL_RULE_COMMAND_ID, vec![]).is_err());
+    }
+
     #[test]
     fn test_no_errors() {
         Tester::new("fixtures/lsp/no_errors", json!({}))
@@ -1386,8 +1738,11 @@ mod test {
 
     #[test]
     fn test_invalid_syntax_file() {
-        Tester::new("fixtures/lsp/invalid_syntax", json!({}))
-            .test_and_snapshot_multiple_file(&["debugger.ts", "invalid.vue"]);
+        Tester::new("fixtures/lsp/invalid_syntax", json!({})).test_and_snapshot_multiple_file(&[
+            "debugger.ts",
+            "invalid.vue",
+            "recovered.js",
+        ]);
     }
 
     #[test]
@@ -105,6 +105,9 @@ tags: {tags:?}"
 
 fn get_snapshot_for_code_action(code_action: &CodeAction) -> String {
     let Some(edits) = &code_action.edit else {
+        if let Some(command) = &code_action.command {
+            return format!("Title: {}\nCommand: {command:#?}\n", code_action.title);
+        }
         return "None Workspace edits".to_string();
     };
 
@@ -237,6 +240,7 @@ impl Tester<'_> {
                         range,
                         context: context.clone(),
                         is_open_document: false,
+                        text: None,
                     },
                 ),
                 fix_all_action: linter
@@ -245,6 +249,7 @@ impl Tester<'_> {
                         range,
                         context: fix_all_context.clone(),
                         is_open_document: false,
+                        text: None,
                     })
                     .into_iter()
                     .next(),
@@ -1,6 +1,10 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, path::Path};
 
+use oxc_allocator::Allocator;
 use oxc_diagnostics::OxcCode;
+use oxc_parser::{ParseOptions, Parser};
+use oxc_semantic::{Semantic, SemanticBuilder};
+use oxc_span::SourceType;
 use tower_lsp_server::ls_types::Range;
 
 /// Returns `true` if LSP ranges `a` and `b` overlap or touch (share a boundary point).
@@ -29,6 +33,26 @@ pub fn get_full_rule_name(rule_code: &OxcCode) -> Option<Cow<'_, str>> {
     }
 }
 
+/// Parse the JavaScript or TypeScript `source_text` of the file at `path` and call `f` with its semantic model.
+///
+/// Returns `None` if the file is not a JavaScript or TypeScript file, or the parser could not recover from a syntax error.
+pub(super) fn with_semantic<T>(
+    path: &Path,
+    source_text: &str,
+    f: impl FnOnce(&Semantic) -> T,
+) -> Option<T> {
+    let source_type = SourceType::from_path(path).ok()?;
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type)
+        .with_options(ParseOptions { recover_from_errors: true, ..ParseOptions::default() })
+        .parse();
+    if ret.panicked {
+        return None;
+    }
+    let semantic = SemanticBuilder::new().with_build_nodes(true).build(&ret.program).semantic;
+    Some(f(&semantic))
+}
+
 #[cfg(test)]
 mod test {
     use tower_lsp_server::ls_types::{Position, Range};
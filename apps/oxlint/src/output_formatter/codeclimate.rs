@@ -0,0 +1,331 @@
+use std::path::{Path, PathBuf};
+
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+
+use oxc_diagnostics::{
+    Error, Severity,
+    reporter::{DiagnosticReporter, DiagnosticResult, Info},
+};
+use oxc_linter::{
+    RuleCategory,
+    rules::{RULES, RuleEnum},
+};
+
+use crate::output_formatter::{
+    InternalFormatter,
+    gitlab::get_repo_path_prefix,
+    sarif::{build_rule_lookup, normalize_uri},
+};
+
+#[derive(Debug, Default)]
+pub struct CodeClimateOutputFormatter;
+
+impl InternalFormatter for CodeClimateOutputFormatter {
+    fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter> {
+        Box::new(CodeClimateReporter::default())
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct CodeClimatePositionJson {
+    line: usize,
+    column: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct CodeClimatePositionsJson {
+    begin: CodeClimatePositionJson,
+    end: CodeClimatePositionJson,
+}
+
+#[derive(Debug, Serialize)]
+struct CodeClimateLocationJson {
+    path: String,
+    positions: CodeClimatePositionsJson,
+}
+
+#[derive(Debug, Serialize)]
+struct CodeClimateContentJson {
+    body: String,
+}
+
+#[derive(Debug, Serialize)]
+struct CodeClimateIssueJson {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    check_name: String,
+    description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<CodeClimateContentJson>,
+    categories: Vec<&'static str>,
+    location: CodeClimateLocationJson,
+    severity: &'static str,
+    fingerprint: String,
+}
+
+/// Renders reports as a list of Code Climate issues.
+///
+/// <https://github.com/codeclimate/platform/blob/master/spec/analyzers/SPEC.md#data-types>
+///
+/// Fingerprints only depend on the rule, the file path, the reported source text and the
+/// number of identical reports before it in the same file. They stay the same when code is
+/// moved around, and are identical on every platform.
+///
+/// Note that, due to syntactic restrictions of JSON arrays, this reporter waits until all
+/// diagnostics have been reported before writing them to the output stream.
+struct CodeClimateReporter {
+    diagnostics: Vec<Error>,
+    /// Path prefix to prepend to CWD-relative paths to make them repo-relative.
+    /// `None` if CWD is the git root or if we're not in a git repository.
+    repo_path_prefix: Option<PathBuf>,
+}
+
+impl Default for CodeClimateReporter {
+    fn default() -> Self {
+        Self { diagnostics: Vec::new(), repo_path_prefix: get_repo_path_prefix() }
+    }
+}
+
+impl DiagnosticReporter for CodeClimateReporter {
+    fn finish(&mut self, _: &DiagnosticResult) -> Option<String> {
+        Some(format_codeclimate(&mut self.diagnostics, self.repo_path_prefix.as_deref()))
+    }
+
+    fn supports_minified_file_fallback(&self) -> bool {
+        false
+    }
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        self.diagnostics.push(error);
+        None
+    }
+}
+
+/// The check name of diagnostics without a code, such as parse errors and unused disable
+/// directives. Code Climate requires a non-empty check name, and GitLab groups issues by it.
+const UNCODED_CHECK_NAME: &str = "oxc/diagnostic";
+
+fn format_codeclimate(diagnostics: &mut Vec<Error>, repo_path_prefix: Option<&Path>) -> String {
+    let rule_lookup = build_rule_lookup();
+    // Number of identical (path, rule, source text) reports seen so far,
+    // used to tell apart fingerprints of repeated occurrences.
+    let mut occurrences: FxHashMap<u64, usize> = FxHashMap::default();
+
+    // Diagnostics are reported in the order in which files finish linting, which changes
+    // between runs. Sort them so that occurrences, and therefore fingerprints, are stable.
+    let mut diagnostics = diagnostics
+        .drain(..)
+        .map(|error| (Info::new(&error), labeled_source_text(&error), error))
+        .collect::<Vec<_>>();
+    diagnostics.sort_by(|(a, a_snippet, _), (b, b_snippet, _)| {
+        (&a.filename, &a.start, &a.end, &a.rule_id, &a.message, a_snippet).cmp(&(
+            &b.filename,
+            &b.start,
+            &b.end,
+            &b.rule_id,
+            &b.message,
+            b_snippet,
+        ))
+    });
+
+    let issues = diagnostics
+        .into_iter()
+        .map(|(info, snippet, error)| {
+            let Info { start, end, filename, message, severity, rule_id } = info;
+            let rule = rule_id.as_deref().and_then(|id| RULES.get(*rule_lookup.get(id)?));
+            let check_name = rule_id.unwrap_or_else(|| UNCODED_CHECK_NAME.to_string());
+            let path = match repo_path_prefix {
+                Some(prefix) if !filename.is_empty() => {
+                    normalize_uri(&prefix.join(&filename).to_string_lossy())
+                }
+                _ => normalize_uri(&filename),
+            };
+
+            let fingerprint = {
+                let base = fnv1a_64(&[path.as_bytes(), check_name.as_bytes(), snippet.as_bytes()]);
+                let occurrence = occurrences.entry(base).or_default();
+                *occurrence += 1;
+                let occurrence = occurrence.to_string();
+                format!("{:016x}", fnv1a_64(&[&base.to_le_bytes(), occurrence.as_bytes()]))
+            };
+
+            CodeClimateIssueJson {
+                kind: "issue",
+                content: error.help().map(|help| CodeClimateContentJson { body: help.to_string() }),
+                description: if message.is_empty() { error.to_string() } else { message },
+                categories: vec![rule.map_or("Bug Risk", rule_category)],
+                location: CodeClimateLocationJson {
+                    path,
+                    positions: CodeClimatePositionsJson {
+                        begin: CodeClimatePositionJson {
+                            line: start.line.max(1),
+                            column: start.column.max(1),
+                        },
+                        end: CodeClimatePositionJson {
+                            line: end.line.max(1),
+                            column: end.column.max(1),
+                        },
+                    },
+                },
+                severity: match severity {
+                    Severity::Error => "critical",
+                    Severity::Warning => "major",
+                    Severity::Advice => "minor",
+                },
+                check_name,
+                fingerprint,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    serde_json::to_string_pretty(&issues).expect("Failed to serialize")
+}
+
+/// Maps an oxlint rule category to one of the categories defined by the Code Climate spec.
+fn rule_category(rule: &RuleEnum) -> &'static str {
+    match rule.category() {
+        RuleCategory::Correctness | RuleCategory::Suspicious | RuleCategory::Nursery => "Bug Risk",
+        RuleCategory::Perf => "Performance",
+        RuleCategory::Pedantic | RuleCategory::Style => "Style",
+        RuleCategory::Restriction => "Compatibility",
+    }
+}
+
+/// Returns the source text covered by the primary label of a diagnostic,
+/// or an empty string if the diagnostic has no source.
+fn labeled_source_text(error: &Error) -> String {
+    let Some(source) = error.source_code() else { return String::new() };
+    let Some(label) = error.labels().first() else { return String::new() };
+    let span = label.span();
+    source
+        .data()
+        .get(span.start as usize..span.end as usize)
+        .map(|bytes| String::from_utf8_lossy(bytes).into_owned())
+        .unwrap_or_default()
+}
+
+/// 64-bit FNV-1a hash over each part, with a separator between parts.
+///
+/// `std`'s `DefaultHasher` is not guaranteed to be stable across Rust releases,
+/// which would change every fingerprint after a toolchain update.
+fn fnv1a_64(parts: &[&[u8]]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    let mut hash = OFFSET_BASIS;
+    for part in parts {
+        for byte in part.iter().chain(std::iter::once(&0)) {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(PRIME);
+        }
+    }
+    hash
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_diagnostics::{Error, NamedSource, OxcDiagnostic};
+    use oxc_span::Span;
+
+    use super::format_codeclimate;
+
+    fn render(diagnostics: Vec<Error>, prefix: Option<&Path>) -> serde_json::Value {
+        let mut diagnostics = diagnostics;
+        serde_json::from_str(&format_codeclimate(&mut diagnostics, prefix)).unwrap()
+    }
+
+    #[test]
+    fn issue_shape() {
+        let error = OxcDiagnostic::warn("Unexpected debugger statement")
+            .with_error_code("eslint", "no-debugger")
+            .with_help("Remove the debugger statement")
+            .with_label(Span::new(0, 8))
+            .with_source_code(NamedSource::new("test.ts", "debugger;"));
+
+        let output = render(vec![error], Some(Path::new("packages/foo")));
+        let issue = &output[0];
+
+        assert_eq!(issue["type"], "issue");
+        assert_eq!(issue["check_name"], "eslint(no-debugger)");
+        assert_eq!(issue["description"], "Unexpected debugger statement");
+        assert_eq!(issue["content"]["body"], "Remove the debugger statement");
+        assert_eq!(issue["categories"][0], "Bug Risk");
+        assert_eq!(issue["severity"], "major");
+        assert_eq!(issue["location"]["path"], "packages/foo/test.ts");
+        assert_eq!(issue["location"]["positions"]["begin"]["line"], 1);
+        assert_eq!(issue["location"]["positions"]["begin"]["column"], 1);
+        assert_eq!(issue["location"]["positions"]["end"]["line"], 1);
+        assert_eq!(issue["location"]["positions"]["end"]["column"], 9);
+        assert_eq!(issue["fingerprint"].as_str().unwrap().len(), 16);
+    }
+
+    #[test]
+    fn fingerprints_are_stable_across_line_shifts() {
+        let diagnostic = |source: &'static str, start: u32| {
+            OxcDiagnostic::warn("Unexpected debugger statement")
+                .with_error_code("eslint", "no-debugger")
+                .with_label(Span::new(start, start + 8))
+                .with_source_code(NamedSource::new("test.js", source))
+        };
+
+        let before = render(vec![diagnostic("debugger;", 0)], None);
+        let after = render(vec![diagnostic("\n\n\ndebugger;", 3)], None);
+
+        assert_eq!(before[0]["fingerprint"], after[0]["fingerprint"]);
+        assert_ne!(before[0]["location"], after[0]["location"]);
+    }
+
+    #[test]
+    fn repeated_occurrences_get_distinct_fingerprints() {
+        let source = "debugger;\ndebugger;";
+        let diagnostic = |start: u32| {
+            OxcDiagnostic::warn("Unexpected debugger statement")
+                .with_error_code("eslint", "no-debugger")
+                .with_label(Span::new(start, start + 8))
+                .with_source_code(NamedSource::new("test.js", source))
+        };
+
+        let output = render(vec![diagnostic(0), diagnostic(10)], None);
+
+        assert_ne!(output[0]["fingerprint"], output[1]["fingerprint"]);
+    }
+
+    #[test]
+    fn fingerprints_do_not_depend_on_report_order() {
+        let diagnostic = |filename: &'static str, start: u32| {
+            OxcDiagnostic::warn("Unexpected debugger statement")
+                .with_error_code("eslint", "no-debugger")
+                .with_label(Span::new(start, start + 8))
+                .with_source_code(NamedSource::new(filename, "debugger;\ndebugger;"))
+        };
+
+        let forward = render(
+            vec![diagnostic("a.js", 0), diagnostic("a.js", 10), diagnostic("b.js", 0)],
+            None,
+        );
+        let backward = render(
+            vec![diagnostic("b.js", 0), diagnostic("a.js", 10), diagnostic("a.js", 0)],
+            None,
+        );
+
+        assert_eq!(forward, backward);
+    }
+
+    #[test]
+    fn diagnostic_without_rule() {
+        let error = OxcDiagnostic::error("Expected `;` but found `:`")
+            .with_label(Span::new(0, 1))
+            .with_source_code(NamedSource::new("parser-error.js", ":"));
+
+        let output = render(vec![error], None);
+        let issue = &output[0];
+
+        assert_eq!(issue["check_name"], "oxc/diagnostic");
+        assert_eq!(issue["categories"][0], "Bug Risk");
+        assert_eq!(issue["severity"], "critical");
+        assert!(issue.get("content").is_none());
+    }
+}
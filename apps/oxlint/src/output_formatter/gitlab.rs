@@ -68,7 +68,7 @@ fn find_git_root_from(start: &Path) -> Option<PathBuf> {
 ///
 /// For example, if git root is `/repo` and CWD is `/repo/packages/foo`,
 /// this returns `Some("packages/foo")`.
-fn get_repo_path_prefix() -> Option<PathBuf> {
+pub(super) fn get_repo_path_prefix() -> Option<PathBuf> {
     let cwd = std::env::current_dir().ok()?;
     let git_root = find_git_root()?;
 
@@ -0,0 +1,424 @@
+use std::{collections::BTreeMap, fmt::Write};
+
+use rustc_hash::FxHashMap;
+
+use oxc_diagnostics::{
+    Error, LabeledSpan, Severity,
+    reporter::{DiagnosticReporter, DiagnosticResult, Info},
+};
+use oxc_linter::rules::RULES;
+
+use crate::output_formatter::{InternalFormatter, sarif::build_rule_lookup, xml_utils::xml_escape};
+
+/// Number of unlabeled lines shown above and below each label in a code frame.
+const CONTEXT_LINES: usize = 1;
+
+const STYLE: &str = r"
+body { font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', Helvetica, Arial, sans-serif; margin: 0; color: #1f2328; background: #f6f8fa; }
+header { padding: 16px 24px; background: #fff; border-bottom: 1px solid #d0d7de; }
+header h1 { margin: 0 0 8px; font-size: 20px; }
+body > input { position: absolute; opacity: 0; pointer-events: none; }
+.controls { padding: 12px 24px; }
+.controls > label { margin-right: 16px; cursor: pointer; color: #59636e; }
+.controls > label::before { content: '\2610  '; }
+#group-rule:checked ~ .controls > label[for=group-rule],
+#group-file:checked ~ .controls > label[for=group-file],
+#show-error:checked ~ .controls > label[for=show-error],
+#show-warning:checked ~ .controls > label[for=show-warning],
+#show-advice:checked ~ .controls > label[for=show-advice] { color: #1f2328; font-weight: 600; }
+#group-rule:checked ~ .controls > label[for=group-rule]::before,
+#group-file:checked ~ .controls > label[for=group-file]::before,
+#show-error:checked ~ .controls > label[for=show-error]::before,
+#show-warning:checked ~ .controls > label[for=show-warning]::before,
+#show-advice:checked ~ .controls > label[for=show-advice]::before { content: '\2611  '; }
+main { padding: 0 24px 12px; }
+details.group { background: #fff; border: 1px solid #d0d7de; border-radius: 6px; margin-bottom: 12px; }
+details.group > summary { padding: 8px 12px; cursor: pointer; font-weight: 600; }
+.diagnostic { border-top: 1px solid #d0d7de; padding: 8px 12px; }
+.severity { display: inline-block; min-width: 60px; font-weight: 600; }
+.severity-error .severity { color: #cf222e; }
+.severity-warning .severity { color: #9a6700; }
+.severity-advice .severity { color: #0969da; }
+.location { color: #59636e; font-family: ui-monospace, SFMono-Regular, Menlo, monospace; }
+.help { color: #59636e; margin: 4px 0; }
+table.frame { border-collapse: collapse; font-family: ui-monospace, SFMono-Regular, Menlo, monospace; font-size: 12px; margin-top: 6px; width: 100%; background: #f6f8fa; }
+table.frame td { padding: 0 8px; white-space: pre; vertical-align: top; }
+table.frame td.ln { color: #8c959f; text-align: right; width: 1%; user-select: none; }
+table.frame tr.note td.code { color: #cf222e; }
+table.frame tr.gap td { color: #8c959f; }
+mark { background: #ffebe9; color: inherit; text-decoration: underline wavy #cf222e; }
+#by-rule, #by-file { display: none; }
+#group-rule:checked ~ main #by-rule, #group-file:checked ~ main #by-file { display: block; }
+#show-error:not(:checked) ~ main .severity-error,
+#show-warning:not(:checked) ~ main .severity-warning,
+#show-advice:not(:checked) ~ main .severity-advice { display: none; }
+";
+
+#[derive(Debug, Default)]
+pub struct HtmlOutputFormatter;
+
+impl InternalFormatter for HtmlOutputFormatter {
+    fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter> {
+        Box::<HtmlReporter>::default()
+    }
+}
+
+/// Renders reports as a self-contained HTML document.
+///
+/// Diagnostics are listed twice, grouped by rule and grouped by file, with code frames
+/// rendered from the diagnostic labels. Switching between the groupings and filtering by
+/// severity is done with CSS sibling selectors only, so the report works without scripts.
+///
+/// Note that this reporter waits until all diagnostics have been reported before writing
+/// the document to the output stream.
+#[derive(Debug, Default)]
+struct HtmlReporter {
+    diagnostics: Vec<Error>,
+}
+
+impl DiagnosticReporter for HtmlReporter {
+    fn finish(&mut self, result: &DiagnosticResult) -> Option<String> {
+        Some(format_html(&mut self.diagnostics, result))
+    }
+
+    fn supports_minified_file_fallback(&self) -> bool {
+        false
+    }
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        self.diagnostics.push(error);
+        None
+    }
+}
+
+struct HtmlDiagnostic {
+    info: Info,
+    severity: Severity,
+    offset: u32,
+    error: Error,
+}
+
+impl HtmlDiagnostic {
+    fn new(error: Error) -> Self {
+        let info = Info::new(&error);
+        let severity = error.severity().unwrap_or(Severity::Error);
+        let offset = error.labels().first().map_or(0, LabeledSpan::offset);
+        Self { info, severity, offset, error }
+    }
+
+    fn severity_name(&self) -> &'static str {
+        match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Advice => "advice",
+        }
+    }
+}
+
+fn format_html(diagnostics: &mut Vec<Error>, result: &DiagnosticResult) -> String {
+    let mut diagnostics: Vec<HtmlDiagnostic> =
+        diagnostics.drain(..).map(HtmlDiagnostic::new).collect();
+    diagnostics.sort_by(|a, b| {
+        (&a.info.filename, a.offset, &a.info.rule_id).cmp(&(
+            &b.info.filename,
+            b.offset,
+            &b.info.rule_id,
+        ))
+    });
+
+    let rule_lookup = build_rule_lookup();
+    let advices_count = diagnostics.iter().filter(|d| d.severity == Severity::Advice).count();
+
+    let mut by_rule: BTreeMap<&str, Vec<&HtmlDiagnostic>> = BTreeMap::new();
+    let mut by_file: BTreeMap<&str, Vec<&HtmlDiagnostic>> = BTreeMap::new();
+    for diagnostic in &diagnostics {
+        by_rule
+            .entry(diagnostic.info.rule_id.as_deref().unwrap_or(""))
+            .or_default()
+            .push(diagnostic);
+        by_file.entry(&diagnostic.info.filename).or_default().push(diagnostic);
+    }
+
+    let mut output = String::new();
+    output.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    output.push_str("<title>Oxlint Report</title>\n<style>");
+    output.push_str(STYLE);
+    output.push_str("</style>\n</head>\n<body>\n");
+
+    writeln!(
+        output,
+        "<header><h1>Oxlint Report</h1><div>{} ({}, {}, {}) in {}</div></header>",
+        plural(diagnostics.len(), "problem"),
+        plural(result.errors_count(), "error"),
+        plural(result.warnings_count(), "warning"),
+        plural(advices_count, "advice"),
+        plural(by_file.len(), "file"),
+    )
+    .unwrap();
+
+    // The inputs must be siblings preceding `.controls` and `<main>` so that the CSS can
+    // highlight their labels and toggle the content. They are hidden, and toggled with
+    // their labels.
+    output.push_str(concat!(
+        "<input type=\"radio\" name=\"group\" id=\"group-rule\" checked>\n",
+        "<input type=\"radio\" name=\"group\" id=\"group-file\">\n",
+        "<input type=\"checkbox\" id=\"show-error\" checked>\n",
+        "<input type=\"checkbox\" id=\"show-warning\" checked>\n",
+        "<input type=\"checkbox\" id=\"show-advice\" checked>\n",
+        "<div class=\"controls\">\n",
+        "Group by:\n",
+        "<label for=\"group-rule\">Rule</label>\n",
+        "<label for=\"group-file\">File</label>\n",
+        "Show:\n",
+        "<label for=\"show-error\">Errors</label>\n",
+        "<label for=\"show-warning\">Warnings</label>\n",
+        "<label for=\"show-advice\">Advices</label>\n",
+        "</div>\n",
+        "<main>\n",
+    ));
+
+    output.push_str("<section id=\"by-rule\">\n");
+    for (rule_id, diagnostics) in &by_rule {
+        output.push_str("<details class=\"group\" open><summary>");
+        if rule_id.is_empty() {
+            output.push_str("Diagnostics without a rule");
+        } else {
+            let docs_url = diagnostics[0].error.url().map(|url| url.to_string()).or_else(|| {
+                let rule = RULES.get(*rule_lookup.get(*rule_id)?)?;
+                Some(format!(
+                    "https://oxc.rs/docs/guide/usage/linter/rules/{}/{}.html",
+                    rule.plugin_name(),
+                    rule.name()
+                ))
+            });
+            match docs_url {
+                Some(url) => {
+                    write!(output, "<a href=\"{}\">{}</a>", xml_escape(&url), xml_escape(rule_id))
+                        .unwrap();
+                }
+                None => output.push_str(&xml_escape(rule_id)),
+            }
+        }
+        writeln!(output, " ({})</summary>", diagnostics.len()).unwrap();
+        for diagnostic in diagnostics {
+            render_diagnostic(&mut output, diagnostic, true);
+        }
+        output.push_str("</details>\n");
+    }
+    output.push_str("</section>\n<section id=\"by-file\">\n");
+    for (filename, diagnostics) in &by_file {
+        let filename = if filename.is_empty() { "Diagnostics without a file" } else { filename };
+        writeln!(
+            output,
+            "<details class=\"group\" open><summary>{} ({})</summary>",
+            xml_escape(filename),
+            diagnostics.len()
+        )
+        .unwrap();
+        for diagnostic in diagnostics {
+            render_diagnostic(&mut output, diagnostic, false);
+        }
+        output.push_str("</details>\n");
+    }
+    output.push_str("</section>\n</main>\n</body>\n</html>\n");
+
+    output
+}
+
+/// `count` followed by `noun`, in the plural unless `count` is 1.
+fn plural(count: usize, noun: &str) -> String {
+    format!("{count} {noun}{}", if count == 1 { "" } else { "s" })
+}
+
+fn render_diagnostic(output: &mut String, diagnostic: &HtmlDiagnostic, show_file: bool) {
+    let HtmlDiagnostic { info, error, .. } = diagnostic;
+    let severity = diagnostic.severity_name();
+
+    write!(
+        output,
+        "<div class=\"diagnostic severity-{severity}\"><span class=\"severity\">{severity}</span> {}",
+        xml_escape(&error.to_string())
+    )
+    .unwrap();
+
+    let location = match (show_file, info.start.line) {
+        (true, 0) => xml_escape(&info.filename).into_owned(),
+        (true, line) => format!("{}:{line}:{}", xml_escape(&info.filename), info.start.column),
+        (false, 0) => String::new(),
+        (false, line) => format!("{line}:{}", info.start.column),
+    };
+    if !location.is_empty() {
+        write!(output, " <span class=\"location\">{location}</span>").unwrap();
+    }
+    if !show_file && let Some(rule_id) = &info.rule_id {
+        write!(output, " <span class=\"location\">{}</span>", xml_escape(rule_id)).unwrap();
+    }
+    if let Some(help) = error.help() {
+        write!(output, "<div class=\"help\">help: {}</div>", xml_escape(&help)).unwrap();
+    }
+    if let Some(source) = error.source_code()
+        && let Ok(source_text) = std::str::from_utf8(source.data())
+    {
+        render_code_frame(output, source_text, error.labels());
+    }
+    output.push_str("</div>\n");
+}
+
+/// Renders the lines covered by `labels` (plus [`CONTEXT_LINES`] around them) as a table,
+/// wrapping the labeled text in `<mark>` and printing label messages below their last line.
+///
+/// Overlapping labels are skipped, only the first one (by offset) is highlighted.
+fn render_code_frame(output: &mut String, source_text: &str, labels: &[LabeledSpan]) {
+    let mut labels: Vec<&LabeledSpan> = labels
+        .iter()
+        .filter(|label| {
+            source_text.get(label.span().start as usize..label.span().end as usize).is_some()
+        })
+        .collect();
+    labels.sort_by_key(|label| label.offset());
+    let mut last_end = None;
+    labels.retain(|label| {
+        let keep = last_end.is_none_or(|end| label.offset() >= end);
+        if keep {
+            last_end = Some(label.span().end);
+        }
+        keep
+    });
+    if labels.is_empty() {
+        return;
+    }
+
+    let line_starts: Vec<usize> = std::iter::once(0)
+        .chain(source_text.match_indices('\n').map(|(index, _)| index + 1))
+        .collect();
+    let line_of = |offset: usize| line_starts.partition_point(|start| *start <= offset) - 1;
+
+    // Lines to display, and the label messages to display after a given line.
+    let mut shown_lines: Vec<(usize, usize)> = Vec::new();
+    let mut notes: FxHashMap<usize, Vec<&str>> = FxHashMap::default();
+    for label in &labels {
+        let span = label.span();
+        let first = line_of(span.start as usize);
+        let last = line_of((span.end as usize).saturating_sub(1).max(span.start as usize));
+        let range = (
+            first.saturating_sub(CONTEXT_LINES),
+            (last + CONTEXT_LINES).min(line_starts.len() - 1),
+        );
+        match shown_lines.last_mut() {
+            Some(previous) if previous.1 + 1 >= range.0 => previous.1 = previous.1.max(range.1),
+            _ => shown_lines.push(range),
+        }
+        if let Some(text) = label.label() {
+            notes.entry(last).or_default().push(text);
+        }
+    }
+
+    output.push_str("<table class=\"frame\">");
+    for (index, (first, last)) in shown_lines.iter().enumerate() {
+        if index > 0 {
+            output.push_str("<tr class=\"gap\"><td class=\"ln\">&#8942;</td><td></td></tr>");
+        }
+        for line in *first..=*last {
+            let line_start = line_starts[line];
+            let line_end = line_starts.get(line + 1).map_or(source_text.len(), |next| next - 1);
+            let line_text = source_text[line_start..line_end].trim_end_matches('\r');
+            let line_end = line_start + line_text.len();
+
+            write!(output, "<tr><td class=\"ln\">{}</td><td class=\"code\">", line + 1).unwrap();
+            let mut cursor = line_start;
+            for label in &labels {
+                let span = label.span();
+                let start = (span.start as usize).max(line_start);
+                let end = (span.end as usize).min(line_end);
+                if start >= end || start < cursor {
+                    continue;
+                }
+                output.push_str(&xml_escape(&source_text[cursor..start]));
+                output.push_str("<mark>");
+                output.push_str(&xml_escape(&source_text[start..end]));
+                output.push_str("</mark>");
+                cursor = end;
+            }
+            output.push_str(&xml_escape(&source_text[cursor..line_end]));
+            output.push_str("</td></tr>");
+
+            for note in notes.get(&line).into_iter().flatten() {
+                write!(
+                    output,
+                    "<tr class=\"note\"><td class=\"ln\"></td><td class=\"code\">&#8593; {}</td></tr>",
+                    xml_escape(note)
+                )
+                .unwrap();
+            }
+        }
+    }
+    output.push_str("</table>");
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_diagnostics::{
+        NamedSource, OxcDiagnostic,
+        reporter::{DiagnosticReporter, DiagnosticResult},
+    };
+    use oxc_span::Span;
+
+    use super::HtmlReporter;
+
+    #[test]
+    fn reporter() {
+        let mut reporter = HtmlReporter::default();
+
+        let error = OxcDiagnostic::warn("Unexpected <debugger> statement")
+            .with_error_code("eslint", "no-debugger")
+            .with_help("Remove the debugger statement")
+            .with_label(Span::new(13, 21).label("here"))
+            .with_source_code(NamedSource::new(
+                "test.js",
+                "let a = 1;\n\n\ndebugger;\nlet b = 2;\n",
+            ));
+
+        let first_result = reporter.render_error(error);
+
+        // reporter keeps it in memory
+        assert!(first_result.is_none());
+
+        let output = reporter.finish(&DiagnosticResult::new(1, 0, false)).unwrap();
+
+        assert!(output.starts_with("<!DOCTYPE html>"));
+        assert!(output.contains("1 problem (0 errors, 1 warning, 0 advices) in 1 file"));
+        // the controls are closed before `<main>`, and `<main>` is closed before `</body>`
+        assert!(output.contains("</div>\n<main>\n"));
+        assert!(output.ends_with("</section>\n</main>\n</body>\n</html>\n"));
+        assert!(output.contains(
+            "<a href=\"https://oxc.rs/docs/guide/usage/linter/rules/eslint/no-debugger.html\">eslint(no-debugger)</a> (1)"
+        ));
+        assert!(output.contains("Unexpected &lt;debugger&gt; statement"));
+        assert!(output.contains("<div class=\"help\">help: Remove the debugger statement</div>"));
+        assert!(
+            output
+                .contains("<td class=\"ln\">4</td><td class=\"code\"><mark>debugger</mark>;</td>")
+        );
+        assert!(output.contains("&#8593; here"));
+        // only the context lines around the label are rendered
+        assert!(output.contains("<td class=\"ln\">3</td>"));
+        assert!(output.contains("<td class=\"ln\">5</td>"));
+        assert!(!output.contains("<td class=\"ln\">1</td>"));
+    }
+
+    #[test]
+    fn multiline_label() {
+        let mut reporter = HtmlReporter::default();
+
+        let error = OxcDiagnostic::error("Unterminated block")
+            .with_label(Span::new(4, 12))
+            .with_source_code(NamedSource::new("test.js", "if (a) {\n  b();\n"));
+
+        reporter.render_error(error);
+        let output = reporter.finish(&DiagnosticResult::new(0, 1, false)).unwrap();
+
+        assert!(output.contains("Diagnostics without a rule (1)"));
+        assert!(output.contains("<td class=\"code\">if (<mark>a) {</mark></td>"));
+        assert!(output.contains("<td class=\"code\"><mark>  b</mark>();</td>"));
+    }
+}
@@ -1,8 +1,10 @@
 mod agent;
 mod checkstyle;
+mod codeclimate;
 mod default;
 mod github;
 mod gitlab;
+mod html;
 mod json;
 mod junit;
 mod sarif;
@@ -15,8 +17,10 @@ use std::time::Duration;
 
 use agent::AgentOutputFormatter;
 use checkstyle::CheckStyleOutputFormatter;
+use codeclimate::CodeClimateOutputFormatter;
 use github::GithubOutputFormatter;
 use gitlab::GitlabOutputFormatter;
+use html::HtmlOutputFormatter;
 use junit::JUnitOutputFormatter;
 use oxc_linter::{OxlintSuppressionFileAction, RuleTimingRecord};
 use rustc_hash::FxHashSet;
@@ -42,6 +46,11 @@ pub enum OutputFormat {
     Stylish,
     JUnit,
     Sarif,
+    /// Self-contained HTML report
+    Html,
+    /// Code Climate issues
+    /// <https://github.com/codeclimate/platform/blob/master/spec/analyzers/SPEC.md>
+    CodeClimate,
 }
 
 impl FromStr for OutputFormat {
@@ -59,6 +68,8 @@ impl FromStr for OutputFormat {
             "stylish" => Ok(Self::Stylish),
             "junit" => Ok(Self::JUnit),
             "sarif" => Ok(Self::Sarif),
+            "html" => Ok(Self::Html),
+            "codeclimate" => Ok(Self::CodeClimate),
             _ => Err(format!("'{s}' is not a known format")),
         }
     }
@@ -165,6 +176,8 @@ impl OutputFormatter {
             OutputFormat::Stylish => Box::<StylishOutputFormatter>::default(),
             OutputFormat::JUnit => Box::<JUnitOutputFormatter>::default(),
             OutputFormat::Sarif => Box::<SarifOutputFormatter>::default(),
+            OutputFormat::Html => Box::<HtmlOutputFormatter>::default(),
+            OutputFormat::CodeClimate => Box::<CodeClimateOutputFormatter>::default(),
         }
     }
 
@@ -194,8 +207,17 @@ mod test {
 
     #[test]
     fn test_output_formatter_diagnostic_formats() {
-        let mut formats: Vec<&str> =
-            vec!["checkstyle", "default", "github", "junit", "agent", "stylish", "unix", "sarif"];
+        let mut formats: Vec<&str> = vec![
+            "checkstyle",
+            "default",
+            "github",
+            "junit",
+            "agent",
+            "stylish",
+            "unix",
+            "sarif",
+            "html",
+        ];
 
         // disabled for windows
         // json will output the offset which will be different for windows
@@ -209,6 +231,11 @@ mod test {
             formats.push("gitlab");
         }
 
+        // same as json: columns differ on windows when there are multiple lines
+        if cfg!(not(target_os = "windows")) {
+            formats.push("codeclimate");
+        }
+
         for fmt in &formats {
             let args_vec = [format!("--format={fmt}"), "test.js".to_string()];
             let args_ref: Vec<&str> = args_vec.iter().map(std::string::String::as_str).collect();
@@ -218,8 +245,17 @@ mod test {
 
     #[test]
     fn test_output_formatter_diagnostic_formats_success() {
-        let mut formats: Vec<&str> =
-            vec!["checkstyle", "default", "github", "junit", "agent", "stylish", "unix", "sarif"];
+        let mut formats: Vec<&str> = vec![
+            "checkstyle",
+            "default",
+            "github",
+            "junit",
+            "agent",
+            "stylish",
+            "unix",
+            "sarif",
+            "html",
+        ];
 
         // disabled for windows
         // json will output the offset which will be different for windows
@@ -233,6 +269,11 @@ mod test {
             formats.push("gitlab");
         }
 
+        // same as json: columns differ on windows when there are multiple lines
+        if cfg!(not(target_os = "windows")) {
+            formats.push("codeclimate");
+        }
+
         for fmt in &formats {
             let args_vec = [format!("--format={fmt}"), "ok.js".to_string()];
             let args_ref: Vec<&str> = args_vec.iter().map(std::string::String::as_str).collect();
@@ -245,8 +286,17 @@ mod test {
     // were being truncated to just the character after the first colon.
     #[test]
     fn test_output_formatter_diagnostic_formats_with_parser_error() {
-        let mut formats: Vec<&str> =
-            vec!["checkstyle", "default", "github", "junit", "agent", "stylish", "unix", "sarif"];
+        let mut formats: Vec<&str> = vec![
+            "checkstyle",
+            "default",
+            "github",
+            "junit",
+            "agent",
+            "stylish",
+            "unix",
+            "sarif",
+            "html",
+        ];
 
         // disabled for windows
         // json will output the offset which will be different for windows
@@ -260,6 +310,11 @@ mod test {
             formats.push("gitlab");
         }
 
+        // same as json: columns differ on windows when there are multiple lines
+        if cfg!(not(target_os = "windows")) {
+            formats.push("codeclimate");
+        }
+
         for fmt in &formats {
             let args_vec = [format!("--format={fmt}"), "parser-error.js".to_string()];
             let args_ref: Vec<&str> = args_vec.iter().map(std::string::String::as_str).collect();
@@ -270,8 +325,17 @@ mod test {
     // Test that each of the formatters can output the disable directive violations.
     #[test]
     fn test_output_formatter_diagnostic_formats_with_disable_directive() {
-        let mut formats: Vec<&str> =
-            vec!["checkstyle", "default", "github", "junit", "agent", "stylish", "unix", "sarif"];
+        let mut formats: Vec<&str> = vec![
+            "checkstyle",
+            "default",
+            "github",
+            "junit",
+            "agent",
+            "stylish",
+            "unix",
+            "sarif",
+            "html",
+        ];
 
         // disabled for windows
         // json will output the offset which will be different for windows
@@ -285,6 +349,11 @@ mod test {
             formats.push("gitlab");
         }
 
+        // same as json: columns differ on windows when there are multiple lines
+        if cfg!(not(target_os = "windows")) {
+            formats.push("codeclimate");
+        }
+
         for fmt in &formats {
             let args_vec = [
                 format!("--format={fmt}"),
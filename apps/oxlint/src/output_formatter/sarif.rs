@@ -366,7 +366,7 @@ impl SarifBuilder {
     }
 }
 
-fn build_rule_lookup() -> FxHashMap<String, usize> {
+pub(super) fn build_rule_lookup() -> FxHashMap<String, usize> {
     RULES
         .iter()
         .enumerate()
@@ -432,7 +432,7 @@ fn region_from_positions(start: &InfoPosition, end: &InfoPosition) -> Option<Sar
     })
 }
 
-fn normalize_uri(filename: &str) -> String {
+pub(super) fn normalize_uri(filename: &str) -> String {
     filename.cow_replace('\\', "/").into_owned()
 }
 
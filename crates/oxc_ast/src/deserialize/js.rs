@@ -0,0 +1,558 @@
+use oxc_allocator::{ArenaBox, ArenaVec};
+use oxc_span::{GetSpan, GetSpanMut, SourceType, Span};
+use serde_json::Value;
+
+use crate::ast::*;
+
+use super::{EstreeDeserializer, FromESTree, Node, Result, invalid_value};
+
+/// Inverse of `ProgramConverter`.
+///
+/// `Program` is not deserialized via `FromESTree`, as it needs `source_text` and `source_type`.
+pub fn program<'a>(
+    value: &Value,
+    source_text: &'a str,
+    source_type: SourceType,
+    d: &EstreeDeserializer<'a, '_>,
+) -> Result<Program<'a>> {
+    let node = Node::new(value)?;
+    node.expect_type("Program")?;
+    let source_type = match node.opt_str("sourceType")? {
+        Some("module") => source_type.with_module(true),
+        Some("script") => source_type.with_script(true),
+        Some("commonjs") => source_type.with_commonjs(true),
+        _ => source_type,
+    };
+    let (directives, body) = d.prepended(node, "body")?;
+    let mut span = d.span(node);
+    // TS-ESTree `Program` starts at its first statement, rather than start of file
+    if d.is_ts {
+        span.start = 0;
+    }
+    Ok(Program::new(
+        span,
+        source_type,
+        source_text,
+        ArenaVec::new_in(d),
+        FromESTree::from_estree_field(node, "hashbang", d)?,
+        directives,
+        body,
+        d,
+    ))
+}
+
+/// Inverse of `Null` converter. Holes in arrays are `null`.
+impl<'a> FromESTree<'a> for Elision {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        if !value.is_null() {
+            return Err(invalid_value(value, "`null`"));
+        }
+        Ok(Elision::new(Span::default(), d))
+    }
+}
+
+/// Inverse of `ParenthesizedExpressionConverter`.
+impl<'a> FromESTree<'a> for ParenthesizedExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        node.expect_type("ParenthesizedExpression")?;
+        Ok(ParenthesizedExpression::new(
+            d.span(node),
+            FromESTree::from_estree_field(node, "expression", d)?,
+            d,
+        ))
+    }
+}
+
+/// Inverse of `ImportMetaMeta` and `ImportMetaProperty`.
+impl<'a> FromESTree<'a> for ImportMeta {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = meta_property(value, "import", "meta")?;
+        Ok(ImportMeta::new(d.span(node), d))
+    }
+}
+
+/// Inverse of `NewTargetMeta` and `NewTargetProperty`.
+impl<'a> FromESTree<'a> for NewTarget {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = meta_property(value, "new", "target")?;
+        Ok(NewTarget::new(d.span(node), d))
+    }
+}
+
+/// A `MetaProperty` node, which must be `<meta>.<property>`.
+fn meta_property<'j>(value: &'j Value, meta: &str, property: &str) -> Result<Node<'j>> {
+    let node = Node::new(value)?;
+    node.expect_type("MetaProperty")?;
+    if node.node("meta")?.str("name")? != meta || node.node("property")?.str("name")? != property {
+        return Err(node.unexpected(&format!("`{meta}.{property}`")));
+    }
+    Ok(node)
+}
+
+/// Inverse of `ClassSuperClass` and `ClassSuperTypeArguments`.
+impl<'a> FromESTree<'a> for Class<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        let heritage = match node.value("superClass") {
+            None | Some(Value::Null) => None,
+            Some(super_class) => Some(ClassHeritage::new(
+                FromESTree::from_estree(super_class, d)?,
+                d.optional_field(node, "superTypeArguments")?,
+                d,
+            )),
+        };
+        let decorators: ArenaVec<'a, Decorator<'a>> =
+            FromESTree::from_estree_field(node, "decorators", d)?;
+        let implements: ArenaVec<'a, TSClassImplements<'a>> =
+            d.optional_field(node, "implements")?;
+        Ok(Class::new(
+            d.span(node),
+            FromESTree::from_estree_field(node, "type", d)?,
+            decorators,
+            FromESTree::from_estree_field(node, "id", d)?,
+            d.optional_field(node, "typeParameters")?,
+            heritage,
+            implements,
+            FromESTree::from_estree_field(node, "body", d)?,
+            d.optional_field(node, "abstract")?,
+            d.optional_field(node, "declare")?,
+            d,
+        ))
+    }
+}
+
+/// Inverse of `FunctionParams`.
+impl<'a> FromESTree<'a> for Function<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        let (this_param, params) = params_with_this(node, FormalParameterKind::FormalParameter, d)?;
+        Ok(Function::new(
+            d.span(node),
+            FromESTree::from_estree_field(node, "type", d)?,
+            FromESTree::from_estree_field(node, "id", d)?,
+            FromESTree::from_estree_field(node, "generator", d)?,
+            FromESTree::from_estree_field(node, "async", d)?,
+            d.optional_field(node, "declare")?,
+            d.optional_field(node, "typeParameters")?,
+            this_param,
+            params,
+            d.optional_field(node, "returnType")?,
+            FromESTree::from_estree_field(node, "body", d)?,
+            d,
+        ))
+    }
+}
+
+/// Inverse of `ArrowFunctionExpressionExpression`.
+///
+/// `expression` is implied by the type of `body`, so is ignored.
+impl<'a> FromESTree<'a> for ArrowFunctionExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        node.expect_type("ArrowFunctionExpression")?;
+        let params = formal_parameters(
+            node.array("params")?,
+            FormalParameterKind::ArrowFormalParameters,
+            d,
+        )?;
+        Ok(ArrowFunctionExpression::new(
+            d.span(node),
+            FromESTree::from_estree_field(node, "async", d)?,
+            d.optional_field(node, "typeParameters")?,
+            params,
+            d.optional_field(node, "returnType")?,
+            FromESTree::from_estree_field(node, "body", d)?,
+            d,
+        ))
+    }
+}
+
+/// Deserialize `params` of a function or TS signature, which starts with the `this` parameter
+/// in TS-ESTree, if there is one.
+pub fn params_with_this<'a>(
+    node: Node<'_>,
+    kind: FormalParameterKind,
+    d: &EstreeDeserializer<'a, '_>,
+) -> Result<(Option<ArenaBox<'a, TSThisParameter<'a>>>, ArenaBox<'a, FormalParameters<'a>>)> {
+    let mut values = node.array("params")?;
+    let mut this_param = None;
+    if let Some((first, rest)) = values.split_first()
+        && let Ok(param) = Node::new(first)
+        && param.r#type == "Identifier"
+        && param.str("name")? == "this"
+    {
+        this_param = Some(FromESTree::from_estree(first, d)?);
+        values = rest;
+    }
+    Ok((this_param, formal_parameters(values, kind, d)?))
+}
+
+/// Inverse of `FormalParametersConverter`.
+impl<'a> FromESTree<'a> for FormalParameters<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let values = value.as_array().ok_or_else(|| invalid_value(value, "an array"))?;
+        formal_parameters(values, FormalParameterKind::FormalParameter, d).map(ArenaBox::unbox)
+    }
+}
+
+/// Deserialize function parameters, the last of which may be a `RestElement`.
+///
+/// ESTree has no node for the parameter list, so its span runs from the start of the first
+/// parameter to the end of the last.
+fn formal_parameters<'a>(
+    values: &[Value],
+    kind: FormalParameterKind,
+    d: &EstreeDeserializer<'a, '_>,
+) -> Result<ArenaBox<'a, FormalParameters<'a>>> {
+    let mut items = ArenaVec::with_capacity_in(values.len(), d);
+    let mut rest = None;
+    let mut span: Option<Span> = None;
+    for (index, value) in values.iter().enumerate() {
+        let param = Node::new(value)?;
+        let param_span = d.span(param);
+        span = Some(span.map_or(param_span, |span| Span::new(span.start, param_span.end)));
+        if param.r#type == "RestElement" {
+            if index + 1 != values.len() {
+                return Err(param.unexpected("a final `RestElement`"));
+            }
+            rest = Some(FromESTree::from_estree(value, d)?);
+        } else {
+            items.push(FormalParameter::from_estree(value, d)?);
+        }
+    }
+    Ok(FormalParameters::boxed(span.unwrap_or_default(), kind, items, rest, d))
+}
+
+/// Inverse of `FormalParameterRest`'s `ESTree` impl.
+impl<'a> FromESTree<'a> for FormalParameterRest<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        node.expect_type("RestElement")?;
+        let span = d.span(node);
+        let type_annotation: Option<ArenaBox<'a, TSTypeAnnotation<'a>>> =
+            d.optional_field(node, "typeAnnotation")?;
+        let rest_span = trim_span(span, type_annotation.as_deref());
+        let decorators: ArenaVec<'a, Decorator<'a>> = d.optional_field(node, "decorators")?;
+        Ok(FormalParameterRest::new(
+            span,
+            decorators,
+            BindingRestElement::new(
+                rest_span,
+                FromESTree::from_estree_field(node, "argument", d)?,
+                d,
+            ),
+            type_annotation,
+            d,
+        ))
+    }
+}
+
+/// Inverse of `FormalParameterConverter`.
+impl<'a> FromESTree<'a> for FormalParameter<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        let span = d.span(node);
+        if node.r#type == "TSParameterProperty" {
+            let parameter = node.node("parameter")?;
+            let (pattern, type_annotation, initializer, optional) = parameter_parts(parameter, d)?;
+            let decorators: ArenaVec<'a, Decorator<'a>> =
+                FromESTree::from_estree_field(node, "decorators", d)?;
+            return Ok(FormalParameter::new(
+                span,
+                decorators,
+                pattern,
+                type_annotation,
+                initializer,
+                optional,
+                FromESTree::from_estree_field(node, "accessibility", d)?,
+                FromESTree::from_estree_field(node, "readonly", d)?,
+                FromESTree::from_estree_field(node, "override", d)?,
+                d,
+            ));
+        }
+        let (pattern, type_annotation, initializer, optional) = parameter_parts(node, d)?;
+        let decorators: ArenaVec<'a, Decorator<'a>> = d.optional_field(node, "decorators")?;
+        Ok(FormalParameter::new(
+            span,
+            decorators,
+            pattern,
+            type_annotation,
+            initializer,
+            optional,
+            None,
+            false,
+            false,
+            d,
+        ))
+    }
+}
+
+/// Binding pattern, type annotation, initializer and `optional` flag of a parameter.
+///
+/// In TS-ESTree, the type annotation is on the `left` of an `AssignmentPattern`,
+/// or on the binding pattern itself.
+#[expect(clippy::type_complexity)]
+fn parameter_parts<'a>(
+    node: Node<'_>,
+    d: &EstreeDeserializer<'a, '_>,
+) -> Result<(
+    BindingPattern<'a>,
+    Option<ArenaBox<'a, TSTypeAnnotation<'a>>>,
+    Option<ArenaBox<'a, Expression<'a>>>,
+    bool,
+)> {
+    let optional = d.optional_field(node, "optional")?;
+    if node.r#type == "AssignmentPattern" {
+        let left = node.node("left")?;
+        let (pattern, type_annotation) = pattern_with_type_annotation(left, d)?;
+        let initializer = FromESTree::from_estree_field(node, "right", d)?;
+        return Ok((pattern, type_annotation, Some(initializer), optional));
+    }
+    let (pattern, type_annotation) = pattern_with_type_annotation(node, d)?;
+    Ok((pattern, type_annotation, None, optional))
+}
+
+/// Deserialize a binding pattern and the TS-ESTree `typeAnnotation` attached to it.
+///
+/// Inverse of `BindingPatternKindAndTsFields`, which extends the pattern's span to include
+/// the type annotation, so the span is trimmed back.
+fn pattern_with_type_annotation<'a>(
+    node: Node<'_>,
+    d: &EstreeDeserializer<'a, '_>,
+) -> Result<(BindingPattern<'a>, Option<ArenaBox<'a, TSTypeAnnotation<'a>>>)> {
+    let mut pattern = BindingPattern::from_estree(node.value, d)?;
+    let type_annotation: Option<ArenaBox<'a, TSTypeAnnotation<'a>>> =
+        d.optional_field(node, "typeAnnotation")?;
+    let mut span = trim_span(pattern.span(), type_annotation.as_deref());
+    if let BindingPattern::BindingIdentifier(ident) = &pattern {
+        let name_len = u32::try_from(ident.name.len()).unwrap_or(u32::MAX);
+        span.end = span.end.min(span.start.saturating_add(name_len));
+    }
+    *pattern.span_mut() = span;
+    Ok((pattern, type_annotation))
+}
+
+/// Remove `type_annotation` from the end of `span`.
+fn trim_span(mut span: Span, type_annotation: Option<&TSTypeAnnotation<'_>>) -> Span {
+    if let Some(type_annotation) = type_annotation
+        && type_annotation.span.start > span.start
+    {
+        span.end = span.end.min(type_annotation.span.start);
+    }
+    span
+}
+
+/// Inverse of `CatchParameterConverter`.
+impl<'a> FromESTree<'a> for CatchParameter<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        let span = d.span(node);
+        let (pattern, type_annotation) = pattern_with_type_annotation(node, d)?;
+        Ok(CatchParameter::new(span, pattern, type_annotation, d))
+    }
+}
+
+/// Inverse of `VariableDeclaratorId`.
+impl<'a> FromESTree<'a> for VariableDeclarator<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        node.expect_type("VariableDeclarator")?;
+        let (id, type_annotation) = pattern_with_type_annotation(node.node("id")?, d)?;
+        Ok(VariableDeclarator::new(
+            d.span(node),
+            id,
+            type_annotation,
+            FromESTree::from_estree_field(node, "init", d)?,
+            d.optional_field(node, "definite")?,
+            d,
+        ))
+    }
+}
+
+/// Inverse of `AssignmentTargetPropertyIdentifierInit`.
+///
+/// Only a shorthand `Property` is an `AssignmentTargetPropertyIdentifier`.
+impl<'a> FromESTree<'a> for AssignmentTargetPropertyIdentifier<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        node.expect_type("Property")?;
+        if !node.flag("shorthand") || node.flag("computed") {
+            return Err(node.unexpected("a shorthand `Property`"));
+        }
+        let property_value = node.node("value")?;
+        let init = match property_value.r#type {
+            "Identifier" => None,
+            "AssignmentPattern" => Some(FromESTree::from_estree_field(property_value, "right", d)?),
+            _ => return Err(property_value.unexpected("`Identifier` or `AssignmentPattern`")),
+        };
+        Ok(AssignmentTargetPropertyIdentifier::new(
+            d.span(node),
+            FromESTree::from_estree_field(node, "key", d)?,
+            init,
+            d,
+        ))
+    }
+}
+
+/// `MemberExpression` variants share ESTree type `MemberExpression`, and are distinguished by
+/// `computed` field and type of `property`.
+impl<'a> FromESTree<'a> for MemberExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        node.expect_type("MemberExpression")?;
+        if node.flag("computed") {
+            Ok(Self::ComputedMemberExpression(FromESTree::from_estree(value, d)?))
+        } else if node.node("property")?.r#type == "PrivateIdentifier" {
+            Ok(Self::PrivateFieldExpression(FromESTree::from_estree(value, d)?))
+        } else {
+            Ok(Self::StaticMemberExpression(FromESTree::from_estree(value, d)?))
+        }
+    }
+}
+
+/// Whether a key is an `Expression` depends on `computed` field of the parent.
+impl<'a> FromESTree<'a> for PropertyKey<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "Identifier" => Ok(Self::StaticIdentifier(FromESTree::from_estree(value, d)?)),
+            "PrivateIdentifier" => Ok(Self::PrivateIdentifier(FromESTree::from_estree(value, d)?)),
+            _ => Ok(Self::from(Expression::from_estree(value, d)?)),
+        }
+    }
+
+    fn from_estree_field(
+        node: Node<'_>,
+        field: &str,
+        d: &EstreeDeserializer<'a, '_>,
+    ) -> Result<Self> {
+        let value = node.value(field).ok_or_else(|| node.missing(field))?;
+        if node.flag("computed") {
+            Ok(Self::from(Expression::from_estree(value, d)?))
+        } else {
+            Self::from_estree(value, d)
+        }
+    }
+}
+
+/// Inverse of `ImportDeclarationSpecifiers` and `ImportDeclarationWithClause`.
+impl<'a> FromESTree<'a> for ImportDeclaration<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        node.expect_type("ImportDeclaration")?;
+        // ESTree does not distinguish `import {} from "x"` from `import "x"`
+        let specifiers: ArenaVec<'a, ImportDeclarationSpecifier<'a>> =
+            FromESTree::from_estree_field(node, "specifiers", d)?;
+        let specifiers = (!specifiers.is_empty()).then_some(specifiers);
+        Ok(ImportDeclaration::new(
+            d.span(node),
+            specifiers,
+            FromESTree::from_estree_field(node, "source", d)?,
+            FromESTree::from_estree_field(node, "phase", d)?,
+            with_clause(node, d)?,
+            d.optional_field(node, "importKind")?,
+            d,
+        ))
+    }
+}
+
+/// Inverse of `ExportAllDeclarationWithClause`.
+impl<'a> FromESTree<'a> for ExportAllDeclaration<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        node.expect_type("ExportAllDeclaration")?;
+        Ok(ExportAllDeclaration::new(
+            d.span(node),
+            FromESTree::from_estree_field(node, "exported", d)?,
+            FromESTree::from_estree_field(node, "source", d)?,
+            with_clause(node, d)?,
+            d.optional_field(node, "exportKind")?,
+            d,
+        ))
+    }
+}
+
+/// `ExportNamedDeclaration` with a `declaration`.
+///
+/// `exportKind` is derived from `declaration`, so is ignored.
+impl<'a> FromESTree<'a> for ExportDeclaration<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        node.expect_type("ExportNamedDeclaration")?;
+        if node.is_null("declaration") {
+            return Err(node.missing("declaration"));
+        }
+        Ok(ExportDeclaration::new(
+            d.span(node),
+            FromESTree::from_estree_field(node, "declaration", d)?,
+            d,
+        ))
+    }
+}
+
+/// `ExportNamedDeclaration` with a `source`. Inverse of `ExportFromDeclarationWithClause`.
+impl<'a> FromESTree<'a> for ExportFromDeclaration<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        node.expect_type("ExportNamedDeclaration")?;
+        if !node.is_null("declaration") || node.is_null("source") {
+            return Err(node.unexpected("`ExportNamedDeclaration` with a `source`"));
+        }
+        let specifiers: ArenaVec<'a, ExportSpecifier<'a>> =
+            FromESTree::from_estree_field(node, "specifiers", d)?;
+        Ok(ExportFromDeclaration::new(
+            d.span(node),
+            specifiers,
+            FromESTree::from_estree_field(node, "source", d)?,
+            d.optional_field(node, "exportKind")?,
+            with_clause(node, d)?,
+            d,
+        ))
+    }
+}
+
+/// `ExportNamedDeclaration` with neither `declaration` nor `source`.
+///
+/// Exported names refer to local bindings, so are `IdentifierReference`s.
+impl<'a> FromESTree<'a> for ExportNamedDeclaration<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        node.expect_type("ExportNamedDeclaration")?;
+        if !node.is_null("declaration") || !node.is_null("source") {
+            return Err(node.unexpected("`ExportNamedDeclaration` without a `source`"));
+        }
+        let mut specifiers: ArenaVec<'a, ExportSpecifier<'a>> =
+            FromESTree::from_estree_field(node, "specifiers", d)?;
+        for specifier in &mut specifiers {
+            if let ModuleExportName::IdentifierName(name) = &specifier.local {
+                specifier.local = ModuleExportName::IdentifierReference(IdentifierReference::new(
+                    name.span, name.name, d,
+                ));
+            }
+        }
+        Ok(ExportNamedDeclaration::new(
+            d.span(node),
+            specifiers,
+            d.optional_field(node, "exportKind")?,
+            d,
+        ))
+    }
+}
+
+/// The import attributes of `node`, or `None` if it has none.
+///
+/// ESTree does not record whether attributes were introduced with `with` or `assert`,
+/// so `with` is assumed.
+fn with_clause<'a>(
+    node: Node<'_>,
+    d: &EstreeDeserializer<'a, '_>,
+) -> Result<Option<ArenaBox<'a, WithClause<'a>>>> {
+    let entries: ArenaVec<'a, ImportAttribute<'a>> =
+        FromESTree::from_estree_field(node, "attributes", d)?;
+    let (Some(first), Some(last)) = (entries.first(), entries.last()) else {
+        return Ok(None);
+    };
+    let span = Span::new(first.span.start, last.span.end);
+    Ok(Some(WithClause::boxed(span, WithClauseKeyword::With, entries, d)))
+}
@@ -0,0 +1,76 @@
+use oxc_allocator::ArenaVec;
+use serde_json::Value;
+
+use crate::ast::*;
+
+use super::{EstreeDeserializer, FromESTree, Node, Result};
+
+/// Inverse of `JSXElementOpeningElement`.
+///
+/// `selfClosing` is implied by absence of `closingElement`, so is ignored.
+impl<'a> FromESTree<'a> for JSXElement<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        node.expect_type("JSXElement")?;
+        let children: ArenaVec<'a, JSXChild<'a>> =
+            FromESTree::from_estree_field(node, "children", d)?;
+        Ok(JSXElement::new(
+            d.span(node),
+            FromESTree::from_estree_field(node, "openingElement", d)?,
+            children,
+            FromESTree::from_estree_field(node, "closingElement", d)?,
+            d,
+        ))
+    }
+}
+
+/// Inverse of `JSXElementIdentifierReference` and `JSXElementThisExpression`.
+///
+/// ESTree represents all names as `JSXIdentifier`s, so whether a name refers to a component is
+/// decided the same way as the parser does: names starting with a lowercase ASCII letter, or
+/// containing a `-`, are intrinsic elements.
+impl<'a> FromESTree<'a> for JSXElementName<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "JSXIdentifier" => {
+                let span = d.span(node);
+                let name = node.str("name")?;
+                let is_reference = !name.contains('-')
+                    && name.as_bytes().first().is_some_and(|b| !b.is_ascii_lowercase());
+                Ok(if is_reference {
+                    JSXElementName::new_identifier_reference(span, Ident::from_str_in(name, d), d)
+                } else if name == "this" {
+                    JSXElementName::new_this_expression(span, d)
+                } else {
+                    JSXElementName::new_identifier(span, Str::from_str_in(name, d), d)
+                })
+            }
+            "JSXNamespacedName" => Ok(Self::NamespacedName(FromESTree::from_estree(value, d)?)),
+            "JSXMemberExpression" => Ok(Self::MemberExpression(FromESTree::from_estree(value, d)?)),
+            _ => Err(node.unexpected("JSXElementName")),
+        }
+    }
+}
+
+/// Inverse of `JSXElementIdentifierReference` and `JSXElementThisExpression`.
+impl<'a> FromESTree<'a> for JSXMemberExpressionObject<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "JSXIdentifier" => {
+                let span = d.span(node);
+                Ok(match node.str("name")? {
+                    "this" => JSXMemberExpressionObject::new_this_expression(span, d),
+                    name => JSXMemberExpressionObject::new_identifier_reference(
+                        span,
+                        Ident::from_str_in(name, d),
+                        d,
+                    ),
+                })
+            }
+            "JSXMemberExpression" => Ok(Self::MemberExpression(FromESTree::from_estree(value, d)?)),
+            _ => Err(node.unexpected("JSXMemberExpressionObject")),
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_syntax::number::{BigintBase, NumberBase};
+use serde_json::Value;
+
+use crate::ast::*;
+
+use super::{EstreeDeserializer, FromESTree, Node, Result, invalid_value};
+
+/// Inverse of `BooleanLiteralRaw`.
+impl<'a> FromESTree<'a> for BooleanLiteral {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = literal_node(value)?;
+        let Some(Value::Bool(value)) = node.value("value") else {
+            return Err(node.unexpected("a boolean `Literal`"));
+        };
+        Ok(BooleanLiteral::new(d.span(node), *value, d))
+    }
+}
+
+/// Inverse of `NullLiteralRaw`.
+impl<'a> FromESTree<'a> for NullLiteral {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = literal_node(value)?;
+        if !node.is_null("value") || !node.is_null("regex") || !node.is_null("bigint") {
+            return Err(node.unexpected("a `null` `Literal`"));
+        }
+        Ok(NullLiteral::new(d.span(node), d))
+    }
+}
+
+/// `base` is not part of ESTree, so is derived from `raw`.
+impl<'a> FromESTree<'a> for NumericLiteral<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = literal_node(value)?;
+        let Some(Value::Number(value)) = node.value("value") else {
+            return Err(node.unexpected("a numeric `Literal`"));
+        };
+        let value = value.as_f64().ok_or_else(|| node.invalid("value"))?;
+        let raw: Option<Str<'a>> = FromESTree::from_estree_field(node, "raw", d)?;
+        let base = number_base(raw.map(|raw| raw.as_str()), value);
+        Ok(NumericLiteral::new(d.span(node), value, raw, base, d))
+    }
+}
+
+/// Inverse of `BigIntLiteralValue` and `BigIntLiteralBigint`.
+impl<'a> FromESTree<'a> for BigIntLiteral<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = literal_node(value)?;
+        let Some(Value::String(bigint)) = node.value("bigint") else {
+            return Err(node.unexpected("a BigInt `Literal`"));
+        };
+        let raw: Option<Str<'a>> = FromESTree::from_estree_field(node, "raw", d)?;
+        let base = raw.map_or(BigintBase::Decimal, |raw| bigint_base(raw.as_str()));
+        Ok(BigIntLiteral::new(d.span(node), Str::from_str_in(bigint, d), raw, base, d))
+    }
+}
+
+/// Inverse of `RegExpLiteralValue`. `value` is ignored, and `regex` used instead.
+impl<'a> FromESTree<'a> for RegExpLiteral<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = literal_node(value)?;
+        if node.is_null("regex") {
+            return Err(node.unexpected("a RegExp `Literal`"));
+        }
+        Ok(RegExpLiteral::new(
+            d.span(node),
+            FromESTree::from_estree_field(node, "regex", d)?,
+            FromESTree::from_estree_field(node, "raw", d)?,
+            d,
+        ))
+    }
+}
+
+/// Inverse of `RegExpFlagsConverter`.
+impl<'a> FromESTree<'a> for RegExpFlags {
+    fn from_estree(value: &Value, _d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let flags = value.as_str().ok_or_else(|| invalid_value(value, "RegExp flags"))?;
+        flags
+            .chars()
+            .try_fold(RegExpFlags::empty(), |flags, c| {
+                RegExpFlags::try_from(c).map(|flag| flags | flag)
+            })
+            .map_err(|_| {
+                OxcDiagnostic::error(format!("Invalid regular expression flags `{flags}`"))
+            })
+    }
+}
+
+/// Inverse of `StringLiteralValue`.
+impl<'a> FromESTree<'a> for StringLiteral<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = literal_node(value)?;
+        let Some(Value::String(value)) = node.value("value") else {
+            return Err(node.unexpected("a string `Literal`"));
+        };
+        Ok(StringLiteral::new(
+            d.span(node),
+            Str::from_str_in(value, d),
+            FromESTree::from_estree_field(node, "raw", d)?,
+            d,
+        ))
+    }
+}
+
+/// Inverse of `TemplateElementConverter`, including its span adjustment in TS-ESTree.
+impl<'a> FromESTree<'a> for TemplateElement<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        node.expect_type("TemplateElement")?;
+        let tail = FromESTree::from_estree_field(node, "tail", d)?;
+        let mut span = node.span();
+        if d.is_ts && !span.is_empty() {
+            span.start += 1;
+            span.end -= if tail { 1 } else { 2 };
+        }
+        Ok(TemplateElement::new(
+            d.convert(span),
+            FromESTree::from_estree_field(node, "value", d)?,
+            tail,
+            d,
+        ))
+    }
+}
+
+/// A `Literal` node.
+fn literal_node(value: &Value) -> Result<Node<'_>> {
+    let node = Node::new(value)?;
+    node.expect_type("Literal")?;
+    Ok(node)
+}
+
+fn number_base(raw: Option<&str>, value: f64) -> NumberBase {
+    match raw.map(str::as_bytes) {
+        Some([b'0', b'x' | b'X', ..]) => NumberBase::Hex,
+        Some([b'0', b'o' | b'O', ..]) => NumberBase::Octal,
+        Some([b'0', b'b' | b'B', ..]) => NumberBase::Binary,
+        Some(raw) if raw.contains(&b'.') => NumberBase::Float,
+        _ if value.fract() == 0.0 => NumberBase::Decimal,
+        _ => NumberBase::Float,
+    }
+}
+
+fn bigint_base(raw: &str) -> BigintBase {
+    match raw.as_bytes() {
+        [b'0', b'x' | b'X', ..] => BigintBase::Hex,
+        [b'0', b'o' | b'O', ..] => BigintBase::Octal,
+        [b'0', b'b' | b'B', ..] => BigintBase::Binary,
+        _ => BigintBase::Decimal,
+    }
+}
@@ -0,0 +1,484 @@
+//! Deserialization of ESTree JSON into an oxc AST.
+//!
+//! The inverse of the `serialize` module: rebuilds an arena-allocated [`Program`] from the
+//! ESTree JSON produced by [`Program::to_estree_json`], or by any other ESTree-compliant tool
+//! (e.g. Acorn, TS-ESLint, or a JS plugin which has modified an AST), so it can be passed to
+//! `Codegen`, `Transformer` or `Minifier`.
+//!
+//! [`FromESTree`] impls for most AST types are generated by `oxc_ast_tools` from the same
+//! `#[estree]` attributes which drive serialization (`generated/derive_from_estree.rs`).
+//! Types which are serialized via converters have hand-written impls in this module's submodules,
+//! which mirror the converters in `serialize`.
+//!
+//! JSON for TypeScript source types is expected to be TS-ESTree (as produced by
+//! [`Program::to_estree_json`] with `include_ts_fields`), and JSON for JavaScript to be ESTree.
+//!
+//! Spans are taken from the `start` and `end` fields of each node, or from `range` if those are
+//! absent. Nodes without position information get an empty span. By default offsets are treated as
+//! UTF-8 byte offsets into `source_text`. ESTree produced for JS consumers uses UTF-16 offsets,
+//! which can be converted back with [`Program::from_estree_json_with_span_converter`].
+//! Comments are not part of ESTree, so the deserialized `Program` has none.
+//!
+//! [`Program::to_estree_json`]: crate::ast::Program::to_estree_json
+
+use oxc_allocator::{Allocator, ArenaBox, ArenaVec, Dummy, GetAllocator};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{SourceType, Span};
+use oxc_str::{Ident, Str};
+use serde_json::{Map, Value};
+
+use crate::{
+    ast::*,
+    builder::{AstBuilder, GetAstBuilder},
+};
+
+mod js;
+mod jsx;
+mod literal;
+mod ts;
+
+pub type Result<T> = std::result::Result<T, OxcDiagnostic>;
+
+impl<'a> Program<'a> {
+    /// Deserialize a [`Program`] from ESTree JSON.
+    ///
+    /// `source_text` is stored on the `Program`, and is what spans in `json` are assumed to refer
+    /// to, as UTF-8 byte offsets. `source_type` determines the language, and whether `json` is
+    /// expected to be TS-ESTree. Its module kind is overridden by the `sourceType` field of the
+    /// JSON, if present.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `json` is not valid JSON, is not an ESTree `Program`, or contains a node
+    /// which is not valid in the position it appears.
+    pub fn from_estree_json(
+        json: &str,
+        source_text: &'a str,
+        source_type: SourceType,
+        allocator: &'a Allocator,
+    ) -> Result<Self> {
+        Self::deserialize_estree_json(json, source_text, source_type, allocator, None)
+    }
+
+    /// Deserialize a [`Program`] from ESTree JSON, converting the span of every node with
+    /// `convert_span`.
+    ///
+    /// Use this for ESTree whose offsets are not UTF-8 byte offsets. e.g. for JSON produced after
+    /// converting the AST to UTF-16 offsets with `Utf8ToUtf16::convert_program`, pass
+    /// `|span| utf8_to_utf16.convert_span_back(span)`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Program::from_estree_json`].
+    pub fn from_estree_json_with_span_converter(
+        json: &str,
+        source_text: &'a str,
+        source_type: SourceType,
+        allocator: &'a Allocator,
+        convert_span: impl Fn(&mut Span),
+    ) -> Result<Self> {
+        Self::deserialize_estree_json(
+            json,
+            source_text,
+            source_type,
+            allocator,
+            Some(&convert_span),
+        )
+    }
+
+    fn deserialize_estree_json(
+        json: &str,
+        source_text: &'a str,
+        source_type: SourceType,
+        allocator: &'a Allocator,
+        convert_span: Option<&dyn Fn(&mut Span)>,
+    ) -> Result<Self> {
+        let value: Value = serde_json::from_str(json)
+            .map_err(|err| OxcDiagnostic::error(format!("Invalid ESTree JSON: {err}")))?;
+        let deserializer = EstreeDeserializer {
+            builder: AstBuilder::new(allocator),
+            is_ts: source_type.is_typescript(),
+            convert_span,
+        };
+        js::program(&value, source_text, source_type, &deserializer)
+    }
+}
+
+/// Deserialize an AST node from an ESTree JSON value.
+///
+/// Impls for most types are generated, see module docs.
+pub trait FromESTree<'a>: Sized {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self>;
+
+    /// Deserialize from `field` of `node`.
+    ///
+    /// Types whose ESTree representation depends on other fields of the parent node
+    /// (e.g. `PropertyKey` on `computed`) override this.
+    fn from_estree_field(
+        node: Node<'_>,
+        field: &str,
+        d: &EstreeDeserializer<'a, '_>,
+    ) -> Result<Self> {
+        match node.value(field) {
+            Some(value) => Self::from_estree(value, d),
+            None => Self::from_missing(d).ok_or_else(|| node.missing(field)),
+        }
+    }
+
+    /// Value to use if field is absent, or `None` if it's required.
+    fn from_missing(_d: &EstreeDeserializer<'a, '_>) -> Option<Self> {
+        None
+    }
+}
+
+impl<'a, T: FromESTree<'a>> FromESTree<'a> for Option<T> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        if value.is_null() { Ok(None) } else { T::from_estree(value, d).map(Some) }
+    }
+
+    fn from_estree_field(
+        node: Node<'_>,
+        field: &str,
+        d: &EstreeDeserializer<'a, '_>,
+    ) -> Result<Self> {
+        match node.value(field) {
+            None | Some(Value::Null) => Ok(None),
+            Some(_) => T::from_estree_field(node, field, d).map(Some),
+        }
+    }
+
+    fn from_missing(_d: &EstreeDeserializer<'a, '_>) -> Option<Self> {
+        Some(None)
+    }
+}
+
+impl<'a, T: FromESTree<'a>> FromESTree<'a> for ArenaBox<'a, T> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        T::from_estree(value, d).map(|inner| ArenaBox::new_in(inner, d))
+    }
+
+    fn from_estree_field(
+        node: Node<'_>,
+        field: &str,
+        d: &EstreeDeserializer<'a, '_>,
+    ) -> Result<Self> {
+        T::from_estree_field(node, field, d).map(|inner| ArenaBox::new_in(inner, d))
+    }
+
+    fn from_missing(d: &EstreeDeserializer<'a, '_>) -> Option<Self> {
+        T::from_missing(d).map(|inner| ArenaBox::new_in(inner, d))
+    }
+}
+
+/// Arrays which are absent are treated as empty, as some ESTree producers omit e.g. `decorators`.
+impl<'a, T: FromESTree<'a>> FromESTree<'a> for ArenaVec<'a, T> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let Some(values) = value.as_array() else {
+            return Err(invalid_value(value, "an array"));
+        };
+        let mut vec = ArenaVec::with_capacity_in(values.len(), d);
+        for value in values {
+            vec.push(T::from_estree(value, d)?);
+        }
+        Ok(vec)
+    }
+
+    fn from_missing(d: &EstreeDeserializer<'a, '_>) -> Option<Self> {
+        Some(ArenaVec::new_in(d))
+    }
+}
+
+/// Flags which are absent are treated as `false`.
+impl<'a> FromESTree<'a> for bool {
+    fn from_estree(value: &Value, _d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        value.as_bool().ok_or_else(|| invalid_value(value, "a boolean"))
+    }
+
+    fn from_missing(_d: &EstreeDeserializer<'a, '_>) -> Option<Self> {
+        Some(false)
+    }
+}
+
+impl<'a> FromESTree<'a> for f64 {
+    fn from_estree(value: &Value, _d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        value.as_f64().ok_or_else(|| invalid_value(value, "a number"))
+    }
+}
+
+impl<'a> FromESTree<'a> for Str<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let s = value.as_str().ok_or_else(|| invalid_value(value, "a string"))?;
+        Ok(Str::from_str_in(s, d))
+    }
+}
+
+impl<'a> FromESTree<'a> for Ident<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let s = value.as_str().ok_or_else(|| invalid_value(value, "a string"))?;
+        Ok(Ident::from_str_in(s, d))
+    }
+}
+
+/// Builds AST nodes from ESTree JSON nodes.
+pub struct EstreeDeserializer<'a, 'c> {
+    builder: AstBuilder<'a>,
+    /// `true` if JSON is TS-ESTree
+    is_ts: bool,
+    convert_span: Option<&'c dyn Fn(&mut Span)>,
+}
+
+impl<'a> GetAstBuilder<'a> for EstreeDeserializer<'a, '_> {
+    type Builder = AstBuilder<'a>;
+
+    fn builder(&self) -> &AstBuilder<'a> {
+        &self.builder
+    }
+}
+
+impl<'a> GetAllocator<'a> for EstreeDeserializer<'a, '_> {
+    fn allocator(&self) -> &'a Allocator {
+        self.builder.allocator()
+    }
+}
+
+impl<'a> EstreeDeserializer<'a, '_> {
+    pub fn allocator(&self) -> &'a Allocator {
+        self.builder.allocator()
+    }
+
+    /// Span of `node`, converted to UTF-8 offsets.
+    pub fn span(&self, node: Node<'_>) -> Span {
+        self.convert(node.span())
+    }
+
+    /// Convert `span` from the offsets used in the JSON to UTF-8 offsets.
+    fn convert(&self, mut span: Span) -> Span {
+        if let Some(convert_span) = self.convert_span {
+            convert_span(&mut span);
+        }
+        span
+    }
+
+    /// Deserialize `value` with the first of `candidates` which succeeds.
+    ///
+    /// Used where several AST types share the same ESTree `type` (e.g. `Literal`).
+    /// If none succeed, returns the error from the first.
+    pub fn first_ok<T>(
+        &self,
+        value: &Value,
+        candidates: &[fn(&Value, &Self) -> Result<T>],
+    ) -> Result<T> {
+        let mut first_err = None;
+        for candidate in candidates {
+            match candidate(value, self) {
+                Ok(result) => return Ok(result),
+                Err(err) => {
+                    first_err.get_or_insert(err);
+                }
+            }
+        }
+        Err(first_err.unwrap_or_else(|| invalid_value(value, "a node")))
+    }
+
+    /// Deserialize array `field` of `node`, whose leading elements may be `P`s, and rest `T`s.
+    ///
+    /// Inverse of `#[estree(prepend_to)]` (e.g. directives in a function body).
+    pub fn prepended<P: FromESTree<'a>, T: FromESTree<'a>>(
+        &self,
+        node: Node<'_>,
+        field: &str,
+    ) -> Result<(ArenaVec<'a, P>, ArenaVec<'a, T>)> {
+        let values = node.array(field)?;
+        let mut prepended = ArenaVec::new_in(self);
+        let mut rest = ArenaVec::with_capacity_in(values.len(), self);
+        for value in values {
+            if rest.is_empty()
+                && let Ok(element) = P::from_estree(value, self)
+            {
+                prepended.push(element);
+            } else {
+                rest.push(T::from_estree(value, self)?);
+            }
+        }
+        Ok((prepended, rest))
+    }
+
+    /// Deserialize array `field` of `node`, whose last element may be an `R`, and rest `T`s.
+    ///
+    /// Inverse of `#[estree(append_to)]` (e.g. rest element of an object pattern).
+    pub fn appended<T: FromESTree<'a>, R: FromESTree<'a>>(
+        &self,
+        node: Node<'_>,
+        field: &str,
+    ) -> Result<(ArenaVec<'a, T>, Option<R>)> {
+        let mut values = node.array(field)?;
+        let mut appended = None;
+        if let Some((last, init)) = values.split_last()
+            && let Ok(element) = R::from_estree(last, self)
+        {
+            appended = Some(element);
+            values = init;
+        }
+        let mut vec = ArenaVec::with_capacity_in(values.len(), self);
+        for value in values {
+            vec.push(T::from_estree(value, self)?);
+        }
+        Ok((vec, appended))
+    }
+
+    /// Deserialize a field which only exists in one of ESTree and TS-ESTree.
+    /// If it's absent, use a default value.
+    pub fn optional_field<T: FromESTree<'a> + Dummy<'a>>(
+        &self,
+        node: Node<'_>,
+        field: &str,
+    ) -> Result<T> {
+        if node.value(field).is_some() {
+            T::from_estree_field(node, field, self)
+        } else {
+            Ok(T::dummy(self.allocator()))
+        }
+    }
+}
+
+/// An ESTree node: a JSON object, usually with a `type` field.
+#[derive(Clone, Copy)]
+pub struct Node<'j> {
+    /// The object this node was created from
+    value: &'j Value,
+    map: &'j Map<String, Value>,
+    /// Empty string if the object has no `type`
+    pub r#type: &'j str,
+}
+
+impl<'j> Node<'j> {
+    /// Create a [`Node`] from an object which must have a `type` field.
+    pub fn new(value: &'j Value) -> Result<Self> {
+        let node = Self::object(value)?;
+        if node.r#type.is_empty() {
+            return Err(OxcDiagnostic::error(
+                "Expected an ESTree node, found an object without a `type`",
+            ));
+        }
+        Ok(node)
+    }
+
+    /// Create a [`Node`] from an object which may not have a `type` field
+    /// (e.g. `value` of a `TemplateElement`).
+    pub fn object(value: &'j Value) -> Result<Self> {
+        let Some(map) = value.as_object() else {
+            return Err(invalid_value(value, "an ESTree node"));
+        };
+        let r#type = map.get("type").and_then(Value::as_str).unwrap_or_default();
+        Ok(Self { value, map, r#type })
+    }
+
+    /// Span from the `start` and `end` fields, or `range`, before conversion.
+    fn span(self) -> Span {
+        let offset = |value: Option<&Value>| {
+            value.and_then(Value::as_u64).and_then(|offset| u32::try_from(offset).ok())
+        };
+        if let (Some(start), Some(end)) =
+            (offset(self.map.get("start")), offset(self.map.get("end")))
+        {
+            return Span::new(start, end);
+        }
+        if let Some(range) = self.map.get("range").and_then(Value::as_array)
+            && let [start, end] = range.as_slice()
+            && let (Some(start), Some(end)) = (offset(Some(start)), offset(Some(end)))
+        {
+            return Span::new(start, end);
+        }
+        Span::default()
+    }
+
+    pub fn expect_type(self, r#type: &str) -> Result<()> {
+        if self.r#type == r#type { Ok(()) } else { Err(self.unexpected(r#type)) }
+    }
+
+    /// Error for a node which is not valid in the position it appears.
+    pub fn unexpected(self, expected: &str) -> OxcDiagnostic {
+        OxcDiagnostic::error(format!(
+            "Unexpected ESTree node `{}`, expected {expected}",
+            self.r#type
+        ))
+        .with_label(self.span())
+    }
+
+    pub fn missing(self, field: &str) -> OxcDiagnostic {
+        OxcDiagnostic::error(format!(
+            "ESTree node `{}` is missing field `{field}`, or it has the wrong type",
+            self.r#type
+        ))
+        .with_label(self.span())
+    }
+
+    /// Error for a field whose value is not valid for this node.
+    fn invalid(self, field: &str) -> OxcDiagnostic {
+        OxcDiagnostic::error(format!(
+            "ESTree node `{}` has an invalid value for field `{field}`",
+            self.r#type
+        ))
+        .with_label(self.span())
+    }
+
+    /// A child node, which must be present.
+    fn node(self, field: &str) -> Result<Node<'j>> {
+        self.opt_node(field)?.ok_or_else(|| self.missing(field))
+    }
+
+    /// A child node, or `None` if the field is absent or `null`.
+    fn opt_node(self, field: &str) -> Result<Option<Node<'j>>> {
+        match self.map.get(field) {
+            None | Some(Value::Null) => Ok(None),
+            Some(value) => Node::new(value).map(Some),
+        }
+    }
+
+    fn array(self, field: &str) -> Result<&'j [Value]> {
+        match self.map.get(field) {
+            None => Ok(&[]),
+            Some(Value::Array(array)) => Ok(array.as_slice()),
+            Some(_) => Err(self.missing(field)),
+        }
+    }
+
+    fn str(self, field: &str) -> Result<&'j str> {
+        self.map.get(field).and_then(Value::as_str).ok_or_else(|| self.missing(field))
+    }
+
+    fn opt_str(self, field: &str) -> Result<Option<&'j str>> {
+        match self.map.get(field) {
+            None | Some(Value::Null) => Ok(None),
+            Some(Value::String(s)) => Ok(Some(s)),
+            Some(_) => Err(self.missing(field)),
+        }
+    }
+
+    /// A boolean field, which is `false` if absent.
+    fn flag(self, field: &str) -> bool {
+        self.map.get(field).and_then(Value::as_bool).unwrap_or(false)
+    }
+
+    /// Get if `field` is absent or `null`.
+    fn is_null(self, field: &str) -> bool {
+        self.map.get(field).is_none_or(Value::is_null)
+    }
+
+    pub fn value(self, field: &str) -> Option<&'j Value> {
+        self.map.get(field)
+    }
+}
+
+/// Error for a JSON value which is not valid in the position it appears.
+pub fn invalid_value(value: &Value, expected: &str) -> OxcDiagnostic {
+    let found = match value {
+        Value::Object(map) => match map.get("type").and_then(Value::as_str) {
+            Some(ty) => format!("node `{ty}`"),
+            None => "an object".to_string(),
+        },
+        Value::Array(_) => "an array".to_string(),
+        value => format!("`{value}`"),
+    };
+    OxcDiagnostic::error(format!("Invalid ESTree value, expected {expected}, found {found}"))
+}
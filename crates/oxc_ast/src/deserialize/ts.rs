@@ -0,0 +1,318 @@
+use oxc_span::Span;
+use serde_json::Value;
+
+use crate::ast::*;
+
+use super::{EstreeDeserializer, FromESTree, Node, Result, invalid_value, js::params_with_this};
+
+/// `this` parameter is an `Identifier` called `this` at start of `params`.
+///
+/// `this_span` is not part of TS-ESTree, so is derived from start of the node.
+impl<'a> FromESTree<'a> for TSThisParameter<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        node.expect_type("Identifier")?;
+        if node.str("name")? != "this" {
+            return Err(node.unexpected("`this` parameter"));
+        }
+        let span = d.span(node);
+        Ok(TSThisParameter::new(
+            span,
+            Span::sized(span.start, 4),
+            FromESTree::from_estree_field(node, "typeAnnotation", d)?,
+            d,
+        ))
+    }
+}
+
+/// Inverse of `TSEnumMemberComputed`.
+impl<'a> FromESTree<'a> for TSEnumMember<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        node.expect_type("TSEnumMember")?;
+        let id = if node.flag("computed") {
+            let id = node.node("id")?;
+            match id.r#type {
+                "Literal" => {
+                    TSEnumMemberName::ComputedString(FromESTree::from_estree(id.value, d)?)
+                }
+                "TemplateLiteral" => {
+                    TSEnumMemberName::ComputedTemplateString(FromESTree::from_estree(id.value, d)?)
+                }
+                _ => return Err(id.unexpected("`Literal` or `TemplateLiteral`")),
+            }
+        } else {
+            FromESTree::from_estree_field(node, "id", d)?
+        };
+        Ok(TSEnumMember::new(
+            d.span(node),
+            id,
+            FromESTree::from_estree_field(node, "initializer", d)?,
+            d,
+        ))
+    }
+}
+
+/// Inverse of `TSParenthesizedTypeConverter`.
+impl<'a> FromESTree<'a> for TSParenthesizedType<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        node.expect_type("TSParenthesizedType")?;
+        Ok(TSParenthesizedType::new(
+            d.span(node),
+            FromESTree::from_estree_field(node, "typeAnnotation", d)?,
+            d,
+        ))
+    }
+}
+
+/// Inverse of `TSMappedTypeOptional`.
+impl<'a> FromESTree<'a> for TSMappedType<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        node.expect_type("TSMappedType")?;
+        Ok(TSMappedType::new(
+            d.span(node),
+            FromESTree::from_estree_field(node, "key", d)?,
+            FromESTree::from_estree_field(node, "constraint", d)?,
+            FromESTree::from_estree_field(node, "nameType", d)?,
+            FromESTree::from_estree_field(node, "typeAnnotation", d)?,
+            mapped_type_modifier(node, "optional", d)?,
+            mapped_type_modifier(node, "readonly", d)?,
+            d,
+        ))
+    }
+}
+
+/// A `TSMappedType` modifier, which is `None` if `false` or `null`.
+fn mapped_type_modifier(
+    node: Node<'_>,
+    field: &str,
+    d: &EstreeDeserializer<'_, '_>,
+) -> Result<Option<TSMappedTypeModifierOperator>> {
+    match node.value(field) {
+        None | Some(Value::Null | Value::Bool(false)) => Ok(None),
+        Some(value) => FromESTree::from_estree(value, d).map(Some),
+    }
+}
+
+/// `True` is serialized as `true`, and other variants as strings.
+impl<'a> FromESTree<'a> for TSMappedTypeModifierOperator {
+    fn from_estree(value: &Value, _d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        match value {
+            Value::Bool(true) => Ok(Self::True),
+            Value::String(s) if s == "+" => Ok(Self::Plus),
+            Value::String(s) if s == "-" => Ok(Self::Minus),
+            _ => Err(invalid_value(value, "`true`, `\"+\"` or `\"-\"`")),
+        }
+    }
+}
+
+/// Inverse of `TSIndexSignatureParameters`.
+impl<'a> FromESTree<'a> for TSIndexSignature<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        node.expect_type("TSIndexSignature")?;
+        let [parameter] = node.array("parameters")? else {
+            return Err(node.invalid("parameters"));
+        };
+        Ok(TSIndexSignature::new(
+            d.span(node),
+            FromESTree::from_estree(parameter, d)?,
+            FromESTree::from_estree_field(node, "typeAnnotation", d)?,
+            FromESTree::from_estree_field(node, "readonly", d)?,
+            FromESTree::from_estree_field(node, "static", d)?,
+            d,
+        ))
+    }
+}
+
+/// Inverse of `TSCallSignatureDeclarationParams`.
+impl<'a> FromESTree<'a> for TSCallSignatureDeclaration<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        node.expect_type("TSCallSignatureDeclaration")?;
+        let (this_param, params) = params_with_this(node, FormalParameterKind::Signature, d)?;
+        Ok(TSCallSignatureDeclaration::new(
+            d.span(node),
+            FromESTree::from_estree_field(node, "typeParameters", d)?,
+            this_param,
+            params,
+            FromESTree::from_estree_field(node, "returnType", d)?,
+            d,
+        ))
+    }
+}
+
+/// Inverse of `TSMethodSignatureParams`.
+impl<'a> FromESTree<'a> for TSMethodSignature<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        node.expect_type("TSMethodSignature")?;
+        let (this_param, params) = params_with_this(node, FormalParameterKind::Signature, d)?;
+        Ok(TSMethodSignature::new(
+            d.span(node),
+            FromESTree::from_estree_field(node, "key", d)?,
+            FromESTree::from_estree_field(node, "computed", d)?,
+            FromESTree::from_estree_field(node, "optional", d)?,
+            FromESTree::from_estree_field(node, "kind", d)?,
+            FromESTree::from_estree_field(node, "typeParameters", d)?,
+            this_param,
+            params,
+            FromESTree::from_estree_field(node, "returnType", d)?,
+            d,
+        ))
+    }
+}
+
+/// Inverse of `TSFunctionTypeParams`.
+impl<'a> FromESTree<'a> for TSFunctionType<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        node.expect_type("TSFunctionType")?;
+        let (this_param, params) = params_with_this(node, FormalParameterKind::Signature, d)?;
+        Ok(TSFunctionType::new(
+            d.span(node),
+            FromESTree::from_estree_field(node, "typeParameters", d)?,
+            this_param,
+            params,
+            FromESTree::from_estree_field(node, "returnType", d)?,
+            d,
+        ))
+    }
+}
+
+/// Inverse of `TSClassImplementsExpression`.
+impl<'a> FromESTree<'a> for TSClassImplements<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        node.expect_type("TSClassImplements")?;
+        Ok(TSClassImplements::new(
+            d.span(node),
+            type_name_from_member_expression(node.node("expression")?, d)?,
+            FromESTree::from_estree_field(node, "typeArguments", d)?,
+            d,
+        ))
+    }
+}
+
+/// Inverse of `TSInterfaceHeritageExpression`.
+impl<'a> FromESTree<'a> for TSInterfaceHeritage<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        node.expect_type("TSInterfaceHeritage")?;
+        Ok(TSInterfaceHeritage::new(
+            d.span(node),
+            type_name_from_member_expression(node.node("expression")?, d)?,
+            FromESTree::from_estree_field(node, "typeArguments", d)?,
+            d,
+        ))
+    }
+}
+
+/// Convert nested `MemberExpression`s (TS-ESTree) to nested `TSQualifiedName`s (Oxc).
+fn type_name_from_member_expression<'a>(
+    node: Node<'_>,
+    d: &EstreeDeserializer<'a, '_>,
+) -> Result<TSTypeName<'a>> {
+    match node.r#type {
+        "Identifier" => {
+            Ok(TSTypeName::IdentifierReference(FromESTree::from_estree(node.value, d)?))
+        }
+        "ThisExpression" => Ok(TSTypeName::ThisExpression(FromESTree::from_estree(node.value, d)?)),
+        "MemberExpression" if !node.flag("computed") && !node.flag("optional") => {
+            let left = type_name_from_member_expression(node.node("object")?, d)?;
+            let right = FromESTree::from_estree_field(node, "property", d)?;
+            Ok(TSTypeName::QualifiedName(TSQualifiedName::boxed(d.span(node), left, right, d)))
+        }
+        _ => Err(node.unexpected("`Identifier`, `ThisExpression` or `MemberExpression`")),
+    }
+}
+
+/// `TSModuleDeclaration` with a string `id`. Inverse of `TSExternalModuleDeclarationConverter`.
+impl<'a> FromESTree<'a> for TSExternalModuleDeclaration<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        node.expect_type("TSModuleDeclaration")?;
+        if node.flag("global") || node.node("id")?.r#type != "Literal" {
+            return Err(node.unexpected("`TSModuleDeclaration` with a string `id`"));
+        }
+        Ok(TSExternalModuleDeclaration::new(
+            d.span(node),
+            FromESTree::from_estree_field(node, "id", d)?,
+            FromESTree::from_estree_field(node, "body", d)?,
+            FromESTree::from_estree_field(node, "declare", d)?,
+            d,
+        ))
+    }
+}
+
+/// `TSModuleDeclaration` with an identifier `id`. Inverse of `TSNamespaceDeclarationConverter`.
+///
+/// A `TSQualifiedName` `id` (`namespace X.Y.Z {}`) is split into nested `TSNamespaceDeclaration`s,
+/// the same as the parser produces.
+impl<'a> FromESTree<'a> for TSNamespaceDeclaration<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        node.expect_type("TSModuleDeclaration")?;
+        let id = node.node("id")?;
+        if node.flag("global") || !matches!(id.r#type, "Identifier" | "TSQualifiedName") {
+            return Err(node.unexpected("`TSModuleDeclaration` with an identifier `id`"));
+        }
+
+        let mut parts = vec![];
+        namespace_id_parts(id, &mut parts)?;
+        let span = d.span(node);
+        let kind = FromESTree::from_estree_field(node, "kind", d)?;
+        let mut body = TSNamespaceDeclarationBody::TSModuleBlock(FromESTree::from_estree_field(
+            node, "body", d,
+        )?);
+        let (outer, inner) = parts.split_first().ok_or_else(|| node.invalid("id"))?;
+        for part in inner.iter().rev() {
+            let id = BindingIdentifier::from_estree(part.value, d)?;
+            let span = Span::new(id.span.start, span.end);
+            body = TSNamespaceDeclarationBody::TSNamespaceDeclaration(
+                TSNamespaceDeclaration::boxed(span, id, body, kind, false, d),
+            );
+        }
+        Ok(TSNamespaceDeclaration::new(
+            span,
+            FromESTree::from_estree(outer.value, d)?,
+            body,
+            kind,
+            FromESTree::from_estree_field(node, "declare", d)?,
+            d,
+        ))
+    }
+}
+
+/// Collect `Identifier`s of a namespace `id` in source order.
+fn namespace_id_parts<'j>(id: Node<'j>, parts: &mut Vec<Node<'j>>) -> Result<()> {
+    match id.r#type {
+        "Identifier" => parts.push(id),
+        "TSQualifiedName" => {
+            namespace_id_parts(id.node("left")?, parts)?;
+            parts.push(id.node("right")?);
+        }
+        _ => return Err(id.unexpected("`Identifier` or `TSQualifiedName`")),
+    }
+    Ok(())
+}
+
+/// `TSModuleDeclaration` with `global: true`. Inverse of `TSGlobalDeclarationId`.
+impl<'a> FromESTree<'a> for TSGlobalDeclaration<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        node.expect_type("TSModuleDeclaration")?;
+        if !node.flag("global") {
+            return Err(node.unexpected("`TSModuleDeclaration` with `global: true`"));
+        }
+        Ok(TSGlobalDeclaration::new(
+            d.span(node),
+            d.span(node.node("id")?),
+            FromESTree::from_estree_field(node, "body", d)?,
+            FromESTree::from_estree_field(node, "declare", d)?,
+            d,
+        ))
+    }
+}
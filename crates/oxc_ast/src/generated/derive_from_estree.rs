@@ -0,0 +1,3260 @@
+// Auto-generated code, DO NOT EDIT DIRECTLY!
+// To edit this generated file you have to edit `tasks/ast_tools/src/generators/estree_deserialize.rs`.
+
+#![allow(clippy::match_same_arms, clippy::too_many_lines)]
+
+use oxc_allocator::Dummy;
+use oxc_syntax::operator::*;
+use serde_json::Value;
+
+use crate::ast::js::*;
+use crate::ast::jsx::*;
+use crate::ast::literal::*;
+use crate::ast::ts::*;
+use crate::deserialize::{EstreeDeserializer, FromESTree, Node, Result, invalid_value};
+
+impl<'a> FromESTree<'a> for Hashbang<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("Hashbang")?;
+        Ok(Hashbang {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            value: FromESTree::from_estree_field(node, "value", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for Directive<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("ExpressionStatement")?;
+        Ok(Directive {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            expression: FromESTree::from_estree_field(node, "expression", d)?,
+            directive: FromESTree::from_estree_field(node, "directive", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for Statement<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "BlockStatement" => Ok(Self::BlockStatement(FromESTree::from_estree(value, d)?)),
+            "BreakStatement" => Ok(Self::BreakStatement(FromESTree::from_estree(value, d)?)),
+            "ContinueStatement" => Ok(Self::ContinueStatement(FromESTree::from_estree(value, d)?)),
+            "DebuggerStatement" => Ok(Self::DebuggerStatement(FromESTree::from_estree(value, d)?)),
+            "DoWhileStatement" => Ok(Self::DoWhileStatement(FromESTree::from_estree(value, d)?)),
+            "EmptyStatement" => Ok(Self::EmptyStatement(FromESTree::from_estree(value, d)?)),
+            "ExpressionStatement" => {
+                Ok(Self::ExpressionStatement(FromESTree::from_estree(value, d)?))
+            }
+            "ForInStatement" => Ok(Self::ForInStatement(FromESTree::from_estree(value, d)?)),
+            "ForOfStatement" => Ok(Self::ForOfStatement(FromESTree::from_estree(value, d)?)),
+            "ForStatement" => Ok(Self::ForStatement(FromESTree::from_estree(value, d)?)),
+            "IfStatement" => Ok(Self::IfStatement(FromESTree::from_estree(value, d)?)),
+            "LabeledStatement" => Ok(Self::LabeledStatement(FromESTree::from_estree(value, d)?)),
+            "ReturnStatement" => Ok(Self::ReturnStatement(FromESTree::from_estree(value, d)?)),
+            "SwitchStatement" => Ok(Self::SwitchStatement(FromESTree::from_estree(value, d)?)),
+            "ThrowStatement" => Ok(Self::ThrowStatement(FromESTree::from_estree(value, d)?)),
+            "TryStatement" => Ok(Self::TryStatement(FromESTree::from_estree(value, d)?)),
+            "WhileStatement" => Ok(Self::WhileStatement(FromESTree::from_estree(value, d)?)),
+            "WithStatement" => Ok(Self::WithStatement(FromESTree::from_estree(value, d)?)),
+            "VariableDeclaration"
+            | "FunctionDeclaration"
+            | "FunctionExpression"
+            | "TSDeclareFunction"
+            | "TSEmptyBodyFunctionExpression"
+            | "ClassDeclaration"
+            | "ClassExpression"
+            | "TSTypeAliasDeclaration"
+            | "TSInterfaceDeclaration"
+            | "TSEnumDeclaration"
+            | "TSModuleDeclaration"
+            | "TSImportEqualsDeclaration" => Ok(Self::from(Declaration::from_estree(value, d)?)),
+            "ImportDeclaration"
+            | "ExportAllDeclaration"
+            | "ExportDefaultDeclaration"
+            | "ExportNamedDeclaration"
+            | "TSExportAssignment"
+            | "TSNamespaceExportDeclaration" => {
+                Ok(Self::from(ModuleDeclaration::from_estree(value, d)?))
+            }
+            _ => Err(node.unexpected("Statement")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for Declaration<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "VariableDeclaration" => {
+                Ok(Self::VariableDeclaration(FromESTree::from_estree(value, d)?))
+            }
+            "FunctionDeclaration"
+            | "FunctionExpression"
+            | "TSDeclareFunction"
+            | "TSEmptyBodyFunctionExpression" => {
+                Ok(Self::FunctionDeclaration(FromESTree::from_estree(value, d)?))
+            }
+            "ClassDeclaration" | "ClassExpression" => {
+                Ok(Self::ClassDeclaration(FromESTree::from_estree(value, d)?))
+            }
+            "TSTypeAliasDeclaration" => {
+                Ok(Self::TSTypeAliasDeclaration(FromESTree::from_estree(value, d)?))
+            }
+            "TSInterfaceDeclaration" => {
+                Ok(Self::TSInterfaceDeclaration(FromESTree::from_estree(value, d)?))
+            }
+            "TSEnumDeclaration" => Ok(Self::TSEnumDeclaration(FromESTree::from_estree(value, d)?)),
+            "TSModuleDeclaration" => d.first_ok(
+                value,
+                &[
+                    |value, d| {
+                        Ok(Self::TSExternalModuleDeclaration(FromESTree::from_estree(value, d)?))
+                    },
+                    |value, d| Ok(Self::TSNamespaceDeclaration(FromESTree::from_estree(value, d)?)),
+                    |value, d| Ok(Self::TSGlobalDeclaration(FromESTree::from_estree(value, d)?)),
+                ],
+            ),
+            "TSImportEqualsDeclaration" => {
+                Ok(Self::TSImportEqualsDeclaration(FromESTree::from_estree(value, d)?))
+            }
+            _ => Err(node.unexpected("Declaration")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for VariableDeclaration<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("VariableDeclaration")?;
+        Ok(VariableDeclaration {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            kind: FromESTree::from_estree_field(node, "kind", d)?,
+            declarations: FromESTree::from_estree_field(node, "declarations", d)?,
+            declare: d.optional_field(node, "declare")?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for VariableDeclarationKind {
+    fn from_estree(value: &Value, _d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        match value.as_str() {
+            Some("var") => Ok(Self::Var),
+            Some("let") => Ok(Self::Let),
+            Some("const") => Ok(Self::Const),
+            Some("using") => Ok(Self::Using),
+            Some("await using") => Ok(Self::AwaitUsing),
+            _ => Err(invalid_value(value, "VariableDeclarationKind")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for BindingPattern<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "Identifier" => Ok(Self::BindingIdentifier(FromESTree::from_estree(value, d)?)),
+            "ObjectPattern" => Ok(Self::ObjectPattern(FromESTree::from_estree(value, d)?)),
+            "ArrayPattern" => Ok(Self::ArrayPattern(FromESTree::from_estree(value, d)?)),
+            "AssignmentPattern" => Ok(Self::AssignmentPattern(FromESTree::from_estree(value, d)?)),
+            _ => Err(node.unexpected("BindingPattern")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for BindingIdentifier<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("Identifier")?;
+        Ok(BindingIdentifier {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            name: FromESTree::from_estree_field(node, "name", d)?,
+            symbol_id: Dummy::dummy(d.allocator()),
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for ObjectPattern<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("ObjectPattern")?;
+        let (properties, rest) = d.appended(node, "properties")?;
+        Ok(ObjectPattern {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            properties,
+            rest,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for BindingRestElement<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("RestElement")?;
+        Ok(BindingRestElement {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            argument: FromESTree::from_estree_field(node, "argument", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for BindingProperty<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("Property")?;
+        Ok(BindingProperty {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            key: FromESTree::from_estree_field(node, "key", d)?,
+            value: FromESTree::from_estree_field(node, "value", d)?,
+            shorthand: FromESTree::from_estree_field(node, "shorthand", d)?,
+            computed: FromESTree::from_estree_field(node, "computed", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for Expression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "Literal" => d.first_ok(
+                value,
+                &[
+                    |value, d| Ok(Self::BooleanLiteral(FromESTree::from_estree(value, d)?)),
+                    |value, d| Ok(Self::NullLiteral(FromESTree::from_estree(value, d)?)),
+                    |value, d| Ok(Self::NumericLiteral(FromESTree::from_estree(value, d)?)),
+                    |value, d| Ok(Self::BigIntLiteral(FromESTree::from_estree(value, d)?)),
+                    |value, d| Ok(Self::RegExpLiteral(FromESTree::from_estree(value, d)?)),
+                    |value, d| Ok(Self::StringLiteral(FromESTree::from_estree(value, d)?)),
+                ],
+            ),
+            "TemplateLiteral" => Ok(Self::TemplateLiteral(FromESTree::from_estree(value, d)?)),
+            "Identifier" => Ok(Self::Identifier(FromESTree::from_estree(value, d)?)),
+            "Super" => Ok(Self::Super(FromESTree::from_estree(value, d)?)),
+            "ArrayExpression" => Ok(Self::ArrayExpression(FromESTree::from_estree(value, d)?)),
+            "ArrowFunctionExpression" => {
+                Ok(Self::ArrowFunctionExpression(FromESTree::from_estree(value, d)?))
+            }
+            "AssignmentExpression" => {
+                Ok(Self::AssignmentExpression(FromESTree::from_estree(value, d)?))
+            }
+            "AwaitExpression" => Ok(Self::AwaitExpression(FromESTree::from_estree(value, d)?)),
+            "BinaryExpression" => d.first_ok(
+                value,
+                &[
+                    |value, d| Ok(Self::BinaryExpression(FromESTree::from_estree(value, d)?)),
+                    |value, d| Ok(Self::PrivateInExpression(FromESTree::from_estree(value, d)?)),
+                ],
+            ),
+            "CallExpression" => Ok(Self::CallExpression(FromESTree::from_estree(value, d)?)),
+            "ChainExpression" => Ok(Self::ChainExpression(FromESTree::from_estree(value, d)?)),
+            "ClassDeclaration" | "ClassExpression" => {
+                Ok(Self::ClassExpression(FromESTree::from_estree(value, d)?))
+            }
+            "ConditionalExpression" => {
+                Ok(Self::ConditionalExpression(FromESTree::from_estree(value, d)?))
+            }
+            "FunctionDeclaration"
+            | "FunctionExpression"
+            | "TSDeclareFunction"
+            | "TSEmptyBodyFunctionExpression" => {
+                Ok(Self::FunctionExpression(FromESTree::from_estree(value, d)?))
+            }
+            "ImportExpression" => Ok(Self::ImportExpression(FromESTree::from_estree(value, d)?)),
+            "LogicalExpression" => Ok(Self::LogicalExpression(FromESTree::from_estree(value, d)?)),
+            "NewExpression" => Ok(Self::NewExpression(FromESTree::from_estree(value, d)?)),
+            "ObjectExpression" => Ok(Self::ObjectExpression(FromESTree::from_estree(value, d)?)),
+            "ParenthesizedExpression" => {
+                Ok(Self::ParenthesizedExpression(FromESTree::from_estree(value, d)?))
+            }
+            "SequenceExpression" => {
+                Ok(Self::SequenceExpression(FromESTree::from_estree(value, d)?))
+            }
+            "TaggedTemplateExpression" => {
+                Ok(Self::TaggedTemplateExpression(FromESTree::from_estree(value, d)?))
+            }
+            "ThisExpression" => Ok(Self::ThisExpression(FromESTree::from_estree(value, d)?)),
+            "UnaryExpression" => Ok(Self::UnaryExpression(FromESTree::from_estree(value, d)?)),
+            "UpdateExpression" => Ok(Self::UpdateExpression(FromESTree::from_estree(value, d)?)),
+            "YieldExpression" => Ok(Self::YieldExpression(FromESTree::from_estree(value, d)?)),
+            "MetaProperty" => d.first_ok(
+                value,
+                &[
+                    |value, d| Ok(Self::ImportMeta(FromESTree::from_estree(value, d)?)),
+                    |value, d| Ok(Self::NewTarget(FromESTree::from_estree(value, d)?)),
+                ],
+            ),
+            "JSXElement" => Ok(Self::JSXElement(FromESTree::from_estree(value, d)?)),
+            "JSXFragment" => Ok(Self::JSXFragment(FromESTree::from_estree(value, d)?)),
+            "TSAsExpression" => Ok(Self::TSAsExpression(FromESTree::from_estree(value, d)?)),
+            "TSSatisfiesExpression" => {
+                Ok(Self::TSSatisfiesExpression(FromESTree::from_estree(value, d)?))
+            }
+            "TSTypeAssertion" => Ok(Self::TSTypeAssertion(FromESTree::from_estree(value, d)?)),
+            "TSNonNullExpression" => {
+                Ok(Self::TSNonNullExpression(FromESTree::from_estree(value, d)?))
+            }
+            "TSInstantiationExpression" => {
+                Ok(Self::TSInstantiationExpression(FromESTree::from_estree(value, d)?))
+            }
+            "V8IntrinsicExpression" => {
+                Ok(Self::V8IntrinsicExpression(FromESTree::from_estree(value, d)?))
+            }
+            "MemberExpression" => Ok(Self::from(MemberExpression::from_estree(value, d)?)),
+            _ => Err(node.unexpected("Expression")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for ComputedMemberExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("MemberExpression")?;
+        Ok(ComputedMemberExpression {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            object: FromESTree::from_estree_field(node, "object", d)?,
+            expression: FromESTree::from_estree_field(node, "property", d)?,
+            optional: FromESTree::from_estree_field(node, "optional", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for StaticMemberExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("MemberExpression")?;
+        Ok(StaticMemberExpression {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            object: FromESTree::from_estree_field(node, "object", d)?,
+            property: FromESTree::from_estree_field(node, "property", d)?,
+            optional: FromESTree::from_estree_field(node, "optional", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for IdentifierName<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("Identifier")?;
+        Ok(IdentifierName {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            name: FromESTree::from_estree_field(node, "name", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for PrivateFieldExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("MemberExpression")?;
+        Ok(PrivateFieldExpression {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            object: FromESTree::from_estree_field(node, "object", d)?,
+            field: FromESTree::from_estree_field(node, "property", d)?,
+            optional: FromESTree::from_estree_field(node, "optional", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for PrivateIdentifier<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("PrivateIdentifier")?;
+        Ok(PrivateIdentifier {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            name: FromESTree::from_estree_field(node, "name", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for RegExp<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        Ok(RegExp {
+            pattern: RegExpPattern {
+                text: FromESTree::from_estree_field(node, "pattern", d)?,
+                pattern: Dummy::dummy(d.allocator()),
+            },
+            flags: FromESTree::from_estree_field(node, "flags", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for RegExpPattern<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        Ok(RegExpPattern {
+            text: FromESTree::from_estree_field(node, "pattern", d)?,
+            pattern: Dummy::dummy(d.allocator()),
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TemplateLiteral<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TemplateLiteral")?;
+        Ok(TemplateLiteral {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            quasis: FromESTree::from_estree_field(node, "quasis", d)?,
+            expressions: FromESTree::from_estree_field(node, "expressions", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TemplateElementValue<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        Ok(TemplateElementValue {
+            raw: FromESTree::from_estree_field(node, "raw", d)?,
+            cooked: FromESTree::from_estree_field(node, "cooked", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for IdentifierReference<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("Identifier")?;
+        Ok(IdentifierReference {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            name: FromESTree::from_estree_field(node, "name", d)?,
+            reference_id: Dummy::dummy(d.allocator()),
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for Super {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("Super")?;
+        Ok(Super { node_id: Dummy::dummy(d.allocator()), span: d.span(node) })
+    }
+}
+
+impl<'a> FromESTree<'a> for ArrayExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("ArrayExpression")?;
+        Ok(ArrayExpression {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            elements: FromESTree::from_estree_field(node, "elements", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for ArrayExpressionElement<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        if value.is_null() {
+            return Ok(Self::Elision(FromESTree::from_estree(value, d)?));
+        }
+        let node = Node::new(value)?;
+        match node.r#type {
+            "SpreadElement" => Ok(Self::SpreadElement(FromESTree::from_estree(value, d)?)),
+            "Literal"
+            | "TemplateLiteral"
+            | "Identifier"
+            | "Super"
+            | "ArrayExpression"
+            | "ArrowFunctionExpression"
+            | "AssignmentExpression"
+            | "AwaitExpression"
+            | "BinaryExpression"
+            | "CallExpression"
+            | "ChainExpression"
+            | "ClassDeclaration"
+            | "ClassExpression"
+            | "ConditionalExpression"
+            | "FunctionDeclaration"
+            | "FunctionExpression"
+            | "TSDeclareFunction"
+            | "TSEmptyBodyFunctionExpression"
+            | "ImportExpression"
+            | "LogicalExpression"
+            | "NewExpression"
+            | "ObjectExpression"
+            | "ParenthesizedExpression"
+            | "SequenceExpression"
+            | "TaggedTemplateExpression"
+            | "ThisExpression"
+            | "UnaryExpression"
+            | "UpdateExpression"
+            | "YieldExpression"
+            | "MetaProperty"
+            | "JSXElement"
+            | "JSXFragment"
+            | "TSAsExpression"
+            | "TSSatisfiesExpression"
+            | "TSTypeAssertion"
+            | "TSNonNullExpression"
+            | "TSInstantiationExpression"
+            | "V8IntrinsicExpression"
+            | "MemberExpression" => Ok(Self::from(Expression::from_estree(value, d)?)),
+            _ => Err(node.unexpected("ArrayExpressionElement")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for SpreadElement<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("SpreadElement")?;
+        Ok(SpreadElement {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            argument: FromESTree::from_estree_field(node, "argument", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSTypeParameterDeclaration<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSTypeParameterDeclaration")?;
+        Ok(TSTypeParameterDeclaration {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            params: FromESTree::from_estree_field(node, "params", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSTypeParameter<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSTypeParameter")?;
+        Ok(TSTypeParameter {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            name: FromESTree::from_estree_field(node, "name", d)?,
+            constraint: FromESTree::from_estree_field(node, "constraint", d)?,
+            default: FromESTree::from_estree_field(node, "default", d)?,
+            r#in: FromESTree::from_estree_field(node, "in", d)?,
+            out: FromESTree::from_estree_field(node, "out", d)?,
+            r#const: FromESTree::from_estree_field(node, "const", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSType<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "TSAnyKeyword" => Ok(Self::TSAnyKeyword(FromESTree::from_estree(value, d)?)),
+            "TSBigIntKeyword" => Ok(Self::TSBigIntKeyword(FromESTree::from_estree(value, d)?)),
+            "TSBooleanKeyword" => Ok(Self::TSBooleanKeyword(FromESTree::from_estree(value, d)?)),
+            "TSIntrinsicKeyword" => {
+                Ok(Self::TSIntrinsicKeyword(FromESTree::from_estree(value, d)?))
+            }
+            "TSNeverKeyword" => Ok(Self::TSNeverKeyword(FromESTree::from_estree(value, d)?)),
+            "TSNullKeyword" => Ok(Self::TSNullKeyword(FromESTree::from_estree(value, d)?)),
+            "TSNumberKeyword" => Ok(Self::TSNumberKeyword(FromESTree::from_estree(value, d)?)),
+            "TSObjectKeyword" => Ok(Self::TSObjectKeyword(FromESTree::from_estree(value, d)?)),
+            "TSStringKeyword" => Ok(Self::TSStringKeyword(FromESTree::from_estree(value, d)?)),
+            "TSSymbolKeyword" => Ok(Self::TSSymbolKeyword(FromESTree::from_estree(value, d)?)),
+            "TSUndefinedKeyword" => {
+                Ok(Self::TSUndefinedKeyword(FromESTree::from_estree(value, d)?))
+            }
+            "TSUnknownKeyword" => Ok(Self::TSUnknownKeyword(FromESTree::from_estree(value, d)?)),
+            "TSVoidKeyword" => Ok(Self::TSVoidKeyword(FromESTree::from_estree(value, d)?)),
+            "TSArrayType" => Ok(Self::TSArrayType(FromESTree::from_estree(value, d)?)),
+            "TSConditionalType" => Ok(Self::TSConditionalType(FromESTree::from_estree(value, d)?)),
+            "TSConstructorType" => Ok(Self::TSConstructorType(FromESTree::from_estree(value, d)?)),
+            "TSFunctionType" => Ok(Self::TSFunctionType(FromESTree::from_estree(value, d)?)),
+            "TSImportType" => Ok(Self::TSImportType(FromESTree::from_estree(value, d)?)),
+            "TSIndexedAccessType" => {
+                Ok(Self::TSIndexedAccessType(FromESTree::from_estree(value, d)?))
+            }
+            "TSInferType" => Ok(Self::TSInferType(FromESTree::from_estree(value, d)?)),
+            "TSIntersectionType" => {
+                Ok(Self::TSIntersectionType(FromESTree::from_estree(value, d)?))
+            }
+            "TSLiteralType" => Ok(Self::TSLiteralType(FromESTree::from_estree(value, d)?)),
+            "TSMappedType" => Ok(Self::TSMappedType(FromESTree::from_estree(value, d)?)),
+            "TSNamedTupleMember" => {
+                Ok(Self::TSNamedTupleMember(FromESTree::from_estree(value, d)?))
+            }
+            "TSTemplateLiteralType" => {
+                Ok(Self::TSTemplateLiteralType(FromESTree::from_estree(value, d)?))
+            }
+            "TSThisType" => Ok(Self::TSThisType(FromESTree::from_estree(value, d)?)),
+            "TSTupleType" => Ok(Self::TSTupleType(FromESTree::from_estree(value, d)?)),
+            "TSTypeLiteral" => Ok(Self::TSTypeLiteral(FromESTree::from_estree(value, d)?)),
+            "TSTypeOperator" => Ok(Self::TSTypeOperatorType(FromESTree::from_estree(value, d)?)),
+            "TSTypePredicate" => Ok(Self::TSTypePredicate(FromESTree::from_estree(value, d)?)),
+            "TSTypeQuery" => Ok(Self::TSTypeQuery(FromESTree::from_estree(value, d)?)),
+            "TSTypeReference" => Ok(Self::TSTypeReference(FromESTree::from_estree(value, d)?)),
+            "TSUnionType" => Ok(Self::TSUnionType(FromESTree::from_estree(value, d)?)),
+            "TSParenthesizedType" => {
+                Ok(Self::TSParenthesizedType(FromESTree::from_estree(value, d)?))
+            }
+            "TSJSDocNullableType" => {
+                Ok(Self::JSDocNullableType(FromESTree::from_estree(value, d)?))
+            }
+            "TSJSDocNonNullableType" => {
+                Ok(Self::JSDocNonNullableType(FromESTree::from_estree(value, d)?))
+            }
+            "TSJSDocUnknownType" => Ok(Self::JSDocUnknownType(FromESTree::from_estree(value, d)?)),
+            _ => Err(node.unexpected("TSType")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for TSAnyKeyword {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSAnyKeyword")?;
+        Ok(TSAnyKeyword { node_id: Dummy::dummy(d.allocator()), span: d.span(node) })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSBigIntKeyword {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSBigIntKeyword")?;
+        Ok(TSBigIntKeyword { node_id: Dummy::dummy(d.allocator()), span: d.span(node) })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSBooleanKeyword {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSBooleanKeyword")?;
+        Ok(TSBooleanKeyword { node_id: Dummy::dummy(d.allocator()), span: d.span(node) })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSIntrinsicKeyword {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSIntrinsicKeyword")?;
+        Ok(TSIntrinsicKeyword { node_id: Dummy::dummy(d.allocator()), span: d.span(node) })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSNeverKeyword {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSNeverKeyword")?;
+        Ok(TSNeverKeyword { node_id: Dummy::dummy(d.allocator()), span: d.span(node) })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSNullKeyword {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSNullKeyword")?;
+        Ok(TSNullKeyword { node_id: Dummy::dummy(d.allocator()), span: d.span(node) })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSNumberKeyword {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSNumberKeyword")?;
+        Ok(TSNumberKeyword { node_id: Dummy::dummy(d.allocator()), span: d.span(node) })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSObjectKeyword {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSObjectKeyword")?;
+        Ok(TSObjectKeyword { node_id: Dummy::dummy(d.allocator()), span: d.span(node) })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSStringKeyword {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSStringKeyword")?;
+        Ok(TSStringKeyword { node_id: Dummy::dummy(d.allocator()), span: d.span(node) })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSSymbolKeyword {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSSymbolKeyword")?;
+        Ok(TSSymbolKeyword { node_id: Dummy::dummy(d.allocator()), span: d.span(node) })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSUndefinedKeyword {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSUndefinedKeyword")?;
+        Ok(TSUndefinedKeyword { node_id: Dummy::dummy(d.allocator()), span: d.span(node) })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSUnknownKeyword {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSUnknownKeyword")?;
+        Ok(TSUnknownKeyword { node_id: Dummy::dummy(d.allocator()), span: d.span(node) })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSVoidKeyword {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSVoidKeyword")?;
+        Ok(TSVoidKeyword { node_id: Dummy::dummy(d.allocator()), span: d.span(node) })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSArrayType<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSArrayType")?;
+        Ok(TSArrayType {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            element_type: FromESTree::from_estree_field(node, "elementType", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSConditionalType<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSConditionalType")?;
+        Ok(TSConditionalType {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            check_type: FromESTree::from_estree_field(node, "checkType", d)?,
+            extends_type: FromESTree::from_estree_field(node, "extendsType", d)?,
+            true_type: FromESTree::from_estree_field(node, "trueType", d)?,
+            false_type: FromESTree::from_estree_field(node, "falseType", d)?,
+            scope_id: Dummy::dummy(d.allocator()),
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSConstructorType<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSConstructorType")?;
+        Ok(TSConstructorType {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            r#abstract: FromESTree::from_estree_field(node, "abstract", d)?,
+            type_parameters: FromESTree::from_estree_field(node, "typeParameters", d)?,
+            params: FromESTree::from_estree_field(node, "params", d)?,
+            return_type: FromESTree::from_estree_field(node, "returnType", d)?,
+            scope_id: Dummy::dummy(d.allocator()),
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for FormalParameterKind {
+    fn from_estree(value: &Value, _d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        match value.as_str() {
+            Some("FormalParameter") => Ok(Self::FormalParameter),
+            Some("UniqueFormalParameters") => Ok(Self::UniqueFormalParameters),
+            Some("ArrowFormalParameters") => Ok(Self::ArrowFormalParameters),
+            Some("Signature") => Ok(Self::Signature),
+            _ => Err(invalid_value(value, "FormalParameterKind")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for Decorator<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("Decorator")?;
+        Ok(Decorator {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            expression: FromESTree::from_estree_field(node, "expression", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSTypeAnnotation<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSTypeAnnotation")?;
+        Ok(TSTypeAnnotation {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            type_annotation: FromESTree::from_estree_field(node, "typeAnnotation", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSImportType<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSImportType")?;
+        Ok(TSImportType {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            source: FromESTree::from_estree_field(node, "source", d)?,
+            options: FromESTree::from_estree_field(node, "options", d)?,
+            qualifier: FromESTree::from_estree_field(node, "qualifier", d)?,
+            type_arguments: FromESTree::from_estree_field(node, "typeArguments", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for ObjectExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("ObjectExpression")?;
+        Ok(ObjectExpression {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            properties: FromESTree::from_estree_field(node, "properties", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for ObjectPropertyKind<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "Property" => Ok(Self::ObjectProperty(FromESTree::from_estree(value, d)?)),
+            "SpreadElement" => Ok(Self::SpreadProperty(FromESTree::from_estree(value, d)?)),
+            _ => Err(node.unexpected("ObjectPropertyKind")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for ObjectProperty<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("Property")?;
+        Ok(ObjectProperty {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            kind: FromESTree::from_estree_field(node, "kind", d)?,
+            key: FromESTree::from_estree_field(node, "key", d)?,
+            value: FromESTree::from_estree_field(node, "value", d)?,
+            method: FromESTree::from_estree_field(node, "method", d)?,
+            shorthand: FromESTree::from_estree_field(node, "shorthand", d)?,
+            computed: FromESTree::from_estree_field(node, "computed", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for PropertyKind {
+    fn from_estree(value: &Value, _d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        match value.as_str() {
+            Some("init") => Ok(Self::Init),
+            Some("get") => Ok(Self::Get),
+            Some("set") => Ok(Self::Set),
+            _ => Err(invalid_value(value, "PropertyKind")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for TSImportTypeQualifier<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "Identifier" => Ok(Self::Identifier(FromESTree::from_estree(value, d)?)),
+            "TSQualifiedName" => Ok(Self::QualifiedName(FromESTree::from_estree(value, d)?)),
+            _ => Err(node.unexpected("TSImportTypeQualifier")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for TSImportTypeQualifiedName<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSQualifiedName")?;
+        Ok(TSImportTypeQualifiedName {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            left: FromESTree::from_estree_field(node, "left", d)?,
+            right: FromESTree::from_estree_field(node, "right", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSTypeParameterInstantiation<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSTypeParameterInstantiation")?;
+        Ok(TSTypeParameterInstantiation {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            params: FromESTree::from_estree_field(node, "params", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSIndexedAccessType<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSIndexedAccessType")?;
+        Ok(TSIndexedAccessType {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            object_type: FromESTree::from_estree_field(node, "objectType", d)?,
+            index_type: FromESTree::from_estree_field(node, "indexType", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSInferType<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSInferType")?;
+        Ok(TSInferType {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            type_parameter: FromESTree::from_estree_field(node, "typeParameter", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSIntersectionType<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSIntersectionType")?;
+        Ok(TSIntersectionType {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            types: FromESTree::from_estree_field(node, "types", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSLiteralType<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSLiteralType")?;
+        Ok(TSLiteralType {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            literal: FromESTree::from_estree_field(node, "literal", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSLiteral<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "Literal" => d.first_ok(
+                value,
+                &[
+                    |value, d| Ok(Self::BooleanLiteral(FromESTree::from_estree(value, d)?)),
+                    |value, d| Ok(Self::NumericLiteral(FromESTree::from_estree(value, d)?)),
+                    |value, d| Ok(Self::BigIntLiteral(FromESTree::from_estree(value, d)?)),
+                    |value, d| Ok(Self::StringLiteral(FromESTree::from_estree(value, d)?)),
+                ],
+            ),
+            "TemplateLiteral" => Ok(Self::TemplateLiteral(FromESTree::from_estree(value, d)?)),
+            "UnaryExpression" => Ok(Self::UnaryExpression(FromESTree::from_estree(value, d)?)),
+            _ => Err(node.unexpected("TSLiteral")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for UnaryExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("UnaryExpression")?;
+        Ok(UnaryExpression {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            operator: FromESTree::from_estree_field(node, "operator", d)?,
+            argument: FromESTree::from_estree_field(node, "argument", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for UnaryOperator {
+    fn from_estree(value: &Value, _d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        match value.as_str() {
+            Some("+") => Ok(Self::UnaryPlus),
+            Some("-") => Ok(Self::UnaryNegation),
+            Some("!") => Ok(Self::LogicalNot),
+            Some("~") => Ok(Self::BitwiseNot),
+            Some("typeof") => Ok(Self::Typeof),
+            Some("void") => Ok(Self::Void),
+            Some("delete") => Ok(Self::Delete),
+            _ => Err(invalid_value(value, "UnaryOperator")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for TSNamedTupleMember<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSNamedTupleMember")?;
+        Ok(TSNamedTupleMember {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            label: FromESTree::from_estree_field(node, "label", d)?,
+            element_type: FromESTree::from_estree_field(node, "elementType", d)?,
+            optional: FromESTree::from_estree_field(node, "optional", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSTupleElement<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "TSOptionalType" => Ok(Self::TSOptionalType(FromESTree::from_estree(value, d)?)),
+            "TSRestType" => Ok(Self::TSRestType(FromESTree::from_estree(value, d)?)),
+            "TSAnyKeyword"
+            | "TSBigIntKeyword"
+            | "TSBooleanKeyword"
+            | "TSIntrinsicKeyword"
+            | "TSNeverKeyword"
+            | "TSNullKeyword"
+            | "TSNumberKeyword"
+            | "TSObjectKeyword"
+            | "TSStringKeyword"
+            | "TSSymbolKeyword"
+            | "TSUndefinedKeyword"
+            | "TSUnknownKeyword"
+            | "TSVoidKeyword"
+            | "TSArrayType"
+            | "TSConditionalType"
+            | "TSConstructorType"
+            | "TSFunctionType"
+            | "TSImportType"
+            | "TSIndexedAccessType"
+            | "TSInferType"
+            | "TSIntersectionType"
+            | "TSLiteralType"
+            | "TSMappedType"
+            | "TSNamedTupleMember"
+            | "TSTemplateLiteralType"
+            | "TSThisType"
+            | "TSTupleType"
+            | "TSTypeLiteral"
+            | "TSTypeOperator"
+            | "TSTypePredicate"
+            | "TSTypeQuery"
+            | "TSTypeReference"
+            | "TSUnionType"
+            | "TSParenthesizedType"
+            | "TSJSDocNullableType"
+            | "TSJSDocNonNullableType"
+            | "TSJSDocUnknownType" => Ok(Self::from(TSType::from_estree(value, d)?)),
+            _ => Err(node.unexpected("TSTupleElement")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for TSOptionalType<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSOptionalType")?;
+        Ok(TSOptionalType {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            type_annotation: FromESTree::from_estree_field(node, "typeAnnotation", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSRestType<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSRestType")?;
+        Ok(TSRestType {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            type_annotation: FromESTree::from_estree_field(node, "typeAnnotation", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSTemplateLiteralType<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSTemplateLiteralType")?;
+        Ok(TSTemplateLiteralType {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            quasis: FromESTree::from_estree_field(node, "quasis", d)?,
+            types: FromESTree::from_estree_field(node, "types", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSThisType {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSThisType")?;
+        Ok(TSThisType { node_id: Dummy::dummy(d.allocator()), span: d.span(node) })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSTupleType<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSTupleType")?;
+        Ok(TSTupleType {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            element_types: FromESTree::from_estree_field(node, "elementTypes", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSTypeLiteral<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSTypeLiteral")?;
+        Ok(TSTypeLiteral {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            members: FromESTree::from_estree_field(node, "members", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSSignature<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "TSIndexSignature" => Ok(Self::TSIndexSignature(FromESTree::from_estree(value, d)?)),
+            "TSPropertySignature" => {
+                Ok(Self::TSPropertySignature(FromESTree::from_estree(value, d)?))
+            }
+            "TSCallSignatureDeclaration" => {
+                Ok(Self::TSCallSignatureDeclaration(FromESTree::from_estree(value, d)?))
+            }
+            "TSConstructSignatureDeclaration" => {
+                Ok(Self::TSConstructSignatureDeclaration(FromESTree::from_estree(value, d)?))
+            }
+            "TSMethodSignature" => Ok(Self::TSMethodSignature(FromESTree::from_estree(value, d)?)),
+            _ => Err(node.unexpected("TSSignature")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for TSIndexSignatureName<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("Identifier")?;
+        Ok(TSIndexSignatureName {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            name: FromESTree::from_estree_field(node, "name", d)?,
+            type_annotation: FromESTree::from_estree_field(node, "typeAnnotation", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSPropertySignature<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSPropertySignature")?;
+        Ok(TSPropertySignature {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            computed: FromESTree::from_estree_field(node, "computed", d)?,
+            optional: FromESTree::from_estree_field(node, "optional", d)?,
+            readonly: FromESTree::from_estree_field(node, "readonly", d)?,
+            key: FromESTree::from_estree_field(node, "key", d)?,
+            type_annotation: FromESTree::from_estree_field(node, "typeAnnotation", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSConstructSignatureDeclaration<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSConstructSignatureDeclaration")?;
+        Ok(TSConstructSignatureDeclaration {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            type_parameters: FromESTree::from_estree_field(node, "typeParameters", d)?,
+            params: FromESTree::from_estree_field(node, "params", d)?,
+            return_type: FromESTree::from_estree_field(node, "returnType", d)?,
+            scope_id: Dummy::dummy(d.allocator()),
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSMethodSignatureKind {
+    fn from_estree(value: &Value, _d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        match value.as_str() {
+            Some("method") => Ok(Self::Method),
+            Some("get") => Ok(Self::Get),
+            Some("set") => Ok(Self::Set),
+            _ => Err(invalid_value(value, "TSMethodSignatureKind")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for TSTypeOperator<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSTypeOperator")?;
+        Ok(TSTypeOperator {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            operator: FromESTree::from_estree_field(node, "operator", d)?,
+            type_annotation: FromESTree::from_estree_field(node, "typeAnnotation", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSTypeOperatorOperator {
+    fn from_estree(value: &Value, _d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        match value.as_str() {
+            Some("keyof") => Ok(Self::Keyof),
+            Some("unique") => Ok(Self::Unique),
+            Some("readonly") => Ok(Self::Readonly),
+            _ => Err(invalid_value(value, "TSTypeOperatorOperator")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for TSTypePredicate<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSTypePredicate")?;
+        Ok(TSTypePredicate {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            parameter_name: FromESTree::from_estree_field(node, "parameterName", d)?,
+            asserts: FromESTree::from_estree_field(node, "asserts", d)?,
+            type_annotation: FromESTree::from_estree_field(node, "typeAnnotation", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSTypePredicateName<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "Identifier" => Ok(Self::Identifier(FromESTree::from_estree(value, d)?)),
+            "TSThisType" => Ok(Self::This(FromESTree::from_estree(value, d)?)),
+            _ => Err(node.unexpected("TSTypePredicateName")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for TSTypeQuery<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSTypeQuery")?;
+        Ok(TSTypeQuery {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            expr_name: FromESTree::from_estree_field(node, "exprName", d)?,
+            type_arguments: FromESTree::from_estree_field(node, "typeArguments", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSTypeQueryExprName<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "TSImportType" => Ok(Self::TSImportType(FromESTree::from_estree(value, d)?)),
+            "Identifier" | "TSQualifiedName" | "ThisExpression" => {
+                Ok(Self::from(TSTypeName::from_estree(value, d)?))
+            }
+            _ => Err(node.unexpected("TSTypeQueryExprName")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for TSTypeName<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "Identifier" => Ok(Self::IdentifierReference(FromESTree::from_estree(value, d)?)),
+            "TSQualifiedName" => Ok(Self::QualifiedName(FromESTree::from_estree(value, d)?)),
+            "ThisExpression" => Ok(Self::ThisExpression(FromESTree::from_estree(value, d)?)),
+            _ => Err(node.unexpected("TSTypeName")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for TSQualifiedName<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSQualifiedName")?;
+        Ok(TSQualifiedName {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            left: FromESTree::from_estree_field(node, "left", d)?,
+            right: FromESTree::from_estree_field(node, "right", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for ThisExpression {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("ThisExpression")?;
+        Ok(ThisExpression { node_id: Dummy::dummy(d.allocator()), span: d.span(node) })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSTypeReference<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSTypeReference")?;
+        Ok(TSTypeReference {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            type_name: FromESTree::from_estree_field(node, "typeName", d)?,
+            type_arguments: FromESTree::from_estree_field(node, "typeArguments", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSUnionType<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSUnionType")?;
+        Ok(TSUnionType {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            types: FromESTree::from_estree_field(node, "types", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for JSDocNullableType<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSJSDocNullableType")?;
+        Ok(JSDocNullableType {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            type_annotation: FromESTree::from_estree_field(node, "typeAnnotation", d)?,
+            postfix: FromESTree::from_estree_field(node, "postfix", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for JSDocNonNullableType<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSJSDocNonNullableType")?;
+        Ok(JSDocNonNullableType {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            type_annotation: FromESTree::from_estree_field(node, "typeAnnotation", d)?,
+            postfix: FromESTree::from_estree_field(node, "postfix", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for JSDocUnknownType {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSJSDocUnknownType")?;
+        Ok(JSDocUnknownType { node_id: Dummy::dummy(d.allocator()), span: d.span(node) })
+    }
+}
+
+impl<'a> FromESTree<'a> for ArrowFunctionBody<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "BlockStatement" => Ok(Self::FunctionBody(FromESTree::from_estree(value, d)?)),
+            "Literal"
+            | "TemplateLiteral"
+            | "Identifier"
+            | "Super"
+            | "ArrayExpression"
+            | "ArrowFunctionExpression"
+            | "AssignmentExpression"
+            | "AwaitExpression"
+            | "BinaryExpression"
+            | "CallExpression"
+            | "ChainExpression"
+            | "ClassDeclaration"
+            | "ClassExpression"
+            | "ConditionalExpression"
+            | "FunctionDeclaration"
+            | "FunctionExpression"
+            | "TSDeclareFunction"
+            | "TSEmptyBodyFunctionExpression"
+            | "ImportExpression"
+            | "LogicalExpression"
+            | "NewExpression"
+            | "ObjectExpression"
+            | "ParenthesizedExpression"
+            | "SequenceExpression"
+            | "TaggedTemplateExpression"
+            | "ThisExpression"
+            | "UnaryExpression"
+            | "UpdateExpression"
+            | "YieldExpression"
+            | "MetaProperty"
+            | "JSXElement"
+            | "JSXFragment"
+            | "TSAsExpression"
+            | "TSSatisfiesExpression"
+            | "TSTypeAssertion"
+            | "TSNonNullExpression"
+            | "TSInstantiationExpression"
+            | "V8IntrinsicExpression"
+            | "MemberExpression" => Ok(Self::from(Expression::from_estree(value, d)?)),
+            _ => Err(node.unexpected("ArrowFunctionBody")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for FunctionBody<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("BlockStatement")?;
+        let (directives, statements) = d.prepended(node, "body")?;
+        Ok(FunctionBody {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            directives,
+            statements,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for AssignmentExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("AssignmentExpression")?;
+        Ok(AssignmentExpression {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            operator: FromESTree::from_estree_field(node, "operator", d)?,
+            left: FromESTree::from_estree_field(node, "left", d)?,
+            right: FromESTree::from_estree_field(node, "right", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for AssignmentOperator {
+    fn from_estree(value: &Value, _d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        match value.as_str() {
+            Some("=") => Ok(Self::Assign),
+            Some("+=") => Ok(Self::Addition),
+            Some("-=") => Ok(Self::Subtraction),
+            Some("*=") => Ok(Self::Multiplication),
+            Some("/=") => Ok(Self::Division),
+            Some("%=") => Ok(Self::Remainder),
+            Some("**=") => Ok(Self::Exponential),
+            Some("<<=") => Ok(Self::ShiftLeft),
+            Some(">>=") => Ok(Self::ShiftRight),
+            Some(">>>=") => Ok(Self::ShiftRightZeroFill),
+            Some("|=") => Ok(Self::BitwiseOR),
+            Some("^=") => Ok(Self::BitwiseXOR),
+            Some("&=") => Ok(Self::BitwiseAnd),
+            Some("||=") => Ok(Self::LogicalOr),
+            Some("&&=") => Ok(Self::LogicalAnd),
+            Some("??=") => Ok(Self::LogicalNullish),
+            _ => Err(invalid_value(value, "AssignmentOperator")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for AssignmentTarget<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "Identifier"
+            | "TSAsExpression"
+            | "TSSatisfiesExpression"
+            | "TSNonNullExpression"
+            | "TSTypeAssertion"
+            | "MemberExpression" => Ok(Self::from(SimpleAssignmentTarget::from_estree(value, d)?)),
+            "ArrayPattern" | "ObjectPattern" => {
+                Ok(Self::from(AssignmentTargetPattern::from_estree(value, d)?))
+            }
+            _ => Err(node.unexpected("AssignmentTarget")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for SimpleAssignmentTarget<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "Identifier" => {
+                Ok(Self::AssignmentTargetIdentifier(FromESTree::from_estree(value, d)?))
+            }
+            "TSAsExpression" => Ok(Self::TSAsExpression(FromESTree::from_estree(value, d)?)),
+            "TSSatisfiesExpression" => {
+                Ok(Self::TSSatisfiesExpression(FromESTree::from_estree(value, d)?))
+            }
+            "TSNonNullExpression" => {
+                Ok(Self::TSNonNullExpression(FromESTree::from_estree(value, d)?))
+            }
+            "TSTypeAssertion" => Ok(Self::TSTypeAssertion(FromESTree::from_estree(value, d)?)),
+            "MemberExpression" => Ok(Self::from(MemberExpression::from_estree(value, d)?)),
+            _ => Err(node.unexpected("SimpleAssignmentTarget")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for TSAsExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSAsExpression")?;
+        Ok(TSAsExpression {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            expression: FromESTree::from_estree_field(node, "expression", d)?,
+            type_annotation: FromESTree::from_estree_field(node, "typeAnnotation", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSSatisfiesExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSSatisfiesExpression")?;
+        Ok(TSSatisfiesExpression {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            expression: FromESTree::from_estree_field(node, "expression", d)?,
+            type_annotation: FromESTree::from_estree_field(node, "typeAnnotation", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSNonNullExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSNonNullExpression")?;
+        Ok(TSNonNullExpression {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            expression: FromESTree::from_estree_field(node, "expression", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSTypeAssertion<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSTypeAssertion")?;
+        Ok(TSTypeAssertion {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            type_annotation: FromESTree::from_estree_field(node, "typeAnnotation", d)?,
+            expression: FromESTree::from_estree_field(node, "expression", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for AssignmentTargetPattern<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "ArrayPattern" => Ok(Self::ArrayAssignmentTarget(FromESTree::from_estree(value, d)?)),
+            "ObjectPattern" => Ok(Self::ObjectAssignmentTarget(FromESTree::from_estree(value, d)?)),
+            _ => Err(node.unexpected("AssignmentTargetPattern")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for ArrayAssignmentTarget<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("ArrayPattern")?;
+        let (elements, rest) = d.appended(node, "elements")?;
+        Ok(ArrayAssignmentTarget {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            elements,
+            rest,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for AssignmentTargetRest<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("RestElement")?;
+        Ok(AssignmentTargetRest {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            target: FromESTree::from_estree_field(node, "argument", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for AssignmentTargetMaybeDefault<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "AssignmentPattern" => {
+                Ok(Self::AssignmentTargetWithDefault(FromESTree::from_estree(value, d)?))
+            }
+            "Identifier"
+            | "TSAsExpression"
+            | "TSSatisfiesExpression"
+            | "TSNonNullExpression"
+            | "TSTypeAssertion"
+            | "MemberExpression"
+            | "ArrayPattern"
+            | "ObjectPattern" => Ok(Self::from(AssignmentTarget::from_estree(value, d)?)),
+            _ => Err(node.unexpected("AssignmentTargetMaybeDefault")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for AssignmentTargetWithDefault<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("AssignmentPattern")?;
+        Ok(AssignmentTargetWithDefault {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            binding: FromESTree::from_estree_field(node, "left", d)?,
+            init: FromESTree::from_estree_field(node, "right", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for ObjectAssignmentTarget<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("ObjectPattern")?;
+        let (properties, rest) = d.appended(node, "properties")?;
+        Ok(ObjectAssignmentTarget {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            properties,
+            rest,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for AssignmentTargetProperty<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "Property" => d.first_ok(
+                value,
+                &[
+                    |value, d| {
+                        Ok(Self::AssignmentTargetPropertyIdentifier(FromESTree::from_estree(
+                            value, d,
+                        )?))
+                    },
+                    |value, d| {
+                        Ok(Self::AssignmentTargetPropertyProperty(FromESTree::from_estree(
+                            value, d,
+                        )?))
+                    },
+                ],
+            ),
+            _ => Err(node.unexpected("AssignmentTargetProperty")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for AssignmentTargetPropertyProperty<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("Property")?;
+        Ok(AssignmentTargetPropertyProperty {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            name: FromESTree::from_estree_field(node, "key", d)?,
+            binding: FromESTree::from_estree_field(node, "value", d)?,
+            computed: FromESTree::from_estree_field(node, "computed", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for AwaitExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("AwaitExpression")?;
+        Ok(AwaitExpression {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            argument: FromESTree::from_estree_field(node, "argument", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for BinaryExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("BinaryExpression")?;
+        Ok(BinaryExpression {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            left: FromESTree::from_estree_field(node, "left", d)?,
+            operator: FromESTree::from_estree_field(node, "operator", d)?,
+            right: FromESTree::from_estree_field(node, "right", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for BinaryOperator {
+    fn from_estree(value: &Value, _d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        match value.as_str() {
+            Some("==") => Ok(Self::Equality),
+            Some("!=") => Ok(Self::Inequality),
+            Some("===") => Ok(Self::StrictEquality),
+            Some("!==") => Ok(Self::StrictInequality),
+            Some("<") => Ok(Self::LessThan),
+            Some("<=") => Ok(Self::LessEqualThan),
+            Some(">") => Ok(Self::GreaterThan),
+            Some(">=") => Ok(Self::GreaterEqualThan),
+            Some("+") => Ok(Self::Addition),
+            Some("-") => Ok(Self::Subtraction),
+            Some("*") => Ok(Self::Multiplication),
+            Some("/") => Ok(Self::Division),
+            Some("%") => Ok(Self::Remainder),
+            Some("**") => Ok(Self::Exponential),
+            Some("<<") => Ok(Self::ShiftLeft),
+            Some(">>") => Ok(Self::ShiftRight),
+            Some(">>>") => Ok(Self::ShiftRightZeroFill),
+            Some("|") => Ok(Self::BitwiseOR),
+            Some("^") => Ok(Self::BitwiseXOR),
+            Some("&") => Ok(Self::BitwiseAnd),
+            Some("in") => Ok(Self::In),
+            Some("instanceof") => Ok(Self::Instanceof),
+            _ => Err(invalid_value(value, "BinaryOperator")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for CallExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("CallExpression")?;
+        Ok(CallExpression {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            callee: FromESTree::from_estree_field(node, "callee", d)?,
+            type_arguments: d.optional_field(node, "typeArguments")?,
+            arguments: FromESTree::from_estree_field(node, "arguments", d)?,
+            optional: FromESTree::from_estree_field(node, "optional", d)?,
+            pure: Dummy::dummy(d.allocator()),
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for Argument<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "SpreadElement" => Ok(Self::SpreadElement(FromESTree::from_estree(value, d)?)),
+            "Literal"
+            | "TemplateLiteral"
+            | "Identifier"
+            | "Super"
+            | "ArrayExpression"
+            | "ArrowFunctionExpression"
+            | "AssignmentExpression"
+            | "AwaitExpression"
+            | "BinaryExpression"
+            | "CallExpression"
+            | "ChainExpression"
+            | "ClassDeclaration"
+            | "ClassExpression"
+            | "ConditionalExpression"
+            | "FunctionDeclaration"
+            | "FunctionExpression"
+            | "TSDeclareFunction"
+            | "TSEmptyBodyFunctionExpression"
+            | "ImportExpression"
+            | "LogicalExpression"
+            | "NewExpression"
+            | "ObjectExpression"
+            | "ParenthesizedExpression"
+            | "SequenceExpression"
+            | "TaggedTemplateExpression"
+            | "ThisExpression"
+            | "UnaryExpression"
+            | "UpdateExpression"
+            | "YieldExpression"
+            | "MetaProperty"
+            | "JSXElement"
+            | "JSXFragment"
+            | "TSAsExpression"
+            | "TSSatisfiesExpression"
+            | "TSTypeAssertion"
+            | "TSNonNullExpression"
+            | "TSInstantiationExpression"
+            | "V8IntrinsicExpression"
+            | "MemberExpression" => Ok(Self::from(Expression::from_estree(value, d)?)),
+            _ => Err(node.unexpected("Argument")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for ChainExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("ChainExpression")?;
+        Ok(ChainExpression {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            expression: FromESTree::from_estree_field(node, "expression", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for ChainElement<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "CallExpression" => Ok(Self::CallExpression(FromESTree::from_estree(value, d)?)),
+            "TSNonNullExpression" => {
+                Ok(Self::TSNonNullExpression(FromESTree::from_estree(value, d)?))
+            }
+            "MemberExpression" => Ok(Self::from(MemberExpression::from_estree(value, d)?)),
+            _ => Err(node.unexpected("ChainElement")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for ClassType {
+    fn from_estree(value: &Value, _d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        match value.as_str() {
+            Some("ClassDeclaration") => Ok(Self::ClassDeclaration),
+            Some("ClassExpression") => Ok(Self::ClassExpression),
+            _ => Err(invalid_value(value, "ClassType")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for ClassBody<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("ClassBody")?;
+        Ok(ClassBody {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            body: FromESTree::from_estree_field(node, "body", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for ClassElement<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "StaticBlock" => Ok(Self::StaticBlock(FromESTree::from_estree(value, d)?)),
+            "MethodDefinition" | "TSAbstractMethodDefinition" => {
+                Ok(Self::MethodDefinition(FromESTree::from_estree(value, d)?))
+            }
+            "PropertyDefinition" | "TSAbstractPropertyDefinition" => {
+                Ok(Self::PropertyDefinition(FromESTree::from_estree(value, d)?))
+            }
+            "AccessorProperty" | "TSAbstractAccessorProperty" => {
+                Ok(Self::AccessorProperty(FromESTree::from_estree(value, d)?))
+            }
+            "TSIndexSignature" => Ok(Self::TSIndexSignature(FromESTree::from_estree(value, d)?)),
+            _ => Err(node.unexpected("ClassElement")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for StaticBlock<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("StaticBlock")?;
+        Ok(StaticBlock {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            body: FromESTree::from_estree_field(node, "body", d)?,
+            scope_id: Dummy::dummy(d.allocator()),
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for MethodDefinition<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        Ok(MethodDefinition {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            r#type: FromESTree::from_estree_field(node, "type", d)?,
+            decorators: FromESTree::from_estree_field(node, "decorators", d)?,
+            key: FromESTree::from_estree_field(node, "key", d)?,
+            value: FromESTree::from_estree_field(node, "value", d)?,
+            kind: FromESTree::from_estree_field(node, "kind", d)?,
+            computed: FromESTree::from_estree_field(node, "computed", d)?,
+            r#static: FromESTree::from_estree_field(node, "static", d)?,
+            r#override: d.optional_field(node, "override")?,
+            optional: d.optional_field(node, "optional")?,
+            accessibility: d.optional_field(node, "accessibility")?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for MethodDefinitionType {
+    fn from_estree(value: &Value, _d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        match value.as_str() {
+            Some("MethodDefinition") => Ok(Self::MethodDefinition),
+            Some("TSAbstractMethodDefinition") => Ok(Self::TSAbstractMethodDefinition),
+            _ => Err(invalid_value(value, "MethodDefinitionType")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for FunctionType {
+    fn from_estree(value: &Value, _d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        match value.as_str() {
+            Some("FunctionDeclaration") => Ok(Self::FunctionDeclaration),
+            Some("FunctionExpression") => Ok(Self::FunctionExpression),
+            Some("TSDeclareFunction") => Ok(Self::TSDeclareFunction),
+            Some("TSEmptyBodyFunctionExpression") => Ok(Self::TSEmptyBodyFunctionExpression),
+            _ => Err(invalid_value(value, "FunctionType")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for MethodDefinitionKind {
+    fn from_estree(value: &Value, _d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        match value.as_str() {
+            Some("constructor") => Ok(Self::Constructor),
+            Some("method") => Ok(Self::Method),
+            Some("get") => Ok(Self::Get),
+            Some("set") => Ok(Self::Set),
+            _ => Err(invalid_value(value, "MethodDefinitionKind")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for TSAccessibility {
+    fn from_estree(value: &Value, _d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        match value.as_str() {
+            Some("private") => Ok(Self::Private),
+            Some("protected") => Ok(Self::Protected),
+            Some("public") => Ok(Self::Public),
+            _ => Err(invalid_value(value, "TSAccessibility")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for PropertyDefinition<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        Ok(PropertyDefinition {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            r#type: FromESTree::from_estree_field(node, "type", d)?,
+            decorators: FromESTree::from_estree_field(node, "decorators", d)?,
+            key: FromESTree::from_estree_field(node, "key", d)?,
+            type_annotation: d.optional_field(node, "typeAnnotation")?,
+            value: FromESTree::from_estree_field(node, "value", d)?,
+            computed: FromESTree::from_estree_field(node, "computed", d)?,
+            r#static: FromESTree::from_estree_field(node, "static", d)?,
+            declare: d.optional_field(node, "declare")?,
+            r#override: d.optional_field(node, "override")?,
+            optional: d.optional_field(node, "optional")?,
+            definite: d.optional_field(node, "definite")?,
+            readonly: d.optional_field(node, "readonly")?,
+            accessibility: d.optional_field(node, "accessibility")?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for PropertyDefinitionType {
+    fn from_estree(value: &Value, _d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        match value.as_str() {
+            Some("PropertyDefinition") => Ok(Self::PropertyDefinition),
+            Some("TSAbstractPropertyDefinition") => Ok(Self::TSAbstractPropertyDefinition),
+            _ => Err(invalid_value(value, "PropertyDefinitionType")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for AccessorProperty<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        Ok(AccessorProperty {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            r#type: FromESTree::from_estree_field(node, "type", d)?,
+            decorators: FromESTree::from_estree_field(node, "decorators", d)?,
+            key: FromESTree::from_estree_field(node, "key", d)?,
+            type_annotation: d.optional_field(node, "typeAnnotation")?,
+            value: FromESTree::from_estree_field(node, "value", d)?,
+            computed: FromESTree::from_estree_field(node, "computed", d)?,
+            r#static: FromESTree::from_estree_field(node, "static", d)?,
+            r#override: d.optional_field(node, "override")?,
+            definite: d.optional_field(node, "definite")?,
+            accessibility: d.optional_field(node, "accessibility")?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for AccessorPropertyType {
+    fn from_estree(value: &Value, _d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        match value.as_str() {
+            Some("AccessorProperty") => Ok(Self::AccessorProperty),
+            Some("TSAbstractAccessorProperty") => Ok(Self::TSAbstractAccessorProperty),
+            _ => Err(invalid_value(value, "AccessorPropertyType")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for ConditionalExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("ConditionalExpression")?;
+        Ok(ConditionalExpression {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            test: FromESTree::from_estree_field(node, "test", d)?,
+            consequent: FromESTree::from_estree_field(node, "consequent", d)?,
+            alternate: FromESTree::from_estree_field(node, "alternate", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for ImportExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("ImportExpression")?;
+        Ok(ImportExpression {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            source: FromESTree::from_estree_field(node, "source", d)?,
+            options: FromESTree::from_estree_field(node, "options", d)?,
+            phase: FromESTree::from_estree_field(node, "phase", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for ImportPhase {
+    fn from_estree(value: &Value, _d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        match value.as_str() {
+            Some("source") => Ok(Self::Source),
+            Some("defer") => Ok(Self::Defer),
+            _ => Err(invalid_value(value, "ImportPhase")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for LogicalExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("LogicalExpression")?;
+        Ok(LogicalExpression {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            left: FromESTree::from_estree_field(node, "left", d)?,
+            operator: FromESTree::from_estree_field(node, "operator", d)?,
+            right: FromESTree::from_estree_field(node, "right", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for LogicalOperator {
+    fn from_estree(value: &Value, _d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        match value.as_str() {
+            Some("||") => Ok(Self::Or),
+            Some("&&") => Ok(Self::And),
+            Some("??") => Ok(Self::Coalesce),
+            _ => Err(invalid_value(value, "LogicalOperator")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for NewExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("NewExpression")?;
+        Ok(NewExpression {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            callee: FromESTree::from_estree_field(node, "callee", d)?,
+            type_arguments: d.optional_field(node, "typeArguments")?,
+            arguments: FromESTree::from_estree_field(node, "arguments", d)?,
+            pure: Dummy::dummy(d.allocator()),
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for SequenceExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("SequenceExpression")?;
+        Ok(SequenceExpression {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            expressions: FromESTree::from_estree_field(node, "expressions", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TaggedTemplateExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TaggedTemplateExpression")?;
+        Ok(TaggedTemplateExpression {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            tag: FromESTree::from_estree_field(node, "tag", d)?,
+            type_arguments: d.optional_field(node, "typeArguments")?,
+            quasi: FromESTree::from_estree_field(node, "quasi", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for UpdateExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("UpdateExpression")?;
+        Ok(UpdateExpression {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            operator: FromESTree::from_estree_field(node, "operator", d)?,
+            prefix: FromESTree::from_estree_field(node, "prefix", d)?,
+            argument: FromESTree::from_estree_field(node, "argument", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for UpdateOperator {
+    fn from_estree(value: &Value, _d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        match value.as_str() {
+            Some("++") => Ok(Self::Increment),
+            Some("--") => Ok(Self::Decrement),
+            _ => Err(invalid_value(value, "UpdateOperator")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for YieldExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("YieldExpression")?;
+        Ok(YieldExpression {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            delegate: FromESTree::from_estree_field(node, "delegate", d)?,
+            argument: FromESTree::from_estree_field(node, "argument", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for PrivateInExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("BinaryExpression")?;
+        Ok(PrivateInExpression {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            left: FromESTree::from_estree_field(node, "left", d)?,
+            right: FromESTree::from_estree_field(node, "right", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for JSXOpeningElement<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("JSXOpeningElement")?;
+        Ok(JSXOpeningElement {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            name: FromESTree::from_estree_field(node, "name", d)?,
+            type_arguments: d.optional_field(node, "typeArguments")?,
+            attributes: FromESTree::from_estree_field(node, "attributes", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for JSXIdentifier<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("JSXIdentifier")?;
+        Ok(JSXIdentifier {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            name: FromESTree::from_estree_field(node, "name", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for JSXNamespacedName<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("JSXNamespacedName")?;
+        Ok(JSXNamespacedName {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            namespace: FromESTree::from_estree_field(node, "namespace", d)?,
+            name: FromESTree::from_estree_field(node, "name", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for JSXMemberExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("JSXMemberExpression")?;
+        Ok(JSXMemberExpression {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            object: FromESTree::from_estree_field(node, "object", d)?,
+            property: FromESTree::from_estree_field(node, "property", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for JSXAttributeItem<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "JSXAttribute" => Ok(Self::Attribute(FromESTree::from_estree(value, d)?)),
+            "JSXSpreadAttribute" => Ok(Self::SpreadAttribute(FromESTree::from_estree(value, d)?)),
+            _ => Err(node.unexpected("JSXAttributeItem")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for JSXAttribute<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("JSXAttribute")?;
+        Ok(JSXAttribute {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            name: FromESTree::from_estree_field(node, "name", d)?,
+            value: FromESTree::from_estree_field(node, "value", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for JSXAttributeName<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "JSXIdentifier" => Ok(Self::Identifier(FromESTree::from_estree(value, d)?)),
+            "JSXNamespacedName" => Ok(Self::NamespacedName(FromESTree::from_estree(value, d)?)),
+            _ => Err(node.unexpected("JSXAttributeName")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for JSXAttributeValue<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "Literal" => Ok(Self::StringLiteral(FromESTree::from_estree(value, d)?)),
+            "JSXExpressionContainer" => {
+                Ok(Self::ExpressionContainer(FromESTree::from_estree(value, d)?))
+            }
+            "JSXElement" => Ok(Self::Element(FromESTree::from_estree(value, d)?)),
+            "JSXFragment" => Ok(Self::Fragment(FromESTree::from_estree(value, d)?)),
+            _ => Err(node.unexpected("JSXAttributeValue")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for JSXExpressionContainer<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("JSXExpressionContainer")?;
+        Ok(JSXExpressionContainer {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            expression: FromESTree::from_estree_field(node, "expression", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for JSXExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "JSXEmptyExpression" => Ok(Self::EmptyExpression(FromESTree::from_estree(value, d)?)),
+            "Literal"
+            | "TemplateLiteral"
+            | "Identifier"
+            | "Super"
+            | "ArrayExpression"
+            | "ArrowFunctionExpression"
+            | "AssignmentExpression"
+            | "AwaitExpression"
+            | "BinaryExpression"
+            | "CallExpression"
+            | "ChainExpression"
+            | "ClassDeclaration"
+            | "ClassExpression"
+            | "ConditionalExpression"
+            | "FunctionDeclaration"
+            | "FunctionExpression"
+            | "TSDeclareFunction"
+            | "TSEmptyBodyFunctionExpression"
+            | "ImportExpression"
+            | "LogicalExpression"
+            | "NewExpression"
+            | "ObjectExpression"
+            | "ParenthesizedExpression"
+            | "SequenceExpression"
+            | "TaggedTemplateExpression"
+            | "ThisExpression"
+            | "UnaryExpression"
+            | "UpdateExpression"
+            | "YieldExpression"
+            | "MetaProperty"
+            | "JSXElement"
+            | "JSXFragment"
+            | "TSAsExpression"
+            | "TSSatisfiesExpression"
+            | "TSTypeAssertion"
+            | "TSNonNullExpression"
+            | "TSInstantiationExpression"
+            | "V8IntrinsicExpression"
+            | "MemberExpression" => Ok(Self::from(Expression::from_estree(value, d)?)),
+            _ => Err(node.unexpected("JSXExpression")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for JSXEmptyExpression {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("JSXEmptyExpression")?;
+        Ok(JSXEmptyExpression { node_id: Dummy::dummy(d.allocator()), span: d.span(node) })
+    }
+}
+
+impl<'a> FromESTree<'a> for JSXFragment<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("JSXFragment")?;
+        Ok(JSXFragment {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            opening_fragment: FromESTree::from_estree_field(node, "openingFragment", d)?,
+            children: FromESTree::from_estree_field(node, "children", d)?,
+            closing_fragment: FromESTree::from_estree_field(node, "closingFragment", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for JSXOpeningFragment {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("JSXOpeningFragment")?;
+        Ok(JSXOpeningFragment { node_id: Dummy::dummy(d.allocator()), span: d.span(node) })
+    }
+}
+
+impl<'a> FromESTree<'a> for JSXChild<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "JSXText" => Ok(Self::Text(FromESTree::from_estree(value, d)?)),
+            "JSXElement" => Ok(Self::Element(FromESTree::from_estree(value, d)?)),
+            "JSXFragment" => Ok(Self::Fragment(FromESTree::from_estree(value, d)?)),
+            "JSXExpressionContainer" => {
+                Ok(Self::ExpressionContainer(FromESTree::from_estree(value, d)?))
+            }
+            "JSXSpreadChild" => Ok(Self::Spread(FromESTree::from_estree(value, d)?)),
+            _ => Err(node.unexpected("JSXChild")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for JSXText<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("JSXText")?;
+        Ok(JSXText {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            value: FromESTree::from_estree_field(node, "value", d)?,
+            raw: FromESTree::from_estree_field(node, "raw", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for JSXSpreadChild<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("JSXSpreadChild")?;
+        Ok(JSXSpreadChild {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            expression: FromESTree::from_estree_field(node, "expression", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for JSXClosingFragment {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("JSXClosingFragment")?;
+        Ok(JSXClosingFragment { node_id: Dummy::dummy(d.allocator()), span: d.span(node) })
+    }
+}
+
+impl<'a> FromESTree<'a> for JSXSpreadAttribute<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("JSXSpreadAttribute")?;
+        Ok(JSXSpreadAttribute {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            argument: FromESTree::from_estree_field(node, "argument", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for JSXClosingElement<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("JSXClosingElement")?;
+        Ok(JSXClosingElement {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            name: FromESTree::from_estree_field(node, "name", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSInstantiationExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSInstantiationExpression")?;
+        Ok(TSInstantiationExpression {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            expression: FromESTree::from_estree_field(node, "expression", d)?,
+            type_arguments: FromESTree::from_estree_field(node, "typeArguments", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for V8IntrinsicExpression<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("V8IntrinsicExpression")?;
+        Ok(V8IntrinsicExpression {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            name: FromESTree::from_estree_field(node, "name", d)?,
+            arguments: FromESTree::from_estree_field(node, "arguments", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for ArrayPattern<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("ArrayPattern")?;
+        let (elements, rest) = d.appended(node, "elements")?;
+        Ok(ArrayPattern {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            elements,
+            rest,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for AssignmentPattern<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("AssignmentPattern")?;
+        Ok(AssignmentPattern {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            left: FromESTree::from_estree_field(node, "left", d)?,
+            right: FromESTree::from_estree_field(node, "right", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSTypeAliasDeclaration<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSTypeAliasDeclaration")?;
+        Ok(TSTypeAliasDeclaration {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            id: FromESTree::from_estree_field(node, "id", d)?,
+            type_parameters: FromESTree::from_estree_field(node, "typeParameters", d)?,
+            type_annotation: FromESTree::from_estree_field(node, "typeAnnotation", d)?,
+            declare: FromESTree::from_estree_field(node, "declare", d)?,
+            scope_id: Dummy::dummy(d.allocator()),
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSInterfaceDeclaration<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSInterfaceDeclaration")?;
+        Ok(TSInterfaceDeclaration {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            id: FromESTree::from_estree_field(node, "id", d)?,
+            type_parameters: FromESTree::from_estree_field(node, "typeParameters", d)?,
+            extends: FromESTree::from_estree_field(node, "extends", d)?,
+            body: FromESTree::from_estree_field(node, "body", d)?,
+            declare: FromESTree::from_estree_field(node, "declare", d)?,
+            scope_id: Dummy::dummy(d.allocator()),
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSInterfaceBody<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSInterfaceBody")?;
+        Ok(TSInterfaceBody {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            body: FromESTree::from_estree_field(node, "body", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSEnumDeclaration<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSEnumDeclaration")?;
+        Ok(TSEnumDeclaration {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            id: FromESTree::from_estree_field(node, "id", d)?,
+            body: FromESTree::from_estree_field(node, "body", d)?,
+            r#const: FromESTree::from_estree_field(node, "const", d)?,
+            declare: FromESTree::from_estree_field(node, "declare", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSEnumBody<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSEnumBody")?;
+        Ok(TSEnumBody {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            members: FromESTree::from_estree_field(node, "members", d)?,
+            scope_id: Dummy::dummy(d.allocator()),
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSEnumMemberName<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "Identifier" => Ok(Self::Identifier(FromESTree::from_estree(value, d)?)),
+            "Literal" => d.first_ok(
+                value,
+                &[
+                    |value, d| Ok(Self::String(FromESTree::from_estree(value, d)?)),
+                    |value, d| Ok(Self::ComputedString(FromESTree::from_estree(value, d)?)),
+                ],
+            ),
+            "TemplateLiteral" => {
+                Ok(Self::ComputedTemplateString(FromESTree::from_estree(value, d)?))
+            }
+            _ => Err(node.unexpected("TSEnumMemberName")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for TSModuleBlock<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSModuleBlock")?;
+        let (directives, body) = d.prepended(node, "body")?;
+        Ok(TSModuleBlock {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            directives,
+            body,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSNamespaceDeclarationBody<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "TSModuleDeclaration" => {
+                Ok(Self::TSNamespaceDeclaration(FromESTree::from_estree(value, d)?))
+            }
+            "TSModuleBlock" => Ok(Self::TSModuleBlock(FromESTree::from_estree(value, d)?)),
+            _ => Err(node.unexpected("TSNamespaceDeclarationBody")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for TSNamespaceDeclarationKind {
+    fn from_estree(value: &Value, _d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        match value.as_str() {
+            Some("module") => Ok(Self::Module),
+            Some("namespace") => Ok(Self::Namespace),
+            _ => Err(invalid_value(value, "TSNamespaceDeclarationKind")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for TSImportEqualsDeclaration<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSImportEqualsDeclaration")?;
+        Ok(TSImportEqualsDeclaration {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            id: FromESTree::from_estree_field(node, "id", d)?,
+            module_reference: FromESTree::from_estree_field(node, "moduleReference", d)?,
+            import_kind: FromESTree::from_estree_field(node, "importKind", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSModuleReference<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "TSExternalModuleReference" => {
+                Ok(Self::ExternalModuleReference(FromESTree::from_estree(value, d)?))
+            }
+            "Identifier" => Ok(Self::IdentifierReference(FromESTree::from_estree(value, d)?)),
+            "TSQualifiedName" => Ok(Self::QualifiedName(FromESTree::from_estree(value, d)?)),
+            _ => Err(node.unexpected("TSModuleReference")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for TSExternalModuleReference<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSExternalModuleReference")?;
+        Ok(TSExternalModuleReference {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            expression: FromESTree::from_estree_field(node, "expression", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for ImportOrExportKind {
+    fn from_estree(value: &Value, _d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        match value.as_str() {
+            Some("value") => Ok(Self::Value),
+            Some("type") => Ok(Self::Type),
+            _ => Err(invalid_value(value, "ImportOrExportKind")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for ModuleDeclaration<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "ImportDeclaration" => Ok(Self::ImportDeclaration(FromESTree::from_estree(value, d)?)),
+            "ExportAllDeclaration" => {
+                Ok(Self::ExportAllDeclaration(FromESTree::from_estree(value, d)?))
+            }
+            "ExportDefaultDeclaration" => {
+                Ok(Self::ExportDefaultDeclaration(FromESTree::from_estree(value, d)?))
+            }
+            "ExportNamedDeclaration" => d.first_ok(
+                value,
+                &[
+                    |value, d| Ok(Self::ExportDeclaration(FromESTree::from_estree(value, d)?)),
+                    |value, d| Ok(Self::ExportNamedDeclaration(FromESTree::from_estree(value, d)?)),
+                    |value, d| Ok(Self::ExportFromDeclaration(FromESTree::from_estree(value, d)?)),
+                ],
+            ),
+            "TSExportAssignment" => {
+                Ok(Self::TSExportAssignment(FromESTree::from_estree(value, d)?))
+            }
+            "TSNamespaceExportDeclaration" => {
+                Ok(Self::TSNamespaceExportDeclaration(FromESTree::from_estree(value, d)?))
+            }
+            _ => Err(node.unexpected("ModuleDeclaration")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for ImportDeclarationSpecifier<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "ImportSpecifier" => Ok(Self::ImportSpecifier(FromESTree::from_estree(value, d)?)),
+            "ImportDefaultSpecifier" => {
+                Ok(Self::ImportDefaultSpecifier(FromESTree::from_estree(value, d)?))
+            }
+            "ImportNamespaceSpecifier" => {
+                Ok(Self::ImportNamespaceSpecifier(FromESTree::from_estree(value, d)?))
+            }
+            _ => Err(node.unexpected("ImportDeclarationSpecifier")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for ImportSpecifier<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("ImportSpecifier")?;
+        Ok(ImportSpecifier {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            imported: FromESTree::from_estree_field(node, "imported", d)?,
+            local: FromESTree::from_estree_field(node, "local", d)?,
+            import_kind: d.optional_field(node, "importKind")?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for ModuleExportName<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "Identifier" => d.first_ok(
+                value,
+                &[
+                    |value, d| Ok(Self::IdentifierName(FromESTree::from_estree(value, d)?)),
+                    |value, d| Ok(Self::IdentifierReference(FromESTree::from_estree(value, d)?)),
+                ],
+            ),
+            "Literal" => Ok(Self::StringLiteral(FromESTree::from_estree(value, d)?)),
+            _ => Err(node.unexpected("ModuleExportName")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for ImportDefaultSpecifier<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("ImportDefaultSpecifier")?;
+        Ok(ImportDefaultSpecifier {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            local: FromESTree::from_estree_field(node, "local", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for ImportNamespaceSpecifier<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("ImportNamespaceSpecifier")?;
+        Ok(ImportNamespaceSpecifier {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            local: FromESTree::from_estree_field(node, "local", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for WithClause<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        Ok(WithClause {
+            node_id: Dummy::dummy(d.allocator()),
+            span: Dummy::dummy(d.allocator()),
+            keyword: Dummy::dummy(d.allocator()),
+            with_entries: FromESTree::from_estree_field(node, "attributes", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for ImportAttribute<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("ImportAttribute")?;
+        Ok(ImportAttribute {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            key: FromESTree::from_estree_field(node, "key", d)?,
+            value: FromESTree::from_estree_field(node, "value", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for ImportAttributeKey<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "Identifier" => Ok(Self::Identifier(FromESTree::from_estree(value, d)?)),
+            "Literal" => Ok(Self::StringLiteral(FromESTree::from_estree(value, d)?)),
+            _ => Err(node.unexpected("ImportAttributeKey")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for ExportDefaultDeclaration<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("ExportDefaultDeclaration")?;
+        Ok(ExportDefaultDeclaration {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            declaration: FromESTree::from_estree_field(node, "declaration", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for ExportDefaultDeclarationKind<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "FunctionDeclaration"
+            | "FunctionExpression"
+            | "TSDeclareFunction"
+            | "TSEmptyBodyFunctionExpression" => d.first_ok(
+                value,
+                &[
+                    |value, d| Ok(Self::FunctionDeclaration(FromESTree::from_estree(value, d)?)),
+                    |value, d| Ok(Self::from(Expression::from_estree(value, d)?)),
+                ],
+            ),
+            "ClassDeclaration" | "ClassExpression" => d.first_ok(
+                value,
+                &[
+                    |value, d| Ok(Self::ClassDeclaration(FromESTree::from_estree(value, d)?)),
+                    |value, d| Ok(Self::from(Expression::from_estree(value, d)?)),
+                ],
+            ),
+            "TSInterfaceDeclaration" => {
+                Ok(Self::TSInterfaceDeclaration(FromESTree::from_estree(value, d)?))
+            }
+            "Literal"
+            | "TemplateLiteral"
+            | "Identifier"
+            | "Super"
+            | "ArrayExpression"
+            | "ArrowFunctionExpression"
+            | "AssignmentExpression"
+            | "AwaitExpression"
+            | "BinaryExpression"
+            | "CallExpression"
+            | "ChainExpression"
+            | "ConditionalExpression"
+            | "ImportExpression"
+            | "LogicalExpression"
+            | "NewExpression"
+            | "ObjectExpression"
+            | "ParenthesizedExpression"
+            | "SequenceExpression"
+            | "TaggedTemplateExpression"
+            | "ThisExpression"
+            | "UnaryExpression"
+            | "UpdateExpression"
+            | "YieldExpression"
+            | "MetaProperty"
+            | "JSXElement"
+            | "JSXFragment"
+            | "TSAsExpression"
+            | "TSSatisfiesExpression"
+            | "TSTypeAssertion"
+            | "TSNonNullExpression"
+            | "TSInstantiationExpression"
+            | "V8IntrinsicExpression"
+            | "MemberExpression" => Ok(Self::from(Expression::from_estree(value, d)?)),
+            _ => Err(node.unexpected("ExportDefaultDeclarationKind")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for ExportSpecifier<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("ExportSpecifier")?;
+        Ok(ExportSpecifier {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            local: FromESTree::from_estree_field(node, "local", d)?,
+            exported: FromESTree::from_estree_field(node, "exported", d)?,
+            export_kind: d.optional_field(node, "exportKind")?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSExportAssignment<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSExportAssignment")?;
+        Ok(TSExportAssignment {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            expression: FromESTree::from_estree_field(node, "expression", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TSNamespaceExportDeclaration<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TSNamespaceExportDeclaration")?;
+        Ok(TSNamespaceExportDeclaration {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            id: FromESTree::from_estree_field(node, "id", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for BlockStatement<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("BlockStatement")?;
+        Ok(BlockStatement {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            body: FromESTree::from_estree_field(node, "body", d)?,
+            scope_id: Dummy::dummy(d.allocator()),
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for BreakStatement<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("BreakStatement")?;
+        Ok(BreakStatement {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            label: FromESTree::from_estree_field(node, "label", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for LabelIdentifier<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("Identifier")?;
+        Ok(LabelIdentifier {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            name: FromESTree::from_estree_field(node, "name", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for ContinueStatement<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("ContinueStatement")?;
+        Ok(ContinueStatement {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            label: FromESTree::from_estree_field(node, "label", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for DebuggerStatement {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("DebuggerStatement")?;
+        Ok(DebuggerStatement { node_id: Dummy::dummy(d.allocator()), span: d.span(node) })
+    }
+}
+
+impl<'a> FromESTree<'a> for DoWhileStatement<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("DoWhileStatement")?;
+        Ok(DoWhileStatement {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            body: FromESTree::from_estree_field(node, "body", d)?,
+            test: FromESTree::from_estree_field(node, "test", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for EmptyStatement {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("EmptyStatement")?;
+        Ok(EmptyStatement { node_id: Dummy::dummy(d.allocator()), span: d.span(node) })
+    }
+}
+
+impl<'a> FromESTree<'a> for ExpressionStatement<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("ExpressionStatement")?;
+        Ok(ExpressionStatement {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            expression: FromESTree::from_estree_field(node, "expression", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for ForInStatement<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("ForInStatement")?;
+        Ok(ForInStatement {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            left: FromESTree::from_estree_field(node, "left", d)?,
+            right: FromESTree::from_estree_field(node, "right", d)?,
+            body: FromESTree::from_estree_field(node, "body", d)?,
+            scope_id: Dummy::dummy(d.allocator()),
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for ForStatementLeft<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "VariableDeclaration" => {
+                Ok(Self::VariableDeclaration(FromESTree::from_estree(value, d)?))
+            }
+            "Identifier"
+            | "TSAsExpression"
+            | "TSSatisfiesExpression"
+            | "TSNonNullExpression"
+            | "TSTypeAssertion"
+            | "MemberExpression"
+            | "ArrayPattern"
+            | "ObjectPattern" => Ok(Self::from(AssignmentTarget::from_estree(value, d)?)),
+            _ => Err(node.unexpected("ForStatementLeft")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for ForOfStatement<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("ForOfStatement")?;
+        Ok(ForOfStatement {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            r#await: FromESTree::from_estree_field(node, "await", d)?,
+            left: FromESTree::from_estree_field(node, "left", d)?,
+            right: FromESTree::from_estree_field(node, "right", d)?,
+            body: FromESTree::from_estree_field(node, "body", d)?,
+            scope_id: Dummy::dummy(d.allocator()),
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for ForStatement<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("ForStatement")?;
+        Ok(ForStatement {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            init: FromESTree::from_estree_field(node, "init", d)?,
+            test: FromESTree::from_estree_field(node, "test", d)?,
+            update: FromESTree::from_estree_field(node, "update", d)?,
+            body: FromESTree::from_estree_field(node, "body", d)?,
+            scope_id: Dummy::dummy(d.allocator()),
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for ForStatementInit<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::new(value)?;
+        match node.r#type {
+            "VariableDeclaration" => {
+                Ok(Self::VariableDeclaration(FromESTree::from_estree(value, d)?))
+            }
+            "Literal"
+            | "TemplateLiteral"
+            | "Identifier"
+            | "Super"
+            | "ArrayExpression"
+            | "ArrowFunctionExpression"
+            | "AssignmentExpression"
+            | "AwaitExpression"
+            | "BinaryExpression"
+            | "CallExpression"
+            | "ChainExpression"
+            | "ClassDeclaration"
+            | "ClassExpression"
+            | "ConditionalExpression"
+            | "FunctionDeclaration"
+            | "FunctionExpression"
+            | "TSDeclareFunction"
+            | "TSEmptyBodyFunctionExpression"
+            | "ImportExpression"
+            | "LogicalExpression"
+            | "NewExpression"
+            | "ObjectExpression"
+            | "ParenthesizedExpression"
+            | "SequenceExpression"
+            | "TaggedTemplateExpression"
+            | "ThisExpression"
+            | "UnaryExpression"
+            | "UpdateExpression"
+            | "YieldExpression"
+            | "MetaProperty"
+            | "JSXElement"
+            | "JSXFragment"
+            | "TSAsExpression"
+            | "TSSatisfiesExpression"
+            | "TSTypeAssertion"
+            | "TSNonNullExpression"
+            | "TSInstantiationExpression"
+            | "V8IntrinsicExpression"
+            | "MemberExpression" => Ok(Self::from(Expression::from_estree(value, d)?)),
+            _ => Err(node.unexpected("ForStatementInit")),
+        }
+    }
+}
+
+impl<'a> FromESTree<'a> for IfStatement<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("IfStatement")?;
+        Ok(IfStatement {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            test: FromESTree::from_estree_field(node, "test", d)?,
+            consequent: FromESTree::from_estree_field(node, "consequent", d)?,
+            alternate: FromESTree::from_estree_field(node, "alternate", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for LabeledStatement<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("LabeledStatement")?;
+        Ok(LabeledStatement {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            label: FromESTree::from_estree_field(node, "label", d)?,
+            body: FromESTree::from_estree_field(node, "body", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for ReturnStatement<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("ReturnStatement")?;
+        Ok(ReturnStatement {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            argument: FromESTree::from_estree_field(node, "argument", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for SwitchStatement<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("SwitchStatement")?;
+        Ok(SwitchStatement {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            discriminant: FromESTree::from_estree_field(node, "discriminant", d)?,
+            cases: FromESTree::from_estree_field(node, "cases", d)?,
+            scope_id: Dummy::dummy(d.allocator()),
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for SwitchCase<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("SwitchCase")?;
+        Ok(SwitchCase {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            test: FromESTree::from_estree_field(node, "test", d)?,
+            consequent: FromESTree::from_estree_field(node, "consequent", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for ThrowStatement<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("ThrowStatement")?;
+        Ok(ThrowStatement {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            argument: FromESTree::from_estree_field(node, "argument", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for TryStatement<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("TryStatement")?;
+        Ok(TryStatement {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            block: FromESTree::from_estree_field(node, "block", d)?,
+            handler: FromESTree::from_estree_field(node, "handler", d)?,
+            finalizer: FromESTree::from_estree_field(node, "finalizer", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for CatchClause<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("CatchClause")?;
+        Ok(CatchClause {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            param: FromESTree::from_estree_field(node, "param", d)?,
+            body: FromESTree::from_estree_field(node, "body", d)?,
+            scope_id: Dummy::dummy(d.allocator()),
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for WhileStatement<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("WhileStatement")?;
+        Ok(WhileStatement {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            test: FromESTree::from_estree_field(node, "test", d)?,
+            body: FromESTree::from_estree_field(node, "body", d)?,
+        })
+    }
+}
+
+impl<'a> FromESTree<'a> for WithStatement<'a> {
+    fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+        let node = Node::object(value)?;
+        node.expect_type("WithStatement")?;
+        Ok(WithStatement {
+            node_id: Dummy::dummy(d.allocator()),
+            span: d.span(node),
+            object: FromESTree::from_estree_field(node, "object", d)?,
+            body: FromESTree::from_estree_field(node, "body", d)?,
+            scope_id: Dummy::dummy(d.allocator()),
+        })
+    }
+}
@@ -23,6 +23,7 @@
 //!
 //! ## Cargo Features
 //! * `"serialize"` enables support for serialization to ESTree JSON
+//! * `"deserialize"` enables support for deserialization from ESTree JSON
 //!
 //! [`BindingIdentifier`]: ast::BindingIdentifier
 //! [`IdentifierReference`]: ast::IdentifierReference
@@ -45,6 +46,8 @@ pub mod ast;
 mod ast_impl;
 mod ast_kind_impl;
 pub mod builder;
+#[cfg(feature = "deserialize")]
+mod deserialize;
 pub mod precedence;
 mod trivia;
 
@@ -62,6 +65,8 @@ mod generated {
     mod derive_dummy;
     #[cfg(feature = "serialize")]
     mod derive_estree;
+    #[cfg(feature = "deserialize")]
+    mod derive_from_estree;
     mod derive_get_address;
     mod derive_get_span;
     mod derive_get_span_mut;
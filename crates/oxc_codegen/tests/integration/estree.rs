@@ -0,0 +1,159 @@
+//! Round-trip through ESTree JSON: parse, serialize to JSON, deserialize, and print.
+//! The printed code must match printing the parsed AST directly.
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::Program;
+use oxc_ast_visit::utf8_to_utf16::Utf8ToUtf16;
+use oxc_codegen::Codegen;
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType};
+
+#[track_caller]
+fn test_round_trip(source_text: &str, source_type: SourceType) {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.diagnostics.is_empty(), "Parse errors: {:?}", ret.diagnostics);
+    let expected = Codegen::new().build(&ret.program).code;
+
+    let json = ret.program.to_estree_json(false, false);
+    let program = Program::from_estree_json(&json, source_text, source_type, &allocator)
+        .unwrap_or_else(|err| panic!("{err:?}\nfor source: {source_text:?}"));
+    let result = Codegen::new().build(&program).code;
+    assert_eq!(result, expected, "\nfor source: {source_text:?}");
+}
+
+#[test]
+fn statements() {
+    let cases = [
+        "#!/usr/bin/env node\n'use strict';\nfoo;",
+        "{ a; } ; debugger;",
+        "label: for (let i = 0, j; i < 10; i++) { if (i) break label; else continue; }",
+        "for (const x of y) {} for (x in y) {} for (;;) {} for (var [a, b] = c; ; ) {}",
+        "async function f() { for await (const x of y) {} }",
+        "while (a) b(); do { c(); } while (d);",
+        "switch (a) { case 1: b(); break; default: }",
+        "try { a(); } catch { b(); } try { a(); } catch ({ message }) {} finally { c(); }",
+        "throw new Error('x');",
+        "var a = 1, b; let { c, d: [e, ...f] = [], ...g } = h; const [, i = 2] = j;",
+        "({ a, b: c = 1, ...d } = e); [f, , g = 1, ...h.i] = j; k.l **= 2; m ??= n;",
+    ];
+    for source_text in cases {
+        test_round_trip(source_text, SourceType::cjs());
+    }
+    test_round_trip("with (a) { b; }", SourceType::script());
+}
+
+#[test]
+fn expressions() {
+    let cases = [
+        "[1, , ...a, 0x10, 1.5, 10n, /a[b]/giu, null, true, 'str', `a${b}c`];",
+        "({ a, b: 1, [c]: 2, ...d, get e() { return 1; }, set e(v) {}, f() {}, async *g() {}, 'h': 3 });",
+        "a?.b?.[c]?.(d); a.#b; #b in a; new A(...b); function f() { new.target; } a`x${y}`;",
+        "(a, b); !a; -a; typeof a; void 0; delete a.b; ++a; a--; a ? b : c;",
+        "a + b * c - (d - e); a && b || c; a ?? b; a instanceof B; a in b; a >>> b;",
+        "(a = 1, { b } = {}, ...c) => a; async (x) => { await x; }; () => ({});",
+        "function* f() { yield; yield* a; }",
+        "import('a', { with: { type: 'json' } }); import.meta.url;",
+        "(function named(a, b = 1, ...c) { 'use strict'; return a; });",
+    ];
+    for source_text in cases {
+        test_round_trip(source_text, SourceType::mjs());
+    }
+}
+
+#[test]
+fn classes() {
+    test_round_trip(
+        "class A extends B { static #a = 1; b; static { this.c = 1; } accessor d = 2; constructor() { super(); } get e() { return this.#a; } static async *[f]() {} }",
+        SourceType::mjs(),
+    );
+    test_round_trip("@dec class A { @dec() m() {} }", SourceType::mjs());
+}
+
+#[test]
+fn modules() {
+    let cases = [
+        "import a, { b, c as d, 'e' as f } from 'x'; import * as g from 'y'; import 'z';",
+        "import h from './h.json' with { type: 'json' };",
+        "export { a, b as c }; export const d = 1; export function e() {} export class F {}",
+        "export * from 'x'; export * as y from 'y'; export { z as 'w', default } from 'z';",
+        "export default function () {}",
+        "export default class {}",
+        "export default a + b;",
+    ];
+    for source_text in cases {
+        test_round_trip(source_text, SourceType::mjs());
+    }
+}
+
+#[test]
+fn jsx() {
+    let cases = [
+        "<div className='a' {...b} data-c={1}><A.B.C /><this.d />text {e} {}<>f</></div>;",
+        "<svg:circle xlink:href='#a' />; <my-element />; <Foo bar=<baz /> />;",
+    ];
+    for source_text in cases {
+        test_round_trip(source_text, SourceType::jsx());
+    }
+}
+
+#[test]
+fn typescript() {
+    let cases = [
+        "let a: string = 'x', b!: number; const { c }: { c: C } = d; let [e]: E[] = f;",
+        "type A<T extends object = {}> = T | null; type B = [a: string, b?: number, ...c: D[]];",
+        "type C = { readonly [P in keyof T]+?: T[P] }; type D = { -readonly [K in T as `a${K}`]-?: 1 };",
+        "type E = (this: Window, a?: string) => void; type F = new (...a: any[]) => G; type H = (I);",
+        "interface J<T> extends K.L<T>, M { (this: J, a: T): void; new (): J; m?(): void; [key: string]: any; readonly p: T; }",
+        "enum N { A, 'B' = 1, C = A | B } declare const enum O {}",
+        "namespace P.Q.R { export const a = 1; } declare module 'S' { export type T = 1; } declare global { var u: U; }",
+        "abstract class V<T> extends W<T> implements X.Y, Z { private readonly a?: T; constructor(public b: string, protected c = 1) { super(); } abstract m(this: V<T>): void; }",
+        "function f<T>(this: T, a: number, b?: string, ...c: T[]): asserts a is number {} declare function g(): void;",
+        "let h = <T,>(a: T): T => a; let i = j as K; let l = m satisfies N; let o = p!; let q = <R>s;",
+        "import type { A } from 'a'; import { type B, C } from 'b'; export type { D }; export type * from 'e'; import F = G.H;",
+        "try {} catch (e: unknown) {} for (const x of y as z[]) {}",
+    ];
+    for source_text in cases {
+        test_round_trip_ts(source_text, SourceType::ts());
+    }
+    test_round_trip_ts("<A<B> c={d as E} />;", SourceType::tsx());
+}
+
+#[track_caller]
+fn test_round_trip_ts(source_text: &str, source_type: SourceType) {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.diagnostics.is_empty(), "Parse errors: {:?}", ret.diagnostics);
+    let expected = Codegen::new().build(&ret.program).code;
+
+    let json = ret.program.to_estree_json(true, false);
+    let program = Program::from_estree_json(&json, source_text, source_type, &allocator)
+        .unwrap_or_else(|err| panic!("{err:?}\nfor source: {source_text:?}"));
+    let result = Codegen::new().build(&program).code;
+    assert_eq!(result, expected, "\nfor source: {source_text:?}");
+}
+
+/// JSON produced for JS consumers has UTF-16 offsets, which are converted back to UTF-8.
+#[test]
+fn utf16_offsets() {
+    let source_text = "const café = '😀'; let ü = 'ü'; let s = `${café}ß`; class Ω { #π = 1 }";
+    let allocator = Allocator::default();
+    let mut ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    let expected_spans = ret.program.body.iter().map(GetSpan::span).collect::<Vec<_>>();
+    let expected = Codegen::new().build(&ret.program).code;
+
+    let utf16 = Utf8ToUtf16::new(source_text);
+    utf16.convert_program(&mut ret.program);
+    let json = ret.program.to_estree_json(false, false);
+    let program = Program::from_estree_json_with_span_converter(
+        &json,
+        source_text,
+        SourceType::mjs(),
+        &allocator,
+        |span| utf16.convert_span_back(span),
+    )
+    .unwrap();
+    let spans = program.body.iter().map(GetSpan::span).collect::<Vec<_>>();
+    assert_eq!(spans, expected_spans);
+    assert_eq!(Codegen::new().build(&program).code, expected);
+}
@@ -0,0 +1,3530 @@
+// Auto-generated code, DO NOT EDIT DIRECTLY!
+// To edit this generated file you have to edit `tasks/ast_tools/src/generators/match_node.rs`.
+
+#![allow(
+    clippy::match_same_arms,
+    clippy::match_wildcard_for_single_variants,
+    clippy::too_many_lines
+)]
+
+use oxc_ast::ast::*;
+use oxc_span::{ContentEq, GetSpan, Span};
+
+use crate::matcher::{Identifier, MatchNode, MatchState};
+
+/// A node captured by a metavariable.
+#[derive(Clone, Copy)]
+pub enum Capture<'a> {
+    Expression(&'a Expression<'a>),
+    IdentifierName(&'a IdentifierName<'a>),
+    IdentifierReference(&'a IdentifierReference<'a>),
+    BindingIdentifier(&'a BindingIdentifier<'a>),
+    ArrayExpressionElement(&'a ArrayExpressionElement<'a>),
+    PropertyKey(&'a PropertyKey<'a>),
+    Argument(&'a Argument<'a>),
+    AssignmentTarget(&'a AssignmentTarget<'a>),
+    SimpleAssignmentTarget(&'a SimpleAssignmentTarget<'a>),
+    AssignmentTargetMaybeDefault(&'a AssignmentTargetMaybeDefault<'a>),
+    Statement(&'a Statement<'a>),
+    ForStatementInit(&'a ForStatementInit<'a>),
+    ForStatementLeft(&'a ForStatementLeft<'a>),
+    BindingPattern(&'a BindingPattern<'a>),
+    FormalParameter(&'a FormalParameter<'a>),
+    ArrowFunctionBody(&'a ArrowFunctionBody<'a>),
+    ImportAttributeKey(&'a ImportAttributeKey<'a>),
+    ExportDefaultDeclarationKind(&'a ExportDefaultDeclarationKind<'a>),
+    ModuleExportName(&'a ModuleExportName<'a>),
+    JSXElementName(&'a JSXElementName<'a>),
+    JSXMemberExpressionObject(&'a JSXMemberExpressionObject<'a>),
+    JSXExpression(&'a JSXExpression<'a>),
+    TSEnumMemberName(&'a TSEnumMemberName<'a>),
+    TSTypeName(&'a TSTypeName<'a>),
+    TSTypePredicateName(&'a TSTypePredicateName<'a>),
+    TSTypeQueryExprName(&'a TSTypeQueryExprName<'a>),
+    TSImportTypeQualifier(&'a TSImportTypeQualifier<'a>),
+    TSModuleReference(&'a TSModuleReference<'a>),
+}
+
+impl<'a> Capture<'a> {
+    /// The identifier which the captured node is, if any.
+    pub fn identifier(self) -> Option<Identifier<'a>> {
+        match self {
+            Self::Expression(it) => MatchNode::identifier(it),
+            Self::IdentifierName(it) => MatchNode::identifier(it),
+            Self::IdentifierReference(it) => MatchNode::identifier(it),
+            Self::BindingIdentifier(it) => MatchNode::identifier(it),
+            Self::ArrayExpressionElement(it) => MatchNode::identifier(it),
+            Self::PropertyKey(it) => MatchNode::identifier(it),
+            Self::Argument(it) => MatchNode::identifier(it),
+            Self::AssignmentTarget(it) => MatchNode::identifier(it),
+            Self::SimpleAssignmentTarget(it) => MatchNode::identifier(it),
+            Self::AssignmentTargetMaybeDefault(it) => MatchNode::identifier(it),
+            Self::Statement(it) => MatchNode::identifier(it),
+            Self::ForStatementInit(it) => MatchNode::identifier(it),
+            Self::ForStatementLeft(it) => MatchNode::identifier(it),
+            Self::BindingPattern(it) => MatchNode::identifier(it),
+            Self::FormalParameter(it) => MatchNode::identifier(it),
+            Self::ArrowFunctionBody(it) => MatchNode::identifier(it),
+            Self::ImportAttributeKey(it) => MatchNode::identifier(it),
+            Self::ExportDefaultDeclarationKind(it) => MatchNode::identifier(it),
+            Self::ModuleExportName(it) => MatchNode::identifier(it),
+            Self::JSXElementName(it) => MatchNode::identifier(it),
+            Self::JSXMemberExpressionObject(it) => MatchNode::identifier(it),
+            Self::JSXExpression(it) => MatchNode::identifier(it),
+            Self::TSEnumMemberName(it) => MatchNode::identifier(it),
+            Self::TSTypeName(it) => MatchNode::identifier(it),
+            Self::TSTypePredicateName(it) => MatchNode::identifier(it),
+            Self::TSTypeQueryExprName(it) => MatchNode::identifier(it),
+            Self::TSImportTypeQualifier(it) => MatchNode::identifier(it),
+            Self::TSModuleReference(it) => MatchNode::identifier(it),
+        }
+    }
+
+    /// Match `self` against `other`, if they are the same type of node.
+    pub fn match_same_type(self, other: Self, m: &mut MatchState<'a>) -> bool {
+        match (self, other) {
+            (Self::Expression(a), Self::Expression(b)) => MatchNode::match_node(a, b, m),
+            (Self::IdentifierName(a), Self::IdentifierName(b)) => MatchNode::match_node(a, b, m),
+            (Self::IdentifierReference(a), Self::IdentifierReference(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::BindingIdentifier(a), Self::BindingIdentifier(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ArrayExpressionElement(a), Self::ArrayExpressionElement(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::PropertyKey(a), Self::PropertyKey(b)) => MatchNode::match_node(a, b, m),
+            (Self::Argument(a), Self::Argument(b)) => MatchNode::match_node(a, b, m),
+            (Self::AssignmentTarget(a), Self::AssignmentTarget(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::SimpleAssignmentTarget(a), Self::SimpleAssignmentTarget(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::AssignmentTargetMaybeDefault(a), Self::AssignmentTargetMaybeDefault(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::Statement(a), Self::Statement(b)) => MatchNode::match_node(a, b, m),
+            (Self::ForStatementInit(a), Self::ForStatementInit(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ForStatementLeft(a), Self::ForStatementLeft(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::BindingPattern(a), Self::BindingPattern(b)) => MatchNode::match_node(a, b, m),
+            (Self::FormalParameter(a), Self::FormalParameter(b)) => MatchNode::match_node(a, b, m),
+            (Self::ArrowFunctionBody(a), Self::ArrowFunctionBody(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ImportAttributeKey(a), Self::ImportAttributeKey(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ExportDefaultDeclarationKind(a), Self::ExportDefaultDeclarationKind(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ModuleExportName(a), Self::ModuleExportName(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::JSXElementName(a), Self::JSXElementName(b)) => MatchNode::match_node(a, b, m),
+            (Self::JSXMemberExpressionObject(a), Self::JSXMemberExpressionObject(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::JSXExpression(a), Self::JSXExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSEnumMemberName(a), Self::TSEnumMemberName(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSTypeName(a), Self::TSTypeName(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSTypePredicateName(a), Self::TSTypePredicateName(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSTypeQueryExprName(a), Self::TSTypeQueryExprName(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSImportTypeQualifier(a), Self::TSImportTypeQualifier(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSModuleReference(a), Self::TSModuleReference(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl GetSpan for Capture<'_> {
+    fn span(&self) -> Span {
+        match self {
+            Self::Expression(it) => GetSpan::span(*it),
+            Self::IdentifierName(it) => GetSpan::span(*it),
+            Self::IdentifierReference(it) => GetSpan::span(*it),
+            Self::BindingIdentifier(it) => GetSpan::span(*it),
+            Self::ArrayExpressionElement(it) => GetSpan::span(*it),
+            Self::PropertyKey(it) => GetSpan::span(*it),
+            Self::Argument(it) => GetSpan::span(*it),
+            Self::AssignmentTarget(it) => GetSpan::span(*it),
+            Self::SimpleAssignmentTarget(it) => GetSpan::span(*it),
+            Self::AssignmentTargetMaybeDefault(it) => GetSpan::span(*it),
+            Self::Statement(it) => GetSpan::span(*it),
+            Self::ForStatementInit(it) => GetSpan::span(*it),
+            Self::ForStatementLeft(it) => GetSpan::span(*it),
+            Self::BindingPattern(it) => GetSpan::span(*it),
+            Self::FormalParameter(it) => GetSpan::span(*it),
+            Self::ArrowFunctionBody(it) => GetSpan::span(*it),
+            Self::ImportAttributeKey(it) => GetSpan::span(*it),
+            Self::ExportDefaultDeclarationKind(it) => GetSpan::span(*it),
+            Self::ModuleExportName(it) => GetSpan::span(*it),
+            Self::JSXElementName(it) => GetSpan::span(*it),
+            Self::JSXMemberExpressionObject(it) => GetSpan::span(*it),
+            Self::JSXExpression(it) => GetSpan::span(*it),
+            Self::TSEnumMemberName(it) => GetSpan::span(*it),
+            Self::TSTypeName(it) => GetSpan::span(*it),
+            Self::TSTypePredicateName(it) => GetSpan::span(*it),
+            Self::TSTypeQueryExprName(it) => GetSpan::span(*it),
+            Self::TSImportTypeQualifier(it) => GetSpan::span(*it),
+            Self::TSModuleReference(it) => GetSpan::span(*it),
+        }
+    }
+}
+
+impl<'a> MatchNode<'a> for Program<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.source_type, &target.source_type)
+            && MatchNode::match_node(&self.hashbang, &target.hashbang, m)
+            && MatchNode::match_node(&self.directives, &target.directives, m)
+            && MatchNode::match_node(&self.body, &target.body, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for Expression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        if let Some(result) = m.match_metavariable(self, target) {
+            return result;
+        }
+        match (self, target) {
+            (Self::BooleanLiteral(a), Self::BooleanLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::NullLiteral(a), Self::NullLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::NumericLiteral(a), Self::NumericLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::BigIntLiteral(a), Self::BigIntLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::RegExpLiteral(a), Self::RegExpLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::StringLiteral(a), Self::StringLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::TemplateLiteral(a), Self::TemplateLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::Identifier(a), Self::Identifier(b)) => MatchNode::match_node(a, b, m),
+            (Self::Super(a), Self::Super(b)) => MatchNode::match_node(a, b, m),
+            (Self::ArrayExpression(a), Self::ArrayExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ArrowFunctionExpression(a), Self::ArrowFunctionExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::AssignmentExpression(a), Self::AssignmentExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::AwaitExpression(a), Self::AwaitExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::BinaryExpression(a), Self::BinaryExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::CallExpression(a), Self::CallExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ChainExpression(a), Self::ChainExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ClassExpression(a), Self::ClassExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ConditionalExpression(a), Self::ConditionalExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::FunctionExpression(a), Self::FunctionExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ImportExpression(a), Self::ImportExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::LogicalExpression(a), Self::LogicalExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::NewExpression(a), Self::NewExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ObjectExpression(a), Self::ObjectExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ParenthesizedExpression(a), Self::ParenthesizedExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::SequenceExpression(a), Self::SequenceExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TaggedTemplateExpression(a), Self::TaggedTemplateExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ThisExpression(a), Self::ThisExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::UnaryExpression(a), Self::UnaryExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::UpdateExpression(a), Self::UpdateExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::YieldExpression(a), Self::YieldExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::PrivateInExpression(a), Self::PrivateInExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ImportMeta(a), Self::ImportMeta(b)) => MatchNode::match_node(a, b, m),
+            (Self::NewTarget(a), Self::NewTarget(b)) => MatchNode::match_node(a, b, m),
+            (Self::JSXElement(a), Self::JSXElement(b)) => MatchNode::match_node(a, b, m),
+            (Self::JSXFragment(a), Self::JSXFragment(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSAsExpression(a), Self::TSAsExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSSatisfiesExpression(a), Self::TSSatisfiesExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSTypeAssertion(a), Self::TSTypeAssertion(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSNonNullExpression(a), Self::TSNonNullExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSInstantiationExpression(a), Self::TSInstantiationExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::StaticMemberExpression(a), Self::StaticMemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::PrivateFieldExpression(a), Self::PrivateFieldExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            _ => false,
+        }
+    }
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        match self {
+            Self::Identifier(it) => MatchNode::identifier(it),
+            _ => None,
+        }
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        Some(Capture::Expression(self))
+    }
+}
+
+impl<'a> MatchNode<'a> for LabelIdentifier<'a> {
+    fn match_node(&'a self, target: &'a Self, _: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.name, &target.name)
+    }
+}
+
+impl<'a> MatchNode<'a> for ThisExpression {
+    fn match_node(&'a self, _: &'a Self, _: &mut MatchState<'a>) -> bool {
+        true
+    }
+}
+
+impl<'a> MatchNode<'a> for ArrayExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.elements, &target.elements, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ArrayExpressionElement<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        if let Some(result) = m.match_metavariable(self, target) {
+            return result;
+        }
+        match (self, target) {
+            (Self::SpreadElement(a), Self::SpreadElement(b)) => MatchNode::match_node(a, b, m),
+            (Self::Elision(a), Self::Elision(b)) => MatchNode::match_node(a, b, m),
+            (Self::BooleanLiteral(a), Self::BooleanLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::NullLiteral(a), Self::NullLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::NumericLiteral(a), Self::NumericLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::BigIntLiteral(a), Self::BigIntLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::RegExpLiteral(a), Self::RegExpLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::StringLiteral(a), Self::StringLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::TemplateLiteral(a), Self::TemplateLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::Identifier(a), Self::Identifier(b)) => MatchNode::match_node(a, b, m),
+            (Self::Super(a), Self::Super(b)) => MatchNode::match_node(a, b, m),
+            (Self::ArrayExpression(a), Self::ArrayExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ArrowFunctionExpression(a), Self::ArrowFunctionExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::AssignmentExpression(a), Self::AssignmentExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::AwaitExpression(a), Self::AwaitExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::BinaryExpression(a), Self::BinaryExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::CallExpression(a), Self::CallExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ChainExpression(a), Self::ChainExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ClassExpression(a), Self::ClassExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ConditionalExpression(a), Self::ConditionalExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::FunctionExpression(a), Self::FunctionExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ImportExpression(a), Self::ImportExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::LogicalExpression(a), Self::LogicalExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::NewExpression(a), Self::NewExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ObjectExpression(a), Self::ObjectExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ParenthesizedExpression(a), Self::ParenthesizedExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::SequenceExpression(a), Self::SequenceExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TaggedTemplateExpression(a), Self::TaggedTemplateExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ThisExpression(a), Self::ThisExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::UnaryExpression(a), Self::UnaryExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::UpdateExpression(a), Self::UpdateExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::YieldExpression(a), Self::YieldExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::PrivateInExpression(a), Self::PrivateInExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ImportMeta(a), Self::ImportMeta(b)) => MatchNode::match_node(a, b, m),
+            (Self::NewTarget(a), Self::NewTarget(b)) => MatchNode::match_node(a, b, m),
+            (Self::JSXElement(a), Self::JSXElement(b)) => MatchNode::match_node(a, b, m),
+            (Self::JSXFragment(a), Self::JSXFragment(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSAsExpression(a), Self::TSAsExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSSatisfiesExpression(a), Self::TSSatisfiesExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSTypeAssertion(a), Self::TSTypeAssertion(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSNonNullExpression(a), Self::TSNonNullExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSInstantiationExpression(a), Self::TSInstantiationExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::StaticMemberExpression(a), Self::StaticMemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::PrivateFieldExpression(a), Self::PrivateFieldExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            _ => false,
+        }
+    }
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        match self {
+            Self::Identifier(it) => MatchNode::identifier(it),
+            _ => None,
+        }
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        if let Some(expression) = self.as_expression() {
+            Some(Capture::Expression(expression))
+        } else {
+            Some(Capture::ArrayExpressionElement(self))
+        }
+    }
+}
+
+impl<'a> MatchNode<'a> for Elision {
+    fn match_node(&'a self, _: &'a Self, _: &mut MatchState<'a>) -> bool {
+        true
+    }
+}
+
+impl<'a> MatchNode<'a> for ObjectExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.properties, &target.properties, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ObjectPropertyKind<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        match (self, target) {
+            (Self::ObjectProperty(a), Self::ObjectProperty(b)) => MatchNode::match_node(a, b, m),
+            (Self::SpreadProperty(a), Self::SpreadProperty(b)) => MatchNode::match_node(a, b, m),
+            _ => false,
+        }
+    }
+}
+
+impl<'a> MatchNode<'a> for ObjectProperty<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.kind, &target.kind)
+            && ContentEq::content_eq(&self.method, &target.method)
+            && ContentEq::content_eq(&self.shorthand, &target.shorthand)
+            && ContentEq::content_eq(&self.computed, &target.computed)
+            && MatchNode::match_node(&self.key, &target.key, m)
+            && MatchNode::match_node(&self.value, &target.value, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for PropertyKey<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        if let Some(result) = m.match_metavariable(self, target) {
+            return result;
+        }
+        match (self, target) {
+            (Self::StaticIdentifier(a), Self::StaticIdentifier(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::PrivateIdentifier(a), Self::PrivateIdentifier(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::BooleanLiteral(a), Self::BooleanLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::NullLiteral(a), Self::NullLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::NumericLiteral(a), Self::NumericLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::BigIntLiteral(a), Self::BigIntLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::RegExpLiteral(a), Self::RegExpLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::StringLiteral(a), Self::StringLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::TemplateLiteral(a), Self::TemplateLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::Identifier(a), Self::Identifier(b)) => MatchNode::match_node(a, b, m),
+            (Self::Super(a), Self::Super(b)) => MatchNode::match_node(a, b, m),
+            (Self::ArrayExpression(a), Self::ArrayExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ArrowFunctionExpression(a), Self::ArrowFunctionExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::AssignmentExpression(a), Self::AssignmentExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::AwaitExpression(a), Self::AwaitExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::BinaryExpression(a), Self::BinaryExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::CallExpression(a), Self::CallExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ChainExpression(a), Self::ChainExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ClassExpression(a), Self::ClassExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ConditionalExpression(a), Self::ConditionalExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::FunctionExpression(a), Self::FunctionExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ImportExpression(a), Self::ImportExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::LogicalExpression(a), Self::LogicalExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::NewExpression(a), Self::NewExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ObjectExpression(a), Self::ObjectExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ParenthesizedExpression(a), Self::ParenthesizedExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::SequenceExpression(a), Self::SequenceExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TaggedTemplateExpression(a), Self::TaggedTemplateExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ThisExpression(a), Self::ThisExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::UnaryExpression(a), Self::UnaryExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::UpdateExpression(a), Self::UpdateExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::YieldExpression(a), Self::YieldExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::PrivateInExpression(a), Self::PrivateInExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ImportMeta(a), Self::ImportMeta(b)) => MatchNode::match_node(a, b, m),
+            (Self::NewTarget(a), Self::NewTarget(b)) => MatchNode::match_node(a, b, m),
+            (Self::JSXElement(a), Self::JSXElement(b)) => MatchNode::match_node(a, b, m),
+            (Self::JSXFragment(a), Self::JSXFragment(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSAsExpression(a), Self::TSAsExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSSatisfiesExpression(a), Self::TSSatisfiesExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSTypeAssertion(a), Self::TSTypeAssertion(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSNonNullExpression(a), Self::TSNonNullExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSInstantiationExpression(a), Self::TSInstantiationExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::StaticMemberExpression(a), Self::StaticMemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::PrivateFieldExpression(a), Self::PrivateFieldExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            _ => false,
+        }
+    }
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        match self {
+            Self::StaticIdentifier(it) => MatchNode::identifier(it),
+            Self::Identifier(it) => MatchNode::identifier(it),
+            _ => None,
+        }
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        if let Some(expression) = self.as_expression() {
+            Some(Capture::Expression(expression))
+        } else {
+            Some(Capture::PropertyKey(self))
+        }
+    }
+}
+
+impl<'a> MatchNode<'a> for TemplateLiteral<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.quasis, &target.quasis, m)
+            && MatchNode::match_node(&self.expressions, &target.expressions, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TaggedTemplateExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.tag, &target.tag, m)
+            && MatchNode::match_node(&self.type_arguments, &target.type_arguments, m)
+            && MatchNode::match_node(&self.quasi, &target.quasi, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TemplateElement<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.tail, &target.tail)
+            && ContentEq::content_eq(&self.lone_surrogates, &target.lone_surrogates)
+            && MatchNode::match_node(&self.value, &target.value, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TemplateElementValue<'a> {
+    fn match_node(&'a self, target: &'a Self, _: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.raw, &target.raw)
+            && ContentEq::content_eq(&self.cooked, &target.cooked)
+    }
+}
+
+impl<'a> MatchNode<'a> for MemberExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        match (self, target) {
+            (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::StaticMemberExpression(a), Self::StaticMemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::PrivateFieldExpression(a), Self::PrivateFieldExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<'a> MatchNode<'a> for ComputedMemberExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.optional, &target.optional)
+            && MatchNode::match_node(&self.object, &target.object, m)
+            && MatchNode::match_node(&self.expression, &target.expression, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for StaticMemberExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.optional, &target.optional)
+            && MatchNode::match_node(&self.object, &target.object, m)
+            && MatchNode::match_node(&self.property, &target.property, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for PrivateFieldExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.optional, &target.optional)
+            && MatchNode::match_node(&self.object, &target.object, m)
+            && MatchNode::match_node(&self.field, &target.field, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for CallExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.optional, &target.optional)
+            && ContentEq::content_eq(&self.pure, &target.pure)
+            && MatchNode::match_node(&self.callee, &target.callee, m)
+            && MatchNode::match_node(&self.type_arguments, &target.type_arguments, m)
+            && MatchNode::match_node(&self.arguments, &target.arguments, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for NewExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.pure, &target.pure)
+            && MatchNode::match_node(&self.callee, &target.callee, m)
+            && MatchNode::match_node(&self.type_arguments, &target.type_arguments, m)
+            && MatchNode::match_node(&self.arguments, &target.arguments, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ImportMeta {
+    fn match_node(&'a self, _: &'a Self, _: &mut MatchState<'a>) -> bool {
+        true
+    }
+}
+
+impl<'a> MatchNode<'a> for NewTarget {
+    fn match_node(&'a self, _: &'a Self, _: &mut MatchState<'a>) -> bool {
+        true
+    }
+}
+
+impl<'a> MatchNode<'a> for SpreadElement<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.argument, &target.argument, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for Argument<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        if let Some(result) = m.match_metavariable(self, target) {
+            return result;
+        }
+        match (self, target) {
+            (Self::SpreadElement(a), Self::SpreadElement(b)) => MatchNode::match_node(a, b, m),
+            (Self::BooleanLiteral(a), Self::BooleanLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::NullLiteral(a), Self::NullLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::NumericLiteral(a), Self::NumericLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::BigIntLiteral(a), Self::BigIntLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::RegExpLiteral(a), Self::RegExpLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::StringLiteral(a), Self::StringLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::TemplateLiteral(a), Self::TemplateLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::Identifier(a), Self::Identifier(b)) => MatchNode::match_node(a, b, m),
+            (Self::Super(a), Self::Super(b)) => MatchNode::match_node(a, b, m),
+            (Self::ArrayExpression(a), Self::ArrayExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ArrowFunctionExpression(a), Self::ArrowFunctionExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::AssignmentExpression(a), Self::AssignmentExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::AwaitExpression(a), Self::AwaitExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::BinaryExpression(a), Self::BinaryExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::CallExpression(a), Self::CallExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ChainExpression(a), Self::ChainExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ClassExpression(a), Self::ClassExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ConditionalExpression(a), Self::ConditionalExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::FunctionExpression(a), Self::FunctionExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ImportExpression(a), Self::ImportExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::LogicalExpression(a), Self::LogicalExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::NewExpression(a), Self::NewExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ObjectExpression(a), Self::ObjectExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ParenthesizedExpression(a), Self::ParenthesizedExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::SequenceExpression(a), Self::SequenceExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TaggedTemplateExpression(a), Self::TaggedTemplateExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ThisExpression(a), Self::ThisExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::UnaryExpression(a), Self::UnaryExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::UpdateExpression(a), Self::UpdateExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::YieldExpression(a), Self::YieldExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::PrivateInExpression(a), Self::PrivateInExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ImportMeta(a), Self::ImportMeta(b)) => MatchNode::match_node(a, b, m),
+            (Self::NewTarget(a), Self::NewTarget(b)) => MatchNode::match_node(a, b, m),
+            (Self::JSXElement(a), Self::JSXElement(b)) => MatchNode::match_node(a, b, m),
+            (Self::JSXFragment(a), Self::JSXFragment(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSAsExpression(a), Self::TSAsExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSSatisfiesExpression(a), Self::TSSatisfiesExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSTypeAssertion(a), Self::TSTypeAssertion(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSNonNullExpression(a), Self::TSNonNullExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSInstantiationExpression(a), Self::TSInstantiationExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::StaticMemberExpression(a), Self::StaticMemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::PrivateFieldExpression(a), Self::PrivateFieldExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            _ => false,
+        }
+    }
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        match self {
+            Self::Identifier(it) => MatchNode::identifier(it),
+            _ => None,
+        }
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        if let Some(expression) = self.as_expression() {
+            Some(Capture::Expression(expression))
+        } else {
+            Some(Capture::Argument(self))
+        }
+    }
+}
+
+impl<'a> MatchNode<'a> for UpdateExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.operator, &target.operator)
+            && ContentEq::content_eq(&self.prefix, &target.prefix)
+            && MatchNode::match_node(&self.argument, &target.argument, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for UnaryExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.operator, &target.operator)
+            && MatchNode::match_node(&self.argument, &target.argument, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for BinaryExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.operator, &target.operator)
+            && MatchNode::match_node(&self.left, &target.left, m)
+            && MatchNode::match_node(&self.right, &target.right, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for PrivateInExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.left, &target.left, m)
+            && MatchNode::match_node(&self.right, &target.right, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for LogicalExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.operator, &target.operator)
+            && MatchNode::match_node(&self.left, &target.left, m)
+            && MatchNode::match_node(&self.right, &target.right, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ConditionalExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.test, &target.test, m)
+            && MatchNode::match_node(&self.consequent, &target.consequent, m)
+            && MatchNode::match_node(&self.alternate, &target.alternate, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for AssignmentExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.operator, &target.operator)
+            && MatchNode::match_node(&self.left, &target.left, m)
+            && MatchNode::match_node(&self.right, &target.right, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for AssignmentTarget<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        if let Some(result) = m.match_metavariable(self, target) {
+            return result;
+        }
+        match (self, target) {
+            (Self::AssignmentTargetIdentifier(a), Self::AssignmentTargetIdentifier(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSAsExpression(a), Self::TSAsExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSSatisfiesExpression(a), Self::TSSatisfiesExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSNonNullExpression(a), Self::TSNonNullExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSTypeAssertion(a), Self::TSTypeAssertion(b)) => MatchNode::match_node(a, b, m),
+            (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::StaticMemberExpression(a), Self::StaticMemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::PrivateFieldExpression(a), Self::PrivateFieldExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ArrayAssignmentTarget(a), Self::ArrayAssignmentTarget(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ObjectAssignmentTarget(a), Self::ObjectAssignmentTarget(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            _ => false,
+        }
+    }
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        match self {
+            Self::AssignmentTargetIdentifier(it) => MatchNode::identifier(it),
+            _ => None,
+        }
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        Some(Capture::AssignmentTarget(self))
+    }
+}
+
+impl<'a> MatchNode<'a> for SimpleAssignmentTarget<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        if let Some(result) = m.match_metavariable(self, target) {
+            return result;
+        }
+        match (self, target) {
+            (Self::AssignmentTargetIdentifier(a), Self::AssignmentTargetIdentifier(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSAsExpression(a), Self::TSAsExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSSatisfiesExpression(a), Self::TSSatisfiesExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSNonNullExpression(a), Self::TSNonNullExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSTypeAssertion(a), Self::TSTypeAssertion(b)) => MatchNode::match_node(a, b, m),
+            (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::StaticMemberExpression(a), Self::StaticMemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::PrivateFieldExpression(a), Self::PrivateFieldExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            _ => false,
+        }
+    }
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        match self {
+            Self::AssignmentTargetIdentifier(it) => MatchNode::identifier(it),
+            _ => None,
+        }
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        Some(Capture::SimpleAssignmentTarget(self))
+    }
+}
+
+impl<'a> MatchNode<'a> for AssignmentTargetPattern<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        match (self, target) {
+            (Self::ArrayAssignmentTarget(a), Self::ArrayAssignmentTarget(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ObjectAssignmentTarget(a), Self::ObjectAssignmentTarget(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<'a> MatchNode<'a> for ArrayAssignmentTarget<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.elements, &target.elements, m)
+            && MatchNode::match_node(&self.rest, &target.rest, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ObjectAssignmentTarget<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.properties, &target.properties, m)
+            && MatchNode::match_node(&self.rest, &target.rest, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for AssignmentTargetRest<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.target, &target.target, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for AssignmentTargetMaybeDefault<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        if let Some(result) = m.match_metavariable(self, target) {
+            return result;
+        }
+        match (self, target) {
+            (Self::AssignmentTargetWithDefault(a), Self::AssignmentTargetWithDefault(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::AssignmentTargetIdentifier(a), Self::AssignmentTargetIdentifier(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSAsExpression(a), Self::TSAsExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSSatisfiesExpression(a), Self::TSSatisfiesExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSNonNullExpression(a), Self::TSNonNullExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSTypeAssertion(a), Self::TSTypeAssertion(b)) => MatchNode::match_node(a, b, m),
+            (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::StaticMemberExpression(a), Self::StaticMemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::PrivateFieldExpression(a), Self::PrivateFieldExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ArrayAssignmentTarget(a), Self::ArrayAssignmentTarget(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ObjectAssignmentTarget(a), Self::ObjectAssignmentTarget(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            _ => false,
+        }
+    }
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        match self {
+            Self::AssignmentTargetIdentifier(it) => MatchNode::identifier(it),
+            _ => None,
+        }
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        Some(Capture::AssignmentTargetMaybeDefault(self))
+    }
+}
+
+impl<'a> MatchNode<'a> for AssignmentTargetWithDefault<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.binding, &target.binding, m)
+            && MatchNode::match_node(&self.init, &target.init, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for AssignmentTargetProperty<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        match (self, target) {
+            (
+                Self::AssignmentTargetPropertyIdentifier(a),
+                Self::AssignmentTargetPropertyIdentifier(b),
+            ) => MatchNode::match_node(a, b, m),
+            (
+                Self::AssignmentTargetPropertyProperty(a),
+                Self::AssignmentTargetPropertyProperty(b),
+            ) => MatchNode::match_node(a, b, m),
+            _ => false,
+        }
+    }
+}
+
+impl<'a> MatchNode<'a> for AssignmentTargetPropertyIdentifier<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.binding, &target.binding, m)
+            && MatchNode::match_node(&self.init, &target.init, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for AssignmentTargetPropertyProperty<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.computed, &target.computed)
+            && MatchNode::match_node(&self.name, &target.name, m)
+            && MatchNode::match_node(&self.binding, &target.binding, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for SequenceExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.expressions, &target.expressions, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for Super {
+    fn match_node(&'a self, _: &'a Self, _: &mut MatchState<'a>) -> bool {
+        true
+    }
+}
+
+impl<'a> MatchNode<'a> for AwaitExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.argument, &target.argument, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ChainExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.expression, &target.expression, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ChainElement<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        match (self, target) {
+            (Self::CallExpression(a), Self::CallExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSNonNullExpression(a), Self::TSNonNullExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::StaticMemberExpression(a), Self::StaticMemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::PrivateFieldExpression(a), Self::PrivateFieldExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<'a> MatchNode<'a> for ParenthesizedExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.expression, &target.expression, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for Statement<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        if let Some(result) = m.match_metavariable(self, target) {
+            return result;
+        }
+        match (self, target) {
+            (Self::BlockStatement(a), Self::BlockStatement(b)) => MatchNode::match_node(a, b, m),
+            (Self::BreakStatement(a), Self::BreakStatement(b)) => MatchNode::match_node(a, b, m),
+            (Self::ContinueStatement(a), Self::ContinueStatement(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::DebuggerStatement(a), Self::DebuggerStatement(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::DoWhileStatement(a), Self::DoWhileStatement(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::EmptyStatement(a), Self::EmptyStatement(b)) => MatchNode::match_node(a, b, m),
+            (Self::ExpressionStatement(a), Self::ExpressionStatement(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ForInStatement(a), Self::ForInStatement(b)) => MatchNode::match_node(a, b, m),
+            (Self::ForOfStatement(a), Self::ForOfStatement(b)) => MatchNode::match_node(a, b, m),
+            (Self::ForStatement(a), Self::ForStatement(b)) => MatchNode::match_node(a, b, m),
+            (Self::IfStatement(a), Self::IfStatement(b)) => MatchNode::match_node(a, b, m),
+            (Self::LabeledStatement(a), Self::LabeledStatement(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ReturnStatement(a), Self::ReturnStatement(b)) => MatchNode::match_node(a, b, m),
+            (Self::SwitchStatement(a), Self::SwitchStatement(b)) => MatchNode::match_node(a, b, m),
+            (Self::ThrowStatement(a), Self::ThrowStatement(b)) => MatchNode::match_node(a, b, m),
+            (Self::TryStatement(a), Self::TryStatement(b)) => MatchNode::match_node(a, b, m),
+            (Self::WhileStatement(a), Self::WhileStatement(b)) => MatchNode::match_node(a, b, m),
+            (Self::WithStatement(a), Self::WithStatement(b)) => MatchNode::match_node(a, b, m),
+            (Self::VariableDeclaration(a), Self::VariableDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::FunctionDeclaration(a), Self::FunctionDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ClassDeclaration(a), Self::ClassDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSTypeAliasDeclaration(a), Self::TSTypeAliasDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSInterfaceDeclaration(a), Self::TSInterfaceDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSEnumDeclaration(a), Self::TSEnumDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSExternalModuleDeclaration(a), Self::TSExternalModuleDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSNamespaceDeclaration(a), Self::TSNamespaceDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSGlobalDeclaration(a), Self::TSGlobalDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSImportEqualsDeclaration(a), Self::TSImportEqualsDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ImportDeclaration(a), Self::ImportDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ExportAllDeclaration(a), Self::ExportAllDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ExportDefaultDeclaration(a), Self::ExportDefaultDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ExportDeclaration(a), Self::ExportDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ExportNamedDeclaration(a), Self::ExportNamedDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ExportFromDeclaration(a), Self::ExportFromDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSExportAssignment(a), Self::TSExportAssignment(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSNamespaceExportDeclaration(a), Self::TSNamespaceExportDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            _ => false,
+        }
+    }
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        crate::matcher::statement_identifier(self)
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        Some(Capture::Statement(self))
+    }
+}
+
+impl<'a> MatchNode<'a> for Directive<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.directive, &target.directive)
+            && MatchNode::match_node(&self.expression, &target.expression, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for Hashbang<'a> {
+    fn match_node(&'a self, target: &'a Self, _: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.value, &target.value)
+    }
+}
+
+impl<'a> MatchNode<'a> for BlockStatement<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.body, &target.body, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for Declaration<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        match (self, target) {
+            (Self::VariableDeclaration(a), Self::VariableDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::FunctionDeclaration(a), Self::FunctionDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ClassDeclaration(a), Self::ClassDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSTypeAliasDeclaration(a), Self::TSTypeAliasDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSInterfaceDeclaration(a), Self::TSInterfaceDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSEnumDeclaration(a), Self::TSEnumDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSExternalModuleDeclaration(a), Self::TSExternalModuleDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSNamespaceDeclaration(a), Self::TSNamespaceDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSGlobalDeclaration(a), Self::TSGlobalDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSImportEqualsDeclaration(a), Self::TSImportEqualsDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<'a> MatchNode<'a> for VariableDeclaration<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.kind, &target.kind)
+            && ContentEq::content_eq(&self.declare, &target.declare)
+            && MatchNode::match_node(&self.declarations, &target.declarations, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for VariableDeclarator<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.definite, &target.definite)
+            && MatchNode::match_node(&self.id, &target.id, m)
+            && MatchNode::match_node(&self.type_annotation, &target.type_annotation, m)
+            && MatchNode::match_node(&self.init, &target.init, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for EmptyStatement {
+    fn match_node(&'a self, _: &'a Self, _: &mut MatchState<'a>) -> bool {
+        true
+    }
+}
+
+impl<'a> MatchNode<'a> for ExpressionStatement<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.expression, &target.expression, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for IfStatement<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.test, &target.test, m)
+            && MatchNode::match_node(&self.consequent, &target.consequent, m)
+            && MatchNode::match_node(&self.alternate, &target.alternate, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for DoWhileStatement<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.body, &target.body, m)
+            && MatchNode::match_node(&self.test, &target.test, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for WhileStatement<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.test, &target.test, m)
+            && MatchNode::match_node(&self.body, &target.body, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ForStatement<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.init, &target.init, m)
+            && MatchNode::match_node(&self.test, &target.test, m)
+            && MatchNode::match_node(&self.update, &target.update, m)
+            && MatchNode::match_node(&self.body, &target.body, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ForStatementInit<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        if let Some(result) = m.match_metavariable(self, target) {
+            return result;
+        }
+        match (self, target) {
+            (Self::VariableDeclaration(a), Self::VariableDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::BooleanLiteral(a), Self::BooleanLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::NullLiteral(a), Self::NullLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::NumericLiteral(a), Self::NumericLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::BigIntLiteral(a), Self::BigIntLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::RegExpLiteral(a), Self::RegExpLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::StringLiteral(a), Self::StringLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::TemplateLiteral(a), Self::TemplateLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::Identifier(a), Self::Identifier(b)) => MatchNode::match_node(a, b, m),
+            (Self::Super(a), Self::Super(b)) => MatchNode::match_node(a, b, m),
+            (Self::ArrayExpression(a), Self::ArrayExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ArrowFunctionExpression(a), Self::ArrowFunctionExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::AssignmentExpression(a), Self::AssignmentExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::AwaitExpression(a), Self::AwaitExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::BinaryExpression(a), Self::BinaryExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::CallExpression(a), Self::CallExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ChainExpression(a), Self::ChainExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ClassExpression(a), Self::ClassExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ConditionalExpression(a), Self::ConditionalExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::FunctionExpression(a), Self::FunctionExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ImportExpression(a), Self::ImportExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::LogicalExpression(a), Self::LogicalExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::NewExpression(a), Self::NewExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ObjectExpression(a), Self::ObjectExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ParenthesizedExpression(a), Self::ParenthesizedExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::SequenceExpression(a), Self::SequenceExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TaggedTemplateExpression(a), Self::TaggedTemplateExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ThisExpression(a), Self::ThisExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::UnaryExpression(a), Self::UnaryExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::UpdateExpression(a), Self::UpdateExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::YieldExpression(a), Self::YieldExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::PrivateInExpression(a), Self::PrivateInExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ImportMeta(a), Self::ImportMeta(b)) => MatchNode::match_node(a, b, m),
+            (Self::NewTarget(a), Self::NewTarget(b)) => MatchNode::match_node(a, b, m),
+            (Self::JSXElement(a), Self::JSXElement(b)) => MatchNode::match_node(a, b, m),
+            (Self::JSXFragment(a), Self::JSXFragment(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSAsExpression(a), Self::TSAsExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSSatisfiesExpression(a), Self::TSSatisfiesExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSTypeAssertion(a), Self::TSTypeAssertion(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSNonNullExpression(a), Self::TSNonNullExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSInstantiationExpression(a), Self::TSInstantiationExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::StaticMemberExpression(a), Self::StaticMemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::PrivateFieldExpression(a), Self::PrivateFieldExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            _ => false,
+        }
+    }
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        match self {
+            Self::Identifier(it) => MatchNode::identifier(it),
+            _ => None,
+        }
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        if let Some(expression) = self.as_expression() {
+            Some(Capture::Expression(expression))
+        } else {
+            Some(Capture::ForStatementInit(self))
+        }
+    }
+}
+
+impl<'a> MatchNode<'a> for ForInStatement<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.left, &target.left, m)
+            && MatchNode::match_node(&self.right, &target.right, m)
+            && MatchNode::match_node(&self.body, &target.body, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ForStatementLeft<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        if let Some(result) = m.match_metavariable(self, target) {
+            return result;
+        }
+        match (self, target) {
+            (Self::VariableDeclaration(a), Self::VariableDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::AssignmentTargetIdentifier(a), Self::AssignmentTargetIdentifier(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSAsExpression(a), Self::TSAsExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSSatisfiesExpression(a), Self::TSSatisfiesExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSNonNullExpression(a), Self::TSNonNullExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSTypeAssertion(a), Self::TSTypeAssertion(b)) => MatchNode::match_node(a, b, m),
+            (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::StaticMemberExpression(a), Self::StaticMemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::PrivateFieldExpression(a), Self::PrivateFieldExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ArrayAssignmentTarget(a), Self::ArrayAssignmentTarget(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ObjectAssignmentTarget(a), Self::ObjectAssignmentTarget(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            _ => false,
+        }
+    }
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        match self {
+            Self::AssignmentTargetIdentifier(it) => MatchNode::identifier(it),
+            _ => None,
+        }
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        Some(Capture::ForStatementLeft(self))
+    }
+}
+
+impl<'a> MatchNode<'a> for ForOfStatement<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.r#await, &target.r#await)
+            && MatchNode::match_node(&self.left, &target.left, m)
+            && MatchNode::match_node(&self.right, &target.right, m)
+            && MatchNode::match_node(&self.body, &target.body, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ContinueStatement<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.label, &target.label, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for BreakStatement<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.label, &target.label, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ReturnStatement<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.argument, &target.argument, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for WithStatement<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.object, &target.object, m)
+            && MatchNode::match_node(&self.body, &target.body, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for SwitchStatement<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.discriminant, &target.discriminant, m)
+            && MatchNode::match_node(&self.cases, &target.cases, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for SwitchCase<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.test, &target.test, m)
+            && MatchNode::match_node(&self.consequent, &target.consequent, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for LabeledStatement<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.label, &target.label, m)
+            && MatchNode::match_node(&self.body, &target.body, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ThrowStatement<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.argument, &target.argument, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TryStatement<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.block, &target.block, m)
+            && MatchNode::match_node(&self.handler, &target.handler, m)
+            && MatchNode::match_node(&self.finalizer, &target.finalizer, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for CatchClause<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.param, &target.param, m)
+            && MatchNode::match_node(&self.body, &target.body, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for CatchParameter<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.pattern, &target.pattern, m)
+            && MatchNode::match_node(&self.type_annotation, &target.type_annotation, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for DebuggerStatement {
+    fn match_node(&'a self, _: &'a Self, _: &mut MatchState<'a>) -> bool {
+        true
+    }
+}
+
+impl<'a> MatchNode<'a> for BindingPattern<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        if let Some(result) = m.match_metavariable(self, target) {
+            return result;
+        }
+        match (self, target) {
+            (Self::BindingIdentifier(a), Self::BindingIdentifier(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ObjectPattern(a), Self::ObjectPattern(b)) => MatchNode::match_node(a, b, m),
+            (Self::ArrayPattern(a), Self::ArrayPattern(b)) => MatchNode::match_node(a, b, m),
+            (Self::AssignmentPattern(a), Self::AssignmentPattern(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            _ => false,
+        }
+    }
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        match self {
+            Self::BindingIdentifier(it) => MatchNode::identifier(it),
+            _ => None,
+        }
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        Some(Capture::BindingPattern(self))
+    }
+}
+
+impl<'a> MatchNode<'a> for AssignmentPattern<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.left, &target.left, m)
+            && MatchNode::match_node(&self.right, &target.right, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ObjectPattern<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.properties, &target.properties, m)
+            && MatchNode::match_node(&self.rest, &target.rest, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for BindingProperty<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.shorthand, &target.shorthand)
+            && ContentEq::content_eq(&self.computed, &target.computed)
+            && MatchNode::match_node(&self.key, &target.key, m)
+            && MatchNode::match_node(&self.value, &target.value, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ArrayPattern<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.elements, &target.elements, m)
+            && MatchNode::match_node(&self.rest, &target.rest, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for BindingRestElement<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.argument, &target.argument, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for Function<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.r#type, &target.r#type)
+            && ContentEq::content_eq(&self.generator, &target.generator)
+            && ContentEq::content_eq(&self.r#async, &target.r#async)
+            && ContentEq::content_eq(&self.declare, &target.declare)
+            && ContentEq::content_eq(&self.pure, &target.pure)
+            && ContentEq::content_eq(&self.pife, &target.pife)
+            && MatchNode::match_node(&self.id, &target.id, m)
+            && MatchNode::match_node(&self.type_parameters, &target.type_parameters, m)
+            && MatchNode::match_node(&self.this_param, &target.this_param, m)
+            && MatchNode::match_node(&self.params, &target.params, m)
+            && MatchNode::match_node(&self.return_type, &target.return_type, m)
+            && MatchNode::match_node(&self.body, &target.body, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for FormalParameters<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.kind, &target.kind)
+            && MatchNode::match_node(&self.items, &target.items, m)
+            && MatchNode::match_node(&self.rest, &target.rest, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for FormalParameter<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        if let Some(result) = m.match_metavariable(self, target) {
+            return result;
+        }
+        ContentEq::content_eq(&self.optional, &target.optional)
+            && ContentEq::content_eq(&self.accessibility, &target.accessibility)
+            && ContentEq::content_eq(&self.readonly, &target.readonly)
+            && ContentEq::content_eq(&self.r#override, &target.r#override)
+            && MatchNode::match_node(&self.decorators, &target.decorators, m)
+            && MatchNode::match_node(&self.pattern, &target.pattern, m)
+            && MatchNode::match_node(&self.type_annotation, &target.type_annotation, m)
+            && MatchNode::match_node(&self.initializer, &target.initializer, m)
+    }
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        crate::matcher::formal_parameter_identifier(self)
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        Some(Capture::FormalParameter(self))
+    }
+}
+
+impl<'a> MatchNode<'a> for FormalParameterRest<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.decorators, &target.decorators, m)
+            && MatchNode::match_node(&self.rest, &target.rest, m)
+            && MatchNode::match_node(&self.type_annotation, &target.type_annotation, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for FunctionBody<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.directives, &target.directives, m)
+            && MatchNode::match_node(&self.statements, &target.statements, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ArrowFunctionBody<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        if let Some(result) = m.match_metavariable(self, target) {
+            return result;
+        }
+        match (self, target) {
+            (Self::FunctionBody(a), Self::FunctionBody(b)) => MatchNode::match_node(a, b, m),
+            (Self::BooleanLiteral(a), Self::BooleanLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::NullLiteral(a), Self::NullLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::NumericLiteral(a), Self::NumericLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::BigIntLiteral(a), Self::BigIntLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::RegExpLiteral(a), Self::RegExpLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::StringLiteral(a), Self::StringLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::TemplateLiteral(a), Self::TemplateLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::Identifier(a), Self::Identifier(b)) => MatchNode::match_node(a, b, m),
+            (Self::Super(a), Self::Super(b)) => MatchNode::match_node(a, b, m),
+            (Self::ArrayExpression(a), Self::ArrayExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ArrowFunctionExpression(a), Self::ArrowFunctionExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::AssignmentExpression(a), Self::AssignmentExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::AwaitExpression(a), Self::AwaitExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::BinaryExpression(a), Self::BinaryExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::CallExpression(a), Self::CallExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ChainExpression(a), Self::ChainExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ClassExpression(a), Self::ClassExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ConditionalExpression(a), Self::ConditionalExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::FunctionExpression(a), Self::FunctionExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ImportExpression(a), Self::ImportExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::LogicalExpression(a), Self::LogicalExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::NewExpression(a), Self::NewExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ObjectExpression(a), Self::ObjectExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ParenthesizedExpression(a), Self::ParenthesizedExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::SequenceExpression(a), Self::SequenceExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TaggedTemplateExpression(a), Self::TaggedTemplateExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ThisExpression(a), Self::ThisExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::UnaryExpression(a), Self::UnaryExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::UpdateExpression(a), Self::UpdateExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::YieldExpression(a), Self::YieldExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::PrivateInExpression(a), Self::PrivateInExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ImportMeta(a), Self::ImportMeta(b)) => MatchNode::match_node(a, b, m),
+            (Self::NewTarget(a), Self::NewTarget(b)) => MatchNode::match_node(a, b, m),
+            (Self::JSXElement(a), Self::JSXElement(b)) => MatchNode::match_node(a, b, m),
+            (Self::JSXFragment(a), Self::JSXFragment(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSAsExpression(a), Self::TSAsExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSSatisfiesExpression(a), Self::TSSatisfiesExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSTypeAssertion(a), Self::TSTypeAssertion(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSNonNullExpression(a), Self::TSNonNullExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSInstantiationExpression(a), Self::TSInstantiationExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::StaticMemberExpression(a), Self::StaticMemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::PrivateFieldExpression(a), Self::PrivateFieldExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            _ => false,
+        }
+    }
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        match self {
+            Self::Identifier(it) => MatchNode::identifier(it),
+            _ => None,
+        }
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        if let Some(expression) = self.as_expression() {
+            Some(Capture::Expression(expression))
+        } else {
+            Some(Capture::ArrowFunctionBody(self))
+        }
+    }
+}
+
+impl<'a> MatchNode<'a> for ArrowFunctionExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.r#async, &target.r#async)
+            && ContentEq::content_eq(&self.pure, &target.pure)
+            && ContentEq::content_eq(&self.pife, &target.pife)
+            && MatchNode::match_node(&self.type_parameters, &target.type_parameters, m)
+            && MatchNode::match_node(&self.params, &target.params, m)
+            && MatchNode::match_node(&self.return_type, &target.return_type, m)
+            && MatchNode::match_node(&self.body, &target.body, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for YieldExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.delegate, &target.delegate)
+            && MatchNode::match_node(&self.argument, &target.argument, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for Class<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.r#type, &target.r#type)
+            && ContentEq::content_eq(&self.r#abstract, &target.r#abstract)
+            && ContentEq::content_eq(&self.declare, &target.declare)
+            && MatchNode::match_node(&self.decorators, &target.decorators, m)
+            && MatchNode::match_node(&self.id, &target.id, m)
+            && MatchNode::match_node(&self.type_parameters, &target.type_parameters, m)
+            && MatchNode::match_node(&self.heritage, &target.heritage, m)
+            && MatchNode::match_node(&self.implements, &target.implements, m)
+            && MatchNode::match_node(&self.body, &target.body, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ClassHeritage<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.expression, &target.expression, m)
+            && MatchNode::match_node(&self.type_arguments, &target.type_arguments, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ClassBody<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.body, &target.body, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ClassElement<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        match (self, target) {
+            (Self::StaticBlock(a), Self::StaticBlock(b)) => MatchNode::match_node(a, b, m),
+            (Self::MethodDefinition(a), Self::MethodDefinition(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::PropertyDefinition(a), Self::PropertyDefinition(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::AccessorProperty(a), Self::AccessorProperty(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSIndexSignature(a), Self::TSIndexSignature(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<'a> MatchNode<'a> for MethodDefinition<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.r#type, &target.r#type)
+            && ContentEq::content_eq(&self.kind, &target.kind)
+            && ContentEq::content_eq(&self.computed, &target.computed)
+            && ContentEq::content_eq(&self.r#static, &target.r#static)
+            && ContentEq::content_eq(&self.r#override, &target.r#override)
+            && ContentEq::content_eq(&self.optional, &target.optional)
+            && ContentEq::content_eq(&self.accessibility, &target.accessibility)
+            && MatchNode::match_node(&self.decorators, &target.decorators, m)
+            && MatchNode::match_node(&self.key, &target.key, m)
+            && MatchNode::match_node(&self.value, &target.value, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for PropertyDefinition<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.r#type, &target.r#type)
+            && ContentEq::content_eq(&self.computed, &target.computed)
+            && ContentEq::content_eq(&self.r#static, &target.r#static)
+            && ContentEq::content_eq(&self.declare, &target.declare)
+            && ContentEq::content_eq(&self.r#override, &target.r#override)
+            && ContentEq::content_eq(&self.optional, &target.optional)
+            && ContentEq::content_eq(&self.definite, &target.definite)
+            && ContentEq::content_eq(&self.readonly, &target.readonly)
+            && ContentEq::content_eq(&self.accessibility, &target.accessibility)
+            && MatchNode::match_node(&self.decorators, &target.decorators, m)
+            && MatchNode::match_node(&self.key, &target.key, m)
+            && MatchNode::match_node(&self.type_annotation, &target.type_annotation, m)
+            && MatchNode::match_node(&self.value, &target.value, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for PrivateIdentifier<'a> {
+    fn match_node(&'a self, target: &'a Self, _: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.name, &target.name)
+    }
+}
+
+impl<'a> MatchNode<'a> for StaticBlock<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.body, &target.body, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ModuleDeclaration<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        match (self, target) {
+            (Self::ImportDeclaration(a), Self::ImportDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ExportAllDeclaration(a), Self::ExportAllDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ExportDefaultDeclaration(a), Self::ExportDefaultDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ExportDeclaration(a), Self::ExportDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ExportNamedDeclaration(a), Self::ExportNamedDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ExportFromDeclaration(a), Self::ExportFromDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSExportAssignment(a), Self::TSExportAssignment(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSNamespaceExportDeclaration(a), Self::TSNamespaceExportDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<'a> MatchNode<'a> for AccessorProperty<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.r#type, &target.r#type)
+            && ContentEq::content_eq(&self.computed, &target.computed)
+            && ContentEq::content_eq(&self.r#static, &target.r#static)
+            && ContentEq::content_eq(&self.r#override, &target.r#override)
+            && ContentEq::content_eq(&self.definite, &target.definite)
+            && ContentEq::content_eq(&self.accessibility, &target.accessibility)
+            && MatchNode::match_node(&self.decorators, &target.decorators, m)
+            && MatchNode::match_node(&self.key, &target.key, m)
+            && MatchNode::match_node(&self.type_annotation, &target.type_annotation, m)
+            && MatchNode::match_node(&self.value, &target.value, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ImportExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.phase, &target.phase)
+            && MatchNode::match_node(&self.source, &target.source, m)
+            && MatchNode::match_node(&self.options, &target.options, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ImportDeclaration<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.phase, &target.phase)
+            && ContentEq::content_eq(&self.import_kind, &target.import_kind)
+            && MatchNode::match_node(&self.specifiers, &target.specifiers, m)
+            && MatchNode::match_node(&self.source, &target.source, m)
+            && MatchNode::match_node(&self.with_clause, &target.with_clause, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ImportDeclarationSpecifier<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        match (self, target) {
+            (Self::ImportSpecifier(a), Self::ImportSpecifier(b)) => MatchNode::match_node(a, b, m),
+            (Self::ImportDefaultSpecifier(a), Self::ImportDefaultSpecifier(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ImportNamespaceSpecifier(a), Self::ImportNamespaceSpecifier(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<'a> MatchNode<'a> for ImportSpecifier<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.import_kind, &target.import_kind)
+            && MatchNode::match_node(&self.imported, &target.imported, m)
+            && MatchNode::match_node(&self.local, &target.local, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ImportDefaultSpecifier<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.local, &target.local, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ImportNamespaceSpecifier<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.local, &target.local, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for WithClause<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.keyword, &target.keyword)
+            && MatchNode::match_node(&self.with_entries, &target.with_entries, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ImportAttribute<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.key, &target.key, m)
+            && MatchNode::match_node(&self.value, &target.value, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ImportAttributeKey<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        if let Some(result) = m.match_metavariable(self, target) {
+            return result;
+        }
+        match (self, target) {
+            (Self::Identifier(a), Self::Identifier(b)) => MatchNode::match_node(a, b, m),
+            (Self::StringLiteral(a), Self::StringLiteral(b)) => MatchNode::match_node(a, b, m),
+            _ => false,
+        }
+    }
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        match self {
+            Self::Identifier(it) => MatchNode::identifier(it),
+            _ => None,
+        }
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        Some(Capture::ImportAttributeKey(self))
+    }
+}
+
+impl<'a> MatchNode<'a> for ExportDeclaration<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.declaration, &target.declaration, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ExportNamedDeclaration<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.export_kind, &target.export_kind)
+            && MatchNode::match_node(&self.specifiers, &target.specifiers, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ExportFromDeclaration<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.export_kind, &target.export_kind)
+            && MatchNode::match_node(&self.specifiers, &target.specifiers, m)
+            && MatchNode::match_node(&self.source, &target.source, m)
+            && MatchNode::match_node(&self.with_clause, &target.with_clause, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ExportDefaultDeclaration<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.declaration, &target.declaration, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ExportAllDeclaration<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.export_kind, &target.export_kind)
+            && MatchNode::match_node(&self.exported, &target.exported, m)
+            && MatchNode::match_node(&self.source, &target.source, m)
+            && MatchNode::match_node(&self.with_clause, &target.with_clause, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ExportSpecifier<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.export_kind, &target.export_kind)
+            && MatchNode::match_node(&self.local, &target.local, m)
+            && MatchNode::match_node(&self.exported, &target.exported, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for ExportDefaultDeclarationKind<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        if let Some(result) = m.match_metavariable(self, target) {
+            return result;
+        }
+        match (self, target) {
+            (Self::FunctionDeclaration(a), Self::FunctionDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ClassDeclaration(a), Self::ClassDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSInterfaceDeclaration(a), Self::TSInterfaceDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::BooleanLiteral(a), Self::BooleanLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::NullLiteral(a), Self::NullLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::NumericLiteral(a), Self::NumericLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::BigIntLiteral(a), Self::BigIntLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::RegExpLiteral(a), Self::RegExpLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::StringLiteral(a), Self::StringLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::TemplateLiteral(a), Self::TemplateLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::Identifier(a), Self::Identifier(b)) => MatchNode::match_node(a, b, m),
+            (Self::Super(a), Self::Super(b)) => MatchNode::match_node(a, b, m),
+            (Self::ArrayExpression(a), Self::ArrayExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ArrowFunctionExpression(a), Self::ArrowFunctionExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::AssignmentExpression(a), Self::AssignmentExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::AwaitExpression(a), Self::AwaitExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::BinaryExpression(a), Self::BinaryExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::CallExpression(a), Self::CallExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ChainExpression(a), Self::ChainExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ClassExpression(a), Self::ClassExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ConditionalExpression(a), Self::ConditionalExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::FunctionExpression(a), Self::FunctionExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ImportExpression(a), Self::ImportExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::LogicalExpression(a), Self::LogicalExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::NewExpression(a), Self::NewExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ObjectExpression(a), Self::ObjectExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ParenthesizedExpression(a), Self::ParenthesizedExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::SequenceExpression(a), Self::SequenceExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TaggedTemplateExpression(a), Self::TaggedTemplateExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ThisExpression(a), Self::ThisExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::UnaryExpression(a), Self::UnaryExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::UpdateExpression(a), Self::UpdateExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::YieldExpression(a), Self::YieldExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::PrivateInExpression(a), Self::PrivateInExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ImportMeta(a), Self::ImportMeta(b)) => MatchNode::match_node(a, b, m),
+            (Self::NewTarget(a), Self::NewTarget(b)) => MatchNode::match_node(a, b, m),
+            (Self::JSXElement(a), Self::JSXElement(b)) => MatchNode::match_node(a, b, m),
+            (Self::JSXFragment(a), Self::JSXFragment(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSAsExpression(a), Self::TSAsExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSSatisfiesExpression(a), Self::TSSatisfiesExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSTypeAssertion(a), Self::TSTypeAssertion(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSNonNullExpression(a), Self::TSNonNullExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSInstantiationExpression(a), Self::TSInstantiationExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::StaticMemberExpression(a), Self::StaticMemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::PrivateFieldExpression(a), Self::PrivateFieldExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            _ => false,
+        }
+    }
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        match self {
+            Self::Identifier(it) => MatchNode::identifier(it),
+            _ => None,
+        }
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        if let Some(expression) = self.as_expression() {
+            Some(Capture::Expression(expression))
+        } else {
+            Some(Capture::ExportDefaultDeclarationKind(self))
+        }
+    }
+}
+
+impl<'a> MatchNode<'a> for ModuleExportName<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        if let Some(result) = m.match_metavariable(self, target) {
+            return result;
+        }
+        match (self, target) {
+            (Self::IdentifierName(a), Self::IdentifierName(b)) => MatchNode::match_node(a, b, m),
+            (Self::IdentifierReference(a), Self::IdentifierReference(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::StringLiteral(a), Self::StringLiteral(b)) => MatchNode::match_node(a, b, m),
+            _ => false,
+        }
+    }
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        match self {
+            Self::IdentifierName(it) => MatchNode::identifier(it),
+            Self::IdentifierReference(it) => MatchNode::identifier(it),
+            _ => None,
+        }
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        Some(Capture::ModuleExportName(self))
+    }
+}
+
+impl<'a> MatchNode<'a> for V8IntrinsicExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.name, &target.name, m)
+            && MatchNode::match_node(&self.arguments, &target.arguments, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for BooleanLiteral {
+    fn match_node(&'a self, target: &'a Self, _: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.value, &target.value)
+    }
+}
+
+impl<'a> MatchNode<'a> for NullLiteral {
+    fn match_node(&'a self, _: &'a Self, _: &mut MatchState<'a>) -> bool {
+        true
+    }
+}
+
+impl<'a> MatchNode<'a> for NumericLiteral<'a> {
+    fn match_node(&'a self, target: &'a Self, _: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.value, &target.value)
+    }
+}
+
+impl<'a> MatchNode<'a> for StringLiteral<'a> {
+    fn match_node(&'a self, target: &'a Self, _: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.value, &target.value)
+            && ContentEq::content_eq(&self.lone_surrogates, &target.lone_surrogates)
+    }
+}
+
+impl<'a> MatchNode<'a> for BigIntLiteral<'a> {
+    fn match_node(&'a self, target: &'a Self, _: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.value, &target.value)
+    }
+}
+
+impl<'a> MatchNode<'a> for RegExpLiteral<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.regex, &target.regex, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for RegExp<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.flags, &target.flags)
+            && MatchNode::match_node(&self.pattern, &target.pattern, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for RegExpPattern<'a> {
+    fn match_node(&'a self, target: &'a Self, _: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.text, &target.text)
+    }
+}
+
+impl<'a> MatchNode<'a> for JSXElement<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.opening_element, &target.opening_element, m)
+            && MatchNode::match_node(&self.children, &target.children, m)
+            && MatchNode::match_node(&self.closing_element, &target.closing_element, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for JSXOpeningElement<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.name, &target.name, m)
+            && MatchNode::match_node(&self.type_arguments, &target.type_arguments, m)
+            && MatchNode::match_node(&self.attributes, &target.attributes, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for JSXClosingElement<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.name, &target.name, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for JSXFragment<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.opening_fragment, &target.opening_fragment, m)
+            && MatchNode::match_node(&self.children, &target.children, m)
+            && MatchNode::match_node(&self.closing_fragment, &target.closing_fragment, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for JSXOpeningFragment {
+    fn match_node(&'a self, _: &'a Self, _: &mut MatchState<'a>) -> bool {
+        true
+    }
+}
+
+impl<'a> MatchNode<'a> for JSXClosingFragment {
+    fn match_node(&'a self, _: &'a Self, _: &mut MatchState<'a>) -> bool {
+        true
+    }
+}
+
+impl<'a> MatchNode<'a> for JSXElementName<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        if let Some(result) = m.match_metavariable(self, target) {
+            return result;
+        }
+        match (self, target) {
+            (Self::Identifier(a), Self::Identifier(b)) => MatchNode::match_node(a, b, m),
+            (Self::IdentifierReference(a), Self::IdentifierReference(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::NamespacedName(a), Self::NamespacedName(b)) => MatchNode::match_node(a, b, m),
+            (Self::MemberExpression(a), Self::MemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ThisExpression(a), Self::ThisExpression(b)) => MatchNode::match_node(a, b, m),
+            _ => false,
+        }
+    }
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        match self {
+            Self::IdentifierReference(it) => MatchNode::identifier(it),
+            _ => None,
+        }
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        Some(Capture::JSXElementName(self))
+    }
+}
+
+impl<'a> MatchNode<'a> for JSXNamespacedName<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.namespace, &target.namespace, m)
+            && MatchNode::match_node(&self.name, &target.name, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for JSXMemberExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.object, &target.object, m)
+            && MatchNode::match_node(&self.property, &target.property, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for JSXMemberExpressionObject<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        if let Some(result) = m.match_metavariable(self, target) {
+            return result;
+        }
+        match (self, target) {
+            (Self::IdentifierReference(a), Self::IdentifierReference(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::MemberExpression(a), Self::MemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ThisExpression(a), Self::ThisExpression(b)) => MatchNode::match_node(a, b, m),
+            _ => false,
+        }
+    }
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        match self {
+            Self::IdentifierReference(it) => MatchNode::identifier(it),
+            _ => None,
+        }
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        Some(Capture::JSXMemberExpressionObject(self))
+    }
+}
+
+impl<'a> MatchNode<'a> for JSXExpressionContainer<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.expression, &target.expression, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for JSXExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        if let Some(result) = m.match_metavariable(self, target) {
+            return result;
+        }
+        match (self, target) {
+            (Self::EmptyExpression(a), Self::EmptyExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::BooleanLiteral(a), Self::BooleanLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::NullLiteral(a), Self::NullLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::NumericLiteral(a), Self::NumericLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::BigIntLiteral(a), Self::BigIntLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::RegExpLiteral(a), Self::RegExpLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::StringLiteral(a), Self::StringLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::TemplateLiteral(a), Self::TemplateLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::Identifier(a), Self::Identifier(b)) => MatchNode::match_node(a, b, m),
+            (Self::Super(a), Self::Super(b)) => MatchNode::match_node(a, b, m),
+            (Self::ArrayExpression(a), Self::ArrayExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ArrowFunctionExpression(a), Self::ArrowFunctionExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::AssignmentExpression(a), Self::AssignmentExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::AwaitExpression(a), Self::AwaitExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::BinaryExpression(a), Self::BinaryExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::CallExpression(a), Self::CallExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ChainExpression(a), Self::ChainExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ClassExpression(a), Self::ClassExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ConditionalExpression(a), Self::ConditionalExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::FunctionExpression(a), Self::FunctionExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ImportExpression(a), Self::ImportExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::LogicalExpression(a), Self::LogicalExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::NewExpression(a), Self::NewExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::ObjectExpression(a), Self::ObjectExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ParenthesizedExpression(a), Self::ParenthesizedExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::SequenceExpression(a), Self::SequenceExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TaggedTemplateExpression(a), Self::TaggedTemplateExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ThisExpression(a), Self::ThisExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::UnaryExpression(a), Self::UnaryExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::UpdateExpression(a), Self::UpdateExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::YieldExpression(a), Self::YieldExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::PrivateInExpression(a), Self::PrivateInExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ImportMeta(a), Self::ImportMeta(b)) => MatchNode::match_node(a, b, m),
+            (Self::NewTarget(a), Self::NewTarget(b)) => MatchNode::match_node(a, b, m),
+            (Self::JSXElement(a), Self::JSXElement(b)) => MatchNode::match_node(a, b, m),
+            (Self::JSXFragment(a), Self::JSXFragment(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSAsExpression(a), Self::TSAsExpression(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSSatisfiesExpression(a), Self::TSSatisfiesExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSTypeAssertion(a), Self::TSTypeAssertion(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSNonNullExpression(a), Self::TSNonNullExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSInstantiationExpression(a), Self::TSInstantiationExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::StaticMemberExpression(a), Self::StaticMemberExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::PrivateFieldExpression(a), Self::PrivateFieldExpression(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            _ => false,
+        }
+    }
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        match self {
+            Self::Identifier(it) => MatchNode::identifier(it),
+            _ => None,
+        }
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        if let Some(expression) = self.as_expression() {
+            Some(Capture::Expression(expression))
+        } else {
+            Some(Capture::JSXExpression(self))
+        }
+    }
+}
+
+impl<'a> MatchNode<'a> for JSXEmptyExpression {
+    fn match_node(&'a self, _: &'a Self, _: &mut MatchState<'a>) -> bool {
+        true
+    }
+}
+
+impl<'a> MatchNode<'a> for JSXAttributeItem<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        match (self, target) {
+            (Self::Attribute(a), Self::Attribute(b)) => MatchNode::match_node(a, b, m),
+            (Self::SpreadAttribute(a), Self::SpreadAttribute(b)) => MatchNode::match_node(a, b, m),
+            _ => false,
+        }
+    }
+}
+
+impl<'a> MatchNode<'a> for JSXAttribute<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.name, &target.name, m)
+            && MatchNode::match_node(&self.value, &target.value, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for JSXSpreadAttribute<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.argument, &target.argument, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for JSXAttributeName<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        match (self, target) {
+            (Self::Identifier(a), Self::Identifier(b)) => MatchNode::match_node(a, b, m),
+            (Self::NamespacedName(a), Self::NamespacedName(b)) => MatchNode::match_node(a, b, m),
+            _ => false,
+        }
+    }
+}
+
+impl<'a> MatchNode<'a> for JSXAttributeValue<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        match (self, target) {
+            (Self::StringLiteral(a), Self::StringLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::ExpressionContainer(a), Self::ExpressionContainer(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::Element(a), Self::Element(b)) => MatchNode::match_node(a, b, m),
+            (Self::Fragment(a), Self::Fragment(b)) => MatchNode::match_node(a, b, m),
+            _ => false,
+        }
+    }
+}
+
+impl<'a> MatchNode<'a> for JSXIdentifier<'a> {
+    fn match_node(&'a self, target: &'a Self, _: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.name, &target.name)
+    }
+}
+
+impl<'a> MatchNode<'a> for JSXChild<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        match (self, target) {
+            (Self::Text(a), Self::Text(b)) => MatchNode::match_node(a, b, m),
+            (Self::Element(a), Self::Element(b)) => MatchNode::match_node(a, b, m),
+            (Self::Fragment(a), Self::Fragment(b)) => MatchNode::match_node(a, b, m),
+            (Self::ExpressionContainer(a), Self::ExpressionContainer(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::Spread(a), Self::Spread(b)) => MatchNode::match_node(a, b, m),
+            _ => false,
+        }
+    }
+}
+
+impl<'a> MatchNode<'a> for JSXSpreadChild<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.expression, &target.expression, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for JSXText<'a> {
+    fn match_node(&'a self, target: &'a Self, _: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.value, &target.value)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSThisParameter<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.type_annotation, &target.type_annotation, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSEnumDeclaration<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.r#const, &target.r#const)
+            && ContentEq::content_eq(&self.declare, &target.declare)
+            && MatchNode::match_node(&self.id, &target.id, m)
+            && MatchNode::match_node(&self.body, &target.body, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSEnumBody<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.members, &target.members, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSEnumMember<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.id, &target.id, m)
+            && MatchNode::match_node(&self.initializer, &target.initializer, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSEnumMemberName<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        if let Some(result) = m.match_metavariable(self, target) {
+            return result;
+        }
+        match (self, target) {
+            (Self::Identifier(a), Self::Identifier(b)) => MatchNode::match_node(a, b, m),
+            (Self::String(a), Self::String(b)) => MatchNode::match_node(a, b, m),
+            (Self::ComputedString(a), Self::ComputedString(b)) => MatchNode::match_node(a, b, m),
+            (Self::ComputedTemplateString(a), Self::ComputedTemplateString(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            _ => false,
+        }
+    }
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        match self {
+            Self::Identifier(it) => MatchNode::identifier(it),
+            _ => None,
+        }
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        Some(Capture::TSEnumMemberName(self))
+    }
+}
+
+impl<'a> MatchNode<'a> for TSTypeAnnotation<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.type_annotation, &target.type_annotation, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSLiteralType<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.literal, &target.literal, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSLiteral<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        match (self, target) {
+            (Self::BooleanLiteral(a), Self::BooleanLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::NumericLiteral(a), Self::NumericLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::BigIntLiteral(a), Self::BigIntLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::StringLiteral(a), Self::StringLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::TemplateLiteral(a), Self::TemplateLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::UnaryExpression(a), Self::UnaryExpression(b)) => MatchNode::match_node(a, b, m),
+            _ => false,
+        }
+    }
+}
+
+impl<'a> MatchNode<'a> for TSType<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        match (self, target) {
+            (Self::TSAnyKeyword(a), Self::TSAnyKeyword(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSBigIntKeyword(a), Self::TSBigIntKeyword(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSBooleanKeyword(a), Self::TSBooleanKeyword(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSIntrinsicKeyword(a), Self::TSIntrinsicKeyword(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSNeverKeyword(a), Self::TSNeverKeyword(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSNullKeyword(a), Self::TSNullKeyword(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSNumberKeyword(a), Self::TSNumberKeyword(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSObjectKeyword(a), Self::TSObjectKeyword(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSStringKeyword(a), Self::TSStringKeyword(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSSymbolKeyword(a), Self::TSSymbolKeyword(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSUndefinedKeyword(a), Self::TSUndefinedKeyword(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSUnknownKeyword(a), Self::TSUnknownKeyword(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSVoidKeyword(a), Self::TSVoidKeyword(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSArrayType(a), Self::TSArrayType(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSConditionalType(a), Self::TSConditionalType(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSConstructorType(a), Self::TSConstructorType(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSFunctionType(a), Self::TSFunctionType(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSImportType(a), Self::TSImportType(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSIndexedAccessType(a), Self::TSIndexedAccessType(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSInferType(a), Self::TSInferType(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSIntersectionType(a), Self::TSIntersectionType(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSLiteralType(a), Self::TSLiteralType(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSMappedType(a), Self::TSMappedType(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSNamedTupleMember(a), Self::TSNamedTupleMember(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSTemplateLiteralType(a), Self::TSTemplateLiteralType(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSThisType(a), Self::TSThisType(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSTupleType(a), Self::TSTupleType(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSTypeLiteral(a), Self::TSTypeLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSTypeOperatorType(a), Self::TSTypeOperatorType(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSTypePredicate(a), Self::TSTypePredicate(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSTypeQuery(a), Self::TSTypeQuery(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSTypeReference(a), Self::TSTypeReference(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSUnionType(a), Self::TSUnionType(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSParenthesizedType(a), Self::TSParenthesizedType(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::JSDocNullableType(a), Self::JSDocNullableType(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::JSDocNonNullableType(a), Self::JSDocNonNullableType(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::JSDocUnknownType(a), Self::JSDocUnknownType(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<'a> MatchNode<'a> for TSConditionalType<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.check_type, &target.check_type, m)
+            && MatchNode::match_node(&self.extends_type, &target.extends_type, m)
+            && MatchNode::match_node(&self.true_type, &target.true_type, m)
+            && MatchNode::match_node(&self.false_type, &target.false_type, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSUnionType<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.types, &target.types, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSIntersectionType<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.types, &target.types, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSParenthesizedType<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.type_annotation, &target.type_annotation, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSTypeOperator<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.operator, &target.operator)
+            && MatchNode::match_node(&self.type_annotation, &target.type_annotation, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSArrayType<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.element_type, &target.element_type, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSIndexedAccessType<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.object_type, &target.object_type, m)
+            && MatchNode::match_node(&self.index_type, &target.index_type, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSTupleType<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.element_types, &target.element_types, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSNamedTupleMember<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.optional, &target.optional)
+            && MatchNode::match_node(&self.label, &target.label, m)
+            && MatchNode::match_node(&self.element_type, &target.element_type, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSOptionalType<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.type_annotation, &target.type_annotation, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSRestType<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.type_annotation, &target.type_annotation, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSTupleElement<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        match (self, target) {
+            (Self::TSOptionalType(a), Self::TSOptionalType(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSRestType(a), Self::TSRestType(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSAnyKeyword(a), Self::TSAnyKeyword(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSBigIntKeyword(a), Self::TSBigIntKeyword(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSBooleanKeyword(a), Self::TSBooleanKeyword(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSIntrinsicKeyword(a), Self::TSIntrinsicKeyword(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSNeverKeyword(a), Self::TSNeverKeyword(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSNullKeyword(a), Self::TSNullKeyword(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSNumberKeyword(a), Self::TSNumberKeyword(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSObjectKeyword(a), Self::TSObjectKeyword(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSStringKeyword(a), Self::TSStringKeyword(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSSymbolKeyword(a), Self::TSSymbolKeyword(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSUndefinedKeyword(a), Self::TSUndefinedKeyword(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSUnknownKeyword(a), Self::TSUnknownKeyword(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSVoidKeyword(a), Self::TSVoidKeyword(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSArrayType(a), Self::TSArrayType(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSConditionalType(a), Self::TSConditionalType(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSConstructorType(a), Self::TSConstructorType(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSFunctionType(a), Self::TSFunctionType(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSImportType(a), Self::TSImportType(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSIndexedAccessType(a), Self::TSIndexedAccessType(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSInferType(a), Self::TSInferType(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSIntersectionType(a), Self::TSIntersectionType(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSLiteralType(a), Self::TSLiteralType(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSMappedType(a), Self::TSMappedType(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSNamedTupleMember(a), Self::TSNamedTupleMember(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSTemplateLiteralType(a), Self::TSTemplateLiteralType(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSThisType(a), Self::TSThisType(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSTupleType(a), Self::TSTupleType(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSTypeLiteral(a), Self::TSTypeLiteral(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSTypeOperatorType(a), Self::TSTypeOperatorType(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSTypePredicate(a), Self::TSTypePredicate(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSTypeQuery(a), Self::TSTypeQuery(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSTypeReference(a), Self::TSTypeReference(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSUnionType(a), Self::TSUnionType(b)) => MatchNode::match_node(a, b, m),
+            (Self::TSParenthesizedType(a), Self::TSParenthesizedType(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::JSDocNullableType(a), Self::JSDocNullableType(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::JSDocNonNullableType(a), Self::JSDocNonNullableType(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::JSDocUnknownType(a), Self::JSDocUnknownType(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<'a> MatchNode<'a> for TSAnyKeyword {
+    fn match_node(&'a self, _: &'a Self, _: &mut MatchState<'a>) -> bool {
+        true
+    }
+}
+
+impl<'a> MatchNode<'a> for TSStringKeyword {
+    fn match_node(&'a self, _: &'a Self, _: &mut MatchState<'a>) -> bool {
+        true
+    }
+}
+
+impl<'a> MatchNode<'a> for TSBooleanKeyword {
+    fn match_node(&'a self, _: &'a Self, _: &mut MatchState<'a>) -> bool {
+        true
+    }
+}
+
+impl<'a> MatchNode<'a> for TSNumberKeyword {
+    fn match_node(&'a self, _: &'a Self, _: &mut MatchState<'a>) -> bool {
+        true
+    }
+}
+
+impl<'a> MatchNode<'a> for TSNeverKeyword {
+    fn match_node(&'a self, _: &'a Self, _: &mut MatchState<'a>) -> bool {
+        true
+    }
+}
+
+impl<'a> MatchNode<'a> for TSIntrinsicKeyword {
+    fn match_node(&'a self, _: &'a Self, _: &mut MatchState<'a>) -> bool {
+        true
+    }
+}
+
+impl<'a> MatchNode<'a> for TSUnknownKeyword {
+    fn match_node(&'a self, _: &'a Self, _: &mut MatchState<'a>) -> bool {
+        true
+    }
+}
+
+impl<'a> MatchNode<'a> for TSNullKeyword {
+    fn match_node(&'a self, _: &'a Self, _: &mut MatchState<'a>) -> bool {
+        true
+    }
+}
+
+impl<'a> MatchNode<'a> for TSUndefinedKeyword {
+    fn match_node(&'a self, _: &'a Self, _: &mut MatchState<'a>) -> bool {
+        true
+    }
+}
+
+impl<'a> MatchNode<'a> for TSVoidKeyword {
+    fn match_node(&'a self, _: &'a Self, _: &mut MatchState<'a>) -> bool {
+        true
+    }
+}
+
+impl<'a> MatchNode<'a> for TSSymbolKeyword {
+    fn match_node(&'a self, _: &'a Self, _: &mut MatchState<'a>) -> bool {
+        true
+    }
+}
+
+impl<'a> MatchNode<'a> for TSThisType {
+    fn match_node(&'a self, _: &'a Self, _: &mut MatchState<'a>) -> bool {
+        true
+    }
+}
+
+impl<'a> MatchNode<'a> for TSObjectKeyword {
+    fn match_node(&'a self, _: &'a Self, _: &mut MatchState<'a>) -> bool {
+        true
+    }
+}
+
+impl<'a> MatchNode<'a> for TSBigIntKeyword {
+    fn match_node(&'a self, _: &'a Self, _: &mut MatchState<'a>) -> bool {
+        true
+    }
+}
+
+impl<'a> MatchNode<'a> for TSTypeReference<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.type_name, &target.type_name, m)
+            && MatchNode::match_node(&self.type_arguments, &target.type_arguments, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSTypeName<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        if let Some(result) = m.match_metavariable(self, target) {
+            return result;
+        }
+        match (self, target) {
+            (Self::IdentifierReference(a), Self::IdentifierReference(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::QualifiedName(a), Self::QualifiedName(b)) => MatchNode::match_node(a, b, m),
+            (Self::ThisExpression(a), Self::ThisExpression(b)) => MatchNode::match_node(a, b, m),
+            _ => false,
+        }
+    }
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        match self {
+            Self::IdentifierReference(it) => MatchNode::identifier(it),
+            _ => None,
+        }
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        Some(Capture::TSTypeName(self))
+    }
+}
+
+impl<'a> MatchNode<'a> for TSQualifiedName<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.left, &target.left, m)
+            && MatchNode::match_node(&self.right, &target.right, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSTypeParameterInstantiation<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.params, &target.params, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSTypeParameter<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.r#in, &target.r#in)
+            && ContentEq::content_eq(&self.out, &target.out)
+            && ContentEq::content_eq(&self.r#const, &target.r#const)
+            && MatchNode::match_node(&self.name, &target.name, m)
+            && MatchNode::match_node(&self.constraint, &target.constraint, m)
+            && MatchNode::match_node(&self.default, &target.default, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSTypeParameterDeclaration<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.params, &target.params, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSTypeAliasDeclaration<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.declare, &target.declare)
+            && MatchNode::match_node(&self.id, &target.id, m)
+            && MatchNode::match_node(&self.type_parameters, &target.type_parameters, m)
+            && MatchNode::match_node(&self.type_annotation, &target.type_annotation, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSClassImplements<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.expression, &target.expression, m)
+            && MatchNode::match_node(&self.type_arguments, &target.type_arguments, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSInterfaceDeclaration<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.declare, &target.declare)
+            && MatchNode::match_node(&self.id, &target.id, m)
+            && MatchNode::match_node(&self.type_parameters, &target.type_parameters, m)
+            && MatchNode::match_node(&self.extends, &target.extends, m)
+            && MatchNode::match_node(&self.body, &target.body, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSInterfaceBody<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.body, &target.body, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSPropertySignature<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.computed, &target.computed)
+            && ContentEq::content_eq(&self.optional, &target.optional)
+            && ContentEq::content_eq(&self.readonly, &target.readonly)
+            && MatchNode::match_node(&self.key, &target.key, m)
+            && MatchNode::match_node(&self.type_annotation, &target.type_annotation, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSSignature<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        match (self, target) {
+            (Self::TSIndexSignature(a), Self::TSIndexSignature(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSPropertySignature(a), Self::TSPropertySignature(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSCallSignatureDeclaration(a), Self::TSCallSignatureDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (
+                Self::TSConstructSignatureDeclaration(a),
+                Self::TSConstructSignatureDeclaration(b),
+            ) => MatchNode::match_node(a, b, m),
+            (Self::TSMethodSignature(a), Self::TSMethodSignature(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<'a> MatchNode<'a> for TSIndexSignature<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.readonly, &target.readonly)
+            && ContentEq::content_eq(&self.r#static, &target.r#static)
+            && MatchNode::match_node(&self.parameter, &target.parameter, m)
+            && MatchNode::match_node(&self.type_annotation, &target.type_annotation, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSCallSignatureDeclaration<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.type_parameters, &target.type_parameters, m)
+            && MatchNode::match_node(&self.this_param, &target.this_param, m)
+            && MatchNode::match_node(&self.params, &target.params, m)
+            && MatchNode::match_node(&self.return_type, &target.return_type, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSMethodSignature<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.computed, &target.computed)
+            && ContentEq::content_eq(&self.optional, &target.optional)
+            && ContentEq::content_eq(&self.kind, &target.kind)
+            && MatchNode::match_node(&self.key, &target.key, m)
+            && MatchNode::match_node(&self.type_parameters, &target.type_parameters, m)
+            && MatchNode::match_node(&self.this_param, &target.this_param, m)
+            && MatchNode::match_node(&self.params, &target.params, m)
+            && MatchNode::match_node(&self.return_type, &target.return_type, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSConstructSignatureDeclaration<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.type_parameters, &target.type_parameters, m)
+            && MatchNode::match_node(&self.params, &target.params, m)
+            && MatchNode::match_node(&self.return_type, &target.return_type, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSIndexSignatureName<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.name, &target.name)
+            && MatchNode::match_node(&self.type_annotation, &target.type_annotation, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSInterfaceHeritage<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.type_name, &target.type_name, m)
+            && MatchNode::match_node(&self.type_arguments, &target.type_arguments, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSTypePredicate<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.asserts, &target.asserts)
+            && MatchNode::match_node(&self.parameter_name, &target.parameter_name, m)
+            && MatchNode::match_node(&self.type_annotation, &target.type_annotation, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSTypePredicateName<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        if let Some(result) = m.match_metavariable(self, target) {
+            return result;
+        }
+        match (self, target) {
+            (Self::Identifier(a), Self::Identifier(b)) => MatchNode::match_node(a, b, m),
+            (Self::This(a), Self::This(b)) => MatchNode::match_node(a, b, m),
+            _ => false,
+        }
+    }
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        match self {
+            Self::Identifier(it) => MatchNode::identifier(it),
+            _ => None,
+        }
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        Some(Capture::TSTypePredicateName(self))
+    }
+}
+
+impl<'a> MatchNode<'a> for TSExternalModuleDeclaration<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.declare, &target.declare)
+            && MatchNode::match_node(&self.id, &target.id, m)
+            && MatchNode::match_node(&self.body, &target.body, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSNamespaceDeclaration<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.kind, &target.kind)
+            && ContentEq::content_eq(&self.declare, &target.declare)
+            && MatchNode::match_node(&self.id, &target.id, m)
+            && MatchNode::match_node(&self.body, &target.body, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSNamespaceDeclarationBody<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        match (self, target) {
+            (Self::TSNamespaceDeclaration(a), Self::TSNamespaceDeclaration(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::TSModuleBlock(a), Self::TSModuleBlock(b)) => MatchNode::match_node(a, b, m),
+            _ => false,
+        }
+    }
+}
+
+impl<'a> MatchNode<'a> for TSGlobalDeclaration<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.declare, &target.declare)
+            && MatchNode::match_node(&self.body, &target.body, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSModuleBlock<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.directives, &target.directives, m)
+            && MatchNode::match_node(&self.body, &target.body, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSTypeLiteral<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.members, &target.members, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSInferType<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.type_parameter, &target.type_parameter, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSTypeQuery<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.expr_name, &target.expr_name, m)
+            && MatchNode::match_node(&self.type_arguments, &target.type_arguments, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSTypeQueryExprName<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        if let Some(result) = m.match_metavariable(self, target) {
+            return result;
+        }
+        match (self, target) {
+            (Self::TSImportType(a), Self::TSImportType(b)) => MatchNode::match_node(a, b, m),
+            (Self::IdentifierReference(a), Self::IdentifierReference(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::QualifiedName(a), Self::QualifiedName(b)) => MatchNode::match_node(a, b, m),
+            (Self::ThisExpression(a), Self::ThisExpression(b)) => MatchNode::match_node(a, b, m),
+            _ => false,
+        }
+    }
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        match self {
+            Self::IdentifierReference(it) => MatchNode::identifier(it),
+            _ => None,
+        }
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        Some(Capture::TSTypeQueryExprName(self))
+    }
+}
+
+impl<'a> MatchNode<'a> for TSImportType<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.source, &target.source, m)
+            && MatchNode::match_node(&self.options, &target.options, m)
+            && MatchNode::match_node(&self.qualifier, &target.qualifier, m)
+            && MatchNode::match_node(&self.type_arguments, &target.type_arguments, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSImportTypeQualifier<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        if let Some(result) = m.match_metavariable(self, target) {
+            return result;
+        }
+        match (self, target) {
+            (Self::Identifier(a), Self::Identifier(b)) => MatchNode::match_node(a, b, m),
+            (Self::QualifiedName(a), Self::QualifiedName(b)) => MatchNode::match_node(a, b, m),
+            _ => false,
+        }
+    }
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        match self {
+            Self::Identifier(it) => MatchNode::identifier(it),
+            _ => None,
+        }
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        Some(Capture::TSImportTypeQualifier(self))
+    }
+}
+
+impl<'a> MatchNode<'a> for TSImportTypeQualifiedName<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.left, &target.left, m)
+            && MatchNode::match_node(&self.right, &target.right, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSFunctionType<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.type_parameters, &target.type_parameters, m)
+            && MatchNode::match_node(&self.this_param, &target.this_param, m)
+            && MatchNode::match_node(&self.params, &target.params, m)
+            && MatchNode::match_node(&self.return_type, &target.return_type, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSConstructorType<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.r#abstract, &target.r#abstract)
+            && MatchNode::match_node(&self.type_parameters, &target.type_parameters, m)
+            && MatchNode::match_node(&self.params, &target.params, m)
+            && MatchNode::match_node(&self.return_type, &target.return_type, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSMappedType<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.optional, &target.optional)
+            && ContentEq::content_eq(&self.readonly, &target.readonly)
+            && MatchNode::match_node(&self.key, &target.key, m)
+            && MatchNode::match_node(&self.constraint, &target.constraint, m)
+            && MatchNode::match_node(&self.name_type, &target.name_type, m)
+            && MatchNode::match_node(&self.type_annotation, &target.type_annotation, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSTemplateLiteralType<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.quasis, &target.quasis, m)
+            && MatchNode::match_node(&self.types, &target.types, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSAsExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.expression, &target.expression, m)
+            && MatchNode::match_node(&self.type_annotation, &target.type_annotation, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSSatisfiesExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.expression, &target.expression, m)
+            && MatchNode::match_node(&self.type_annotation, &target.type_annotation, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSTypeAssertion<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.type_annotation, &target.type_annotation, m)
+            && MatchNode::match_node(&self.expression, &target.expression, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSImportEqualsDeclaration<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.import_kind, &target.import_kind)
+            && MatchNode::match_node(&self.id, &target.id, m)
+            && MatchNode::match_node(&self.module_reference, &target.module_reference, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSModuleReference<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        if let Some(result) = m.match_metavariable(self, target) {
+            return result;
+        }
+        match (self, target) {
+            (Self::ExternalModuleReference(a), Self::ExternalModuleReference(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::IdentifierReference(a), Self::IdentifierReference(b)) => {
+                MatchNode::match_node(a, b, m)
+            }
+            (Self::QualifiedName(a), Self::QualifiedName(b)) => MatchNode::match_node(a, b, m),
+            _ => false,
+        }
+    }
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        match self {
+            Self::IdentifierReference(it) => MatchNode::identifier(it),
+            _ => None,
+        }
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        Some(Capture::TSModuleReference(self))
+    }
+}
+
+impl<'a> MatchNode<'a> for TSExternalModuleReference<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.expression, &target.expression, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSNonNullExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.expression, &target.expression, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for Decorator<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.expression, &target.expression, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSExportAssignment<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.expression, &target.expression, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSNamespaceExportDeclaration<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.id, &target.id, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for TSInstantiationExpression<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        MatchNode::match_node(&self.expression, &target.expression, m)
+            && MatchNode::match_node(&self.type_arguments, &target.type_arguments, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for JSDocNullableType<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.postfix, &target.postfix)
+            && MatchNode::match_node(&self.type_annotation, &target.type_annotation, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for JSDocNonNullableType<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.postfix, &target.postfix)
+            && MatchNode::match_node(&self.type_annotation, &target.type_annotation, m)
+    }
+}
+
+impl<'a> MatchNode<'a> for JSDocUnknownType {
+    fn match_node(&'a self, _: &'a Self, _: &mut MatchState<'a>) -> bool {
+        true
+    }
+}
+
+impl<'a> MatchNode<'a> for Comment {
+    fn match_node(&'a self, target: &'a Self, _: &mut MatchState<'a>) -> bool {
+        ContentEq::content_eq(&self.attached_to, &target.attached_to)
+            && ContentEq::content_eq(&self.kind, &target.kind)
+            && ContentEq::content_eq(&self.position, &target.position)
+            && ContentEq::content_eq(&self.newlines, &target.newlines)
+            && ContentEq::content_eq(&self.content, &target.content)
+    }
+}
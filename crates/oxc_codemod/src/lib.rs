@@ -0,0 +1,347 @@
+//! # Oxc Codemod
+//!
+//! Structural search and replace for JavaScript and TypeScript.
+//!
+//! A [`Rule`] is a code-shaped [`Pattern`] with metavariables (`$OBJ.foo($$$ARGS)`), and
+//! optionally a rewrite template which uses the same metavariables (`$OBJ.bar($$$ARGS)`).
+//! [`Codemod::run`] finds matches of its rules in a source file and replaces each match with
+//! its filled-in template. Only the matched code is replaced, so the formatting and comments of
+//! the rest of the file, and of code captured by metavariables, are untouched.
+//!
+//! Matching is binding-aware: a metavariable which appears more than once must match
+//! references to the same binding each time, and [`Rule::with_import`] restricts an identifier
+//! in the pattern to references to a specific import, however it is named locally.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use oxc_codemod::{Codemod, Rule};
+//! use oxc_span::SourceType;
+//!
+//! let rule = Rule::rewrite("$OBJ.foo($$$ARGS)", "$OBJ.bar($$$ARGS)").unwrap();
+//! let ret = Codemod::new(vec![rule]).run("a.foo(1, /* two */ 2);", SourceType::mjs());
+//! assert_eq!(ret.code, "a.bar(1, /* two */ 2);");
+//! ```
+//!
+//! ## Implementation
+//!
+//! Patterns are parsed into the same allocator as the program, and compared with it node by node.
+//! `MatchNode` impls for each AST type are generated by `oxc_ast_tools`, and compare the same
+//! fields as [`ContentEq`], so spans, raw text of literals and semantic IDs are ignored.
+//! Identifiers in the program are resolved with [`oxc_semantic`].
+//!
+//! [`ContentEq`]: oxc_span::ContentEq
+
+use oxc_allocator::Allocator;
+use oxc_diagnostics::{Diagnostics, OxcDiagnostic};
+use oxc_parser::{ParseOptions, Parser};
+use oxc_semantic::SemanticBuilder;
+use oxc_span::{GetSpan, SourceType, Span};
+use rustc_hash::FxHashMap;
+
+mod generated {
+    pub mod match_node;
+}
+mod matcher;
+mod pattern;
+mod template;
+
+use matcher::{Finder, Resolutions};
+pub use pattern::Pattern;
+use template::Template;
+
+/// A pattern to search for, and optionally what to replace matches with.
+#[derive(Debug, Clone)]
+pub struct Rule {
+    pattern: Pattern,
+    rewrite: Option<Template>,
+    /// Pattern identifier -> (module, imported name)
+    imports: FxHashMap<String, (String, String)>,
+}
+
+impl Rule {
+    /// A rule which only finds matches of `pattern`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `pattern` cannot be parsed.
+    pub fn search(pattern: &str) -> Result<Self, OxcDiagnostic> {
+        Ok(Self { pattern: Pattern::parse(pattern)?, rewrite: None, imports: FxHashMap::default() })
+    }
+
+    /// A rule which replaces matches of `pattern` with `template`, after substituting the code
+    /// captured by each metavariable.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `pattern` cannot be parsed, or `template` uses a metavariable which
+    /// `pattern` does not capture.
+    pub fn rewrite(pattern: &str, template: &str) -> Result<Self, OxcDiagnostic> {
+        let pattern = Pattern::parse(pattern)?;
+        let rewrite = Template::parse(template, &pattern.metavariables())?;
+        Ok(Self { pattern, rewrite: Some(rewrite), imports: FxHashMap::default() })
+    }
+
+    /// Only match identifier `name` in the pattern against references to export `imported` of
+    /// `module`, e.g. `rule.with_import("useState", "react", "useState")`.
+    ///
+    /// The reference can have any local name, so `import { useState as useS } from "react"`
+    /// and `useS()` matches `useState()`. Use `"default"` for default imports and `"*"` for
+    /// namespace imports.
+    #[must_use]
+    pub fn with_import(
+        mut self,
+        name: impl Into<String>,
+        module: impl Into<String>,
+        imported: impl Into<String>,
+    ) -> Self {
+        self.imports.insert(name.into(), (module.into(), imported.into()));
+        self
+    }
+}
+
+/// A match of a [`Rule`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    /// Index of the rule in [`Codemod`].
+    pub rule: usize,
+    pub span: Span,
+    /// Spans of the nodes captured by each metavariable. Empty for a `$$$NAME` which matched
+    /// nothing.
+    pub captures: FxHashMap<String, Vec<Span>>,
+}
+
+/// A replacement of the text at `span`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edit {
+    pub span: Span,
+    pub content: String,
+}
+
+impl GetSpan for Edit {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+pub struct CodemodReturn {
+    /// Matches of all rules, in source order.
+    ///
+    /// Matches do not overlap. Code inside a match is not searched for further matches, and if
+    /// two rules match overlapping code, the earlier match wins (the earlier rule, for matches
+    /// at the same position).
+    pub matches: Vec<Match>,
+    /// Edits of the rules which have a rewrite template, in source order.
+    pub edits: Vec<Edit>,
+    /// Source text with `edits` applied.
+    pub code: String,
+    /// Parse errors. If there are any, nothing is matched.
+    pub diagnostics: Diagnostics,
+}
+
+/// Runs [`Rule`]s over source files.
+#[derive(Debug, Clone, Default)]
+pub struct Codemod {
+    rules: Vec<Rule>,
+}
+
+impl Codemod {
+    pub fn new(rules: Vec<Rule>) -> Self {
+        Self { rules }
+    }
+
+    pub fn run(&self, source_text: &str, source_type: SourceType) -> CodemodReturn {
+        let allocator = Allocator::default();
+        let options = ParseOptions { preserve_parens: false, ..ParseOptions::default() };
+        let ret = Parser::new(&allocator, source_text, source_type).with_options(options).parse();
+        if ret.panicked || ret.diagnostics.has_errors() {
+            return CodemodReturn {
+                matches: vec![],
+                edits: vec![],
+                code: source_text.to_string(),
+                diagnostics: ret.diagnostics,
+            };
+        }
+        let program = ret.program;
+        let semantic = SemanticBuilder::new().build(&program).semantic;
+        let resolutions = Resolutions::new(&program, semantic.scoping());
+
+        let mut matches = vec![];
+        let mut edits = vec![];
+        let mut raw_matches = self
+            .rules
+            .iter()
+            .enumerate()
+            .flat_map(|(index, rule)| {
+                let pattern = rule.pattern.parse_in(&allocator);
+                let finder = Finder::new(rule, &pattern, &resolutions);
+                finder.find(&program).into_iter().map(move |m| (index, m))
+            })
+            .collect::<Vec<_>>();
+        raw_matches.sort_by_key(|(index, m)| (m.span.start, *index));
+
+        let mut end = 0;
+        for (index, raw_match) in raw_matches {
+            if raw_match.span.start < end {
+                continue;
+            }
+            end = raw_match.span.end;
+            let captures = raw_match.captures;
+            if let Some(template) = &self.rules[index].rewrite {
+                let content = template.render(source_text, &captures);
+                edits.push(Edit { span: raw_match.span, content });
+            }
+            matches.push(Match { rule: index, span: raw_match.span, captures });
+        }
+
+        let code = apply_edits(source_text, &edits);
+        CodemodReturn { matches, edits, code, diagnostics: ret.diagnostics }
+    }
+}
+
+/// Apply non-overlapping `edits`, sorted by position, to `source_text`.
+fn apply_edits(source_text: &str, edits: &[Edit]) -> String {
+    let mut code = String::with_capacity(source_text.len());
+    let mut last = 0;
+    for edit in edits {
+        code.push_str(&source_text[last..edit.span.start as usize]);
+        code.push_str(&edit.content);
+        last = edit.span.end as usize;
+    }
+    code.push_str(&source_text[last..]);
+    code
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_span::SourceType;
+
+    use super::{Codemod, Rule};
+
+    fn rewrite(rule: Rule, source_text: &str) -> String {
+        Codemod::new(vec![rule]).run(source_text, SourceType::tsx()).code
+    }
+
+    #[test]
+    fn rewrites_calls() {
+        let rule = Rule::rewrite("$OBJ.foo($$$ARGS)", "$OBJ.bar($$$ARGS)").unwrap();
+        assert_eq!(rewrite(rule.clone(), "a.foo()"), "a.bar()");
+        assert_eq!(
+            rewrite(rule.clone(), "x.y[z].foo(1 /* c */,\n  2)"),
+            "x.y[z].bar(1 /* c */,\n  2)"
+        );
+        assert_eq!(rewrite(rule.clone(), "let v = f(a.foo(b));"), "let v = f(a.bar(b));");
+        assert_eq!(rewrite(rule, "a.foo; foo(a); a.fooo();"), "a.foo; foo(a); a.fooo();");
+    }
+
+    #[test]
+    fn metavariables_in_lists() {
+        let rule = Rule::rewrite("f($FIRST, $$$REST)", "g($$$REST, $FIRST)").unwrap();
+        assert_eq!(rewrite(rule.clone(), "f(1, 2, 3)"), "g(2, 3, 1)");
+        assert_eq!(rewrite(rule, "f()"), "f()");
+
+        let rule = Rule::rewrite("[$$$_, $LAST]", "$LAST").unwrap();
+        assert_eq!(rewrite(rule, "x = [1, 2, 3]"), "x = 3");
+    }
+
+    #[test]
+    fn repeated_metavariables() {
+        let rule = Rule::rewrite("$A === $A", "Number.isNaN($A) === false").unwrap();
+        assert_eq!(rewrite(rule.clone(), "if (x === x) {}"), "if (Number.isNaN(x) === false) {}");
+        assert_eq!(rewrite(rule.clone(), "if (x === y) {}"), "if (x === y) {}");
+        // Same name, different bindings
+        assert_eq!(
+            rewrite(rule, "let x; function f(x) { return (x) === g(() => x); }"),
+            "let x; function f(x) { return (x) === g(() => x); }"
+        );
+
+        let rule = Rule::search("$A + $A").unwrap();
+        let ret = Codemod::new(vec![rule])
+            .run("let a; { let a; outer + outer; } a + a;", SourceType::mjs());
+        assert_eq!(ret.matches.len(), 2);
+    }
+
+    #[test]
+    fn statements() {
+        let rule =
+            Rule::rewrite("const $X = require($SOURCE);", "import $X from $SOURCE;").unwrap();
+        assert_eq!(
+            rewrite(rule, "const fs = require('fs');\nfoo();"),
+            "import fs from 'fs';\nfoo();"
+        );
+
+        let rule =
+            Rule::rewrite("if ($COND) { $$$BODY }", "if (!($COND)) return; $$$BODY").unwrap();
+        assert_eq!(
+            rewrite(rule, "function f() { if (a) { b(); c(); } }"),
+            "function f() { if (!(a)) return; b(); c(); }"
+        );
+
+        let rule = Rule::rewrite("$A = 1; $A = 2;", "$A = 2;").unwrap();
+        assert_eq!(rewrite(rule, "x = 1; x = 2; y = 1; z = 2;"), "x = 2; y = 1; z = 2;");
+    }
+
+    #[test]
+    fn statement_sequences() {
+        let rule = Rule::rewrite("$A = 1; $$$MID; $A = 2;", "$$$MID $A = 2;").unwrap();
+        assert_eq!(rewrite(rule, "x = 1; f(); g(); x = 2; h();"), "f(); g(); x = 2; h();");
+
+        let rule = Rule::rewrite("use($X); $$$REST", "{ $$$REST }").unwrap();
+        assert_eq!(rewrite(rule, "a(); use(b); c(); d();"), "a(); { c(); d(); }");
+
+        // Matching a statement list is linear in its length
+        let source_text = "x = 0;\n".repeat(50_000);
+        let rule = Rule::search("$A = 1; $A = 2;").unwrap();
+        let ret = Codemod::new(vec![rule]).run(&source_text, SourceType::mjs());
+        assert!(ret.matches.is_empty());
+    }
+
+    #[test]
+    fn assignment_targets_and_properties() {
+        let rule = Rule::rewrite("$OBJ.$PROP", "$OBJ?.$PROP").unwrap();
+        assert_eq!(rewrite(rule, "a.b = c.d;"), "a?.b = c?.d;");
+    }
+
+    #[test]
+    fn imports() {
+        let rule = Rule::rewrite("useState($INIT)", "useSignal($INIT)")
+            .unwrap()
+            .with_import("useState", "react", "useState");
+        assert_eq!(
+            rewrite(rule.clone(), "import { useState as s } from 'react'; s(0);"),
+            "import { useState as s } from 'react'; useSignal(0);"
+        );
+        assert_eq!(
+            rewrite(rule.clone(), "import { useState } from 'preact'; useState(0);"),
+            "import { useState } from 'preact'; useState(0);"
+        );
+        assert_eq!(rewrite(rule, "useState(0);"), "useState(0);");
+    }
+
+    #[test]
+    fn search_reports_captures() {
+        let rule = Rule::search("console.log($$$ARGS)").unwrap();
+        let source_text = "console.log(a, b); console.log();";
+        let ret = Codemod::new(vec![rule]).run(source_text, SourceType::mjs());
+        assert_eq!(ret.code, source_text);
+        assert!(ret.edits.is_empty());
+        let args = ret
+            .matches
+            .iter()
+            .map(|m| m.captures["ARGS"].iter().map(|span| span.source_text(source_text)).collect())
+            .collect::<Vec<Vec<_>>>();
+        assert_eq!(args, vec![vec!["a", "b"], vec![]]);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(Rule::search("foo(").is_err());
+        assert!(Rule::search("").is_err());
+        assert!(Rule::rewrite("foo($A)", "bar($B)").is_err());
+
+        let rule = Rule::search("foo()").unwrap();
+        let ret = Codemod::new(vec![rule]).run("foo(", SourceType::mjs());
+        assert!(ret.diagnostics.has_errors());
+        assert!(ret.matches.is_empty());
+    }
+}
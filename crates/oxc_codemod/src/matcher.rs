@@ -0,0 +1,635 @@
+use oxc_allocator::{ArenaBox, ArenaVec};
+use oxc_ast::ast::{
+    BindingIdentifier, Expression, FormalParameter, IdentifierName, IdentifierReference,
+    ImportDeclarationSpecifier, Program, SimpleAssignmentTarget, Statement,
+};
+use oxc_ast_visit::{Visit, walk};
+use oxc_semantic::{Scoping, SymbolId};
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashMap;
+
+pub use crate::generated::match_node::Capture;
+use crate::{
+    Rule,
+    pattern::{Metavariable, ParsedPattern},
+};
+
+/// Compare a pattern node against a node of the target program.
+///
+/// Impls for most AST types are generated in `generated/match_node.rs`. They compare the same
+/// fields as `ContentEq`, and check for a metavariable in types where one can appear.
+pub trait MatchNode<'a> {
+    /// Whether `target` matches `self`, binding metavariables in `m`.
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool;
+
+    /// The identifier which this node is, if any, e.g. `Expression::Identifier`.
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        None
+    }
+
+    /// The metavariable which this node is written as, if any.
+    fn metavariable(&'a self) -> Option<Metavariable<'a>> {
+        self.identifier().and_then(|identifier| Metavariable::from_name(identifier.name()))
+    }
+
+    /// This node, as a metavariable capture.
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        None
+    }
+}
+
+impl<'a, T: MatchNode<'a>> MatchNode<'a> for Option<T> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        match (self, target) {
+            (Some(pattern), Some(target)) => pattern.match_node(target, m),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        self.as_ref()?.identifier()
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        self.as_ref()?.capture()
+    }
+}
+
+impl<'a, T: MatchNode<'a>> MatchNode<'a> for ArenaBox<'_, T> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        (**self).match_node(target, m)
+    }
+
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        (**self).identifier()
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        (**self).capture()
+    }
+}
+
+impl<'a, T: MatchNode<'a>> MatchNode<'a> for ArenaVec<'_, T> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        m.match_list(self, target)
+    }
+}
+
+/// An identifier in the pattern or the target program.
+#[derive(Clone, Copy)]
+pub enum Identifier<'a> {
+    Reference(&'a IdentifierReference<'a>),
+    Binding(&'a BindingIdentifier<'a>),
+    Name(&'a IdentifierName<'a>),
+}
+
+impl<'a> Identifier<'a> {
+    pub fn name(self) -> &'a str {
+        match self {
+            Self::Reference(ident) => ident.name.as_str(),
+            Self::Binding(ident) => ident.name.as_str(),
+            Self::Name(ident) => ident.name.as_str(),
+        }
+    }
+}
+
+impl<'a> MatchNode<'a> for IdentifierReference<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        if let Some(result) = m.match_metavariable(self, target) {
+            return result;
+        }
+        if !m.comparing_captures
+            && let Some((module, imported)) = m.rule.imports.get(self.name.as_str())
+        {
+            return m.resolutions.symbol(Identifier::Reference(target)).is_some_and(|symbol_id| {
+                m.resolutions.imports.get(&symbol_id) == Some(&(module.as_str(), imported.as_str()))
+            });
+        }
+        m.match_identifier(Identifier::Reference(self), Identifier::Reference(target))
+    }
+
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        Some(Identifier::Reference(self))
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        Some(Capture::IdentifierReference(self))
+    }
+}
+
+impl<'a> MatchNode<'a> for BindingIdentifier<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        if let Some(result) = m.match_metavariable(self, target) {
+            return result;
+        }
+        m.match_identifier(Identifier::Binding(self), Identifier::Binding(target))
+    }
+
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        Some(Identifier::Binding(self))
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        Some(Capture::BindingIdentifier(self))
+    }
+}
+
+impl<'a> MatchNode<'a> for IdentifierName<'a> {
+    fn match_node(&'a self, target: &'a Self, m: &mut MatchState<'a>) -> bool {
+        if let Some(result) = m.match_metavariable(self, target) {
+            return result;
+        }
+        self.name == target.name
+    }
+
+    fn identifier(&'a self) -> Option<Identifier<'a>> {
+        Some(Identifier::Name(self))
+    }
+
+    fn capture(&'a self) -> Option<Capture<'a>> {
+        Some(Capture::IdentifierName(self))
+    }
+}
+
+/// `$STMT;` stands in for any statement.
+pub fn statement_identifier<'a>(statement: &'a Statement<'a>) -> Option<Identifier<'a>> {
+    match statement {
+        Statement::ExpressionStatement(statement) => statement.expression.identifier(),
+        _ => None,
+    }
+}
+
+/// `$$$PARAMS` in a parameter list stands in for any parameters, not only identifiers.
+pub fn formal_parameter_identifier<'a>(param: &'a FormalParameter<'a>) -> Option<Identifier<'a>> {
+    if param.decorators.is_empty() && param.type_annotation.is_none() && param.initializer.is_none()
+    {
+        param.pattern.identifier()
+    } else {
+        None
+    }
+}
+
+/// Symbols which identifiers in the target program refer to.
+pub struct Resolutions<'a> {
+    scoping: &'a Scoping,
+    /// Module and imported name of each symbol declared by an import.
+    imports: FxHashMap<SymbolId, (&'a str, &'a str)>,
+}
+
+impl<'a> Resolutions<'a> {
+    pub fn new(program: &Program<'a>, scoping: &'a Scoping) -> Self {
+        let mut imports = FxHashMap::default();
+        for statement in &program.body {
+            let Statement::ImportDeclaration(decl) = statement else { continue };
+            for specifier in decl.specifiers.iter().flatten() {
+                let (local, imported) = match specifier {
+                    ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                        (&specifier.local, specifier.imported.name().as_str())
+                    }
+                    ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                        (&specifier.local, "default")
+                    }
+                    ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                        (&specifier.local, "*")
+                    }
+                };
+                if let Some(symbol_id) = local.symbol_id.get() {
+                    imports.insert(symbol_id, (decl.source.value.as_str(), imported));
+                }
+            }
+        }
+        Self { scoping, imports }
+    }
+
+    /// Symbol which an identifier in the target program refers to, or declares.
+    fn symbol(&self, identifier: Identifier<'_>) -> Option<SymbolId> {
+        match identifier {
+            Identifier::Reference(ident) => {
+                self.scoping.get_reference(ident.reference_id.get()?).symbol_id()
+            }
+            Identifier::Binding(ident) => ident.symbol_id.get(),
+            Identifier::Name(_) => None,
+        }
+    }
+}
+
+/// Captured nodes of each metavariable. `$NAME` captures a single node.
+pub type Captures<'a> = FxHashMap<&'a str, Vec<Capture<'a>>>;
+
+/// State of matching one pattern at one position in the target program.
+pub struct MatchState<'a> {
+    rule: &'a Rule,
+    resolutions: &'a Resolutions<'a>,
+    captures: Captures<'a>,
+    /// Comparing a node against an earlier capture of the same metavariable, rather than against
+    /// the pattern. Both nodes are in the target program, so metavariables are not expanded, and
+    /// identifiers are compared by symbol.
+    comparing_captures: bool,
+}
+
+impl<'a> MatchState<'a> {
+    fn new(rule: &'a Rule, resolutions: &'a Resolutions<'a>) -> Self {
+        Self { rule, resolutions, captures: Captures::default(), comparing_captures: false }
+    }
+
+    /// If `pattern` is a metavariable, bind it to `target`.
+    pub fn match_metavariable<T: MatchNode<'a>>(
+        &mut self,
+        pattern: &'a T,
+        target: &'a T,
+    ) -> Option<bool> {
+        if self.comparing_captures {
+            return None;
+        }
+        let name = pattern.metavariable()?.name();
+        let capture = target.capture()?;
+        Some(self.bind(name, vec![capture]))
+    }
+
+    /// Identifiers in the pattern match by name. Identifiers in the target program must also
+    /// refer to the same symbol.
+    fn match_identifier(&self, a: Identifier<'a>, b: Identifier<'a>) -> bool {
+        a.name() == b.name()
+            && (!self.comparing_captures
+                || self.resolutions.symbol(a) == self.resolutions.symbol(b))
+    }
+
+    /// Match the whole of `targets` against `patterns`. `$$$NAME` matches as few nodes as possible.
+    fn match_list<T: MatchNode<'a>>(&mut self, patterns: &'a [T], targets: &'a [T]) -> bool {
+        let Some((pattern, rest_patterns)) = patterns.split_first() else {
+            return targets.is_empty();
+        };
+        if let Some(name) = self.multiple_metavariable(pattern) {
+            for len in 0..=targets.len() {
+                let snapshot = self.captures.clone();
+                if self.bind_list(name, &targets[..len])
+                    && self.match_list(rest_patterns, &targets[len..])
+                {
+                    return true;
+                }
+                self.captures = snapshot;
+            }
+            return false;
+        }
+        let Some((target, rest_targets)) = targets.split_first() else {
+            return false;
+        };
+        let snapshot = self.captures.clone();
+        if pattern.match_node(target, self) && self.match_list(rest_patterns, rest_targets) {
+            return true;
+        }
+        self.captures = snapshot;
+        false
+    }
+
+    /// Match a prefix of `targets` against `patterns`, and return the length of the prefix.
+    ///
+    /// Prefer the longest prefix, so a trailing `$$$REST` takes the rest of the list.
+    /// This only backtracks at `$$$NAME`s, so the common case is linear in the length of `targets`.
+    fn match_prefix<T: MatchNode<'a>>(
+        &mut self,
+        patterns: &'a [T],
+        targets: &'a [T],
+    ) -> Option<usize> {
+        let Some((pattern, rest_patterns)) = patterns.split_first() else {
+            return Some(0);
+        };
+        if let Some(name) = self.multiple_metavariable(pattern) {
+            if rest_patterns.is_empty() {
+                return self.bind_list(name, targets).then_some(targets.len());
+            }
+            for len in (0..=targets.len()).rev() {
+                let snapshot = self.captures.clone();
+                if self.bind_list(name, &targets[..len])
+                    && let Some(rest_len) = self.match_prefix(rest_patterns, &targets[len..])
+                {
+                    return Some(len + rest_len);
+                }
+                self.captures = snapshot;
+            }
+            return None;
+        }
+        let (target, rest_targets) = targets.split_first()?;
+        let snapshot = self.captures.clone();
+        if pattern.match_node(target, self)
+            && let Some(rest_len) = self.match_prefix(rest_patterns, rest_targets)
+        {
+            return Some(1 + rest_len);
+        }
+        self.captures = snapshot;
+        None
+    }
+
+    /// `$$$NAME` as a list element.
+    fn multiple_metavariable<T: MatchNode<'a>>(&self, pattern: &'a T) -> Option<&'a str> {
+        if self.comparing_captures {
+            return None;
+        }
+        match pattern.metavariable()? {
+            Metavariable::Multiple(name) => Some(name),
+            Metavariable::Single(_) => None,
+        }
+    }
+
+    fn bind_list<T: MatchNode<'a>>(&mut self, name: &'a str, nodes: &'a [T]) -> bool {
+        let Some(captures) = nodes.iter().map(MatchNode::capture).collect::<Option<Vec<_>>>()
+        else {
+            return false;
+        };
+        self.bind(name, captures)
+    }
+
+    /// Capture `nodes` as `name`, or check they are equivalent to what `name` already captured.
+    fn bind(&mut self, name: &'a str, nodes: Vec<Capture<'a>>) -> bool {
+        if name == "_" {
+            return true;
+        }
+        if let Some(captured) = self.captures.get(name) {
+            let captured = captured.clone();
+            return captured.len() == nodes.len()
+                && captured.into_iter().zip(nodes).all(|(a, b)| self.equivalent(a, b));
+        }
+        self.captures.insert(name, nodes);
+        true
+    }
+
+    /// Whether `a` and `b` are the same code. Identifiers must refer to the same binding.
+    fn equivalent(&mut self, a: Capture<'a>, b: Capture<'a>) -> bool {
+        if let (Some(a), Some(b)) = (a.identifier(), b.identifier()) {
+            return self.resolutions.symbol(a) == self.resolutions.symbol(b)
+                && a.name() == b.name();
+        }
+        let comparing_captures = std::mem::replace(&mut self.comparing_captures, true);
+        let result = a.match_same_type(b, self);
+        self.comparing_captures = comparing_captures;
+        result
+    }
+
+    fn into_captures(self) -> FxHashMap<String, Vec<Span>> {
+        self.captures
+            .into_iter()
+            .map(|(name, nodes)| (name.to_string(), nodes.iter().map(GetSpan::span).collect()))
+            .collect()
+    }
+}
+
+/// A match of a rule's pattern, before converting into a [`crate::Match`].
+pub struct RawMatch {
+    pub span: Span,
+    pub captures: FxHashMap<String, Vec<Span>>,
+}
+
+/// Finds all non-overlapping matches of one rule's pattern in a program.
+/// A match is not searched for further matches.
+pub struct Finder<'a, 'p> {
+    rule: &'p Rule,
+    pattern: &'p ParsedPattern<'a>,
+    resolutions: &'p Resolutions<'a>,
+    matches: Vec<RawMatch>,
+}
+
+impl<'a, 'p> Finder<'a, 'p> {
+    pub fn new(
+        rule: &'p Rule,
+        pattern: &'p ParsedPattern<'a>,
+        resolutions: &'p Resolutions<'a>,
+    ) -> Self {
+        Self { rule, pattern, resolutions, matches: vec![] }
+    }
+
+    pub fn find(mut self, program: &Program<'a>) -> Vec<RawMatch> {
+        self.visit_program(program);
+        self.matches
+    }
+
+    /// Match `pattern` against `target`, and record the match.
+    fn try_match<'t, T: MatchNode<'t> + GetSpan>(&mut self, pattern: &'t T, target: &'t T) -> bool
+    where
+        'a: 't,
+        'p: 't,
+    {
+        let mut m = MatchState::new(self.rule, self.resolutions);
+        if !pattern.match_node(target, &mut m) {
+            return false;
+        }
+        self.matches.push(RawMatch { span: target.span(), captures: m.into_captures() });
+        true
+    }
+}
+
+impl<'a> Visit<'a> for Finder<'a, '_> {
+    fn visit_expression(&mut self, it: &Expression<'a>) {
+        if let ParsedPattern::Expression(pattern) = self.pattern
+            && self.try_match(pattern, it)
+        {
+            return;
+        }
+        walk::walk_expression(self, it);
+    }
+
+    fn visit_simple_assignment_target(&mut self, it: &SimpleAssignmentTarget<'a>) {
+        // `a.b` in `a.b = c` is not an `Expression`
+        if let ParsedPattern::Expression(pattern) = self.pattern
+            && let Some(pattern) = pattern.as_member_expression()
+            && let Some(target) = it.as_member_expression()
+            && self.try_match(pattern, target)
+        {
+            return;
+        }
+        walk::walk_simple_assignment_target(self, it);
+    }
+
+    fn visit_statement(&mut self, it: &Statement<'a>) {
+        if let ParsedPattern::Statement(pattern) = self.pattern
+            && self.try_match(pattern, it)
+        {
+            return;
+        }
+        walk::walk_statement(self, it);
+    }
+
+    fn visit_statements(&mut self, it: &ArenaVec<'a, Statement<'a>>) {
+        let ParsedPattern::Statements(patterns) = self.pattern else {
+            walk::walk_statements(self, it);
+            return;
+        };
+        let mut start = 0;
+        while start < it.len() {
+            let targets = &it[start..];
+            let mut m = MatchState::new(self.rule, self.resolutions);
+            if let Some(len) = m.match_prefix(patterns.as_slice(), targets)
+                && len > 0
+            {
+                let span = Span::new(targets[0].span().start, targets[len - 1].span().end);
+                self.matches.push(RawMatch { span, captures: m.into_captures() });
+                start += len;
+            } else {
+                self.visit_statement(&it[start]);
+                start += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::{ParseOptions, Parser};
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use super::{Finder, Resolutions};
+    use crate::Rule;
+
+    /// The source text of each match of `rule` in `source_text`, and of the nodes captured by
+    /// each of its metavariables.
+    fn find(rule: &Rule, source_text: &str) -> Vec<(String, BTreeMap<String, Vec<String>>)> {
+        let allocator = Allocator::default();
+        let options = ParseOptions { preserve_parens: false, ..ParseOptions::default() };
+        let ret =
+            Parser::new(&allocator, source_text, SourceType::mjs()).with_options(options).parse();
+        assert!(ret.diagnostics.is_empty());
+        let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+        let resolutions = Resolutions::new(&ret.program, semantic.scoping());
+        let pattern = rule.pattern.parse_in(&allocator);
+        Finder::new(rule, &pattern, &resolutions)
+            .find(&ret.program)
+            .into_iter()
+            .map(|m| {
+                let captures = m
+                    .captures
+                    .into_iter()
+                    .map(|(name, spans)| {
+                        let texts = spans
+                            .iter()
+                            .map(|span| span.source_text(source_text).to_string())
+                            .collect();
+                        (name, texts)
+                    })
+                    .collect();
+                (m.span.source_text(source_text).to_string(), captures)
+            })
+            .collect()
+    }
+
+    fn find_texts(rule: &Rule, source_text: &str) -> Vec<String> {
+        find(rule, source_text).into_iter().map(|(text, _)| text).collect()
+    }
+
+    #[test]
+    fn multiple_metavariable_matches_empty_sequence() {
+        let rule = Rule::search("f($$$ARGS)").unwrap();
+        let matches = find(&rule, "f();");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].1["ARGS"], Vec::<String>::new());
+
+        let rule = Rule::search("f($A, $$$MID, $B)").unwrap();
+        let matches = find(&rule, "f(1, 2);");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].1["A"], ["1"]);
+        assert_eq!(matches[0].1["MID"], Vec::<String>::new());
+        assert_eq!(matches[0].1["B"], ["2"]);
+        // `$A` and `$B` are still required
+        assert!(find(&rule, "f(1);").is_empty());
+
+        let rule = Rule::search("a(); $$$MID; b();").unwrap();
+        let matches = find(&rule, "a(); b();");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].0, "a(); b();");
+        assert_eq!(matches[0].1["MID"], Vec::<String>::new());
+    }
+
+    #[test]
+    fn repeated_metavariable_binds_equal_nodes() {
+        let rule = Rule::search("$A + $A").unwrap();
+        assert_eq!(find_texts(&rule, "x.y + x.y; x.y + x.z;"), ["x.y + x.y"]);
+        // Literals are compared by value, not by raw text
+        assert_eq!(find_texts(&rule, "1 + 1.0; 1 + 2;"), ["1 + 1.0"]);
+
+        let rule = Rule::search("f($$$A, $$$A)").unwrap();
+        let matches = find(&rule, "f(1, 2, 1, 2); f(1, 2, 1); f();");
+        let texts = matches.iter().map(|(text, _)| text.as_str()).collect::<Vec<_>>();
+        assert_eq!(texts, ["f(1, 2, 1, 2)", "f()"]);
+        assert_eq!(matches[0].1["A"], ["1", "2"]);
+
+        // `$_` is not captured, so it does not need to match the same code each time
+        let rule = Rule::search("$_ + $_").unwrap();
+        assert_eq!(find_texts(&rule, "a + b;"), ["a + b"]);
+    }
+
+    #[test]
+    fn metavariables_in_statement_and_expression_position() {
+        // In expression position, a metavariable matches any expression
+        let rule = Rule::search("foo($X)").unwrap();
+        let matches = find(&rule, "foo(a + b);");
+        assert_eq!(matches[0].1["X"], ["a + b"]);
+
+        // As an expression statement, it matches any statement
+        let rule = Rule::search("if ($COND) $BODY;").unwrap();
+        let matches = find(&rule, "if (a) throw e; if (b) { c(); }");
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].1["BODY"], ["throw e;"]);
+        assert_eq!(matches[1].1["BODY"], ["{ c(); }"]);
+
+        // `$$$BODY;` matches any statements in a list
+        let rule = Rule::search("function $F() { $$$BODY; }").unwrap();
+        let matches = find(&rule, "function f() { a(); return b; }");
+        assert_eq!(matches[0].1["BODY"], ["a();", "return b;"]);
+
+        // An optional child must be present in the target when it is in the pattern
+        let rule = Rule::search("return $X;").unwrap();
+        assert_eq!(
+            find_texts(&rule, "function f() { return; } function g() { return 1; }"),
+            ["return 1;"]
+        );
+    }
+
+    #[test]
+    fn imports() {
+        let rule =
+            Rule::search("useState($X)").unwrap().with_import("useState", "react", "useState");
+        // Imported with the same name
+        assert_eq!(
+            find_texts(&rule, "import { useState } from 'react'; useState(0);"),
+            ["useState(0)"]
+        );
+        // Aliased
+        assert_eq!(
+            find_texts(&rule, "import { useState as s } from 'react'; s(0); useState(1);"),
+            ["s(0)"]
+        );
+        // Imported twice, under both names
+        assert_eq!(
+            find_texts(
+                &rule,
+                "import { useState, useState as s } from 'react'; useState(0); s(1);"
+            ),
+            ["useState(0)", "s(1)"]
+        );
+        // Shadowed by a parameter
+        assert!(
+            find_texts(&rule, "import { useState } from 'react'; (useState) => useState(0);")
+                .is_empty()
+        );
+
+        let rule = Rule::search("React.createElement($$$ARGS)")
+            .unwrap()
+            .with_import("React", "react", "default");
+        assert_eq!(
+            find_texts(&rule, "import R from 'react'; R.createElement('a');"),
+            ["R.createElement('a')"]
+        );
+        assert!(find_texts(&rule, "import * as R from 'react'; R.createElement('a');").is_empty());
+
+        let rule = Rule::search("React.createElement($$$ARGS)")
+            .unwrap()
+            .with_import("React", "react", "*");
+        assert_eq!(
+            find_texts(&rule, "import * as R from 'react'; R.createElement('a');"),
+            ["R.createElement('a')"]
+        );
+    }
+}
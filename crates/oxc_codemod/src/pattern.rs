@@ -0,0 +1,220 @@
+use oxc_allocator::{Allocator, ArenaBox, ArenaVec};
+use oxc_ast::{
+    ast::{BindingIdentifier, Expression, IdentifierName, IdentifierReference, Statement},
+    builder::AstBuilder,
+};
+use oxc_ast_visit::Visit;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::{ParseOptions, Parser};
+use oxc_span::SourceType;
+use rustc_hash::FxHashSet;
+
+/// A compiled search pattern.
+///
+/// Patterns are code with metavariables in place of the parts which may vary:
+///
+/// * `$NAME` matches any single node, e.g. `$OBJ.foo()` matches `a.foo()` and `a.b[c].foo()`.
+/// * `$$$NAME` matches any number of nodes in a list, e.g. arguments, array elements,
+///   parameters or statements: `foo($$$ARGS)` matches `foo()` and `foo(a, ...b)`.
+/// * `$_` and `$$$_` match the same as above, but are not captured.
+///
+/// Metavariable names are uppercase letters, digits and `_`. A metavariable which appears more
+/// than once must match equivalent code each time. For identifiers, that means references to
+/// the same binding, not just the same name.
+///
+/// A pattern which is a single expression matches that expression anywhere, including nested
+/// in other expressions. A pattern of one or more statements matches consecutive statements in
+/// any statement list.
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    source: String,
+    metavariables: FxHashSet<String>,
+}
+
+/// A [`Pattern`] parsed into the allocator of the program it is matched against.
+pub enum ParsedPattern<'a> {
+    /// A single expression.
+    Expression(Expression<'a>),
+    /// A single statement which is not an expression statement.
+    Statement(Statement<'a>),
+    /// Two or more statements.
+    Statements(ArenaVec<'a, Statement<'a>>),
+}
+
+impl Pattern {
+    /// Compile `source` into a pattern.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `source` is not valid TypeScript or JSX, or is empty.
+    pub fn parse(source: &str) -> Result<Self, OxcDiagnostic> {
+        let allocator = Allocator::default();
+        let pattern = parse(source, &allocator)?;
+
+        let mut collector = MetavariableCollector::default();
+        match &pattern {
+            ParsedPattern::Expression(expression) => collector.visit_expression(expression),
+            ParsedPattern::Statement(statement) => collector.visit_statement(statement),
+            ParsedPattern::Statements(statements) => collector.visit_statements(statements),
+        }
+        let mut metavariables = collector.names;
+        metavariables.remove("_");
+
+        Ok(Self { source: source.to_string(), metavariables })
+    }
+
+    /// Names of all capturing metavariables in the pattern.
+    pub fn metavariables(&self) -> FxHashSet<&str> {
+        self.metavariables.iter().map(String::as_str).collect()
+    }
+
+    /// Parse the pattern into `allocator`, so it can be compared with a program in the same
+    /// allocator.
+    pub(crate) fn parse_in<'a>(&self, allocator: &'a Allocator) -> ParsedPattern<'a> {
+        let source = allocator.alloc_str(&self.source);
+        parse(source, allocator).expect("pattern was valid when it was compiled")
+    }
+}
+
+fn parse<'a>(
+    source: &'a str,
+    allocator: &'a Allocator,
+) -> Result<ParsedPattern<'a>, OxcDiagnostic> {
+    let options = ParseOptions {
+        allow_return_outside_function: true,
+        preserve_parens: false,
+        ..ParseOptions::default()
+    };
+    let ret = Parser::new(allocator, source, SourceType::tsx()).with_options(options).parse();
+    if let Some(error) = ret.diagnostics.errors().next() {
+        return Err(OxcDiagnostic::error(format!("Invalid pattern `{source}`: {error}")));
+    }
+    let program = ret.program;
+
+    // A leading string literal is parsed as a directive. In a pattern, it is an expression.
+    let builder = AstBuilder::new(allocator);
+    let mut body =
+        ArenaVec::with_capacity_in(program.directives.len() + program.body.len(), &builder);
+    for directive in program.directives {
+        let expression =
+            Expression::StringLiteral(ArenaBox::new_in(directive.expression, &builder));
+        body.push(Statement::new_expression_statement(directive.span, expression, &builder));
+    }
+    body.extend(program.body);
+
+    match body.len() {
+        0 => Err(OxcDiagnostic::error(format!("Empty pattern `{source}`"))),
+        // `foo()` is an expression pattern, not an expression statement pattern.
+        1 => Ok(match body.pop().unwrap() {
+            Statement::ExpressionStatement(statement) => {
+                ParsedPattern::Expression(statement.unbox().expression)
+            }
+            statement => ParsedPattern::Statement(statement),
+        }),
+        _ => Ok(ParsedPattern::Statements(body)),
+    }
+}
+
+/// Collects names of metavariables in a pattern.
+#[derive(Default)]
+struct MetavariableCollector {
+    names: FxHashSet<String>,
+}
+
+impl MetavariableCollector {
+    fn add(&mut self, name: &str) {
+        if let Some(metavariable) = Metavariable::from_name(name) {
+            self.names.insert(metavariable.name().to_string());
+        }
+    }
+}
+
+impl<'a> Visit<'a> for MetavariableCollector {
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        self.add(&it.name);
+    }
+
+    fn visit_binding_identifier(&mut self, it: &BindingIdentifier<'a>) {
+        self.add(&it.name);
+    }
+
+    fn visit_identifier_name(&mut self, it: &IdentifierName<'a>) {
+        self.add(&it.name);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metavariable<'p> {
+    /// `$NAME`
+    Single(&'p str),
+    /// `$$$NAME`
+    Multiple(&'p str),
+}
+
+impl<'p> Metavariable<'p> {
+    /// The metavariable an identifier name is written as, if any.
+    pub fn from_name(name: &'p str) -> Option<Self> {
+        if let Some(name) = name.strip_prefix("$$$") {
+            return is_metavariable_name(name).then_some(Self::Multiple(name));
+        }
+        name.strip_prefix('$').filter(|name| is_metavariable_name(name)).map(Self::Single)
+    }
+
+    pub fn name(self) -> &'p str {
+        match self {
+            Self::Single(name) | Self::Multiple(name) => name,
+        }
+    }
+}
+
+pub fn is_metavariable_name(name: &str) -> bool {
+    let mut bytes = name.bytes();
+    bytes.next().is_some_and(|b| b.is_ascii_uppercase() || b == b'_')
+        && bytes.all(|b| b.is_ascii_uppercase() || b.is_ascii_digit() || b == b'_')
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+
+    use super::{Metavariable, ParsedPattern, Pattern, is_metavariable_name};
+
+    #[test]
+    fn metavariable_names() {
+        assert_eq!(Metavariable::from_name("$A"), Some(Metavariable::Single("A")));
+        assert_eq!(Metavariable::from_name("$$$ARGS"), Some(Metavariable::Multiple("ARGS")));
+        assert_eq!(Metavariable::from_name("$_"), Some(Metavariable::Single("_")));
+        assert_eq!(Metavariable::from_name("$A_1"), Some(Metavariable::Single("A_1")));
+        assert_eq!(Metavariable::from_name("$$A"), None);
+        assert_eq!(Metavariable::from_name("$a"), None);
+        assert_eq!(Metavariable::from_name("$1"), None);
+        assert_eq!(Metavariable::from_name("$"), None);
+        assert_eq!(Metavariable::from_name("A"), None);
+        assert!(!is_metavariable_name(""));
+    }
+
+    #[test]
+    fn collects_metavariables() {
+        let pattern = Pattern::parse("$OBJ.$PROP($$$ARGS, $_, $$$_)").unwrap();
+        let mut names = pattern.metavariables().into_iter().collect::<Vec<_>>();
+        names.sort_unstable();
+        assert_eq!(names, ["ARGS", "OBJ", "PROP"]);
+
+        let pattern = Pattern::parse("function $F($$$PARAMS) { $$$BODY; }").unwrap();
+        let mut names = pattern.metavariables().into_iter().collect::<Vec<_>>();
+        names.sort_unstable();
+        assert_eq!(names, ["BODY", "F", "PARAMS"]);
+    }
+
+    #[test]
+    fn pattern_kinds() {
+        let allocator = Allocator::default();
+        let parse = |source: &str| Pattern::parse(source).unwrap().parse_in(&allocator);
+        assert!(matches!(parse("foo($A)"), ParsedPattern::Expression(_)));
+        assert!(matches!(parse("foo($A);"), ParsedPattern::Expression(_)));
+        // A leading string is an expression, not a directive
+        assert!(matches!(parse("'use strict'"), ParsedPattern::Expression(_)));
+        assert!(matches!(parse("return $A;"), ParsedPattern::Statement(_)));
+        assert!(matches!(parse("$A = 1; $A = 2;"), ParsedPattern::Statements(_)));
+    }
+}
@@ -0,0 +1,128 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::pattern::Metavariable;
+
+/// A rewrite template: code with metavariables, which are replaced with the code they captured.
+#[derive(Debug, Clone)]
+pub struct Template {
+    parts: Vec<TemplatePart>,
+}
+
+#[derive(Debug, Clone)]
+enum TemplatePart {
+    Text(String),
+    Metavariable(String),
+}
+
+impl Template {
+    /// Split `template` into text and the metavariables captured by the pattern.
+    /// `$` not followed by a metavariable name is left as text, e.g. `$.ajax($URL)`.
+    pub fn parse(template: &str, metavariables: &FxHashSet<&str>) -> Result<Self, OxcDiagnostic> {
+        let mut parts = vec![];
+        let mut text = String::new();
+        let mut rest = template;
+        while let Some(index) = rest.find('$') {
+            text.push_str(&rest[..index]);
+            rest = &rest[index..];
+            let len = rest
+                .bytes()
+                .skip_while(|&b| b == b'$')
+                .take_while(|&b| b.is_ascii_uppercase() || b.is_ascii_digit() || b == b'_')
+                .count();
+            let dollars = rest.bytes().take_while(|&b| b == b'$').count();
+            let candidate = &rest[..dollars + len];
+            match Metavariable::from_name(candidate) {
+                Some(metavariable) if metavariable.name() != "_" => {
+                    let name = metavariable.name();
+                    if !metavariables.contains(name) {
+                        return Err(OxcDiagnostic::error(format!(
+                            "Rewrite template uses `{candidate}`, which the pattern does not capture"
+                        )));
+                    }
+                    parts.push(TemplatePart::Text(std::mem::take(&mut text)));
+                    parts.push(TemplatePart::Metavariable(name.to_string()));
+                    rest = &rest[candidate.len()..];
+                }
+                _ => {
+                    text.push_str(&rest[..dollars]);
+                    rest = &rest[dollars..];
+                }
+            }
+        }
+        text.push_str(rest);
+        parts.push(TemplatePart::Text(text));
+        Ok(Self { parts })
+    }
+
+    /// Fill in the template with the source text of the captured nodes. A `$$$NAME` capture
+    /// includes the original separators and comments between its nodes.
+    pub fn render(&self, source_text: &str, captures: &FxHashMap<String, Vec<Span>>) -> String {
+        let mut code = String::new();
+        for part in &self.parts {
+            match part {
+                TemplatePart::Text(text) => code.push_str(text),
+                TemplatePart::Metavariable(name) => {
+                    if let Some((first, last)) =
+                        captures.get(name).and_then(|spans| Some((spans.first()?, spans.last()?)))
+                    {
+                        code.push_str(Span::new(first.start, last.end).source_text(source_text));
+                    }
+                }
+            }
+        }
+        code
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_span::Span;
+    use rustc_hash::{FxHashMap, FxHashSet};
+
+    use super::Template;
+
+    fn metavariables<'a>(names: &[&'a str]) -> FxHashSet<&'a str> {
+        names.iter().copied().collect()
+    }
+
+    #[test]
+    fn unbound_metavariable() {
+        let template = Template::parse("f($A, $$$REST)", &metavariables(&["A", "REST"])).unwrap();
+        let source_text = "x";
+
+        // `$$$REST` which matched nothing renders as nothing
+        let mut captures = FxHashMap::default();
+        captures.insert("A".to_string(), vec![Span::new(0, 1)]);
+        captures.insert("REST".to_string(), vec![]);
+        assert_eq!(template.render(source_text, &captures), "f(x, )");
+
+        // So does a metavariable without captures
+        captures.remove("REST");
+        assert_eq!(template.render(source_text, &captures), "f(x, )");
+
+        // A metavariable which the pattern does not capture is an error
+        assert!(Template::parse("f($B)", &metavariables(&["A"])).is_err());
+        assert!(Template::parse("f($$$B)", &metavariables(&["A"])).is_err());
+    }
+
+    #[test]
+    fn text_with_dollars() {
+        let captures = FxHashMap::default();
+        let metavariables = metavariables(&[]);
+        for text in ["$.ajax(url)", "$_", "$$$_", "`${a}`", "$lower", "$"] {
+            let template = Template::parse(text, &metavariables).unwrap();
+            assert_eq!(template.render("", &captures), text);
+        }
+    }
+
+    #[test]
+    fn multiple_metavariable_keeps_separators() {
+        let source_text = "f(a, /* b */ b)";
+        let mut captures = FxHashMap::default();
+        captures.insert("ARGS".to_string(), vec![Span::new(2, 3), Span::new(13, 14)]);
+        let template = Template::parse("g($$$ARGS)", &metavariables(&["ARGS"])).unwrap();
+        assert_eq!(template.render(source_text, &captures), "g(a, /* b */ b)");
+    }
+}
@@ -0,0 +1,402 @@
+//! Compatibility data of built-in APIs.
+//!
+//! Unlike [`ESFeature`](crate::ESFeature), which is generated from Babel's data for the syntax
+//! transforms, built-in APIs cannot be transformed and are only checked by the linter.
+//! The data follows [MDN's browser-compat-data](https://github.com/mdn/browser-compat-data).
+
+#![allow(clippy::enum_glob_use)]
+
+use std::sync::OnceLock;
+
+use browserslist::Version;
+use rustc_hash::FxHashMap;
+
+use super::{Engine, EngineTargets};
+
+/// A built-in global, static method or prototype method.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Builtin {
+    // Globals
+    GlobalThis,
+    StructuredClone,
+    QueueMicrotask,
+    BigInt,
+    WeakRef,
+    FinalizationRegistry,
+    AggregateError,
+    Promise,
+    Map,
+    Set,
+    WeakMap,
+    WeakSet,
+    Symbol,
+    Iterator,
+    // Static methods
+    ObjectAssign,
+    ObjectEntries,
+    ObjectValues,
+    ObjectGetOwnPropertyDescriptors,
+    ObjectFromEntries,
+    ObjectHasOwn,
+    ObjectGroupBy,
+    MapGroupBy,
+    ArrayFrom,
+    ArrayOf,
+    SymbolAsyncIterator,
+    PromiseAllSettled,
+    PromiseAny,
+    PromiseWithResolvers,
+    PromiseTry,
+    IteratorFrom,
+    // Prototype methods
+    ArrayPrototypeFind,
+    ArrayPrototypeFindIndex,
+    ArrayPrototypeFill,
+    ArrayPrototypeIncludes,
+    ArrayPrototypeFlat,
+    ArrayPrototypeFlatMap,
+    ArrayPrototypeAt,
+    ArrayPrototypeFindLast,
+    ArrayPrototypeFindLastIndex,
+    ArrayPrototypeToReversed,
+    ArrayPrototypeToSorted,
+    ArrayPrototypeToSpliced,
+    ArrayPrototypeWith,
+    StringPrototypeStartsWith,
+    StringPrototypeEndsWith,
+    StringPrototypeIncludes,
+    StringPrototypeRepeat,
+    StringPrototypePadStart,
+    StringPrototypePadEnd,
+    StringPrototypeTrimStart,
+    StringPrototypeTrimEnd,
+    StringPrototypeMatchAll,
+    StringPrototypeReplaceAll,
+    StringPrototypeAt,
+    StringPrototypeIsWellFormed,
+    StringPrototypeToWellFormed,
+    PromisePrototypeFinally,
+    SetPrototypeUnion,
+    SetPrototypeIntersection,
+    SetPrototypeDifference,
+    SetPrototypeSymmetricDifference,
+    SetPrototypeIsSubsetOf,
+    SetPrototypeIsSupersetOf,
+    SetPrototypeIsDisjointFrom,
+    IteratorPrototypeMap,
+    IteratorPrototypeFilter,
+    IteratorPrototypeTake,
+    IteratorPrototypeDrop,
+    IteratorPrototypeFlatMap,
+    IteratorPrototypeReduce,
+    IteratorPrototypeToArray,
+    IteratorPrototypeForEach,
+    IteratorPrototypeSome,
+    IteratorPrototypeEvery,
+    IteratorPrototypeFind,
+}
+
+impl Builtin {
+    /// The last variant, so that [`BuiltinSet`] can check that all built-ins fit in its bits.
+    const LAST: Self = Self::IteratorPrototypeFind;
+
+    /// The path of the API, e.g. `structuredClone`, `Object.hasOwn` or `Array.prototype.at`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::GlobalThis => "globalThis",
+            Self::StructuredClone => "structuredClone",
+            Self::QueueMicrotask => "queueMicrotask",
+            Self::BigInt => "BigInt",
+            Self::WeakRef => "WeakRef",
+            Self::FinalizationRegistry => "FinalizationRegistry",
+            Self::AggregateError => "AggregateError",
+            Self::Promise => "Promise",
+            Self::Map => "Map",
+            Self::Set => "Set",
+            Self::WeakMap => "WeakMap",
+            Self::WeakSet => "WeakSet",
+            Self::Symbol => "Symbol",
+            Self::Iterator => "Iterator",
+            Self::ObjectAssign => "Object.assign",
+            Self::ObjectEntries => "Object.entries",
+            Self::ObjectValues => "Object.values",
+            Self::ObjectGetOwnPropertyDescriptors => "Object.getOwnPropertyDescriptors",
+            Self::ObjectFromEntries => "Object.fromEntries",
+            Self::ObjectHasOwn => "Object.hasOwn",
+            Self::ObjectGroupBy => "Object.groupBy",
+            Self::MapGroupBy => "Map.groupBy",
+            Self::ArrayFrom => "Array.from",
+            Self::ArrayOf => "Array.of",
+            Self::SymbolAsyncIterator => "Symbol.asyncIterator",
+            Self::PromiseAllSettled => "Promise.allSettled",
+            Self::PromiseAny => "Promise.any",
+            Self::PromiseWithResolvers => "Promise.withResolvers",
+            Self::PromiseTry => "Promise.try",
+            Self::IteratorFrom => "Iterator.from",
+            Self::ArrayPrototypeFind => "Array.prototype.find",
+            Self::ArrayPrototypeFindIndex => "Array.prototype.findIndex",
+            Self::ArrayPrototypeFill => "Array.prototype.fill",
+            Self::ArrayPrototypeIncludes => "Array.prototype.includes",
+            Self::ArrayPrototypeFlat => "Array.prototype.flat",
+            Self::ArrayPrototypeFlatMap => "Array.prototype.flatMap",
+            Self::ArrayPrototypeAt => "Array.prototype.at",
+            Self::ArrayPrototypeFindLast => "Array.prototype.findLast",
+            Self::ArrayPrototypeFindLastIndex => "Array.prototype.findLastIndex",
+            Self::ArrayPrototypeToReversed => "Array.prototype.toReversed",
+            Self::ArrayPrototypeToSorted => "Array.prototype.toSorted",
+            Self::ArrayPrototypeToSpliced => "Array.prototype.toSpliced",
+            Self::ArrayPrototypeWith => "Array.prototype.with",
+            Self::StringPrototypeStartsWith => "String.prototype.startsWith",
+            Self::StringPrototypeEndsWith => "String.prototype.endsWith",
+            Self::StringPrototypeIncludes => "String.prototype.includes",
+            Self::StringPrototypeRepeat => "String.prototype.repeat",
+            Self::StringPrototypePadStart => "String.prototype.padStart",
+            Self::StringPrototypePadEnd => "String.prototype.padEnd",
+            Self::StringPrototypeTrimStart => "String.prototype.trimStart",
+            Self::StringPrototypeTrimEnd => "String.prototype.trimEnd",
+            Self::StringPrototypeMatchAll => "String.prototype.matchAll",
+            Self::StringPrototypeReplaceAll => "String.prototype.replaceAll",
+            Self::StringPrototypeAt => "String.prototype.at",
+            Self::StringPrototypeIsWellFormed => "String.prototype.isWellFormed",
+            Self::StringPrototypeToWellFormed => "String.prototype.toWellFormed",
+            Self::PromisePrototypeFinally => "Promise.prototype.finally",
+            Self::SetPrototypeUnion => "Set.prototype.union",
+            Self::SetPrototypeIntersection => "Set.prototype.intersection",
+            Self::SetPrototypeDifference => "Set.prototype.difference",
+            Self::SetPrototypeSymmetricDifference => "Set.prototype.symmetricDifference",
+            Self::SetPrototypeIsSubsetOf => "Set.prototype.isSubsetOf",
+            Self::SetPrototypeIsSupersetOf => "Set.prototype.isSupersetOf",
+            Self::SetPrototypeIsDisjointFrom => "Set.prototype.isDisjointFrom",
+            Self::IteratorPrototypeMap => "Iterator.prototype.map",
+            Self::IteratorPrototypeFilter => "Iterator.prototype.filter",
+            Self::IteratorPrototypeTake => "Iterator.prototype.take",
+            Self::IteratorPrototypeDrop => "Iterator.prototype.drop",
+            Self::IteratorPrototypeFlatMap => "Iterator.prototype.flatMap",
+            Self::IteratorPrototypeReduce => "Iterator.prototype.reduce",
+            Self::IteratorPrototypeToArray => "Iterator.prototype.toArray",
+            Self::IteratorPrototypeForEach => "Iterator.prototype.forEach",
+            Self::IteratorPrototypeSome => "Iterator.prototype.some",
+            Self::IteratorPrototypeEvery => "Iterator.prototype.every",
+            Self::IteratorPrototypeFind => "Iterator.prototype.find",
+        }
+    }
+}
+
+/// A set of [`Builtin`]s.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct BuiltinSet(u128);
+
+const _: () = assert!((Builtin::LAST as u32) < u128::BITS, "`BuiltinSet` is too small");
+
+impl BuiltinSet {
+    pub fn insert(&mut self, builtin: Builtin) {
+        self.0 |= 1 << builtin as u32;
+    }
+
+    pub fn contains(self, builtin: Builtin) -> bool {
+        self.0 & (1 << builtin as u32) != 0
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl FromIterator<Builtin> for BuiltinSet {
+    fn from_iter<I: IntoIterator<Item = Builtin>>(iter: I) -> Self {
+        let mut set = Self::default();
+        for builtin in iter {
+            set.insert(builtin);
+        }
+        set
+    }
+}
+
+/// Minimum engine versions supporting each [`Builtin`].
+///
+/// Engines missing from an entry do not support the API.
+/// APIs defined by ECMAScript also list the `es` version they were added in.
+pub fn builtins() -> &'static FxHashMap<Builtin, EngineTargets> {
+    use Builtin::*;
+    use Engine::*;
+
+    /// `(chrome, edge, firefox, safari, ios, node, opera, samsung)` versions.
+    type Versions = (
+        &'static str,
+        &'static str,
+        &'static str,
+        &'static str,
+        &'static str,
+        &'static str,
+        &'static str,
+        &'static str,
+    );
+
+    fn targets(es: Option<u16>, versions: Versions) -> EngineTargets {
+        let (chrome, edge, firefox, safari, ios, node, opera, samsung) = versions;
+        let mut targets = FxHashMap::from_iter(
+            [
+                (Chrome, chrome),
+                (Edge, edge),
+                (Firefox, firefox),
+                (Safari, safari),
+                (Ios, ios),
+                (Node, node),
+                (Opera, opera),
+                (Samsung, samsung),
+            ]
+            .map(|(engine, version)| (engine, version.parse().unwrap())),
+        );
+        if let Some(es) = es {
+            targets.insert(Es, Version(es, 0, 0));
+        }
+        EngineTargets::new(targets)
+    }
+
+    static BUILTINS: OnceLock<FxHashMap<Builtin, EngineTargets>> = OnceLock::new();
+    BUILTINS.get_or_init(|| {
+        let map = ("38", "12", "13", "8", "8", "0.12", "25", "3");
+        let object_entries = ("54", "14", "47", "10.1", "10.3", "7", "41", "6");
+        let find = ("45", "12", "25", "8", "8", "4", "32", "5");
+        let starts_with = ("41", "12", "17", "9", "9", "4", "28", "4");
+        let set_methods = ("122", "122", "127", "17", "17", "22", "108", "26");
+        let iterator_helpers = ("122", "122", "131", "18.4", "18.4", "22", "108", "26");
+        let change_array_by_copy = ("110", "110", "115", "16", "16", "20", "96", "21");
+        let group_by = ("117", "117", "119", "17.4", "17.4", "21", "103", "24");
+        let weak_ref = ("84", "84", "79", "14.1", "14.5", "14.6", "70", "14");
+        let at = ("92", "92", "90", "15.4", "15.4", "16.6", "78", "16");
+        let find_last = ("97", "97", "104", "15.4", "15.4", "18", "83", "18");
+        let flat = ("69", "79", "62", "12", "12", "11", "56", "10");
+        let pad = ("57", "15", "48", "10", "10", "8", "44", "7");
+        let trim = ("66", "79", "61", "12", "12", "10", "53", "9");
+        let well_formed = ("111", "111", "119", "16.4", "16.4", "20", "97", "22");
+        FxHashMap::from_iter([
+            (GlobalThis, targets(Some(2020), ("71", "79", "65", "12.1", "12.2", "12", "58", "10"))),
+            (StructuredClone, targets(None, ("98", "98", "94", "15.4", "15.4", "17", "84", "18"))),
+            (QueueMicrotask, targets(None, ("71", "79", "69", "12.1", "12.2", "11", "58", "10"))),
+            (BigInt, targets(Some(2020), ("67", "79", "68", "14", "14", "10.4", "54", "9"))),
+            (WeakRef, targets(Some(2021), weak_ref)),
+            (FinalizationRegistry, targets(Some(2021), weak_ref)),
+            (AggregateError, targets(Some(2021), ("85", "85", "79", "14", "14", "15", "71", "14"))),
+            (Promise, targets(Some(2015), ("32", "12", "29", "8", "8", "0.12", "19", "2"))),
+            (Map, targets(Some(2015), map)),
+            (Set, targets(Some(2015), map)),
+            (WeakMap, targets(Some(2015), ("36", "12", "6", "8", "8", "0.12", "23", "3"))),
+            (WeakSet, targets(Some(2015), ("36", "12", "34", "9", "9", "0.12", "23", "3"))),
+            (Symbol, targets(Some(2015), ("38", "12", "36", "9", "9", "0.12", "25", "3"))),
+            (Iterator, targets(Some(2025), iterator_helpers)),
+            (ObjectAssign, targets(Some(2015), ("45", "12", "34", "9", "9", "4", "32", "5"))),
+            (ObjectEntries, targets(Some(2017), object_entries)),
+            (ObjectValues, targets(Some(2017), object_entries)),
+            (
+                ObjectGetOwnPropertyDescriptors,
+                targets(Some(2017), ("54", "15", "50", "10", "10", "7", "41", "6")),
+            ),
+            (
+                ObjectFromEntries,
+                targets(Some(2019), ("73", "79", "63", "12.1", "12.2", "12", "60", "11")),
+            ),
+            (
+                ObjectHasOwn,
+                targets(Some(2022), ("93", "93", "92", "15.4", "15.4", "16.9", "79", "17")),
+            ),
+            (ObjectGroupBy, targets(Some(2024), group_by)),
+            (MapGroupBy, targets(Some(2024), group_by)),
+            (ArrayFrom, targets(Some(2015), ("45", "12", "32", "9", "9", "4", "32", "5"))),
+            (ArrayOf, targets(Some(2015), ("45", "12", "25", "9", "9", "4", "32", "5"))),
+            (
+                SymbolAsyncIterator,
+                targets(Some(2018), ("63", "79", "57", "11.1", "11.3", "10", "50", "8")),
+            ),
+            (
+                PromiseAllSettled,
+                targets(Some(2020), ("76", "79", "71", "13", "13", "12.9", "63", "12")),
+            ),
+            (PromiseAny, targets(Some(2021), ("85", "85", "79", "14", "14", "15", "71", "14"))),
+            (
+                PromiseWithResolvers,
+                targets(Some(2024), ("119", "119", "121", "17.4", "17.4", "22", "105", "25")),
+            ),
+            (
+                PromiseTry,
+                targets(Some(2025), ("128", "128", "134", "18.2", "18.2", "23", "114", "28")),
+            ),
+            (IteratorFrom, targets(Some(2025), iterator_helpers)),
+            (ArrayPrototypeFind, targets(Some(2015), find)),
+            (ArrayPrototypeFindIndex, targets(Some(2015), find)),
+            (ArrayPrototypeFill, targets(Some(2015), ("45", "12", "31", "8", "8", "4", "32", "5"))),
+            (
+                ArrayPrototypeIncludes,
+                targets(Some(2016), ("47", "14", "43", "9", "9", "6", "34", "5")),
+            ),
+            (ArrayPrototypeFlat, targets(Some(2019), flat)),
+            (ArrayPrototypeFlatMap, targets(Some(2019), flat)),
+            (ArrayPrototypeAt, targets(Some(2022), at)),
+            (ArrayPrototypeFindLast, targets(Some(2023), find_last)),
+            (ArrayPrototypeFindLastIndex, targets(Some(2023), find_last)),
+            (ArrayPrototypeToReversed, targets(Some(2023), change_array_by_copy)),
+            (ArrayPrototypeToSorted, targets(Some(2023), change_array_by_copy)),
+            (ArrayPrototypeToSpliced, targets(Some(2023), change_array_by_copy)),
+            (ArrayPrototypeWith, targets(Some(2023), change_array_by_copy)),
+            (StringPrototypeStartsWith, targets(Some(2015), starts_with)),
+            (StringPrototypeEndsWith, targets(Some(2015), starts_with)),
+            (
+                StringPrototypeIncludes,
+                targets(Some(2015), ("41", "12", "40", "9", "9", "4", "28", "4")),
+            ),
+            (
+                StringPrototypeRepeat,
+                targets(Some(2015), ("41", "12", "24", "9", "9", "4", "28", "4")),
+            ),
+            (StringPrototypePadStart, targets(Some(2017), pad)),
+            (StringPrototypePadEnd, targets(Some(2017), pad)),
+            (StringPrototypeTrimStart, targets(Some(2019), trim)),
+            (StringPrototypeTrimEnd, targets(Some(2019), trim)),
+            (
+                StringPrototypeMatchAll,
+                targets(Some(2020), ("73", "79", "67", "13", "13", "12", "60", "11")),
+            ),
+            (
+                StringPrototypeReplaceAll,
+                targets(Some(2021), ("85", "85", "77", "13.1", "13.4", "15", "71", "14")),
+            ),
+            (StringPrototypeAt, targets(Some(2022), at)),
+            (StringPrototypeIsWellFormed, targets(Some(2024), well_formed)),
+            (StringPrototypeToWellFormed, targets(Some(2024), well_formed)),
+            (
+                PromisePrototypeFinally,
+                targets(Some(2018), ("63", "18", "58", "11.1", "11.3", "10", "50", "8")),
+            ),
+            (SetPrototypeUnion, targets(Some(2025), set_methods)),
+            (SetPrototypeIntersection, targets(Some(2025), set_methods)),
+            (SetPrototypeDifference, targets(Some(2025), set_methods)),
+            (SetPrototypeSymmetricDifference, targets(Some(2025), set_methods)),
+            (SetPrototypeIsSubsetOf, targets(Some(2025), set_methods)),
+            (SetPrototypeIsSupersetOf, targets(Some(2025), set_methods)),
+            (SetPrototypeIsDisjointFrom, targets(Some(2025), set_methods)),
+            (IteratorPrototypeMap, targets(Some(2025), iterator_helpers)),
+            (IteratorPrototypeFilter, targets(Some(2025), iterator_helpers)),
+            (IteratorPrototypeTake, targets(Some(2025), iterator_helpers)),
+            (IteratorPrototypeDrop, targets(Some(2025), iterator_helpers)),
+            (IteratorPrototypeFlatMap, targets(Some(2025), iterator_helpers)),
+            (IteratorPrototypeReduce, targets(Some(2025), iterator_helpers)),
+            (IteratorPrototypeToArray, targets(Some(2025), iterator_helpers)),
+            (IteratorPrototypeForEach, targets(Some(2025), iterator_helpers)),
+            (IteratorPrototypeSome, targets(Some(2025), iterator_helpers)),
+            (IteratorPrototypeEvery, targets(Some(2025), iterator_helpers)),
+            (IteratorPrototypeFind, targets(Some(2025), iterator_helpers)),
+        ])
+    })
+}
+
+#[test]
+fn test_builtins() {
+    // Every built-in has compat data, and `Builtin::LAST` is the last variant.
+    assert_eq!(builtins().len(), Builtin::LAST as usize + 1);
+
+    let mut set = BuiltinSet::default();
+    assert!(set.is_empty());
+    set.insert(Builtin::LAST);
+    assert!(set.contains(Builtin::LAST));
+    assert!(!set.contains(Builtin::GlobalThis));
+}
@@ -13,7 +13,7 @@ use crate::browserslist_query::BrowserslistQuery;
 use crate::{babel_targets::BabelTargets, es_target::ESVersion};
 
 use super::{
-    Engine,
+    BuiltinSet, Engine, builtins,
     es_features::{ESFeature, features},
 };
 
@@ -111,6 +111,36 @@ impl EngineTargets {
         })
     }
 
+    /// Find a target engine whose version is lower than the `required` minimum versions.
+    ///
+    /// An engine missing from `required` is treated as unsupported, except `es`, which is only
+    /// checked when `required` lists it. `android` falls back to the `chrome` version.
+    /// Returns the unsupported engine and its target version, choosing the engine with the
+    /// lowest name when there are several.
+    pub fn find_unsupported(&self, required: &EngineTargets) -> Option<(Engine, Version)> {
+        self.iter()
+            .filter(|(engine, target_version)| {
+                if **engine == Engine::Es {
+                    return required.get(engine).is_some_and(|v| target_version.0 < v.0);
+                }
+                let required_version = required.get(engine).or_else(|| {
+                    (**engine == Engine::Android).then(|| required.get(&Engine::Chrome)).flatten()
+                });
+                required_version.is_none_or(|v| *target_version < v)
+            })
+            .min_by_key(|(engine, _)| engine.to_string())
+            .map(|(engine, version)| (*engine, *version))
+    }
+
+    /// The [`Builtin`](crate::Builtin)s not supported by all target engines.
+    pub fn unsupported_builtins(&self) -> BuiltinSet {
+        builtins()
+            .iter()
+            .filter(|(_, required)| self.find_unsupported(required).is_some())
+            .map(|(builtin, _)| *builtin)
+            .collect()
+    }
+
     /// Parses the value returned from `browserslist`.
     pub fn parse_versions(versions: Vec<(String, String)>) -> Self {
         let mut engine_targets = Self::default();
@@ -232,3 +262,48 @@ fn test_supports_es_feature() {
     let rhino = EngineTargets::new(FxHashMap::from_iter([(Engine::Rhino, Version(1, 7, 15))]));
     assert!(!rhino.supports_es_feature(ES2020OptionalChaining));
 }
+
+#[test]
+fn test_find_unsupported() {
+    let required = EngineTargets::new(FxHashMap::from_iter([
+        (Engine::Chrome, Version(92, 0, 0)),
+        (Engine::Firefox, Version(90, 0, 0)),
+        (Engine::Es, Version(2022, 0, 0)),
+    ]));
+
+    assert_eq!(EngineTargets::default().find_unsupported(&required), None);
+    assert_eq!(EngineTargets::from_target("chrome92").unwrap().find_unsupported(&required), None);
+    assert_eq!(
+        EngineTargets::from_target("chrome91,firefox80").unwrap().find_unsupported(&required),
+        Some((Engine::Chrome, Version(91, 0, 0)))
+    );
+    assert_eq!(
+        EngineTargets::from_target("es2021").unwrap().find_unsupported(&required),
+        Some((Engine::Es, Version(2021, 0, 0)))
+    );
+    // Missing compatibility data is not proof of support.
+    assert_eq!(
+        EngineTargets::from_target("safari16").unwrap().find_unsupported(&required),
+        Some((Engine::Safari, Version(16, 0, 0)))
+    );
+    // Android uses the Chrome data.
+    assert_eq!(EngineTargets::from_target("android100").unwrap().find_unsupported(&required), None);
+
+    // Web APIs are not listed with an `es` version.
+    let web_api = EngineTargets::new(FxHashMap::from_iter([(Engine::Chrome, Version(98, 0, 0))]));
+    assert_eq!(EngineTargets::from_target("es2015").unwrap().find_unsupported(&web_api), None);
+}
+
+#[test]
+fn test_unsupported_builtins() {
+    use crate::Builtin;
+
+    let unsupported = EngineTargets::from_target("chrome100").unwrap().unsupported_builtins();
+    assert!(unsupported.contains(Builtin::ObjectGroupBy));
+    assert!(unsupported.contains(Builtin::ArrayPrototypeToSorted));
+    assert!(!unsupported.contains(Builtin::ArrayPrototypeAt));
+    assert!(!unsupported.contains(Builtin::StructuredClone));
+
+    assert!(EngineTargets::from_target("esnext").unwrap().unsupported_builtins().is_empty());
+    assert!(EngineTargets::default().unsupported_builtins().is_empty());
+}
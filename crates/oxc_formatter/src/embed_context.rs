@@ -12,6 +12,12 @@
 /// it is JS↔CSS pair-specific, and `oxc_formatter` must never depend on language crates.
 pub struct CssInJsTemplate;
 
+/// Parent→child parse-mode context for SQL dispatched from a JS template literal (sql-in-js).
+///
+/// Requests `${}` placeholder markers; its ABSENCE means a backtick is a
+/// quoted identifier (MySQL) rather than the start of a marker.
+pub struct SqlInJsTemplate;
+
 /// Child→parent pair context for HTML/Angular formatted as an embedded child.
 ///
 /// NOTE: This lives here permanently, NOT in a future HTML formatter crate:
@@ -25,7 +25,7 @@ use oxc_span::SourceType;
 // External call-sites use the text-in `format`, `format_fragment`,
 // or the special-purpose AST-in `format_program`.
 pub(crate) use crate::ast_nodes::{AstNode, AstNodes};
-pub use crate::embed_context::{CssInJsTemplate, HtmlEmbedMeta};
+pub use crate::embed_context::{CssInJsTemplate, HtmlEmbedMeta, SqlInJsTemplate};
 // `JsFormatContext` is public solely as the type parameter of the `Formatted`
 // returned by `format` / `format_fragment`.
 // Its methods are not part of the public contract.
@@ -247,6 +247,7 @@ pub fn parse_for_format<'a>(
         // The formatter does not use `Ident` hashes, but `detect_code_removal` runs semantic
         // analysis on this AST, and semantic requires hashed `Ident`s.
         enable_ident_hashes: cfg!(feature = "detect_code_removal"),
+        recover_from_errors: false, // never format a partially parsed program
     };
     Parser::new(allocator, source_text, source_type).with_options(options).parse()
 }
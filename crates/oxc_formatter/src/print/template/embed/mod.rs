@@ -2,6 +2,7 @@ mod css;
 mod graphql;
 mod html;
 mod markdown;
+mod sql;
 
 use rustc_hash::FxHashMap;
 
@@ -31,6 +32,7 @@ pub(super) fn try_format_embedded_template<'a>(
         Some("md" | "markdown") if tagged.quasi.is_no_substitution_template() => {
             markdown::try_embed_markdown(tagged, f)
         }
+        Some("sql") => sql::format_sql_doc(tagged.quasi(), f),
         _ => false,
     }
 }
@@ -70,6 +72,7 @@ pub(super) fn try_format_graphql_call<'a>(
 /// Supported languages:
 /// - HTML
 /// - GraphQL
+/// - SQL
 pub(super) fn try_format_comment_embedded<'a>(
     template: &AstNode<'a, TemplateLiteral<'a>>,
     f: &mut JsFormatter<'_, 'a>,
@@ -96,6 +99,7 @@ pub(super) fn try_format_comment_embedded<'a>(
     match text {
         " HTML " => html::format_html_doc(template, f, false),
         " GraphQL " => graphql::format_graphql_doc(template, f),
+        " sql " | " SQL " => sql::format_sql_doc(template, f),
         _ => false,
     }
 }
@@ -0,0 +1,126 @@
+use oxc_allocator::ArenaStringBuilder;
+use oxc_ast::ast::*;
+use oxc_formatter_core::{
+    FormatElement, IndentWidth, dispatch_fragment_ir, format_element::TextWidth,
+};
+
+use crate::{
+    ast_nodes::AstNode,
+    embed_context::SqlInJsTemplate,
+    formatter::prelude::*,
+    print::template::{
+        FormatTemplateExpression, FormatTemplateExpressionOptions, TemplateExpression,
+    },
+    write,
+};
+
+// sql-in-js interpolation marker `` `PLACEHOLDER-N` ``, the same one css-in-js uses.
+// NOTE: Keep these in sync with `oxc_formatter_sql`'s.
+const PLACEHOLDER_PREFIX: &str = "`PLACEHOLDER-";
+const PLACEHOLDER_SUFFIX: &str = "`";
+
+/// Re-emit a (already arena-backed) text slice as a `Text` element.
+/// No-op for an empty slice.
+fn write_text_piece<'a>(text: &'a str, indent_width: IndentWidth, f: &mut JsFormatter<'_, 'a>) {
+    if text.is_empty() {
+        return;
+    }
+    let width = TextWidth::from_text(text, indent_width);
+    f.write_element(FormatElement::Text { text, width });
+}
+
+/// Format a SQL template literal via the Doc→IR path with placeholder replacement.
+///
+/// Called from both:
+/// - tagged template (sql`...`)
+/// - and comment-tagged template (`db.query(/* sql */ `...`)`)
+///
+/// Same three phases as `css.rs`: join quasis with placeholder markers,
+/// format through the dispatcher, then replace the markers with `${expr}` Docs.
+pub(super) fn format_sql_doc<'a>(
+    quasi: &AstNode<'a, TemplateLiteral<'a>>,
+    f: &mut JsFormatter<'_, 'a>,
+) -> bool {
+    let quasis = &quasi.quasis;
+    let expressions: Vec<_> = quasi.expressions().iter().collect();
+
+    // Phase 1: Build joined text
+    // quasis[0].raw + "`PLACEHOLDER-0`" + quasis[1].raw + ...
+    // Use `.raw` (not `.cooked`): escape sequences must round-trip unchanged.
+    let joined = if expressions.is_empty() {
+        quasis[0].value.raw.as_str()
+    } else {
+        let mut sb = ArenaStringBuilder::new_in(f.allocator());
+        for (idx, quasi_elem) in quasis.iter().enumerate() {
+            if idx > 0 {
+                sb.push_str(PLACEHOLDER_PREFIX);
+                let _ = std::fmt::Write::write_fmt(&mut sb, std::format_args!("{}", idx - 1));
+                sb.push_str(PLACEHOLDER_SUFFIX);
+            }
+            sb.push_str(quasi_elem.value.raw.as_str());
+        }
+        sb.into_str()
+    };
+
+    if joined.trim().is_empty() {
+        write!(f, ["``"]);
+        return true;
+    }
+
+    // Phase 2: Format via the dispatcher (IR path)
+    let Some(ir) = dispatch_fragment_ir(f, "sql", joined, Some(&SqlInJsTemplate)) else {
+        return false;
+    };
+
+    // Every placeholder must survive, either as a typed `EmbedPlaceholder` (an operand)
+    // or as a sentinel inside a verbatim `Text` run (a string literal or comment).
+    // Otherwise keep the template as written.
+    let placeholder_count: usize = ir
+        .iter()
+        .map(|el| match el {
+            FormatElement::EmbedPlaceholder(_) => 1,
+            FormatElement::Text { text, .. } => {
+                super::count_placeholders(text, PLACEHOLDER_PREFIX, PLACEHOLDER_SUFFIX)
+            }
+            _ => 0,
+        })
+        .sum();
+    if placeholder_count != expressions.len() {
+        return false;
+    }
+
+    // Phase 3: Replace each `${exprN}` placeholder with the formatted expression.
+    let format_content = format_once(move |f: &mut JsFormatter<'_, 'a>| {
+        let indent_width = f.options().indent_width;
+        for element in ir {
+            match element {
+                FormatElement::EmbedPlaceholder(index) => {
+                    let Some(&expr) = expressions.get(index as usize) else {
+                        continue;
+                    };
+                    let te = TemplateExpression::Expression(expr);
+                    FormatTemplateExpression::new(&te, FormatTemplateExpressionOptions::default())
+                        .fmt(f);
+                }
+                // A sentinel inside a string literal or comment is always inline `${expr}`.
+                FormatElement::Text { text, .. } if text.contains(PLACEHOLDER_PREFIX) => {
+                    let parts =
+                        super::split_on_placeholders(text, PLACEHOLDER_PREFIX, PLACEHOLDER_SUFFIX);
+                    for (i, part) in parts.iter().enumerate() {
+                        if i % 2 == 0 {
+                            write_text_piece(part, indent_width, f);
+                        } else if let Ok(idx) = part.parse::<usize>()
+                            && let Some(&expr) = expressions.get(idx)
+                        {
+                            write!(f, ["${", expr, "}"]);
+                        }
+                    }
+                }
+                _ => f.write_element(element),
+            }
+        }
+    });
+
+    write!(f, ["`", block_indent(&format_content), "`"]);
+    true
+}
@@ -151,6 +151,36 @@ pub fn format_to_ir<'a>(
     Ok(EmbeddedIr { ir: elements, tailwind_classes })
 }
 
+/// Parse `source_text` into a stylesheet AST without formatting it,
+/// for consumers that only inspect the tree (e.g. `oxc_linter`'s stylelint rules).
+///
+/// Unlike [`format()`], the source is NOT newline-normalized:
+/// a leading BOM and front matter are blanked byte-preservingly instead,
+/// so every span indexes straight into `source_text`.
+/// `template_placeholders` has the same meaning as in [`format_to_ir()`],
+/// and also tolerates top-level declarations like any css-in-js fragment.
+///
+/// # Errors
+/// Same as [`format()`].
+pub fn parse<'a>(
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    variant: CssVariant,
+    template_placeholders: bool,
+) -> Result<Stylesheet<'a>, OxcDiagnostic> {
+    let mut parse_source = source_text;
+    if let Some(rest) = parse_source.strip_prefix('\u{feff}') {
+        parse_source = allocator.alloc_concat_strs_array(["   ", rest]);
+    }
+    if let Some(fm) = parse_front_matter(parse_source) {
+        parse_source = allocator.alloc_str(&blank_front_matter(parse_source, fm.raw.len()));
+    }
+
+    let options = CssFormatOptions { variant, ..CssFormatOptions::default() };
+    parse_stylesheet(allocator, parse_source, options, template_placeholders)
+        .map(|(stylesheet, _)| stylesheet)
+}
+
 /// Normalized arena source, its front matter (when present),
 /// and the copy the CSS parser actually sees
 /// (front matter blanked byte-preservingly so every span, comment, and source-gap scan aligns with `source`).
@@ -245,6 +275,7 @@ fn to_diagnostic(error: &oxc_css_parser::error::Error) -> OxcDiagnostic {
     OxcDiagnostic::error(format!("Syntax error: {}", error.kind)).with_label(to_span(&error.span))
 }
 
+/// Convert an `oxc-css-parser` span (`usize` offsets) into an [`oxc_span::Span`].
 pub fn to_span(span: &oxc_css_parser::Span) -> Span {
     Span::new(
         u32::try_from(span.start).unwrap_or(u32::MAX),
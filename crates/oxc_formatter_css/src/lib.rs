@@ -35,8 +35,11 @@ pub const TEMPLATE_PLACEHOLDER_PREFIX: &str = "`PLACEHOLDER-";
 /// Closing affix of the css-in-js marker (the closing backtick).
 pub const TEMPLATE_PLACEHOLDER_SUFFIX: &str = "`";
 
+/// The `oxc-css-parser` AST that [`parse`] returns.
+pub use oxc_css_parser::ast;
+
 pub use crate::{
     context::CssFormatContext,
-    format::{format, format_to_ir, format_with_session},
+    format::{format, format_to_ir, format_with_session, parse, to_span},
     options::{CssFormatOptions, CssVariant, SingleQuote, TrailingCommas},
 };
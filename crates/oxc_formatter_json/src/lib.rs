@@ -25,4 +25,5 @@ pub use crate::{
         BracketSpacing, Expand, JsonFormatOptions, JsonVariant, QuoteProps, SingleQuote,
         TrailingCommas,
     },
+    parse::{ParsedJson, parse_json},
 };
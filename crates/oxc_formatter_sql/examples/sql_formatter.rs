@@ -0,0 +1,71 @@
+#![expect(clippy::print_stdout)]
+//! # SQL Formatter Example
+//!
+//! ## Usage
+//!
+//! Create a `test.sql` file and run:
+//! ```bash
+//! cargo run -p oxc_formatter_sql --example sql_formatter [filename]
+//! cargo run -p oxc_formatter_sql --example sql_formatter -- --print-width 100 [filename]
+//! cargo run -p oxc_formatter_sql --example sql_formatter -- --keyword-case lower [filename]
+//! cargo run -p oxc_formatter_sql --example sql_formatter -- --diff [filename]
+//! ```
+
+use std::fs;
+
+use pico_args::Arguments;
+
+use oxc_allocator::Allocator;
+use oxc_formatter_core::LineWidth;
+use oxc_formatter_sql::{KeywordCase, SqlFormatOptions};
+use oxc_tasks_common::print_diff_in_terminal;
+
+fn main() -> Result<(), String> {
+    let mut args = Arguments::from_env();
+    // Show diff between original and formatted code
+    let show_diff = args.contains("--diff");
+    let print_width = args.opt_value_from_str::<&'static str, u16>("--print-width").unwrap_or(None);
+    let keyword_case =
+        args.opt_value_from_str::<&'static str, String>("--keyword-case").unwrap_or(None);
+    let name = args.free_from_str().unwrap_or_else(|_| "test.sql".to_string());
+
+    let source_text = fs::read_to_string(&name).map_err(|_| format!("Missing '{name}'"))?;
+
+    let line_width = match print_width {
+        Some(width) => LineWidth::try_from(width).unwrap(),
+        None => LineWidth::try_from(80).unwrap(),
+    };
+    let keyword_case = match keyword_case.as_deref() {
+        Some("lower") => KeywordCase::Lower,
+        Some("preserve") => KeywordCase::Preserve,
+        _ => KeywordCase::Upper,
+    };
+    let options = SqlFormatOptions { line_width, keyword_case, ..Default::default() };
+
+    let allocator = Allocator::new();
+    let formatted = match oxc_formatter_sql::format(&allocator, &source_text, options) {
+        Ok(formatted) => formatted,
+        Err(error) => {
+            println!("{}", error.render());
+            return Err("Parsed with Errors.".to_string());
+        }
+    };
+
+    if std::env::var("DUMP_IR").is_ok() {
+        println!("{:#?}", formatted.document());
+    }
+
+    let formatted_code = formatted.print().unwrap().into_code();
+
+    if show_diff {
+        if source_text == formatted_code {
+            print!("{formatted_code}");
+        } else {
+            print_diff_in_terminal(&source_text, &formatted_code);
+        }
+    } else {
+        print!("{formatted_code}");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use oxc_formatter_core::{FormatContext, SourceText};
+
+use crate::options::SqlFormatOptions;
+
+/// Formatting context for SQL.
+pub struct SqlFormatContext<'a> {
+    options: SqlFormatOptions,
+    source_text: SourceText<'a>,
+}
+
+impl<'a> SqlFormatContext<'a> {
+    pub fn new(options: SqlFormatOptions, source_code: &'a str) -> Self {
+        Self { options, source_text: SourceText::new(source_code) }
+    }
+
+    /// Returns the source text with the arena lifetime (vs the trait's borrow-elided `&str`).
+    pub fn source_text(&self) -> SourceText<'a> {
+        self.source_text
+    }
+}
+
+impl FormatContext for SqlFormatContext<'_> {
+    type Options = SqlFormatOptions;
+
+    fn options(&self) -> &Self::Options {
+        &self.options
+    }
+
+    fn source_code(&self) -> &str {
+        &self.source_text
+    }
+}
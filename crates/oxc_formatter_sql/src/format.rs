@@ -0,0 +1,118 @@
+use oxc_allocator::Allocator;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_formatter_core::{
+    Buffer, Document, EmbeddedIr, Format, FormatSession, FormatState, Formatted, VecBuffer,
+    builders::{hard_line_break, text},
+    write,
+};
+
+use crate::{
+    context::SqlFormatContext,
+    lexer,
+    options::SqlFormatOptions,
+    print::{self, SqlFormatter, Statement},
+};
+
+/// Tokenize `source_text` as SQL and build its formatter IR.
+///
+/// # Errors
+/// Returns an [`OxcDiagnostic`] for unterminated strings, quoted identifiers and comments,
+/// and for unbalanced brackets.
+pub fn format<'a>(
+    allocator: &'a Allocator,
+    source_text: &str,
+    options: SqlFormatOptions,
+) -> Result<Formatted<'a, SqlFormatContext<'a>>, OxcDiagnostic> {
+    let (has_bom, source_text) = oxc_formatter_core::spec::split_bom(source_text);
+    let (statements, source) = parse(allocator, source_text, false)?;
+
+    let context = SqlFormatContext::new(options, source);
+    let mut state = FormatState::new(context, allocator);
+    let mut buffer = VecBuffer::with_capacity(source.len() / 2, &mut state);
+
+    write!(&mut buffer, FormatSqlRoot { statements: &statements, has_bom });
+
+    let elements = buffer.into_vec();
+    let context = state.into_context();
+
+    let ir = Document::new(elements, Vec::new());
+
+    Ok(Formatted::new(ir, context))
+}
+
+/// Tokenize `source_text` and build the formatter IR for embedding into another
+/// formatter's document (dispatcher path, e.g. sql-in-js).
+///
+/// Unlike [`format()`], this:
+/// - allocates from the session's shared arena and `GroupId` space,
+///   so the IR lives as long as the parent's document
+/// - emits neither a BOM nor the trailing newline (the parent owns the layout
+///   around the embedded part)
+/// - when `template_placeholders` is set, reads `` `PLACEHOLDER-N` `` markers
+///   (see [`crate::TEMPLATE_PLACEHOLDER_PREFIX`]) as interpolations and emits
+///   [`oxc_formatter_core::FormatElement::EmbedPlaceholder`] for them
+///
+/// # Errors
+/// Same as [`format()`].
+pub fn format_to_ir<'a>(
+    session: &FormatSession<'a>,
+    source_text: &str,
+    options: SqlFormatOptions,
+    template_placeholders: bool,
+) -> Result<EmbeddedIr<'a>, OxcDiagnostic> {
+    let (statements, source) = parse(session.allocator(), source_text, template_placeholders)?;
+
+    let context = SqlFormatContext::new(options, source);
+    let mut state = FormatState::new_with_session(context, session.clone());
+    let mut buffer = VecBuffer::new(&mut state);
+
+    write!(&mut buffer, FormatSqlEmbedded { statements: &statements });
+
+    // SQL never collects Tailwind classes.
+    Ok(EmbeddedIr { ir: buffer.into_vec(), tailwind_classes: Vec::new() })
+}
+
+/// Copies the newline-normalized source into the arena so every token slice carries `'a`,
+/// then tokenizes and folds it into statements.
+fn parse<'a>(
+    allocator: &'a Allocator,
+    source_text: &str,
+    template_placeholders: bool,
+) -> Result<(Vec<Statement<'a>>, &'a str), OxcDiagnostic> {
+    let source: &'a str =
+        allocator.alloc_str(&oxc_formatter_core::normalize_newlines(source_text, ['\r']));
+    let tokens = lexer::tokenize(source, template_placeholders)?;
+    Ok((print::parse(tokens)?, source))
+}
+
+/// Emits the statements and the final newline.
+struct FormatSqlRoot<'s, 'a> {
+    statements: &'s [Statement<'a>],
+    has_bom: bool,
+}
+
+impl<'a> Format<'a, SqlFormatContext<'a>> for FormatSqlRoot<'_, 'a> {
+    fn fmt(&self, f: &mut SqlFormatter<'_, 'a>) {
+        if self.has_bom {
+            write!(f, text("\u{feff}"));
+        }
+
+        print::write_statements(self.statements, f);
+
+        // POSIX convention: every formatted file ends with a newline.
+        if !self.statements.is_empty() {
+            write!(f, hard_line_break());
+        }
+    }
+}
+
+/// Emits the statements only; no BOM, no final newline.
+struct FormatSqlEmbedded<'s, 'a> {
+    statements: &'s [Statement<'a>],
+}
+
+impl<'a> Format<'a, SqlFormatContext<'a>> for FormatSqlEmbedded<'_, 'a> {
+    fn fmt(&self, f: &mut SqlFormatter<'_, 'a>) {
+        print::write_statements(self.statements, f);
+    }
+}
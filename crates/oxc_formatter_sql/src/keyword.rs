@@ -0,0 +1,178 @@
+//! Keyword tables.
+//!
+//! The formatter never needs to know which dialect it is looking at:
+//! the keyword set is the reserved words common to PostgreSQL, MySQL, SQLite and SQL Server,
+//! and the clause table lists the keywords that start a new line.
+
+/// Words re-cased by [`crate::KeywordCase`], in upper case.
+///
+/// Function names (`count`, `coalesce`, ...) and type names are deliberately absent:
+/// they are identifiers as far as the casing option is concerned.
+const KEYWORDS: &[&str] = &[
+    "ADD",
+    "ALL",
+    "ALTER",
+    "AND",
+    "ANY",
+    "AS",
+    "ASC",
+    "BEGIN",
+    "BETWEEN",
+    "BY",
+    "CASCADE",
+    "CASE",
+    "CHECK",
+    "COLLATE",
+    "COLUMN",
+    "COMMIT",
+    "CONFLICT",
+    "CONSTRAINT",
+    "CREATE",
+    "CROSS",
+    "DEFAULT",
+    "DELETE",
+    "DESC",
+    "DISTINCT",
+    "DO",
+    "DROP",
+    "DUPLICATE",
+    "ELSE",
+    "END",
+    "ESCAPE",
+    "EXCEPT",
+    "EXISTS",
+    "FALSE",
+    "FETCH",
+    "FILTER",
+    "FIRST",
+    "FOR",
+    "FOREIGN",
+    "FROM",
+    "FULL",
+    "GROUP",
+    "HAVING",
+    "IF",
+    "ILIKE",
+    "IN",
+    "INDEX",
+    "INNER",
+    "INSERT",
+    "INTERSECT",
+    "INTO",
+    "IS",
+    "JOIN",
+    "KEY",
+    "LAST",
+    "LATERAL",
+    "LEFT",
+    "LIKE",
+    "LIMIT",
+    "NATURAL",
+    "NEXT",
+    "NOT",
+    "NOTHING",
+    "NULL",
+    "NULLS",
+    "OFFSET",
+    "ON",
+    "ONLY",
+    "OR",
+    "ORDER",
+    "OUTER",
+    "OVER",
+    "PARTITION",
+    "PRIMARY",
+    "RECURSIVE",
+    "REFERENCES",
+    "RETURNING",
+    "RIGHT",
+    "ROLLBACK",
+    "ROWS",
+    "SELECT",
+    "SET",
+    "SIMILAR",
+    "TABLE",
+    "THEN",
+    "TO",
+    "TRUE",
+    "TRUNCATE",
+    "UNION",
+    "UNIQUE",
+    "UPDATE",
+    "USING",
+    "VALUES",
+    "VIEW",
+    "WHEN",
+    "WHERE",
+    "WINDOW",
+    "WITH",
+];
+
+/// Returns the upper-case spelling of `word` if it is a keyword.
+pub fn as_keyword(word: &str) -> Option<&'static str> {
+    // Every keyword is ASCII and at most 10 bytes long; anything else can be rejected without a search.
+    if word.len() > 10 || !word.is_ascii() {
+        return None;
+    }
+    KEYWORDS.iter().copied().find(|keyword| keyword.eq_ignore_ascii_case(word))
+}
+
+/// Keyword sequences that start a clause, longest first within each leading word
+/// so that `LEFT OUTER JOIN` wins over `LEFT JOIN`.
+const CLAUSES: &[&[&str]] = &[
+    &["SELECT"],
+    &["FROM"],
+    &["WHERE"],
+    &["GROUP", "BY"],
+    &["HAVING"],
+    &["WINDOW"],
+    &["ORDER", "BY"],
+    &["LIMIT"],
+    &["OFFSET"],
+    &["FETCH"],
+    &["INSERT", "INTO"],
+    &["INSERT"],
+    &["VALUES"],
+    &["UPDATE"],
+    &["SET"],
+    &["DELETE", "FROM"],
+    &["DELETE"],
+    &["RETURNING"],
+    &["WITH", "RECURSIVE"],
+    &["WITH"],
+    &["ON", "CONFLICT"],
+    &["ON", "DUPLICATE", "KEY", "UPDATE"],
+    &["UNION", "ALL"],
+    &["UNION"],
+    &["INTERSECT", "ALL"],
+    &["INTERSECT"],
+    &["EXCEPT", "ALL"],
+    &["EXCEPT"],
+    &["JOIN"],
+    &["INNER", "JOIN"],
+    &["CROSS", "JOIN"],
+    &["NATURAL", "JOIN"],
+    &["LEFT", "OUTER", "JOIN"],
+    &["LEFT", "JOIN"],
+    &["RIGHT", "OUTER", "JOIN"],
+    &["RIGHT", "JOIN"],
+    &["FULL", "OUTER", "JOIN"],
+    &["FULL", "JOIN"],
+];
+
+/// Clauses whose keyword line is the whole clause: the set operators separating two queries.
+pub fn is_set_operator(first_keyword: &str) -> bool {
+    matches!(first_keyword, "UNION" | "INTERSECT" | "EXCEPT")
+}
+
+/// Returns how many of `keywords` (upper-cased keywords, `None` for any other token)
+/// form a clause keyword, or `0` if they don't start one.
+pub fn match_clause(keywords: &[Option<&'static str>]) -> usize {
+    CLAUSES
+        .iter()
+        .find(|clause| {
+            clause.len() <= keywords.len()
+                && clause.iter().zip(keywords).all(|(expected, actual)| Some(*expected) == *actual)
+        })
+        .map_or(0, |clause| clause.len())
+}
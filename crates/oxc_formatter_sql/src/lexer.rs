@@ -0,0 +1,252 @@
+//! A dialect-agnostic SQL tokenizer.
+//!
+//! Only what layout needs is classified; every token keeps its exact source text,
+//! so the printer can re-emit literals, quoted names and comments verbatim.
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+
+use crate::{TEMPLATE_PLACEHOLDER_PREFIX, TEMPLATE_PLACEHOLDER_SUFFIX, keyword::as_keyword};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TokenKind {
+    /// Identifier or keyword.
+    Word,
+    /// `"name"`, `` `name` ``.
+    QuotedIdentifier,
+    /// `'text'`, `$$text$$`, `$tag$text$tag$`.
+    String,
+    Number,
+    /// Bind parameters: `$1`, `?`, `:name`, `@name`.
+    Parameter,
+    /// A sql-in-js `${}` placeholder; the payload is the interpolation index.
+    Placeholder(u32),
+    Operator,
+    Comma,
+    Semicolon,
+    Dot,
+    OpenParen,
+    CloseParen,
+    OpenBracket,
+    CloseBracket,
+    LineComment,
+    BlockComment,
+    /// Any other character, kept with its original spacing.
+    Other,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Token<'a> {
+    pub kind: TokenKind,
+    pub text: &'a str,
+    /// Upper-cased spelling when this word is a keyword (never for qualified names like `t.order`).
+    pub keyword: Option<&'static str>,
+    pub preceded_by_whitespace: bool,
+    pub preceded_by_newline: bool,
+}
+
+impl Token<'_> {
+    pub fn is_comment(&self) -> bool {
+        matches!(self.kind, TokenKind::LineComment | TokenKind::BlockComment)
+    }
+
+    /// Operand-like tokens: two of these written without whitespace between them
+    /// (`prefix_${x}`, `N'text'`) are one operand and must stay glued.
+    pub fn is_operand(&self) -> bool {
+        matches!(
+            self.kind,
+            TokenKind::Word
+                | TokenKind::QuotedIdentifier
+                | TokenKind::String
+                | TokenKind::Number
+                | TokenKind::Parameter
+                | TokenKind::Placeholder(_)
+        )
+    }
+}
+
+/// Operators, longest first.
+const OPERATORS: &[&str] = &[
+    "->>", "#>>", "->", "#>", "@>", "<@", "<=", ">=", "<>", "!=", "||", "&&", "::", "=", "<", ">",
+    "+", "-", "*", "/", "%", "^", "&", "|", "~", "!", "#",
+];
+
+/// Splits `source` into tokens.
+///
+/// `template_placeholders` enables the sql-in-js `` `PLACEHOLDER-N` `` markers;
+/// otherwise a backtick starts a MySQL quoted identifier.
+///
+/// # Errors
+/// Returns an [`OxcDiagnostic`] for unterminated strings, quoted identifiers and block comments.
+pub fn tokenize(
+    source: &str,
+    template_placeholders: bool,
+) -> Result<Vec<Token<'_>>, OxcDiagnostic> {
+    let mut tokens = Vec::with_capacity(source.len() / 4);
+    let bytes = source.as_bytes();
+    let mut pos = 0;
+    let mut preceded_by_whitespace = false;
+    let mut preceded_by_newline = false;
+
+    while pos < source.len() {
+        let rest = &source[pos..];
+        let c = rest.chars().next().unwrap_or_default();
+        if c.is_whitespace() {
+            preceded_by_whitespace = true;
+            preceded_by_newline |= c == '\n';
+            pos += c.len_utf8();
+            continue;
+        }
+
+        let start = pos;
+        let (kind, len) = if let Some((index, len)) =
+            template_placeholders.then(|| placeholder_at(rest)).flatten()
+        {
+            (TokenKind::Placeholder(index), len)
+        } else if rest.starts_with("--") {
+            (TokenKind::LineComment, rest.find('\n').unwrap_or(rest.len()))
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            let end = comment.find("*/").ok_or_else(|| unterminated("comment", start))?;
+            (TokenKind::BlockComment, end + 4)
+        } else {
+            match c {
+                '\'' => (TokenKind::String, quoted_len(rest, '\'', start)?),
+                '"' | '`' => (TokenKind::QuotedIdentifier, quoted_len(rest, c, start)?),
+                ',' => (TokenKind::Comma, 1),
+                ';' => (TokenKind::Semicolon, 1),
+                '(' => (TokenKind::OpenParen, 1),
+                ')' => (TokenKind::CloseParen, 1),
+                '[' => (TokenKind::OpenBracket, 1),
+                ']' => (TokenKind::CloseBracket, 1),
+                '.' if !bytes.get(pos + 1).is_some_and(u8::is_ascii_digit) => (TokenKind::Dot, 1),
+                '$' => dollar(rest, start)?,
+                '?' => (TokenKind::Parameter, 1 + digits_len(&rest[1..])),
+                ':' | '@' if rest[1..].starts_with(is_identifier_start) => {
+                    (TokenKind::Parameter, 1 + identifier_len(&rest[1..]))
+                }
+                '@' if rest[1..].starts_with('@') => {
+                    (TokenKind::Parameter, 2 + identifier_len(&rest[2..]))
+                }
+                c if c.is_ascii_digit() || c == '.' => (TokenKind::Number, number_len(rest)),
+                c if is_identifier_start(c) => (TokenKind::Word, identifier_len(rest)),
+                _ => match OPERATORS.iter().find(|op| rest.starts_with(**op)) {
+                    Some(op) => (TokenKind::Operator, op.len()),
+                    None => (TokenKind::Other, c.len_utf8()),
+                },
+            }
+        };
+
+        pos += len;
+        tokens.push(Token {
+            kind,
+            text: &source[start..pos],
+            keyword: None,
+            preceded_by_whitespace,
+            preceded_by_newline,
+        });
+        preceded_by_whitespace = false;
+        preceded_by_newline = false;
+    }
+
+    // A word is a keyword unless it is part of a qualified name (`t.order`, `order.id`).
+    for i in 0..tokens.len() {
+        if tokens[i].kind == TokenKind::Word
+            && !(i > 0 && tokens[i - 1].kind == TokenKind::Dot)
+            && tokens.get(i + 1).is_none_or(|next| next.kind != TokenKind::Dot)
+        {
+            tokens[i].keyword = as_keyword(tokens[i].text);
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Matches `` `PLACEHOLDER-N` `` at the start of `rest`, returning `(N, length)`.
+fn placeholder_at(rest: &str) -> Option<(u32, usize)> {
+    let after_prefix = rest.strip_prefix(TEMPLATE_PLACEHOLDER_PREFIX)?;
+    let digits = digits_len(after_prefix);
+    let index = after_prefix[..digits].parse().ok()?;
+    after_prefix[digits..].starts_with(TEMPLATE_PLACEHOLDER_SUFFIX).then(|| {
+        (index, TEMPLATE_PLACEHOLDER_PREFIX.len() + digits + TEMPLATE_PLACEHOLDER_SUFFIX.len())
+    })
+}
+
+/// Length of a `quote`-delimited token, where a doubled quote is an escaped quote.
+fn quoted_len(rest: &str, quote: char, start: usize) -> Result<usize, OxcDiagnostic> {
+    let mut chars = rest.char_indices().skip(1).peekable();
+    while let Some((i, c)) = chars.next() {
+        if c == quote {
+            if chars.peek().is_some_and(|&(_, next)| next == quote) {
+                chars.next();
+                continue;
+            }
+            return Ok(i + 1);
+        }
+    }
+    let what = if quote == '\'' { "string" } else { "quoted identifier" };
+    Err(unterminated(what, start))
+}
+
+/// `$1` parameters, `$name` parameters and `$$...$$` / `$tag$...$tag$` dollar-quoted strings.
+fn dollar(rest: &str, start: usize) -> Result<(TokenKind, usize), OxcDiagnostic> {
+    let after = &rest[1..];
+    if after.starts_with(|c: char| c.is_ascii_digit()) {
+        return Ok((TokenKind::Parameter, 1 + digits_len(after)));
+    }
+    let tag_len = if after.starts_with(is_identifier_start) { identifier_len(after) } else { 0 };
+    if !after[tag_len..].starts_with('$') {
+        return Ok(if tag_len == 0 {
+            (TokenKind::Other, 1)
+        } else {
+            (TokenKind::Parameter, 1 + tag_len)
+        });
+    }
+    let delimiter = &rest[..tag_len + 2];
+    let body = &rest[delimiter.len()..];
+    let end = body.find(delimiter).ok_or_else(|| unterminated("dollar-quoted string", start))?;
+    Ok((TokenKind::String, delimiter.len() * 2 + end))
+}
+
+fn is_identifier_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn identifier_len(rest: &str) -> usize {
+    rest.find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$')).unwrap_or(rest.len())
+}
+
+fn digits_len(rest: &str) -> usize {
+    rest.bytes().position(|b| !b.is_ascii_digit()).unwrap_or(rest.len())
+}
+
+/// `1`, `1.5`, `.5`, `1e-3`, `0x1F`.
+fn number_len(rest: &str) -> usize {
+    let bytes = rest.as_bytes();
+    if bytes.len() > 2 && bytes[0] == b'0' && matches!(bytes[1], b'x' | b'X') {
+        return 2 + rest[2..]
+            .bytes()
+            .position(|b| !b.is_ascii_hexdigit())
+            .unwrap_or(rest.len() - 2);
+    }
+    let mut len = digits_len(rest);
+    if bytes.get(len) == Some(&b'.') {
+        len += 1 + digits_len(&rest[len + 1..]);
+    }
+    if matches!(bytes.get(len), Some(b'e' | b'E')) {
+        let mut exponent = len + 1;
+        if matches!(bytes.get(exponent), Some(b'+' | b'-')) {
+            exponent += 1;
+        }
+        let exponent_digits = digits_len(&rest[exponent..]);
+        if exponent_digits > 0 {
+            len = exponent + exponent_digits;
+        }
+    }
+    len
+}
+
+fn unterminated(what: &str, start: usize) -> OxcDiagnostic {
+    let start = u32::try_from(start).unwrap_or(u32::MAX);
+    OxcDiagnostic::error(format!("Syntax error: unterminated {what}"))
+        .with_label(Span::empty(start))
+}
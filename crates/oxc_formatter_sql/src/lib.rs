@@ -0,0 +1,42 @@
+//! SQL formatter built on top of `oxc_formatter_core`.
+//!
+//! SQL has too many dialects to parse faithfully, so this formatter works on a token stream:
+//! it re-cases keywords, puts each clause (`SELECT`, `FROM`, `WHERE`, ...) on its own line,
+//! and breaks a clause's comma-separated items and `AND` / `OR` conditions when they don't fit.
+//! Everything else (identifiers, literals, operators, comments) is kept as written.
+//!
+//! ```ignore
+//! use oxc_allocator::Allocator;
+//! use oxc_formatter_sql::{SqlFormatOptions, format};
+//!
+//! let allocator = Allocator::new();
+//! let formatted = format(&allocator, "select a from t", SqlFormatOptions::default()).unwrap();
+//! let out = formatted.print().unwrap().into_code();
+//! assert_eq!(out, "SELECT a\nFROM t\n");
+//! ```
+
+mod context;
+mod format;
+mod keyword;
+mod lexer;
+mod options;
+mod print;
+
+/// sql-in-js `${}` interpolation marker, opening affix.
+///
+/// The parent (JS) formatter substitutes each interpolation with a
+/// backtick-delimited `` `PLACEHOLDER-N` `` marker before dispatching to [`format_to_ir`],
+/// the same marker css-in-js uses (see `oxc_formatter_css::TEMPLATE_PLACEHOLDER_PREFIX`).
+/// The lexer reads a marker as one opaque operand, so `${table}.id` or `LIMIT ${n}` stay intact.
+///
+/// The producer-side constant lives in `oxc_formatter`'s `embed/sql.rs`
+/// (which doesn't depend on this crate).
+pub const TEMPLATE_PLACEHOLDER_PREFIX: &str = "`PLACEHOLDER-";
+/// Closing affix of the sql-in-js marker (the closing backtick).
+pub const TEMPLATE_PLACEHOLDER_SUFFIX: &str = "`";
+
+pub use crate::{
+    context::SqlFormatContext,
+    format::{format, format_to_ir},
+    options::{KeywordCase, SqlFormatOptions},
+};
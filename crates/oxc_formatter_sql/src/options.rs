@@ -0,0 +1,56 @@
+use oxc_formatter_core::{
+    CoreFormatOptions, FormatOptions, IndentStyle, IndentWidth, LineEnding, LineWidth,
+};
+
+/// Format options for SQL.
+///
+/// Prettier has no SQL language, so only the shared layout options carry over;
+/// [`KeywordCase`] is SQL-specific.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct SqlFormatOptions {
+    pub indent_style: IndentStyle,
+    pub indent_width: IndentWidth,
+    pub line_width: LineWidth,
+    pub line_ending: LineEnding,
+    /// Casing applied to SQL keywords (`select` -> `SELECT`).
+    pub keyword_case: KeywordCase,
+}
+
+/// How SQL keywords are cased.
+///
+/// Only reserved words are affected; identifiers, function names and quoted names keep their casing.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum KeywordCase {
+    /// `SELECT`
+    #[default]
+    Upper,
+    /// `select`
+    Lower,
+    /// Keep the casing from the source.
+    Preserve,
+}
+
+impl FormatOptions for SqlFormatOptions {
+    fn indent_style(&self) -> IndentStyle {
+        self.indent_style
+    }
+
+    fn indent_width(&self) -> IndentWidth {
+        self.indent_width
+    }
+
+    fn line_width(&self) -> LineWidth {
+        self.line_width
+    }
+
+    fn line_ending(&self) -> LineEnding {
+        self.line_ending
+    }
+
+    fn apply_core(&mut self, core: CoreFormatOptions) {
+        self.indent_style = core.indent_style;
+        self.indent_width = core.indent_width;
+        self.line_width = core.line_width;
+        self.line_ending = core.line_ending;
+    }
+}
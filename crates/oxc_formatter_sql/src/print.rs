@@ -0,0 +1,437 @@
+//! Token tree and printer.
+//!
+//! The token stream is folded into statements (split on `;`), each statement into clauses
+//! (split on clause keywords, see [`crate::keyword`]), and every bracket pair into a nested node.
+//! A bracket pair whose content starts with `SELECT` / `WITH` is a subquery and is printed
+//! as indented statements; any other bracket pair is an inline group.
+//!
+//! Layout:
+//! - every clause starts on its own line;
+//! - a clause body stays on the keyword's line when it fits, otherwise it moves to an indented
+//!   block with one comma-separated item or `AND` / `OR` condition per line;
+//! - statements are separated by a blank line.
+
+use cow_utils::CowUtils;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_formatter_core::{
+    Buffer, Format, FormatElement, Formatter,
+    builders::{
+        FormatWith, block_indent, empty_line, group, hard_line_break, indent, soft_block_indent,
+        soft_line_break_or_space, space, text, token,
+    },
+    write,
+};
+use oxc_span::Span;
+
+use crate::{
+    context::SqlFormatContext,
+    keyword::{is_set_operator, match_clause},
+    lexer::{Token, TokenKind},
+    options::KeywordCase,
+};
+
+pub type SqlFormatter<'buf, 'a> = Formatter<'buf, 'a, SqlFormatContext<'a>>;
+
+/// `Format` impl for `&'static str` specialized to `SqlFormatContext`.
+impl<'a> Format<'a, SqlFormatContext<'a>> for &'static str {
+    #[inline]
+    fn fmt(&self, f: &mut SqlFormatter<'_, 'a>) {
+        write!(f, token(self));
+    }
+}
+
+/// Wraps a re-entrant SQL closure in a [`FormatWith`]. The closure's context is
+/// pinned to [`SqlFormatContext`] so call sites don't have to annotate it.
+#[inline]
+const fn format_with<'a, T>(formatter: T) -> FormatWith<T>
+where
+    T: Fn(&mut SqlFormatter<'_, 'a>),
+{
+    FormatWith::new(formatter)
+}
+
+// ---
+
+enum Node<'a> {
+    Token(Token<'a>),
+    /// `( ... )` or `[ ... ]`.
+    Brackets {
+        open: Token<'a>,
+        children: Vec<Node<'a>>,
+        close: Token<'a>,
+    },
+    /// `( SELECT ... )`.
+    Subquery {
+        open: Token<'a>,
+        statements: Vec<Statement<'a>>,
+        close: Token<'a>,
+    },
+}
+
+impl<'a> Node<'a> {
+    fn first_token(&self) -> &Token<'a> {
+        match self {
+            Self::Token(token) => token,
+            Self::Brackets { open, .. } | Self::Subquery { open, .. } => open,
+        }
+    }
+
+    fn last_token(&self) -> &Token<'a> {
+        match self {
+            Self::Token(token) => token,
+            Self::Brackets { close, .. } | Self::Subquery { close, .. } => close,
+        }
+    }
+
+    fn as_token(&self) -> Option<&Token<'a>> {
+        match self {
+            Self::Token(token) => Some(token),
+            _ => None,
+        }
+    }
+
+    fn keyword(&self) -> Option<&'static str> {
+        self.as_token().and_then(|token| token.keyword)
+    }
+}
+
+pub struct Statement<'a> {
+    clauses: Vec<Clause<'a>>,
+    semicolon: bool,
+}
+
+struct Clause<'a> {
+    /// Comments on their own lines before the clause keyword.
+    leading_comments: Vec<Token<'a>>,
+    /// Empty for a statement that does not start with a clause keyword (`CREATE TABLE ...`).
+    keyword: Vec<Token<'a>>,
+    body: Vec<Node<'a>>,
+}
+
+/// Folds the token stream into statements.
+///
+/// # Errors
+/// Returns an [`OxcDiagnostic`] for unbalanced brackets.
+pub fn parse(tokens: Vec<Token<'_>>) -> Result<Vec<Statement<'_>>, OxcDiagnostic> {
+    let mut tokens = tokens.into_iter();
+    let nodes = parse_nodes(&mut tokens, None)?;
+    Ok(split_statements(nodes))
+}
+
+fn parse_nodes<'a>(
+    tokens: &mut impl Iterator<Item = Token<'a>>,
+    close: Option<TokenKind>,
+) -> Result<Vec<Node<'a>>, OxcDiagnostic> {
+    let mut nodes = vec![];
+    while let Some(token) = tokens.next() {
+        let expected_close = match token.kind {
+            TokenKind::OpenParen => TokenKind::CloseParen,
+            TokenKind::OpenBracket => TokenKind::CloseBracket,
+            TokenKind::CloseParen | TokenKind::CloseBracket => {
+                return if close == Some(token.kind) {
+                    // Smuggle the closing token out as the last node.
+                    nodes.push(Node::Token(token));
+                    Ok(nodes)
+                } else {
+                    Err(OxcDiagnostic::error(format!("Syntax error: unexpected `{}`", token.text)))
+                };
+            }
+            _ => {
+                nodes.push(Node::Token(token));
+                continue;
+            }
+        };
+
+        let mut children = parse_nodes(tokens, Some(expected_close))?;
+        let Some(Node::Token(close)) = children.pop() else {
+            unreachable!("`parse_nodes` ends with the closing token when `close` is set")
+        };
+        let is_subquery = token.kind == TokenKind::OpenParen
+            && children
+                .iter()
+                .find(|node| !node.as_token().is_some_and(Token::is_comment))
+                .and_then(Node::keyword)
+                .is_some_and(|keyword| matches!(keyword, "SELECT" | "WITH"));
+        nodes.push(if is_subquery {
+            Node::Subquery { open: token, statements: split_statements(children), close }
+        } else {
+            Node::Brackets { open: token, children, close }
+        });
+    }
+
+    match close {
+        None => Ok(nodes),
+        Some(_) => {
+            Err(OxcDiagnostic::error("Syntax error: unclosed bracket").with_label(Span::empty(0)))
+        }
+    }
+}
+
+fn split_statements(nodes: Vec<Node<'_>>) -> Vec<Statement<'_>> {
+    let mut statements = vec![];
+    let mut current = vec![];
+    for node in nodes {
+        if node.as_token().is_some_and(|token| token.kind == TokenKind::Semicolon) {
+            statements.push(Statement {
+                clauses: split_clauses(std::mem::take(&mut current)),
+                semicolon: true,
+            });
+        } else {
+            current.push(node);
+        }
+    }
+    if !current.is_empty() {
+        statements.push(Statement { clauses: split_clauses(current), semicolon: false });
+    }
+    statements
+}
+
+fn split_clauses(nodes: Vec<Node<'_>>) -> Vec<Clause<'_>> {
+    let keywords: Vec<_> = nodes.iter().map(Node::keyword).collect();
+    let mut clauses: Vec<Clause<'_>> = vec![];
+    let mut current = Clause { leading_comments: vec![], keyword: vec![], body: vec![] };
+    let mut nodes = nodes.into_iter().enumerate();
+
+    while let Some((i, node)) = nodes.next() {
+        let clause_len = match_clause(&keywords[i..]);
+        let only_comments = current.keyword.is_empty()
+            && current.body.iter().all(|node| node.as_token().is_some_and(Token::is_comment));
+        if clause_len > 0 && starts_clause(&keywords, i, clauses.is_empty() && only_comments) {
+            // Own-line comments at the end of a body lead the next clause.
+            let split_at = if only_comments {
+                0
+            } else {
+                current
+                    .body
+                    .iter()
+                    .rposition(|node| {
+                        !node.as_token().is_some_and(|t| t.is_comment() && t.preceded_by_newline)
+                    })
+                    .map_or(0, |i| i + 1)
+            };
+            let leading_comments: Vec<_> = current
+                .body
+                .drain(split_at..)
+                .filter_map(|node| node.as_token().copied())
+                .collect();
+            if current.keyword.is_empty() && current.body.is_empty() {
+                current.leading_comments.extend(leading_comments);
+            } else {
+                clauses.push(std::mem::replace(
+                    &mut current,
+                    Clause { leading_comments, keyword: vec![], body: vec![] },
+                ));
+            }
+            let Node::Token(first) = node else { unreachable!("clause keywords are tokens") };
+            current.keyword.push(first);
+            for _ in 1..clause_len {
+                if let Some((_, Node::Token(token))) = nodes.next() {
+                    current.keyword.push(token);
+                }
+            }
+        } else {
+            current.body.push(node);
+        }
+    }
+    clauses.push(current);
+    clauses
+}
+
+/// Whether the clause keyword at `keywords[i]` really starts a clause in this position.
+fn starts_clause(keywords: &[Option<&'static str>], i: usize, at_start: bool) -> bool {
+    let previous = i.checked_sub(1).and_then(|i| keywords[i]);
+    match keywords[i] {
+        // `WITH TIME ZONE`, `WITH ORDINALITY`, ...: only a leading `WITH` is a CTE.
+        Some("WITH") => at_start,
+        // `ON CONFLICT (id) DO UPDATE SET`
+        Some("UPDATE") => previous != Some("DO"),
+        // `IS DISTINCT FROM`
+        Some("FROM") => previous != Some("DISTINCT"),
+        // `CHARACTER SET utf8` has no keyword before `SET`, but `DO UPDATE SET` does not either.
+        _ => true,
+    }
+}
+
+// ---
+
+pub fn write_statements<'a>(statements: &[Statement<'a>], f: &mut SqlFormatter<'_, 'a>) {
+    for (i, statement) in statements.iter().enumerate() {
+        if i > 0 {
+            write!(f, empty_line());
+        }
+        write_statement(statement, f);
+    }
+}
+
+fn write_statement<'a>(statement: &Statement<'a>, f: &mut SqlFormatter<'_, 'a>) {
+    for (i, clause) in statement.clauses.iter().enumerate() {
+        if i > 0 {
+            write!(f, hard_line_break());
+        }
+        write_clause(clause, f);
+    }
+    if statement.semicolon {
+        let ends_with_line_comment = statement
+            .clauses
+            .last()
+            .and_then(|clause| clause.body.last())
+            .and_then(Node::as_token)
+            .is_some_and(|token| token.kind == TokenKind::LineComment);
+        if ends_with_line_comment {
+            write!(f, hard_line_break());
+        }
+        write!(f, ";");
+    }
+}
+
+fn write_clause<'a>(clause: &Clause<'a>, f: &mut SqlFormatter<'_, 'a>) {
+    for comment in &clause.leading_comments {
+        write_token(comment, f);
+        write!(f, hard_line_break());
+    }
+
+    if clause.keyword.is_empty() {
+        write!(f, group(&format_with(|f| write_body(&clause.body, f))));
+        return;
+    }
+
+    for (i, keyword) in clause.keyword.iter().enumerate() {
+        if i > 0 {
+            write!(f, space());
+        }
+        write_token(keyword, f);
+    }
+    if clause.body.is_empty() {
+        return;
+    }
+    // Set operators separate two queries; whatever follows them on the line is the next query's.
+    if clause.keyword.first().and_then(|t| t.keyword).is_some_and(is_set_operator) {
+        write!(f, [hard_line_break(), group(&format_with(|f| write_body(&clause.body, f)))]);
+        return;
+    }
+    write!(
+        f,
+        group(&indent(&format_with(|f| {
+            write!(f, soft_line_break_or_space());
+            write_body(&clause.body, f);
+        })))
+    );
+}
+
+/// Writes a clause body or bracket content: items joined by the spacing rules,
+/// with a breakable line after each top-level comma and before each `AND` / `OR`.
+fn write_body<'a>(nodes: &[Node<'a>], f: &mut SqlFormatter<'_, 'a>) {
+    let mut previous: Option<&Node<'a>> = None;
+    let mut after_line_comment = false;
+    let mut previous_is_unary = false;
+    let mut in_between = false;
+
+    for node in nodes {
+        let current = node.first_token();
+        if let Some(previous) = previous {
+            let previous_token = previous.last_token();
+            let is_logical = matches!(current.keyword, Some("AND" | "OR")) && !in_between;
+            if after_line_comment {
+                write!(f, hard_line_break());
+            } else if current.is_comment() && !current.preceded_by_newline {
+                // Trailing comment: stays on the line it annotates.
+                write!(f, space());
+            } else if previous_token.kind == TokenKind::Comma || is_logical {
+                write!(f, soft_line_break_or_space());
+            } else if current.is_comment() && current.preceded_by_newline {
+                write!(f, hard_line_break());
+            } else if needs_space(previous_token, current, previous_is_unary) {
+                write!(f, space());
+            }
+        }
+        if current.keyword == Some("AND") {
+            in_between = false;
+        } else if current.keyword == Some("BETWEEN") {
+            in_between = true;
+        }
+
+        write_node(node, f);
+
+        after_line_comment = current.kind == TokenKind::LineComment;
+        previous_is_unary = matches!(node, Node::Token(t) if is_unary_operator(t, previous));
+        if !node.as_token().is_some_and(Token::is_comment) || after_line_comment {
+            previous = Some(node);
+        }
+    }
+}
+
+fn write_node<'a>(node: &Node<'a>, f: &mut SqlFormatter<'_, 'a>) {
+    match node {
+        Node::Token(token) => write_token(token, f),
+        Node::Brackets { open, children, close } => {
+            write_token(open, f);
+            if !children.is_empty() {
+                write!(f, group(&soft_block_indent(&format_with(|f| write_body(children, f)))));
+            }
+            write_token(close, f);
+        }
+        Node::Subquery { open, statements, close } => {
+            write_token(open, f);
+            write!(f, block_indent(&format_with(|f| write_statements(statements, f))));
+            write_token(close, f);
+        }
+    }
+}
+
+fn write_token<'a>(sql_token: &Token<'a>, f: &mut SqlFormatter<'_, 'a>) {
+    match (sql_token.kind, sql_token.keyword) {
+        (TokenKind::Placeholder(index), _) => {
+            f.write_element(FormatElement::EmbedPlaceholder(index));
+        }
+        (_, Some(keyword)) => match f.options().keyword_case {
+            KeywordCase::Upper => write!(f, token(keyword)),
+            KeywordCase::Lower => {
+                let lower = f.allocator().alloc_str(&keyword.cow_to_ascii_lowercase());
+                write!(f, text(lower));
+            }
+            KeywordCase::Preserve => write!(f, text(sql_token.text)),
+        },
+        _ => write!(f, text(sql_token.text)),
+    }
+}
+
+/// `-1`, `NOT -x`, `(+1)`: a sign with no left operand binds to the operand after it.
+fn is_unary_operator(token: &Token<'_>, previous: Option<&Node<'_>>) -> bool {
+    token.kind == TokenKind::Operator
+        && matches!(token.text, "-" | "+" | "~" | "!")
+        && previous.is_none_or(|previous| {
+            let previous = previous.last_token();
+            previous.keyword.is_some()
+                || matches!(
+                    previous.kind,
+                    TokenKind::Operator | TokenKind::Comma | TokenKind::OpenParen
+                )
+        })
+}
+
+/// Whether a space separates `previous` and `current` on one line.
+fn needs_space(previous: &Token<'_>, current: &Token<'_>, previous_is_unary: bool) -> bool {
+    use TokenKind::{
+        CloseBracket, CloseParen, Comma, Dot, OpenBracket, OpenParen, Other, Semicolon,
+    };
+
+    if matches!(current.kind, CloseParen | CloseBracket | Comma | Semicolon)
+        || matches!(previous.kind, OpenParen | OpenBracket | Dot)
+        || current.kind == Dot
+        || previous.text == "::"
+        || current.text == "::"
+        || previous_is_unary
+    {
+        return false;
+    }
+    match current.kind {
+        // `IN (`, `VALUES (` vs. `count(`: keywords are spaced, calls keep the source spacing.
+        OpenParen => previous.keyword.is_some() || current.preceded_by_whitespace,
+        // `arr[1]`
+        OpenBracket => current.preceded_by_whitespace,
+        _ if current.kind == Other || previous.kind == Other => current.preceded_by_whitespace,
+        // `prefix_${x}`, `N'text'`: one operand.
+        _ if previous.is_operand() && current.is_operand() => current.preceded_by_whitespace,
+        _ => true,
+    }
+}
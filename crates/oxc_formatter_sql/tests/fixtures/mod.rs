@@ -0,0 +1,79 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_formatter_sql::{KeywordCase, SqlFormatOptions, format};
+use oxc_formatter_tests::{
+    FixtureFormatter, OptionSet, apply_core_options, build_fixture_snapshot,
+};
+
+struct SqlHarness;
+
+impl FixtureFormatter for SqlHarness {
+    type Options = SqlFormatOptions;
+
+    fn parse_options(json: &OptionSet) -> Self::Options {
+        let mut options = SqlFormatOptions::default();
+        apply_core_options(&mut options, json);
+
+        if let Some(keyword_case) = json.get("keywordCase").and_then(|value| value.as_str()) {
+            options.keyword_case = match keyword_case {
+                "lower" => KeywordCase::Lower,
+                "preserve" => KeywordCase::Preserve,
+                _ => KeywordCase::Upper,
+            };
+        }
+        options
+    }
+
+    fn format(source: &str, _path: &Path, options: &Self::Options) -> String {
+        let allocator = Allocator::default();
+        format(&allocator, source, *options)
+            .expect("format should succeed")
+            .print()
+            .expect("print should succeed")
+            .into_code()
+    }
+}
+
+fn test_file(path: &Path) {
+    // `insta::assert_snapshot!` is invoked from this file so the snapshot's
+    // `source:` header records this consumer crate, not the shared harness.
+    let snap = build_fixture_snapshot::<SqlHarness>(path);
+    insta::with_settings!({
+        snapshot_path => snap.path,
+        prepend_module_to_snapshot => false,
+        snapshot_suffix => "",
+        omit_expression => true,
+    }, {
+        insta::assert_snapshot!(snap.name, snap.body);
+    });
+}
+
+// Include auto-generated test functions from build.rs
+include!(concat!(env!("OUT_DIR"), "/generated_tests.rs"));
+
+// ---
+
+/// Unterminated tokens and unbalanced brackets must surface as `Err` (the oxfmt fallback trigger).
+#[test]
+fn syntax_error_is_err() {
+    let allocator = Allocator::default();
+    for source in ["select 'abc", "select \"abc", "select 1 /* abc", "select (1", "select 1)"] {
+        assert!(
+            format(&allocator, source, SqlFormatOptions::default()).is_err(),
+            "{source:?} should fail to format"
+        );
+    }
+}
+
+/// Empty input stays empty instead of gaining a newline.
+#[test]
+fn empty_input() {
+    let allocator = Allocator::default();
+    let formatted = format(&allocator, " \n", SqlFormatOptions::default())
+        .expect("empty input should format")
+        .print()
+        .expect("print should succeed")
+        .into_code();
+    assert_eq!(formatted, "");
+}
@@ -0,0 +1,6 @@
+-- active users
+select id, -- the key
+  name /* display */
+from users
+-- only active
+where active;
@@ -0,0 +1,3 @@
+insert into users (id, name, email) values ($1, $2, $3) on conflict (id) do update set name = excluded.name, email = excluded.email returning id;
+update accounts set balance = balance - :amount where id = ?;
+delete from sessions where expires_at < now()::timestamp;
@@ -0,0 +1 @@
+Select "Order".id, t.select, -x, data->>'name' From "Order" Join t On t.id = "Order".id Where a Is Distinct From b
@@ -0,0 +1,4 @@
+select u.id, u.first_name, u.last_name, u.email_address, count(o.id) as order_count, sum(o.total) as lifetime_value
+from users u left outer join orders o on o.user_id = u.id
+where u.created_at between '2020-01-01' and '2021-01-01' and u.deleted_at is null or u.status in ('admin', 'owner', 'maintainer', 'billing')
+group by u.id, u.first_name, u.last_name, u.email_address having count(o.id) > 0 order by lifetime_value desc nulls last;
@@ -0,0 +1 @@
+select id, name from users where active = true and age >= 18 order by name asc limit 10
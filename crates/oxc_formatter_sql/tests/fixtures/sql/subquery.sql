@@ -0,0 +1,3 @@
+with recent as (select * from orders where created_at > now() - interval '7 days')
+select * from users where id in (select user_id from recent where total > 100) and exists (select 1 from sessions s where s.user_id = users.id);
+select a from t1 union all select a from t2
@@ -2,4 +2,7 @@ mod builder;
 pub mod parser;
 
 pub use builder::{JSDocBuilder, JSDocBuilderResult};
-pub use parser::{JSDoc, JSDocTag};
+pub use parser::{
+    JSDoc, JSDocFunctionParam, JSDocFunctionType, JSDocRecordField, JSDocTag, JSDocType,
+    JSDocTypeKind, JSDocTypeParseError, parse_type_expression,
+};
@@ -1,5 +1,10 @@
 use oxc_span::Span;
 
+use super::{
+    jsdoc_type::{JSDocType, JSDocTypeParseError},
+    parse_type::parse_type_expression,
+};
+
 /// Represents the raw text of a JSDoc tag *outside* the type expression (`{}`) and tag name (e.g., `@param`),
 /// such as the parameter name or trailing description.
 ///
@@ -29,6 +34,11 @@ impl<'a> JSDocCommentPart<'a> {
         Self { raw: part_content, span }
     }
 
+    /// Returns the raw text, including whitespace and leading `*` of continuation lines.
+    pub fn raw(&self) -> &'a str {
+        self.raw
+    }
+
     // For example, `Span` for the following comment part:
     // ```
     // /**
@@ -224,6 +234,16 @@ impl<'a> JSDocTagTypePart<'a> {
         // +1 for `{`, -1 for `}`
         self.raw[1..self.raw.len() - 1].trim()
     }
+
+    /// Parses the type content into a [`JSDocType`].
+    ///
+    /// # Errors
+    ///
+    /// When the content is not a valid type expression, e.g. `{}` or `{Array<}`.
+    pub fn parsed_type(&self) -> Result<JSDocType<'a>, JSDocTypeParseError> {
+        // +1 for `{`, -1 for `}`
+        parse_type_expression(&self.raw[1..self.raw.len() - 1], self.span.start + 1)
+    }
 }
 
 /// Represents a single component of a type name in a JSDoc tag
@@ -2,8 +2,9 @@ use oxc_span::Span;
 
 use crate::parser::utils;
 
-use super::jsdoc_parts::{
-    JSDocCommentPart, JSDocTagKindPart, JSDocTagTypeNamePart, JSDocTagTypePart,
+use super::{
+    jsdoc_parts::{JSDocCommentPart, JSDocTagKindPart, JSDocTagTypeNamePart, JSDocTagTypePart},
+    jsdoc_type::JSDocType,
 };
 
 // Initially, I attempted to parse into specific structures such as:
@@ -74,6 +75,13 @@ impl<'a> JSDocTag<'a> {
         })
     }
 
+    /// Parsed type expression of [`Self::r#type`].
+    ///
+    /// Returns `None` when there is no type, or it is not a valid type expression.
+    pub fn parsed_type(&self) -> Option<JSDocType<'a>> {
+        self.r#type().and_then(|type_part| type_part.parsed_type().ok())
+    }
+
     /// Use for `@yields`, `@returns`, ...etc.
     /// Comment can be multiline.
     ///
@@ -0,0 +1,105 @@
+use oxc_span::Span;
+
+/// A parsed type expression, such as `Array<string>` in `@param {Array<string>} x`.
+///
+/// Both the [Closure Compiler](https://github.com/google/closure-compiler/wiki/Types-in-the-Closure-Type-System)
+/// syntax used by JSDoc and the TypeScript flavored syntax used by `checkJs` are supported.
+///
+/// Create with [`JSDocTagTypePart::parsed_type`](super::jsdoc_parts::JSDocTagTypePart::parsed_type)
+/// or [`parse_type_expression`](super::parse_type_expression).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JSDocType<'a> {
+    /// The span in the source text, excluding surrounding whitespace.
+    pub span: Span,
+    pub kind: JSDocTypeKind<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JSDocTypeKind<'a> {
+    /// `*`
+    Any,
+    /// `?` on its own
+    Unknown,
+    /// `string`, `null`, `Foo.Bar`, `Foo#bar`, `module:foo/bar`
+    Name(&'a str),
+    /// `'foo'` or `"foo"`, without the quotes
+    StringLiteral(&'a str),
+    /// `1`, `-1.5`
+    NumberLiteral(&'a str),
+    /// `?T` or `T?`
+    Nullable(Box<JSDocType<'a>>),
+    /// `!T` or `T!`
+    NonNullable(Box<JSDocType<'a>>),
+    /// `T=`
+    Optional(Box<JSDocType<'a>>),
+    /// `...T`, or `...` on its own in function parameters
+    Variadic(Option<Box<JSDocType<'a>>>),
+    /// `(T)`
+    Parenthesized(Box<JSDocType<'a>>),
+    /// `A | B`
+    Union(Vec<JSDocType<'a>>),
+    /// `A & B`
+    Intersection(Vec<JSDocType<'a>>),
+    /// `Array<string>`, `Array.<string>`, `Object<string, number>`
+    Generic { base: Box<JSDocType<'a>>, args: Vec<JSDocType<'a>> },
+    /// `string[]`
+    Array(Box<JSDocType<'a>>),
+    /// `T['key']`
+    IndexedAccess { object: Box<JSDocType<'a>>, index: Box<JSDocType<'a>> },
+    /// `[string, number]`
+    Tuple(Vec<JSDocType<'a>>),
+    /// `{ a: number, b?: string, c }`
+    Record(Vec<JSDocRecordField<'a>>),
+    /// `function(this:T, string): number` or `(a: string) => void`
+    Function(Box<JSDocFunctionType<'a>>),
+    /// `import("./foo").Bar`
+    Import { path: &'a str, qualifier: Option<&'a str> },
+    /// `typeof foo`
+    TypeOf(Box<JSDocType<'a>>),
+    /// `keyof T`
+    KeyOf(Box<JSDocType<'a>>),
+}
+
+/// A field of a record type, such as `b?: string` in `{ a: number, b?: string }`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JSDocRecordField<'a> {
+    pub span: Span,
+    /// The key, without quotes for string keys.
+    pub key: &'a str,
+    /// `b?: string`
+    pub optional: bool,
+    /// `None` for fields without a type, such as `c` in `{ a: number, c }`.
+    pub value: Option<JSDocType<'a>>,
+}
+
+/// A function type, such as `function(this:T, string): number` or `(a: string) => void`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JSDocFunctionType<'a> {
+    /// `function(this:T)`
+    pub this_type: Option<JSDocType<'a>>,
+    /// `function(new:T)`
+    pub new_type: Option<JSDocType<'a>>,
+    pub params: Vec<JSDocFunctionParam<'a>>,
+    /// `None` when the return type is omitted, as in `function(string)`.
+    pub return_type: Option<JSDocType<'a>>,
+    /// Whether this is a TypeScript flavored `(a: string) => void` type.
+    pub arrow: bool,
+}
+
+/// A parameter of a function type.
+///
+/// Parameters of Closure Compiler function types have no name: `string` in `function(string)`.
+/// Parameters of arrow function types may have no type: `a` in `(a) => void`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JSDocFunctionParam<'a> {
+    pub span: Span,
+    pub name: Option<&'a str>,
+    pub r#type: Option<JSDocType<'a>>,
+}
+
+/// Error returned when a type expression cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JSDocTypeParseError {
+    pub message: String,
+    pub span: Span,
+}
@@ -1,8 +1,15 @@
 mod jsdoc;
 pub mod jsdoc_parts;
 mod jsdoc_tag;
+mod jsdoc_type;
 mod parse;
+mod parse_type;
 mod utils;
 
 pub use jsdoc::JSDoc;
 pub use jsdoc_tag::JSDocTag;
+pub use jsdoc_type::{
+    JSDocFunctionParam, JSDocFunctionType, JSDocRecordField, JSDocType, JSDocTypeKind,
+    JSDocTypeParseError,
+};
+pub use parse_type::parse_type_expression;
@@ -0,0 +1,753 @@
+use oxc_span::Span;
+use oxc_syntax::identifier::{is_identifier_part, is_identifier_start};
+
+use super::jsdoc_type::{
+    JSDocFunctionParam, JSDocFunctionType, JSDocRecordField, JSDocType, JSDocTypeKind,
+    JSDocTypeParseError,
+};
+
+type Result<T> = std::result::Result<T, JSDocTypeParseError>;
+
+/// Parse a type expression, e.g. the `Array<string>` of `@param {Array<string>} x`.
+///
+/// source_text: Inside of `{HERE}`, NOT includes `{` and `}`
+/// span_start: Global positioned `Span` start for `source_text`
+///
+/// Leading `*`s of continuation lines are skipped, so a type may span multiple lines.
+///
+/// # Errors
+///
+/// When `source_text` is not a valid type expression.
+pub fn parse_type_expression(source_text: &str, span_start: u32) -> Result<JSDocType<'_>> {
+    TypeParser { source: source_text, span_start, pos: 0, prev_end: 0, at_line_start: false }
+        .parse()
+}
+
+struct TypeParser<'a> {
+    source: &'a str,
+    span_start: u32,
+    /// Current byte offset in `source`.
+    pos: usize,
+    /// End of the last consumed token, used as the end of node spans.
+    prev_end: usize,
+    /// Whether only whitespace is between the last line break and `pos`.
+    at_line_start: bool,
+}
+
+impl<'a> TypeParser<'a> {
+    fn parse(mut self) -> Result<JSDocType<'a>> {
+        self.skip_whitespace();
+        if self.at_end() {
+            return Err(self.error("Expected a type"));
+        }
+        let ty = self.parse_optional_type()?;
+        self.skip_whitespace();
+        if !self.at_end() {
+            return Err(self.unexpected());
+        }
+        Ok(ty)
+    }
+
+    /// `T=`, which is only valid at the top level and in function parameters and records.
+    fn parse_optional_type(&mut self) -> Result<JSDocType<'a>> {
+        let start = self.start();
+        let ty = self.parse_union_type()?;
+        if self.peek() == Some('=') && self.peek_at(1) != Some('>') {
+            self.bump(1);
+            return Ok(self.node(start, JSDocTypeKind::Optional(Box::new(ty))));
+        }
+        Ok(ty)
+    }
+
+    /// `A | B`, with an optional leading `|`.
+    fn parse_union_type(&mut self) -> Result<JSDocType<'a>> {
+        let start = self.start();
+        self.eat('|');
+        let first = self.parse_intersection_type()?;
+        if self.peek() != Some('|') {
+            return Ok(first);
+        }
+        let mut types = vec![first];
+        while self.eat('|') {
+            types.push(self.parse_intersection_type()?);
+        }
+        Ok(self.node(start, JSDocTypeKind::Union(types)))
+    }
+
+    /// `A & B`
+    fn parse_intersection_type(&mut self) -> Result<JSDocType<'a>> {
+        let start = self.start();
+        let first = self.parse_prefix_type()?;
+        if self.peek() != Some('&') {
+            return Ok(first);
+        }
+        let mut types = vec![first];
+        while self.eat('&') {
+            types.push(self.parse_prefix_type()?);
+        }
+        Ok(self.node(start, JSDocTypeKind::Intersection(types)))
+    }
+
+    /// `?T`, `!T`, `...T`, `typeof x`, `keyof T`
+    fn parse_prefix_type(&mut self) -> Result<JSDocType<'a>> {
+        let start = self.start();
+        if self.eat_str("...") {
+            let ty =
+                if self.starts_type() { Some(Box::new(self.parse_prefix_type()?)) } else { None };
+            return Ok(self.node(start, JSDocTypeKind::Variadic(ty)));
+        }
+        if self.eat('?') {
+            if !self.starts_type() {
+                return Ok(self.node(start, JSDocTypeKind::Unknown));
+            }
+            let ty = self.parse_prefix_type()?;
+            return Ok(self.node(start, JSDocTypeKind::Nullable(Box::new(ty))));
+        }
+        if self.eat('!') {
+            let ty = self.parse_prefix_type()?;
+            return Ok(self.node(start, JSDocTypeKind::NonNullable(Box::new(ty))));
+        }
+        if self.eat_keyword("typeof") {
+            let ty = self.parse_postfix_type()?;
+            return Ok(self.node(start, JSDocTypeKind::TypeOf(Box::new(ty))));
+        }
+        if self.eat_keyword("keyof") {
+            let ty = self.parse_prefix_type()?;
+            return Ok(self.node(start, JSDocTypeKind::KeyOf(Box::new(ty))));
+        }
+        self.parse_postfix_type()
+    }
+
+    /// `T<U>`, `T.<U>`, `T[]`, `T['key']`, `T?`, `T!`
+    fn parse_postfix_type(&mut self) -> Result<JSDocType<'a>> {
+        let start = self.start();
+        let mut ty = self.parse_primary_type()?;
+        loop {
+            let kind = if self.eat_str(".<") || self.eat('<') {
+                let args = self.parse_list('>', Self::parse_optional_type)?;
+                JSDocTypeKind::Generic { base: Box::new(ty), args }
+            } else if self.eat('[') {
+                if self.eat(']') {
+                    JSDocTypeKind::Array(Box::new(ty))
+                } else {
+                    let index = self.parse_union_type()?;
+                    self.expect(']')?;
+                    JSDocTypeKind::IndexedAccess { object: Box::new(ty), index: Box::new(index) }
+                }
+            } else if self.eat('?') {
+                JSDocTypeKind::Nullable(Box::new(ty))
+            } else if self.eat('!') {
+                JSDocTypeKind::NonNullable(Box::new(ty))
+            } else {
+                return Ok(ty);
+            };
+            ty = self.node(start, kind);
+        }
+    }
+
+    fn parse_primary_type(&mut self) -> Result<JSDocType<'a>> {
+        let start = self.start();
+        let kind = match self.peek() {
+            Some('*') => {
+                self.bump(1);
+                JSDocTypeKind::Any
+            }
+            Some('(') if self.is_arrow_function() => {
+                return self.parse_arrow_function_type();
+            }
+            Some('(') => {
+                self.bump(1);
+                let ty = self.parse_optional_type()?;
+                self.expect(')')?;
+                JSDocTypeKind::Parenthesized(Box::new(ty))
+            }
+            Some('{') => {
+                self.bump(1);
+                JSDocTypeKind::Record(self.parse_record_fields()?)
+            }
+            Some('[') => {
+                self.bump(1);
+                JSDocTypeKind::Tuple(self.parse_list(']', Self::parse_optional_type)?)
+            }
+            Some('\'' | '"') => JSDocTypeKind::StringLiteral(self.parse_string()?),
+            Some('0'..='9' | '-') => JSDocTypeKind::NumberLiteral(self.parse_number()?),
+            Some(c) if is_identifier_start(c) => {
+                let name = self.parse_name_path();
+                match name {
+                    "function" if self.peek() == Some('(') => {
+                        return self.parse_function_type(start);
+                    }
+                    "import" if self.peek() == Some('(') => self.parse_import_type()?,
+                    _ => JSDocTypeKind::Name(name),
+                }
+            }
+            _ => return Err(self.unexpected()),
+        };
+        Ok(self.node(start, kind))
+    }
+
+    /// `Foo`, `Foo.Bar`, `Foo#bar`, `Foo~bar`, `module:foo/bar.Baz`
+    fn parse_name_path(&mut self) -> &'a str {
+        let start = self.pos;
+        self.eat_identifier();
+        if &self.source[start..self.pos] == "module" && self.peek_raw() == Some(':') {
+            self.bump(1);
+            while self.peek_raw().is_some_and(|c| !is_delimiter(c)) {
+                self.bump_char();
+            }
+            return &self.source[start..self.pos];
+        }
+        while matches!(self.peek_raw(), Some('.' | '#' | '~'))
+            && self.peek_raw_at(1).is_some_and(is_identifier_start)
+        {
+            self.bump(1);
+            self.eat_identifier();
+        }
+        &self.source[start..self.pos]
+    }
+
+    /// `import("./foo").Bar`, after `import`
+    fn parse_import_type(&mut self) -> Result<JSDocTypeKind<'a>> {
+        self.expect('(')?;
+        let path = self.parse_string()?;
+        self.expect(')')?;
+        let qualifier = if self.peek_raw() == Some('.') {
+            self.bump(1);
+            if !self.peek_raw().is_some_and(is_identifier_start) {
+                return Err(self.unexpected());
+            }
+            Some(self.parse_name_path())
+        } else {
+            None
+        };
+        Ok(JSDocTypeKind::Import { path, qualifier })
+    }
+
+    /// `function(this:T, new:U, string, number=, ...boolean): void`, after `function`
+    fn parse_function_type(&mut self, start: usize) -> Result<JSDocType<'a>> {
+        self.expect('(')?;
+        let mut this_type = None;
+        let mut new_type = None;
+        let mut params = vec![];
+        loop {
+            if self.eat(')') {
+                break;
+            }
+            if self.eat_labeled("this") {
+                this_type = Some(self.parse_optional_type()?);
+            } else if self.eat_labeled("new") {
+                new_type = Some(self.parse_optional_type()?);
+            } else {
+                let param_start = self.start();
+                let ty = self.parse_optional_type()?;
+                params.push(JSDocFunctionParam {
+                    span: self.span(param_start),
+                    name: None,
+                    r#type: Some(ty),
+                });
+            }
+            if !self.eat(',') {
+                self.expect(')')?;
+                break;
+            }
+        }
+        let return_type = if self.eat(':') { Some(self.parse_prefix_type()?) } else { None };
+        let function = JSDocFunctionType { this_type, new_type, params, return_type, arrow: false };
+        Ok(self.node(start, JSDocTypeKind::Function(Box::new(function))))
+    }
+
+    /// `(a: string, b?: number, ...c: boolean[]) => void`
+    fn parse_arrow_function_type(&mut self) -> Result<JSDocType<'a>> {
+        let start = self.start();
+        self.expect('(')?;
+        let mut this_type = None;
+        let mut params = vec![];
+        loop {
+            if self.eat(')') {
+                break;
+            }
+            let param_start = self.start();
+            let rest = self.eat_str("...");
+            if !self.peek().is_some_and(is_identifier_start) {
+                return Err(self.unexpected());
+            }
+            let name_start = self.pos;
+            self.eat_identifier();
+            let name = &self.source[name_start..self.pos];
+            let optional = self.eat('?');
+            let mut ty = if self.eat(':') { Some(self.parse_union_type()?) } else { None };
+            if name == "this" && !rest {
+                this_type = ty;
+            } else {
+                if optional && let Some(inner) = ty.take() {
+                    ty = Some(JSDocType {
+                        span: inner.span,
+                        kind: JSDocTypeKind::Optional(Box::new(inner)),
+                    });
+                }
+                if rest {
+                    let inner = ty.take().map(Box::new);
+                    ty = Some(self.node(param_start, JSDocTypeKind::Variadic(inner)));
+                }
+                params.push(JSDocFunctionParam {
+                    span: self.span(param_start),
+                    name: Some(name),
+                    r#type: ty,
+                });
+            }
+            if !self.eat(',') {
+                self.expect(')')?;
+                break;
+            }
+        }
+        if !self.eat_str("=>") {
+            return Err(self.error("Expected `=>`"));
+        }
+        let return_type = Some(self.parse_union_type()?);
+        let function =
+            JSDocFunctionType { this_type, new_type: None, params, return_type, arrow: true };
+        Ok(self.node(start, JSDocTypeKind::Function(Box::new(function))))
+    }
+
+    /// `{ a: number, 'b'?: string; c }`, after `{`
+    fn parse_record_fields(&mut self) -> Result<Vec<JSDocRecordField<'a>>> {
+        let mut fields = vec![];
+        loop {
+            if self.eat('}') {
+                return Ok(fields);
+            }
+            let start = self.start();
+            let key = match self.peek() {
+                Some('\'' | '"') => self.parse_string()?,
+                Some('0'..='9') => self.parse_number()?,
+                Some(c) if is_identifier_start(c) => {
+                    let key_start = self.pos;
+                    self.eat_identifier();
+                    &self.source[key_start..self.pos]
+                }
+                _ => return Err(self.unexpected()),
+            };
+            let optional = self.eat('?');
+            let value = if self.eat(':') { Some(self.parse_optional_type()?) } else { None };
+            fields.push(JSDocRecordField { span: self.span(start), key, optional, value });
+            if !self.eat(',') && !self.eat(';') {
+                self.expect('}')?;
+                return Ok(fields);
+            }
+        }
+    }
+
+    /// Items separated by `,` until `close`, allowing a trailing `,`.
+    fn parse_list(
+        &mut self,
+        close: char,
+        mut parse_item: impl FnMut(&mut Self) -> Result<JSDocType<'a>>,
+    ) -> Result<Vec<JSDocType<'a>>> {
+        let mut items = vec![];
+        loop {
+            if self.eat(close) {
+                return Ok(items);
+            }
+            items.push(parse_item(self)?);
+            if !self.eat(',') {
+                self.expect(close)?;
+                return Ok(items);
+            }
+        }
+    }
+
+    /// A string literal, returning the content without quotes.
+    fn parse_string(&mut self) -> Result<&'a str> {
+        let start = self.start();
+        let Some(quote @ ('\'' | '"')) = self.peek() else {
+            return Err(self.error("Expected a string literal"));
+        };
+        self.bump(1);
+        let content_start = self.pos;
+        loop {
+            match self.peek_raw() {
+                None | Some('\n') => {
+                    self.pos = start;
+                    return Err(self.error("Unterminated string literal"));
+                }
+                Some('\\') => {
+                    self.bump(1);
+                    self.bump_char();
+                }
+                Some(c) if c == quote => break,
+                Some(_) => self.bump_char(),
+            }
+        }
+        let content = &self.source[content_start..self.pos];
+        self.bump(1);
+        Ok(content)
+    }
+
+    /// `1`, `-1`, `1.5`
+    fn parse_number(&mut self) -> Result<&'a str> {
+        let start = self.start();
+        if self.peek_raw() == Some('-') {
+            self.bump(1);
+        }
+        let digits_start = self.pos;
+        while self.peek_raw().is_some_and(|c| c.is_ascii_digit() || c == '.') {
+            self.bump(1);
+        }
+        if self.pos == digits_start {
+            self.pos = start;
+            return Err(self.unexpected());
+        }
+        Ok(&self.source[start..self.pos])
+    }
+
+    /// Whether the `(` at the current position starts a `(...) => T` type.
+    fn is_arrow_function(&self) -> bool {
+        let mut depth = 0;
+        let mut chars = self.source[self.pos..].char_indices();
+        let mut quote = None;
+        while let Some((i, c)) = chars.next() {
+            match (quote, c) {
+                (Some(_), '\\') => {
+                    chars.next();
+                }
+                (Some(q), c) if c == q => quote = None,
+                (None, '\'' | '"') => quote = Some(c),
+                (None, '(') => depth += 1,
+                (None, ')') => {
+                    depth -= 1;
+                    if depth == 0 {
+                        let rest = &self.source[self.pos + i + 1..];
+                        return rest.trim_start().starts_with("=>");
+                    }
+                }
+                _ => {}
+            }
+        }
+        false
+    }
+
+    /// Whether the next token can start a type, used to tell `?T` from `?`.
+    fn starts_type(&mut self) -> bool {
+        self.peek().is_some_and(|c| {
+            is_identifier_start(c)
+                || matches!(c, '*' | '?' | '!' | '(' | '{' | '[' | '\'' | '"')
+                || c.is_ascii_digit()
+                || (c == '.' && self.source[self.pos..].starts_with("..."))
+        })
+    }
+
+    // Tokens
+
+    /// Skip whitespace, and `*` at the start of continuation lines.
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek_raw() {
+            if c == '\n' {
+                self.at_line_start = true;
+            } else if c == '*' && self.at_line_start {
+                self.at_line_start = false;
+            } else if !c.is_whitespace() {
+                break;
+            }
+            self.bump_char();
+        }
+        self.at_line_start = false;
+    }
+
+    /// The next character, after whitespace.
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.peek_raw()
+    }
+
+    /// The character `offset` bytes after the next character, after whitespace.
+    fn peek_at(&mut self, offset: usize) -> Option<char> {
+        self.skip_whitespace();
+        self.peek_raw_at(offset)
+    }
+
+    fn peek_raw(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn peek_raw_at(&self, offset: usize) -> Option<char> {
+        self.source.get(self.pos + offset..).and_then(|s| s.chars().next())
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.bump(c.len_utf8());
+            return true;
+        }
+        false
+    }
+
+    fn eat_str(&mut self, s: &str) -> bool {
+        self.skip_whitespace();
+        if self.source[self.pos..].starts_with(s) {
+            self.bump(s.len());
+            return true;
+        }
+        false
+    }
+
+    /// `keyword` followed by something other than an identifier character.
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        self.skip_whitespace();
+        let rest = &self.source[self.pos..];
+        if rest.starts_with(keyword)
+            && rest[keyword.len()..].chars().next().is_some_and(|c| !is_identifier_part(c))
+        {
+            self.bump(keyword.len());
+            return true;
+        }
+        false
+    }
+
+    /// `label:`, as in `function(this:T)`.
+    fn eat_labeled(&mut self, label: &str) -> bool {
+        self.skip_whitespace();
+        let (pos, prev_end) = (self.pos, self.prev_end);
+        if self.eat_keyword(label) && self.eat(':') {
+            return true;
+        }
+        (self.pos, self.prev_end) = (pos, prev_end);
+        false
+    }
+
+    fn eat_identifier(&mut self) {
+        if self.peek_raw().is_some_and(is_identifier_start) {
+            self.bump_char();
+            while self.peek_raw().is_some_and(is_identifier_part) {
+                self.bump_char();
+            }
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<()> {
+        if self.eat(c) {
+            return Ok(());
+        }
+        Err(self.error(&format!("Expected `{c}`")))
+    }
+
+    fn bump(&mut self, len: usize) {
+        self.pos += len;
+        self.prev_end = self.pos;
+    }
+
+    fn bump_char(&mut self) {
+        self.bump(self.peek_raw().map_or(0, char::len_utf8));
+    }
+
+    fn at_end(&self) -> bool {
+        self.pos >= self.source.len()
+    }
+
+    // Nodes
+
+    /// Start of the next token.
+    fn start(&mut self) -> usize {
+        self.skip_whitespace();
+        self.pos
+    }
+
+    fn span(&self, start: usize) -> Span {
+        Span::new(self.offset(start), self.offset(self.prev_end.max(start)))
+    }
+
+    fn node(&self, start: usize, kind: JSDocTypeKind<'a>) -> JSDocType<'a> {
+        JSDocType { span: self.span(start), kind }
+    }
+
+    fn offset(&self, pos: usize) -> u32 {
+        self.span_start + u32::try_from(pos).unwrap_or_default()
+    }
+
+    fn error(&self, message: &str) -> JSDocTypeParseError {
+        let end = self.pos + self.peek_raw().map_or(0, char::len_utf8);
+        JSDocTypeParseError {
+            message: message.to_string(),
+            span: Span::new(self.offset(self.pos), self.offset(end)),
+        }
+    }
+
+    fn unexpected(&self) -> JSDocTypeParseError {
+        match self.peek_raw() {
+            Some(c) => self.error(&format!("Unexpected `{c}`")),
+            None => self.error("Unexpected end of type"),
+        }
+    }
+}
+
+/// Characters which end a `module:` name path.
+fn is_delimiter(c: char) -> bool {
+    c.is_whitespace()
+        || matches!(c, '|' | '&' | ',' | '<' | '>' | '(' | ')' | '{' | '}' | '[' | ']')
+        || matches!(c, '=' | '!' | '?' | ';')
+}
+
+#[cfg(test)]
+#[expect(clippy::literal_string_with_formatting_args)]
+mod test {
+    use oxc_span::Span;
+
+    use super::parse_type_expression;
+    use crate::parser::jsdoc_type::{JSDocType, JSDocTypeKind};
+
+    fn parse(source_text: &str) -> JSDocType<'_> {
+        parse_type_expression(source_text, 0).unwrap_or_else(|e| panic!("{source_text}: {e:?}"))
+    }
+
+    /// Print the type with explicit grouping, to check precedence and coverage at once.
+    fn print(ty: &JSDocType) -> String {
+        let join =
+            |types: &[JSDocType], sep: &str| types.iter().map(print).collect::<Vec<_>>().join(sep);
+        match &ty.kind {
+            JSDocTypeKind::Any => "*".to_string(),
+            JSDocTypeKind::Unknown => "?".to_string(),
+            JSDocTypeKind::Name(name) => (*name).to_string(),
+            JSDocTypeKind::StringLiteral(s) => format!("'{s}'"),
+            JSDocTypeKind::NumberLiteral(n) => (*n).to_string(),
+            JSDocTypeKind::Nullable(t) => format!("nullable({})", print(t)),
+            JSDocTypeKind::NonNullable(t) => format!("non_nullable({})", print(t)),
+            JSDocTypeKind::Optional(t) => format!("optional({})", print(t)),
+            JSDocTypeKind::Variadic(t) => {
+                format!("variadic({})", t.as_deref().map(print).unwrap_or_default())
+            }
+            JSDocTypeKind::Parenthesized(t) => format!("({})", print(t)),
+            JSDocTypeKind::Union(types) => format!("union({})", join(types, ", ")),
+            JSDocTypeKind::Intersection(types) => format!("intersection({})", join(types, ", ")),
+            JSDocTypeKind::Generic { base, args } => {
+                format!("{}<{}>", print(base), join(args, ", "))
+            }
+            JSDocTypeKind::Array(t) => format!("array({})", print(t)),
+            JSDocTypeKind::IndexedAccess { object, index } => {
+                format!("{}[{}]", print(object), print(index))
+            }
+            JSDocTypeKind::Tuple(types) => format!("[{}]", join(types, ", ")),
+            JSDocTypeKind::Record(fields) => {
+                let fields = fields
+                    .iter()
+                    .map(|field| {
+                        let optional = if field.optional { "?" } else { "" };
+                        match &field.value {
+                            Some(value) => format!("{}{optional}: {}", field.key, print(value)),
+                            None => format!("{}{optional}", field.key),
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                format!("{{{}}}", fields.join(", "))
+            }
+            JSDocTypeKind::Function(function) => {
+                let mut params = vec![];
+                if let Some(t) = &function.this_type {
+                    params.push(format!("this: {}", print(t)));
+                }
+                if let Some(t) = &function.new_type {
+                    params.push(format!("new: {}", print(t)));
+                }
+                for param in &function.params {
+                    let ty = param.r#type.as_ref().map(print);
+                    params.push(match (param.name, ty) {
+                        (Some(name), Some(ty)) => format!("{name}: {ty}"),
+                        (Some(name), None) => name.to_string(),
+                        (None, ty) => ty.unwrap_or_default(),
+                    });
+                }
+                let return_type = function.return_type.as_ref().map(print).unwrap_or_default();
+                let kind = if function.arrow { "arrow" } else { "function" };
+                format!("{kind}({}): {return_type}", params.join(", "))
+            }
+            JSDocTypeKind::Import { path, qualifier } => match qualifier {
+                Some(qualifier) => format!("import('{path}').{qualifier}"),
+                None => format!("import('{path}')"),
+            },
+            JSDocTypeKind::TypeOf(t) => format!("typeof({})", print(t)),
+            JSDocTypeKind::KeyOf(t) => format!("keyof({})", print(t)),
+        }
+    }
+
+    #[test]
+    fn parses_type_expressions() {
+        for (source_text, expected) in [
+            ("string", "string"),
+            ("  Foo.Bar  ", "Foo.Bar"),
+            ("Foo#bar", "Foo#bar"),
+            ("Foo~bar", "Foo~bar"),
+            ("module:foo/bar-baz.Qux", "module:foo/bar-baz.Qux"),
+            ("*", "*"),
+            ("?", "?"),
+            ("'a' | \"b\" | 1 | -1.5", "union('a', 'b', 1, -1.5)"),
+            ("?number", "nullable(number)"),
+            ("number?", "nullable(number)"),
+            ("!Object", "non_nullable(Object)"),
+            ("string=", "optional(string)"),
+            ("string|number=", "optional(union(string, number))"),
+            ("...number", "variadic(number)"),
+            ("| a | b", "union(a, b)"),
+            ("A & B | C", "union(intersection(A, B), C)"),
+            ("(A | B)[]", "array((union(A, B)))"),
+            ("Array<string>", "Array<string>"),
+            ("Array.<string>", "Array<string>"),
+            ("Object<string, Array<number>>", "Object<string, Array<number>>"),
+            ("string[][]", "array(array(string))"),
+            ("T['key']", "T['key']"),
+            ("?Array<string>", "nullable(Array<string>)"),
+            ("[string, number=]", "[string, optional(number)]"),
+            ("{}", "{}"),
+            ("{a: number, 'b'?: string; c, }", "{a: number, b?: string, c}"),
+            ("{a: {b: string=}}", "{a: {b: optional(string)}}"),
+            ("function()", "function(): "),
+            (
+                "function(this:T, new:U, string, number=, ...boolean): void",
+                "function(this: T, new: U, string, optional(number), variadic(boolean)): void",
+            ),
+            ("function(...)", "function(variadic()): "),
+            ("function(): string|number", "union(function(): string, number)"),
+            ("() => void", "arrow(): void"),
+            (
+                "(a: string, b?: number, ...c: T[]) => A | B",
+                "arrow(a: string, b: optional(number), c: variadic(array(T))): union(A, B)",
+            ),
+            ("(this: Foo, a) => void", "arrow(this: Foo, a): void"),
+            ("((a: string) => void)[]", "array((arrow(a: string): void))"),
+            ("import('./foo')", "import('./foo')"),
+            ("import(\"./foo\").Bar.Baz", "import('./foo').Bar.Baz"),
+            ("typeof foo", "typeof(foo)"),
+            ("typeof import('./foo')", "typeof(import('./foo'))"),
+            ("keyof T", "keyof(T)"),
+            ("typeofFoo", "typeofFoo"),
+            ("Object<\n * string,\n * number\n * >", "Object<string, number>"),
+        ] {
+            assert_eq!(print(&parse(source_text)), expected, "{source_text}");
+        }
+    }
+
+    #[test]
+    fn spans() {
+        let source_text = " Array<string | number> ";
+        let ty = parse_type_expression(source_text, 10).unwrap();
+        assert_eq!(ty.span, Span::new(11, 33));
+        let JSDocTypeKind::Generic { args, .. } = &ty.kind else { unreachable!() };
+        assert_eq!(args[0].span, Span::new(17, 32));
+        let JSDocTypeKind::Union(types) = &args[0].kind else { unreachable!() };
+        assert_eq!(types[1].span, Span::new(26, 32));
+    }
+
+    #[test]
+    fn errors() {
+        for (source_text, message, span) in [
+            ("", "Expected a type", Span::new(0, 0)),
+            ("  ", "Expected a type", Span::new(2, 2)),
+            ("Array<string", "Expected `>`", Span::new(12, 12)),
+            ("string number", "Unexpected `n`", Span::new(7, 8)),
+            ("{a: }", "Unexpected `}`", Span::new(4, 5)),
+            ("'foo", "Unterminated string literal", Span::new(0, 1)),
+            ("(a: string) =>", "Unexpected end of type", Span::new(14, 14)),
+            ("|", "Unexpected end of type", Span::new(1, 1)),
+        ] {
+            let error = parse_type_expression(source_text, 0).unwrap_err();
+            assert_eq!((error.message.as_str(), error.span), (message, span), "{source_text}");
+        }
+    }
+}
@@ -13,9 +13,12 @@ use tower_lsp_server::{
         DidChangeWorkspaceFoldersParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
         DidSaveTextDocumentParams, DocumentDiagnosticParams, DocumentDiagnosticReport,
         DocumentDiagnosticReportKind, DocumentDiagnosticReportResult, DocumentFormattingParams,
-        ExecuteCommandParams, FullDocumentDiagnosticReport, InitializeParams, InitializeResult,
-        InitializedParams, MessageType, RelatedFullDocumentDiagnosticReport, ServerInfo,
-        TextDocumentContentChangeEvent, TextEdit, Uri, WorkspaceEdit,
+        ExecuteCommandParams, FoldingRange, FoldingRangeParams, FullDocumentDiagnosticReport,
+        InitializeParams, InitializeResult, InitializedParams, MessageType,
+        RelatedFullDocumentDiagnosticReport, SelectionRange, SelectionRangeParams,
+        SemanticTokensDeltaParams, SemanticTokensFullDeltaResult, SemanticTokensParams,
+        SemanticTokensResult, ServerInfo, TextDocumentContentChangeEvent, TextEdit, Uri,
+        WorkspaceEdit,
     },
 };
 use tracing::{debug, error, info, warn};
@@ -749,6 +752,7 @@ impl LanguageServer for Backend {
         };
 
         worker.remove_uri_cache(&params.text_document.uri).await;
+        worker.remove_semantic_tokens(&params.text_document.uri);
 
         // Clone the root URI now so we can use it after dropping the read lock.
         let worker_root_uri = if self.worker_manager.is_single_file_mode() {
@@ -797,13 +801,14 @@ impl LanguageServer for Backend {
             return Ok(None);
         };
 
-        let is_open_document = self.file_system.is_open(&uri);
+        let text = self.file_system.get_document(&uri).text;
 
         let params = crate::CodeActionParams {
+            is_open_document: text.is_some(),
+            text,
             uri,
             range: params.range,
             context: params.context,
-            is_open_document,
         };
 
         let code_actions = worker.get_code_actions_or_commands(&params).await;
@@ -816,14 +821,15 @@ impl LanguageServer for Backend {
     }
 
     /// It will execute the given command with the provided arguments.
-    /// Currently, only the `fixAll` command is supported.
+    /// The workspace edits returned by the tools, which can span multiple files,
+    /// are applied with `workspace/applyEdit`.
     ///
     /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#workspace_executeCommand>
     async fn execute_command(
         &self,
         params: ExecuteCommandParams,
     ) -> Result<Option<serde_json::Value>> {
-        // at the moment we only support `fixAll` command, which returns text-edits.
+        // at the moment all commands return workspace edits.
         // move this check when we support other type of commands
         if !self.capabilities.get().unwrap().workspace_apply_edit {
             return Err(Error::invalid_params("client does not support workspace apply edit"));
@@ -954,6 +960,76 @@ impl LanguageServer for Backend {
             }
         }
     }
+
+    /// It will return the semantic tokens of the document, if the tool provides them.
+    ///
+    /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#semanticTokens_fullRequest>
+    async fn semantic_tokens_full(
+        &self,
+        params: SemanticTokensParams,
+    ) -> Result<Option<SemanticTokensResult>> {
+        let uri = &params.text_document.uri;
+        let Some(worker) = self.worker_manager.get_worker_for_uri(uri).await else {
+            return Ok(None);
+        };
+
+        let document = self.file_system.get_document(uri);
+        Ok(worker.semantic_tokens_full(&document).await.map(SemanticTokensResult::Tokens))
+    }
+
+    /// It will return the changes to the semantic tokens since the `previousResultId`.
+    /// When the previous result is unknown, the full semantic tokens are returned.
+    ///
+    /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#semanticTokens_deltaRequest>
+    async fn semantic_tokens_full_delta(
+        &self,
+        params: SemanticTokensDeltaParams,
+    ) -> Result<Option<SemanticTokensFullDeltaResult>> {
+        let uri = &params.text_document.uri;
+        let Some(worker) = self.worker_manager.get_worker_for_uri(uri).await else {
+            return Ok(None);
+        };
+
+        let document = self.file_system.get_document(uri);
+        Ok(worker.semantic_tokens_delta(&document, &params.previous_result_id).await)
+    }
+
+    /// It will return the folding ranges of the document.
+    ///
+    /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_foldingRange>
+    async fn folding_range(&self, params: FoldingRangeParams) -> Result<Option<Vec<FoldingRange>>> {
+        let uri = &params.text_document.uri;
+        let Some(worker) = self.worker_manager.get_worker_for_uri(uri).await else {
+            return Ok(None);
+        };
+
+        let document = self.file_system.get_document(uri);
+        let ranges = worker.folding_ranges(&document).await;
+        if ranges.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(ranges))
+    }
+
+    /// It will return the selection ranges for the requested positions of the document.
+    ///
+    /// See: <https://microsoft.github.io/language-server-protocol/specifications/specification-current/#textDocument_selectionRange>
+    async fn selection_range(
+        &self,
+        params: SelectionRangeParams,
+    ) -> Result<Option<Vec<SelectionRange>>> {
+        let uri = &params.text_document.uri;
+        let Some(worker) = self.worker_manager.get_worker_for_uri(uri).await else {
+            return Ok(None);
+        };
+
+        let document = self.file_system.get_document(uri);
+        let ranges = worker.selection_ranges(&document, &params.positions).await;
+        if ranges.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(ranges))
+    }
 }
 
 impl Backend {
@@ -11,6 +11,7 @@ mod file_system;
 mod language_id;
 mod options;
 mod position;
+mod semantic_tokens;
 #[cfg(test)]
 mod tests;
 mod tool;
@@ -21,7 +22,7 @@ mod worker_manager;
 
 pub use crate::capabilities::{Capabilities, DiagnosticMode};
 pub use crate::language_id::LanguageId;
-pub use crate::position::offset_to_position;
+pub use crate::position::{offset_to_position, position_to_offset};
 pub use crate::tool::{
     ClientMessage, DiagnosticResult, Tool, ToolBuildResult, ToolBuilder, ToolRestartChanges,
 };
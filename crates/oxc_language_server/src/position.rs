@@ -45,9 +45,50 @@ pub fn offset_to_position(source_text: &str, offset: u32) -> Position {
     Position::new(line, character)
 }
 
+/// Convert an LSP position to a UTF-8 byte offset.
+///
+/// This is the inverse of [`offset_to_position`]. A character offset past the end of its line
+/// is clamped to the line end, and a line past the end of the text is clamped to the text end.
+///
+/// # Panics
+///
+/// Panics if the computed offset does not fit in `u32`.
+pub fn position_to_offset(source_text: &str, position: Position) -> u32 {
+    let bytes = source_text.as_bytes();
+    let mut line = 0;
+    let mut i = 0;
+
+    while line < position.line {
+        match bytes.get(i) {
+            None => break,
+            Some(b'\r') => {
+                i += if bytes.get(i + 1) == Some(&b'\n') { 2 } else { 1 };
+                line += 1;
+            }
+            Some(b'\n') => {
+                i += 1;
+                line += 1;
+            }
+            Some(_) => i += 1,
+        }
+    }
+
+    let mut character = 0;
+    for ch in source_text[i..].chars() {
+        if character >= position.character || matches!(ch, '\r' | '\n') {
+            break;
+        }
+        character += if ch.len_utf16() == 2 { 2 } else { 1 };
+        i += ch.len_utf8();
+    }
+
+    u32::try_from(i).expect("offset must fit in u32")
+}
+
 #[cfg(test)]
 mod tests {
-    use super::offset_to_position;
+    use super::{offset_to_position, position_to_offset};
+    use tower_lsp_server::ls_types::Position;
 
     fn assert_position(source_text: &str, offset: usize, expected: (u32, u32)) {
         let position = offset_to_position(
@@ -147,4 +188,26 @@ mod tests {
     fn utf16_len(s: &str) -> usize {
         s.encode_utf16().count()
     }
+
+    #[test]
+    fn position_to_offset_round_trip() {
+        let source = "£a\r\nb🍄c\rd\n";
+        for (offset, _) in source.char_indices().chain([(source.len(), ' ')]) {
+            let offset = u32::try_from(offset).unwrap();
+            let position = offset_to_position(source, offset);
+            // Offsets between `\r` and `\n` share a position with the line end.
+            if source.as_bytes().get(offset as usize) == Some(&b'\n')
+                && source.as_bytes().get(offset as usize - 1) == Some(&b'\r')
+            {
+                continue;
+            }
+            assert_eq!(position_to_offset(source, position), offset);
+        }
+    }
+
+    #[test]
+    fn position_to_offset_clamps() {
+        assert_eq!(position_to_offset("ab\ncd", Position::new(0, 10)), 2);
+        assert_eq!(position_to_offset("ab\ncd", Position::new(5, 0)), 5);
+    }
 }
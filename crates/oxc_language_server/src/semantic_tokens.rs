@@ -0,0 +1,165 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicU64, Ordering},
+};
+
+use tower_lsp_server::ls_types::{
+    SemanticToken, SemanticTokens, SemanticTokensDelta, SemanticTokensEdit,
+    SemanticTokensFullDeltaResult, Uri,
+};
+
+use crate::ConcurrentHashMap;
+
+/// Number of integers a single [`SemanticToken`] is encoded into.
+const TOKEN_SIZE: u32 = 5;
+
+/// Remembers the last semantic tokens sent for each document,
+/// so `textDocument/semanticTokens/full/delta` requests can be answered with edits.
+#[derive(Default)]
+pub struct SemanticTokensCache {
+    results: ConcurrentHashMap<Uri, (String, Arc<[SemanticToken]>)>,
+    next_result_id: AtomicU64,
+}
+
+impl SemanticTokensCache {
+    /// Store the tokens for the given URI and return them as a full result.
+    pub fn full(&self, uri: &Uri, tokens: Vec<SemanticToken>) -> SemanticTokens {
+        let result_id = self.store(uri, &tokens);
+        SemanticTokens { result_id: Some(result_id), data: tokens }
+    }
+
+    /// Store the tokens for the given URI and return the edits from the previous result.
+    /// When the previous result is unknown, the full tokens are returned instead.
+    pub fn delta(
+        &self,
+        uri: &Uri,
+        previous_result_id: &str,
+        tokens: Vec<SemanticToken>,
+    ) -> SemanticTokensFullDeltaResult {
+        let previous = self
+            .results
+            .pin()
+            .get(uri)
+            .filter(|(result_id, _)| result_id == previous_result_id)
+            .map(|(_, previous)| Arc::clone(previous));
+
+        let Some(previous) = previous else {
+            return SemanticTokensFullDeltaResult::Tokens(self.full(uri, tokens));
+        };
+
+        let result_id = self.store(uri, &tokens);
+        SemanticTokensFullDeltaResult::TokensDelta(SemanticTokensDelta {
+            result_id: Some(result_id),
+            edits: diff_tokens(&previous, &tokens).into_iter().collect(),
+        })
+    }
+
+    pub fn remove(&self, uri: &Uri) {
+        self.results.pin().remove(uri);
+    }
+
+    fn store(&self, uri: &Uri, tokens: &[SemanticToken]) -> String {
+        let result_id = self.next_result_id.fetch_add(1, Ordering::Relaxed).to_string();
+        self.results.pin().insert(uri.clone(), (result_id.clone(), Arc::from(tokens)));
+        result_id
+    }
+}
+
+/// Compute a single edit replacing the changed middle part of `previous` with the one of `current`.
+/// Returns `None` when both token lists are equal.
+fn diff_tokens(
+    previous: &[SemanticToken],
+    current: &[SemanticToken],
+) -> Option<SemanticTokensEdit> {
+    let prefix = previous.iter().zip(current).take_while(|(a, b)| a == b).count();
+    let suffix = previous[prefix..]
+        .iter()
+        .rev()
+        .zip(current[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let deleted = previous.len() - prefix - suffix;
+    let inserted = &current[prefix..current.len() - suffix];
+    if deleted == 0 && inserted.is_empty() {
+        return None;
+    }
+
+    let to_data_index = |count: usize| u32::try_from(count).unwrap() * TOKEN_SIZE;
+    Some(SemanticTokensEdit {
+        start: to_data_index(prefix),
+        delete_count: to_data_index(deleted),
+        data: if inserted.is_empty() { None } else { Some(inserted.to_vec()) },
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use tower_lsp_server::ls_types::{
+        SemanticToken, SemanticTokensEdit, SemanticTokensFullDeltaResult,
+    };
+
+    use super::{SemanticTokensCache, diff_tokens};
+
+    fn token(delta_line: u32, length: u32) -> SemanticToken {
+        SemanticToken {
+            delta_line,
+            delta_start: 0,
+            length,
+            token_type: 0,
+            token_modifiers_bitset: 0,
+        }
+    }
+
+    #[test]
+    fn test_diff_tokens() {
+        let previous = [token(0, 1), token(1, 2), token(1, 3)];
+
+        assert_eq!(diff_tokens(&previous, &previous), None);
+        assert_eq!(
+            diff_tokens(&previous, &[token(0, 1), token(1, 5), token(1, 3)]),
+            Some(SemanticTokensEdit { start: 5, delete_count: 5, data: Some(vec![token(1, 5)]) })
+        );
+        assert_eq!(
+            diff_tokens(&previous, &[token(0, 1), token(1, 3)]),
+            Some(SemanticTokensEdit { start: 5, delete_count: 5, data: None })
+        );
+        assert_eq!(
+            diff_tokens(&previous, &[token(0, 1), token(1, 2), token(1, 3), token(2, 4)]),
+            Some(SemanticTokensEdit { start: 15, delete_count: 0, data: Some(vec![token(2, 4)]) })
+        );
+        assert_eq!(
+            diff_tokens(&previous, &[]),
+            Some(SemanticTokensEdit { start: 0, delete_count: 15, data: None })
+        );
+    }
+
+    #[test]
+    fn test_delta_requires_previous_result() {
+        let cache = SemanticTokensCache::default();
+        let uri = "file:///path/to/file.js".parse().unwrap();
+
+        let full = cache.full(&uri, vec![token(0, 1)]);
+        let result_id = full.result_id.unwrap();
+
+        let SemanticTokensFullDeltaResult::TokensDelta(delta) =
+            cache.delta(&uri, &result_id, vec![token(0, 2)])
+        else {
+            panic!("expected a delta result");
+        };
+        assert_ne!(delta.result_id.as_deref(), Some(result_id.as_str()));
+        assert_eq!(delta.edits.len(), 1);
+
+        // The old result ID is outdated now, so the full tokens are returned.
+        assert!(matches!(
+            cache.delta(&uri, &result_id, vec![token(0, 2)]),
+            SemanticTokensFullDeltaResult::Tokens(_)
+        ));
+
+        cache.remove(&uri);
+        assert!(matches!(
+            cache.delta(&uri, delta.result_id.as_deref().unwrap(), vec![token(0, 2)]),
+            SemanticTokensFullDeltaResult::Tokens(_)
+        ));
+    }
+}
@@ -218,6 +218,49 @@ impl Tool for FakeTool {
         self.run_diagnostic(document)
     }
 
+    fn semantic_tokens(&self, document: &TextDocument) -> Option<Vec<SemanticToken>> {
+        if !document.uri.as_str().ends_with("semantic.config") {
+            return None;
+        }
+        // one token for each line
+        let text = document.text.as_deref().unwrap_or_default();
+        Some(
+            text.lines()
+                .map(|line| SemanticToken {
+                    delta_line: 1,
+                    delta_start: 0,
+                    length: u32::try_from(line.len()).unwrap(),
+                    token_type: 0,
+                    token_modifiers_bitset: 0,
+                })
+                .collect(),
+        )
+    }
+
+    fn folding_ranges(&self, document: &TextDocument) -> Vec<FoldingRange> {
+        if !document.uri.as_str().ends_with("semantic.config") {
+            return vec![];
+        }
+        vec![FoldingRange { start_line: 0, end_line: 1, ..Default::default() }]
+    }
+
+    fn selection_ranges(
+        &self,
+        document: &TextDocument,
+        positions: &[Position],
+    ) -> Vec<SelectionRange> {
+        if !document.uri.as_str().ends_with("semantic.config") {
+            return vec![];
+        }
+        positions
+            .iter()
+            .map(|position| SelectionRange {
+                range: Range::new(*position, *position),
+                parent: None,
+            })
+            .collect()
+    }
+
     fn remove_uri_cache(&self, uri: &Uri) {
         if let Some(cache_uris) = &self.cache_uris {
             cache_uris.lock().unwrap().retain(|cached_uri| cached_uri != uri);
@@ -600,6 +643,48 @@ fn code_action(id: i64, uri: &str) -> Request {
     Request::build("textDocument/codeAction").id(id).params(json!(params)).finish()
 }
 
+fn semantic_tokens_full(id: i64, uri: &str) -> Request {
+    let params = SemanticTokensParams {
+        text_document: TextDocumentIdentifier { uri: uri.parse().unwrap() },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+    };
+
+    Request::build("textDocument/semanticTokens/full").id(id).params(json!(params)).finish()
+}
+
+fn semantic_tokens_delta(id: i64, uri: &str, previous_result_id: &str) -> Request {
+    let params = SemanticTokensDeltaParams {
+        text_document: TextDocumentIdentifier { uri: uri.parse().unwrap() },
+        previous_result_id: previous_result_id.to_string(),
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+    };
+
+    Request::build("textDocument/semanticTokens/full/delta").id(id).params(json!(params)).finish()
+}
+
+fn folding_range(id: i64, uri: &str) -> Request {
+    let params = FoldingRangeParams {
+        text_document: TextDocumentIdentifier { uri: uri.parse().unwrap() },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+    };
+
+    Request::build("textDocument/foldingRange").id(id).params(json!(params)).finish()
+}
+
+fn selection_range(id: i64, uri: &str, positions: Vec<Position>) -> Request {
+    let params = SelectionRangeParams {
+        text_document: TextDocumentIdentifier { uri: uri.parse().unwrap() },
+        positions,
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+    };
+
+    Request::build("textDocument/selectionRange").id(id).params(json!(params)).finish()
+}
+
 fn test_configuration_request(id: i64) -> Request {
     Request::build("test/configuration").id(id).params(json!(null)).finish()
 }
@@ -636,8 +721,8 @@ mod test_suite {
     use tower_lsp_server::{
         jsonrpc::{Error, ErrorCode, Id, Response},
         ls_types::{
-            ApplyWorkspaceEditResponse, InitializeResult, MessageType, PublishDiagnosticsParams,
-            ServerInfo, WorkspaceEdit, WorkspaceFolder,
+            ApplyWorkspaceEditResponse, InitializeResult, MessageType, Position,
+            PublishDiagnosticsParams, ServerInfo, WorkspaceEdit, WorkspaceFolder,
         },
     };
 
@@ -650,9 +735,11 @@ mod test_suite {
             acknowledge_unregistrations, code_action, create_workspace_manager,
             create_workspace_manager_with_builder, diagnostic, did_change,
             did_change_configuration, did_change_watched_files, did_close, did_open, did_save,
-            execute_command_request, initialize_request, initialize_request_workspace_folders,
-            initialized_notification, response_to_configuration, shutdown_request,
-            test_configuration_request, workspace_folders_changed,
+            execute_command_request, folding_range, initialize_request,
+            initialize_request_workspace_folders, initialized_notification,
+            response_to_configuration, selection_range, semantic_tokens_delta,
+            semantic_tokens_full, shutdown_request, test_configuration_request,
+            workspace_folders_changed,
         },
     };
 
@@ -1765,6 +1852,100 @@ mod test_suite {
         server.shutdown(4).await;
     }
 
+    #[tokio::test]
+    async fn test_semantic_tokens() {
+        let mut server = TestServer::new_initialized(
+            |client| Backend::new(client, server_info(), create_workspace_manager()),
+            initialize_request(InitializeRequestOptions::default()),
+        )
+        .await;
+
+        let file = format!("{WORKSPACE}/semantic.config");
+        server.send_request(did_open(&file, "a\nbb\nccc")).await;
+
+        server.send_request(semantic_tokens_full(3, &file)).await;
+        let response = server.recv_response().await;
+        assert_eq!(response.id(), &Id::Number(3));
+        assert_eq!(
+            response.result(),
+            Some(
+                &json!({ "resultId": "0", "data": [1, 0, 1, 0, 0, 1, 0, 2, 0, 0, 1, 0, 3, 0, 0] })
+            )
+        );
+
+        server.send_request(did_change(&file, "a\nbbbb\nccc")).await;
+        server.send_request(semantic_tokens_delta(4, &file, "0")).await;
+        let response = server.recv_response().await;
+        assert_eq!(response.id(), &Id::Number(4));
+        assert_eq!(
+            response.result(),
+            Some(&json!({
+                "resultId": "1",
+                "edits": [{ "start": 5, "deleteCount": 5, "data": [1, 0, 4, 0, 0] }]
+            }))
+        );
+
+        // unknown previous result, the full tokens are returned
+        server.send_request(semantic_tokens_delta(5, &file, "0")).await;
+        let response = server.recv_response().await;
+        assert_eq!(response.id(), &Id::Number(5));
+        assert_eq!(
+            response.result(),
+            Some(
+                &json!({ "resultId": "2", "data": [1, 0, 1, 0, 0, 1, 0, 4, 0, 0, 1, 0, 3, 0, 0] })
+            )
+        );
+
+        // the tool does not provide tokens for this file
+        let other_file = format!("{WORKSPACE}/file.txt");
+        server.send_request(did_open(&other_file, "some text")).await;
+        server.send_request(semantic_tokens_full(6, &other_file)).await;
+        let response = server.recv_response().await;
+        assert_eq!(response.id(), &Id::Number(6));
+        assert_eq!(response.result(), Some(&Value::Null));
+
+        server.shutdown(7).await;
+    }
+
+    #[tokio::test]
+    async fn test_folding_and_selection_ranges() {
+        let mut server = TestServer::new_initialized(
+            |client| Backend::new(client, server_info(), create_workspace_manager()),
+            initialize_request(InitializeRequestOptions::default()),
+        )
+        .await;
+
+        let file = format!("{WORKSPACE}/semantic.config");
+        server.send_request(did_open(&file, "a\nbb")).await;
+
+        server.send_request(folding_range(3, &file)).await;
+        let response = server.recv_response().await;
+        assert_eq!(response.id(), &Id::Number(3));
+        assert_eq!(response.result(), Some(&json!([{ "startLine": 0, "endLine": 1 }])));
+
+        server.send_request(selection_range(4, &file, vec![Position::new(1, 1)])).await;
+        let response = server.recv_response().await;
+        assert_eq!(response.id(), &Id::Number(4));
+        assert_eq!(
+            response.result(),
+            Some(&json!([{
+                "range": {
+                    "start": { "line": 1, "character": 1 },
+                    "end": { "line": 1, "character": 1 }
+                }
+            }]))
+        );
+
+        let other_file = format!("{WORKSPACE}/file.txt");
+        server.send_request(did_open(&other_file, "some text")).await;
+        server.send_request(folding_range(5, &other_file)).await;
+        let response = server.recv_response().await;
+        assert_eq!(response.id(), &Id::Number(5));
+        assert_eq!(response.result(), Some(&Value::Null));
+
+        server.shutdown(6).await;
+    }
+
     #[tokio::test]
     async fn test_diagnostic_on_open() {
         let mut server = TestServer::new_initialized(
@@ -1,8 +1,8 @@
 use tower_lsp_server::{
     jsonrpc::ErrorCode,
     ls_types::{
-        CodeActionOrCommand, Diagnostic, MessageType, Pattern, ServerCapabilities, TextEdit, Uri,
-        WorkspaceEdit,
+        CodeActionOrCommand, Diagnostic, FoldingRange, MessageType, Pattern, Position,
+        SelectionRange, SemanticToken, ServerCapabilities, TextEdit, Uri, WorkspaceEdit,
     },
 };
 
@@ -133,6 +133,34 @@ pub trait Tool: Send + Sync {
         Ok(Vec::new())
     }
 
+    /// Compute the semantic tokens of the given text document.
+    ///
+    /// The token types and modifiers index into the legend the tool advertised in
+    /// [`ToolBuilder::server_capabilities`]. Delta requests are computed by the server from the
+    /// previously returned tokens, so tools only need to provide the full token list.
+    /// Returns [`None`] when the tool does not provide semantic tokens for the document.
+    fn semantic_tokens(&self, _document: &TextDocument) -> Option<Vec<SemanticToken>> {
+        None
+    }
+
+    /// Compute the folding ranges of the given text document.
+    ///
+    /// Not all tools will implement folding ranges, so the default implementation returns an empty vector.
+    fn folding_ranges(&self, _document: &TextDocument) -> Vec<FoldingRange> {
+        Vec::new()
+    }
+
+    /// Compute the selection ranges of the given text document, one for each of the `positions`.
+    ///
+    /// Not all tools will implement selection ranges, so the default implementation returns an empty vector.
+    fn selection_ranges(
+        &self,
+        _document: &TextDocument,
+        _positions: &[Position],
+    ) -> Vec<SelectionRange> {
+        Vec::new()
+    }
+
     /// Remove internal cache for the given URI, if any.
     fn remove_uri_cache(&self, _uri: &Uri) {
         // Default implementation does nothing.
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use tower_lsp_server::ls_types::{CodeActionContext, Range, Uri};
 
 /// Code-action request data passed from the language-server backend to a tool.
@@ -6,4 +8,6 @@ pub struct CodeActionParams {
     pub range: Range,
     pub context: CodeActionContext,
     pub is_open_document: bool,
+    /// The content of the document when it is open in the editor.
+    pub text: Option<Arc<str>>,
 }
@@ -7,7 +7,8 @@ use tower_lsp_server::{
     jsonrpc::ErrorCode,
     ls_types::{
         CodeActionOrCommand, Diagnostic, DidChangeWatchedFilesRegistrationOptions, FileEvent,
-        FileSystemWatcher, GlobPattern, OneOf, Registration, RelativePattern, TextEdit,
+        FileSystemWatcher, FoldingRange, GlobPattern, OneOf, Position, Registration,
+        RelativePattern, SelectionRange, SemanticTokens, SemanticTokensFullDeltaResult, TextEdit,
         Unregistration, Uri, WatchKind, WorkspaceEdit,
     },
 };
@@ -17,6 +18,7 @@ use crate::{
     CodeActionParams, TextDocument, ToolRestartChanges,
     capabilities::DiagnosticMode,
     file_system::LSPFileSystem,
+    semantic_tokens::SemanticTokensCache,
     tool::{ClientMessage, DiagnosticResult, Tool, ToolBuilder},
 };
 
@@ -48,6 +50,8 @@ pub struct WorkspaceWorker {
     diagnostic_mode: DiagnosticMode,
     // Keep track of published diagnostics to clear them on shutdown (only in push mode)
     published_diagnostics: Mutex<FxHashSet<Uri>>,
+    // Last semantic tokens sent for each document, to answer delta requests
+    semantic_tokens: SemanticTokensCache,
 }
 
 impl WorkspaceWorker {
@@ -66,6 +70,7 @@ impl WorkspaceWorker {
             options: Mutex::new(None),
             diagnostic_mode,
             published_diagnostics: Mutex::new(FxHashSet::default()),
+            semantic_tokens: SemanticTokensCache::default(),
         }
     }
 
@@ -114,6 +119,13 @@ impl WorkspaceWorker {
         }
     }
 
+    /// Remove the last semantic tokens of the given URI.
+    /// Unlike [`remove_uri_cache`](Self::remove_uri_cache), this is only needed when the document is closed,
+    /// because the tokens are the base for the next delta request.
+    pub fn remove_semantic_tokens(&self, uri: &Uri) {
+        self.semantic_tokens.remove(uri);
+    }
+
     /// Common aggregator for tool-provided diagnostics.
     async fn collect_diagnostics_with<F>(
         &self,
@@ -214,6 +226,51 @@ impl WorkspaceWorker {
         tool.run_format(document)
     }
 
+    /// Get the semantic tokens of a file from the current tool.
+    /// Returns `None` if the tool does not provide semantic tokens for the file.
+    pub async fn semantic_tokens_full(
+        &self,
+        document: &TextDocument<'_>,
+    ) -> Option<SemanticTokens> {
+        let tokens = self.tool.read().await.as_ref()?.semantic_tokens(document)?;
+        Some(self.semantic_tokens.full(document.uri, tokens))
+    }
+
+    /// Get the semantic tokens of a file as edits to the result with `previous_result_id`.
+    /// Falls back to the full tokens when the previous result is no longer known.
+    pub async fn semantic_tokens_delta(
+        &self,
+        document: &TextDocument<'_>,
+        previous_result_id: &str,
+    ) -> Option<SemanticTokensFullDeltaResult> {
+        let tokens = self.tool.read().await.as_ref()?.semantic_tokens(document)?;
+        Some(self.semantic_tokens.delta(document.uri, previous_result_id, tokens))
+    }
+
+    /// Get the folding ranges of a file from the current tool.
+    pub async fn folding_ranges(&self, document: &TextDocument<'_>) -> Vec<FoldingRange> {
+        self.tool
+            .read()
+            .await
+            .as_ref()
+            .map(|tool| tool.folding_ranges(document))
+            .unwrap_or_default()
+    }
+
+    /// Get the selection ranges for the given positions of a file from the current tool.
+    pub async fn selection_ranges(
+        &self,
+        document: &TextDocument<'_>,
+        positions: &[Position],
+    ) -> Vec<SelectionRange> {
+        self.tool
+            .read()
+            .await
+            .as_ref()
+            .map(|tool| tool.selection_ranges(document, positions))
+            .unwrap_or_default()
+    }
+
     /// Shutdown the worker and return any necessary changes to be made after shutdown.
     /// This includes clearing diagnostics and unregistering file watchers.
     pub async fn shutdown(
@@ -781,6 +838,7 @@ mod tests {
                 range: Range::default(),
                 context: CodeActionContext::default(),
                 is_open_document: false,
+                text: None,
             })
             .await;
 
@@ -792,6 +850,7 @@ mod tests {
                 range: Range::default(),
                 context: CodeActionContext::default(),
                 is_open_document: false,
+                text: None,
             })
             .await;
 
@@ -0,0 +1,111 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// Expose the compiler version, target, enabled features and dependency versions to
+/// `rust_plugin`, which refuses to load plugins built differently (Rust has no stable ABI).
+fn main() {
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let rustc_version = Command::new(rustc)
+        .arg("--version")
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map_or_else(|| "unknown rustc".to_string(), |version| version.trim().to_string());
+
+    let mut features = env::vars()
+        .filter_map(|(key, _)| key.strip_prefix("CARGO_FEATURE_").map(ToString::to_string))
+        .collect::<Vec<_>>();
+    features.sort_unstable();
+
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let lock_file =
+        manifest_dir.ancestors().map(|dir| dir.join("Cargo.lock")).find(|path| path.is_file());
+    let dependencies = lock_file.as_deref().map_or_else(
+        || "unlocked".to_string(),
+        |path| locked_dependencies(path, env!("CARGO_PKG_NAME")).join("\n"),
+    );
+
+    println!("cargo::rustc-env=OXC_LINTER_RUSTC_VERSION={rustc_version}");
+    println!("cargo::rustc-env=OXC_LINTER_TARGET={}", env::var("TARGET").unwrap());
+    println!("cargo::rustc-env=OXC_LINTER_FEATURES_HASH={:016x}", fnv1a_64(&features.join(",")));
+    println!("cargo::rustc-env=OXC_LINTER_DEPENDENCIES_HASH={:016x}", fnv1a_64(&dependencies));
+    println!("cargo::rerun-if-env-changed=RUSTC");
+    if let Some(lock_file) = lock_file {
+        println!("cargo::rerun-if-changed={}", lock_file.display());
+    }
+}
+
+/// The `name version` of every package `root` depends on, directly or not, in the lock file
+/// at `path`, sorted. Packages of the lock file which `root` doesn't depend on, such as other
+/// dependencies of a plugin, are left out.
+fn locked_dependencies(path: &Path, root: &str) -> Vec<String> {
+    let lock = fs::read_to_string(path).unwrap_or_default();
+
+    // `name version` of each package, and the packages it depends on as written in the lock
+    // file: `name` when only one version is locked, `name version` otherwise.
+    let mut packages: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut versions: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for package in lock.split("[[package]]").skip(1) {
+        let field = |name: &str| {
+            package.lines().find_map(|line| {
+                line.strip_prefix(name)?
+                    .trim_start()
+                    .strip_prefix('=')?
+                    .trim()
+                    .strip_prefix('"')?
+                    .strip_suffix('"')
+                    .map(ToString::to_string)
+            })
+        };
+        let (Some(name), Some(version)) = (field("name"), field("version")) else {
+            continue;
+        };
+        let dependencies = package
+            .split_once("dependencies = [")
+            .and_then(|(_, rest)| rest.split_once(']'))
+            .map(|(list, _)| {
+                list.split(',')
+                    .filter_map(|dependency| {
+                        let dependency = dependency.trim().trim_matches('"');
+                        // Drop the source, as in `name version (registry+https://...)`
+                        let dependency = dependency.split(" (").next()?;
+                        (!dependency.is_empty()).then(|| dependency.to_string())
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        versions.entry(name.clone()).or_default().push(format!("{name} {version}"));
+        packages.insert(format!("{name} {version}"), dependencies);
+    }
+
+    let resolve = |dependency: &str| -> Vec<String> {
+        if packages.contains_key(dependency) {
+            vec![dependency.to_string()]
+        } else {
+            versions.get(dependency).cloned().unwrap_or_default()
+        }
+    };
+
+    let mut seen = BTreeSet::new();
+    let mut stack = resolve(root);
+    while let Some(package) = stack.pop() {
+        if !seen.insert(package.clone()) {
+            continue;
+        }
+        for dependency in packages.get(&package).into_iter().flatten() {
+            stack.extend(resolve(dependency));
+        }
+    }
+    seen.into_iter().collect()
+}
+
+fn fnv1a_64(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
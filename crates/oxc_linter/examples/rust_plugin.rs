@@ -0,0 +1,112 @@
+//! # Rust Plugin Example
+//!
+//! A plugin with two rules:
+//! - `example/no-banned-identifier` reports references to an identifier (`foo` by default).
+//! - `example/no-exclusive-test` reports `.only` tests in test files, such as `it.only(...)`.
+//!   Like the rules of the `jest` plugin, it needs the `jest` or `vitest` plugin to be enabled.
+//!
+//! ## Usage
+//!
+//! Build the plugin with the same compiler and profile as oxlint:
+//! ```bash
+//! cargo build -p oxc_linter --example rust_plugin
+//! ```
+//!
+//! Then load it from `.oxlintrc.json`:
+//! ```json
+//! {
+//!   "plugins": ["jest"],
+//!   "rustPlugins": ["./target/debug/examples/librust_plugin.so"],
+//!   "rules": {
+//!     "example/no-banned-identifier": ["error", { "name": "foo" }],
+//!     "example/no-exclusive-test": "warn"
+//!   }
+//! }
+//! ```
+
+use serde::Deserialize;
+
+use oxc_ast::{AstKind, ast::Expression};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_linter::{
+    LintContext, PossibleJestNode, Rule, RuleCategory, RuleMeta, rust_plugin::RustPluginRegistrar,
+};
+use oxc_semantic::AstNode;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct NoBannedIdentifier {
+    name: String,
+}
+
+impl Default for NoBannedIdentifier {
+    fn default() -> Self {
+        Self { name: "foo".to_string() }
+    }
+}
+
+impl RuleMeta for NoBannedIdentifier {
+    const NAME: &'static str = "no-banned-identifier";
+    const PLUGIN: &'static str = "example";
+    const CATEGORY: RuleCategory = RuleCategory::Restriction;
+    const VERSION: &'static str = "next";
+}
+
+impl Rule for NoBannedIdentifier {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        match value {
+            serde_json::Value::Array(mut options) if !options.is_empty() => {
+                serde_json::from_value(options.swap_remove(0))
+            }
+            _ => Ok(Self::default()),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if let AstKind::IdentifierReference(ident) = node.kind()
+            && ident.name == self.name.as_str()
+        {
+            ctx.diagnostic(
+                OxcDiagnostic::warn(format!("Unexpected reference to `{}`", self.name))
+                    .with_label(ident.span),
+            );
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+struct NoExclusiveTest;
+
+impl RuleMeta for NoExclusiveTest {
+    const NAME: &'static str = "no-exclusive-test";
+    const PLUGIN: &'static str = "example";
+    const CATEGORY: RuleCategory = RuleCategory::Correctness;
+    const VERSION: &'static str = "next";
+}
+
+impl Rule for NoExclusiveTest {
+    fn run_on_jest_node<'a, 'c>(
+        &self,
+        jest_node: &PossibleJestNode<'a, 'c>,
+        ctx: &'c LintContext<'a>,
+    ) {
+        let AstKind::CallExpression(call) = jest_node.node.kind() else { return };
+        let Expression::StaticMemberExpression(callee) = &call.callee else { return };
+        if callee.property.name == "only"
+            && let Expression::Identifier(object) = &callee.object
+            && matches!(object.name.as_str(), "describe" | "it" | "test")
+        {
+            ctx.diagnostic(
+                OxcDiagnostic::warn(format!("Unexpected `{}.only`", object.name))
+                    .with_label(callee.property.span),
+            );
+        }
+    }
+}
+
+fn register(registrar: &mut RustPluginRegistrar) {
+    registrar.register_rule::<NoBannedIdentifier>();
+    registrar.register_rule::<NoExclusiveTest>();
+}
+
+oxc_linter::declare_rust_plugin!("example", register);
@@ -22,12 +22,18 @@ use crate::{
     external_linter::ExternalLinter,
     external_plugin_store::{ExternalOptionsId, ExternalRuleId},
     rules::RULES,
+    rust_plugin::load_rust_plugin,
+    utils::resolve_compat_targets,
 };
 
 use super::{
     Config,
     categories::OxlintCategories,
-    config_store::{ResolvedOxlintOverride, ResolvedOxlintOverrideRules, ResolvedOxlintOverrides},
+    config_store::{
+        NATIVE_TYPE_AWARE_RULES, ResolvedOxlintOverride, ResolvedOxlintOverrideRules,
+        ResolvedOxlintOverrides,
+    },
+    rules::parse_rule_key,
 };
 
 #[must_use = "You dropped your builder without building a Linter! Did you mean to call .build()?"]
@@ -282,6 +288,38 @@ impl ConfigStoreBuilder {
             }
         }
 
+        // Rust plugins run in-process, so unlike JS plugins they don't need the external linter
+        if let Some(rust_plugins) = &oxlintrc.rust_plugins {
+            let mut rust_plugins = rust_plugins.iter().collect_vec();
+            rust_plugins.sort_unstable_by(|a, b| {
+                a.specifier
+                    .cmp(&b.specifier)
+                    .then_with(|| a.name.cmp(&b.name))
+                    .then_with(|| a.config_dir.cmp(&b.config_dir))
+            });
+
+            for entry in rust_plugins {
+                Self::load_rust_plugin(
+                    &entry.config_dir,
+                    &entry.specifier,
+                    entry.name.as_deref(),
+                    external_plugin_store,
+                )?;
+            }
+        }
+
+        if let Some(rule_names) = &oxlintrc.options.native_type_aware_rules {
+            for rule_name in rule_names {
+                let (plugin_name, name) = parse_rule_key(rule_name);
+                if plugin_name != "typescript" || !NATIVE_TYPE_AWARE_RULES.contains(&name.as_str())
+                {
+                    return Err(ConfigBuilderError::UnsupportedNativeTypeAwareRule {
+                        rule_name: rule_name.clone(),
+                    });
+                }
+            }
+        }
+
         let plugins = oxlintrc.plugins.unwrap_or_default();
 
         let rules =
@@ -481,6 +519,18 @@ impl ConfigStoreBuilder {
         }
     }
 
+    /// Resolve the compat targets once for the whole config, looking for a browserslist
+    /// configuration from the config's directory, or the current directory without a config file.
+    fn resolve_compat_targets(&mut self) {
+        let dir = match self.config.path.as_deref().and_then(Path::parent) {
+            Some(dir) if !dir.as_os_str().is_empty() => Some(dir.to_path_buf()),
+            _ => std::env::current_dir().ok(),
+        };
+        let compat = &mut self.config.settings.compat;
+        compat.resolved_targets =
+            Some(resolve_compat_targets(compat.targets.as_ref(), dir.as_deref()));
+    }
+
     /// Builds a [`Config`] from the current state of the builder.
     ///
     /// # Errors
@@ -496,6 +546,15 @@ impl ConfigStoreBuilder {
         // to be taken out.
         let plugins = self.plugins();
 
+        if plugins.contains(LintPlugins::COMPAT)
+            || self
+                .overrides
+                .iter()
+                .any(|o| o.plugins.is_some_and(|p| p.contains(LintPlugins::COMPAT)))
+        {
+            self.resolve_compat_targets();
+        }
+
         let overrides = std::mem::take(&mut self.overrides);
         let resolved_overrides = self.resolve_overrides(overrides, external_plugin_store)?;
 
@@ -711,6 +770,50 @@ impl ConfigStoreBuilder {
             Err(ConfigBuilderError::ReservedExternalPluginName { plugin_name })
         }
     }
+
+    fn load_rust_plugin(
+        resolve_dir: &Path,
+        plugin_specifier: &str,
+        alias: Option<&str>,
+        external_plugin_store: &mut ExternalPluginStore,
+    ) -> Result<(), ConfigBuilderError> {
+        let load_failed = |error: String| ConfigBuilderError::RustPluginLoadFailed {
+            plugin_specifier: plugin_specifier.to_string(),
+            error,
+        };
+
+        // The specifier is a path to the library, relative to the config directory
+        let plugin_path = resolve_dir
+            .join(plugin_specifier)
+            .canonicalize()
+            .map_err(|e| load_failed(e.to_string()))?;
+
+        if external_plugin_store.is_plugin_registered(&plugin_path) {
+            return Ok(());
+        }
+
+        if let Some(alias_name) = alias
+            && !is_normal_plugin_name(alias_name)
+        {
+            return Err(load_failed(format!(
+                "Plugin alias '{alias_name}' is not valid. \
+                 Strip plugin package prefixes, e.g. use 'foo' or '@scope/foo'."
+            )));
+        }
+
+        let plugin = load_rust_plugin(&plugin_path).map_err(load_failed)?;
+
+        let plugin_name = alias.unwrap_or(plugin.name);
+        if LintPlugins::try_from(plugin_name).is_ok() {
+            return Err(load_failed(format!(
+                "Plugin name '{plugin_name}' is reserved for a built-in plugin. \
+                 Load the plugin under a different name, e.g. \
+                 {{ \"name\": \"{plugin_name}-rs\", \"specifier\": \"{plugin_specifier}\" }}."
+            )));
+        }
+
+        external_plugin_store.register_rust_plugin(plugin_path, alias, plugin).map_err(load_failed)
+    }
 }
 
 fn get_name(plugin_name: &str, rule_name: &str) -> CompactStr {
@@ -761,6 +864,11 @@ pub enum ConfigBuilderError {
     NoExternalLinterConfigured {
         plugin_specifier: String,
     },
+    /// A Rust plugin listed in `rustPlugins` could not be loaded.
+    RustPluginLoadFailed {
+        plugin_specifier: String,
+        error: String,
+    },
     ReservedExternalPluginName {
         plugin_name: String,
     },
@@ -779,6 +887,10 @@ pub enum ConfigBuilderError {
     UnsupportedNamedConfig {
         name: String,
     },
+    /// A rule in `options.nativeTypeAwareRules` has no native implementation.
+    UnsupportedNativeTypeAwareRule {
+        rule_name: String,
+    },
     /// A cycle was found while resolving configuration files referenced by `extends`.
     CircularExtends {
         cycle: Vec<PathBuf>,
@@ -820,6 +932,9 @@ impl Display for ConfigBuilderError {
                 )?;
                 Ok(())
             }
+            ConfigBuilderError::RustPluginLoadFailed { plugin_specifier, error } => {
+                write!(f, "Failed to load Rust plugin: {plugin_specifier}\n  {error}")
+            }
             ConfigBuilderError::ReservedExternalPluginName { plugin_name } => {
                 write!(
                     f,
@@ -867,6 +982,17 @@ impl Display for ConfigBuilderError {
                      If this is a file path, add a file extension (e.g., \".json\")."
                 )
             }
+            ConfigBuilderError::UnsupportedNativeTypeAwareRule { rule_name } => {
+                write!(
+                    f,
+                    "`options.nativeTypeAwareRules` contains '{rule_name}', which cannot run natively. \
+                     Supported rules: {}.",
+                    NATIVE_TYPE_AWARE_RULES
+                        .iter()
+                        .map(|name| format!("typescript/{name}"))
+                        .join(", ")
+                )
+            }
             ConfigBuilderError::CircularExtends { cycle, referenced_from } => {
                 writeln!(f, "Circular oxlint config extends detected:")?;
                 write!(f, "{}", cycle.iter().map(|path| path.display()).join("\n -> "))?;
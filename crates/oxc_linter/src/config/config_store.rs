@@ -1,4 +1,5 @@
 use std::{
+    ffi::OsStr,
     path::{Path, PathBuf},
     sync::Arc,
 };
@@ -8,13 +9,24 @@ use rustc_hash::{FxHashMap, FxHashSet};
 use crate::{
     AllowWarnDeny,
     external_plugin_store::{ExternalOptionsId, ExternalPluginStore, ExternalRuleId},
+    loader::{LINT_JSON_EXTENSIONS, LINT_STYLESHEET_EXTENSIONS},
     rules::{RULES, RuleEnum},
 };
 
 use super::{
     GlobSet, LintConfig, LintPlugins, OxlintEnv, OxlintGlobals, categories::OxlintCategories,
+    rules::parse_rule_key,
 };
 
+/// Type-aware rules which can run natively on `oxc_type_checker`, rather than in `tsgolint`,
+/// when selected with `options.nativeTypeAwareRules`.
+pub const NATIVE_TYPE_AWARE_RULES: [&str; 4] = [
+    "await-thenable",
+    "no-floating-promises",
+    "no-misused-promises",
+    "restrict-template-expressions",
+];
+
 // TODO: support `categories` et. al. in overrides.
 #[derive(Debug, Clone)]
 pub struct ResolvedLinterState {
@@ -292,6 +304,8 @@ pub struct ConfigStore {
     base: Config,
     nested_configs: FxHashMap<PathBuf, Config>,
     external_plugin_store: Arc<ExternalPluginStore>,
+    /// [`RuleEnum::id`]s of the type-aware rules which the root config selects to run natively.
+    native_type_aware_rules: FxHashSet<usize>,
 }
 
 impl ConfigStore {
@@ -300,10 +314,31 @@ impl ConfigStore {
         nested_configs: FxHashMap<PathBuf, Config>,
         external_plugin_store: ExternalPluginStore,
     ) -> Self {
+        let native_type_aware_rules = base_config
+            .base
+            .config
+            .options
+            .native_type_aware_rules
+            .as_ref()
+            .map(|names| {
+                let names = names.iter().map(|name| parse_rule_key(name)).collect::<Vec<_>>();
+                RULES
+                    .iter()
+                    .filter(|rule| {
+                        rule.is_tsgolint_rule()
+                            && names.iter().any(|(plugin_name, rule_name)| {
+                                plugin_name == rule.plugin_name() && rule_name == rule.name()
+                            })
+                    })
+                    .map(RuleEnum::id)
+                    .collect()
+            })
+            .unwrap_or_default();
         Self {
             base: base_config,
             nested_configs,
             external_plugin_store: Arc::new(external_plugin_store),
+            native_type_aware_rules,
         }
     }
 
@@ -321,7 +356,9 @@ impl ConfigStore {
                 .base
                 .rules
                 .iter()
-                .filter(|(rule, _)| !rule.is_tsgolint_rule())
+                .filter(|(rule, _)| {
+                    !rule.is_tsgolint_rule() || self.is_native_type_aware_rule(rule)
+                })
                 .map(|(rule, _)| rule.clone())
                 .collect::<FxHashSet<_>>()
         };
@@ -339,7 +376,10 @@ impl ConfigStore {
                 if !severity.is_warn_deny() {
                     continue;
                 }
-                if !type_aware_enabled && rule.is_tsgolint_rule() {
+                if !type_aware_enabled
+                    && rule.is_tsgolint_rule()
+                    && !self.is_native_type_aware_rule(rule)
+                {
                     continue;
                 }
                 builtin_rules.insert(rule.clone());
@@ -368,6 +408,12 @@ impl ConfigStore {
         self.base.base.config.options.type_aware.unwrap_or(false)
     }
 
+    /// Whether `rule` is a type-aware rule which the root config selects to run natively,
+    /// in the regular linter, rather than in `tsgolint`.
+    pub fn is_native_type_aware_rule(&self, rule: &RuleEnum) -> bool {
+        self.native_type_aware_rules.contains(&rule.id())
+    }
+
     /// Whether type-checking diagnostics are enabled in the root config.
     pub fn type_check_enabled(&self) -> bool {
         self.base.base.config.options.type_check.unwrap_or(false)
@@ -409,6 +455,29 @@ impl ConfigStore {
         Config::apply_overrides(self.get_related_config(path), path)
     }
 
+    /// Return `false` for a stylesheet or JSON file when the plugin that lints such files
+    /// (`stylelint` or `json`) is not enabled for it. They have no script to lint otherwise,
+    /// so they are not even read.
+    pub fn lints_path(&self, path: &Path) -> bool {
+        let Some(ext) = path.extension().and_then(OsStr::to_str) else {
+            return true;
+        };
+        let plugin = if LINT_STYLESHEET_EXTENSIONS.contains(&ext) {
+            LintPlugins::STYLELINT
+        } else if LINT_JSON_EXTENSIONS.contains(&ext) {
+            LintPlugins::JSON
+        } else {
+            return true;
+        };
+        self.resolve(path).config.plugins.contains(plugin)
+    }
+
+    /// Return `true` when the `svelte` plugin is enabled for the Svelte component at `path`,
+    /// so that its markup is linted in addition to its `<script>` blocks.
+    pub fn lints_svelte_markup(&self, path: &Path) -> bool {
+        self.resolve(path).config.plugins.contains(LintPlugins::SVELTE)
+    }
+
     fn get_nearest_config(&self, path: &Path) -> Option<&Config> {
         // TODO(perf): should we cache the computed nearest config for every directory,
         // so we don't have to recompute it for every file?
@@ -21,7 +21,7 @@ pub use overrides::OxlintOverrides;
 pub use oxlintrc::Oxlintrc;
 pub use plugins::LintPlugins;
 pub use rules::{ESLintRule, OxlintRules, normalize_rule_name};
-pub use settings::{OxlintSettings, ReactVersion, jsdoc::JSDocPluginSettings};
+pub use settings::{CompatTargets, OxlintSettings, ReactVersion, jsdoc::JSDocPluginSettings};
 
 pub use oxc_config::GlobSet;
 
@@ -40,6 +40,19 @@ pub struct OxlintOptions {
     /// Note that this requires the `oxlint-tsgolint` package to be installed.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub type_check: Option<bool>,
+    /// Type-aware rules to run natively, on Oxc's own type inference, rather than in `tsgolint`.
+    ///
+    /// Native rules run in-process and do not require `typeAware` or the `oxlint-tsgolint`
+    /// package. They only use type information available in the file being linted, so may
+    /// report fewer problems than `tsgolint`.
+    ///
+    /// Supported rules: `typescript/await-thenable`, `typescript/no-floating-promises`,
+    /// `typescript/no-misused-promises` and `typescript/restrict-template-expressions`.
+    /// Only supported in the root configuration file.
+    ///
+    /// Note: Native type-aware rules are experimental.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub native_type_aware_rules: Option<Vec<String>>,
     /// Ensure warnings produce a non-zero exit code.
     ///
     /// Equivalent to passing `--deny-warnings` on the CLI.
@@ -71,6 +84,7 @@ impl OxlintOptions {
     pub fn is_empty(&self) -> bool {
         self.type_aware.is_none()
             && self.type_check.is_none()
+            && self.native_type_aware_rules.is_none()
             && self.deny_warnings.is_none()
             && self.max_warnings.is_none()
             && self.report_unused_disable_directives.is_none()
@@ -82,6 +96,10 @@ impl OxlintOptions {
         Self {
             type_aware: self.type_aware.or(other.type_aware),
             type_check: self.type_check.or(other.type_check),
+            native_type_aware_rules: self
+                .native_type_aware_rules
+                .clone()
+                .or_else(|| other.native_type_aware_rules.clone()),
             deny_warnings: self.deny_warnings.or(other.deny_warnings),
             max_warnings: self.max_warnings.or(other.max_warnings),
             report_unused_disable_directives: self
@@ -240,6 +258,29 @@ pub struct Oxlintrc {
     #[serde(rename = "jsPlugins", default, skip_serializing_if = "Option::is_none")]
     #[schemars(schema_with = "external_plugins_schema")]
     pub external_plugins: Option<FxHashSet<ExternalPluginEntry>>,
+    /// Rust plugins, custom rules written in Rust and compiled to a dynamic library.
+    ///
+    /// Each entry is a path to the library, relative to the config file. As with `jsPlugins`,
+    /// an entry can be an object with a `name` to load the plugin under a different name.
+    ///
+    /// A plugin must be built against the same version of `oxc_linter` as Oxlint, with the same
+    /// Rust compiler. Plugins which were not are rejected when loading them.
+    ///
+    /// Note: Rust plugins are experimental and not subject to semver.
+    ///
+    /// Example:
+    ///
+    /// ```json
+    /// {
+    ///   "rustPlugins": ["./target/release/libacme_oxlint_plugin.so"],
+    ///   "rules": {
+    ///     "acme/no-foo": "error"
+    ///   }
+    /// }
+    /// ```
+    #[serde(rename = "rustPlugins", default, skip_serializing_if = "Option::is_none")]
+    #[schemars(schema_with = "external_plugins_schema")]
+    pub rust_plugins: Option<FxHashSet<ExternalPluginEntry>>,
     pub categories: OxlintCategories,
     /// Example
     ///
@@ -464,6 +505,15 @@ impl Oxlintrc {
             (None, None) => None,
         };
 
+        let rust_plugins = match (&self.rust_plugins, &other.rust_plugins) {
+            (Some(self_rust), Some(other_rust)) => {
+                Some(self_rust.iter().chain(other_rust.iter()).cloned().collect())
+            }
+            (Some(self_rust), None) => Some(self_rust.clone()),
+            (None, Some(other_rust)) => Some(other_rust.clone()),
+            (None, None) => None,
+        };
+
         let schema = self.schema.clone().or(other.schema);
         let options = self.options.merge(&other.options);
 
@@ -471,6 +521,7 @@ impl Oxlintrc {
             schema,
             plugins,
             external_plugins,
+            rust_plugins,
             categories,
             rules: OxlintRules::new(rules),
             settings,
@@ -490,8 +541,8 @@ impl Oxlintrc {
     /// participates in the `Hash`/`Eq` implementation of each entry, so changing it
     /// requires rehashing the set.
     pub fn set_config_dir(&mut self, config_dir: &Path) {
-        if let Some(external_plugins) = &mut self.external_plugins {
-            *external_plugins = std::mem::take(external_plugins)
+        for plugins in [&mut self.external_plugins, &mut self.rust_plugins].into_iter().flatten() {
+            *plugins = std::mem::take(plugins)
                 .into_iter()
                 .map(|mut entry| {
                     entry.config_dir = config_dir.to_path_buf();
@@ -758,7 +809,7 @@ mod test {
             serde_json::from_str(r#"{ "plugins": ["typescript", "unicorn"] }"#).unwrap();
         assert_eq!(config.plugins, Some(LintPlugins::TYPESCRIPT | LintPlugins::UNICORN));
         let config: Oxlintrc =
-            serde_json::from_str(r#"{ "plugins": ["typescript", "unicorn", "react", "oxc", "import", "jsdoc", "jest", "vitest", "jsx-a11y", "nextjs", "react-perf", "promise", "node", "vue"] }"#).unwrap();
+            serde_json::from_str(r#"{ "plugins": ["typescript", "unicorn", "react", "oxc", "import", "jsdoc", "jest", "vitest", "jsx-a11y", "nextjs", "react-perf", "promise", "node", "vue", "stylelint", "json", "compat", "svelte", "angular", "testing-library", "playwright", "security"] }"#).unwrap();
         assert_eq!(config.plugins, Some(LintPlugins::all()));
 
         let config: Oxlintrc =
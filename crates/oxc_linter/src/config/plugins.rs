@@ -69,6 +69,7 @@ pub fn plugin_display_name(plugin_name: &str) -> &str {
         "jsx_a11y" => "jsx-a11y",
         "react_perf" => "react-perf",
         "nextjs" => "next",
+        "testing_library" => "testing-library",
         _ => plugin_name,
     }
 }
@@ -88,9 +89,8 @@ pub fn is_normal_plugin_name(plugin_name: &str) -> bool {
 }
 
 bitflags! {
-    // NOTE: may be increased to a u32 if needed
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-    pub struct LintPlugins: u16 {
+    pub struct LintPlugins: u32 {
         /// Not really a plugin. Included for completeness.
         const ESLINT = 0;
         /// `eslint-plugin-react`, plus `eslint-plugin-react-hooks`
@@ -121,6 +121,22 @@ bitflags! {
         const NODE = 1 << 12;
         /// `eslint-plugin-vue`
         const VUE = 1 << 13;
+        /// `stylelint` core rules, for stylesheet files and css-in-js templates
+        const STYLELINT = 1 << 14;
+        /// `json` rules, for JSON files such as `package.json` and `tsconfig.json`
+        const JSON = 1 << 15;
+        /// `eslint-plugin-compat`, plus syntax checks against the same browser targets
+        const COMPAT = 1 << 16;
+        /// `eslint-plugin-svelte`, for Svelte 5 components and `.svelte.js` modules
+        const SVELTE = 1 << 17;
+        /// `@angular-eslint/eslint-plugin`, plus rules for inline component templates
+        const ANGULAR = 1 << 18;
+        /// `eslint-plugin-testing-library`, for files using Testing Library queries and events
+        const TESTING_LIBRARY = 1 << 19;
+        /// `eslint-plugin-playwright`, for Playwright Test end-to-end tests
+        const PLAYWRIGHT = 1 << 20;
+        /// `eslint-plugin-security`, plus `eslint-plugin-no-unsanitized`
+        const SECURITY = 1 << 21;
     }
 }
 
@@ -186,6 +202,14 @@ impl TryFrom<&str> for LintPlugins {
             "promise" => Ok(LintPlugins::PROMISE),
             "node" => Ok(LintPlugins::NODE),
             "vue" => Ok(LintPlugins::VUE),
+            "stylelint" => Ok(LintPlugins::STYLELINT),
+            "json" => Ok(LintPlugins::JSON),
+            "compat" => Ok(LintPlugins::COMPAT),
+            "svelte" => Ok(LintPlugins::SVELTE),
+            "angular" | "angular-eslint" | "@angular-eslint" => Ok(LintPlugins::ANGULAR),
+            "testing-library" | "testing_library" => Ok(LintPlugins::TESTING_LIBRARY),
+            "playwright" => Ok(LintPlugins::PLAYWRIGHT),
+            "security" => Ok(LintPlugins::SECURITY),
             // "eslint" is not really a plugin, so it's 'empty'. This has the added benefit of
             // making it the default value.
             "eslint" => Ok(LintPlugins::ESLINT),
@@ -211,6 +235,14 @@ impl From<LintPlugins> for &'static str {
             LintPlugins::PROMISE => "promise",
             LintPlugins::NODE => "node",
             LintPlugins::VUE => "vue",
+            LintPlugins::STYLELINT => "stylelint",
+            LintPlugins::JSON => "json",
+            LintPlugins::COMPAT => "compat",
+            LintPlugins::SVELTE => "svelte",
+            LintPlugins::ANGULAR => "angular",
+            LintPlugins::TESTING_LIBRARY => "testing-library",
+            LintPlugins::PLAYWRIGHT => "playwright",
+            LintPlugins::SECURITY => "security",
             _ => "",
         }
     }
@@ -282,6 +314,14 @@ impl JsonSchema for LintPlugins {
             Promise,
             Node,
             Vue,
+            Stylelint,
+            Json,
+            Compat,
+            Svelte,
+            Angular,
+            TestingLibrary,
+            Playwright,
+            Security,
         }
 
         let enum_schema = r#gen.subschema_for::<LintPluginOptionsSchema>();
@@ -196,14 +196,32 @@ impl OxlintRules {
                         });
                     }
                 } else {
-                    // Plugin name is not a built-in plugin. If external plugins are enabled,
-                    // try to resolve it as an external JS plugin; otherwise ignore it.
-                    if external_plugin_store.is_enabled() {
+                    // Plugin name is not a built-in plugin. If it's a Rust plugin or external plugins
+                    // are enabled, try to resolve it as a plugin rule; otherwise ignore it.
+                    if external_plugin_store.is_enabled()
+                        || external_plugin_store.has_rust_plugin(plugin_name)
+                    {
                         match external_plugin_store.lookup_rule_id(plugin_name, rule_name) {
                             Ok(external_rule_id) => {
-                                // Add options to store and get options ID
-                                let options_id = external_plugin_store
-                                    .add_options(external_rule_id, &rule_config.config);
+                                // Add options to store and get options ID.
+                                // Rust plugin rules are configured right away, like built-in rules.
+                                let options_id = if external_rule_id.is_rust_plugin_rule() {
+                                    match external_plugin_store
+                                        .configure_rust_rule(external_rule_id, &rule_config.config)
+                                    {
+                                        Ok(options_id) => options_id,
+                                        Err(e) => {
+                                            errors.push(OverrideRulesError::RuleConfiguration {
+                                                rule_name: rule_config.full_name().into_owned(),
+                                                message: e.to_string(),
+                                            });
+                                            continue;
+                                        }
+                                    }
+                                } else {
+                                    external_plugin_store
+                                        .add_options(external_rule_id, &rule_config.config)
+                                };
 
                                 external_rules_for_override
                                     .entry(external_rule_id)
@@ -638,7 +656,7 @@ impl<'de> Deserialize<'de> for OxlintRules {
     }
 }
 
-fn parse_rule_key(name: &str) -> (String, String) {
+pub(super) fn parse_rule_key(name: &str) -> (String, String) {
     // For scoped packages (starting with `@`), split at the last `/` to handle
     // packages like `@eslint-react/naming-convention` with rule `rule-name`.
     // For non-scoped packages, split at the first `/`.
@@ -671,9 +689,12 @@ pub fn normalize_rule_name(name: &str) -> String {
 
 pub(super) fn unalias_plugin_name(plugin_name: &str, rule_name: &str) -> (String, String) {
     let normalized = super::plugins::normalize_plugin_name(plugin_name);
+    let is_no_unsanitized = normalized == "no-unsanitized";
     let plugin_name = match normalized.as_ref() {
         // e.g. "@next/google-font-display", "@next/next/google-font-display"
         "@next" | "@next/next" => "nextjs".to_string(),
+        // e.g. "no-unsanitized/property"
+        "no-unsanitized" => "security".to_string(),
         plugin_name => match LintPlugins::try_from(plugin_name) {
             Ok(LintPlugins::ESLINT) => "eslint".to_string(),
             Ok(plugin) => {
@@ -684,7 +705,16 @@ pub(super) fn unalias_plugin_name(plugin_name: &str, rule_name: &str) -> (String
         },
     };
 
-    (plugin_name, rule_name.to_string())
+    // e.g. "n/no-unsupported-features/node-builtins"
+    let rule_name = if plugin_name == "node" {
+        rule_name.cow_replace('/', "-").into_owned()
+    } else if is_no_unsanitized {
+        format!("no-unsanitized-{rule_name}")
+    } else {
+        rule_name.to_string()
+    };
+
+    (plugin_name, rule_name)
 }
 
 fn parse_rule_value(
@@ -1027,6 +1057,14 @@ mod test {
             "react-perf/jsx-no-new-object-as-prop"
         );
         assert_eq!(super::normalize_rule_name("@next/next/no-img-element"), "next/no-img-element");
+        assert_eq!(
+            super::normalize_rule_name("node/no-unsupported-features/node-builtins"),
+            "node/no-unsupported-features-node-builtins"
+        );
+        assert_eq!(
+            super::normalize_rule_name("no-unsanitized/property"),
+            "security/no-unsanitized-property"
+        );
     }
 
     #[test]
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use oxc_compat::EngineTargets;
+use oxc_str::CompactStr;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Configure Compat plugin rules.
+///
+/// See [eslint-plugin-compat](https://github.com/amilajack/eslint-plugin-compat)'s
+/// configuration for a full reference.
+#[derive(Debug, Clone, Deserialize, Serialize, Default, JsonSchema)]
+pub struct CompatPluginSettings {
+    /// The environments to check the code against.
+    ///
+    /// Either a [browserslist](https://github.com/browserslist/browserslist) query, or a list of
+    /// targets in the format of the transformer's `target` option, such as `es2020` or `chrome90`.
+    ///
+    /// When not set, the `.browserslistrc` or `browserslist` field of `package.json` nearest to the
+    /// config file is used, falling back to the `defaults` query.
+    ///
+    /// Example:
+    ///
+    /// ```json
+    /// {
+    ///   "settings": {
+    ///     "compat": {
+    ///       "targets": ["chrome100", "safari15"]
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    #[serde(default)]
+    pub targets: Option<CompatTargets>,
+
+    /// APIs which are polyfilled, and therefore not reported, such as `structuredClone` or
+    /// `Array.prototype.at`.
+    #[serde(default)]
+    pub polyfills: Vec<CompactStr>,
+
+    /// The engines resolved from `targets` or the browserslist configuration when the config
+    /// is built, so they are not resolved again for every file.
+    #[serde(skip)]
+    #[schemars(skip)]
+    pub(crate) resolved_targets: Option<Result<Arc<EngineTargets>, String>>,
+}
+
+// `resolved_targets` is derived from `targets` and the config's directory.
+impl PartialEq for CompatPluginSettings {
+    fn eq(&self, other: &Self) -> bool {
+        self.targets == other.targets && self.polyfills == other.polyfills
+    }
+}
+
+impl Eq for CompatPluginSettings {}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum CompatTargets {
+    Query(String),
+    List(Vec<String>),
+}
+
+impl CompatTargets {
+    pub fn queries(&self) -> Vec<String> {
+        match self {
+            Self::Query(query) => vec![query.clone()],
+            Self::List(list) => list.clone(),
+        }
+    }
+}
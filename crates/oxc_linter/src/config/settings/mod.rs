@@ -1,3 +1,4 @@
+mod compat;
 mod jest;
 pub mod jsdoc;
 mod jsx_a11y;
@@ -9,11 +10,12 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use self::{
-    jest::JestPluginSettings, jsdoc::JSDocPluginSettings, jsx_a11y::JSXA11yPluginSettings,
-    next::NextPluginSettings, react::ReactPluginSettings, vitest::VitestPluginSettings,
+    compat::CompatPluginSettings, jest::JestPluginSettings, jsdoc::JSDocPluginSettings,
+    jsx_a11y::JSXA11yPluginSettings, next::NextPluginSettings, react::ReactPluginSettings,
+    vitest::VitestPluginSettings,
 };
 
-pub use self::react::ReactVersion;
+pub use self::{compat::CompatTargets, react::ReactVersion};
 
 /// # Oxlint Plugin Settings
 ///
@@ -64,6 +66,9 @@ pub struct OxlintSettings {
 
     #[serde(default)]
     pub jest: JestPluginSettings,
+
+    #[serde(default)]
+    pub compat: CompatPluginSettings,
 }
 
 #[derive(Deserialize, Default)]
@@ -87,6 +92,9 @@ struct WellKnownOxlintSettings {
 
     #[serde(default)]
     pub jest: JestPluginSettings,
+
+    #[serde(default)]
+    pub compat: CompatPluginSettings,
 }
 
 pub type OxlintSettingsJson = serde_json::Map<String, serde_json::Value>;
@@ -112,6 +120,7 @@ impl<'de> Deserialize<'de> for OxlintSettings {
             jsdoc: well_known_settings.jsdoc,
             vitest: well_known_settings.vitest,
             jest: well_known_settings.jest,
+            compat: well_known_settings.compat,
         })
     }
 }
@@ -137,6 +146,7 @@ impl OxlintSettings {
                         settings_to_override.jsdoc = well_known_settings.jsdoc;
                         settings_to_override.vitest = well_known_settings.vitest;
                         settings_to_override.jest = well_known_settings.jest;
+                        settings_to_override.compat = well_known_settings.compat;
                     }
                     Err(e) => {
                         panic!("Failed to parse override settings: {e:?}");
@@ -150,6 +160,7 @@ impl OxlintSettings {
                 settings_to_override.jsdoc = self.jsdoc.clone();
                 settings_to_override.vitest = self.vitest.clone();
                 settings_to_override.jest = self.jest.clone();
+                settings_to_override.compat = self.compat.clone();
             }
         }
     }
@@ -306,6 +317,26 @@ mod test {
         assert_eq!(raw_json["globalSetting"], "value");
     }
 
+    #[test]
+    fn test_parse_compat_settings() {
+        let settings = OxlintSettings::deserialize(&serde_json::json!({
+            "compat": {
+                "targets": "> 0.5%, not dead",
+                "polyfills": ["structuredClone"]
+            }
+        }))
+        .unwrap();
+        assert_eq!(settings.compat.targets.unwrap().queries(), ["> 0.5%, not dead"]);
+        assert_eq!(settings.compat.polyfills, ["structuredClone"]);
+
+        let settings = OxlintSettings::deserialize(&serde_json::json!({
+            "compat": { "targets": ["es2020", "chrome90"] }
+        }))
+        .unwrap();
+        assert_eq!(settings.compat.targets.unwrap().queries(), ["es2020", "chrome90"]);
+        assert!(OxlintSettings::default().compat.targets.is_none());
+    }
+
     #[test]
     fn test_integer_jest_version_settings() {
         let json_value = serde_json::json!({
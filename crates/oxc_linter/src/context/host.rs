@@ -22,14 +22,21 @@ use crate::{
     disable_directives::{DisableDirectives, DisableDirectivesBuilder, RuleCommentType},
     fixer::{Fix, FixKind, Message, PossibleFixes},
     frameworks::FrameworkOptions,
+    loader::{JsonSource, StylesheetSource, TemplateSource},
     module_record::ModuleRecord,
     options::LintOptions,
+    rule::RuleFixMeta,
     rules::RuleEnum,
-    utils::ReactCompilerResults,
+    utils::{
+        JsonDocument, NodeResolver, ReactCompilerResults, Stylesheets, SvelteTemplate, Workspaces,
+    },
 };
 
 #[cfg(not(test))]
-use crate::frameworks::{has_jest_imports, has_vitest_imports, is_jestlike_file};
+use crate::frameworks::{
+    has_jest_imports, has_playwright_imports, has_testing_library_imports, has_vitest_imports,
+    is_jestlike_file, is_playwright_file,
+};
 
 use super::LintContext;
 
@@ -50,9 +57,32 @@ pub struct ContextSubHost<'a> {
     pub(super) parser_tokens: ArenaBox<'a, [Token]>,
     /// Stable source text for this script section
     /// which remains available even after `semantic` is taken while running JS plugins.
+    /// The whole file for a section that stands in for a stylesheet, JSON file or Svelte markup.
     pub(super) source_text: &'a str,
     /// The source text offset of the sub host
     pub(super) source_text_offset: u32,
+    /// The stylesheet file this (empty) script section stands in for, if any.
+    /// See [`StylesheetSource`].
+    pub(super) stylesheet: Option<StylesheetSource<'a>>,
+    /// Lazily-parsed stylesheets of this section, for the `stylelint` rules:
+    /// the whole file for a stylesheet file, the css-in-js templates otherwise.
+    pub(super) stylesheets: OnceCell<Stylesheets<'a>>,
+    /// The JSON file this (empty) script section stands in for, if any.
+    /// See [`JsonSource`].
+    pub(super) json: Option<JsonSource<'a>>,
+    /// Lazily-parsed JSON document of this section, for the `json` rules.
+    pub(super) json_document: OnceCell<Result<JsonDocument<'a>, OxcDiagnostic>>,
+    /// The Svelte component markup this (empty) script section stands in for, if any.
+    /// See [`TemplateSource`].
+    pub(super) template: Option<TemplateSource<'a>>,
+    /// Lazily-scanned Svelte markup of this section, for the `svelte` template rules.
+    pub(super) svelte_template: OnceCell<SvelteTemplate<'a>>,
+    /// The resolver of the `node` rules, shared by the files of a lint run.
+    /// Created on first access when the lint service doesn't provide one.
+    pub(super) node_resolver: OnceCell<Arc<NodeResolver>>,
+    /// The workspaces of the `json` rules, shared by the files of a lint run.
+    /// Created on first access when the lint service doesn't provide them.
+    pub(super) workspaces: OnceCell<Arc<Workspaces>>,
 }
 
 impl<'a> ContextSubHost<'a> {
@@ -75,7 +105,12 @@ impl<'a> ContextSubHost<'a> {
             "`LintContext` depends on `Semantic::cfg`, Build your semantic with cfg enabled(`SemanticBuilder::with_cfg`)."
         );
 
-        let source_text = semantic.source_text();
+        let source_text = options
+            .stylesheet
+            .map(|stylesheet| stylesheet.source_text)
+            .or_else(|| options.json.map(|json| json.source_text))
+            .or_else(|| options.template.map(|template| template.source_text))
+            .unwrap_or_else(|| semantic.source_text());
 
         let disable_directives = DisableDirectivesBuilder::new()
             .with_respect_eslint_disable_directives(options.respect_eslint_disable_directives)
@@ -89,6 +124,14 @@ impl<'a> ContextSubHost<'a> {
             disable_directives,
             framework_options: options.framework_options,
             parser_tokens: options.parser_tokens,
+            stylesheet: options.stylesheet,
+            stylesheets: OnceCell::new(),
+            json: options.json,
+            json_document: OnceCell::new(),
+            template: options.template,
+            svelte_template: OnceCell::new(),
+            node_resolver: options.node_resolver.map(OnceCell::from).unwrap_or_default(),
+            workspaces: options.workspaces.map(OnceCell::from).unwrap_or_default(),
         }
     }
 
@@ -118,6 +161,41 @@ impl<'a> ContextSubHost<'a> {
     pub fn source_text(&self) -> &'a str {
         self.source_text
     }
+
+    /// The stylesheet file this section stands in for.
+    /// Only `stylelint` rules run on such a section.
+    #[inline]
+    pub fn stylesheet(&self) -> Option<StylesheetSource<'a>> {
+        self.stylesheet
+    }
+
+    /// The JSON file this section stands in for.
+    /// Only `json` rules run on such a section.
+    #[inline]
+    pub fn json(&self) -> Option<JsonSource<'a>> {
+        self.json
+    }
+
+    /// The Svelte component markup this section stands in for.
+    /// Only `svelte` rules run on such a section.
+    #[inline]
+    pub fn template(&self) -> Option<TemplateSource<'a>> {
+        self.template
+    }
+
+    /// The only plugin whose rules run on this section,
+    /// if it stands in for a stylesheet, JSON file or Svelte markup rather than a script.
+    pub fn file_plugin(&self) -> Option<&'static str> {
+        if self.stylesheet.is_some() {
+            Some("stylelint")
+        } else if self.json.is_some() {
+            Some("json")
+        } else if self.template.is_some() {
+            Some("svelte")
+        } else {
+            None
+        }
+    }
 }
 
 #[non_exhaustive]
@@ -125,6 +203,11 @@ pub struct ContextSubHostOptions<'a> {
     pub framework_options: FrameworkOptions,
     pub parser_tokens: ArenaBox<'a, [Token]>,
     pub respect_eslint_disable_directives: bool,
+    pub stylesheet: Option<StylesheetSource<'a>>,
+    pub json: Option<JsonSource<'a>>,
+    pub template: Option<TemplateSource<'a>>,
+    pub node_resolver: Option<Arc<NodeResolver>>,
+    pub workspaces: Option<Arc<Workspaces>>,
 }
 
 impl Default for ContextSubHostOptions<'_> {
@@ -133,6 +216,11 @@ impl Default for ContextSubHostOptions<'_> {
             framework_options: FrameworkOptions::Default,
             parser_tokens: ArenaBox::new_empty_boxed_slice(),
             respect_eslint_disable_directives: true,
+            stylesheet: None,
+            json: None,
+            template: None,
+            node_resolver: None,
+            workspaces: None,
         }
     }
 }
@@ -349,6 +437,19 @@ impl<'a> ContextHost<'a> {
         self.diagnostics.borrow_mut().push(diagnostic);
     }
 
+    /// Report the syntax error of the current stylesheet or JSON file,
+    /// if a rule parsed it.
+    pub(crate) fn report_file_syntax_error(&self) {
+        let sub_host = self.current_sub_host();
+        let error =
+            sub_host.stylesheets.get().and_then(Stylesheets::syntax_error).or_else(|| {
+                sub_host.json_document.get().and_then(|document| document.as_ref().err())
+            });
+        if let Some(error) = error {
+            self.push_diagnostic(Message::new(error.clone(), PossibleFixes::None));
+        }
+    }
+
     // Append a list of diagnostics. Only used in report_unused_directives.
     fn append_diagnostics(&self, mut diagnostics: Vec<Message>) {
         if self.with_ignore_fixes {
@@ -501,6 +602,25 @@ impl<'a> ContextHost<'a> {
         }
     }
 
+    /// Creates a new [`LintContext`] for a rule of a Rust plugin.
+    pub(crate) fn spawn_for_rust_plugin_rule(
+        self: Rc<Self>,
+        plugin_name: &'static str,
+        rule_name: &'static str,
+        #[cfg_attr(not(debug_assertions), expect(unused_variables))] fix: RuleFixMeta,
+        severity: AllowWarnDeny,
+    ) -> LintContext<'a> {
+        LintContext {
+            parent: self,
+            current_rule_name: rule_name,
+            current_plugin_name: plugin_name,
+            current_plugin_display_name: plugin_name,
+            #[cfg(debug_assertions)]
+            current_rule_fix_capabilities: fix,
+            severity: severity.into(),
+        }
+    }
+
     /// Creates a new [`LintContext`] for testing purposes only.
     #[cfg(test)]
     pub(crate) fn spawn_for_test(self: Rc<Self>) -> LintContext<'a> {
@@ -535,6 +655,16 @@ impl<'a> ContextHost<'a> {
             self.frameworks.set(FrameworkFlags::Vitest, vitest_like);
             self.frameworks.set(FrameworkFlags::Jest, jest_like);
         }
+        if self.plugins().contains(LintPlugins::TESTING_LIBRARY) {
+            let testing_library_like = is_jestlike_file(&self.file_path)
+                || has_testing_library_imports(self.module_record());
+            self.frameworks.set(FrameworkFlags::TestingLibrary, testing_library_like);
+        }
+        if self.plugins().contains(LintPlugins::PLAYWRIGHT) {
+            let playwright_like =
+                is_playwright_file(&self.file_path) || has_playwright_imports(self.module_record());
+            self.frameworks.set(FrameworkFlags::Playwright, playwright_like);
+        }
 
         self
     }
@@ -550,6 +680,12 @@ impl<'a> ContextHost<'a> {
 
             self.frameworks.set(FrameworkFlags::Jest, self.plugins().has_jest());
         }
+        self.frameworks.set(
+            FrameworkFlags::TestingLibrary,
+            self.plugins().contains(LintPlugins::TESTING_LIBRARY),
+        );
+        self.frameworks
+            .set(FrameworkFlags::Playwright, self.plugins().contains(LintPlugins::PLAYWRIGHT));
 
         self
     }
@@ -1,6 +1,6 @@
 #![expect(rustdoc::private_intra_doc_links)] // useful for intellisense
 
-use std::{ffi::OsStr, ops::Deref, path::Path, rc::Rc};
+use std::{ffi::OsStr, ops::Deref, path::Path, rc::Rc, sync::Arc};
 
 use javascript_globals::{GLOBALS, GLOBALS_BUILTIN, GLOBALS_ES2026};
 
@@ -19,7 +19,10 @@ use crate::{
     disable_directives::DisableDirectives,
     fixer::{Fix, FixKind, Message, PossibleFixes, RuleFix, RuleFixer},
     frameworks::FrameworkOptions,
-    utils::{ReactCompilerResults, build_react_compiler_results},
+    utils::{
+        JsonDocument, NodeResolver, ReactCompilerResults, Stylesheets, SvelteTemplate, Workspaces,
+        build_react_compiler_results, build_stylesheets,
+    },
 };
 
 mod host;
@@ -96,6 +99,45 @@ impl<'a> LintContext<'a> {
             .get_or_init(|| build_react_compiler_results(&self.parent))
     }
 
+    /// Stylesheets of the current script section, for the `stylelint` rules:
+    /// the whole file for a stylesheet file, the css-in-js templates otherwise.
+    /// Parsed at most once per section, on first access.
+    pub fn stylesheets(&self) -> &Stylesheets<'a> {
+        self.parent.current_sub_host().stylesheets.get_or_init(|| build_stylesheets(&self.parent))
+    }
+
+    /// The JSON document of the current section, for the `json` rules.
+    /// `None` unless the file being linted is a JSON file that parses.
+    /// Parsed at most once per section, on first access.
+    pub fn json(&self) -> Option<&JsonDocument<'a>> {
+        let sub_host = self.parent.current_sub_host();
+        let source = sub_host.json()?;
+        sub_host
+            .json_document
+            .get_or_init(|| JsonDocument::parse(self.parent.allocator(), source))
+            .as_ref()
+            .ok()
+    }
+
+    /// The markup of the current Svelte component, for the `svelte` template rules.
+    /// `None` unless the current section stands in for the markup of a `.svelte` file.
+    /// Scanned at most once per section, on first access.
+    pub fn svelte_template(&self) -> Option<&SvelteTemplate<'a>> {
+        let sub_host = self.parent.current_sub_host();
+        let source = sub_host.template()?;
+        Some(sub_host.svelte_template.get_or_init(|| SvelteTemplate::scan(source.source_text)))
+    }
+
+    /// The resolver of the `node` rules.
+    pub fn node_resolver(&self) -> &NodeResolver {
+        self.parent.current_sub_host().node_resolver.get_or_init(Arc::default)
+    }
+
+    /// The workspaces of the `json` rules.
+    pub fn workspaces(&self) -> &Workspaces {
+        self.parent.current_sub_host().workspaces.get_or_init(Arc::default)
+    }
+
     #[inline]
     pub fn module_record(&self) -> &ModuleRecord {
         self.parent.module_record()
@@ -118,7 +160,7 @@ impl<'a> LintContext<'a> {
     /// Get a snippet of source text covered by the given [`Span`]. For details,
     /// see [`Span::source_text`].
     pub fn source_range(&self, span: Span) -> &'a str {
-        span.source_text(self.parent.semantic().source_text())
+        span.source_text(self.parent.source_text())
     }
 
     /// Finds the next occurrence of the given token within a bounded span,
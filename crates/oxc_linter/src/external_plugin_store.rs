@@ -9,7 +9,11 @@ use smallvec::SmallVec;
 use oxc_index::{IndexVec, define_index_type, index_vec};
 use serde::{Serialize, Serializer};
 
-use crate::ExternalLinter;
+use crate::{
+    ExternalLinter,
+    rule::RuleFixMeta,
+    rust_plugin::{DynRule, LoadedRustPlugin, intern_plugin_name},
+};
 
 define_index_type! {
     pub struct ExternalPluginId = u32;
@@ -24,6 +28,15 @@ define_index_type! {
 impl ExternalRuleId {
     /// Dummy value used in first element of `ExternalPluginStore::options`, which is a dummy
     pub const DUMMY: Self = Self::from_usize(0);
+
+    /// IDs of rules from Rust plugins start here.
+    /// IDs below it belong to JS plugin rules, and must match the rule indexes on JS side.
+    const RUST_PLUGIN_START: usize = 1 << 31;
+
+    /// Returns `true` if this rule is from a Rust plugin, and so runs in-process rather than on JS side.
+    pub fn is_rust_plugin_rule(self) -> bool {
+        self.index() >= Self::RUST_PLUGIN_START
+    }
 }
 
 define_index_type! {
@@ -35,6 +48,9 @@ impl ExternalOptionsId {
     /// The value `0`.
     /// Used as the ID when a rule does not have options.
     pub const NONE: Self = Self::from_usize(0);
+
+    /// IDs of configured Rust plugin rules start here, so they never collide with options sent to JS.
+    const RUST_PLUGIN_START: usize = 1 << 31;
 }
 
 #[derive(Debug)]
@@ -48,6 +64,12 @@ pub struct ExternalPluginStore {
     /// The rule ID is also stored, so that can merge options with the rule's default options on JS side.
     options: IndexVec<ExternalOptionsId, (ExternalRuleId, SmallVec<[serde_json::Value; 1]>)>,
 
+    /// Rules of Rust plugins, indexed by `ExternalRuleId - ExternalRuleId::RUST_PLUGIN_START`.
+    rust_rules: Vec<RustPluginRule>,
+    /// Configured instances of Rust plugin rules,
+    /// indexed by `ExternalOptionsId - ExternalOptionsId::RUST_PLUGIN_START`.
+    rust_rule_instances: Vec<Box<dyn DynRule>>,
+
     is_enabled: bool,
 }
 
@@ -67,6 +89,8 @@ impl ExternalPluginStore {
             plugin_names: FxHashMap::default(),
             rules: IndexVec::default(),
             options,
+            rust_rules: Vec::new(),
+            rust_rule_instances: Vec::new(),
             is_enabled,
         }
     }
@@ -76,9 +100,18 @@ impl ExternalPluginStore {
         self.is_enabled
     }
 
-    /// Returns `true` if no external plugins have been loaded.
+    /// Returns `true` if no JS plugins have been loaded.
+    ///
+    /// Rust plugins are not counted, as they don't need the external linter.
     pub fn is_empty(&self) -> bool {
-        self.plugins.is_empty()
+        self.plugins.iter().all(|plugin| plugin.is_rust_plugin)
+    }
+
+    /// Returns `true` if `plugin_name` is the name of a loaded Rust plugin.
+    pub fn has_rust_plugin(&self, plugin_name: &str) -> bool {
+        self.plugin_names
+            .get(plugin_name)
+            .is_some_and(|&plugin_id| self.plugins[plugin_id].is_rust_plugin)
     }
 
     pub fn is_plugin_registered(&self, plugin_path: &Path) -> bool {
@@ -101,9 +134,11 @@ impl ExternalPluginStore {
         let newly_inserted = self.registered_plugin_paths.insert(plugin_path);
         assert!(newly_inserted, "register_plugin: plugin already registered");
 
-        let plugin_id = self
-            .plugins
-            .push(ExternalPlugin { name: plugin_name.clone(), rules: FxHashMap::default() });
+        let plugin_id = self.plugins.push(ExternalPlugin {
+            name: plugin_name.clone(),
+            rules: FxHashMap::default(),
+            is_rust_plugin: false,
+        });
         self.plugin_names.insert(plugin_name, plugin_id);
 
         assert!(
@@ -119,6 +154,46 @@ impl ExternalPluginStore {
         }
     }
 
+    /// Register a Rust plugin loaded from `plugin_path`, under `alias` if provided.
+    ///
+    /// # Errors
+    /// Returns an error if another plugin with the same name is already registered.
+    pub(crate) fn register_rust_plugin(
+        &mut self,
+        plugin_path: PathBuf,
+        alias: Option<&str>,
+        plugin: LoadedRustPlugin,
+    ) -> Result<(), String> {
+        let plugin_name = alias.unwrap_or(plugin.name);
+        if self.plugin_names.contains_key(plugin_name) {
+            return Err(format!("A plugin named '{plugin_name}' is already loaded."));
+        }
+        self.registered_plugin_paths.insert(plugin_path);
+
+        let plugin_name = intern_plugin_name(plugin_name);
+        let plugin_id = self.plugins.push(ExternalPlugin {
+            name: plugin_name.to_string(),
+            rules: FxHashMap::default(),
+            is_rust_plugin: true,
+        });
+        self.plugin_names.insert(plugin_name.to_string(), plugin_id);
+
+        for rule in plugin.rules {
+            let rule_id = ExternalRuleId::from_usize(
+                ExternalRuleId::RUST_PLUGIN_START + self.rust_rules.len(),
+            );
+            self.plugins[plugin_id].rules.insert(rule.name.to_string(), rule_id);
+            self.rust_rules.push(RustPluginRule {
+                plugin_name,
+                name: rule.name,
+                fix: rule.fix,
+                plugin_id,
+                configure: rule.configure,
+            });
+        }
+        Ok(())
+    }
+
     /// # Errors
     /// Returns an error if the plugin, or rule could not be found
     pub fn lookup_rule_id(
@@ -142,6 +217,10 @@ impl ExternalPluginStore {
         &self,
         external_rule_id: ExternalRuleId,
     ) -> (/* plugin name */ &str, /* rule name */ &str) {
+        if external_rule_id.is_rust_plugin_rule() {
+            let rule = self.rust_rule(external_rule_id);
+            return (&self.plugins[rule.plugin_id].name, rule.name);
+        }
         let external_rule = &self.rules[external_rule_id];
         let plugin = &self.plugins[external_rule.plugin_id];
         (&plugin.name, &external_rule.name)
@@ -161,6 +240,46 @@ impl ExternalPluginStore {
         }
     }
 
+    /// Create an instance of a Rust plugin rule with `options`, and return its [`ExternalOptionsId`].
+    ///
+    /// # Errors
+    /// Returns an error if the rule rejects the options.
+    pub(crate) fn configure_rust_rule(
+        &mut self,
+        rule_id: ExternalRuleId,
+        options: &SmallVec<[serde_json::Value; 1]>,
+    ) -> Result<ExternalOptionsId, serde_json::Error> {
+        // Same convention as built-in rules: `Value::Null` is the equivalent of empty config
+        let config = if options.is_empty() {
+            serde_json::Value::Null
+        } else {
+            serde_json::Value::Array(options.to_vec())
+        };
+        let instance = (self.rust_rule(rule_id).configure)(config)?;
+        let options_id = ExternalOptionsId::from_usize(
+            ExternalOptionsId::RUST_PLUGIN_START + self.rust_rule_instances.len(),
+        );
+        self.rust_rule_instances.push(instance);
+        Ok(options_id)
+    }
+
+    /// Get a configured Rust plugin rule, along with its plugin name, name and fix capabilities.
+    pub(crate) fn rust_rule_instance(
+        &self,
+        rule_id: ExternalRuleId,
+        options_id: ExternalOptionsId,
+    ) -> (&'static str, &'static str, RuleFixMeta, &dyn DynRule) {
+        let rule = self.rust_rule(rule_id);
+        let instance =
+            &self.rust_rule_instances[options_id.index() - ExternalOptionsId::RUST_PLUGIN_START];
+        (rule.plugin_name, rule.name, rule.fix, instance.as_ref())
+    }
+
+    fn rust_rule(&self, rule_id: ExternalRuleId) -> &RustPluginRule {
+        debug_assert!(rule_id.is_rust_plugin_rule());
+        &self.rust_rules[rule_id.index() - ExternalRuleId::RUST_PLUGIN_START]
+    }
+
     /// Send options to JS side.
     ///
     /// # Errors
@@ -267,6 +386,7 @@ impl std::error::Error for ExternalRuleLookupError {}
 struct ExternalPlugin {
     name: String,
     rules: FxHashMap<String, ExternalRuleId>,
+    is_rust_plugin: bool,
 }
 
 #[derive(Debug)]
@@ -274,3 +394,20 @@ struct ExternalRule {
     name: String,
     plugin_id: ExternalPluginId,
 }
+
+struct RustPluginRule {
+    plugin_name: &'static str,
+    name: &'static str,
+    fix: RuleFixMeta,
+    plugin_id: ExternalPluginId,
+    configure: fn(serde_json::Value) -> Result<Box<dyn DynRule>, serde_json::Error>,
+}
+
+impl fmt::Debug for RustPluginRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RustPluginRule")
+            .field("plugin_name", &self.plugin_name)
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
@@ -1,6 +1,8 @@
+use oxc_allocator::Allocator;
 use oxc_codegen::{Codegen, CodegenOptions};
 use oxc_diagnostics::OxcCode;
 use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::{ParseOptions, Parser};
 use oxc_span::{GetSpan, SourceType, Span};
 use std::borrow::Cow;
 
@@ -240,11 +242,31 @@ fn format_replace_message(target_text: &str, replacement_text: &str) -> String {
     }
 }
 
+/// Maximum number of times [`Fixer::fix_in_passes`] lints and fixes the same source text.
+/// Same as ESLint.
+pub const MAX_FIX_PASSES: usize = 10;
+
 #[derive(Debug)]
 pub struct FixResult<'a> {
     pub fixed: bool,
     pub fixed_code: Cow<'a, str>,
     pub messages: Vec<Message>,
+    /// Number of fixes which were not applied because they produced invalid syntax.
+    /// Always `0` if the [`Fixer`] was created without a [`SourceType`], and in release
+    /// builds of [`Fixer::fix`].
+    pub rolled_back: usize,
+}
+
+#[derive(Debug)]
+pub struct MultiPassFixResult<'a> {
+    pub fixed: bool,
+    /// Source text after all passes. `messages` refer to this source text.
+    pub fixed_code: Cow<'a, str>,
+    pub messages: Vec<Message>,
+    /// Number of passes which changed the source text.
+    pub passes: usize,
+    /// Number of messages left with a fix which could not be applied.
+    pub unapplied_fixes: usize,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -319,7 +341,8 @@ pub struct Fixer<'a> {
     // The behavior is oriented by `oxlint` where only one PossibleFixes is applied.
     fix_index: u8,
 
-    #[cfg(debug_assertions)]
+    /// When set, fixes which produce invalid syntax can be rolled back.
+    /// See [`Fixer::fix_and_roll_back`].
     source_type: Option<SourceType>,
 }
 
@@ -327,17 +350,9 @@ impl<'a> Fixer<'a> {
     pub fn new(
         source_text: &'a str,
         messages: Vec<Message>,
-        #[cfg_attr(not(debug_assertions), expect(unused_variables))] source_type: Option<
-            SourceType,
-        >,
+        source_type: Option<SourceType>,
     ) -> Self {
-        Self {
-            source_text,
-            messages,
-            fix_index: 0,
-            #[cfg(debug_assertions)]
-            source_type,
-        }
+        Self { source_text, messages, fix_index: 0, source_type }
     }
 
     #[cfg(test)]
@@ -347,26 +362,184 @@ impl<'a> Fixer<'a> {
         self
     }
 
-    /// # Panics
-    pub fn fix(mut self) -> FixResult<'a> {
+    /// Apply all non-overlapping fixes in a single pass.
+    ///
+    /// In debug builds, fixes which produce invalid syntax are rolled back as with
+    /// [`Fixer::fix_and_roll_back`]. Release builds skip the extra parse of the fixed code.
+    pub fn fix(self) -> FixResult<'a> {
+        self.fix_impl(cfg!(debug_assertions))
+    }
+
+    /// Apply all non-overlapping fixes in a single pass.
+    ///
+    /// If a [`SourceType`] was provided and the fixed code does not parse, the fixes are
+    /// bisected, and those which produce invalid syntax are rolled back.
+    pub fn fix_and_roll_back(self) -> FixResult<'a> {
+        self.fix_impl(true)
+    }
+
+    fn fix_impl(mut self, roll_back: bool) -> FixResult<'a> {
         let source_text = self.source_text;
         if self.messages.iter().all(|m| m.fixes.is_empty()) {
             return FixResult {
                 fixed: false,
                 fixed_code: Cow::Borrowed(source_text),
                 messages: self.messages,
+                rolled_back: 0,
             };
         }
 
         self.messages.sort_unstable_by_key(|m| m.fixes.span());
+
+        let mut applied = vec![false; self.messages.len()];
+        let mut output = self.apply_fixes(&mut applied, |_| true);
+        let mut fixed = applied.contains(&true);
+        let mut rolled_back = 0;
+
+        if fixed
+            && roll_back
+            && let Some(source_type) = self.source_type
+            && !is_valid_syntax(&output, source_type)
+        {
+            // Find the culprits by bisecting the applied fixes, so that only a few parses are
+            // needed when most fixes are valid.
+            let candidates = (0..applied.len()).filter(|&index| applied[index]).collect::<Vec<_>>();
+            let mut accepted = vec![false; applied.len()];
+            for half in candidates.chunks(candidates.len().div_ceil(2)) {
+                rolled_back += self.accept_valid_fixes(half, &mut accepted, source_type);
+            }
+            let mut applied_accepted = vec![false; accepted.len()];
+            output = self.apply_fixes(&mut applied_accepted, |index| accepted[index]);
+            fixed = applied_accepted.contains(&true);
+            applied = applied_accepted;
+        }
+
+        // only keep messages that were not fixed
+        let messages = self
+            .messages
+            .into_iter()
+            .zip(applied)
+            .filter_map(|(m, applied)| (!applied).then_some(m))
+            .collect();
+
+        FixResult { fixed, fixed_code: Cow::Owned(output), messages, rolled_back }
+    }
+
+    /// Apply fixes, lint the fixed code again with `relint`, and repeat until no fix can be
+    /// applied anymore or [`MAX_FIX_PASSES`] is reached.
+    ///
+    /// Fixes which were skipped because they overlap with another fix get another chance in
+    /// the next pass. `relint` returns `None` if the fixed code can not be linted (e.g. it fails
+    /// to parse), in which case the pass which produced it is rolled back.
+    ///
+    /// Returned messages always refer to the returned code. Messages whose fix could not be
+    /// applied get a note saying so.
+    pub fn fix_in_passes<F>(self, mut relint: F) -> MultiPassFixResult<'a>
+    where
+        F: FnMut(&str) -> Option<Vec<Message>>,
+    {
+        let Self { source_text, mut messages, fix_index, source_type } = self;
+        let mut code = Cow::Borrowed(source_text);
+        let mut passes = 0;
+
+        while passes < MAX_FIX_PASSES && messages.iter().any(|m| !m.fixes.is_empty()) {
+            let unfixed_messages = messages.clone();
+            // `relint` parses the fixed code, so the syntax is only checked separately, to find
+            // the fixes to roll back, when it fails.
+            let FixResult { fixed, fixed_code, messages: remaining, .. } =
+                Fixer { source_text: &code, messages, fix_index, source_type: None }.fix();
+            if !fixed {
+                messages = remaining;
+                break;
+            }
+            let mut fixed_code = fixed_code.into_owned();
+            let mut relinted = relint(&fixed_code);
+            if relinted.is_none() && source_type.is_some() {
+                let retry = Fixer {
+                    source_text: &code,
+                    messages: unfixed_messages.clone(),
+                    fix_index,
+                    source_type,
+                }
+                .fix_and_roll_back();
+                if retry.fixed {
+                    fixed_code = retry.fixed_code.into_owned();
+                    relinted = relint(&fixed_code);
+                }
+            }
+            if let Some(relinted) = relinted {
+                code = Cow::Owned(fixed_code);
+                messages = relinted;
+                passes += 1;
+            } else {
+                messages = unfixed_messages;
+                break;
+            }
+        }
+
+        let mut unapplied_fixes = 0;
+        for message in &mut messages {
+            if message.fixes.is_empty() {
+                continue;
+            }
+            unapplied_fixes += 1;
+            let note = match message.error.note.take() {
+                Some(note) => Cow::Owned(format!("{note}\n{UNAPPLIED_FIX_NOTE}")),
+                None => Cow::Borrowed(UNAPPLIED_FIX_NOTE),
+            };
+            message.error.note = Some(note);
+        }
+
+        MultiPassFixResult {
+            fixed: passes > 0,
+            fixed_code: code,
+            messages,
+            passes,
+            unapplied_fixes,
+        }
+    }
+
+    /// Adds the fixes of `candidates` to `accepted` if the code stays valid with all of them,
+    /// and otherwise bisects them to find the fixes which produce invalid syntax. Returns the
+    /// number of fixes which were rolled back.
+    fn accept_valid_fixes(
+        &self,
+        candidates: &[usize],
+        accepted: &mut [bool],
+        source_type: SourceType,
+    ) -> usize {
+        for &index in candidates {
+            accepted[index] = true;
+        }
+        let mut applied = vec![false; accepted.len()];
+        let output = self.apply_fixes(&mut applied, |index| accepted[index]);
+        if is_valid_syntax(&output, source_type) {
+            return 0;
+        }
+        for &index in candidates {
+            accepted[index] = false;
+        }
+        if candidates.len() == 1 {
+            return 1;
+        }
+        let (left, right) = candidates.split_at(candidates.len() / 2);
+        self.accept_valid_fixes(left, accepted, source_type)
+            + self.accept_valid_fixes(right, accepted, source_type)
+    }
+
+    /// Writes the fixes of messages accepted by `filter` into a copy of the source text,
+    /// skipping fixes which overlap a previously applied one. `applied` records which messages
+    /// had their fix applied. Expects messages to be sorted by fix span.
+    fn apply_fixes(&self, applied: &mut [bool], filter: impl Fn(usize) -> bool) -> String {
+        let source_text = self.source_text;
         let mut fixed = false;
         let mut output = String::with_capacity(source_text.len());
         let mut last_pos: u32 = 0;
 
-        // only keep messages that were not fixed
-        let mut filtered_messages = Vec::with_capacity(self.messages.len());
-
-        for m in self.messages {
+        for (index, m) in self.messages.iter().enumerate() {
+            if !filter(index) {
+                continue;
+            }
             let fix = match &m.fixes {
                 PossibleFixes::None => None,
                 PossibleFixes::Single(fix) => Some(fix),
@@ -375,14 +548,12 @@ impl<'a> Fixer<'a> {
                 PossibleFixes::Multiple(multiple) => multiple.get(self.fix_index as usize),
             };
             let Some(Fix { content, span, .. }) = fix else {
-                filtered_messages.push(m);
                 continue;
             };
             let start = span.start;
             let end = span.end;
             debug_assert!(start <= end, "Negative range is invalid: {span:?}");
             if start > end {
-                filtered_messages.push(m);
                 continue;
             }
 
@@ -393,11 +564,11 @@ impl<'a> Fixer<'a> {
             // would incorrectly be considered as overlapping.
             let overlaps = fixed && last_pos >= start;
             if overlaps {
-                filtered_messages.push(m);
                 continue;
             }
 
             fixed = true;
+            applied[index] = true;
             let offset = last_pos as usize;
             output.push_str(&source_text[offset..start as usize]);
             output.push_str(content);
@@ -405,31 +576,25 @@ impl<'a> Fixer<'a> {
         }
 
         output.push_str(&source_text[last_pos as usize..]);
-
-        #[cfg(debug_assertions)]
-        if fixed && let Some(source_type) = self.source_type {
-            use oxc_allocator::Allocator;
-            use oxc_parser::{ParseOptions, Parser};
-
-            let allocator = Allocator::default();
-            let parse_result = Parser::new(&allocator, &output, source_type)
-                .with_options(ParseOptions {
-                    parse_regular_expression: true,
-                    allow_return_outside_function: true,
-                    ..ParseOptions::default()
-                })
-                .parse();
-            debug_assert!(
-                parse_result.diagnostics.is_empty() && !parse_result.panicked,
-                "Linter fixer produced invalid syntax.\n\nInput code: \n```\n{source_text}\n```\n\nFixed code: \n```\n{output}\n```\n\nParse errors: {:?}",
-                parse_result.diagnostics
-            );
-        }
-
-        FixResult { fixed, fixed_code: Cow::Owned(output), messages: filtered_messages }
+        output
     }
 }
 
+const UNAPPLIED_FIX_NOTE: &str = "This problem has a fix which could not be applied automatically, \
+    because it conflicts with other fixes or produces invalid syntax.";
+
+fn is_valid_syntax(source_text: &str, source_type: SourceType) -> bool {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type)
+        .with_options(ParseOptions {
+            parse_regular_expression: true,
+            allow_return_outside_function: true,
+            ..ParseOptions::default()
+        })
+        .parse();
+    ret.diagnostics.is_empty() && !ret.panicked
+}
+
 #[cfg(test)]
 mod test {
     use std::borrow::Cow;
@@ -441,7 +606,8 @@ mod test {
     use crate::FixKind;
 
     use super::{
-        CompositeFix, Fix, FixResult, Fixer, Message, PossibleFixes, format_replace_message,
+        CompositeFix, Fix, FixResult, Fixer, MAX_FIX_PASSES, Message, PossibleFixes,
+        format_replace_message,
     };
 
     fn insert_at_end() -> OxcDiagnostic {
@@ -863,29 +1029,91 @@ mod test {
         assert_fixes_merged(fixes, &Fix::new("baz\nqux", Span::new(0, 7)), source_text);
     }
 
-    // Test that debug assertion catches invalid syntax after fixes
     #[test]
-    #[cfg_attr(debug_assertions, should_panic(expected = "Linter fixer produced invalid syntax."))]
-    fn debug_assert_catches_invalid_fix() {
-        // This test demonstrates that the debug assertion catches fixes that produce invalid syntax.
-        // In debug builds, this will panic. In release builds, it will pass (no assertion).
+    fn roll_back_fix_which_produces_invalid_syntax() {
         let source_text = "var answer = 42;";
         let source_type = SourceType::default();
 
-        // Create a fix that produces invalid syntax by replacing 'var' with invalid tokens
+        // "!!!INVALID answer = 42;" is invalid syntax
         let fix = Fix::new(Cow::Borrowed("!!!INVALID"), Span::new(0, 3));
         let message =
             create_message(OxcDiagnostic::warn("Invalid fix test"), PossibleFixes::Single(fix));
 
-        let fixer = Fixer::new(source_text, vec![message], Some(source_type));
+        let result = Fixer::new(source_text, vec![message], Some(source_type)).fix_and_roll_back();
+        assert!(!result.fixed);
+        assert_eq!(result.fixed_code, source_text);
+        assert_eq!(result.rolled_back, 1);
+        assert_eq!(result.messages.len(), 1);
+    }
+
+    #[test]
+    fn keep_valid_fixes_when_rolling_back() {
+        let source_text = "var answer = 42;";
+        let source_type = SourceType::default();
+
+        let messages = vec![
+            create_message(
+                OxcDiagnostic::warn("Valid fix test"),
+                PossibleFixes::Single(Fix::new(Cow::Borrowed("let"), Span::new(0, 3))),
+            ),
+            create_message(
+                OxcDiagnostic::warn("Invalid fix test"),
+                PossibleFixes::Single(Fix::new(Cow::Borrowed("= ="), Span::new(11, 12))),
+            ),
+            create_message(
+                OxcDiagnostic::warn("Valid fix test"),
+                PossibleFixes::Single(Fix::new(Cow::Borrowed("43"), Span::new(13, 15))),
+            ),
+        ];
+
+        let result = Fixer::new(source_text, messages, Some(source_type)).fix_and_roll_back();
+        assert!(result.fixed);
+        assert_eq!(result.fixed_code, "let answer = 43;");
+        assert_eq!(result.rolled_back, 1);
+        assert_eq!(result.messages.len(), 1);
+        assert_eq!(result.messages[0].error.message, "Invalid fix test");
+    }
+
+    #[test]
+    fn bisect_many_fixes_to_roll_back_invalid_ones() {
+        // `a;` repeated, where fixes replace some of them with `a` (valid) and two with `=`.
+        let source_text = "a;".repeat(16);
+        let messages = (0..16u32)
+            .map(|index| {
+                let content = if matches!(index, 5 | 11) { "=" } else { "b" };
+                create_message(
+                    OxcDiagnostic::warn("fix"),
+                    PossibleFixes::Single(Fix::new(content, Span::new(index * 2, index * 2 + 1))),
+                )
+            })
+            .collect();
 
-        // In debug builds, this will panic because "!!!INVALID answer = 42;" is invalid syntax
-        let _result = fixer.fix();
+        let result =
+            Fixer::new(&source_text, messages, Some(SourceType::default())).fix_and_roll_back();
+        let mut expected = "b;".repeat(16);
+        expected.replace_range(10..11, "a");
+        expected.replace_range(22..23, "a");
+        assert!(result.fixed);
+        assert_eq!(result.fixed_code, expected);
+        assert_eq!(result.rolled_back, 2);
+        assert_eq!(result.messages.len(), 2);
     }
 
     #[test]
-    fn valid_fix_passes_debug_assertion() {
-        // This test demonstrates that valid fixes pass the debug assertion
+    fn invalid_syntax_is_not_checked_without_source_type() {
+        let source_text = "var answer = 42;";
+        let fix = Fix::new(Cow::Borrowed("!!!INVALID"), Span::new(0, 3));
+        let message =
+            create_message(OxcDiagnostic::warn("Invalid fix test"), PossibleFixes::Single(fix));
+
+        let result = Fixer::new(source_text, vec![message], None).fix_and_roll_back();
+        assert!(result.fixed);
+        assert_eq!(result.fixed_code, "!!!INVALID answer = 42;");
+        assert_eq!(result.rolled_back, 0);
+    }
+
+    #[test]
+    fn valid_fix_is_applied() {
         let source_text = "var answer = 42;";
         let source_type = SourceType::default();
 
@@ -896,9 +1124,117 @@ mod test {
 
         let fixer = Fixer::new(source_text, vec![message], Some(source_type));
 
-        let result = fixer.fix();
+        let result = fixer.fix_and_roll_back();
+        assert!(result.fixed);
+        assert_eq!(result.fixed_code, "let answer = 42;");
+        assert_eq!(result.rolled_back, 0);
+    }
+
+    /// A fake linter which reports every `var` and asks to replace it with `let`, and every
+    /// `let` and asks to replace it with `const`.
+    fn lint_declarations(source_text: &str) -> Vec<Message> {
+        let mut messages = vec![];
+        for (keyword, replacement) in [("var", "let"), ("let", "const")] {
+            for (start, _) in source_text.match_indices(keyword) {
+                #[expect(clippy::cast_possible_truncation)]
+                let span = Span::sized(start as u32, keyword.len() as u32);
+                messages.push(create_message(
+                    OxcDiagnostic::warn(format!("Unexpected {keyword}")).with_label(span),
+                    PossibleFixes::Single(Fix::new(replacement, span)),
+                ));
+            }
+        }
+        messages
+    }
+
+    #[test]
+    fn fix_in_passes_until_stable() {
+        let source_text = "var a = 1;";
+        let result = Fixer::new(source_text, lint_declarations(source_text), None)
+            .fix_in_passes(|code| Some(lint_declarations(code)));
+
+        assert!(result.fixed);
+        assert_eq!(result.fixed_code, "const a = 1;");
+        assert_eq!(result.passes, 2);
+        assert!(result.messages.is_empty());
+        assert_eq!(result.unapplied_fixes, 0);
+    }
+
+    #[test]
+    fn fix_in_passes_applies_overlapping_fixes_in_later_passes() {
+        // The second fix starts where the first one ends, so it's only applied in the next pass.
+        let lint = |code: &str| {
+            let mut messages = vec![];
+            if code.starts_with("var") {
+                messages.push(create_message(replace_var(), PossibleFixes::Single(REPLACE_VAR)));
+            }
+            if code.contains(" answer") {
+                let fix = Fix::new(" foo", Span::new(3, 10));
+                messages.push(create_message(replace_id(), PossibleFixes::Single(fix)));
+            }
+            Some(messages)
+        };
+        let result = Fixer::new(TEST_CODE, lint(TEST_CODE).unwrap(), None).fix_in_passes(lint);
+
+        assert_eq!(result.fixed_code, "let foo = 6 * 7;");
+        assert_eq!(result.passes, 2);
+        assert!(result.messages.is_empty());
+    }
+
+    #[test]
+    fn fix_in_passes_stops_after_max_passes() {
+        // Every pass finds a new problem.
+        let relint = |code: &str| {
+            Some(vec![create_message(
+                insert_at_end(),
+                #[expect(clippy::cast_possible_truncation)]
+                PossibleFixes::Single(Fix::new(";", Span::empty(code.len() as u32))),
+            )])
+        };
+        let source_text = "a";
+        let messages = relint(source_text).unwrap();
+        let result = Fixer::new(source_text, messages, None).fix_in_passes(relint);
+
+        assert_eq!(result.passes, MAX_FIX_PASSES);
+        assert_eq!(result.fixed_code, format!("a{}", ";".repeat(MAX_FIX_PASSES)));
+        assert_eq!(result.unapplied_fixes, 1);
+        assert!(result.messages[0].error.note.as_deref().unwrap().contains("could not be applied"));
+    }
+
+    #[test]
+    fn fix_in_passes_rolls_back_pass_which_can_not_be_linted() {
+        let source_text = "var a = 1;";
+        let result = Fixer::new(source_text, lint_declarations(source_text), None)
+            .fix_in_passes(|code| if code.starts_with("let") { None } else { Some(vec![]) });
+
+        assert!(!result.fixed);
+        assert_eq!(result.fixed_code, source_text);
+        assert_eq!(result.passes, 0);
+        assert_eq!(result.unapplied_fixes, 1);
+        assert_eq!(result.messages[0].error.note.as_deref(), Some(super::UNAPPLIED_FIX_NOTE),);
+    }
+
+    #[test]
+    fn fix_in_passes_rolls_back_invalid_fixes_when_relint_fails() {
+        let source_text = "var answer = 42;";
+        let messages = vec![
+            create_message(
+                OxcDiagnostic::warn("Valid fix test"),
+                PossibleFixes::Single(Fix::new(Cow::Borrowed("let"), Span::new(0, 3))),
+            ),
+            create_message(
+                OxcDiagnostic::warn("Invalid fix test"),
+                PossibleFixes::Single(Fix::new(Cow::Borrowed("= ="), Span::new(11, 12))),
+            ),
+        ];
+        let result =
+            Fixer::new(source_text, messages, Some(SourceType::default())).fix_in_passes(|code| {
+                super::is_valid_syntax(code, SourceType::default()).then(Vec::new)
+            });
+
         assert!(result.fixed);
         assert_eq!(result.fixed_code, "let answer = 42;");
+        assert_eq!(result.passes, 1);
     }
 
     #[test]
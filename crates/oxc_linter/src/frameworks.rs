@@ -41,6 +41,11 @@ bitflags! {
         const OtherTest = 1 << 11;
         /// Flag for if any test frameworks are used, such as Jest or Vitest.
         const Test = Self::Jest.bits() | Self::Vitest.bits() | Self::OtherTest.bits();
+
+        /// Uses [Testing Library](https://testing-library.com/).
+        const TestingLibrary = 1 << 12;
+        /// Uses [Playwright Test](https://playwright.dev/).
+        const Playwright = 1 << 13;
     }
 }
 
@@ -72,6 +77,16 @@ impl FrameworkFlags {
     pub const fn is_jest(self) -> bool {
         self.contains(Self::Jest)
     }
+
+    #[inline]
+    pub const fn is_testing_library(self) -> bool {
+        self.contains(Self::TestingLibrary)
+    }
+
+    #[inline]
+    pub const fn is_playwright(self) -> bool {
+        self.contains(Self::Playwright)
+    }
 }
 
 /// <https://jestjs.io/docs/configuration#testmatch-arraystring>
@@ -105,6 +120,48 @@ pub fn has_jest_imports(module_record: &ModuleRecord) -> bool {
     module_record.import_entries.iter().any(|entry| entry.module_request.name() == "@jest/globals")
 }
 
+/// Whether `path` is an end-to-end test, such as `login.e2e.ts`, or a test file inside an
+/// `e2e` or `playwright` directory, such as `e2e/login.spec.ts`.
+#[cfg(not(test))]
+pub fn is_playwright_file(path: &Path) -> bool {
+    use std::ffi::OsStr;
+
+    let is_e2e_file = path
+        .file_name()
+        .and_then(OsStr::to_str)
+        .and_then(|filename| filename.split('.').rev().nth(1))
+        .is_some_and(|name_or_first_ext| name_or_first_ext == "e2e");
+    if is_e2e_file {
+        return true;
+    }
+
+    is_jestlike_file(path)
+        && path.components().any(|c| match c {
+            std::path::Component::Normal(p) => {
+                p == OsStr::new("e2e") || p == OsStr::new("playwright")
+            }
+            _ => false,
+        })
+}
+
+#[cfg(not(test))]
+pub fn has_testing_library_imports(module_record: &ModuleRecord) -> bool {
+    module_record
+        .import_entries
+        .iter()
+        .any(|entry| entry.module_request.name().starts_with("@testing-library/"))
+}
+
+#[cfg(not(test))]
+pub fn has_playwright_imports(module_record: &ModuleRecord) -> bool {
+    module_record.import_entries.iter().any(|entry| {
+        let name = entry.module_request.name();
+        name == "@playwright/test"
+            || name == "playwright/test"
+            || name.starts_with("@playwright/experimental-ct-")
+    })
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 
 pub enum FrameworkOptions {
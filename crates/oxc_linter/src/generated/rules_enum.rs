@@ -10,6 +10,13 @@
 )]
 #[cfg(feature = "ruledocs")]
 use crate::rule::RuleInfo;
+pub use crate::rules::angular::no_input_rename::NoInputRename as AngularNoInputRename;
+pub use crate::rules::angular::no_output_on_prefix::NoOutputOnPrefix as AngularNoOutputOnPrefix;
+pub use crate::rules::angular::template_banana_in_box::TemplateBananaInBox as AngularTemplateBananaInBox;
+pub use crate::rules::angular::template_eqeqeq::TemplateEqeqeq as AngularTemplateEqeqeq;
+pub use crate::rules::angular::use_lifecycle_interface::UseLifecycleInterface as AngularUseLifecycleInterface;
+pub use crate::rules::compat::compat::Compat as CompatCompat;
+pub use crate::rules::compat::no_unsupported_syntax::NoUnsupportedSyntax as CompatNoUnsupportedSyntax;
 pub use crate::rules::eslint::accessor_pairs::AccessorPairs as EslintAccessorPairs;
 pub use crate::rules::eslint::array_callback_return::ArrayCallbackReturn as EslintArrayCallbackReturn;
 pub use crate::rules::eslint::arrow_body_style::ArrowBodyStyle as EslintArrowBodyStyle;
@@ -313,6 +320,11 @@ pub use crate::rules::jsdoc::require_throws_type::RequireThrowsType as JsdocRequ
 pub use crate::rules::jsdoc::require_yields::RequireYields as JsdocRequireYields;
 pub use crate::rules::jsdoc::require_yields_description::RequireYieldsDescription as JsdocRequireYieldsDescription;
 pub use crate::rules::jsdoc::require_yields_type::RequireYieldsType as JsdocRequireYieldsType;
+pub use crate::rules::json::consistent_dependency_versions::ConsistentDependencyVersions as JsonConsistentDependencyVersions;
+pub use crate::rules::json::no_duplicate_keys::NoDuplicateKeys as JsonNoDuplicateKeys;
+pub use crate::rules::json::sort_dependencies::SortDependencies as JsonSortDependencies;
+pub use crate::rules::json::valid_package_json::ValidPackageJson as JsonValidPackageJson;
+pub use crate::rules::json::valid_tsconfig::ValidTsconfig as JsonValidTsconfig;
 pub use crate::rules::jsx_a11y::alt_text::AltText as JsxA11YAltText;
 pub use crate::rules::jsx_a11y::anchor_ambiguous_text::AnchorAmbiguousText as JsxA11YAnchorAmbiguousText;
 pub use crate::rules::jsx_a11y::anchor_has_content::AnchorHasContent as JsxA11YAnchorHasContent;
@@ -374,13 +386,20 @@ pub use crate::rules::node::callback_return::CallbackReturn as NodeCallbackRetur
 pub use crate::rules::node::exports_style::ExportsStyle as NodeExportsStyle;
 pub use crate::rules::node::global_require::GlobalRequire as NodeGlobalRequire;
 pub use crate::rules::node::handle_callback_err::HandleCallbackErr as NodeHandleCallbackErr;
+pub use crate::rules::node::hashbang::Hashbang as NodeHashbang;
+pub use crate::rules::node::no_deprecated_api::NoDeprecatedApi as NodeNoDeprecatedApi;
 pub use crate::rules::node::no_exports_assign::NoExportsAssign as NodeNoExportsAssign;
+pub use crate::rules::node::no_missing_import::NoMissingImport as NodeNoMissingImport;
+pub use crate::rules::node::no_missing_require::NoMissingRequire as NodeNoMissingRequire;
 pub use crate::rules::node::no_mixed_requires::NoMixedRequires as NodeNoMixedRequires;
 pub use crate::rules::node::no_new_require::NoNewRequire as NodeNoNewRequire;
 pub use crate::rules::node::no_path_concat::NoPathConcat as NodeNoPathConcat;
 pub use crate::rules::node::no_process_env::NoProcessEnv as NodeNoProcessEnv;
 pub use crate::rules::node::no_sync::NoSync as NodeNoSync;
 pub use crate::rules::node::no_top_level_await::NoTopLevelAwait as NodeNoTopLevelAwait;
+pub use crate::rules::node::no_unpublished_import::NoUnpublishedImport as NodeNoUnpublishedImport;
+pub use crate::rules::node::no_unsupported_features_node_builtins::NoUnsupportedFeaturesNodeBuiltins as NodeNoUnsupportedFeaturesNodeBuiltins;
+pub use crate::rules::node::prefer_node_protocol::PreferNodeProtocol as NodePreferNodeProtocol;
 pub use crate::rules::oxc::approx_constant::ApproxConstant as OxcApproxConstant;
 pub use crate::rules::oxc::bad_array_method_on_arguments::BadArrayMethodOnArguments as OxcBadArrayMethodOnArguments;
 pub use crate::rules::oxc::bad_bitwise_operator::BadBitwiseOperator as OxcBadBitwiseOperator;
@@ -408,6 +427,10 @@ pub use crate::rules::oxc::no_this_in_exported_function::NoThisInExportedFunctio
 pub use crate::rules::oxc::number_arg_out_of_range::NumberArgOutOfRange as OxcNumberArgOutOfRange;
 pub use crate::rules::oxc::only_used_in_recursion::OnlyUsedInRecursion as OxcOnlyUsedInRecursion;
 pub use crate::rules::oxc::uninvoked_array_callback::UninvokedArrayCallback as OxcUninvokedArrayCallback;
+pub use crate::rules::playwright::missing_playwright_await::MissingPlaywrightAwait as PlaywrightMissingPlaywrightAwait;
+pub use crate::rules::playwright::no_force_option::NoForceOption as PlaywrightNoForceOption;
+pub use crate::rules::playwright::no_wait_for_timeout::NoWaitForTimeout as PlaywrightNoWaitForTimeout;
+pub use crate::rules::playwright::prefer_web_first_assertions::PreferWebFirstAssertions as PlaywrightPreferWebFirstAssertions;
 pub use crate::rules::promise::always_return::AlwaysReturn as PromiseAlwaysReturn;
 pub use crate::rules::promise::avoid_new::AvoidNew as PromiseAvoidNew;
 pub use crate::rules::promise::catch_or_return::CatchOrReturn as PromiseCatchOrReturn;
@@ -513,6 +536,30 @@ pub use crate::rules::react_perf::jsx_no_jsx_as_prop::JsxNoJsxAsProp as ReactPer
 pub use crate::rules::react_perf::jsx_no_new_array_as_prop::JsxNoNewArrayAsProp as ReactPerfJsxNoNewArrayAsProp;
 pub use crate::rules::react_perf::jsx_no_new_function_as_prop::JsxNoNewFunctionAsProp as ReactPerfJsxNoNewFunctionAsProp;
 pub use crate::rules::react_perf::jsx_no_new_object_as_prop::JsxNoNewObjectAsProp as ReactPerfJsxNoNewObjectAsProp;
+pub use crate::rules::security::detect_child_process::DetectChildProcess as SecurityDetectChildProcess;
+pub use crate::rules::security::detect_eval_with_expression::DetectEvalWithExpression as SecurityDetectEvalWithExpression;
+pub use crate::rules::security::detect_non_literal_fs_filename::DetectNonLiteralFsFilename as SecurityDetectNonLiteralFsFilename;
+pub use crate::rules::security::detect_non_literal_regexp::DetectNonLiteralRegexp as SecurityDetectNonLiteralRegexp;
+pub use crate::rules::security::detect_non_literal_require::DetectNonLiteralRequire as SecurityDetectNonLiteralRequire;
+pub use crate::rules::security::detect_possible_timing_attacks::DetectPossibleTimingAttacks as SecurityDetectPossibleTimingAttacks;
+pub use crate::rules::security::no_tainted_code::NoTaintedCode as SecurityNoTaintedCode;
+pub use crate::rules::security::no_tainted_command::NoTaintedCommand as SecurityNoTaintedCommand;
+pub use crate::rules::security::no_tainted_html::NoTaintedHtml as SecurityNoTaintedHtml;
+pub use crate::rules::security::no_tainted_path::NoTaintedPath as SecurityNoTaintedPath;
+pub use crate::rules::security::no_unsanitized_method::NoUnsanitizedMethod as SecurityNoUnsanitizedMethod;
+pub use crate::rules::security::no_unsanitized_property::NoUnsanitizedProperty as SecurityNoUnsanitizedProperty;
+pub use crate::rules::stylelint::color_no_invalid_hex::ColorNoInvalidHex as StylelintColorNoInvalidHex;
+pub use crate::rules::stylelint::declaration_block_no_duplicate_properties::DeclarationBlockNoDuplicateProperties as StylelintDeclarationBlockNoDuplicateProperties;
+pub use crate::rules::stylelint::no_descending_specificity::NoDescendingSpecificity as StylelintNoDescendingSpecificity;
+pub use crate::rules::stylelint::no_duplicate_selectors::NoDuplicateSelectors as StylelintNoDuplicateSelectors;
+pub use crate::rules::stylelint::unit_no_unknown::UnitNoUnknown as StylelintUnitNoUnknown;
+pub use crate::rules::svelte::no_at_html_tags::NoAtHtmlTags as SvelteNoAtHtmlTags;
+pub use crate::rules::svelte::no_reactive_destructuring::NoReactiveDestructuring as SvelteNoReactiveDestructuring;
+pub use crate::rules::svelte::valid_rune_placement::ValidRunePlacement as SvelteValidRunePlacement;
+pub use crate::rules::testing_library::await_async_queries::AwaitAsyncQueries as TestingLibraryAwaitAsyncQueries;
+pub use crate::rules::testing_library::no_node_access::NoNodeAccess as TestingLibraryNoNodeAccess;
+pub use crate::rules::testing_library::prefer_screen_queries::PreferScreenQueries as TestingLibraryPreferScreenQueries;
+pub use crate::rules::testing_library::prefer_user_event::PreferUserEvent as TestingLibraryPreferUserEvent;
 pub use crate::rules::typescript::adjacent_overload_signatures::AdjacentOverloadSignatures as TypescriptAdjacentOverloadSignatures;
 pub use crate::rules::typescript::array_type::ArrayType as TypescriptArrayType;
 pub use crate::rules::typescript::await_thenable::AwaitThenable as TypescriptAwaitThenable;
@@ -1715,13 +1762,20 @@ pub enum RuleEnum {
     NodeExportsStyle(NodeExportsStyle),
     NodeGlobalRequire(NodeGlobalRequire),
     NodeHandleCallbackErr(NodeHandleCallbackErr),
+    NodeHashbang(NodeHashbang),
+    NodeNoDeprecatedApi(NodeNoDeprecatedApi),
     NodeNoExportsAssign(NodeNoExportsAssign),
+    NodeNoMissingImport(NodeNoMissingImport),
+    NodeNoMissingRequire(NodeNoMissingRequire),
     NodeNoMixedRequires(NodeNoMixedRequires),
     NodeNoNewRequire(NodeNoNewRequire),
     NodeNoPathConcat(NodeNoPathConcat),
     NodeNoProcessEnv(NodeNoProcessEnv),
     NodeNoSync(NodeNoSync),
     NodeNoTopLevelAwait(NodeNoTopLevelAwait),
+    NodeNoUnpublishedImport(NodeNoUnpublishedImport),
+    NodeNoUnsupportedFeaturesNodeBuiltins(NodeNoUnsupportedFeaturesNodeBuiltins),
+    NodePreferNodeProtocol(NodePreferNodeProtocol),
     VueComponentDefinitionNameCasing(VueComponentDefinitionNameCasing),
     VueDefineEmitsDeclaration(VueDefineEmitsDeclaration),
     VueDefinePropsDeclaration(VueDefinePropsDeclaration),
@@ -1768,6 +1822,46 @@ pub enum RuleEnum {
     VueValidDefineOptions(VueValidDefineOptions),
     VueValidDefineProps(VueValidDefineProps),
     VueValidNextTick(VueValidNextTick),
+    StylelintColorNoInvalidHex(StylelintColorNoInvalidHex),
+    StylelintDeclarationBlockNoDuplicateProperties(StylelintDeclarationBlockNoDuplicateProperties),
+    StylelintNoDescendingSpecificity(StylelintNoDescendingSpecificity),
+    StylelintNoDuplicateSelectors(StylelintNoDuplicateSelectors),
+    StylelintUnitNoUnknown(StylelintUnitNoUnknown),
+    CompatCompat(CompatCompat),
+    CompatNoUnsupportedSyntax(CompatNoUnsupportedSyntax),
+    AngularNoInputRename(AngularNoInputRename),
+    AngularNoOutputOnPrefix(AngularNoOutputOnPrefix),
+    AngularTemplateBananaInBox(AngularTemplateBananaInBox),
+    AngularTemplateEqeqeq(AngularTemplateEqeqeq),
+    AngularUseLifecycleInterface(AngularUseLifecycleInterface),
+    TestingLibraryAwaitAsyncQueries(TestingLibraryAwaitAsyncQueries),
+    TestingLibraryNoNodeAccess(TestingLibraryNoNodeAccess),
+    TestingLibraryPreferScreenQueries(TestingLibraryPreferScreenQueries),
+    TestingLibraryPreferUserEvent(TestingLibraryPreferUserEvent),
+    PlaywrightMissingPlaywrightAwait(PlaywrightMissingPlaywrightAwait),
+    PlaywrightNoForceOption(PlaywrightNoForceOption),
+    PlaywrightNoWaitForTimeout(PlaywrightNoWaitForTimeout),
+    PlaywrightPreferWebFirstAssertions(PlaywrightPreferWebFirstAssertions),
+    SecurityDetectChildProcess(SecurityDetectChildProcess),
+    SecurityDetectEvalWithExpression(SecurityDetectEvalWithExpression),
+    SecurityDetectNonLiteralFsFilename(SecurityDetectNonLiteralFsFilename),
+    SecurityDetectNonLiteralRegexp(SecurityDetectNonLiteralRegexp),
+    SecurityDetectNonLiteralRequire(SecurityDetectNonLiteralRequire),
+    SecurityDetectPossibleTimingAttacks(SecurityDetectPossibleTimingAttacks),
+    SecurityNoTaintedCode(SecurityNoTaintedCode),
+    SecurityNoTaintedCommand(SecurityNoTaintedCommand),
+    SecurityNoTaintedHtml(SecurityNoTaintedHtml),
+    SecurityNoTaintedPath(SecurityNoTaintedPath),
+    SecurityNoUnsanitizedMethod(SecurityNoUnsanitizedMethod),
+    SecurityNoUnsanitizedProperty(SecurityNoUnsanitizedProperty),
+    SvelteNoAtHtmlTags(SvelteNoAtHtmlTags),
+    SvelteNoReactiveDestructuring(SvelteNoReactiveDestructuring),
+    SvelteValidRunePlacement(SvelteValidRunePlacement),
+    JsonConsistentDependencyVersions(JsonConsistentDependencyVersions),
+    JsonNoDuplicateKeys(JsonNoDuplicateKeys),
+    JsonSortDependencies(JsonSortDependencies),
+    JsonValidPackageJson(JsonValidPackageJson),
+    JsonValidTsconfig(JsonValidTsconfig),
 }
 const IMPORT_CONSISTENT_TYPE_SPECIFIER_STYLE_ID: usize = 0usize;
 const IMPORT_DEFAULT_ID: usize = IMPORT_CONSISTENT_TYPE_SPECIFIER_STYLE_ID + 1usize;
@@ -2688,14 +2782,21 @@ const NODE_CALLBACK_RETURN_ID: usize = VITEST_WARN_TODO_ID + 1usize;
 const NODE_EXPORTS_STYLE_ID: usize = NODE_CALLBACK_RETURN_ID + 1usize;
 const NODE_GLOBAL_REQUIRE_ID: usize = NODE_EXPORTS_STYLE_ID + 1usize;
 const NODE_HANDLE_CALLBACK_ERR_ID: usize = NODE_GLOBAL_REQUIRE_ID + 1usize;
-const NODE_NO_EXPORTS_ASSIGN_ID: usize = NODE_HANDLE_CALLBACK_ERR_ID + 1usize;
-const NODE_NO_MIXED_REQUIRES_ID: usize = NODE_NO_EXPORTS_ASSIGN_ID + 1usize;
+const NODE_HASHBANG_ID: usize = NODE_HANDLE_CALLBACK_ERR_ID + 1usize;
+const NODE_NO_DEPRECATED_API_ID: usize = NODE_HASHBANG_ID + 1usize;
+const NODE_NO_EXPORTS_ASSIGN_ID: usize = NODE_NO_DEPRECATED_API_ID + 1usize;
+const NODE_NO_MISSING_IMPORT_ID: usize = NODE_NO_EXPORTS_ASSIGN_ID + 1usize;
+const NODE_NO_MISSING_REQUIRE_ID: usize = NODE_NO_MISSING_IMPORT_ID + 1usize;
+const NODE_NO_MIXED_REQUIRES_ID: usize = NODE_NO_MISSING_REQUIRE_ID + 1usize;
 const NODE_NO_NEW_REQUIRE_ID: usize = NODE_NO_MIXED_REQUIRES_ID + 1usize;
 const NODE_NO_PATH_CONCAT_ID: usize = NODE_NO_NEW_REQUIRE_ID + 1usize;
 const NODE_NO_PROCESS_ENV_ID: usize = NODE_NO_PATH_CONCAT_ID + 1usize;
 const NODE_NO_SYNC_ID: usize = NODE_NO_PROCESS_ENV_ID + 1usize;
 const NODE_NO_TOP_LEVEL_AWAIT_ID: usize = NODE_NO_SYNC_ID + 1usize;
-const VUE_COMPONENT_DEFINITION_NAME_CASING_ID: usize = NODE_NO_TOP_LEVEL_AWAIT_ID + 1usize;
+const NODE_NO_UNPUBLISHED_IMPORT_ID: usize = NODE_NO_TOP_LEVEL_AWAIT_ID + 1usize;
+const NODE_NO_UNSUPPORTED_FEATURES_NODE_BUILTINS_ID: usize = NODE_NO_UNPUBLISHED_IMPORT_ID + 1usize;
+const NODE_PREFER_NODE_PROTOCOL_ID: usize = NODE_NO_UNSUPPORTED_FEATURES_NODE_BUILTINS_ID + 1usize;
+const VUE_COMPONENT_DEFINITION_NAME_CASING_ID: usize = NODE_PREFER_NODE_PROTOCOL_ID + 1usize;
 const VUE_DEFINE_EMITS_DECLARATION_ID: usize = VUE_COMPONENT_DEFINITION_NAME_CASING_ID + 1usize;
 const VUE_DEFINE_PROPS_DECLARATION_ID: usize = VUE_DEFINE_EMITS_DECLARATION_ID + 1usize;
 const VUE_DEFINE_PROPS_DESTRUCTURING_ID: usize = VUE_DEFINE_PROPS_DECLARATION_ID + 1usize;
@@ -2748,7 +2849,54 @@ const VUE_VALID_DEFINE_EMITS_ID: usize = VUE_RETURN_IN_EMITS_VALIDATOR_ID + 1usi
 const VUE_VALID_DEFINE_OPTIONS_ID: usize = VUE_VALID_DEFINE_EMITS_ID + 1usize;
 const VUE_VALID_DEFINE_PROPS_ID: usize = VUE_VALID_DEFINE_OPTIONS_ID + 1usize;
 const VUE_VALID_NEXT_TICK_ID: usize = VUE_VALID_DEFINE_PROPS_ID + 1usize;
-static RULE_NAMES: [&str; 870usize] = [
+const STYLELINT_COLOR_NO_INVALID_HEX_ID: usize = VUE_VALID_NEXT_TICK_ID + 1usize;
+const STYLELINT_DECLARATION_BLOCK_NO_DUPLICATE_PROPERTIES_ID: usize =
+    STYLELINT_COLOR_NO_INVALID_HEX_ID + 1usize;
+const STYLELINT_NO_DESCENDING_SPECIFICITY_ID: usize =
+    STYLELINT_DECLARATION_BLOCK_NO_DUPLICATE_PROPERTIES_ID + 1usize;
+const STYLELINT_NO_DUPLICATE_SELECTORS_ID: usize = STYLELINT_NO_DESCENDING_SPECIFICITY_ID + 1usize;
+const STYLELINT_UNIT_NO_UNKNOWN_ID: usize = STYLELINT_NO_DUPLICATE_SELECTORS_ID + 1usize;
+const COMPAT_COMPAT_ID: usize = STYLELINT_UNIT_NO_UNKNOWN_ID + 1usize;
+const COMPAT_NO_UNSUPPORTED_SYNTAX_ID: usize = COMPAT_COMPAT_ID + 1usize;
+const ANGULAR_NO_INPUT_RENAME_ID: usize = COMPAT_NO_UNSUPPORTED_SYNTAX_ID + 1usize;
+const ANGULAR_NO_OUTPUT_ON_PREFIX_ID: usize = ANGULAR_NO_INPUT_RENAME_ID + 1usize;
+const ANGULAR_TEMPLATE_BANANA_IN_BOX_ID: usize = ANGULAR_NO_OUTPUT_ON_PREFIX_ID + 1usize;
+const ANGULAR_TEMPLATE_EQEQEQ_ID: usize = ANGULAR_TEMPLATE_BANANA_IN_BOX_ID + 1usize;
+const ANGULAR_USE_LIFECYCLE_INTERFACE_ID: usize = ANGULAR_TEMPLATE_EQEQEQ_ID + 1usize;
+const TESTING_LIBRARY_AWAIT_ASYNC_QUERIES_ID: usize = ANGULAR_USE_LIFECYCLE_INTERFACE_ID + 1usize;
+const TESTING_LIBRARY_NO_NODE_ACCESS_ID: usize = TESTING_LIBRARY_AWAIT_ASYNC_QUERIES_ID + 1usize;
+const TESTING_LIBRARY_PREFER_SCREEN_QUERIES_ID: usize = TESTING_LIBRARY_NO_NODE_ACCESS_ID + 1usize;
+const TESTING_LIBRARY_PREFER_USER_EVENT_ID: usize =
+    TESTING_LIBRARY_PREFER_SCREEN_QUERIES_ID + 1usize;
+const PLAYWRIGHT_MISSING_PLAYWRIGHT_AWAIT_ID: usize = TESTING_LIBRARY_PREFER_USER_EVENT_ID + 1usize;
+const PLAYWRIGHT_NO_FORCE_OPTION_ID: usize = PLAYWRIGHT_MISSING_PLAYWRIGHT_AWAIT_ID + 1usize;
+const PLAYWRIGHT_NO_WAIT_FOR_TIMEOUT_ID: usize = PLAYWRIGHT_NO_FORCE_OPTION_ID + 1usize;
+const PLAYWRIGHT_PREFER_WEB_FIRST_ASSERTIONS_ID: usize = PLAYWRIGHT_NO_WAIT_FOR_TIMEOUT_ID + 1usize;
+const SECURITY_DETECT_CHILD_PROCESS_ID: usize = PLAYWRIGHT_PREFER_WEB_FIRST_ASSERTIONS_ID + 1usize;
+const SECURITY_DETECT_EVAL_WITH_EXPRESSION_ID: usize = SECURITY_DETECT_CHILD_PROCESS_ID + 1usize;
+const SECURITY_DETECT_NON_LITERAL_FS_FILENAME_ID: usize =
+    SECURITY_DETECT_EVAL_WITH_EXPRESSION_ID + 1usize;
+const SECURITY_DETECT_NON_LITERAL_REGEXP_ID: usize =
+    SECURITY_DETECT_NON_LITERAL_FS_FILENAME_ID + 1usize;
+const SECURITY_DETECT_NON_LITERAL_REQUIRE_ID: usize =
+    SECURITY_DETECT_NON_LITERAL_REGEXP_ID + 1usize;
+const SECURITY_DETECT_POSSIBLE_TIMING_ATTACKS_ID: usize =
+    SECURITY_DETECT_NON_LITERAL_REQUIRE_ID + 1usize;
+const SECURITY_NO_TAINTED_CODE_ID: usize = SECURITY_DETECT_POSSIBLE_TIMING_ATTACKS_ID + 1usize;
+const SECURITY_NO_TAINTED_COMMAND_ID: usize = SECURITY_NO_TAINTED_CODE_ID + 1usize;
+const SECURITY_NO_TAINTED_HTML_ID: usize = SECURITY_NO_TAINTED_COMMAND_ID + 1usize;
+const SECURITY_NO_TAINTED_PATH_ID: usize = SECURITY_NO_TAINTED_HTML_ID + 1usize;
+const SECURITY_NO_UNSANITIZED_METHOD_ID: usize = SECURITY_NO_TAINTED_PATH_ID + 1usize;
+const SECURITY_NO_UNSANITIZED_PROPERTY_ID: usize = SECURITY_NO_UNSANITIZED_METHOD_ID + 1usize;
+const SVELTE_NO_AT_HTML_TAGS_ID: usize = SECURITY_NO_UNSANITIZED_PROPERTY_ID + 1usize;
+const SVELTE_NO_REACTIVE_DESTRUCTURING_ID: usize = SVELTE_NO_AT_HTML_TAGS_ID + 1usize;
+const SVELTE_VALID_RUNE_PLACEMENT_ID: usize = SVELTE_NO_REACTIVE_DESTRUCTURING_ID + 1usize;
+const JSON_CONSISTENT_DEPENDENCY_VERSIONS_ID: usize = SVELTE_VALID_RUNE_PLACEMENT_ID + 1usize;
+const JSON_NO_DUPLICATE_KEYS_ID: usize = JSON_CONSISTENT_DEPENDENCY_VERSIONS_ID + 1usize;
+const JSON_SORT_DEPENDENCIES_ID: usize = JSON_NO_DUPLICATE_KEYS_ID + 1usize;
+const JSON_VALID_PACKAGE_JSON_ID: usize = JSON_SORT_DEPENDENCIES_ID + 1usize;
+const JSON_VALID_TSCONFIG_ID: usize = JSON_VALID_PACKAGE_JSON_ID + 1usize;
+static RULE_NAMES: [&str; 917usize] = [
     ImportConsistentTypeSpecifierStyle::NAME,
     ImportDefault::NAME,
     ImportExport::NAME,
@@ -3566,13 +3714,20 @@ static RULE_NAMES: [&str; 870usize] = [
     NodeExportsStyle::NAME,
     NodeGlobalRequire::NAME,
     NodeHandleCallbackErr::NAME,
+    NodeHashbang::NAME,
+    NodeNoDeprecatedApi::NAME,
     NodeNoExportsAssign::NAME,
+    NodeNoMissingImport::NAME,
+    NodeNoMissingRequire::NAME,
     NodeNoMixedRequires::NAME,
     NodeNoNewRequire::NAME,
     NodeNoPathConcat::NAME,
     NodeNoProcessEnv::NAME,
     NodeNoSync::NAME,
     NodeNoTopLevelAwait::NAME,
+    NodeNoUnpublishedImport::NAME,
+    NodeNoUnsupportedFeaturesNodeBuiltins::NAME,
+    NodePreferNodeProtocol::NAME,
     VueComponentDefinitionNameCasing::NAME,
     VueDefineEmitsDeclaration::NAME,
     VueDefinePropsDeclaration::NAME,
@@ -3619,6 +3774,46 @@ static RULE_NAMES: [&str; 870usize] = [
     VueValidDefineOptions::NAME,
     VueValidDefineProps::NAME,
     VueValidNextTick::NAME,
+    StylelintColorNoInvalidHex::NAME,
+    StylelintDeclarationBlockNoDuplicateProperties::NAME,
+    StylelintNoDescendingSpecificity::NAME,
+    StylelintNoDuplicateSelectors::NAME,
+    StylelintUnitNoUnknown::NAME,
+    CompatCompat::NAME,
+    CompatNoUnsupportedSyntax::NAME,
+    AngularNoInputRename::NAME,
+    AngularNoOutputOnPrefix::NAME,
+    AngularTemplateBananaInBox::NAME,
+    AngularTemplateEqeqeq::NAME,
+    AngularUseLifecycleInterface::NAME,
+    TestingLibraryAwaitAsyncQueries::NAME,
+    TestingLibraryNoNodeAccess::NAME,
+    TestingLibraryPreferScreenQueries::NAME,
+    TestingLibraryPreferUserEvent::NAME,
+    PlaywrightMissingPlaywrightAwait::NAME,
+    PlaywrightNoForceOption::NAME,
+    PlaywrightNoWaitForTimeout::NAME,
+    PlaywrightPreferWebFirstAssertions::NAME,
+    SecurityDetectChildProcess::NAME,
+    SecurityDetectEvalWithExpression::NAME,
+    SecurityDetectNonLiteralFsFilename::NAME,
+    SecurityDetectNonLiteralRegexp::NAME,
+    SecurityDetectNonLiteralRequire::NAME,
+    SecurityDetectPossibleTimingAttacks::NAME,
+    SecurityNoTaintedCode::NAME,
+    SecurityNoTaintedCommand::NAME,
+    SecurityNoTaintedHtml::NAME,
+    SecurityNoTaintedPath::NAME,
+    SecurityNoUnsanitizedMethod::NAME,
+    SecurityNoUnsanitizedProperty::NAME,
+    SvelteNoAtHtmlTags::NAME,
+    SvelteNoReactiveDestructuring::NAME,
+    SvelteValidRunePlacement::NAME,
+    JsonConsistentDependencyVersions::NAME,
+    JsonNoDuplicateKeys::NAME,
+    JsonSortDependencies::NAME,
+    JsonValidPackageJson::NAME,
+    JsonValidTsconfig::NAME,
 ];
 impl RuleEnum {
     pub fn id(&self) -> usize {
@@ -4566,13 +4761,22 @@ impl RuleEnum {
             Self::NodeExportsStyle(_) => NODE_EXPORTS_STYLE_ID,
             Self::NodeGlobalRequire(_) => NODE_GLOBAL_REQUIRE_ID,
             Self::NodeHandleCallbackErr(_) => NODE_HANDLE_CALLBACK_ERR_ID,
+            Self::NodeHashbang(_) => NODE_HASHBANG_ID,
+            Self::NodeNoDeprecatedApi(_) => NODE_NO_DEPRECATED_API_ID,
             Self::NodeNoExportsAssign(_) => NODE_NO_EXPORTS_ASSIGN_ID,
+            Self::NodeNoMissingImport(_) => NODE_NO_MISSING_IMPORT_ID,
+            Self::NodeNoMissingRequire(_) => NODE_NO_MISSING_REQUIRE_ID,
             Self::NodeNoMixedRequires(_) => NODE_NO_MIXED_REQUIRES_ID,
             Self::NodeNoNewRequire(_) => NODE_NO_NEW_REQUIRE_ID,
             Self::NodeNoPathConcat(_) => NODE_NO_PATH_CONCAT_ID,
             Self::NodeNoProcessEnv(_) => NODE_NO_PROCESS_ENV_ID,
             Self::NodeNoSync(_) => NODE_NO_SYNC_ID,
             Self::NodeNoTopLevelAwait(_) => NODE_NO_TOP_LEVEL_AWAIT_ID,
+            Self::NodeNoUnpublishedImport(_) => NODE_NO_UNPUBLISHED_IMPORT_ID,
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(_) => {
+                NODE_NO_UNSUPPORTED_FEATURES_NODE_BUILTINS_ID
+            }
+            Self::NodePreferNodeProtocol(_) => NODE_PREFER_NODE_PROTOCOL_ID,
             Self::VueComponentDefinitionNameCasing(_) => VUE_COMPONENT_DEFINITION_NAME_CASING_ID,
             Self::VueDefineEmitsDeclaration(_) => VUE_DEFINE_EMITS_DECLARATION_ID,
             Self::VueDefinePropsDeclaration(_) => VUE_DEFINE_PROPS_DECLARATION_ID,
@@ -4623,6 +4827,54 @@ impl RuleEnum {
             Self::VueValidDefineOptions(_) => VUE_VALID_DEFINE_OPTIONS_ID,
             Self::VueValidDefineProps(_) => VUE_VALID_DEFINE_PROPS_ID,
             Self::VueValidNextTick(_) => VUE_VALID_NEXT_TICK_ID,
+            Self::StylelintColorNoInvalidHex(_) => STYLELINT_COLOR_NO_INVALID_HEX_ID,
+            Self::StylelintDeclarationBlockNoDuplicateProperties(_) => {
+                STYLELINT_DECLARATION_BLOCK_NO_DUPLICATE_PROPERTIES_ID
+            }
+            Self::StylelintNoDescendingSpecificity(_) => STYLELINT_NO_DESCENDING_SPECIFICITY_ID,
+            Self::StylelintNoDuplicateSelectors(_) => STYLELINT_NO_DUPLICATE_SELECTORS_ID,
+            Self::StylelintUnitNoUnknown(_) => STYLELINT_UNIT_NO_UNKNOWN_ID,
+            Self::CompatCompat(_) => COMPAT_COMPAT_ID,
+            Self::CompatNoUnsupportedSyntax(_) => COMPAT_NO_UNSUPPORTED_SYNTAX_ID,
+            Self::AngularNoInputRename(_) => ANGULAR_NO_INPUT_RENAME_ID,
+            Self::AngularNoOutputOnPrefix(_) => ANGULAR_NO_OUTPUT_ON_PREFIX_ID,
+            Self::AngularTemplateBananaInBox(_) => ANGULAR_TEMPLATE_BANANA_IN_BOX_ID,
+            Self::AngularTemplateEqeqeq(_) => ANGULAR_TEMPLATE_EQEQEQ_ID,
+            Self::AngularUseLifecycleInterface(_) => ANGULAR_USE_LIFECYCLE_INTERFACE_ID,
+            Self::TestingLibraryAwaitAsyncQueries(_) => TESTING_LIBRARY_AWAIT_ASYNC_QUERIES_ID,
+            Self::TestingLibraryNoNodeAccess(_) => TESTING_LIBRARY_NO_NODE_ACCESS_ID,
+            Self::TestingLibraryPreferScreenQueries(_) => TESTING_LIBRARY_PREFER_SCREEN_QUERIES_ID,
+            Self::TestingLibraryPreferUserEvent(_) => TESTING_LIBRARY_PREFER_USER_EVENT_ID,
+            Self::PlaywrightMissingPlaywrightAwait(_) => PLAYWRIGHT_MISSING_PLAYWRIGHT_AWAIT_ID,
+            Self::PlaywrightNoForceOption(_) => PLAYWRIGHT_NO_FORCE_OPTION_ID,
+            Self::PlaywrightNoWaitForTimeout(_) => PLAYWRIGHT_NO_WAIT_FOR_TIMEOUT_ID,
+            Self::PlaywrightPreferWebFirstAssertions(_) => {
+                PLAYWRIGHT_PREFER_WEB_FIRST_ASSERTIONS_ID
+            }
+            Self::SecurityDetectChildProcess(_) => SECURITY_DETECT_CHILD_PROCESS_ID,
+            Self::SecurityDetectEvalWithExpression(_) => SECURITY_DETECT_EVAL_WITH_EXPRESSION_ID,
+            Self::SecurityDetectNonLiteralFsFilename(_) => {
+                SECURITY_DETECT_NON_LITERAL_FS_FILENAME_ID
+            }
+            Self::SecurityDetectNonLiteralRegexp(_) => SECURITY_DETECT_NON_LITERAL_REGEXP_ID,
+            Self::SecurityDetectNonLiteralRequire(_) => SECURITY_DETECT_NON_LITERAL_REQUIRE_ID,
+            Self::SecurityDetectPossibleTimingAttacks(_) => {
+                SECURITY_DETECT_POSSIBLE_TIMING_ATTACKS_ID
+            }
+            Self::SecurityNoTaintedCode(_) => SECURITY_NO_TAINTED_CODE_ID,
+            Self::SecurityNoTaintedCommand(_) => SECURITY_NO_TAINTED_COMMAND_ID,
+            Self::SecurityNoTaintedHtml(_) => SECURITY_NO_TAINTED_HTML_ID,
+            Self::SecurityNoTaintedPath(_) => SECURITY_NO_TAINTED_PATH_ID,
+            Self::SecurityNoUnsanitizedMethod(_) => SECURITY_NO_UNSANITIZED_METHOD_ID,
+            Self::SecurityNoUnsanitizedProperty(_) => SECURITY_NO_UNSANITIZED_PROPERTY_ID,
+            Self::SvelteNoAtHtmlTags(_) => SVELTE_NO_AT_HTML_TAGS_ID,
+            Self::SvelteNoReactiveDestructuring(_) => SVELTE_NO_REACTIVE_DESTRUCTURING_ID,
+            Self::SvelteValidRunePlacement(_) => SVELTE_VALID_RUNE_PLACEMENT_ID,
+            Self::JsonConsistentDependencyVersions(_) => JSON_CONSISTENT_DEPENDENCY_VERSIONS_ID,
+            Self::JsonNoDuplicateKeys(_) => JSON_NO_DUPLICATE_KEYS_ID,
+            Self::JsonSortDependencies(_) => JSON_SORT_DEPENDENCIES_ID,
+            Self::JsonValidPackageJson(_) => JSON_VALID_PACKAGE_JSON_ID,
+            Self::JsonValidTsconfig(_) => JSON_VALID_TSCONFIG_ID,
         }
     }
     pub fn name(&self) -> &'static str {
@@ -5615,13 +5867,22 @@ impl RuleEnum {
             Self::NodeExportsStyle(_) => NodeExportsStyle::CATEGORY,
             Self::NodeGlobalRequire(_) => NodeGlobalRequire::CATEGORY,
             Self::NodeHandleCallbackErr(_) => NodeHandleCallbackErr::CATEGORY,
+            Self::NodeHashbang(_) => NodeHashbang::CATEGORY,
+            Self::NodeNoDeprecatedApi(_) => NodeNoDeprecatedApi::CATEGORY,
             Self::NodeNoExportsAssign(_) => NodeNoExportsAssign::CATEGORY,
+            Self::NodeNoMissingImport(_) => NodeNoMissingImport::CATEGORY,
+            Self::NodeNoMissingRequire(_) => NodeNoMissingRequire::CATEGORY,
             Self::NodeNoMixedRequires(_) => NodeNoMixedRequires::CATEGORY,
             Self::NodeNoNewRequire(_) => NodeNoNewRequire::CATEGORY,
             Self::NodeNoPathConcat(_) => NodeNoPathConcat::CATEGORY,
             Self::NodeNoProcessEnv(_) => NodeNoProcessEnv::CATEGORY,
             Self::NodeNoSync(_) => NodeNoSync::CATEGORY,
             Self::NodeNoTopLevelAwait(_) => NodeNoTopLevelAwait::CATEGORY,
+            Self::NodeNoUnpublishedImport(_) => NodeNoUnpublishedImport::CATEGORY,
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(_) => {
+                NodeNoUnsupportedFeaturesNodeBuiltins::CATEGORY
+            }
+            Self::NodePreferNodeProtocol(_) => NodePreferNodeProtocol::CATEGORY,
             Self::VueComponentDefinitionNameCasing(_) => VueComponentDefinitionNameCasing::CATEGORY,
             Self::VueDefineEmitsDeclaration(_) => VueDefineEmitsDeclaration::CATEGORY,
             Self::VueDefinePropsDeclaration(_) => VueDefinePropsDeclaration::CATEGORY,
@@ -5674,6 +5935,56 @@ impl RuleEnum {
             Self::VueValidDefineOptions(_) => VueValidDefineOptions::CATEGORY,
             Self::VueValidDefineProps(_) => VueValidDefineProps::CATEGORY,
             Self::VueValidNextTick(_) => VueValidNextTick::CATEGORY,
+            Self::StylelintColorNoInvalidHex(_) => StylelintColorNoInvalidHex::CATEGORY,
+            Self::StylelintDeclarationBlockNoDuplicateProperties(_) => {
+                StylelintDeclarationBlockNoDuplicateProperties::CATEGORY
+            }
+            Self::StylelintNoDescendingSpecificity(_) => StylelintNoDescendingSpecificity::CATEGORY,
+            Self::StylelintNoDuplicateSelectors(_) => StylelintNoDuplicateSelectors::CATEGORY,
+            Self::StylelintUnitNoUnknown(_) => StylelintUnitNoUnknown::CATEGORY,
+            Self::CompatCompat(_) => CompatCompat::CATEGORY,
+            Self::CompatNoUnsupportedSyntax(_) => CompatNoUnsupportedSyntax::CATEGORY,
+            Self::AngularNoInputRename(_) => AngularNoInputRename::CATEGORY,
+            Self::AngularNoOutputOnPrefix(_) => AngularNoOutputOnPrefix::CATEGORY,
+            Self::AngularTemplateBananaInBox(_) => AngularTemplateBananaInBox::CATEGORY,
+            Self::AngularTemplateEqeqeq(_) => AngularTemplateEqeqeq::CATEGORY,
+            Self::AngularUseLifecycleInterface(_) => AngularUseLifecycleInterface::CATEGORY,
+            Self::TestingLibraryAwaitAsyncQueries(_) => TestingLibraryAwaitAsyncQueries::CATEGORY,
+            Self::TestingLibraryNoNodeAccess(_) => TestingLibraryNoNodeAccess::CATEGORY,
+            Self::TestingLibraryPreferScreenQueries(_) => {
+                TestingLibraryPreferScreenQueries::CATEGORY
+            }
+            Self::TestingLibraryPreferUserEvent(_) => TestingLibraryPreferUserEvent::CATEGORY,
+            Self::PlaywrightMissingPlaywrightAwait(_) => PlaywrightMissingPlaywrightAwait::CATEGORY,
+            Self::PlaywrightNoForceOption(_) => PlaywrightNoForceOption::CATEGORY,
+            Self::PlaywrightNoWaitForTimeout(_) => PlaywrightNoWaitForTimeout::CATEGORY,
+            Self::PlaywrightPreferWebFirstAssertions(_) => {
+                PlaywrightPreferWebFirstAssertions::CATEGORY
+            }
+            Self::SecurityDetectChildProcess(_) => SecurityDetectChildProcess::CATEGORY,
+            Self::SecurityDetectEvalWithExpression(_) => SecurityDetectEvalWithExpression::CATEGORY,
+            Self::SecurityDetectNonLiteralFsFilename(_) => {
+                SecurityDetectNonLiteralFsFilename::CATEGORY
+            }
+            Self::SecurityDetectNonLiteralRegexp(_) => SecurityDetectNonLiteralRegexp::CATEGORY,
+            Self::SecurityDetectNonLiteralRequire(_) => SecurityDetectNonLiteralRequire::CATEGORY,
+            Self::SecurityDetectPossibleTimingAttacks(_) => {
+                SecurityDetectPossibleTimingAttacks::CATEGORY
+            }
+            Self::SecurityNoTaintedCode(_) => SecurityNoTaintedCode::CATEGORY,
+            Self::SecurityNoTaintedCommand(_) => SecurityNoTaintedCommand::CATEGORY,
+            Self::SecurityNoTaintedHtml(_) => SecurityNoTaintedHtml::CATEGORY,
+            Self::SecurityNoTaintedPath(_) => SecurityNoTaintedPath::CATEGORY,
+            Self::SecurityNoUnsanitizedMethod(_) => SecurityNoUnsanitizedMethod::CATEGORY,
+            Self::SecurityNoUnsanitizedProperty(_) => SecurityNoUnsanitizedProperty::CATEGORY,
+            Self::SvelteNoAtHtmlTags(_) => SvelteNoAtHtmlTags::CATEGORY,
+            Self::SvelteNoReactiveDestructuring(_) => SvelteNoReactiveDestructuring::CATEGORY,
+            Self::SvelteValidRunePlacement(_) => SvelteValidRunePlacement::CATEGORY,
+            Self::JsonConsistentDependencyVersions(_) => JsonConsistentDependencyVersions::CATEGORY,
+            Self::JsonNoDuplicateKeys(_) => JsonNoDuplicateKeys::CATEGORY,
+            Self::JsonSortDependencies(_) => JsonSortDependencies::CATEGORY,
+            Self::JsonValidPackageJson(_) => JsonValidPackageJson::CATEGORY,
+            Self::JsonValidTsconfig(_) => JsonValidTsconfig::CATEGORY,
         }
     }
     #[doc = r" This [`Rule`]'s auto-fix capabilities."]
@@ -6606,13 +6917,22 @@ impl RuleEnum {
             Self::NodeExportsStyle(_) => NodeExportsStyle::FIX,
             Self::NodeGlobalRequire(_) => NodeGlobalRequire::FIX,
             Self::NodeHandleCallbackErr(_) => NodeHandleCallbackErr::FIX,
+            Self::NodeHashbang(_) => NodeHashbang::FIX,
+            Self::NodeNoDeprecatedApi(_) => NodeNoDeprecatedApi::FIX,
             Self::NodeNoExportsAssign(_) => NodeNoExportsAssign::FIX,
+            Self::NodeNoMissingImport(_) => NodeNoMissingImport::FIX,
+            Self::NodeNoMissingRequire(_) => NodeNoMissingRequire::FIX,
             Self::NodeNoMixedRequires(_) => NodeNoMixedRequires::FIX,
             Self::NodeNoNewRequire(_) => NodeNoNewRequire::FIX,
             Self::NodeNoPathConcat(_) => NodeNoPathConcat::FIX,
             Self::NodeNoProcessEnv(_) => NodeNoProcessEnv::FIX,
             Self::NodeNoSync(_) => NodeNoSync::FIX,
             Self::NodeNoTopLevelAwait(_) => NodeNoTopLevelAwait::FIX,
+            Self::NodeNoUnpublishedImport(_) => NodeNoUnpublishedImport::FIX,
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(_) => {
+                NodeNoUnsupportedFeaturesNodeBuiltins::FIX
+            }
+            Self::NodePreferNodeProtocol(_) => NodePreferNodeProtocol::FIX,
             Self::VueComponentDefinitionNameCasing(_) => VueComponentDefinitionNameCasing::FIX,
             Self::VueDefineEmitsDeclaration(_) => VueDefineEmitsDeclaration::FIX,
             Self::VueDefinePropsDeclaration(_) => VueDefinePropsDeclaration::FIX,
@@ -6663,6 +6983,50 @@ impl RuleEnum {
             Self::VueValidDefineOptions(_) => VueValidDefineOptions::FIX,
             Self::VueValidDefineProps(_) => VueValidDefineProps::FIX,
             Self::VueValidNextTick(_) => VueValidNextTick::FIX,
+            Self::StylelintColorNoInvalidHex(_) => StylelintColorNoInvalidHex::FIX,
+            Self::StylelintDeclarationBlockNoDuplicateProperties(_) => {
+                StylelintDeclarationBlockNoDuplicateProperties::FIX
+            }
+            Self::StylelintNoDescendingSpecificity(_) => StylelintNoDescendingSpecificity::FIX,
+            Self::StylelintNoDuplicateSelectors(_) => StylelintNoDuplicateSelectors::FIX,
+            Self::StylelintUnitNoUnknown(_) => StylelintUnitNoUnknown::FIX,
+            Self::CompatCompat(_) => CompatCompat::FIX,
+            Self::CompatNoUnsupportedSyntax(_) => CompatNoUnsupportedSyntax::FIX,
+            Self::AngularNoInputRename(_) => AngularNoInputRename::FIX,
+            Self::AngularNoOutputOnPrefix(_) => AngularNoOutputOnPrefix::FIX,
+            Self::AngularTemplateBananaInBox(_) => AngularTemplateBananaInBox::FIX,
+            Self::AngularTemplateEqeqeq(_) => AngularTemplateEqeqeq::FIX,
+            Self::AngularUseLifecycleInterface(_) => AngularUseLifecycleInterface::FIX,
+            Self::TestingLibraryAwaitAsyncQueries(_) => TestingLibraryAwaitAsyncQueries::FIX,
+            Self::TestingLibraryNoNodeAccess(_) => TestingLibraryNoNodeAccess::FIX,
+            Self::TestingLibraryPreferScreenQueries(_) => TestingLibraryPreferScreenQueries::FIX,
+            Self::TestingLibraryPreferUserEvent(_) => TestingLibraryPreferUserEvent::FIX,
+            Self::PlaywrightMissingPlaywrightAwait(_) => PlaywrightMissingPlaywrightAwait::FIX,
+            Self::PlaywrightNoForceOption(_) => PlaywrightNoForceOption::FIX,
+            Self::PlaywrightNoWaitForTimeout(_) => PlaywrightNoWaitForTimeout::FIX,
+            Self::PlaywrightPreferWebFirstAssertions(_) => PlaywrightPreferWebFirstAssertions::FIX,
+            Self::SecurityDetectChildProcess(_) => SecurityDetectChildProcess::FIX,
+            Self::SecurityDetectEvalWithExpression(_) => SecurityDetectEvalWithExpression::FIX,
+            Self::SecurityDetectNonLiteralFsFilename(_) => SecurityDetectNonLiteralFsFilename::FIX,
+            Self::SecurityDetectNonLiteralRegexp(_) => SecurityDetectNonLiteralRegexp::FIX,
+            Self::SecurityDetectNonLiteralRequire(_) => SecurityDetectNonLiteralRequire::FIX,
+            Self::SecurityDetectPossibleTimingAttacks(_) => {
+                SecurityDetectPossibleTimingAttacks::FIX
+            }
+            Self::SecurityNoTaintedCode(_) => SecurityNoTaintedCode::FIX,
+            Self::SecurityNoTaintedCommand(_) => SecurityNoTaintedCommand::FIX,
+            Self::SecurityNoTaintedHtml(_) => SecurityNoTaintedHtml::FIX,
+            Self::SecurityNoTaintedPath(_) => SecurityNoTaintedPath::FIX,
+            Self::SecurityNoUnsanitizedMethod(_) => SecurityNoUnsanitizedMethod::FIX,
+            Self::SecurityNoUnsanitizedProperty(_) => SecurityNoUnsanitizedProperty::FIX,
+            Self::SvelteNoAtHtmlTags(_) => SvelteNoAtHtmlTags::FIX,
+            Self::SvelteNoReactiveDestructuring(_) => SvelteNoReactiveDestructuring::FIX,
+            Self::SvelteValidRunePlacement(_) => SvelteValidRunePlacement::FIX,
+            Self::JsonConsistentDependencyVersions(_) => JsonConsistentDependencyVersions::FIX,
+            Self::JsonNoDuplicateKeys(_) => JsonNoDuplicateKeys::FIX,
+            Self::JsonSortDependencies(_) => JsonSortDependencies::FIX,
+            Self::JsonValidPackageJson(_) => JsonValidPackageJson::FIX,
+            Self::JsonValidTsconfig(_) => JsonValidTsconfig::FIX,
         }
     }
     #[cfg(feature = "ruledocs")]
@@ -7853,13 +8217,22 @@ impl RuleEnum {
             Self::NodeExportsStyle(_) => NodeExportsStyle::documentation(),
             Self::NodeGlobalRequire(_) => NodeGlobalRequire::documentation(),
             Self::NodeHandleCallbackErr(_) => NodeHandleCallbackErr::documentation(),
+            Self::NodeHashbang(_) => NodeHashbang::documentation(),
+            Self::NodeNoDeprecatedApi(_) => NodeNoDeprecatedApi::documentation(),
             Self::NodeNoExportsAssign(_) => NodeNoExportsAssign::documentation(),
+            Self::NodeNoMissingImport(_) => NodeNoMissingImport::documentation(),
+            Self::NodeNoMissingRequire(_) => NodeNoMissingRequire::documentation(),
             Self::NodeNoMixedRequires(_) => NodeNoMixedRequires::documentation(),
             Self::NodeNoNewRequire(_) => NodeNoNewRequire::documentation(),
             Self::NodeNoPathConcat(_) => NodeNoPathConcat::documentation(),
             Self::NodeNoProcessEnv(_) => NodeNoProcessEnv::documentation(),
             Self::NodeNoSync(_) => NodeNoSync::documentation(),
             Self::NodeNoTopLevelAwait(_) => NodeNoTopLevelAwait::documentation(),
+            Self::NodeNoUnpublishedImport(_) => NodeNoUnpublishedImport::documentation(),
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(_) => {
+                NodeNoUnsupportedFeaturesNodeBuiltins::documentation()
+            }
+            Self::NodePreferNodeProtocol(_) => NodePreferNodeProtocol::documentation(),
             Self::VueComponentDefinitionNameCasing(_) => {
                 VueComponentDefinitionNameCasing::documentation()
             }
@@ -7926,6 +8299,78 @@ impl RuleEnum {
             Self::VueValidDefineOptions(_) => VueValidDefineOptions::documentation(),
             Self::VueValidDefineProps(_) => VueValidDefineProps::documentation(),
             Self::VueValidNextTick(_) => VueValidNextTick::documentation(),
+            Self::StylelintColorNoInvalidHex(_) => StylelintColorNoInvalidHex::documentation(),
+            Self::StylelintDeclarationBlockNoDuplicateProperties(_) => {
+                StylelintDeclarationBlockNoDuplicateProperties::documentation()
+            }
+            Self::StylelintNoDescendingSpecificity(_) => {
+                StylelintNoDescendingSpecificity::documentation()
+            }
+            Self::StylelintNoDuplicateSelectors(_) => {
+                StylelintNoDuplicateSelectors::documentation()
+            }
+            Self::StylelintUnitNoUnknown(_) => StylelintUnitNoUnknown::documentation(),
+            Self::CompatCompat(_) => CompatCompat::documentation(),
+            Self::CompatNoUnsupportedSyntax(_) => CompatNoUnsupportedSyntax::documentation(),
+            Self::AngularNoInputRename(_) => AngularNoInputRename::documentation(),
+            Self::AngularNoOutputOnPrefix(_) => AngularNoOutputOnPrefix::documentation(),
+            Self::AngularTemplateBananaInBox(_) => AngularTemplateBananaInBox::documentation(),
+            Self::AngularTemplateEqeqeq(_) => AngularTemplateEqeqeq::documentation(),
+            Self::AngularUseLifecycleInterface(_) => AngularUseLifecycleInterface::documentation(),
+            Self::TestingLibraryAwaitAsyncQueries(_) => {
+                TestingLibraryAwaitAsyncQueries::documentation()
+            }
+            Self::TestingLibraryNoNodeAccess(_) => TestingLibraryNoNodeAccess::documentation(),
+            Self::TestingLibraryPreferScreenQueries(_) => {
+                TestingLibraryPreferScreenQueries::documentation()
+            }
+            Self::TestingLibraryPreferUserEvent(_) => {
+                TestingLibraryPreferUserEvent::documentation()
+            }
+            Self::PlaywrightMissingPlaywrightAwait(_) => {
+                PlaywrightMissingPlaywrightAwait::documentation()
+            }
+            Self::PlaywrightNoForceOption(_) => PlaywrightNoForceOption::documentation(),
+            Self::PlaywrightNoWaitForTimeout(_) => PlaywrightNoWaitForTimeout::documentation(),
+            Self::PlaywrightPreferWebFirstAssertions(_) => {
+                PlaywrightPreferWebFirstAssertions::documentation()
+            }
+            Self::SecurityDetectChildProcess(_) => SecurityDetectChildProcess::documentation(),
+            Self::SecurityDetectEvalWithExpression(_) => {
+                SecurityDetectEvalWithExpression::documentation()
+            }
+            Self::SecurityDetectNonLiteralFsFilename(_) => {
+                SecurityDetectNonLiteralFsFilename::documentation()
+            }
+            Self::SecurityDetectNonLiteralRegexp(_) => {
+                SecurityDetectNonLiteralRegexp::documentation()
+            }
+            Self::SecurityDetectNonLiteralRequire(_) => {
+                SecurityDetectNonLiteralRequire::documentation()
+            }
+            Self::SecurityDetectPossibleTimingAttacks(_) => {
+                SecurityDetectPossibleTimingAttacks::documentation()
+            }
+            Self::SecurityNoTaintedCode(_) => SecurityNoTaintedCode::documentation(),
+            Self::SecurityNoTaintedCommand(_) => SecurityNoTaintedCommand::documentation(),
+            Self::SecurityNoTaintedHtml(_) => SecurityNoTaintedHtml::documentation(),
+            Self::SecurityNoTaintedPath(_) => SecurityNoTaintedPath::documentation(),
+            Self::SecurityNoUnsanitizedMethod(_) => SecurityNoUnsanitizedMethod::documentation(),
+            Self::SecurityNoUnsanitizedProperty(_) => {
+                SecurityNoUnsanitizedProperty::documentation()
+            }
+            Self::SvelteNoAtHtmlTags(_) => SvelteNoAtHtmlTags::documentation(),
+            Self::SvelteNoReactiveDestructuring(_) => {
+                SvelteNoReactiveDestructuring::documentation()
+            }
+            Self::SvelteValidRunePlacement(_) => SvelteValidRunePlacement::documentation(),
+            Self::JsonConsistentDependencyVersions(_) => {
+                JsonConsistentDependencyVersions::documentation()
+            }
+            Self::JsonNoDuplicateKeys(_) => JsonNoDuplicateKeys::documentation(),
+            Self::JsonSortDependencies(_) => JsonSortDependencies::documentation(),
+            Self::JsonValidPackageJson(_) => JsonValidPackageJson::documentation(),
+            Self::JsonValidTsconfig(_) => JsonValidTsconfig::documentation(),
         }
     }
     #[cfg(feature = "ruledocs")]
@@ -10274,8 +10719,17 @@ impl RuleEnum {
                 .or_else(|| NodeGlobalRequire::schema(generator)),
             Self::NodeHandleCallbackErr(_) => NodeHandleCallbackErr::config_schema(generator)
                 .or_else(|| NodeHandleCallbackErr::schema(generator)),
+            Self::NodeHashbang(_) => {
+                NodeHashbang::config_schema(generator).or_else(|| NodeHashbang::schema(generator))
+            }
+            Self::NodeNoDeprecatedApi(_) => NodeNoDeprecatedApi::config_schema(generator)
+                .or_else(|| NodeNoDeprecatedApi::schema(generator)),
             Self::NodeNoExportsAssign(_) => NodeNoExportsAssign::config_schema(generator)
                 .or_else(|| NodeNoExportsAssign::schema(generator)),
+            Self::NodeNoMissingImport(_) => NodeNoMissingImport::config_schema(generator)
+                .or_else(|| NodeNoMissingImport::schema(generator)),
+            Self::NodeNoMissingRequire(_) => NodeNoMissingRequire::config_schema(generator)
+                .or_else(|| NodeNoMissingRequire::schema(generator)),
             Self::NodeNoMixedRequires(_) => NodeNoMixedRequires::config_schema(generator)
                 .or_else(|| NodeNoMixedRequires::schema(generator)),
             Self::NodeNoNewRequire(_) => NodeNoNewRequire::config_schema(generator)
@@ -10289,6 +10743,14 @@ impl RuleEnum {
             }
             Self::NodeNoTopLevelAwait(_) => NodeNoTopLevelAwait::config_schema(generator)
                 .or_else(|| NodeNoTopLevelAwait::schema(generator)),
+            Self::NodeNoUnpublishedImport(_) => NodeNoUnpublishedImport::config_schema(generator)
+                .or_else(|| NodeNoUnpublishedImport::schema(generator)),
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(_) => {
+                NodeNoUnsupportedFeaturesNodeBuiltins::config_schema(generator)
+                    .or_else(|| NodeNoUnsupportedFeaturesNodeBuiltins::schema(generator))
+            }
+            Self::NodePreferNodeProtocol(_) => NodePreferNodeProtocol::config_schema(generator)
+                .or_else(|| NodePreferNodeProtocol::schema(generator)),
             Self::VueComponentDefinitionNameCasing(_) => {
                 VueComponentDefinitionNameCasing::config_schema(generator)
                     .or_else(|| VueComponentDefinitionNameCasing::schema(generator))
@@ -10425,6 +10887,135 @@ impl RuleEnum {
                 .or_else(|| VueValidDefineProps::schema(generator)),
             Self::VueValidNextTick(_) => VueValidNextTick::config_schema(generator)
                 .or_else(|| VueValidNextTick::schema(generator)),
+            Self::StylelintColorNoInvalidHex(_) => {
+                StylelintColorNoInvalidHex::config_schema(generator)
+                    .or_else(|| StylelintColorNoInvalidHex::schema(generator))
+            }
+            Self::StylelintDeclarationBlockNoDuplicateProperties(_) => {
+                StylelintDeclarationBlockNoDuplicateProperties::config_schema(generator)
+                    .or_else(|| StylelintDeclarationBlockNoDuplicateProperties::schema(generator))
+            }
+            Self::StylelintNoDescendingSpecificity(_) => {
+                StylelintNoDescendingSpecificity::config_schema(generator)
+                    .or_else(|| StylelintNoDescendingSpecificity::schema(generator))
+            }
+            Self::StylelintNoDuplicateSelectors(_) => {
+                StylelintNoDuplicateSelectors::config_schema(generator)
+                    .or_else(|| StylelintNoDuplicateSelectors::schema(generator))
+            }
+            Self::StylelintUnitNoUnknown(_) => StylelintUnitNoUnknown::config_schema(generator)
+                .or_else(|| StylelintUnitNoUnknown::schema(generator)),
+            Self::CompatCompat(_) => {
+                CompatCompat::config_schema(generator).or_else(|| CompatCompat::schema(generator))
+            }
+            Self::CompatNoUnsupportedSyntax(_) => {
+                CompatNoUnsupportedSyntax::config_schema(generator)
+                    .or_else(|| CompatNoUnsupportedSyntax::schema(generator))
+            }
+            Self::AngularNoInputRename(_) => AngularNoInputRename::config_schema(generator)
+                .or_else(|| AngularNoInputRename::schema(generator)),
+            Self::AngularNoOutputOnPrefix(_) => AngularNoOutputOnPrefix::config_schema(generator)
+                .or_else(|| AngularNoOutputOnPrefix::schema(generator)),
+            Self::AngularTemplateBananaInBox(_) => {
+                AngularTemplateBananaInBox::config_schema(generator)
+                    .or_else(|| AngularTemplateBananaInBox::schema(generator))
+            }
+            Self::AngularTemplateEqeqeq(_) => AngularTemplateEqeqeq::config_schema(generator)
+                .or_else(|| AngularTemplateEqeqeq::schema(generator)),
+            Self::AngularUseLifecycleInterface(_) => {
+                AngularUseLifecycleInterface::config_schema(generator)
+                    .or_else(|| AngularUseLifecycleInterface::schema(generator))
+            }
+            Self::TestingLibraryAwaitAsyncQueries(_) => {
+                TestingLibraryAwaitAsyncQueries::config_schema(generator)
+                    .or_else(|| TestingLibraryAwaitAsyncQueries::schema(generator))
+            }
+            Self::TestingLibraryNoNodeAccess(_) => {
+                TestingLibraryNoNodeAccess::config_schema(generator)
+                    .or_else(|| TestingLibraryNoNodeAccess::schema(generator))
+            }
+            Self::TestingLibraryPreferScreenQueries(_) => {
+                TestingLibraryPreferScreenQueries::config_schema(generator)
+                    .or_else(|| TestingLibraryPreferScreenQueries::schema(generator))
+            }
+            Self::TestingLibraryPreferUserEvent(_) => {
+                TestingLibraryPreferUserEvent::config_schema(generator)
+                    .or_else(|| TestingLibraryPreferUserEvent::schema(generator))
+            }
+            Self::PlaywrightMissingPlaywrightAwait(_) => {
+                PlaywrightMissingPlaywrightAwait::config_schema(generator)
+                    .or_else(|| PlaywrightMissingPlaywrightAwait::schema(generator))
+            }
+            Self::PlaywrightNoForceOption(_) => PlaywrightNoForceOption::config_schema(generator)
+                .or_else(|| PlaywrightNoForceOption::schema(generator)),
+            Self::PlaywrightNoWaitForTimeout(_) => {
+                PlaywrightNoWaitForTimeout::config_schema(generator)
+                    .or_else(|| PlaywrightNoWaitForTimeout::schema(generator))
+            }
+            Self::PlaywrightPreferWebFirstAssertions(_) => {
+                PlaywrightPreferWebFirstAssertions::config_schema(generator)
+                    .or_else(|| PlaywrightPreferWebFirstAssertions::schema(generator))
+            }
+            Self::SecurityDetectChildProcess(_) => {
+                SecurityDetectChildProcess::config_schema(generator)
+                    .or_else(|| SecurityDetectChildProcess::schema(generator))
+            }
+            Self::SecurityDetectEvalWithExpression(_) => {
+                SecurityDetectEvalWithExpression::config_schema(generator)
+                    .or_else(|| SecurityDetectEvalWithExpression::schema(generator))
+            }
+            Self::SecurityDetectNonLiteralFsFilename(_) => {
+                SecurityDetectNonLiteralFsFilename::config_schema(generator)
+                    .or_else(|| SecurityDetectNonLiteralFsFilename::schema(generator))
+            }
+            Self::SecurityDetectNonLiteralRegexp(_) => {
+                SecurityDetectNonLiteralRegexp::config_schema(generator)
+                    .or_else(|| SecurityDetectNonLiteralRegexp::schema(generator))
+            }
+            Self::SecurityDetectNonLiteralRequire(_) => {
+                SecurityDetectNonLiteralRequire::config_schema(generator)
+                    .or_else(|| SecurityDetectNonLiteralRequire::schema(generator))
+            }
+            Self::SecurityDetectPossibleTimingAttacks(_) => {
+                SecurityDetectPossibleTimingAttacks::config_schema(generator)
+                    .or_else(|| SecurityDetectPossibleTimingAttacks::schema(generator))
+            }
+            Self::SecurityNoTaintedCode(_) => SecurityNoTaintedCode::config_schema(generator)
+                .or_else(|| SecurityNoTaintedCode::schema(generator)),
+            Self::SecurityNoTaintedCommand(_) => SecurityNoTaintedCommand::config_schema(generator)
+                .or_else(|| SecurityNoTaintedCommand::schema(generator)),
+            Self::SecurityNoTaintedHtml(_) => SecurityNoTaintedHtml::config_schema(generator)
+                .or_else(|| SecurityNoTaintedHtml::schema(generator)),
+            Self::SecurityNoTaintedPath(_) => SecurityNoTaintedPath::config_schema(generator)
+                .or_else(|| SecurityNoTaintedPath::schema(generator)),
+            Self::SecurityNoUnsanitizedMethod(_) => {
+                SecurityNoUnsanitizedMethod::config_schema(generator)
+                    .or_else(|| SecurityNoUnsanitizedMethod::schema(generator))
+            }
+            Self::SecurityNoUnsanitizedProperty(_) => {
+                SecurityNoUnsanitizedProperty::config_schema(generator)
+                    .or_else(|| SecurityNoUnsanitizedProperty::schema(generator))
+            }
+            Self::SvelteNoAtHtmlTags(_) => SvelteNoAtHtmlTags::config_schema(generator)
+                .or_else(|| SvelteNoAtHtmlTags::schema(generator)),
+            Self::SvelteNoReactiveDestructuring(_) => {
+                SvelteNoReactiveDestructuring::config_schema(generator)
+                    .or_else(|| SvelteNoReactiveDestructuring::schema(generator))
+            }
+            Self::SvelteValidRunePlacement(_) => SvelteValidRunePlacement::config_schema(generator)
+                .or_else(|| SvelteValidRunePlacement::schema(generator)),
+            Self::JsonConsistentDependencyVersions(_) => {
+                JsonConsistentDependencyVersions::config_schema(generator)
+                    .or_else(|| JsonConsistentDependencyVersions::schema(generator))
+            }
+            Self::JsonNoDuplicateKeys(_) => JsonNoDuplicateKeys::config_schema(generator)
+                .or_else(|| JsonNoDuplicateKeys::schema(generator)),
+            Self::JsonSortDependencies(_) => JsonSortDependencies::config_schema(generator)
+                .or_else(|| JsonSortDependencies::schema(generator)),
+            Self::JsonValidPackageJson(_) => JsonValidPackageJson::config_schema(generator)
+                .or_else(|| JsonValidPackageJson::schema(generator)),
+            Self::JsonValidTsconfig(_) => JsonValidTsconfig::config_schema(generator)
+                .or_else(|| JsonValidTsconfig::schema(generator)),
         }
     }
     pub fn plugin_name(&self) -> &'static str {
@@ -11246,13 +11837,20 @@ impl RuleEnum {
             Self::NodeExportsStyle(_) => "node",
             Self::NodeGlobalRequire(_) => "node",
             Self::NodeHandleCallbackErr(_) => "node",
+            Self::NodeHashbang(_) => "node",
+            Self::NodeNoDeprecatedApi(_) => "node",
             Self::NodeNoExportsAssign(_) => "node",
+            Self::NodeNoMissingImport(_) => "node",
+            Self::NodeNoMissingRequire(_) => "node",
             Self::NodeNoMixedRequires(_) => "node",
             Self::NodeNoNewRequire(_) => "node",
             Self::NodeNoPathConcat(_) => "node",
             Self::NodeNoProcessEnv(_) => "node",
             Self::NodeNoSync(_) => "node",
             Self::NodeNoTopLevelAwait(_) => "node",
+            Self::NodeNoUnpublishedImport(_) => "node",
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(_) => "node",
+            Self::NodePreferNodeProtocol(_) => "node",
             Self::VueComponentDefinitionNameCasing(_) => "vue",
             Self::VueDefineEmitsDeclaration(_) => "vue",
             Self::VueDefinePropsDeclaration(_) => "vue",
@@ -11299,6 +11897,46 @@ impl RuleEnum {
             Self::VueValidDefineOptions(_) => "vue",
             Self::VueValidDefineProps(_) => "vue",
             Self::VueValidNextTick(_) => "vue",
+            Self::StylelintColorNoInvalidHex(_) => "stylelint",
+            Self::StylelintDeclarationBlockNoDuplicateProperties(_) => "stylelint",
+            Self::StylelintNoDescendingSpecificity(_) => "stylelint",
+            Self::StylelintNoDuplicateSelectors(_) => "stylelint",
+            Self::StylelintUnitNoUnknown(_) => "stylelint",
+            Self::CompatCompat(_) => "compat",
+            Self::CompatNoUnsupportedSyntax(_) => "compat",
+            Self::AngularNoInputRename(_) => "angular",
+            Self::AngularNoOutputOnPrefix(_) => "angular",
+            Self::AngularTemplateBananaInBox(_) => "angular",
+            Self::AngularTemplateEqeqeq(_) => "angular",
+            Self::AngularUseLifecycleInterface(_) => "angular",
+            Self::TestingLibraryAwaitAsyncQueries(_) => "testing_library",
+            Self::TestingLibraryNoNodeAccess(_) => "testing_library",
+            Self::TestingLibraryPreferScreenQueries(_) => "testing_library",
+            Self::TestingLibraryPreferUserEvent(_) => "testing_library",
+            Self::PlaywrightMissingPlaywrightAwait(_) => "playwright",
+            Self::PlaywrightNoForceOption(_) => "playwright",
+            Self::PlaywrightNoWaitForTimeout(_) => "playwright",
+            Self::PlaywrightPreferWebFirstAssertions(_) => "playwright",
+            Self::SecurityDetectChildProcess(_) => "security",
+            Self::SecurityDetectEvalWithExpression(_) => "security",
+            Self::SecurityDetectNonLiteralFsFilename(_) => "security",
+            Self::SecurityDetectNonLiteralRegexp(_) => "security",
+            Self::SecurityDetectNonLiteralRequire(_) => "security",
+            Self::SecurityDetectPossibleTimingAttacks(_) => "security",
+            Self::SecurityNoTaintedCode(_) => "security",
+            Self::SecurityNoTaintedCommand(_) => "security",
+            Self::SecurityNoTaintedHtml(_) => "security",
+            Self::SecurityNoTaintedPath(_) => "security",
+            Self::SecurityNoUnsanitizedMethod(_) => "security",
+            Self::SecurityNoUnsanitizedProperty(_) => "security",
+            Self::SvelteNoAtHtmlTags(_) => "svelte",
+            Self::SvelteNoReactiveDestructuring(_) => "svelte",
+            Self::SvelteValidRunePlacement(_) => "svelte",
+            Self::JsonConsistentDependencyVersions(_) => "json",
+            Self::JsonNoDuplicateKeys(_) => "json",
+            Self::JsonSortDependencies(_) => "json",
+            Self::JsonValidPackageJson(_) => "json",
+            Self::JsonValidTsconfig(_) => "json",
         }
     }
     pub fn from_configuration(
@@ -12335,6 +12973,18 @@ impl RuleEnum {
             Self::NodeHandleCallbackErr(_) => {
                 Ok(Self::NodeHandleCallbackErr(NodeHandleCallbackErr::from_configuration(value)?))
             }
+            Self::NodeHashbang(_) => {
+                Ok(Self::NodeHashbang(NodeHashbang::from_configuration(value)?))
+            }
+            Self::NodeNoDeprecatedApi(_) => {
+                Ok(Self::NodeNoDeprecatedApi(NodeNoDeprecatedApi::from_configuration(value)?))
+            }
+            Self::NodeNoMissingImport(_) => {
+                Ok(Self::NodeNoMissingImport(NodeNoMissingImport::from_configuration(value)?))
+            }
+            Self::NodeNoMissingRequire(_) => {
+                Ok(Self::NodeNoMissingRequire(NodeNoMissingRequire::from_configuration(value)?))
+            }
             Self::NodeNoMixedRequires(_) => {
                 Ok(Self::NodeNoMixedRequires(NodeNoMixedRequires::from_configuration(value)?))
             }
@@ -12345,6 +12995,17 @@ impl RuleEnum {
             Self::NodeNoTopLevelAwait(_) => {
                 Ok(Self::NodeNoTopLevelAwait(NodeNoTopLevelAwait::from_configuration(value)?))
             }
+            Self::NodeNoUnpublishedImport(_) => Ok(Self::NodeNoUnpublishedImport(
+                NodeNoUnpublishedImport::from_configuration(value)?,
+            )),
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(_) => {
+                Ok(Self::NodeNoUnsupportedFeaturesNodeBuiltins(
+                    NodeNoUnsupportedFeaturesNodeBuiltins::from_configuration(value)?,
+                ))
+            }
+            Self::NodePreferNodeProtocol(_) => {
+                Ok(Self::NodePreferNodeProtocol(NodePreferNodeProtocol::from_configuration(value)?))
+            }
             Self::VueComponentDefinitionNameCasing(_) => {
                 Ok(Self::VueComponentDefinitionNameCasing(
                     VueComponentDefinitionNameCasing::from_configuration(value)?,
@@ -12390,6 +13051,23 @@ impl RuleEnum {
             Self::VueReturnInComputedProperty(_) => Ok(Self::VueReturnInComputedProperty(
                 VueReturnInComputedProperty::from_configuration(value)?,
             )),
+            Self::CompatCompat(_) => {
+                Ok(Self::CompatCompat(CompatCompat::from_configuration(value)?))
+            }
+            Self::TestingLibraryPreferUserEvent(_) => Ok(Self::TestingLibraryPreferUserEvent(
+                TestingLibraryPreferUserEvent::from_configuration(value)?,
+            )),
+            Self::PlaywrightMissingPlaywrightAwait(_) => {
+                Ok(Self::PlaywrightMissingPlaywrightAwait(
+                    PlaywrightMissingPlaywrightAwait::from_configuration(value)?,
+                ))
+            }
+            Self::SecurityNoUnsanitizedMethod(_) => Ok(Self::SecurityNoUnsanitizedMethod(
+                SecurityNoUnsanitizedMethod::from_configuration(value)?,
+            )),
+            Self::SecurityNoUnsanitizedProperty(_) => Ok(Self::SecurityNoUnsanitizedProperty(
+                SecurityNoUnsanitizedProperty::from_configuration(value)?,
+            )),
             _ => Ok(RULES[self.id()].clone()),
         }
     }
@@ -13251,13 +13929,20 @@ impl RuleEnum {
             Self::NodeExportsStyle(rule) => rule.run(node, ctx),
             Self::NodeGlobalRequire(rule) => rule.run(node, ctx),
             Self::NodeHandleCallbackErr(rule) => rule.run(node, ctx),
+            Self::NodeHashbang(rule) => rule.run(node, ctx),
+            Self::NodeNoDeprecatedApi(rule) => rule.run(node, ctx),
             Self::NodeNoExportsAssign(rule) => rule.run(node, ctx),
+            Self::NodeNoMissingImport(rule) => rule.run(node, ctx),
+            Self::NodeNoMissingRequire(rule) => rule.run(node, ctx),
             Self::NodeNoMixedRequires(rule) => rule.run(node, ctx),
             Self::NodeNoNewRequire(rule) => rule.run(node, ctx),
             Self::NodeNoPathConcat(rule) => rule.run(node, ctx),
             Self::NodeNoProcessEnv(rule) => rule.run(node, ctx),
             Self::NodeNoSync(rule) => rule.run(node, ctx),
             Self::NodeNoTopLevelAwait(rule) => rule.run(node, ctx),
+            Self::NodeNoUnpublishedImport(rule) => rule.run(node, ctx),
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(rule) => rule.run(node, ctx),
+            Self::NodePreferNodeProtocol(rule) => rule.run(node, ctx),
             Self::VueComponentDefinitionNameCasing(rule) => rule.run(node, ctx),
             Self::VueDefineEmitsDeclaration(rule) => rule.run(node, ctx),
             Self::VueDefinePropsDeclaration(rule) => rule.run(node, ctx),
@@ -13304,6 +13989,46 @@ impl RuleEnum {
             Self::VueValidDefineOptions(rule) => rule.run(node, ctx),
             Self::VueValidDefineProps(rule) => rule.run(node, ctx),
             Self::VueValidNextTick(rule) => rule.run(node, ctx),
+            Self::StylelintColorNoInvalidHex(rule) => rule.run(node, ctx),
+            Self::StylelintDeclarationBlockNoDuplicateProperties(rule) => rule.run(node, ctx),
+            Self::StylelintNoDescendingSpecificity(rule) => rule.run(node, ctx),
+            Self::StylelintNoDuplicateSelectors(rule) => rule.run(node, ctx),
+            Self::StylelintUnitNoUnknown(rule) => rule.run(node, ctx),
+            Self::CompatCompat(rule) => rule.run(node, ctx),
+            Self::CompatNoUnsupportedSyntax(rule) => rule.run(node, ctx),
+            Self::AngularNoInputRename(rule) => rule.run(node, ctx),
+            Self::AngularNoOutputOnPrefix(rule) => rule.run(node, ctx),
+            Self::AngularTemplateBananaInBox(rule) => rule.run(node, ctx),
+            Self::AngularTemplateEqeqeq(rule) => rule.run(node, ctx),
+            Self::AngularUseLifecycleInterface(rule) => rule.run(node, ctx),
+            Self::TestingLibraryAwaitAsyncQueries(rule) => rule.run(node, ctx),
+            Self::TestingLibraryNoNodeAccess(rule) => rule.run(node, ctx),
+            Self::TestingLibraryPreferScreenQueries(rule) => rule.run(node, ctx),
+            Self::TestingLibraryPreferUserEvent(rule) => rule.run(node, ctx),
+            Self::PlaywrightMissingPlaywrightAwait(rule) => rule.run(node, ctx),
+            Self::PlaywrightNoForceOption(rule) => rule.run(node, ctx),
+            Self::PlaywrightNoWaitForTimeout(rule) => rule.run(node, ctx),
+            Self::PlaywrightPreferWebFirstAssertions(rule) => rule.run(node, ctx),
+            Self::SecurityDetectChildProcess(rule) => rule.run(node, ctx),
+            Self::SecurityDetectEvalWithExpression(rule) => rule.run(node, ctx),
+            Self::SecurityDetectNonLiteralFsFilename(rule) => rule.run(node, ctx),
+            Self::SecurityDetectNonLiteralRegexp(rule) => rule.run(node, ctx),
+            Self::SecurityDetectNonLiteralRequire(rule) => rule.run(node, ctx),
+            Self::SecurityDetectPossibleTimingAttacks(rule) => rule.run(node, ctx),
+            Self::SecurityNoTaintedCode(rule) => rule.run(node, ctx),
+            Self::SecurityNoTaintedCommand(rule) => rule.run(node, ctx),
+            Self::SecurityNoTaintedHtml(rule) => rule.run(node, ctx),
+            Self::SecurityNoTaintedPath(rule) => rule.run(node, ctx),
+            Self::SecurityNoUnsanitizedMethod(rule) => rule.run(node, ctx),
+            Self::SecurityNoUnsanitizedProperty(rule) => rule.run(node, ctx),
+            Self::SvelteNoAtHtmlTags(rule) => rule.run(node, ctx),
+            Self::SvelteNoReactiveDestructuring(rule) => rule.run(node, ctx),
+            Self::SvelteValidRunePlacement(rule) => rule.run(node, ctx),
+            Self::JsonConsistentDependencyVersions(rule) => rule.run(node, ctx),
+            Self::JsonNoDuplicateKeys(rule) => rule.run(node, ctx),
+            Self::JsonSortDependencies(rule) => rule.run(node, ctx),
+            Self::JsonValidPackageJson(rule) => rule.run(node, ctx),
+            Self::JsonValidTsconfig(rule) => rule.run(node, ctx),
         }
     }
     pub(crate) fn run<'a, const TIMINGS: bool>(
@@ -14138,13 +14863,20 @@ impl RuleEnum {
             Self::NodeExportsStyle(rule) => rule.run_once(ctx),
             Self::NodeGlobalRequire(rule) => rule.run_once(ctx),
             Self::NodeHandleCallbackErr(rule) => rule.run_once(ctx),
+            Self::NodeHashbang(rule) => rule.run_once(ctx),
+            Self::NodeNoDeprecatedApi(rule) => rule.run_once(ctx),
             Self::NodeNoExportsAssign(rule) => rule.run_once(ctx),
+            Self::NodeNoMissingImport(rule) => rule.run_once(ctx),
+            Self::NodeNoMissingRequire(rule) => rule.run_once(ctx),
             Self::NodeNoMixedRequires(rule) => rule.run_once(ctx),
             Self::NodeNoNewRequire(rule) => rule.run_once(ctx),
             Self::NodeNoPathConcat(rule) => rule.run_once(ctx),
             Self::NodeNoProcessEnv(rule) => rule.run_once(ctx),
             Self::NodeNoSync(rule) => rule.run_once(ctx),
             Self::NodeNoTopLevelAwait(rule) => rule.run_once(ctx),
+            Self::NodeNoUnpublishedImport(rule) => rule.run_once(ctx),
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(rule) => rule.run_once(ctx),
+            Self::NodePreferNodeProtocol(rule) => rule.run_once(ctx),
             Self::VueComponentDefinitionNameCasing(rule) => rule.run_once(ctx),
             Self::VueDefineEmitsDeclaration(rule) => rule.run_once(ctx),
             Self::VueDefinePropsDeclaration(rule) => rule.run_once(ctx),
@@ -14191,6 +14923,46 @@ impl RuleEnum {
             Self::VueValidDefineOptions(rule) => rule.run_once(ctx),
             Self::VueValidDefineProps(rule) => rule.run_once(ctx),
             Self::VueValidNextTick(rule) => rule.run_once(ctx),
+            Self::StylelintColorNoInvalidHex(rule) => rule.run_once(ctx),
+            Self::StylelintDeclarationBlockNoDuplicateProperties(rule) => rule.run_once(ctx),
+            Self::StylelintNoDescendingSpecificity(rule) => rule.run_once(ctx),
+            Self::StylelintNoDuplicateSelectors(rule) => rule.run_once(ctx),
+            Self::StylelintUnitNoUnknown(rule) => rule.run_once(ctx),
+            Self::CompatCompat(rule) => rule.run_once(ctx),
+            Self::CompatNoUnsupportedSyntax(rule) => rule.run_once(ctx),
+            Self::AngularNoInputRename(rule) => rule.run_once(ctx),
+            Self::AngularNoOutputOnPrefix(rule) => rule.run_once(ctx),
+            Self::AngularTemplateBananaInBox(rule) => rule.run_once(ctx),
+            Self::AngularTemplateEqeqeq(rule) => rule.run_once(ctx),
+            Self::AngularUseLifecycleInterface(rule) => rule.run_once(ctx),
+            Self::TestingLibraryAwaitAsyncQueries(rule) => rule.run_once(ctx),
+            Self::TestingLibraryNoNodeAccess(rule) => rule.run_once(ctx),
+            Self::TestingLibraryPreferScreenQueries(rule) => rule.run_once(ctx),
+            Self::TestingLibraryPreferUserEvent(rule) => rule.run_once(ctx),
+            Self::PlaywrightMissingPlaywrightAwait(rule) => rule.run_once(ctx),
+            Self::PlaywrightNoForceOption(rule) => rule.run_once(ctx),
+            Self::PlaywrightNoWaitForTimeout(rule) => rule.run_once(ctx),
+            Self::PlaywrightPreferWebFirstAssertions(rule) => rule.run_once(ctx),
+            Self::SecurityDetectChildProcess(rule) => rule.run_once(ctx),
+            Self::SecurityDetectEvalWithExpression(rule) => rule.run_once(ctx),
+            Self::SecurityDetectNonLiteralFsFilename(rule) => rule.run_once(ctx),
+            Self::SecurityDetectNonLiteralRegexp(rule) => rule.run_once(ctx),
+            Self::SecurityDetectNonLiteralRequire(rule) => rule.run_once(ctx),
+            Self::SecurityDetectPossibleTimingAttacks(rule) => rule.run_once(ctx),
+            Self::SecurityNoTaintedCode(rule) => rule.run_once(ctx),
+            Self::SecurityNoTaintedCommand(rule) => rule.run_once(ctx),
+            Self::SecurityNoTaintedHtml(rule) => rule.run_once(ctx),
+            Self::SecurityNoTaintedPath(rule) => rule.run_once(ctx),
+            Self::SecurityNoUnsanitizedMethod(rule) => rule.run_once(ctx),
+            Self::SecurityNoUnsanitizedProperty(rule) => rule.run_once(ctx),
+            Self::SvelteNoAtHtmlTags(rule) => rule.run_once(ctx),
+            Self::SvelteNoReactiveDestructuring(rule) => rule.run_once(ctx),
+            Self::SvelteValidRunePlacement(rule) => rule.run_once(ctx),
+            Self::JsonConsistentDependencyVersions(rule) => rule.run_once(ctx),
+            Self::JsonNoDuplicateKeys(rule) => rule.run_once(ctx),
+            Self::JsonSortDependencies(rule) => rule.run_once(ctx),
+            Self::JsonValidPackageJson(rule) => rule.run_once(ctx),
+            Self::JsonValidTsconfig(rule) => rule.run_once(ctx),
         }
     }
     pub(crate) fn run_once<const TIMINGS: bool>(
@@ -15138,13 +15910,22 @@ impl RuleEnum {
             Self::NodeExportsStyle(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::NodeGlobalRequire(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::NodeHandleCallbackErr(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::NodeHashbang(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::NodeNoDeprecatedApi(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::NodeNoExportsAssign(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::NodeNoMissingImport(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::NodeNoMissingRequire(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::NodeNoMixedRequires(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::NodeNoNewRequire(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::NodeNoPathConcat(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::NodeNoProcessEnv(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::NodeNoSync(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::NodeNoTopLevelAwait(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::NodeNoUnpublishedImport(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(rule) => {
+                rule.run_on_jest_node(jest_node, ctx)
+            }
+            Self::NodePreferNodeProtocol(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::VueComponentDefinitionNameCasing(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::VueDefineEmitsDeclaration(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::VueDefinePropsDeclaration(rule) => rule.run_on_jest_node(jest_node, ctx),
@@ -15195,6 +15976,50 @@ impl RuleEnum {
             Self::VueValidDefineOptions(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::VueValidDefineProps(rule) => rule.run_on_jest_node(jest_node, ctx),
             Self::VueValidNextTick(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::StylelintColorNoInvalidHex(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::StylelintDeclarationBlockNoDuplicateProperties(rule) => {
+                rule.run_on_jest_node(jest_node, ctx)
+            }
+            Self::StylelintNoDescendingSpecificity(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::StylelintNoDuplicateSelectors(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::StylelintUnitNoUnknown(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::CompatCompat(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::CompatNoUnsupportedSyntax(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::AngularNoInputRename(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::AngularNoOutputOnPrefix(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::AngularTemplateBananaInBox(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::AngularTemplateEqeqeq(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::AngularUseLifecycleInterface(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::TestingLibraryAwaitAsyncQueries(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::TestingLibraryNoNodeAccess(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::TestingLibraryPreferScreenQueries(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::TestingLibraryPreferUserEvent(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::PlaywrightMissingPlaywrightAwait(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::PlaywrightNoForceOption(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::PlaywrightNoWaitForTimeout(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::PlaywrightPreferWebFirstAssertions(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::SecurityDetectChildProcess(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::SecurityDetectEvalWithExpression(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::SecurityDetectNonLiteralFsFilename(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::SecurityDetectNonLiteralRegexp(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::SecurityDetectNonLiteralRequire(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::SecurityDetectPossibleTimingAttacks(rule) => {
+                rule.run_on_jest_node(jest_node, ctx)
+            }
+            Self::SecurityNoTaintedCode(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::SecurityNoTaintedCommand(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::SecurityNoTaintedHtml(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::SecurityNoTaintedPath(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::SecurityNoUnsanitizedMethod(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::SecurityNoUnsanitizedProperty(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::SvelteNoAtHtmlTags(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::SvelteNoReactiveDestructuring(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::SvelteValidRunePlacement(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::JsonConsistentDependencyVersions(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::JsonNoDuplicateKeys(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::JsonSortDependencies(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::JsonValidPackageJson(rule) => rule.run_on_jest_node(jest_node, ctx),
+            Self::JsonValidTsconfig(rule) => rule.run_on_jest_node(jest_node, ctx),
         }
     }
     pub(crate) fn run_on_jest_node<'a, 'c, const TIMINGS: bool>(
@@ -16030,13 +16855,20 @@ impl RuleEnum {
             Self::NodeExportsStyle(rule) => rule.should_run(ctx),
             Self::NodeGlobalRequire(rule) => rule.should_run(ctx),
             Self::NodeHandleCallbackErr(rule) => rule.should_run(ctx),
+            Self::NodeHashbang(rule) => rule.should_run(ctx),
+            Self::NodeNoDeprecatedApi(rule) => rule.should_run(ctx),
             Self::NodeNoExportsAssign(rule) => rule.should_run(ctx),
+            Self::NodeNoMissingImport(rule) => rule.should_run(ctx),
+            Self::NodeNoMissingRequire(rule) => rule.should_run(ctx),
             Self::NodeNoMixedRequires(rule) => rule.should_run(ctx),
             Self::NodeNoNewRequire(rule) => rule.should_run(ctx),
             Self::NodeNoPathConcat(rule) => rule.should_run(ctx),
             Self::NodeNoProcessEnv(rule) => rule.should_run(ctx),
             Self::NodeNoSync(rule) => rule.should_run(ctx),
             Self::NodeNoTopLevelAwait(rule) => rule.should_run(ctx),
+            Self::NodeNoUnpublishedImport(rule) => rule.should_run(ctx),
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(rule) => rule.should_run(ctx),
+            Self::NodePreferNodeProtocol(rule) => rule.should_run(ctx),
             Self::VueComponentDefinitionNameCasing(rule) => rule.should_run(ctx),
             Self::VueDefineEmitsDeclaration(rule) => rule.should_run(ctx),
             Self::VueDefinePropsDeclaration(rule) => rule.should_run(ctx),
@@ -16083,6 +16915,46 @@ impl RuleEnum {
             Self::VueValidDefineOptions(rule) => rule.should_run(ctx),
             Self::VueValidDefineProps(rule) => rule.should_run(ctx),
             Self::VueValidNextTick(rule) => rule.should_run(ctx),
+            Self::StylelintColorNoInvalidHex(rule) => rule.should_run(ctx),
+            Self::StylelintDeclarationBlockNoDuplicateProperties(rule) => rule.should_run(ctx),
+            Self::StylelintNoDescendingSpecificity(rule) => rule.should_run(ctx),
+            Self::StylelintNoDuplicateSelectors(rule) => rule.should_run(ctx),
+            Self::StylelintUnitNoUnknown(rule) => rule.should_run(ctx),
+            Self::CompatCompat(rule) => rule.should_run(ctx),
+            Self::CompatNoUnsupportedSyntax(rule) => rule.should_run(ctx),
+            Self::AngularNoInputRename(rule) => rule.should_run(ctx),
+            Self::AngularNoOutputOnPrefix(rule) => rule.should_run(ctx),
+            Self::AngularTemplateBananaInBox(rule) => rule.should_run(ctx),
+            Self::AngularTemplateEqeqeq(rule) => rule.should_run(ctx),
+            Self::AngularUseLifecycleInterface(rule) => rule.should_run(ctx),
+            Self::TestingLibraryAwaitAsyncQueries(rule) => rule.should_run(ctx),
+            Self::TestingLibraryNoNodeAccess(rule) => rule.should_run(ctx),
+            Self::TestingLibraryPreferScreenQueries(rule) => rule.should_run(ctx),
+            Self::TestingLibraryPreferUserEvent(rule) => rule.should_run(ctx),
+            Self::PlaywrightMissingPlaywrightAwait(rule) => rule.should_run(ctx),
+            Self::PlaywrightNoForceOption(rule) => rule.should_run(ctx),
+            Self::PlaywrightNoWaitForTimeout(rule) => rule.should_run(ctx),
+            Self::PlaywrightPreferWebFirstAssertions(rule) => rule.should_run(ctx),
+            Self::SecurityDetectChildProcess(rule) => rule.should_run(ctx),
+            Self::SecurityDetectEvalWithExpression(rule) => rule.should_run(ctx),
+            Self::SecurityDetectNonLiteralFsFilename(rule) => rule.should_run(ctx),
+            Self::SecurityDetectNonLiteralRegexp(rule) => rule.should_run(ctx),
+            Self::SecurityDetectNonLiteralRequire(rule) => rule.should_run(ctx),
+            Self::SecurityDetectPossibleTimingAttacks(rule) => rule.should_run(ctx),
+            Self::SecurityNoTaintedCode(rule) => rule.should_run(ctx),
+            Self::SecurityNoTaintedCommand(rule) => rule.should_run(ctx),
+            Self::SecurityNoTaintedHtml(rule) => rule.should_run(ctx),
+            Self::SecurityNoTaintedPath(rule) => rule.should_run(ctx),
+            Self::SecurityNoUnsanitizedMethod(rule) => rule.should_run(ctx),
+            Self::SecurityNoUnsanitizedProperty(rule) => rule.should_run(ctx),
+            Self::SvelteNoAtHtmlTags(rule) => rule.should_run(ctx),
+            Self::SvelteNoReactiveDestructuring(rule) => rule.should_run(ctx),
+            Self::SvelteValidRunePlacement(rule) => rule.should_run(ctx),
+            Self::JsonConsistentDependencyVersions(rule) => rule.should_run(ctx),
+            Self::JsonNoDuplicateKeys(rule) => rule.should_run(ctx),
+            Self::JsonSortDependencies(rule) => rule.should_run(ctx),
+            Self::JsonValidPackageJson(rule) => rule.should_run(ctx),
+            Self::JsonValidTsconfig(rule) => rule.should_run(ctx),
         }
     }
     pub fn is_tsgolint_rule(&self) -> bool {
@@ -17272,13 +18144,22 @@ impl RuleEnum {
             Self::NodeExportsStyle(_) => NodeExportsStyle::IS_TSGOLINT_RULE,
             Self::NodeGlobalRequire(_) => NodeGlobalRequire::IS_TSGOLINT_RULE,
             Self::NodeHandleCallbackErr(_) => NodeHandleCallbackErr::IS_TSGOLINT_RULE,
+            Self::NodeHashbang(_) => NodeHashbang::IS_TSGOLINT_RULE,
+            Self::NodeNoDeprecatedApi(_) => NodeNoDeprecatedApi::IS_TSGOLINT_RULE,
             Self::NodeNoExportsAssign(_) => NodeNoExportsAssign::IS_TSGOLINT_RULE,
+            Self::NodeNoMissingImport(_) => NodeNoMissingImport::IS_TSGOLINT_RULE,
+            Self::NodeNoMissingRequire(_) => NodeNoMissingRequire::IS_TSGOLINT_RULE,
             Self::NodeNoMixedRequires(_) => NodeNoMixedRequires::IS_TSGOLINT_RULE,
             Self::NodeNoNewRequire(_) => NodeNoNewRequire::IS_TSGOLINT_RULE,
             Self::NodeNoPathConcat(_) => NodeNoPathConcat::IS_TSGOLINT_RULE,
             Self::NodeNoProcessEnv(_) => NodeNoProcessEnv::IS_TSGOLINT_RULE,
             Self::NodeNoSync(_) => NodeNoSync::IS_TSGOLINT_RULE,
             Self::NodeNoTopLevelAwait(_) => NodeNoTopLevelAwait::IS_TSGOLINT_RULE,
+            Self::NodeNoUnpublishedImport(_) => NodeNoUnpublishedImport::IS_TSGOLINT_RULE,
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(_) => {
+                NodeNoUnsupportedFeaturesNodeBuiltins::IS_TSGOLINT_RULE
+            }
+            Self::NodePreferNodeProtocol(_) => NodePreferNodeProtocol::IS_TSGOLINT_RULE,
             Self::VueComponentDefinitionNameCasing(_) => {
                 VueComponentDefinitionNameCasing::IS_TSGOLINT_RULE
             }
@@ -17345,6 +18226,78 @@ impl RuleEnum {
             Self::VueValidDefineOptions(_) => VueValidDefineOptions::IS_TSGOLINT_RULE,
             Self::VueValidDefineProps(_) => VueValidDefineProps::IS_TSGOLINT_RULE,
             Self::VueValidNextTick(_) => VueValidNextTick::IS_TSGOLINT_RULE,
+            Self::StylelintColorNoInvalidHex(_) => StylelintColorNoInvalidHex::IS_TSGOLINT_RULE,
+            Self::StylelintDeclarationBlockNoDuplicateProperties(_) => {
+                StylelintDeclarationBlockNoDuplicateProperties::IS_TSGOLINT_RULE
+            }
+            Self::StylelintNoDescendingSpecificity(_) => {
+                StylelintNoDescendingSpecificity::IS_TSGOLINT_RULE
+            }
+            Self::StylelintNoDuplicateSelectors(_) => {
+                StylelintNoDuplicateSelectors::IS_TSGOLINT_RULE
+            }
+            Self::StylelintUnitNoUnknown(_) => StylelintUnitNoUnknown::IS_TSGOLINT_RULE,
+            Self::CompatCompat(_) => CompatCompat::IS_TSGOLINT_RULE,
+            Self::CompatNoUnsupportedSyntax(_) => CompatNoUnsupportedSyntax::IS_TSGOLINT_RULE,
+            Self::AngularNoInputRename(_) => AngularNoInputRename::IS_TSGOLINT_RULE,
+            Self::AngularNoOutputOnPrefix(_) => AngularNoOutputOnPrefix::IS_TSGOLINT_RULE,
+            Self::AngularTemplateBananaInBox(_) => AngularTemplateBananaInBox::IS_TSGOLINT_RULE,
+            Self::AngularTemplateEqeqeq(_) => AngularTemplateEqeqeq::IS_TSGOLINT_RULE,
+            Self::AngularUseLifecycleInterface(_) => AngularUseLifecycleInterface::IS_TSGOLINT_RULE,
+            Self::TestingLibraryAwaitAsyncQueries(_) => {
+                TestingLibraryAwaitAsyncQueries::IS_TSGOLINT_RULE
+            }
+            Self::TestingLibraryNoNodeAccess(_) => TestingLibraryNoNodeAccess::IS_TSGOLINT_RULE,
+            Self::TestingLibraryPreferScreenQueries(_) => {
+                TestingLibraryPreferScreenQueries::IS_TSGOLINT_RULE
+            }
+            Self::TestingLibraryPreferUserEvent(_) => {
+                TestingLibraryPreferUserEvent::IS_TSGOLINT_RULE
+            }
+            Self::PlaywrightMissingPlaywrightAwait(_) => {
+                PlaywrightMissingPlaywrightAwait::IS_TSGOLINT_RULE
+            }
+            Self::PlaywrightNoForceOption(_) => PlaywrightNoForceOption::IS_TSGOLINT_RULE,
+            Self::PlaywrightNoWaitForTimeout(_) => PlaywrightNoWaitForTimeout::IS_TSGOLINT_RULE,
+            Self::PlaywrightPreferWebFirstAssertions(_) => {
+                PlaywrightPreferWebFirstAssertions::IS_TSGOLINT_RULE
+            }
+            Self::SecurityDetectChildProcess(_) => SecurityDetectChildProcess::IS_TSGOLINT_RULE,
+            Self::SecurityDetectEvalWithExpression(_) => {
+                SecurityDetectEvalWithExpression::IS_TSGOLINT_RULE
+            }
+            Self::SecurityDetectNonLiteralFsFilename(_) => {
+                SecurityDetectNonLiteralFsFilename::IS_TSGOLINT_RULE
+            }
+            Self::SecurityDetectNonLiteralRegexp(_) => {
+                SecurityDetectNonLiteralRegexp::IS_TSGOLINT_RULE
+            }
+            Self::SecurityDetectNonLiteralRequire(_) => {
+                SecurityDetectNonLiteralRequire::IS_TSGOLINT_RULE
+            }
+            Self::SecurityDetectPossibleTimingAttacks(_) => {
+                SecurityDetectPossibleTimingAttacks::IS_TSGOLINT_RULE
+            }
+            Self::SecurityNoTaintedCode(_) => SecurityNoTaintedCode::IS_TSGOLINT_RULE,
+            Self::SecurityNoTaintedCommand(_) => SecurityNoTaintedCommand::IS_TSGOLINT_RULE,
+            Self::SecurityNoTaintedHtml(_) => SecurityNoTaintedHtml::IS_TSGOLINT_RULE,
+            Self::SecurityNoTaintedPath(_) => SecurityNoTaintedPath::IS_TSGOLINT_RULE,
+            Self::SecurityNoUnsanitizedMethod(_) => SecurityNoUnsanitizedMethod::IS_TSGOLINT_RULE,
+            Self::SecurityNoUnsanitizedProperty(_) => {
+                SecurityNoUnsanitizedProperty::IS_TSGOLINT_RULE
+            }
+            Self::SvelteNoAtHtmlTags(_) => SvelteNoAtHtmlTags::IS_TSGOLINT_RULE,
+            Self::SvelteNoReactiveDestructuring(_) => {
+                SvelteNoReactiveDestructuring::IS_TSGOLINT_RULE
+            }
+            Self::SvelteValidRunePlacement(_) => SvelteValidRunePlacement::IS_TSGOLINT_RULE,
+            Self::JsonConsistentDependencyVersions(_) => {
+                JsonConsistentDependencyVersions::IS_TSGOLINT_RULE
+            }
+            Self::JsonNoDuplicateKeys(_) => JsonNoDuplicateKeys::IS_TSGOLINT_RULE,
+            Self::JsonSortDependencies(_) => JsonSortDependencies::IS_TSGOLINT_RULE,
+            Self::JsonValidPackageJson(_) => JsonValidPackageJson::IS_TSGOLINT_RULE,
+            Self::JsonValidTsconfig(_) => JsonValidTsconfig::IS_TSGOLINT_RULE,
         }
     }
     #[doc = r" The version of oxlint in which this rule was first available."]
@@ -18336,13 +19289,22 @@ impl RuleEnum {
             Self::NodeExportsStyle(_) => NodeExportsStyle::VERSION,
             Self::NodeGlobalRequire(_) => NodeGlobalRequire::VERSION,
             Self::NodeHandleCallbackErr(_) => NodeHandleCallbackErr::VERSION,
+            Self::NodeHashbang(_) => NodeHashbang::VERSION,
+            Self::NodeNoDeprecatedApi(_) => NodeNoDeprecatedApi::VERSION,
             Self::NodeNoExportsAssign(_) => NodeNoExportsAssign::VERSION,
+            Self::NodeNoMissingImport(_) => NodeNoMissingImport::VERSION,
+            Self::NodeNoMissingRequire(_) => NodeNoMissingRequire::VERSION,
             Self::NodeNoMixedRequires(_) => NodeNoMixedRequires::VERSION,
             Self::NodeNoNewRequire(_) => NodeNoNewRequire::VERSION,
             Self::NodeNoPathConcat(_) => NodeNoPathConcat::VERSION,
             Self::NodeNoProcessEnv(_) => NodeNoProcessEnv::VERSION,
             Self::NodeNoSync(_) => NodeNoSync::VERSION,
             Self::NodeNoTopLevelAwait(_) => NodeNoTopLevelAwait::VERSION,
+            Self::NodeNoUnpublishedImport(_) => NodeNoUnpublishedImport::VERSION,
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(_) => {
+                NodeNoUnsupportedFeaturesNodeBuiltins::VERSION
+            }
+            Self::NodePreferNodeProtocol(_) => NodePreferNodeProtocol::VERSION,
             Self::VueComponentDefinitionNameCasing(_) => VueComponentDefinitionNameCasing::VERSION,
             Self::VueDefineEmitsDeclaration(_) => VueDefineEmitsDeclaration::VERSION,
             Self::VueDefinePropsDeclaration(_) => VueDefinePropsDeclaration::VERSION,
@@ -18395,6 +19357,56 @@ impl RuleEnum {
             Self::VueValidDefineOptions(_) => VueValidDefineOptions::VERSION,
             Self::VueValidDefineProps(_) => VueValidDefineProps::VERSION,
             Self::VueValidNextTick(_) => VueValidNextTick::VERSION,
+            Self::StylelintColorNoInvalidHex(_) => StylelintColorNoInvalidHex::VERSION,
+            Self::StylelintDeclarationBlockNoDuplicateProperties(_) => {
+                StylelintDeclarationBlockNoDuplicateProperties::VERSION
+            }
+            Self::StylelintNoDescendingSpecificity(_) => StylelintNoDescendingSpecificity::VERSION,
+            Self::StylelintNoDuplicateSelectors(_) => StylelintNoDuplicateSelectors::VERSION,
+            Self::StylelintUnitNoUnknown(_) => StylelintUnitNoUnknown::VERSION,
+            Self::CompatCompat(_) => CompatCompat::VERSION,
+            Self::CompatNoUnsupportedSyntax(_) => CompatNoUnsupportedSyntax::VERSION,
+            Self::AngularNoInputRename(_) => AngularNoInputRename::VERSION,
+            Self::AngularNoOutputOnPrefix(_) => AngularNoOutputOnPrefix::VERSION,
+            Self::AngularTemplateBananaInBox(_) => AngularTemplateBananaInBox::VERSION,
+            Self::AngularTemplateEqeqeq(_) => AngularTemplateEqeqeq::VERSION,
+            Self::AngularUseLifecycleInterface(_) => AngularUseLifecycleInterface::VERSION,
+            Self::TestingLibraryAwaitAsyncQueries(_) => TestingLibraryAwaitAsyncQueries::VERSION,
+            Self::TestingLibraryNoNodeAccess(_) => TestingLibraryNoNodeAccess::VERSION,
+            Self::TestingLibraryPreferScreenQueries(_) => {
+                TestingLibraryPreferScreenQueries::VERSION
+            }
+            Self::TestingLibraryPreferUserEvent(_) => TestingLibraryPreferUserEvent::VERSION,
+            Self::PlaywrightMissingPlaywrightAwait(_) => PlaywrightMissingPlaywrightAwait::VERSION,
+            Self::PlaywrightNoForceOption(_) => PlaywrightNoForceOption::VERSION,
+            Self::PlaywrightNoWaitForTimeout(_) => PlaywrightNoWaitForTimeout::VERSION,
+            Self::PlaywrightPreferWebFirstAssertions(_) => {
+                PlaywrightPreferWebFirstAssertions::VERSION
+            }
+            Self::SecurityDetectChildProcess(_) => SecurityDetectChildProcess::VERSION,
+            Self::SecurityDetectEvalWithExpression(_) => SecurityDetectEvalWithExpression::VERSION,
+            Self::SecurityDetectNonLiteralFsFilename(_) => {
+                SecurityDetectNonLiteralFsFilename::VERSION
+            }
+            Self::SecurityDetectNonLiteralRegexp(_) => SecurityDetectNonLiteralRegexp::VERSION,
+            Self::SecurityDetectNonLiteralRequire(_) => SecurityDetectNonLiteralRequire::VERSION,
+            Self::SecurityDetectPossibleTimingAttacks(_) => {
+                SecurityDetectPossibleTimingAttacks::VERSION
+            }
+            Self::SecurityNoTaintedCode(_) => SecurityNoTaintedCode::VERSION,
+            Self::SecurityNoTaintedCommand(_) => SecurityNoTaintedCommand::VERSION,
+            Self::SecurityNoTaintedHtml(_) => SecurityNoTaintedHtml::VERSION,
+            Self::SecurityNoTaintedPath(_) => SecurityNoTaintedPath::VERSION,
+            Self::SecurityNoUnsanitizedMethod(_) => SecurityNoUnsanitizedMethod::VERSION,
+            Self::SecurityNoUnsanitizedProperty(_) => SecurityNoUnsanitizedProperty::VERSION,
+            Self::SvelteNoAtHtmlTags(_) => SvelteNoAtHtmlTags::VERSION,
+            Self::SvelteNoReactiveDestructuring(_) => SvelteNoReactiveDestructuring::VERSION,
+            Self::SvelteValidRunePlacement(_) => SvelteValidRunePlacement::VERSION,
+            Self::JsonConsistentDependencyVersions(_) => JsonConsistentDependencyVersions::VERSION,
+            Self::JsonNoDuplicateKeys(_) => JsonNoDuplicateKeys::VERSION,
+            Self::JsonSortDependencies(_) => JsonSortDependencies::VERSION,
+            Self::JsonValidPackageJson(_) => JsonValidPackageJson::VERSION,
+            Self::JsonValidTsconfig(_) => JsonValidTsconfig::VERSION,
         }
     }
     #[doc = r" Whether this rule declares a configuration type."]
@@ -19421,13 +20433,22 @@ impl RuleEnum {
             Self::NodeExportsStyle(_) => NodeExportsStyle::HAS_CONFIG,
             Self::NodeGlobalRequire(_) => NodeGlobalRequire::HAS_CONFIG,
             Self::NodeHandleCallbackErr(_) => NodeHandleCallbackErr::HAS_CONFIG,
+            Self::NodeHashbang(_) => NodeHashbang::HAS_CONFIG,
+            Self::NodeNoDeprecatedApi(_) => NodeNoDeprecatedApi::HAS_CONFIG,
             Self::NodeNoExportsAssign(_) => NodeNoExportsAssign::HAS_CONFIG,
+            Self::NodeNoMissingImport(_) => NodeNoMissingImport::HAS_CONFIG,
+            Self::NodeNoMissingRequire(_) => NodeNoMissingRequire::HAS_CONFIG,
             Self::NodeNoMixedRequires(_) => NodeNoMixedRequires::HAS_CONFIG,
             Self::NodeNoNewRequire(_) => NodeNoNewRequire::HAS_CONFIG,
             Self::NodeNoPathConcat(_) => NodeNoPathConcat::HAS_CONFIG,
             Self::NodeNoProcessEnv(_) => NodeNoProcessEnv::HAS_CONFIG,
             Self::NodeNoSync(_) => NodeNoSync::HAS_CONFIG,
             Self::NodeNoTopLevelAwait(_) => NodeNoTopLevelAwait::HAS_CONFIG,
+            Self::NodeNoUnpublishedImport(_) => NodeNoUnpublishedImport::HAS_CONFIG,
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(_) => {
+                NodeNoUnsupportedFeaturesNodeBuiltins::HAS_CONFIG
+            }
+            Self::NodePreferNodeProtocol(_) => NodePreferNodeProtocol::HAS_CONFIG,
             Self::VueComponentDefinitionNameCasing(_) => {
                 VueComponentDefinitionNameCasing::HAS_CONFIG
             }
@@ -19484,6 +20505,64 @@ impl RuleEnum {
             Self::VueValidDefineOptions(_) => VueValidDefineOptions::HAS_CONFIG,
             Self::VueValidDefineProps(_) => VueValidDefineProps::HAS_CONFIG,
             Self::VueValidNextTick(_) => VueValidNextTick::HAS_CONFIG,
+            Self::StylelintColorNoInvalidHex(_) => StylelintColorNoInvalidHex::HAS_CONFIG,
+            Self::StylelintDeclarationBlockNoDuplicateProperties(_) => {
+                StylelintDeclarationBlockNoDuplicateProperties::HAS_CONFIG
+            }
+            Self::StylelintNoDescendingSpecificity(_) => {
+                StylelintNoDescendingSpecificity::HAS_CONFIG
+            }
+            Self::StylelintNoDuplicateSelectors(_) => StylelintNoDuplicateSelectors::HAS_CONFIG,
+            Self::StylelintUnitNoUnknown(_) => StylelintUnitNoUnknown::HAS_CONFIG,
+            Self::CompatCompat(_) => CompatCompat::HAS_CONFIG,
+            Self::CompatNoUnsupportedSyntax(_) => CompatNoUnsupportedSyntax::HAS_CONFIG,
+            Self::AngularNoInputRename(_) => AngularNoInputRename::HAS_CONFIG,
+            Self::AngularNoOutputOnPrefix(_) => AngularNoOutputOnPrefix::HAS_CONFIG,
+            Self::AngularTemplateBananaInBox(_) => AngularTemplateBananaInBox::HAS_CONFIG,
+            Self::AngularTemplateEqeqeq(_) => AngularTemplateEqeqeq::HAS_CONFIG,
+            Self::AngularUseLifecycleInterface(_) => AngularUseLifecycleInterface::HAS_CONFIG,
+            Self::TestingLibraryAwaitAsyncQueries(_) => TestingLibraryAwaitAsyncQueries::HAS_CONFIG,
+            Self::TestingLibraryNoNodeAccess(_) => TestingLibraryNoNodeAccess::HAS_CONFIG,
+            Self::TestingLibraryPreferScreenQueries(_) => {
+                TestingLibraryPreferScreenQueries::HAS_CONFIG
+            }
+            Self::TestingLibraryPreferUserEvent(_) => TestingLibraryPreferUserEvent::HAS_CONFIG,
+            Self::PlaywrightMissingPlaywrightAwait(_) => {
+                PlaywrightMissingPlaywrightAwait::HAS_CONFIG
+            }
+            Self::PlaywrightNoForceOption(_) => PlaywrightNoForceOption::HAS_CONFIG,
+            Self::PlaywrightNoWaitForTimeout(_) => PlaywrightNoWaitForTimeout::HAS_CONFIG,
+            Self::PlaywrightPreferWebFirstAssertions(_) => {
+                PlaywrightPreferWebFirstAssertions::HAS_CONFIG
+            }
+            Self::SecurityDetectChildProcess(_) => SecurityDetectChildProcess::HAS_CONFIG,
+            Self::SecurityDetectEvalWithExpression(_) => {
+                SecurityDetectEvalWithExpression::HAS_CONFIG
+            }
+            Self::SecurityDetectNonLiteralFsFilename(_) => {
+                SecurityDetectNonLiteralFsFilename::HAS_CONFIG
+            }
+            Self::SecurityDetectNonLiteralRegexp(_) => SecurityDetectNonLiteralRegexp::HAS_CONFIG,
+            Self::SecurityDetectNonLiteralRequire(_) => SecurityDetectNonLiteralRequire::HAS_CONFIG,
+            Self::SecurityDetectPossibleTimingAttacks(_) => {
+                SecurityDetectPossibleTimingAttacks::HAS_CONFIG
+            }
+            Self::SecurityNoTaintedCode(_) => SecurityNoTaintedCode::HAS_CONFIG,
+            Self::SecurityNoTaintedCommand(_) => SecurityNoTaintedCommand::HAS_CONFIG,
+            Self::SecurityNoTaintedHtml(_) => SecurityNoTaintedHtml::HAS_CONFIG,
+            Self::SecurityNoTaintedPath(_) => SecurityNoTaintedPath::HAS_CONFIG,
+            Self::SecurityNoUnsanitizedMethod(_) => SecurityNoUnsanitizedMethod::HAS_CONFIG,
+            Self::SecurityNoUnsanitizedProperty(_) => SecurityNoUnsanitizedProperty::HAS_CONFIG,
+            Self::SvelteNoAtHtmlTags(_) => SvelteNoAtHtmlTags::HAS_CONFIG,
+            Self::SvelteNoReactiveDestructuring(_) => SvelteNoReactiveDestructuring::HAS_CONFIG,
+            Self::SvelteValidRunePlacement(_) => SvelteValidRunePlacement::HAS_CONFIG,
+            Self::JsonConsistentDependencyVersions(_) => {
+                JsonConsistentDependencyVersions::HAS_CONFIG
+            }
+            Self::JsonNoDuplicateKeys(_) => JsonNoDuplicateKeys::HAS_CONFIG,
+            Self::JsonSortDependencies(_) => JsonSortDependencies::HAS_CONFIG,
+            Self::JsonValidPackageJson(_) => JsonValidPackageJson::HAS_CONFIG,
+            Self::JsonValidTsconfig(_) => JsonValidTsconfig::HAS_CONFIG,
         }
     }
     #[doc = r" Additional information about this rule."]
@@ -20417,13 +21496,22 @@ impl RuleEnum {
             Self::NodeExportsStyle(_) => NodeExportsStyle::INFO,
             Self::NodeGlobalRequire(_) => NodeGlobalRequire::INFO,
             Self::NodeHandleCallbackErr(_) => NodeHandleCallbackErr::INFO,
+            Self::NodeHashbang(_) => NodeHashbang::INFO,
+            Self::NodeNoDeprecatedApi(_) => NodeNoDeprecatedApi::INFO,
             Self::NodeNoExportsAssign(_) => NodeNoExportsAssign::INFO,
+            Self::NodeNoMissingImport(_) => NodeNoMissingImport::INFO,
+            Self::NodeNoMissingRequire(_) => NodeNoMissingRequire::INFO,
             Self::NodeNoMixedRequires(_) => NodeNoMixedRequires::INFO,
             Self::NodeNoNewRequire(_) => NodeNoNewRequire::INFO,
             Self::NodeNoPathConcat(_) => NodeNoPathConcat::INFO,
             Self::NodeNoProcessEnv(_) => NodeNoProcessEnv::INFO,
             Self::NodeNoSync(_) => NodeNoSync::INFO,
             Self::NodeNoTopLevelAwait(_) => NodeNoTopLevelAwait::INFO,
+            Self::NodeNoUnpublishedImport(_) => NodeNoUnpublishedImport::INFO,
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(_) => {
+                NodeNoUnsupportedFeaturesNodeBuiltins::INFO
+            }
+            Self::NodePreferNodeProtocol(_) => NodePreferNodeProtocol::INFO,
             Self::VueComponentDefinitionNameCasing(_) => VueComponentDefinitionNameCasing::INFO,
             Self::VueDefineEmitsDeclaration(_) => VueDefineEmitsDeclaration::INFO,
             Self::VueDefinePropsDeclaration(_) => VueDefinePropsDeclaration::INFO,
@@ -20474,6 +21562,50 @@ impl RuleEnum {
             Self::VueValidDefineOptions(_) => VueValidDefineOptions::INFO,
             Self::VueValidDefineProps(_) => VueValidDefineProps::INFO,
             Self::VueValidNextTick(_) => VueValidNextTick::INFO,
+            Self::StylelintColorNoInvalidHex(_) => StylelintColorNoInvalidHex::INFO,
+            Self::StylelintDeclarationBlockNoDuplicateProperties(_) => {
+                StylelintDeclarationBlockNoDuplicateProperties::INFO
+            }
+            Self::StylelintNoDescendingSpecificity(_) => StylelintNoDescendingSpecificity::INFO,
+            Self::StylelintNoDuplicateSelectors(_) => StylelintNoDuplicateSelectors::INFO,
+            Self::StylelintUnitNoUnknown(_) => StylelintUnitNoUnknown::INFO,
+            Self::CompatCompat(_) => CompatCompat::INFO,
+            Self::CompatNoUnsupportedSyntax(_) => CompatNoUnsupportedSyntax::INFO,
+            Self::AngularNoInputRename(_) => AngularNoInputRename::INFO,
+            Self::AngularNoOutputOnPrefix(_) => AngularNoOutputOnPrefix::INFO,
+            Self::AngularTemplateBananaInBox(_) => AngularTemplateBananaInBox::INFO,
+            Self::AngularTemplateEqeqeq(_) => AngularTemplateEqeqeq::INFO,
+            Self::AngularUseLifecycleInterface(_) => AngularUseLifecycleInterface::INFO,
+            Self::TestingLibraryAwaitAsyncQueries(_) => TestingLibraryAwaitAsyncQueries::INFO,
+            Self::TestingLibraryNoNodeAccess(_) => TestingLibraryNoNodeAccess::INFO,
+            Self::TestingLibraryPreferScreenQueries(_) => TestingLibraryPreferScreenQueries::INFO,
+            Self::TestingLibraryPreferUserEvent(_) => TestingLibraryPreferUserEvent::INFO,
+            Self::PlaywrightMissingPlaywrightAwait(_) => PlaywrightMissingPlaywrightAwait::INFO,
+            Self::PlaywrightNoForceOption(_) => PlaywrightNoForceOption::INFO,
+            Self::PlaywrightNoWaitForTimeout(_) => PlaywrightNoWaitForTimeout::INFO,
+            Self::PlaywrightPreferWebFirstAssertions(_) => PlaywrightPreferWebFirstAssertions::INFO,
+            Self::SecurityDetectChildProcess(_) => SecurityDetectChildProcess::INFO,
+            Self::SecurityDetectEvalWithExpression(_) => SecurityDetectEvalWithExpression::INFO,
+            Self::SecurityDetectNonLiteralFsFilename(_) => SecurityDetectNonLiteralFsFilename::INFO,
+            Self::SecurityDetectNonLiteralRegexp(_) => SecurityDetectNonLiteralRegexp::INFO,
+            Self::SecurityDetectNonLiteralRequire(_) => SecurityDetectNonLiteralRequire::INFO,
+            Self::SecurityDetectPossibleTimingAttacks(_) => {
+                SecurityDetectPossibleTimingAttacks::INFO
+            }
+            Self::SecurityNoTaintedCode(_) => SecurityNoTaintedCode::INFO,
+            Self::SecurityNoTaintedCommand(_) => SecurityNoTaintedCommand::INFO,
+            Self::SecurityNoTaintedHtml(_) => SecurityNoTaintedHtml::INFO,
+            Self::SecurityNoTaintedPath(_) => SecurityNoTaintedPath::INFO,
+            Self::SecurityNoUnsanitizedMethod(_) => SecurityNoUnsanitizedMethod::INFO,
+            Self::SecurityNoUnsanitizedProperty(_) => SecurityNoUnsanitizedProperty::INFO,
+            Self::SvelteNoAtHtmlTags(_) => SvelteNoAtHtmlTags::INFO,
+            Self::SvelteNoReactiveDestructuring(_) => SvelteNoReactiveDestructuring::INFO,
+            Self::SvelteValidRunePlacement(_) => SvelteValidRunePlacement::INFO,
+            Self::JsonConsistentDependencyVersions(_) => JsonConsistentDependencyVersions::INFO,
+            Self::JsonNoDuplicateKeys(_) => JsonNoDuplicateKeys::INFO,
+            Self::JsonSortDependencies(_) => JsonSortDependencies::INFO,
+            Self::JsonValidPackageJson(_) => JsonValidPackageJson::INFO,
+            Self::JsonValidTsconfig(_) => JsonValidTsconfig::INFO,
         }
     }
     #[doc = r" A short, one-line summary of what this rule does."]
@@ -21300,13 +22432,20 @@ impl RuleEnum {
             Self::NodeExportsStyle(rule) => rule.types_info(),
             Self::NodeGlobalRequire(rule) => rule.types_info(),
             Self::NodeHandleCallbackErr(rule) => rule.types_info(),
+            Self::NodeHashbang(rule) => rule.types_info(),
+            Self::NodeNoDeprecatedApi(rule) => rule.types_info(),
             Self::NodeNoExportsAssign(rule) => rule.types_info(),
+            Self::NodeNoMissingImport(rule) => rule.types_info(),
+            Self::NodeNoMissingRequire(rule) => rule.types_info(),
             Self::NodeNoMixedRequires(rule) => rule.types_info(),
             Self::NodeNoNewRequire(rule) => rule.types_info(),
             Self::NodeNoPathConcat(rule) => rule.types_info(),
             Self::NodeNoProcessEnv(rule) => rule.types_info(),
             Self::NodeNoSync(rule) => rule.types_info(),
             Self::NodeNoTopLevelAwait(rule) => rule.types_info(),
+            Self::NodeNoUnpublishedImport(rule) => rule.types_info(),
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(rule) => rule.types_info(),
+            Self::NodePreferNodeProtocol(rule) => rule.types_info(),
             Self::VueComponentDefinitionNameCasing(rule) => rule.types_info(),
             Self::VueDefineEmitsDeclaration(rule) => rule.types_info(),
             Self::VueDefinePropsDeclaration(rule) => rule.types_info(),
@@ -21353,6 +22492,46 @@ impl RuleEnum {
             Self::VueValidDefineOptions(rule) => rule.types_info(),
             Self::VueValidDefineProps(rule) => rule.types_info(),
             Self::VueValidNextTick(rule) => rule.types_info(),
+            Self::StylelintColorNoInvalidHex(rule) => rule.types_info(),
+            Self::StylelintDeclarationBlockNoDuplicateProperties(rule) => rule.types_info(),
+            Self::StylelintNoDescendingSpecificity(rule) => rule.types_info(),
+            Self::StylelintNoDuplicateSelectors(rule) => rule.types_info(),
+            Self::StylelintUnitNoUnknown(rule) => rule.types_info(),
+            Self::CompatCompat(rule) => rule.types_info(),
+            Self::CompatNoUnsupportedSyntax(rule) => rule.types_info(),
+            Self::AngularNoInputRename(rule) => rule.types_info(),
+            Self::AngularNoOutputOnPrefix(rule) => rule.types_info(),
+            Self::AngularTemplateBananaInBox(rule) => rule.types_info(),
+            Self::AngularTemplateEqeqeq(rule) => rule.types_info(),
+            Self::AngularUseLifecycleInterface(rule) => rule.types_info(),
+            Self::TestingLibraryAwaitAsyncQueries(rule) => rule.types_info(),
+            Self::TestingLibraryNoNodeAccess(rule) => rule.types_info(),
+            Self::TestingLibraryPreferScreenQueries(rule) => rule.types_info(),
+            Self::TestingLibraryPreferUserEvent(rule) => rule.types_info(),
+            Self::PlaywrightMissingPlaywrightAwait(rule) => rule.types_info(),
+            Self::PlaywrightNoForceOption(rule) => rule.types_info(),
+            Self::PlaywrightNoWaitForTimeout(rule) => rule.types_info(),
+            Self::PlaywrightPreferWebFirstAssertions(rule) => rule.types_info(),
+            Self::SecurityDetectChildProcess(rule) => rule.types_info(),
+            Self::SecurityDetectEvalWithExpression(rule) => rule.types_info(),
+            Self::SecurityDetectNonLiteralFsFilename(rule) => rule.types_info(),
+            Self::SecurityDetectNonLiteralRegexp(rule) => rule.types_info(),
+            Self::SecurityDetectNonLiteralRequire(rule) => rule.types_info(),
+            Self::SecurityDetectPossibleTimingAttacks(rule) => rule.types_info(),
+            Self::SecurityNoTaintedCode(rule) => rule.types_info(),
+            Self::SecurityNoTaintedCommand(rule) => rule.types_info(),
+            Self::SecurityNoTaintedHtml(rule) => rule.types_info(),
+            Self::SecurityNoTaintedPath(rule) => rule.types_info(),
+            Self::SecurityNoUnsanitizedMethod(rule) => rule.types_info(),
+            Self::SecurityNoUnsanitizedProperty(rule) => rule.types_info(),
+            Self::SvelteNoAtHtmlTags(rule) => rule.types_info(),
+            Self::SvelteNoReactiveDestructuring(rule) => rule.types_info(),
+            Self::SvelteValidRunePlacement(rule) => rule.types_info(),
+            Self::JsonConsistentDependencyVersions(rule) => rule.types_info(),
+            Self::JsonNoDuplicateKeys(rule) => rule.types_info(),
+            Self::JsonSortDependencies(rule) => rule.types_info(),
+            Self::JsonValidPackageJson(rule) => rule.types_info(),
+            Self::JsonValidTsconfig(rule) => rule.types_info(),
         }
     }
     pub fn run_info(&self) -> RuleRunFunctionsImplemented {
@@ -22174,13 +23353,20 @@ impl RuleEnum {
             Self::NodeExportsStyle(rule) => rule.run_info(),
             Self::NodeGlobalRequire(rule) => rule.run_info(),
             Self::NodeHandleCallbackErr(rule) => rule.run_info(),
+            Self::NodeHashbang(rule) => rule.run_info(),
+            Self::NodeNoDeprecatedApi(rule) => rule.run_info(),
             Self::NodeNoExportsAssign(rule) => rule.run_info(),
+            Self::NodeNoMissingImport(rule) => rule.run_info(),
+            Self::NodeNoMissingRequire(rule) => rule.run_info(),
             Self::NodeNoMixedRequires(rule) => rule.run_info(),
             Self::NodeNoNewRequire(rule) => rule.run_info(),
             Self::NodeNoPathConcat(rule) => rule.run_info(),
             Self::NodeNoProcessEnv(rule) => rule.run_info(),
             Self::NodeNoSync(rule) => rule.run_info(),
             Self::NodeNoTopLevelAwait(rule) => rule.run_info(),
+            Self::NodeNoUnpublishedImport(rule) => rule.run_info(),
+            Self::NodeNoUnsupportedFeaturesNodeBuiltins(rule) => rule.run_info(),
+            Self::NodePreferNodeProtocol(rule) => rule.run_info(),
             Self::VueComponentDefinitionNameCasing(rule) => rule.run_info(),
             Self::VueDefineEmitsDeclaration(rule) => rule.run_info(),
             Self::VueDefinePropsDeclaration(rule) => rule.run_info(),
@@ -22227,6 +23413,46 @@ impl RuleEnum {
             Self::VueValidDefineOptions(rule) => rule.run_info(),
             Self::VueValidDefineProps(rule) => rule.run_info(),
             Self::VueValidNextTick(rule) => rule.run_info(),
+            Self::StylelintColorNoInvalidHex(rule) => rule.run_info(),
+            Self::StylelintDeclarationBlockNoDuplicateProperties(rule) => rule.run_info(),
+            Self::StylelintNoDescendingSpecificity(rule) => rule.run_info(),
+            Self::StylelintNoDuplicateSelectors(rule) => rule.run_info(),
+            Self::StylelintUnitNoUnknown(rule) => rule.run_info(),
+            Self::CompatCompat(rule) => rule.run_info(),
+            Self::CompatNoUnsupportedSyntax(rule) => rule.run_info(),
+            Self::AngularNoInputRename(rule) => rule.run_info(),
+            Self::AngularNoOutputOnPrefix(rule) => rule.run_info(),
+            Self::AngularTemplateBananaInBox(rule) => rule.run_info(),
+            Self::AngularTemplateEqeqeq(rule) => rule.run_info(),
+            Self::AngularUseLifecycleInterface(rule) => rule.run_info(),
+            Self::TestingLibraryAwaitAsyncQueries(rule) => rule.run_info(),
+            Self::TestingLibraryNoNodeAccess(rule) => rule.run_info(),
+            Self::TestingLibraryPreferScreenQueries(rule) => rule.run_info(),
+            Self::TestingLibraryPreferUserEvent(rule) => rule.run_info(),
+            Self::PlaywrightMissingPlaywrightAwait(rule) => rule.run_info(),
+            Self::PlaywrightNoForceOption(rule) => rule.run_info(),
+            Self::PlaywrightNoWaitForTimeout(rule) => rule.run_info(),
+            Self::PlaywrightPreferWebFirstAssertions(rule) => rule.run_info(),
+            Self::SecurityDetectChildProcess(rule) => rule.run_info(),
+            Self::SecurityDetectEvalWithExpression(rule) => rule.run_info(),
+            Self::SecurityDetectNonLiteralFsFilename(rule) => rule.run_info(),
+            Self::SecurityDetectNonLiteralRegexp(rule) => rule.run_info(),
+            Self::SecurityDetectNonLiteralRequire(rule) => rule.run_info(),
+            Self::SecurityDetectPossibleTimingAttacks(rule) => rule.run_info(),
+            Self::SecurityNoTaintedCode(rule) => rule.run_info(),
+            Self::SecurityNoTaintedCommand(rule) => rule.run_info(),
+            Self::SecurityNoTaintedHtml(rule) => rule.run_info(),
+            Self::SecurityNoTaintedPath(rule) => rule.run_info(),
+            Self::SecurityNoUnsanitizedMethod(rule) => rule.run_info(),
+            Self::SecurityNoUnsanitizedProperty(rule) => rule.run_info(),
+            Self::SvelteNoAtHtmlTags(rule) => rule.run_info(),
+            Self::SvelteNoReactiveDestructuring(rule) => rule.run_info(),
+            Self::SvelteValidRunePlacement(rule) => rule.run_info(),
+            Self::JsonConsistentDependencyVersions(rule) => rule.run_info(),
+            Self::JsonNoDuplicateKeys(rule) => rule.run_info(),
+            Self::JsonSortDependencies(rule) => rule.run_info(),
+            Self::JsonValidPackageJson(rule) => rule.run_info(),
+            Self::JsonValidTsconfig(rule) => rule.run_info(),
         }
     }
 }
@@ -23180,13 +24406,22 @@ pub static RULES: std::sync::LazyLock<Vec<RuleEnum>> = std::sync::LazyLock::new(
         RuleEnum::NodeExportsStyle(NodeExportsStyle::default()),
         RuleEnum::NodeGlobalRequire(NodeGlobalRequire::default()),
         RuleEnum::NodeHandleCallbackErr(NodeHandleCallbackErr::default()),
+        RuleEnum::NodeHashbang(NodeHashbang::default()),
+        RuleEnum::NodeNoDeprecatedApi(NodeNoDeprecatedApi::default()),
         RuleEnum::NodeNoExportsAssign(NodeNoExportsAssign::default()),
+        RuleEnum::NodeNoMissingImport(NodeNoMissingImport::default()),
+        RuleEnum::NodeNoMissingRequire(NodeNoMissingRequire::default()),
         RuleEnum::NodeNoMixedRequires(NodeNoMixedRequires::default()),
         RuleEnum::NodeNoNewRequire(NodeNoNewRequire::default()),
         RuleEnum::NodeNoPathConcat(NodeNoPathConcat::default()),
         RuleEnum::NodeNoProcessEnv(NodeNoProcessEnv::default()),
         RuleEnum::NodeNoSync(NodeNoSync::default()),
         RuleEnum::NodeNoTopLevelAwait(NodeNoTopLevelAwait::default()),
+        RuleEnum::NodeNoUnpublishedImport(NodeNoUnpublishedImport::default()),
+        RuleEnum::NodeNoUnsupportedFeaturesNodeBuiltins(
+            NodeNoUnsupportedFeaturesNodeBuiltins::default(),
+        ),
+        RuleEnum::NodePreferNodeProtocol(NodePreferNodeProtocol::default()),
         RuleEnum::VueComponentDefinitionNameCasing(VueComponentDefinitionNameCasing::default()),
         RuleEnum::VueDefineEmitsDeclaration(VueDefineEmitsDeclaration::default()),
         RuleEnum::VueDefinePropsDeclaration(VueDefinePropsDeclaration::default()),
@@ -23237,5 +24472,49 @@ pub static RULES: std::sync::LazyLock<Vec<RuleEnum>> = std::sync::LazyLock::new(
         RuleEnum::VueValidDefineOptions(VueValidDefineOptions::default()),
         RuleEnum::VueValidDefineProps(VueValidDefineProps::default()),
         RuleEnum::VueValidNextTick(VueValidNextTick::default()),
+        RuleEnum::StylelintColorNoInvalidHex(StylelintColorNoInvalidHex::default()),
+        RuleEnum::StylelintDeclarationBlockNoDuplicateProperties(
+            StylelintDeclarationBlockNoDuplicateProperties::default(),
+        ),
+        RuleEnum::StylelintNoDescendingSpecificity(StylelintNoDescendingSpecificity::default()),
+        RuleEnum::StylelintNoDuplicateSelectors(StylelintNoDuplicateSelectors::default()),
+        RuleEnum::StylelintUnitNoUnknown(StylelintUnitNoUnknown::default()),
+        RuleEnum::CompatCompat(CompatCompat::default()),
+        RuleEnum::CompatNoUnsupportedSyntax(CompatNoUnsupportedSyntax::default()),
+        RuleEnum::AngularNoInputRename(AngularNoInputRename::default()),
+        RuleEnum::AngularNoOutputOnPrefix(AngularNoOutputOnPrefix::default()),
+        RuleEnum::AngularTemplateBananaInBox(AngularTemplateBananaInBox::default()),
+        RuleEnum::AngularTemplateEqeqeq(AngularTemplateEqeqeq::default()),
+        RuleEnum::AngularUseLifecycleInterface(AngularUseLifecycleInterface::default()),
+        RuleEnum::TestingLibraryAwaitAsyncQueries(TestingLibraryAwaitAsyncQueries::default()),
+        RuleEnum::TestingLibraryNoNodeAccess(TestingLibraryNoNodeAccess::default()),
+        RuleEnum::TestingLibraryPreferScreenQueries(TestingLibraryPreferScreenQueries::default()),
+        RuleEnum::TestingLibraryPreferUserEvent(TestingLibraryPreferUserEvent::default()),
+        RuleEnum::PlaywrightMissingPlaywrightAwait(PlaywrightMissingPlaywrightAwait::default()),
+        RuleEnum::PlaywrightNoForceOption(PlaywrightNoForceOption::default()),
+        RuleEnum::PlaywrightNoWaitForTimeout(PlaywrightNoWaitForTimeout::default()),
+        RuleEnum::PlaywrightPreferWebFirstAssertions(PlaywrightPreferWebFirstAssertions::default()),
+        RuleEnum::SecurityDetectChildProcess(SecurityDetectChildProcess::default()),
+        RuleEnum::SecurityDetectEvalWithExpression(SecurityDetectEvalWithExpression::default()),
+        RuleEnum::SecurityDetectNonLiteralFsFilename(SecurityDetectNonLiteralFsFilename::default()),
+        RuleEnum::SecurityDetectNonLiteralRegexp(SecurityDetectNonLiteralRegexp::default()),
+        RuleEnum::SecurityDetectNonLiteralRequire(SecurityDetectNonLiteralRequire::default()),
+        RuleEnum::SecurityDetectPossibleTimingAttacks(
+            SecurityDetectPossibleTimingAttacks::default(),
+        ),
+        RuleEnum::SecurityNoTaintedCode(SecurityNoTaintedCode::default()),
+        RuleEnum::SecurityNoTaintedCommand(SecurityNoTaintedCommand::default()),
+        RuleEnum::SecurityNoTaintedHtml(SecurityNoTaintedHtml::default()),
+        RuleEnum::SecurityNoTaintedPath(SecurityNoTaintedPath::default()),
+        RuleEnum::SecurityNoUnsanitizedMethod(SecurityNoUnsanitizedMethod::default()),
+        RuleEnum::SecurityNoUnsanitizedProperty(SecurityNoUnsanitizedProperty::default()),
+        RuleEnum::SvelteNoAtHtmlTags(SvelteNoAtHtmlTags::default()),
+        RuleEnum::SvelteNoReactiveDestructuring(SvelteNoReactiveDestructuring::default()),
+        RuleEnum::SvelteValidRunePlacement(SvelteValidRunePlacement::default()),
+        RuleEnum::JsonConsistentDependencyVersions(JsonConsistentDependencyVersions::default()),
+        RuleEnum::JsonNoDuplicateKeys(JsonNoDuplicateKeys::default()),
+        RuleEnum::JsonSortDependencies(JsonSortDependencies::default()),
+        RuleEnum::JsonValidPackageJson(JsonValidPackageJson::default()),
+        RuleEnum::JsonValidTsconfig(JsonValidTsconfig::default()),
     ]
 });
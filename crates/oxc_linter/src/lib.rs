@@ -12,6 +12,7 @@ use std::{
     ptr::{self, NonNull},
     rc::Rc,
     string::ToString,
+    sync::Arc,
 };
 
 use oxc_allocator::{Allocator, AllocatorPool, ArenaVec, CloneIn, TakeIn};
@@ -49,6 +50,7 @@ mod utils;
 
 pub mod loader;
 pub mod rules;
+pub mod rust_plugin;
 pub mod table;
 
 mod generated {
@@ -73,7 +75,7 @@ pub use crate::{
         Config, ConfigBuilderError, ConfigStore, ConfigStoreBuilder, ESLintRule, LintIgnoreMatcher,
         LintPlugins, Oxlintrc, ResolvedLinterState,
     },
-    context::{ContextSubHost, ContextSubHostOptions, LintContext},
+    context::{ContextHost, ContextSubHost, ContextSubHostOptions, LintContext},
     external_linter::{
         ExternalLinter, ExternalLinterCreateWorkspaceCb, ExternalLinterDestroyWorkspaceCb,
         ExternalLinterLintFileCb, ExternalLinterLoadPluginCb, ExternalLinterSetupRuleConfigsCb,
@@ -83,20 +85,19 @@ pub use crate::{
     fixer::{Fix, FixKind, Fixer, Message, PossibleFixes, oxc_code_short_canonical_name},
     frameworks::FrameworkFlags,
     lint_runner::{DirectivesStore, LintRunner, LintRunnerBuilder},
-    loader::LINTABLE_EXTENSIONS,
+    loader::{LINT_JSON_EXTENSIONS, LINTABLE_EXTENSIONS},
     module_record::ModuleRecord,
     options::LintOptions,
     options::{AllowWarnDeny, InvalidFilterKind, LintFilter, LintFilterKind},
-    rule::{RuleCategory, RuleFixMeta, RuleMeta, RuleRunFunctionsImplemented, RuleRunner},
+    rule::{Rule, RuleCategory, RuleFixMeta, RuleMeta, RuleRunFunctionsImplemented, RuleRunner},
     service::{LintService, LintServiceOptions, OsFileSystem, RuntimeFileSystem},
-    suppression::{OxlintSuppressionFileAction, SuppressionManager},
+    suppression::{OxlintSuppressionFileAction, SuppressionDebtSummary, SuppressionManager},
     timing::{RuleTimingRecord, RuleTimingSource, RuleTimingStore},
     tsgolint::TsGoLintState,
-    utils::{read_to_arena_str, read_to_string},
+    utils::{PossibleJestNode, read_to_arena_str, read_to_string},
 };
 use crate::{
     config::{LintConfig, OxlintEnv, OxlintGlobals, OxlintSettings},
-    context::ContextHost,
     external_linter::GlobalsAndEnvs,
     fixer::CompositeFix,
     loader::LINT_PARTIAL_LOADER_EXTENSIONS,
@@ -321,6 +322,16 @@ impl Linter {
         self.config.number_of_rules(type_aware)
     }
 
+    /// See [`ConfigStore::lints_path`].
+    pub fn lints_path(&self, path: &Path) -> bool {
+        self.config.lints_path(path)
+    }
+
+    /// See [`ConfigStore::lints_svelte_markup`].
+    pub fn lints_svelte_markup(&self, path: &Path) -> bool {
+        self.config.lints_svelte_markup(path)
+    }
+
     /// Return `true` if `Linter` has an external linter (JS plugins).
     pub fn has_external_linter(&self) -> bool {
         self.external_linter.is_some()
@@ -355,6 +366,12 @@ impl Linter {
         rule_timing_store: Option<&RuleTimingStore>,
     ) -> (Vec<Message>, Option<DisableDirectives>) {
         let ResolvedLinterState { rules, config, external_rules } = self.config.resolve(path);
+        // Rules of Rust plugins run in-process, only the rest are sent to JS side
+        let js_plugin_rules = if external_rules.iter().any(|(id, _, _)| id.is_rust_plugin_rule()) {
+            external_rules.iter().filter(|(id, _, _)| !id.is_rust_plugin_rule()).copied().collect()
+        } else {
+            Arc::clone(&external_rules)
+        };
         let mut timing_recorder = TIMINGS.then(|| RuleTimingRecorder::with_capacity(rules.len()));
 
         let mut ctx_host =
@@ -369,10 +386,17 @@ impl Linter {
 
         loop {
             let semantic = ctx_host.semantic();
+            // A stylesheet, JSON file or Svelte markup has no script: only the rules of its plugin run.
+            let file_plugin = ctx_host.current_sub_host().file_plugin();
             let rules = rules
                 .iter()
                 .filter(|(rule, _)| {
-                    if rule.is_tsgolint_rule() {
+                    if file_plugin.is_some_and(|plugin| rule.plugin_name() != plugin) {
+                        return false;
+                    }
+
+                    // Type-aware rules run in `tsgolint`, unless selected to run natively
+                    if rule.is_tsgolint_rule() && !self.config.is_native_type_aware_rule(rule) {
                         return false;
                     }
 
@@ -449,13 +473,19 @@ impl Linter {
             // can mutably access `ctx_host` via `Rc::get_mut` without panicking due to multiple references.
             drop(rules);
 
-            self.run_external_rules(
-                &external_rules,
-                path,
-                &mut ctx_host,
-                allocator,
-                js_allocator_pool,
-            );
+            if file_plugin.is_some() {
+                ctx_host.report_file_syntax_error();
+            } else {
+                self.run_rust_plugin_rules(&external_rules, &ctx_host);
+
+                self.run_external_rules(
+                    &js_plugin_rules,
+                    path,
+                    &mut ctx_host,
+                    allocator,
+                    js_allocator_pool,
+                );
+            }
 
             // Report unused directives is now handled differently with type-aware linting
 
@@ -500,6 +530,54 @@ impl Linter {
         result
     }
 
+    /// Run the rules of Rust plugins in `external_rules` on the current sub host.
+    fn run_rust_plugin_rules(
+        &self,
+        external_rules: &[(ExternalRuleId, ExternalOptionsId, AllowWarnDeny)],
+        ctx_host: &Rc<ContextHost<'_>>,
+    ) {
+        let external_plugin_store = self.config.external_plugin_store();
+        let rules = external_rules
+            .iter()
+            .filter(|(rule_id, _, _)| rule_id.is_rust_plugin_rule())
+            .map(|&(rule_id, options_id, severity)| {
+                let (plugin_name, rule_name, fix, rule) =
+                    external_plugin_store.rust_rule_instance(rule_id, options_id);
+                (rule, plugin_name, rule_name, fix, severity)
+            })
+            .filter(|(rule, ..)| rule.should_run(ctx_host))
+            .map(|(rule, plugin_name, rule_name, fix, severity)| {
+                let ctx = Rc::clone(ctx_host).spawn_for_rust_plugin_rule(
+                    plugin_name,
+                    rule_name,
+                    fix,
+                    severity,
+                );
+                (rule, ctx)
+            })
+            .collect::<Vec<_>>();
+        if rules.is_empty() {
+            return;
+        }
+
+        for (rule, ctx) in &rules {
+            rule.run_once(ctx);
+        }
+        for node in ctx_host.semantic().nodes() {
+            for (rule, ctx) in &rules {
+                rule.run(node, ctx);
+            }
+        }
+        // Same as for built-in rules, which only run on Jest nodes with a test plugin enabled
+        if ctx_host.plugins().has_test() && ctx_host.frameworks().is_test() {
+            for jest_node in iter_possible_jest_call_node(ctx_host.semantic()) {
+                for (rule, ctx) in &rules {
+                    rule.run_on_jest_node(&jest_node, ctx);
+                }
+            }
+        }
+    }
+
     #[cfg(all(target_pointer_width = "64", target_endian = "little"))]
     fn run_external_rules<'a>(
         &self,
@@ -4,8 +4,11 @@ use oxc_span::SourceType;
 
 mod partial_loader;
 mod source;
-pub use partial_loader::{LINT_PARTIAL_LOADER_EXTENSIONS, LINTABLE_EXTENSIONS, PartialLoader};
-pub use source::JavaScriptSource;
+pub use partial_loader::{
+    LINT_JSON_EXTENSIONS, LINT_PARTIAL_LOADER_EXTENSIONS, LINT_STYLESHEET_EXTENSIONS,
+    LINTABLE_EXTENSIONS, PartialLoader,
+};
+pub use source::{JavaScriptSource, JsonSource, StylesheetSource, TemplateSource};
 
 // TODO: use oxc_resolver::FileSystem. We can't do so until that crate exposes FileSystemOs
 // externally.
@@ -64,6 +67,12 @@ mod test {
             "foo.astro",
             "foo.svelte",
             "foo.vue",
+            "foo.css",
+            "foo.scss",
+            "foo.less",
+            "foo.json",
+            "foo.jsonc",
+            "foo.json5",
         ];
 
         for path in paths {
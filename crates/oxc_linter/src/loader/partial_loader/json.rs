@@ -0,0 +1,39 @@
+use oxc_formatter_json::JsonVariant;
+
+use crate::loader::JavaScriptSource;
+
+pub struct JsonPartialLoader<'a> {
+    source_text: &'a str,
+    variant: JsonVariant,
+}
+
+impl<'a> JsonPartialLoader<'a> {
+    pub fn new(source_text: &'a str, variant: JsonVariant) -> Self {
+        Self { source_text, variant }
+    }
+
+    /// A JSON document has no script to extract: return a single empty section that carries
+    /// the whole file, so that the file still gets a [`crate::ContextSubHost`] to lint.
+    pub fn parse(self) -> Vec<JavaScriptSource<'a>> {
+        vec![JavaScriptSource::json(self.source_text, self.variant)]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_formatter_json::JsonVariant;
+
+    use super::JsonPartialLoader;
+
+    #[test]
+    fn test_parse_json() {
+        let source_text = r#"{ "name": "foo" }"#;
+        let sources = JsonPartialLoader::new(source_text, JsonVariant::Jsonc).parse();
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].source_text, "");
+        assert!(sources[0].stylesheet.is_none());
+        let json = sources[0].json.unwrap();
+        assert_eq!(json.source_text, source_text);
+        assert_eq!(json.variant, JsonVariant::Jsonc);
+    }
+}
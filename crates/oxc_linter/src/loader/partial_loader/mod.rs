@@ -1,12 +1,18 @@
 use memchr::{memmem::Finder, memmem::FinderRev};
+use oxc_formatter_css::CssVariant;
+use oxc_formatter_json::JsonVariant;
 use oxc_span::VALID_EXTENSIONS;
 
 use crate::loader::JavaScriptSource;
 
 mod astro;
+mod json;
+mod stylesheet;
 mod svelte;
 mod vue;
 pub use astro::AstroPartialLoader;
+pub use json::JsonPartialLoader;
+pub use stylesheet::StylesheetPartialLoader;
 pub use svelte::SveltePartialLoader;
 pub use vue::VuePartialLoader;
 
@@ -15,9 +21,16 @@ const SCRIPT_END: &str = "</script>";
 const COMMENT_START: &str = "<!--";
 const COMMENT_END: &str = "-->";
 
+/// Stylesheet file extensions. They contain no JS/TS at all,
+/// but the [`PartialLoader`] still loads them (as one empty section) for the `stylelint` rules.
+pub const LINT_STYLESHEET_EXTENSIONS: &[&str] = &["css", "scss", "less"];
+
+/// JSON file extensions, loaded the same way as stylesheets for the `json` rules.
+pub const LINT_JSON_EXTENSIONS: &[&str] = &["json", "jsonc", "json5"];
+
 /// File extensions that can contain JS/TS code in certain parts, such as in `<script>` tags, and can
 /// be loaded using the [`PartialLoader`].
-pub const LINT_PARTIAL_LOADER_EXTENSIONS: &[&str] = &["vue", "astro", "svelte"];
+pub const LINT_PARTIAL_LOADER_EXTENSIONS: &[&str] = constcat::concat_slices!([&str]: &["vue", "astro", "svelte"], LINT_STYLESHEET_EXTENSIONS, LINT_JSON_EXTENSIONS);
 
 /// All valid JavaScript/TypeScript extensions, plus additional framework files that
 /// contain JavaScript/TypeScript code in them (e.g., Vue, Astro, Svelte, etc.).
@@ -34,6 +47,14 @@ impl PartialLoader {
             "vue" => Some(VuePartialLoader::new(source_text).parse()),
             "astro" => Some(AstroPartialLoader::new(source_text).parse()),
             "svelte" => Some(SveltePartialLoader::new(source_text).parse()),
+            "css" => Some(StylesheetPartialLoader::new(source_text, CssVariant::Css).parse()),
+            "scss" => Some(StylesheetPartialLoader::new(source_text, CssVariant::Scss).parse()),
+            "less" => Some(StylesheetPartialLoader::new(source_text, CssVariant::Less).parse()),
+            // Comments are common in `.json` files too (`tsconfig.json`, `.vscode/settings.json`).
+            "json" | "jsonc" => {
+                Some(JsonPartialLoader::new(source_text, JsonVariant::Jsonc).parse())
+            }
+            "json5" => Some(JsonPartialLoader::new(source_text, JsonVariant::Json5).parse()),
             _ => None,
         }
     }
@@ -0,0 +1,38 @@
+use oxc_formatter_css::CssVariant;
+
+use crate::loader::JavaScriptSource;
+
+pub struct StylesheetPartialLoader<'a> {
+    source_text: &'a str,
+    variant: CssVariant,
+}
+
+impl<'a> StylesheetPartialLoader<'a> {
+    pub fn new(source_text: &'a str, variant: CssVariant) -> Self {
+        Self { source_text, variant }
+    }
+
+    /// A stylesheet has no script to extract: return a single empty section that carries
+    /// the whole file, so that the file still gets a [`crate::ContextSubHost`] to lint.
+    pub fn parse(self) -> Vec<JavaScriptSource<'a>> {
+        vec![JavaScriptSource::stylesheet(self.source_text, self.variant)]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_formatter_css::CssVariant;
+
+    use super::StylesheetPartialLoader;
+
+    #[test]
+    fn test_parse_stylesheet() {
+        let source_text = "a { color: red; }";
+        let sources = StylesheetPartialLoader::new(source_text, CssVariant::Css).parse();
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].source_text, "");
+        let stylesheet = sources[0].stylesheet.unwrap();
+        assert_eq!(stylesheet.source_text, source_text);
+        assert_eq!(stylesheet.variant, CssVariant::Css);
+    }
+}
@@ -1,3 +1,5 @@
+use oxc_formatter_css::CssVariant;
+use oxc_formatter_json::JsonVariant;
 use oxc_span::SourceType;
 
 use crate::frameworks::FrameworkOptions;
@@ -15,6 +17,36 @@ pub struct JavaScriptSource<'a> {
 
     // some partial sources can have special options defined, like Vue's `<script setup>`.
     pub framework_options: FrameworkOptions,
+
+    /// Set for stylesheet files, whose only (empty) JS section carries the whole stylesheet.
+    pub stylesheet: Option<StylesheetSource<'a>>,
+
+    /// Set for JSON files, whose only (empty) JS section carries the whole JSON document.
+    pub json: Option<JsonSource<'a>>,
+
+    /// Set for the (empty) JS section of a Svelte component that carries its markup.
+    pub template: Option<TemplateSource<'a>>,
+}
+
+/// A whole stylesheet file (`.css`, `.scss`, `.less`), linted by the `stylelint` rules only.
+#[derive(Debug, Clone, Copy)]
+pub struct StylesheetSource<'a> {
+    pub source_text: &'a str,
+    pub variant: CssVariant,
+}
+
+/// A whole JSON file (`.json`, `.jsonc`, `.json5`), linted by the `json` rules only.
+#[derive(Debug, Clone, Copy)]
+pub struct JsonSource<'a> {
+    pub source_text: &'a str,
+    pub variant: JsonVariant,
+}
+
+/// The markup of a Svelte component, linted by the `svelte` template rules only.
+#[derive(Debug, Clone, Copy)]
+pub struct TemplateSource<'a> {
+    /// The whole component file. `<script>` and `<style>` blocks are skipped when scanned.
+    pub source_text: &'a str,
 }
 
 impl<'a> JavaScriptSource<'a> {
@@ -25,6 +57,9 @@ impl<'a> JavaScriptSource<'a> {
             start: 0,
             is_partial: false,
             framework_options: FrameworkOptions::Default,
+            stylesheet: None,
+            json: None,
+            template: None,
         }
     }
 
@@ -43,7 +78,40 @@ impl<'a> JavaScriptSource<'a> {
         framework_options: FrameworkOptions,
         start: u32,
     ) -> Self {
-        Self { source_text, source_type, start, is_partial: true, framework_options }
+        Self {
+            source_text,
+            source_type,
+            start,
+            is_partial: true,
+            framework_options,
+            stylesheet: None,
+            json: None,
+            template: None,
+        }
+    }
+
+    /// An empty JS section standing in for the stylesheet `source_text`.
+    pub fn stylesheet(source_text: &'a str, variant: CssVariant) -> Self {
+        Self {
+            stylesheet: Some(StylesheetSource { source_text, variant }),
+            ..Self::partial(&source_text[..0], SourceType::mjs(), 0)
+        }
+    }
+
+    /// An empty JS section standing in for the JSON document `source_text`.
+    pub fn json(source_text: &'a str, variant: JsonVariant) -> Self {
+        Self {
+            json: Some(JsonSource { source_text, variant }),
+            ..Self::partial(&source_text[..0], SourceType::mjs(), 0)
+        }
+    }
+
+    /// An empty JS section standing in for the markup of the Svelte component `source_text`.
+    pub fn template(source_text: &'a str) -> Self {
+        Self {
+            template: Some(TemplateSource { source_text }),
+            ..Self::partial(&source_text[..0], SourceType::mjs(), 0)
+        }
     }
 
     pub fn as_str(&self) -> &'a str {
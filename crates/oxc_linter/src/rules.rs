@@ -924,13 +924,20 @@ pub(crate) mod node {
     pub mod exports_style;
     pub mod global_require;
     pub mod handle_callback_err;
+    pub mod hashbang;
+    pub mod no_deprecated_api;
     pub mod no_exports_assign;
+    pub mod no_missing_import;
+    pub mod no_missing_require;
     pub mod no_mixed_requires;
     pub mod no_new_require;
     pub mod no_path_concat;
     pub mod no_process_env;
     pub mod no_sync;
     pub mod no_top_level_await;
+    pub mod no_unpublished_import;
+    pub mod no_unsupported_features_node_builtins;
+    pub mod prefer_node_protocol;
 }
 
 /// <https://github.com/vuejs/eslint-plugin-vue>
@@ -983,6 +990,72 @@ pub(crate) mod vue {
     pub mod valid_next_tick;
 }
 
+/// <https://github.com/stylelint/stylelint>
+pub(crate) mod stylelint {
+    pub mod color_no_invalid_hex;
+    pub mod declaration_block_no_duplicate_properties;
+    pub mod no_descending_specificity;
+    pub mod no_duplicate_selectors;
+    pub mod unit_no_unknown;
+}
+
+pub(crate) mod compat {
+    #[expect(clippy::module_inception)]
+    pub mod compat;
+    pub mod no_unsupported_syntax;
+}
+
+pub(crate) mod angular {
+    pub mod no_input_rename;
+    pub mod no_output_on_prefix;
+    pub mod template_banana_in_box;
+    pub mod template_eqeqeq;
+    pub mod use_lifecycle_interface;
+}
+
+pub(crate) mod testing_library {
+    pub mod await_async_queries;
+    pub mod no_node_access;
+    pub mod prefer_screen_queries;
+    pub mod prefer_user_event;
+}
+
+pub(crate) mod playwright {
+    pub mod missing_playwright_await;
+    pub mod no_force_option;
+    pub mod no_wait_for_timeout;
+    pub mod prefer_web_first_assertions;
+}
+
+pub(crate) mod security {
+    pub mod detect_child_process;
+    pub mod detect_eval_with_expression;
+    pub mod detect_non_literal_fs_filename;
+    pub mod detect_non_literal_regexp;
+    pub mod detect_non_literal_require;
+    pub mod detect_possible_timing_attacks;
+    pub mod no_tainted_code;
+    pub mod no_tainted_command;
+    pub mod no_tainted_html;
+    pub mod no_tainted_path;
+    pub mod no_unsanitized_method;
+    pub mod no_unsanitized_property;
+}
+
+pub(crate) mod svelte {
+    pub mod no_at_html_tags;
+    pub mod no_reactive_destructuring;
+    pub mod valid_rune_placement;
+}
+
+pub(crate) mod json {
+    pub mod consistent_dependency_versions;
+    pub mod no_duplicate_keys;
+    pub mod sort_dependencies;
+    pub mod valid_package_json;
+    pub mod valid_tsconfig;
+}
+
 pub(crate) mod shared;
 
 // Re-export RuleEnum, RULES, and all rule type aliases from generated code
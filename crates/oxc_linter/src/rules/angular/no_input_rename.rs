@@ -0,0 +1,135 @@
+use oxc_ast::{AstKind, ast::Expression};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        AngularBindingKind, get_angular_binding, get_angular_decorator, get_decorator_metadata,
+        get_object_property,
+    },
+};
+
+fn no_input_rename_diagnostic(span: Span, alias: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Input bindings should not be aliased as `{alias}`"))
+        .with_help("Rename the property instead, so that the input has one name everywhere.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoInputRename;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow aliasing inputs, as in `@Input('alias')`, `@Input({ alias })` or
+    /// `input(value, { alias })`.
+    ///
+    /// An input of a directive may still be aliased to an attribute of the directive's
+    /// selector, such as `@Input('appTooltip') text` for the selector `[appTooltip]`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An aliased input has one name in templates and another in the class, which makes it
+    /// harder to find where it is used.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// @Component({ selector: 'app-user' })
+    /// class UserComponent {
+    ///   @Input('user') userModel: User;
+    ///   label = input('', { alias: 'title' });
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// @Component({ selector: 'app-user' })
+    /// class UserComponent {
+    ///   @Input() user: User;
+    ///   title = input('');
+    /// }
+    /// ```
+    NoInputRename,
+    angular,
+    style,
+    version = "next",
+    short_description = "Disallow aliasing inputs.",
+);
+
+impl Rule for NoInputRename {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::PropertyDefinition(property) = node.kind() else {
+            return;
+        };
+        let Some(binding) = get_angular_binding(property) else {
+            return;
+        };
+        let Some(alias) = binding.alias else {
+            return;
+        };
+        if binding.kind != AngularBindingKind::Input
+            || selector_has_attribute(node, ctx, alias.value.as_str())
+        {
+            return;
+        }
+        ctx.diagnostic(no_input_rename_diagnostic(alias.span, &alias.value));
+    }
+}
+
+/// Whether the selector of the directive declaring `node` has the attribute `name`,
+/// such as `[appTooltip]` for `appTooltip`.
+fn selector_has_attribute(node: &AstNode, ctx: &LintContext, name: &str) -> bool {
+    let Some(AstKind::Class(class)) =
+        ctx.nodes().ancestor_kinds(node.id()).find(|kind| matches!(kind, AstKind::Class(_)))
+    else {
+        return false;
+    };
+    let Some(selector) = ["Directive", "Component"].iter().find_map(|decorator| {
+        let metadata =
+            get_decorator_metadata(get_angular_decorator(&class.decorators, decorator)?)?;
+        match get_object_property(metadata, "selector")? {
+            Expression::StringLiteral(selector) => Some(selector.value.as_str()),
+            _ => None,
+        }
+    }) else {
+        return false;
+    };
+    selector.contains(&format!("[{name}]")) || selector.contains(&format!("[{name}="))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "class Test { @Input() user: User; }",
+        "class Test { @Input({ required: true }) user: User; }",
+        "class Test { @Output('change') changed = new EventEmitter(); }",
+        "class Test { user = input<User>(); }",
+        "class Test { user = input.required<User>({ transform: toUser }); }",
+        "class Test { changed = output({ alias: 'change' }); }",
+        "class Test { label = other('', { alias: 'title' }); }",
+        "@Directive({ selector: '[appTooltip]' }) class Test { @Input('appTooltip') text: string; }",
+        "@Directive({ selector: 'button[appTooltip=top]' }) class Test { position = input('', { alias: 'appTooltip' }); }",
+    ];
+
+    let fail = vec![
+        "class Test { @Input('user') userModel: User; }",
+        "class Test { @Input({ alias: 'user', required: true }) userModel: User; }",
+        "class Test { label = input('', { alias: 'title' }); }",
+        "class Test { label = input.required({ alias: 'title' }); }",
+        "class Test { value = model(0, { alias: 'count' }); }",
+        "@Directive({ selector: '[appTooltip]' }) class Test { @Input('tooltipText') text: string; }",
+        "@Component({ selector: 'app-tooltip' }) class Test { @Input('appTooltip') text: string; }",
+    ];
+
+    Tester::new(NoInputRename::NAME, NoInputRename::PLUGIN, pass, fail)
+        .change_rule_path_extension("ts")
+        .test_and_snapshot();
+}
@@ -0,0 +1,127 @@
+use std::borrow::Cow;
+
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{AngularBindingKind, get_angular_binding},
+};
+
+fn no_output_on_prefix_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Output `{name}` should not be prefixed with `on`"))
+        .with_help("Name the output after the event, such as `change` rather than `onChange`.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoOutputOnPrefix;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow outputs whose public name starts with `on`, such as `onChange`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Angular binds events with `(change)="..."`, so an `on` prefix is redundant and
+    /// reads as `(onChange)`. It can also conflict with native DOM event attributes.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// @Component({ selector: 'app-search' })
+    /// class SearchComponent {
+    ///   @Output() onSearch = new EventEmitter<string>();
+    ///   onClear = output();
+    ///   cancel = output({ alias: 'onCancel' });
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// @Component({ selector: 'app-search' })
+    /// class SearchComponent {
+    ///   @Output() search = new EventEmitter<string>();
+    ///   clear = output();
+    ///   online = output<boolean>();
+    /// }
+    /// ```
+    NoOutputOnPrefix,
+    angular,
+    style,
+    version = "next",
+    short_description = "Disallow outputs whose public name starts with `on`, such as `onChange`.",
+);
+
+impl Rule for NoOutputOnPrefix {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::PropertyDefinition(property) = node.kind() else {
+            return;
+        };
+        let Some(binding) = get_angular_binding(property) else {
+            return;
+        };
+        if binding.kind != AngularBindingKind::Output {
+            return;
+        }
+        let (name, span) = if let Some(alias) = binding.alias {
+            (Cow::Borrowed(alias.value.as_str()), alias.span)
+        } else {
+            let Some(name) = property.key.static_name() else {
+                return;
+            };
+            (name, property.key.span())
+        };
+        if has_on_prefix(&name) {
+            ctx.diagnostic(no_output_on_prefix_diagnostic(span, &name));
+        }
+    }
+}
+
+/// `on`, followed by the end of the name or a character which is not a lowercase letter.
+fn has_on_prefix(name: &str) -> bool {
+    name.strip_prefix("on").is_some_and(|rest| !rest.starts_with(|c: char| c.is_ascii_lowercase()))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "class Test { @Output() change = new EventEmitter(); }",
+        "class Test { @Output() online = new EventEmitter(); }",
+        "class Test { @Output() one = new EventEmitter(); }",
+        "class Test { @Output('change') onChangeEvent = new EventEmitter(); }",
+        "class Test { @Output() buttonChange = new EventEmitter(); }",
+        "class Test { @Input() onChange: () => void; }",
+        "class Test { onChange = new EventEmitter(); }",
+        "class Test { change = output(); }",
+        "class Test { onboarding = output<boolean>(); }",
+        "class Test { onChange = output({ alias: 'change' }); }",
+        "class Test { change = outputFromObservable(change$, { alias: 'changed' }); }",
+        "class Test { onChange = input(); }",
+        "class Test { onChange() {} }",
+    ];
+
+    let fail = vec![
+        "class Test { @Output() onChange = new EventEmitter(); }",
+        "class Test { @Output() on = new EventEmitter(); }",
+        "class Test { @Output() on_change = new EventEmitter(); }",
+        "class Test { @Output('onChange') change = new EventEmitter(); }",
+        "class Test { @Output() 'onChange' = new EventEmitter(); }",
+        "class Test { onChange = output(); }",
+        "class Test { change = output({ alias: 'onChange' }); }",
+        "class Test { onChange = outputFromObservable(change$); }",
+        "class Test { change = outputFromObservable(change$, { alias: 'onChange' }); }",
+    ];
+
+    Tester::new(NoOutputOnPrefix::NAME, NoOutputOnPrefix::PLUGIN, pass, fail)
+        .change_rule_path_extension("ts")
+        .test_and_snapshot();
+}
@@ -0,0 +1,121 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{AstNode, context::LintContext, rule::Rule, utils::get_component_template};
+
+fn template_banana_in_box_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Invalid binding syntax. Use `[(...)]` instead of `([...])`")
+        .with_help(format!("Replace `([{name}])` with `[({name})]`."))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct TemplateBananaInBox;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require two-way bindings in inline component templates to be written as
+    /// `[(...)]` ("banana in a box") rather than `([...])`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `([ngModel])="name"` is an event binding to an event named `[ngModel]`, which never
+    /// fires, so the two-way binding silently does nothing.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// @Component({
+    ///   selector: 'app-name',
+    ///   template: '<input ([ngModel])="name">',
+    /// })
+    /// class NameComponent {}
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// @Component({
+    ///   selector: 'app-name',
+    ///   template: '<input [(ngModel)]="name">',
+    /// })
+    /// class NameComponent {}
+    /// ```
+    TemplateBananaInBox,
+    angular,
+    correctness,
+    fix,
+    version = "next",
+    short_description = "Require two-way bindings in inline component templates to be written as `[(...)]`.",
+);
+
+impl Rule for TemplateBananaInBox {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::Class(class) = node.kind() else {
+            return;
+        };
+        let Some(template) = get_component_template(class, ctx.source_text()) else {
+            return;
+        };
+        for attribute in &template.attributes {
+            let Some(name) =
+                attribute.name.strip_prefix("([").and_then(|name| name.strip_suffix("])"))
+            else {
+                continue;
+            };
+            ctx.diagnostic_with_fix(
+                template_banana_in_box_diagnostic(attribute.name_span, name),
+                |fixer| fixer.replace(attribute.name_span, format!("[({name})]")),
+            );
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "@Component({ template: '<input [(ngModel)]=\"name\">' }) class Test {}",
+        "@Component({ template: '<input [value]=\"name\" (input)=\"onInput()\">' }) class Test {}",
+        "@Component({ template: `<p>([notBound])</p>` }) class Test {}",
+        "@Component({ templateUrl: './test.html' }) class Test {}",
+        "@Directive({ template: '<input ([ngModel])=\"name\">' }) class Test {}",
+        "@Component({ template: `<input ([ngModel])=\"${name}\">` }) class Test {}",
+        "const template = '<input ([ngModel])=\"name\">';",
+    ];
+
+    let fail = vec![
+        "@Component({ template: '<input ([ngModel])=\"name\">' }) class Test {}",
+        "@Component({
+          selector: 'app-test',
+          template: `
+            <app-slider ([value])=\"volume\" />
+            <!-- ([ignored])=\"commented\" -->
+            <input type=\"text\" ([ngModel])='name' required>
+          `,
+        })
+        class Test {}",
+    ];
+
+    let fix = vec![
+        (
+            "@Component({ template: '<input ([ngModel])=\"name\">' }) class Test {}",
+            "@Component({ template: '<input [(ngModel)]=\"name\">' }) class Test {}",
+            None,
+        ),
+        (
+            "@Component({ template: `<app-slider ([value])=\"volume\" ([max])=\"max\" />` }) class Test {}",
+            "@Component({ template: `<app-slider [(value)]=\"volume\" [(max)]=\"max\" />` }) class Test {}",
+            None,
+        ),
+    ];
+
+    Tester::new(TemplateBananaInBox::NAME, TemplateBananaInBox::PLUGIN, pass, fail)
+        .change_rule_path_extension("ts")
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -0,0 +1,164 @@
+use oxc_ast::{
+    AstKind,
+    ast::{BinaryExpression, BinaryOperator},
+};
+use oxc_ast_visit::{Visit, walk::walk_binary_expression};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNode, context::LintContext, rule::Rule, utils::get_component_template};
+
+fn template_eqeqeq_diagnostic(span: Span, operator: &str, expected: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Expected `{expected}` but received `{operator}`"))
+        .with_help(format!("Use `{expected}` to compare without type coercion."))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct TemplateEqeqeq;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require `===` and `!==` in the expressions of inline component templates.
+    ///
+    /// Expressions of bindings, interpolations, `*ngIf` and `@if`/`@switch` blocks are checked.
+    /// Expressions which are not valid TypeScript, such as pipes with arguments
+    /// (`date | format:'short'`), are skipped.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `==` and `!=` coerce their operands, so `'0' == false` is `true`.
+    /// Templates are type-checked less strictly than classes, which makes these
+    /// comparisons easier to miss.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// @Component({
+    ///   selector: 'app-items',
+    ///   template: '<p *ngIf="items.length == 0">No items</p>',
+    /// })
+    /// class ItemsComponent {}
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// @Component({
+    ///   selector: 'app-items',
+    ///   template: '<p *ngIf="items.length === 0">No items</p>',
+    /// })
+    /// class ItemsComponent {}
+    /// ```
+    TemplateEqeqeq,
+    angular,
+    suspicious,
+    fix,
+    version = "next",
+    short_description = "Require `===` and `!==` in the expressions of inline component templates.",
+);
+
+impl Rule for TemplateEqeqeq {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::Class(class) = node.kind() else {
+            return;
+        };
+        let Some(template) = get_component_template(class, ctx.source_text()) else {
+            return;
+        };
+        for expression in &template.expressions {
+            let Some(parsed) = expression.parse(ctx.allocator()) else {
+                continue;
+            };
+            let mut finder = LooseEqualityFinder { text: expression.text, operators: vec![] };
+            finder.visit_expression(&parsed);
+            for (operator, span) in finder.operators {
+                let (operator, expected) = if operator == BinaryOperator::Equality {
+                    ("==", "===")
+                } else {
+                    ("!=", "!==")
+                };
+                let span = expression.map_span(span);
+                ctx.diagnostic_with_fix(
+                    template_eqeqeq_diagnostic(span, operator, expected),
+                    |fixer| fixer.replace(span, expected),
+                );
+            }
+        }
+    }
+}
+
+/// Collects the `==` and `!=` operators of an expression, with the spans of the operators.
+struct LooseEqualityFinder<'a> {
+    text: &'a str,
+    operators: Vec<(BinaryOperator, Span)>,
+}
+
+impl<'a> Visit<'a> for LooseEqualityFinder<'a> {
+    fn visit_binary_expression(&mut self, expression: &BinaryExpression<'a>) {
+        if matches!(expression.operator, BinaryOperator::Equality | BinaryOperator::Inequality) {
+            let operator = expression.operator.as_str();
+            let between = Span::new(expression.left.span().end, expression.right.span().start);
+            // Comments cannot contain the operator: Angular expressions do not support them.
+            if let Some(offset) = between.source_text(self.text).find(operator) {
+                #[expect(clippy::cast_possible_truncation)]
+                let span = Span::sized(between.start + offset as u32, operator.len() as u32);
+                self.operators.push((expression.operator, span));
+            }
+        }
+        walk_binary_expression(self, expression);
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "@Component({ template: '<p *ngIf=\"items.length === 0\">{{ a !== b }}</p>' }) class Test {}",
+        "@Component({ template: '<p [hidden]=\"a === b\" (click)=\"c = d\">x == y</p>' }) class Test {}",
+        "@Component({ template: `<p>{{ value | date:'short' }}</p>` }) class Test {}",
+        "@Component({ template: `@if (a === b) { <p>{{ c }}</p> }` }) class Test {}",
+        "@Component({ template: '<p title=\"a == b\"></p>' }) class Test {}",
+        "@Component({ templateUrl: './test.html' }) class Test {}",
+        "class Test { template = '<p *ngIf=\"a == b\"></p>'; }",
+    ];
+
+    let fail = vec![
+        "@Component({ template: '<p *ngIf=\"items.length == 0; else loading\"></p>' }) class Test {}",
+        "@Component({ template: '<p [hidden]=\"a != b\"></p>' }) class Test {}",
+        "@Component({ template: '<button (click)=\"enabled = count == 0\"></button>' }) class Test {}",
+        "@Component({ template: `<p>{{ (a == b) ? 'yes' : 'no' }}</p>` }) class Test {}",
+        "@Component({ template: `<p title=\"{{ a != null }}\"></p>` }) class Test {}",
+        "@Component({
+          template: `
+            @if (user.role == 'admin') {
+              <p>Admin</p>
+            } @else if (user.role != 'guest') {
+              <p>{{ user.name }}</p>
+            }
+          `,
+        })
+        class Test {}",
+    ];
+
+    let fix = vec![
+        (
+            "@Component({ template: '<p [hidden]=\"a != b\">{{ c==d }}</p>' }) class Test {}",
+            "@Component({ template: '<p [hidden]=\"a !== b\">{{ c===d }}</p>' }) class Test {}",
+            None,
+        ),
+        (
+            "@Component({ template: `@if (a == b) {}` }) class Test {}",
+            "@Component({ template: `@if (a === b) {}` }) class Test {}",
+            None,
+        ),
+    ];
+
+    Tester::new(TemplateEqeqeq::NAME, TemplateEqeqeq::PLUGIN, pass, fail)
+        .change_rule_path_extension("ts")
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -0,0 +1,145 @@
+use oxc_ast::{
+    AstKind,
+    ast::{ClassElement, TSTypeName},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNode, context::LintContext, rule::Rule, utils::is_angular_class};
+
+fn use_lifecycle_interface_diagnostic(span: Span, method: &str, interface: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Lifecycle method `{method}` should be declared by implementing `{interface}`"
+    ))
+    .with_help(format!(
+        "Add `implements {interface}` to the class, importing `{interface}` from `@angular/core`."
+    ))
+    .with_label(span)
+}
+
+/// Lifecycle methods and the `@angular/core` interfaces which declare them.
+const LIFECYCLE_INTERFACES: [(&str, &str); 9] = [
+    ("ngOnChanges", "OnChanges"),
+    ("ngOnInit", "OnInit"),
+    ("ngDoCheck", "DoCheck"),
+    ("ngAfterContentInit", "AfterContentInit"),
+    ("ngAfterContentChecked", "AfterContentChecked"),
+    ("ngAfterViewInit", "AfterViewInit"),
+    ("ngAfterViewChecked", "AfterViewChecked"),
+    ("ngOnDestroy", "OnDestroy"),
+    ("ngDoBootstrap", "DoBootstrap"),
+];
+
+#[derive(Debug, Default, Clone)]
+pub struct UseLifecycleInterface;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require Angular classes which define a lifecycle method, such as `ngOnInit`,
+    /// to implement the matching interface, such as `OnInit`.
+    ///
+    /// Only classes decorated with `@Component`, `@Directive`, `@Pipe`, `@Injectable`
+    /// or `@NgModule` are checked.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The interface makes the type checker verify the signature of the method,
+    /// and catches a misspelled method name, which Angular would silently never call.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// @Component({ selector: 'app-root' })
+    /// class AppComponent {
+    ///   ngOnInit() {}
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// @Component({ selector: 'app-root' })
+    /// class AppComponent implements OnInit {
+    ///   ngOnInit() {}
+    /// }
+    /// ```
+    UseLifecycleInterface,
+    angular,
+    style,
+    version = "next",
+    short_description = "Require Angular classes which define a lifecycle method to implement the matching interface.",
+);
+
+impl Rule for UseLifecycleInterface {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::Class(class) = node.kind() else {
+            return;
+        };
+        if !is_angular_class(class) {
+            return;
+        }
+        // `implements OnInit` or `implements ng.OnInit`
+        let implemented = class
+            .implements
+            .iter()
+            .filter_map(|implements| match &implements.expression {
+                TSTypeName::IdentifierReference(ident) => Some(ident.name.as_str()),
+                TSTypeName::QualifiedName(name) => Some(name.right.name.as_str()),
+                TSTypeName::ThisExpression(_) => None,
+            })
+            .collect::<Vec<_>>();
+        for element in &class.body.body {
+            let ClassElement::MethodDefinition(method) = element else {
+                continue;
+            };
+            if method.r#static {
+                continue;
+            }
+            let Some(name) = method.key.static_name() else {
+                continue;
+            };
+            let Some((_, interface)) =
+                LIFECYCLE_INTERFACES.iter().find(|(method_name, _)| *method_name == name)
+            else {
+                continue;
+            };
+            if !implemented.contains(interface) {
+                ctx.diagnostic(use_lifecycle_interface_diagnostic(
+                    method.key.span(),
+                    &name,
+                    interface,
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "@Component({}) class Test implements OnInit { ngOnInit() {} }",
+        "@Directive({}) class Test implements OnInit, OnDestroy { ngOnInit() {} ngOnDestroy() {} }",
+        "@Injectable() class Test implements ng.OnDestroy { ngOnDestroy() {} }",
+        "@NgModule({}) class Test implements DoBootstrap { ngDoBootstrap() {} }",
+        "@Component({}) class Test { ngOnInitialize() {} onInit() {} }",
+        "@Component({}) class Test { static ngOnInit() {} }",
+        "class Test { ngOnInit() {} }",
+        "@Other({}) class Test { ngOnInit() {} }",
+    ];
+
+    let fail = vec![
+        "@Component({}) class Test { ngOnInit() {} }",
+        "@Directive({}) class Test implements OnInit { ngOnInit() {} ngOnDestroy() {} }",
+        "@Pipe({}) class Test implements ng.OnInit { ngOnChanges(changes) {} }",
+        "@Component({}) export class Test extends Base { ngAfterViewInit() {} ngAfterViewChecked() {} }",
+        "@Injectable() class Test { ngOnDestroy = () => {}; 'ngDoCheck'() {} }",
+    ];
+
+    Tester::new(UseLifecycleInterface::NAME, UseLifecycleInterface::PLUGIN, pass, fail)
+        .change_rule_path_extension("ts")
+        .test_and_snapshot();
+}
@@ -0,0 +1,314 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, StaticMemberExpression, UnaryOperator},
+};
+use oxc_compat::{Builtin, EngineTargets, builtins};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::IsGlobalReference;
+use oxc_span::{GetSpan, Span};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    utils::{compat_target_name, compat_targets},
+};
+
+fn compat_diagnostic(builtin: Builtin, target: &str, span: Span) -> OxcDiagnostic {
+    let name = builtin.name();
+    OxcDiagnostic::warn(format!("`{name}` is not supported in {target}."))
+        .with_help(format!(
+            "Avoid `{name}`, or add it to `settings.compat.polyfills` if it is polyfilled."
+        ))
+        .with_label(span)
+}
+
+fn invalid_targets_diagnostic(error: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Invalid compat targets: {error}"))
+        .with_help("Fix `settings.compat.targets` or the browserslist configuration.")
+        .with_label(Span::empty(0))
+}
+
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct Compat {
+    /// Also check prototype methods called on values of unknown type, such as `items.at(-1)`.
+    ///
+    /// By default, prototype methods are only checked on array, string and template literals,
+    /// since the type of other receivers is not known.
+    aggressive: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow built-in APIs which are not supported by the targeted environments, such as
+    /// `structuredClone`, `Object.hasOwn` or `Array.prototype.at`.
+    ///
+    /// The targets are read from `settings.compat.targets`, then from the `.browserslistrc` or
+    /// `browserslist` field of `package.json` nearest to the config file, and default to the
+    /// browserslist `defaults` query.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Unlike syntax, built-in APIs are not transformed when compiling for older environments,
+    /// so using them without a polyfill throws at runtime.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with `{ "settings": { "compat": { "targets": "chrome 90" } } }`:
+    /// ```js
+    /// const copy = structuredClone(value);
+    /// const last = [1, 2, 3].at(-1);
+    /// Object.hasOwn(object, "key");
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with `{ "settings": { "compat": { "targets": "chrome 90" } } }`:
+    /// ```js
+    /// const copy = JSON.parse(JSON.stringify(value));
+    /// const last = [1, 2, 3].slice(-1)[0];
+    /// if (typeof structuredClone === "function") {}
+    /// ```
+    Compat,
+    compat,
+    correctness,
+    config = Compat,
+    version = "next",
+    short_description = "Disallow built-in APIs which are not supported by the targeted environments.",
+);
+
+impl Rule for Compat {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        DefaultRuleConfig::<Self>::from_value(value).map(DefaultRuleConfig::into_inner)
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let targets = match compat_targets(ctx) {
+            Ok(targets) => targets,
+            Err(error) => {
+                ctx.diagnostic(invalid_targets_diagnostic(&error));
+                return;
+            }
+        };
+        if targets.is_any_target() {
+            return;
+        }
+
+        for node in ctx.nodes() {
+            let (builtin, span) = match node.kind() {
+                AstKind::IdentifierReference(ident) => {
+                    let Some(builtin) = global_builtin(&ident.name)
+                        .filter(|_| ident.is_global_reference(ctx.scoping()))
+                    else {
+                        continue;
+                    };
+                    (builtin, ident.span)
+                }
+                AstKind::StaticMemberExpression(member) => {
+                    let Some(builtin) = static_builtin(member, ctx)
+                        .or_else(|| self.prototype_builtin(node, member, ctx))
+                    else {
+                        continue;
+                    };
+                    (builtin, member.property.span)
+                }
+                _ => continue,
+            };
+            if is_feature_detection(node, ctx) || is_polyfilled(builtin, ctx) {
+                continue;
+            }
+            report_unsupported(builtin, span, &targets, ctx);
+        }
+    }
+}
+
+impl Compat {
+    /// `[].at(0)`, `"".at(0)`, or `items.at(0)` when `aggressive`.
+    fn prototype_builtin(
+        &self,
+        node: &AstNode,
+        member: &StaticMemberExpression,
+        ctx: &LintContext,
+    ) -> Option<Builtin> {
+        let AstKind::CallExpression(call) = ctx.nodes().parent_kind(node.id()) else {
+            return None;
+        };
+        if call.callee.get_inner_expression().span() != member.span {
+            return None;
+        }
+        let name = member.property.name.as_str();
+        match member.object.get_inner_expression() {
+            Expression::ArrayExpression(_) => array_prototype_builtin(name),
+            Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => {
+                string_prototype_builtin(name)
+            }
+            _ if self.aggressive => {
+                array_prototype_builtin(name).or_else(|| string_prototype_builtin(name))
+            }
+            _ => None,
+        }
+    }
+}
+
+fn report_unsupported(builtin: Builtin, span: Span, targets: &EngineTargets, ctx: &LintContext) {
+    if let Some((engine, version)) = targets.find_unsupported(&builtins()[&builtin]) {
+        ctx.diagnostic(compat_diagnostic(builtin, &compat_target_name(engine, version), span));
+    }
+}
+
+/// `typeof structuredClone`, `typeof Object.hasOwn`
+fn is_feature_detection(node: &AstNode, ctx: &LintContext) -> bool {
+    matches!(
+        ctx.nodes().parent_kind(node.id()),
+        AstKind::UnaryExpression(unary) if unary.operator == UnaryOperator::Typeof
+    )
+}
+
+/// A polyfill of `Promise` also covers `Promise.any`.
+fn is_polyfilled(builtin: Builtin, ctx: &LintContext) -> bool {
+    let name = builtin.name();
+    ctx.settings().compat.polyfills.iter().any(|polyfill| {
+        name.strip_prefix(polyfill.as_str())
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+    })
+}
+
+fn global_builtin(name: &str) -> Option<Builtin> {
+    Some(match name {
+        "globalThis" => Builtin::GlobalThis,
+        "structuredClone" => Builtin::StructuredClone,
+        "queueMicrotask" => Builtin::QueueMicrotask,
+        "BigInt" => Builtin::BigInt,
+        "WeakRef" => Builtin::WeakRef,
+        "FinalizationRegistry" => Builtin::FinalizationRegistry,
+        "AggregateError" => Builtin::AggregateError,
+        _ => return None,
+    })
+}
+
+/// `Object.hasOwn`, `Promise.any`
+fn static_builtin(member: &StaticMemberExpression, ctx: &LintContext) -> Option<Builtin> {
+    let Expression::Identifier(object) = &member.object else {
+        return None;
+    };
+    let builtin = match (object.name.as_str(), member.property.name.as_str()) {
+        ("Object", "fromEntries") => Builtin::ObjectFromEntries,
+        ("Object", "hasOwn") => Builtin::ObjectHasOwn,
+        ("Object", "groupBy") => Builtin::ObjectGroupBy,
+        ("Map", "groupBy") => Builtin::MapGroupBy,
+        ("Promise", "allSettled") => Builtin::PromiseAllSettled,
+        ("Promise", "any") => Builtin::PromiseAny,
+        ("Promise", "withResolvers") => Builtin::PromiseWithResolvers,
+        _ => return None,
+    };
+    object.is_global_reference(ctx.scoping()).then_some(builtin)
+}
+
+fn array_prototype_builtin(name: &str) -> Option<Builtin> {
+    Some(match name {
+        "includes" => Builtin::ArrayPrototypeIncludes,
+        "flat" => Builtin::ArrayPrototypeFlat,
+        "flatMap" => Builtin::ArrayPrototypeFlatMap,
+        "at" => Builtin::ArrayPrototypeAt,
+        "findLast" => Builtin::ArrayPrototypeFindLast,
+        "findLastIndex" => Builtin::ArrayPrototypeFindLastIndex,
+        "toReversed" => Builtin::ArrayPrototypeToReversed,
+        "toSorted" => Builtin::ArrayPrototypeToSorted,
+        "toSpliced" => Builtin::ArrayPrototypeToSpliced,
+        "with" => Builtin::ArrayPrototypeWith,
+        _ => return None,
+    })
+}
+
+fn string_prototype_builtin(name: &str) -> Option<Builtin> {
+    Some(match name {
+        "padStart" => Builtin::StringPrototypePadStart,
+        "padEnd" => Builtin::StringPrototypePadEnd,
+        "trimStart" => Builtin::StringPrototypeTrimStart,
+        "trimEnd" => Builtin::StringPrototypeTrimEnd,
+        "matchAll" => Builtin::StringPrototypeMatchAll,
+        "replaceAll" => Builtin::StringPrototypeReplaceAll,
+        "at" => Builtin::StringPrototypeAt,
+        "isWellFormed" => Builtin::StringPrototypeIsWellFormed,
+        "toWellFormed" => Builtin::StringPrototypeToWellFormed,
+        _ => return None,
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let targets = |targets: serde_json::Value| {
+        Some(serde_json::json!({ "settings": { "compat": { "targets": targets } } }))
+    };
+
+    let pass = vec![
+        ("structuredClone(value)", None, targets(serde_json::json!("chrome 98"))),
+        ("structuredClone(value)", None, targets(serde_json::json!(["chrome100", "safari16"]))),
+        ("[1, 2].at(-1)", None, targets(serde_json::json!("chrome 92, safari 15.4"))),
+        ("Object.hasOwn(object, 'key')", None, targets(serde_json::json!("es2022"))),
+        // Web APIs are not part of any ECMAScript version.
+        ("structuredClone(value)", None, targets(serde_json::json!("es2015"))),
+        // Feature detection
+        (
+            "if (typeof structuredClone === 'function') {}",
+            None,
+            targets(serde_json::json!("chrome 90")),
+        ),
+        ("typeof Object.hasOwn", None, targets(serde_json::json!("chrome 90"))),
+        // Shadowed globals
+        (
+            "function structuredClone(value) {} structuredClone(value)",
+            None,
+            targets(serde_json::json!("chrome 90")),
+        ),
+        ("const Object = {}; Object.hasOwn(a, b)", None, targets(serde_json::json!("chrome 90"))),
+        // Receivers of unknown type are only checked with `aggressive`.
+        ("items.at(-1)", None, targets(serde_json::json!("chrome 90"))),
+        ("[1, 2].at", None, targets(serde_json::json!("chrome 90"))),
+        // Polyfills
+        (
+            "structuredClone(value)",
+            None,
+            Some(serde_json::json!({
+                "settings": { "compat": { "targets": "chrome 90", "polyfills": ["structuredClone"] } }
+            })),
+        ),
+        (
+            "Promise.any([a, b])",
+            None,
+            Some(serde_json::json!({
+                "settings": { "compat": { "targets": "chrome 80", "polyfills": ["Promise"] } }
+            })),
+        ),
+    ];
+
+    let fail = vec![
+        ("structuredClone(value)", None, targets(serde_json::json!("chrome 90"))),
+        ("const last = [1, 2, 3].at(-1);", None, targets(serde_json::json!("safari 15"))),
+        ("`abc`.replaceAll('a', 'b')", None, targets(serde_json::json!("firefox 70"))),
+        ("Object.hasOwn(object, 'key')", None, targets(serde_json::json!("es2021"))),
+        ("Object.groupBy(items, fn)", None, targets(serde_json::json!(["chrome120", "safari17"]))),
+        ("new WeakRef(value)", None, targets(serde_json::json!("chrome 83"))),
+        ("globalThis.foo", None, targets(serde_json::json!("ie 11"))),
+        (
+            "items.toSorted()",
+            Some(serde_json::json!([{ "aggressive": true }])),
+            targets(serde_json::json!("chrome 100")),
+        ),
+        (
+            "Promise.any([a, b])",
+            None,
+            Some(serde_json::json!({
+                "settings": { "compat": { "targets": "chrome 80", "polyfills": ["Promise.allSettled"] } }
+            })),
+        ),
+        ("structuredClone(value)", None, targets(serde_json::json!("not a valid query"))),
+    ];
+
+    Tester::new(Compat::NAME, Compat::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,359 @@
+use oxc_ast::{
+    AstKind,
+    ast::{
+        Argument, AssignmentOperator, BinaryOperator, Expression, LogicalOperator,
+        ObjectPropertyKind, PropertyKey, VariableDeclarationKind,
+    },
+};
+use oxc_compat::{ESFeature, EngineTargets, features};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::{
+    RegexUnsupportedPatterns, has_unsupported_regular_expression_pattern,
+};
+use oxc_span::Span;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{compat_target_name, compat_targets, run_on_regex_node},
+};
+
+fn no_unsupported_syntax_diagnostic(feature: ESFeature, target: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unsupported syntax in {target}: {}.", feature_name(feature)))
+        .with_help("Transpile this syntax for the targeted environments, or avoid using it.")
+        .with_label(span)
+}
+
+fn invalid_targets_diagnostic(error: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Invalid compat targets: {error}"))
+        .with_help("Fix `settings.compat.targets` or the browserslist configuration.")
+        .with_label(Span::empty(0))
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnsupportedSyntax;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow syntax which is not supported by the targeted environments, such as optional
+    /// chaining, class static blocks or the RegExp `v` flag.
+    ///
+    /// The targets are resolved the same way as for `compat/compat`, and checked against the
+    /// compatibility data the transformer uses to decide which syntax to lower.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Unsupported syntax fails to parse, so the whole script does not run in the targeted
+    /// environments. This usually means the code is shipped without being transpiled for them.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with `{ "settings": { "compat": { "targets": "chrome 79" } } }`:
+    /// ```js
+    /// const name = user?.name ?? "anonymous";
+    /// const pattern = /[\p{L}--[a-z]]/v;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with `{ "settings": { "compat": { "targets": "chrome 79" } } }`:
+    /// ```js
+    /// const name = (user && user.name) || "anonymous";
+    /// const pattern = /\p{L}/u;
+    /// ```
+    NoUnsupportedSyntax,
+    compat,
+    correctness,
+    version = "next",
+    short_description = "Disallow syntax which is not supported by the targeted environments.",
+);
+
+impl Rule for NoUnsupportedSyntax {
+    fn run_once(&self, ctx: &LintContext) {
+        let targets = match compat_targets(ctx) {
+            Ok(targets) => targets,
+            Err(error) => {
+                ctx.diagnostic(invalid_targets_diagnostic(&error));
+                return;
+            }
+        };
+        if targets.is_any_target() {
+            return;
+        }
+
+        for node in ctx.nodes() {
+            let mut check = |feature: ESFeature, span: Span| {
+                if let Some((engine, version)) = targets.find_unsupported(&features()[&feature]) {
+                    let target = compat_target_name(engine, version);
+                    ctx.diagnostic(no_unsupported_syntax_diagnostic(feature, &target, span));
+                }
+            };
+            check_node(node, ctx, &mut check);
+            check_regex(node, ctx, &targets, &mut check);
+        }
+    }
+}
+
+fn check_node(node: &AstNode, ctx: &LintContext, check: &mut impl FnMut(ESFeature, Span)) {
+    match node.kind() {
+        AstKind::ArrowFunctionExpression(arrow) => {
+            check(ESFeature::ES2015ArrowFunctions, arrow.span);
+            if arrow.r#async {
+                check(ESFeature::ES2017AsyncToGenerator, arrow.span);
+            }
+        }
+        AstKind::Function(func) => match (func.r#async, func.generator) {
+            (true, true) => check(ESFeature::ES2018AsyncGeneratorFunctions, func.span),
+            (true, false) => check(ESFeature::ES2017AsyncToGenerator, func.span),
+            (false, true) => check(ESFeature::ES2015Regenerator, func.span),
+            (false, false) => {}
+        },
+        AstKind::Class(class) => check(ESFeature::ES2015Classes, class.span),
+        AstKind::PropertyDefinition(prop) => check(ESFeature::ES2022ClassProperties, prop.span),
+        AstKind::MethodDefinition(method)
+            if matches!(method.key, PropertyKey::PrivateIdentifier(_)) =>
+        {
+            check(ESFeature::ES2022PrivateMethods, method.span);
+        }
+        AstKind::StaticBlock(block) => check(ESFeature::ES2022ClassStaticBlock, block.span),
+        AstKind::PrivateInExpression(expr) => {
+            check(ESFeature::ES2022PrivatePropertyInObject, expr.span);
+        }
+        AstKind::TemplateLiteral(lit) => check(ESFeature::ES2015TemplateLiterals, lit.span),
+        AstKind::VariableDeclaration(decl) => match decl.kind {
+            VariableDeclarationKind::Var => {}
+            VariableDeclarationKind::Let | VariableDeclarationKind::Const => {
+                check(ESFeature::ES2015BlockScoping, decl.span);
+            }
+            VariableDeclarationKind::Using | VariableDeclarationKind::AwaitUsing => {
+                check(ESFeature::ES2026ExplicitResourceManagement, decl.span);
+            }
+        },
+        AstKind::ForOfStatement(stmt) => {
+            check(ESFeature::ES2015ForOf, stmt.span);
+            if stmt.r#await && is_top_level(node, ctx) {
+                check(ESFeature::ES2022TopLevelAwait, stmt.span);
+            }
+        }
+        AstKind::AwaitExpression(expr) if is_top_level(node, ctx) => {
+            check(ESFeature::ES2022TopLevelAwait, expr.span);
+        }
+        AstKind::ChainExpression(expr) => check(ESFeature::ES2020OptionalChaining, expr.span),
+        AstKind::LogicalExpression(expr) if expr.operator == LogicalOperator::Coalesce => {
+            check(ESFeature::ES2020NullishCoalescingOperator, expr.span);
+        }
+        AstKind::BinaryExpression(expr) if expr.operator == BinaryOperator::Exponential => {
+            check(ESFeature::ES2016ExponentiationOperator, expr.span);
+        }
+        AstKind::AssignmentExpression(expr) => {
+            if expr.operator.is_logical() {
+                check(ESFeature::ES2021LogicalAssignmentOperators, expr.span);
+            } else if expr.operator == AssignmentOperator::Exponential {
+                check(ESFeature::ES2016ExponentiationOperator, expr.span);
+            }
+        }
+        AstKind::ObjectExpression(object) => {
+            for property in &object.properties {
+                if let ObjectPropertyKind::SpreadProperty(spread) = property {
+                    check(ESFeature::ES2018ObjectRestSpread, spread.span);
+                }
+            }
+        }
+        AstKind::ObjectPattern(pattern) => {
+            if let Some(rest) = &pattern.rest {
+                check(ESFeature::ES2018ObjectRestSpread, rest.span);
+            }
+        }
+        AstKind::CatchClause(clause) if clause.param.is_none() => {
+            check(ESFeature::ES2019OptionalCatchBinding, clause.span);
+        }
+        AstKind::NumericLiteral(lit) if lit.raw.is_some_and(|raw| raw.contains('_')) => {
+            check(ESFeature::ES2021NumericSeparator, lit.span);
+        }
+        AstKind::BigIntLiteral(lit) => {
+            check(ESFeature::ES2020BigInt, lit.span);
+            if lit.raw.is_some_and(|raw| raw.contains('_')) {
+                check(ESFeature::ES2021NumericSeparator, lit.span);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Flags of regex literals and `new RegExp()` calls, and the syntax of their patterns.
+fn check_regex<'a>(
+    node: &AstNode<'a>,
+    ctx: &LintContext<'a>,
+    targets: &EngineTargets,
+    check: &mut impl FnMut(ESFeature, Span),
+) {
+    let flags = match node.kind() {
+        AstKind::RegExpLiteral(lit) => Some((lit.regex.flags.to_string(), lit.span)),
+        AstKind::NewExpression(expr) if expr.callee.is_specific_id("RegExp") => {
+            regex_constructor_flags(expr.arguments.get(1))
+        }
+        AstKind::CallExpression(expr) if expr.callee.is_specific_id("RegExp") => {
+            regex_constructor_flags(expr.arguments.get(1))
+        }
+        _ => None,
+    };
+    if let Some((flags, span)) = flags {
+        for (flag, feature) in [
+            ('y', ESFeature::ES2015StickyRegex),
+            ('u', ESFeature::ES2015UnicodeRegex),
+            ('s', ESFeature::ES2018DotallRegex),
+            ('d', ESFeature::ES2022MatchIndicesRegex),
+            ('v', ESFeature::ES2024UnicodeSetsRegex),
+        ] {
+            if flags.contains(flag) {
+                check(feature, span);
+            }
+        }
+    }
+
+    run_on_regex_node(node, ctx, |pattern, span| {
+        let supported = RegexUnsupportedPatterns {
+            named_capture_groups: false,
+            duplicate_named_capture_groups: false,
+            unicode_property_escapes: false,
+            look_behind_assertions: false,
+            pattern_modifiers: false,
+        };
+        for (feature, unsupported) in [
+            (
+                ESFeature::ES2018NamedCapturingGroupsRegex,
+                RegexUnsupportedPatterns { named_capture_groups: true, ..supported },
+            ),
+            (
+                ESFeature::ES2025DuplicateNamedCapturingGroupsRegex,
+                RegexUnsupportedPatterns { duplicate_named_capture_groups: true, ..supported },
+            ),
+            (
+                ESFeature::ES2018UnicodePropertyRegex,
+                RegexUnsupportedPatterns { unicode_property_escapes: true, ..supported },
+            ),
+            (
+                ESFeature::ES2018LookbehindRegex,
+                RegexUnsupportedPatterns { look_behind_assertions: true, ..supported },
+            ),
+            (
+                ESFeature::ES2025RegexpModifiers,
+                RegexUnsupportedPatterns { pattern_modifiers: true, ..supported },
+            ),
+        ] {
+            if targets.find_unsupported(&features()[&feature]).is_some()
+                && has_unsupported_regular_expression_pattern(pattern, &unsupported)
+            {
+                check(feature, span);
+            }
+        }
+    });
+}
+
+/// The flags passed to `new RegExp(pattern, "v")` as a string literal.
+fn regex_constructor_flags(arg: Option<&Argument>) -> Option<(String, Span)> {
+    match arg?.as_expression()?.get_inner_expression() {
+        Expression::StringLiteral(lit) => Some((lit.value.to_string(), lit.span)),
+        _ => None,
+    }
+}
+
+fn is_top_level(node: &AstNode, ctx: &LintContext) -> bool {
+    !ctx.nodes()
+        .ancestor_kinds(node.id())
+        .any(|kind| matches!(kind, AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)))
+}
+
+fn feature_name(feature: ESFeature) -> &'static str {
+    match feature {
+        ESFeature::ES2015ArrowFunctions => "arrow functions",
+        ESFeature::ES2015Classes => "classes",
+        ESFeature::ES2015TemplateLiterals => "template literals",
+        ESFeature::ES2015BlockScoping => "`let` and `const` declarations",
+        ESFeature::ES2015ForOf => "`for...of` loops",
+        ESFeature::ES2015Regenerator => "generator functions",
+        ESFeature::ES2015StickyRegex => "RegExp `y` flag",
+        ESFeature::ES2015UnicodeRegex => "RegExp `u` flag",
+        ESFeature::ES2016ExponentiationOperator => "exponentiation operator",
+        ESFeature::ES2017AsyncToGenerator => "async functions",
+        ESFeature::ES2018AsyncGeneratorFunctions => "async generator functions",
+        ESFeature::ES2018ObjectRestSpread => "object rest and spread properties",
+        ESFeature::ES2018DotallRegex => "RegExp `s` flag",
+        ESFeature::ES2018NamedCapturingGroupsRegex => "RegExp named capture groups",
+        ESFeature::ES2018UnicodePropertyRegex => "RegExp Unicode property escapes",
+        ESFeature::ES2018LookbehindRegex => "RegExp lookbehind assertions",
+        ESFeature::ES2019OptionalCatchBinding => "optional catch binding",
+        ESFeature::ES2020OptionalChaining => "optional chaining",
+        ESFeature::ES2020NullishCoalescingOperator => "nullish coalescing operator",
+        ESFeature::ES2020BigInt => "BigInt literals",
+        ESFeature::ES2021LogicalAssignmentOperators => "logical assignment operators",
+        ESFeature::ES2021NumericSeparator => "numeric separators",
+        ESFeature::ES2022ClassProperties => "class fields",
+        ESFeature::ES2022PrivateMethods => "private methods",
+        ESFeature::ES2022ClassStaticBlock => "class static blocks",
+        ESFeature::ES2022PrivatePropertyInObject => "private field `in` checks",
+        ESFeature::ES2022TopLevelAwait => "top-level `await`",
+        ESFeature::ES2022MatchIndicesRegex => "RegExp `d` flag",
+        ESFeature::ES2024UnicodeSetsRegex => "RegExp `v` flag",
+        ESFeature::ES2025DuplicateNamedCapturingGroupsRegex => {
+            "duplicate RegExp named capture groups"
+        }
+        ESFeature::ES2025RegexpModifiers => "RegExp modifiers",
+        ESFeature::ES2026ExplicitResourceManagement => "`using` declarations",
+        _ => unreachable!("{feature:?} is not checked"),
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let targets = |targets: serde_json::Value| {
+        Some(serde_json::json!({ "settings": { "compat": { "targets": targets } } }))
+    };
+
+    let pass = vec![
+        ("const name = user?.name ?? 'anonymous';", None, targets(serde_json::json!("chrome 91"))),
+        ("const name = user?.name;", None, targets(serde_json::json!(["es2020"]))),
+        ("class A { #x = 1; static {} }", None, targets(serde_json::json!("chrome 98"))),
+        ("const pattern = /[\\p{L}--[a-z]]/v;", None, targets(serde_json::json!("chrome 112"))),
+        ("const pattern = /(?<year>\\d+)/;", None, targets(serde_json::json!("safari 11.1"))),
+        ("a ||= b", None, targets(serde_json::json!("es2021"))),
+        ("async function f() { await x }", None, targets(serde_json::json!("es2017"))),
+        ("const { a, ...rest } = b", None, targets(serde_json::json!("firefox 60"))),
+        ("try {} catch {}", None, targets(serde_json::json!("node 10"))),
+        ("new RegExp('a', 'gi')", None, targets(serde_json::json!("chrome 50"))),
+    ];
+
+    let fail = vec![
+        ("const name = user?.name ?? 'anonymous';", None, targets(serde_json::json!("chrome 79"))),
+        ("const name = user?.name;", None, targets(serde_json::json!(["es2019"]))),
+        ("class A { #x = 1; static {} }", None, targets(serde_json::json!("chrome 90"))),
+        (
+            "class A { #x() {} has(o) { return #x in o } }",
+            None,
+            targets(serde_json::json!("es2021")),
+        ),
+        ("const pattern = /[\\p{L}--[a-z]]/v;", None, targets(serde_json::json!("chrome 110"))),
+        (
+            "const pattern = new RegExp('[a]', 'v');",
+            None,
+            targets(serde_json::json!("firefox 115")),
+        ),
+        ("const pattern = /(?<year>\\d+)(?<=a)/s;", None, targets(serde_json::json!("safari 11"))),
+        ("const pattern = /(?i:a)b/;", None, targets(serde_json::json!("es2024"))),
+        ("a ||= b; c **= 2", None, targets(serde_json::json!("es2015"))),
+        ("const big = 1_000n;", None, targets(serde_json::json!("es2019"))),
+        ("await load(); for await (const x of y) {}", None, targets(serde_json::json!("es2021"))),
+        ("const copy = { ...object }", None, targets(serde_json::json!("es2017"))),
+        ("try {} catch {}", None, targets(serde_json::json!("es2018"))),
+        ("using resource = getResource()", None, targets(serde_json::json!("chrome 120"))),
+        ("async function* f() {}", None, targets(serde_json::json!("es2017"))),
+        ("const f = () => `${a}`;", None, targets(serde_json::json!("ie 11"))),
+        ("a?.b", None, targets(serde_json::json!("not a valid query"))),
+    ];
+
+    Tester::new(NoUnsupportedSyntax::NAME, NoUnsupportedSyntax::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -1,5 +1,6 @@
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{JSDocType, JSDocTypeKind};
 use oxc_span::Span;
 
 use crate::{
@@ -94,8 +95,11 @@ impl Rule for RequireProperty {
                         continue;
                     };
 
-                    let r#type = type_part.parsed();
-                    if r#type == "Object" || r#type == "object" || r#type == "PlainObject" {
+                    if let Ok(JSDocType {
+                        kind: JSDocTypeKind::Name("Object" | "object" | "PlainObject"),
+                        ..
+                    }) = type_part.parsed_type()
+                    {
                         should_report = Some(tag.kind.span.merge(type_part.span));
                     }
                 }
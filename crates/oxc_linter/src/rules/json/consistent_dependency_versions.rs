@@ -0,0 +1,223 @@
+use oxc_ast::ast::Expression;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::{
+        WORKSPACE_DEPENDENCY_FIELDS, WorkspaceDependency, find_workspace, is_package_json,
+        is_plain_version_range, json_key, json_properties, json_property, json_string,
+    },
+};
+
+fn consistent_dependency_versions_diagnostic(
+    span: Span,
+    dependency: &str,
+    version: &str,
+    other_version: &str,
+    other_package: &str,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "`{dependency}` is `{version}` here, but `{other_version}` in `{other_package}`"
+    ))
+    .with_help("Use the same version range for a dependency across the workspace.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ConsistentDependencyVersions;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require every package of a workspace to depend on the same version range of a dependency.
+    ///
+    /// The workspace is found from the nearest `pnpm-workspace.yaml`, or `package.json` with
+    /// `workspaces`, above the linted `package.json`. Its packages, and the workspace root itself,
+    /// are compared on their `dependencies`, `devDependencies` and `optionalDependencies`.
+    /// Ranges using a protocol (`workspace:`, `npm:`, `file:`, ...) are ignored,
+    /// and so are `peerDependencies`, which are usually meant to be looser.
+    ///
+    /// The suggested fix switches to the range most packages use.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Mismatched ranges install several copies of the same dependency,
+    /// which bloats the install and bundles, and breaks libraries that rely on being a singleton,
+    /// like React. They also make upgrades easy to get half-done.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```json
+    /// // packages/a/package.json
+    /// { "dependencies": { "react": "^18.3.1" } }
+    /// // packages/b/package.json
+    /// { "dependencies": { "react": "^18.2.0" } }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```json
+    /// // packages/a/package.json
+    /// { "dependencies": { "react": "^18.3.1" } }
+    /// // packages/b/package.json
+    /// { "dependencies": { "react": "^18.3.1" } }
+    /// ```
+    ConsistentDependencyVersions,
+    json,
+    suspicious,
+    conditional_suggestion,
+    version = "next",
+    short_description = "Require the same version range of a dependency across a workspace.",
+);
+
+impl Rule for ConsistentDependencyVersions {
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(document) = ctx.json() else {
+            return;
+        };
+        let Some(root) = document.root_object() else {
+            return;
+        };
+        let Some(workspace) = find_workspace(ctx).filter(|workspace| workspace.has_dependencies())
+        else {
+            return;
+        };
+
+        for field in WORKSPACE_DEPENDENCY_FIELDS {
+            let Some(Expression::ObjectExpression(dependencies)) = json_property(root, field)
+            else {
+                continue;
+            };
+            for property in json_properties(dependencies) {
+                let (Some(dependency), Some(version)) =
+                    (json_key(property), json_string(&property.value))
+                else {
+                    continue;
+                };
+                if !is_plain_version_range(version) {
+                    continue;
+                }
+                let other_versions = workspace.dependency_versions(&dependency, ctx.file_path());
+                let Some(other) = other_versions.iter().find(|other| other.version != version)
+                else {
+                    continue;
+                };
+
+                let diagnostic = consistent_dependency_versions_diagnostic(
+                    document.span(&property.value),
+                    &dependency,
+                    version,
+                    other.version,
+                    &workspace.display(other.package),
+                );
+                match most_used_version(version, &other_versions) {
+                    Some(most_used) if most_used != version => {
+                        let span = document.span(&property.value);
+                        let replacement = format!("\"{most_used}\"");
+                        ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                            fixer.replace(span, replacement)
+                        });
+                    }
+                    _ => ctx.diagnostic(diagnostic),
+                }
+            }
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.current_sub_host().json().is_some() && is_package_json(ctx.file_path())
+    }
+}
+
+/// The version used by the most packages, counting `version` as well.
+/// `None` on a tie.
+fn most_used_version<'v>(version: &'v str, others: &[WorkspaceDependency<'v>]) -> Option<&'v str> {
+    let mut counts: FxHashMap<&str, usize> = FxHashMap::default();
+    *counts.entry(version).or_default() += 1;
+    for other in others {
+        *counts.entry(other.version).or_default() += 1;
+    }
+    let max = counts.values().copied().max()?;
+    let mut most_used = counts.into_iter().filter(|(_, count)| *count == max);
+    let (most_used_version, _) = most_used.next()?;
+    most_used.next().is_none().then_some(most_used_version)
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use crate::tester::Tester;
+
+    // See `fixtures/json`: in each workspace, `packages/a` and `packages/b` use `react@^18.3.1`.
+    let npm = |package: &str| {
+        Some(PathBuf::from(format!("../json/workspace/packages/{package}/package.json")))
+    };
+    let pnpm = |package: &str| {
+        Some(PathBuf::from(format!("../json/pnpm-workspace/packages/{package}/package.json")))
+    };
+
+    let pass = vec![
+        (r#"{ "dependencies": { "react": "^18.3.1", "vue": "^3.5.0" } }"#, None, None, npm("c")),
+        (r#"{ "devDependencies": { "typescript": "^5.6.0" } }"#, None, None, npm("c")),
+        // Protocols are not compared.
+        (
+            r#"{ "dependencies": { "react": "npm:@preact/compat@^18.3.1", "a": "workspace:*" } }"#,
+            None,
+            None,
+            npm("c"),
+        ),
+        // Peer dependencies are not compared.
+        (r#"{ "peerDependencies": { "react": ">=18" } }"#, None, None, npm("c")),
+        // The package being linted is compared to the others, not to its copy on disk.
+        (
+            r#"{ "dependencies": { "react": "^18.3.1", "lodash": "^4.17.0" } }"#,
+            None,
+            None,
+            npm("a"),
+        ),
+        (r#"{ "dependencies": { "react": "^18.3.1" } }"#, None, None, pnpm("c")),
+    ];
+
+    let fail = vec![
+        (r#"{ "dependencies": { "react": "^18.2.0" } }"#, None, None, npm("c")),
+        (
+            r#"{ "devDependencies": { "react": "18.3.1", "typescript": "~5.6.0" } }"#,
+            None,
+            None,
+            npm("c"),
+        ),
+        (r#"{ "optionalDependencies": { "react": "^18.2.0" } }"#, None, None, pnpm("c")),
+        // The workspace root is one of the packages.
+        (r#"{ "dependencies": { "typescript": "^5.5.0" } }"#, None, None, npm("c")),
+    ];
+
+    let fix = vec![
+        (
+            r#"{ "dependencies": { "react": "^18.2.0" } }"#,
+            r#"{ "dependencies": { "react": "^18.3.1" } }"#,
+            None,
+            npm("c"),
+        ),
+        (
+            r#"{ "dependencies": { "react": "^18.2.0" } }"#,
+            r#"{ "dependencies": { "react": "^18.3.1" } }"#,
+            None,
+            pnpm("c"),
+        ),
+    ];
+
+    Tester::new(
+        ConsistentDependencyVersions::NAME,
+        ConsistentDependencyVersions::PLUGIN,
+        pass,
+        fail,
+    )
+    .change_rule_path_extension("json")
+    .expect_fix(fix)
+    .test_and_snapshot();
+}
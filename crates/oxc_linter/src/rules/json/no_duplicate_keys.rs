@@ -0,0 +1,137 @@
+use std::borrow::Cow;
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::{json_key, json_properties, walk_json_objects},
+};
+
+fn no_duplicate_keys_diagnostic(first: Span, second: Span, key: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Duplicate key \"{key}\""))
+        .with_help("Only the last value is kept when the file is parsed.")
+        .with_labels([first.label("Key is first used here"), second.label("and used again here")])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDuplicateKeys;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow duplicate keys in JSON objects.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// JSON parsers silently keep only the last value of a duplicated key,
+    /// so the earlier value is dead and usually a merge or copy-paste mistake.
+    /// Some parsers reject duplicate keys altogether.
+    ///
+    /// The fix removes the earlier entries, which keeps the parsed value unchanged.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```json
+    /// { "name": "foo", "version": "1.0.0", "name": "bar" }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```json
+    /// { "name": "bar", "version": "1.0.0" }
+    /// ```
+    NoDuplicateKeys,
+    json,
+    correctness,
+    fix,
+    version = "next",
+    short_description = "Disallow duplicate keys in JSON objects.",
+);
+
+impl Rule for NoDuplicateKeys {
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(document) = ctx.json() else {
+            return;
+        };
+        let Some(root) = document.root() else {
+            return;
+        };
+        walk_json_objects(root, &mut |object| {
+            let properties = json_properties(object).collect::<Vec<_>>();
+            let mut seen: FxHashMap<Cow<str>, usize> = FxHashMap::default();
+            for (index, property) in properties.iter().enumerate() {
+                let Some(key) = json_key(property) else {
+                    continue;
+                };
+                let Some(previous) = seen.insert(key.clone(), index) else {
+                    continue;
+                };
+                let first = properties[previous];
+                // The entry after the first one, which exists since `property` comes later.
+                let next = properties[previous + 1];
+                ctx.diagnostic_with_fix(
+                    no_duplicate_keys_diagnostic(
+                        document.span(&first.key),
+                        document.span(&property.key),
+                        &key,
+                    ),
+                    |fixer| {
+                        fixer.delete_range(Span::new(
+                            document.span(first).start,
+                            document.span(next).start,
+                        ))
+                    },
+                );
+            }
+        });
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.current_sub_host().json().is_some()
+    }
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r#"{ "a": 1, "b": 2 }"#, None, None, None),
+        (r#"{ "a": { "b": 1 }, "b": { "a": 1 } }"#, None, None, None),
+        (r#"[{ "a": 1 }, { "a": 2 }]"#, None, None, None),
+        (r#"{ "a": 1, "A": 2 }"#, None, None, None),
+        ("", None, None, None),
+        ("// only a comment\n", None, None, Some(PathBuf::from("test.jsonc"))),
+        ("{ a: 1, 'b': 2 }", None, None, Some(PathBuf::from("test.json5"))),
+    ];
+
+    let fail = vec![
+        (r#"{ "a": 1, "b": 2, "a": 3 }"#, None, None, None),
+        (r#"{ "a": 1, "a": 2, "a": 3 }"#, None, None, None),
+        (r#"{ "a": { "b": 1, "b": 2 } }"#, None, None, None),
+        (r#"[{ "a": 1 }, { "a": 2, "a": 3 }]"#, None, None, None),
+        ("{\n  \"a\": 1,\n  // comment\n  \"a\": 2\n}", None, None, None),
+        ("{ a: 1, 'a': 2, \"a\": 3 }", None, None, Some(PathBuf::from("test.json5"))),
+    ];
+
+    let fix = vec![
+        (r#"{ "a": 1, "b": 2, "a": 3 }"#, r#"{ "b": 2, "a": 3 }"#, None),
+        (
+            "{\n  \"name\": \"foo\",\n  \"version\": \"1.0.0\",\n  \"name\": \"bar\"\n}",
+            "{\n  \"version\": \"1.0.0\",\n  \"name\": \"bar\"\n}",
+            None,
+        ),
+        (r#"{ "a": 1, "b": { "c": 1, "c": 2 } }"#, r#"{ "a": 1, "b": { "c": 2 } }"#, None),
+    ];
+
+    Tester::new(NoDuplicateKeys::NAME, NoDuplicateKeys::PLUGIN, pass, fail)
+        .change_rule_path_extension("json")
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -0,0 +1,197 @@
+use oxc_ast::ast::Expression;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::{
+        PACKAGE_JSON_DEPENDENCY_FIELDS, is_package_json, json_key, json_properties, json_property,
+    },
+};
+
+fn sort_dependencies_diagnostic(
+    span: Span,
+    field: &str,
+    dependency: &str,
+    previous: &str,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{field}` are not sorted"))
+        .with_help(format!("Move `{dependency}` before `{previous}`."))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct SortDependencies;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require the dependency lists of `package.json` to be sorted by package name:
+    /// `dependencies`, `devDependencies`, `optionalDependencies` and `peerDependencies`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Package managers sort these lists whenever they add a dependency,
+    /// so an unsorted list causes noisy diffs on the next install,
+    /// and makes it harder to spot a dependency at a glance.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```json
+    /// { "dependencies": { "react": "^19.0.0", "clsx": "^2.1.0" } }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```json
+    /// { "dependencies": { "clsx": "^2.1.0", "react": "^19.0.0" } }
+    /// ```
+    SortDependencies,
+    json,
+    style,
+    conditional_fix,
+    version = "next",
+    short_description = "Require the dependency lists of `package.json` to be sorted.",
+);
+
+impl Rule for SortDependencies {
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(document) = ctx.json() else {
+            return;
+        };
+        let Some(root) = document.root_object() else {
+            return;
+        };
+        for field in PACKAGE_JSON_DEPENDENCY_FIELDS {
+            let Some(Expression::ObjectExpression(object)) = json_property(root, field) else {
+                continue;
+            };
+            let properties = json_properties(object).collect::<Vec<_>>();
+            let keys =
+                properties.iter().map(|property| json_key(property)).collect::<Option<Vec<_>>>();
+            let Some(keys) = keys else {
+                continue;
+            };
+            // Like `Object.keys(dependencies).sort()`, which package managers use.
+            let Some(unsorted) = keys.windows(2).position(|pair| pair[0] > pair[1]) else {
+                continue;
+            };
+            let diagnostic = sort_dependencies_diagnostic(
+                document.span(&properties[unsorted + 1].key),
+                field,
+                &keys[unsorted + 1],
+                &keys[unsorted],
+            );
+
+            // The entries move, everything between them stays in place.
+            // Don't fix when that would detach comments from their entries.
+            let separators = properties
+                .windows(2)
+                .map(|pair| document.text(&Span::new(pair[0].span.end, pair[1].span.start)))
+                .collect::<Vec<_>>();
+            if separators.iter().any(|separator| separator.contains('/')) {
+                ctx.diagnostic(diagnostic);
+                continue;
+            }
+
+            let mut sorted = properties.iter().zip(&keys).collect::<Vec<_>>();
+            sorted.sort_by_key(|(_, key)| *key);
+            let mut content = String::new();
+            for (index, (property, _)) in sorted.iter().enumerate() {
+                content.push_str(document.text(&property.span));
+                if let Some(separator) = separators.get(index) {
+                    content.push_str(separator);
+                }
+            }
+            let span = Span::new(
+                document.span(properties[0]).start,
+                document.span(properties[properties.len() - 1]).end,
+            );
+            ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(span, content));
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.current_sub_host().json().is_some() && is_package_json(ctx.file_path())
+    }
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use crate::tester::Tester;
+
+    let package_json = || Some(PathBuf::from("package.json"));
+
+    let pass = vec![
+        (r#"{ "dependencies": { "a": "1", "b": "1" } }"#, None, None, package_json()),
+        (r#"{ "dependencies": {}, "devDependencies": { "a": "1" } }"#, None, None, package_json()),
+        (
+            r#"{ "dependencies": { "@scope/z": "1", "a": "1", "b-c": "1", "b.d": "1" } }"#,
+            None,
+            None,
+            package_json(),
+        ),
+        // Only dependency lists are checked.
+        (
+            r#"{ "version": "1.0.0", "name": "foo", "scripts": { "z": "", "a": "" } }"#,
+            None,
+            None,
+            package_json(),
+        ),
+        // Only `package.json` is checked.
+        (
+            r#"{ "dependencies": { "b": "1", "a": "1" } }"#,
+            None,
+            None,
+            Some(PathBuf::from("other.json")),
+        ),
+    ];
+
+    let fail = vec![
+        (r#"{ "dependencies": { "b": "1", "a": "1" } }"#, None, None, package_json()),
+        (r#"{ "devDependencies": { "a": "1", "c": "1", "b": "1" } }"#, None, None, package_json()),
+        (
+            r#"{ "peerDependencies": { "react": "*", "@types/react": "*" } }"#,
+            None,
+            None,
+            package_json(),
+        ),
+        (r#"{ "optionalDependencies": { "b": "1", "B": "1" } }"#, None, None, package_json()),
+        (
+            "{\n  \"dependencies\": {\n    \"b\": \"1\",\n    // why a\n    \"a\": \"1\"\n  }\n}",
+            None,
+            None,
+            package_json(),
+        ),
+    ];
+
+    let fix = vec![
+        (
+            r#"{ "dependencies": { "b": "1", "a": "1" } }"#,
+            r#"{ "dependencies": { "a": "1", "b": "1" } }"#,
+            None,
+            package_json(),
+        ),
+        (
+            "{\n  \"devDependencies\": {\n    \"react\": \"^19.0.0\",\n    \"clsx\": \"^2.1.0\",\n    \"@types/react\": \"^19.0.0\"\n  }\n}",
+            "{\n  \"devDependencies\": {\n    \"@types/react\": \"^19.0.0\",\n    \"clsx\": \"^2.1.0\",\n    \"react\": \"^19.0.0\"\n  }\n}",
+            None,
+            package_json(),
+        ),
+        (
+            r#"{ "dependencies": { "b": "1", "a": "1" }, "devDependencies": { "d": "1", "c": "1" } }"#,
+            r#"{ "dependencies": { "a": "1", "b": "1" }, "devDependencies": { "c": "1", "d": "1" } }"#,
+            None,
+            package_json(),
+        ),
+    ];
+
+    Tester::new(SortDependencies::NAME, SortDependencies::PLUGIN, pass, fail)
+        .change_rule_path_extension("json")
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
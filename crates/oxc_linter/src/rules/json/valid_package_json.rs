@@ -0,0 +1,395 @@
+use oxc_ast::ast::Expression;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::{
+        JsonDocument, is_package_json, json_array_elements, json_key, json_properties,
+        json_property, json_string,
+    },
+};
+
+fn invalid_type_diagnostic(span: Span, field: &str, expected: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{field}` must be {expected}")).with_label(span)
+}
+
+fn invalid_name_diagnostic(span: Span, reason: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Invalid package name: {reason}"))
+        .with_help("See <https://docs.npmjs.com/cli/configuring-npm/package-json#name>.")
+        .with_label(span)
+}
+
+fn invalid_version_diagnostic(span: Span, version: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Invalid version \"{version}\""))
+        .with_help("The version must be a semantic version like `1.2.3` or `1.0.0-beta.1`.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ValidPackageJson;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Check that the well-known fields of `package.json` have valid values:
+    /// - `name` follows the npm package name rules (lowercase, URL-safe, at most 214 characters)
+    /// - `version` is a semantic version
+    /// - `type` is `"module"` or `"commonjs"`
+    /// - the other fields have the types npm and Node.js expect, e.g.
+    ///   `private` is a boolean and `dependencies` maps names to version strings
+    ///
+    /// ### Why is this bad?
+    ///
+    /// npm refuses to publish a package with an invalid name or version,
+    /// and an invalid field is ignored or misread by Node.js and package managers,
+    /// often without any warning.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```json
+    /// {
+    ///   "name": "My Package",
+    ///   "version": "1.0",
+    ///   "type": "esm",
+    ///   "private": "true"
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```json
+    /// {
+    ///   "name": "my-package",
+    ///   "version": "1.0.0",
+    ///   "type": "module",
+    ///   "private": true
+    /// }
+    /// ```
+    ValidPackageJson,
+    json,
+    correctness,
+    version = "next",
+    short_description = "Check that the fields of `package.json` have valid values.",
+);
+
+/// The shape npm expects for a field.
+#[derive(Clone, Copy)]
+enum Shape {
+    String,
+    Boolean,
+    StringArray,
+    /// An object of strings, like `dependencies` or `scripts`.
+    StringMap,
+    /// A string, or an object of strings, like `bin`.
+    StringOrStringMap,
+    /// An array of strings, or an object with a `packages` array of strings: `workspaces`.
+    Workspaces,
+    /// An array of strings, or `true`, like `bundleDependencies`.
+    StringArrayOrTrue,
+}
+
+impl Shape {
+    fn expected(self) -> &'static str {
+        match self {
+            Self::String => "a string",
+            Self::Boolean => "a boolean",
+            Self::StringArray => "an array of strings",
+            Self::StringMap => "an object of strings",
+            Self::StringOrStringMap => "a string or an object of strings",
+            Self::Workspaces => "an array of strings, or an object with a `packages` array",
+            Self::StringArrayOrTrue => "an array of strings or `true`",
+        }
+    }
+
+    fn matches(self, value: &Expression) -> bool {
+        match self {
+            Self::String => json_string(value).is_some(),
+            Self::Boolean => matches!(value, Expression::BooleanLiteral(_)),
+            Self::StringArray => is_string_array(value),
+            Self::StringMap => is_string_map(value),
+            Self::StringOrStringMap => json_string(value).is_some() || is_string_map(value),
+            Self::Workspaces => {
+                is_string_array(value)
+                    || matches!(value, Expression::ObjectExpression(object)
+                        if json_property(object, "packages").is_none_or(is_string_array))
+            }
+            Self::StringArrayOrTrue => {
+                is_string_array(value)
+                    || matches!(value, Expression::BooleanLiteral(boolean) if boolean.value)
+            }
+        }
+    }
+}
+
+const FIELDS: [(&str, Shape); 28] = [
+    ("bin", Shape::StringOrStringMap),
+    ("browser", Shape::StringOrStringMap),
+    ("bundleDependencies", Shape::StringArrayOrTrue),
+    ("bundledDependencies", Shape::StringArrayOrTrue),
+    ("cpu", Shape::StringArray),
+    ("dependencies", Shape::StringMap),
+    ("description", Shape::String),
+    ("devDependencies", Shape::StringMap),
+    ("engines", Shape::StringMap),
+    ("files", Shape::StringArray),
+    ("homepage", Shape::String),
+    ("keywords", Shape::StringArray),
+    ("license", Shape::String),
+    ("main", Shape::String),
+    ("module", Shape::String),
+    ("name", Shape::String),
+    ("optionalDependencies", Shape::StringMap),
+    ("os", Shape::StringArray),
+    ("packageManager", Shape::String),
+    ("peerDependencies", Shape::StringMap),
+    ("private", Shape::Boolean),
+    ("scripts", Shape::StringMap),
+    ("sideEffects", Shape::Boolean),
+    ("type", Shape::String),
+    ("types", Shape::String),
+    ("typings", Shape::String),
+    ("version", Shape::String),
+    ("workspaces", Shape::Workspaces),
+];
+
+impl Rule for ValidPackageJson {
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(document) = ctx.json() else {
+            return;
+        };
+        let Some(root) = document.root_object() else {
+            if let Some(root) = document.root() {
+                ctx.diagnostic(invalid_type_diagnostic(
+                    document.span(root),
+                    "package.json",
+                    "an object",
+                ));
+            }
+            return;
+        };
+
+        for property in json_properties(root) {
+            let Some(key) = json_key(property) else {
+                continue;
+            };
+            let Some(&(field, shape)) = FIELDS.iter().find(|(field, _)| *field == key) else {
+                continue;
+            };
+            let value = &property.value;
+            // `sideEffects` may also list the files that have side effects.
+            if field == "sideEffects" && is_string_array(value) {
+                continue;
+            }
+            if !shape.matches(value) {
+                ctx.diagnostic(invalid_type_diagnostic(
+                    document.span(value),
+                    field,
+                    shape.expected(),
+                ));
+                continue;
+            }
+            match field {
+                "name" => check_name(ctx, document, value),
+                "version" => check_version(ctx, document, value),
+                "type" if !matches!(json_string(value), Some("module" | "commonjs")) => {
+                    ctx.diagnostic(invalid_type_diagnostic(
+                        document.span(value),
+                        field,
+                        "\"module\" or \"commonjs\"",
+                    ));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.current_sub_host().json().is_some() && is_package_json(ctx.file_path())
+    }
+}
+
+fn is_string_array(value: &Expression) -> bool {
+    json_array_elements(value)
+        .is_some_and(|mut elements| elements.all(|element| json_string(element).is_some()))
+}
+
+fn is_string_map(value: &Expression) -> bool {
+    matches!(value, Expression::ObjectExpression(object)
+        if json_properties(object).all(|property| json_string(&property.value).is_some()))
+}
+
+fn check_name(ctx: &LintContext, document: &JsonDocument, value: &Expression) {
+    let Some(name) = json_string(value) else {
+        return;
+    };
+    if let Some(reason) = invalid_name_reason(name) {
+        ctx.diagnostic(invalid_name_diagnostic(document.span(value), reason));
+    }
+}
+
+/// The rules of `validate-npm-package-name` for new packages.
+fn invalid_name_reason(name: &str) -> Option<&'static str> {
+    if name.is_empty() {
+        return Some("it must not be empty");
+    }
+    if name.len() > 214 {
+        return Some("it must not be longer than 214 characters");
+    }
+    if name.trim() != name {
+        return Some("it must not have leading or trailing spaces");
+    }
+    if name.starts_with('.') || name.starts_with('_') {
+        return Some("it must not start with a period or an underscore");
+    }
+    if matches!(name, "node_modules" | "favicon.ico") {
+        return Some("it is a reserved name");
+    }
+    if name.bytes().any(|b| b.is_ascii_uppercase()) {
+        return Some("it must not contain capital letters");
+    }
+    let parts = match name.strip_prefix('@') {
+        Some(scoped) => match scoped.split_once('/') {
+            Some((scope, package)) if !scope.is_empty() && !package.is_empty() => {
+                vec![scope, package]
+            }
+            _ => return Some("a scoped name must look like `@scope/name`"),
+        },
+        None => vec![name],
+    };
+    let is_url_safe = |part: &str| {
+        part.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b"-._".contains(&b))
+    };
+    if !parts.into_iter().all(is_url_safe) {
+        return Some(
+            "it may only contain URL-safe characters: lowercase letters, digits, `-`, `.` and `_`",
+        );
+    }
+    None
+}
+
+fn check_version(ctx: &LintContext, document: &JsonDocument, value: &Expression) {
+    let Some(version) = json_string(value) else {
+        return;
+    };
+    if !is_valid_semver(version) {
+        ctx.diagnostic(invalid_version_diagnostic(document.span(value), version));
+    }
+}
+
+/// A [semantic version](https://semver.org), optionally prefixed with `v` as npm allows.
+fn is_valid_semver(version: &str) -> bool {
+    let version = version.strip_prefix('v').unwrap_or(version);
+    let (version, build) = match version.split_once('+') {
+        Some((version, build)) => (version, Some(build)),
+        None => (version, None),
+    };
+    let (core, prerelease) = match version.split_once('-') {
+        Some((core, prerelease)) => (core, Some(prerelease)),
+        None => (version, None),
+    };
+
+    let is_number = |part: &str| {
+        !part.is_empty()
+            && part.bytes().all(|b| b.is_ascii_digit())
+            && (part == "0" || !part.starts_with('0'))
+    };
+    let is_identifier = |part: &str| {
+        !part.is_empty() && part.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+    };
+
+    let core = core.split('.').collect::<Vec<_>>();
+    core.len() == 3
+        && core.into_iter().all(is_number)
+        && prerelease.is_none_or(|prerelease| {
+            prerelease.split('.').all(|part| {
+                is_identifier(part)
+                    && (is_number(part) || !part.bytes().all(|b| b.is_ascii_digit()))
+            })
+        })
+        && build.is_none_or(|build| build.split('.').all(is_identifier))
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use crate::tester::Tester;
+
+    let package_json = || Some(PathBuf::from("package.json"));
+
+    let pass = vec![
+        (r#"{ "name": "foo", "version": "1.0.0" }"#, None, None, package_json()),
+        (
+            r#"{ "name": "@scope/foo.bar_baz-2", "version": "0.1.0-beta.1+build.5" }"#,
+            None,
+            None,
+            package_json(),
+        ),
+        (r#"{ "version": "v1.2.3" }"#, None, None, package_json()),
+        (
+            r#"{ "private": true, "type": "module", "sideEffects": ["*.css"] }"#,
+            None,
+            None,
+            package_json(),
+        ),
+        (r#"{ "type": "commonjs", "sideEffects": false }"#, None, None, package_json()),
+        (r#"{ "bin": "cli.js", "browser": { "./a.js": "./b.js" } }"#, None, None, package_json()),
+        (
+            r#"{ "bin": { "foo": "cli.js" }, "files": ["dist"], "keywords": [] }"#,
+            None,
+            None,
+            package_json(),
+        ),
+        (
+            r#"{ "dependencies": { "a": "^1.0.0" }, "scripts": { "build": "tsc" } }"#,
+            None,
+            None,
+            package_json(),
+        ),
+        (r#"{ "workspaces": ["packages/*"] }"#, None, None, package_json()),
+        (
+            r#"{ "workspaces": { "packages": ["packages/*"], "nohoist": [] } }"#,
+            None,
+            None,
+            package_json(),
+        ),
+        (r#"{ "bundleDependencies": true }"#, None, None, package_json()),
+        // Unknown fields are left alone.
+        (
+            r#"{ "prettier": { "semi": false }, "exports": { ".": "./index.js" } }"#,
+            None,
+            None,
+            package_json(),
+        ),
+        // Only `package.json` is checked.
+        (r#"{ "name": "Foo", "version": "1" }"#, None, None, Some(PathBuf::from("other.json"))),
+    ];
+
+    let fail = vec![
+        (r#"{ "name": "My Package" }"#, None, None, package_json()),
+        (r#"{ "name": "Foo" }"#, None, None, package_json()),
+        (r#"{ "name": ".foo" }"#, None, None, package_json()),
+        (r#"{ "name": "" }"#, None, None, package_json()),
+        (r#"{ "name": "@scope" }"#, None, None, package_json()),
+        (r#"{ "name": "foo!" }"#, None, None, package_json()),
+        (r#"{ "name": 1 }"#, None, None, package_json()),
+        (r#"{ "version": "1.0" }"#, None, None, package_json()),
+        (r#"{ "version": "01.0.0" }"#, None, None, package_json()),
+        (r#"{ "version": "1.0.0-01" }"#, None, None, package_json()),
+        (r#"{ "type": "esm" }"#, None, None, package_json()),
+        (r#"{ "private": "true" }"#, None, None, package_json()),
+        (r#"{ "dependencies": { "a": 1 } }"#, None, None, package_json()),
+        (r#"{ "dependencies": ["a"] }"#, None, None, package_json()),
+        (r#"{ "files": "dist" }"#, None, None, package_json()),
+        (r#"{ "bin": ["cli.js"] }"#, None, None, package_json()),
+        (r#"{ "workspaces": "packages/*" }"#, None, None, package_json()),
+        (r#"["foo"]"#, None, None, package_json()),
+    ];
+
+    Tester::new(ValidPackageJson::NAME, ValidPackageJson::PLUGIN, pass, fail)
+        .change_rule_path_extension("json")
+        .test_and_snapshot();
+}
@@ -0,0 +1,430 @@
+use cow_utils::CowUtils;
+use oxc_ast::ast::{Expression, ObjectExpression};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use phf::phf_set;
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::{
+        JsonDocument, is_tsconfig, json_array_elements, json_key, json_properties, json_property,
+        json_string,
+    },
+};
+
+fn invalid_type_diagnostic(span: Span, field: &str, expected: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{field}` must be {expected}")).with_label(span)
+}
+
+fn misplaced_compiler_option_diagnostic(span: Span, option: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "`{option}` is a compiler option, but it is not in `compilerOptions`"
+    ))
+    .with_help(format!("Move `{option}` into `compilerOptions`, it has no effect here."))
+    .with_label(span)
+}
+
+fn missing_extends_diagnostic(span: Span, extends: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("The extended config `{extends}` does not exist")).with_label(span)
+}
+
+fn empty_files_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("The `files` list is empty")
+        .with_help("Remove `files`, or add `include` or `references`: TypeScript rejects a config without any input.")
+        .with_label(span)
+}
+
+fn conflicting_options_diagnostic(
+    span: Span,
+    other_span: Span,
+    option: &str,
+    other_option: &str,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{option}` cannot be used with `{other_option}`"))
+        .with_labels([span.primary(), other_span.into()])
+}
+
+fn module_resolution_mismatch_diagnostic(
+    span: Span,
+    module_resolution_span: Span,
+    module_resolution: &str,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "`module` must be `{module_resolution}` when `moduleResolution` is `{module_resolution}`"
+    ))
+    .with_labels([span.primary(), module_resolution_span.into()])
+}
+
+fn requires_no_emit_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("`allowImportingTsExtensions` requires `noEmit` or `emitDeclarationOnly`")
+        .with_help("The emitted JavaScript would still import `.ts` files.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ValidTsconfig;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Check `tsconfig.json` and `jsconfig.json` (and variants like `tsconfig.build.json`)
+    /// for mistakes that TypeScript ignores or only reports when it runs:
+    /// - a compiler option at the top level instead of in `compilerOptions`
+    /// - a relative `extends` that does not exist
+    /// - `files`, `include`, `exclude` and `references` of the wrong type, or an empty `files` list
+    /// - conflicting compiler options: `noEmit` with `emitDeclarationOnly`,
+    ///   `allowImportingTsExtensions` without `noEmit` or `emitDeclarationOnly`,
+    ///   and `moduleResolution: "NodeNext"` (or `Node16`) without the same `module`
+    ///
+    /// Options that may come from an extended config are only checked when the config extends nothing.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A misplaced option is silently ignored, so the project compiles with different settings
+    /// than it appears to. The other mistakes fail the build, but only once `tsc` runs,
+    /// while editors may keep working with a partially applied config.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```json
+    /// {
+    ///   "strict": true,
+    ///   "extends": "./tsconfig.bsae.json",
+    ///   "compilerOptions": { "noEmit": true, "emitDeclarationOnly": true }
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```json
+    /// {
+    ///   "extends": "./tsconfig.base.json",
+    ///   "compilerOptions": { "strict": true, "noEmit": true }
+    /// }
+    /// ```
+    ValidTsconfig,
+    json,
+    correctness,
+    version = "next",
+    short_description = "Check `tsconfig.json` for misplaced, missing or conflicting options.",
+);
+
+/// Common compiler options, to detect ones misplaced at the top level.
+static COMPILER_OPTIONS: phf::Set<&'static str> = phf_set! {
+    "allowArbitraryExtensions", "allowImportingTsExtensions", "allowJs",
+    "allowSyntheticDefaultImports", "allowUnreachableCode", "allowUnusedLabels", "baseUrl",
+    "checkJs", "composite", "declaration", "declarationDir", "declarationMap", "downlevelIteration",
+    "emitDeclarationOnly", "emitDecoratorMetadata", "esModuleInterop", "exactOptionalPropertyTypes",
+    "experimentalDecorators", "forceConsistentCasingInFileNames", "importHelpers", "incremental",
+    "inlineSourceMap", "inlineSources", "isolatedDeclarations", "isolatedModules", "jsx",
+    "jsxFactory", "jsxFragmentFactory", "jsxImportSource", "lib", "module", "moduleDetection",
+    "moduleResolution", "noEmit", "noEmitOnError", "noFallthroughCasesInSwitch", "noImplicitAny",
+    "noImplicitOverride", "noImplicitReturns", "noImplicitThis", "noPropertyAccessFromIndexSignature",
+    "noUncheckedIndexedAccess", "noUncheckedSideEffectImports", "noUnusedLocals",
+    "noUnusedParameters", "outDir", "outFile", "paths", "removeComments", "resolveJsonModule",
+    "rewriteRelativeImportExtensions", "rootDir", "rootDirs", "skipLibCheck", "sourceMap",
+    "strict", "strictBindCallApply", "strictFunctionTypes", "strictNullChecks",
+    "strictPropertyInitialization", "target", "tsBuildInfoFile", "typeRoots", "types",
+    "useDefineForClassFields", "useUnknownInCatchVariables", "verbatimModuleSyntax",
+};
+
+impl Rule for ValidTsconfig {
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(document) = ctx.json() else {
+            return;
+        };
+        let Some(root) = document.root_object() else {
+            if let Some(root) = document.root() {
+                ctx.diagnostic(invalid_type_diagnostic(
+                    document.span(root),
+                    "tsconfig",
+                    "an object",
+                ));
+            }
+            return;
+        };
+
+        for property in json_properties(root) {
+            if let Some(key) = json_key(property)
+                && COMPILER_OPTIONS.contains(key.as_ref())
+            {
+                ctx.diagnostic(misplaced_compiler_option_diagnostic(
+                    document.span(&property.key),
+                    &key,
+                ));
+            }
+        }
+
+        let extends = json_property(root, "extends");
+        if let Some(extends) = extends {
+            check_extends(ctx, document, extends);
+        }
+
+        for field in ["files", "include", "exclude"] {
+            if let Some(value) = json_property(root, field)
+                && !is_string_array(value)
+            {
+                ctx.diagnostic(invalid_type_diagnostic(
+                    document.span(value),
+                    field,
+                    "an array of strings",
+                ));
+            }
+        }
+        if let Some(references) = json_property(root, "references")
+            && !json_array_elements(references).is_some_and(|mut references| {
+                references.all(|reference| {
+                    matches!(reference, Expression::ObjectExpression(reference)
+                    if json_property(reference, "path").and_then(json_string).is_some())
+                })
+            })
+        {
+            ctx.diagnostic(invalid_type_diagnostic(
+                document.span(references),
+                "references",
+                "an array of `{ \"path\": string }` objects",
+            ));
+        }
+        if let Some(files) = json_property(root, "files")
+            && json_array_elements(files).is_some_and(|mut files| files.next().is_none())
+            && json_property(root, "include").is_none()
+            && json_property(root, "references").is_none()
+            && extends.is_none()
+        {
+            ctx.diagnostic(empty_files_diagnostic(document.span(files)));
+        }
+
+        for field in ["compilerOptions", "watchOptions", "typeAcquisition"] {
+            if let Some(value) = json_property(root, field)
+                && !matches!(value, Expression::ObjectExpression(_))
+            {
+                ctx.diagnostic(invalid_type_diagnostic(document.span(value), field, "an object"));
+            }
+        }
+        if let Some(Expression::ObjectExpression(compiler_options)) =
+            json_property(root, "compilerOptions")
+        {
+            check_compiler_options(ctx, document, compiler_options, extends.is_some());
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.current_sub_host().json().is_some() && is_tsconfig(ctx.file_path())
+    }
+}
+
+fn is_string_array(value: &Expression) -> bool {
+    json_array_elements(value)
+        .is_some_and(|mut elements| elements.all(|element| json_string(element).is_some()))
+}
+
+fn check_extends(ctx: &LintContext, document: &JsonDocument, extends: &Expression) {
+    let configs = match extends {
+        Expression::StringLiteral(_) => vec![extends],
+        _ if is_string_array(extends) => {
+            json_array_elements(extends).into_iter().flatten().collect()
+        }
+        _ => {
+            ctx.diagnostic(invalid_type_diagnostic(
+                document.span(extends),
+                "extends",
+                "a string or an array of strings",
+            ));
+            return;
+        }
+    };
+    let Some(dir) = ctx.file_path().parent() else {
+        return;
+    };
+    for config in configs {
+        let Some(path) = json_string(config) else {
+            continue;
+        };
+        // Package configs (`@tsconfig/node22/tsconfig.json`) are resolved from `node_modules`,
+        // which is not checked.
+        if !(path.starts_with("./") || path.starts_with("../")) {
+            continue;
+        }
+        let resolved = dir.join(path);
+        let exists = resolved.is_file()
+            || (resolved.extension().is_none_or(|ext| ext != "json")
+                && resolved.with_added_extension("json").is_file());
+        if !exists {
+            ctx.diagnostic(missing_extends_diagnostic(document.span(config), path));
+        }
+    }
+}
+
+fn check_compiler_options(
+    ctx: &LintContext,
+    document: &JsonDocument,
+    compiler_options: &ObjectExpression,
+    has_extends: bool,
+) {
+    let option = |name: &str| json_property(compiler_options, name);
+    let is_enabled =
+        |name: &str| matches!(option(name), Some(Expression::BooleanLiteral(b)) if b.value);
+    let span_of = |name: &str| {
+        json_properties(compiler_options)
+            .filter(|property| json_key(property).is_some_and(|key| key == name))
+            .last()
+            .map(|property| document.span(&property.key))
+    };
+
+    if is_enabled("noEmit")
+        && is_enabled("emitDeclarationOnly")
+        && let (Some(span), Some(other_span)) = (span_of("emitDeclarationOnly"), span_of("noEmit"))
+    {
+        ctx.diagnostic(conflicting_options_diagnostic(
+            span,
+            other_span,
+            "emitDeclarationOnly",
+            "noEmit",
+        ));
+    }
+
+    if is_enabled("allowImportingTsExtensions")
+        && !has_extends
+        && !is_enabled("noEmit")
+        && !is_enabled("emitDeclarationOnly")
+        && let Some(span) = span_of("allowImportingTsExtensions")
+    {
+        ctx.diagnostic(requires_no_emit_diagnostic(span));
+    }
+
+    if let Some(module_resolution) = option("moduleResolution").and_then(json_string)
+        && let Some(module) = option("module")
+        && let Some(module_name) = json_string(module)
+    {
+        let module_resolution_lower = module_resolution.cow_to_ascii_lowercase();
+        if matches!(module_resolution_lower.as_ref(), "node16" | "nodenext")
+            && module_name.cow_to_ascii_lowercase() != module_resolution_lower
+            && let Some(module_resolution_span) = span_of("moduleResolution")
+        {
+            ctx.diagnostic(module_resolution_mismatch_diagnostic(
+                document.span(module),
+                module_resolution_span,
+                module_resolution,
+            ));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use crate::tester::Tester;
+
+    let tsconfig = || Some(PathBuf::from("tsconfig.json"));
+    // See `fixtures/json/tsconfig`, which has a `tsconfig.base.json`.
+    let with_base = || Some(PathBuf::from("../json/tsconfig/tsconfig.json"));
+
+    let pass = vec![
+        (
+            r#"{ "compilerOptions": { "strict": true, "target": "es2022" } }"#,
+            None,
+            None,
+            tsconfig(),
+        ),
+        (r#"{ "extends": "./tsconfig.base.json", "include": ["src"] }"#, None, None, with_base()),
+        (r#"{ "extends": "./tsconfig.base" }"#, None, None, with_base()),
+        (
+            r#"{ "extends": ["@tsconfig/node22/tsconfig.json", "./tsconfig.base.json"] }"#,
+            None,
+            None,
+            with_base(),
+        ),
+        (r#"{ "files": [], "references": [{ "path": "./packages/a" }] }"#, None, None, tsconfig()),
+        (
+            r#"{ "compilerOptions": { "noEmit": true, "allowImportingTsExtensions": true } }"#,
+            None,
+            None,
+            tsconfig(),
+        ),
+        (
+            r#"{ "compilerOptions": { "emitDeclarationOnly": true, "allowImportingTsExtensions": true } }"#,
+            None,
+            None,
+            tsconfig(),
+        ),
+        // May be set in the extended config.
+        (
+            r#"{ "extends": "./tsconfig.base.json", "compilerOptions": { "allowImportingTsExtensions": true } }"#,
+            None,
+            None,
+            with_base(),
+        ),
+        (
+            r#"{ "compilerOptions": { "module": "NodeNext", "moduleResolution": "nodenext" } }"#,
+            None,
+            None,
+            tsconfig(),
+        ),
+        (
+            r#"{ "compilerOptions": { "module": "ESNext", "moduleResolution": "Bundler" } }"#,
+            None,
+            None,
+            tsconfig(),
+        ),
+        // Tools often keep their options in `tsconfig.json`.
+        (r#"{ "ts-node": { "esm": true }, "vueCompilerOptions": {} }"#, None, None, tsconfig()),
+        ("{\n  // comments are fine\n  \"compilerOptions\": {},\n}", None, None, tsconfig()),
+        (
+            r#"{ "compilerOptions": { "strict": true } }"#,
+            None,
+            None,
+            Some(PathBuf::from("tsconfig.build.json")),
+        ),
+        // Only tsconfig files are checked.
+        (r#"{ "strict": true }"#, None, None, Some(PathBuf::from("tsconfig-like.json"))),
+    ];
+
+    let fail = vec![
+        (
+            r#"{ "strict": true, "compilerOptions": { "target": "es2022" } }"#,
+            None,
+            None,
+            tsconfig(),
+        ),
+        (r#"{ "outDir": "dist" }"#, None, None, Some(PathBuf::from("jsconfig.json"))),
+        (r#"{ "extends": "./tsconfig.bsae.json" }"#, None, None, with_base()),
+        (r#"{ "extends": ["./tsconfig.base.json", "../missing"] }"#, None, None, with_base()),
+        (r#"{ "extends": 1 }"#, None, None, tsconfig()),
+        (r#"{ "include": "src" }"#, None, None, tsconfig()),
+        (r#"{ "files": [] }"#, None, None, tsconfig()),
+        (r#"{ "references": ["./packages/a"] }"#, None, None, tsconfig()),
+        (r#"{ "compilerOptions": [] }"#, None, None, tsconfig()),
+        (
+            r#"{ "compilerOptions": { "noEmit": true, "emitDeclarationOnly": true } }"#,
+            None,
+            None,
+            tsconfig(),
+        ),
+        (
+            r#"{ "compilerOptions": { "allowImportingTsExtensions": true } }"#,
+            None,
+            None,
+            tsconfig(),
+        ),
+        (
+            r#"{ "compilerOptions": { "module": "ESNext", "moduleResolution": "NodeNext" } }"#,
+            None,
+            None,
+            tsconfig(),
+        ),
+        (
+            r#"{ "compilerOptions": { "module": "nodenext", "moduleResolution": "node16" } }"#,
+            None,
+            None,
+            Some(PathBuf::from("tsconfig.app.json")),
+        ),
+        ("[]", None, None, tsconfig()),
+    ];
+
+    Tester::new(ValidTsconfig::NAME, ValidTsconfig::PLUGIN, pass, fail)
+        .change_rule_path_extension("json")
+        .test_and_snapshot();
+}
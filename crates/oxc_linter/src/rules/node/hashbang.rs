@@ -0,0 +1,231 @@
+use rustc_hash::FxHashMap;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    utils::find_node_package,
+};
+
+fn missing_hashbang_diagnostic(span: Span, expected: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("This file needs the hashbang `{expected}`."))
+        .with_help("The file is an executable in the `bin` field of `package.json`.")
+        .with_label(span)
+}
+
+fn wrong_hashbang_diagnostic(span: Span, expected: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("This file needs the hashbang `{expected}`."))
+        .with_help("`/usr/bin/env` finds the executable on the `PATH` of the user.")
+        .with_label(span)
+}
+
+fn crlf_hashbang_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("The hashbang must end with a Unix line break (LF).")
+        .with_help("The carriage return would be read as part of the name of the executable.")
+        .with_label(span)
+}
+
+fn unexpected_hashbang_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("This file needs no hashbang.")
+        .with_help("The file is not an executable in the `bin` field of `package.json`.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct HashbangConfig {
+    /// Glob patterns, relative to `package.json`, of files which are executables although
+    /// they are not in the `bin` field.
+    additional_executables: Vec<String>,
+    /// The executable to run files with, by file extension, such as
+    /// `{ ".ts": "tsx" }`. Files with other extensions are run with `node`.
+    executable_map: FxHashMap<String, String>,
+    /// Whether to ignore files which are not published, such as scripts which are not
+    /// in the `bin` field.
+    ignore_unpublished: bool,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct Hashbang(Box<HashbangConfig>);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require a correct hashbang, `#!/usr/bin/env node`, in the executables in the `bin`
+    /// field of `package.json`, and disallow a hashbang in other files.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// npm links the `bin` files onto the `PATH`, and the system runs them with the
+    /// executable of their hashbang. Without one, the file is run as a shell script. A
+    /// hashbang in a module which is not executed is misleading.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule, in a file in `bin`:
+    /// ```js
+    /// #!/usr/local/bin/node
+    /// console.log("Hello");
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule, in a file in `bin`:
+    /// ```js
+    /// #!/usr/bin/env node
+    /// console.log("Hello");
+    /// ```
+    Hashbang,
+    node,
+    style,
+    fix,
+    config = HashbangConfig,
+    version = "next",
+    short_description = "Require a correct hashbang in executables, and disallow it in other files.",
+);
+
+impl Rule for Hashbang {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        DefaultRuleConfig::<Self>::from_value(value).map(DefaultRuleConfig::into_inner)
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(package) = find_node_package(ctx) else {
+            return;
+        };
+        let Some(relative_path) = package.relative_path(ctx.file_path()) else {
+            return;
+        };
+        let is_executable = package.bin.contains(&relative_path)
+            || self
+                .0
+                .additional_executables
+                .iter()
+                .any(|pattern| fast_glob::glob_match(pattern, &relative_path));
+        let hashbang = ctx.nodes().program().hashbang.as_ref();
+
+        if !is_executable {
+            if let Some(hashbang) = hashbang
+                && (!self.0.ignore_unpublished || package.is_published(ctx.file_path()))
+            {
+                ctx.diagnostic_with_fix(unexpected_hashbang_diagnostic(hashbang.span), |fixer| {
+                    let line_break = line_break_after(hashbang.span, ctx).unwrap_or_default();
+                    fixer.delete_range(Span::new(
+                        hashbang.span.start,
+                        hashbang.span.end + line_break,
+                    ))
+                });
+            }
+            return;
+        }
+
+        let executable = ctx
+            .file_extension()
+            .and_then(|extension| {
+                self.0.executable_map.get(&format!(".{}", extension.to_string_lossy()))
+            })
+            .map_or("node", String::as_str);
+        let expected = format!("#!/usr/bin/env {executable}");
+        let Some(hashbang) = hashbang else {
+            ctx.diagnostic_with_fix(
+                missing_hashbang_diagnostic(Span::empty(0), &expected),
+                |fixer| fixer.insert_text_before_range(Span::empty(0), format!("{expected}\n")),
+            );
+            return;
+        };
+        if hashbang.value.trim_end() != expected.trim_start_matches("#!") {
+            ctx.diagnostic_with_fix(wrong_hashbang_diagnostic(hashbang.span, &expected), |fixer| {
+                fixer.replace(hashbang.span, expected.clone())
+            });
+        }
+        if line_break_after(hashbang.span, ctx) == Some(2) {
+            let span = Span::sized(hashbang.span.end, 2);
+            ctx.diagnostic_with_fix(crlf_hashbang_diagnostic(span), |fixer| {
+                fixer.replace(span, "\n")
+            });
+        }
+    }
+}
+
+/// The length of the line break after `span`: 2 for CRLF, 1 for LF, or `None`.
+fn line_break_after(span: Span, ctx: &LintContext) -> Option<u32> {
+    let rest = &ctx.source_text()[span.end as usize..];
+    if rest.starts_with("\r\n") {
+        Some(2)
+    } else if rest.starts_with('\n') {
+        Some(1)
+    } else {
+        None
+    }
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let bin = || Some(PathBuf::from("../node/bin/cli.js"));
+    let lib = || Some(PathBuf::from("../node/lib/index.js"));
+    let tool = || Some(PathBuf::from("../node/bin/tool.ts"));
+    let tool_config = || {
+        Some(json!([{ "additionalExecutables": ["bin/*.ts"], "executableMap": { ".ts": "tsx" } }]))
+    };
+
+    let pass = vec![
+        ("#!/usr/bin/env node\nconsole.log(1);", None, None, bin()),
+        ("#!/usr/bin/env node \nconsole.log(1);", None, None, bin()),
+        ("console.log(1);", None, None, lib()),
+        ("#!/usr/bin/env tsx\nconsole.log(1);", tool_config(), None, tool()),
+        (
+            "#!/usr/bin/env node\nconsole.log(1);",
+            Some(json!([{ "ignoreUnpublished": true }])),
+            None,
+            Some(PathBuf::from("../node/scripts/build.js")),
+        ),
+    ];
+
+    let fail = vec![
+        ("console.log(1);", None, None, bin()),
+        ("#!/usr/local/bin/node\nconsole.log(1);", None, None, bin()),
+        ("#!/usr/bin/env node\r\nconsole.log(1);", None, None, bin()),
+        ("#!/usr/bin/env node\nconsole.log(1);", None, None, lib()),
+        (
+            "#!/usr/bin/env node\nconsole.log(1);",
+            None,
+            None,
+            Some(PathBuf::from("../node/scripts/build.js")),
+        ),
+        ("#!/usr/bin/env node\nconsole.log(1);", tool_config(), None, tool()),
+    ];
+
+    let fix = vec![
+        ("console.log(1);", "#!/usr/bin/env node\nconsole.log(1);", None, bin()),
+        (
+            "#!/usr/local/bin/node\nconsole.log(1);",
+            "#!/usr/bin/env node\nconsole.log(1);",
+            None,
+            bin(),
+        ),
+        (
+            "#!/usr/bin/env node\r\nconsole.log(1);",
+            "#!/usr/bin/env node\nconsole.log(1);",
+            None,
+            bin(),
+        ),
+        ("#!/usr/bin/env node\nconsole.log(1);", "console.log(1);", None, lib()),
+        (
+            "#!/usr/bin/env node\nconsole.log(1);",
+            "#!/usr/bin/env tsx\nconsole.log(1);",
+            tool_config(),
+            tool(),
+        ),
+    ];
+
+    Tester::new(Hashbang::NAME, Hashbang::PLUGIN, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,267 @@
+use rustc_hash::FxHashSet;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_str::CompactStr;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    utils::{NodeApiKind, NodeApiReference, collect_node_api_references},
+};
+
+fn no_deprecated_api_diagnostic(span: Span, api: &DeprecatedApi) -> OxcDiagnostic {
+    let diagnostic =
+        OxcDiagnostic::warn(format!("`{}` is deprecated since Node.js {}", api.name, api.since))
+            .with_label(span);
+    match api.replacement {
+        Some(replacement) => diagnostic.with_help(format!("Use {replacement} instead.")),
+        None => diagnostic.with_help("It has no replacement, and may be removed."),
+    }
+}
+
+/// A deprecated Node.js API. A name ending with `()` is only deprecated when it is called
+/// or constructed, such as `Buffer()`.
+struct DeprecatedApi {
+    name: &'static str,
+    since: &'static str,
+    replacement: Option<&'static str>,
+}
+
+const fn api(
+    name: &'static str,
+    since: &'static str,
+    replacement: Option<&'static str>,
+) -> DeprecatedApi {
+    DeprecatedApi { name, since, replacement }
+}
+
+/// Deprecated built-in modules and members of built-in modules.
+const DEPRECATED_MODULE_APIS: [DeprecatedApi; 58] = [
+    api("buffer.Buffer()", "6.0.0", Some("`buffer.Buffer.alloc()` or `buffer.Buffer.from()`")),
+    api("buffer.SlowBuffer", "6.0.0", Some("`buffer.Buffer.allocUnsafeSlow()`")),
+    api("constants", "6.3.0", Some("the `constants` property of each module")),
+    api("crypto.Credentials", "0.12.0", Some("`tls.SecureContext`")),
+    api("crypto.DEFAULT_ENCODING", "10.0.0", None),
+    api("crypto._toBuf", "11.0.0", None),
+    api("crypto.createCipher", "10.0.0", Some("`crypto.createCipheriv()`")),
+    api("crypto.createCredentials", "0.12.0", Some("`tls.createSecureContext()`")),
+    api("crypto.createDecipher", "10.0.0", Some("`crypto.createDecipheriv()`")),
+    api("crypto.fips", "10.0.0", Some("`crypto.getFips()` and `crypto.setFips()`")),
+    api("crypto.prng", "11.0.0", Some("`crypto.randomBytes()`")),
+    api("crypto.pseudoRandomBytes", "11.0.0", Some("`crypto.randomBytes()`")),
+    api("crypto.rng", "11.0.0", Some("`crypto.randomBytes()`")),
+    api("domain", "4.0.0", None),
+    api("events.EventEmitter.listenerCount", "3.2.0", Some("`emitter.listenerCount()`")),
+    api("events.listenerCount", "3.2.0", Some("`emitter.listenerCount()`")),
+    api("fs.exists", "4.0.0", Some("`fs.stat()` or `fs.access()`")),
+    api("fs.lchmod", "0.4.0", None),
+    api("fs.lchmodSync", "0.4.0", None),
+    api("module.createRequireFromPath", "12.2.0", Some("`module.createRequire()`")),
+    api("os.getNetworkInterfaces", "0.6.0", Some("`os.networkInterfaces()`")),
+    api("os.tmpDir", "7.0.0", Some("`os.tmpdir()`")),
+    api("path._makeLong", "9.0.0", Some("`path.toNamespacedPath()`")),
+    api("process.EventEmitter", "0.6.0", Some("`events.EventEmitter`")),
+    api("process.assert", "10.0.0", Some("the `assert` module")),
+    api("process.binding", "10.9.0", None),
+    api("punycode", "7.0.0", Some("the `punycode` package")),
+    api("sys", "1.0.0", Some("the `util` module")),
+    api("tls.CleartextStream", "0.10.0", None),
+    api("tls.CryptoStream", "0.12.0", Some("`tls.TLSSocket`")),
+    api("tls.SecurePair", "6.0.0", Some("`tls.TLSSocket`")),
+    api("tls.createSecurePair", "6.0.0", Some("`tls.TLSSocket`")),
+    api("tls.parseCertString", "8.6.0", Some("`querystring.parse()`")),
+    api("url.parse", "11.0.0", Some("`new URL()`")),
+    api("url.resolve", "11.0.0", Some("`new URL()`")),
+    api("util._extend", "6.0.0", Some("`Object.assign()`")),
+    api("util.debug", "0.12.0", Some("`console.error()`")),
+    api("util.error", "0.12.0", Some("`console.error()`")),
+    api("util.isArray", "4.0.0", Some("`Array.isArray()`")),
+    api("util.isBoolean", "4.0.0", Some("`typeof value === \"boolean\"`")),
+    api("util.isBuffer", "4.0.0", Some("`Buffer.isBuffer()`")),
+    api("util.isDate", "4.0.0", Some("`util.types.isDate()`")),
+    api("util.isError", "4.0.0", Some("`util.types.isNativeError()`")),
+    api("util.isFunction", "4.0.0", Some("`typeof value === \"function\"`")),
+    api("util.isNull", "4.0.0", Some("`value === null`")),
+    api("util.isNullOrUndefined", "4.0.0", Some("`value == null`")),
+    api("util.isNumber", "4.0.0", Some("`typeof value === \"number\"`")),
+    api("util.isObject", "4.0.0", Some("`value !== null && typeof value === \"object\"`")),
+    api("util.isPrimitive", "4.0.0", Some("`Object(value) !== value`")),
+    api("util.isRegExp", "4.0.0", Some("`util.types.isRegExp()`")),
+    api("util.isString", "4.0.0", Some("`typeof value === \"string\"`")),
+    api("util.isSymbol", "4.0.0", Some("`typeof value === \"symbol\"`")),
+    api("util.isUndefined", "4.0.0", Some("`value === undefined`")),
+    api("util.log", "6.0.0", Some("a third-party logger")),
+    api("util.print", "0.12.0", Some("`console.log()`")),
+    api("util.pump", "0.10.0", Some("`stream.pipeline()`")),
+    api("util.puts", "0.12.0", Some("`console.log()`")),
+    api(
+        "util.types.isWebAssemblyCompiledModule",
+        "9.0.0",
+        Some("`value instanceof WebAssembly.Module`"),
+    ),
+];
+
+/// Deprecated globals and members of globals.
+const DEPRECATED_GLOBAL_APIS: [DeprecatedApi; 7] = [
+    api("Buffer()", "6.0.0", Some("`Buffer.alloc()` or `Buffer.from()`")),
+    api("GLOBAL", "6.0.0", Some("`globalThis`")),
+    api("process.EventEmitter", "0.6.0", Some("`events.EventEmitter`")),
+    api("process.assert", "10.0.0", Some("the `assert` module")),
+    api("process.binding", "10.9.0", None),
+    api("require.extensions", "0.12.0", Some("a compiler which builds the files ahead of time")),
+    api("root", "6.0.0", Some("`globalThis`")),
+];
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct NoDeprecatedApiConfig {
+    /// Deprecated modules and members of modules to allow, such as `"url.parse"`.
+    ignore_module_items: FxHashSet<CompactStr>,
+    /// Deprecated globals and members of globals to allow, such as `"Buffer()"`.
+    ignore_global_items: FxHashSet<CompactStr>,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoDeprecatedApi(Box<NoDeprecatedApiConfig>);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow deprecated Node.js APIs: built-in modules, their members, and globals.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Deprecated APIs are insecure, have surprising behavior, or are superseded by a
+    /// better API, and may be removed in a future version of Node.js.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const buffer = new Buffer(10);
+    /// const { parse } = require("node:url");
+    /// import { exists } from "node:fs";
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const buffer = Buffer.alloc(10);
+    /// const url = new URL(input);
+    /// import { access } from "node:fs";
+    /// ```
+    NoDeprecatedApi,
+    node,
+    correctness,
+    config = NoDeprecatedApiConfig,
+    version = "next",
+    short_description = "Disallow deprecated Node.js APIs.",
+);
+
+impl Rule for NoDeprecatedApi {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        DefaultRuleConfig::<Self>::from_value(value).map(DefaultRuleConfig::into_inner)
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let mut globals: Vec<&str> = DEPRECATED_GLOBAL_APIS
+            .iter()
+            .filter_map(|api| api.name.trim_end_matches("()").split('.').next())
+            .collect();
+        globals.dedup();
+        for reference in collect_node_api_references(&globals, ctx) {
+            let (apis, ignores): (&[DeprecatedApi], _) = match reference.kind {
+                NodeApiKind::Module => (&DEPRECATED_MODULE_APIS, &self.0.ignore_module_items),
+                NodeApiKind::Global => (&DEPRECATED_GLOBAL_APIS, &self.0.ignore_global_items),
+            };
+            let Some(api) = apis.iter().find(|api| match api.name.strip_suffix("()") {
+                Some(name) => name == reference.path && is_invoked(&reference, ctx),
+                None => api.name == reference.path,
+            }) else {
+                continue;
+            };
+            if !ignores.contains(api.name) {
+                ctx.diagnostic(no_deprecated_api_diagnostic(reference.span, api));
+            }
+        }
+    }
+}
+
+/// Whether the API of `reference` is called, as in `Buffer(10)`, or constructed, as in
+/// `new Buffer(10)`.
+fn is_invoked(reference: &NodeApiReference, ctx: &LintContext) -> bool {
+    let parent = ctx
+        .nodes()
+        .ancestors(reference.node_id)
+        .find(|node| !matches!(node.kind(), AstKind::ParenthesizedExpression(_)));
+    match parent.map(AstNode::kind) {
+        Some(AstKind::CallExpression(call)) => {
+            call.callee.get_inner_expression().span() == reference.span
+        }
+        Some(AstKind::NewExpression(new)) => {
+            new.callee.get_inner_expression().span() == reference.span
+        }
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("const buffer = Buffer.alloc(10);", None),
+        ("const buffer = Buffer.from('a');", None),
+        ("Buffer.isBuffer(value);", None),
+        ("const { Buffer } = require('buffer'); Buffer.alloc(10);", None),
+        ("const url = new URL(input);", None),
+        ("import { access } from 'node:fs'; access('a', () => {});", None),
+        ("import fs from 'fs'; fs.existsSync('a');", None),
+        ("const util = require('util'); util.types.isDate(value);", None),
+        ("function f(Buffer) { return new Buffer(10); }", None),
+        ("const url = require('url-parse'); url.parse(input);", None),
+        ("const root = document.body; root.append(child);", None),
+        ("import { parse } from 'node:url';", None),
+        (
+            "const { parse } = require('node:url'); parse(input);",
+            Some(json!([{ "ignoreModuleItems": ["url.parse"] }])),
+        ),
+        ("new Buffer(10);", Some(json!([{ "ignoreGlobalItems": ["Buffer()"] }]))),
+    ];
+
+    let fail = vec![
+        ("const buffer = new Buffer(10);", None),
+        ("const buffer = Buffer('a');", None),
+        ("const { Buffer } = require('buffer'); new Buffer(10);", None),
+        ("new (require('buffer').Buffer)(10);", None),
+        ("import { SlowBuffer } from 'node:buffer'; new SlowBuffer(10);", None),
+        ("import domain from 'domain';", None),
+        ("const punycode = require('punycode');", None),
+        ("const { parse } = require('node:url'); parse(input);", None),
+        ("import url from 'url'; url.parse(input);", None),
+        ("import { exists } from 'fs'; exists('a', () => {});", None),
+        ("require('fs').exists('a', () => {});", None),
+        ("const util = require('util'); util.isArray(value);", None),
+        ("import * as util from 'node:util'; if (util.isString(value)) {}", None),
+        ("const { _extend } = require('util'); _extend(a, b);", None),
+        ("const crypto = require('crypto'); crypto.createCipher('aes192', key);", None),
+        (
+            "const { EventEmitter } = require('events'); EventEmitter.listenerCount(emitter, 'a');",
+            None,
+        ),
+        ("process.binding('fs');", None),
+        ("const os = require('os'); os.tmpDir();", None),
+        ("require.extensions['.txt'] = load;", None),
+        ("GLOBAL.foo = 1;", None),
+        ("new Buffer(10);", Some(json!([{ "ignoreModuleItems": ["buffer.Buffer()"] }]))),
+    ];
+
+    Tester::new(NoDeprecatedApi::NAME, NoDeprecatedApi::PLUGIN, pass, fail).test_and_snapshot();
+}
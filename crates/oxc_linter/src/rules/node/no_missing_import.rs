@@ -0,0 +1,153 @@
+use rustc_hash::FxHashSet;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use oxc_str::CompactStr;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    utils::{NodeModuleSyntax, get_node_import_source, is_missing_node_module, node_package_name},
+};
+
+fn no_missing_import_diagnostic(span: Span, specifier: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{specifier}` is not found."))
+        .with_help("Check that the path is correct, or install the package.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct NoMissingImportConfig {
+    /// Packages which are allowed to be missing, such as packages provided by the environment
+    /// the code runs in.
+    allow_modules: FxHashSet<CompactStr>,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoMissingImport(Box<NoMissingImportConfig>);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow `import` and `export ... from` declarations, and `import()` expressions, of
+    /// modules which do not exist.
+    ///
+    /// Modules are resolved as Node.js resolves ES modules, using the `import` condition of
+    /// `exports`. TypeScript sources imported with a `.js` extension, and the `paths` of
+    /// `tsconfig.json`, are also resolved. Built-in modules and type-only imports are ignored.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Importing a module which does not exist throws when the file is loaded. This is
+    /// often caused by a typo in the path, or a package missing from `dependencies`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// import typo from "./utli.js";
+    /// import notInstalled from "not-installed";
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import util from "./util.js";
+    /// import fs from "node:fs";
+    /// ```
+    NoMissingImport,
+    node,
+    correctness,
+    config = NoMissingImportConfig,
+    version = "next",
+    short_description = "Disallow imports of modules which do not exist.",
+);
+
+impl Rule for NoMissingImport {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        DefaultRuleConfig::<Self>::from_value(value).map(DefaultRuleConfig::into_inner)
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let Some((source, is_type)) = get_node_import_source(node) else {
+            return;
+        };
+        if is_type
+            || ctx.source_type().is_typescript_definition()
+            || node_package_name(&source.value)
+                .is_some_and(|name| self.0.allow_modules.contains(name))
+        {
+            return;
+        }
+        if is_missing_node_module(&source.value, NodeModuleSyntax::Import, ctx) {
+            ctx.diagnostic(no_missing_import_diagnostic(source.span, &source.value));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let path = || Some(PathBuf::from("../node/lib/index.ts"));
+
+    let pass = vec![
+        ("import util from './util.js';", None, None, path()),
+        ("import util from './util';", None, None, path()),
+        ("import dir from './dir';", None, None, path()),
+        ("import data from './data.json' with { type: 'json' };", None, None, path()),
+        ("import { value } from '../src/module.js';", None, None, path()),
+        ("import dep from 'dep';", None, None, path()),
+        ("import exported from 'exported';", None, None, path()),
+        ("import esm from 'exported/esm-only';", None, None, path()),
+        ("import devDep from 'dev-dep';", None, None, path()),
+        ("import fs from 'fs';", None, None, path()),
+        ("import { test } from 'node:test';", None, None, path()),
+        ("import mod from 'https://example.com/mod.js';", None, None, path()),
+        ("import type { Options } from './types.js';", None, None, path()),
+        ("export type { Options } from './types.js';", None, None, path()),
+        ("export * from './util.js';", None, None, path()),
+        ("const mod = await import(name);", None, None, path()),
+        ("const util = await import('./util.js');", None, None, path()),
+        (
+            "import vscode from 'vscode';",
+            Some(json!([{ "allowModules": ["vscode"] }])),
+            None,
+            path(),
+        ),
+        (
+            "import sub from 'vscode/sub';",
+            Some(json!([{ "allowModules": ["vscode"] }])),
+            None,
+            path(),
+        ),
+    ];
+
+    let fail = vec![
+        ("import typo from './utli.js';", None, None, path()),
+        ("import nested from './dir/missing';", None, None, path()),
+        ("import { value } from '../src/missing.js';", None, None, path()),
+        ("import notInstalled from 'not-installed';", None, None, path()),
+        ("import scoped from '@scope/not-installed';", None, None, path()),
+        ("import internal from 'exported/internal';", None, None, path()),
+        ("export { default } from './missing.js';", None, None, path()),
+        ("export * from './missing.js';", None, None, path()),
+        ("const missing = await import('./missing.js');", None, None, path()),
+        (
+            "import vscode from 'vscode';",
+            Some(json!([{ "allowModules": ["other"] }])),
+            None,
+            path(),
+        ),
+    ];
+
+    Tester::new(NoMissingImport::NAME, NoMissingImport::PLUGIN, pass, fail).test_and_snapshot();
+}
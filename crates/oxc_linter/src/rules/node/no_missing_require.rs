@@ -0,0 +1,151 @@
+use rustc_hash::FxHashSet;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, CallExpression, StringLiteral},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use oxc_str::CompactStr;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    utils::{NodeModuleSyntax, is_missing_node_module, node_package_name},
+};
+
+fn no_missing_require_diagnostic(span: Span, specifier: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{specifier}` is not found."))
+        .with_help("Check that the path is correct, or install the package.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct NoMissingRequireConfig {
+    /// Packages which are allowed to be missing, such as packages provided by the environment
+    /// the code runs in.
+    allow_modules: FxHashSet<CompactStr>,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoMissingRequire(Box<NoMissingRequireConfig>);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow `require()` and `require.resolve()` calls of modules which do not exist.
+    ///
+    /// Modules are resolved as Node.js resolves CommonJS modules, using the `require`
+    /// condition of `exports`. Built-in modules are ignored.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Requiring a module which does not exist throws when the call is evaluated. This is
+    /// often caused by a typo in the path, or a package missing from `dependencies`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const typo = require("./utli");
+    /// const notInstalled = require("not-installed");
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const util = require("./util");
+    /// const fs = require("node:fs");
+    /// ```
+    NoMissingRequire,
+    node,
+    correctness,
+    config = NoMissingRequireConfig,
+    version = "next",
+    short_description = "Disallow `require()` calls of modules which do not exist.",
+);
+
+impl Rule for NoMissingRequire {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        DefaultRuleConfig::<Self>::from_value(value).map(DefaultRuleConfig::into_inner)
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Some(source) = get_required_source(call) else {
+            return;
+        };
+        if node_package_name(&source.value).is_some_and(|name| self.0.allow_modules.contains(name))
+        {
+            return;
+        }
+        if is_missing_node_module(&source.value, NodeModuleSyntax::Require, ctx) {
+            ctx.diagnostic(no_missing_require_diagnostic(source.span, &source.value));
+        }
+    }
+}
+
+/// The source of `require("source")` or `require.resolve("source")`.
+fn get_required_source<'a>(call: &'a CallExpression<'a>) -> Option<&'a StringLiteral<'a>> {
+    if let Some(source) = call.common_js_require() {
+        return Some(source);
+    }
+    let member = call.callee.get_member_expr()?;
+    if !(member.object().is_specific_id("require")
+        && member.static_property_name() == Some("resolve"))
+    {
+        return None;
+    }
+    match call.arguments.first()? {
+        Argument::StringLiteral(source) => Some(source),
+        _ => None,
+    }
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let path = || Some(PathBuf::from("../node/lib/index.js"));
+
+    let pass = vec![
+        ("const util = require('./util');", None, None, path()),
+        ("const util = require('./util.js');", None, None, path()),
+        ("const dir = require('./dir');", None, None, path()),
+        ("const data = require('./data.json');", None, None, path()),
+        ("const dep = require('dep');", None, None, path()),
+        ("const exported = require('exported');", None, None, path()),
+        ("const fs = require('fs');", None, None, path()),
+        ("const test = require('node:test');", None, None, path()),
+        ("const mod = require(name);", None, None, path()),
+        ("const path = require.resolve('./util');", None, None, path()),
+        ("const mod = foo.require('./missing');", None, None, path()),
+        (
+            "const vscode = require('vscode');",
+            Some(json!([{ "allowModules": ["vscode"] }])),
+            None,
+            path(),
+        ),
+    ];
+
+    let fail = vec![
+        ("const typo = require('./utli');", None, None, path()),
+        ("const nested = require('./dir/missing.js');", None, None, path()),
+        ("const notInstalled = require('not-installed');", None, None, path()),
+        ("const esm = require('exported/esm-only');", None, None, path()),
+        ("const path = require.resolve('./missing');", None, None, path()),
+        ("function load() { return require('../missing'); }", None, None, path()),
+    ];
+
+    Tester::new(NoMissingRequire::NAME, NoMissingRequire::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,179 @@
+use rustc_hash::FxHashSet;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use oxc_str::CompactStr;
+
+use crate::{
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    utils::{
+        NodeModuleSyntax, find_node_package, get_node_import_source, node_package_name,
+        resolve_node_module,
+    },
+};
+
+fn no_unpublished_import_diagnostic(span: Span, specifier: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{specifier}` is not published."))
+        .with_help(
+            "Published files can only import published files, and packages in `dependencies`.",
+        )
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct NoUnpublishedImportConfig {
+    /// Packages which are allowed to be imported although they are not published.
+    allow_modules: FxHashSet<CompactStr>,
+    /// Whether to ignore type-only imports, which are removed when compiling TypeScript.
+    ignore_type_import: bool,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoUnpublishedImport(Box<NoUnpublishedImportConfig>);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow published files from importing files which are not published, or packages
+    /// which are only in `devDependencies`.
+    ///
+    /// The files published to npm are the `main` and `bin` files, and the files matched by
+    /// the `files` field of the nearest `package.json`, or every file when it has no `files`
+    /// field. Files of private packages are never published, so they are not checked.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `devDependencies` are not installed with the package, and unpublished files are not
+    /// included in it, so the import throws for the users of the package.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule, in a file listed in `files`:
+    /// ```js
+    /// import helper from "../test/helper.js";
+    /// import devDependency from "dev-dependency";
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule, in a file listed in `files`:
+    /// ```js
+    /// import util from "./util.js";
+    /// import dependency from "dependency";
+    /// ```
+    NoUnpublishedImport,
+    node,
+    correctness,
+    config = NoUnpublishedImportConfig,
+    version = "next",
+    short_description = "Disallow published files from importing unpublished files and packages.",
+);
+
+impl Rule for NoUnpublishedImport {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        DefaultRuleConfig::<Self>::from_value(value).map(DefaultRuleConfig::into_inner)
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(package) = find_node_package(ctx) else {
+            return;
+        };
+        if !package.is_published(ctx.file_path()) {
+            return;
+        }
+        for node in ctx.nodes() {
+            let Some((source, is_type)) = get_node_import_source(node) else {
+                continue;
+            };
+            if is_type && self.0.ignore_type_import {
+                continue;
+            }
+            let specifier = source.value.as_str();
+            let is_unpublished = match node_package_name(specifier) {
+                Some(name) => {
+                    !self.0.allow_modules.contains(name)
+                        && package.dev_dependencies.contains(name)
+                        && !package.dependencies.contains(name)
+                }
+                None if specifier.starts_with('.') => {
+                    resolve_node_module(specifier, NodeModuleSyntax::Import, ctx)
+                        .is_ok_and(|path| !package.is_published(&path))
+                }
+                None => false,
+            };
+            if is_unpublished {
+                ctx.diagnostic(no_unpublished_import_diagnostic(source.span, specifier));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let lib = || Some(PathBuf::from("../node/lib/index.ts"));
+    let bin = || Some(PathBuf::from("../node/bin/cli.js"));
+
+    let pass = vec![
+        ("import util from './util.js';", None, None, lib()),
+        ("import dir from './dir/index.js';", None, None, lib()),
+        ("import dep from 'dep';", None, None, lib()),
+        ("import exported from 'exported';", None, None, lib()),
+        ("import fs from 'node:fs';", None, None, lib()),
+        ("import missing from './missing.js';", None, None, lib()),
+        ("import lib from '../lib/index.js';", None, None, bin()),
+        (
+            "import helper from './helper.js';",
+            None,
+            None,
+            Some(PathBuf::from("../node/test/index.js")),
+        ),
+        ("import devDep from 'dev-dep';", None, None, Some(PathBuf::from("../node/test/index.js"))),
+        (
+            "import helper from '../test/helper.js';",
+            None,
+            None,
+            Some(PathBuf::from("../node/lib/util.test.js")),
+        ),
+        (
+            "import devDep from 'dev-dep';",
+            None,
+            None,
+            Some(PathBuf::from("../node/private/index.js")),
+        ),
+        (
+            "import devDep from 'dev-dep';",
+            Some(json!([{ "allowModules": ["dev-dep"] }])),
+            None,
+            lib(),
+        ),
+        (
+            "import type { Options } from 'dev-dep';",
+            Some(json!([{ "ignoreTypeImport": true }])),
+            None,
+            lib(),
+        ),
+    ];
+
+    let fail = vec![
+        ("import helper from '../test/helper.js';", None, None, lib()),
+        ("import devDep from 'dev-dep';", None, None, lib()),
+        ("import devDep from 'dev-dep/lib/index.js';", None, None, lib()),
+        ("import type { Options } from 'dev-dep';", None, None, lib()),
+        ("export * from '../test/helper.js';", None, None, lib()),
+        ("const helper = await import('../test/helper.js');", None, None, lib()),
+        ("import util from './util.test.js';", None, None, lib()),
+        ("import devDep from 'dev-dep';", None, None, bin()),
+    ];
+
+    Tester::new(NoUnpublishedImport::NAME, NoUnpublishedImport::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
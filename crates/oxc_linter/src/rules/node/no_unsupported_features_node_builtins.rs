@@ -0,0 +1,359 @@
+use rustc_hash::FxHashSet;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use oxc_compat::Version;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use oxc_str::CompactStr;
+
+use crate::{
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    utils::{
+        NodeApiKind, collect_node_api_references, is_node_version_supported, min_node_version,
+        node_version_range,
+    },
+};
+
+fn no_unsupported_features_node_builtins_diagnostic(
+    span: Span,
+    name: &str,
+    supported: &str,
+    range: &str,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{name}` is not supported until Node.js {supported}"))
+        .with_help(format!("The configured range of Node.js versions is `{range}`."))
+        .with_label(span)
+}
+
+/// The versions of Node.js which added an API, as described by [`is_node_version_supported`].
+type Since = &'static [Version];
+
+/// Built-in modules, and their members, which are not supported by every maintained
+/// version of Node.js. Members are joined to the name of their module with `.`.
+const MODULE_APIS: [(&str, Since); 84] = [
+    ("assert/strict", &[Version(15, 0, 0)]),
+    ("buffer.Blob", &[Version(14, 18, 0), Version(15, 7, 0)]),
+    ("buffer.File", &[Version(18, 13, 0), Version(19, 2, 0)]),
+    ("buffer.isAscii", &[Version(18, 15, 0), Version(19, 6, 0)]),
+    ("buffer.isUtf8", &[Version(18, 14, 0), Version(19, 4, 0)]),
+    ("buffer.resolveObjectURL", &[Version(16, 7, 0)]),
+    ("crypto.X509Certificate", &[Version(15, 6, 0)]),
+    ("crypto.checkPrime", &[Version(15, 8, 0)]),
+    ("crypto.generatePrime", &[Version(15, 8, 0)]),
+    ("crypto.getRandomValues", &[Version(17, 4, 0)]),
+    ("crypto.hash", &[Version(20, 12, 0), Version(21, 7, 0)]),
+    ("crypto.hkdf", &[Version(15, 0, 0)]),
+    ("crypto.randomUUID", &[Version(14, 17, 0), Version(15, 6, 0)]),
+    ("crypto.subtle", &[Version(17, 4, 0)]),
+    ("crypto.webcrypto", &[Version(15, 0, 0)]),
+    ("diagnostics_channel", &[Version(14, 17, 0), Version(15, 1, 0)]),
+    ("diagnostics_channel.subscribe", &[Version(16, 17, 0), Version(18, 7, 0)]),
+    ("diagnostics_channel.tracingChannel", &[Version(19, 9, 0)]),
+    ("dns/promises", &[Version(15, 0, 0)]),
+    ("events.addAbortListener", &[Version(18, 18, 0), Version(20, 5, 0)]),
+    ("events.getEventListeners", &[Version(14, 17, 0), Version(15, 2, 0)]),
+    ("events.getMaxListeners", &[Version(18, 17, 0), Version(19, 9, 0)]),
+    ("events.setMaxListeners", &[Version(15, 4, 0)]),
+    ("fs.cp", &[Version(16, 7, 0)]),
+    ("fs.cpSync", &[Version(16, 7, 0)]),
+    ("fs.glob", &[Version(22, 0, 0)]),
+    ("fs.globSync", &[Version(22, 0, 0)]),
+    ("fs.openAsBlob", &[Version(19, 8, 0)]),
+    ("fs.promises.cp", &[Version(16, 7, 0)]),
+    ("fs.promises.glob", &[Version(22, 0, 0)]),
+    ("fs.promises.statfs", &[Version(18, 15, 0), Version(19, 6, 0)]),
+    ("fs.rm", &[Version(14, 14, 0)]),
+    ("fs.rmSync", &[Version(14, 14, 0)]),
+    ("fs.statfs", &[Version(18, 15, 0), Version(19, 6, 0)]),
+    ("fs.statfsSync", &[Version(18, 15, 0), Version(19, 6, 0)]),
+    ("fs/promises", &[Version(14, 0, 0)]),
+    ("fs/promises.constants", &[Version(18, 4, 0)]),
+    ("fs/promises.cp", &[Version(16, 7, 0)]),
+    ("fs/promises.glob", &[Version(22, 0, 0)]),
+    ("fs/promises.statfs", &[Version(18, 15, 0), Version(19, 6, 0)]),
+    ("inspector/promises", &[Version(19, 0, 0)]),
+    ("module.enableCompileCache", &[Version(22, 1, 0)]),
+    ("module.findPackageJSON", &[Version(22, 14, 0), Version(23, 2, 0)]),
+    ("module.isBuiltin", &[Version(16, 17, 0), Version(18, 6, 0)]),
+    ("module.register", &[Version(18, 19, 0), Version(20, 6, 0)]),
+    ("os.availableParallelism", &[Version(18, 14, 0), Version(19, 4, 0)]),
+    ("os.devNull", &[Version(16, 3, 0)]),
+    ("os.machine", &[Version(16, 18, 0), Version(18, 9, 0)]),
+    ("path.matchesGlob", &[Version(20, 17, 0), Version(22, 5, 0)]),
+    ("path/posix", &[Version(15, 3, 0)]),
+    ("path/win32", &[Version(15, 3, 0)]),
+    ("perf_hooks.createHistogram", &[Version(15, 9, 0)]),
+    ("process.constrainedMemory", &[Version(18, 15, 0), Version(19, 6, 0)]),
+    ("process.getBuiltinModule", &[Version(20, 16, 0), Version(22, 3, 0)]),
+    ("process.loadEnvFile", &[Version(20, 12, 0), Version(21, 7, 0)]),
+    ("readline/promises", &[Version(17, 0, 0)]),
+    ("sea", &[Version(20, 12, 0), Version(21, 7, 0)]),
+    ("sqlite", &[Version(22, 5, 0)]),
+    ("stream.Readable.fromWeb", &[Version(17, 0, 0)]),
+    ("stream.Readable.toWeb", &[Version(17, 0, 0)]),
+    ("stream.Writable.fromWeb", &[Version(17, 0, 0)]),
+    ("stream.Writable.toWeb", &[Version(17, 0, 0)]),
+    ("stream.addAbortSignal", &[Version(14, 17, 0), Version(15, 4, 0)]),
+    ("stream.compose", &[Version(16, 9, 0)]),
+    ("stream.duplexPair", &[Version(22, 6, 0)]),
+    ("stream.getDefaultHighWaterMark", &[Version(18, 17, 0), Version(19, 9, 0)]),
+    ("stream.isErrored", &[Version(16, 14, 0), Version(17, 3, 0)]),
+    ("stream.isReadable", &[Version(16, 14, 0), Version(17, 4, 0)]),
+    ("stream/consumers", &[Version(16, 7, 0)]),
+    ("stream/promises", &[Version(15, 0, 0)]),
+    ("stream/web", &[Version(16, 5, 0)]),
+    ("test", &[Version(16, 17, 0), Version(18, 0, 0)]),
+    ("test/reporters", &[Version(18, 17, 0), Version(19, 9, 0)]),
+    ("timers/promises", &[Version(15, 0, 0)]),
+    ("util.MIMEType", &[Version(18, 13, 0), Version(19, 1, 0)]),
+    ("util.aborted", &[Version(18, 16, 0), Version(19, 7, 0)]),
+    ("util.getSystemErrorMap", &[Version(14, 17, 0), Version(16, 0, 0)]),
+    ("util.parseArgs", &[Version(16, 17, 0), Version(18, 3, 0)]),
+    ("util.parseEnv", &[Version(20, 12, 0), Version(21, 7, 0)]),
+    ("util.stripVTControlCharacters", &[Version(16, 11, 0)]),
+    ("util.styleText", &[Version(20, 12, 0), Version(21, 7, 0)]),
+    ("util.transferableAbortSignal", &[Version(18, 11, 0)]),
+    ("util/types", &[Version(15, 3, 0)]),
+    ("worker_threads.markAsUncloneable", &[Version(22, 10, 0), Version(23, 0, 0)]),
+];
+
+/// Globals, and their members, which are not supported by every maintained version of
+/// Node.js.
+const GLOBAL_APIS: [(&str, Since); 41] = [
+    ("AbortController", &[Version(14, 17, 0), Version(15, 0, 0)]),
+    ("AbortSignal", &[Version(14, 17, 0), Version(15, 0, 0)]),
+    ("AbortSignal.any", &[Version(20, 3, 0)]),
+    ("AbortSignal.timeout", &[Version(16, 14, 0), Version(17, 3, 0)]),
+    ("Blob", &[Version(18, 0, 0)]),
+    ("BroadcastChannel", &[Version(18, 0, 0)]),
+    ("Buffer.copyBytesFrom", &[Version(18, 16, 0), Version(19, 8, 0)]),
+    ("Buffer.isAscii", &[Version(18, 15, 0), Version(19, 6, 0)]),
+    ("Buffer.isUtf8", &[Version(18, 14, 0), Version(19, 4, 0)]),
+    ("ByteLengthQueuingStrategy", &[Version(18, 0, 0)]),
+    ("CompressionStream", &[Version(18, 0, 0)]),
+    ("CountQueuingStrategy", &[Version(18, 0, 0)]),
+    ("CustomEvent", &[Version(19, 0, 0)]),
+    ("DOMException", &[Version(17, 0, 0)]),
+    ("DecompressionStream", &[Version(18, 0, 0)]),
+    ("Event", &[Version(15, 0, 0)]),
+    ("EventTarget", &[Version(15, 0, 0)]),
+    ("File", &[Version(20, 0, 0)]),
+    ("FormData", &[Version(18, 0, 0)]),
+    ("Headers", &[Version(18, 0, 0)]),
+    ("MessageChannel", &[Version(15, 0, 0)]),
+    ("MessageEvent", &[Version(15, 0, 0)]),
+    ("MessagePort", &[Version(15, 0, 0)]),
+    ("Navigator", &[Version(21, 0, 0)]),
+    ("ReadableStream", &[Version(18, 0, 0)]),
+    ("Request", &[Version(18, 0, 0)]),
+    ("Response", &[Version(18, 0, 0)]),
+    ("TextDecoderStream", &[Version(18, 0, 0)]),
+    ("TextEncoderStream", &[Version(18, 0, 0)]),
+    ("TransformStream", &[Version(18, 0, 0)]),
+    ("WebSocket", &[Version(22, 0, 0)]),
+    ("WritableStream", &[Version(18, 0, 0)]),
+    ("crypto", &[Version(19, 0, 0)]),
+    ("fetch", &[Version(18, 0, 0)]),
+    ("navigator", &[Version(21, 0, 0)]),
+    ("performance", &[Version(16, 0, 0)]),
+    ("process.constrainedMemory", &[Version(18, 15, 0), Version(19, 6, 0)]),
+    ("process.finalization", &[Version(22, 5, 0)]),
+    ("process.getBuiltinModule", &[Version(20, 16, 0), Version(22, 3, 0)]),
+    ("process.loadEnvFile", &[Version(20, 12, 0), Version(21, 7, 0)]),
+    ("structuredClone", &[Version(17, 0, 0)]),
+];
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct NoUnsupportedFeaturesNodeBuiltinsConfig {
+    /// The range of Node.js versions to support, such as `">=18.0.0"`. Defaults to the
+    /// `engines.node` field of the nearest `package.json`, then `">=16.0.0"`.
+    version: Option<String>,
+    /// APIs to allow although they are not supported, such as `"fs.cp"` or `"fetch"`.
+    ignores: FxHashSet<CompactStr>,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoUnsupportedFeaturesNodeBuiltins(Box<NoUnsupportedFeaturesNodeBuiltinsConfig>);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow Node.js built-in modules, their members, and globals which are not
+    /// supported by the lowest Node.js version the package supports. Members of modules
+    /// are reported where they are used, including through variables and destructuring.
+    ///
+    /// The supported versions are read from the `engines.node` field of the nearest
+    /// `package.json`, unless the `version` option is set.
+    ///
+    /// This rule is `n/no-unsupported-features/node-builtins` in `eslint-plugin-n`, and can
+    /// also be configured as `node/no-unsupported-features/node-builtins`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Using an API which does not exist in a supported version of Node.js throws, or
+    /// loads `undefined`, for the users of that version.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule, with `"engines": { "node": ">=16.0.0" }`:
+    /// ```js
+    /// import { cp } from "node:fs/promises";
+    /// await cp(source, destination);
+    /// const response = await fetch(url);
+    /// const copy = structuredClone(value);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule, with `"engines": { "node": ">=16.0.0" }`:
+    /// ```js
+    /// import { readFile } from "node:fs/promises";
+    /// const { rm } = require("node:fs");
+    /// ```
+    NoUnsupportedFeaturesNodeBuiltins,
+    node,
+    correctness,
+    config = NoUnsupportedFeaturesNodeBuiltinsConfig,
+    version = "next",
+    short_description = "Disallow Node.js built-in APIs which are not supported by the configured Node.js versions.",
+);
+
+impl Rule for NoUnsupportedFeaturesNodeBuiltins {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        DefaultRuleConfig::<Self>::from_value(value).map(DefaultRuleConfig::into_inner)
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let range = node_version_range(self.0.version.as_deref(), ctx);
+        let Some(min_version) = min_node_version(&range) else {
+            return;
+        };
+        let mut globals: Vec<&str> =
+            GLOBAL_APIS.iter().filter_map(|(name, _)| name.split('.').next()).collect();
+        globals.dedup();
+        for reference in collect_node_api_references(&globals, ctx) {
+            let apis: &[(&str, Since)] = match reference.kind {
+                NodeApiKind::Module => &MODULE_APIS,
+                NodeApiKind::Global => &GLOBAL_APIS,
+            };
+            let Some((name, since)) = apis.iter().find(|(name, _)| *name == reference.path) else {
+                continue;
+            };
+            if self.0.ignores.contains(*name) || is_node_version_supported(min_version, since) {
+                continue;
+            }
+            ctx.diagnostic(no_unsupported_features_node_builtins_diagnostic(
+                reference.span,
+                name,
+                &format_since(since),
+                &range,
+            ));
+        }
+    }
+}
+
+/// Format `since` as `22.3.0`, or `22.3.0 (backported to 20.16.0)`.
+fn format_since(since: Since) -> String {
+    let Some((added, backports)) = since.split_last() else {
+        return String::new();
+    };
+    if backports.is_empty() {
+        return added.to_string();
+    }
+    let backports = backports.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ");
+    format!("{added} (backported to {backports})")
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let path = || Some(PathBuf::from("../node/lib/index.js"));
+    let modern = || Some(PathBuf::from("../node/modern/index.js"));
+    let version = |range: &str| Some(json!([{ "version": range }]));
+
+    let pass = vec![
+        ("import { readFile } from 'node:fs/promises';", None, None, path()),
+        ("const { rm } = require('fs');", None, None, path()),
+        ("import fs from 'fs'; fs.readFileSync('a');", None, None, path()),
+        ("const ac = new AbortController();", None, None, path()),
+        ("performance.now();", None, None, path()),
+        ("function f(fetch) { return fetch(url); }", None, None, path()),
+        ("const fetch = require('node-fetch'); fetch(url);", None, None, path()),
+        ("const response = await fetch(url);", None, None, modern()),
+        ("import { glob } from 'node:fs/promises';", None, None, modern()),
+        ("import path from 'node:path'; path.matchesGlob('a', '*');", None, None, modern()),
+        ("const response = await fetch(url);", version(">=18"), None, path()),
+        ("structuredClone(value);", version("^18.0.0 || >=20.0.0"), None, path()),
+        (
+            "const { styleText } = require('node:util');",
+            version("^20.12.0 || >=21.7.0"),
+            None,
+            path(),
+        ),
+        ("const { styleText } = require('node:util');", version(">=20.12.0"), None, path()),
+        ("fetch(url);", Some(json!([{ "ignores": ["fetch"] }])), None, path()),
+        (
+            "import { cp } from 'fs/promises';",
+            Some(json!([{ "ignores": ["fs/promises.cp"] }])),
+            None,
+            path(),
+        ),
+        ("import { test } from 'node:test';", version(">=18"), None, path()),
+        ("import { test } from 'node:test';", version("^16.17.0 || >=18"), None, path()),
+        ("import { DatabaseSync } from 'node:sqlite';", None, None, modern()),
+        ("import { cp } from 'node:fs/promises';", None, None, path()),
+    ];
+
+    let fail = vec![
+        ("const response = await fetch(url);", None, None, path()),
+        ("const copy = structuredClone(value);", None, None, path()),
+        ("import { cp } from 'node:fs/promises'; await cp('a', 'b');", None, None, path()),
+        ("import fs from 'node:fs'; fs.cp('a', 'b', () => {});", None, None, path()),
+        ("import * as fs from 'fs'; await fs.promises.cp('a', 'b');", None, None, path()),
+        ("const fs = require('fs'); fs.promises.cp('a', 'b');", None, None, path()),
+        ("const { promises } = require('fs'); promises.cp('a', 'b');", None, None, path()),
+        ("const { promises: { glob } } = require('fs'); glob('*');", None, None, path()),
+        ("require('fs').cpSync('a', 'b');", None, None, path()),
+        ("const { parseArgs } = require('node:util'); parseArgs({});", None, None, path()),
+        (
+            "import { styleText } from 'node:util'; styleText('red', 'a');",
+            version(">=21.0.0"),
+            None,
+            path(),
+        ),
+        ("import { test } from 'node:test';", None, None, path()),
+        ("import readline from 'node:readline/promises';", None, None, path()),
+        ("process.getBuiltinModule('fs');", None, None, path()),
+        (
+            "const { getBuiltinModule } = require('process'); getBuiltinModule('fs');",
+            None,
+            None,
+            path(),
+        ),
+        ("const ws = new WebSocket(url);", None, None, path()),
+        ("import { DatabaseSync } from 'node:sqlite';", version(">=22.0.0"), None, path()),
+        ("const uuid = crypto.randomUUID();", None, None, path()),
+        (
+            "const { randomUUID } = require('crypto'); randomUUID();",
+            version(">=14.0.0"),
+            None,
+            path(),
+        ),
+        ("navigator.hardwareConcurrency;", version(">=20"), None, path()),
+    ];
+
+    Tester::new(
+        NoUnsupportedFeaturesNodeBuiltins::NAME,
+        NoUnsupportedFeaturesNodeBuiltins::PLUGIN,
+        pass,
+        fail,
+    )
+    .test_and_snapshot();
+}
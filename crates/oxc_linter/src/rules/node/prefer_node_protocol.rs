@@ -0,0 +1,126 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use oxc_compat::Version;
+use oxc_macros::declare_oxc_lint;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    rules::shared::prefer_node_protocol,
+    utils::{NodeModuleSyntax, is_node_version_supported, min_node_version, node_version_range},
+};
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct PreferNodeProtocolConfig {
+    /// The range of Node.js versions to support, such as `">=18.0.0"`. Defaults to the
+    /// `engines.node` field of the nearest `package.json`, then `">=16.0.0"`.
+    version: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct PreferNodeProtocol(Box<PreferNodeProtocolConfig>);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Prefer using the `node:` protocol when importing Node.js built-in modules, when
+    /// every supported version of Node.js supports it.
+    ///
+    /// `import` supports the `node:` protocol since Node.js 12.20.0 and 14.13.1, and
+    /// `require()` since Node.js 14.18.0 and 16.0.0. The supported versions are read from
+    /// the `engines.node` field of the nearest `package.json`, unless the `version` option
+    /// is set.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The `node:` protocol makes it clear that the module is built into Node.js, and
+    /// cannot be shadowed by a package in `node_modules`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// import fs from "fs";
+    /// const path = require("path");
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import fs from "node:fs";
+    /// const path = require("node:path");
+    /// ```
+    PreferNodeProtocol,
+    node,
+    style,
+    fix,
+    config = PreferNodeProtocolConfig,
+    version = "next",
+    short_description = "Prefer using the `node:` protocol when importing Node.js built-in modules.",
+);
+
+impl Rule for PreferNodeProtocol {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        DefaultRuleConfig::<Self>::from_value(value).map(DefaultRuleConfig::into_inner)
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        prefer_node_protocol::run(node, ctx, |syntax| {
+            let range = node_version_range(self.0.version.as_deref(), ctx);
+            let Some(min_version) = min_node_version(&range) else {
+                return false;
+            };
+            let since: &[Version] = match syntax {
+                NodeModuleSyntax::Import => &[Version(12, 20, 0), Version(14, 13, 1)],
+                NodeModuleSyntax::Require => &[Version(14, 18, 0), Version(16, 0, 0)],
+            };
+            is_node_version_supported(min_version, since)
+        });
+    }
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let path = || Some(PathBuf::from("../node/lib/index.js"));
+    let version = |range: &str| Some(json!([{ "version": range }]));
+
+    let pass = vec![
+        ("import fs from 'node:fs';", None, None, path()),
+        ("import fs from './fs';", None, None, path()),
+        ("import dep from 'dep';", None, None, path()),
+        ("const fs = require('node:fs/promises');", None, None, path()),
+        ("export { promises } from 'node:fs';", None, None, path()),
+        ("import fs from 'fs';", version(">=12.0.0"), None, path()),
+        ("import fs from 'fs';", version("^13.0.0"), None, path()),
+        ("const fs = require('fs');", version(">=14.13.1"), None, path()),
+        ("const fs = require('fs');", version("^14.17.0 || >=16.0.0"), None, path()),
+    ];
+
+    let fail = vec![
+        ("import fs from 'fs';", None, None, path()),
+        ("import { readFile } from 'fs/promises';", None, None, path()),
+        ("export { promises } from 'fs';", None, None, path()),
+        ("const fs = await import('fs');", None, None, path()),
+        ("const path = require('path');", None, None, path()),
+        ("import fs from 'fs';", version("^12.20.0 || >=14.13.1"), None, path()),
+        ("import fs from 'fs';", version(">=14.13.1"), None, path()),
+        ("const fs = require('fs');", version("^14.18.0 || >=16.0.0"), None, path()),
+    ];
+
+    let fix = vec![
+        ("import fs from 'fs';", "import fs from 'node:fs';", None, path()),
+        ("const path = require(\"path\");", "const path = require(\"node:path\");", None, path()),
+    ];
+
+    Tester::new(PreferNodeProtocol::NAME, PreferNodeProtocol::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -0,0 +1,180 @@
+use oxc_ast::{AstKind, ast::CallExpression};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use oxc_str::CompactStr;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::{DefaultRuleConfig, Rule},
+    utils::{
+        PLAYWRIGHT_ASYNC_MATCHERS, PlaywrightExpectKind, is_promise_handled,
+        parse_playwright_expect,
+    },
+};
+
+fn missing_playwright_await_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{name}` must be awaited or returned"))
+        .with_help("Without `await`, the test finishes before the assertion or step runs.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct MissingPlaywrightAwaitConfig {
+    /// Custom asynchronous matchers, added with `expect.extend`, which must be awaited as well.
+    custom_matchers: Vec<CompactStr>,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct MissingPlaywrightAwait(Box<MissingPlaywrightAwaitConfig>);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require asynchronous Playwright APIs to be awaited or returned:
+    /// web-first assertions such as `expect(locator).toBeVisible()`, `expect.poll(...)`,
+    /// assertions with `.resolves` or `.rejects`, and `test.step(...)`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// These APIs return promises. If the promise is not awaited, the test continues without
+    /// waiting for the assertion, which then cannot fail the test, and may even run after the
+    /// test has finished.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// test('saves', async ({ page }) => {
+    ///   expect(page.getByText('Saved')).toBeVisible();
+    ///   expect.poll(() => getStatus()).toBe('done');
+    ///   test.step('log out', async () => {});
+    /// });
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// test('saves', async ({ page }) => {
+    ///   await expect(page.getByText('Saved')).toBeVisible();
+    ///   await expect.poll(() => getStatus()).toBe('done');
+    ///   await test.step('log out', async () => {});
+    /// });
+    /// ```
+    MissingPlaywrightAwait,
+    playwright,
+    correctness,
+    fix,
+    config = MissingPlaywrightAwaitConfig,
+    version = "next",
+    short_description = "Require asynchronous Playwright APIs to be awaited or returned.",
+);
+
+impl Rule for MissingPlaywrightAwait {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        DefaultRuleConfig::<Self>::from_value(value).map(DefaultRuleConfig::into_inner)
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Some(name) = self.get_async_call_name(call) else {
+            return;
+        };
+        if is_promise_handled(node, ctx) {
+            return;
+        }
+        ctx.diagnostic_with_fix(missing_playwright_await_diagnostic(call.span, name), |fixer| {
+            fixer.insert_text_before_range(call.span, "await ")
+        });
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.frameworks().is_playwright()
+    }
+}
+
+impl MissingPlaywrightAwait {
+    /// Returns the name of the asynchronous API called by `call`, such as `toBeVisible`,
+    /// `expect.poll` or `test.step`.
+    fn get_async_call_name<'a>(&self, call: &CallExpression<'a>) -> Option<&'a str> {
+        if let Some(member) = call.callee.get_member_expr()
+            && member.object().is_specific_id("test")
+            && member.static_property_name() == Some("step")
+        {
+            return Some("test.step");
+        }
+        let expect = parse_playwright_expect(call)?;
+        if expect.kind == PlaywrightExpectKind::Poll {
+            return Some("expect.poll");
+        }
+        let is_async = expect.is_promise_assertion
+            || PLAYWRIGHT_ASYNC_MATCHERS.contains(&expect.matcher)
+            || self.0.custom_matchers.iter().any(|matcher| matcher == expect.matcher);
+        is_async.then_some(expect.matcher)
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("async () => { await expect(page.getByText('Saved')).toBeVisible(); }", None),
+        ("async () => { await expect(page).not.toHaveURL('/login'); }", None),
+        ("async () => { await expect.soft(locator).toHaveText('Ada'); }", None),
+        ("async () => { await expect.poll(() => getStatus()).toBe('done'); }", None),
+        ("async () => { await expect(promise).resolves.toBe(1); }", None),
+        ("async () => { await test.step('log in', async () => {}); }", None),
+        ("() => expect(locator).toBeVisible()", None),
+        ("() => { return expect(locator).toBeChecked(); }", None),
+        (
+            "async () => { await Promise.all([expect(a).toBeVisible(), expect(b).toBeHidden()]); }",
+            None,
+        ),
+        ("async () => { const assertion = expect(a).toBeVisible(); await assertion; }", None),
+        ("() => { expect(value).toBe(1); expect(list).toHaveLength(2); }", None),
+        ("() => { expect(locator).toBeFoo(); }", None),
+        (
+            "async () => { await expect(page).toHaveSnapshot(); }",
+            Some(json!([{ "customMatchers": ["toHaveSnapshot"] }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("() => { expect(page.getByText('Saved')).toBeVisible(); }", None),
+        ("() => { expect(page).not.toHaveURL('/login'); }", None),
+        ("() => { expect.soft(locator).toHaveText('Ada'); }", None),
+        ("() => { expect.poll(() => getStatus()).toBe('done'); }", None),
+        ("() => { expect(promise).rejects.toThrow(); }", None),
+        ("() => { test.step('log in', async () => {}); }", None),
+        ("() => { const assertion = expect(a).toBeVisible(); }", None),
+        (
+            "() => { expect(page).toHaveSnapshot(); }",
+            Some(json!([{ "customMatchers": ["toHaveSnapshot"] }])),
+        ),
+    ];
+
+    let fix = vec![
+        (
+            "async () => { expect(page.getByText('Saved')).toBeVisible(); }",
+            "async () => { await expect(page.getByText('Saved')).toBeVisible(); }",
+            None,
+        ),
+        (
+            "async () => { test.step('log in', async () => {}); }",
+            "async () => { await test.step('log in', async () => {}); }",
+            None,
+        ),
+    ];
+
+    Tester::new(MissingPlaywrightAwait::NAME, MissingPlaywrightAwait::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
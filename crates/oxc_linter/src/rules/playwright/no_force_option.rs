@@ -0,0 +1,133 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, Expression, MemberExpression, ObjectPropertyKind},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+};
+
+fn no_force_option_diagnostic(span: Span, method: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unexpected use of `force: true` in `{method}`"))
+        .with_help("Fix what makes the element not actionable, such as an overlay, instead of skipping the checks.")
+        .with_label(span)
+}
+
+/// Actions which accept the `force` option, to skip the actionability checks.
+const FORCE_ACTIONS: [&str; 11] = [
+    "check",
+    "click",
+    "dblclick",
+    "dragTo",
+    "fill",
+    "hover",
+    "selectOption",
+    "selectText",
+    "setChecked",
+    "tap",
+    "uncheck",
+];
+
+#[derive(Debug, Default, Clone)]
+pub struct NoForceOption;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow `force: true` in the options of actions, such as `click` and `fill`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `force` skips the checks that the element is visible, stable, enabled and receives
+    /// events. A user could not perform the action, so the test passes although the page
+    /// is broken, for example when the element is covered by an overlay.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// await page.getByRole('button').click({ force: true });
+    /// await page.locator('input').fill('Ada', { force: true });
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// await page.getByRole('button').click();
+    /// await page.locator('input').fill('Ada');
+    /// ```
+    NoForceOption,
+    playwright,
+    suspicious,
+    version = "next",
+    short_description = "Disallow `force: true` in the options of actions.",
+);
+
+impl Rule for NoForceOption {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Some(method) =
+            call.callee.get_member_expr().and_then(MemberExpression::static_property_name)
+        else {
+            return;
+        };
+        if !FORCE_ACTIONS.contains(&method) {
+            return;
+        }
+        for argument in &call.arguments {
+            let Argument::ObjectExpression(options) = argument else {
+                continue;
+            };
+            for property in &options.properties {
+                let ObjectPropertyKind::ObjectProperty(property) = property else {
+                    continue;
+                };
+                if property.key.is_specific_static_name("force")
+                    && matches!(&property.value, Expression::BooleanLiteral(value) if value.value)
+                {
+                    ctx.diagnostic(no_force_option_diagnostic(property.span, method));
+                }
+            }
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.frameworks().is_playwright()
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "await page.getByRole('button').click();",
+        "await page.locator('input').fill('Ada');",
+        "await page.locator('input').click({ force: false });",
+        "await page.locator('input').check({ timeout: 1000 });",
+        "await page.locator('input').dragTo(target, { trial: true });",
+        "await page.locator('input').press('Enter', { force: true });",
+        "await page.locator('input').click({ ...options });",
+        "await page.locator('input').click({ force });",
+    ];
+
+    let fail = vec![
+        "await page.getByRole('button').click({ force: true });",
+        "await page.locator('input').fill('Ada', { force: true });",
+        "await page.locator('a').dblclick({ delay: 100, force: true });",
+        "await page.locator('#item').dragTo(target, { force: true });",
+        "await page.locator('#menu').hover({ 'force': true });",
+        "await page.getByLabel('Agree').check({ force: true });",
+        "await page.getByLabel('Agree').setChecked(true, { force: true });",
+        "await page.locator('select').selectOption('blue', { force: true });",
+        "await page.locator('button').tap({ force: true });",
+    ];
+
+    Tester::new(NoForceOption::NAME, NoForceOption::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,118 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+};
+
+fn no_wait_for_timeout_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected use of `waitForTimeout`")
+        .with_help("Wait for a condition instead, such as `await expect(locator).toBeVisible()`.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoWaitForTimeout;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow `page.waitForTimeout()`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A fixed delay makes tests slow when it is too long, and flaky when it is too short,
+    /// such as on a loaded CI machine. Playwright's locators and web-first assertions
+    /// already wait until the page is ready.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// await page.click('#submit');
+    /// await page.waitForTimeout(1000);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// await page.click('#submit');
+    /// await expect(page.getByText('Saved')).toBeVisible();
+    /// ```
+    NoWaitForTimeout,
+    playwright,
+    correctness,
+    suggestion,
+    version = "next",
+    short_description = "Disallow `page.waitForTimeout()`.",
+);
+
+impl Rule for NoWaitForTimeout {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Some(member) = call.callee.as_member_expression() else {
+            return;
+        };
+        if member.static_property_name() != Some("waitForTimeout") {
+            return;
+        }
+        // `await page.waitForTimeout(1000);` can be removed as a whole.
+        let statement = ctx
+            .nodes()
+            .ancestors(node.id())
+            .find(|ancestor| !matches!(ancestor.kind(), AstKind::AwaitExpression(_)))
+            .filter(|ancestor| matches!(ancestor.kind(), AstKind::ExpressionStatement(_)));
+        match statement {
+            Some(statement) => ctx
+                .diagnostic_with_suggestion(no_wait_for_timeout_diagnostic(call.span), |fixer| {
+                    fixer.delete_range(statement.kind().span())
+                }),
+            None => ctx.diagnostic(no_wait_for_timeout_diagnostic(call.span)),
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.frameworks().is_playwright()
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "async function f() { await page.waitForLoadState(); }",
+        "async function f() { await page.waitForURL('/home'); }",
+        "async function f() { await page.waitForFunction(() => window.ready); }",
+        "async function f() { await expect(page.getByText('Saved')).toBeVisible(); }",
+        "function waitForTimeout() {} waitForTimeout(1000);",
+        "async function f() { await page['waitFor'](1000); }",
+    ];
+
+    let fail = vec![
+        "async function f() { await page.waitForTimeout(1000); }",
+        "async function f() { page.waitForTimeout(2000); }",
+        "async function f() { const done = page.waitForTimeout(1000); }",
+        "async function f() { await frame.waitForTimeout(500); }",
+        "async function f() { await page['waitForTimeout'](1000); }",
+        "test('saves', async ({ page }) => { await page.click('#submit'); await page.waitForTimeout(1000); });",
+    ];
+
+    let fix = vec![
+        (
+            "async function f() { await page.click('#submit'); await page.waitForTimeout(1000); }",
+            "async function f() { await page.click('#submit');  }",
+            None,
+        ),
+        ("async function f() { page.waitForTimeout(2000); }", "async function f() {  }", None),
+    ];
+
+    Tester::new(NoWaitForTimeout::NAME, NoWaitForTimeout::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
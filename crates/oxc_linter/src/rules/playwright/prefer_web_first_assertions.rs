@@ -0,0 +1,259 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, CallExpression, Expression},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::{PlaywrightExpect, PlaywrightExpectKind, parse_playwright_expect},
+};
+
+fn prefer_web_first_assertions_diagnostic(
+    span: Span,
+    method: &str,
+    matcher: &str,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Prefer `{matcher}()` over asserting the result of `{method}()`"))
+        .with_help(format!(
+            "`{matcher}()` retries until the locator matches, while `{method}()` reads the page once."
+        ))
+        .with_label(span)
+}
+
+/// Locator methods which return a boolean, and the matchers which assert them.
+const BOOLEAN_METHODS: [(&str, &str); 6] = [
+    ("isChecked", "toBeChecked"),
+    ("isDisabled", "toBeDisabled"),
+    ("isEditable", "toBeEditable"),
+    ("isEnabled", "toBeEnabled"),
+    ("isHidden", "toBeHidden"),
+    ("isVisible", "toBeVisible"),
+];
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferWebFirstAssertions;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Prefer web-first assertions, such as `expect(locator).toBeVisible()`, over assertions
+    /// on the awaited result of a locator method, such as
+    /// `expect(await locator.isVisible()).toBe(true)`.
+    ///
+    /// `isVisible()`, `isHidden()`, `isEnabled()`, `isDisabled()`, `isChecked()`,
+    /// `isEditable()`, `textContent()`, `innerText()`, `inputValue()` and `getAttribute()`
+    /// are checked.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The locator method reads the page once, so the assertion fails if the page has not
+    /// updated yet. Web-first assertions retry until the condition is met or the timeout
+    /// expires, and print the locator and its state when they fail.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// expect(await page.getByText('Saved').isVisible()).toBe(true);
+    /// expect(await page.locator('h1').textContent()).toBe('Welcome');
+    /// expect(await page.getByLabel('Name').inputValue()).not.toBe('');
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// await expect(page.getByText('Saved')).toBeVisible();
+    /// await expect(page.locator('h1')).toHaveText('Welcome');
+    /// await expect(page.getByLabel('Name')).not.toHaveValue('');
+    /// ```
+    PreferWebFirstAssertions,
+    playwright,
+    style,
+    fix,
+    version = "next",
+    short_description = "Prefer web-first assertions over assertions on the result of a locator method.",
+);
+
+impl Rule for PreferWebFirstAssertions {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Some(expect) = parse_playwright_expect(call) else {
+            return;
+        };
+        if expect.kind == PlaywrightExpectKind::Poll || expect.is_promise_assertion {
+            return;
+        }
+        let Some(Expression::AwaitExpression(actual)) =
+            expect.actual().map(Expression::get_inner_expression)
+        else {
+            return;
+        };
+        let Expression::CallExpression(method_call) = actual.argument.get_inner_expression() else {
+            return;
+        };
+        let Some(member) = method_call.callee.get_member_expr() else {
+            return;
+        };
+        let Some(method) = member.static_property_name() else {
+            return;
+        };
+        let Some(replacement) = get_web_first_assertion(method, method_call, &expect, ctx) else {
+            return;
+        };
+
+        ctx.diagnostic_with_fix(
+            prefer_web_first_assertions_diagnostic(call.span, method, replacement.matcher),
+            |fixer| {
+                let is_awaited =
+                    matches!(ctx.nodes().parent_kind(node.id()), AstKind::AwaitExpression(_));
+                let text = format!(
+                    "{}{}({}){}.{}({})",
+                    if is_awaited { "" } else { "await " },
+                    fixer.source_range(expect.expect_call.callee.span()),
+                    fixer.source_range(member.object().span()),
+                    if replacement.negated { ".not" } else { "" },
+                    replacement.matcher,
+                    replacement.arguments.join(", "),
+                );
+                fixer.replace(call.span, text)
+            },
+        );
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.frameworks().is_playwright()
+    }
+}
+
+/// A web-first assertion which replaces an assertion on the result of a locator method.
+struct WebFirstAssertion<'a> {
+    matcher: &'static str,
+    negated: bool,
+    arguments: Vec<&'a str>,
+}
+
+fn get_web_first_assertion<'a>(
+    method: &str,
+    method_call: &CallExpression<'a>,
+    expect: &PlaywrightExpect<'a, '_>,
+    ctx: &LintContext<'a>,
+) -> Option<WebFirstAssertion<'a>> {
+    let expected = match expect.matcher_arguments {
+        [] => None,
+        [argument] => Some(argument.as_expression()?),
+        _ => return None,
+    };
+
+    if let Some((_, matcher)) = BOOLEAN_METHODS.iter().find(|(name, _)| *name == method) {
+        let expected = match (expect.matcher, expected) {
+            ("toBe" | "toEqual" | "toStrictEqual", Some(Expression::BooleanLiteral(literal))) => {
+                literal.value
+            }
+            ("toBeTruthy", None) => true,
+            ("toBeFalsy", None) => false,
+            _ => return None,
+        };
+        return Some(WebFirstAssertion {
+            matcher,
+            negated: expect.negated == expected,
+            arguments: vec![],
+        });
+    }
+
+    let expected = ctx.source_range(expected?.span());
+    let (matcher, arguments) = match (method, expect.matcher) {
+        ("textContent" | "innerText", "toBe" | "toEqual" | "toStrictEqual" | "toMatch") => {
+            ("toHaveText", vec![expected])
+        }
+        ("textContent" | "innerText", "toContain") => ("toContainText", vec![expected]),
+        ("inputValue", "toBe" | "toEqual" | "toStrictEqual") => ("toHaveValue", vec![expected]),
+        ("getAttribute", "toBe" | "toEqual" | "toStrictEqual") => {
+            let [Argument::StringLiteral(name)] = method_call.arguments.as_slice() else {
+                return None;
+            };
+            ("toHaveAttribute", vec![ctx.source_range(name.span), expected])
+        }
+        _ => return None,
+    };
+    Some(WebFirstAssertion { matcher, negated: expect.negated, arguments })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "await expect(page.getByText('Saved')).toBeVisible();",
+        "await expect(page.locator('h1')).toHaveText('Welcome');",
+        "await expect(page.getByLabel('Name')).not.toHaveValue('');",
+        "expect(await page.title()).toBe('Home');",
+        "expect(await response.json()).toEqual({ ok: true });",
+        "expect(await page.locator('h1').textContent()).toBeDefined();",
+        "expect(await page.locator('a').getAttribute(name)).toBe('/home');",
+        "expect(page.locator('a').isVisible()).toBeTruthy();",
+        "const visible = await page.locator('a').isVisible(); expect(visible).toBe(true);",
+        "await expect.poll(async () => await page.locator('a').isVisible()).toBe(true);",
+        "expect(await page.locator('a').isVisible()).toBe(flag);",
+    ];
+
+    let fail = vec![
+        "expect(await page.getByText('Saved').isVisible()).toBe(true);",
+        "expect(await page.getByText('Saved').isVisible()).toBeTruthy();",
+        "expect(await page.getByRole('button').isEnabled()).toBe(false);",
+        "expect(await page.getByRole('button').isDisabled()).not.toBe(true);",
+        "expect(await page.getByLabel('Agree').isChecked()).toBeFalsy();",
+        "expect.soft(await page.locator('dialog').isHidden()).toEqual(true);",
+        "expect(await page.locator('h1').textContent()).toBe('Welcome');",
+        "expect(await page.locator('h1').innerText()).toContain('Welcome');",
+        "expect(await page.getByLabel('Name').inputValue()).not.toBe('');",
+        "expect(await page.locator('a').getAttribute('href')).toBe('/home');",
+    ];
+
+    let fix = vec![
+        (
+            "expect(await page.getByText('Saved').isVisible()).toBe(true);",
+            "await expect(page.getByText('Saved')).toBeVisible();",
+            None,
+        ),
+        (
+            "expect(await page.getByRole('button').isEnabled()).toBe(false);",
+            "await expect(page.getByRole('button')).not.toBeEnabled();",
+            None,
+        ),
+        (
+            "expect(await page.getByRole('button').isDisabled()).not.toBe(false);",
+            "await expect(page.getByRole('button')).toBeDisabled();",
+            None,
+        ),
+        (
+            "expect.soft(await locator.isHidden()).toBeTruthy();",
+            "await expect.soft(locator).toBeHidden();",
+            None,
+        ),
+        (
+            "expect(await page.locator('h1').innerText()).toContain('Welcome');",
+            "await expect(page.locator('h1')).toContainText('Welcome');",
+            None,
+        ),
+        (
+            "expect(await page.getByLabel('Name').inputValue()).not.toBe('');",
+            "await expect(page.getByLabel('Name')).not.toHaveValue('');",
+            None,
+        ),
+        (
+            "expect(await page.locator('a').getAttribute('href')).toBe('/home');",
+            "await expect(page.locator('a')).toHaveAttribute('href', '/home');",
+            None,
+        ),
+    ];
+
+    Tester::new(PreferWebFirstAssertions::NAME, PreferWebFirstAssertions::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
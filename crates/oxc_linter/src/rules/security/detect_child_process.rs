@@ -0,0 +1,107 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{NodeApiKind, collect_node_api_references, is_constant_value},
+};
+
+fn detect_child_process_diagnostic(span: Span, api: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{api}` called with a command which is not a constant"))
+        .with_help("The command is run by a shell, so input such as `; rm -rf ~` runs other commands. Pass the arguments in an array to `execFile` or `spawn` instead.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DetectChildProcess;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow `exec` and `execSync` of `child_process` with a command which is not a
+    /// constant, such as a template literal with expressions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `exec` runs the command with a shell. If any part of the command comes from the
+    /// user, they can add shell syntax such as `;`, `&&` or `$(...)` to run other commands
+    /// (command injection). `execFile` and `spawn` pass the arguments to the program
+    /// without a shell.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// import { exec } from 'node:child_process';
+    /// exec(`git log ${branch}`, callback);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import { execFile } from 'node:child_process';
+    /// execFile('git', ['log', branch], callback);
+    /// ```
+    DetectChildProcess,
+    security,
+    suspicious,
+    version = "next",
+    short_description = "Disallow `child_process.exec` with a command which is not a constant.",
+);
+
+impl Rule for DetectChildProcess {
+    fn run_once(&self, ctx: &LintContext) {
+        for reference in collect_node_api_references(&[], ctx) {
+            if reference.kind != NodeApiKind::Module
+                || !matches!(
+                    reference.path.as_str(),
+                    "child_process.exec" | "child_process.execSync"
+                )
+            {
+                continue;
+            }
+            let AstKind::CallExpression(call) = ctx.nodes().parent_kind(reference.node_id) else {
+                continue;
+            };
+            if call.callee.span() != reference.span {
+                continue;
+            }
+            let Some(command) = call.arguments.first() else {
+                continue;
+            };
+            if command.as_expression().is_some_and(|command| is_constant_value(command, ctx)) {
+                continue;
+            }
+            ctx.diagnostic(detect_child_process_diagnostic(command.span(), &reference.path));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "import { exec } from 'child_process'; exec('git status', cb);",
+        "import { exec } from 'child_process'; const command = 'ls -la'; exec(command);",
+        "import { execFile } from 'child_process'; execFile('git', ['log', branch], cb);",
+        "import { spawn } from 'node:child_process'; spawn('ls', [dir]);",
+        "const cp = require('child_process'); cp.execSync(`npm run ${'build'}`);",
+        "exec(`git log ${branch}`);",
+        "import { exec } from './shell'; exec(`git log ${branch}`);",
+    ];
+
+    let fail = vec![
+        "import { exec } from 'child_process'; exec(`git log ${branch}`, cb);",
+        "import { execSync } from 'node:child_process'; execSync('rm -rf ' + dir);",
+        "const cp = require('child_process'); cp.exec(req.query.command);",
+        "const { exec } = require('child_process'); exec(command);",
+        "import * as childProcess from 'child_process'; childProcess.execSync(`convert ${file} out.png`);",
+        "import cp from 'child_process'; let command = 'ls'; command += dir; cp.exec(command);",
+    ];
+
+    Tester::new(DetectChildProcess::NAME, DetectChildProcess::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
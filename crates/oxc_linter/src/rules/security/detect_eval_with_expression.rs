@@ -0,0 +1,114 @@
+use oxc_ast::{AstKind, ast::Expression};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{AstNode, context::LintContext, rule::Rule, utils::is_constant_value};
+
+fn detect_eval_with_expression_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{name}` called with code which is not a constant"))
+        .with_help("Code built from user input can run anything with the permissions of the page or process.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DetectEvalWithExpression;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow calling `eval`, `Function` and `new Function` with code which is not a
+    /// constant, such as a variable or a template literal with expressions.
+    ///
+    /// Variables which are never reassigned, and are initialized with a constant, are
+    /// allowed.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// If any part of the code comes from the user, such as a query parameter or a request
+    /// body, the user can run arbitrary code with the permissions of the page or process.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// eval(req.body.expression);
+    /// const getter = new Function('obj', `return obj.${field}`);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// eval('1 + 1');
+    /// const getter = (obj) => obj[field];
+    /// ```
+    DetectEvalWithExpression,
+    security,
+    suspicious,
+    version = "next",
+    short_description = "Disallow `eval` and `Function` with code which is not a constant.",
+);
+
+impl Rule for DetectEvalWithExpression {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (callee, arguments, span) = match node.kind() {
+            AstKind::CallExpression(call) => (&call.callee, &call.arguments, call.span),
+            AstKind::NewExpression(new) => (&new.callee, &new.arguments, new.span),
+            _ => return,
+        };
+        let Expression::Identifier(ident) = callee.get_inner_expression() else {
+            return;
+        };
+        // `eval` only runs its first argument, while `Function` takes the names of the
+        // parameters before the body.
+        let code = match ident.name.as_str() {
+            "eval" if matches!(node.kind(), AstKind::CallExpression(_)) => {
+                &arguments[..arguments.len().min(1)]
+            }
+            "Function" => &arguments[..],
+            _ => return,
+        };
+        if !ctx.is_reference_to_global_variable(ident) {
+            return;
+        }
+        if code.iter().all(|argument| {
+            argument.as_expression().is_some_and(|expression| is_constant_value(expression, ctx))
+        }) {
+            return;
+        }
+        ctx.diagnostic(detect_eval_with_expression_diagnostic(span, &ident.name));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "eval('1 + 1');",
+        "eval(`1 + ${2}`);",
+        "const code = 'x + 1'; eval(code);",
+        "eval();",
+        "new Function('a', 'b', 'return a + b');",
+        "Function('return this')();",
+        "function eval(code) {} eval(input);",
+        "const Function = class {}; new Function(body);",
+        "obj.eval(input);",
+        "new eval(input);",
+    ];
+
+    let fail = vec![
+        "eval(input);",
+        "eval(req.body.expression);",
+        "eval(`return ${field}`);",
+        "eval('return ' + field);",
+        "let code = 'x'; code = input; eval(code);",
+        "const code = input; eval(code);",
+        "new Function('obj', `return obj.${field}`);",
+        "Function(body)();",
+        "new Function(...parts);",
+        "eval(input, 'ignored');",
+    ];
+
+    Tester::new(DetectEvalWithExpression::NAME, DetectEvalWithExpression::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
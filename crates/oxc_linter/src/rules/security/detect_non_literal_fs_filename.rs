@@ -0,0 +1,140 @@
+use oxc_ast::{AstKind, ast::Expression};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{NodeApiKind, collect_node_api_references, fs_path_arguments, is_constant_value},
+};
+
+fn detect_non_literal_fs_filename_diagnostic(span: Span, api: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{api}` called with a path which is not a constant"))
+        .with_help("A path from user input can reach any file which the process can access, for example with `../`.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DetectNonLiteralFsFilename;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow calling the functions of `fs` and `fs/promises`, such as `readFile`, with
+    /// paths which are not constants.
+    ///
+    /// Paths built with `path.join` or `path.resolve` from constants, `__dirname` and
+    /// `__filename` are allowed.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// If the user can choose any part of a path, they can read, overwrite or delete other
+    /// files which the process can access, such as `../../.env` (path traversal). Check
+    /// that a path built from input stays within the expected directory.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// import fs from 'node:fs';
+    /// fs.readFileSync(req.query.file);
+    /// fs.createReadStream(`uploads/${name}`);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import fs from 'node:fs';
+    /// import path from 'node:path';
+    /// fs.readFileSync(path.join(__dirname, 'config.json'));
+    /// ```
+    DetectNonLiteralFsFilename,
+    security,
+    restriction,
+    version = "next",
+    short_description = "Disallow `fs` functions with paths which are not constants.",
+);
+
+impl Rule for DetectNonLiteralFsFilename {
+    fn run_once(&self, ctx: &LintContext) {
+        for reference in collect_node_api_references(&[], ctx) {
+            if reference.kind != NodeApiKind::Module {
+                continue;
+            }
+            let Some(positions) = fs_path_arguments(&reference.path) else {
+                continue;
+            };
+            let AstKind::CallExpression(call) = ctx.nodes().parent_kind(reference.node_id) else {
+                continue;
+            };
+            if call.callee.span() != reference.span {
+                continue;
+            }
+            for &position in positions {
+                let Some(argument) = call.arguments.get(position) else {
+                    continue;
+                };
+                if argument.as_expression().is_some_and(|path| is_constant_path(path, ctx)) {
+                    continue;
+                }
+                ctx.diagnostic(detect_non_literal_fs_filename_diagnostic(
+                    argument.span(),
+                    &reference.path,
+                ));
+            }
+        }
+    }
+}
+
+/// Whether `expression` is a constant, or joins constants with `path.join` or `path.resolve`.
+fn is_constant_path<'a>(expression: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+    if is_constant_value(expression, ctx) {
+        return true;
+    }
+    let Expression::CallExpression(call) = expression.get_inner_expression() else {
+        return false;
+    };
+    let Expression::StaticMemberExpression(member) = &call.callee else {
+        return false;
+    };
+    member.object.is_specific_id("path")
+        && matches!(member.property.name.as_str(), "join" | "resolve" | "normalize")
+        && call.arguments.iter().all(|argument| {
+            argument.as_expression().is_some_and(|argument| is_constant_path(argument, ctx))
+        })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "import fs from 'fs'; fs.readFileSync('config.json');",
+        "import fs from 'node:fs'; fs.readFile(`${__dirname}/config.json`, cb);",
+        "import fs from 'fs'; import path from 'path'; fs.readFileSync(path.join(__dirname, 'a.json'));",
+        "const fs = require('fs'); const file = './data.json'; fs.writeFile(file, data, cb);",
+        "const fs = require('fs'); fs.writeFile('out.txt', req.body, cb);",
+        "import { readFile } from 'fs/promises'; await readFile(flag ? 'a.txt' : 'b.txt');",
+        "import fs from 'fs'; fs.copyFile('a.txt', 'b.txt', cb);",
+        "import fs from 'fs'; fs.read(fd, buffer);",
+        "import fs from 'fs'; fs.createReadStream;",
+        "readFile(input);",
+        "const fs = require('graceful'); fs.readFile(input);",
+    ];
+
+    let fail = vec![
+        "import fs from 'fs'; fs.readFileSync(req.query.file);",
+        "import fs from 'node:fs'; fs.createReadStream(`uploads/${name}`);",
+        "const fs = require('fs'); fs.unlink(path, cb);",
+        "const { readFile } = require('fs'); readFile('./dir/' + name, cb);",
+        "import { promises } from 'fs'; await promises.rm(dir);",
+        "import * as fsp from 'node:fs/promises'; await fsp.writeFile(target, data);",
+        "import { mkdir } from 'fs/promises'; await mkdir(dir, { recursive: true });",
+        "import fs from 'fs'; fs.rename('a.txt', target, cb);",
+        "import fs from 'fs'; import path from 'path'; fs.existsSync(path.join(root, name));",
+        "const fs = require('fs'); fs.promises.readFile(file);",
+    ];
+
+    Tester::new(DetectNonLiteralFsFilename::NAME, DetectNonLiteralFsFilename::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
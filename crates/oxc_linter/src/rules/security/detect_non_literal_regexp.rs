@@ -0,0 +1,97 @@
+use oxc_ast::{AstKind, ast::Expression};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNode, context::LintContext, rule::Rule, utils::is_constant_value};
+
+fn detect_non_literal_regexp_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("`RegExp` created from a pattern which is not a constant")
+        .with_help("A pattern from user input can take exponential time to match, which blocks the event loop.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DetectNonLiteralRegexp;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow `RegExp` and `new RegExp` with a pattern which is not a constant.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A pattern such as `(a+)+$` takes exponential time to match some strings. If the
+    /// user can choose the pattern, they can block the event loop of a server for minutes
+    /// with a single request (ReDoS). Escape the input when it should be matched literally.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const matcher = new RegExp(req.query.search);
+    /// const matcher = RegExp(`^${prefix}`);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const matcher = new RegExp('^[a-z]+$', 'i');
+    /// const matcher = /^[a-z]+$/i;
+    /// ```
+    DetectNonLiteralRegexp,
+    security,
+    restriction,
+    version = "next",
+    short_description = "Disallow `RegExp` with a pattern which is not a constant.",
+);
+
+impl Rule for DetectNonLiteralRegexp {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (callee, arguments) = match node.kind() {
+            AstKind::CallExpression(call) => (&call.callee, &call.arguments),
+            AstKind::NewExpression(new) => (&new.callee, &new.arguments),
+            _ => return,
+        };
+        let Expression::Identifier(ident) = callee.get_inner_expression() else {
+            return;
+        };
+        if ident.name != "RegExp" || !ctx.is_reference_to_global_variable(ident) {
+            return;
+        }
+        let Some(pattern) = arguments.first() else {
+            return;
+        };
+        if pattern.as_expression().is_some_and(|pattern| is_constant_value(pattern, ctx)) {
+            return;
+        }
+        ctx.diagnostic(detect_non_literal_regexp_diagnostic(pattern.span()));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "new RegExp('^[a-z]+$', 'i');",
+        "RegExp(`^foo`);",
+        "new RegExp(/foo/, flags);",
+        "const pattern = '^foo'; new RegExp(pattern);",
+        "const prefix = 'foo'; new RegExp(`^${prefix}-bar`);",
+        "new RegExp();",
+        "class RegExp {} new RegExp(input);",
+        "new MyRegExp(input);",
+    ];
+
+    let fail = vec![
+        "new RegExp(req.query.search);",
+        "RegExp(`^${prefix}`);",
+        "new RegExp('^' + input + '$');",
+        "let pattern = 'foo'; pattern = input; new RegExp(pattern);",
+        "function matcher(pattern) { return new RegExp(pattern, 'g'); }",
+        "new RegExp(...args);",
+    ];
+
+    Tester::new(DetectNonLiteralRegexp::NAME, DetectNonLiteralRegexp::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
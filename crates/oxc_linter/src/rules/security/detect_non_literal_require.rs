@@ -0,0 +1,95 @@
+use oxc_ast::{AstKind, ast::Expression};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNode, context::LintContext, rule::Rule, utils::is_constant_value};
+
+fn detect_non_literal_require_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("`require` called with a module which is not a constant")
+        .with_help(
+            "A module path from user input can load and run any file which the process can read.",
+        )
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DetectNonLiteralRequire;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow `require` with a module which is not a constant.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `require` runs the module that it loads. If the user can choose any part of the
+    /// path, they can run other files of the project or of `node_modules`, or read JSON
+    /// files which should stay private.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const handler = require(`./handlers/${req.params.name}`);
+    /// const locale = require(localePath);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const handlers = { create: require('./handlers/create') };
+    /// const handler = handlers[req.params.name];
+    /// ```
+    DetectNonLiteralRequire,
+    security,
+    restriction,
+    version = "next",
+    short_description = "Disallow `require` with a module which is not a constant.",
+);
+
+impl Rule for DetectNonLiteralRequire {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Expression::Identifier(ident) = call.callee.get_inner_expression() else {
+            return;
+        };
+        if ident.name != "require" || !ctx.is_reference_to_global_variable(ident) {
+            return;
+        }
+        let Some(module) = call.arguments.first() else {
+            return;
+        };
+        if module.as_expression().is_some_and(|module| is_constant_value(module, ctx)) {
+            return;
+        }
+        ctx.diagnostic(detect_non_literal_require_diagnostic(module.span()));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "require('fs');",
+        "require(`./handlers/create`);",
+        "const name = './config'; require(name);",
+        "require(__dirname + '/config.json');",
+        "require();",
+        "function require(name) {} require(name);",
+        "import(name);",
+    ];
+
+    let fail = vec![
+        "require(`./handlers/${req.params.name}`);",
+        "require(localePath);",
+        "require('./locales/' + locale);",
+        "const path = getPath(); require(path);",
+        "require(...modules);",
+    ];
+
+    Tester::new(DetectNonLiteralRequire::NAME, DetectNonLiteralRequire::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -0,0 +1,145 @@
+use oxc_ast::{AstKind, ast::Expression};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{AstNode, context::LintContext, rule::Rule, utils::is_constant_value};
+
+fn detect_possible_timing_attacks_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{name}` is compared with an operator which is not timing-safe"))
+        .with_help("The comparison stops at the first character which differs, so the time it takes reveals how much of the value was guessed. Use `crypto.timingSafeEqual` instead.")
+        .with_label(span)
+}
+
+/// Words which name secrets in variables and properties, such as `passwordHash` or `api_token`.
+const SECRET_WORDS: [&str; 8] =
+    ["hash", "hmac", "passwd", "password", "secret", "signature", "token", "apikey"];
+
+#[derive(Debug, Default, Clone)]
+pub struct DetectPossibleTimingAttacks;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow comparing secrets, such as passwords, tokens and signatures, with `==`,
+    /// `===`, `!=` or `!==`.
+    ///
+    /// A value is considered a secret when its variable or property is named after one,
+    /// such as `password`, `apiKey` or `session.token`. Comparisons with constants, such
+    /// as `token === undefined`, are allowed.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// String comparison returns as soon as a character differs. By measuring how long
+    /// many requests take, an attacker can guess a secret one character at a time.
+    /// `crypto.timingSafeEqual` takes the same time however many characters match.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// if (req.headers.token === apiToken) {}
+    /// if (user.passwordHash !== hash(input)) {}
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import { timingSafeEqual } from 'node:crypto';
+    /// if (timingSafeEqual(Buffer.from(req.headers.token), Buffer.from(apiToken))) {}
+    /// if (token === undefined) {}
+    /// ```
+    DetectPossibleTimingAttacks,
+    security,
+    restriction,
+    version = "next",
+    short_description = "Disallow comparing secrets with operators which are not timing-safe.",
+);
+
+impl Rule for DetectPossibleTimingAttacks {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::BinaryExpression(binary) = node.kind() else {
+            return;
+        };
+        if !binary.operator.is_equality() {
+            return;
+        }
+        let Some(name) = secret_name(&binary.left).or_else(|| secret_name(&binary.right)) else {
+            return;
+        };
+        if is_constant_value(&binary.left, ctx) || is_constant_value(&binary.right, ctx) {
+            return;
+        }
+        ctx.diagnostic(detect_possible_timing_attacks_diagnostic(binary.span, name));
+    }
+}
+
+/// The name of the variable or property read by `expression`, if it is named after a secret.
+fn secret_name<'a>(expression: &Expression<'a>) -> Option<&'a str> {
+    let name = match expression.get_inner_expression() {
+        Expression::Identifier(ident) => ident.name.as_str(),
+        Expression::StaticMemberExpression(member) => member.property.name.as_str(),
+        _ => return None,
+    };
+    let words = split_words(name);
+    let is_secret = words.iter().any(|word| SECRET_WORDS.contains(&word.as_str()))
+        // Two words can name a secret together, as in `apiKey`.
+        || words.windows(2).any(|pair| SECRET_WORDS.contains(&pair.concat().as_str()));
+    is_secret.then_some(name)
+}
+
+/// Split a camelCase, PascalCase or snake_case name into lowercase words.
+fn split_words(name: &str) -> Vec<String> {
+    let mut words = vec![];
+    let mut word = String::new();
+    let mut is_previous_uppercase = false;
+    for c in name.chars() {
+        let is_uppercase = c.is_ascii_uppercase();
+        if ((is_uppercase && !is_previous_uppercase) || !c.is_ascii_alphanumeric())
+            && !word.is_empty()
+        {
+            words.push(std::mem::take(&mut word));
+        }
+        if c.is_ascii_alphanumeric() {
+            word.push(c.to_ascii_lowercase());
+        }
+        is_previous_uppercase = is_uppercase;
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "if (token === undefined) {}",
+        "if (password == null) {}",
+        "if (typeof secret !== 'string') {}",
+        "if (user.passwordHash === '') {}",
+        "if (token.length === 32) {}",
+        "if (location.hash === '#top') {}",
+        "if (hashtag === other) {}",
+        "if (tokenizer === parser) {}",
+        "if (a === b) {}",
+        "if (password > other) {}",
+        "if (crypto.timingSafeEqual(Buffer.from(token), Buffer.from(expected))) {}",
+    ];
+
+    let fail = vec![
+        "if (req.headers.token === apiToken) {}",
+        "if (user.passwordHash !== hash(input)) {}",
+        "if (password == input) {}",
+        "if (input === secret) {}",
+        "const valid = signature === computeSignature(body);",
+        "if (api_key !== req.query.key) {}",
+        "if (apiKey === provided) {}",
+        "if (HMAC != digest) {}",
+        "if (SESSION_TOKEN === cookie) {}",
+    ];
+
+    Tester::new(DetectPossibleTimingAttacks::NAME, DetectPossibleTimingAttacks::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
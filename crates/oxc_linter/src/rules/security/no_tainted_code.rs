@@ -0,0 +1,166 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, Expression},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        DEFAULT_TAINT_SOURCES, NodeApiKind, TaintAnalysis, TaintConfig, TaintFlow,
+        collect_node_api_references,
+    },
+};
+
+fn no_tainted_code_diagnostic(span: Span, function: &str, flow: &TaintFlow) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("User input flows into the code run by `{function}`"))
+        .with_help("Code built from user input can run anything with the permissions of the page or process. Parse the input as data, such as with `JSON.parse`, instead.")
+        .with_labels(flow.labels(span, "runs as code"))
+}
+
+/// Functions of `vm` whose first argument is the code.
+const VM_FUNCTIONS: [&str; 5] = [
+    "vm.Script",
+    "vm.compileFunction",
+    "vm.runInContext",
+    "vm.runInNewContext",
+    "vm.runInThisContext",
+];
+
+#[derive(Debug, Default, Clone)]
+pub struct NoTaintedCode;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow running code built from user input, such as `req.body`, `location.hash` or
+    /// `document.cookie`, with `eval`, `Function`, `setTimeout` and `setInterval` with a
+    /// string, and the functions of `vm`.
+    ///
+    /// Unlike `security/detect-eval-with-expression`, the rule follows the input through
+    /// variables, destructuring, template literals and concatenation within a function, and
+    /// only reports code which contains it. The diagnostic shows the path of the input from
+    /// its source to the code.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// If any part of the code comes from the user, they can run arbitrary code with the
+    /// permissions of the page or process.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const expression = location.hash.slice(1);
+    /// const result = eval(expression);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const result = JSON.parse(decodeURIComponent(location.hash.slice(1)));
+    /// ```
+    NoTaintedCode,
+    security,
+    suspicious,
+    version = "next",
+    short_description = "Disallow running code built from user input.",
+);
+
+impl Rule for NoTaintedCode {
+    fn run_once(&self, ctx: &LintContext) {
+        let mut analysis = TaintAnalysis::new(
+            ctx,
+            TaintConfig { sources: &DEFAULT_TAINT_SOURCES, sanitizers: &[] },
+        );
+        for node in ctx.nodes() {
+            let (callee, arguments) = match node.kind() {
+                AstKind::CallExpression(call) => (&call.callee, &call.arguments),
+                AstKind::NewExpression(new) => (&new.callee, &new.arguments),
+                _ => continue,
+            };
+            let Expression::Identifier(ident) = callee.get_inner_expression() else {
+                continue;
+            };
+            let is_call = matches!(node.kind(), AstKind::CallExpression(_));
+            // `Function` takes the names of the parameters before the body.
+            let code = match ident.name.as_str() {
+                "eval" | "setTimeout" | "setInterval" if is_call => {
+                    &arguments[..arguments.len().min(1)]
+                }
+                "Function" => &arguments[..],
+                _ => continue,
+            };
+            if !ctx.is_reference_to_global_variable(ident) {
+                continue;
+            }
+            report_tainted_code(&mut analysis, code, &ident.name, ctx);
+        }
+        for reference in collect_node_api_references(&[], ctx) {
+            if reference.kind != NodeApiKind::Module
+                || !VM_FUNCTIONS.contains(&reference.path.as_str())
+            {
+                continue;
+            }
+            let (callee, arguments) = match ctx.nodes().parent_kind(reference.node_id) {
+                AstKind::CallExpression(call) => (&call.callee, &call.arguments),
+                AstKind::NewExpression(new) => (&new.callee, &new.arguments),
+                _ => continue,
+            };
+            if callee.span() != reference.span {
+                continue;
+            }
+            let code = &arguments[..arguments.len().min(1)];
+            report_tainted_code(&mut analysis, code, &reference.path, ctx);
+        }
+    }
+}
+
+fn report_tainted_code<'a>(
+    analysis: &mut TaintAnalysis<'_, 'a>,
+    code: &[Argument<'a>],
+    function: &str,
+    ctx: &LintContext<'a>,
+) {
+    for argument in code {
+        let Some(expression) = argument.as_expression() else {
+            continue;
+        };
+        if let Some(flow) = analysis.taint_of(expression) {
+            ctx.diagnostic(no_tainted_code_diagnostic(argument.span(), function, &flow));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "eval(input);",
+        "eval('1 + 1');",
+        "const expression = location.hash.slice(1); JSON.parse(expression);",
+        "setTimeout(() => render(location.hash), 100);",
+        "const delay = Number(req.query.delay); setTimeout(update, delay);",
+        "function eval(code) {} eval(req.body.code);",
+        "new Function('a', 'b', 'return a + b');",
+        "import vm from './vm'; vm.runInThisContext(req.body.code);",
+        "let code = req.body.code; code = 'return 1'; new Function(code);",
+        "const valid = /^\\d+$/.test(req.query.id); eval(`check(${valid})`);",
+    ];
+
+    let fail = vec![
+        "eval(req.body.code);",
+        "const expression = location.hash.slice(1); eval(expression);",
+        "const { formula } = req.body; const fn = new Function('x', `return ${formula}`);",
+        "setTimeout('update(' + document.cookie + ')', 100);",
+        "setInterval(`poll('${window.name}')`);",
+        "import vm from 'node:vm'; vm.runInNewContext(req.query.script, sandbox);",
+        "const vm = require('vm'); const script = new vm.Script(process.argv[2]);",
+        "let code = ''; for (const line of req.body.lines) { code += line; } eval(code);",
+    ];
+
+    Tester::new(NoTaintedCode::NAME, NoTaintedCode::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,142 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        DEFAULT_TAINT_SOURCES, NodeApiKind, TaintAnalysis, TaintConfig, TaintFlow,
+        collect_node_api_references,
+    },
+};
+
+fn no_tainted_command_diagnostic(span: Span, api: &str, flow: &TaintFlow) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("User input flows into the command of `{api}`"))
+        .with_help("Input such as `; rm -rf ~` runs other commands. Pass the input as a separate argument to `execFile` or `spawn` without a shell, or check it against a list of allowed values.")
+        .with_labels(flow.labels(span, "runs as a command"))
+}
+
+/// Functions of `child_process` whose first argument is the command.
+const COMMAND_FUNCTIONS: [&str; 6] = [
+    "child_process.exec",
+    "child_process.execFile",
+    "child_process.execFileSync",
+    "child_process.execSync",
+    "child_process.spawn",
+    "child_process.spawnSync",
+];
+
+const SANITIZERS: [&str; 3] = ["escapeShellArg", "quote", "shellEscape"];
+
+#[derive(Debug, Default, Clone)]
+pub struct NoTaintedCommand;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow running commands of `child_process`, such as `exec` and `spawn`, which are
+    /// built from user input, such as `req.query`, `process.argv` or `location.hash`.
+    ///
+    /// Unlike `security/detect-child-process`, the rule follows the input through
+    /// variables, destructuring, template literals and concatenation within a function, and
+    /// only reports commands which contain it. The diagnostic shows the path of the input
+    /// from its source to the command. Values passed to `escapeShellArg`, `shellEscape` or
+    /// `quote` are allowed.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// If any part of the command comes from the user, they can add shell syntax such as
+    /// `;`, `&&` or `$(...)` to run other commands (command injection), or choose which
+    /// program runs.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// import { exec } from 'node:child_process';
+    /// app.get('/log', (req, res) => {
+    ///   const { branch } = req.query;
+    ///   exec(`git log ${branch}`, callback);
+    /// });
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import { execFile } from 'node:child_process';
+    /// app.get('/log', (req, res) => {
+    ///   execFile('git', ['log', req.query.branch], callback);
+    /// });
+    /// ```
+    NoTaintedCommand,
+    security,
+    suspicious,
+    version = "next",
+    short_description = "Disallow running commands built from user input.",
+);
+
+impl Rule for NoTaintedCommand {
+    fn run_once(&self, ctx: &LintContext) {
+        let mut analysis = TaintAnalysis::new(
+            ctx,
+            TaintConfig { sources: &DEFAULT_TAINT_SOURCES, sanitizers: &SANITIZERS },
+        );
+        for reference in collect_node_api_references(&[], ctx) {
+            if reference.kind != NodeApiKind::Module
+                || !COMMAND_FUNCTIONS.contains(&reference.path.as_str())
+            {
+                continue;
+            }
+            let AstKind::CallExpression(call) = ctx.nodes().parent_kind(reference.node_id) else {
+                continue;
+            };
+            if call.callee.span() != reference.span {
+                continue;
+            }
+            let Some(command) = call.arguments.first().and_then(|arg| arg.as_expression()) else {
+                continue;
+            };
+            if let Some(flow) = analysis.taint_of(command) {
+                ctx.diagnostic(no_tainted_command_diagnostic(
+                    command.span(),
+                    &reference.path,
+                    &flow,
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "import { exec } from 'child_process'; exec(`git log ${branch}`, cb);",
+        "import { execFile } from 'child_process'; execFile('git', ['log', req.query.branch], cb);",
+        "import { exec } from 'child_process'; let dir = req.query.dir; dir = 'logs'; exec(`ls ${dir}`);",
+        "import { exec } from 'child_process'; exec(`ls ${escapeShellArg(req.query.dir)}`);",
+        "import { exec } from 'child_process'; exec(`head -n ${Number(req.query.lines)} log.txt`);",
+        "import { exec } from 'child_process'; exec(`echo ${req.query.name.length}`);",
+        "import { exec } from 'child_process'; const ok = req.query.name.startsWith('a'); exec(`echo ${ok}`);",
+        "import { exec } from './shell'; exec(req.query.command);",
+        "import { exec } from 'child_process'; function run(req) { const cmd = 'ls'; exec(cmd); }",
+        "import { exec } from 'child_process'; const { length } = process.argv; exec(`echo ${typeof length}`);",
+    ];
+
+    let fail = vec![
+        "import { exec } from 'child_process'; exec(req.query.command);",
+        "import { exec } from 'child_process'; app.get('/', (req, res) => { const { dir } = req.query; exec(`ls ${dir}`); });",
+        "const cp = require('child_process'); const args = process.argv.slice(2); cp.execSync('convert ' + args.join(' '));",
+        "import { spawn } from 'node:child_process'; spawn(req.body.program, ['--version']);",
+        "import { exec } from 'child_process'; let command = 'ls '; command += req.params.dir; exec(command);",
+        "import { exec } from 'child_process'; let dir = 'logs'; if (admin) { dir = req.query.dir; } exec(`ls ${dir}`);",
+        "import { exec } from 'child_process'; const name = location.hash.slice(1).trim(); exec(name || 'ls');",
+        "import { exec } from 'child_process'; for (const file of req.body.files) { exec(`rm ${file}`); }",
+        "import { exec } from 'child_process'; let target; ({ target } = req.query); exec(`ping ${target}`);",
+        "import { exec } from 'child_process'; const host = req.query.host; setTimeout(() => exec(`ping ${host}`));",
+    ];
+
+    Tester::new(NoTaintedCommand::NAME, NoTaintedCommand::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,204 @@
+use oxc_ast::{
+    AstKind,
+    ast::{
+        AssignmentOperator, Expression, JSXAttributeValue, JSXExpression, ObjectExpression,
+        ObjectPropertyKind,
+    },
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        DEFAULT_TAINT_SOURCES, TaintAnalysis, TaintConfig, TaintFlow, html_method_arguments,
+        html_property_name,
+    },
+};
+
+fn no_tainted_html_diagnostic(span: Span, sink: &str, flow: &TaintFlow) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("User input flows into the HTML of `{sink}`"))
+        .with_help("HTML from user input can run scripts in the page (XSS). Use `textContent`, or sanitize the HTML with `DOMPurify.sanitize`.")
+        .with_labels(flow.labels(span, "parsed as HTML"))
+}
+
+const SANITIZERS: [&str; 4] =
+    ["DOMPurify.sanitize", "Sanitizer.escapeHTML", "encodeURIComponent", "escapeHTML"];
+
+#[derive(Debug, Default, Clone)]
+pub struct NoTaintedHtml;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow HTML built from user input, such as `location.hash`, `document.cookie` or
+    /// `req.query`, in `innerHTML`, `outerHTML`, `dangerouslySetInnerHTML` and methods which
+    /// parse HTML, such as `insertAdjacentHTML` and `document.write`.
+    ///
+    /// Unlike `security/no-unsanitized-property` and `security/no-unsanitized-method`, the
+    /// rule follows the input through variables, destructuring, template literals and
+    /// concatenation within a function, and only reports HTML which contains it. The
+    /// diagnostic shows the path of the input from its source to the HTML. Values passed to
+    /// `DOMPurify.sanitize`, `escapeHTML`, `Sanitizer.escapeHTML` or `encodeURIComponent`
+    /// are allowed.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The browser parses the value as HTML. If any part of it comes from the user, they
+    /// can add elements such as `<img src=x onerror=...>` which run scripts with the
+    /// permissions of the page (cross-site scripting).
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const name = new URLSearchParams(location.search).get('name');
+    /// greeting.innerHTML = `Hello, <b>${name}</b>`;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const name = new URLSearchParams(location.search).get('name');
+    /// greeting.innerHTML = DOMPurify.sanitize(`Hello, <b>${name}</b>`);
+    /// ```
+    NoTaintedHtml,
+    security,
+    suspicious,
+    version = "next",
+    short_description = "Disallow HTML built from user input.",
+);
+
+impl Rule for NoTaintedHtml {
+    fn run_once(&self, ctx: &LintContext) {
+        let mut analysis = TaintAnalysis::new(
+            ctx,
+            TaintConfig { sources: &DEFAULT_TAINT_SOURCES, sanitizers: &SANITIZERS },
+        );
+        for node in ctx.nodes() {
+            match node.kind() {
+                AstKind::AssignmentExpression(assignment) => {
+                    if !matches!(
+                        assignment.operator,
+                        AssignmentOperator::Assign | AssignmentOperator::Addition
+                    ) {
+                        continue;
+                    }
+                    let Some(property) = html_property_name(&assignment.left) else {
+                        continue;
+                    };
+                    if let Some(flow) = analysis.taint_of(&assignment.right) {
+                        ctx.diagnostic(no_tainted_html_diagnostic(
+                            assignment.right.span(),
+                            property,
+                            &flow,
+                        ));
+                    }
+                }
+                AstKind::CallExpression(call) => {
+                    let Some((method, html)) = html_method_arguments(call) else {
+                        continue;
+                    };
+                    for argument in html {
+                        let Some(expression) = argument.as_expression() else {
+                            continue;
+                        };
+                        if let Some(flow) = analysis.taint_of(expression) {
+                            ctx.diagnostic(no_tainted_html_diagnostic(
+                                argument.span(),
+                                method,
+                                &flow,
+                            ));
+                        }
+                    }
+                }
+                AstKind::JSXAttribute(attribute) => {
+                    if !attribute.is_identifier("dangerouslySetInnerHTML") {
+                        continue;
+                    }
+                    let Some(JSXAttributeValue::ExpressionContainer(container)) = &attribute.value
+                    else {
+                        continue;
+                    };
+                    if let JSXExpression::ObjectExpression(object) = &container.expression {
+                        check_inner_html(&mut analysis, object, ctx);
+                    }
+                }
+                // Props of `React.createElement`, and other objects of props.
+                AstKind::ObjectProperty(property) => {
+                    if !property.key.is_specific_static_name("dangerouslySetInnerHTML") {
+                        continue;
+                    }
+                    if let Expression::ObjectExpression(object) =
+                        property.value.get_inner_expression()
+                    {
+                        check_inner_html(&mut analysis, object, ctx);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Check the `__html` of the value of `dangerouslySetInnerHTML`.
+fn check_inner_html<'a>(
+    analysis: &mut TaintAnalysis<'_, 'a>,
+    object: &ObjectExpression<'a>,
+    ctx: &LintContext<'a>,
+) {
+    for property in &object.properties {
+        let ObjectPropertyKind::ObjectProperty(property) = property else {
+            continue;
+        };
+        if !property.key.is_specific_static_name("__html") {
+            continue;
+        }
+        if let Some(flow) = analysis.taint_of(&property.value) {
+            ctx.diagnostic(no_tainted_html_diagnostic(
+                property.value.span(),
+                "dangerouslySetInnerHTML",
+                &flow,
+            ));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "element.innerHTML = html;",
+        "element.innerHTML = `<b>${name}</b>`;",
+        "element.textContent = location.hash;",
+        "element.innerHTML = DOMPurify.sanitize(location.hash);",
+        "element.innerHTML = DOMPurify['sanitize'](location.hash);",
+        "const name = location.hash.slice(1); element.innerHTML = `<b>${escapeHTML(name)}</b>`;",
+        "link.innerHTML = `<a href=\"?q=${encodeURIComponent(location.search)}\">Retry</a>`;",
+        "element.innerHTML = `${location.hash.length} results`;",
+        "let html = location.hash; html = '<p>Not found</p>'; element.innerHTML = html;",
+        "const page = document.cookie.includes('dark') ? 'dark' : 'light'; element.innerHTML = `<p>${page}</p>`;",
+        "list.insertAdjacentHTML(location.hash, '<li>Item</li>');",
+        "stream.write(location.hash);",
+        "const Comment = ({ body }) => <div dangerouslySetInnerHTML={{ __html: body }} />;",
+    ];
+
+    let fail = vec![
+        "element.innerHTML = location.hash;",
+        "element.innerHTML = DOMPurify[method](location.hash);",
+        "const name = new URLSearchParams(location.search).get('name'); greeting.innerHTML = `Hello, <b>${name}</b>`;",
+        "element.outerHTML = '<p>' + window.location.hash.slice(1) + '</p>';",
+        "let html = '<ul>'; for (const item of req.body.items) { html += `<li>${item}</li>`; } list.innerHTML = html;",
+        "document.write(document.referrer);",
+        "const { hash } = location; list.insertAdjacentHTML('beforeend', `<li>${decodeURIComponent(hash)}</li>`);",
+        "const message = document.cookie.split('=')[1]; const Banner = () => <div dangerouslySetInnerHTML={{ __html: message }} />;",
+        "React.createElement('div', { dangerouslySetInnerHTML: { __html: window.name } });",
+        "let html = ''; if (preview) { html = location.search; } element.innerHTML = html;",
+    ];
+
+    Tester::new(NoTaintedHtml::NAME, NoTaintedHtml::PLUGIN, pass, fail)
+        .change_rule_path_extension("jsx")
+        .test_and_snapshot();
+}
@@ -0,0 +1,128 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        DEFAULT_TAINT_SOURCES, NodeApiKind, TaintAnalysis, TaintConfig, TaintFlow,
+        collect_node_api_references, fs_path_arguments,
+    },
+};
+
+fn no_tainted_path_diagnostic(span: Span, api: &str, flow: &TaintFlow) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("User input flows into the path of `{api}`"))
+        .with_help("A path from user input can reach any file which the process can access, for example with `../`. Use `path.basename`, or check that the resolved path stays within the expected directory.")
+        .with_labels(flow.labels(span, "used as a path"))
+}
+
+const SANITIZERS: [&str; 2] = ["path.basename", "sanitizeFilename"];
+
+#[derive(Debug, Default, Clone)]
+pub struct NoTaintedPath;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow calling the functions of `fs` and `fs/promises`, such as `readFile`, with
+    /// paths built from user input, such as `req.params`, `req.query` or `process.argv`.
+    ///
+    /// Unlike `security/detect-non-literal-fs-filename`, the rule follows the input through
+    /// variables, destructuring, template literals and concatenation within a function, and
+    /// only reports paths which contain it. The diagnostic shows the path of the input from
+    /// its source to the call. Values passed to `path.basename` or `sanitizeFilename` are
+    /// allowed.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// If the user can choose any part of a path, they can read, overwrite or delete other
+    /// files which the process can access, such as `../../.env` (path traversal).
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// import fs from 'node:fs';
+    /// app.get('/files/:name', (req, res) => {
+    ///   const file = `uploads/${req.params.name}`;
+    ///   res.send(fs.readFileSync(file));
+    /// });
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import fs from 'node:fs';
+    /// import path from 'node:path';
+    /// app.get('/files/:name', (req, res) => {
+    ///   const file = `uploads/${path.basename(req.params.name)}`;
+    ///   res.send(fs.readFileSync(file));
+    /// });
+    /// ```
+    NoTaintedPath,
+    security,
+    suspicious,
+    version = "next",
+    short_description = "Disallow `fs` functions with paths built from user input.",
+);
+
+impl Rule for NoTaintedPath {
+    fn run_once(&self, ctx: &LintContext) {
+        let mut analysis = TaintAnalysis::new(
+            ctx,
+            TaintConfig { sources: &DEFAULT_TAINT_SOURCES, sanitizers: &SANITIZERS },
+        );
+        for reference in collect_node_api_references(&[], ctx) {
+            if reference.kind != NodeApiKind::Module {
+                continue;
+            }
+            let Some(positions) = fs_path_arguments(&reference.path) else {
+                continue;
+            };
+            let AstKind::CallExpression(call) = ctx.nodes().parent_kind(reference.node_id) else {
+                continue;
+            };
+            if call.callee.span() != reference.span {
+                continue;
+            }
+            for &position in positions {
+                let Some(path) = call.arguments.get(position).and_then(|arg| arg.as_expression())
+                else {
+                    continue;
+                };
+                if let Some(flow) = analysis.taint_of(path) {
+                    ctx.diagnostic(no_tainted_path_diagnostic(path.span(), &reference.path, &flow));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "import fs from 'fs'; fs.readFileSync(file);",
+        "import fs from 'fs'; fs.readFileSync(`uploads/${name}`);",
+        "import fs from 'fs'; import path from 'path'; fs.readFileSync(`uploads/${path.basename(req.params.name)}`);",
+        "import fs from 'fs'; fs.writeFile('out.txt', req.body, cb);",
+        "import fs from 'fs'; let file = req.query.file; file = 'default.txt'; fs.readFile(file, cb);",
+        "import fs from 'fs'; const exists = req.query.file.endsWith('.txt'); fs.readFile(`${exists}.log`, cb);",
+        "import { readFile } from './files'; readFile(req.query.file);",
+    ];
+
+    let fail = vec![
+        "import fs from 'fs'; fs.readFileSync(req.query.file);",
+        "import fs from 'node:fs'; app.get('/files/:name', (req, res) => { const file = `uploads/${req.params.name}`; res.send(fs.readFileSync(file)); });",
+        "import { readFile } from 'fs/promises'; const [, , input] = process.argv; await readFile(input);",
+        "import fs from 'fs'; import path from 'path'; fs.unlinkSync(path.join(root, req.body.name));",
+        "import fs from 'fs'; fs.rename('a.txt', req.query.target, cb);",
+        "const fs = require('fs'); fs.promises.readFile(req.query.file);",
+        "import fs from 'fs'; const { file = 'index.html' } = req.query; fs.createReadStream(file);",
+        "import fs from 'fs'; let file = 'index.html'; while (next) { fs.readFile(file, cb); file = req.query.next; }",
+    ];
+
+    Tester::new(NoTaintedPath::NAME, NoTaintedPath::PLUGIN, pass, fail).test_and_snapshot();
+}
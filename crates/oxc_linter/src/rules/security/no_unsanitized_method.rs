@@ -0,0 +1,137 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_str::CompactStr;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    utils::{html_method_arguments, is_sanitized_html},
+};
+
+fn no_unsanitized_method_diagnostic(span: Span, method: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unsanitized value passed to `{method}`"))
+        .with_help("HTML from user input can run scripts in the page (XSS). Insert text nodes, or sanitize the HTML with one of the `escape` functions.")
+        .with_label(span)
+}
+
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct NoUnsanitizedMethodConfig {
+    /// Functions and template tags whose result is sanitized HTML, such as
+    /// `"DOMPurify.sanitize"`.
+    escape: Vec<CompactStr>,
+}
+
+impl Default for NoUnsanitizedMethodConfig {
+    fn default() -> Self {
+        Self {
+            escape: ["DOMPurify.sanitize", "Sanitizer.escapeHTML", "escapeHTML"]
+                .into_iter()
+                .map(CompactStr::new)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoUnsanitizedMethod(Box<NoUnsanitizedMethodConfig>);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow passing HTML which may not be sanitized to `insertAdjacentHTML`,
+    /// `document.write`, `document.writeln`, `createContextualFragment` and
+    /// `setHTMLUnsafe`.
+    ///
+    /// Constants, and the results of the `escape` functions and template tags, such as
+    /// `DOMPurify.sanitize(html)`, are allowed.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// These methods parse their argument as HTML. If any part of it comes from the user,
+    /// they can add elements such as `<img src=x onerror=...>` which run scripts with the
+    /// permissions of the page (cross-site scripting).
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// list.insertAdjacentHTML('beforeend', `<li>${item.name}</li>`);
+    /// document.write(location.hash);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// list.insertAdjacentHTML('beforeend', DOMPurify.sanitize(`<li>${item.name}</li>`));
+    /// document.write('<p>Loading…</p>');
+    /// ```
+    NoUnsanitizedMethod,
+    security,
+    suspicious,
+    config = NoUnsanitizedMethodConfig,
+    version = "next",
+    short_description = "Disallow passing unsanitized HTML to methods which parse it, such as `insertAdjacentHTML`.",
+);
+
+impl Rule for NoUnsanitizedMethod {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        DefaultRuleConfig::<Self>::from_value(value).map(DefaultRuleConfig::into_inner)
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Some((method, html)) = html_method_arguments(call) else {
+            return;
+        };
+        for argument in html {
+            if argument
+                .as_expression()
+                .is_some_and(|argument| is_sanitized_html(argument, &self.0.escape, ctx))
+            {
+                continue;
+            }
+            ctx.diagnostic(no_unsanitized_method_diagnostic(argument.span(), method));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("list.insertAdjacentHTML('beforeend', '<li>Item</li>');", None),
+        ("list.insertAdjacentHTML(position, '<li>Item</li>');", None),
+        ("list.insertAdjacentHTML('beforeend', DOMPurify.sanitize(`<li>${name}</li>`));", None),
+        ("list.insertAdjacentHTML('beforeend', escapeHTML`<li>${name}</li>`);", None),
+        ("list.insertAdjacentText('beforeend', name);", None),
+        ("document.write('<p>Loading…</p>');", None),
+        ("stream.write(chunk);", None),
+        ("response.writeln(line);", None),
+        ("range.createContextualFragment('<b>bold</b>');", None),
+        ("element.setHTMLUnsafe(clean(html));", Some(json!([{ "escape": ["clean"] }]))),
+    ];
+
+    let fail = vec![
+        ("list.insertAdjacentHTML('beforeend', `<li>${item.name}</li>`);", None),
+        ("list.insertAdjacentHTML('afterbegin', html);", None),
+        ("document.write(location.hash);", None),
+        ("window.document.writeln('<p>' + message + '</p>');", None),
+        ("iframe.contentDocument.write(markup);", None),
+        ("range.createContextualFragment(html);", None),
+        ("element.setHTMLUnsafe(html);", None),
+        ("document.write('<p>', body, '</p>');", None),
+    ];
+
+    Tester::new(NoUnsanitizedMethod::NAME, NoUnsanitizedMethod::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -0,0 +1,207 @@
+use oxc_ast::{
+    AstKind,
+    ast::{
+        AssignmentOperator, Expression, JSXAttributeValue, JSXExpression, ObjectExpression,
+        ObjectPropertyKind,
+    },
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_str::CompactStr;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+    utils::{html_property_name, is_sanitized_html},
+};
+
+fn no_unsanitized_property_diagnostic(span: Span, property: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unsanitized value assigned to `{property}`"))
+        .with_help("HTML from user input can run scripts in the page (XSS). Use `textContent`, or sanitize the HTML with one of the `escape` functions.")
+        .with_label(span)
+}
+
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct NoUnsanitizedPropertyConfig {
+    /// Functions and template tags whose result is sanitized HTML, such as
+    /// `"DOMPurify.sanitize"`.
+    escape: Vec<CompactStr>,
+}
+
+impl Default for NoUnsanitizedPropertyConfig {
+    fn default() -> Self {
+        Self {
+            escape: ["DOMPurify.sanitize", "Sanitizer.escapeHTML", "escapeHTML"]
+                .into_iter()
+                .map(CompactStr::new)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct NoUnsanitizedProperty(Box<NoUnsanitizedPropertyConfig>);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow assigning HTML which may not be sanitized to `innerHTML` and `outerHTML`,
+    /// and to the `__html` of `dangerouslySetInnerHTML` in React.
+    ///
+    /// Constants, and the results of the `escape` functions and template tags, such as
+    /// `DOMPurify.sanitize(html)`, are allowed.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The browser parses the value as HTML. If any part of it comes from the user, they
+    /// can add elements such as `<img src=x onerror=...>` which run scripts with the
+    /// permissions of the page (cross-site scripting).
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// element.innerHTML = `<b>${name}</b>`;
+    /// const Comment = ({ body }) => <div dangerouslySetInnerHTML={{ __html: body }} />;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// element.textContent = name;
+    /// element.innerHTML = DOMPurify.sanitize(html);
+    /// const Comment = ({ body }) => <div dangerouslySetInnerHTML={{ __html: DOMPurify.sanitize(body) }} />;
+    /// ```
+    NoUnsanitizedProperty,
+    security,
+    suspicious,
+    config = NoUnsanitizedPropertyConfig,
+    version = "next",
+    short_description = "Disallow assigning unsanitized HTML to `innerHTML`, `outerHTML` and `dangerouslySetInnerHTML`.",
+);
+
+impl Rule for NoUnsanitizedProperty {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        DefaultRuleConfig::<Self>::from_value(value).map(DefaultRuleConfig::into_inner)
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::AssignmentExpression(assignment) => {
+                if !matches!(
+                    assignment.operator,
+                    AssignmentOperator::Assign | AssignmentOperator::Addition
+                ) {
+                    return;
+                }
+                let Some(property) = html_property_name(&assignment.left) else {
+                    return;
+                };
+                if !is_sanitized_html(&assignment.right, &self.0.escape, ctx) {
+                    ctx.diagnostic(no_unsanitized_property_diagnostic(
+                        assignment.right.span(),
+                        property,
+                    ));
+                }
+            }
+            AstKind::JSXAttribute(attribute) => {
+                if !attribute.is_identifier("dangerouslySetInnerHTML") {
+                    return;
+                }
+                let Some(JSXAttributeValue::ExpressionContainer(container)) = &attribute.value
+                else {
+                    return;
+                };
+                if let JSXExpression::ObjectExpression(object) = &container.expression {
+                    self.check_inner_html(object, ctx);
+                }
+            }
+            // Props of `React.createElement`, and other objects of props.
+            AstKind::ObjectProperty(property) => {
+                if !property.key.is_specific_static_name("dangerouslySetInnerHTML") {
+                    return;
+                }
+                if let Expression::ObjectExpression(object) = property.value.get_inner_expression()
+                {
+                    self.check_inner_html(object, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl NoUnsanitizedProperty {
+    /// Check the `__html` of the value of `dangerouslySetInnerHTML`.
+    fn check_inner_html<'a>(&self, object: &ObjectExpression<'a>, ctx: &LintContext<'a>) {
+        for property in &object.properties {
+            let ObjectPropertyKind::ObjectProperty(property) = property else {
+                continue;
+            };
+            if property.key.is_specific_static_name("__html")
+                && !is_sanitized_html(&property.value, &self.0.escape, ctx)
+            {
+                ctx.diagnostic(no_unsanitized_property_diagnostic(
+                    property.value.span(),
+                    "dangerouslySetInnerHTML",
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("element.innerHTML = '';", None),
+        ("element.innerHTML = '<b>Hello</b>';", None),
+        ("element.outerHTML = `<p>${'static'}</p>`;", None),
+        ("const markup = '<hr>'; element.innerHTML = markup;", None),
+        ("element.innerHTML = DOMPurify.sanitize(html);", None),
+        ("element.innerHTML = escapeHTML`<b>${name}</b>`;", None),
+        ("element.innerHTML = '<b>' + Sanitizer.escapeHTML(name) + '</b>';", None),
+        ("element.innerHTML = cond ? '<b>a</b>' : DOMPurify.sanitize(html);", None),
+        ("element.textContent = name;", None),
+        ("element.innerHTML += '<br>';", None),
+        ("element.innerHTML = clean(html);", Some(json!([{ "escape": ["clean"] }]))),
+        (
+            "element.innerHTML = window.DOMPurify['sanitize'](html);",
+            Some(json!([{ "escape": ["window.DOMPurify.sanitize"] }])),
+        ),
+        ("<div dangerouslySetInnerHTML={{ __html: '<b>Hello</b>' }} />;", None),
+        ("<div dangerouslySetInnerHTML={{ __html: DOMPurify.sanitize(body) }} />;", None),
+        ("React.createElement('div', { dangerouslySetInnerHTML: { __html: '<br>' } });", None),
+        ("<div title={{ __html: body }} />;", None),
+    ];
+
+    let fail = vec![
+        ("element.innerHTML = html;", None),
+        ("element.innerHTML = `<b>${name}</b>`;", None),
+        ("element.outerHTML = '<p>' + text + '</p>';", None),
+        ("element.innerHTML += comment.body;", None),
+        ("element['innerHTML'] = html;", None),
+        ("let markup = '<hr>'; markup = input; element.innerHTML = markup;", None),
+        ("element.innerHTML = DOMPurify.sanitize(html);", Some(json!([{ "escape": ["clean"] }]))),
+        (
+            "element.innerHTML = DOMPurify.sanitize(html);",
+            Some(json!([{ "escape": ["window.DOMPurify.sanitize"] }])),
+        ),
+        ("element.innerHTML = DOMPurify[method](html);", None),
+        ("element.innerHTML = cond ? html : '';", None),
+        ("<div dangerouslySetInnerHTML={{ __html: body }} />;", None),
+        ("<div dangerouslySetInnerHTML={{ __html: `<p>${body}</p>` }} />;", None),
+        ("React.createElement('div', { dangerouslySetInnerHTML: { __html: body } });", None),
+    ];
+
+    Tester::new(NoUnsanitizedProperty::NAME, NoUnsanitizedProperty::PLUGIN, pass, fail)
+        .change_rule_path_extension("jsx")
+        .test_and_snapshot();
+}
@@ -1,5 +1,7 @@
 mod eslint_unicorn;
 mod jest_vitest;
+mod node_unicorn;
 
 pub use eslint_unicorn::*;
 pub use jest_vitest::*;
+pub use node_unicorn::*;
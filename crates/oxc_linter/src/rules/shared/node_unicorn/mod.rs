@@ -0,0 +1 @@
+pub mod prefer_node_protocol;
@@ -0,0 +1,70 @@
+use nodejs_built_in_modules::is_nodejs_builtin_module;
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, TSModuleReference},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+
+use crate::{AstNode, context::LintContext, utils::NodeModuleSyntax};
+
+fn prefer_node_protocol_diagnostic(span: Span, module_name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "Prefer using the `node:` protocol when importing Node.js built-in modules.",
+    )
+    .with_help(format!("Prefer `node:{module_name}` over `{module_name}`."))
+    .with_label(span)
+}
+
+/// Report `node` when it loads a Node.js built-in module without the `node:` protocol,
+/// unless `is_supported` returns `false` for the syntax which loads it.
+pub fn run<'a>(
+    node: &AstNode<'a>,
+    ctx: &LintContext<'a>,
+    is_supported: impl Fn(NodeModuleSyntax) -> bool,
+) {
+    let string_lit_value_with_span = match node.kind() {
+        AstKind::ImportExpression(import) => match &import.source {
+            Expression::StringLiteral(str_lit) => {
+                Some((str_lit.value, str_lit.span, NodeModuleSyntax::Import))
+            }
+            _ => None,
+        },
+        AstKind::TSImportEqualsDeclaration(import) => match &import.module_reference {
+            TSModuleReference::ExternalModuleReference(external) => Some((
+                external.expression.value,
+                external.expression.span,
+                NodeModuleSyntax::Require,
+            )),
+            _ => None,
+        },
+        AstKind::CallExpression(call) if !call.optional => {
+            call.common_js_require().map(|s| (s.value, s.span, NodeModuleSyntax::Require))
+        }
+        AstKind::ImportDeclaration(import) => {
+            Some((import.source.value, import.source.span, NodeModuleSyntax::Import))
+        }
+        AstKind::ExportFromDeclaration(export) => {
+            Some((export.source.value, export.source.span, NodeModuleSyntax::Import))
+        }
+        _ => return,
+    };
+    let Some((string_lit_value, span, syntax)) = string_lit_value_with_span else {
+        return;
+    };
+    let module_name = string_lit_value.as_str();
+    if module_name.starts_with("node:")
+        || !is_nodejs_builtin_module(module_name)
+        || !is_supported(syntax)
+    {
+        return;
+    }
+
+    ctx.diagnostic_with_fix(prefer_node_protocol_diagnostic(span, &string_lit_value), |fixer| {
+        // Smallest module name is 2 chars, plus 2 for quotes = 4.
+        debug_assert!(span.size() >= 4, "node stdlib module name should be at least 4 chars long");
+        // We're replacing inside the string literal, shift to account for quotes.
+        let span = span.shrink_left(1).shrink_right(1);
+        fixer.replace(span, format!("node:{string_lit_value}"))
+    });
+}
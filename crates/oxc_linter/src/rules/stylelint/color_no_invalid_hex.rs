@@ -0,0 +1,124 @@
+use oxc_css_parser::ast::ComponentValue;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::{should_run_stylelint, walk_component_values, walk_declarations},
+};
+
+fn color_no_invalid_hex_diagnostic(span: Span, hex: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unexpected invalid hex color \"{hex}\""))
+        .with_help("A hex color has 3, 4, 6 or 8 hexadecimal digits.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ColorNoInvalidHex;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow invalid hex colors.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Longhand hex colors can be either 6 or 8 (with alpha channel) hexadecimal characters,
+    /// and their shorthand variants are 3 and 4 characters respectively.
+    /// Any other hex color is invalid, and the browser drops the whole declaration.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```css
+    /// a { color: #00; }
+    /// a { color: #fff1az; }
+    /// a { color: #12345aa; }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```css
+    /// a { color: #000; }
+    /// a { color: #000f; }
+    /// a { color: #fff1a0; }
+    /// a { color: #123450aa; }
+    /// ```
+    ColorNoInvalidHex,
+    stylelint,
+    correctness,
+    version = "next",
+    short_description = "Disallow invalid hex colors.",
+);
+
+impl Rule for ColorNoInvalidHex {
+    fn run_once(&self, ctx: &LintContext) {
+        for stylesheet in ctx.stylesheets().iter() {
+            walk_declarations(&stylesheet.stylesheet, &mut |declaration| {
+                walk_component_values(&declaration.value, &mut |value| {
+                    if let ComponentValue::HexColor(hex) = value
+                        && !is_valid_hex(hex.value.trim_start_matches('#'))
+                    {
+                        ctx.diagnostic(color_no_invalid_hex_diagnostic(
+                            stylesheet.span(&hex.span),
+                            stylesheet.text(&hex.span),
+                        ));
+                    }
+                });
+            });
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        should_run_stylelint(ctx)
+    }
+}
+
+fn is_valid_hex(digits: &str) -> bool {
+    matches!(digits.len(), 3 | 4 | 6 | 8) && digits.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("a { color: pink; }", None, None, None),
+        ("a { color: #000; }", None, None, None),
+        ("a { color: #000f; }", None, None, None),
+        ("a { color: #fff1a0; }", None, None, None),
+        ("a { color: #123450aa; }", None, None, None),
+        ("a { color: #ABCDEF; }", None, None, None),
+        ("a { background: url(#ZZZ); }", None, None, None),
+        ("a { color: rgb(0 0 0 / 50%); }", None, None, None),
+        ("#foo { color: red; }", None, None, None),
+        ("a { color: #fff; }", None, None, Some(PathBuf::from("test.scss"))),
+        ("@c: #fff; a { color: @c; }", None, None, Some(PathBuf::from("test.less"))),
+        ("const Button = styled.a`color: #fff;`", None, None, Some(PathBuf::from("test.tsx"))),
+        // Not a css-in-js tag.
+        ("const s = html`<p style='color: #ff'></p>`", None, None, Some(PathBuf::from("test.tsx"))),
+    ];
+
+    let fail = vec![
+        ("a { color: #00; }", None, None, None),
+        ("a { color: #fff1az; }", None, None, None),
+        ("a { color: #12345aa; }", None, None, None),
+        ("a { border: 1px solid #ggg; }", None, None, None),
+        ("a { color: rgb(0, 0, 0); background: linear-gradient(#fff, #ff); }", None, None, None),
+        ("a { color: #00; }", None, None, Some(PathBuf::from("test.scss"))),
+        (
+            "const Button = styled.a`\n  color: ${(p) => p.color};\n  background: #ff;\n`",
+            None,
+            None,
+            Some(PathBuf::from("test.tsx")),
+        ),
+        ("const style = css`color: #12345;`", None, None, Some(PathBuf::from("test.ts"))),
+    ];
+
+    Tester::new(ColorNoInvalidHex::NAME, ColorNoInvalidHex::PLUGIN, pass, fail)
+        .change_rule_path_extension("css")
+        .test_and_snapshot();
+}
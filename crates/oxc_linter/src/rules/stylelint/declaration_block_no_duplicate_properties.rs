@@ -0,0 +1,159 @@
+use std::borrow::Cow;
+
+use cow_utils::CowUtils;
+use oxc_css_parser::ast::{InterpolableIdent, Statement};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::{should_run_stylelint, walk_statement_lists},
+};
+
+fn declaration_block_no_duplicate_properties_diagnostic(
+    first: Span,
+    second: Span,
+    property: &str,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unexpected duplicate \"{property}\""))
+        .with_help("Remove one of the declarations; only the last one takes effect.")
+        .with_labels([
+            first.label("Property is first declared here"),
+            second.label("and declared again here"),
+        ])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DeclarationBlockNoDuplicateProperties;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow duplicate properties within declaration blocks.
+    ///
+    /// Property names are compared case-insensitively, except custom properties.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The later declaration silently overrides the earlier one,
+    /// which is usually a leftover from editing or a copy-paste mistake.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```css
+    /// a { color: pink; color: orange; }
+    /// a { color: pink; background: orange; COLOR: orange; }
+    /// a { --custom: pink; --custom: orange; }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```css
+    /// a { color: pink; }
+    /// a { color: pink; background: orange; }
+    /// a { --custom: pink; --CUSTOM: orange; }
+    /// ```
+    DeclarationBlockNoDuplicateProperties,
+    stylelint,
+    correctness,
+    version = "next",
+    short_description = "Disallow duplicate properties within declaration blocks.",
+);
+
+impl Rule for DeclarationBlockNoDuplicateProperties {
+    fn run_once(&self, ctx: &LintContext) {
+        for stylesheet in ctx.stylesheets().iter() {
+            walk_statement_lists(&stylesheet.stylesheet, &mut |statements, _| {
+                let mut seen: FxHashMap<Cow<str>, Span> = FxHashMap::default();
+                for statement in statements {
+                    let Statement::Declaration(declaration) = statement else {
+                        continue;
+                    };
+                    // Skip interpolated names and legacy IE hacks like `*zoom`.
+                    let InterpolableIdent::Literal(name) = &declaration.name else {
+                        continue;
+                    };
+                    if declaration.name_prefix.is_some() {
+                        continue;
+                    }
+                    let key = if name.raw.starts_with("--") {
+                        Cow::Borrowed(name.raw)
+                    } else {
+                        name.raw.cow_to_ascii_lowercase()
+                    };
+                    let span = stylesheet.span(&name.span);
+                    if let Some(&first) = seen.get(&key) {
+                        ctx.diagnostic(declaration_block_no_duplicate_properties_diagnostic(
+                            first, span, name.raw,
+                        ));
+                    } else {
+                        seen.insert(key, span);
+                    }
+                }
+            });
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        should_run_stylelint(ctx)
+    }
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("a { color: pink; }", None, None, None),
+        ("a { color: pink; background: orange; }", None, None, None),
+        ("a { --custom: pink; --CUSTOM: orange; }", None, None, None),
+        ("a { color: pink; } b { color: orange; }", None, None, None),
+        ("a { color: pink; &:hover { color: orange; } }", None, None, None),
+        ("a { color: pink; @media (min-width: 1px) { color: orange; } }", None, None, None),
+        ("@font-face { font-family: a; src: url(a.woff); }", None, None, None),
+        ("a { $c: red; $c: blue; color: $c; }", None, None, Some(PathBuf::from("test.scss"))),
+        ("a { @c: red; @c: blue; color: @c; }", None, None, Some(PathBuf::from("test.less"))),
+        (
+            "const A = styled.a`color: pink;`; const B = styled.b`color: orange;`",
+            None,
+            None,
+            Some(PathBuf::from("test.tsx")),
+        ),
+    ];
+
+    let fail = vec![
+        ("a { color: pink; color: orange; }", None, None, None),
+        ("a { color: pink; background: orange; COLOR: orange; }", None, None, None),
+        ("a { --custom: pink; --custom: orange; }", None, None, None),
+        ("a { color: pink; &:hover { color: orange; color: red; } }", None, None, None),
+        ("@media print { a { display: none; display: block; } }", None, None, None),
+        ("@font-face { src: url(a.woff); src: url(b.woff); }", None, None, None),
+        (
+            "a { color: pink; b { color: orange; } color: red; }",
+            None,
+            None,
+            Some(PathBuf::from("test.scss")),
+        ),
+        (".a { width: 1px; width: 2px; }", None, None, Some(PathBuf::from("test.less"))),
+        (
+            "const Button = styled.button`\n  color: ${(p) => p.color};\n  padding: 0;\n  color: red;\n`",
+            None,
+            None,
+            Some(PathBuf::from("test.tsx")),
+        ),
+    ];
+
+    Tester::new(
+        DeclarationBlockNoDuplicateProperties::NAME,
+        DeclarationBlockNoDuplicateProperties::PLUGIN,
+        pass,
+        fail,
+    )
+    .change_rule_path_extension("css")
+    .test_and_snapshot();
+}
@@ -0,0 +1,319 @@
+use cow_utils::CowUtils;
+use oxc_css_parser::ast::{
+    ComplexSelector, ComplexSelectorChild, CompoundSelector, InterpolableIdent,
+    PseudoClassSelector, PseudoClassSelectorArgKind, SimpleSelector, Statement, TypeSelector,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_formatter_css::TEMPLATE_PLACEHOLDER_PREFIX;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::{ParsedStylesheet, display_selector, should_run_stylelint, walk_statement_lists},
+};
+
+fn no_descending_specificity_diagnostic(
+    span: Span,
+    prior_span: Span,
+    selector: &str,
+    prior: &str,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Expected selector \"{selector}\" to come before \"{prior}\""))
+        .with_help("Move this rule before the more specific one that targets the same elements.")
+        .with_labels([
+            prior_span.label("More specific selector comes first"),
+            span.primary_label("less specific selector comes after it"),
+        ])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDescendingSpecificity;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow selectors of lower specificity from coming after overriding selectors
+    /// of higher specificity.
+    ///
+    /// Selectors are compared when they end with the same compound selector,
+    /// ignoring its pseudo-classes (e.g. `a`, `b a` and `#c a:hover` all compare),
+    /// and are in the same stylesheet or at-rule.
+    /// Each selector of a selector list is compared separately.
+    /// Nested rules are not checked.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Source order matters in CSS only between selectors of equal specificity.
+    /// A less specific selector that comes later reads as if it overrode the earlier one,
+    /// but it never does, which makes the stylesheet hard to follow.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```css
+    /// b a {}
+    /// a {}
+    ///
+    /// a + a {}
+    /// a {}
+    ///
+    /// #container a { top: 10px; }
+    /// a:hover { top: 10px; }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```css
+    /// a {}
+    /// b a {}
+    ///
+    /// a:hover {}
+    /// #container a:hover {}
+    ///
+    /// b a {}
+    /// @media print { a {} }
+    /// ```
+    NoDescendingSpecificity,
+    stylelint,
+    suspicious,
+    version = "next",
+    short_description = "Disallow selectors of lower specificity from coming after overriding selectors of higher specificity.",
+);
+
+/// Selector specificity: `(ids, classes, types)`, compared lexicographically.
+type Specificity = (u32, u32, u32);
+
+struct PriorSelector {
+    specificity: Specificity,
+    text: String,
+    span: Span,
+}
+
+impl Rule for NoDescendingSpecificity {
+    fn run_once(&self, ctx: &LintContext) {
+        for stylesheet in ctx.stylesheets().iter() {
+            walk_statement_lists(&stylesheet.stylesheet, &mut |statements, in_rule| {
+                if in_rule {
+                    return;
+                }
+                let mut priors: FxHashMap<String, Vec<PriorSelector>> = FxHashMap::default();
+                for statement in statements {
+                    let Statement::QualifiedRule(rule) = statement else {
+                        continue;
+                    };
+                    for selector in &rule.selector.selectors {
+                        check_selector(ctx, stylesheet, selector, &mut priors);
+                    }
+                }
+            });
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        should_run_stylelint(ctx)
+    }
+}
+
+fn check_selector(
+    ctx: &LintContext,
+    stylesheet: &ParsedStylesheet,
+    selector: &ComplexSelector,
+    priors: &mut FxHashMap<String, Vec<PriorSelector>>,
+) {
+    let text = stylesheet.text(&selector.span);
+    if text.contains(TEMPLATE_PLACEHOLDER_PREFIX) || has_nesting(selector) {
+        return;
+    }
+    let Some(reference) = reference_key(stylesheet, selector) else {
+        return;
+    };
+
+    let specificity = complex_specificity(selector);
+    let text = display_selector(text);
+    let span = stylesheet.span(&selector.span);
+    let priors = priors.entry(reference).or_default();
+    if let Some(prior) = priors.iter().find(|prior| prior.specificity > specificity) {
+        ctx.diagnostic(no_descending_specificity_diagnostic(span, prior.span, &text, &prior.text));
+    }
+    priors.push(PriorSelector { specificity, text, span });
+}
+
+/// The last compound selector without its pseudo-classes, e.g. `a::before` for `#b > a:hover::before`.
+fn reference_key(stylesheet: &ParsedStylesheet, selector: &ComplexSelector) -> Option<String> {
+    let compound = last_compound(selector)?;
+    let key = compound
+        .children
+        .iter()
+        .filter(|simple| !is_pseudo_class(simple))
+        .map(|simple| stylesheet.text(simple_span(simple)))
+        .collect::<String>();
+    (!key.is_empty()).then_some(key)
+}
+
+fn last_compound<'s, 'a>(selector: &'s ComplexSelector<'a>) -> Option<&'s CompoundSelector<'a>> {
+    selector.children.iter().rev().find_map(|child| match child {
+        ComplexSelectorChild::CompoundSelector(compound) => Some(compound),
+        ComplexSelectorChild::Combinator(_) => None,
+    })
+}
+
+fn has_nesting(selector: &ComplexSelector) -> bool {
+    selector.children.iter().any(|child| {
+        matches!(child, ComplexSelectorChild::CompoundSelector(compound)
+            if compound.children.iter().any(|simple| matches!(simple, SimpleSelector::Nesting(_))))
+    })
+}
+
+fn is_pseudo_class(simple: &SimpleSelector) -> bool {
+    matches!(simple, SimpleSelector::PseudoClass(pseudo) if !is_legacy_pseudo_element(pseudo))
+}
+
+/// `:before`, `:after`, `:first-line` and `:first-letter` are pseudo-elements
+/// despite the single colon.
+fn is_legacy_pseudo_element(pseudo: &PseudoClassSelector) -> bool {
+    let InterpolableIdent::Literal(name) = &pseudo.name else {
+        return false;
+    };
+    matches!(
+        name.name.cow_to_ascii_lowercase().as_ref(),
+        "before" | "after" | "first-line" | "first-letter"
+    )
+}
+
+fn simple_span<'s>(simple: &'s SimpleSelector) -> &'s oxc_css_parser::Span {
+    match simple {
+        SimpleSelector::Class(s) => &s.span,
+        SimpleSelector::Id(s) => &s.span,
+        SimpleSelector::Type(TypeSelector::TagName(s)) => &s.span,
+        SimpleSelector::Type(TypeSelector::Universal(s)) => &s.span,
+        SimpleSelector::Attribute(s) => &s.span,
+        SimpleSelector::PseudoClass(s) => &s.span,
+        SimpleSelector::PseudoElement(s) => &s.span,
+        SimpleSelector::Nesting(s) => &s.span,
+        SimpleSelector::SassPlaceholder(s) => &s.span,
+    }
+}
+
+fn add(a: Specificity, b: Specificity) -> Specificity {
+    (a.0 + b.0, a.1 + b.1, a.2 + b.2)
+}
+
+fn complex_specificity(selector: &ComplexSelector) -> Specificity {
+    selector.children.iter().fold((0, 0, 0), |specificity, child| match child {
+        ComplexSelectorChild::CompoundSelector(compound) => {
+            add(specificity, compound_specificity(compound))
+        }
+        ComplexSelectorChild::Combinator(_) => specificity,
+    })
+}
+
+fn compound_specificity(compound: &CompoundSelector) -> Specificity {
+    compound
+        .children
+        .iter()
+        .fold((0, 0, 0), |specificity, simple| add(specificity, simple_specificity(simple)))
+}
+
+fn simple_specificity(simple: &SimpleSelector) -> Specificity {
+    match simple {
+        SimpleSelector::Id(_) => (1, 0, 0),
+        SimpleSelector::Class(_)
+        | SimpleSelector::Attribute(_)
+        | SimpleSelector::SassPlaceholder(_) => (0, 1, 0),
+        SimpleSelector::Type(TypeSelector::TagName(_)) | SimpleSelector::PseudoElement(_) => {
+            (0, 0, 1)
+        }
+        SimpleSelector::Type(TypeSelector::Universal(_)) | SimpleSelector::Nesting(_) => (0, 0, 0),
+        SimpleSelector::PseudoClass(pseudo) => pseudo_class_specificity(pseudo),
+    }
+}
+
+/// <https://www.w3.org/TR/selectors-4/#specificity-rules>
+fn pseudo_class_specificity(pseudo: &PseudoClassSelector) -> Specificity {
+    if is_legacy_pseudo_element(pseudo) {
+        return (0, 0, 1);
+    }
+    let name = match &pseudo.name {
+        InterpolableIdent::Literal(name) => name.name.cow_to_ascii_lowercase(),
+        _ => return (0, 1, 0),
+    };
+    let Some(arg) = &pseudo.arg else {
+        return (0, 1, 0);
+    };
+    // The most specific selector of the argument list.
+    let max_argument = match &arg.kind {
+        PseudoClassSelectorArgKind::SelectorList(list) => {
+            list.selectors.iter().map(complex_specificity).max()
+        }
+        PseudoClassSelectorArgKind::RelativeSelectorList(list) => {
+            list.selectors.iter().map(|s| complex_specificity(&s.complex_selector)).max()
+        }
+        PseudoClassSelectorArgKind::CompoundSelectorList(list) => {
+            list.selectors.iter().map(compound_specificity).max()
+        }
+        _ => None,
+    };
+    match name.as_ref() {
+        "where" => (0, 0, 0),
+        "is" | "not" | "has" | "matches" | "-webkit-any" | "-moz-any" => {
+            max_argument.unwrap_or_default()
+        }
+        _ => add((0, 1, 0), max_argument.unwrap_or_default()),
+    }
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("a {} b a {}", None, None, None),
+        ("a:hover {} #container a:hover {}", None, None, None),
+        ("b a {} @media print { a {} }", None, None, None),
+        ("#a b {} c {}", None, None, None),
+        ("a::before {} b a {}", None, None, None),
+        ("b a::before {} a {}", None, None, None),
+        (":where(#c) .b {} .b {}", None, None, None),
+        ("a {} :is(#b) a {}", None, None, None),
+        ("a {} a:hover {}", None, None, None),
+        ("#a {} #a {}", None, None, None),
+        (".a { b {} } b {}", None, None, None),
+        ("a, b {} c a {}", None, None, None),
+        (".a { & .b {} } .b {}", None, None, Some(PathBuf::from("test.scss"))),
+        (
+            "const A = styled.a`\n  ${Icon} a {}\n  a {}\n`",
+            None,
+            None,
+            Some(PathBuf::from("test.tsx")),
+        ),
+    ];
+
+    let fail = vec![
+        ("b a {} a {}", None, None, None),
+        ("a + a {} a {}", None, None, None),
+        ("#container a { top: 10px; } a:hover { top: 10px; }", None, None, None),
+        ("b a:hover {} a {}", None, None, None),
+        ("a:hover {} a {}", None, None, None),
+        (".a .b {} :where(.c) .b {}", None, None, None),
+        (".a .b {} :not(.c) {} .b {}", None, None, None),
+        (":is(#a, .b) c {} .d c {}", None, None, None),
+        ("a, b c {} c {}", None, None, None),
+        ("@media print { #a b {} b {} }", None, None, None),
+        ("b a:before {} a:before {}", None, None, None),
+        (".a .b {} .b {}", None, None, Some(PathBuf::from("test.scss"))),
+        (
+            "const Global = createGlobalStyle`\n  nav a { color: red; }\n  a { color: blue; }\n`",
+            None,
+            None,
+            Some(PathBuf::from("test.tsx")),
+        ),
+    ];
+
+    Tester::new(NoDescendingSpecificity::NAME, NoDescendingSpecificity::PLUGIN, pass, fail)
+        .change_rule_path_extension("css")
+        .test_and_snapshot();
+}
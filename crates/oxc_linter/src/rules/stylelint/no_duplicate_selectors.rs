@@ -0,0 +1,163 @@
+use oxc_css_parser::ast::Statement;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::{display_selector, normalize_selector, should_run_stylelint, walk_statement_lists},
+};
+
+fn no_duplicate_selectors_diagnostic(first: Span, second: Span, selector: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unexpected duplicate selector \"{selector}\""))
+        .with_help("Merge the declarations into the first rule.")
+        .with_labels([
+            first.label("Selector is first used here"),
+            second.label("and used again here"),
+        ])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDuplicateSelectors;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow duplicate selectors within a stylesheet.
+    ///
+    /// Two rules are duplicates when their selector lists contain the same selectors,
+    /// in any order and with any whitespace, and they share the same parent
+    /// (the stylesheet, an at-rule, or a rule they are nested in).
+    /// A selector that appears twice in the same selector list is a duplicate, too.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A duplicate rule splits the styles of one selector across the stylesheet,
+    /// so whoever edits one of them easily misses, or is overridden by, the other.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```css
+    /// .foo, .bar {}
+    /// .bar, .foo {}
+    ///
+    /// a > b {}
+    /// a>b {}
+    ///
+    /// a, b, a {}
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```css
+    /// .foo {}
+    /// .bar {}
+    ///
+    /// .foo {}
+    /// @media (min-width: 10px) {
+    ///   .foo {}
+    /// }
+    /// ```
+    NoDuplicateSelectors,
+    stylelint,
+    suspicious,
+    version = "next",
+    short_description = "Disallow duplicate selectors within a stylesheet.",
+);
+
+impl Rule for NoDuplicateSelectors {
+    fn run_once(&self, ctx: &LintContext) {
+        for stylesheet in ctx.stylesheets().iter() {
+            walk_statement_lists(&stylesheet.stylesheet, &mut |statements, _| {
+                let mut seen: FxHashMap<String, Span> = FxHashMap::default();
+                for statement in statements {
+                    let Statement::QualifiedRule(rule) = statement else {
+                        continue;
+                    };
+
+                    let mut selectors: FxHashMap<String, Span> = FxHashMap::default();
+                    for selector in &rule.selector.selectors {
+                        let text = stylesheet.text(&selector.span);
+                        let span = stylesheet.span(&selector.span);
+                        if let Some(&first) = selectors.get(&normalize_selector(text)) {
+                            ctx.diagnostic(no_duplicate_selectors_diagnostic(
+                                first,
+                                span,
+                                &display_selector(text),
+                            ));
+                        } else {
+                            selectors.insert(normalize_selector(text), span);
+                        }
+                    }
+
+                    let mut list = selectors.into_keys().collect::<Vec<_>>();
+                    list.sort_unstable();
+                    let key = list.join(", ");
+                    let span = stylesheet.span(&rule.selector.span);
+                    if let Some(&first) = seen.get(&key) {
+                        ctx.diagnostic(no_duplicate_selectors_diagnostic(
+                            first,
+                            span,
+                            &display_selector(stylesheet.text(&rule.selector.span)),
+                        ));
+                    } else {
+                        seen.insert(key, span);
+                    }
+                }
+            });
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        should_run_stylelint(ctx)
+    }
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("a {} b {}", None, None, None),
+        ("a {} a b {}", None, None, None),
+        ("a b {} a  c {}", None, None, None),
+        (".foo, .bar {} .foo {}", None, None, None),
+        ("a {} @media print { a {} }", None, None, None),
+        ("@media print { a {} } @media screen { a {} }", None, None, None),
+        ("a { b {} } c { b {} }", None, None, None),
+        ("@keyframes x { from {} to {} } @keyframes y { from {} to {} }", None, None, None),
+        ("[data-x='a  b'] {} [data-x='a b'] {}", None, None, None),
+        (".a { &:hover {} &:focus {} }", None, None, Some(PathBuf::from("test.scss"))),
+        (
+            "const A = styled.a`&:hover {}`; const B = styled.b`&:hover {}`",
+            None,
+            None,
+            Some(PathBuf::from("test.tsx")),
+        ),
+    ];
+
+    let fail = vec![
+        ("a {} a {}", None, None, None),
+        (".foo, .bar {} .bar, .foo {}", None, None, None),
+        ("a > b {} a>b {}", None, None, None),
+        ("a  b {}\na b {}", None, None, None),
+        ("a, b, a {}", None, None, None),
+        ("@media print { a {} a {} }", None, None, None),
+        (".a { &:hover {} color: red; &:hover {} }", None, None, Some(PathBuf::from("test.scss"))),
+        (".a { .b {} .b {} }", None, None, Some(PathBuf::from("test.less"))),
+        (
+            "const Link = styled.a`\n  &:hover { color: red; }\n  padding: ${(p) => p.padding};\n  &:hover { color: blue; }\n`",
+            None,
+            None,
+            Some(PathBuf::from("test.tsx")),
+        ),
+    ];
+
+    Tester::new(NoDuplicateSelectors::NAME, NoDuplicateSelectors::PLUGIN, pass, fail)
+        .change_rule_path_extension("css")
+        .test_and_snapshot();
+}
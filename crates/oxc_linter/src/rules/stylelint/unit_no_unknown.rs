@@ -0,0 +1,137 @@
+use cow_utils::CowUtils;
+use oxc_css_parser::ast::ComponentValue;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::{should_run_stylelint, walk_component_values, walk_declarations},
+};
+
+fn unit_no_unknown_diagnostic(span: Span, unit: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unexpected unknown unit \"{unit}\"")).with_label(span)
+}
+
+/// Units of CSS Values and Units Level 4, plus container query and font-relative
+/// units of Level 5. Lowercase, as units are ASCII case-insensitive.
+const KNOWN_UNITS: phf::Set<&'static str> = phf::phf_set! {
+    // font-relative lengths
+    "em", "rem", "ex", "rex", "cap", "rcap", "ch", "rch", "ic", "ric", "lh", "rlh",
+    // viewport-percentage lengths
+    "vw", "svw", "lvw", "dvw", "vh", "svh", "lvh", "dvh", "vi", "svi", "lvi", "dvi",
+    "vb", "svb", "lvb", "dvb", "vmin", "svmin", "lvmin", "dvmin", "vmax", "svmax", "lvmax", "dvmax",
+    // container query lengths
+    "cqw", "cqh", "cqi", "cqb", "cqmin", "cqmax",
+    // absolute lengths
+    "cm", "mm", "q", "in", "pt", "pc", "px",
+    // angles
+    "deg", "grad", "rad", "turn",
+    // durations
+    "s", "ms",
+    // frequencies
+    "hz", "khz",
+    // resolutions
+    "dpi", "dpcm", "dppx", "x",
+    // flexible lengths
+    "fr",
+};
+
+#[derive(Debug, Default, Clone)]
+pub struct UnitNoUnknown;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unknown units.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A dimension with an unknown unit is invalid, and the browser drops the whole declaration.
+    /// This is usually a typo, like `10pixels` or `1.5sec`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```css
+    /// a { width: 10pixels; }
+    /// a { transition: opacity 1.5sec; }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```css
+    /// a { width: 10px; }
+    /// a { transition: opacity 1.5s; }
+    /// a { height: 100dvh; }
+    /// ```
+    UnitNoUnknown,
+    stylelint,
+    correctness,
+    version = "next",
+    short_description = "Disallow unknown units.",
+);
+
+impl Rule for UnitNoUnknown {
+    fn run_once(&self, ctx: &LintContext) {
+        for stylesheet in ctx.stylesheets().iter() {
+            walk_declarations(&stylesheet.stylesheet, &mut |declaration| {
+                walk_component_values(&declaration.value, &mut |value| {
+                    if let ComponentValue::Dimension(dimension) = value
+                        && !KNOWN_UNITS.contains(&dimension.unit.name.cow_to_ascii_lowercase())
+                    {
+                        ctx.diagnostic(unit_no_unknown_diagnostic(
+                            stylesheet.span(&dimension.unit.span),
+                            dimension.unit.raw,
+                        ));
+                    }
+                });
+            });
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        should_run_stylelint(ctx)
+    }
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("a { width: 10px; }", None, None, None),
+        ("a { width: 10PX; }", None, None, None),
+        ("a { width: 100%; margin: 0; }", None, None, None),
+        ("a { height: 100dvh; width: 50cqi; line-height: 1rlh; }", None, None, None),
+        ("a { transform: rotate(45deg); transition: all 1.5s 200ms; }", None, None, None),
+        ("a { width: calc(100% - 2rem); }", None, None, None),
+        ("a { grid-template-columns: 1fr 2fr; }", None, None, None),
+        ("a { background-image: image-set('a.png' 1x, 'a-2x.png' 2x); }", None, None, None),
+        ("@media (min-resolution: 2dppx) { a { color: red; } }", None, None, None),
+        ("a { background: url(10pixels.png); }", None, None, None),
+        ("$gap: 10px; a { margin: $gap * 2; }", None, None, Some(PathBuf::from("test.scss"))),
+        ("const Box = styled.div`width: 10px;`", None, None, Some(PathBuf::from("test.tsx"))),
+    ];
+
+    let fail = vec![
+        ("a { width: 10pixels; }", None, None, None),
+        ("a { transition: opacity 1.5sec; }", None, None, None),
+        ("a { margin: 0 10pz 0 0; }", None, None, None),
+        ("a { width: calc(100% - 10pxx); }", None, None, None),
+        ("a { font-size: 10PIXELS; }", None, None, None),
+        (".a { .b { width: 1xp; } }", None, None, Some(PathBuf::from("test.scss"))),
+        (
+            "const Box = styled.div`\n  padding: ${(p) => p.padding};\n  width: 10pixels;\n`",
+            None,
+            None,
+            Some(PathBuf::from("test.tsx")),
+        ),
+    ];
+
+    Tester::new(UnitNoUnknown::NAME, UnitNoUnknown::PLUGIN, pass, fail)
+        .change_rule_path_extension("css")
+        .test_and_snapshot();
+}
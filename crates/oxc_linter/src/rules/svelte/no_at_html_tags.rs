@@ -0,0 +1,86 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::SvelteTagKind,
+};
+
+fn no_at_html_tags_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("`{@html}` can lead to XSS attacks.")
+        .with_help("Render the content as text, or sanitize it before rendering it as HTML.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoAtHtmlTags;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow the `{@html}` tag in Svelte markup.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `{@html}` inserts its value into the DOM without escaping it.
+    /// If the value contains user input, an attacker can inject scripts into the page.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```svelte
+    /// <p>{@html comment.body}</p>
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```svelte
+    /// <p>{comment.body}</p>
+    /// ```
+    NoAtHtmlTags,
+    svelte,
+    suspicious,
+    version = "next",
+    short_description = "Disallow the `{@html}` tag in Svelte markup.",
+);
+
+impl Rule for NoAtHtmlTags {
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(template) = ctx.svelte_template() else {
+            return;
+        };
+        for tag in template.tags() {
+            if tag.kind == SvelteTagKind::Special("html") {
+                ctx.diagnostic(no_at_html_tags_diagnostic(tag.span));
+            }
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.current_sub_host().template().is_some()
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "<p>{html}</p>",
+        "<p>{'{@html html}'}</p>",
+        "<!-- {@html html} -->",
+        "<script>const html = '{@html html}';</script>\n<p>{html}</p>",
+        "<style>p::before { content: '{@html html}'; }</style>",
+    ];
+
+    let fail = vec![
+        "{@html html}",
+        "<p>{@html comment.body}</p>",
+        "<script>\n  let { html } = $props();\n</script>\n\n{#if html}\n  <div>{@html html}</div>\n{/if}",
+    ];
+
+    Tester::new(NoAtHtmlTags::NAME, NoAtHtmlTags::PLUGIN, pass, fail)
+        .change_rule_path_extension("svelte")
+        .test_and_snapshot();
+}
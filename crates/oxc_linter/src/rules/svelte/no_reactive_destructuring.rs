@@ -0,0 +1,210 @@
+use oxc_ast::{
+    AstKind,
+    ast::{BindingPattern, Expression},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    ast_util::get_declaration_of_variable,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::{get_rune_name, is_svelte_file},
+};
+
+fn no_reactive_destructuring_diagnostic(span: Span, name: &str, rune: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Destructuring `{name}` loses the reactivity of `{rune}(...)`"))
+        .with_help(format!(
+            "The destructured values are read once. Wrap `{name}` in `$derived(...)` to keep them up to date."
+        ))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoReactiveDestructuring;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow destructuring reactive state, derived values or props at the top level of a
+    /// Svelte component or `.svelte.js` module.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The top level of a component runs once. Destructuring a variable declared with
+    /// `$state(...)`, `$derived(...)` or `$props()` there reads its current properties into
+    /// plain variables, which do not update when the state changes.
+    /// Destructuring a `$derived(...)` declaration instead keeps each value reactive.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```svelte
+    /// <script>
+    ///   const props = $props();
+    ///   const { user } = props;
+    ///   let position = $state({ x: 0, y: 0 });
+    ///   let [x, y] = position;
+    /// </script>
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```svelte
+    /// <script>
+    ///   const { user } = $props();
+    ///   let position = $state({ x: 0, y: 0 });
+    ///   const { x, y } = $derived(position);
+    /// </script>
+    /// ```
+    NoReactiveDestructuring,
+    svelte,
+    correctness,
+    suggestion,
+    version = "next",
+    short_description = "Disallow destructuring reactive state, derived values or props at the top level of a Svelte component or `.svelte.js` module.",
+);
+
+impl Rule for NoReactiveDestructuring {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::VariableDeclarator(declarator) = node.kind() else {
+            return;
+        };
+        if !matches!(
+            declarator.id,
+            BindingPattern::ObjectPattern(_) | BindingPattern::ArrayPattern(_)
+        ) || node.scope_id() != ctx.scoping().root_scope_id()
+        {
+            return;
+        }
+        let Some(Expression::Identifier(ident)) =
+            declarator.init.as_ref().map(Expression::get_inner_expression)
+        else {
+            return;
+        };
+        let Some(declaration) = get_declaration_of_variable(ident, ctx.semantic()) else {
+            return;
+        };
+        let AstKind::VariableDeclarator(declaration) = declaration.kind() else {
+            return;
+        };
+        let Some(Expression::CallExpression(call)) =
+            declaration.init.as_ref().map(Expression::get_inner_expression)
+        else {
+            return;
+        };
+        let Some(rune @ ("$state" | "$state.raw" | "$derived" | "$derived.by" | "$props")) =
+            get_rune_name(call, ctx.scoping())
+        else {
+            return;
+        };
+        let init = declarator.init.as_ref().unwrap();
+        ctx.diagnostic_with_suggestion(
+            no_reactive_destructuring_diagnostic(init.span(), &ident.name, rune),
+            |fixer| {
+                fixer.replace(init.span(), format!("$derived({})", fixer.source_range(init.span())))
+            },
+        );
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        is_svelte_file(ctx)
+    }
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("<script>let { a, b } = $props();</script>", None, None, None),
+        (
+            "<script>let position = $state({ x: 0 }); const { x } = $derived(position);</script>",
+            None,
+            None,
+            None,
+        ),
+        ("<script>const props = $props(); const user = props.user;</script>", None, None, None),
+        ("<script>const options = { a: 1 }; const { a } = options;</script>", None, None, None),
+        ("<script>const data = load(); const { a } = data;</script>", None, None, None),
+        (
+            "<script>let position = $state({ x: 0 }); function log() { const { x } = position; console.log(x); }</script>",
+            None,
+            None,
+            None,
+        ),
+        (
+            "<script>let position = $state({ x: 0 }); $effect(() => { const { x } = position; console.log(x); });</script>",
+            None,
+            None,
+            None,
+        ),
+        (
+            "<script>let list = $state.snapshot(items); const [first] = list;</script>",
+            None,
+            None,
+            None,
+        ),
+        (
+            "const state = $state({ a: 1 }); const { a } = state;",
+            None,
+            None,
+            Some(PathBuf::from("state.js")),
+        ),
+    ];
+
+    let fail = vec![
+        ("<script>const props = $props(); const { user } = props;</script>", None, None, None),
+        (
+            "<script>let position = $state({ x: 0, y: 0 }); let [x, y] = position;</script>",
+            None,
+            None,
+            None,
+        ),
+        (
+            "<script>let position = $state.raw({ x: 0 }); let { x } = position;</script>",
+            None,
+            None,
+            None,
+        ),
+        (
+            "<script>const total = $derived(sum(items)); const { count } = total;</script>",
+            None,
+            None,
+            None,
+        ),
+        (
+            "<script lang=\"ts\">const data = $derived.by(() => load()); const { a } = data!;</script>",
+            None,
+            None,
+            None,
+        ),
+        (
+            "export const state = $state({ a: 1 }); export const { a } = state;",
+            None,
+            None,
+            Some(PathBuf::from("state.svelte.js")),
+        ),
+    ];
+
+    let fix = vec![
+        (
+            "<script>const props = $props(); const { user } = props;</script>",
+            "<script>const props = $props(); const { user } = $derived(props);</script>",
+            None,
+        ),
+        (
+            "<script>let position = $state({ x: 0, y: 0 }); let [x, y] = position;</script>",
+            "<script>let position = $state({ x: 0, y: 0 }); let [x, y] = $derived(position);</script>",
+            None,
+        ),
+    ];
+
+    Tester::new(NoReactiveDestructuring::NAME, NoReactiveDestructuring::PLUGIN, pass, fail)
+        .change_rule_path_extension("svelte")
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -0,0 +1,265 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, MethodDefinitionKind, SimpleAssignmentTarget},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::{get_rune_name, is_svelte_file},
+};
+
+fn state_invalid_placement_diagnostic(span: Span, rune: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "`{rune}(...)` can only be used as a variable declaration initializer or a class field"
+    ))
+    .with_help("Declare the state with `let`, or as a class field assigned in the constructor.")
+    .with_label(span)
+}
+
+fn props_invalid_placement_diagnostic(span: Span, rune: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "`{rune}()` can only be used at the top level of a component as a variable declaration initializer"
+    ))
+    .with_label(span)
+}
+
+fn effect_invalid_placement_diagnostic(span: Span, rune: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{rune}()` can only be used as an expression statement"))
+        .with_help("`$effect.root()` returns a cleanup function, if one is needed.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ValidRunePlacement;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require Svelte 5 runes to be called where the Svelte compiler accepts them.
+    ///
+    /// - `$state(...)`, `$state.raw(...)`, `$derived(...)` and `$derived.by(...)` must
+    ///   initialize a variable or a class field, or be assigned to a field of `this` at the
+    ///   top level of a constructor.
+    /// - `$props()` and `$props.id()` must initialize a variable at the top level of a component.
+    /// - `$effect(...)` and `$effect.pre(...)` must be expression statements.
+    ///
+    /// Runes shadowed by a local binding are ignored.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Runes are compiler instructions rather than functions. The compiler rejects
+    /// them anywhere else, such as `return $state(0)` or `items.map(() => $derived(x))`,
+    /// since it cannot turn them into reactive bindings there.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```svelte
+    /// <script>
+    ///   function createCounter() {
+    ///     return { count: $state(0) };
+    ///   }
+    ///   const cleanup = $effect(() => {});
+    /// </script>
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```svelte
+    /// <script>
+    ///   let { initial } = $props();
+    ///   let count = $state(initial);
+    ///   const double = $derived(count * 2);
+    ///   $effect(() => console.log(double));
+    /// </script>
+    /// ```
+    ValidRunePlacement,
+    svelte,
+    correctness,
+    version = "next",
+    short_description = "Require Svelte 5 runes to be called where the Svelte compiler accepts them.",
+);
+
+impl Rule for ValidRunePlacement {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Some(rune) = get_rune_name(call, ctx.scoping()) else {
+            return;
+        };
+        let mut ancestors = ctx.nodes().ancestors(node.id()).filter(|ancestor| {
+            !matches!(
+                ancestor.kind(),
+                AstKind::ParenthesizedExpression(_)
+                    | AstKind::TSAsExpression(_)
+                    | AstKind::TSSatisfiesExpression(_)
+                    | AstKind::TSNonNullExpression(_)
+                    | AstKind::TSTypeAssertion(_)
+            )
+        });
+        let Some(parent) = ancestors.next() else {
+            return;
+        };
+        let is_initializer = matches!(
+            parent.kind(),
+            AstKind::VariableDeclarator(declarator)
+                if declarator.init.as_ref().is_some_and(|init| init.span().contains_inclusive(call.span))
+        );
+
+        match rune {
+            "$state" | "$state.raw" | "$derived" | "$derived.by" => {
+                let is_valid = is_initializer
+                    || match parent.kind() {
+                        AstKind::PropertyDefinition(property) => property
+                            .value
+                            .as_ref()
+                            .is_some_and(|value| value.span().contains_inclusive(call.span)),
+                        AstKind::AssignmentExpression(assignment) => {
+                            assignment.right.span().contains_inclusive(call.span)
+                                && is_this_member(assignment.left.as_simple_assignment_target())
+                                && is_in_constructor_body(ancestors.map(AstNode::kind))
+                        }
+                        _ => false,
+                    };
+                if !is_valid {
+                    ctx.diagnostic(state_invalid_placement_diagnostic(call.span, rune));
+                }
+            }
+            "$props" | "$props.id" => {
+                let is_component = ctx.file_extension().is_some_and(|ext| ext == "svelte");
+                if !(is_initializer
+                    && is_component
+                    && node.scope_id() == ctx.scoping().root_scope_id())
+                {
+                    ctx.diagnostic(props_invalid_placement_diagnostic(call.span, rune));
+                }
+            }
+            "$effect" | "$effect.pre"
+                if !matches!(parent.kind(), AstKind::ExpressionStatement(_)) =>
+            {
+                ctx.diagnostic(effect_invalid_placement_diagnostic(call.span, rune));
+            }
+            _ => {}
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        is_svelte_file(ctx)
+    }
+}
+
+/// Whether `target` is `this.field` or `this.#field`.
+fn is_this_member(target: Option<&SimpleAssignmentTarget>) -> bool {
+    let object = match target {
+        Some(SimpleAssignmentTarget::StaticMemberExpression(member)) => &member.object,
+        Some(SimpleAssignmentTarget::PrivateFieldExpression(field)) => &field.object,
+        _ => return false,
+    };
+    matches!(object, Expression::ThisExpression(_))
+}
+
+/// Whether the ancestors of an assignment are a statement at the top level of a constructor.
+fn is_in_constructor_body<'a>(mut ancestors: impl Iterator<Item = AstKind<'a>>) -> bool {
+    matches!(ancestors.next(), Some(AstKind::ExpressionStatement(_)))
+        && matches!(ancestors.next(), Some(AstKind::FunctionBody(_)))
+        && matches!(ancestors.next(), Some(AstKind::Function(_)))
+        && matches!(
+            ancestors.next(),
+            Some(AstKind::MethodDefinition(method)) if method.kind == MethodDefinitionKind::Constructor
+        )
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("<script>let count = $state(0);</script>", None, None, None),
+        ("<script>let count = $state.raw({ a: 1 });</script>", None, None, None),
+        ("<script>const double = $derived(count * 2);</script>", None, None, None),
+        ("<script>const total = $derived.by(() => items.length);</script>", None, None, None),
+        ("<script lang=\"ts\">let count = $state(0) as number;</script>", None, None, None),
+        ("<script>let { a, b = 1, ...rest } = $props();</script>", None, None, None),
+        ("<script>const id = $props.id();</script>", None, None, None),
+        ("<script>$effect(() => { console.log(count); });</script>", None, None, None),
+        ("<script>$effect.pre(() => {});</script>", None, None, None),
+        ("<script>const cleanup = $effect.root(() => {});</script>", None, None, None),
+        ("<script>const snapshot = $state.snapshot(count);</script>", None, None, None),
+        (
+            "<script>function create() { let count = $state(0); return { get count() { return count; } }; }</script>",
+            None,
+            None,
+            None,
+        ),
+        (
+            "<script>class Counter { count = $state(0); #step = $state(1); }</script>",
+            None,
+            None,
+            None,
+        ),
+        (
+            "<script>class Counter { constructor(initial) { this.count = $state(initial); } }</script>",
+            None,
+            None,
+            None,
+        ),
+        ("<script>import { $state } from './state'; foo($state(0));</script>", None, None, None),
+        ("<script>function f($derived) { return $derived(1); }</script>", None, None, None),
+        (
+            "export function createCounter() { let count = $state(0); return { get count() { return count; } }; }",
+            None,
+            None,
+            Some(PathBuf::from("counter.svelte.js")),
+        ),
+        (
+            "export class Counter { count = $state(0); }",
+            None,
+            None,
+            Some(PathBuf::from("counter.svelte.ts")),
+        ),
+        // Not a Svelte module.
+        ("const count = foo($state(0));", None, None, Some(PathBuf::from("counter.js"))),
+    ];
+
+    let fail = vec![
+        ("<script>function create() { return { count: $state(0) }; }</script>", None, None, None),
+        ("<script>const items = list.map(() => $derived(count));</script>", None, None, None),
+        ("<script>let count; count = $state(0);</script>", None, None, None),
+        ("<script>foo($state.raw([]));</script>", None, None, None),
+        ("<script>const total = $derived.by(() => items.length) + 1;</script>", None, None, None),
+        (
+            "<script>class Counter { constructor() { if (x) { this.count = $state(0); } } }</script>",
+            None,
+            None,
+            None,
+        ),
+        (
+            "<script>class Counter { increment() { this.count = $state(0); } }</script>",
+            None,
+            None,
+            None,
+        ),
+        ("<script>function f() { let { a } = $props(); }</script>", None, None, None),
+        ("<script>foo($props());</script>", None, None, None),
+        ("<script>const cleanup = $effect(() => {});</script>", None, None, None),
+        ("<script>foo($effect.pre(() => {}));</script>", None, None, None),
+        ("export const props = $props();", None, None, Some(PathBuf::from("props.svelte.js"))),
+        (
+            "export const counter = { count: $state(0) };",
+            None,
+            None,
+            Some(PathBuf::from("counter.svelte.ts")),
+        ),
+    ];
+
+    Tester::new(ValidRunePlacement::NAME, ValidRunePlacement::PLUGIN, pass, fail)
+        .change_rule_path_extension("svelte")
+        .test_and_snapshot();
+}
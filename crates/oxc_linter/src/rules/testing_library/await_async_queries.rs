@@ -0,0 +1,120 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::{get_testing_library_query_name, is_promise_handled, is_testing_library_async_query},
+};
+
+fn await_async_queries_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Promise returned from async query `{name}` must be handled"))
+        .with_help(format!(
+            "`{name}` resolves once the element is found. Await it, return it, or chain it with `.then()`."
+        ))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct AwaitAsyncQueries;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require the promises returned by asynchronous queries, such as `findByText` and
+    /// `findAllByRole`, to be handled.
+    ///
+    /// A promise is handled when it is awaited, returned, chained with `.then()`,
+    /// `.catch()` or `.finally()`, passed to `Promise.all()`, asserted with
+    /// `expect(...).resolves` or `expect(...).rejects`, or assigned to a variable which is
+    /// handled in one of these ways.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `find*` queries wait for the element to appear. Without waiting for the promise,
+    /// the test continues before the element is found and never sees the query fail.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// test('shows the user', () => {
+    ///   render(<User />);
+    ///   screen.findByText('Ada');
+    ///   expect(screen.findByRole('button')).toBeDefined();
+    /// });
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// test('shows the user', async () => {
+    ///   render(<User />);
+    ///   await screen.findByText('Ada');
+    ///   const button = screen.findByRole('button');
+    ///   expect(await button).toBeDefined();
+    /// });
+    /// ```
+    AwaitAsyncQueries,
+    testing_library,
+    correctness,
+    version = "next",
+    short_description = "Require the promises returned by asynchronous queries to be handled.",
+);
+
+impl Rule for AwaitAsyncQueries {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Some(name) = get_testing_library_query_name(call) else {
+            return;
+        };
+        if is_testing_library_async_query(name) && !is_promise_handled(node, ctx) {
+            ctx.diagnostic(await_async_queries_diagnostic(call.callee.span(), name));
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.frameworks().is_testing_library()
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "async () => { await screen.findByText('foo'); }",
+        "async () => { await findAllByRole('button'); }",
+        "async () => { const element = await within(list).findByText('foo'); }",
+        "() => screen.findByText('foo')",
+        "() => { return screen.findByText('foo'); }",
+        "() => { screen.findByText('foo').then((element) => element.click()); }",
+        "async () => { await Promise.all([screen.findByText('a'), screen.findByText('b')]); }",
+        "async () => { await expect(screen.findByText('foo')).resolves.toBeInTheDocument(); }",
+        "async () => { const promise = screen.findByText('foo'); await promise; }",
+        "() => { const promise = findByText('foo'); return promise; }",
+        "async () => { await (cond ? screen.findByText('a') : screen.findByText('b')); }",
+        "async () => { var p = screen.findByText('x'); var p = p; await p; }",
+        "() => { screen.getByText('foo'); screen.queryAllByRole('button'); }",
+        "() => { findSomething('foo'); screen.findByFoo('bar'); }",
+    ];
+
+    let fail = vec![
+        "() => { screen.findByText('foo'); }",
+        "() => { findAllByRole('button'); }",
+        "() => { within(list).findByLabelText('Name'); }",
+        "() => { const element = screen.findByTestId('foo'); }",
+        "() => { const promise = screen.findByText('foo'); expect(promise).toBeDefined(); }",
+        "() => { expect(screen.findByText('foo')).toBeInTheDocument(); }",
+        "() => { const elements = [screen.findByText('a')]; }",
+        "async () => { await wait(); screen.findByRole('dialog'); }",
+        "async () => { var p = screen.findByText('x'); var p = p; }",
+        "async () => { await (screen.findByText('a') ? a : b); }",
+    ];
+
+    Tester::new(AwaitAsyncQueries::NAME, AwaitAsyncQueries::PLUGIN, pass, fail).test_and_snapshot();
+}
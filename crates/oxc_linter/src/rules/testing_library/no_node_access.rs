@@ -0,0 +1,131 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+};
+
+fn no_node_access_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Avoid accessing DOM nodes with `{name}`"))
+        .with_help("Query the element with `screen` instead, such as `screen.getByRole(...)`.")
+        .with_label(span)
+}
+
+/// Properties and methods of DOM nodes which return other nodes.
+const NODE_ACCESS_PROPERTIES: [&str; 24] = [
+    "activeElement",
+    "childElementCount",
+    "children",
+    "closest",
+    "firstChild",
+    "firstElementChild",
+    "fullscreenElement",
+    "getElementById",
+    "getElementsByClassName",
+    "getElementsByName",
+    "getElementsByTagName",
+    "getElementsByTagNameNS",
+    "lastChild",
+    "lastElementChild",
+    "nextElementSibling",
+    "nextSibling",
+    "parentElement",
+    "parentNode",
+    "pointerLockElement",
+    "previousElementSibling",
+    "previousSibling",
+    "querySelector",
+    "querySelectorAll",
+    "scripts",
+];
+
+#[derive(Debug, Default, Clone)]
+pub struct NoNodeAccess;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow traversing the DOM directly, with properties such as `parentElement`,
+    /// `children` and `firstChild`, or methods such as `closest` and `querySelector`.
+    ///
+    /// `props.children` is allowed.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Testing Library queries find elements the way users do, by their role, label or
+    /// text. Tests which walk the DOM depend on its exact structure, and break when
+    /// the markup changes although the behavior has not.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const button = screen.getByText('Submit').closest('button');
+    /// const { container } = render(<Form />);
+    /// container.querySelector('input');
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const button = screen.getByRole('button', { name: 'Submit' });
+    /// const input = screen.getByLabelText('Name');
+    /// ```
+    NoNodeAccess,
+    testing_library,
+    pedantic,
+    version = "next",
+    short_description = "Disallow traversing the DOM directly.",
+);
+
+impl Rule for NoNodeAccess {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::StaticMemberExpression(member) = node.kind() else {
+            return;
+        };
+        let name = member.property.name.as_str();
+        if !NODE_ACCESS_PROPERTIES.contains(&name) {
+            return;
+        }
+        if name == "children" && member.object.is_specific_id("props") {
+            return;
+        }
+        ctx.diagnostic(no_node_access_diagnostic(member.property.span, name));
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.frameworks().is_testing_library()
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "screen.getByRole('button', { name: 'Submit' });",
+        "within(screen.getByRole('list')).getAllByRole('listitem');",
+        "expect(screen.getByText('foo')).toBeInTheDocument();",
+        "const { children } = props; function Foo(props) { return props.children; }",
+        "user.parent; element.textContent; element.focus();",
+        "const obj = { firstChild: 1 };",
+    ];
+
+    let fail = vec![
+        "screen.getByText('Submit').closest('button');",
+        "const { container } = render(<Form />); container.querySelector('input');",
+        "container.querySelectorAll('li');",
+        "screen.getByRole('list').children;",
+        "screen.getByText('foo').parentElement.parentElement;",
+        "expect(container.firstChild).toMatchSnapshot();",
+        "document.getElementById('root');",
+        "screen.getByRole('row').nextElementSibling;",
+    ];
+
+    Tester::new(NoNodeAccess::NAME, NoNodeAccess::PLUGIN, pass, fail)
+        .change_rule_path_extension("tsx")
+        .test_and_snapshot();
+}
@@ -0,0 +1,153 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, IdentifierReference},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    ast_util::get_declaration_of_variable,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::get_testing_library_query_name,
+};
+
+fn prefer_screen_queries_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Use `screen.{name}` instead of `{name}`"))
+        .with_help(
+            "Queries on `screen` always search the whole document, and are the same in every test.",
+        )
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferScreenQueries;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require queries to be called on `screen`, rather than destructured from the result
+    /// of `render`.
+    ///
+    /// Queries on `within(element)`, or destructured from it, are allowed, since they are
+    /// scoped to an element. Queries imported from `@testing-library/dom`, which take the
+    /// container as their first argument, are also allowed.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Destructuring queries from `render` makes every test keep track of them, and has
+    /// to be updated whenever another query is used. `screen` is always available and
+    /// searches the whole document.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// const { getByText } = render(<Component />);
+    /// getByText('foo');
+    ///
+    /// const utils = render(<Component />);
+    /// utils.getByRole('button');
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// render(<Component />);
+    /// screen.getByText('foo');
+    /// within(screen.getByRole('list')).getAllByRole('listitem');
+    /// ```
+    PreferScreenQueries,
+    testing_library,
+    style,
+    version = "next",
+    short_description = "Require queries to be called on `screen`.",
+);
+
+impl Rule for PreferScreenQueries {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Some(name) = get_testing_library_query_name(call) else {
+            return;
+        };
+        let is_allowed = match &call.callee {
+            Expression::Identifier(ident) => is_declared_as_queries(ident, ctx),
+            Expression::StaticMemberExpression(member) => {
+                match member.object.get_inner_expression() {
+                    Expression::Identifier(ident) => {
+                        ident.name == "screen" || is_declared_as_queries(ident, ctx)
+                    }
+                    object => is_within_call(object),
+                }
+            }
+            _ => return,
+        };
+        if !is_allowed {
+            ctx.diagnostic(prefer_screen_queries_diagnostic(call.callee.span(), name));
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.frameworks().is_testing_library()
+    }
+}
+
+/// Whether `expression` is `within(element)` or `getQueriesForElement(element)`.
+fn is_within_call(expression: &Expression) -> bool {
+    matches!(
+        expression.get_inner_expression(),
+        Expression::CallExpression(call)
+            if call.callee.is_specific_id("within")
+                || call.callee.is_specific_id("getQueriesForElement")
+    )
+}
+
+/// Whether `ident` is imported, such as the queries of `@testing-library/dom`, or is
+/// initialized with `within(element)`, as in `const { getByText } = within(element)`.
+fn is_declared_as_queries<'a>(ident: &IdentifierReference<'a>, ctx: &LintContext<'a>) -> bool {
+    get_declaration_of_variable(ident, ctx.semantic()).is_some_and(|declaration| match declaration
+        .kind()
+    {
+        AstKind::ImportSpecifier(_) | AstKind::ImportNamespaceSpecifier(_) => true,
+        AstKind::VariableDeclarator(declarator) => {
+            declarator.init.as_ref().is_some_and(is_within_call)
+        }
+        _ => false,
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "screen.getByText('foo');",
+        "screen.queryAllByRole('button');",
+        "await screen.findByTestId('foo');",
+        "within(screen.getByRole('list')).getAllByRole('listitem');",
+        "const list = within(screen.getByRole('list')); list.getByText('foo');",
+        "const { getByText } = within(screen.getByRole('dialog')); getByText('foo');",
+        "getQueriesForElement(container).getByText('foo');",
+        "import { getByText } from '@testing-library/dom'; getByText(container, 'foo');",
+        "import * as dom from '@testing-library/dom'; dom.getByText(container, 'foo');",
+        "const { container } = render(<Foo />); expect(container).toBeDefined();",
+        "utils.getSomething('foo');",
+    ];
+
+    let fail = vec![
+        "const { getByText } = render(<Foo />); getByText('foo');",
+        "const { findAllByRole } = render(<Foo />); await findAllByRole('button');",
+        "const utils = render(<Foo />); utils.getByRole('button');",
+        "render(<Foo />).getByLabelText('Name');",
+        "const { getByText: byText, queryByTestId } = render(<Foo />); queryByTestId('foo');",
+        "getByText('foo');",
+        "component.queryByPlaceholderText('Search');",
+    ];
+
+    Tester::new(PreferScreenQueries::NAME, PreferScreenQueries::PLUGIN, pass, fail)
+        .change_rule_path_extension("tsx")
+        .test_and_snapshot();
+}
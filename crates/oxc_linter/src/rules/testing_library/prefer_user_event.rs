@@ -0,0 +1,163 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use oxc_str::CompactStr;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::{DefaultRuleConfig, Rule},
+};
+
+fn prefer_user_event_diagnostic(span: Span, event: &str, methods: &[&str]) -> OxcDiagnostic {
+    let methods =
+        methods.iter().map(|method| format!("`userEvent.{method}`")).collect::<Vec<_>>().join(", ");
+    OxcDiagnostic::warn(format!("Prefer {methods} over `fireEvent.{event}`"))
+        .with_help("`userEvent` fires the whole sequence of events that a user interaction causes.")
+        .with_label(span)
+}
+
+/// Events of `fireEvent`, and the `userEvent` methods which fire them.
+const USER_EVENT_METHODS: [(&str, &[&str]); 25] = [
+    ("blur", &["tab", "click"]),
+    ("change", &["upload", "type", "clear", "selectOptions", "deselectOptions"]),
+    ("click", &["click", "type", "selectOptions", "deselectOptions"]),
+    ("dblClick", &["dblClick"]),
+    ("focus", &["tab", "click"]),
+    ("input", &["type", "upload", "selectOptions", "deselectOptions", "paste"]),
+    ("keyDown", &["keyboard"]),
+    ("keyPress", &["keyboard"]),
+    ("keyUp", &["keyboard"]),
+    ("mouseDown", &["click", "dblClick", "selectOptions", "deselectOptions"]),
+    ("mouseEnter", &["hover", "unhover"]),
+    ("mouseLeave", &["hover", "unhover"]),
+    ("mouseMove", &["hover", "unhover"]),
+    ("mouseOut", &["unhover"]),
+    ("mouseOver", &["hover"]),
+    ("mouseUp", &["click", "dblClick", "selectOptions", "deselectOptions"]),
+    ("paste", &["paste"]),
+    ("pointerDown", &["click", "dblClick", "selectOptions", "deselectOptions"]),
+    ("pointerEnter", &["hover", "unhover"]),
+    ("pointerLeave", &["hover", "unhover"]),
+    ("pointerMove", &["hover", "unhover"]),
+    ("pointerOut", &["unhover"]),
+    ("pointerOver", &["hover"]),
+    ("pointerUp", &["click", "dblClick", "selectOptions", "deselectOptions"]),
+    ("select", &["selectOptions", "deselectOptions"]),
+];
+
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct PreferUserEventConfig {
+    /// Events of `fireEvent` which are allowed, such as `["blur", "focus"]`.
+    allowed_methods: Vec<CompactStr>,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct PreferUserEvent(Box<PreferUserEventConfig>);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Prefer `userEvent` methods, such as `userEvent.click`, over the `fireEvent` events
+    /// which they fire, such as `fireEvent.click`.
+    ///
+    /// Events without an equivalent in `userEvent`, such as `fireEvent.scroll`, are allowed.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A user interaction fires several events. Clicking an input fires `pointerdown`,
+    /// `mousedown`, `focus`, `pointerup`, `mouseup` and `click`, while `fireEvent.click`
+    /// fires only `click`. Tests using `fireEvent` can pass although the component does
+    /// not work for users, or fail although it does.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// fireEvent.click(screen.getByRole('button'));
+    /// fireEvent.change(input, { target: { value: 'Ada' } });
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const user = userEvent.setup();
+    /// await user.click(screen.getByRole('button'));
+    /// await user.type(input, 'Ada');
+    /// fireEvent.scroll(window, { target: { scrollY: 100 } });
+    /// ```
+    PreferUserEvent,
+    testing_library,
+    style,
+    config = PreferUserEventConfig,
+    version = "next",
+    short_description = "Prefer `userEvent` methods over the `fireEvent` events which they fire.",
+);
+
+impl Rule for PreferUserEvent {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        DefaultRuleConfig::<Self>::from_value(value).map(DefaultRuleConfig::into_inner)
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Some(member) = call.callee.as_member_expression() else {
+            return;
+        };
+        if !member.object().is_specific_id("fireEvent") {
+            return;
+        }
+        let Some((span, event)) = member.static_property_info() else {
+            return;
+        };
+        if self.0.allowed_methods.iter().any(|allowed| allowed == event) {
+            return;
+        }
+        let Some((_, methods)) = USER_EVENT_METHODS.iter().find(|(name, _)| *name == event) else {
+            return;
+        };
+        ctx.diagnostic(prefer_user_event_diagnostic(span, event, methods));
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.frameworks().is_testing_library()
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("await userEvent.click(screen.getByRole('button'));", None),
+        ("const user = userEvent.setup(); await user.type(input, 'Ada');", None),
+        ("fireEvent.scroll(window, { target: { scrollY: 100 } });", None),
+        ("fireEvent.animationEnd(element);", None),
+        ("fireEvent(element, new MouseEvent('click'));", None),
+        ("otherEvent.click(element);", None),
+        ("fireEvent.click(element);", Some(json!([{ "allowedMethods": ["click"] }]))),
+        (
+            "fireEvent.mouseOver(element);",
+            Some(json!([{ "allowedMethods": ["mouseOver", "blur"] }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("fireEvent.click(screen.getByRole('button'));", None),
+        ("fireEvent.change(input, { target: { value: 'Ada' } });", None),
+        ("fireEvent.dblClick(element);", None),
+        ("fireEvent.keyDown(input, { key: 'Enter' });", None),
+        ("fireEvent.mouseOver(element);", None),
+        ("fireEvent['pointerDown'](element);", None),
+        ("fireEvent.blur(input);", Some(json!([{ "allowedMethods": ["focus"] }]))),
+    ];
+
+    Tester::new(PreferUserEvent::NAME, PreferUserEvent::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -1,6 +1,16 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_type_checker::{TypeInference, TypeKind};
 
-use crate::rule::Rule;
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn await_thenable_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected `await` of a non-Promise (non-\"Thenable\") value.")
+        .with_help("Remove the unnecessary `await`.")
+        .with_label(span)
+}
 
 #[derive(Debug, Default, Clone)]
 pub struct AwaitThenable;
@@ -54,4 +64,75 @@ declare_oxc_lint!(
     short_description = "This rule disallows awaiting a value that is not a Thenable.",
 );
 
-impl Rule for AwaitThenable {}
+impl Rule for AwaitThenable {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::AwaitExpression(await_expr) = node.kind() else { return };
+
+        let Some(kind) = TypeInference::new(ctx.semantic()).expression_type(&await_expr.argument)
+        else {
+            return;
+        };
+        if kind.is_thenable() || matches!(kind, TypeKind::Any | TypeKind::Unknown | TypeKind::Never)
+        {
+            return;
+        }
+
+        ctx.diagnostic_with_suggestion(await_thenable_diagnostic(await_expr.span), |fixer| {
+            fixer
+                .delete_range(Span::new(await_expr.span.start, await_expr.argument.span().start))
+                .with_message("Remove unnecessary `await`")
+        });
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "async function f() { await Promise.resolve(1); }",
+        "async function f() { await new Promise((resolve) => resolve(1)); }",
+        "async function f(p: Promise<number>) { await p; }",
+        "async function f(p: PromiseLike<number>) { await p; }",
+        "async function g() {} async function f() { await g(); }",
+        "const g = async () => 1; async function f() { await g(); }",
+        "async function f() { const p = Promise.all([]); await p; }",
+        "async function f() { await Promise.resolve(1).then(() => 2); }",
+        "async function f() { await { then() {} }; }",
+        "async function f(x: any) { await x; }",
+        "async function f(x: unknown) { await x; }",
+        // Not known from this file
+        "import { g } from 'g'; async function f() { await g(); }",
+        "async function f(x) { await x; }",
+        "async function f(x: Foo) { await x; }",
+        "async function f() { let p = 1; p = Promise.resolve(2); await p; }",
+        // `Promise` is shadowed
+        "class Promise {} async function f() { await new Promise(); }",
+    ];
+
+    let fail = vec![
+        "async function f() { await 12; }",
+        "async function f() { await 'value'; }",
+        "async function f() { await (() => {}); }",
+        "async function f() { await null; }",
+        "async function f() { await undefined; }",
+        "async function f(x: number) { await x; }",
+        "async function f() { const x = 'a' + 1; await x; }",
+        "declare const getPromise: () => Promise<string>; async function f() { await getPromise; }",
+        "function g(): number { return 1; } async function f() { await g(); }",
+        "async function f() { await [Promise.resolve(1)]; }",
+    ];
+
+    let fix = vec![
+        ("async function f() { await 12; }", "async function f() { 12; }"),
+        (
+            "async function f(x: number) { return (await x) + 1; }",
+            "async function f(x: number) { return (x) + 1; }",
+        ),
+    ];
+
+    Tester::new(AwaitThenable::NAME, AwaitThenable::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .with_native_type_aware(true)
+        .test_and_snapshot();
+}
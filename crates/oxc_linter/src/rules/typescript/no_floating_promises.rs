@@ -1,12 +1,45 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, CallExpression, Expression, UnaryOperator},
+};
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_type_checker::{TypeInference, TypeKind};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    AstNode,
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
     utils::TypeOrValueSpecifier,
 };
 
+fn floating_promise_diagnostic(span: Span, ignore_void: bool) -> OxcDiagnostic {
+    let message = if ignore_void {
+        "Promises must be awaited, end with a call to .catch, end with a call to .then with a rejection handler or be explicitly marked as ignored with the `void` operator."
+    } else {
+        "Promises must be awaited, end with a call to .catch, or end with a call to .then with a rejection handler."
+    };
+    OxcDiagnostic::warn(message).with_label(span)
+}
+
+fn floating_promise_array_diagnostic(span: Span, ignore_void: bool) -> OxcDiagnostic {
+    let message = if ignore_void {
+        "An array of Promises may be unintentional. Consider handling the promises' fulfillment or rejection with Promise.all or similar, or explicitly marking the expression as ignored with the `void` operator."
+    } else {
+        "An array of Promises may be unintentional. Consider handling the promises' fulfillment or rejection with Promise.all or similar."
+    };
+    OxcDiagnostic::warn(message).with_label(span)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Floating {
+    Promise,
+    PromiseArray,
+}
+
 #[derive(Debug, Default, Clone, Deserialize)]
 pub struct NoFloatingPromises(Box<NoFloatingPromisesConfig>);
 
@@ -120,6 +153,167 @@ impl Rule for NoFloatingPromises {
     fn to_configuration(&self) -> Option<Result<serde_json::Value, serde_json::Error>> {
         Some(serde_json::to_value(&*self.0))
     }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ExpressionStatement(stmt) = node.kind() else { return };
+
+        let infer = TypeInference::new(ctx.semantic());
+        let Some(floating) = self.floating(&stmt.expression, infer) else { return };
+
+        let ignore_void = self.0.ignore_void;
+        let diagnostic = match floating {
+            Floating::Promise => floating_promise_diagnostic(stmt.expression.span(), ignore_void),
+            Floating::PromiseArray => {
+                floating_promise_array_diagnostic(stmt.expression.span(), ignore_void)
+            }
+        };
+        if ignore_void && floating == Floating::Promise {
+            ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                fixer
+                    .insert_text_before(&stmt.expression, "void ")
+                    .with_message("Add void operator to ignore")
+            });
+        } else {
+            ctx.diagnostic(diagnostic);
+        }
+    }
+}
+
+impl NoFloatingPromises {
+    /// Whether `expr`, used as a statement, leaves a Promise unhandled.
+    ///
+    /// Only expressions which this file shows to be Promises are reported.
+    fn floating<'a>(
+        &self,
+        expr: &Expression<'a>,
+        infer: TypeInference<'_, 'a>,
+    ) -> Option<Floating> {
+        match expr.without_parentheses() {
+            Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::Void => {
+                if self.0.ignore_void { None } else { self.floating(&unary.argument, infer) }
+            }
+            Expression::SequenceExpression(sequence) => {
+                sequence.expressions.iter().find_map(|expr| self.floating(expr, infer))
+            }
+            Expression::ConditionalExpression(conditional) => self
+                .floating(&conditional.consequent, infer)
+                .or_else(|| self.floating(&conditional.alternate, infer)),
+            Expression::ArrayExpression(array) => {
+                let has_promise = array.elements.iter().any(|element| {
+                    element
+                        .as_expression()
+                        .is_some_and(|expr| infer.expression_type(expr) == Some(TypeKind::Promise))
+                });
+                has_promise.then_some(Floating::PromiseArray)
+            }
+            expr @ Expression::CallExpression(call) => self.floating_call(expr, call, infer),
+            expr => (infer.expression_type(expr) == Some(TypeKind::Promise))
+                .then_some(Floating::Promise),
+        }
+    }
+
+    fn floating_call<'a>(
+        &self,
+        expr: &Expression<'a>,
+        call: &CallExpression<'a>,
+        infer: TypeInference<'_, 'a>,
+    ) -> Option<Floating> {
+        let callee = call.callee.without_parentheses();
+        if self.0.ignore_iife
+            && matches!(
+                callee,
+                Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_)
+            )
+        {
+            return None;
+        }
+        if let Expression::Identifier(ident) = callee
+            && self.0.allow_for_known_safe_calls.iter().any(|s| s.matches_local_name(&ident.name))
+        {
+            return None;
+        }
+        if let Some(member) = callee.as_member_expression() {
+            let object = member.object();
+            match member.static_property_name() {
+                // A rejection handler handles the Promise
+                Some("then") if call.arguments.len() >= 2 => return None,
+                Some("catch") if !call.arguments.is_empty() => return None,
+                // `.finally()` passes on the rejection, so the Promise is as handled as `object`
+                Some("finally") if infer.expression_type(object) == Some(TypeKind::Promise) => {
+                    return self.floating(object, infer);
+                }
+                // `array.map(async () => {})` creates an array of Promises
+                Some("map")
+                    if infer.expression_type(object) == Some(TypeKind::Array)
+                        && call
+                            .arguments
+                            .first()
+                            .and_then(Argument::as_expression)
+                            .is_some_and(|callback| {
+                                infer.is_promise_returning_function(callback)
+                            }) =>
+                {
+                    return Some(Floating::PromiseArray);
+                }
+                _ => {}
+            }
+        }
+        (infer.expression_type(expr) == Some(TypeKind::Promise)).then_some(Floating::Promise)
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("async function f() { await Promise.resolve(1); }", None),
+        ("async function g() {} async function f() { await g(); }", None),
+        ("async function g() {} function f() { return g(); }", None),
+        ("async function g() {} void g();", None),
+        ("Promise.resolve(1).then(() => {}, () => {});", None),
+        ("Promise.reject(1).catch(() => {});", None),
+        ("Promise.reject(1).catch(() => {}).finally(() => {});", None),
+        ("const p = Promise.resolve(1); const q = p;", None),
+        ("function g() { return 1; } g();", None),
+        ("import { g } from 'g'; g();", None),
+        ("declare function g(): Foo; g();", None),
+        ("foo.bar();", None),
+        ("[1, 2, 3].map((x) => x + 1);", None),
+        ("(async () => {})();", Some(json!([{ "ignoreIIFE": true }]))),
+        ("async function g() {} g();", Some(json!([{ "allowForKnownSafeCalls": ["g"] }]))),
+        (
+            "async function g() {} g();",
+            Some(json!([{ "allowForKnownSafeCalls": [{ "from": "file", "name": ["g", "h"] }] }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("async function g() {} g();", None),
+        ("const g = async () => {}; g();", None),
+        ("function g(): Promise<void> { return Promise.resolve(); } g();", None),
+        ("declare const g: () => Promise<string>; g();", None),
+        ("const p = new Promise((resolve) => resolve(1)); p;", None),
+        ("Promise.resolve(1);", None),
+        ("Promise.resolve(1).then(() => {});", None),
+        ("Promise.reject(1).finally(() => {});", None),
+        ("(async () => {})();", None),
+        ("async function g() {} cond ? g() : null;", None),
+        ("async function g() {} void g();", Some(json!([{ "ignoreVoid": false }]))),
+        ("async function g() {} [g(), 1];", None),
+        ("[1, 2, 3].map(async (x) => x + 1);", None),
+    ];
+
+    let fix = vec![
+        ("async function g() {} g();", "async function g() {} void g();"),
+        ("Promise.resolve(1).then(() => {});", "void Promise.resolve(1).then(() => {});"),
+    ];
+
+    Tester::new(NoFloatingPromises::NAME, NoFloatingPromises::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .with_native_type_aware(true)
+        .test_and_snapshot();
 }
 
 #[cfg(test)]
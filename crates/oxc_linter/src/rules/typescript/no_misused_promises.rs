@@ -1,8 +1,47 @@
+use oxc_ast::{
+    AstKind,
+    ast::{
+        Argument, CallExpression, Expression, FormalParameter, LogicalOperator, ObjectPropertyKind,
+        TSType, TSTypeAnnotation, UnaryOperator,
+    },
+};
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_type_checker::{TypeInference, TypeKind};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::rule::{DefaultRuleConfig, Rule};
+use crate::{
+    AstNode,
+    ast_util::get_declaration_of_variable,
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+};
+
+fn conditional_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected non-Promise value in a boolean conditional.")
+        .with_help("Did you forget to `await` the Promise?")
+        .with_label(span)
+}
+
+fn spread_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected a non-Promise value to be spreaded in an object.")
+        .with_help("Did you forget to `await` the Promise?")
+        .with_label(span)
+}
+
+fn void_return_argument_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Promise returned in function argument where a void return was expected.")
+        .with_label(span)
+}
+
+fn void_return_variable_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "Promise-returning function provided to variable where a void return was expected.",
+    )
+    .with_label(span)
+}
 
 fn default_checks_void_return() -> ChecksVoidReturn {
     ChecksVoidReturn::Boolean(true)
@@ -61,6 +100,22 @@ pub struct ChecksVoidReturnOptions {
     pub variables: bool,
 }
 
+impl ChecksVoidReturn {
+    fn arguments(&self) -> bool {
+        match self {
+            Self::Boolean(enabled) => *enabled,
+            Self::Options(options) => options.arguments,
+        }
+    }
+
+    fn variables(&self) -> bool {
+        match self {
+            Self::Boolean(enabled) => *enabled,
+            Self::Options(options) => options.variables,
+        }
+    }
+}
+
 impl Default for ChecksVoidReturnOptions {
     fn default() -> Self {
         Self {
@@ -138,4 +193,164 @@ impl Rule for NoMisusedPromises {
     fn to_configuration(&self) -> Option<Result<serde_json::Value, serde_json::Error>> {
         Some(serde_json::to_value(&*self.0))
     }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let infer = TypeInference::new(ctx.semantic());
+        let config = &self.0;
+        match node.kind() {
+            AstKind::IfStatement(stmt) if config.checks_conditionals => {
+                check_conditional(&stmt.test, infer, ctx);
+            }
+            AstKind::WhileStatement(stmt) if config.checks_conditionals => {
+                check_conditional(&stmt.test, infer, ctx);
+            }
+            AstKind::DoWhileStatement(stmt) if config.checks_conditionals => {
+                check_conditional(&stmt.test, infer, ctx);
+            }
+            AstKind::ForStatement(stmt) if config.checks_conditionals => {
+                if let Some(test) = &stmt.test {
+                    check_conditional(test, infer, ctx);
+                }
+            }
+            AstKind::ConditionalExpression(expr) if config.checks_conditionals => {
+                check_conditional(&expr.test, infer, ctx);
+            }
+            // The right operand of `a && b` is only used as a value
+            AstKind::LogicalExpression(expr)
+                if config.checks_conditionals && expr.operator != LogicalOperator::Coalesce =>
+            {
+                check_conditional(&expr.left, infer, ctx);
+            }
+            AstKind::UnaryExpression(expr)
+                if config.checks_conditionals && expr.operator == UnaryOperator::LogicalNot =>
+            {
+                check_conditional(&expr.argument, infer, ctx);
+            }
+            AstKind::ObjectExpression(object) if config.checks_spreads => {
+                for property in &object.properties {
+                    if let ObjectPropertyKind::SpreadProperty(spread) = property
+                        && infer.expression_type(&spread.argument) == Some(TypeKind::Promise)
+                    {
+                        ctx.diagnostic(spread_diagnostic(spread.argument.span()));
+                    }
+                }
+            }
+            AstKind::CallExpression(call) if config.checks_void_return.arguments() => {
+                for (index, argument) in call.arguments.iter().enumerate() {
+                    let Some(argument) = argument.as_expression() else { continue };
+                    if infer.is_promise_returning_function(argument)
+                        && expects_void_returning_argument(call, index, infer, ctx)
+                    {
+                        ctx.diagnostic(void_return_argument_diagnostic(argument.span()));
+                    }
+                }
+            }
+            AstKind::VariableDeclarator(decl) if config.checks_void_return.variables() => {
+                if let Some(annotation) = &decl.type_annotation
+                    && is_void_returning_function_type(annotation)
+                    && let Some(init) = &decl.init
+                    && infer.is_promise_returning_function(init)
+                {
+                    ctx.diagnostic(void_return_variable_diagnostic(init.span()));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn check_conditional<'a>(
+    test: &Expression<'a>,
+    infer: TypeInference<'_, 'a>,
+    ctx: &LintContext<'a>,
+) {
+    if infer.expression_type(test) == Some(TypeKind::Promise) {
+        ctx.diagnostic(conditional_diagnostic(test.span()));
+    }
+}
+
+/// Whether argument `index` of `call` is a callback whose return value is ignored, as known
+/// from this file: the callback of `array.forEach()`, or a parameter of a function declared in
+/// this file which is annotated as a `void`-returning function type.
+fn expects_void_returning_argument<'a>(
+    call: &CallExpression<'a>,
+    index: usize,
+    infer: TypeInference<'_, 'a>,
+    ctx: &LintContext<'a>,
+) -> bool {
+    let callee = call.callee.without_parentheses();
+    if let Some(member) = callee.as_member_expression() {
+        return index == 0
+            && member.static_property_name() == Some("forEach")
+            && infer.expression_type(member.object()) == Some(TypeKind::Array);
+    }
+    let Expression::Identifier(ident) = callee else { return false };
+    let Some(declaration) = get_declaration_of_variable(ident, ctx.semantic()) else {
+        return false;
+    };
+    let AstKind::Function(func) = declaration.kind() else { return false };
+    if call.arguments.iter().take(index + 1).any(Argument::is_spread) {
+        return false;
+    }
+    func.params
+        .items
+        .get(index)
+        .and_then(|param: &FormalParameter| param.type_annotation.as_deref())
+        .is_some_and(is_void_returning_function_type)
+}
+
+fn is_void_returning_function_type(annotation: &TSTypeAnnotation) -> bool {
+    matches!(
+        &annotation.type_annotation,
+        TSType::TSFunctionType(func)
+            if matches!(func.return_type.type_annotation, TSType::TSVoidKeyword(_))
+    )
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("async function f() { const p = Promise.resolve(1); if (await p) {} }", None),
+        ("function f(x: boolean) { if (x) {} }", None),
+        ("declare const p: Foo; if (p) {}", None),
+        ("async function g() {} const x = g() ?? 1;", None),
+        ("const p = Promise.resolve(1); const x = cond && p;", None),
+        ("const p = Promise.resolve(1); if (p) {}", Some(json!([{ "checksConditionals": false }]))),
+        ("async function f() { const p = Promise.resolve({}); ({ ...(await p) }); }", None),
+        ("const p = Promise.resolve({}); ({ ...p });", Some(json!([{ "checksSpreads": false }]))),
+        ("for (const x of [1, 2, 3]) {}", None),
+        ("[1, 2, 3].forEach((x) => {});", None),
+        ("[1, 2, 3].map(async (x) => x);", None),
+        ("foo.forEach(async (x) => {});", None),
+        ("function g(cb: () => Promise<void>) {} g(async () => {});", None),
+        ("function g(cb: () => void) {} g(() => {});", None),
+        ("const cb: () => Promise<void> = async () => {};", None),
+        (
+            "[1, 2, 3].forEach(async (x) => {});",
+            Some(json!([{ "checksVoidReturn": { "arguments": false } }])),
+        ),
+        ("const cb: () => void = async () => {};", Some(json!([{ "checksVoidReturn": false }]))),
+    ];
+
+    let fail = vec![
+        ("const p = Promise.resolve(1); if (p) {}", None),
+        ("async function g() {} while (g()) {}", None),
+        ("const p = new Promise(() => {}); do {} while (p);", None),
+        ("const p = Promise.resolve(1); for (; p; ) {}", None),
+        ("const p = Promise.resolve(1); const x = p ? 1 : 2;", None),
+        ("const p = Promise.resolve(1); const x = p || 1;", None),
+        ("const p = Promise.resolve(1); const x = !p;", None),
+        ("const getData = async () => ({}); console.log({ foo: 42, ...getData() });", None),
+        ("[1, 2, 3].forEach(async (x) => { await x; });", None),
+        ("async function cb() {} [1, 2, 3].forEach(cb);", None),
+        ("function g(cb: () => void) {} g(async () => {});", None),
+        ("const cb: () => void = async () => {};", None),
+    ];
+
+    Tester::new(NoMisusedPromises::NAME, NoMisusedPromises::PLUGIN, pass, fail)
+        .with_native_type_aware(true)
+        .test_and_snapshot();
 }
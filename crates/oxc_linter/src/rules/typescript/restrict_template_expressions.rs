@@ -1,12 +1,26 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_type_checker::{TypeInference, TypeKind};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    AstNode,
+    context::LintContext,
     rule::{DefaultRuleConfig, Rule},
     utils::{LibFrom, LibSpecifier, NameSpecifier, TypeOrValueSpecifier},
 };
 
+fn invalid_type_diagnostic(span: Span, kind: TypeKind) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Invalid type \"{}\" of template literal expression.",
+        kind.as_str()
+    ))
+    .with_label(span)
+}
+
 fn default_restrict_template_allow() -> Vec<TypeOrValueSpecifier> {
     vec![TypeOrValueSpecifier::Lib(LibSpecifier {
         from: LibFrom::Lib,
@@ -135,4 +149,81 @@ impl Rule for RestrictTemplateExpressions {
     fn to_configuration(&self) -> Option<Result<serde_json::Value, serde_json::Error>> {
         Some(serde_json::to_value(&*self.0))
     }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::TemplateLiteral(template) = node.kind() else { return };
+        // Tagged templates pass their expressions to the tag, rather than stringifying them
+        if matches!(ctx.nodes().parent_kind(node.id()), AstKind::TaggedTemplateExpression(_)) {
+            return;
+        }
+
+        let infer = TypeInference::new(ctx.semantic());
+        for expr in &template.expressions {
+            let Some(kind) = infer.expression_type(expr) else { continue };
+            if !self.is_allowed(kind) {
+                ctx.diagnostic(invalid_type_diagnostic(expr.span(), kind));
+            }
+        }
+    }
+}
+
+impl RestrictTemplateExpressions {
+    fn is_allowed(&self, kind: TypeKind) -> bool {
+        let config = &self.0;
+        match kind {
+            TypeKind::String => true,
+            TypeKind::Any => config.allow_any,
+            TypeKind::Array => config.allow_array,
+            TypeKind::Boolean => config.allow_boolean,
+            TypeKind::Null | TypeKind::Undefined | TypeKind::Void => config.allow_nullish,
+            TypeKind::Number | TypeKind::BigInt => config.allow_number,
+            TypeKind::RegExp => config.allow_reg_exp,
+            TypeKind::Never => config.allow_never,
+            TypeKind::Unknown
+            | TypeKind::Symbol
+            | TypeKind::Promise
+            | TypeKind::Function
+            | TypeKind::Object => false,
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("const msg = `arg = ${'foo'}`;", None),
+        ("function f(x: string) { return `${x}`; }", None),
+        ("const msg = `arg = ${1} ${1n}`;", None),
+        ("const msg = `arg = ${true}`;", None),
+        ("const msg = `arg = ${null} ${undefined}`;", None),
+        ("const msg = `arg = ${/a/}`;", None),
+        ("function f(x: any) { return `${x}`; }", None),
+        ("function f(x: Foo) { return `${x}`; }", None),
+        ("function f(x) { return `${x}`; }", None),
+        ("const msg = tag`${{}}`;", None),
+        ("const msg = `${[1, 2]}`;", Some(json!([{ "allowArray": true }]))),
+        ("function f(x: never) { return `${x}`; }", Some(json!([{ "allowNever": true }]))),
+    ];
+
+    let fail = vec![
+        ("const msg = `arg = ${{}}`;", None),
+        ("const msg = `arg = ${[1, 2]}`;", None),
+        ("const msg = `arg = ${() => {}}`;", None),
+        ("const msg = `arg = ${Promise.resolve(1)}`;", None),
+        ("function f(x: unknown) { return `${x}`; }", None),
+        ("function f(x: symbol) { return `${x}`; }", None),
+        ("function f(x: never) { return `${x}`; }", None),
+        ("function f(x: any) { return `${x}`; }", Some(json!([{ "allowAny": false }]))),
+        ("const msg = `arg = ${1}`;", Some(json!([{ "allowNumber": false }]))),
+        ("const msg = `arg = ${true}`;", Some(json!([{ "allowBoolean": false }]))),
+        ("const msg = `arg = ${null}`;", Some(json!([{ "allowNullish": false }]))),
+        ("const msg = `arg = ${/a/}`;", Some(json!([{ "allowRegExp": false }]))),
+    ];
+
+    Tester::new(RestrictTemplateExpressions::NAME, RestrictTemplateExpressions::PLUGIN, pass, fail)
+        .with_native_type_aware(true)
+        .test_and_snapshot();
 }
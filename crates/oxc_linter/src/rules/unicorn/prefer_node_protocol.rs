@@ -1,21 +1,6 @@
-use nodejs_built_in_modules::is_nodejs_builtin_module;
-use oxc_ast::{
-    AstKind,
-    ast::{Expression, TSModuleReference},
-};
-use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
 
-use crate::{AstNode, context::LintContext, rule::Rule};
-
-fn prefer_node_protocol_diagnostic(span: Span, module_name: &str) -> OxcDiagnostic {
-    OxcDiagnostic::warn(
-        "Prefer using the `node:` protocol when importing Node.js built-in modules.",
-    )
-    .with_help(format!("Prefer `node:{module_name}` over `{module_name}`."))
-    .with_label(span)
-}
+use crate::{AstNode, context::LintContext, rule::Rule, rules::shared::prefer_node_protocol};
 
 #[derive(Debug, Default, Clone)]
 pub struct PreferNodeProtocol;
@@ -50,47 +35,7 @@ declare_oxc_lint!(
 
 impl Rule for PreferNodeProtocol {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        let string_lit_value_with_span = match node.kind() {
-            AstKind::ImportExpression(import) => match &import.source {
-                Expression::StringLiteral(str_lit) => Some((str_lit.value, str_lit.span)),
-                _ => None,
-            },
-            AstKind::TSImportEqualsDeclaration(import) => match &import.module_reference {
-                TSModuleReference::ExternalModuleReference(external) => {
-                    Some((external.expression.value, external.expression.span))
-                }
-                _ => None,
-            },
-            AstKind::CallExpression(call) if !call.optional => {
-                call.common_js_require().map(|s| (s.value, s.span))
-            }
-            AstKind::ImportDeclaration(import) => Some((import.source.value, import.source.span)),
-            AstKind::ExportFromDeclaration(export) => {
-                Some((export.source.value, export.source.span))
-            }
-            _ => return,
-        };
-        let Some((string_lit_value, span)) = string_lit_value_with_span else {
-            return;
-        };
-        let module_name = string_lit_value.as_str();
-        if module_name.starts_with("node:") || !is_nodejs_builtin_module(module_name) {
-            return;
-        }
-
-        ctx.diagnostic_with_fix(
-            prefer_node_protocol_diagnostic(span, &string_lit_value),
-            |fixer| {
-                // Smallest module name is 2 chars, plus 2 for quotes = 4.
-                debug_assert!(
-                    span.size() >= 4,
-                    "node stdlib module name should be at least 4 chars long"
-                );
-                // We're replacing inside the string literal, shift to account for quotes.
-                let span = span.shrink_left(1).shrink_right(1);
-                fixer.replace(span, format!("node:{string_lit_value}"))
-            },
-        );
+        prefer_node_protocol::run(node, ctx, |_| true);
     }
 }
 
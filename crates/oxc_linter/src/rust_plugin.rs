@@ -0,0 +1,371 @@
+//! Custom rules written in Rust, compiled to a dynamic library and loaded at runtime.
+//!
+//! A plugin is a `cdylib` crate which depends on the same `oxc_linter` as the `oxlint` binary
+//! loading it, implements its rules with [`Rule`] and [`RuleMeta`], and declares itself with
+//! [`declare_rust_plugin!`]:
+//!
+//! ```ignore
+//! fn register(registrar: &mut RustPluginRegistrar) {
+//!     registrar.register_rule::<NoFoo>();
+//! }
+//!
+//! oxc_linter::declare_rust_plugin!("acme", register);
+//! ```
+//!
+//! The plugin is then enabled in `.oxlintrc.json` with `"rustPlugins": ["./libacme.so"]`,
+//! and its rules configured like any other rule, e.g. `"acme/no-foo": "error"`.
+//!
+//! Rust has no stable ABI, so the plugin must be built with the same `oxc_linter` version,
+//! features, dependency versions, compiler, target and build profile as `oxlint`.
+//! The [`RustPluginDeclaration`] records all of these, and plugins which don't match are
+//! rejected before any of their code is called.
+//!
+//! [`declare_rust_plugin!`]: crate::declare_rust_plugin
+
+use std::{
+    alloc::{GlobalAlloc, Layout},
+    ffi::{CStr, c_char},
+    fmt,
+    path::{Path, PathBuf},
+    ptr,
+    sync::{
+        LazyLock, Mutex,
+        atomic::{AtomicPtr, Ordering},
+    },
+};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_semantic::AstNode;
+
+use crate::{
+    context::{ContextHost, LintContext},
+    rule::{Rule, RuleFixMeta, RuleMeta},
+    utils::PossibleJestNode,
+};
+
+/// Version of the plugin ABI.
+///
+/// Bump this when the layout of [`RustPluginDeclaration`] changes. `abi_version` must stay its
+/// first field, so that a mismatched declaration can always be detected.
+pub const RUST_PLUGIN_ABI_VERSION: u32 = 1;
+
+/// Name of the symbol which [`declare_rust_plugin!`](crate::declare_rust_plugin) exports.
+const DECLARATION_SYMBOL: &CStr = c"OXLINT_RUST_PLUGIN";
+
+/// Identifies the build of `oxc_linter` a plugin was compiled against.
+/// NUL-terminated, so it can be read through a C pointer before anything else is trusted.
+///
+/// The hashes of the enabled features and of the locked versions of the dependencies are
+/// computed by the build script, as both can change the layout of types shared with plugins.
+const BUILD_ID: &str = constcat::concat!(
+    "oxc_linter ",
+    env!("CARGO_PKG_VERSION"),
+    "; ",
+    env!("OXC_LINTER_RUSTC_VERSION"),
+    "; ",
+    env!("OXC_LINTER_TARGET"),
+    "; ",
+    BUILD_PROFILE,
+    "; features ",
+    env!("OXC_LINTER_FEATURES_HASH"),
+    "; dependencies ",
+    env!("OXC_LINTER_DEPENDENCIES_HASH"),
+    "\0"
+);
+
+const BUILD_PROFILE: &str = if cfg!(debug_assertions) { "debug-assertions" } else { "release" };
+
+/// Declaration exported by a plugin. Create it with
+/// [`declare_rust_plugin!`](crate::declare_rust_plugin) rather than by hand.
+#[repr(C)]
+pub struct RustPluginDeclaration {
+    abi_version: u32,
+    build_id: *const c_char,
+    name: &'static str,
+    allocator: &'static HostAllocator,
+    register: fn(&mut RustPluginRegistrar),
+}
+
+// SAFETY: `build_id` points to a `'static` string which is never mutated.
+unsafe impl Sync for RustPluginDeclaration {}
+
+impl RustPluginDeclaration {
+    #[doc(hidden)]
+    pub const fn new(
+        name: &'static str,
+        allocator: &'static HostAllocator,
+        register: fn(&mut RustPluginRegistrar),
+    ) -> Self {
+        Self {
+            abi_version: RUST_PLUGIN_ABI_VERSION,
+            build_id: BUILD_ID.as_ptr().cast(),
+            name,
+            allocator,
+            register,
+        }
+    }
+}
+
+/// Global allocator of a plugin.
+///
+/// Rules, diagnostics and fixes are allocated on one side of the plugin boundary and dropped on
+/// the other, so both sides must share one allocator. This forwards every allocation to the
+/// global allocator of the `oxlint` binary, which is installed when the plugin is loaded,
+/// before any plugin code runs.
+///
+/// There is deliberately no fallback: memory from another allocator could end up freed by the
+/// host's, or the other way around. Allocating before the host allocator is installed fails,
+/// and freeing aborts the process.
+pub struct HostAllocator {
+    host: AtomicPtr<HostAllocatorFns>,
+}
+
+struct HostAllocatorFns {
+    alloc: unsafe fn(Layout) -> *mut u8,
+    alloc_zeroed: unsafe fn(Layout) -> *mut u8,
+    dealloc: unsafe fn(*mut u8, Layout),
+    realloc: unsafe fn(*mut u8, Layout, usize) -> *mut u8,
+}
+
+/// Entry points of the global allocator of the binary this copy of `oxc_linter` is linked into.
+static GLOBAL_ALLOCATOR_FNS: HostAllocatorFns = HostAllocatorFns {
+    alloc: std::alloc::alloc,
+    alloc_zeroed: std::alloc::alloc_zeroed,
+    dealloc: std::alloc::dealloc,
+    realloc: std::alloc::realloc,
+};
+
+impl HostAllocator {
+    #[doc(hidden)]
+    pub const fn new() -> Self {
+        Self { host: AtomicPtr::new(ptr::null_mut()) }
+    }
+
+    /// Forward all allocations to the global allocator of the calling binary.
+    fn install(&self) {
+        self.host.store(ptr::from_ref(&GLOBAL_ALLOCATOR_FNS).cast_mut(), Ordering::Release);
+    }
+
+    fn host(&self) -> Option<&'static HostAllocatorFns> {
+        // SAFETY: The pointer is either null or was created from a `'static` reference in `install`
+        unsafe { self.host.load(Ordering::Acquire).as_ref() }
+    }
+}
+
+// SAFETY: All memory is allocated and freed by the host's global allocator. Before it is
+// installed, no allocation succeeds, so there is no memory to free, reallocate or leak to the host.
+unsafe impl GlobalAlloc for HostAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        match self.host() {
+            // SAFETY: Caller upholds the contract of `GlobalAlloc::alloc`
+            Some(host) => unsafe { (host.alloc)(layout) },
+            None => ptr::null_mut(),
+        }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        match self.host() {
+            // SAFETY: Caller upholds the contract of `GlobalAlloc::alloc_zeroed`
+            Some(host) => unsafe { (host.alloc_zeroed)(layout) },
+            None => ptr::null_mut(),
+        }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        match self.host() {
+            // SAFETY: Caller upholds the contract of `GlobalAlloc::dealloc`
+            Some(host) => unsafe { (host.dealloc)(ptr, layout) },
+            // `ptr` was not allocated by this allocator
+            None => std::process::abort(),
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        match self.host() {
+            // SAFETY: Caller upholds the contract of `GlobalAlloc::realloc`
+            Some(host) => unsafe { (host.realloc)(ptr, layout, new_size) },
+            // `ptr` was not allocated by this allocator
+            None => std::process::abort(),
+        }
+    }
+}
+
+/// Collects the rules of a plugin while it is being loaded.
+#[derive(Default)]
+pub struct RustPluginRegistrar {
+    pub(crate) rules: Vec<RustPluginRuleDefinition>,
+}
+
+impl RustPluginRegistrar {
+    /// Register a rule. It is enabled with `<plugin name>/<R::NAME>` in the config.
+    pub fn register_rule<R: Rule + RuleMeta + Send + Sync + 'static>(&mut self) {
+        self.rules.push(RustPluginRuleDefinition {
+            name: R::NAME,
+            fix: R::FIX,
+            configure: |config| Ok(Box::new(R::from_configuration(config)?)),
+        });
+    }
+}
+
+pub(crate) struct RustPluginRuleDefinition {
+    pub name: &'static str,
+    pub fix: RuleFixMeta,
+    pub configure: fn(serde_json::Value) -> Result<Box<dyn DynRule>, serde_json::Error>,
+}
+
+/// Object-safe subset of [`Rule`], used to run configured rules of plugins.
+pub(crate) trait DynRule: fmt::Debug + Send + Sync {
+    fn should_run(&self, ctx: &ContextHost) -> bool;
+
+    fn run_once(&self, ctx: &LintContext);
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>);
+
+    fn run_on_jest_node<'a, 'c>(
+        &self,
+        jest_node: &PossibleJestNode<'a, 'c>,
+        ctx: &'c LintContext<'a>,
+    );
+}
+
+impl<R: Rule + Send + Sync> DynRule for R {
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        Rule::should_run(self, ctx)
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        Rule::run_once(self, ctx);
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        Rule::run(self, node, ctx);
+    }
+
+    fn run_on_jest_node<'a, 'c>(
+        &self,
+        jest_node: &PossibleJestNode<'a, 'c>,
+        ctx: &'c LintContext<'a>,
+    ) {
+        Rule::run_on_jest_node(self, jest_node, ctx);
+    }
+}
+
+/// A loaded plugin.
+pub(crate) struct LoadedRustPlugin {
+    pub name: &'static str,
+    pub rules: Vec<RustPluginRuleDefinition>,
+}
+
+/// Declarations of the plugins loaded so far, by canonical path.
+///
+/// Libraries are never unloaded, so when a config is loaded again, e.g. when the language
+/// server reloads it, their declarations are reused rather than loading the library again.
+static LOADED_PLUGINS: LazyLock<Mutex<FxHashMap<PathBuf, &'static RustPluginDeclaration>>> =
+    LazyLock::new(Mutex::default);
+
+/// Names which plugins are registered under.
+///
+/// Rule contexts need `'static` plugin names. A name is only allocated the first time it is
+/// used, so registering plugins again, e.g. on every config reload, does not use more memory.
+static PLUGIN_NAMES: LazyLock<Mutex<FxHashSet<&'static str>>> = LazyLock::new(Mutex::default);
+
+/// A `'static` copy of the plugin name `name`.
+pub(crate) fn intern_plugin_name(name: &str) -> &'static str {
+    let mut names = PLUGIN_NAMES.lock().expect("plugin names mutex poisoned");
+    if let Some(name) = names.get(name) {
+        return name;
+    }
+    let name: &'static str = Box::leak(name.into());
+    names.insert(name);
+    name
+}
+
+/// Load the plugin at `path` and collect its rules. `path` must be canonical.
+///
+/// The library is never unloaded, as its code backs rules and diagnostics until the process exits.
+pub(crate) fn load_rust_plugin(path: &Path) -> Result<LoadedRustPlugin, String> {
+    let mut loaded_plugins = LOADED_PLUGINS.lock().expect("loaded plugins mutex poisoned");
+    let declaration = if let Some(declaration) = loaded_plugins.get(path) {
+        *declaration
+    } else {
+        let declaration = load_declaration(path)?;
+        loaded_plugins.insert(path.to_path_buf(), declaration);
+        declaration
+    };
+    drop(loaded_plugins);
+
+    let mut registrar = RustPluginRegistrar::default();
+    (declaration.register)(&mut registrar);
+
+    Ok(LoadedRustPlugin { name: declaration.name, rules: registrar.rules })
+}
+
+/// Load the library at `path`, check that it is a plugin built for this `oxc_linter`, and
+/// switch it to the global allocator.
+fn load_declaration(path: &Path) -> Result<&'static RustPluginDeclaration, String> {
+    // SAFETY: Loading a library runs its initializers. Like JS plugins, Rust plugins are trusted code.
+    let library = unsafe { libloading::Library::new(path) }.map_err(|err| err.to_string())?;
+
+    // SAFETY: `OXLINT_RUST_PLUGIN` is a data symbol, so its address is read rather than called
+    let declaration = unsafe { library.get::<*const RustPluginDeclaration>(DECLARATION_SYMBOL) }
+        .map(|symbol| *symbol)
+        .map_err(|_| {
+            "Not an oxlint Rust plugin: it does not export `OXLINT_RUST_PLUGIN`. \
+             Declare the plugin with `oxc_linter::declare_rust_plugin!`."
+                .to_string()
+        })?;
+
+    // SAFETY: `abi_version` is the first field in every version of the declaration
+    let abi_version = unsafe { declaration.cast::<u32>().read() };
+    if abi_version != RUST_PLUGIN_ABI_VERSION {
+        return Err(format!(
+            "Plugin uses ABI version {abi_version}, but oxlint uses version {RUST_PLUGIN_ABI_VERSION}. \
+             Rebuild the plugin against the `oxc_linter` version used by oxlint."
+        ));
+    }
+
+    // SAFETY: Same ABI version, so the declaration has the layout of `RustPluginDeclaration`,
+    // and lives as long as the library, which is leaked below
+    let declaration = unsafe { &*declaration };
+    // SAFETY: `build_id` is a NUL-terminated `'static` string
+    let build_id = unsafe { CStr::from_ptr(declaration.build_id) }.to_string_lossy();
+    let expected_build_id = &BUILD_ID[..BUILD_ID.len() - 1];
+    if build_id != expected_build_id {
+        return Err(format!(
+            "Plugin was built for `{build_id}`, but oxlint was built for `{expected_build_id}`. \
+             Rust plugins must be built with the same `oxc_linter` version, features, dependency versions, \
+             compiler and profile as oxlint."
+        ));
+    }
+
+    std::mem::forget(library);
+
+    // Before `register`, the first plugin code to run
+    declaration.allocator.install();
+
+    Ok(declaration)
+}
+
+/// Declare a Rust plugin, exporting it from the `cdylib` for oxlint to load.
+///
+/// Takes the plugin name, and a function which registers the plugin's rules with a
+/// [`RustPluginRegistrar`].
+///
+/// Also installs the plugin's global allocator, so must not be combined with `#[global_allocator]`.
+#[macro_export]
+macro_rules! declare_rust_plugin {
+    ($name:expr, $register:path $(,)?) => {
+        #[global_allocator]
+        static OXLINT_RUST_PLUGIN_ALLOCATOR: $crate::rust_plugin::HostAllocator =
+            $crate::rust_plugin::HostAllocator::new();
+
+        #[unsafe(no_mangle)]
+        pub static OXLINT_RUST_PLUGIN: $crate::rust_plugin::RustPluginDeclaration =
+            $crate::rust_plugin::RustPluginDeclaration::new(
+                $name,
+                &OXLINT_RUST_PLUGIN_ALLOCATOR,
+                $register,
+            );
+    };
+}
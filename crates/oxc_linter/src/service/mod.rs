@@ -21,6 +21,9 @@ pub struct LintServiceOptions {
     tsconfig: Option<PathBuf>,
 
     cross_module: bool,
+
+    /// Lint files with syntax errors the parser can recover from.
+    recover_from_errors: bool,
 }
 
 impl LintServiceOptions {
@@ -29,7 +32,7 @@ impl LintServiceOptions {
     where
         T: Into<Box<Path>>,
     {
-        Self { cwd: cwd.into(), tsconfig: None, cross_module: false }
+        Self { cwd: cwd.into(), tsconfig: None, cross_module: false, recover_from_errors: false }
     }
 
     #[inline]
@@ -54,6 +57,18 @@ impl LintServiceOptions {
         self
     }
 
+    /// Lint files with syntax errors, if the parser can recover from them
+    /// (see [`oxc_parser::ParseOptions::recover_from_errors`]).
+    ///
+    /// The syntax errors are reported along with the lint diagnostics, and diagnostics within
+    /// source text discarded by the parser are dropped.
+    #[inline]
+    #[must_use]
+    pub fn with_recover_from_errors(mut self, recover_from_errors: bool) -> Self {
+        self.recover_from_errors = recover_from_errors;
+        self
+    }
+
     #[inline]
     pub fn cwd(&self) -> &Path {
         &self.cwd
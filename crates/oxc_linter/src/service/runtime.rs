@@ -5,7 +5,7 @@ use std::{
     hash::BuildHasherDefault,
     mem::take,
     path::{Path, PathBuf},
-    sync::{Arc, Mutex, mpsc},
+    sync::{Arc, Mutex, Weak, mpsc},
 };
 
 use indexmap::IndexSet;
@@ -24,17 +24,20 @@ use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, OxcDiagnostic}
 use oxc_parser::{ParseOptions, Parser, Token, config::RuntimeParserConfig};
 use oxc_resolver::Resolver;
 use oxc_semantic::{Semantic, SemanticBuilder};
-use oxc_span::{SourceType, VALID_EXTENSIONS};
+use oxc_span::{SourceType, Span, VALID_EXTENSIONS};
 use oxc_str::CompactStr;
 
 use crate::{
     Fixer, Linter, Message, PossibleFixes, RuleTimingStore,
     context::{ContextSubHost, ContextSubHostOptions},
     disable_directives::DisableDirectives,
-    loader::{JavaScriptSource, LINT_PARTIAL_LOADER_EXTENSIONS, PartialLoader},
+    loader::{
+        JavaScriptSource, LINT_JSON_EXTENSIONS, LINT_PARTIAL_LOADER_EXTENSIONS,
+        LINT_STYLESHEET_EXTENSIONS, PartialLoader,
+    },
     module_record::ModuleRecord,
     suppression::DiffManager,
-    utils::read_to_arena_str,
+    utils::{NodeResolver, Workspaces, read_to_arena_str},
 };
 
 use super::LintServiceOptions;
@@ -45,7 +48,12 @@ type ModulesByPath =
 pub struct Runtime {
     cwd: Box<Path>,
     pub(super) linter: Linter,
-    resolver: Option<Resolver>,
+    /// Resolves the imports of the module graph when `cross_module` is enabled,
+    /// and configures the resolver of the `node` rules.
+    resolver: Resolver,
+    cross_module: bool,
+    /// Lint sections with syntax errors the parser recovered from.
+    recover_from_errors: bool,
 
     /// Pool of allocators for parsing and linting.
     allocator_pool: AllocatorPool,
@@ -139,6 +147,29 @@ struct SectionContent<'a> {
     /// Parser tokens for the section.
     /// Empty if section parsing failed, or if token collection was not requested (no JS plugins).
     parser_tokens: ArenaBox<'a, [Token]>,
+    /// Syntax errors the parser recovered from. Empty unless `Runtime.recover_from_errors` is set.
+    recovered_errors: RecoveredErrors,
+}
+
+/// Syntax errors of a section which is still linted, because the parser recovered from them.
+/// Spans are relative to the whole file.
+#[derive(Default)]
+struct RecoveredErrors {
+    diagnostics: Vec<OxcDiagnostic>,
+    /// Source text discarded by the parser. Lint diagnostics within it are dropped.
+    discarded_spans: Vec<Span>,
+}
+
+impl RecoveredErrors {
+    fn extend(&mut self, other: Self) {
+        self.diagnostics.extend(other.diagnostics);
+        self.discarded_spans.extend(other.discarded_spans);
+    }
+
+    /// Whether `span` is within source text discarded by the parser.
+    fn is_discarded(&self, span: Span) -> bool {
+        self.discarded_spans.iter().any(|discarded| discarded.contains_inclusive(span))
+    }
 }
 
 /// A module with its source text and semantic, ready to be linted.
@@ -256,7 +287,7 @@ impl Runtime {
         #[cfg(not(all(target_pointer_width = "64", target_endian = "little")))]
         let allocator_pool = AllocatorPool::new(thread_count);
 
-        let resolver = options.cross_module.then(|| Self::get_resolver(options.tsconfig));
+        let resolver = Self::get_resolver(options.tsconfig);
 
         Self {
             allocator_pool,
@@ -265,6 +296,8 @@ impl Runtime {
             cwd: options.cwd,
             linter,
             resolver,
+            cross_module: options.cross_module,
+            recover_from_errors: options.recover_from_errors,
             modules_by_path: papaya::HashMap::builder()
                 .hasher(BuildHasherDefault::default())
                 .resize_mode(papaya::ResizeMode::Blocking)
@@ -284,6 +317,17 @@ impl Runtime {
         pool
     }
 
+    /// A resolver for the `node` rules with empty caches, so that a long-running service
+    /// such as the language server sees changes to `node_modules` and `package.json` files.
+    fn node_resolver(&self) -> Arc<NodeResolver> {
+        Arc::new(NodeResolver::new(&self.resolver))
+    }
+
+    /// The workspaces for the `json` rules, empty for the same reason as [`Self::node_resolver`].
+    fn workspaces() -> Arc<Workspaces> {
+        Arc::default()
+    }
+
     pub fn set_disable_directives_map(
         &mut self,
         map: Arc<Mutex<FxHashMap<PathBuf, DisableDirectives>>>,
@@ -365,7 +409,7 @@ impl Runtime {
         tx_error: Option<&'a DiagnosticSender>,
         on_module_to_lint: impl Fn(&'a Self, ModuleToLint) + Send + Sync + Clone + 'a,
     ) {
-        if self.resolver.is_none() {
+        if !self.cross_module {
             paths.par_iter().for_each(|path| {
                 let output =
                     self.process_path(file_system, paths, path, check_syntax_errors, tx_error);
@@ -611,6 +655,8 @@ impl Runtime {
         rule_timing_store: Option<&RuleTimingStore>,
     ) {
         self.modules_by_path.pin().reserve(paths.len());
+        let node_resolver = &self.node_resolver();
+        let workspaces = &Self::workspaces();
         let paths_set: IndexSet<Arc<OsStr>, FxBuildHasher> = paths.into_iter().collect();
 
         rayon::scope(|scope| {
@@ -634,24 +680,45 @@ impl Runtime {
                             dep.section_contents.len()
                         );
 
+                        let fix = me.linter.options().fix.is_some();
+                        // Module records are needed to lint the fixed source text again.
+                        let original_records: SmallVec<[Option<Arc<ModuleRecord>>; 1]> = if fix {
+                            module_to_lint
+                                .section_module_records
+                                .iter()
+                                .map(|record_result| record_result.as_ref().ok().cloned())
+                                .collect()
+                        } else {
+                            SmallVec::new()
+                        };
+
                         let respect_eslint_disable_directives =
                             me.linter.respect_eslint_disable_directives();
+                        let mut recovered_errors = RecoveredErrors::default();
                         let context_sub_hosts: Vec<ContextSubHost<'_>> = module_to_lint
                             .section_module_records
                             .into_iter()
                             .zip(dep.section_contents.drain(..))
                             .filter_map(|(record_result, section)| match record_result {
-                                Ok(module_record) => Some(ContextSubHost::new(
-                                    section.semantic.unwrap(),
-                                    Arc::clone(&module_record),
-                                    section.source.start,
-                                    ContextSubHostOptions {
-                                        framework_options: section.source.framework_options,
-                                        parser_tokens: section.parser_tokens,
-                                        respect_eslint_disable_directives,
-                                        ..Default::default()
-                                    },
-                                )),
+                                Ok(module_record) => {
+                                    recovered_errors.extend(section.recovered_errors);
+                                    Some(ContextSubHost::new(
+                                        section.semantic.unwrap(),
+                                        Arc::clone(&module_record),
+                                        section.source.start,
+                                        ContextSubHostOptions {
+                                            framework_options: section.source.framework_options,
+                                            parser_tokens: section.parser_tokens,
+                                            stylesheet: section.source.stylesheet,
+                                            json: section.source.json,
+                                            template: section.source.template,
+                                            respect_eslint_disable_directives,
+                                            node_resolver: Some(Arc::clone(node_resolver)),
+                                            workspaces: Some(Arc::clone(workspaces)),
+                                            ..Default::default()
+                                        },
+                                    ))
+                                }
                                 Err(messages) => {
                                     if !messages.is_empty() {
                                         let diagnostics = DiagnosticService::wrap_diagnostics(
@@ -667,11 +734,21 @@ impl Runtime {
                             })
                             .collect();
 
+                        if !recovered_errors.diagnostics.is_empty() {
+                            let diagnostics = DiagnosticService::wrap_diagnostics(
+                                &me.cwd,
+                                path,
+                                dep.source_text,
+                                take(&mut recovered_errors.diagnostics),
+                            );
+                            tx_error.send(diagnostics).unwrap();
+                        }
+
                         if context_sub_hosts.is_empty() {
                             return;
                         }
 
-                        let (mut messages, disable_directives) =
+                        let (mut messages, mut disable_directives) =
                             me.linter.run_with_disable_directives::<TIMINGS>(
                                 path,
                                 context_sub_hosts,
@@ -679,16 +756,9 @@ impl Runtime {
                                 me.js_allocator_pool(),
                                 rule_timing_store,
                             );
+                        messages.retain(|message| !recovered_errors.is_discarded(message.span));
 
-                        // Store the disable directives for this file
-                        if let Some(disable_directives) = disable_directives {
-                            me.disable_directives_map
-                                .lock()
-                                .expect("disable_directives_map mutex poisoned")
-                                .insert(path.to_path_buf(), disable_directives);
-                        }
-
-                        if me.linter.options().fix.is_some() {
+                        if fix {
                             let fix_result = Fixer::new(
                                 dep.source_text,
                                 messages,
@@ -696,19 +766,33 @@ impl Runtime {
                                     if st.is_javascript() { st.with_jsx(true) } else { st }
                                 }),
                             )
-                            .fix();
+                            .fix_in_passes(|fixed_code| {
+                                let (messages, directives) = me.relint_fixed_source(
+                                    path,
+                                    fixed_code,
+                                    &original_records,
+                                    node_resolver,
+                                    workspaces,
+                                    allocator_guard,
+                                )?;
+                                disable_directives = directives;
+                                Some(messages)
+                            });
                             if fix_result.fixed {
-                                // write to file, replacing only the changed part
-                                let start = 0;
-                                let end = start + dep.source_text.len();
-                                new_source_text
-                                    .to_mut()
-                                    .replace_range(start..end, &fix_result.fixed_code);
+                                new_source_text = fix_result.fixed_code;
                             }
 
                             messages = fix_result.messages;
                         }
 
+                        // Store the disable directives for this file
+                        if let Some(disable_directives) = disable_directives {
+                            me.disable_directives_map
+                                .lock()
+                                .expect("disable_directives_map mutex poisoned")
+                                .insert(path.to_path_buf(), disable_directives);
+                        }
+
                         if !diff_manager.skip() {
                             messages = diff_manager.collect_file(path, &self.cwd, messages);
                         }
@@ -718,7 +802,7 @@ impl Runtime {
                             let diagnostics = DiagnosticService::wrap_diagnostics(
                                 &me.cwd,
                                 path,
-                                dep.source_text,
+                                &new_source_text,
                                 errors,
                             );
                             tx_error.send(diagnostics).unwrap();
@@ -756,6 +840,8 @@ impl Runtime {
         use std::sync::Mutex;
 
         self.modules_by_path.pin().reserve(paths.len());
+        let node_resolver = &self.node_resolver();
+        let workspaces = &Self::workspaces();
         let paths_set: IndexSet<Arc<OsStr>, FxBuildHasher> = paths.into_iter().collect();
 
         let messages = Mutex::new(Vec::<Message>::new());
@@ -777,22 +863,33 @@ impl Runtime {
 
                             let respect_eslint_disable_directives =
                                 me.linter.respect_eslint_disable_directives();
+                            let mut recovered_errors = RecoveredErrors::default();
                             let context_sub_hosts: Vec<ContextSubHost<'_>> = module_to_lint
                                 .section_module_records
                                 .into_iter()
                                 .zip(section_contents.drain(..))
                                 .filter_map(|(record_result, section)| match record_result {
-                                    Ok(module_record) => Some(ContextSubHost::new(
-                                        section.semantic.unwrap(),
-                                        Arc::clone(&module_record),
-                                        section.source.start,
-                                        ContextSubHostOptions {
-                                            framework_options: section.source.framework_options,
-                                            parser_tokens: section.parser_tokens,
-                                            respect_eslint_disable_directives,
-                                            ..Default::default()
-                                        },
-                                    )),
+                                    Ok(module_record) => {
+                                        recovered_errors.extend(section.recovered_errors);
+                                        Some(ContextSubHost::new(
+                                            section.semantic.unwrap(),
+                                            Arc::clone(&module_record),
+                                            section.source.start,
+                                            ContextSubHostOptions {
+                                                framework_options: section
+                                                    .source
+                                                    .framework_options,
+                                                parser_tokens: section.parser_tokens,
+                                                stylesheet: section.source.stylesheet,
+                                                json: section.source.json,
+                                                template: section.source.template,
+                                                respect_eslint_disable_directives,
+                                                node_resolver: Some(Arc::clone(node_resolver)),
+                                                workspaces: Some(Arc::clone(workspaces)),
+                                                ..Default::default()
+                                            },
+                                        ))
+                                    }
                                     Err(diagnostics) => {
                                         if !diagnostics.is_empty() {
                                             messages.lock().unwrap().extend(
@@ -806,13 +903,21 @@ impl Runtime {
                                 })
                                 .collect();
 
+                            if !recovered_errors.diagnostics.is_empty() {
+                                messages.lock().unwrap().extend(
+                                    take(&mut recovered_errors.diagnostics).into_iter().map(
+                                        |diagnostic| Message::new(diagnostic, PossibleFixes::None),
+                                    ),
+                                );
+                            }
+
                             if context_sub_hosts.is_empty() {
                                 return;
                             }
 
                             let path = Path::new(&module_to_lint.path);
 
-                            let (section_messages, disable_directives) =
+                            let (mut section_messages, disable_directives) =
                                 me.linter.run_with_disable_directives::<false>(
                                     path,
                                     context_sub_hosts,
@@ -820,6 +925,8 @@ impl Runtime {
                                     me.js_allocator_pool(),
                                     None,
                                 );
+                            section_messages
+                                .retain(|message| !recovered_errors.is_discarded(message.span));
 
                             if let Some(disable_directives) = disable_directives {
                                 me.disable_directives_map
@@ -863,24 +970,23 @@ impl Runtime {
                                 section_contents.len()
                             );
 
-                            for (record_result, _section) in module_to_lint
+                            for (record_result, section) in module_to_lint
                                 .section_module_records
                                 .into_iter()
                                 .zip(section_contents.drain(..))
                             {
-                                match record_result {
-                                    Ok(_) => {}
-                                    Err(diagnostics) => {
-                                        if !diagnostics.is_empty() {
-                                            let wrapped = DiagnosticService::wrap_diagnostics(
-                                                &me.cwd,
-                                                Path::new(&module_to_lint.path),
-                                                source_text,
-                                                diagnostics,
-                                            );
-                                            tx_error.send(wrapped).unwrap();
-                                        }
-                                    }
+                                let diagnostics = match record_result {
+                                    Ok(_) => section.recovered_errors.diagnostics,
+                                    Err(diagnostics) => diagnostics,
+                                };
+                                if !diagnostics.is_empty() {
+                                    let wrapped = DiagnosticService::wrap_diagnostics(
+                                        &me.cwd,
+                                        Path::new(&module_to_lint.path),
+                                        source_text,
+                                        diagnostics,
+                                    );
+                                    tx_error.send(wrapped).unwrap();
                                 }
                             }
                         },
@@ -901,6 +1007,8 @@ impl Runtime {
         use std::sync::Mutex;
 
         self.modules_by_path.pin().reserve(paths.len());
+        let node_resolver = &self.node_resolver();
+        let workspaces = &Self::workspaces();
         let paths_set: IndexSet<Arc<OsStr>, FxBuildHasher> = paths.into_iter().collect();
 
         let messages = Mutex::new(Vec::<Message>::new());
@@ -932,7 +1040,12 @@ impl Runtime {
                                     ContextSubHostOptions {
                                         framework_options: section.source.framework_options,
                                         parser_tokens: section.parser_tokens,
+                                        stylesheet: section.source.stylesheet,
+                                        json: section.source.json,
+                                        template: section.source.template,
                                         respect_eslint_disable_directives,
+                                        node_resolver: Some(Arc::clone(node_resolver)),
+                                        workspaces: Some(Arc::clone(workspaces)),
                                         ..Default::default()
                                     },
                                 )),
@@ -994,6 +1107,13 @@ impl Runtime {
             return None;
         }
 
+        // Stylesheets and JSON files are linted only as entry files, and only by their plugin.
+        if (LINT_STYLESHEET_EXTENSIONS.contains(&ext) || LINT_JSON_EXTENSIONS.contains(&ext))
+            && !(paths.contains(path) && self.linter.lints_path(Path::new(path)))
+        {
+            return None;
+        }
+
         let allocator_guard = self.allocator_pool.get();
 
         if paths.contains(path) {
@@ -1064,6 +1184,89 @@ impl Runtime {
         }
     }
 
+    /// Parses and lints the module at `path` again, after fixes were applied to its source text.
+    ///
+    /// The fixed source text is allocated in the same arena as the original one, and module
+    /// records of the original sections lend their resolved imports to the new ones.
+    /// Returns `None` if any section of the fixed source text fails to parse, including when
+    /// the parser recovered from a syntax error, so that fixes never introduce syntax errors.
+    ///
+    /// Rules are not timed, since `--timing` reports the time spent on the original source text.
+    fn relint_fixed_source(
+        &self,
+        path: &Path,
+        source_text: &str,
+        original_records: &[Option<Arc<ModuleRecord>>],
+        node_resolver: &Arc<NodeResolver>,
+        workspaces: &Arc<Workspaces>,
+        allocator: &Allocator,
+    ) -> Option<(Vec<Message>, Option<DisableDirectives>)> {
+        let ext = path.extension().and_then(OsStr::to_str)?;
+        let mut source_type = SourceType::from_path(path).unwrap_or_default();
+        if source_type.is_javascript() {
+            source_type = source_type.with_jsx(true);
+        }
+        let source_text = allocator.alloc_str(source_text);
+
+        let mut section_contents = SectionContents::new();
+        let records = self.process_source(
+            path,
+            ext,
+            true,
+            source_type,
+            source_text,
+            allocator,
+            Some(&mut section_contents),
+        );
+
+        let respect_eslint_disable_directives = self.linter.respect_eslint_disable_directives();
+        let mut context_sub_hosts = Vec::with_capacity(records.len());
+        for (index, (record_result, section)) in
+            records.into_iter().zip(section_contents).enumerate()
+        {
+            let module_record = record_result.ok()?.module_record;
+            if !section.recovered_errors.diagnostics.is_empty() {
+                return None;
+            }
+            if let Some(Some(original_record)) = original_records.get(index) {
+                module_record.write_loaded_modules().extend(
+                    original_record
+                        .loaded_modules()
+                        .iter()
+                        .map(|(specifier, module)| (specifier.clone(), Weak::clone(module))),
+                );
+            }
+            context_sub_hosts.push(ContextSubHost::new(
+                section.semantic.unwrap(),
+                module_record,
+                section.source.start,
+                ContextSubHostOptions {
+                    framework_options: section.source.framework_options,
+                    parser_tokens: section.parser_tokens,
+                    stylesheet: section.source.stylesheet,
+                    json: section.source.json,
+                    template: section.source.template,
+                    respect_eslint_disable_directives,
+                    node_resolver: Some(Arc::clone(node_resolver)),
+                    workspaces: Some(Arc::clone(workspaces)),
+                    ..Default::default()
+                },
+            ));
+        }
+
+        if context_sub_hosts.is_empty() {
+            return Some((Vec::new(), None));
+        }
+
+        Some(self.linter.run_with_disable_directives::<false>(
+            path,
+            context_sub_hosts,
+            allocator,
+            self.js_allocator_pool(),
+            None,
+        ))
+    }
+
     #[expect(clippy::too_many_arguments)]
     fn process_source<'a>(
         &self,
@@ -1075,8 +1278,12 @@ impl Runtime {
         allocator: &'a Allocator,
         mut out_sections: Option<&mut SectionContents<'a>>,
     ) -> SmallVec<[Result<ResolvedModuleRecord, Vec<OxcDiagnostic>>; 1]> {
-        let section_sources = PartialLoader::parse(ext, source_text)
+        let mut section_sources = PartialLoader::parse(ext, source_text)
             .unwrap_or_else(|| vec![JavaScriptSource::partial(source_text, source_type, 0)]);
+        // The markup of a Svelte component, for the `svelte` template rules.
+        if ext == "svelte" && self.linter.lints_svelte_markup(path) {
+            section_sources.push(JavaScriptSource::template(source_text));
+        }
 
         let mut section_module_records = SmallVec::<
             [Result<ResolvedModuleRecord, Vec<OxcDiagnostic>>; 1],
@@ -1089,26 +1296,26 @@ impl Runtime {
                 section_source.source_type,
                 check_syntax_errors,
             ) {
-                Ok((record, semantic, parser_tokens)) => {
+                Ok((record, semantic, parser_tokens, recovered_errors)) => {
                     section_module_records.push(Ok(record));
                     if let Some(sections) = &mut out_sections {
+                        let RecoveredErrors { diagnostics, discarded_spans } = recovered_errors;
                         sections.push(SectionContent {
                             source: section_source,
                             semantic: Some(semantic),
                             parser_tokens,
+                            recovered_errors: RecoveredErrors {
+                                diagnostics: offset_diagnostics(diagnostics, section_source.start),
+                                discarded_spans: discarded_spans
+                                    .into_iter()
+                                    .map(|span| span.move_right(section_source.start))
+                                    .collect(),
+                            },
                         });
                     }
                 }
                 Err(err) => {
-                    let err: Vec<OxcDiagnostic> = err
-                        .into_iter()
-                        .map(|mut diagnostic| {
-                            for label in &mut diagnostic.labels {
-                                label.set_span_offset(label.offset() + section_source.start);
-                            }
-                            diagnostic
-                        })
-                        .collect();
+                    let err = offset_diagnostics(err, section_source.start);
 
                     section_module_records.push(Err(err));
                     if let Some(sections) = &mut out_sections {
@@ -1116,6 +1323,7 @@ impl Runtime {
                             source: section_source,
                             semantic: None,
                             parser_tokens: ArenaBox::new_empty_boxed_slice(),
+                            recovered_errors: RecoveredErrors::default(),
                         });
                     }
                 }
@@ -1132,20 +1340,33 @@ impl Runtime {
         source_text: &'a str,
         source_type: SourceType,
         check_syntax_errors: bool,
-    ) -> Result<(ResolvedModuleRecord, Semantic<'a>, ArenaBox<'a, [Token]>), Vec<OxcDiagnostic>>
-    {
+    ) -> Result<
+        (ResolvedModuleRecord, Semantic<'a>, ArenaBox<'a, [Token]>, RecoveredErrors),
+        Vec<OxcDiagnostic>,
+    > {
         let collect_tokens = self.linter.has_external_linter();
         let ret = Parser::new(allocator, source_text, source_type)
             .with_options(ParseOptions {
                 parse_regular_expression: true,
                 allow_return_outside_function: true,
+                recover_from_errors: self.recover_from_errors,
                 ..ParseOptions::default()
             })
             .with_config(RuntimeParserConfig::new(collect_tokens))
             .parse();
 
+        let mut recovered_errors = RecoveredErrors::default();
         if !ret.diagnostics.is_empty() {
-            return Err(if ret.is_flow_language { vec![] } else { ret.diagnostics.into() });
+            if ret.is_flow_language {
+                return Err(vec![]);
+            }
+            if !self.recover_from_errors || ret.panicked {
+                return Err(ret.diagnostics.into());
+            }
+            recovered_errors = RecoveredErrors {
+                diagnostics: ret.diagnostics.into(),
+                discarded_spans: ret.discarded_spans.into_vec(),
+            };
         }
 
         let semantic_ret = SemanticBuilder::new_linter()
@@ -1153,7 +1374,9 @@ impl Runtime {
             .build(allocator.alloc(ret.program));
 
         if !semantic_ret.diagnostics.is_empty() {
-            return Err(semantic_ret.diagnostics.into());
+            let mut diagnostics = recovered_errors.diagnostics;
+            diagnostics.extend(semantic_ret.diagnostics);
+            return Err(diagnostics);
         }
 
         let mut semantic = semantic_ret.semantic;
@@ -1166,13 +1389,13 @@ impl Runtime {
         let mut resolved_module_requests: Vec<ResolvedModuleRequest> = vec![];
 
         // If import plugin is enabled.
-        if let Some(resolver) = &self.resolver {
+        if self.cross_module {
             // Retrieve all dependent modules from this module.
             resolved_module_requests = module_record
                 .requested_modules
                 .keys()
                 .filter_map(|specifier| {
-                    let resolution = resolver.resolve_file(path, specifier).ok()?;
+                    let resolution = self.resolver.resolve_file(path, specifier).ok()?;
                     Some(ResolvedModuleRequest {
                         specifier: specifier.clone(),
                         resolved_requested_path: Arc::<OsStr>::from(resolution.path().as_os_str()),
@@ -1180,6 +1403,24 @@ impl Runtime {
                 })
                 .collect();
         }
-        Ok((ResolvedModuleRecord { module_record, resolved_module_requests }, semantic, tokens))
+        Ok((
+            ResolvedModuleRecord { module_record, resolved_module_requests },
+            semantic,
+            tokens,
+            recovered_errors,
+        ))
     }
 }
+
+/// Move the labels of diagnostics of a section by the offset of the section in the file.
+fn offset_diagnostics(diagnostics: Vec<OxcDiagnostic>, offset: u32) -> Vec<OxcDiagnostic> {
+    diagnostics
+        .into_iter()
+        .map(|mut diagnostic| {
+            for label in &mut diagnostic.labels {
+                label.set_span_offset(label.offset() + offset);
+            }
+            diagnostic
+        })
+        .collect()
+}
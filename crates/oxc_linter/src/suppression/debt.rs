@@ -0,0 +1,426 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display, Write},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use oxc_diagnostics::OxcDiagnostic;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::suppression::{FileSuppressionsMap, Filename};
+
+/// A calendar date in the `YYYY-MM-DD` format, used for the `expires` field of suppressions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SuppressionDate {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl SuppressionDate {
+    pub fn new(year: u16, month: u8, day: u8) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+        Some(Self { year, month, day })
+    }
+
+    /// Parses a date in the `YYYY-MM-DD` format.
+    pub fn parse(date: &str) -> Option<Self> {
+        let mut parts = date.split('-');
+        let (Some(year), Some(month), Some(day), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return None;
+        };
+        if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+            return None;
+        }
+        Self::new(year.parse().ok()?, month.parse().ok()?, day.parse().ok()?)
+    }
+
+    /// The current date in UTC.
+    pub fn today() -> Self {
+        let seconds = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        #[expect(clippy::cast_possible_wrap)]
+        Self::from_days_since_epoch((seconds / 86_400) as i64)
+    }
+
+    /// Converts a number of days since 1970-01-01 into a date.
+    ///
+    /// <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn from_days_since_epoch(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u8;
+        let month = if month_index < 10 { month_index + 3 } else { month_index - 9 } as u8;
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        Self { year: year as u16, month, day }
+    }
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl Display for SuppressionDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl Serialize for SuppressionDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SuppressionDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let date = String::deserialize(deserializer)?;
+        Self::parse(&date).ok_or_else(|| {
+            serde::de::Error::custom(format!("invalid date `{date}`, expected `YYYY-MM-DD`"))
+        })
+    }
+}
+
+/// Repository-wide settings for the suppressions of a single rule,
+/// stored under the `$rules` key of the suppression file.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct RuleBudget {
+    /// Maximum number of suppressed violations across all files.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget: Option<usize>,
+    /// Who is responsible for paying down the suppressed violations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// Date after which the remaining violations are no longer accepted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires: Option<SuppressionDate>,
+}
+
+/// Checks the rule budgets and expiry dates against the current violation counts.
+///
+/// `counts` holds the violations of every file tracked by the suppression file, using the
+/// counts of this run for linted files. Expired entries are only reported while they still
+/// have violations left.
+pub fn check_debt_limits(
+    rules: &BTreeMap<String, RuleBudget>,
+    file_entries: &FxHashMap<Filename, FileSuppressionsMap>,
+    counts: &FxHashMap<Filename, FileSuppressionsMap>,
+    today: SuppressionDate,
+) -> Vec<OxcDiagnostic> {
+    let mut totals: FxHashMap<&str, usize> = FxHashMap::default();
+    for file_counts in counts.values() {
+        for (rule, diagnostic) in file_counts {
+            *totals.entry(rule).or_default() += diagnostic.count;
+        }
+    }
+
+    let mut errors = vec![];
+
+    for (rule, settings) in rules {
+        let total = totals.get(rule.as_str()).copied().unwrap_or_default();
+        let owner = settings.owner.as_deref();
+
+        if let Some(budget) = settings.budget
+            && total > budget
+        {
+            errors.push(with_owner(
+                OxcDiagnostic::error(format!(
+                    "Rule `{rule}` has {}, which exceeds its suppression budget of {budget}.",
+                    violations(total)
+                ))
+                .with_help(format!(
+                    "Fix the new violations of `{rule}`, or raise its budget in the suppressions file."
+                )),
+                owner,
+            ));
+        }
+
+        if let Some(expires) = settings.expires
+            && expires < today
+            && total > 0
+        {
+            errors.push(with_owner(
+                OxcDiagnostic::error(format!(
+                    "Suppressions for rule `{rule}` expired on {expires}, {} {}.",
+                    violations(total),
+                    if total == 1 { "remains" } else { "remain" }
+                ))
+                .with_help(format!(
+                    "Fix the remaining violations of `{rule}`, or extend its expiry date in the suppressions file."
+                )),
+                owner,
+            ));
+        }
+    }
+
+    let mut expired_entries: Vec<(&Filename, &String, SuppressionDate, Option<&str>)> = vec![];
+    for (filename, file_rules) in file_entries {
+        for (rule, entry) in file_rules {
+            let Some(expires) = entry.expires else { continue };
+            let remaining = counts
+                .get(filename)
+                .and_then(|file_counts| file_counts.get(rule))
+                .map_or(0, |diagnostic| diagnostic.count);
+            if expires < today && remaining > 0 {
+                expired_entries.push((filename, rule, expires, entry.owner.as_deref()));
+            }
+        }
+    }
+    expired_entries.sort_unstable_by_key(|(filename, rule, _, _)| (*filename, *rule));
+    for (filename, rule, expires, owner) in expired_entries {
+        errors.push(with_owner(
+            OxcDiagnostic::error(format!(
+                "Suppressions for rule `{rule}` in `{filename}` expired on {expires}."
+            ))
+            .with_help(format!(
+                "Fix the violations of `{rule}` in `{filename}`, or extend the expiry date in the suppressions file."
+            )),
+            owner,
+        ));
+    }
+
+    errors
+}
+
+/// `count` followed by "violation" or "violations".
+fn violations(count: usize) -> String {
+    if count == 1 { "1 violation".to_string() } else { format!("{count} violations") }
+}
+
+fn with_owner(diagnostic: OxcDiagnostic, owner: Option<&str>) -> OxcDiagnostic {
+    match owner {
+        Some(owner) => diagnostic.with_note(format!("Owner: {owner}")),
+        None => diagnostic,
+    }
+}
+
+/// Remaining suppressed violations, grouped by rule and by directory.
+#[derive(Debug, Default)]
+pub struct SuppressionDebtSummary {
+    by_rule: BTreeMap<String, (usize, RuleBudget)>,
+    by_directory: BTreeMap<String, usize>,
+    total: usize,
+}
+
+impl SuppressionDebtSummary {
+    pub fn new(
+        rules: &BTreeMap<String, RuleBudget>,
+        suppressions: &FxHashMap<Filename, FileSuppressionsMap>,
+    ) -> Self {
+        let mut summary = Self::default();
+
+        for (filename, file_rules) in suppressions {
+            let filename = filename.to_string();
+            let directory = filename.rsplit_once('/').map_or(".", |(directory, _)| directory);
+            for (rule, diagnostic) in file_rules {
+                if diagnostic.count == 0 {
+                    continue;
+                }
+                summary.total += diagnostic.count;
+                *summary.by_directory.entry(directory.to_string()).or_default() += diagnostic.count;
+                summary
+                    .by_rule
+                    .entry(rule.clone())
+                    .or_insert_with(|| (0, rules.get(rule).cloned().unwrap_or_default()))
+                    .0 += diagnostic.count;
+            }
+        }
+
+        // Rules with settings but without remaining violations are listed as paid off.
+        for (rule, settings) in rules {
+            summary.by_rule.entry(rule.clone()).or_insert_with(|| (0, settings.clone()));
+        }
+
+        summary
+    }
+
+    pub fn total(&self) -> usize {
+        self.total
+    }
+}
+
+impl Display for SuppressionDebtSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .by_rule
+            .keys()
+            .chain(self.by_directory.keys())
+            .map(String::len)
+            .max()
+            .unwrap_or_default();
+
+        writeln!(f, "Suppressed violations by rule:")?;
+        for (rule, (count, settings)) in &self.by_rule {
+            let mut line = format!("  {rule:width$}  {count:>5}");
+            if let Some(budget) = settings.budget {
+                write!(line, " / {budget} budget")?;
+            }
+            if let Some(owner) = &settings.owner {
+                write!(line, "  owner: {owner}")?;
+            }
+            if let Some(expires) = settings.expires {
+                write!(line, "  expires: {expires}")?;
+            }
+            writeln!(f, "{line}")?;
+        }
+
+        writeln!(f, "Suppressed violations by directory:")?;
+        for (directory, count) in &self.by_directory {
+            writeln!(f, "  {directory:width$}  {count:>5}")?;
+        }
+
+        let noun = if self.total == 1 { "violation" } else { "violations" };
+        writeln!(f, "Total: {} suppressed {noun}.", self.total)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{collections::BTreeMap, path::Path};
+
+    use rustc_hash::FxHashMap;
+
+    use super::{RuleBudget, SuppressionDate, SuppressionDebtSummary, check_debt_limits};
+    use crate::suppression::{DiagnosticCounts, FileSuppressionsMap, Filename};
+
+    fn counts(entries: &[(&str, &str, usize)]) -> FxHashMap<Filename, FileSuppressionsMap> {
+        let mut map: FxHashMap<Filename, FileSuppressionsMap> = FxHashMap::default();
+        for (filename, rule, count) in entries {
+            map.entry(Filename::new(Path::new(filename)))
+                .or_default()
+                .insert((*rule).to_string(), DiagnosticCounts::new(*count));
+        }
+        map
+    }
+
+    #[test]
+    fn parse_date() {
+        assert_eq!(SuppressionDate::parse("2024-02-29"), SuppressionDate::new(2024, 2, 29));
+        assert_eq!(SuppressionDate::parse("2024-02-29").unwrap().to_string(), "2024-02-29");
+        assert!(SuppressionDate::parse("2023-02-29").is_none());
+        assert!(SuppressionDate::parse("2023-13-01").is_none());
+        assert!(SuppressionDate::parse("2023-1-01").is_none());
+        assert!(SuppressionDate::parse("2023-01-01-01").is_none());
+        assert!(SuppressionDate::parse("tomorrow").is_none());
+    }
+
+    #[test]
+    fn date_from_days_since_epoch() {
+        assert_eq!(
+            SuppressionDate::from_days_since_epoch(0),
+            SuppressionDate::new(1970, 1, 1).unwrap()
+        );
+        assert_eq!(
+            SuppressionDate::from_days_since_epoch(19_782),
+            SuppressionDate::new(2024, 2, 29).unwrap()
+        );
+        assert_eq!(
+            SuppressionDate::from_days_since_epoch(20_453),
+            SuppressionDate::new(2025, 12, 31).unwrap()
+        );
+    }
+
+    #[test]
+    fn budget_exceeded() {
+        let rules = BTreeMap::from([(
+            "no-console".to_string(),
+            RuleBudget { budget: Some(2), owner: Some("@web".to_string()), expires: None },
+        )]);
+        let today = SuppressionDate::new(2025, 1, 1).unwrap();
+
+        let within = counts(&[("a.js", "no-console", 1), ("b/c.js", "no-console", 1)]);
+        assert!(check_debt_limits(&rules, &within, &within, today).is_empty());
+
+        let exceeded = counts(&[("a.js", "no-console", 2), ("b/c.js", "no-console", 1)]);
+        let errors = check_debt_limits(&rules, &within, &exceeded, today);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].to_string(),
+            "Rule `no-console` has 3 violations, which exceeds its suppression budget of 2."
+        );
+    }
+
+    #[test]
+    fn expired_entries() {
+        let today = SuppressionDate::new(2025, 1, 1).unwrap();
+        let rules = BTreeMap::from([(
+            "no-console".to_string(),
+            RuleBudget { budget: None, owner: None, expires: SuppressionDate::new(2024, 12, 31) },
+        )]);
+        let mut file_entries = counts(&[("a.js", "no-debugger", 1)]);
+        for entry in file_entries.values_mut().flat_map(|rules| rules.values_mut()) {
+            entry.expires = SuppressionDate::new(2024, 6, 1);
+        }
+
+        let current = counts(&[("a.js", "no-console", 1), ("a.js", "no-debugger", 1)]);
+        let errors = check_debt_limits(&rules, &file_entries, &current, today);
+        let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            messages,
+            [
+                "Suppressions for rule `no-console` expired on 2024-12-31, 1 violation remains.",
+                "Suppressions for rule `no-debugger` in `a.js` expired on 2024-06-01.",
+            ]
+        );
+
+        // Nothing is reported once the violations have been fixed.
+        let fixed = counts(&[]);
+        assert!(check_debt_limits(&rules, &file_entries, &fixed, today).is_empty());
+    }
+
+    #[test]
+    fn summary() {
+        let rules = BTreeMap::from([
+            (
+                "no-console".to_string(),
+                RuleBudget {
+                    budget: Some(5),
+                    owner: Some("@web".to_string()),
+                    expires: SuppressionDate::new(2026, 12, 31),
+                },
+            ),
+            ("no-alert".to_string(), RuleBudget::default()),
+        ]);
+        let suppressions = counts(&[
+            ("a.js", "no-console", 1),
+            ("src/b.js", "no-console", 2),
+            ("src/b.js", "typescript/array-type", 1),
+        ]);
+
+        let summary = SuppressionDebtSummary::new(&rules, &suppressions);
+        assert_eq!(summary.total(), 4);
+        assert_eq!(
+            summary.to_string(),
+            [
+                "Suppressed violations by rule:",
+                "  no-alert                   0",
+                "  no-console                 3 / 5 budget  owner: @web  expires: 2026-12-31",
+                "  typescript/array-type      1",
+                "Suppressed violations by directory:",
+                "  .                          1",
+                "  src                        3",
+                "Total: 4 suppressed violations.",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use std::{
+    collections::BTreeMap,
     path::{Path, PathBuf},
     sync::Arc,
 };
@@ -6,9 +7,11 @@ use std::{
 use oxc_diagnostics::{DiagnosticSender, DiagnosticService, OxcDiagnostic};
 use rustc_hash::FxHashMap;
 
+mod debt;
 mod diff;
 mod tracking;
 
+pub use debt::{RuleBudget, SuppressionDate, SuppressionDebtSummary};
 pub use tracking::{
     DiagnosticCounts, Filename, SuppressionFile, SuppressionFileState, SuppressionTracking,
 };
@@ -31,7 +34,7 @@ impl RuntimeSuppressionMap {
         let mut map = self.inner.lock().unwrap();
         let entry = map.entry(filename).or_default();
         for (rule, diagnostic) in counts {
-            entry.entry(rule).or_insert(DiagnosticCounts { count: 0 }).count += diagnostic.count;
+            entry.entry(rule).or_insert(DiagnosticCounts::new(0)).count += diagnostic.count;
         }
     }
 
@@ -75,6 +78,8 @@ pub struct SuppressionManager {
     prune_suppression: bool,
     //If the source of truth exists
     file_exists: bool,
+    /// Whether a rule budget was exceeded or a suppression expired.
+    exceeds_debt_limits: bool,
 }
 
 impl SuppressionManager {
@@ -99,6 +104,7 @@ impl SuppressionManager {
                 suppress_all,
                 prune_suppression,
                 file_exists,
+                exceeds_debt_limits: false,
             };
         }
 
@@ -110,6 +116,7 @@ impl SuppressionManager {
                 suppress_all,
                 prune_suppression,
                 file_exists,
+                exceeds_debt_limits: false,
             },
             Err(err) => Self {
                 suppressions_by_file: None,
@@ -118,6 +125,7 @@ impl SuppressionManager {
                 suppress_all,
                 prune_suppression,
                 file_exists,
+                exceeds_debt_limits: false,
             },
         }
     }
@@ -155,6 +163,20 @@ impl SuppressionManager {
         let runtime_map = diff_manager.into_runtime_map().into_inner();
 
         let static_map = self.concurrent_map();
+        let rules = self.rules();
+
+        let debt_errors = debt::check_debt_limits(
+            &rules,
+            &static_map,
+            &Self::compute_current(&static_map, &runtime_map),
+            SuppressionDate::today(),
+        );
+        if !debt_errors.is_empty() {
+            self.exceeds_debt_limits = true;
+            let diagnostics =
+                DiagnosticService::wrap_diagnostics(cwd, Path::new(""), "", debt_errors);
+            tx_error.send(diagnostics).unwrap();
+        }
 
         if self.is_updating_file() {
             let new_map = if self.suppress_all {
@@ -162,7 +184,8 @@ impl SuppressionManager {
             } else {
                 Self::compute_prune(&static_map, &runtime_map, cwd)
             };
-            self.suppressions_by_file = Some(SuppressionTracking::from_map(new_map));
+            self.suppressions_by_file =
+                Some(SuppressionTracking::from_map(new_map).with_rules(rules));
             self.has_been_updated();
             self.write()
         } else {
@@ -180,6 +203,31 @@ impl SuppressionManager {
         }
     }
 
+    /// Whether [`SuppressionManager::finalize`] found a rule over its budget,
+    /// or expired suppressions that still have violations.
+    pub fn exceeds_debt_limits(&self) -> bool {
+        self.exceeds_debt_limits
+    }
+
+    /// Summary of the violations recorded in the suppression file, by rule and by directory.
+    pub fn debt_summary(&self) -> Option<SuppressionDebtSummary> {
+        let file = self.suppressions_by_file.as_ref()?;
+        Some(SuppressionDebtSummary::new(file.rules(), file.suppressions()))
+    }
+
+    /// Current violation counts of every tracked file: runtime counts for linted files,
+    /// static counts for files that were not linted in this run.
+    fn compute_current(
+        static_map: &StaticSuppressionMap,
+        runtime_map: &FxHashMap<Filename, FileSuppressionsMap>,
+    ) -> FxHashMap<Filename, FileSuppressionsMap> {
+        let mut result: FxHashMap<Filename, FileSuppressionsMap> = static_map.as_ref().clone();
+        for (filename, runtime_rules) in runtime_map {
+            result.insert(filename.clone(), runtime_rules.clone());
+        }
+        result
+    }
+
     /// Suppress mode: overwrite counts with runtime values for seen files.
     /// Unseen files keep their static values.
     fn compute_suppress(
@@ -197,7 +245,20 @@ impl SuppressionManager {
             // For seen files, replace all rules with the runtime counts.
             // This ensures that rules which are no longer error-severity
             // (e.g. warnings) are removed from the suppression file.
-            result.insert(filename.clone(), runtime_rules.clone());
+            let static_rules = static_map.get(filename);
+            let file_rules = runtime_rules
+                .iter()
+                .map(|(rule, runtime_count)| {
+                    let counts = match static_rules.and_then(|rules| rules.get(rule)) {
+                        Some(static_count) => {
+                            runtime_count.clone().with_annotations_of(static_count)
+                        }
+                        None => runtime_count.clone(),
+                    };
+                    (rule.clone(), counts)
+                })
+                .collect();
+            result.insert(filename.clone(), file_rules);
         }
 
         result
@@ -220,7 +281,10 @@ impl SuppressionManager {
                     if let Some(runtime_count) = runtime_rules.get(rule) {
                         let count = static_count.count.min(runtime_count.count);
                         if count > 0 {
-                            file_rules.insert(rule.clone(), DiagnosticCounts { count });
+                            file_rules.insert(
+                                rule.clone(),
+                                DiagnosticCounts::new(count).with_annotations_of(static_count),
+                            );
                         }
                     }
                     // Rule not in runtime = pruned, don't include
@@ -321,6 +385,10 @@ impl SuppressionManager {
         }
     }
 
+    fn rules(&self) -> BTreeMap<String, RuleBudget> {
+        self.suppressions_by_file.as_ref().map(|f| f.rules().clone()).unwrap_or_default()
+    }
+
     fn concurrent_map(&self) -> StaticSuppressionMap {
         self.suppressions_by_file.as_ref().map(|f| Arc::clone(f.suppressions())).unwrap_or_default()
     }
@@ -1,16 +1,36 @@
 use cow_utils::CowUtils;
-use std::{ffi::OsStr, fs, path::Path, sync::Arc};
+use std::{collections::BTreeMap, ffi::OsStr, fs, path::Path, sync::Arc};
 
 use oxc_diagnostics::OxcDiagnostic;
 use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 
-use crate::read_to_string;
+use crate::{
+    read_to_string,
+    suppression::debt::{RuleBudget, SuppressionDate},
+};
 
 #[derive(Debug, Default, Clone, Deserialize, Serialize)]
 #[serde(default)]
 pub struct DiagnosticCounts {
     pub count: usize,
+    /// Who is responsible for fixing these violations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// Date after which these violations are no longer suppressed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires: Option<SuppressionDate>,
+}
+
+impl DiagnosticCounts {
+    pub fn new(count: usize) -> Self {
+        Self { count, owner: None, expires: None }
+    }
+
+    /// Same count, keeping the `owner` and `expires` annotations of `annotated`.
+    pub fn with_annotations_of(self, annotated: &Self) -> Self {
+        Self { owner: annotated.owner.clone(), expires: annotated.expires, ..self }
+    }
 }
 
 #[derive(Debug, Default, Clone, Deserialize, Serialize, Hash, Eq, PartialEq, Ord, PartialOrd)]
@@ -36,7 +56,6 @@ fn serialize_arc_map<S>(map: &AllSuppressionsMap, serializer: S) -> Result<S::Ok
 where
     S: serde::Serializer,
 {
-    use std::collections::BTreeMap;
     let sorted: BTreeMap<&Filename, BTreeMap<&String, &DiagnosticCounts>> = map
         .iter()
         .map(|(filename, rules)| (filename, rules.iter().collect::<BTreeMap<_, _>>()))
@@ -51,16 +70,30 @@ where
     FxHashMap::<Filename, FileSuppressionsMap>::deserialize(deserializer).map(Arc::new)
 }
 
+/// Contents of the suppression file: per-file, per-rule violation counts, plus optional
+/// repository-wide rule settings stored under the reserved `$rules` key.
+///
+/// ```json
+/// {
+///   "$rules": { "no-console": { "budget": 10, "owner": "@web", "expires": "2026-12-31" } },
+///   "src/index.js": { "no-console": { "count": 2 } }
+/// }
+/// ```
 #[derive(Debug, Clone, Deserialize, Serialize)]
-#[serde(transparent)]
 pub struct SuppressionTracking {
-    #[serde(deserialize_with = "deserialize_arc_map", serialize_with = "serialize_arc_map")]
+    #[serde(rename = "$rules", default, skip_serializing_if = "BTreeMap::is_empty")]
+    rules: BTreeMap<String, RuleBudget>,
+    #[serde(
+        flatten,
+        deserialize_with = "deserialize_arc_map",
+        serialize_with = "serialize_arc_map"
+    )]
     suppressions: AllSuppressionsMap,
 }
 
 impl Default for SuppressionTracking {
     fn default() -> Self {
-        Self { suppressions: Arc::new(FxHashMap::default()) }
+        Self { rules: BTreeMap::new(), suppressions: Arc::new(FxHashMap::default()) }
     }
 }
 
@@ -103,13 +136,23 @@ impl SuppressionTracking {
     }
 
     pub fn from_map(map: FxHashMap<Filename, FileSuppressionsMap>) -> Self {
-        Self { suppressions: Arc::new(map) }
+        Self { rules: BTreeMap::new(), suppressions: Arc::new(map) }
+    }
+
+    #[must_use]
+    pub fn with_rules(mut self, rules: BTreeMap<String, RuleBudget>) -> Self {
+        self.rules = rules;
+        self
     }
 
     pub fn suppressions(&self) -> &AllSuppressionsMap {
         &self.suppressions
     }
 
+    pub fn rules(&self) -> &BTreeMap<String, RuleBudget> {
+        &self.rules
+    }
+
     pub fn save(&self, path: &Path) -> Result<(), OxcDiagnostic> {
         let content = serde_json::to_string_pretty(&self).map_err(|err| {
             OxcDiagnostic::error(format!("Failed to serialize suppression file: {err}"))
@@ -329,6 +329,8 @@ pub struct Tester {
     snapshot_suffix: Option<&'static str>,
     current_working_directory: Box<Path>,
     plugins: LintPlugins,
+    /// Run a type-aware rule natively, instead of skipping it as a `tsgolint` rule.
+    native_type_aware: bool,
 }
 
 impl Tester {
@@ -356,6 +358,7 @@ impl Tester {
             snapshot_suffix: None,
             current_working_directory,
             plugins: LintPlugins::default(),
+            native_type_aware: false,
         }
     }
 
@@ -414,6 +417,13 @@ impl Tester {
         self
     }
 
+    /// Select the rule in `options.nativeTypeAwareRules`, so that a type-aware rule is run
+    /// natively rather than skipped.
+    pub fn with_native_type_aware(mut self, yes: bool) -> Self {
+        self.native_type_aware = yes;
+        self
+    }
+
     /// Add cases that should fix problems found in the source code.
     ///
     /// These cases will fail if no fixes are produced or if the fixed source
@@ -648,6 +658,16 @@ impl Tester {
             );
         }
         let rule = self.find_rule().from_configuration(rule_config.unwrap_or_default()).unwrap();
+        let eslint_config = if self.native_type_aware {
+            let mut config = eslint_config.unwrap_or_else(|| json!({}));
+            config.as_object_mut().unwrap().insert(
+                "options".into(),
+                json!({ "nativeTypeAwareRules": [format!("{}/{}", self.plugin_name, self.rule_name)] }),
+            );
+            Some(config)
+        } else {
+            eslint_config
+        };
         let mut external_plugin_store = ExternalPluginStore::default();
         let linter = Linter::new(
             self.lint_options,
@@ -708,7 +728,13 @@ impl Tester {
             let fix_result =
                 Fixer::new(source_text, result, SourceType::from_path(path_to_lint).ok())
                     .with_fix_index(fix_index)
-                    .fix();
+                    .fix_and_roll_back();
+            assert!(
+                fix_result.rolled_back == 0,
+                "Linter fixer produced invalid syntax. {} fix(es) had to be rolled back.\n\nInput code: \n```\n{source_text}\n```\n\nFixed code: \n```\n{}\n```",
+                fix_result.rolled_back,
+                fix_result.fixed_code
+            );
             return TestResult::Fixed(fix_result.fixed_code.to_string());
         }
 
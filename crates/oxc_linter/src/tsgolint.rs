@@ -574,7 +574,10 @@ impl TsGoLintState {
                     .rules
                     .iter()
                     .filter_map(|(rule, status)| {
-                        if status.is_warn_deny() && rule.is_tsgolint_rule() {
+                        if status.is_warn_deny()
+                            && rule.is_tsgolint_rule()
+                            && !self.config_store.is_native_type_aware_rule(rule)
+                        {
                             let rule_name = rule.name().to_string();
                             let options = match rule.to_configuration() {
                                 Some(Ok(config)) => Some(config),
@@ -0,0 +1,399 @@
+use oxc_ast::ast::{
+    Argument, CallExpression, Class, Decorator, Expression, ObjectExpression, ObjectPropertyKind,
+    PropertyDefinition, PropertyKey, StringLiteral,
+};
+use oxc_span::{GetSpan, Span};
+
+use crate::utils::{TemplateExpression, find_closing_bracket};
+
+/// Decorators which make a class an Angular class.
+pub const ANGULAR_CLASS_DECORATORS: [&str; 5] =
+    ["Component", "Directive", "Pipe", "Injectable", "NgModule"];
+
+/// The call of the decorator `@name(...)` among `decorators`, such as `@Component({...})`.
+pub fn get_angular_decorator<'a, 'b>(
+    decorators: &'b [Decorator<'a>],
+    name: &str,
+) -> Option<&'b CallExpression<'a>> {
+    decorators.iter().find_map(|decorator| match &decorator.expression {
+        Expression::CallExpression(call) if call.callee.is_specific_id(name) => Some(&**call),
+        _ => None,
+    })
+}
+
+/// Whether `class` is decorated with one of [`ANGULAR_CLASS_DECORATORS`].
+pub fn is_angular_class(class: &Class) -> bool {
+    ANGULAR_CLASS_DECORATORS
+        .iter()
+        .any(|name| get_angular_decorator(&class.decorators, name).is_some())
+}
+
+/// The object literal passed to a decorator, such as `{ selector: 'app-root' }` in
+/// `@Component({ selector: 'app-root' })`.
+pub fn get_decorator_metadata<'a, 'b>(
+    call: &'b CallExpression<'a>,
+) -> Option<&'b ObjectExpression<'a>> {
+    match call.arguments.first()? {
+        Argument::ObjectExpression(object) => Some(object),
+        _ => None,
+    }
+}
+
+/// The value of the property `name` of an object literal.
+pub fn get_object_property<'a, 'b>(
+    object: &'b ObjectExpression<'a>,
+    name: &str,
+) -> Option<&'b Expression<'a>> {
+    object.properties.iter().find_map(|property| match property {
+        ObjectPropertyKind::ObjectProperty(property)
+            if !property.computed
+                && matches!(&property.key, PropertyKey::StaticIdentifier(key) if key.name == name) =>
+        {
+            Some(&property.value)
+        }
+        _ => None,
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AngularBindingKind {
+    Input,
+    Output,
+}
+
+/// An input or output of an Angular directive, declared by a class property.
+#[derive(Debug, Clone, Copy)]
+pub struct AngularBinding<'a, 'b> {
+    pub kind: AngularBindingKind,
+    /// The public name of the binding, such as `'value'` in `@Input('value')` or
+    /// `output({ alias: 'value' })`, if it differs from the property name.
+    pub alias: Option<&'b StringLiteral<'a>>,
+}
+
+/// The binding declared by `property` with one of the `@Input()` or `@Output()` decorators,
+/// or with one of the `input()`, `input.required()`, `model()`, `model.required()`, `output()`
+/// or `outputFromObservable()` functions.
+pub fn get_angular_binding<'a, 'b>(
+    property: &'b PropertyDefinition<'a>,
+) -> Option<AngularBinding<'a, 'b>> {
+    for (name, kind) in
+        [("Input", AngularBindingKind::Input), ("Output", AngularBindingKind::Output)]
+    {
+        if let Some(decorator) = get_angular_decorator(&property.decorators, name) {
+            // `@Input('alias')` or `@Input({ alias: 'alias' })`
+            let alias = match decorator.arguments.first() {
+                Some(Argument::StringLiteral(alias)) => Some(&**alias),
+                Some(Argument::ObjectExpression(options)) => get_alias_option(options),
+                _ => None,
+            };
+            return Some(AngularBinding { kind, alias });
+        }
+    }
+
+    let Some(Expression::CallExpression(call)) = &property.value else {
+        return None;
+    };
+    let (kind, options) = match &call.callee {
+        Expression::Identifier(ident) => match ident.name.as_str() {
+            "input" | "model" => (AngularBindingKind::Input, 1),
+            "output" => (AngularBindingKind::Output, 0),
+            "outputFromObservable" => (AngularBindingKind::Output, 1),
+            _ => return None,
+        },
+        callee
+            if callee.is_specific_member_access("input", "required")
+                || callee.is_specific_member_access("model", "required") =>
+        {
+            (AngularBindingKind::Input, 0)
+        }
+        _ => return None,
+    };
+    let alias = match call.arguments.get(options) {
+        Some(Argument::ObjectExpression(options)) => get_alias_option(options),
+        _ => None,
+    };
+    Some(AngularBinding { kind, alias })
+}
+
+fn get_alias_option<'a, 'b>(options: &'b ObjectExpression<'a>) -> Option<&'b StringLiteral<'a>> {
+    match get_object_property(options, "alias")? {
+        Expression::StringLiteral(alias) => Some(alias),
+        _ => None,
+    }
+}
+
+/// The inline template of the `@Component({ template: '...' })` decorator of `class`.
+///
+/// Only string literals and template literals without substitutions are supported. Templates in
+/// separate files (`templateUrl`) are not linted.
+pub fn get_component_template<'a>(
+    class: &Class<'a>,
+    source_text: &'a str,
+) -> Option<AngularTemplate<'a>> {
+    let decorator = get_angular_decorator(&class.decorators, "Component")?;
+    let template = get_object_property(get_decorator_metadata(decorator)?, "template")?;
+    match template {
+        Expression::StringLiteral(_) => {}
+        Expression::TemplateLiteral(literal) if literal.expressions.is_empty() => {}
+        _ => return None,
+    }
+    // The raw text between the quotes, so that offsets in the template are offsets in the file.
+    let span = template.span().shrink(1);
+    Some(AngularTemplate::scan(span.source_text(source_text), span.start))
+}
+
+/// An Angular template, scanned for the attributes of its elements and for the expressions of
+/// its bindings, interpolations and control flow blocks.
+pub struct AngularTemplate<'a> {
+    /// Attributes of all elements, in source order.
+    pub attributes: Vec<AngularAttribute<'a>>,
+    /// Expressions of property bindings (`[value]="expr"`), event bindings (`(click)="expr"`),
+    /// structural directives (`*ngIf="expr"`), interpolations (`{{ expr }}`) and control flow
+    /// blocks (`@if (expr) {`), in source order.
+    pub expressions: Vec<TemplateExpression<'a>>,
+}
+
+/// An attribute of an element in an Angular template, such as `[(ngModel)]="name"`.
+#[derive(Debug, Clone, Copy)]
+pub struct AngularAttribute<'a> {
+    pub name: &'a str,
+    /// Span of the name in the linted section.
+    pub name_span: Span,
+    /// The value, without quotes.
+    pub value: Option<TemplateExpression<'a>>,
+}
+
+impl AngularAttribute<'_> {
+    /// Whether the value of this attribute is an expression rather than text.
+    pub fn is_binding(&self) -> bool {
+        self.name.starts_with(['[', '(', '*'])
+            || ["bind-", "on-", "bindon-"].iter().any(|prefix| self.name.starts_with(prefix))
+    }
+}
+
+impl<'a> AngularTemplate<'a> {
+    /// Scan `text`, which starts at `start` in the linted section.
+    pub fn scan(text: &'a str, start: u32) -> Self {
+        let mut scanner = TemplateScanner {
+            text,
+            start,
+            template: Self { attributes: vec![], expressions: vec![] },
+        };
+        scanner.scan();
+        scanner.template
+    }
+}
+
+struct TemplateScanner<'a> {
+    text: &'a str,
+    start: u32,
+    template: AngularTemplate<'a>,
+}
+
+impl<'a> TemplateScanner<'a> {
+    #[expect(clippy::cast_possible_truncation)]
+    fn offset(&self, index: usize) -> u32 {
+        self.start + index as u32
+    }
+
+    fn expression(&self, start: usize, end: usize) -> Option<TemplateExpression<'a>> {
+        TemplateExpression::new(&self.text[start..end], self.offset(start))
+    }
+
+    fn scan(&mut self) {
+        let bytes = self.text.as_bytes();
+        let mut index = 0;
+        while index < bytes.len() {
+            let rest = &self.text[index..];
+            if rest.starts_with("<!--") {
+                index = rest.find("-->").map_or(bytes.len(), |end| index + end + 3);
+            } else if rest.starts_with('<')
+                && rest[1..].bytes().next().is_some_and(|byte| byte.is_ascii_alphabetic())
+            {
+                index = self.scan_element(index);
+            } else if rest.starts_with("{{") {
+                let Some(close) = rest.find("}}") else { break };
+                self.template.expressions.extend(self.expression(index + 2, index + close));
+                index += close + 2;
+            } else if let Some(keyword) = ["@if", "@else if", "@switch", "@case"]
+                .iter()
+                .find(|keyword| rest.starts_with(**keyword))
+            {
+                index += keyword.len();
+                let condition_start =
+                    index + self.text[index..].len() - self.text[index..].trim_start().len();
+                if bytes.get(condition_start) == Some(&b'(')
+                    && let Some(end) = find_closing_bracket(self.text, condition_start, b'(', b')')
+                {
+                    // `@if (user$ | async; as user) {`
+                    let condition_end = self.text[condition_start..end]
+                        .find(';')
+                        .map_or(end, |semicolon| condition_start + semicolon);
+                    self.template
+                        .expressions
+                        .extend(self.expression(condition_start + 1, condition_end));
+                    index = end + 1;
+                }
+            } else {
+                index += rest.chars().next().map_or(1, char::len_utf8);
+            }
+        }
+    }
+
+    /// Scan the `{{ ... }}` interpolations of an attribute value between `start` and `end`.
+    fn scan_interpolations(&mut self, start: usize, end: usize) {
+        let mut index = start;
+        while let Some(open) = self.text[index..end].find("{{") {
+            let open = index + open + 2;
+            let Some(close) = self.text[open..end].find("}}") else { return };
+            let close = open + close;
+            self.template.expressions.extend(self.expression(open, close));
+            index = close + 2;
+        }
+    }
+
+    /// Scan the start tag at `start`, returning the index after it.
+    fn scan_element(&mut self, start: usize) -> usize {
+        let bytes = self.text.as_bytes();
+        let mut index = start + 1;
+        while index < bytes.len()
+            && !bytes[index].is_ascii_whitespace()
+            && !matches!(bytes[index], b'>' | b'/')
+        {
+            index += 1;
+        }
+        loop {
+            while index < bytes.len()
+                && (bytes[index].is_ascii_whitespace() || bytes[index] == b'/')
+            {
+                index += 1;
+            }
+            if index >= bytes.len() {
+                return index;
+            }
+            if bytes[index] == b'>' {
+                return index + 1;
+            }
+
+            let name_start = index;
+            while index < bytes.len()
+                && !bytes[index].is_ascii_whitespace()
+                && !matches!(bytes[index], b'=' | b'>')
+                && (bytes[index] != b'/' || bytes.get(index + 1) != Some(&b'>'))
+            {
+                index += 1;
+            }
+            let name = &self.text[name_start..index];
+            let name_span = Span::new(self.offset(name_start), self.offset(index));
+
+            while index < bytes.len() && bytes[index].is_ascii_whitespace() {
+                index += 1;
+            }
+            let mut value = None;
+            if bytes.get(index) == Some(&b'=') {
+                index += 1;
+                while index < bytes.len() && bytes[index].is_ascii_whitespace() {
+                    index += 1;
+                }
+                let (value_start, value_end) =
+                    if let Some(&quote @ (b'"' | b'\'')) = bytes.get(index) {
+                        let value_start = index + 1;
+                        let value_end = self.text[value_start..]
+                            .find(quote as char)
+                            .map_or(bytes.len(), |end| value_start + end);
+                        index = (value_end + 1).min(bytes.len());
+                        (value_start, value_end)
+                    } else {
+                        let value_start = index;
+                        while index < bytes.len()
+                            && !bytes[index].is_ascii_whitespace()
+                            && bytes[index] != b'>'
+                        {
+                            index += 1;
+                        }
+                        (value_start, index)
+                    };
+                value = self.expression(value_start, value_end);
+                let attribute = AngularAttribute { name, name_span, value };
+                if attribute.is_binding() {
+                    self.add_binding(&attribute, value_start, value_end);
+                } else {
+                    self.scan_interpolations(value_start, value_end);
+                }
+            }
+            self.template.attributes.push(AngularAttribute { name, name_span, value });
+        }
+    }
+
+    fn add_binding(&mut self, attribute: &AngularAttribute<'a>, start: usize, end: usize) {
+        let Some(value) = attribute.value else { return };
+        if attribute.name.starts_with('*') {
+            // Microsyntax: only `*ngIf="condition; else other"` starts with an expression.
+            if value.text.starts_with("let ") {
+                return;
+            }
+            let end = self.text[start..end].find(';').map_or(end, |semicolon| start + semicolon);
+            self.template.expressions.extend(self.expression(start, end));
+        } else {
+            self.template.expressions.push(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::AngularTemplate;
+
+    #[test]
+    fn scan() {
+        let text = r#"
+<!-- <p [hidden]="commented"></p> -->
+<input ([ngModel])="name" class="a {{ klass }}" (input)="onInput($event)" disabled />
+<p *ngIf="user; else loading">Hello {{ user.name }}!</p>
+<li *ngFor="let item of items">{{ item }}</li>
+@if (count > 1) {
+  <span [title]='count'>{{ count }}</span>
+} @else if (count === 1) {
+}
+"#;
+        let template = AngularTemplate::scan(text, 10);
+        let attributes = template
+            .attributes
+            .iter()
+            .map(|attribute| (attribute.name, attribute.value.map(|value| value.text)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            attributes,
+            [
+                ("([ngModel])", Some("name")),
+                ("class", Some("a {{ klass }}")),
+                ("(input)", Some("onInput($event)")),
+                ("disabled", None),
+                ("*ngIf", Some("user; else loading")),
+                ("*ngFor", Some("let item of items")),
+                ("[title]", Some("count")),
+            ]
+        );
+        let expressions =
+            template.expressions.iter().map(|expression| expression.text).collect::<Vec<_>>();
+        assert_eq!(
+            expressions,
+            [
+                "name",
+                "klass",
+                "onInput($event)",
+                "user",
+                "user.name",
+                "item",
+                "count > 1",
+                "count",
+                "count",
+                "count === 1"
+            ]
+        );
+        for expression in &template.expressions {
+            let start = expression.span.start as usize - 10;
+            assert_eq!(&text[start..start + expression.text.len()], expression.text);
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use std::{fs, path::Path, sync::Arc};
+
+use oxc_compat::{Engine, EngineTargets, Version};
+
+use crate::{LintContext, config::CompatTargets};
+
+/// The engines the file being linted must run in, as resolved when the config was built.
+///
+/// # Errors
+///
+/// When the targets are neither valid Oxc targets (`es2020`, `chrome90`) nor a valid
+/// browserslist query.
+pub fn compat_targets(ctx: &LintContext) -> Result<Arc<EngineTargets>, String> {
+    let compat = &ctx.settings().compat;
+    match &compat.resolved_targets {
+        Some(targets) => targets.clone(),
+        None => resolve_compat_targets(compat.targets.as_ref(), None),
+    }
+}
+
+/// Resolve the engines to check the code against.
+///
+/// Uses `targets`, then the nearest `.browserslistrc` or `browserslist` field of `package.json`
+/// starting from `dir`, then the browserslist `defaults` query.
+///
+/// # Errors
+///
+/// When the targets are neither valid Oxc targets (`es2020`, `chrome90`) nor a valid
+/// browserslist query.
+pub fn resolve_compat_targets(
+    targets: Option<&CompatTargets>,
+    dir: Option<&Path>,
+) -> Result<Arc<EngineTargets>, String> {
+    let queries = match targets {
+        Some(targets) => targets.queries(),
+        None => dir.and_then(find_browserslist).unwrap_or_else(|| vec!["defaults".to_string()]),
+    };
+    parse_targets(&queries).map(Arc::new)
+}
+
+fn parse_targets(queries: &[String]) -> Result<EngineTargets, String> {
+    EngineTargets::from_target_list(queries)
+        .or_else(|_| EngineTargets::try_from_query(&queries.join(", ")))
+}
+
+/// The browserslist queries of the nearest `.browserslistrc` or `package.json` with a
+/// `browserslist` field, starting from `dir`.
+fn find_browserslist(dir: &Path) -> Option<Vec<String>> {
+    dir.ancestors().find_map(|dir| {
+        if let Ok(source) = fs::read_to_string(dir.join(".browserslistrc")) {
+            return Some(parse_browserslistrc(&source));
+        }
+        let source = fs::read_to_string(dir.join("package.json")).ok()?;
+        let manifest: serde_json::Value = serde_json::from_str(&source).ok()?;
+        package_json_browserslist(manifest.get("browserslist")?)
+    })
+}
+
+/// The queries of a `.browserslistrc` that apply to the `production` environment,
+/// which is what browserslist uses when `BROWSERSLIST_ENV` and `NODE_ENV` are not set.
+fn parse_browserslistrc(source: &str) -> Vec<String> {
+    let mut in_production = true;
+    let mut queries = vec![];
+    for line in source.lines() {
+        let line = line.split_once('#').map_or(line, |(line, _)| line).trim();
+        if let Some(envs) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+            in_production = envs.split_whitespace().any(|env| env == "production");
+        } else if in_production && !line.is_empty() {
+            queries.push(line.to_string());
+        }
+    }
+    queries
+}
+
+/// The queries of the `browserslist` field of `package.json`: a query, a list of queries,
+/// or queries per environment.
+fn package_json_browserslist(value: &serde_json::Value) -> Option<Vec<String>> {
+    match value {
+        serde_json::Value::String(query) => Some(vec![query.clone()]),
+        serde_json::Value::Array(queries) => Some(
+            queries.iter().filter_map(|query| query.as_str().map(ToString::to_string)).collect(),
+        ),
+        serde_json::Value::Object(envs) => {
+            package_json_browserslist(envs.get("production").or_else(|| envs.get("defaults"))?)
+        }
+        _ => None,
+    }
+}
+
+/// The name of a target for diagnostics, e.g. `es2019`, `chrome 90` or `safari 15.4`.
+pub fn compat_target_name(engine: Engine, version: Version) -> String {
+    match (engine, version) {
+        (Engine::Es, Version(year, ..)) => format!("es{year}"),
+        (_, Version(major, 0, 0)) => format!("{engine} {major}"),
+        (_, Version(major, minor, 0)) => format!("{engine} {major}.{minor}"),
+        _ => format!("{engine} {version}"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{package_json_browserslist, parse_browserslistrc};
+
+    #[test]
+    fn browserslistrc() {
+        let source = "
+# Browsers that we support
+> 0.5%
+last 2 versions # comment
+
+[development]
+last 1 chrome version
+
+[production staging]
+not dead
+";
+        assert_eq!(parse_browserslistrc(source), ["> 0.5%", "last 2 versions", "not dead"]);
+    }
+
+    #[test]
+    fn package_json() {
+        let value = serde_json::json!("defaults");
+        assert_eq!(package_json_browserslist(&value).unwrap(), ["defaults"]);
+        let value = serde_json::json!(["chrome 100", "firefox 100"]);
+        assert_eq!(package_json_browserslist(&value).unwrap(), ["chrome 100", "firefox 100"]);
+        let value =
+            serde_json::json!({ "production": ["> 1%"], "development": ["last 1 chrome version"] });
+        assert_eq!(package_json_browserslist(&value).unwrap(), ["> 1%"]);
+        assert_eq!(package_json_browserslist(&serde_json::json!(1)), None);
+    }
+}
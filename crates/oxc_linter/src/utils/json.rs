@@ -0,0 +1,148 @@
+use std::{borrow::Cow, path::Path};
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{
+    ArrayExpressionElement, Expression, ObjectExpression, ObjectProperty, ObjectPropertyKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{GetSpan, Span};
+
+use crate::loader::JsonSource;
+
+/// The dependency maps of a `package.json`, as `"name": "version range"` objects.
+pub const PACKAGE_JSON_DEPENDENCY_FIELDS: [&str; 4] =
+    ["dependencies", "devDependencies", "optionalDependencies", "peerDependencies"];
+
+/// A JSON file parsed for the `json` rules.
+///
+/// The document is parsed as a JS expression, so its values are [`Expression`]s:
+/// objects, arrays, string/numeric/boolean/null literals
+/// (and, for JSON5, identifier keys, signed numbers and the like).
+pub struct JsonDocument<'a> {
+    root: Option<&'a Expression<'a>>,
+    /// The text that was parsed, which the AST spans index into.
+    parsed_text: &'a str,
+    /// Offset of the file in `parsed_text`.
+    source_offset: u32,
+}
+
+impl<'a> JsonDocument<'a> {
+    /// # Errors
+    /// Returns the syntax error of the file, if any.
+    pub fn parse(allocator: &'a Allocator, source: JsonSource<'a>) -> Result<Self, OxcDiagnostic> {
+        let parsed = oxc_formatter_json::parse_json(allocator, source.source_text, source.variant)?;
+        Ok(Self {
+            root: parsed.expression,
+            parsed_text: parsed.wrapped_source,
+            source_offset: parsed.source_offset,
+        })
+    }
+
+    /// The top-level value, `None` for an empty document.
+    pub fn root(&self) -> Option<&'a Expression<'a>> {
+        self.root
+    }
+
+    /// The top-level value, if it is an object.
+    pub fn root_object(&self) -> Option<&'a ObjectExpression<'a>> {
+        match self.root? {
+            Expression::ObjectExpression(object) => Some(object),
+            _ => None,
+        }
+    }
+
+    /// Map the span of a JSON node to a span in the linted file.
+    pub fn span(&self, node: &impl GetSpan) -> Span {
+        let span = node.span();
+        Span::new(span.start - self.source_offset, span.end - self.source_offset)
+    }
+
+    /// Source text of a JSON node.
+    pub fn text(&self, node: &impl GetSpan) -> &'a str {
+        node.span().source_text(self.parsed_text)
+    }
+}
+
+/// The properties of a JSON object, in source order.
+pub fn json_properties<'a>(
+    object: &'a ObjectExpression<'a>,
+) -> impl Iterator<Item = &'a ObjectProperty<'a>> {
+    object.properties.iter().filter_map(|property| match property {
+        ObjectPropertyKind::ObjectProperty(property) => Some(property.as_ref()),
+        ObjectPropertyKind::SpreadProperty(_) => None,
+    })
+}
+
+/// The key of a JSON object property.
+pub fn json_key<'a>(property: &ObjectProperty<'a>) -> Option<Cow<'a, str>> {
+    property.key.static_name()
+}
+
+/// The value of the property `key` of a JSON object.
+/// The last one, like `JSON.parse`, if the key is duplicated.
+pub fn json_property<'a>(
+    object: &'a ObjectExpression<'a>,
+    key: &str,
+) -> Option<&'a Expression<'a>> {
+    json_properties(object)
+        .filter(|property| json_key(property).is_some_and(|name| name == key))
+        .last()
+        .map(|property| &property.value)
+}
+
+/// The value of a JSON string.
+pub fn json_string<'a>(value: &'a Expression<'a>) -> Option<&'a str> {
+    match value {
+        Expression::StringLiteral(string) => Some(string.value.as_str()),
+        _ => None,
+    }
+}
+
+/// The elements of a JSON array.
+pub fn json_array_elements<'a>(
+    value: &'a Expression<'a>,
+) -> Option<impl Iterator<Item = &'a Expression<'a>>> {
+    match value {
+        Expression::ArrayExpression(array) => {
+            Some(array.elements.iter().filter_map(ArrayExpressionElement::as_expression))
+        }
+        _ => None,
+    }
+}
+
+/// Call `f` on every object in `value`, outermost first.
+pub fn walk_json_objects<'a>(
+    value: &'a Expression<'a>,
+    f: &mut impl FnMut(&'a ObjectExpression<'a>),
+) {
+    match value {
+        Expression::ObjectExpression(object) => {
+            f(object);
+            for property in json_properties(object) {
+                walk_json_objects(&property.value, f);
+            }
+        }
+        Expression::ArrayExpression(array) => {
+            for element in array.elements.iter().filter_map(ArrayExpressionElement::as_expression) {
+                walk_json_objects(element, f);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// `package.json`
+pub fn is_package_json(path: &Path) -> bool {
+    path.file_name().is_some_and(|name| name == "package.json")
+}
+
+/// `tsconfig.json`, `jsconfig.json`, and their variants like `tsconfig.build.json`
+pub fn is_tsconfig(path: &Path) -> bool {
+    path.file_name().and_then(|name| name.to_str()).is_some_and(|name| {
+        ["tsconfig", "jsconfig"].iter().any(|base| {
+            name.strip_prefix(base)
+                .and_then(|rest| rest.strip_suffix(".json"))
+                .is_some_and(|variant| variant.is_empty() || variant.starts_with('.'))
+        })
+    })
+}
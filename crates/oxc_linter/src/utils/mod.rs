@@ -14,21 +14,31 @@ use oxc_span::Span;
 use oxc_str::static_ident;
 use oxc_syntax::identifier::{is_identifier_part, is_identifier_start};
 
+mod angular;
 mod comment;
+mod compat;
 mod config;
 mod control_flow;
 mod express;
 mod jest;
 mod jsdoc;
+mod json;
 mod nextjs;
 mod node;
+mod playwright;
 mod promise;
 mod react;
 mod react_compiler;
 mod react_perf;
 mod regex;
 mod schemars;
+mod security;
 mod static_value;
+mod stylelint;
+mod svelte;
+mod taint;
+mod template;
+mod testing_library;
 mod this_expression;
 mod typescript;
 mod unicorn;
@@ -36,11 +46,14 @@ mod url;
 mod vitest;
 mod vue;
 pub mod vue_casing;
+mod workspace;
 
 pub use self::{
-    comment::*, config::*, control_flow::*, express::*, jest::*, jsdoc::*, nextjs::*, node::*,
-    promise::*, react::*, react_compiler::*, react_perf::*, regex::*, schemars::*, static_value::*,
-    this_expression::*, typescript::*, unicorn::*, url::*, vitest::*, vue::*,
+    angular::*, comment::*, compat::*, config::*, control_flow::*, express::*, jest::*, jsdoc::*,
+    json::*, nextjs::*, node::*, playwright::*, promise::*, react::*, react_compiler::*,
+    react_perf::*, regex::*, schemars::*, security::*, static_value::*, stylelint::*, svelte::*,
+    taint::*, template::*, testing_library::*, this_expression::*, typescript::*, unicorn::*,
+    url::*, vitest::*, vue::*, workspace::*,
 };
 
 /// List of Eslint rules that have TypeScript equivalents.
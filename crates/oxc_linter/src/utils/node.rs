@@ -1,6 +1,26 @@
-use oxc_ast::ast::{AssignmentTarget, IdentifierReference, MemberExpression};
-use oxc_semantic::IsGlobalReference;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use cow_utils::CowUtils;
+use nodejs_built_in_modules::is_nodejs_builtin_module;
+use oxc_ast::{
+    AstKind,
+    ast::{
+        AssignmentTarget, BindingPattern, Expression, IdentifierReference,
+        ImportDeclarationSpecifier, MemberExpression, StringLiteral,
+    },
+};
+use oxc_compat::Version;
+use oxc_resolver::{
+    PathUtil, Resolution, ResolveError, ResolveOptions, Resolver, TsconfigDiscovery,
+};
+use oxc_semantic::{AstNode, IsGlobalReference, NodeId, SymbolId};
+use oxc_span::{GetSpan, Span};
 use oxc_str::static_ident;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{config::GlobalValue, context::LintContext};
 
@@ -29,3 +49,614 @@ pub fn is_global_module_exports(member_expr: &MemberExpression, ctx: &LintContex
             .get_identifier_reference()
             .is_some_and(|ident| is_global_module_reference(ident, ctx))
 }
+
+/// The Node.js versions to support when neither the rule nor `package.json` configures them.
+pub const DEFAULT_NODE_VERSION_RANGE: &str = ">=16.0.0";
+
+/// The fields of a `package.json` which the `node` rules read.
+#[derive(Debug, Default)]
+pub struct NodePackage {
+    /// The directory containing the `package.json`.
+    pub dir: PathBuf,
+    /// The `engines.node` field.
+    pub engines_node: Option<String>,
+    pub private: bool,
+    /// The patterns of the `files` field, or `None` when every file is published.
+    pub files: Option<Vec<String>>,
+    /// The `main` and `bin` files, relative to [`NodePackage::dir`].
+    pub entries: Vec<String>,
+    /// The `bin` files, relative to [`NodePackage::dir`].
+    pub bin: Vec<String>,
+    /// The packages of `dependencies`, `peerDependencies` and `optionalDependencies`.
+    pub dependencies: FxHashSet<String>,
+    pub dev_dependencies: FxHashSet<String>,
+}
+
+impl NodePackage {
+    fn parse(dir: &Path, manifest: &serde_json::Value) -> Self {
+        let strings = |value: Option<&serde_json::Value>| -> Vec<String> {
+            match value {
+                Some(serde_json::Value::String(value)) => vec![normalize_package_path(value)],
+                Some(serde_json::Value::Array(values)) => values
+                    .iter()
+                    .filter_map(serde_json::Value::as_str)
+                    .map(ToString::to_string)
+                    .collect(),
+                Some(serde_json::Value::Object(values)) => values
+                    .values()
+                    .filter_map(serde_json::Value::as_str)
+                    .map(normalize_package_path)
+                    .collect(),
+                _ => vec![],
+            }
+        };
+        let keys = |field: &str| -> FxHashSet<String> {
+            manifest
+                .get(field)
+                .and_then(serde_json::Value::as_object)
+                .map(|dependencies| dependencies.keys().cloned().collect())
+                .unwrap_or_default()
+        };
+
+        let bin = strings(manifest.get("bin"));
+        let mut entries = strings(manifest.get("main"));
+        entries.extend(bin.iter().cloned());
+        let mut dependencies = keys("dependencies");
+        dependencies.extend(keys("peerDependencies"));
+        dependencies.extend(keys("optionalDependencies"));
+        Self {
+            dir: dir.to_path_buf(),
+            engines_node: manifest
+                .get("engines")
+                .and_then(|engines| engines.get("node"))
+                .and_then(serde_json::Value::as_str)
+                .map(ToString::to_string),
+            private: manifest.get("private").and_then(serde_json::Value::as_bool) == Some(true),
+            files: manifest.get("files").map(|files| strings(Some(files))),
+            entries,
+            bin,
+            dependencies,
+            dev_dependencies: keys("devDependencies"),
+        }
+    }
+
+    /// The path of `path` relative to the package, with `/` separators, or `None` when it is
+    /// outside of the package.
+    pub fn relative_path(&self, path: &Path) -> Option<String> {
+        let path = path.normalize();
+        let relative = path.strip_prefix(&self.dir).ok()?;
+        Some(relative.to_string_lossy().cow_replace('\\', "/").into_owned())
+    }
+
+    /// Whether `path` is included when the package is published to npm: it is listed in
+    /// `files`, or is the `main` or a `bin` file. Nothing is published from a private package.
+    pub fn is_published(&self, path: &Path) -> bool {
+        if self.private {
+            return false;
+        }
+        let Some(relative) = self.relative_path(path) else {
+            return false;
+        };
+        if relative.split('/').any(|segment| segment == "node_modules") {
+            return false;
+        }
+        if self.entries.contains(&relative) {
+            return true;
+        }
+        let Some(files) = &self.files else {
+            return true;
+        };
+        files.iter().fold(false, |published, pattern| {
+            let (negated, pattern) =
+                pattern.strip_prefix('!').map_or((false, pattern.as_str()), |rest| (true, rest));
+            let pattern = normalize_package_path(pattern);
+            let pattern = pattern.trim_end_matches('/');
+            let matches = fast_glob::glob_match(pattern, &relative)
+                || fast_glob::glob_match(format!("{pattern}/**"), &relative);
+            if matches { !negated } else { published }
+        })
+    }
+}
+
+fn normalize_package_path(path: &str) -> String {
+    path.trim_start_matches("./").trim_start_matches('/').to_string()
+}
+
+/// How a module is loaded, which selects the conditions of `exports` used to resolve it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeModuleSyntax {
+    /// `import` declarations and `import()` expressions.
+    Import,
+    /// `require()` calls.
+    Require,
+}
+
+/// Resolves modules as Node.js would and finds `package.json` files, for the `node` rules.
+///
+/// The lint service creates one for each run, so the results are never stale.
+pub struct NodeResolver {
+    import: Resolver,
+    /// Shares the cache of `import`.
+    require: Resolver,
+    /// `package.json` files found for a directory, keyed by the directory.
+    packages: Mutex<FxHashMap<PathBuf, Option<Arc<NodePackage>>>>,
+}
+
+impl NodeResolver {
+    /// Create a resolver with the `tsconfig.json` of the lint service's module `resolver`.
+    pub fn new(resolver: &Resolver) -> Self {
+        let options = resolver.options();
+        let import = Resolver::new(node_resolve_options("import", options));
+        let require = import.clone_with_options(node_resolve_options("require", options));
+        Self { import, require, packages: Mutex::default() }
+    }
+
+    /// Resolve `specifier` from the file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// When `specifier` cannot be resolved.
+    pub fn resolve(
+        &self,
+        path: &Path,
+        specifier: &str,
+        syntax: NodeModuleSyntax,
+    ) -> Result<PathBuf, ResolveError> {
+        let resolver = match syntax {
+            NodeModuleSyntax::Import => &self.import,
+            NodeModuleSyntax::Require => &self.require,
+        };
+        resolver.resolve_file(path, specifier).map(Resolution::into_path_buf)
+    }
+
+    /// The nearest `package.json` of the directory `dir`.
+    pub fn find_package(&self, dir: &Path) -> Option<Arc<NodePackage>> {
+        let mut cache = self.packages.lock().unwrap();
+        cache
+            .entry(dir.to_path_buf())
+            .or_insert_with(|| {
+                dir.ancestors().find_map(|dir| {
+                    let source = fs::read_to_string(dir.join("package.json")).ok()?;
+                    let manifest: serde_json::Value = serde_json::from_str(&source).ok()?;
+                    Some(Arc::new(NodePackage::parse(&dir.normalize(), &manifest)))
+                })
+            })
+            .clone()
+    }
+}
+
+impl Default for NodeResolver {
+    fn default() -> Self {
+        Self::new(&Resolver::new(ResolveOptions {
+            tsconfig: Some(TsconfigDiscovery::Auto),
+            ..ResolveOptions::default()
+        }))
+    }
+}
+
+/// The options to resolve modules loaded with the `exports` condition `condition`, with the
+/// `tsconfig.json` of `options`.
+fn node_resolve_options(condition: &str, options: &ResolveOptions) -> ResolveOptions {
+    ResolveOptions {
+        extensions: [".js", ".mjs", ".cjs", ".jsx", ".ts", ".mts", ".cts", ".tsx", ".d.ts"]
+            .into_iter()
+            .chain([".json", ".node"])
+            .map(ToString::to_string)
+            .collect(),
+        extension_alias: vec![
+            (".js".into(), vec![".js".into(), ".ts".into(), ".tsx".into()]),
+            (".mjs".into(), vec![".mjs".into(), ".mts".into()]),
+            (".cjs".into(), vec![".cjs".into(), ".cts".into()]),
+        ],
+        condition_names: vec!["node".into(), condition.into()],
+        tsconfig: options.tsconfig.clone(),
+        ..ResolveOptions::default()
+    }
+}
+
+/// The nearest `package.json` of the file being linted.
+pub fn find_node_package(ctx: &LintContext) -> Option<Arc<NodePackage>> {
+    ctx.node_resolver().find_package(ctx.file_path().parent()?)
+}
+
+/// The range of Node.js versions the file being linted must support: `version` when set,
+/// then the `engines.node` field of the nearest `package.json`, then
+/// [`DEFAULT_NODE_VERSION_RANGE`].
+pub fn node_version_range(version: Option<&str>, ctx: &LintContext) -> String {
+    version
+        .map(ToString::to_string)
+        .or_else(|| find_node_package(ctx).and_then(|package| package.engines_node.clone()))
+        .unwrap_or_else(|| DEFAULT_NODE_VERSION_RANGE.to_string())
+}
+
+/// The lowest version of a semver range, such as `>=18.0.0`, `^20.9 || >=22` or `18.x`.
+///
+/// Returns `None` when the range cannot be parsed.
+pub fn min_node_version(range: &str) -> Option<Version> {
+    range.split("||").map(min_version_of_comparators).collect::<Option<Vec<_>>>()?.into_iter().min()
+}
+
+/// The lowest version of a set of comparators which must all match, such as
+/// `>=18 <22` or `18.0.0 - 20`.
+fn min_version_of_comparators(comparators: &str) -> Option<Version> {
+    let comparators = comparators.trim();
+    if let Some((from, _)) = comparators.split_once(" - ") {
+        return parse_partial_version(from.trim()).map(|(version, _)| version);
+    }
+    let mut min = Version(0, 0, 0);
+    let mut tokens = comparators.split_whitespace();
+    while let Some(token) = tokens.next() {
+        // Allow a space between the operator and the version, as in `>= 18`.
+        let token = if token.chars().all(|c| matches!(c, '<' | '>' | '=' | '^' | '~')) {
+            format!("{token}{}", tokens.next()?)
+        } else {
+            token.to_string()
+        };
+        if token.starts_with('<') {
+            continue;
+        }
+        let (is_exclusive, version) = match token.strip_prefix('>') {
+            Some(version) => match version.strip_prefix('=') {
+                Some(version) => (false, version),
+                None => (true, version),
+            },
+            None => (false, token.trim_start_matches(['=', '^', '~'])),
+        };
+        let (mut version, precision) = parse_partial_version(version)?;
+        if is_exclusive {
+            version = match precision {
+                0 => return None,
+                1 => Version(version.0 + 1, 0, 0),
+                2 => Version(version.0, version.1 + 1, 0),
+                _ => Version(version.0, version.1, version.2 + 1),
+            };
+        }
+        min = min.max(version);
+    }
+    Some(min)
+}
+
+/// Parse a version which may omit parts or use wildcards, such as `18`, `18.x` or `*`,
+/// returning the lowest matching version and the number of parts given.
+fn parse_partial_version(version: &str) -> Option<(Version, usize)> {
+    let version = version.trim_start_matches('v');
+    // Ignore pre-release and build metadata, as in `18.0.0-rc.1`.
+    let version = version.split(['-', '+']).next()?;
+    let mut parts = [0; 3];
+    let mut precision = 0;
+    for part in version.split('.') {
+        if precision == 3 {
+            return None;
+        }
+        if matches!(part, "x" | "X" | "*" | "") {
+            break;
+        }
+        parts[precision] = part.parse().ok()?;
+        precision += 1;
+    }
+    Some((Version(parts[0], parts[1], parts[2]), precision))
+}
+
+/// Whether `version` supports a feature added in the versions `since`: the version in each
+/// older release line the feature was backported to, then the version which added it.
+///
+/// For example, `[Version(20, 16, 0), Version(22, 3, 0)]` is supported by `20.16.0` and later
+/// `20.x` versions, and by `22.3.0` and later.
+pub fn is_node_version_supported(version: Version, since: &[Version]) -> bool {
+    let Some((added, backports)) = since.split_last() else {
+        return true;
+    };
+    version >= *added
+        || backports.iter().any(|backport| version.0 == backport.0 && version >= *backport)
+}
+
+/// Whether `specifier` is a Node.js built-in module, such as `fs`, `fs/promises` or `node:test`.
+pub fn is_node_builtin_specifier(specifier: &str) -> bool {
+    specifier.starts_with("node:") || is_nodejs_builtin_module(specifier)
+}
+
+/// The name of the package imported by `specifier`, such as `lodash` for `lodash/map` or
+/// `@scope/pkg` for `@scope/pkg/lib/index.js`. Returns `None` for relative, absolute and
+/// `#subpath` imports.
+pub fn node_package_name(specifier: &str) -> Option<&str> {
+    if specifier.is_empty() || specifier.starts_with(['.', '/', '#']) || specifier.contains(':') {
+        return None;
+    }
+    let end = if specifier.starts_with('@') {
+        specifier.match_indices('/').nth(1).map(|(index, _)| index)
+    } else {
+        specifier.find('/')
+    };
+    Some(end.map_or(specifier, |end| &specifier[..end]))
+}
+
+/// Resolve `specifier` from the file being linted, as Node.js would, including TypeScript
+/// sources imported with a `.js` extension and `paths` of the configured or nearest `tsconfig.json`.
+///
+/// # Errors
+///
+/// When `specifier` cannot be resolved.
+pub fn resolve_node_module(
+    specifier: &str,
+    syntax: NodeModuleSyntax,
+    ctx: &LintContext,
+) -> Result<PathBuf, ResolveError> {
+    ctx.node_resolver().resolve(ctx.file_path(), specifier, syntax)
+}
+
+/// The source of an import declaration, `export ... from` declaration or `import()` expression,
+/// and whether it only imports types.
+pub fn get_node_import_source<'a>(node: &AstNode<'a>) -> Option<(&'a StringLiteral<'a>, bool)> {
+    match node.kind() {
+        AstKind::ImportDeclaration(decl) => Some((&decl.source, decl.import_kind.is_type())),
+        AstKind::ExportFromDeclaration(decl) => Some((&decl.source, decl.export_kind.is_type())),
+        AstKind::ExportAllDeclaration(decl) => Some((&decl.source, decl.export_kind.is_type())),
+        AstKind::ImportExpression(import) => match &import.source {
+            Expression::StringLiteral(source) => Some((source, false)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Whether `specifier` is a module which cannot be found from the file being linted.
+///
+/// Built-in modules and URLs, such as `https://example.com/mod.js`, are never missing.
+pub fn is_missing_node_module(
+    specifier: &str,
+    syntax: NodeModuleSyntax,
+    ctx: &LintContext,
+) -> bool {
+    if specifier.is_empty() || is_node_builtin_specifier(specifier) || has_url_scheme(specifier) {
+        return false;
+    }
+    matches!(
+        resolve_node_module(specifier, syntax, ctx),
+        Err(ResolveError::NotFound(_)
+            | ResolveError::ExtensionAlias(..)
+            | ResolveError::PackagePathNotExported { .. })
+    )
+}
+
+/// Whether `specifier` starts with a URL scheme, such as `https:` or `data:`.
+fn has_url_scheme(specifier: &str) -> bool {
+    specifier.split_once(':').is_some_and(|(scheme, _)| {
+        scheme.len() > 1
+            && scheme.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+    })
+}
+
+/// Whether a Node.js API is provided by a built-in module or is a global.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeApiKind {
+    Module,
+    Global,
+}
+
+/// A reference to a Node.js API, such as the `fs/promises` module, `fs.cp` or `Buffer.from`.
+#[derive(Debug)]
+pub struct NodeApiReference {
+    pub kind: NodeApiKind,
+    /// The path of the API, such as `fs/promises`, `fs.promises.cp` or `Buffer.from`.
+    /// Members of a module are joined to its name with `.`.
+    pub path: String,
+    pub span: Span,
+    /// The node of the reference, whose parent is the call when the API is called.
+    pub node_id: NodeId,
+}
+
+/// Collect the references to Node.js APIs in the file being linted: the built-in modules which
+/// are imported or required, the members used from them, and the members used from `globals`.
+///
+/// Members are followed through variables and destructuring, as in
+/// `const { promises } = require("fs"); promises.cp()`, which references `fs.promises.cp`.
+pub fn collect_node_api_references(globals: &[&str], ctx: &LintContext) -> Vec<NodeApiReference> {
+    let mut collector = NodeApiCollector { ctx, visited: FxHashSet::default(), references: vec![] };
+    for node in ctx.nodes() {
+        match node.kind() {
+            AstKind::ImportDeclaration(decl) if !decl.import_kind.is_type() => {
+                let Some(module) = builtin_module_name(&decl.source.value) else {
+                    continue;
+                };
+                collector.push(
+                    NodeApiKind::Module,
+                    module.to_string(),
+                    decl.source.span,
+                    node.id(),
+                );
+                for specifier in decl.specifiers.iter().flatten() {
+                    match specifier {
+                        ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                            if specifier.import_kind.is_type() {
+                                continue;
+                            }
+                            let path = format!("{module}.{}", specifier.imported.name());
+                            collector.collect_symbol(
+                                NodeApiKind::Module,
+                                specifier.local.symbol_id(),
+                                &path,
+                                true,
+                            );
+                        }
+                        ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                            collector.collect_symbol(
+                                NodeApiKind::Module,
+                                specifier.local.symbol_id(),
+                                module,
+                                false,
+                            );
+                        }
+                        ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                            collector.collect_symbol(
+                                NodeApiKind::Module,
+                                specifier.local.symbol_id(),
+                                module,
+                                false,
+                            );
+                        }
+                    }
+                }
+            }
+            AstKind::ImportExpression(import) => {
+                let Expression::StringLiteral(source) = &import.source else {
+                    continue;
+                };
+                if let Some(module) = builtin_module_name(&source.value) {
+                    collector.push(NodeApiKind::Module, module.to_string(), source.span, node.id());
+                }
+            }
+            AstKind::CallExpression(call) => {
+                let Some(source) = call.common_js_require() else {
+                    continue;
+                };
+                if let Some(module) = builtin_module_name(&source.value) {
+                    collector.push(NodeApiKind::Module, module.to_string(), source.span, node.id());
+                    collector.collect_expression(NodeApiKind::Module, node, module);
+                }
+            }
+            _ => {}
+        }
+    }
+    for &name in globals {
+        let Some(reference_ids) = ctx.scoping().root_unresolved_references().get(name) else {
+            continue;
+        };
+        for &reference_id in reference_ids {
+            let node = ctx.nodes().get_node(ctx.scoping().get_reference(reference_id).node_id());
+            collector.push(NodeApiKind::Global, name.to_string(), node.span(), node.id());
+            collector.collect_expression(NodeApiKind::Global, node, name);
+        }
+    }
+    collector.references
+}
+
+/// The name of the built-in module imported by `specifier`, without the `node:` protocol.
+fn builtin_module_name(specifier: &str) -> Option<&str> {
+    is_node_builtin_specifier(specifier)
+        .then(|| specifier.strip_prefix("node:").unwrap_or(specifier))
+}
+
+struct NodeApiCollector<'c, 'a> {
+    ctx: &'c LintContext<'a>,
+    visited: FxHashSet<SymbolId>,
+    references: Vec<NodeApiReference>,
+}
+
+impl<'a> NodeApiCollector<'_, 'a> {
+    fn push(&mut self, kind: NodeApiKind, path: String, span: Span, node_id: NodeId) {
+        self.references.push(NodeApiReference { kind, path, span, node_id });
+    }
+
+    /// Follow the references of a variable which holds the module or its member at `path`.
+    /// When `is_member`, each reference is itself a reference to `path`.
+    fn collect_symbol(
+        &mut self,
+        kind: NodeApiKind,
+        symbol_id: SymbolId,
+        path: &str,
+        is_member: bool,
+    ) {
+        if !self.visited.insert(symbol_id) {
+            return;
+        }
+        for reference in self.ctx.semantic().symbol_references(symbol_id) {
+            let node = self.ctx.nodes().get_node(reference.node_id());
+            if is_member {
+                self.push(kind, path.to_string(), node.span(), node.id());
+            }
+            self.collect_expression(kind, node, path);
+        }
+    }
+
+    /// Follow the uses of `node`, an expression which evaluates to the API at `path`.
+    fn collect_expression(&mut self, kind: NodeApiKind, node: &AstNode<'a>, path: &str) {
+        let parent = self.ctx.nodes().parent_node(node.id());
+        match parent.kind() {
+            AstKind::ParenthesizedExpression(_)
+            | AstKind::TSAsExpression(_)
+            | AstKind::TSSatisfiesExpression(_)
+            | AstKind::TSNonNullExpression(_) => self.collect_expression(kind, parent, path),
+            AstKind::StaticMemberExpression(member) if member.object.span() == node.span() => {
+                let path = format!("{path}.{}", member.property.name);
+                self.push(kind, path.clone(), member.span, parent.id());
+                self.collect_expression(kind, parent, &path);
+            }
+            AstKind::VariableDeclarator(declarator)
+                if declarator.init.as_ref().is_some_and(|init| init.span() == node.span()) =>
+            {
+                self.collect_pattern(kind, &declarator.id, path, false);
+            }
+            _ => {}
+        }
+    }
+
+    fn collect_pattern(
+        &mut self,
+        kind: NodeApiKind,
+        pattern: &BindingPattern<'a>,
+        path: &str,
+        is_member: bool,
+    ) {
+        match pattern {
+            BindingPattern::BindingIdentifier(ident) => {
+                self.collect_symbol(kind, ident.symbol_id(), path, is_member);
+            }
+            BindingPattern::ObjectPattern(object) => {
+                for property in &object.properties {
+                    let Some(name) = property.key.static_name() else {
+                        continue;
+                    };
+                    self.collect_pattern(kind, &property.value, &format!("{path}.{name}"), true);
+                }
+            }
+            BindingPattern::AssignmentPattern(assignment) => {
+                self.collect_pattern(kind, &assignment.left, path, is_member);
+            }
+            BindingPattern::ArrayPattern(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_compat::Version;
+
+    use super::{is_node_version_supported, min_node_version, node_package_name};
+
+    #[test]
+    fn min_version() {
+        assert_eq!(min_node_version(">=16.0.0"), Some(Version(16, 0, 0)));
+        assert_eq!(min_node_version(">= 18"), Some(Version(18, 0, 0)));
+        assert_eq!(min_node_version("^20.9 || >=22"), Some(Version(20, 9, 0)));
+        assert_eq!(min_node_version("18.x"), Some(Version(18, 0, 0)));
+        assert_eq!(min_node_version("~18.12.1"), Some(Version(18, 12, 1)));
+        assert_eq!(min_node_version(">18"), Some(Version(19, 0, 0)));
+        assert_eq!(min_node_version(">18.1.0 <20"), Some(Version(18, 1, 1)));
+        assert_eq!(min_node_version("16.14.0 - 20"), Some(Version(16, 14, 0)));
+        assert_eq!(min_node_version("<20"), Some(Version(0, 0, 0)));
+        assert_eq!(min_node_version("*"), Some(Version(0, 0, 0)));
+        assert_eq!(min_node_version("v20.1.0-rc.1"), Some(Version(20, 1, 0)));
+        assert_eq!(min_node_version("latest"), None);
+    }
+
+    #[test]
+    fn version_supported() {
+        let since = [Version(20, 16, 0), Version(22, 3, 0)];
+        assert!(is_node_version_supported(Version(20, 16, 0), &since));
+        assert!(is_node_version_supported(Version(22, 3, 0), &since));
+        assert!(is_node_version_supported(Version(23, 0, 0), &since));
+        assert!(!is_node_version_supported(Version(20, 15, 0), &since));
+        assert!(!is_node_version_supported(Version(21, 0, 0), &since));
+        assert!(!is_node_version_supported(Version(22, 2, 0), &since));
+    }
+
+    #[test]
+    fn package_name() {
+        assert_eq!(node_package_name("lodash"), Some("lodash"));
+        assert_eq!(node_package_name("lodash/map"), Some("lodash"));
+        assert_eq!(node_package_name("@scope/pkg/lib/index.js"), Some("@scope/pkg"));
+        assert_eq!(node_package_name("./util"), None);
+        assert_eq!(node_package_name("#internal"), None);
+        assert_eq!(node_package_name("node:fs"), None);
+    }
+}
@@ -0,0 +1,115 @@
+use oxc_ast::ast::{Argument, CallExpression, Expression};
+
+/// Matchers of Playwright which retry until they pass, and so return a promise.
+///
+/// <https://playwright.dev/docs/test-assertions#auto-retrying-assertions>
+pub const PLAYWRIGHT_ASYNC_MATCHERS: [&str; 31] = [
+    "toBeAttached",
+    "toBeChecked",
+    "toBeDisabled",
+    "toBeEditable",
+    "toBeEmpty",
+    "toBeEnabled",
+    "toBeFocused",
+    "toBeHidden",
+    "toBeInViewport",
+    "toBeOK",
+    "toBeVisible",
+    "toContainClass",
+    "toContainText",
+    "toHaveAccessibleDescription",
+    "toHaveAccessibleErrorMessage",
+    "toHaveAccessibleName",
+    "toHaveAttribute",
+    "toHaveCSS",
+    "toHaveClass",
+    "toHaveCount",
+    "toHaveId",
+    "toHaveJSProperty",
+    "toHaveRole",
+    "toHaveScreenshot",
+    "toHaveText",
+    "toHaveTitle",
+    "toHaveURL",
+    "toHaveValue",
+    "toHaveValues",
+    "toMatchAriaSnapshot",
+    "toPass",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaywrightExpectKind {
+    /// `expect(value)`
+    Expect,
+    /// `expect.soft(value)`
+    Soft,
+    /// `expect.poll(() => value)`
+    Poll,
+}
+
+/// An assertion of Playwright Test, such as `expect(locator).not.toBeVisible()`.
+#[derive(Debug)]
+pub struct PlaywrightExpect<'a, 'b> {
+    pub kind: PlaywrightExpectKind,
+    /// The `expect(value)` call.
+    pub expect_call: &'b CallExpression<'a>,
+    /// Whether the assertion is negated with `.not`.
+    pub negated: bool,
+    /// Whether the assertion has `.resolves` or `.rejects`.
+    pub is_promise_assertion: bool,
+    pub matcher: &'a str,
+    pub matcher_arguments: &'b [Argument<'a>],
+}
+
+impl<'a> PlaywrightExpect<'a, '_> {
+    /// The value passed to `expect`.
+    pub fn actual(&self) -> Option<&Expression<'a>> {
+        self.expect_call.arguments.first().and_then(Argument::as_expression)
+    }
+}
+
+/// Parses `call` as the matcher call of an assertion, such as `expect(locator).toBeVisible()`
+/// or `expect.soft(page).not.toHaveURL(url)`.
+pub fn parse_playwright_expect<'a, 'b>(
+    call: &'b CallExpression<'a>,
+) -> Option<PlaywrightExpect<'a, 'b>> {
+    let Expression::StaticMemberExpression(matcher) = &call.callee else {
+        return None;
+    };
+    let mut negated = false;
+    let mut is_promise_assertion = false;
+    let mut object = &matcher.object;
+    let expect_call = loop {
+        match object {
+            Expression::StaticMemberExpression(modifier) => {
+                match modifier.property.name.as_str() {
+                    "not" => negated = true,
+                    "resolves" | "rejects" => is_promise_assertion = true,
+                    _ => return None,
+                }
+                object = &modifier.object;
+            }
+            Expression::CallExpression(expect_call) => break expect_call,
+            _ => return None,
+        }
+    };
+    let kind = match &expect_call.callee {
+        Expression::Identifier(ident) if ident.name == "expect" => PlaywrightExpectKind::Expect,
+        Expression::StaticMemberExpression(member) if member.object.is_specific_id("expect") => {
+            match member.property.name.as_str() {
+                "soft" => PlaywrightExpectKind::Soft,
+                "poll" => PlaywrightExpectKind::Poll,
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+    Some(PlaywrightExpect {
+        kind,
+        expect_call,
+        negated,
+        is_promise_assertion,
+        matcher: matcher.property.name.as_str(),
+        matcher_arguments: &call.arguments,
+    })
+}
@@ -2,9 +2,10 @@ use rustc_hash::FxHashSet;
 
 use oxc_ast::{
     AstKind,
-    ast::{CallExpression, Expression, IdentifierReference, NewExpression},
+    ast::{BindingPattern, CallExpression, Expression, IdentifierReference, NewExpression},
 };
-use oxc_semantic::SymbolId;
+use oxc_semantic::{AstNode, SymbolId};
+use oxc_span::GetSpan;
 
 use crate::context::LintContext;
 
@@ -131,3 +132,101 @@ pub fn get_promise_constructor_inline_executor<'a>(
 
     None
 }
+
+/// Whether the promise produced by `node` is handled: awaited, returned (including as the body
+/// of an arrow function), chained with `.then()`, `.catch()` or `.finally()`, passed to
+/// `Promise.all()` and friends, asserted with `expect(promise).resolves` or
+/// `expect(promise).rejects`, or assigned to a variable which is handled in one of these ways.
+pub fn is_promise_handled<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    is_promise_handled_impl(node, ctx, &mut FxHashSet::default())
+}
+
+/// `visited` holds the variables already followed, for `var p = findByText('x'); var p = p;`.
+fn is_promise_handled_impl<'a>(
+    node: &AstNode<'a>,
+    ctx: &LintContext<'a>,
+    visited: &mut FxHashSet<SymbolId>,
+) -> bool {
+    let mut current = node;
+    loop {
+        let parent = ctx.nodes().parent_node(current.id());
+        match parent.kind() {
+            AstKind::ParenthesizedExpression(_)
+            | AstKind::TSAsExpression(_)
+            | AstKind::TSSatisfiesExpression(_)
+            | AstKind::TSNonNullExpression(_)
+            // The body of an expression arrow function is wrapped in a statement.
+            | AstKind::ExpressionStatement(_)
+            | AstKind::FunctionBody(_) => current = parent,
+            // The consequent or alternate, not the test.
+            AstKind::ConditionalExpression(conditional) => {
+                if conditional.test.span() == current.kind().span() {
+                    return false;
+                }
+                current = parent;
+            }
+            AstKind::AwaitExpression(_) | AstKind::ReturnStatement(_) => return true,
+            AstKind::ArrowFunctionExpression(arrow) => return arrow.is_expression(),
+            AstKind::StaticMemberExpression(member) => {
+                return member.object.span() == current.kind().span()
+                    && matches!(member.property.name.as_str(), "then" | "catch" | "finally");
+            }
+            AstKind::ArrayExpression(_) => {
+                let AstKind::CallExpression(call) = ctx.nodes().parent_kind(parent.id()) else {
+                    return false;
+                };
+                return call.callee.get_member_expr().is_some_and(|member| {
+                    member.object().is_specific_id("Promise")
+                        && matches!(
+                            member.static_property_name(),
+                            Some("all" | "allSettled" | "any" | "race")
+                        )
+                });
+            }
+            AstKind::CallExpression(call) if call.callee.is_specific_id("expect") => {
+                let Some(chain) = get_resolves_or_rejects_chain(parent, ctx) else {
+                    return false;
+                };
+                current = chain;
+            }
+            AstKind::VariableDeclarator(declarator) => {
+                let BindingPattern::BindingIdentifier(ident) = &declarator.id else {
+                    return false;
+                };
+                let symbol_id = ident.symbol_id();
+                if !visited.insert(symbol_id) {
+                    return false;
+                }
+                return ctx.semantic().symbol_references(symbol_id).any(|reference| {
+                    is_promise_handled_impl(ctx.nodes().get_node(reference.node_id()), ctx, visited)
+                });
+            }
+            _ => return false,
+        }
+    }
+}
+
+/// For `expect(promise)`, returns the outermost node of its assertion chain, such as
+/// `expect(promise).resolves.toBe(1)`, if the chain contains `.resolves` or `.rejects`.
+fn get_resolves_or_rejects_chain<'a, 'b>(
+    expect_call: &'b AstNode<'a>,
+    ctx: &'b LintContext<'a>,
+) -> Option<&'b AstNode<'a>> {
+    let mut current = expect_call;
+    let mut has_resolves_or_rejects = false;
+    loop {
+        let parent = ctx.nodes().parent_node(current.id());
+        match parent.kind() {
+            AstKind::StaticMemberExpression(member)
+                if member.object.span() == current.kind().span() =>
+            {
+                has_resolves_or_rejects |=
+                    matches!(member.property.name.as_str(), "resolves" | "rejects");
+            }
+            AstKind::CallExpression(call) if call.callee.span() == current.kind().span() => {}
+            _ => break,
+        }
+        current = parent;
+    }
+    has_resolves_or_rejects.then_some(current)
+}
@@ -0,0 +1,231 @@
+use rustc_hash::FxHashSet;
+
+use oxc_ast::{
+    AstKind,
+    ast::{
+        Argument, AssignmentTarget, BinaryOperator, BindingPattern, CallExpression, Expression,
+        IdentifierReference,
+    },
+};
+use oxc_semantic::{IsGlobalReference, SymbolId};
+use oxc_str::CompactStr;
+
+use crate::context::LintContext;
+
+/// Whether `expression` always evaluates to the same value, so it cannot be controlled by the
+/// user: a literal, a template literal or an operation whose operands are constant, or a
+/// variable which is never reassigned and is initialized with a constant value.
+///
+/// `__dirname` and `__filename` are constant, since they only depend on the location of the file.
+pub fn is_constant_value<'a>(expression: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+    is_constant_expression(expression, ctx, &mut FxHashSet::default())
+}
+
+fn is_constant_expression<'a>(
+    expression: &Expression<'a>,
+    ctx: &LintContext<'a>,
+    visited: &mut FxHashSet<SymbolId>,
+) -> bool {
+    match expression.get_inner_expression() {
+        Expression::StringLiteral(_)
+        | Expression::NumericLiteral(_)
+        | Expression::BigIntLiteral(_)
+        | Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_)
+        | Expression::RegExpLiteral(_) => true,
+        Expression::TemplateLiteral(template) => {
+            template.expressions.iter().all(|expr| is_constant_expression(expr, ctx, visited))
+        }
+        Expression::BinaryExpression(binary) => {
+            is_constant_expression(&binary.left, ctx, visited)
+                && is_constant_expression(&binary.right, ctx, visited)
+        }
+        Expression::LogicalExpression(logical) => {
+            is_constant_expression(&logical.left, ctx, visited)
+                && is_constant_expression(&logical.right, ctx, visited)
+        }
+        Expression::ConditionalExpression(conditional) => {
+            is_constant_expression(&conditional.consequent, ctx, visited)
+                && is_constant_expression(&conditional.alternate, ctx, visited)
+        }
+        Expression::UnaryExpression(unary) => is_constant_expression(&unary.argument, ctx, visited),
+        Expression::Identifier(ident) => is_constant_identifier(ident, ctx, visited),
+        _ => false,
+    }
+}
+
+fn is_constant_identifier<'a>(
+    ident: &IdentifierReference<'a>,
+    ctx: &LintContext<'a>,
+    visited: &mut FxHashSet<SymbolId>,
+) -> bool {
+    let Some(symbol_id) = ctx.scoping().get_reference(ident.reference_id()).symbol_id() else {
+        return matches!(ident.name.as_str(), "undefined" | "__dirname" | "__filename")
+            && ident.is_global_reference(ctx.scoping());
+    };
+    if !visited.insert(symbol_id) || ctx.scoping().symbol_is_mutated(symbol_id) {
+        return false;
+    }
+    let AstKind::VariableDeclarator(declarator) =
+        ctx.nodes().kind(ctx.scoping().symbol_declaration(symbol_id))
+    else {
+        return false;
+    };
+    matches!(declarator.id, BindingPattern::BindingIdentifier(_))
+        && declarator.init.as_ref().is_some_and(|init| is_constant_expression(init, ctx, visited))
+}
+
+/// Whether `expression` is HTML which cannot contain markup from the user: a constant value, the
+/// result of one of the `escape` functions or template tags, such as `DOMPurify.sanitize(html)`,
+/// or a template literal or concatenation of such values.
+pub fn is_sanitized_html<'a>(
+    expression: &Expression<'a>,
+    escape: &[CompactStr],
+    ctx: &LintContext<'a>,
+) -> bool {
+    if is_constant_value(expression, ctx) {
+        return true;
+    }
+    match expression.get_inner_expression() {
+        Expression::CallExpression(call) => is_escape_function(&call.callee, escape),
+        Expression::TaggedTemplateExpression(tagged) => is_escape_function(&tagged.tag, escape),
+        Expression::TemplateLiteral(template) => {
+            template.expressions.iter().all(|expr| is_sanitized_html(expr, escape, ctx))
+        }
+        Expression::BinaryExpression(binary) if binary.operator == BinaryOperator::Addition => {
+            is_sanitized_html(&binary.left, escape, ctx)
+                && is_sanitized_html(&binary.right, escape, ctx)
+        }
+        Expression::LogicalExpression(logical) => {
+            is_sanitized_html(&logical.left, escape, ctx)
+                && is_sanitized_html(&logical.right, escape, ctx)
+        }
+        Expression::ConditionalExpression(conditional) => {
+            is_sanitized_html(&conditional.consequent, escape, ctx)
+                && is_sanitized_html(&conditional.alternate, escape, ctx)
+        }
+        _ => false,
+    }
+}
+
+/// Whether `callee` is one of the `escape` functions, such as `escapeHTML` or `DOMPurify.sanitize`.
+fn is_escape_function(callee: &Expression, escape: &[CompactStr]) -> bool {
+    escape.iter().any(|escape| is_member_path(callee, escape))
+}
+
+/// Whether `expression` is the dot-separated `path`, such as `DOMPurify.sanitize` for
+/// `DOMPurify.sanitize` or `DOMPurify['sanitize']`. Compares the names in place, from the last one.
+pub fn is_member_path(expression: &Expression, path: &str) -> bool {
+    match expression.get_inner_expression() {
+        Expression::Identifier(ident) => ident.name == path,
+        Expression::StaticMemberExpression(member) => {
+            path.rsplit_once('.').is_some_and(|(object, property)| {
+                member.property.name == property && is_member_path(&member.object, object)
+            })
+        }
+        Expression::ComputedMemberExpression(member) => {
+            path.rsplit_once('.').is_some_and(|(object, property)| {
+                member.static_property_name().is_some_and(|name| name == property)
+                    && is_member_path(&member.object, object)
+            })
+        }
+        _ => false,
+    }
+}
+
+/// Functions of `fs` which take paths, and the indexes of the paths in their arguments.
+/// `Sync` functions, such as `readFileSync`, take the same arguments.
+const FS_PATH_ARGUMENTS: [(&str, &[usize]); 36] = [
+    ("access", &[0]),
+    ("appendFile", &[0]),
+    ("chmod", &[0]),
+    ("chown", &[0]),
+    ("copyFile", &[0, 1]),
+    ("cp", &[0, 1]),
+    ("createReadStream", &[0]),
+    ("createWriteStream", &[0]),
+    ("exists", &[0]),
+    ("lchmod", &[0]),
+    ("lchown", &[0]),
+    ("link", &[0, 1]),
+    ("lstat", &[0]),
+    ("lutimes", &[0]),
+    ("mkdir", &[0]),
+    ("mkdtemp", &[0]),
+    ("open", &[0]),
+    ("openAsBlob", &[0]),
+    ("opendir", &[0]),
+    ("readFile", &[0]),
+    ("readdir", &[0]),
+    ("readlink", &[0]),
+    ("realpath", &[0]),
+    ("rename", &[0, 1]),
+    ("rm", &[0]),
+    ("rmdir", &[0]),
+    ("stat", &[0]),
+    ("statfs", &[0]),
+    ("symlink", &[0, 1]),
+    ("truncate", &[0]),
+    ("unlink", &[0]),
+    ("unwatchFile", &[0]),
+    ("utimes", &[0]),
+    ("watch", &[0]),
+    ("watchFile", &[0]),
+    ("writeFile", &[0]),
+];
+
+/// The indexes of the paths in the arguments of a function of `fs`, by the path of the function
+/// as in `fs.readFile`, `fs.promises.rm` or `fs/promises.writeFile`.
+pub fn fs_path_arguments(path: &str) -> Option<&'static [usize]> {
+    let function = ["fs.promises.", "fs/promises.", "fs."]
+        .iter()
+        .find_map(|prefix| path.strip_prefix(prefix))?;
+    let name = function.strip_suffix("Sync").unwrap_or(function);
+    let index = FS_PATH_ARGUMENTS.binary_search_by(|(key, _)| key.cmp(&name)).ok()?;
+    Some(FS_PATH_ARGUMENTS[index].1)
+}
+
+/// The name and the HTML arguments of a call to a method which parses HTML, such as
+/// `insertAdjacentHTML`, `document.write` or `createContextualFragment`.
+pub fn html_method_arguments<'b, 'a>(
+    call: &'b CallExpression<'a>,
+) -> Option<(&'a str, &'b [Argument<'a>])> {
+    let Expression::StaticMemberExpression(member) = call.callee.get_inner_expression() else {
+        return None;
+    };
+    let method = member.property.name.as_str();
+    let html = match method {
+        "insertAdjacentHTML" => &call.arguments[call.arguments.len().min(1)..],
+        "createContextualFragment" | "setHTMLUnsafe" => &call.arguments[..],
+        "write" | "writeln" if is_document(&member.object) => &call.arguments[..],
+        _ => return None,
+    };
+    Some((method, html))
+}
+
+/// The name of the HTML property assigned to by `target`, as in `element.innerHTML`.
+pub fn html_property_name<'a>(target: &AssignmentTarget<'a>) -> Option<&'a str> {
+    let name = match target {
+        AssignmentTarget::StaticMemberExpression(member) => member.property.name.as_str(),
+        AssignmentTarget::ComputedMemberExpression(member) => {
+            member.static_property_name()?.as_str()
+        }
+        _ => return None,
+    };
+    matches!(name, "innerHTML" | "outerHTML").then_some(name)
+}
+
+/// Whether `expression` is a document, as in `document`, `window.document` or
+/// `iframe.contentDocument`.
+fn is_document(expression: &Expression) -> bool {
+    match expression.get_inner_expression() {
+        Expression::Identifier(ident) => ident.name == "document",
+        Expression::StaticMemberExpression(member) => {
+            matches!(
+                member.property.name.as_str(),
+                "document" | "contentDocument" | "ownerDocument"
+            )
+        }
+        _ => false,
+    }
+}
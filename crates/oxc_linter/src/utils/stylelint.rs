@@ -0,0 +1,325 @@
+use std::fmt::Write;
+
+use oxc_allocator::ArenaStringBuilder;
+use oxc_ast::{
+    AstKind, AstType,
+    ast::{Expression, TemplateLiteral},
+};
+use oxc_css_parser::ast::{
+    ComponentValue, Declaration, QualifiedRule, SimpleBlock, Statement, Stylesheet,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_formatter_css::{CssVariant, TEMPLATE_PLACEHOLDER_PREFIX, TEMPLATE_PLACEHOLDER_SUFFIX};
+use oxc_span::Span;
+
+use crate::context::ContextHost;
+
+/// Tags of css-in-js templates (styled-components, emotion): the tag's root identifier,
+/// so `styled.div`, `styled(Button)` and `styled.div.attrs(...)` all match `styled`.
+const CSS_IN_JS_TAGS: [&str; 5] =
+    ["createGlobalStyle", "css", "injectGlobal", "keyframes", "styled"];
+
+/// A stylesheet parsed for the `stylelint` rules:
+/// either a whole stylesheet file, or a single css-in-js template.
+pub struct ParsedStylesheet<'a> {
+    pub stylesheet: Stylesheet<'a>,
+    /// The text that was parsed, which the CSS spans index into.
+    /// For a template, its quasis joined with `` `PLACEHOLDER-N` `` markers.
+    source_text: &'a str,
+    /// `(offset in source_text, offset in the linted script)` for the start of every
+    /// part of `source_text` copied from the script, in ascending order.
+    segments: Vec<(u32, u32)>,
+}
+
+impl<'a> ParsedStylesheet<'a> {
+    /// Map a CSS span to a span in the linted script.
+    pub fn span(&self, span: &oxc_css_parser::Span) -> Span {
+        let span = oxc_formatter_css::to_span(span);
+        Span::new(self.offset(span.start), self.offset(span.end))
+    }
+
+    /// Source text covered by a CSS span.
+    pub fn text(&self, span: &oxc_css_parser::Span) -> &'a str {
+        &self.source_text[span.start..span.end]
+    }
+
+    fn offset(&self, offset: u32) -> u32 {
+        let index = self.segments.partition_point(|&(start, _)| start <= offset).saturating_sub(1);
+        let (start, original_start) = self.segments[index];
+        original_start + (offset - start)
+    }
+}
+
+/// All stylesheets of a script section, see [`build_stylesheets`].
+#[derive(Default)]
+pub struct Stylesheets<'a> {
+    stylesheets: Vec<ParsedStylesheet<'a>>,
+    /// Syntax error of a stylesheet file.
+    /// A css-in-js template that fails to parse is skipped silently instead:
+    /// its interpolations can stand for anything.
+    syntax_error: Option<OxcDiagnostic>,
+}
+
+impl<'a> Stylesheets<'a> {
+    pub fn iter(&self) -> impl Iterator<Item = &ParsedStylesheet<'a>> {
+        self.stylesheets.iter()
+    }
+
+    pub fn syntax_error(&self) -> Option<&OxcDiagnostic> {
+        self.syntax_error.as_ref()
+    }
+}
+
+/// Parse the stylesheets of the current script section:
+/// the whole file for a stylesheet file, every css-in-js tagged template otherwise.
+pub fn build_stylesheets<'a>(host: &ContextHost<'a>) -> Stylesheets<'a> {
+    let allocator = host.allocator();
+
+    if let Some(source) = host.current_sub_host().stylesheet() {
+        return match oxc_formatter_css::parse(allocator, source.source_text, source.variant, false)
+        {
+            Ok(stylesheet) => Stylesheets {
+                stylesheets: vec![ParsedStylesheet {
+                    stylesheet,
+                    source_text: source.source_text,
+                    segments: vec![(0, 0)],
+                }],
+                syntax_error: None,
+            },
+            Err(error) => Stylesheets { stylesheets: vec![], syntax_error: Some(error) },
+        };
+    }
+
+    let stylesheets = host
+        .semantic()
+        .nodes()
+        .iter()
+        .filter_map(|node| {
+            let AstKind::TaggedTemplateExpression(tagged) = node.kind() else {
+                return None;
+            };
+            if !tag_name(&tagged.tag).is_some_and(|name| CSS_IN_JS_TAGS.contains(&name)) {
+                return None;
+            }
+            parse_template(host, &tagged.quasi)
+        })
+        .collect();
+
+    Stylesheets { stylesheets, syntax_error: None }
+}
+
+fn tag_name<'a>(expr: &'a Expression<'a>) -> Option<&'a str> {
+    match expr.get_inner_expression() {
+        Expression::Identifier(ident) => Some(ident.name.as_str()),
+        Expression::StaticMemberExpression(member) => tag_name(&member.object),
+        Expression::CallExpression(call) => tag_name(&call.callee),
+        _ => None,
+    }
+}
+
+/// Join the quasis with placeholder markers, the same way the formatter embeds css-in-js,
+/// and parse the result as SCSS.
+fn parse_template<'a>(
+    host: &ContextHost<'a>,
+    quasi: &TemplateLiteral<'a>,
+) -> Option<ParsedStylesheet<'a>> {
+    let mut source_text = ArenaStringBuilder::new_in(host.allocator());
+    let mut segments = Vec::with_capacity(quasi.quasis.len());
+    for (index, element) in quasi.quasis.iter().enumerate() {
+        if index > 0 {
+            source_text.push_str(TEMPLATE_PLACEHOLDER_PREFIX);
+            write!(source_text, "{}", index - 1).ok()?;
+            source_text.push_str(TEMPLATE_PLACEHOLDER_SUFFIX);
+        }
+        segments.push((u32::try_from(source_text.len()).ok()?, element.span.start));
+        source_text.push_str(element.value.raw.as_str());
+    }
+    let source_text = source_text.into_str();
+    if source_text.trim().is_empty() {
+        return None;
+    }
+
+    let stylesheet =
+        oxc_formatter_css::parse(host.allocator(), source_text, CssVariant::Scss, true).ok()?;
+    Some(ParsedStylesheet { stylesheet, source_text, segments })
+}
+
+/// Shared `should_run` for the `stylelint` rules: the file is a stylesheet,
+/// or it has tagged templates that may be css-in-js.
+pub fn should_run_stylelint(host: &ContextHost) -> bool {
+    host.current_sub_host().stylesheet().is_some()
+        || host.semantic().nodes().contains(AstType::TaggedTemplateExpression)
+}
+
+/// Call `f` on every list of sibling statements: the stylesheet itself, then every block
+/// nested in it, in source order. `in_rule` tells whether the list is inside a qualified rule,
+/// i.e. whether its selectors are nested.
+pub fn walk_statement_lists<'s, 'a>(
+    stylesheet: &'s Stylesheet<'a>,
+    f: &mut impl FnMut(&'s [Statement<'a>], bool),
+) {
+    walk_statements(&stylesheet.statements, false, f);
+}
+
+fn walk_statements<'s, 'a>(
+    statements: &'s [Statement<'a>],
+    in_rule: bool,
+    f: &mut impl FnMut(&'s [Statement<'a>], bool),
+) {
+    f(statements, in_rule);
+    for statement in statements {
+        let (block, is_rule) = match statement {
+            Statement::QualifiedRule(QualifiedRule { block, .. }) => (block, true),
+            Statement::LessConditionalQualifiedRule(rule) => (&rule.block, true),
+            Statement::AtRule(at_rule) => match &at_rule.block {
+                Some(block) => (block, false),
+                None => continue,
+            },
+            Statement::KeyframeBlock(keyframe) => (&keyframe.block, false),
+            Statement::LessMixinDefinition(mixin) => (&mixin.block, true),
+            Statement::UnknownSassAtRule(at_rule) => match &at_rule.block {
+                Some(block) => (block, false),
+                None => continue,
+            },
+            Statement::SassIfAtRule(if_rule) => {
+                for clause in std::iter::once(&if_rule.if_clause).chain(&if_rule.else_if_clauses) {
+                    walk_block(&clause.block, in_rule, f);
+                }
+                if let Some(block) = &if_rule.else_clause {
+                    walk_block(block, in_rule, f);
+                }
+                continue;
+            }
+            Statement::Declaration(declaration) => {
+                // Sass nested properties: `font: { family: x; size: y; }`
+                for value in &declaration.value {
+                    if let ComponentValue::SassNestingDeclaration(nesting) = value {
+                        walk_block(&nesting.block, in_rule, f);
+                    }
+                }
+                continue;
+            }
+            _ => continue,
+        };
+        walk_block(block, in_rule || is_rule, f);
+    }
+}
+
+fn walk_block<'s, 'a>(
+    block: &'s SimpleBlock<'a>,
+    in_rule: bool,
+    f: &mut impl FnMut(&'s [Statement<'a>], bool),
+) {
+    walk_statements(&block.statements, in_rule, f);
+}
+
+/// Call `f` on every declaration in `stylesheet`, in source order.
+pub fn walk_declarations<'s, 'a>(
+    stylesheet: &'s Stylesheet<'a>,
+    f: &mut impl FnMut(&'s Declaration<'a>),
+) {
+    walk_statement_lists(stylesheet, &mut |statements, _| {
+        for statement in statements {
+            if let Statement::Declaration(declaration) = statement {
+                f(declaration);
+            }
+        }
+    });
+}
+
+/// Call `f` on every component value in `values`, descending into functions,
+/// brackets and Sass/Less expressions.
+pub fn walk_component_values<'s, 'a>(
+    values: &'s [ComponentValue<'a>],
+    f: &mut impl FnMut(&'s ComponentValue<'a>),
+) {
+    for value in values {
+        walk_component_value(value, f);
+    }
+}
+
+fn walk_component_value<'s, 'a>(
+    value: &'s ComponentValue<'a>,
+    f: &mut impl FnMut(&'s ComponentValue<'a>),
+) {
+    f(value);
+    match value {
+        ComponentValue::Function(function) => walk_component_values(&function.args, f),
+        ComponentValue::BracketBlock(block) => walk_component_values(&block.value, f),
+        ComponentValue::SassList(list) => walk_component_values(&list.elements, f),
+        ComponentValue::LessList(list) => walk_component_values(&list.elements, f),
+        ComponentValue::Calc(calc) => {
+            walk_component_value(&calc.left, f);
+            walk_component_value(&calc.right, f);
+        }
+        ComponentValue::SassBinaryExpression(binary) => {
+            walk_component_value(&binary.left, f);
+            walk_component_value(&binary.right, f);
+        }
+        ComponentValue::LessBinaryOperation(binary) => {
+            walk_component_value(&binary.left, f);
+            walk_component_value(&binary.right, f);
+        }
+        ComponentValue::SassParenthesizedExpression(expr) => walk_component_value(&expr.expr, f),
+        ComponentValue::SassUnaryExpression(expr) => walk_component_value(&expr.expr, f),
+        ComponentValue::LessParenthesizedOperation(expr) => {
+            walk_component_value(&expr.operation, f);
+        }
+        ComponentValue::LessNegativeValue(expr) => walk_component_value(&expr.value, f),
+        ComponentValue::SassKeywordArgument(arg) => walk_component_value(&arg.value, f),
+        ComponentValue::SassMap(map) => {
+            for item in &map.items {
+                walk_component_value(&item.key, f);
+                walk_component_value(&item.value, f);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// A selector as written, with runs of whitespace collapsed to one space, for diagnostics.
+pub fn display_selector(selector: &str) -> String {
+    selector.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Normalize the whitespace of a selector so that equivalent selectors compare equal:
+/// runs of whitespace collapse to one space, and combinators (`>`, `+`, `~`) and commas
+/// lose the whitespace around them. Strings and attribute selectors are kept as written.
+pub fn normalize_selector(selector: &str) -> String {
+    let mut normalized = String::with_capacity(selector.len());
+    let mut quote = None;
+    let mut bracket_depth = 0_u32;
+    let mut pending_space = false;
+    for c in selector.trim().chars() {
+        if let Some(q) = quote {
+            normalized.push(c);
+            if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '"' | '\'' => quote = Some(c),
+            '[' => bracket_depth += 1,
+            ']' => bracket_depth = bracket_depth.saturating_sub(1),
+            _ => {}
+        }
+        if bracket_depth == 0 {
+            if c.is_whitespace() {
+                pending_space = true;
+                continue;
+            }
+            if matches!(c, '>' | '+' | '~' | ',') {
+                pending_space = false;
+                normalized.push(c);
+                continue;
+            }
+            if pending_space && !normalized.ends_with(['>', '+', '~', ',', '(']) {
+                normalized.push(' ');
+            }
+            pending_space = false;
+        }
+        normalized.push(c);
+    }
+    normalized
+}
@@ -0,0 +1,249 @@
+use cow_utils::CowUtils;
+use oxc_ast::ast::{CallExpression, Expression};
+use oxc_semantic::{IsGlobalReference, Scoping};
+use oxc_span::Span;
+
+use crate::{
+    context::ContextHost,
+    utils::{TemplateExpression, find_closing_bracket},
+};
+
+/// The markup of a Svelte component, scanned for the `svelte` template rules.
+///
+/// Only the `{...}` tags are collected, in text and attribute values alike. `<script>` and
+/// `<style>` blocks and HTML comments are skipped.
+pub struct SvelteTemplate<'a> {
+    tags: Vec<SvelteTag<'a>>,
+}
+
+/// A `{...}` tag in Svelte markup.
+#[derive(Debug, Clone, Copy)]
+pub struct SvelteTag<'a> {
+    /// Span of the tag, including the braces.
+    pub span: Span,
+    pub kind: SvelteTagKind<'a>,
+    /// The expression of the tag, such as `html` in `{@html html}` or `items` in
+    /// `{#each items as item}`.
+    pub expression: Option<TemplateExpression<'a>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SvelteTagKind<'a> {
+    /// `{expression}`
+    Expression,
+    /// `{@html ...}`, `{@render ...}`, `{@const ...}` or `{@debug ...}`, without the `@`.
+    Special(&'a str),
+    /// `{#if ...}`, `{:else ...}` or `{/if}`, with the leading character.
+    Block(&'a str),
+}
+
+impl<'a> SvelteTemplate<'a> {
+    pub fn scan(source_text: &'a str) -> Self {
+        let bytes = source_text.as_bytes();
+        let mut tags = vec![];
+        let mut index = 0;
+        while index < bytes.len() {
+            match bytes[index] {
+                b'<' if source_text[index..].starts_with("<!--") => {
+                    index = source_text[index..].find("-->").map_or(bytes.len(), |end| index + end);
+                }
+                b'<' => {
+                    index = skip_raw_text_element(source_text, index).unwrap_or(index + 1);
+                    continue;
+                }
+                b'{' => {
+                    let Some(end) = find_closing_bracket(source_text, index, b'{', b'}') else {
+                        break;
+                    };
+                    tags.push(SvelteTag::new(source_text, index, end));
+                    index = end;
+                }
+                _ => {}
+            }
+            index += 1;
+        }
+        Self { tags }
+    }
+
+    /// All tags, in source order.
+    pub fn tags(&self) -> &[SvelteTag<'a>] {
+        &self.tags
+    }
+}
+
+impl<'a> SvelteTag<'a> {
+    /// The tag from the `{` at `start` to the `}` at `end`.
+    fn new(source_text: &'a str, start: usize, end: usize) -> Self {
+        #[expect(clippy::cast_possible_truncation)]
+        let span = Span::new(start as u32, end as u32 + 1);
+        let content = &source_text[start + 1..end];
+        let content_start = start + 1;
+        let expression = |offset: usize, len: usize| {
+            let offset = content_start + offset;
+            #[expect(clippy::cast_possible_truncation)]
+            TemplateExpression::new(&source_text[offset..offset + len], offset as u32)
+        };
+
+        let Some(prefix @ (b'@' | b'#' | b':' | b'/')) = content.bytes().next() else {
+            return Self {
+                span,
+                kind: SvelteTagKind::Expression,
+                expression: expression(0, content.len()),
+            };
+        };
+        let name_end = content[1..]
+            .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+            .map_or(content.len(), |end| end + 1);
+        let name = &content[1..name_end];
+        let rest = &content[name_end..];
+        let rest_expression = |len: usize| expression(name_end, len);
+        let (kind, expression) = match (prefix, name) {
+            (b'@', "html" | "render") => {
+                (SvelteTagKind::Special(name), rest_expression(rest.len()))
+            }
+            (b'@', _) => (SvelteTagKind::Special(name), None),
+            (b'#', "if" | "key") => {
+                (SvelteTagKind::Block(&content[..name_end]), rest_expression(rest.len()))
+            }
+            // `{#each items as item}`, `{#await promise then value}`
+            (b'#', "each" | "await") => {
+                let end = [" as ", " then ", " catch "]
+                    .iter()
+                    .filter_map(|keyword| rest.find(keyword))
+                    .min()
+                    .unwrap_or(rest.len());
+                (SvelteTagKind::Block(&content[..name_end]), rest_expression(end))
+            }
+            // `{:else if condition}`
+            (b':', "else") => {
+                let trimmed = rest.trim_start();
+                let expression = trimmed.strip_prefix("if").and_then(|condition| {
+                    let offset = content.len() - condition.len();
+                    expression(offset, condition.len())
+                });
+                (SvelteTagKind::Block(&content[..name_end]), expression)
+            }
+            _ => (SvelteTagKind::Block(&content[..name_end]), None),
+        };
+        Self { span, kind, expression }
+    }
+}
+
+/// Skip a `<script>` or `<style>` element starting at the `<` at `start`, returning the index
+/// after its closing tag. `None` if `start` is not the start of such an element.
+fn skip_raw_text_element(source_text: &str, start: usize) -> Option<usize> {
+    let rest = &source_text[start + 1..];
+    let name = ["script", "style"].into_iter().find(|name| {
+        rest.get(..name.len()).is_some_and(|prefix| prefix.eq_ignore_ascii_case(name))
+            && rest[name.len()..]
+                .bytes()
+                .next()
+                .is_some_and(|byte| byte.is_ascii_whitespace() || matches!(byte, b'>' | b'/'))
+    })?;
+    // Attribute values may contain `>`, such as `<script generics="T extends Record<string, unknown>">`.
+    let bytes = source_text.as_bytes();
+    let mut index = start + 1 + name.len();
+    while index < bytes.len() && bytes[index] != b'>' {
+        match bytes[index] {
+            quote @ (b'"' | b'\'') => {
+                index +=
+                    source_text[index + 1..].find(quote as char).map_or(bytes.len(), |end| end + 1);
+            }
+            b'{' => index = find_closing_bracket(source_text, index, b'{', b'}')?,
+            _ => {}
+        }
+        index += 1;
+    }
+    let closing_tag = format!("</{name}");
+    let content = &source_text[index.min(bytes.len())..];
+    let end = content.cow_to_ascii_lowercase().find(&closing_tag).unwrap_or(content.len());
+    Some(source_text.len() - content.len() + end + closing_tag.len())
+}
+
+/// Whether the file being linted is a Svelte component or a `.svelte.js`/`.svelte.ts`
+/// module, where runes such as `$state` are available.
+pub fn is_svelte_file(ctx: &ContextHost) -> bool {
+    let Some(file_name) = ctx.file_path().file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    [".svelte", ".svelte.js", ".svelte.ts"].iter().any(|ext| file_name.ends_with(ext))
+}
+
+/// The name of the rune called by `call`, such as `$state` or `$derived.by`.
+/// `None` if the rune is shadowed by a local binding.
+pub fn get_rune_name(call: &CallExpression, scoping: &Scoping) -> Option<&'static str> {
+    let (object, property) = match &call.callee {
+        Expression::Identifier(ident) => (&**ident, None),
+        Expression::StaticMemberExpression(member) => {
+            let Expression::Identifier(object) = &member.object else {
+                return None;
+            };
+            (&**object, Some(member.property.name.as_str()))
+        }
+        _ => return None,
+    };
+    if !object.is_global_reference(scoping) {
+        return None;
+    }
+    let object = object.name.as_str();
+    let name = match (object, property) {
+        ("$state", None) => "$state",
+        ("$state", Some("raw")) => "$state.raw",
+        ("$state", Some("snapshot")) => "$state.snapshot",
+        ("$derived", None) => "$derived",
+        ("$derived", Some("by")) => "$derived.by",
+        ("$effect", None) => "$effect",
+        ("$effect", Some("pre")) => "$effect.pre",
+        ("$effect", Some("root")) => "$effect.root",
+        ("$effect", Some("tracking")) => "$effect.tracking",
+        ("$props", None) => "$props",
+        ("$props", Some("id")) => "$props.id",
+        ("$bindable", None) => "$bindable",
+        ("$inspect", None) => "$inspect",
+        ("$host", None) => "$host",
+        _ => return None,
+    };
+    Some(name)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{SvelteTagKind, SvelteTemplate};
+
+    #[test]
+    fn scan() {
+        let source_text = r#"<script lang="ts" generics="T extends Record<string, unknown>">
+  let { items } = $props();
+  const style = { color: "red" };
+</script>
+<!-- {commented} -->
+<style>p { color: red; }</style>
+{#each items as item (item.id)}
+  <p title={item.title}>{@html item.body}</p>
+{:else if items === undefined}
+  {`}${"}"}`}
+{/each}"#;
+        let template = SvelteTemplate::scan(source_text);
+        let tags = template
+            .tags()
+            .iter()
+            .map(|tag| (tag.kind, tag.expression.map(|expression| expression.text)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tags,
+            [
+                (SvelteTagKind::Block("#each"), Some("items")),
+                (SvelteTagKind::Expression, Some("item.title")),
+                (SvelteTagKind::Special("html"), Some("item.body")),
+                (SvelteTagKind::Block(":else"), Some("items === undefined")),
+                (SvelteTagKind::Expression, Some(r#"`}${"}"}`"#)),
+                (SvelteTagKind::Block("/each"), None),
+            ]
+        );
+        for tag in template.tags() {
+            if let Some(expression) = tag.expression {
+                assert_eq!(expression.span.source_text(source_text), expression.text);
+            }
+        }
+    }
+}
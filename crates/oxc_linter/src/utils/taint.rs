@@ -0,0 +1,473 @@
+use rustc_hash::FxHashMap;
+
+use oxc_ast::{
+    AstKind,
+    ast::{
+        ArrayExpressionElement, AssignmentOperator, BinaryOperator, BindingPattern, CallExpression,
+        ChainElement, Expression, IdentifierReference, ObjectPropertyKind, PropertyKey,
+    },
+};
+use oxc_diagnostics::LabeledSpan;
+use oxc_semantic::{NodeId, SymbolId};
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, utils::is_member_path};
+
+/// Values which are controlled by the user in servers, command line tools and browsers.
+pub const DEFAULT_TAINT_SOURCES: [&str; 20] = [
+    "document.URL",
+    "document.cookie",
+    "document.documentURI",
+    "document.location",
+    "document.referrer",
+    "location.hash",
+    "location.href",
+    "location.pathname",
+    "location.search",
+    "process.argv",
+    "req.body",
+    "req.cookies",
+    "req.headers",
+    "req.params",
+    "req.query",
+    "request.body",
+    "request.headers",
+    "request.params",
+    "request.query",
+    "window.name",
+];
+
+/// Methods which return a number or a boolean, so their result is never tainted.
+const UNTAINTED_METHODS: [&str; 12] = [
+    "charCodeAt",
+    "endsWith",
+    "every",
+    "has",
+    "includes",
+    "indexOf",
+    "lastIndexOf",
+    "localeCompare",
+    "some",
+    "startsWith",
+    "test",
+    "valueOf",
+];
+
+/// Functions which convert their argument to a number or a boolean.
+const UNTAINTED_FUNCTIONS: [&str; 5] = ["Boolean", "Number", "isNaN", "parseFloat", "parseInt"];
+
+/// The values which a rule considers tainted, and the functions which sanitize them.
+#[derive(Debug, Clone, Copy)]
+pub struct TaintConfig<'c> {
+    /// Member paths of the sources, such as `req.query`. Members of a source, such as
+    /// `req.query.name`, are tainted as well. `window.`, `globalThis.` and `self.` are
+    /// ignored at the start of a path.
+    pub sources: &'c [&'c str],
+    /// Functions and template tags whose result is safe to pass to the sink, such as
+    /// `DOMPurify.sanitize` or `path.basename`.
+    pub sanitizers: &'c [&'c str],
+}
+
+/// A step of a [`TaintFlow`], such as the source or an assignment to a variable.
+#[derive(Debug, Clone)]
+pub struct TaintStep {
+    pub span: Span,
+    pub label: String,
+}
+
+/// How a tainted value flows from its source to an expression.
+#[derive(Debug, Clone)]
+pub struct TaintFlow {
+    /// The steps of the flow, from the source to the last variable that held the value.
+    pub steps: Vec<TaintStep>,
+}
+
+impl TaintFlow {
+    fn new(span: Span, label: String) -> Self {
+        Self { steps: vec![TaintStep { span, label }] }
+    }
+
+    fn with_step(mut self, span: Span, label: String) -> Self {
+        self.steps.push(TaintStep { span, label });
+        self
+    }
+
+    /// The labels of the flow, followed by the primary label of the `sink`.
+    pub fn labels(&self, sink: Span, label: impl Into<String>) -> Vec<LabeledSpan> {
+        self.steps
+            .iter()
+            .map(|step| step.span.label(step.label.clone()))
+            .chain(std::iter::once(sink.primary_label(label)))
+            .collect()
+    }
+}
+
+/// Intra-procedural taint analysis: finds whether an expression may hold a value from one of
+/// the sources, following variables, assignments, destructuring, template literals,
+/// concatenation and calls, and stopping at sanitizers.
+///
+/// The writes to a variable which reach a read are found with the control flow graph: a
+/// write reaches a read when the read is reachable from it, unless another write comes
+/// between them in the basic block of the read. Writes in another function, such as the
+/// outer function of a closure, always reach the read.
+pub struct TaintAnalysis<'c, 'a> {
+    ctx: &'c LintContext<'a>,
+    config: TaintConfig<'c>,
+    /// The taint of the values written to variables, by the node of the write. `None` while
+    /// a write is analyzed, so cycles such as `x = x + 1` end.
+    writes: FxHashMap<NodeId, Option<TaintFlow>>,
+}
+
+/// A write to a variable: its declaration, an assignment or an update.
+#[derive(Clone, Copy)]
+struct Write {
+    node_id: NodeId,
+    /// Where the write happens, after its value is evaluated.
+    position: u32,
+}
+
+impl<'c, 'a> TaintAnalysis<'c, 'a> {
+    pub fn new(ctx: &'c LintContext<'a>, config: TaintConfig<'c>) -> Self {
+        Self { ctx, config, writes: FxHashMap::default() }
+    }
+
+    /// How a value from one of the sources flows to `expression`, or `None` if it does not.
+    pub fn taint_of(&mut self, expression: &Expression<'a>) -> Option<TaintFlow> {
+        if let Expression::StaticMemberExpression(member) = expression.get_inner_expression()
+            && member.property.name == "length"
+        {
+            return None;
+        }
+        if let Some(source) = self.source_of(expression) {
+            return Some(TaintFlow::new(
+                expression.span(),
+                format!("`{source}` is controlled by the user"),
+            ));
+        }
+        match expression.get_inner_expression() {
+            Expression::Identifier(ident) => self.taint_of_reference(ident),
+            Expression::StaticMemberExpression(member) => self.taint_of(&member.object),
+            Expression::ComputedMemberExpression(member) => self.taint_of(&member.object),
+            Expression::ChainExpression(chain) => match &chain.expression {
+                ChainElement::CallExpression(call) => self.taint_of_call(call),
+                ChainElement::TSNonNullExpression(expression) => {
+                    self.taint_of(&expression.expression)
+                }
+                element => self.taint_of(element.as_member_expression()?.object()),
+            },
+            Expression::TemplateLiteral(template) => {
+                template.expressions.iter().find_map(|expression| self.taint_of(expression))
+            }
+            Expression::BinaryExpression(binary) if binary.operator == BinaryOperator::Addition => {
+                self.taint_of(&binary.left).or_else(|| self.taint_of(&binary.right))
+            }
+            Expression::LogicalExpression(logical) => {
+                self.taint_of(&logical.left).or_else(|| self.taint_of(&logical.right))
+            }
+            Expression::ConditionalExpression(conditional) => self
+                .taint_of(&conditional.consequent)
+                .or_else(|| self.taint_of(&conditional.alternate)),
+            Expression::SequenceExpression(sequence) => self.taint_of(sequence.expressions.last()?),
+            Expression::AssignmentExpression(assignment) => self.taint_of(&assignment.right),
+            Expression::AwaitExpression(expression) => self.taint_of(&expression.argument),
+            Expression::ArrayExpression(array) => {
+                array.elements.iter().find_map(|element| match element {
+                    ArrayExpressionElement::SpreadElement(spread) => {
+                        self.taint_of(&spread.argument)
+                    }
+                    element => self.taint_of(element.as_expression()?),
+                })
+            }
+            Expression::ObjectExpression(object) => {
+                object.properties.iter().find_map(|property| match property {
+                    ObjectPropertyKind::ObjectProperty(property) => self.taint_of(&property.value),
+                    ObjectPropertyKind::SpreadProperty(spread) => self.taint_of(&spread.argument),
+                })
+            }
+            Expression::CallExpression(call) => self.taint_of_call(call),
+            Expression::NewExpression(new) => {
+                new.arguments.iter().find_map(|argument| self.taint_of(argument.as_expression()?))
+            }
+            Expression::TaggedTemplateExpression(tagged) => {
+                if self.is_sanitizer(&tagged.tag) {
+                    return None;
+                }
+                tagged.quasi.expressions.iter().find_map(|expression| self.taint_of(expression))
+            }
+            _ => None,
+        }
+    }
+
+    /// Calls are tainted when their receiver or one of their arguments is tainted, unless
+    /// they are sanitizers or return numbers or booleans.
+    fn taint_of_call(&mut self, call: &CallExpression<'a>) -> Option<TaintFlow> {
+        if self.is_sanitizer(&call.callee) {
+            return None;
+        }
+        let receiver = match call.callee.get_inner_expression() {
+            Expression::Identifier(ident) if UNTAINTED_FUNCTIONS.contains(&ident.name.as_str()) => {
+                return None;
+            }
+            Expression::StaticMemberExpression(member) => {
+                if UNTAINTED_METHODS.contains(&member.property.name.as_str())
+                    || member.object.is_specific_id("Math")
+                {
+                    return None;
+                }
+                Some(&member.object)
+            }
+            Expression::ComputedMemberExpression(member) => Some(&member.object),
+            _ => None,
+        };
+        receiver.and_then(|receiver| self.taint_of(receiver)).or_else(|| {
+            call.arguments.iter().find_map(|argument| self.taint_of(argument.as_expression()?))
+        })
+    }
+
+    fn taint_of_reference(&mut self, ident: &IdentifierReference<'a>) -> Option<TaintFlow> {
+        let reference = self.ctx.scoping().get_reference(ident.reference_id());
+        let symbol_id = reference.symbol_id()?;
+        self.taint_of_symbol_at(symbol_id, reference.node_id(), ident.span)
+    }
+
+    /// The taint of the variable `symbol_id` when it is read at `read`.
+    fn taint_of_symbol_at(
+        &mut self,
+        symbol_id: SymbolId,
+        read: NodeId,
+        read_span: Span,
+    ) -> Option<TaintFlow> {
+        for write in self.reaching_writes(symbol_id, read, read_span) {
+            if let Some(flow) = self.taint_of_write(symbol_id, write.node_id) {
+                return Some(flow);
+            }
+        }
+        None
+    }
+
+    fn reaching_writes(&self, symbol_id: SymbolId, read: NodeId, read_span: Span) -> Vec<Write> {
+        let ctx = self.ctx;
+        let nodes = ctx.nodes();
+        let cfg = ctx.cfg();
+        let read_block = nodes.cfg_id(read);
+        let read_function = enclosing_function(read, ctx);
+
+        let declaration = ctx.scoping().symbol_declaration(symbol_id);
+        let writes = std::iter::once(Write {
+            node_id: declaration,
+            position: nodes.get_node(declaration).span().end,
+        })
+        .chain(ctx.scoping().get_resolved_references(symbol_id).filter(|r| r.is_write()).map(
+            |reference| {
+                let node_id = reference.node_id();
+                let position = write_expression(node_id, ctx)
+                    .map_or_else(|| nodes.get_node(node_id).span().end, |node| node.span().end);
+                Write { node_id, position }
+            },
+        ))
+        .collect::<Vec<_>>();
+
+        let is_before_read = |write: &Write| {
+            nodes.cfg_id(write.node_id) == read_block
+                && enclosing_function(write.node_id, ctx) == read_function
+                && write.position <= read_span.start
+        };
+        // Code in a basic block runs in order, so the last write before the read in its
+        // block hides all the other writes.
+        if let Some(last) =
+            writes.iter().filter(|write| is_before_read(write)).max_by_key(|w| w.position)
+        {
+            return vec![*last];
+        }
+        writes
+            .into_iter()
+            .filter(|write| {
+                let write_block = nodes.cfg_id(write.node_id);
+                if enclosing_function(write.node_id, ctx) != read_function {
+                    return true;
+                }
+                if write_block == read_block {
+                    // A write after the read in the same block reaches it in the next iteration.
+                    return cfg.is_cyclic(read_block);
+                }
+                cfg.is_reachable(write_block, read_block)
+            })
+            .collect()
+    }
+
+    /// The taint of the value written to `symbol_id` at `write`.
+    fn taint_of_write(&mut self, symbol_id: SymbolId, write: NodeId) -> Option<TaintFlow> {
+        if let Some(flow) = self.writes.get(&write) {
+            return flow.clone();
+        }
+        self.writes.insert(write, None);
+        let flow = self.compute_taint_of_write(symbol_id, write);
+        self.writes.insert(write, flow.clone());
+        flow
+    }
+
+    fn compute_taint_of_write(&mut self, symbol_id: SymbolId, write: NodeId) -> Option<TaintFlow> {
+        let ctx = self.ctx;
+        let name = ctx.scoping().symbol_name(symbol_id);
+        match ctx.nodes().kind(write) {
+            AstKind::VariableDeclarator(declarator) => {
+                let flow = match &declarator.init {
+                    Some(init) => self
+                        .taint_of(init)
+                        .or_else(|| self.taint_of_destructured(init, &declarator.id, symbol_id))?,
+                    // `for (const item of items)`
+                    None => match ctx.nodes().parent_kind(ctx.nodes().parent_id(write)) {
+                        AstKind::ForOfStatement(statement) => self.taint_of(&statement.right)?,
+                        AstKind::ForInStatement(statement) => self.taint_of(&statement.right)?,
+                        _ => return None,
+                    },
+                };
+                let label = if matches!(declarator.id, BindingPattern::BindingIdentifier(_)) {
+                    format!("assigned to `{name}`")
+                } else {
+                    format!("destructured into `{name}`")
+                };
+                Some(flow.with_step(ctx.scoping().symbol_span(symbol_id), label))
+            }
+            AstKind::IdentifierReference(ident) => {
+                let node = write_expression(write, ctx)?;
+                let flow = match node.kind() {
+                    AstKind::AssignmentExpression(assignment) => {
+                        let flow = self.taint_of(&assignment.right);
+                        if assignment.operator == AssignmentOperator::Assign {
+                            flow?
+                        } else {
+                            // `x += value` keeps the taint of `x`.
+                            flow.or_else(|| self.taint_of_symbol_at(symbol_id, write, ident.span))?
+                        }
+                    }
+                    AstKind::ForOfStatement(statement) => self.taint_of(&statement.right)?,
+                    AstKind::ForInStatement(statement) => self.taint_of(&statement.right)?,
+                    _ => return None,
+                };
+                Some(flow.with_step(ident.span, format!("assigned to `{name}`")))
+            }
+            _ => None,
+        }
+    }
+
+    /// The taint of a property of `init` destructured into `symbol_id`, such as `hash` in
+    /// `const { hash } = location`.
+    fn taint_of_destructured(
+        &self,
+        init: &Expression<'a>,
+        pattern: &BindingPattern<'a>,
+        symbol_id: SymbolId,
+    ) -> Option<TaintFlow> {
+        let mut path = member_path(init)?;
+        path.extend(binding_keys(pattern, symbol_id)?);
+        let source = self.source_of_path(&path)?;
+        Some(TaintFlow::new(init.span(), format!("`{source}` is controlled by the user")))
+    }
+
+    /// The source which `expression` reads, such as `req.query` for `req.query.name`.
+    fn source_of(&self, expression: &Expression<'a>) -> Option<&'c str> {
+        self.source_of_path(&member_path(expression)?)
+    }
+
+    fn source_of_path(&self, path: &[&str]) -> Option<&'c str> {
+        let global_path = match path.split_first() {
+            Some((&("window" | "globalThis" | "self"), rest)) if !rest.is_empty() => rest,
+            _ => path,
+        };
+        self.config.sources.iter().copied().find(|source| {
+            let segments = source.split('.').collect::<Vec<_>>();
+            [path, global_path].iter().any(|path| path.starts_with(&segments))
+        })
+    }
+
+    fn is_sanitizer(&self, callee: &Expression<'a>) -> bool {
+        self.config.sanitizers.iter().any(|sanitizer| is_member_path(callee, sanitizer))
+    }
+}
+
+/// The names of a chain of static member expressions, such as `["req", "query", "name"]`
+/// for `req.query.name` or `req.query[key]`.
+fn member_path<'a>(expression: &Expression<'a>) -> Option<Vec<&'a str>> {
+    match expression.get_inner_expression() {
+        Expression::Identifier(ident) => Some(vec![ident.name.as_str()]),
+        Expression::StaticMemberExpression(member) => {
+            let mut path = member_path(&member.object)?;
+            path.push(member.property.name.as_str());
+            Some(path)
+        }
+        Expression::ComputedMemberExpression(member) => {
+            let mut path = member_path(&member.object)?;
+            if let Some(name) = member.static_property_name() {
+                path.push(name.as_str());
+            }
+            Some(path)
+        }
+        _ => None,
+    }
+}
+
+/// The static keys of the properties which `pattern` destructures into `symbol_id`, such as
+/// `["a", "b"]` for `{ a: { b: value } }`. The keys stop at the first element of an array or a
+/// computed key.
+fn binding_keys<'a>(pattern: &BindingPattern<'a>, symbol_id: SymbolId) -> Option<Vec<&'a str>> {
+    match pattern {
+        BindingPattern::BindingIdentifier(ident) => (ident.symbol_id() == symbol_id).then(Vec::new),
+        BindingPattern::AssignmentPattern(assignment) => binding_keys(&assignment.left, symbol_id),
+        BindingPattern::ObjectPattern(object) => {
+            object
+                .properties
+                .iter()
+                .find_map(|property| {
+                    let mut keys = binding_keys(&property.value, symbol_id)?;
+                    match &property.key {
+                        PropertyKey::StaticIdentifier(key) => keys.insert(0, key.name.as_str()),
+                        PropertyKey::StringLiteral(key) => keys.insert(0, key.value.as_str()),
+                        _ => keys.clear(),
+                    }
+                    Some(keys)
+                })
+                // The rest of an object has the properties which are not destructured.
+                .or_else(|| {
+                    object.rest.as_ref().and_then(|rest| binding_keys(&rest.argument, symbol_id))
+                })
+        }
+        BindingPattern::ArrayPattern(array) => array
+            .elements
+            .iter()
+            .flatten()
+            .chain(array.rest.as_ref().map(|rest| &rest.argument))
+            .find_map(|element| binding_keys(element, symbol_id).map(|_| Vec::new())),
+    }
+}
+
+/// The assignment, `for...of` or `for...in` statement which writes the identifier at `node_id`,
+/// looking through destructuring targets such as `({ a } = value)`.
+fn write_expression<'c, 'a>(
+    node_id: NodeId,
+    ctx: &'c LintContext<'a>,
+) -> Option<&'c oxc_semantic::AstNode<'a>> {
+    ctx.nodes().ancestors(node_id).find_map(|node| match node.kind() {
+        AstKind::AssignmentExpression(_)
+        | AstKind::ForOfStatement(_)
+        | AstKind::ForInStatement(_)
+        | AstKind::UpdateExpression(_) => Some(Some(node)),
+        AstKind::ObjectAssignmentTarget(_)
+        | AstKind::ArrayAssignmentTarget(_)
+        | AstKind::AssignmentTargetWithDefault(_)
+        | AstKind::AssignmentTargetPropertyIdentifier(_)
+        | AstKind::AssignmentTargetPropertyProperty(_)
+        | AstKind::AssignmentTargetRest(_) => None,
+        _ => Some(None),
+    })?
+}
+
+/// The function which contains `node_id`, or `None` at the top level.
+fn enclosing_function(node_id: NodeId, ctx: &LintContext) -> Option<NodeId> {
+    ctx.nodes()
+        .ancestors(node_id)
+        .find(|node| {
+            matches!(node.kind(), AstKind::Function(_) | AstKind::ArrowFunctionExpression(_))
+        })
+        .map(oxc_semantic::AstNode::id)
+}
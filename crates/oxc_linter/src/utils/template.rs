@@ -0,0 +1,83 @@
+use oxc_allocator::Allocator;
+use oxc_ast::ast::Expression;
+use oxc_parser::Parser;
+use oxc_span::{SourceType, Span};
+
+/// A JS expression embedded in component markup, such as `count + 1` in the Svelte tag
+/// `{count + 1}` or the Angular interpolation `{{ count + 1 }}`.
+#[derive(Debug, Clone, Copy)]
+pub struct TemplateExpression<'a> {
+    /// Source text of the expression.
+    pub text: &'a str,
+    /// Span of the expression in the linted section.
+    pub span: Span,
+}
+
+impl<'a> TemplateExpression<'a> {
+    /// The expression `text`, which starts at `start` in the linted section.
+    /// `None` if `text` is only whitespace.
+    #[expect(clippy::cast_possible_truncation)]
+    pub fn new(text: &'a str, start: u32) -> Option<Self> {
+        let trimmed = text.trim_start();
+        let start = start + (text.len() - trimmed.len()) as u32;
+        let text = trimmed.trim_end();
+        if text.is_empty() {
+            return None;
+        }
+        Some(Self { text, span: Span::sized(start, text.len() as u32) })
+    }
+
+    /// Parse the expression as TypeScript. `None` if it is not a valid expression,
+    /// such as an Angular pipe with arguments (`date | format:'short'`).
+    ///
+    /// Spans of the returned AST are relative to [`Self::text`]: map them to the linted
+    /// section with [`Self::map_span`].
+    pub fn parse(&self, allocator: &'a Allocator) -> Option<Expression<'a>> {
+        Parser::new(allocator, self.text, SourceType::ts()).parse_expression().ok()
+    }
+
+    /// Map a span of the parsed expression to a span in the linted section.
+    pub fn map_span(&self, span: Span) -> Span {
+        Span::new(self.span.start + span.start, self.span.start + span.end)
+    }
+}
+
+/// Find the index of the `close` character (`}` or `)`) which balances the `open` character
+/// at `start` in `text`, skipping over strings and template literals.
+pub fn find_closing_bracket(text: &str, start: usize, open: u8, close: u8) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut depth = 0usize;
+    let mut index = start;
+    while index < bytes.len() {
+        match bytes[index] {
+            quote @ (b'\'' | b'"') => {
+                index += 1;
+                while index < bytes.len() && bytes[index] != quote {
+                    index += if bytes[index] == b'\\' { 2 } else { 1 };
+                }
+            }
+            b'`' => {
+                index += 1;
+                while index < bytes.len() && bytes[index] != b'`' {
+                    match bytes[index] {
+                        b'\\' => index += 2,
+                        b'$' if bytes.get(index + 1) == Some(&b'{') => {
+                            index = find_closing_bracket(text, index + 1, b'{', b'}')? + 1;
+                        }
+                        _ => index += 1,
+                    }
+                }
+            }
+            byte if byte == open => depth += 1,
+            byte if byte == close => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+    None
+}
@@ -0,0 +1,49 @@
+use oxc_ast::ast::{CallExpression, Expression};
+
+/// The suffixes of the built-in Testing Library queries, as in `getByRole`.
+const QUERY_SUFFIXES: [&str; 8] =
+    ["LabelText", "PlaceholderText", "Text", "DisplayValue", "AltText", "Title", "Role", "TestId"];
+
+/// Whether `name` is a built-in query, such as `getByText`, `queryAllByRole` or `findByTestId`.
+pub fn is_testing_library_query(name: &str) -> bool {
+    let Some(rest) = ["get", "query", "find"].iter().find_map(|variant| name.strip_prefix(variant))
+    else {
+        return false;
+    };
+    let rest = rest.strip_prefix("All").unwrap_or(rest);
+    rest.strip_prefix("By").is_some_and(|suffix| QUERY_SUFFIXES.contains(&suffix))
+}
+
+/// Whether `name` is a built-in asynchronous query, such as `findByText` or `findAllByRole`.
+pub fn is_testing_library_async_query(name: &str) -> bool {
+    name.starts_with("find") && is_testing_library_query(name)
+}
+
+/// Returns the name of the query called by `call`, as in `getByText(...)`,
+/// `screen.getByText(...)` or `within(list).getByText(...)`.
+pub fn get_testing_library_query_name<'a>(call: &CallExpression<'a>) -> Option<&'a str> {
+    let name = match &call.callee {
+        Expression::Identifier(ident) => ident.name.as_str(),
+        Expression::StaticMemberExpression(member) => member.property.name.as_str(),
+        _ => return None,
+    };
+    is_testing_library_query(name).then_some(name)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{is_testing_library_async_query, is_testing_library_query};
+
+    #[test]
+    fn test_is_testing_library_query() {
+        for name in ["getByText", "getAllByRole", "queryByTestId", "findAllByLabelText"] {
+            assert!(is_testing_library_query(name), "{name}");
+        }
+        for name in ["getBy", "getByFoo", "findText", "byText", "getAllText", "render"] {
+            assert!(!is_testing_library_query(name), "{name}");
+        }
+        assert!(is_testing_library_async_query("findByRole"));
+        assert!(is_testing_library_async_query("findAllByAltText"));
+        assert!(!is_testing_library_async_query("getByRole"));
+    }
+}
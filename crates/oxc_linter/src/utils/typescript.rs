@@ -48,6 +48,20 @@ pub enum TypeOrValueSpecifier {
     Package(PackageSpecifier),
 }
 
+impl TypeOrValueSpecifier {
+    /// Whether this specifier can match a value named `name` which is declared in the file being
+    /// linted, or whose declaration is unknown.
+    ///
+    /// `lib` and `package` specifiers require knowing where a value is declared, so never match.
+    pub fn matches_local_name(&self, name: &str) -> bool {
+        match self {
+            Self::String(specifier) => specifier == name,
+            Self::File(file) => file.name.contains(name),
+            Self::Lib(_) | Self::Package(_) => false,
+        }
+    }
+}
+
 /// Describes specific types or values declared in local files.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
@@ -112,6 +126,16 @@ pub enum NameSpecifier {
     Multiple(Vec<String>),
 }
 
+impl NameSpecifier {
+    /// Whether `name` is one of the names in this specifier.
+    pub fn contains(&self, name: &str) -> bool {
+        match self {
+            Self::Single(single) => single == name,
+            Self::Multiple(names) => names.iter().any(|n| n == name),
+        }
+    }
+}
+
 /// Returns `true` when `node_id` has a TypeScript ambient declaration ancestor
 /// such as `declare module`, `declare namespace`, or `declare global`,
 /// including `global {}` nested inside ambient modules or namespaces.
@@ -0,0 +1,263 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use cow_utils::CowUtils;
+use rustc_hash::FxHashMap;
+
+use crate::context::LintContext;
+
+/// The dependency maps of a `package.json` compared across a workspace.
+/// `peerDependencies` are left out, as they are usually meant to be looser.
+pub const WORKSPACE_DEPENDENCY_FIELDS: [&str; 3] =
+    ["dependencies", "devDependencies", "optionalDependencies"];
+
+/// The workspaces found during a lint run, for the `json` rules.
+///
+/// The lint service creates one for each run, so that every `package.json` of a workspace
+/// is read once per run, and a long-running service such as the language server sees changes.
+#[derive(Default)]
+pub struct Workspaces {
+    /// The workspace rooted at each directory looked at, or `None` if it is not a workspace root.
+    roots: Mutex<FxHashMap<PathBuf, Option<Arc<Workspace>>>>,
+}
+
+impl Workspaces {
+    /// The workspace that contains the directory `dir`, if any.
+    pub fn find(&self, dir: &Path) -> Option<Arc<Workspace>> {
+        let mut roots = self.roots.lock().unwrap();
+        dir.ancestors().find_map(|root| {
+            roots.entry(root.to_path_buf()).or_insert_with(|| Workspace::load(root)).clone()
+        })
+    }
+}
+
+/// The workspace of the file being linted, if any.
+pub fn find_workspace(ctx: &LintContext) -> Option<Arc<Workspace>> {
+    ctx.workspaces().find(ctx.file_path().parent()?)
+}
+
+/// A workspace: a root directory and the dependencies of each of its packages.
+pub struct Workspace {
+    root: PathBuf,
+    /// The `package.json` of each package, the workspace root included.
+    packages: Vec<WorkspacePackage>,
+    /// `dependency -> [(version range, index in packages)]`, for plain version ranges.
+    dependencies: FxHashMap<String, Vec<(String, usize)>>,
+}
+
+struct WorkspacePackage {
+    path: PathBuf,
+    /// `path` with symlinks resolved, to recognize the file being linted.
+    canonical_path: Option<PathBuf>,
+}
+
+/// The version range of a dependency in a package of a workspace.
+#[derive(Debug, Clone, Copy)]
+pub struct WorkspaceDependency<'w> {
+    pub version: &'w str,
+    /// The `package.json` of the package.
+    pub package: &'w Path,
+}
+
+impl Workspace {
+    /// The workspace rooted at `root`, if `root` is a workspace root.
+    fn load(root: &Path) -> Option<Arc<Self>> {
+        let patterns = workspace_patterns(root)?;
+        let mut paths = vec![root.join("package.json")];
+        let (excluded, included): (Vec<_>, Vec<_>) =
+            patterns.iter().map(String::as_str).partition(|pattern| pattern.starts_with('!'));
+        for pattern in included {
+            expand_pattern(root, pattern, &mut paths);
+        }
+        for pattern in excluded {
+            let mut excluded_paths = vec![];
+            expand_pattern(root, &pattern[1..], &mut excluded_paths);
+            paths.retain(|path| !excluded_paths.contains(path));
+        }
+        paths.sort_unstable();
+        paths.dedup();
+
+        let mut dependencies: FxHashMap<String, Vec<(String, usize)>> = FxHashMap::default();
+        for (index, path) in paths.iter().enumerate() {
+            let Ok(source) = fs::read_to_string(path) else {
+                continue;
+            };
+            let Ok(serde_json::Value::Object(manifest)) = serde_json::from_str(&source) else {
+                continue;
+            };
+            for field in WORKSPACE_DEPENDENCY_FIELDS {
+                let Some(serde_json::Value::Object(versions)) = manifest.get(field) else {
+                    continue;
+                };
+                for (dependency, version) in versions {
+                    if let Some(version) =
+                        version.as_str().filter(|version| is_plain_version_range(version))
+                    {
+                        dependencies
+                            .entry(dependency.clone())
+                            .or_default()
+                            .push((version.to_string(), index));
+                    }
+                }
+            }
+        }
+
+        let packages = paths
+            .into_iter()
+            .map(|path| WorkspacePackage { canonical_path: fs::canonicalize(&path).ok(), path })
+            .collect();
+        Some(Arc::new(Self { root: root.to_path_buf(), packages, dependencies }))
+    }
+
+    /// Whether no package of the workspace has a dependency.
+    pub fn has_dependencies(&self) -> bool {
+        !self.dependencies.is_empty()
+    }
+
+    /// The version ranges of `dependency` in all packages but the `package.json` at `current`.
+    pub fn dependency_versions<'w>(
+        &'w self,
+        dependency: &str,
+        current: &Path,
+    ) -> Vec<WorkspaceDependency<'w>> {
+        let Some(versions) = self.dependencies.get(dependency) else {
+            return vec![];
+        };
+        let current = fs::canonicalize(current).unwrap_or_else(|_| current.to_path_buf());
+        versions
+            .iter()
+            .map(|(version, index)| (version, &self.packages[*index]))
+            .filter(|(_, package)| package.canonical_path.as_ref() != Some(&current))
+            .map(|(version, package)| WorkspaceDependency { version, package: &package.path })
+            .collect()
+    }
+
+    /// `package` relative to the workspace root, for diagnostics.
+    pub fn display(&self, package: &Path) -> String {
+        package
+            .strip_prefix(&self.root)
+            .unwrap_or(package)
+            .to_string_lossy()
+            .cow_replace('\\', "/")
+            .into_owned()
+    }
+}
+
+/// A plain semver range, as opposed to a protocol (`workspace:*`, `npm:foo@1`, `file:../foo`)
+/// or a git/GitHub/URL specifier, which can't be compared.
+pub fn is_plain_version_range(version: &str) -> bool {
+    !version.contains([':', '/'])
+}
+
+/// The package globs of the workspace rooted at `root`, if `root` is a workspace root:
+/// `packages` of `pnpm-workspace.yaml`, or `workspaces` of `package.json`.
+fn workspace_patterns(root: &Path) -> Option<Vec<String>> {
+    if let Ok(source) = fs::read_to_string(root.join("pnpm-workspace.yaml")) {
+        return Some(pnpm_workspace_patterns(&source));
+    }
+    let source = fs::read_to_string(root.join("package.json")).ok()?;
+    let manifest: serde_json::Value = serde_json::from_str(&source).ok()?;
+    let workspaces = manifest.get("workspaces")?;
+    let patterns = workspaces.get("packages").unwrap_or(workspaces).as_array()?;
+    Some(patterns.iter().filter_map(|pattern| pattern.as_str().map(ToString::to_string)).collect())
+}
+
+/// The `packages` list of a `pnpm-workspace.yaml`.
+/// Only the block sequence form is understood, which is what pnpm itself writes.
+fn pnpm_workspace_patterns(source: &str) -> Vec<String> {
+    let mut patterns = vec![];
+    let mut in_packages = false;
+    for line in source.lines() {
+        let line = line.split(" #").next().unwrap_or_default().trim_end();
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if in_packages && let Some(item) = trimmed.strip_prefix('-') {
+            patterns.push(item.trim().trim_matches(['"', '\'']).to_string());
+        } else if !line.starts_with([' ', '\t']) {
+            in_packages = trimmed == "packages:";
+        }
+    }
+    patterns
+}
+
+/// Add the `package.json` of every directory matching the glob `pattern` under `root`.
+fn expand_pattern(root: &Path, pattern: &str, packages: &mut Vec<PathBuf>) {
+    let pattern = pattern.trim_start_matches("./").trim_end_matches('/');
+    let mut dirs = vec![root.to_path_buf()];
+    for segment in pattern.split('/').filter(|segment| !segment.is_empty() && *segment != ".") {
+        let mut matched = vec![];
+        for dir in dirs {
+            if segment == "**" {
+                collect_dirs(&dir, &mut matched);
+            } else if segment.contains(['*', '?', '[', '{']) {
+                matched.extend(sub_dirs(&dir).filter(|sub_dir| {
+                    sub_dir
+                        .file_name()
+                        .is_some_and(|name| fast_glob::glob_match(segment, name.as_encoded_bytes()))
+                }));
+            } else {
+                let sub_dir = dir.join(segment);
+                if sub_dir.is_dir() {
+                    matched.push(sub_dir);
+                }
+            }
+        }
+        dirs = matched;
+    }
+    packages.extend(
+        dirs.into_iter().map(|dir| dir.join("package.json")).filter(|package| package.is_file()),
+    );
+}
+
+/// The sub-directories of `dir`, skipping `node_modules` and hidden directories.
+fn sub_dirs(dir: &Path) -> impl Iterator<Item = PathBuf> {
+    fs::read_dir(dir).into_iter().flatten().filter_map(|entry| {
+        let entry = entry.ok()?;
+        let name = entry.file_name();
+        if name == "node_modules" || name.as_encoded_bytes().starts_with(b".") {
+            return None;
+        }
+        entry.file_type().ok()?.is_dir().then(|| entry.path())
+    })
+}
+
+/// `dir` and all its descendant directories, for a `**` glob segment.
+fn collect_dirs(dir: &Path, dirs: &mut Vec<PathBuf>) {
+    dirs.push(dir.to_path_buf());
+    for sub_dir in sub_dirs(dir) {
+        collect_dirs(&sub_dir, dirs);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::{Workspaces, pnpm_workspace_patterns};
+
+    #[test]
+    fn test_pnpm_workspace_patterns() {
+        let source = "packages:\n  - 'packages/*'\n  - \"apps/**\" # apps\n  - '!**/test/**'\ncatalog:\n  react: ^19.0.0\n";
+        assert_eq!(pnpm_workspace_patterns(source), ["packages/*", "apps/**", "!**/test/**"]);
+        assert_eq!(pnpm_workspace_patterns("packages:\n- tools/*\n"), ["tools/*"]);
+        assert!(pnpm_workspace_patterns("catalog:\n  - a\n").is_empty());
+    }
+
+    #[test]
+    fn test_workspaces_are_cached_by_root() {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/json/workspace");
+        let workspaces = Workspaces::default();
+        let a = workspaces.find(&fixtures.join("packages/a")).unwrap();
+        let b = workspaces.find(&fixtures.join("packages/b")).unwrap();
+        assert!(std::sync::Arc::ptr_eq(&a, &b));
+
+        let versions = a.dependency_versions("react", &fixtures.join("packages/a/package.json"));
+        let packages = versions.iter().map(|dep| a.display(dep.package)).collect::<Vec<_>>();
+        assert_eq!(packages, ["packages/b/package.json"]);
+    }
+}
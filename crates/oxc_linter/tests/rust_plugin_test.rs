@@ -0,0 +1,172 @@
+use std::{
+    env::consts::{DLL_PREFIX, DLL_SUFFIX},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::Allocator;
+use oxc_linter::{
+    ConfigBuilderError, ConfigStore, ConfigStoreBuilder, ContextSubHost, ContextSubHostOptions,
+    ExternalPluginStore, LintOptions, Linter, ModuleRecord, Oxlintrc,
+};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+
+/// Path of the `rust_plugin` example.
+///
+/// `cargo test` builds the example along with this test, with the same profile and features.
+/// When running this test alone, build it first with `cargo build -p oxc_linter --example rust_plugin`.
+fn example_plugin_path() -> PathBuf {
+    let exe = std::env::current_exe().unwrap();
+    // `<target dir>/<profile>/deps/<test binary>`
+    let profile_dir = exe.parent().and_then(Path::parent).unwrap();
+    let path = profile_dir.join("examples").join(format!("{DLL_PREFIX}rust_plugin{DLL_SUFFIX}"));
+    assert!(
+        path.exists(),
+        "`rust_plugin` example not found at {}. Build it with `cargo build -p oxc_linter --example rust_plugin`.",
+        path.display()
+    );
+    path
+}
+
+fn build_linter(config: &serde_json::Value) -> Result<Linter, ConfigBuilderError> {
+    let oxlintrc: Oxlintrc = serde_json::from_value(config.clone()).unwrap();
+    let mut store = ExternalPluginStore::new(false);
+    let config = ConfigStoreBuilder::from_oxlintrc(true, oxlintrc, None, &mut store, None)?
+        .build(&mut store)?;
+    Ok(Linter::new(
+        LintOptions::default(),
+        ConfigStore::new(config, FxHashMap::default(), store),
+        None,
+    ))
+}
+
+fn lint(linter: &Linter, source_text: &str) -> Vec<String> {
+    lint_file(linter, "test.mjs", source_text)
+}
+
+fn lint_file(linter: &Linter, path: &str, source_text: &str) -> Vec<String> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    let program = allocator.alloc(ret.program);
+    let semantic = SemanticBuilder::new_linter().with_cfg(true).build(program).semantic;
+    let sub_host = ContextSubHost::new(
+        semantic,
+        Arc::new(ModuleRecord::default()),
+        0,
+        ContextSubHostOptions::default(),
+    );
+    linter
+        .run(Path::new(path), vec![sub_host], &allocator)
+        .into_iter()
+        .map(|message| format!("{}: {}", message.error.code, message.error.message))
+        .collect()
+}
+
+#[test]
+fn lint_with_rust_plugin() {
+    let plugin_path = example_plugin_path();
+
+    let linter = build_linter(&serde_json::json!({
+        "rustPlugins": [plugin_path],
+        "rules": { "example/no-banned-identifier": "error" }
+    }))
+    .unwrap();
+    assert_eq!(
+        lint(&linter, "foo(); bar();"),
+        ["example(no-banned-identifier): Unexpected reference to `foo`"]
+    );
+
+    // Options are passed to the rule, and the plugin can be renamed
+    let linter = build_linter(&serde_json::json!({
+        "rustPlugins": [{ "name": "renamed", "specifier": plugin_path }],
+        "rules": { "renamed/no-banned-identifier": ["error", { "name": "bar" }] }
+    }))
+    .unwrap();
+    assert_eq!(
+        lint(&linter, "foo(); bar();"),
+        ["renamed(no-banned-identifier): Unexpected reference to `bar`"]
+    );
+}
+
+#[test]
+fn rust_plugin_jest_rule() {
+    let plugin_path = example_plugin_path();
+
+    let linter = build_linter(&serde_json::json!({
+        "plugins": ["jest"],
+        "rustPlugins": [plugin_path],
+        "rules": { "example/no-exclusive-test": "error" }
+    }))
+    .unwrap();
+    let source_text = "it.only('works', () => {}); it('works too', () => {});";
+    assert_eq!(
+        lint_file(&linter, "foo.test.mjs", source_text),
+        ["example(no-exclusive-test): Unexpected `it.only`"]
+    );
+    // Only test files are checked
+    assert!(lint_file(&linter, "foo.mjs", source_text).is_empty());
+}
+
+#[test]
+fn reload_rust_plugin() {
+    let plugin_path = example_plugin_path();
+
+    // Loading the same plugin again, as the language server does on every config change,
+    // reuses the loaded library
+    for _ in 0..3 {
+        let linter = build_linter(&serde_json::json!({
+            "rustPlugins": [{ "name": "reloaded", "specifier": plugin_path }],
+            "rules": { "reloaded/no-banned-identifier": "error" }
+        }))
+        .unwrap();
+        assert_eq!(
+            lint(&linter, "foo();"),
+            ["reloaded(no-banned-identifier): Unexpected reference to `foo`"]
+        );
+    }
+}
+
+#[test]
+fn rust_plugin_errors() {
+    let plugin_path = example_plugin_path();
+
+    let err = build_linter(&serde_json::json!({
+        "rustPlugins": [plugin_path],
+        "rules": { "example/no-banned-identifier": ["error", { "unknown": true }] }
+    }))
+    .err()
+    .unwrap();
+    assert!(matches!(err, ConfigBuilderError::RuleConfigurationErrors { .. }), "{err}");
+
+    let err = build_linter(&serde_json::json!({
+        "rustPlugins": [plugin_path],
+        "rules": { "example/no-such-rule": "error" }
+    }))
+    .err()
+    .unwrap();
+    assert!(err.to_string().contains("Rule 'no-such-rule' not found in plugin 'example'"), "{err}");
+
+    let err = build_linter(&serde_json::json!({
+        "rustPlugins": [{ "name": "eslint", "specifier": plugin_path }],
+    }))
+    .err()
+    .unwrap();
+    assert!(err.to_string().contains("Plugin name 'eslint' is reserved"), "{err}");
+}
+
+#[test]
+fn load_invalid_rust_plugin() {
+    let err =
+        build_linter(&serde_json::json!({ "rustPlugins": ["./does-not-exist.so"] })).err().unwrap();
+    assert!(matches!(err, ConfigBuilderError::RustPluginLoadFailed { .. }), "{err}");
+
+    // Not a dynamic library
+    let not_a_library = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml");
+    let err = build_linter(&serde_json::json!({ "rustPlugins": [not_a_library] })).err().unwrap();
+    assert!(err.to_string().contains("Failed to load Rust plugin"), "{err}");
+    assert!(matches!(err, ConfigBuilderError::RustPluginLoadFailed { .. }), "{err}");
+}
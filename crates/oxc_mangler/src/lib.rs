@@ -14,14 +14,19 @@ use oxc_allocator::{Allocator, ArenaHashSet, ArenaVec, BitSet};
 use oxc_ast::ast::{Declaration, Program, Statement};
 use oxc_data_structures::inline_string::InlineString;
 use oxc_ecmascript::BoundNames;
-use oxc_semantic::{AstNodes, Reference, Scoping, Semantic, SemanticBuilder, Stats, SymbolId};
+use oxc_semantic::{
+    AstNodes, Reference, ScopeId, Scoping, Semantic, SemanticBuilder, Stats, SymbolId,
+};
 use oxc_span::SourceType;
 use oxc_str::{CompactStr, Ident, Str};
 
 pub(crate) mod base54;
 mod keep_names;
+mod name_cache;
 
 pub use keep_names::MangleOptionsKeepNames;
+use name_cache::ScopePaths;
+pub use name_cache::{InvalidMangleNameCacheTarget, MangleNameCache};
 
 #[derive(Default, Debug, Clone)]
 pub struct MangleOptions {
@@ -49,6 +54,14 @@ pub struct MangleOptions {
     /// Default: empty.
     pub reserved: FxHashSet<CompactStr>,
 
+    /// Mangled names from a previous build, reused so unchanged bindings keep their names.
+    /// Pass an empty cache to start one. Equivalent to terser's `nameCache`.
+    ///
+    /// When set, [`ManglerReturn::name_cache`] returns it updated with this program's names.
+    ///
+    /// Default: `None`.
+    pub name_cache: Option<MangleNameCache>,
+
     /// Use more readable mangled names
     /// (e.g. `slot_0`, `slot_1`, `slot_2`, ...) for debugging.
     ///
@@ -94,6 +107,9 @@ pub struct ManglerReturn {
     /// A vector where each element corresponds to a class in declaration order.
     /// Each element is a mapping from original private member names to their mangled names.
     pub class_private_mappings: IndexVec<ClassId, FxHashMap<String, CompactStr>>,
+    /// [`MangleOptions::name_cache`] updated with the names of this program's renamed bindings.
+    /// `None` if no name cache was given.
+    pub name_cache: Option<MangleNameCache>,
 }
 
 /// # Name Mangler / Symbol Minification
@@ -112,7 +128,7 @@ pub struct ManglerReturn {
 /// let parsed = Parser::new(&allocator, source, SourceType::mjs()).parse();
 /// assert!(parsed.diagnostics.is_empty());
 ///
-/// let ManglerReturn { scoping, class_private_mappings } = Mangler::new()
+/// let ManglerReturn { scoping, class_private_mappings, .. } = Mangler::new()
 ///     .with_options(MangleOptions {
 ///         top_level: Some(true),
 ///         debug: true,
@@ -324,10 +340,15 @@ impl<'t> Mangler<'t> {
             builder = builder.with_stats(stats);
         }
         let mut semantic = builder.build(program).semantic;
-        let class_private_mappings = self.build_with_semantic(&mut semantic, program);
-        ManglerReturn { scoping: semantic.into_scoping(), class_private_mappings }
+        let (class_private_mappings, name_cache) = self.build_with_semantic(&mut semantic, program);
+        ManglerReturn { scoping: semantic.into_scoping(), class_private_mappings, name_cache }
     }
 
+    /// Mangles the program in place of `semantic`'s scoping.
+    ///
+    /// Returns the private member mappings and the updated [`MangleNameCache`]
+    /// (see [`ManglerReturn`]).
+    ///
     /// # Panics
     ///
     /// Panics if the child_ids does not exist in scope_tree.
@@ -335,14 +356,14 @@ impl<'t> Mangler<'t> {
         self,
         semantic: &mut Semantic<'_>,
         program: &Program<'_>,
-    ) -> IndexVec<ClassId, FxHashMap<String, CompactStr>> {
+    ) -> (IndexVec<ClassId, FxHashMap<String, CompactStr>>, Option<MangleNameCache>) {
         let class_private_mappings = Self::collect_private_members_from_semantic(semantic);
-        if self.options.debug {
-            self.build_with_semantic_impl(semantic, program, debug_name);
+        let name_cache = if self.options.debug {
+            self.build_with_semantic_impl(semantic, program, debug_name)
         } else {
-            self.build_with_semantic_impl(semantic, program, base54);
-        }
-        class_private_mappings
+            self.build_with_semantic_impl(semantic, program, base54)
+        };
+        (class_private_mappings, name_cache)
     }
 
     /// Mangle the program: rewrite local bindings to the shortest legal names.
@@ -392,13 +413,21 @@ impl<'t> Mangler<'t> {
         semantic: &mut Semantic<'_>,
         program: &Program<'_>,
         generate_name: G,
-    ) {
+    ) -> Option<MangleNameCache> {
         let (scoping, ast_nodes) = semantic.scoping_mut_and_nodes();
         let allocator = self.temp_allocator.as_ref();
+        let scope_paths =
+            self.options.name_cache.as_ref().map(|_| ScopePaths::new(scoping, ast_nodes));
 
         // ── Phase 1: collect constraints — names we must not reuse or shadow. ──
-        let constraints =
-            Constraints::collect(allocator, scoping, ast_nodes, program, &self.options);
+        let constraints = Constraints::collect(
+            allocator,
+            scoping,
+            ast_nodes,
+            program,
+            &self.options,
+            scope_paths.as_ref(),
+        );
         // ── Phase 2: assign slots — give bindings that can share a name the same slot. ──
         let slots = SlotAssignment::compute(allocator, scoping, ast_nodes, &constraints);
         // ── Phase 3: rank slots by reference frequency (hottest first). ──
@@ -406,8 +435,29 @@ impl<'t> Mangler<'t> {
         // ── Phase 4: generate that many short, collision-free names. ──
         let names =
             NameTable::generate(allocator, scoping, &constraints, &ranking, &slots, generate_name);
+        // Original names of the bindings we rename, recorded in the name cache below.
+        let renamed = if self.options.name_cache.is_some() {
+            renamed_symbols(scoping, &constraints, &slots)
+        } else {
+            vec![]
+        };
+        let pinned = constraints.pinned;
         // ── Phase 5: give each slot its name and rewrite every reference. ──
         names.apply(allocator, scoping, &ranking);
+        for (symbol_id, name) in pinned {
+            scoping.set_symbol_name(symbol_id, Ident::from(name.as_str()));
+        }
+
+        let mut name_cache = self.options.name_cache?;
+        let scope_paths = scope_paths?;
+        for (symbol_id, original) in renamed {
+            name_cache.insert_generated(
+                scope_paths.get(scoping.symbol_scope_id(symbol_id)).clone(),
+                original,
+                CompactStr::new(scoping.symbol_name(symbol_id)),
+            );
+        }
+        Some(name_cache)
     }
 
     /// Collects and generates mangled names for private members using semantic information
@@ -495,6 +545,11 @@ struct Constraints<'a, 's> {
     /// Names preserved by the `keep_names` option (function / class names).
     keep_name_names: FxHashSet<&'s str>,
     keep_name_symbols: Option<BitSet<'a>>,
+    /// Mangled names from [`MangleOptions::name_cache`] — never generated for other slots.
+    cached_names: FxHashSet<&'s str>,
+    /// Symbols renamed to their cached name instead of being assigned a slot.
+    pinned: Vec<(SymbolId, CompactStr)>,
+    pinned_symbols: Option<BitSet<'a>>,
 }
 
 /// Phase 2 output — each symbol's slot, plus the names a direct `eval` can see.
@@ -525,6 +580,7 @@ impl<'a, 's> Constraints<'a, 's> {
         ast_nodes: &AstNodes,
         program: &'a Program<'a>,
         options: &'s MangleOptions,
+        scope_paths: Option<&ScopePaths>,
     ) -> Self {
         let top_level = options.top_level(program.source_type);
         let (exported_names, exported_symbols) = if top_level && program.source_type.is_module() {
@@ -534,14 +590,92 @@ impl<'a, 's> Constraints<'a, 's> {
         };
         let (keep_name_names, keep_name_symbols) =
             collect_keep_name_symbols(options.keep_names, allocator, scoping, ast_nodes);
-        Self {
+        let mut constraints = Self {
             top_level,
             reserved: &options.reserved,
             exported_names,
             exported_symbols,
             keep_name_names,
             keep_name_symbols,
+            cached_names: options
+                .name_cache
+                .iter()
+                .flat_map(MangleNameCache::targets)
+                .map(CompactStr::as_str)
+                .collect(),
+            pinned: Vec::new(),
+            pinned_symbols: None,
+        };
+        if let (Some(name_cache), Some(scope_paths)) = (&options.name_cache, scope_paths)
+            && !name_cache.is_empty()
+        {
+            constraints.pin_cached_names(allocator, scoping, name_cache, scope_paths);
+        }
+        constraints
+    }
+
+    /// Pin candidates found in the name cache to their cached names.
+    ///
+    /// A cached name is only usable if nothing else can claim it: no keyword, no global the
+    /// program references, no binding that keeps its original name (including bindings in
+    /// direct-`eval` scopes), and no earlier binding pinned to the same name in the same scope,
+    /// an enclosing scope or an enclosed scope. Bindings in unrelated scopes may share a name.
+    fn pin_cached_names(
+        &mut self,
+        allocator: &'a Allocator,
+        scoping: &'s Scoping,
+        name_cache: &'s MangleNameCache,
+        scope_paths: &ScopePaths,
+    ) {
+        let root_unresolved_references = scoping.root_unresolved_references();
+        let eval_binding_names = scoping
+            .iter_bindings()
+            .filter(|(scope_id, _)| scoping.scope_flags(*scope_id).contains_direct_eval())
+            .flat_map(|(_, bindings)| bindings.keys().map(Ident::as_str))
+            .collect::<FxHashSet<_>>();
+
+        // Symbol IDs are in declaration order, so conflicting pins are resolved in declaration
+        // order.
+        let (candidates, kept): (Vec<_>, Vec<_>) = scoping
+            .symbol_ids()
+            .partition(|&symbol_id| self.is_mangle_candidate(symbol_id, scoping));
+        let kept_names = kept
+            .into_iter()
+            .map(|symbol_id| scoping.symbol_name(symbol_id))
+            .collect::<FxHashSet<_>>();
+
+        let mut pinned_symbols = BitSet::new_in(scoping.symbols_len(), allocator);
+        // Scopes of the bindings pinned to each name.
+        let mut pinned_names = FxHashMap::<&str, Vec<ScopeId>>::default();
+        for symbol_id in candidates {
+            let scope_id = scoping.symbol_scope_id(symbol_id);
+            let Some(name) =
+                name_cache.get(scope_paths.get(scope_id), scoping.symbol_name(symbol_id))
+            else {
+                continue;
+            };
+            let is_available = !oxc_syntax::keyword::is_reserved_keyword(name)
+                && !self.is_kept_name(name)
+                && !root_unresolved_references.contains_key(name.as_str())
+                && !kept_names.contains(name.as_str())
+                && !self.keep_name_names.contains(name.as_str())
+                && !eval_binding_names.contains(name.as_str());
+            if !is_available {
+                continue;
+            }
+            let pinned_scopes = pinned_names.entry(name.as_str()).or_default();
+            let is_shadowed = pinned_scopes.iter().any(|&other| {
+                other == scope_id
+                    || scoping.scope_is_descendant_of(scope_id, other)
+                    || scoping.scope_is_descendant_of(other, scope_id)
+            });
+            if !is_shadowed {
+                pinned_scopes.push(scope_id);
+                pinned_symbols.set_bit(symbol_id.index());
+                self.pinned.push((symbol_id, name.clone()));
+            }
         }
+        self.pinned_symbols = Some(pinned_symbols);
     }
 
     /// Whether a binding with this name must keep it.
@@ -568,7 +702,8 @@ impl<'a, 's> Constraints<'a, 's> {
                 || self.exported_symbols.as_ref().is_some_and(|e| e.has_bit(symbol_id.index())))
             || scoping.scope_flags(scope_id).contains_direct_eval()
             || self.is_kept_name(scoping.symbol_name(symbol_id))
-            || self.keep_name_symbols.as_ref().is_some_and(|keep| keep.has_bit(symbol_id.index())))
+            || self.keep_name_symbols.as_ref().is_some_and(|keep| keep.has_bit(symbol_id.index()))
+            || self.pinned_symbols.as_ref().is_some_and(|pinned| pinned.has_bit(symbol_id.index())))
     }
 }
 
@@ -795,6 +930,7 @@ impl<'a, const CAPACITY: usize> NameTable<'a, CAPACITY> {
                 // TODO: only skip the names that are kept in the current scope
                 || constraints.keep_name_names.contains(name)
                 || slots.eval_reserved_names.contains(name)
+                || constraints.cached_names.contains(name)
         };
 
         let count = ranking.frequencies.len();
@@ -858,6 +994,22 @@ fn sort_batch_by_slot(symbols: &mut [&SlotFrequency<'_>]) {
     symbols.sort_unstable_by_key(|a| a.slot);
 }
 
+/// Symbols that will be renamed, paired with their original names.
+fn renamed_symbols(
+    scoping: &Scoping,
+    constraints: &Constraints,
+    slots: &SlotAssignment,
+) -> Vec<(SymbolId, CompactStr)> {
+    scoping
+        .symbol_ids()
+        .filter(|symbol_id| {
+            slots.slots[symbol_id.index()] != SLOT_UNASSIGNED
+                || constraints.pinned_symbols.as_ref().is_some_and(|p| p.has_bit(symbol_id.index()))
+        })
+        .map(|symbol_id| (symbol_id, CompactStr::new(scoping.symbol_name(symbol_id))))
+        .collect()
+}
+
 fn collect_exported_symbols<'a>(
     program: &Program<'a>,
     allocator: &'a Allocator,
@@ -0,0 +1,232 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+use oxc_ast::AstKind;
+use oxc_semantic::{AstNodes, NodeId, ScopeId, Scoping};
+use oxc_str::CompactStr;
+use oxc_syntax::{identifier::is_identifier_name, keyword::is_reserved_keyword};
+use rustc_hash::FxHashMap;
+
+/// Cache entries: scope path -> original binding name -> mangled name.
+type Entries = BTreeMap<CompactStr, BTreeMap<CompactStr, CompactStr>>;
+
+/// A persistent mangled-name cache, similar to terser's `nameCache`.
+///
+/// Maps the original name of a binding to the name it was mangled to, keyed by the path of the
+/// scope which declares it. Pass the cache from a previous build in [`MangleOptions::name_cache`]
+/// and the mangler reuses each cached name whose binding still exists in the same scope, so
+/// unchanged bindings keep their mangled names across builds. The updated cache is returned in
+/// [`ManglerReturn::name_cache`].
+///
+/// A cached name is ignored (and the binding renamed as usual) when it would clash with a
+/// keyword, a global the program references, a name that must be kept, or an earlier binding
+/// pinned to the same name in an enclosing or enclosed scope. Generated names never collide with
+/// a cached name.
+///
+/// ## Scope paths
+///
+/// The root scope's path is [`MangleNameCache::ROOT_SCOPE`]. Other scopes append a `/`
+/// separated segment to their parent's path (no `/` before the first segment):
+///
+/// * The name of the function or class which the scope belongs to, or of the variable,
+///   method or property it is assigned to, e.g. `foo` for `function foo() {}` and
+///   `const foo = () => {}`, and `C/method` for `class C { method() {} }`.
+/// * `#n` for an anonymous scope (e.g. a block), where `n` counts earlier anonymous
+///   siblings. A named scope with the same name as an earlier sibling is `name#n`.
+///
+/// With the `serialize` feature, the cache serializes to a map of scope paths to maps of names,
+/// e.g. `{ "": { "foo": "e" }, "foo": { "x": "t" } }`.
+///
+/// [`MangleOptions::name_cache`]: crate::MangleOptions::name_cache
+/// [`ManglerReturn::name_cache`]: crate::ManglerReturn::name_cache
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(Serialize, Deserialize),
+    serde(try_from = "Entries", into = "Entries")
+)]
+pub struct MangleNameCache(Entries);
+
+impl MangleNameCache {
+    /// Path of the root scope.
+    pub const ROOT_SCOPE: &'static str = "";
+
+    /// Add a cache entry after validating that `target` can be used as a binding name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `target` is not an `IdentifierName`, is a reserved keyword, or is
+    /// `arguments` / `eval`.
+    pub fn insert(
+        &mut self,
+        scope_path: CompactStr,
+        original: CompactStr,
+        target: CompactStr,
+    ) -> Result<(), InvalidMangleNameCacheTarget> {
+        if !is_valid_name_cache_target(&target) {
+            return Err(InvalidMangleNameCacheTarget { scope_path, original, target });
+        }
+        self.insert_generated(scope_path, original, target);
+        Ok(())
+    }
+
+    /// Return the cached mangled name for a binding declared in the scope at `scope_path`.
+    pub fn get(&self, scope_path: &str, original: &str) -> Option<&CompactStr> {
+        self.0.get(scope_path)?.get(original)
+    }
+
+    /// Iterate over scope paths, original binding names and their mangled names, sorted by scope
+    /// path and then by original name.
+    pub fn iter(&self) -> impl Iterator<Item = (&CompactStr, &CompactStr, &CompactStr)> {
+        self.0.iter().flat_map(|(scope_path, names)| {
+            names.iter().map(move |(original, target)| (scope_path, original, target))
+        })
+    }
+
+    /// Iterate over the mangled names in the cache.
+    pub fn targets(&self) -> impl Iterator<Item = &CompactStr> {
+        self.0.values().flat_map(BTreeMap::values)
+    }
+
+    /// Return the number of cached bindings.
+    pub fn len(&self) -> usize {
+        self.0.values().map(BTreeMap::len).sum()
+    }
+
+    /// Return whether the cache contains no mappings.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub(crate) fn insert_generated(
+        &mut self,
+        scope_path: CompactStr,
+        original: CompactStr,
+        target: CompactStr,
+    ) {
+        self.0.entry(scope_path).or_default().insert(original, target);
+    }
+}
+
+impl TryFrom<Entries> for MangleNameCache {
+    type Error = InvalidMangleNameCacheTarget;
+
+    fn try_from(entries: Entries) -> Result<Self, Self::Error> {
+        let mut cache = Self::default();
+        for (scope_path, names) in entries {
+            for (original, target) in names {
+                cache.insert(scope_path.clone(), original, target)?;
+            }
+        }
+        Ok(cache)
+    }
+}
+
+impl From<MangleNameCache> for Entries {
+    fn from(cache: MangleNameCache) -> Self {
+        cache.0
+    }
+}
+
+/// Path of each scope, which identifies it across builds. See [`MangleNameCache`].
+pub struct ScopePaths(Vec<CompactStr>);
+
+impl ScopePaths {
+    pub fn new(scoping: &Scoping, ast_nodes: &AstNodes) -> Self {
+        let mut paths = Vec::<CompactStr>::with_capacity(scoping.scopes_len());
+        // Number of earlier siblings with each name, keyed by parent scope.
+        let mut counts = FxHashMap::<(ScopeId, Cow<str>), u32>::default();
+        // Scope IDs are assigned in source order, so parents come before their children, and
+        // siblings are visited in source order.
+        for scope_id in scoping.scope_descendants_from_root() {
+            let Some(parent_id) = scoping.scope_parent_id(scope_id) else {
+                paths.push(CompactStr::new(MangleNameCache::ROOT_SCOPE));
+                continue;
+            };
+            let name = scope_name(ast_nodes, scoping.get_node_id(scope_id));
+            let count = counts.entry((parent_id, name.clone().unwrap_or_default())).or_default();
+            let segment = match name {
+                Some(name) if *count == 0 => name.into_owned(),
+                name => format!("{}#{count}", name.unwrap_or_default()),
+            };
+            *count += 1;
+            let parent_path = &paths[parent_id.index()];
+            let path = if parent_path.is_empty() {
+                CompactStr::from(segment)
+            } else {
+                CompactStr::from(format!("{parent_path}/{segment}"))
+            };
+            paths.push(path);
+        }
+        Self(paths)
+    }
+
+    pub fn get(&self, scope_id: ScopeId) -> &CompactStr {
+        &self.0[scope_id.index()]
+    }
+}
+
+/// Name of the function or class a scope belongs to, or of what it is assigned to.
+fn scope_name<'a>(ast_nodes: &AstNodes<'a>, node_id: NodeId) -> Option<Cow<'a, str>> {
+    let assigned_name = || match ast_nodes.parent_kind(node_id) {
+        AstKind::VariableDeclarator(decl) => {
+            decl.id.get_binding_identifier().map(|ident| Cow::Borrowed(ident.name.as_str()))
+        }
+        AstKind::MethodDefinition(method) => method.key.static_name(),
+        AstKind::PropertyDefinition(property) => property.key.static_name(),
+        AstKind::ObjectProperty(property) => property.key.static_name(),
+        _ => None,
+    };
+    match ast_nodes.kind(node_id) {
+        AstKind::Function(func) => {
+            func.id.as_ref().map(|ident| Cow::Borrowed(ident.name.as_str())).or_else(assigned_name)
+        }
+        AstKind::Class(class) => {
+            class.id.as_ref().map(|ident| Cow::Borrowed(ident.name.as_str())).or_else(assigned_name)
+        }
+        AstKind::ArrowFunctionExpression(_) => assigned_name(),
+        _ => None,
+    }
+}
+
+/// An invalid mangled-name cache target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidMangleNameCacheTarget {
+    pub scope_path: CompactStr,
+    pub original: CompactStr,
+    pub target: CompactStr,
+}
+
+impl std::fmt::Display for InvalidMangleNameCacheTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid name cache target for '{}' in scope '{}': '{}' must be an identifier other than a reserved keyword, 'arguments', or 'eval'",
+            self.original, self.scope_path, self.target
+        )
+    }
+}
+
+impl std::error::Error for InvalidMangleNameCacheTarget {}
+
+fn is_valid_name_cache_target(name: &str) -> bool {
+    is_identifier_name(name) && !is_reserved_keyword(name) && !matches!(name, "arguments" | "eval")
+}
+
+#[cfg(all(test, feature = "serialize"))]
+mod test {
+    use super::MangleNameCache;
+
+    #[test]
+    fn serializes_by_scope_path() {
+        let mut cache = MangleNameCache::default();
+        cache.insert("".into(), "foo".into(), "e".into()).unwrap();
+        cache.insert("foo".into(), "x".into(), "t".into()).unwrap();
+        let json = serde_json::to_string(&cache).unwrap();
+        assert_eq!(json, r#"{"":{"foo":"e"},"foo":{"x":"t"}}"#);
+        assert_eq!(serde_json::from_str::<MangleNameCache>(&json).unwrap(), cache);
+        assert!(serde_json::from_str::<MangleNameCache>(r#"{"":{"foo":"class"}}"#).is_err());
+    }
+}
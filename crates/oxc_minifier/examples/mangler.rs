@@ -54,6 +54,7 @@ fn main() -> std::io::Result<()> {
             FxHashSet::default()
         },
         debug,
+        ..MangleOptions::default()
     };
     let printed = mangler(&source_text, source_type, options.clone());
     println!("{printed}");
@@ -71,7 +71,9 @@ use rustc_hash::FxHashMap;
 
 use crate::state::CompressionMode;
 
-pub use oxc_mangler::{MangleOptions, MangleOptionsKeepNames};
+pub use oxc_mangler::{
+    InvalidMangleNameCacheTarget, MangleNameCache, MangleOptions, MangleOptionsKeepNames,
+};
 pub use property_mangler::{
     InvalidManglePropertyCacheTarget, ManglePropertiesOptions, ManglePropertyCache,
     PropertyMangleCollection, PropertyMangler, PropertyMapping,
@@ -116,6 +118,9 @@ pub struct MinifierReturn {
     /// Updated property-name cache when property mangling ran.
     pub property_mangle_cache: Option<ManglePropertyCache>,
 
+    /// Updated name cache when mangling ran with [`MangleOptions::name_cache`] set.
+    pub mangle_name_cache: Option<MangleNameCache>,
+
     /// Total number of iterations ran. Useful for debugging performance issues.
     pub iterations: u8,
 }
@@ -175,7 +180,7 @@ impl<'a> Minifier {
                 (Some(stats), iterations)
             })
             .unwrap_or_default();
-        let (scoping, class_private_mappings) = mangle
+        let (scoping, class_private_mappings, mangle_name_cache) = mangle
             .map(|options| {
                 let mut builder =
                     SemanticBuilder::new().with_build_nodes(true).with_class_table(true);
@@ -183,12 +188,20 @@ impl<'a> Minifier {
                     builder = builder.with_stats(stats);
                 }
                 let mut semantic = builder.build(program).semantic;
-                let class_private_mappings = Mangler::default()
+                let (class_private_mappings, name_cache) = Mangler::default()
                     .with_options(options)
                     .build_with_semantic(&mut semantic, program);
-                (semantic.into_scoping(), class_private_mappings)
+                (semantic.into_scoping(), class_private_mappings, name_cache)
             })
-            .map_or((None, None), |(scoping, mappings)| (Some(scoping), Some(mappings)));
-        MinifierReturn { scoping, class_private_mappings, property_mangle_cache, iterations }
+            .map_or((None, None, None), |(scoping, mappings, name_cache)| {
+                (Some(scoping), Some(mappings), name_cache)
+            });
+        MinifierReturn {
+            scoping,
+            class_private_mappings,
+            property_mangle_cache,
+            mangle_name_cache,
+            iterations,
+        }
     }
 }
@@ -35,6 +35,16 @@ pub struct CompressOptions {
     /// Default `true`
     pub sequences: bool,
 
+    /// Inline calls to small functions whose body is a single `return` statement.
+    ///
+    /// `function f(x) { return g(x) } f(1)` -> `g(1)`
+    ///
+    /// Applies to function declarations called once or which only forward to another call,
+    /// and to immediately invoked function expressions.
+    ///
+    /// Default `false`
+    pub inline_functions: bool,
+
     /// Drop unreferenced functions and variables.
     pub unused: CompressOptionsUnused,
 
@@ -71,6 +81,7 @@ impl CompressOptions {
             drop_console: false,
             join_vars: true,
             sequences: true,
+            inline_functions: false,
             unused: CompressOptionsUnused::Remove,
             treeshake: TreeShakeOptions::default(),
             drop_labels: FxHashSet::default(),
@@ -86,6 +97,7 @@ impl CompressOptions {
             drop_console: false,
             join_vars: true,
             sequences: true,
+            inline_functions: false,
             unused: CompressOptionsUnused::Keep,
             treeshake: TreeShakeOptions::default(),
             drop_labels: FxHashSet::default(),
@@ -101,6 +113,7 @@ impl CompressOptions {
             drop_console: false,
             join_vars: false,
             sequences: false,
+            inline_functions: false,
             unused: CompressOptionsUnused::Remove,
             treeshake: TreeShakeOptions::default(),
             drop_labels: FxHashSet::default(),
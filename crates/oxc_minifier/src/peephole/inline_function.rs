@@ -0,0 +1,408 @@
+use oxc_allocator::{ArenaVec, CloneIn, GetAllocator, TakeIn};
+use oxc_ast::ast::*;
+use oxc_ast_visit::{VisitJs, VisitJsMut, walk_js, walk_js_mut};
+use oxc_semantic::{ReferenceFlags, Scoping};
+use oxc_str::Ident;
+use oxc_syntax::symbol::SymbolId;
+
+use crate::{
+    TraverseCtx,
+    generated::ancestor::Ancestor,
+    symbol_value::{FreshValueKind, InlineFunction},
+};
+
+use super::PeepholeOptimizations;
+
+/// Name and resolved symbol of each identifier in an inlineable body.
+type BodyReferences<'a> = Vec<(Ident<'a>, Option<SymbolId>)>;
+
+/// Call-site function inlining.
+///
+/// A call to a function whose body is a single `return <expression>` is
+/// replaced with that expression, with the arguments substituted for the
+/// parameters:
+///
+/// ```js
+/// function add(a, b) { return a + b }
+/// add(x, 1)          // -> x + 1
+/// ((a) => a.b)(x)    // -> x.b
+/// ```
+///
+/// Function declarations are inlined where they are called once, or at every
+/// call when the body only forwards to another call (`function f(x) { return
+/// g(x) }`). Once all calls are inlined, the declaration is removed as unused.
+///
+/// The rewrite must not be observable, so:
+/// - neither the call nor the function is annotated as pure, since a pure
+///   call can be removed whole;
+/// - the function is not async or a generator, has only plain identifier
+///   parameters and no directives, is never reassigned, and is declared
+///   directly in a function body or the program;
+/// - the body contains no `this`, `super`, `arguments`, `new.target`,
+///   `import.meta`, private names, direct `eval`, nested functions or classes,
+///   tagged templates, JSX or assignments to identifiers;
+/// - each parameter is read at most once, so no argument is duplicated;
+/// - every argument is a literal or a never-reassigned binding, so moving its
+///   evaluation into the body cannot change its value or drop a side effect;
+/// - every other identifier in the body resolves to the same binding at the
+///   call site, and the call site has the same strictness as the function.
+///
+/// Limitation: a declaration is recorded when it is exited, so calls which
+/// precede the declaration in source order are not inlined.
+impl<'a> PeepholeOptimizations {
+    /// Record the body of a function declaration if calls to it can be inlined.
+    pub fn init_inline_function_value(func: &Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !ctx.options().inline_functions {
+            return;
+        }
+        // Sloppy-mode block-level functions are also bound in the enclosing
+        // function scope (Annex B), so a call may not see this declaration.
+        if !matches!(ctx.parent(), Ancestor::ProgramBody(_) | Ancestor::FunctionBodyStatements(_)) {
+            return;
+        }
+        let Some(symbol_id) = func.id.as_ref().and_then(|id| id.symbol_id.get()) else { return };
+        if ctx
+            .scoping()
+            .scope_flags(ctx.scoping().symbol_scope_id(symbol_id))
+            .contains_direct_eval()
+        {
+            return;
+        }
+        // Set by `init_function_declaration_symbol_value`. Redeclared functions have no
+        // fresh value kind.
+        if !ctx.state.symbols.value(symbol_id).is_some_and(|value| {
+            value.kind == FreshValueKind::Function && !value.references.has_writes()
+        }) {
+            return;
+        }
+        let Some(params) = Self::inline_function_params(&func.params) else { return };
+        let Some(body) = Self::function_return_expression(func) else { return };
+        let Some(references) = Self::check_inline_body(body, &params, Some(symbol_id), ctx) else {
+            return;
+        };
+        let function = InlineFunction {
+            params,
+            body: body.clone_in(ctx.allocator()),
+            references,
+            strict: ctx.scoping().scope_flags(func.scope_id()).is_strict_mode(),
+            trivial: Self::is_trivial_inline_body(body),
+        };
+        ctx.state.symbols.set_inline_function(symbol_id, function);
+    }
+
+    /// Replace a call to an inlineable function declaration or an immediately
+    /// invoked function expression with the function's returned expression.
+    pub fn inline_function_call(expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !ctx.options().inline_functions {
+            return;
+        }
+        let Expression::CallExpression(call) = expr else { return };
+        // Pure calls can be dropped whole, which inlining would prevent.
+        if call.pure || call.optional || call.arguments.iter().any(Argument::is_spread) {
+            return;
+        }
+        let new_expr = match &call.callee {
+            Expression::Identifier(ident) => {
+                let Some(symbol_id) = ctx.scoping().get_reference(ident.reference_id()).symbol_id()
+                else {
+                    return;
+                };
+                let Some(value) = ctx.state.symbols.value(symbol_id) else { return };
+                let Some(function) = &value.inline_function else { return };
+                if !(function.trivial || value.references.has_single_read())
+                    || function.strict
+                        != ctx.scoping().scope_flags(ctx.current_scope_id()).is_strict_mode()
+                {
+                    return;
+                }
+                let InlineFunction { params, body, references, .. } = function;
+                if !Self::can_inline_call(params, references, &call.arguments, ctx) {
+                    return;
+                }
+                let (params, references) = (params.clone(), references.clone());
+                let body = body.clone_in(ctx.allocator());
+                Self::instantiate_inline_body(body, &params, &references, &mut call.arguments, ctx)
+            }
+            Expression::FunctionExpression(func) => {
+                let Some(params) = Self::inline_function_params(&func.params) else { return };
+                let Some(body) = Self::function_return_expression(func) else { return };
+                let Some(references) = Self::check_inline_body(body, &params, None, ctx) else {
+                    return;
+                };
+                if !Self::can_inline_call(&params, &references, &call.arguments, ctx) {
+                    return;
+                }
+                let body = body.clone_in(ctx.allocator());
+                Self::instantiate_inline_body(body, &params, &references, &mut call.arguments, ctx)
+            }
+            Expression::ArrowFunctionExpression(arrow) => {
+                if arrow.r#async || arrow.pure {
+                    return;
+                }
+                let Some(params) = Self::inline_function_params(&arrow.params) else { return };
+                let Some(body) = Self::arrow_return_expression(arrow) else { return };
+                let Some(references) = Self::check_inline_body(body, &params, None, ctx) else {
+                    return;
+                };
+                if !Self::can_inline_call(&params, &references, &call.arguments, ctx) {
+                    return;
+                }
+                let body = body.clone_in(ctx.allocator());
+                Self::instantiate_inline_body(body, &params, &references, &mut call.arguments, ctx)
+            }
+            _ => return,
+        };
+        ctx.replace_expression(expr, new_expr);
+    }
+
+    /// Parameter symbols, if every parameter is a plain identifier without a default.
+    fn inline_function_params(params: &FormalParameters<'a>) -> Option<Vec<SymbolId>> {
+        if params.rest.is_some() {
+            return None;
+        }
+        params
+            .items
+            .iter()
+            .map(|param| match &param.pattern {
+                BindingPattern::BindingIdentifier(ident) if param.initializer.is_none() => {
+                    ident.symbol_id.get()
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// `<expression>` of `function () { return <expression> }`.
+    fn function_return_expression<'e>(func: &'e Function<'a>) -> Option<&'e Expression<'a>> {
+        if func.r#async || func.generator || func.pure {
+            return None;
+        }
+        Self::body_return_expression(func.body.as_ref()?)
+    }
+
+    /// `<expression>` of `() => <expression>` or `() => { return <expression> }`.
+    fn arrow_return_expression<'e>(
+        arrow: &'e ArrowFunctionExpression<'a>,
+    ) -> Option<&'e Expression<'a>> {
+        arrow.get_expression().or_else(|| Self::body_return_expression(arrow.get_function_body()?))
+    }
+
+    fn body_return_expression<'e>(body: &'e FunctionBody<'a>) -> Option<&'e Expression<'a>> {
+        if !body.directives.is_empty() {
+            return None;
+        }
+        match body.statements.as_slice() {
+            [Statement::ReturnStatement(ret)] => ret.argument.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Check that `body` can be evaluated in place of a call, and collect what
+    /// each of its identifiers resolves to.
+    fn check_inline_body(
+        body: &Expression<'a>,
+        params: &[SymbolId],
+        function: Option<SymbolId>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<BodyReferences<'a>> {
+        let mut checker = InlineBodyChecker {
+            scoping: ctx.scoping(),
+            params,
+            param_reads: vec![0; params.len()],
+            function,
+            references: vec![],
+            inlineable: true,
+        };
+        checker.visit_expression(body);
+        checker.inlineable.then_some(checker.references)
+    }
+
+    /// Bodies which are no larger than a call to the function, so inlining them
+    /// at every call site does not grow the output.
+    fn is_trivial_inline_body(body: &Expression<'a>) -> bool {
+        fn is_simple_operand(expr: &Expression<'_>) -> bool {
+            match expr {
+                Expression::Identifier(_) => true,
+                Expression::StaticMemberExpression(member) => is_simple_operand(&member.object),
+                _ => expr.is_literal(),
+            }
+        }
+        match body {
+            Expression::CallExpression(call) => {
+                is_simple_operand(&call.callee)
+                    && call
+                        .arguments
+                        .iter()
+                        .all(|arg| arg.as_expression().is_some_and(is_simple_operand))
+            }
+            _ => is_simple_operand(body),
+        }
+    }
+
+    /// Whether `arguments` can be moved into the function body, and every name
+    /// in the body resolves to the same binding at the call site.
+    fn can_inline_call(
+        params: &[SymbolId],
+        references: &BodyReferences<'a>,
+        arguments: &ArenaVec<'a, Argument<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> bool {
+        let args_inlineable = arguments.iter().enumerate().all(|(index, arg)| {
+            arg.as_expression().is_some_and(|arg| {
+                // Arguments without a parameter are dropped, so must have no side effects.
+                (index < params.len() || arg.is_literal()) && Self::is_inlineable_argument(arg, ctx)
+            })
+        });
+        let scope_id = ctx.current_scope_id();
+        args_inlineable
+            && references.iter().all(|(name, symbol_id)| {
+                symbol_id.is_some_and(|symbol_id| params.contains(&symbol_id))
+                    || ctx.scoping().find_binding(scope_id, *name) == *symbol_id
+            })
+    }
+
+    /// Substitute `arguments` into `body`, a copy of a checked function body.
+    fn instantiate_inline_body(
+        mut body: Expression<'a>,
+        params: &[SymbolId],
+        references: &BodyReferences<'a>,
+        arguments: &mut ArenaVec<'a, Argument<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut values = arguments
+            .iter_mut()
+            .take(params.len())
+            .map(|arg| Some(arg.to_expression_mut().take_in(ctx)))
+            .collect::<Vec<_>>();
+        values.resize_with(params.len(), || None);
+        InlineBodyInstantiator { ctx, params, values, references: references.iter() }
+            .visit_expression(&mut body);
+        body
+    }
+
+    /// Literals and bindings which are never reassigned have the same value
+    /// wherever they are evaluated.
+    fn is_inlineable_argument(arg: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        if arg.is_literal() || ctx.is_expression_undefined(arg) {
+            return true;
+        }
+        match arg {
+            Expression::Identifier(ident) => ctx
+                .scoping()
+                .get_reference(ident.reference_id())
+                .symbol_id()
+                .is_some_and(|symbol_id| !ctx.scoping().symbol_is_mutated(symbol_id)),
+            _ => false,
+        }
+    }
+}
+
+/// Checks a function body for constructs which depend on being evaluated
+/// inside the function. See [`PeepholeOptimizations::inline_function_call`].
+struct InlineBodyChecker<'a, 's> {
+    scoping: &'s Scoping,
+    params: &'s [SymbolId],
+    param_reads: Vec<u32>,
+    /// The function being inlined, which must not call itself.
+    function: Option<SymbolId>,
+    references: BodyReferences<'a>,
+    inlineable: bool,
+}
+
+impl<'a> VisitJs<'a> for InlineBodyChecker<'a, '_> {
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        if !self.inlineable {
+            return;
+        }
+        match expr {
+            Expression::Identifier(ident) => {
+                let reference = self.scoping.get_reference(ident.reference_id());
+                let symbol_id = reference.symbol_id();
+                if ident.name == "arguments"
+                    || reference.is_write()
+                    || (symbol_id.is_some() && symbol_id == self.function)
+                {
+                    self.inlineable = false;
+                    return;
+                }
+                if let Some(index) =
+                    symbol_id.and_then(|symbol_id| self.params.iter().position(|&p| p == symbol_id))
+                {
+                    self.param_reads[index] += 1;
+                    if self.param_reads[index] > 1 {
+                        self.inlineable = false;
+                        return;
+                    }
+                }
+                self.references.push((ident.name, symbol_id));
+            }
+            Expression::ThisExpression(_)
+            | Expression::Super(_)
+            | Expression::NewTarget(_)
+            | Expression::ImportMeta(_)
+            | Expression::FunctionExpression(_)
+            | Expression::ArrowFunctionExpression(_)
+            | Expression::ClassExpression(_)
+            | Expression::YieldExpression(_)
+            | Expression::AwaitExpression(_)
+            | Expression::TaggedTemplateExpression(_)
+            | Expression::JSXElement(_)
+            | Expression::JSXFragment(_)
+            | Expression::V8IntrinsicExpression(_) => self.inlineable = false,
+            Expression::CallExpression(call) if call.callee.is_specific_id("eval") => {
+                self.inlineable = false;
+            }
+            _ => walk_js::walk_expression(self, expr),
+        }
+    }
+
+    /// Identifiers outside expression position are assignment targets.
+    fn visit_identifier_reference(&mut self, _ident: &IdentifierReference<'a>) {
+        self.inlineable = false;
+    }
+
+    fn visit_private_identifier(&mut self, _ident: &PrivateIdentifier<'a>) {
+        self.inlineable = false;
+    }
+}
+
+/// Substitutes arguments for parameters in a copied function body, and gives
+/// every other identifier a fresh reference.
+struct InlineBodyInstantiator<'a, 'c, 'r> {
+    ctx: &'c mut TraverseCtx<'a>,
+    params: &'c [SymbolId],
+    /// Argument for each parameter, `None` once substituted or if not passed.
+    values: Vec<Option<Expression<'a>>>,
+    references: std::slice::Iter<'r, (Ident<'a>, Option<SymbolId>)>,
+}
+
+impl<'a> VisitJsMut<'a> for InlineBodyInstantiator<'a, '_, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        let Expression::Identifier(ident) = expr else {
+            walk_js_mut::walk_expression(self, expr);
+            return;
+        };
+        let Some(&(_, symbol_id)) = self.references.next() else {
+            unreachable!("references are collected from the same body");
+        };
+        if let Some(index) =
+            symbol_id.and_then(|symbol_id| self.params.iter().position(|&p| p == symbol_id))
+        {
+            let span = ident.span;
+            *expr = self.values[index]
+                .take()
+                .unwrap_or_else(|| Expression::new_void_0(span, &*self.ctx));
+        } else {
+            let reference_id =
+                self.ctx.create_reference(ident.name, symbol_id, ReferenceFlags::Read);
+            ident.reference_id.set(Some(reference_id));
+        }
+    }
+
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        walk_js_mut::walk_object_property(self, prop);
+        // `{ a }` with `a` substituted is no longer shorthand.
+        prop.shorthand = prop.shorthand
+            && matches!(&prop.value, Expression::Identifier(ident) if prop.key.is_specific_static_name(&ident.name));
+    }
+}
@@ -1,6 +1,7 @@
 mod convert_to_dotted_properties;
 mod fold_constants;
 mod inline;
+mod inline_function;
 mod minimize_conditional_expression;
 mod minimize_conditions;
 mod minimize_expression_in_boolean_context;
@@ -453,6 +454,7 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
                 Statement::LabeledStatement(_) => Self::try_fold_labeled(stmt, ctx),
                 Statement::FunctionDeclaration(f) => {
                     Self::init_function_declaration_symbol_value(f.id.as_ref(), ctx);
+                    Self::init_inline_function_value(f, ctx);
                     Self::remove_unused_function_declaration(stmt, ctx);
                 }
                 Statement::ClassDeclaration(c) => {
@@ -605,6 +607,7 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
                 Expression::CallExpression(_) => {
                     Self::fold_call_expression(expr, ctx);
                     Self::substitute_iife_call(expr, ctx);
+                    Self::inline_function_call(expr, ctx);
                     Self::remove_dead_code_call_expression(expr, ctx);
                     Self::replace_concat_chain(expr, ctx);
                     Self::replace_known_global_methods(expr, ctx);
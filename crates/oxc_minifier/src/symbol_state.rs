@@ -12,7 +12,7 @@ use crate::{
     CompressOptions,
     symbol_liveness::SymbolLiveness,
     symbol_metadata::{FunctionSummary, MemberWriteEffect, PersistentSymbolMetadata},
-    symbol_value::SymbolValue,
+    symbol_value::{InlineFunction, SymbolValue},
 };
 
 /// Symbol-indexed data owned by the minifier.
@@ -66,6 +66,13 @@ impl<'a> SymbolState<'a> {
         self.values.get(symbol_id)?.as_ref()
     }
 
+    /// Attach an inlineable body to a symbol whose value is already initialized.
+    pub fn set_inline_function(&mut self, symbol_id: SymbolId, function: InlineFunction<'a>) {
+        if let Some(value) = &mut self.values[symbol_id] {
+            value.inline_function = Some(function);
+        }
+    }
+
     pub fn set_function_summary(&mut self, symbol_id: SymbolId, summary: FunctionSummary) {
         self.persistent.entry(symbol_id).or_default().set_function_summary(summary);
     }
@@ -1,5 +1,7 @@
+use oxc_ast::ast::Expression;
 use oxc_ecmascript::constant_evaluation::ConstantValue;
-use oxc_syntax::reference::ReferenceFlags;
+use oxc_str::Ident;
+use oxc_syntax::{reference::ReferenceFlags, symbol::SymbolId};
 
 /// The kind of fresh value a binding was initialized with, or `None` when the
 /// value may alias another binding (or is untracked).
@@ -110,6 +112,32 @@ pub struct SymbolValue<'a> {
     /// indistinguishable inside `if (x)` / `x ? …` / `!x`, so such reads fold to
     /// `false` there. See `minimize_expression_in_boolean_context` / #14001.
     pub boolean_falsy: bool,
+
+    /// The returned expression of a function declaration which calls to it can
+    /// be replaced with. See `PeepholeOptimizations::inline_function_call`.
+    pub inline_function: Option<InlineFunction<'a>>,
+}
+
+/// A function whose body is a single `return <expression>`, recorded when its
+/// declaration is exited so later calls in the same pass can be inlined.
+#[derive(Debug)]
+pub struct InlineFunction<'a> {
+    /// Parameter symbols, in order.
+    pub params: Vec<SymbolId>,
+
+    /// A copy of the returned expression. Its identifiers carry no
+    /// `ReferenceId`s; fresh references are created for each inlined copy.
+    pub body: Expression<'a>,
+
+    /// Name and resolved symbol of each identifier in `body`, in visit order.
+    pub references: Vec<(Ident<'a>, Option<SymbolId>)>,
+
+    /// Whether the function body is strict mode code.
+    pub strict: bool,
+
+    /// Whether `body` is small enough to inline at every call site, rather
+    /// than only at the single call to a function called once.
+    pub trivial: bool,
 }
 
 impl SymbolValue<'_> {
@@ -369,6 +369,7 @@ impl<'a> TraverseCtx<'a, MinifierState<'a>> {
             references,
             kind: if has_multiple_value_declarations { FreshValueKind::None } else { kind },
             boolean_falsy,
+            inline_function: None,
         };
         self.state.symbols.init_value(symbol_id, symbol_value);
     }
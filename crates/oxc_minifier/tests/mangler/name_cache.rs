@@ -0,0 +1,146 @@
+use oxc_allocator::Allocator;
+use oxc_codegen::Codegen;
+use oxc_mangler::{MangleNameCache, MangleOptions, Mangler};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+fn mangle_with_cache(source_text: &str, name_cache: MangleNameCache) -> (String, MangleNameCache) {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    assert!(ret.diagnostics.is_empty(), "Parser errors: {:?}", ret.diagnostics);
+    let program = ret.program;
+    let options = MangleOptions { name_cache: Some(name_cache), ..MangleOptions::default() };
+    let mangler_return = Mangler::new().with_options(options).build(&program);
+    let code = Codegen::new().with_scoping(Some(mangler_return.scoping)).build(&program).code;
+    (code, mangler_return.name_cache.unwrap())
+}
+
+fn codegen(source_text: &str) -> String {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    assert!(ret.diagnostics.is_empty(), "Parser errors: {:?}", ret.diagnostics);
+    Codegen::new().build(&ret.program).code
+}
+
+fn name_cache(entries: &[(&str, &str, &str)]) -> MangleNameCache {
+    let mut cache = MangleNameCache::default();
+    for &(scope_path, original, target) in entries {
+        cache.insert(scope_path.into(), original.into(), target.into()).unwrap();
+    }
+    cache
+}
+
+fn entries(cache: &MangleNameCache) -> Vec<(&str, &str, &str)> {
+    cache.iter().map(|(scope, k, v)| (scope.as_str(), k.as_str(), v.as_str())).collect()
+}
+
+#[test]
+fn records_top_level_names() {
+    let (code, cache) = mangle_with_cache(
+        "const foo = 1; function bar(x) { return x + foo } export { bar }",
+        MangleNameCache::default(),
+    );
+    assert_eq!(code, codegen("const e = 1; function t(t) { return t + e } export { t as bar }"));
+    assert_eq!(entries(&cache), [("", "bar", "t"), ("", "foo", "e"), ("bar", "x", "t")]);
+}
+
+#[test]
+fn reuses_cached_names_across_builds() {
+    let (_, cache) = mangle_with_cache(
+        "const foo = 1; const bar = 2; export { foo, bar }",
+        MangleNameCache::default(),
+    );
+    assert_eq!(entries(&cache), [("", "bar", "t"), ("", "foo", "e")]);
+
+    // A new binding declared first would shift every slot; cached bindings keep their names
+    // and the new one takes a name no cached binding uses.
+    let (code, cache) = mangle_with_cache(
+        "const baz = 0; const foo = 1; const bar = 2; export { baz, foo, bar }",
+        cache,
+    );
+    assert_eq!(
+        code,
+        codegen("const n = 0; const e = 1; const t = 2; export { n as baz, e as foo, t as bar }")
+    );
+    assert_eq!(entries(&cache), [("", "bar", "t"), ("", "baz", "n"), ("", "foo", "e")]);
+
+    // Entries for bindings missing from this build are kept for later builds.
+    let (code, cache) = mangle_with_cache("const bar = 2; export { bar }", cache);
+    assert_eq!(code, codegen("const t = 2; export { t as bar }"));
+    assert_eq!(entries(&cache), [("", "bar", "t"), ("", "baz", "n"), ("", "foo", "e")]);
+}
+
+#[test]
+fn ignores_unusable_cached_names() {
+    // Clashes with a global referenced by the program.
+    let (code, cache) =
+        mangle_with_cache("const foo = 1; use(foo); export {}", name_cache(&[("", "foo", "use")]));
+    assert_eq!(code, codegen("const e = 1; use(e); export {}"));
+    assert_eq!(entries(&cache), [("", "foo", "e")]);
+
+    // Clashes with an exported name.
+    let (code, _) =
+        mangle_with_cache("const foo = 1; export const x = foo", name_cache(&[("", "foo", "x")]));
+    assert_eq!(code, codegen("const e = 1; export const x = e"));
+
+    // Two bindings pinned to the same name: the first one declared wins.
+    let (code, _) = mangle_with_cache(
+        "const foo = 1; const bar = 2; export { foo, bar }",
+        name_cache(&[("", "foo", "a"), ("", "bar", "a")]),
+    );
+    assert_eq!(code, codegen("const a = 1; const e = 2; export { a as foo, e as bar }"));
+
+    // Nested bindings never shadow a cached name.
+    let (code, _) = mangle_with_cache(
+        "const foo = 1; export function f(x) { return x + foo }",
+        name_cache(&[("", "foo", "e")]),
+    );
+    assert_eq!(code, codegen("const e = 1; export function f(t) { return t + e }"));
+}
+
+#[test]
+fn reuses_cached_names_in_nested_scopes() {
+    let source = "export function f(a, b) { return a + b } export const g = (a) => { { let c = a; use(c) } }";
+    let (code, cache) = mangle_with_cache(source, MangleNameCache::default());
+    assert_eq!(
+        code,
+        codegen(
+            "export function f(e, t) { return e + t } export const g = (e) => { { let t = e; use(t) } }"
+        )
+    );
+    assert_eq!(
+        entries(&cache),
+        [("f", "a", "e"), ("f", "b", "t"), ("g", "a", "e"), ("g/#0", "c", "t")]
+    );
+
+    // A new parameter in `f` keeps the names of the others, and `g` is unaffected.
+    let source = "export function f(z, a, b) { return z + a + b } export const g = (a) => { { let c = a; use(c) } }";
+    let (code, cache) = mangle_with_cache(source, cache);
+    assert_eq!(
+        code,
+        codegen(
+            "export function f(n, e, t) { return n + e + t } export const g = (e) => { { let t = e; use(t) } }"
+        )
+    );
+    assert_eq!(
+        entries(&cache),
+        [("f", "a", "e"), ("f", "b", "t"), ("f", "z", "n"), ("g", "a", "e"), ("g/#0", "c", "t")]
+    );
+
+    // A cached name is not used when it would shadow a binding pinned in an enclosing scope.
+    let (code, _) = mangle_with_cache(
+        "export function f(a) { return (b) => a + b }",
+        name_cache(&[("f", "a", "x"), ("f/#0", "b", "x")]),
+    );
+    assert_eq!(code, codegen("export function f(x) { return (e) => x + e }"));
+}
+
+#[test]
+fn rejects_invalid_targets() {
+    let mut cache = MangleNameCache::default();
+    for target in ["not-valid", "class", "arguments", "eval"] {
+        let error = cache.insert("".into(), "foo".into(), target.into()).unwrap_err();
+        assert_eq!(error.target, target);
+    }
+    assert!(cache.is_empty());
+}
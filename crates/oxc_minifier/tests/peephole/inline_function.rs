@@ -0,0 +1,136 @@
+use oxc_minifier::{CompressOptions, CompressOptionsUnused};
+use oxc_span::SourceType;
+
+use crate::{default_options, test_options, test_options_source_type};
+
+fn options() -> CompressOptions {
+    CompressOptions {
+        inline_functions: true,
+        unused: CompressOptionsUnused::Remove,
+        ..default_options()
+    }
+}
+
+#[track_caller]
+fn test(source_text: &str, expected: &str) {
+    test_options(source_text, expected, &options());
+}
+
+#[track_caller]
+fn test_same(source_text: &str) {
+    test(source_text, source_text);
+}
+
+#[test]
+fn disabled_by_default() {
+    let options = CompressOptions { unused: CompressOptionsUnused::Remove, ..default_options() };
+    test_options(
+        "function f(a) { return a + 1 } log(f(x))",
+        "function f(a) { return a + 1 } log(f(x))",
+        &options,
+    );
+}
+
+#[test]
+fn single_use_function() {
+    test(
+        "function f(a, b) { return a + b } export function g(x) { return f(x, 1) }",
+        "export function g(x) { return x + 1 }",
+    );
+    test(
+        "function f(a) { return a.b.c } export function g(x) { return f(x) }",
+        "export function g(x) { return x.b.c }",
+    );
+    test("function f() { return g() + h() } log(f())", "log(g() + h())");
+    test("function f(a) { return { a } } log(f(1))", "log({ a: 1 })");
+    test(
+        "function f(a) { return { a } } export function g(x) { return f(x) }",
+        "export function g(x) { return { a: x } }",
+    );
+    // A missing argument is `undefined`, an unused literal argument is dropped.
+    test("function f(a, b) { return [a, b] } log(f(1))", "log([1, void 0])");
+    test("function f(a) { return g() } log(f(1, 2))", "log(g())");
+}
+
+#[test]
+fn trivial_wrapper() {
+    test(
+        "function f(a) { return g(a) } export function h(x) { return [f(1), f(x)] }",
+        "export function h(x) { return [g(1), g(x)] }",
+    );
+    test(
+        "function f(a, b) { return a.b(b, 1) } export function h(x, y) { return [f(x, 2), f(y, 3)] }",
+        "export function h(x, y) { return [x.b(2, 1), y.b(3, 1)] }",
+    );
+    // Non-trivial bodies are not duplicated.
+    test_same("function f(a) { return g(a) + 1 } log(f(1), f(2))");
+}
+
+#[test]
+fn iife() {
+    test("export function f(x) { return ((a) => a.b)(x) }", "export function f(x) { return x.b }");
+    test(
+        "export function f(x) { return (function (a, b) { return a * b })(2, x) }",
+        "export function f(x) { return 2 * x }",
+    );
+    test("log(((a, b) => { return g(a, b) })(1, 2))", "log(g(1, 2))");
+    test_same("export function f(x) { return ((a) => a + a)(x) }");
+}
+
+#[test]
+fn this_and_arguments() {
+    test_same("function f(a) { return this.x + a } log(f(1))");
+    test_same("function f(a) { return arguments.length + a } log(f(1))");
+    test_same("log((function (a) { return this[a] })(1))");
+    test_same("function f() { return new.target } log(f())");
+    test_same("export class A { #x; m(a) { function f(o) { return o.#x } return f(a) } }");
+    test_same("export class A extends B { m() { return ((a) => super.m(a))(1) } }");
+}
+
+#[test]
+fn unsupported_functions() {
+    test_same("async function f(a) { return g(a) } log(f(1))");
+    test_same("function* f(a) { return g(a) } log(f(1))");
+    test_same("function f(a = 1) { return g(a) } log(f(1))");
+    test_same("function f({ a }) { return g(a) } log(f(x))");
+    test_same("function f(...a) { return g(a) } log(f(1))");
+    test_same("function f(a) { for (let b of a) g(b); return a } log(f(x))");
+    test_same("function f(a) { return () => a } log(f(1))");
+    test_same("function f(a) { return g`${a}` } log(f(1))");
+    test_same("function f(a) { return eval(a) } log(f(1))");
+    test_same("function f(a) { return x = a } log(f(1))");
+    test_same("function f(a) { return f(a) } log(f(1))");
+    test_same("function f(a) { return g(a) } f = h, log(f(1))");
+}
+
+#[test]
+fn unsafe_arguments() {
+    // Arguments with side effects or mutable values would be evaluated at a different time.
+    test_same("function f(a) { return g(a) + h() } log(f(x()))");
+    test_same("function f(a) { return g(a) } log(f(x))");
+    test_same("let x = 1; x++; function f(a) { return g() + a } log(f(x))");
+    test_same("function f(a) { return g(a) } log(f(...x))");
+    test_same("function f() { return g() } log(f(x()))");
+    // Parameters read twice would duplicate the argument.
+    test_same("function f(a) { return a * a } log(f(2))");
+    test_same("function f(a) { return g(a) } log(/* @__PURE__ */ f(1))");
+}
+
+#[test]
+fn shadowed_names() {
+    test_same(
+        "export let x = g(); function f() { return x } export function h() { let x = g(); return [x, x, f()] }",
+    );
+    test_same("function f() { return x } export function h(x) { return [x, f()] }");
+}
+
+#[test]
+fn sloppy_mode() {
+    let source_type = SourceType::cjs();
+    // Annex B binds both declarations to the same name in the enclosing scope.
+    let source_text = "{ function f() { return 1 } } { function f() { return 2 } } log(f())";
+    test_options_source_type(source_text, source_text, source_type, &options());
+    // A strict function body cannot be inlined into sloppy code.
+    let source_text = "function f(a) { 'use strict'; return g(a) } log(f(1))";
+    test_options_source_type(source_text, source_text, source_type, &options());
+}
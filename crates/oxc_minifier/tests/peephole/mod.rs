@@ -5,6 +5,7 @@ mod dead_code_elimination;
 mod esbuild;
 mod fold_constants;
 mod inline;
+mod inline_function;
 mod inline_single_use_variable;
 mod manual_pure_functions;
 mod merge_assignments_to_declarations;
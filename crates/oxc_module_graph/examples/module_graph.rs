@@ -0,0 +1,68 @@
+#![expect(clippy::print_stdout)]
+//! # Module Graph Example
+//!
+//! This example builds the module graph of a project from its entry points, and prints the
+//! requests of each module, the requests which could not be resolved, and import cycles.
+//!
+//! ## Usage
+//!
+//! ```bash
+//! cargo run -p oxc_module_graph --example module_graph [entry...]
+//! ```
+
+use std::{env, path::Path};
+
+use oxc_module_graph::{ModuleGraph, ModuleGraphBuilder, ModuleId, Resolution, ResolveOptions};
+
+// Instruction:
+// create a `test.js`,
+// run `cargo run -p oxc_module_graph --example module_graph`
+// or `just example module_graph`
+
+/// Print the module graph of the given entry points
+fn main() {
+    let mut entries = env::args().skip(1).collect::<Vec<_>>();
+    if entries.is_empty() {
+        entries.push("test.js".to_string());
+    }
+
+    let options = ResolveOptions {
+        extensions: [".ts", ".tsx", ".mts", ".cts", ".js", ".jsx", ".mjs", ".cjs", ".json"]
+            .into_iter()
+            .map(ToString::to_string)
+            .collect(),
+        condition_names: vec!["import".into(), "require".into(), "node".into()],
+        builtin_modules: true,
+        ..ResolveOptions::default()
+    };
+    let graph = ModuleGraphBuilder::new(options).with_node_modules(false).build(&entries);
+    let cwd = env::current_dir().unwrap_or_default();
+    let display = |id: ModuleId| display_path(&graph, id, &cwd);
+
+    for module in graph.modules() {
+        println!("{}", display(module.id));
+        for error in &module.errors {
+            println!("  error: {error}");
+        }
+        for import in &module.imports {
+            let resolution = match &import.resolution {
+                Resolution::Module(id) => display(*id),
+                Resolution::File(path) => path.display().to_string(),
+                Resolution::Builtin(name) => name.clone(),
+                Resolution::Unresolved(error) => format!("unresolved ({error})"),
+            };
+            println!("  {:?} '{}' -> {resolution}", import.kind, import.specifier);
+        }
+    }
+
+    let cycles = graph.cycles();
+    println!("\n{} modules, {} cycles", graph.len(), cycles.len());
+    for cycle in cycles {
+        println!("  {}", cycle.into_iter().map(display).collect::<Vec<_>>().join(" -> "));
+    }
+}
+
+fn display_path(graph: &ModuleGraph, id: ModuleId, cwd: &Path) -> String {
+    let path = &graph.module(id).path;
+    path.strip_prefix(cwd).unwrap_or(path).display().to_string()
+}
@@ -0,0 +1,3 @@
+export { a as fromA } from './a';
+export const b = 2;
+export default 3;
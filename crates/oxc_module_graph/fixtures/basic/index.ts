@@ -0,0 +1,12 @@
+import { a } from './a';
+import type { T } from './types';
+import { renamed } from './reexports';
+import data from './data.json';
+import { readFile } from 'node:fs';
+import './missing';
+
+export const value: T = `${a}${renamed}${data}${readFile}`;
+
+export async function load() {
+  return import('./lazy');
+}
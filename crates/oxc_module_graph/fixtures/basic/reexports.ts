@@ -0,0 +1,6 @@
+export { a as renamed } from './a';
+export * as ns from './a';
+export { readFile } from 'node:fs';
+import d from './b';
+export { d };
+export * from './b';
@@ -0,0 +1 @@
+export type T = string;
@@ -0,0 +1 @@
+exports.b = require('./b.js');
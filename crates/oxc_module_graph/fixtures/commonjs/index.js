@@ -0,0 +1,4 @@
+const a = require('./a.js');
+require('./a.js');
+require(name);
+module.exports = require('./shadowed.js');
@@ -0,0 +1,4 @@
+function load(require) {
+  return require('./a.js');
+}
+module.exports = load;
@@ -0,0 +1,4 @@
+import './b.js';
+export { circular } from './c.js';
+
+import('./lazy.js');
@@ -0,0 +1,3 @@
+import './a.js';
+import './self.js';
+import './type.ts';
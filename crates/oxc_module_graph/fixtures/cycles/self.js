@@ -0,0 +1 @@
+import './self.js';
@@ -0,0 +1,2 @@
+import type { X } from './type.ts';
+export type Y = string;
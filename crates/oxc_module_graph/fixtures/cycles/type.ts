@@ -0,0 +1,2 @@
+import type { Y } from './type-dep.ts';
+export type X = Y;
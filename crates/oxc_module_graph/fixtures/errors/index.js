@@ -0,0 +1 @@
+import './invalid.js';
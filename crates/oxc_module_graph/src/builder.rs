@@ -0,0 +1,352 @@
+use std::path::{Path, PathBuf};
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{Argument, BindingIdentifier, CallExpression, Expression, Program};
+use oxc_ast_visit::{Visit, walk::walk_call_expression};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_index::IndexVec;
+use oxc_parser::{ParseOptions, Parser};
+use oxc_resolver::{ResolveError, ResolveOptions, Resolver};
+use oxc_span::{SourceType, Span};
+use oxc_str::CompactStr;
+use oxc_syntax::module_record::{
+    ExportEntry, ExportExportName, ExportImportName, ImportImportName, ModuleRecord,
+};
+use rayon::prelude::*;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    ModuleGraph,
+    module::{Export, ExportSource, Import, ImportKind, Module, ModuleId, Resolution},
+};
+
+/// Builds a [`ModuleGraph`] from entry points.
+pub struct ModuleGraphBuilder {
+    resolver: Resolver,
+    node_modules: bool,
+}
+
+impl ModuleGraphBuilder {
+    pub fn new(options: ResolveOptions) -> Self {
+        Self::with_resolver(Resolver::new(options))
+    }
+
+    /// Use an existing resolver, to share its cache.
+    pub fn with_resolver(resolver: Resolver) -> Self {
+        Self { resolver, node_modules: true }
+    }
+
+    /// Whether to parse files in `node_modules`. Default `true`.
+    ///
+    /// When disabled, requests resolved to `node_modules` are [`Resolution::File`].
+    #[must_use]
+    pub fn with_node_modules(mut self, yes: bool) -> Self {
+        self.node_modules = yes;
+        self
+    }
+
+    pub fn resolver(&self) -> &Resolver {
+        &self.resolver
+    }
+
+    /// Parse the entry points and every module they transitively request.
+    ///
+    /// Modules are parsed in parallel, one breadth-first level at a time. Files which cannot
+    /// be read or parsed are still added to the graph, with [`Module::errors`].
+    pub fn build<P: AsRef<Path>>(&self, entries: &[P]) -> ModuleGraph {
+        let mut modules = IndexVec::<ModuleId, Module>::new();
+        let mut paths = FxHashMap::<PathBuf, ModuleId>::default();
+        let mut frontier = vec![];
+        let entries = entries
+            .iter()
+            .map(|path| {
+                let path = path.as_ref();
+                let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+                add_module(&mut modules, &mut paths, path, &mut frontier)
+            })
+            .collect::<Vec<_>>();
+
+        while !frontier.is_empty() {
+            let parsed = frontier
+                .par_iter()
+                .map(|&id| self.parse_module(&modules[id].path))
+                .collect::<Vec<_>>();
+            let mut next_frontier = vec![];
+            for (id, mut parsed) in frontier.into_iter().zip(parsed) {
+                for import in &mut parsed.imports {
+                    if let Resolution::File(path) = &import.resolution
+                        && self.should_parse(path)
+                    {
+                        let id =
+                            add_module(&mut modules, &mut paths, path.clone(), &mut next_frontier);
+                        import.resolution = Resolution::Module(id);
+                    }
+                }
+                let module = &mut modules[id];
+                module.source_type = parsed.source_type;
+                module.has_module_syntax = parsed.has_module_syntax;
+                module.imports = parsed.imports;
+                module.exports = parsed.exports;
+                module.star_exports = parsed.star_exports;
+                module.errors = parsed.errors;
+            }
+            frontier = next_frontier;
+        }
+
+        ModuleGraph::new(modules, paths, entries)
+    }
+
+    fn should_parse(&self, path: &Path) -> bool {
+        SourceType::from_path(path).is_ok()
+            && (self.node_modules || !path.components().any(|c| c.as_os_str() == "node_modules"))
+    }
+
+    /// Read and parse a module, and resolve its requests.
+    ///
+    /// Requests are resolved to [`Resolution::File`]; the caller turns them into modules.
+    fn parse_module(&self, path: &Path) -> ParsedModule {
+        let mut parsed = ParsedModule::default();
+        let source_type = match SourceType::from_path(path) {
+            Ok(source_type) => source_type,
+            Err(err) => {
+                parsed.errors.push(OxcDiagnostic::error(err.to_string()));
+                return parsed;
+            }
+        };
+        parsed.source_type = source_type;
+        let source_text = match std::fs::read_to_string(path) {
+            Ok(source_text) => source_text,
+            Err(err) => {
+                parsed.errors.push(OxcDiagnostic::error(format!(
+                    "Failed to read {}: {err}",
+                    path.display()
+                )));
+                return parsed;
+            }
+        };
+
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, &source_text, source_type)
+            .with_options(ParseOptions {
+                allow_return_outside_function: true,
+                ..ParseOptions::default()
+            })
+            .parse();
+        parsed.errors = ret.diagnostics.into();
+        parsed.has_module_syntax = ret.module_record.has_module_syntax;
+        self.collect_imports(path, &source_text, &ret.module_record, &mut parsed);
+        self.collect_requires(path, &ret.program, &mut parsed);
+        collect_exports(&ret.module_record, &mut parsed);
+        parsed
+    }
+
+    fn collect_imports(
+        &self,
+        path: &Path,
+        source_text: &str,
+        record: &ModuleRecord,
+        parsed: &mut ParsedModule,
+    ) {
+        let mut requested_modules = record
+            .requested_modules
+            .iter()
+            .filter_map(|(specifier, requests)| Some((specifier, requests.first()?, requests)))
+            .collect::<Vec<_>>();
+        requested_modules.sort_unstable_by_key(|(_, first, _)| first.span.start);
+        for (specifier, first, requests) in requested_modules {
+            parsed.imports.push(Import {
+                specifier: CompactStr::from(specifier.as_str()),
+                span: first.span,
+                kind: ImportKind::Static,
+                is_type: requests.iter().all(|request| request.is_type),
+                resolution: self.resolve(path, specifier),
+            });
+        }
+
+        for dynamic_import in &record.dynamic_imports {
+            let Some(specifier) = string_literal(dynamic_import.module_request, source_text) else {
+                continue;
+            };
+            if parsed
+                .imports
+                .iter()
+                .any(|import| import.kind == ImportKind::Dynamic && import.specifier == specifier)
+            {
+                continue;
+            }
+            parsed.imports.push(Import {
+                specifier: CompactStr::from(specifier),
+                span: dynamic_import.module_request,
+                kind: ImportKind::Dynamic,
+                is_type: false,
+                resolution: self.resolve(path, specifier),
+            });
+        }
+    }
+
+    fn collect_requires(&self, path: &Path, program: &Program, parsed: &mut ParsedModule) {
+        let mut collector = RequireCollector::default();
+        collector.visit_program(program);
+        if collector.declares_require {
+            return;
+        }
+        for (specifier, span) in collector.requires {
+            if parsed
+                .imports
+                .iter()
+                .any(|import| import.kind == ImportKind::Require && import.specifier == specifier)
+            {
+                continue;
+            }
+            parsed.imports.push(Import {
+                specifier: CompactStr::from(specifier),
+                span,
+                kind: ImportKind::Require,
+                is_type: false,
+                resolution: self.resolve(path, specifier),
+            });
+        }
+    }
+
+    fn resolve(&self, path: &Path, specifier: &str) -> Resolution {
+        match self.resolver.resolve_file(path, specifier) {
+            Ok(resolution) => Resolution::File(resolution.into_path_buf()),
+            Err(ResolveError::Builtin { resolved, .. }) => Resolution::Builtin(resolved),
+            Err(err) => Resolution::Unresolved(err),
+        }
+    }
+}
+
+/// Collects `require('mod')` calls with a string literal specifier.
+///
+/// Scopes are not analyzed, so calls are only used when `require` is not declared anywhere in
+/// the module, such as in `function load(require) {}`.
+#[derive(Default)]
+struct RequireCollector<'a> {
+    requires: Vec<(&'a str, Span)>,
+    declares_require: bool,
+}
+
+impl<'a> Visit<'a> for RequireCollector<'a> {
+    fn visit_binding_identifier(&mut self, it: &BindingIdentifier<'a>) {
+        if it.name == "require" {
+            self.declares_require = true;
+        }
+    }
+
+    fn visit_call_expression(&mut self, it: &CallExpression<'a>) {
+        if let Expression::Identifier(callee) = &it.callee
+            && callee.name == "require"
+            && let [Argument::StringLiteral(specifier)] = it.arguments.as_slice()
+        {
+            self.requires.push((specifier.value.as_str(), specifier.span));
+        }
+        walk_call_expression(self, it);
+    }
+}
+
+/// Get the module for `path`, adding it to `frontier` if it is new.
+fn add_module(
+    modules: &mut IndexVec<ModuleId, Module>,
+    paths: &mut FxHashMap<PathBuf, ModuleId>,
+    path: PathBuf,
+    frontier: &mut Vec<ModuleId>,
+) -> ModuleId {
+    *paths.entry(path).or_insert_with_key(|path| {
+        let id = modules.push(Module::new(modules.next_idx(), path.clone()));
+        frontier.push(id);
+        id
+    })
+}
+
+#[derive(Default)]
+struct ParsedModule {
+    source_type: SourceType,
+    has_module_syntax: bool,
+    imports: Vec<Import>,
+    exports: Vec<Export>,
+    star_exports: Vec<usize>,
+    errors: Vec<OxcDiagnostic>,
+}
+
+impl ParsedModule {
+    fn static_import(&self, specifier: &str) -> Option<usize> {
+        self.imports
+            .iter()
+            .position(|import| import.kind == ImportKind::Static && import.specifier == specifier)
+    }
+}
+
+fn collect_exports(record: &ModuleRecord, parsed: &mut ParsedModule) {
+    for entry in &record.local_export_entries {
+        let Some((name, span)) = export_name(entry) else { continue };
+        parsed.exports.push(Export {
+            name,
+            span,
+            is_type: entry.is_type,
+            source: ExportSource::Local,
+        });
+    }
+
+    for entry in &record.indirect_export_entries {
+        let Some((name, span)) = export_name(entry) else { continue };
+        let Some(module_request) = &entry.module_request else { continue };
+        let Some(import) = parsed.static_import(&module_request.name) else { continue };
+        let imported_name = match &entry.import_name {
+            ExportImportName::Name(imported) => {
+                // `import a from 'mod'; export { a }` is recorded with the local name of the
+                // default import.
+                let is_default_import = record.import_entries.iter().any(|import_entry| {
+                    matches!(import_entry.import_name, ImportImportName::Default(_))
+                        && import_entry.local_name.name == imported.name
+                        && import_entry.module_request.name == module_request.name
+                });
+                Some(if is_default_import {
+                    CompactStr::new("default")
+                } else {
+                    CompactStr::from(imported.name.as_str())
+                })
+            }
+            // `export * as ns from 'mod'`
+            ExportImportName::All | ExportImportName::AllButDefault => None,
+            ExportImportName::Null => continue,
+        };
+        parsed.exports.push(Export {
+            name,
+            span,
+            is_type: entry.is_type,
+            source: ExportSource::ReExport { import, name: imported_name },
+        });
+    }
+
+    for entry in &record.star_export_entries {
+        if let Some(module_request) = &entry.module_request
+            && let Some(import) = parsed.static_import(&module_request.name)
+        {
+            parsed.star_exports.push(import);
+        }
+    }
+}
+
+fn export_name(entry: &ExportEntry) -> Option<(CompactStr, Span)> {
+    match &entry.export_name {
+        ExportExportName::Name(name) => Some((CompactStr::from(name.name.as_str()), name.span)),
+        ExportExportName::Default(span) => Some((CompactStr::new("default"), *span)),
+        ExportExportName::Null => None,
+    }
+}
+
+/// The value of a string literal or a template literal without substitutions, such as the
+/// specifier in `import('./foo')`. Literals with escapes are skipped.
+fn string_literal(span: Span, source_text: &str) -> Option<&str> {
+    let text = span.source_text(source_text);
+    let quote = text.chars().next()?;
+    if !matches!(quote, '\'' | '"' | '`') || text.len() < 2 || !text.ends_with(quote) {
+        return None;
+    }
+    let value = &text[1..text.len() - 1];
+    if value.contains('\\') || (quote == '`' && value.contains("${")) {
+        return None;
+    }
+    Some(value)
+}
@@ -0,0 +1,264 @@
+use std::path::{Path, PathBuf};
+
+use oxc_index::IndexVec;
+use oxc_str::CompactStr;
+use petgraph::{algo::tarjan_scc, graph::DiGraph};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::module::{ExportSource, ImportKind, Module, ModuleId, Resolution};
+
+/// The modules of a project and the requests between them.
+///
+/// Create with [`ModuleGraphBuilder`](crate::ModuleGraphBuilder).
+#[derive(Debug)]
+pub struct ModuleGraph {
+    modules: IndexVec<ModuleId, Module>,
+    paths: FxHashMap<PathBuf, ModuleId>,
+    entries: Vec<ModuleId>,
+    /// Reverse edges of [`Self::dependencies`].
+    importers: IndexVec<ModuleId, Vec<ModuleId>>,
+}
+
+/// Where an export is declared, from [`ModuleGraph::resolve_export`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedExport {
+    pub kind: ResolvedExportKind,
+    /// The modules the export is re-exported through, from the queried module to the module
+    /// which declares it.
+    pub chain: Vec<ModuleId>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolvedExportKind {
+    /// Declared by `module`. `export` is an index into [`Module::exports`].
+    Local { module: ModuleId, export: usize },
+    /// The namespace object of a module, from `export * as ns from 'mod'`.
+    Namespace(ModuleId),
+    /// Re-exported by `module` from a request which does not resolve to a module in the graph.
+    External {
+        module: ModuleId,
+        /// Index into [`Module::imports`].
+        import: usize,
+        /// The name in the imported module, or `None` for a namespace re-export.
+        name: Option<CompactStr>,
+    },
+}
+
+enum Lookup {
+    Found(ResolvedExport),
+    NotFound,
+    /// Provided by more than one `export *`.
+    Ambiguous,
+}
+
+impl ModuleGraph {
+    pub(crate) fn new(
+        modules: IndexVec<ModuleId, Module>,
+        paths: FxHashMap<PathBuf, ModuleId>,
+        entries: Vec<ModuleId>,
+    ) -> Self {
+        let mut importers =
+            IndexVec::<ModuleId, Vec<ModuleId>>::from_vec(vec![vec![]; modules.len()]);
+        for module in &modules {
+            for dependency in dependencies(module) {
+                importers[dependency].push(module.id);
+            }
+        }
+        Self { modules, paths, entries, importers }
+    }
+
+    /// The entry points, in the order passed to
+    /// [`ModuleGraphBuilder::build`](crate::ModuleGraphBuilder::build).
+    pub fn entries(&self) -> &[ModuleId] {
+        &self.entries
+    }
+
+    pub fn modules(&self) -> impl ExactSizeIterator<Item = &Module> {
+        self.modules.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.modules.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.modules.is_empty()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `id` is not from this graph.
+    pub fn module(&self, id: ModuleId) -> &Module {
+        &self.modules[id]
+    }
+
+    /// Find a module by its resolved path.
+    pub fn module_by_path(&self, path: &Path) -> Option<&Module> {
+        self.paths.get(path).map(|&id| &self.modules[id])
+    }
+
+    /// Modules requested by `id`, including type-only and dynamic requests, in source order.
+    pub fn dependencies(&self, id: ModuleId) -> impl Iterator<Item = ModuleId> {
+        dependencies(&self.modules[id])
+    }
+
+    /// Modules which request `id`, including type-only and dynamic requests, in id order.
+    pub fn importers(&self, id: ModuleId) -> &[ModuleId] {
+        &self.importers[id]
+    }
+
+    /// Find where the export `name` of module `id` is declared, following re-exports and
+    /// `export *`, as in [ResolveExport](https://tc39.es/ecma262/#sec-resolveexport).
+    ///
+    /// Returns `None` if the export does not exist, is provided by more than one `export *`
+    /// with different sources, or is circular.
+    pub fn resolve_export(&self, id: ModuleId, name: &str) -> Option<ResolvedExport> {
+        match self.lookup_export(id, name, &mut FxHashSet::default()) {
+            Lookup::Found(resolved) => Some(resolved),
+            Lookup::NotFound | Lookup::Ambiguous => None,
+        }
+    }
+
+    fn lookup_export<'g>(
+        &'g self,
+        id: ModuleId,
+        name: &'g str,
+        visited: &mut FxHashSet<(ModuleId, &'g str)>,
+    ) -> Lookup {
+        if !visited.insert((id, name)) {
+            // Circular import request.
+            return Lookup::NotFound;
+        }
+        let module = &self.modules[id];
+        if let Some(index) = module.exports.iter().position(|export| export.name == name) {
+            let ExportSource::ReExport { import, name: imported_name } =
+                &module.exports[index].source
+            else {
+                let kind = ResolvedExportKind::Local { module: id, export: index };
+                return Lookup::Found(ResolvedExport { kind, chain: vec![id] });
+            };
+            let import = *import;
+            let Some(target) = module.imports[import].resolution.module_id() else {
+                let kind = ResolvedExportKind::External {
+                    module: id,
+                    import,
+                    name: imported_name.clone(),
+                };
+                return Lookup::Found(ResolvedExport { kind, chain: vec![id] });
+            };
+            let Some(imported_name) = imported_name else {
+                let kind = ResolvedExportKind::Namespace(target);
+                return Lookup::Found(ResolvedExport { kind, chain: vec![id, target] });
+            };
+            return match self.lookup_export(target, imported_name, visited) {
+                Lookup::Found(mut resolved) => {
+                    resolved.chain.insert(0, id);
+                    Lookup::Found(resolved)
+                }
+                lookup => lookup,
+            };
+        }
+
+        // `export *` does not re-export `default`.
+        if name == "default" {
+            return Lookup::NotFound;
+        }
+        let mut star_resolution: Option<ResolvedExport> = None;
+        for &import in &module.star_exports {
+            let Some(target) = module.imports[import].resolution.module_id() else { continue };
+            match self.lookup_export(target, name, visited) {
+                Lookup::Found(mut resolved) => match &star_resolution {
+                    Some(existing) if existing.kind != resolved.kind => return Lookup::Ambiguous,
+                    Some(_) => {}
+                    None => {
+                        resolved.chain.insert(0, id);
+                        star_resolution = Some(resolved);
+                    }
+                },
+                Lookup::Ambiguous => return Lookup::Ambiguous,
+                Lookup::NotFound => {}
+            }
+        }
+        star_resolution.map_or(Lookup::NotFound, Lookup::Found)
+    }
+
+    /// All names exported by module `id`, including names from `export *`, as in
+    /// [GetExportedNames](https://tc39.es/ecma262/#sec-getexportednames).
+    ///
+    /// Ambiguous names from `export *` are included.
+    pub fn exported_names(&self, id: ModuleId) -> Vec<&str> {
+        let mut names = vec![];
+        let mut seen = FxHashSet::default();
+        self.collect_exported_names(id, &mut FxHashSet::default(), &mut seen, &mut names);
+        names
+    }
+
+    fn collect_exported_names<'g>(
+        &'g self,
+        id: ModuleId,
+        visited: &mut FxHashSet<ModuleId>,
+        seen: &mut FxHashSet<&'g str>,
+        names: &mut Vec<&'g str>,
+    ) {
+        if !visited.insert(id) {
+            return;
+        }
+        let module = &self.modules[id];
+        let is_star_export = visited.len() > 1;
+        for export in &module.exports {
+            if is_star_export && export.name == "default" {
+                continue;
+            }
+            if seen.insert(export.name.as_str()) {
+                names.push(export.name.as_str());
+            }
+        }
+        for &import in &module.star_exports {
+            if let Some(target) = module.imports[import].resolution.module_id() {
+                self.collect_exported_names(target, visited, seen, names);
+            }
+        }
+    }
+
+    /// Groups of modules which import each other, through static imports which are not
+    /// type-only and `require()` calls.
+    ///
+    /// Each cycle is sorted by id, and cycles are sorted by their first id.
+    pub fn cycles(&self) -> Vec<Vec<ModuleId>> {
+        let mut graph = DiGraph::<ModuleId, ()>::with_capacity(self.modules.len(), 0);
+        let nodes = self.modules.iter().map(|module| graph.add_node(module.id)).collect::<Vec<_>>();
+        for module in &self.modules {
+            for import in &module.imports {
+                if matches!(import.kind, ImportKind::Static | ImportKind::Require)
+                    && !import.is_type
+                    && let Resolution::Module(target) = import.resolution
+                {
+                    graph.update_edge(nodes[module.id.index()], nodes[target.index()], ());
+                }
+            }
+        }
+        let mut cycles = tarjan_scc(&graph)
+            .into_iter()
+            .filter(|component| {
+                component.len() > 1 || graph.contains_edge(component[0], component[0])
+            })
+            .map(|component| {
+                let mut cycle = component.into_iter().map(|node| graph[node]).collect::<Vec<_>>();
+                cycle.sort_unstable();
+                cycle
+            })
+            .collect::<Vec<_>>();
+        cycles.sort_unstable_by_key(|cycle| cycle[0]);
+        cycles
+    }
+}
+
+/// Modules requested by `module`, without duplicates.
+fn dependencies(module: &Module) -> impl Iterator<Item = ModuleId> {
+    let mut seen = FxHashSet::default();
+    module
+        .imports
+        .iter()
+        .filter_map(|import| import.resolution.module_id())
+        .filter(move |&id| seen.insert(id))
+}
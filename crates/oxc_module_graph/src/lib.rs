@@ -0,0 +1,246 @@
+//! # Oxc Module Graph
+//!
+//! Parallel module resolution and a queryable module graph for JavaScript and TypeScript
+//! projects.
+//!
+//! [`ModuleGraphBuilder::build`] parses the entry points, resolves their static imports,
+//! re-exports, `import()` and `require()` calls with [`oxc_resolver`], and repeats for every
+//! module found, parsing each level of the graph in parallel. The resulting [`ModuleGraph`]
+//! owns no ASTs; it keeps the requests and exports of each [`Module`] so that tools such as
+//! bundlers, documentation generators and dependency auditors can share one resolution pass.
+//!
+//! ## Scope
+//!
+//! This crate is for tools which only need the shape of a project: which modules request which,
+//! what they export, and where re-exports lead. It is not used by the rest of Oxc yet:
+//!
+//! - `oxc_linter` keeps building its own module records. Its import rules need the import
+//!   entries and semantic information of every module, and it lints modules as soon as their
+//!   dependencies are loaded to bound memory use, instead of building the whole graph first.
+//! - The minifier works on a single file, and Oxc has no type checker to share the graph with.
+//!
+//! Moving those onto this crate is left to future changes, which will extend it as needed.
+//!
+//! ## Usage
+//!
+//! ```no_run
+//! use oxc_module_graph::{ModuleGraphBuilder, ResolveOptions};
+//!
+//! let options = ResolveOptions {
+//!     extensions: vec![".ts".into(), ".js".into()],
+//!     ..ResolveOptions::default()
+//! };
+//! let graph = ModuleGraphBuilder::new(options).build(&["src/index.ts"]);
+//! let entry = graph.entries()[0];
+//! for dependency in graph.dependencies(entry) {
+//!     println!("{}", graph.module(dependency).path.display());
+//! }
+//! if let Some(resolved) = graph.resolve_export(entry, "foo") {
+//!     println!("{:?}", resolved.kind);
+//! }
+//! for cycle in graph.cycles() {
+//!     println!("cycle: {cycle:?}");
+//! }
+//! ```
+//!
+//! Exports are only tracked for ECMAScript module syntax; `module.exports` assignments are not.
+//!
+//! See `examples/module_graph.rs` for a command line tool which prints the graph of a project.
+
+mod builder;
+mod graph;
+mod module;
+
+pub use oxc_resolver::{ResolveError, ResolveOptions, Resolver};
+
+pub use builder::ModuleGraphBuilder;
+pub use graph::{ModuleGraph, ResolvedExport, ResolvedExportKind};
+pub use module::{Export, ExportSource, Import, ImportKind, Module, ModuleId, Resolution};
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use super::*;
+
+    fn fixture(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures").join(name)
+    }
+
+    fn build(entries: &[&str]) -> ModuleGraph {
+        let options = ResolveOptions {
+            extensions: vec![".ts".into(), ".js".into(), ".json".into()],
+            builtin_modules: true,
+            ..ResolveOptions::default()
+        };
+        let entries = entries.iter().map(|entry| fixture(entry)).collect::<Vec<_>>();
+        ModuleGraphBuilder::new(options).build(&entries)
+    }
+
+    fn id(graph: &ModuleGraph, name: &str) -> ModuleId {
+        graph.module_by_path(&fixture(name)).unwrap_or_else(|| panic!("{name} not in graph")).id
+    }
+
+    fn names(graph: &ModuleGraph, ids: impl IntoIterator<Item = ModuleId>) -> Vec<String> {
+        ids.into_iter()
+            .map(|id| graph.module(id).path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn imports() {
+        let graph = build(&["basic/index.ts"]);
+        let index = graph.entries()[0];
+        assert_eq!(graph.len(), 6);
+        assert!(graph.modules().all(|module| module.errors.is_empty()));
+
+        let module = graph.module(index);
+        assert!(module.has_module_syntax);
+        let imports = module
+            .imports
+            .iter()
+            .map(|import| (import.specifier.as_str(), import.kind, import.is_type))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            imports,
+            [
+                ("./a", ImportKind::Static, false),
+                ("./types", ImportKind::Static, true),
+                ("./reexports", ImportKind::Static, false),
+                ("./data.json", ImportKind::Static, false),
+                ("node:fs", ImportKind::Static, false),
+                ("./missing", ImportKind::Static, false),
+                ("./lazy", ImportKind::Dynamic, false),
+            ]
+        );
+        assert!(matches!(module.imports[3].resolution, Resolution::File(_)));
+        assert_eq!(module.imports[4].resolution, Resolution::Builtin("node:fs".into()));
+        assert!(matches!(module.imports[5].resolution, Resolution::Unresolved(_)));
+
+        assert_eq!(
+            names(&graph, graph.dependencies(index)),
+            ["a.ts", "types.ts", "reexports.ts", "lazy.ts"]
+        );
+        assert_eq!(
+            names(&graph, graph.importers(id(&graph, "basic/a.ts")).iter().copied()),
+            ["index.ts", "reexports.ts", "b.js"]
+        );
+    }
+
+    #[test]
+    fn ids_are_stable() {
+        let paths = |graph: &ModuleGraph| {
+            graph.modules().map(|module| module.path.clone()).collect::<Vec<_>>()
+        };
+        let graph = build(&["basic/index.ts"]);
+        for _ in 0..5 {
+            assert_eq!(paths(&build(&["basic/index.ts"])), paths(&graph));
+        }
+    }
+
+    #[test]
+    fn resolve_export() {
+        let graph = build(&["basic/index.ts"]);
+        let reexports = id(&graph, "basic/reexports.ts");
+        let a = id(&graph, "basic/a.ts");
+        let b = id(&graph, "basic/b.js");
+
+        // `export { a as renamed } from './a'`
+        let resolved = graph.resolve_export(reexports, "renamed").unwrap();
+        assert_eq!(resolved.kind, ResolvedExportKind::Local { module: a, export: 0 });
+        assert_eq!(resolved.chain, [reexports, a]);
+
+        // `export * from './b'`, where `b.js` re-exports from `a.ts`
+        let resolved = graph.resolve_export(reexports, "fromA").unwrap();
+        assert_eq!(resolved.chain, [reexports, b, a]);
+        assert_eq!(graph.module(a).exports[0].name, "a");
+        assert!(matches!(resolved.kind, ResolvedExportKind::Local { module, .. } if module == a));
+
+        // `import d from './b'; export { d }`
+        let resolved = graph.resolve_export(reexports, "d").unwrap();
+        assert_eq!(resolved.chain, [reexports, b]);
+
+        // `export * as ns from './a'`
+        let resolved = graph.resolve_export(reexports, "ns").unwrap();
+        assert_eq!(resolved.kind, ResolvedExportKind::Namespace(a));
+
+        // `export { readFile } from 'node:fs'`
+        let resolved = graph.resolve_export(reexports, "readFile").unwrap();
+        assert_eq!(
+            resolved.kind,
+            ResolvedExportKind::External {
+                module: reexports,
+                import: 1,
+                name: Some("readFile".into())
+            }
+        );
+
+        // `export *` does not re-export `default`.
+        assert!(graph.resolve_export(reexports, "default").is_none());
+        assert!(graph.resolve_export(reexports, "missing").is_none());
+
+        assert_eq!(
+            graph.exported_names(reexports),
+            ["renamed", "ns", "readFile", "d", "b", "fromA"]
+        );
+    }
+
+    #[test]
+    fn ambiguous_star_exports() {
+        let graph = build(&["ambiguous/index.js"]);
+        let index = graph.entries()[0];
+        assert!(graph.resolve_export(index, "x").is_none());
+        assert!(graph.resolve_export(index, "y").is_some());
+    }
+
+    #[test]
+    fn cycles() {
+        let graph = build(&["cycles/index.js"]);
+        let cycles =
+            graph.cycles().into_iter().map(|cycle| names(&graph, cycle)).collect::<Vec<_>>();
+        // `type.ts` and `type-dep.ts` only import each other with `import type`, and
+        // `lazy.js` is only requested by `import()`.
+        assert_eq!(cycles, [vec!["a.js", "b.js", "c.js"], vec!["self.js"]]);
+
+        // Circular re-exports are not resolved.
+        let a = id(&graph, "cycles/a.js");
+        assert!(graph.resolve_export(a, "circular").is_none());
+    }
+
+    #[test]
+    fn require() {
+        let graph = build(&["commonjs/index.js"]);
+        let index = graph.module(graph.entries()[0]);
+        assert!(!index.has_module_syntax);
+        let imports = index
+            .imports
+            .iter()
+            .map(|import| (import.specifier.as_str(), import.kind))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            imports,
+            [("./a.js", ImportKind::Require), ("./shadowed.js", ImportKind::Require)]
+        );
+        assert_eq!(names(&graph, graph.dependencies(index.id)), ["a.js", "shadowed.js"]);
+
+        // `require` is a parameter.
+        assert!(graph.module(id(&graph, "commonjs/shadowed.js")).imports.is_empty());
+
+        let cycles =
+            graph.cycles().into_iter().map(|cycle| names(&graph, cycle)).collect::<Vec<_>>();
+        assert_eq!(cycles, [vec!["a.js", "b.js"]]);
+    }
+
+    #[test]
+    fn errors() {
+        let graph = build(&["errors/index.js", "errors/not-found.js"]);
+        assert_eq!(graph.len(), 3);
+        let [index, not_found] = graph.entries() else { unreachable!() };
+        assert!(graph.module(*index).errors.is_empty());
+        assert!(!graph.module(*not_found).errors.is_empty());
+        // Modules with syntax errors are kept.
+        let invalid = graph.module(id(&graph, "errors/invalid.js"));
+        assert!(!invalid.errors.is_empty());
+        assert_eq!(invalid.exports.len(), 2);
+    }
+}
@@ -0,0 +1,133 @@
+use std::path::PathBuf;
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_index::define_nonmax_u32_index_type;
+use oxc_resolver::ResolveError;
+use oxc_span::{SourceType, Span};
+use oxc_str::CompactStr;
+
+define_nonmax_u32_index_type! {
+    /// Index of a [`Module`] in a [`ModuleGraph`](crate::ModuleGraph).
+    ///
+    /// Modules are numbered in breadth-first order from the entry points, so ids are stable
+    /// across runs for the same project.
+    pub struct ModuleId;
+}
+
+/// A parsed source file in a [`ModuleGraph`](crate::ModuleGraph).
+#[derive(Debug)]
+pub struct Module {
+    pub id: ModuleId,
+    pub path: PathBuf,
+    pub source_type: SourceType,
+    /// Whether the module contains `import`, `export` or `import.meta`.
+    pub has_module_syntax: bool,
+    /// Modules requested by `import`, `export ... from`, `import()` and `require()`.
+    ///
+    /// Static requests come first in source order, followed by `import()` and then `require()`
+    /// calls in source order. Each kind of request is listed once per specifier.
+    pub imports: Vec<Import>,
+    /// Named exports, including re-exports such as `export { a } from 'mod'`.
+    pub exports: Vec<Export>,
+    /// `export * from 'mod'`, as indices into [`Self::imports`].
+    pub star_exports: Vec<usize>,
+    /// Errors from reading and parsing the file.
+    pub errors: Vec<OxcDiagnostic>,
+}
+
+impl Module {
+    pub(crate) fn new(id: ModuleId, path: PathBuf) -> Self {
+        Self {
+            id,
+            path,
+            source_type: SourceType::default(),
+            has_module_syntax: false,
+            imports: vec![],
+            exports: vec![],
+            star_exports: vec![],
+            errors: vec![],
+        }
+    }
+
+    /// Get an export by its exported name.
+    pub fn export(&self, name: &str) -> Option<&Export> {
+        self.exports.iter().find(|export| export.name == name)
+    }
+}
+
+/// A module request.
+#[derive(Debug, Clone)]
+pub struct Import {
+    /// `./foo` in `import foo from './foo'`
+    pub specifier: CompactStr,
+    /// Span of the specifier, including quotes.
+    pub span: Span,
+    pub kind: ImportKind,
+    /// `import type { a } from 'mod'` or `export type { a } from 'mod'`.
+    ///
+    /// `false` if any static request of the specifier is not type-only.
+    pub is_type: bool,
+    pub resolution: Resolution,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportKind {
+    /// `import a from 'mod'`, `import 'mod'` or `export { a } from 'mod'`
+    Static,
+    /// `import('mod')` with a string literal specifier
+    Dynamic,
+    /// `require('mod')` with a string literal specifier, in a module which does not declare
+    /// `require`
+    Require,
+}
+
+/// Where a module request resolves to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Resolution {
+    /// A module in the graph.
+    Module(ModuleId),
+    /// A file which is not parsed, such as a JSON or CSS file, or a file in `node_modules`
+    /// when [`ModuleGraphBuilder::with_node_modules`](crate::ModuleGraphBuilder::with_node_modules)
+    /// is disabled.
+    File(PathBuf),
+    /// A Node.js builtin module, such as `node:fs`.
+    Builtin(String),
+    /// The specifier could not be resolved.
+    Unresolved(ResolveError),
+}
+
+impl Resolution {
+    /// The module in the graph, if resolved to one.
+    pub fn module_id(&self) -> Option<ModuleId> {
+        match self {
+            Self::Module(id) => Some(*id),
+            _ => None,
+        }
+    }
+}
+
+/// A named export.
+#[derive(Debug, Clone)]
+pub struct Export {
+    /// The exported name. `default` for `export default`.
+    pub name: CompactStr,
+    /// Span of the exported name, or of `export default`.
+    pub span: Span,
+    /// `export type { a }`
+    pub is_type: bool,
+    pub source: ExportSource,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExportSource {
+    /// Declared in the module: `export const a = 1`, `export { a }` or `export default a`.
+    Local,
+    /// Re-exported from another module.
+    ReExport {
+        /// Index into [`Module::imports`].
+        import: usize,
+        /// The name in the imported module, or `None` for a namespace re-export
+        /// (`export * as ns from 'mod'`).
+        name: Option<CompactStr>,
+    },
+}
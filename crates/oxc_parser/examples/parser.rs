@@ -7,7 +7,7 @@
 //!
 //! Create a `test.js` file and run:
 //! ```bash
-//! cargo run -p oxc_parser --example parser [filename] [--ast] [--estree] [--comments]
+//! cargo run -p oxc_parser --example parser [filename] [--ast] [--estree] [--comments] [--recover]
 //! ```
 //!
 //! ## Options
@@ -15,6 +15,7 @@
 //! - `--ast`: Display the parsed AST structure
 //! - `--estree`: Display the ESTree representation
 //! - `--comments`: Display extracted comments
+//! - `--recover`: Recover from fatal syntax errors
 
 use std::{fs, path::Path};
 
@@ -37,6 +38,7 @@ fn main() -> Result<(), String> {
     let show_ast = args.contains("--ast");
     let show_estree = args.contains("--estree");
     let show_comments = args.contains("--comments");
+    let recover_from_errors = args.contains("--recover");
     let name = args.free_from_str().unwrap_or_else(|_| "test.js".to_string());
 
     // Read source file
@@ -47,7 +49,11 @@ fn main() -> Result<(), String> {
     // Parse the source code
     let allocator = Allocator::default();
     let ret = Parser::new(&allocator, &source_text, source_type)
-        .with_options(ParseOptions { parse_regular_expression: true, ..ParseOptions::default() })
+        .with_options(ParseOptions {
+            parse_regular_expression: true,
+            recover_from_errors,
+            ..ParseOptions::default()
+        })
         .parse();
     let mut program = ret.program;
 
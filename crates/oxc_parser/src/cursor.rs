@@ -200,7 +200,13 @@ impl<'a, C: Config> ParserImpl<'a, C> {
                 range,
                 opening_span,
             );
-            self.set_fatal_error(error);
+            // Unclosed brackets at the end of the file are common while typing,
+            // keep the nodes enclosed by them when recovering from errors.
+            if self.options.recover_from_errors && self.at(Kind::Eof) {
+                self.error(error);
+            } else {
+                self.set_fatal_error(error);
+            }
         }
         self.advance(kind);
     }
@@ -16,6 +16,10 @@ pub struct FatalError<'a> {
     pub error: ParserDiagnostic<'a>,
     /// Length of `errors` at time fatal error is recorded
     pub errors_len: usize,
+    /// Length of the lexer's `errors` at time fatal error is recorded
+    pub lexer_errors_len: usize,
+    /// Start of the token the fatal error is recorded at
+    pub position: u32,
 }
 
 impl<'a, C: Config> ParserImpl<'a, C> {
@@ -83,8 +87,14 @@ impl<'a, C: Config> ParserImpl<'a, C> {
     #[cold]
     pub(crate) fn set_fatal_error(&mut self, error: ParserDiagnostic<'a>) {
         if self.fatal_error.is_none() {
+            let position = self.cur_start();
             self.lexer.advance_to_end();
-            self.fatal_error = Some(FatalError { error, errors_len: self.errors.len() });
+            self.fatal_error = Some(FatalError {
+                error,
+                errors_len: self.errors.len(),
+                lexer_errors_len: self.lexer.errors.len(),
+                position,
+            });
         }
     }
 
@@ -99,6 +109,183 @@ impl<'a, C: Config> ParserImpl<'a, C> {
     }
 }
 
+// ==================== Fatal Error Recovery ====================
+//
+// With `ParseOptions::recover_from_errors`, a fatal error does not abort the whole parse.
+// Elements of statement lists and class bodies are parsed with `parse_with_recovery`:
+// when a fatal error is recorded while parsing an element, the partial element is discarded,
+// the fatal error is turned into a regular error, and tokens are skipped until the next
+// statement or class member boundary. Since the innermost list recovers first, an error inside
+// a function body only discards the statement containing it.
+
+/// The kind of list an element is recovered in, which determines where the next element starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecoveryContext {
+    Statement,
+    ClassElement,
+}
+
+impl<C: Config> ParserImpl<'_, C> {
+    /// Parse a list element with `parse_element`, recovering from a fatal error if
+    /// [`ParseOptions::recover_from_errors`](crate::ParseOptions::recover_from_errors) is enabled.
+    ///
+    /// Returns `Err` with the span of the skipped source text if the element was discarded.
+    #[inline]
+    pub(crate) fn parse_with_recovery<T>(
+        &mut self,
+        recovery_ctx: RecoveryContext,
+        parse_element: impl FnOnce(&mut Self) -> T,
+    ) -> Result<T, Span> {
+        if !self.options.recover_from_errors {
+            return Ok(parse_element(self));
+        }
+
+        let start = self.cur_start();
+        let errors_len = self.errors.len();
+        let discarded_spans_len = self.discarded_spans.len();
+        let checkpoint = self.checkpoint();
+        let element = parse_element(self);
+        let Some(fatal_error) = self.fatal_error.take() else {
+            return Ok(element);
+        };
+
+        // Keep the errors reported before the fatal error, and report the fatal error itself.
+        let mut errors = self.errors.split_off(errors_len.min(self.errors.len()));
+        errors.truncate(fatal_error.errors_len.saturating_sub(errors_len));
+        self.rewind(checkpoint);
+        self.errors.extend(errors);
+        self.error(fatal_error.error);
+
+        self.skip_to_list_element_boundary(
+            start,
+            fatal_error.position,
+            fatal_error.lexer_errors_len,
+            recovery_ctx,
+        );
+        // Elements discarded within this element are covered by its span.
+        self.discarded_spans.truncate(discarded_spans_len);
+        let span = self.end_span(start);
+        self.discarded_spans.push(span);
+        Err(span)
+    }
+
+    /// Skip tokens from the start of a discarded element until the start of the next element,
+    /// a `;` ending the element, or the `}` closing the enclosing list.
+    ///
+    /// The element is scanned from its start to track the brackets it opened,
+    /// so closing brackets after the error are matched up with them.
+    #[cold]
+    fn skip_to_list_element_boundary(
+        &mut self,
+        start: u32,
+        fatal_error_position: u32,
+        lexer_errors_len: usize,
+        recovery_ctx: RecoveryContext,
+    ) {
+        let mut brackets = vec![];
+        let mut past_error = false;
+        loop {
+            let token = self.cur_token();
+            let kind = token.kind();
+            if !past_error && token.start() >= fatal_error_position {
+                past_error = true;
+                // Errors of the tokens up to the fatal error have already been reported.
+                self.lexer.errors.truncate(lexer_errors_len);
+            }
+            // Always skip the first token, so the list makes progress.
+            let is_boundary = past_error && token.start() > start;
+            match kind {
+                Kind::Eof => return,
+                Kind::Semicolon if is_boundary && brackets.is_empty() => {
+                    self.bump_any();
+                    return;
+                }
+                Kind::LCurly | Kind::LParen | Kind::LBrack | Kind::TemplateHead => {
+                    brackets.push(kind);
+                }
+                Kind::RParen | Kind::RBrack => {
+                    if let Some(index) = brackets
+                        .iter()
+                        .rposition(|&open| open != Kind::LCurly && open != Kind::TemplateHead)
+                    {
+                        brackets.truncate(index);
+                    }
+                }
+                Kind::RCurly => {
+                    // Brackets left open by the broken element are closed by this `}`.
+                    match brackets
+                        .iter()
+                        .rposition(|&open| matches!(open, Kind::LCurly | Kind::TemplateHead))
+                    {
+                        Some(index) => {
+                            let is_template = brackets[index] == Kind::TemplateHead;
+                            brackets.truncate(index);
+                            if is_template {
+                                self.re_lex_template_substitution_tail();
+                                if self.at(Kind::TemplateMiddle) {
+                                    brackets.push(Kind::TemplateHead);
+                                }
+                            }
+                        }
+                        // Closes the enclosing list.
+                        None if is_boundary => return,
+                        None => {}
+                    }
+                }
+                _ if is_boundary
+                    && token.is_on_new_line()
+                    && self.is_at_list_element_start(recovery_ctx, brackets.is_empty()) =>
+                {
+                    return;
+                }
+                _ => {}
+            }
+            self.bump_any();
+        }
+    }
+
+    /// Whether the current token, which is on a new line after a fatal error, starts a new element.
+    fn is_at_list_element_start(
+        &self,
+        recovery_ctx: RecoveryContext,
+        brackets_closed: bool,
+    ) -> bool {
+        let kind = self.cur_kind();
+        match recovery_ctx {
+            // Keywords starting a statement end the discarded element even inside unclosed brackets,
+            // e.g. `foo(a,` followed by `const b = 1;` on the next line.
+            RecoveryContext::Statement => matches!(
+                kind,
+                Kind::Var
+                    | Kind::Let
+                    | Kind::Const
+                    | Kind::Function
+                    | Kind::Class
+                    | Kind::Import
+                    | Kind::Export
+                    | Kind::If
+                    | Kind::For
+                    | Kind::While
+                    | Kind::Do
+                    | Kind::Return
+                    | Kind::Throw
+                    | Kind::Try
+                    | Kind::Switch
+                    | Kind::Break
+                    | Kind::Continue
+                    | Kind::Case
+                    | Kind::Default
+                    | Kind::Interface
+                    | Kind::Enum
+            ),
+            RecoveryContext::ClassElement => {
+                brackets_closed
+                    && (kind.is_identifier_or_keyword() || matches!(kind, Kind::At | Kind::LBrack))
+            }
+        }
+    }
+}
+
 // ==================== Merge Conflict Marker Detection ====================
 //
 // Git merge conflict markers detection and error recovery.
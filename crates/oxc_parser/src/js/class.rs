@@ -5,6 +5,7 @@ use oxc_span::{GetSpan, Span};
 
 use crate::{
     Context, ParserConfig as Config, ParserImpl, StatementContext, diagnostics,
+    error_handler::RecoveryContext,
     lexer::Kind,
     modifiers::{ModifierKind, ModifierKinds, Modifiers},
 };
@@ -231,14 +232,26 @@ impl<'a, C: Config> ParserImpl<'a, C> {
     fn parse_class_body(&mut self) -> ArenaBox<'a, ClassBody<'a>> {
         let start = self.cur_start();
         let class_elements = self.parse_normal_list_breakable(Kind::LCurly, Kind::RCurly, |p| {
-            // Skip empty class element `;`
-            if p.eat(Kind::Semicolon) {
-                while p.eat(Kind::Semicolon) {}
-                if p.at(Kind::RCurly) {
-                    return None;
+            loop {
+                // Skip empty class element `;`
+                if p.eat(Kind::Semicolon) {
+                    while p.eat(Kind::Semicolon) {}
+                    if p.at(Kind::RCurly) {
+                        return None;
+                    }
+                }
+                match p
+                    .parse_with_recovery(RecoveryContext::ClassElement, Self::parse_class_element)
+                {
+                    Ok(element) => return Some(element),
+                    // Discarded when recovering from a fatal error, continue with the next element.
+                    Err(_) => {
+                        if p.at(Kind::RCurly) || p.has_fatal_error() {
+                            return None;
+                        }
+                    }
                 }
             }
-            Some(Self::parse_class_element(p))
         });
         ClassBody::boxed(self.end_span(start), class_elements, self)
     }
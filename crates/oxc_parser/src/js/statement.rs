@@ -6,6 +6,7 @@ use oxc_str::Str;
 use super::{VariableDeclarationParent, grammar::CoverGrammar};
 use crate::{
     Context, ParserConfig as Config, ParserImpl, StatementContext, diagnostics,
+    error_handler::RecoveryContext,
     lexer::Kind,
     modifiers::{ModifierKind, Modifiers},
 };
@@ -68,7 +69,7 @@ impl<'a, C: Config> ParserImpl<'a, C> {
                 None
             };
 
-            let stmt = self.parse_statement_list_item(stmt_ctx);
+            let stmt = self.parse_statement_list_item_with_recovery(stmt_ctx);
 
             // Don't reparse a module declaration: `export` already committed to the
             // Module goal while parsing, so reparsing would record the export twice.
@@ -125,6 +126,18 @@ impl<'a, C: Config> ParserImpl<'a, C> {
         (directives, statements)
     }
 
+    /// Parse a `StatementListItem`, or an [`EmptyStatement`] spanning the skipped source text
+    /// if it is discarded when recovering from a fatal error.
+    pub(crate) fn parse_statement_list_item_with_recovery(
+        &mut self,
+        stmt_ctx: StatementContext,
+    ) -> Statement<'a> {
+        self.parse_with_recovery(RecoveryContext::Statement, |p| {
+            p.parse_statement_list_item(stmt_ctx)
+        })
+        .unwrap_or_else(|span| Statement::new_empty_statement(span, self))
+    }
+
     /// `StatementListItem`[Yield, Await, Return] :
     ///     Statement[?Yield, ?Await, ?Return]
     ///     Declaration[?Yield, ?Await]
@@ -266,7 +279,7 @@ impl<'a, C: Config> ParserImpl<'a, C> {
     pub(crate) fn parse_block(&mut self) -> ArenaBox<'a, BlockStatement<'a>> {
         let start = self.cur_start();
         let body = self.parse_normal_list(Kind::LCurly, Kind::RCurly, |p| {
-            p.parse_statement_list_item(StatementContext::StatementList)
+            p.parse_statement_list_item_with_recovery(StatementContext::StatementList)
         });
         BlockStatement::boxed(self.end_span(start), body, self)
     }
@@ -796,7 +809,8 @@ impl<'a, C: Config> ParserImpl<'a, C> {
             {
                 break;
             }
-            let stmt = self.parse_statement_list_item(StatementContext::StatementList);
+            let stmt =
+                self.parse_statement_list_item_with_recovery(StatementContext::StatementList);
             if let Statement::VariableDeclaration(var_decl) = &stmt
                 && var_decl.kind.is_using()
             {
@@ -141,7 +141,8 @@ pub(crate) const MAX_LEN: usize = if size_of::<usize>() >= 8 {
 /// 3. [`panicked`] will be false
 ///
 /// When the parser cannot recover, it will abort and terminate parsing early. [`program`] will
-/// be empty and [`panicked`] will be `true`.
+/// be empty and [`panicked`] will be `true`. With [`ParseOptions::recover_from_errors`] enabled,
+/// the parser instead discards the statement or class member containing the error and continues.
 ///
 /// [`program`]: ParserReturn::program
 /// [`diagnostics`]: ParserReturn::diagnostics
@@ -179,6 +180,16 @@ pub struct ParserReturn<'a> {
     /// Tokens are only collected when tokens are enabled in [`ParserConfig`].
     pub tokens: ArenaVec<'a, Token>,
 
+    /// Spans of the source text discarded when recovering from fatal errors with
+    /// [`ParseOptions::recover_from_errors`], in source order.
+    ///
+    /// A discarded statement is replaced by an [`EmptyStatement`] with the same span, and a
+    /// discarded class member is removed. Nodes within these spans are error placeholders, not
+    /// source code, so consumers such as linters should not report on them.
+    ///
+    /// [`EmptyStatement`]: oxc_ast::ast::EmptyStatement
+    pub discarded_spans: Box<[Span]>,
+
     /// Whether the parser panicked and terminated early.
     ///
     /// This will be `false` if parsing was successful, or if parsing was able to recover from a
@@ -245,6 +256,25 @@ pub struct ParseOptions {
     ///
     /// Default: `true`
     pub enable_ident_hashes: bool,
+
+    /// Recover from unrecoverable syntax errors, for editor use where files are often incomplete.
+    ///
+    /// By default, parsing stops at the first unrecoverable error and an empty [`Program`]
+    /// is returned with [`ParserReturn::panicked`] set to `true`.
+    ///
+    /// If this option is `true`, a statement or class member containing such an error is
+    /// discarded, tokens are skipped until the next statement or class member, and parsing
+    /// continues. A discarded statement is replaced by an [`EmptyStatement`] spanning the
+    /// skipped source text, the skipped source text is recorded in
+    /// [`ParserReturn::discarded_spans`], and missing closing brackets at the end of the file are reported
+    /// without discarding the enclosing nodes. All errors are still reported in
+    /// [`ParserReturn::diagnostics`], but the returned [`Program`] can be used for
+    /// semantic analysis.
+    ///
+    /// Default: `false`
+    ///
+    /// [`EmptyStatement`]: oxc_ast::ast::EmptyStatement
+    pub recover_from_errors: bool,
 }
 
 impl Default for ParseOptions {
@@ -256,6 +286,7 @@ impl Default for ParseOptions {
             preserve_parens: true,
             allow_v8_intrinsics: false,
             enable_ident_hashes: true,
+            recover_from_errors: false,
         }
     }
 }
@@ -625,6 +656,9 @@ struct ParserImpl<'a, C: ParserConfig> {
 
     fatal_error: Option<FatalError<'a>>,
 
+    /// Spans of the source text discarded when recovering from fatal errors.
+    discarded_spans: Vec<Span>,
+
     /// The current parsing token
     token: Token,
 
@@ -670,6 +704,7 @@ impl<'a, C: ParserConfig> ParserImpl<'a, C> {
             errors: vec![],
             deferred_script_errors: vec![],
             fatal_error: None,
+            discarded_spans: vec![],
             token: Token::default(),
             prev_token_end: 0,
             state: ParserState::new(),
@@ -701,6 +736,7 @@ impl<'a, C: ParserConfig> ParserImpl<'a, C> {
             program = Program::dummy(self.allocator());
             program.source_type = self.source_type;
             program.source_text = self.source_text;
+            self.discarded_spans.clear();
         }
 
         self.check_unfinished_errors();
@@ -709,6 +745,7 @@ impl<'a, C: ParserConfig> ParserImpl<'a, C> {
             panicked = true;
             self.lexer.errors.clear();
             self.errors.clear();
+            self.discarded_spans.clear();
             self.error(overlong_error);
         }
 
@@ -765,6 +802,7 @@ impl<'a, C: ParserConfig> ParserImpl<'a, C> {
             diagnostics: errors,
             irregular_whitespaces,
             tokens,
+            discarded_spans: self.discarded_spans.into_boxed_slice(),
             panicked,
             is_flow_language,
         }
@@ -809,8 +847,10 @@ impl<'a, C: ParserConfig> ParserImpl<'a, C> {
         // we need to reparse statements that were originally parsed with `await` as identifier.
         // TypeScript's behavior: initially parse `await /x/` as division, then reparse as
         // await expression with regex when ESM is detected.
-        // Preserve a fatal error from the initial parse instead of rewinding past it.
+        // Preserve a fatal error from the initial parse instead of rewinding past it,
+        // and likewise the errors of statements discarded when recovering from one.
         if self.fatal_error.is_none()
+            && self.discarded_spans.is_empty()
             && self.source_type.is_unambiguous()
             && self.module_record_builder.has_module_syntax()
             && !self.state.potential_await_reparse.is_empty()
@@ -850,7 +890,7 @@ impl<'a, C: ParserConfig> ParserImpl<'a, C> {
 
             // Parse the statement with await context enabled (TopLevel context is already set)
             let stmt = self.context_add(Context::Await, |p| {
-                p.parse_statement_list_item(StatementContext::StatementList)
+                p.parse_statement_list_item_with_recovery(StatementContext::StatementList)
             });
 
             // Replace the statement if the index is valid
@@ -1151,6 +1191,112 @@ mod test {
         }
     }
 
+    fn parse_with_recovery<'a>(allocator: &'a Allocator, source: &'a str) -> ParserReturn<'a> {
+        let options = ParseOptions { recover_from_errors: true, ..ParseOptions::default() };
+        let source_type = SourceType::ts();
+        Parser::new(allocator, source, source_type).with_options(options).parse()
+    }
+
+    fn statement_texts<'a>(source: &'a str, statements: &[Statement]) -> Vec<&'a str> {
+        statements.iter().map(|stmt| stmt.span().source_text(source)).collect()
+    }
+
+    #[test]
+    fn recover_from_errors() {
+        let allocator = Allocator::default();
+        let source = "const a = ;\nfoo(a, { b: 1 + }, c);\nlet d = 1;\n}\nd;";
+
+        let ret = Parser::new(&allocator, source, SourceType::ts()).parse();
+        assert!(ret.panicked);
+        assert!(ret.program.body.is_empty());
+
+        let ret = parse_with_recovery(&allocator, source);
+        assert!(!ret.panicked);
+        assert_eq!(
+            statement_texts(source, &ret.program.body),
+            vec!["const a = ;", "foo(a, { b: 1 + }, c);", "let d = 1;", "}\nd;"]
+        );
+        assert!(matches!(ret.program.body[0], Statement::EmptyStatement(_)));
+        assert!(matches!(ret.program.body[1], Statement::EmptyStatement(_)));
+        assert!(matches!(ret.program.body[2], Statement::VariableDeclaration(_)));
+        assert!(matches!(ret.program.body[3], Statement::EmptyStatement(_)));
+        assert_eq!(ret.diagnostics.len(), 3);
+        assert_eq!(
+            ret.discarded_spans.iter().map(|span| span.source_text(source)).collect::<Vec<_>>(),
+            vec!["const a = ;", "foo(a, { b: 1 + }, c);", "}\nd;"]
+        );
+    }
+
+    #[test]
+    fn recover_from_errors_in_nested_lists() {
+        let allocator = Allocator::default();
+        let source = "function f() {\n  foo(a,\n  const b = `${a}`;\n  return b;\n}\nclass C {\n  a = ;\n  b() {}\n}";
+        let ret = parse_with_recovery(&allocator, source);
+        assert!(!ret.panicked);
+        assert_eq!(ret.diagnostics.len(), 2);
+
+        let Statement::FunctionDeclaration(function) = &ret.program.body[0] else {
+            panic!("expected function declaration");
+        };
+        assert_eq!(
+            statement_texts(source, &function.body.as_ref().unwrap().statements),
+            vec!["foo(a,", "const b = `${a}`;", "return b;"]
+        );
+        let Statement::ClassDeclaration(class) = &ret.program.body[1] else {
+            panic!("expected class declaration");
+        };
+        assert_eq!(class.body.body.len(), 1);
+        assert_eq!(class.body.body[0].span().source_text(source), "b() {}");
+        assert_eq!(
+            ret.discarded_spans.iter().map(|span| span.source_text(source)).collect::<Vec<_>>(),
+            vec!["foo(a,", "a = ;"]
+        );
+    }
+
+    #[test]
+    fn recover_from_unclosed_brackets_at_end_of_file() {
+        let allocator = Allocator::default();
+        let source = "const a = 1;\nfunction f() {\n  if (a) {\n    foo(a";
+        let ret = parse_with_recovery(&allocator, source);
+        assert!(!ret.panicked);
+        assert_eq!(ret.diagnostics.len(), 3);
+        assert_eq!(ret.program.body.len(), 2);
+        assert!(matches!(ret.program.body[1], Statement::FunctionDeclaration(_)));
+        assert_eq!(
+            ret.discarded_spans.iter().map(|span| span.source_text(source)).collect::<Vec<_>>(),
+            vec!["foo(a"]
+        );
+    }
+
+    #[test]
+    fn recover_from_errors_after_top_level_await() {
+        let allocator = Allocator::default();
+        let source = "await\nfoo();\nexport()s.height = height;";
+        let ret = Parser::new(&allocator, source, SourceType::unambiguous())
+            .with_options(ParseOptions { recover_from_errors: true, ..ParseOptions::default() })
+            .parse();
+        assert!(!ret.panicked);
+        assert_eq!(ret.diagnostics.len(), 1);
+        assert_eq!(
+            ret.discarded_spans.iter().map(|span| span.source_text(source)).collect::<Vec<_>>(),
+            vec!["export()s.height = height;"]
+        );
+    }
+
+    #[test]
+    fn discard_nested_recovered_spans() {
+        let allocator = Allocator::default();
+        // The inner error is recovered in the function body, the outer one discards the
+        // whole declaration.
+        let source = "const f = function () {\n  a = ;\n} +;\nf;";
+        let ret = parse_with_recovery(&allocator, source);
+        assert!(!ret.panicked);
+        assert_eq!(
+            ret.discarded_spans.iter().map(|span| span.source_text(source)).collect::<Vec<_>>(),
+            vec!["const f = function () {\n  a = ;\n} +;"]
+        );
+    }
+
     #[test]
     fn binary_file() {
         let allocator = Allocator::default();
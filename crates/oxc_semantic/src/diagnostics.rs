@@ -445,3 +445,46 @@ pub fn ts_export_assignment_cannot_be_used_with_other_exports(span: Span) -> Oxc
         .with_label(span)
         .with_help("If you want to use `export =`, remove other `export`s and put all of them to the right hand value of `export =`. If you want to use `export`s, remove `export =` statement.")
 }
+
+#[cold]
+pub fn rename_invalid_name(name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Cannot rename to `{name}`: it is not a valid identifier"))
+}
+
+#[cold]
+pub fn rename_unsupported(name: &str, reason: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Cannot rename `{name}`: {reason}")).with_label(span)
+}
+
+#[cold]
+pub fn rename_redeclaration(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Cannot rename to `{name}`: it is already declared in this scope"))
+        .with_label(span.label(format!("`{name}` is declared here")))
+}
+
+#[cold]
+pub fn rename_shadowed(name: &str, reference: Span, binding: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!(
+        "Cannot rename to `{name}`: a reference would resolve to another `{name}`"
+    ))
+    .with_labels([
+        reference.label("This reference would be shadowed"),
+        binding.label(format!("by this `{name}`")),
+    ])
+}
+
+#[cold]
+pub fn rename_captures(name: &str, reference: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!(
+        "Cannot rename to `{name}`: an existing reference to `{name}` would resolve to the renamed symbol"
+    ))
+    .with_label(reference)
+}
+
+#[cold]
+pub fn rename_jsx_intrinsic(name: &str, reference: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!(
+        "Cannot rename to `{name}`: a lowercase JSX tag is an intrinsic element, not a component"
+    ))
+    .with_label(reference)
+}
@@ -1,6 +1,8 @@
+use std::cell::OnceCell;
+
 use rustc_hash::FxHashMap;
 
-use oxc_jsdoc::JSDoc;
+use oxc_jsdoc::{JSDoc, JSDocTag, JSDocType};
 use oxc_span::{GetSpan, Span};
 
 use crate::{AstNode, AstNodes};
@@ -10,11 +12,53 @@ pub struct JSDocFinder<'a> {
     /// JSDocs by Span
     attached: FxHashMap<u32, Vec<JSDoc<'a>>>,
     not_attached: Vec<JSDoc<'a>>,
+    /// `@typedef` and `@callback` declarations by name, collected on first use
+    typedefs: OnceCell<FxHashMap<&'a str, JSDocTypedef<'a>>>,
+    /// `@template` type parameters by name, by the same Span as `attached`, collected on first use
+    templates: OnceCell<FxHashMap<u32, FxHashMap<&'a str, JSDocTemplate<'a>>>>,
+}
+
+/// A type declared with `@typedef` or `@callback`.
+///
+/// ```js
+/// /**
+///  * @typedef {{ x: number, y: number }} Point
+///  * @callback Compare
+///  * @param {Point} a
+///  * @param {Point} b
+///  * @returns {number}
+///  */
+/// ```
+#[derive(Debug, Clone)]
+pub struct JSDocTypedef<'a> {
+    pub name: &'a str,
+    /// Span of the tag.
+    pub span: Span,
+    /// The declared type, e.g. `{ x: number, y: number }` for `Point` above.
+    ///
+    /// `None` for `@callback`s, and `@typedef`s without a valid type.
+    pub r#type: Option<JSDocType<'a>>,
+    /// Whether this is declared with `@callback`.
+    pub callback: bool,
+}
+
+/// A type parameter declared with `@template`, e.g. `K` and `V` in `@template {string} K, V`.
+///
+/// Type parameters are in scope in the JSDoc of the node they are attached to.
+#[derive(Debug, Clone)]
+pub struct JSDocTemplate<'a> {
+    pub name: &'a str,
+    /// Span of the name.
+    pub span: Span,
+    /// `string` in `@template {string} K`.
+    pub constraint: Option<JSDocType<'a>>,
+    /// Whether a default is given, as in `@template [T=string]`.
+    pub default: bool,
 }
 
 impl<'a> JSDocFinder<'a> {
     pub fn new(attached: FxHashMap<u32, Vec<JSDoc<'a>>>, not_attached: Vec<JSDoc<'a>>) -> Self {
-        Self { attached, not_attached }
+        Self { attached, not_attached, typedefs: OnceCell::new(), templates: OnceCell::new() }
     }
 
     pub fn get_one_by_node<'b>(
@@ -49,12 +93,115 @@ impl<'a> JSDocFinder<'a> {
     pub fn iter_all<'b>(&'b self) -> impl Iterator<Item = &'b JSDoc<'a>> + 'b {
         self.attached.values().flatten().chain(self.not_attached.iter())
     }
+
+    /// All `@typedef` and `@callback` declarations in the file, by name.
+    ///
+    /// Like TypeScript's `checkJs`, these are visible in the whole file regardless of where they
+    /// are written. When a name is declared more than once, the first declaration is used.
+    pub fn typedefs(&self) -> &FxHashMap<&'a str, JSDocTypedef<'a>> {
+        self.typedefs.get_or_init(|| {
+            let mut typedefs =
+                self.iter_all().flat_map(JSDoc::tags).filter_map(parse_typedef).collect::<Vec<_>>();
+            typedefs.sort_unstable_by_key(|typedef| typedef.span.start);
+            let mut map = FxHashMap::default();
+            for typedef in typedefs {
+                map.entry(typedef.name).or_insert(typedef);
+            }
+            map
+        })
+    }
+
+    /// The `@typedef` or `@callback` declaring `name`.
+    pub fn get_typedef(&self, name: &str) -> Option<&JSDocTypedef<'a>> {
+        self.typedefs().get(name)
+    }
+
+    /// The `@template` type parameters of the JSDocs attached to `node`, by name.
+    pub fn get_templates_by_node<'b>(
+        &'b self,
+        nodes: &AstNodes<'a>,
+        node: &AstNode<'a>,
+    ) -> Option<&'b FxHashMap<&'a str, JSDocTemplate<'a>>> {
+        if !nodes.flags(node.id()).has_jsdoc() {
+            return None;
+        }
+        self.templates().get(&node.kind().span().start)
+    }
+
+    fn templates(&self) -> &FxHashMap<u32, FxHashMap<&'a str, JSDocTemplate<'a>>> {
+        self.templates.get_or_init(|| {
+            self.attached
+                .iter()
+                .filter_map(|(&start, jsdocs)| {
+                    let mut templates = FxHashMap::default();
+                    let tags = jsdocs.iter().flat_map(JSDoc::tags);
+                    for template in
+                        tags.filter(|tag| tag.kind.parsed() == "template").flat_map(parse_templates)
+                    {
+                        templates.entry(template.name).or_insert(template);
+                    }
+                    (!templates.is_empty()).then_some((start, templates))
+                })
+                .collect()
+        })
+    }
+}
+
+/// `@typedef {type} Name` or `@callback Name`
+fn parse_typedef<'a>(tag: &JSDocTag<'a>) -> Option<JSDocTypedef<'a>> {
+    let callback = match tag.kind.parsed() {
+        "typedef" => false,
+        "callback" => true,
+        _ => return None,
+    };
+    let (type_part, name_part, _) = tag.type_name_comment();
+    let name = name_part?.parsed();
+    let r#type = type_part.and_then(|type_part| type_part.parsed_type().ok());
+    Some(JSDocTypedef { name, span: tag.span, r#type, callback })
+}
+
+/// `@template {constraint} K, V` or `@template [T=default]`
+fn parse_templates<'a>(tag: &JSDocTag<'a>) -> Vec<JSDocTemplate<'a>> {
+    let (type_part, comment_part) = tag.type_comment();
+    let constraint = type_part.and_then(|type_part| type_part.parsed_type().ok());
+    let raw = comment_part.raw();
+    let mut templates = vec![];
+    let mut offset = raw.len() - raw.trim_start().len();
+    // Names are separated by `,`, and the description follows the last name.
+    for segment in raw[offset..].split(',') {
+        let leading = segment.len() - segment.trim_start().len();
+        let segment = segment.trim_start();
+        let (name, default, end) = if let Some(inner) = segment.strip_prefix('[') {
+            let Some(close) = inner.find(']') else { break };
+            let inner = &inner[..close];
+            let name = inner.split_once('=').map_or(inner, |(name, _)| name).trim();
+            let name_start = 1 + inner.find(name).unwrap_or_default();
+            (name, inner.contains('='), (name_start, close + 2))
+        } else {
+            let len = segment.find(char::is_whitespace).unwrap_or(segment.len());
+            (&segment[..len], false, (0, len))
+        };
+        if name.is_empty() {
+            break;
+        }
+        let start =
+            comment_part.span.start + u32::try_from(offset + leading + end.0).unwrap_or_default();
+        let span = Span::sized(start, u32::try_from(name.len()).unwrap_or_default());
+        templates.push(JSDocTemplate { name, span, constraint: constraint.clone(), default });
+        // Any text after the name is the description.
+        if !segment[end.1.min(segment.len())..].trim().is_empty() {
+            break;
+        }
+        offset += leading + segment.len() + 1;
+    }
+    templates
 }
 
 #[cfg(test)]
 mod test {
     use oxc_allocator::Allocator;
-    use oxc_jsdoc::JSDoc;
+    use oxc_ast::AstKind;
+    use oxc_jsdoc::{JSDoc, JSDocType, JSDocTypeKind};
     use oxc_parser::Parser;
     use oxc_span::{SourceType, Span};
 
@@ -900,4 +1047,90 @@ c7 */",
             );
         }
     }
+
+    #[test]
+    fn typedefs() {
+        let source_text = "
+            /**
+             * @typedef {{ x: number, y: number }} Point
+             * @typedef {Array<} Invalid
+             */
+            /**
+             * @callback Compare
+             * @param {Point} a
+             * @returns {number}
+             */
+            function f() {}
+            /** @typedef Untyped */
+            /** @typedef {string} Point */
+        ";
+        let allocator = Allocator::default();
+        let semantic = build_semantic_default(&allocator, source_text);
+        let mut typedefs = semantic.jsdoc().typedefs().values().collect::<Vec<_>>();
+        typedefs.sort_unstable_by_key(|typedef| typedef.span.start);
+        let typedefs = typedefs
+            .iter()
+            .map(|typedef| (typedef.name, typedef.r#type.is_some(), typedef.callback))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            typedefs,
+            [
+                ("Point", true, false),
+                ("Invalid", false, false),
+                ("Compare", false, true),
+                ("Untyped", false, false)
+            ]
+        );
+
+        let point = semantic.jsdoc().get_typedef("Point").unwrap();
+        let Some(JSDocType { kind: JSDocTypeKind::Record(fields), .. }) = &point.r#type else {
+            panic!("expected a record type");
+        };
+        assert_eq!(fields.iter().map(|field| field.key).collect::<Vec<_>>(), ["x", "y"]);
+        assert!(semantic.jsdoc().get_typedef("Missing").is_none());
+    }
+
+    #[test]
+    fn templates() {
+        let source_text = "
+            /**
+             * @template {string} K, V
+             * @template [T=number] The element type, with a comment
+             * @template U description
+             * @param {K} key
+             */
+            function f(key) {}
+        ";
+        let allocator = Allocator::default();
+        let semantic = build_semantic_default(&allocator, source_text);
+        let node = semantic
+            .nodes()
+            .iter()
+            .find(|node| matches!(node.kind(), AstKind::Function(_)))
+            .unwrap();
+        let templates = semantic.jsdoc().get_templates_by_node(semantic.nodes(), node).unwrap();
+        assert_eq!(templates["V"].span.source_text(source_text), "V");
+        let mut templates = templates.values().collect::<Vec<_>>();
+        templates.sort_unstable_by_key(|template| template.span.start);
+        let templates = templates
+            .iter()
+            .map(|template| {
+                (
+                    template.name,
+                    template.span.source_text(source_text),
+                    template.constraint.is_some(),
+                    template.default,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            templates,
+            [
+                ("K", "K", true, false),
+                ("V", "V", true, false),
+                ("T", "T", false, true),
+                ("U", "U", false, false),
+            ]
+        );
+    }
 }
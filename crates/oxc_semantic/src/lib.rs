@@ -38,6 +38,7 @@ mod jsdoc;
 mod label;
 mod multi_index_vec;
 mod node;
+mod rename;
 mod scoping;
 mod stats;
 pub mod ts_enum;
@@ -48,10 +49,11 @@ pub use ast_types_bitset::AstTypesBitset;
 pub use builder::{SemanticBuilder, SemanticBuilderReturn};
 pub use is_global_reference::IsGlobalReference;
 #[cfg(feature = "jsdoc")]
-pub use jsdoc::JSDocFinder;
+pub use jsdoc::{JSDocFinder, JSDocTemplate, JSDocTypedef};
 pub use node::{Ancestry, AncestryStack, AstNode, AstNodes};
 #[cfg(feature = "jsdoc")]
-pub use oxc_jsdoc::{JSDoc, JSDocTag};
+pub use oxc_jsdoc::{JSDoc, JSDocTag, JSDocType, JSDocTypeKind};
+pub use rename::{RenameEdit, SymbolRename};
 pub use scoping::Scoping;
 pub use stats::Stats;
 
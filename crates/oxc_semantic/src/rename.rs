@@ -0,0 +1,345 @@
+//! Binding-aware renaming of symbols, as text edits.
+//!
+//! [`Semantic::rename_symbol`] computes the edits that rename a symbol in its own module,
+//! and [`Semantic::rename_import`] the edits that keep a module importing it working
+//! when the rename changes an export name.
+
+use oxc_ast::{AstKind, ast::JSXElementName};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{GetSpan, Span};
+use oxc_str::Ident;
+use oxc_syntax::{
+    identifier::is_identifier_name,
+    keyword::is_reserved_keyword,
+    module_record::{ExportImportName, ImportImportName, ModuleRecord},
+    node::NodeId,
+    scope::ScopeId,
+    symbol::{SymbolFlags, SymbolId},
+};
+
+use crate::{Semantic, diagnostics};
+
+/// Replace the source text covered by `span` with `text`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenameEdit {
+    pub span: Span,
+    pub text: String,
+}
+
+impl RenameEdit {
+    fn new(span: Span, text: impl Into<String>) -> Self {
+        Self { span, text: text.into() }
+    }
+
+    /// Apply non-overlapping `edits`, sorted by position, to `source_text`.
+    pub fn apply(source_text: &str, edits: &[Self]) -> String {
+        let mut output = String::with_capacity(source_text.len());
+        let mut last = 0;
+        for edit in edits {
+            output.push_str(&source_text[last..edit.span.start as usize]);
+            output.push_str(&edit.text);
+            last = edit.span.end as usize;
+        }
+        output.push_str(&source_text[last..]);
+        output
+    }
+}
+
+/// The result of [`Semantic::rename_symbol`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolRename {
+    /// Edits to the module declaring the symbol, sorted by position.
+    pub edits: Vec<RenameEdit>,
+    /// The symbol is exported by its declaration (`export function foo() {}`), so the module
+    /// now exports it under the new name. Modules importing it need [`Semantic::rename_import`].
+    ///
+    /// Other exports keep their name: `export { foo }` becomes `export { bar as foo }`.
+    pub export_renamed: bool,
+}
+
+impl Semantic<'_> {
+    /// Rename `symbol_id` to `new_name`, returning the minimal text edits.
+    ///
+    /// Shorthand properties are expanded (`{ foo }` becomes `{ foo: bar }`), and exports and
+    /// imports by name keep the name they are exported or imported as
+    /// (`import { foo }` becomes `import { foo as bar }`).
+    ///
+    /// Requires the AST nodes, see [`SemanticBuilder::with_build_nodes`](crate::SemanticBuilder::with_build_nodes).
+    ///
+    /// # Errors
+    ///
+    /// When `new_name` is not a valid identifier, or when the rename would change what a name
+    /// refers to: `new_name` is already declared in the same scope, a reference to the symbol
+    /// would be shadowed by another `new_name`, or an existing reference to `new_name` would
+    /// resolve to the renamed symbol. Also when a JSX component would become an intrinsic element,
+    /// and for symbols that are also accessed as properties (enum members, namespace exports).
+    pub fn rename_symbol(
+        &self,
+        symbol_id: SymbolId,
+        new_name: &str,
+    ) -> Result<SymbolRename, OxcDiagnostic> {
+        let scoping = &self.scoping;
+        let old_name = scoping.symbol_name(symbol_id);
+        if !is_identifier_name(new_name) || is_reserved_keyword(new_name) {
+            return Err(diagnostics::rename_invalid_name(new_name));
+        }
+        if new_name == old_name {
+            return Ok(SymbolRename { edits: vec![], export_renamed: false });
+        }
+        let symbol_span = scoping.symbol_span(symbol_id);
+        if scoping.symbol_flags(symbol_id).contains(SymbolFlags::EnumMember) {
+            return Err(diagnostics::rename_unsupported(
+                old_name,
+                "enum members are accessed as properties",
+                symbol_span,
+            ));
+        }
+
+        let symbol_scope = scoping.symbol_scope_id(symbol_id);
+        if let Some(existing) = scoping.get_binding(symbol_scope, Ident::from(new_name)) {
+            return Err(diagnostics::rename_redeclaration(new_name, scoping.symbol_span(existing)));
+        }
+        self.check_references_keep_resolving(symbol_id, symbol_scope, new_name)?;
+
+        let mut edits = vec![];
+        let mut export_renamed = false;
+        for node in self.nodes.iter() {
+            let AstKind::BindingIdentifier(ident) = node.kind() else {
+                continue;
+            };
+            if ident.symbol_id.get() != Some(symbol_id) {
+                continue;
+            }
+            let text = match self.nodes.parent_kind(node.id()) {
+                // `import { foo }`
+                AstKind::ImportSpecifier(specifier) if specifier.imported.span() == ident.span => {
+                    format!("{old_name} as {new_name}")
+                }
+                // `const { foo } = obj`
+                AstKind::BindingProperty(property) if property.shorthand => {
+                    format!("{old_name}: {new_name}")
+                }
+                // `const { foo = 1 } = obj`
+                AstKind::AssignmentPattern(_)
+                    if matches!(
+                        self.nodes.ancestor_kinds(node.id()).nth(1),
+                        Some(AstKind::BindingProperty(property)) if property.shorthand
+                    ) =>
+                {
+                    format!("{old_name}: {new_name}")
+                }
+                _ => new_name.to_string(),
+            };
+            edits.push(RenameEdit::new(ident.span, text));
+        }
+        if edits.is_empty() {
+            return Err(diagnostics::rename_unsupported(
+                old_name,
+                "it has no declaration to rename",
+                symbol_span,
+            ));
+        }
+
+        for declaration in std::iter::once(scoping.symbol_declaration(symbol_id))
+            .chain(scoping.symbol_redeclarations(symbol_id).iter().map(|r| r.declaration))
+        {
+            if !self.is_exported_declaration(declaration) {
+                continue;
+            }
+            if symbol_scope != scoping.root_scope_id() {
+                return Err(diagnostics::rename_unsupported(
+                    old_name,
+                    "namespace exports are accessed as properties",
+                    symbol_span,
+                ));
+            }
+            export_renamed = true;
+        }
+
+        for reference in scoping.get_resolved_references(symbol_id) {
+            let node_id = reference.node_id();
+            let AstKind::IdentifierReference(ident) = self.nodes.kind(node_id) else {
+                continue;
+            };
+            let text = match self.nodes.parent_kind(node_id) {
+                // `({ foo })`
+                AstKind::ObjectProperty(property) if property.shorthand => {
+                    format!("{old_name}: {new_name}")
+                }
+                // `({ foo } = obj)`
+                AstKind::AssignmentTargetPropertyIdentifier(_) => format!("{old_name}: {new_name}"),
+                // `export { foo }`
+                AstKind::ExportSpecifier(specifier)
+                    if specifier.exported.span() == specifier.local.span() =>
+                {
+                    format!("{new_name} as {old_name}")
+                }
+                // `<Foo />`
+                AstKind::JSXOpeningElement(element)
+                    if matches!(&element.name, JSXElementName::IdentifierReference(_))
+                        && new_name.as_bytes()[0].is_ascii_lowercase() =>
+                {
+                    return Err(diagnostics::rename_jsx_intrinsic(new_name, ident.span));
+                }
+                _ => new_name.to_string(),
+            };
+            edits.push(RenameEdit::new(ident.span, text));
+        }
+
+        edits.sort_unstable_by_key(|edit| edit.span.start);
+        edits.dedup();
+        Ok(SymbolRename { edits, export_renamed })
+    }
+
+    /// Edits for a module importing from another module, after the other module renamed its
+    /// export `old_name` to `new_name` (see [`SymbolRename::export_renamed`]).
+    ///
+    /// `specifier` is the module specifier resolving to the other module, as written in this one.
+    /// Local names are kept (`import { foo }` becomes `import { bar as foo }`), and so are
+    /// re-export names. Members of namespace imports are renamed (`ns.foo` becomes `ns.bar`).
+    ///
+    /// `new_name` must be a valid identifier, which [`Semantic::rename_symbol`] checks.
+    /// Requires the AST nodes, see [`SemanticBuilder::with_build_nodes`](crate::SemanticBuilder::with_build_nodes).
+    pub fn rename_import(
+        &self,
+        module_record: &ModuleRecord,
+        specifier: &str,
+        old_name: &str,
+        new_name: &str,
+    ) -> Vec<RenameEdit> {
+        let mut edits = vec![];
+        for entry in &module_record.import_entries {
+            if entry.module_request.name != specifier {
+                continue;
+            }
+            match &entry.import_name {
+                ImportImportName::Name(name) if name.name == old_name => {
+                    let text = if name.span == entry.local_name.span {
+                        format!("{new_name} as {old_name}")
+                    } else {
+                        new_name.to_string()
+                    };
+                    edits.push(RenameEdit::new(name.span, text));
+                }
+                ImportImportName::NamespaceObject => {
+                    if let Some(namespace) =
+                        self.scoping.get_root_binding(Ident::from(entry.local_name.name.as_str()))
+                    {
+                        self.rename_namespace_members(namespace, old_name, new_name, &mut edits);
+                    }
+                }
+                _ => {}
+            }
+        }
+        for entry in &module_record.indirect_export_entries {
+            if entry.module_request.as_ref().is_none_or(|request| request.name != specifier) {
+                continue;
+            }
+            if let ExportImportName::Name(name) = &entry.import_name
+                && name.name == old_name
+            {
+                let text = if entry.export_name.span() == Some(name.span) {
+                    format!("{new_name} as {old_name}")
+                } else {
+                    new_name.to_string()
+                };
+                edits.push(RenameEdit::new(name.span, text));
+            }
+        }
+        edits.sort_unstable_by_key(|edit| edit.span.start);
+        edits
+    }
+
+    /// Check that references keep resolving to the same symbols when `symbol_id`,
+    /// declared in `symbol_scope`, is renamed to `new_name`.
+    fn check_references_keep_resolving(
+        &self,
+        symbol_id: SymbolId,
+        symbol_scope: ScopeId,
+        new_name: &str,
+    ) -> Result<(), OxcDiagnostic> {
+        let scoping = &self.scoping;
+        let new_ident = Ident::from(new_name);
+
+        // A reference to the symbol from a scope where another `new_name` is declared in between.
+        for reference in scoping.get_resolved_references(symbol_id) {
+            let binding = scoping
+                .scope_ancestors(reference.scope_id())
+                .take_while(|&scope_id| scope_id != symbol_scope)
+                .find_map(|scope_id| scoping.get_binding(scope_id, new_ident));
+            if let Some(binding) = binding {
+                return Err(diagnostics::rename_shadowed(
+                    new_name,
+                    self.nodes.kind(reference.node_id()).span(),
+                    scoping.symbol_span(binding),
+                ));
+            }
+        }
+
+        // A reference to a global `new_name` from within the symbol's scope.
+        let is_in_symbol_scope = |scope_id: ScopeId| {
+            scope_id == symbol_scope || scoping.scope_is_descendant_of(scope_id, symbol_scope)
+        };
+        if let Some(reference_ids) = scoping.root_unresolved_references().get(new_name) {
+            for &reference_id in reference_ids {
+                let reference = scoping.get_reference(reference_id);
+                if is_in_symbol_scope(reference.scope_id()) {
+                    return Err(diagnostics::rename_captures(
+                        new_name,
+                        self.nodes.kind(reference.node_id()).span(),
+                    ));
+                }
+            }
+        }
+
+        // A reference to another `new_name`, declared outside the symbol's scope,
+        // from within the symbol's scope.
+        for other in scoping.symbol_ids() {
+            if scoping.symbol_name(other) != new_name
+                || is_in_symbol_scope(scoping.symbol_scope_id(other))
+            {
+                continue;
+            }
+            for reference in scoping.get_resolved_references(other) {
+                if is_in_symbol_scope(reference.scope_id()) {
+                    return Err(diagnostics::rename_captures(
+                        new_name,
+                        self.nodes.kind(reference.node_id()).span(),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether the declaration node `node_id` is exported by name: `export const foo = 1`.
+    fn is_exported_declaration(&self, node_id: NodeId) -> bool {
+        let mut parent = self.nodes.parent_kind(node_id);
+        if let AstKind::VariableDeclaration(_) = parent {
+            parent = self.nodes.parent_kind(self.nodes.parent_id(node_id));
+        }
+        matches!(parent, AstKind::ExportDeclaration(_))
+    }
+
+    /// Rename the `old_name` members accessed on the namespace import `namespace`.
+    fn rename_namespace_members(
+        &self,
+        namespace: SymbolId,
+        old_name: &str,
+        new_name: &str,
+        edits: &mut Vec<RenameEdit>,
+    ) {
+        for reference in self.scoping.get_resolved_references(namespace) {
+            let span = match self.nodes.parent_kind(reference.node_id()) {
+                // `ns.foo`, the reference being the object since the property is not one.
+                AstKind::StaticMemberExpression(member) if member.property.name == old_name => {
+                    member.property.span
+                }
+                // `ns.Foo` in types
+                AstKind::TSQualifiedName(name) if name.right.name == old_name => name.right.span,
+                _ => continue,
+            };
+            edits.push(RenameEdit::new(span, new_name));
+        }
+    }
+}
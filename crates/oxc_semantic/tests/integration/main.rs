@@ -4,6 +4,7 @@ pub mod cfg;
 pub mod classes;
 pub mod enum_values;
 pub mod modules;
+pub mod rename;
 pub mod scopes;
 pub mod symbols;
 pub mod util;
@@ -0,0 +1,196 @@
+use oxc_allocator::Allocator;
+use oxc_semantic::{RenameEdit, SemanticBuilder};
+use oxc_span::SourceType;
+
+/// Rename the first symbol named `name` in `source_text` to `new_name`.
+/// Returns the renamed source text, or the error message.
+fn rename(source_text: &str, name: &str, new_name: &str) -> Result<String, String> {
+    rename_with_export(source_text, name, new_name).map(|(output, _)| output)
+}
+
+fn rename_with_export(
+    source_text: &str,
+    name: &str,
+    new_name: &str,
+) -> Result<(String, bool), String> {
+    let allocator = Allocator::default();
+    let source_type = SourceType::tsx();
+    let parsed = oxc_parser::Parser::new(&allocator, source_text, source_type).parse();
+    assert!(parsed.diagnostics.is_empty(), "{:?}", parsed.diagnostics);
+    let semantic = SemanticBuilder::new().with_build_nodes(true).build(&parsed.program).semantic;
+    let symbol_id = semantic
+        .scoping()
+        .symbol_ids()
+        .find(|&symbol_id| semantic.scoping().symbol_name(symbol_id) == name)
+        .unwrap();
+    semantic
+        .rename_symbol(symbol_id, new_name)
+        .map(|rename| (RenameEdit::apply(source_text, &rename.edits), rename.export_renamed))
+        .map_err(|error| error.to_string())
+}
+
+/// Rename the export `old_name` of the module `./mod`, imported by `source_text`.
+fn rename_import(source_text: &str, old_name: &str, new_name: &str) -> String {
+    let allocator = Allocator::default();
+    let parsed = oxc_parser::Parser::new(&allocator, source_text, SourceType::ts()).parse();
+    assert!(parsed.diagnostics.is_empty(), "{:?}", parsed.diagnostics);
+    let semantic = SemanticBuilder::new().with_build_nodes(true).build(&parsed.program).semantic;
+    let edits = semantic.rename_import(&parsed.module_record, "./mod", old_name, new_name);
+    RenameEdit::apply(source_text, &edits)
+}
+
+#[test]
+fn test_rename_references() {
+    assert_eq!(
+        rename("let a = 1; a++; function f() { return a; }", "a", "b").unwrap(),
+        "let b = 1; b++; function f() { return b; }"
+    );
+    // Only the binding resolving to the symbol is renamed.
+    assert_eq!(
+        rename("let a = 1; function f(a) { return a; } a;", "a", "b").unwrap(),
+        "let b = 1; function f(a) { return a; } b;"
+    );
+    assert_eq!(rename("let a; obj.a = a;", "a", "b").unwrap(), "let b; obj.a = b;");
+    assert_eq!(rename("var a; var a; a;", "a", "b").unwrap(), "var b; var b; b;");
+    assert_eq!(
+        rename("interface A { x: A } let a: A; type B = typeof a;", "A", "C").unwrap(),
+        "interface C { x: C } let a: C; type B = typeof a;"
+    );
+    assert_eq!(rename("let a;", "a", "a").unwrap(), "let a;");
+}
+
+#[test]
+fn test_rename_shorthand_properties() {
+    assert_eq!(rename("let a; ({ a });", "a", "b").unwrap(), "let b; ({ a: b });");
+    assert_eq!(rename("let a; ({ a } = obj);", "a", "b").unwrap(), "let b; ({ a: b } = obj);");
+    assert_eq!(
+        rename("let a; ({ a = 1 } = obj);", "a", "b").unwrap(),
+        "let b; ({ a: b = 1 } = obj);"
+    );
+    assert_eq!(rename("const { a } = obj; a;", "a", "b").unwrap(), "const { a: b } = obj; b;");
+    assert_eq!(
+        rename("const { a = 1 } = obj; a;", "a", "b").unwrap(),
+        "const { a: b = 1 } = obj; b;"
+    );
+    assert_eq!(
+        rename("const { x: a } = obj; ({ a: a });", "a", "b").unwrap(),
+        "const { x: b } = obj; ({ a: b });"
+    );
+}
+
+#[test]
+fn test_rename_imports_and_exports() {
+    assert_eq!(
+        rename("import { a } from './mod'; a;", "a", "b").unwrap(),
+        "import { a as b } from './mod'; b;"
+    );
+    assert_eq!(
+        rename("import { x as a } from './mod'; a;", "a", "b").unwrap(),
+        "import { x as b } from './mod'; b;"
+    );
+    assert_eq!(
+        rename("import a, * as ns from './mod'; a;", "a", "b").unwrap(),
+        "import b, * as ns from './mod'; b;"
+    );
+    assert_eq!(
+        rename_with_export("let a; export { a, a as c };", "a", "b").unwrap(),
+        ("let b; export { b as a, b as c };".to_string(), false)
+    );
+    assert_eq!(
+        rename_with_export("export default function a() {} a();", "a", "b").unwrap(),
+        ("export default function b() {} b();".to_string(), false)
+    );
+    assert_eq!(
+        rename_with_export("export const a = 1; a;", "a", "b").unwrap(),
+        ("export const b = 1; b;".to_string(), true)
+    );
+    assert_eq!(
+        rename_with_export("export function a() {}", "a", "b").unwrap(),
+        ("export function b() {}".to_string(), true)
+    );
+}
+
+#[test]
+fn test_rename_jsx() {
+    assert_eq!(
+        rename("function Foo() {} <Foo><Foo.Bar /></Foo>;", "Foo", "Bar").unwrap(),
+        "function Bar() {} <Bar><Bar.Bar /></Bar>;"
+    );
+    assert_eq!(rename("let a; <a.B />;", "a", "b").unwrap(), "let b; <b.B />;");
+    assert_eq!(
+        rename("function Foo() {} <Foo />;", "Foo", "foo").unwrap_err(),
+        "Cannot rename to `foo`: a lowercase JSX tag is an intrinsic element, not a component"
+    );
+    // Intrinsic elements are not references.
+    assert_eq!(rename("let div; <div />;", "div", "span").unwrap(), "let span; <div />;");
+}
+
+#[test]
+fn test_rename_conflicts() {
+    assert_eq!(
+        rename("let a, b;", "a", "b").unwrap_err(),
+        "Cannot rename to `b`: it is already declared in this scope"
+    );
+    assert_eq!(
+        rename("let a; function f() { let b; return a; }", "a", "b").unwrap_err(),
+        "Cannot rename to `b`: a reference would resolve to another `b`"
+    );
+    assert_eq!(
+        rename("let a; function f() { return b; }", "a", "b").unwrap_err(),
+        "Cannot rename to `b`: an existing reference to `b` would resolve to the renamed symbol"
+    );
+    assert_eq!(
+        rename("let b; function f() { let a; return b; }", "a", "b").unwrap_err(),
+        "Cannot rename to `b`: an existing reference to `b` would resolve to the renamed symbol"
+    );
+    // `b` is declared in a scope without references to `a`.
+    assert_eq!(
+        rename("let a; function f() { let b; return b; } a;", "a", "b").unwrap(),
+        "let b; function f() { let b; return b; } b;"
+    );
+    // References to an inner `b` keep resolving to it.
+    assert_eq!(
+        rename("function f() { let a; { let b; b; } }", "a", "b").unwrap(),
+        "function f() { let b; { let b; b; } }"
+    );
+    assert_eq!(
+        rename("let a;", "a", "class").unwrap_err(),
+        "Cannot rename to `class`: it is not a valid identifier"
+    );
+    assert_eq!(
+        rename("let a;", "a", "1a").unwrap_err(),
+        "Cannot rename to `1a`: it is not a valid identifier"
+    );
+    assert_eq!(
+        rename("enum E { A } E.A;", "A", "B").unwrap_err(),
+        "Cannot rename `A`: enum members are accessed as properties"
+    );
+    assert_eq!(
+        rename("namespace N { export const a = 1; } N.a;", "a", "b").unwrap_err(),
+        "Cannot rename `a`: namespace exports are accessed as properties"
+    );
+}
+
+#[test]
+fn test_rename_import() {
+    assert_eq!(
+        rename_import("import { a } from './mod'; a;", "a", "b"),
+        "import { b as a } from './mod'; a;"
+    );
+    assert_eq!(
+        rename_import("import { a as c, x } from './mod'; c;", "a", "b"),
+        "import { b as c, x } from './mod'; c;"
+    );
+    assert_eq!(
+        rename_import("import { a } from './other'; a;", "a", "b"),
+        "import { a } from './other'; a;"
+    );
+    assert_eq!(
+        rename_import("import * as ns from './mod'; ns.a(); ns.x; type T = ns.a;", "a", "b"),
+        "import * as ns from './mod'; ns.b(); ns.x; type T = ns.b;"
+    );
+    assert_eq!(
+        rename_import("export { a, a as c } from './mod';", "a", "b"),
+        "export { b as a, b as c } from './mod';"
+    );
+}
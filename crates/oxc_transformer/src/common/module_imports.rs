@@ -23,6 +23,10 @@
 //!     BoundIdentifier::new(Ident::new_const("React"), symbol_id),
 //!     false,
 //! );
+//!
+//! // ESM: import 'core-js/modules/es.array.at.js';
+//! // CJS: require('core-js/modules/es.array.at.js');
+//! ctx.state.module_imports.add_side_effect_import(Str::from("core-js/modules/es.array.at.js"));
 //! ```
 //!
 //! > NOTE: Using `import` or `require` is determined by `TransformState::source_type`.
@@ -97,6 +101,18 @@ impl<'a> ModuleImportsStore<'a> {
         self.add_import(source, Import::Named(NamedImport { imported, local }), front);
     }
 
+    /// Add side-effect `import` or `require` to top of program.
+    ///
+    /// Which it will be depends on the source type.
+    ///
+    /// * `import 'source';` or
+    /// * `require('source');`
+    ///
+    /// Nothing is added if `source` is already imported.
+    pub fn add_side_effect_import(&mut self, source: Str<'a>) {
+        self.imports.entry(source).or_default();
+    }
+
     /// Returns `true` if no imports have been scheduled for insertion.
     pub fn is_empty(&self) -> bool {
         self.imports.is_empty()
@@ -141,27 +157,31 @@ impl<'a> ModuleImportsStore<'a> {
         names: Vec<Import<'a>>,
         ctx: &TraverseCtx<'a>,
     ) -> Statement<'a> {
-        let specifiers = ArenaVec::from_iter_in(
-            names.into_iter().map(|import| match import {
-                Import::Named(import) => ImportDeclarationSpecifier::new_import_specifier(
-                    SPAN,
-                    ModuleExportName::new_identifier_name(SPAN, import.imported, ctx),
-                    import.local.create_binding_identifier(ctx),
-                    ImportOrExportKind::Value,
-                    ctx,
-                ),
-                Import::Default(local) => ImportDeclarationSpecifier::new_import_default_specifier(
-                    SPAN,
-                    local.create_binding_identifier(ctx),
-                    ctx,
-                ),
-            }),
-            ctx,
-        );
+        let specifiers = (!names.is_empty()).then(|| {
+            ArenaVec::from_iter_in(
+                names.into_iter().map(|import| match import {
+                    Import::Named(import) => ImportDeclarationSpecifier::new_import_specifier(
+                        SPAN,
+                        ModuleExportName::new_identifier_name(SPAN, import.imported, ctx),
+                        import.local.create_binding_identifier(ctx),
+                        ImportOrExportKind::Value,
+                        ctx,
+                    ),
+                    Import::Default(local) => {
+                        ImportDeclarationSpecifier::new_import_default_specifier(
+                            SPAN,
+                            local.create_binding_identifier(ctx),
+                            ctx,
+                        )
+                    }
+                }),
+                ctx,
+            )
+        });
 
         Statement::new_import_declaration(
             SPAN,
-            Some(specifiers),
+            specifiers,
             StringLiteral::new(SPAN, source, None, ctx),
             None,
             None,
@@ -187,7 +207,11 @@ impl<'a> ModuleImportsStore<'a> {
             let arg = Argument::new_string_literal(SPAN, source, None, ctx);
             [arg]
         };
-        let Some(Import::Default(local)) = names.into_iter().next() else { unreachable!() };
+        let Some(import) = names.into_iter().next() else {
+            let expr = Expression::new_call_expression(SPAN, callee, None, args, false, ctx);
+            return Statement::new_expression_statement(SPAN, expr, ctx);
+        };
+        let Import::Default(local) = import else { unreachable!() };
         let id = local.create_binding_pattern(ctx);
         let var_kind = VariableDeclarationKind::Var;
         let decl = {
@@ -33,6 +33,7 @@ mod es2021;
 mod es2022;
 mod es2026;
 mod jsx;
+mod polyfills;
 mod proposals;
 mod regexp;
 mod typescript;
@@ -53,6 +54,7 @@ use es2021::ES2021;
 use es2022::ES2022;
 use es2026::ES2026;
 use jsx::Jsx;
+use polyfills::Polyfills;
 use regexp::RegExp;
 use rustc_hash::FxHashMap;
 use state::TransformState;
@@ -75,10 +77,12 @@ pub use crate::{
     es2026::ES2026Options,
     jsx::{JsxOptions, JsxRuntime, ReactRefreshOptions},
     options::{
-        ESFeature, ESTarget, Engine, EngineTargets, EnvOptions, Module, TransformOptions,
+        Builtin, BuiltinSet, ESFeature, ESTarget, Engine, EngineTargets, EnvOptions, Module,
+        TransformOptions,
         babel::{BabelEnvOptions, BabelOptions},
     },
     plugins::{PluginsOptions, StyledComponentsOptions},
+    polyfills::PolyfillsOptions,
     proposals::ProposalOptions,
     typescript::{RewriteExtensionsMode, TypeScriptOptions},
 };
@@ -184,6 +188,7 @@ impl<'a> Transformer<'a> {
             x2_es2017: ES2017::new(self.env.es2017),
             x3_es2015: ES2015::new(self.env.es2015),
             x4_regexp: RegExp::new(self.env.regexp),
+            polyfills: Polyfills::new(self.env.polyfills),
         };
 
         let mut reusable_ctx = ReusableTraverseCtx::new(self.state, scoping, allocator);
@@ -214,6 +219,7 @@ struct TransformerImpl<'a> {
     #[expect(unused)]
     x3_es2015: ES2015<'a>,
     x4_regexp: RegExp,
+    polyfills: Option<Polyfills>,
     common: Common<'a>,
 }
 
@@ -313,6 +319,9 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.common.enter_identifier_reference(node, ctx);
+        if let Some(polyfills) = self.polyfills.as_mut() {
+            polyfills.enter_identifier_reference(node, ctx);
+        }
     }
 
     fn enter_binding_pattern(&mut self, pat: &mut BindingPattern<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -356,6 +365,26 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         self.x2_es2022.enter_class_body(body, ctx);
     }
 
+    fn enter_computed_member_expression(
+        &mut self,
+        expr: &mut ComputedMemberExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(polyfills) = self.polyfills.as_mut() {
+            polyfills.enter_computed_member_expression(expr, ctx);
+        }
+    }
+
+    fn enter_static_member_expression(
+        &mut self,
+        expr: &mut StaticMemberExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(polyfills) = self.polyfills.as_mut() {
+            polyfills.enter_static_member_expression(expr, ctx);
+        }
+    }
+
     fn enter_static_block(&mut self, block: &mut StaticBlock<'a>, ctx: &mut TraverseCtx<'a>) {
         self.common.enter_static_block(block, ctx);
         self.x2_es2022.enter_static_block(block, ctx);
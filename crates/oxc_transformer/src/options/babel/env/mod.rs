@@ -43,11 +43,13 @@ pub struct BabelEnvOptions {
     pub exclude: Option<serde_json::Value>,
 
     /// Polyfill injection mode.
-    #[deprecated = "Not Implemented"]
+    ///
+    /// Only `"usage"` is supported, which imports `core-js@3` polyfills.
     pub use_built_ins: Option<serde_json::Value>,
 
     /// `core-js` version/options for polyfill injection.
-    #[deprecated = "Not Implemented"]
+    ///
+    /// Only `core-js@3` is supported, and `proposals` is ignored.
     pub corejs: Option<serde_json::Value>,
 
     /// Force all eligible transforms regardless of targets.
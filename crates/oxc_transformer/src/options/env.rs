@@ -10,6 +10,7 @@ use crate::{
     es2021::ES2021Options,
     es2022::{ClassPropertiesOptions, ES2022Options},
     es2026::ES2026Options,
+    polyfills::PolyfillsOptions,
     regexp::RegExpOptions,
 };
 
@@ -22,6 +23,7 @@ use oxc_compat::{ESFeature, EngineTargets};
 ///
 /// Options are listed in evaluation order: syntax is lowered from the newest
 /// supported edition (ES2026) down to ES2015, then RegExp features.
+/// Polyfills are collected alongside.
 pub struct EnvOptions {
     /// Specify what module code is generated.
     ///
@@ -59,6 +61,11 @@ pub struct EnvOptions {
     ///
     /// Runs after all syntax lowering.
     pub regexp: RegExpOptions,
+
+    /// Polyfill options.
+    ///
+    /// Not a transform; imports are added for the built-ins used in the original code.
+    pub polyfills: PolyfillsOptions,
 }
 
 impl EnvOptions {
@@ -110,6 +117,7 @@ impl EnvOptions {
                 match_indices: true,
                 set_notation: true,
             },
+            polyfills: PolyfillsOptions::default(),
         }
     }
 
@@ -139,9 +147,22 @@ impl EnvOptions {
     }
 }
 
-impl From<BabelEnvOptions> for EnvOptions {
-    fn from(o: BabelEnvOptions) -> Self {
-        Self::from(o.targets)
+impl TryFrom<BabelEnvOptions> for EnvOptions {
+    type Error = String;
+
+    fn try_from(o: BabelEnvOptions) -> Result<Self, Self::Error> {
+        let mut options = Self::from(o.targets);
+        if o.use_built_ins.as_ref().and_then(|v| v.as_str()) == Some("usage") {
+            // `corejs: 3`, `corejs: "3.38"` or `corejs: { version: "3.38" }`.
+            let version = match o.corejs.as_ref().map(|v| v.get("version").unwrap_or(v)) {
+                Some(serde_json::Value::String(version)) => version.clone(),
+                Some(serde_json::Value::Number(version)) => version.to_string(),
+                Some(version) => return Err(format!("Invalid core-js version `{version}`.")),
+                None => "3".to_string(),
+            };
+            options.polyfills.core_js = Some(PolyfillsOptions::parse_core_js_version(&version)?);
+        }
+        Ok(options)
     }
 }
 
@@ -194,6 +215,10 @@ impl From<EngineTargets> for EnvOptions {
                 match_indices: o.has_feature(ES2022MatchIndicesRegex),
                 set_notation: o.has_feature(ES2024UnicodeSetsRegex),
             },
+            polyfills: PolyfillsOptions {
+                core_js: None,
+                unsupported_builtins: o.unsupported_builtins(),
+            },
         }
     }
 }
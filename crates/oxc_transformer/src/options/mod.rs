@@ -28,7 +28,7 @@ mod module;
 use babel::BabelOptions;
 pub use env::EnvOptions;
 pub use module::Module;
-pub use oxc_compat::{ESFeature, Engine, EngineTargets};
+pub use oxc_compat::{Builtin, BuiltinSet, ESFeature, Engine, EngineTargets};
 pub use oxc_syntax::es_target::ESTarget;
 
 /// <https://babel.dev/docs/options>
@@ -313,6 +313,7 @@ impl TryFrom<&BabelOptions> for TransformOptions {
                 es2016,
                 es2015,
                 regexp,
+                polyfills: env.polyfills,
             },
             proposals: ProposalOptions::default(),
             helper_loader,
@@ -0,0 +1,238 @@
+//! Polyfills
+//!
+//! Imports `core-js` polyfills for the built-ins which are used in the code and are not
+//! supported by the targets.
+//!
+//! ```js
+//! Object.groupBy(items, fn);
+//! items.at(-1);
+//! ```
+//!
+//! With targets which support neither, this becomes:
+//!
+//! ```js
+//! import 'core-js/modules/es.object.group-by.js';
+//! import 'core-js/modules/es.array.at.js';
+//! import 'core-js/modules/es.string.at-alternative.js';
+//! Object.groupBy(items, fn);
+//! items.at(-1);
+//! ```
+//!
+//! Global references (`structuredClone`) and static methods (`Object.groupBy`) are resolved
+//! through semantic, so shadowed bindings are not polyfilled. As with Babel, the receiver of a
+//! prototype method is unknown, so all built-ins with the method name are polyfilled.
+//!
+//! Built-ins which cannot be polyfilled, such as `BigInt` and `WeakRef`, are ignored, and so are
+//! built-ins whose `core-js` module was added after the configured `core-js` version.
+//!
+//! References:
+//! * Babel: <https://babeljs.io/docs/babel-preset-env#usebuiltins-usage>
+//! * <https://github.com/babel/babel-polyfills/tree/main/packages/babel-plugin-polyfill-corejs3>
+
+use oxc_ast::ast::*;
+use oxc_compat::{Builtin, BuiltinSet, Version, builtins};
+use oxc_semantic::IsGlobalReference;
+use oxc_str::Str;
+use oxc_traverse::Traverse;
+use rustc_hash::FxHashMap;
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+mod options;
+
+pub use options::PolyfillsOptions;
+
+pub struct Polyfills {
+    /// Unsupported globals, e.g. `structuredClone`.
+    globals: FxHashMap<&'static str, Builtin>,
+    /// Unsupported static methods, e.g. `Object.groupBy`.
+    static_methods: FxHashMap<(&'static str, &'static str), Builtin>,
+    /// Unsupported prototype methods by method name, e.g. `at` for `Array.prototype.at` and
+    /// `String.prototype.at`.
+    prototype_methods: FxHashMap<&'static str, Vec<Builtin>>,
+    /// Built-ins which have been imported.
+    imported: BuiltinSet,
+}
+
+impl Polyfills {
+    pub fn new(options: PolyfillsOptions) -> Option<Self> {
+        let core_js = options.core_js?;
+        if options.unsupported_builtins.is_empty() {
+            return None;
+        }
+        let mut polyfills = Self {
+            globals: FxHashMap::default(),
+            static_methods: FxHashMap::default(),
+            prototype_methods: FxHashMap::default(),
+            imported: BuiltinSet::default(),
+        };
+        let builtins = builtins()
+            .keys()
+            .copied()
+            .filter(|builtin| options.unsupported_builtins.contains(*builtin))
+            .filter(|builtin| {
+                core_js_module(*builtin).is_some_and(|(_, added)| Version(3, added, 0) <= core_js)
+            });
+        for builtin in builtins {
+            let name = builtin.name();
+            if let Some((_, method)) = name.split_once(".prototype.") {
+                polyfills.prototype_methods.entry(method).or_default().push(builtin);
+            } else if let Some((object, method)) = name.split_once('.') {
+                polyfills.static_methods.insert((object, method), builtin);
+            } else {
+                polyfills.globals.insert(name, builtin);
+            }
+        }
+        // Import in a stable order.
+        for builtins in polyfills.prototype_methods.values_mut() {
+            builtins.sort_unstable_by_key(|builtin| core_js_module(*builtin));
+        }
+        Some(polyfills)
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Polyfills {
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(&builtin) = self.globals.get(ident.name.as_str())
+            && ident.is_global_reference(ctx.scoping())
+        {
+            self.import(builtin, ctx);
+        }
+    }
+
+    fn enter_static_member_expression(
+        &mut self,
+        expr: &mut StaticMemberExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.enter_member(&expr.object, expr.property.name.as_str(), ctx);
+    }
+
+    fn enter_computed_member_expression(
+        &mut self,
+        expr: &mut ComputedMemberExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(property) = expr.static_property_name() {
+            self.enter_member(&expr.object, property.as_str(), ctx);
+        }
+    }
+}
+
+impl<'a> Polyfills {
+    /// Import the polyfills for `object[property]`.
+    fn enter_member(&mut self, object: &Expression<'a>, property: &str, ctx: &mut TraverseCtx<'a>) {
+        if let Expression::Identifier(ident) = object
+            && let Some(&builtin) = self.static_methods.get(&(ident.name.as_str(), property))
+        {
+            if ident.is_global_reference(ctx.scoping()) {
+                self.import(builtin, ctx);
+            }
+            return;
+        }
+        if let Some(builtins) = self.prototype_methods.get(property) {
+            for builtin in builtins.clone() {
+                self.import(builtin, ctx);
+            }
+        }
+    }
+
+    fn import(&mut self, builtin: Builtin, ctx: &mut TraverseCtx<'a>) {
+        if self.imported.contains(builtin) {
+            return;
+        }
+        self.imported.insert(builtin);
+        let Some((module, _)) = core_js_module(builtin) else { return };
+        let source = Str::from_strs_array_in(["core-js/modules/", module, ".js"], ctx);
+        ctx.state.module_imports.add_side_effect_import(source);
+    }
+}
+
+/// The `core-js@3` module polyfilling `builtin`, and the minor version of `core-js@3` which
+/// added it.
+///
+/// <https://github.com/zloirock/core-js/tree/master/packages/core-js/modules>
+/// <https://github.com/zloirock/core-js/blob/master/packages/core-js-compat/src/modules-by-versions.mjs>
+fn core_js_module(builtin: Builtin) -> Option<(&'static str, u16)> {
+    let module = match builtin {
+        Builtin::GlobalThis => ("es.global-this", 3),
+        Builtin::StructuredClone => ("web.structured-clone", 20),
+        Builtin::QueueMicrotask => ("web.queue-microtask", 0),
+        // Cannot be polyfilled.
+        Builtin::BigInt | Builtin::WeakRef | Builtin::FinalizationRegistry => return None,
+        Builtin::AggregateError => ("es.aggregate-error", 7),
+        Builtin::Promise => ("es.promise", 0),
+        Builtin::Map => ("es.map", 0),
+        Builtin::Set => ("es.set", 0),
+        Builtin::WeakMap => ("es.weak-map", 0),
+        Builtin::WeakSet => ("es.weak-set", 0),
+        Builtin::Symbol => ("es.symbol", 0),
+        Builtin::Iterator => ("es.iterator.constructor", 39),
+        Builtin::ObjectAssign => ("es.object.assign", 0),
+        Builtin::ObjectEntries => ("es.object.entries", 0),
+        Builtin::ObjectValues => ("es.object.values", 0),
+        Builtin::ObjectGetOwnPropertyDescriptors => ("es.object.get-own-property-descriptors", 0),
+        Builtin::ObjectFromEntries => ("es.object.from-entries", 0),
+        Builtin::ObjectHasOwn => ("es.object.has-own", 17),
+        Builtin::ObjectGroupBy => ("es.object.group-by", 34),
+        Builtin::MapGroupBy => ("es.map.group-by", 34),
+        Builtin::ArrayFrom => ("es.array.from", 0),
+        Builtin::ArrayOf => ("es.array.of", 0),
+        Builtin::SymbolAsyncIterator => ("es.symbol.async-iterator", 0),
+        Builtin::PromiseAllSettled => ("es.promise.all-settled", 2),
+        Builtin::PromiseAny => ("es.promise.any", 7),
+        Builtin::PromiseWithResolvers => ("es.promise.with-resolvers", 34),
+        Builtin::PromiseTry => ("es.promise.try", 39),
+        Builtin::IteratorFrom => ("es.iterator.from", 39),
+        Builtin::ArrayPrototypeFind => ("es.array.find", 0),
+        Builtin::ArrayPrototypeFindIndex => ("es.array.find-index", 0),
+        Builtin::ArrayPrototypeFill => ("es.array.fill", 0),
+        Builtin::ArrayPrototypeIncludes => ("es.array.includes", 0),
+        Builtin::ArrayPrototypeFlat => ("es.array.flat", 0),
+        Builtin::ArrayPrototypeFlatMap => ("es.array.flat-map", 0),
+        Builtin::ArrayPrototypeAt => ("es.array.at", 17),
+        Builtin::ArrayPrototypeFindLast => ("es.array.find-last", 23),
+        Builtin::ArrayPrototypeFindLastIndex => ("es.array.find-last-index", 23),
+        Builtin::ArrayPrototypeToReversed => ("es.array.to-reversed", 27),
+        Builtin::ArrayPrototypeToSorted => ("es.array.to-sorted", 27),
+        Builtin::ArrayPrototypeToSpliced => ("es.array.to-spliced", 27),
+        Builtin::ArrayPrototypeWith => ("es.array.with", 27),
+        Builtin::StringPrototypeStartsWith => ("es.string.starts-with", 0),
+        Builtin::StringPrototypeEndsWith => ("es.string.ends-with", 0),
+        Builtin::StringPrototypeIncludes => ("es.string.includes", 0),
+        Builtin::StringPrototypeRepeat => ("es.string.repeat", 0),
+        Builtin::StringPrototypePadStart => ("es.string.pad-start", 0),
+        Builtin::StringPrototypePadEnd => ("es.string.pad-end", 0),
+        Builtin::StringPrototypeTrimStart => ("es.string.trim-start", 0),
+        Builtin::StringPrototypeTrimEnd => ("es.string.trim-end", 0),
+        Builtin::StringPrototypeMatchAll => ("es.string.match-all", 0),
+        Builtin::StringPrototypeReplaceAll => ("es.string.replace-all", 7),
+        Builtin::StringPrototypeAt => ("es.string.at-alternative", 17),
+        Builtin::StringPrototypeIsWellFormed => ("es.string.is-well-formed", 32),
+        Builtin::StringPrototypeToWellFormed => ("es.string.to-well-formed", 32),
+        Builtin::PromisePrototypeFinally => ("es.promise.finally", 0),
+        Builtin::SetPrototypeUnion => ("es.set.union.v2", 37),
+        Builtin::SetPrototypeIntersection => ("es.set.intersection.v2", 37),
+        Builtin::SetPrototypeDifference => ("es.set.difference.v2", 37),
+        Builtin::SetPrototypeSymmetricDifference => ("es.set.symmetric-difference.v2", 37),
+        Builtin::SetPrototypeIsSubsetOf => ("es.set.is-subset-of.v2", 37),
+        Builtin::SetPrototypeIsSupersetOf => ("es.set.is-superset-of.v2", 37),
+        Builtin::SetPrototypeIsDisjointFrom => ("es.set.is-disjoint-from.v2", 37),
+        Builtin::IteratorPrototypeMap => ("es.iterator.map", 39),
+        Builtin::IteratorPrototypeFilter => ("es.iterator.filter", 39),
+        Builtin::IteratorPrototypeTake => ("es.iterator.take", 39),
+        Builtin::IteratorPrototypeDrop => ("es.iterator.drop", 39),
+        Builtin::IteratorPrototypeFlatMap => ("es.iterator.flat-map", 39),
+        Builtin::IteratorPrototypeReduce => ("es.iterator.reduce", 39),
+        Builtin::IteratorPrototypeToArray => ("es.iterator.to-array", 39),
+        Builtin::IteratorPrototypeForEach => ("es.iterator.for-each", 39),
+        Builtin::IteratorPrototypeSome => ("es.iterator.some", 39),
+        Builtin::IteratorPrototypeEvery => ("es.iterator.every", 39),
+        Builtin::IteratorPrototypeFind => ("es.iterator.find", 39),
+    };
+    Some(module)
+}
@@ -0,0 +1,32 @@
+use std::str::FromStr;
+
+use oxc_compat::{BuiltinSet, Version};
+
+#[derive(Debug, Default, Clone, Copy)]
+/// Polyfill injection options.
+pub struct PolyfillsOptions {
+    /// Import `core-js@3` polyfills for the used built-ins in [`Self::unsupported_builtins`].
+    ///
+    /// The version of `core-js` which is installed. Polyfills which were added in later versions
+    /// are not imported, so specify the minor version, e.g. `3.38`.
+    ///
+    /// Same as `babel-preset-env`'s `useBuiltIns: "usage"` with `corejs`.
+    pub core_js: Option<Version>,
+
+    /// Built-ins which are not supported by the targets.
+    pub unsupported_builtins: BuiltinSet,
+}
+
+impl PolyfillsOptions {
+    /// Parse the `core-js` version, e.g. `3` or `3.38`.
+    ///
+    /// # Errors
+    ///
+    /// When the version is invalid or not `core-js@3`.
+    pub fn parse_core_js_version(version: &str) -> Result<Version, String> {
+        match Version::from_str(version) {
+            Ok(version) if version.0 == 3 => Ok(version),
+            _ => Err(format!("Invalid core-js version `{version}`, only core-js@3 is supported.")),
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use oxc_span::SourceType;
+use oxc_transformer::{BabelOptions, EnvOptions, PolyfillsOptions, TransformOptions};
+
+use crate::{codegen, test, test_with_source_type};
+
+fn options_with_core_js(target: &str, core_js: &str) -> TransformOptions {
+    let mut env = EnvOptions::from_target(target).unwrap();
+    env.polyfills.core_js = Some(PolyfillsOptions::parse_core_js_version(core_js).unwrap());
+    TransformOptions { env, ..TransformOptions::default() }
+}
+
+fn options(target: &str) -> TransformOptions {
+    options_with_core_js(target, "3.39")
+}
+
+#[test]
+fn core_js() {
+    let chrome100 = options("chrome100");
+    for (source_text, expected) in [
+        (
+            "Object.groupBy(a, f); Promise.withResolvers(); structuredClone(x);",
+            "import 'core-js/modules/es.object.group-by.js';\nimport 'core-js/modules/es.promise.with-resolvers.js';\nObject.groupBy(a, f);\nPromise.withResolvers();\nstructuredClone(x);\n",
+        ),
+        // Prototype methods, including computed and optional member expressions.
+        (
+            "a.toSorted(); a?.with(0, 1); a['toReversed']();",
+            "import 'core-js/modules/es.array.to-sorted.js';\nimport 'core-js/modules/es.array.with.js';\nimport 'core-js/modules/es.array.to-reversed.js';\na.toSorted();\na?.with(0, 1);\na['toReversed']();\n",
+        ),
+        // Imported once.
+        (
+            "Object.groupBy(a); Object.groupBy(b);",
+            "import 'core-js/modules/es.object.group-by.js';\nObject.groupBy(a);\nObject.groupBy(b);\n",
+        ),
+    ] {
+        assert_eq!(test(source_text, &chrome100).unwrap(), expected, "{source_text}");
+    }
+
+    // The receiver of a prototype method is unknown.
+    assert_eq!(
+        test("a.at(-1);", &options("chrome90")).unwrap(),
+        "import 'core-js/modules/es.array.at.js';\nimport 'core-js/modules/es.string.at-alternative.js';\na.at(-1);\n",
+    );
+
+    // ES2015 built-ins, and iterator helpers which share their names with array methods.
+    assert_eq!(
+        test("new Map(Array.from(s)); Promise.resolve(); a.find(f);", &options("chrome30"))
+            .unwrap(),
+        "import 'core-js/modules/es.map.js';\nimport 'core-js/modules/es.array.from.js';\nimport 'core-js/modules/es.promise.js';\nimport 'core-js/modules/es.array.find.js';\nimport 'core-js/modules/es.iterator.find.js';\nnew Map(Array.from(s));\nPromise.resolve();\na.find(f);\n",
+    );
+    assert_eq!(
+        test("Iterator.from(it).toArray(); s.union(t);", &options("chrome100")).unwrap(),
+        "import 'core-js/modules/es.iterator.to-array.js';\nimport 'core-js/modules/es.iterator.from.js';\nimport 'core-js/modules/es.iterator.constructor.js';\nimport 'core-js/modules/es.set.union.v2.js';\nIterator.from(it).toArray();\ns.union(t);\n",
+    );
+
+    // `require` in scripts.
+    assert_eq!(
+        test_with_source_type("Object.hasOwn(a, b);", SourceType::cjs(), &options("chrome90"))
+            .unwrap(),
+        "require('core-js/modules/es.object.has-own.js');\nObject.hasOwn(a, b);\n",
+    );
+}
+
+#[test]
+fn core_js_skipped() {
+    let chrome100 = options("chrome100");
+    for source_text in [
+        // Supported by the targets.
+        "a.at(-1); structuredClone(x); Object.hasOwn(a, b);",
+        // Not globals.
+        "const Object = {}; Object.groupBy(a, f);",
+        "function f(structuredClone) { structuredClone(x); }",
+        // Cannot be polyfilled.
+        "new WeakRef(a);",
+    ] {
+        assert_eq!(
+            test(source_text, &chrome100).unwrap(),
+            codegen(source_text, SourceType::mjs()),
+            "{source_text}"
+        );
+    }
+
+    // Not enabled.
+    let options = TransformOptions {
+        env: EnvOptions::from_target("chrome100").unwrap(),
+        ..TransformOptions::default()
+    };
+    let source_text = "Object.groupBy(a, f);";
+    assert_eq!(test(source_text, &options).unwrap(), codegen(source_text, SourceType::mjs()));
+}
+
+#[test]
+fn core_js_version() {
+    // Modules added after the installed `core-js` version are not imported.
+    let source_text = "Object.groupBy(a, f); Object.entries(a);";
+    assert_eq!(
+        test(source_text, &options_with_core_js("chrome50", "3.33")).unwrap(),
+        "import 'core-js/modules/es.object.entries.js';\nObject.groupBy(a, f);\nObject.entries(a);\n",
+    );
+    assert_eq!(
+        test(source_text, &options_with_core_js("chrome50", "3.34")).unwrap(),
+        "import 'core-js/modules/es.object.group-by.js';\nimport 'core-js/modules/es.object.entries.js';\nObject.groupBy(a, f);\nObject.entries(a);\n",
+    );
+
+    assert!(PolyfillsOptions::parse_core_js_version("2.6").is_err());
+    assert!(PolyfillsOptions::parse_core_js_version("latest").is_err());
+}
+
+#[test]
+fn babel_use_built_ins() {
+    let babel_options = |corejs: serde_json::Value| -> BabelOptions {
+        serde_json::from_value(serde_json::json!({
+            "presets": [["env", { "targets": { "chrome": "100" }, "useBuiltIns": "usage", "corejs": corejs }]]
+        }))
+        .unwrap()
+    };
+    let source_text = "Object.groupBy(a, f);";
+    let expected = "import 'core-js/modules/es.object.group-by.js';\nObject.groupBy(a, f);\n";
+    for corejs in [serde_json::json!("3.38"), serde_json::json!({ "version": "3.38" })] {
+        let options = TransformOptions::try_from(&babel_options(corejs)).unwrap();
+        assert_eq!(test(source_text, &options).unwrap(), expected);
+    }
+
+    // `corejs: 3` is `core-js@3.0`, as in Babel.
+    let options = TransformOptions::try_from(&babel_options(serde_json::json!(3))).unwrap();
+    assert_eq!(test(source_text, &options).unwrap(), codegen(source_text, SourceType::mjs()));
+
+    assert!(TransformOptions::try_from(&babel_options(serde_json::json!(2))).is_err());
+}
@@ -0,0 +1,489 @@
+//! File-local type inference.
+//!
+//! The checker does not build types for a whole [`Program`](crate::compiler::Program) yet. Until
+//! it does, [`TypeInference`] answers the narrower question "what kind of value does this
+//! expression evaluate to?" using only one file's AST and [`Semantic`]: literals, operators,
+//! type annotations, `async` functions and the `Promise` global.
+//!
+//! Inference is conservative. Whenever the answer depends on something outside the file (an
+//! import, an un-annotated parameter, a user-defined type), it gives up and returns `None`, so
+//! callers can rely on every `Some` they get.
+
+use oxc_ast::{
+    AstKind,
+    ast::{
+        ArrowFunctionExpression, BindingPattern, CallExpression, Expression, FormalParameter,
+        Function, IdentifierReference, NewExpression, ObjectExpression, ObjectPropertyKind,
+        SimpleAssignmentTarget, TSLiteral, TSSignature, TSType, TSTypeName, TSTypeOperatorOperator,
+        UnaryExpression, VariableDeclarator,
+    },
+};
+use oxc_semantic::{IsGlobalReference, Semantic, SymbolId};
+use oxc_syntax::operator::{BinaryOperator, LogicalOperator, UnaryOperator};
+
+/// How deep to follow identifiers to their initializers before giving up.
+/// Guards against cycles like `const a = b, b = a`.
+const MAX_DEPTH: u8 = 16;
+
+/// The kind of value an expression evaluates to.
+///
+/// This is much coarser than a TypeScript type: `string` and `"foo"` are both
+/// [`TypeKind::String`], and type arguments (e.g. `T` in `Promise<T>`) are dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TypeKind {
+    /// `any`
+    Any,
+    /// `unknown`
+    Unknown,
+    /// `never`
+    Never,
+    /// `void`
+    Void,
+    /// `undefined`
+    Undefined,
+    /// `null`
+    Null,
+    /// `boolean` and boolean literals.
+    Boolean,
+    /// `number` and numeric literals.
+    Number,
+    /// `bigint` and bigint literals.
+    BigInt,
+    /// `string`, string literals and template literals.
+    String,
+    /// `symbol` and `unique symbol`.
+    Symbol,
+    /// `RegExp` and regular expression literals.
+    RegExp,
+    /// Arrays and tuples.
+    Array,
+    /// `Promise` and `PromiseLike`.
+    Promise,
+    /// Functions, classes and function types.
+    Function,
+    /// Object literals, type literals and `object`, which are known not to be thenable.
+    Object,
+}
+
+impl TypeKind {
+    /// Name of the kind, as it would appear in a diagnostic.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Any => "any",
+            Self::Unknown => "unknown",
+            Self::Never => "never",
+            Self::Void => "void",
+            Self::Undefined => "undefined",
+            Self::Null => "null",
+            Self::Boolean => "boolean",
+            Self::Number => "number",
+            Self::BigInt => "bigint",
+            Self::String => "string",
+            Self::Symbol => "symbol",
+            Self::RegExp => "RegExp",
+            Self::Array => "array",
+            Self::Promise => "Promise",
+            Self::Function => "function",
+            Self::Object => "object",
+        }
+    }
+
+    /// Returns `true` for `Promise` and `PromiseLike`.
+    pub fn is_thenable(self) -> bool {
+        self == Self::Promise
+    }
+
+    /// Returns `true` for `null`, `undefined` and `void`.
+    pub fn is_nullish(self) -> bool {
+        matches!(self, Self::Null | Self::Undefined | Self::Void)
+    }
+}
+
+/// Infers the [`TypeKind`] of expressions in a single file.
+///
+/// Cheap to create: it borrows the file's [`Semantic`] and holds no other state. The
+/// [`Semantic`] must be built with AST nodes, to look up the declarations of symbols.
+#[derive(Clone, Copy)]
+pub struct TypeInference<'s, 'a> {
+    semantic: &'s Semantic<'a>,
+}
+
+impl<'s, 'a> TypeInference<'s, 'a> {
+    pub fn new(semantic: &'s Semantic<'a>) -> Self {
+        Self { semantic }
+    }
+
+    /// The kind of value `expr` evaluates to, or `None` if it cannot be determined from this file.
+    pub fn expression_type(self, expr: &Expression<'a>) -> Option<TypeKind> {
+        self.expression_type_impl(expr, 0)
+    }
+
+    /// The kind of value described by the type annotation `ty`, or `None` if it refers to a type
+    /// declared elsewhere, or to a union of different kinds.
+    pub fn annotation_type(self, ty: &TSType<'a>) -> Option<TypeKind> {
+        match ty {
+            TSType::TSAnyKeyword(_) => Some(TypeKind::Any),
+            TSType::TSUnknownKeyword(_) => Some(TypeKind::Unknown),
+            TSType::TSNeverKeyword(_) => Some(TypeKind::Never),
+            TSType::TSVoidKeyword(_) => Some(TypeKind::Void),
+            TSType::TSUndefinedKeyword(_) => Some(TypeKind::Undefined),
+            TSType::TSNullKeyword(_) => Some(TypeKind::Null),
+            TSType::TSBooleanKeyword(_) => Some(TypeKind::Boolean),
+            TSType::TSNumberKeyword(_) => Some(TypeKind::Number),
+            TSType::TSBigIntKeyword(_) => Some(TypeKind::BigInt),
+            TSType::TSStringKeyword(_) | TSType::TSTemplateLiteralType(_) => Some(TypeKind::String),
+            TSType::TSSymbolKeyword(_) => Some(TypeKind::Symbol),
+            TSType::TSObjectKeyword(_) => Some(TypeKind::Object),
+            TSType::TSArrayType(_) | TSType::TSTupleType(_) => Some(TypeKind::Array),
+            TSType::TSFunctionType(_) | TSType::TSConstructorType(_) => Some(TypeKind::Function),
+            TSType::TSTypeLiteral(literal) => {
+                let has_then = literal.members.iter().any(|member| {
+                    let key = match member {
+                        TSSignature::TSPropertySignature(prop) => &prop.key,
+                        TSSignature::TSMethodSignature(method) => &method.key,
+                        _ => return false,
+                    };
+                    key.is_specific_static_name("then")
+                });
+                (!has_then).then_some(TypeKind::Object)
+            }
+            TSType::TSLiteralType(literal) => match &literal.literal {
+                TSLiteral::BooleanLiteral(_) => Some(TypeKind::Boolean),
+                TSLiteral::NumericLiteral(_) => Some(TypeKind::Number),
+                TSLiteral::BigIntLiteral(_) => Some(TypeKind::BigInt),
+                TSLiteral::StringLiteral(_) | TSLiteral::TemplateLiteral(_) => {
+                    Some(TypeKind::String)
+                }
+                TSLiteral::UnaryExpression(unary) => self.unary_type(unary, 0),
+            },
+            TSType::TSParenthesizedType(ty) => self.annotation_type(&ty.type_annotation),
+            TSType::TSTypeOperatorType(op) => match op.operator {
+                TSTypeOperatorOperator::Readonly => self.annotation_type(&op.type_annotation),
+                TSTypeOperatorOperator::Unique => Some(TypeKind::Symbol),
+                TSTypeOperatorOperator::Keyof => None,
+            },
+            TSType::TSUnionType(union) => {
+                same_kind(union.types.iter().map(|ty| self.annotation_type(ty)))
+            }
+            TSType::TSTypeReference(reference) => {
+                // A local declaration (`interface Promise {}`, `type RegExp = ...`) shadows the lib type
+                let TSTypeName::IdentifierReference(ident) = &reference.type_name else {
+                    return None;
+                };
+                if !self.is_global(ident) {
+                    return None;
+                }
+                match ident.name.as_str() {
+                    "Promise" | "PromiseLike" => Some(TypeKind::Promise),
+                    "Array" | "ReadonlyArray" => Some(TypeKind::Array),
+                    "RegExp" => Some(TypeKind::RegExp),
+                    "Function" => Some(TypeKind::Function),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// The kind of value a call to `func` returns, from its return type annotation, or from
+    /// it being `async`.
+    pub fn function_return_type(self, func: &Function<'a>) -> Option<TypeKind> {
+        if let Some(return_type) = &func.return_type {
+            return self.annotation_type(&return_type.type_annotation);
+        }
+        (func.r#async && !func.generator).then_some(TypeKind::Promise)
+    }
+
+    /// The kind of value a call to `arrow` returns, from its return type annotation, from it
+    /// being `async`, or from its expression body.
+    pub fn arrow_return_type(self, arrow: &ArrowFunctionExpression<'a>) -> Option<TypeKind> {
+        self.arrow_return_type_impl(arrow, 0)
+    }
+
+    /// The kind of value calling `callee` returns.
+    ///
+    /// `callee` may be a function or arrow function expression, or an identifier which refers to
+    /// a function declared in this file.
+    pub fn callee_return_type(self, callee: &Expression<'a>) -> Option<TypeKind> {
+        self.callee_return_type_impl(callee, 0)
+    }
+
+    /// Returns `true` if `expr` is a function which returns a `Promise`, either directly or
+    /// through an identifier which refers to one.
+    pub fn is_promise_returning_function(self, expr: &Expression<'a>) -> bool {
+        self.callee_return_type(expr.get_inner_expression()) == Some(TypeKind::Promise)
+    }
+
+    fn expression_type_impl(self, expr: &Expression<'a>, depth: u8) -> Option<TypeKind> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        match expr {
+            Expression::BooleanLiteral(_) => Some(TypeKind::Boolean),
+            Expression::NullLiteral(_) => Some(TypeKind::Null),
+            Expression::NumericLiteral(_) => Some(TypeKind::Number),
+            Expression::BigIntLiteral(_) => Some(TypeKind::BigInt),
+            Expression::RegExpLiteral(_) => Some(TypeKind::RegExp),
+            Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => Some(TypeKind::String),
+            Expression::ArrayExpression(_) => Some(TypeKind::Array),
+            Expression::ObjectExpression(object) => object_type(object),
+            Expression::FunctionExpression(_)
+            | Expression::ArrowFunctionExpression(_)
+            | Expression::ClassExpression(_) => Some(TypeKind::Function),
+            Expression::Identifier(ident) => self.identifier_type(ident, depth),
+            Expression::ParenthesizedExpression(paren) => {
+                self.expression_type_impl(&paren.expression, depth)
+            }
+            Expression::TSSatisfiesExpression(satisfies) => {
+                self.expression_type_impl(&satisfies.expression, depth)
+            }
+            Expression::TSAsExpression(as_expr) => self.annotation_type(&as_expr.type_annotation),
+            Expression::TSTypeAssertion(assertion) => {
+                self.annotation_type(&assertion.type_annotation)
+            }
+            Expression::TSNonNullExpression(non_null) => self
+                .expression_type_impl(&non_null.expression, depth)
+                .filter(|kind| !kind.is_nullish()),
+            Expression::SequenceExpression(sequence) => {
+                self.expression_type_impl(sequence.expressions.last()?, depth)
+            }
+            Expression::AssignmentExpression(assign) if assign.operator.is_assign() => {
+                self.expression_type_impl(&assign.right, depth)
+            }
+            Expression::ConditionalExpression(conditional) => same_kind([
+                self.expression_type_impl(&conditional.consequent, depth),
+                self.expression_type_impl(&conditional.alternate, depth),
+            ]),
+            Expression::LogicalExpression(logical) => match logical.operator {
+                // `a ?? b` is `a` unless `a` is nullish
+                LogicalOperator::Coalesce => {
+                    match self.expression_type_impl(&logical.left, depth) {
+                        Some(kind) if !kind.is_nullish() => Some(kind),
+                        _ => same_kind([
+                            self.expression_type_impl(&logical.left, depth),
+                            self.expression_type_impl(&logical.right, depth),
+                        ]),
+                    }
+                }
+                LogicalOperator::And | LogicalOperator::Or => same_kind([
+                    self.expression_type_impl(&logical.left, depth),
+                    self.expression_type_impl(&logical.right, depth),
+                ]),
+            },
+            Expression::UnaryExpression(unary) => self.unary_type(unary, depth),
+            // `x++` is a `number`, unless `x` is a `bigint`
+            Expression::UpdateExpression(update) => {
+                let is_bigint = match &update.argument {
+                    SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                        self.identifier_type(ident, depth) == Some(TypeKind::BigInt)
+                    }
+                    _ => false,
+                };
+                Some(if is_bigint { TypeKind::BigInt } else { TypeKind::Number })
+            }
+            Expression::BinaryExpression(binary) => {
+                let operator = binary.operator;
+                if operator.is_equality()
+                    || operator.is_compare()
+                    || operator.is_in()
+                    || operator.is_instance_of()
+                {
+                    return Some(TypeKind::Boolean);
+                }
+                let left = self.expression_type_impl(&binary.left, depth);
+                let right = self.expression_type_impl(&binary.right, depth);
+                if operator == BinaryOperator::Addition
+                    && (left == Some(TypeKind::String) || right == Some(TypeKind::String))
+                {
+                    return Some(TypeKind::String);
+                }
+                match (left?, right?) {
+                    (TypeKind::Number, TypeKind::Number) => Some(TypeKind::Number),
+                    (TypeKind::BigInt, TypeKind::BigInt) => Some(TypeKind::BigInt),
+                    _ => None,
+                }
+            }
+            Expression::AwaitExpression(await_expr) => {
+                // Awaiting a non-thenable evaluates to the value itself.
+                // Awaiting a `Promise` evaluates to its type argument, which is not tracked.
+                self.expression_type_impl(&await_expr.argument, depth)
+                    .filter(|kind| !kind.is_thenable())
+            }
+            Expression::CallExpression(call) => self.call_type(call, depth),
+            Expression::NewExpression(new_expr) => self.new_type(new_expr),
+            _ => None,
+        }
+    }
+
+    fn identifier_type(self, ident: &IdentifierReference<'a>, depth: u8) -> Option<TypeKind> {
+        let Some(symbol_id) = self.symbol_id(ident) else {
+            return match ident.name.as_str() {
+                "undefined" => Some(TypeKind::Undefined),
+                "NaN" | "Infinity" => Some(TypeKind::Number),
+                _ => None,
+            };
+        };
+        match self.semantic.symbol_declaration(symbol_id).kind() {
+            AstKind::VariableDeclarator(decl) => self.declarator_type(decl, symbol_id, depth),
+            AstKind::FormalParameter(param) => self.parameter_type(param),
+            AstKind::Function(_) | AstKind::Class(_) => Some(TypeKind::Function),
+            _ => None,
+        }
+    }
+
+    fn declarator_type(
+        self,
+        decl: &VariableDeclarator<'a>,
+        symbol_id: SymbolId,
+        depth: u8,
+    ) -> Option<TypeKind> {
+        if !matches!(decl.id, BindingPattern::BindingIdentifier(_)) {
+            return None;
+        }
+        if let Some(annotation) = &decl.type_annotation {
+            return self.annotation_type(&annotation.type_annotation);
+        }
+        // The initializer only determines the type if the variable is never reassigned
+        if self.semantic.scoping().symbol_is_mutated(symbol_id) {
+            return None;
+        }
+        self.expression_type_impl(decl.init.as_ref()?, depth + 1)
+    }
+
+    fn parameter_type(self, param: &FormalParameter<'a>) -> Option<TypeKind> {
+        if !matches!(param.pattern, BindingPattern::BindingIdentifier(_)) {
+            return None;
+        }
+        self.annotation_type(&param.type_annotation.as_ref()?.type_annotation)
+    }
+
+    fn unary_type(self, unary: &UnaryExpression<'a>, depth: u8) -> Option<TypeKind> {
+        match unary.operator {
+            UnaryOperator::Typeof => Some(TypeKind::String),
+            UnaryOperator::LogicalNot | UnaryOperator::Delete => Some(TypeKind::Boolean),
+            UnaryOperator::Void => Some(TypeKind::Undefined),
+            UnaryOperator::UnaryPlus => Some(TypeKind::Number),
+            UnaryOperator::UnaryNegation | UnaryOperator::BitwiseNot => {
+                match self.expression_type_impl(&unary.argument, depth)? {
+                    TypeKind::BigInt => Some(TypeKind::BigInt),
+                    TypeKind::Any | TypeKind::Unknown => None,
+                    _ => Some(TypeKind::Number),
+                }
+            }
+        }
+    }
+
+    fn call_type(self, call: &CallExpression<'a>, depth: u8) -> Option<TypeKind> {
+        let callee = call.callee.without_parentheses();
+        if let Some(member) = callee.as_member_expression() {
+            let method = member.static_property_name()?;
+            let object = member.object().without_parentheses();
+            // `Promise.resolve()`, `Promise.all()` etc.
+            if let Expression::Identifier(ident) = object
+                && ident.name == "Promise"
+                && self.is_global(ident)
+            {
+                return matches!(
+                    method,
+                    "resolve" | "reject" | "all" | "allSettled" | "any" | "race" | "try"
+                )
+                .then_some(TypeKind::Promise);
+            }
+            // `promise.then()`, `promise.catch()`, `promise.finally()`
+            if matches!(method, "then" | "catch" | "finally")
+                && self.expression_type_impl(object, depth) == Some(TypeKind::Promise)
+            {
+                return Some(TypeKind::Promise);
+            }
+            return None;
+        }
+        self.callee_return_type_impl(callee, depth)
+    }
+
+    fn callee_return_type_impl(self, callee: &Expression<'a>, depth: u8) -> Option<TypeKind> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        match callee.without_parentheses() {
+            Expression::FunctionExpression(func) => self.function_return_type(func),
+            Expression::ArrowFunctionExpression(arrow) => self.arrow_return_type_impl(arrow, depth),
+            Expression::Identifier(ident) => {
+                let symbol_id = self.symbol_id(ident)?;
+                match self.semantic.symbol_declaration(symbol_id).kind() {
+                    AstKind::Function(func) => self.function_return_type(func),
+                    AstKind::VariableDeclarator(decl)
+                        if matches!(decl.id, BindingPattern::BindingIdentifier(_))
+                            && !self.semantic.scoping().symbol_is_mutated(symbol_id) =>
+                    {
+                        if let Some(annotation) = &decl.type_annotation {
+                            let TSType::TSFunctionType(func_type) = &annotation.type_annotation
+                            else {
+                                return None;
+                            };
+                            return self.annotation_type(&func_type.return_type.type_annotation);
+                        }
+                        self.callee_return_type_impl(decl.init.as_ref()?, depth + 1)
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn arrow_return_type_impl(
+        self,
+        arrow: &ArrowFunctionExpression<'a>,
+        depth: u8,
+    ) -> Option<TypeKind> {
+        if let Some(return_type) = &arrow.return_type {
+            return self.annotation_type(&return_type.type_annotation);
+        }
+        if arrow.r#async {
+            return Some(TypeKind::Promise);
+        }
+        self.expression_type_impl(arrow.get_expression()?, depth + 1)
+    }
+
+    fn new_type(self, new_expr: &NewExpression<'a>) -> Option<TypeKind> {
+        let Expression::Identifier(ident) = new_expr.callee.without_parentheses() else {
+            return None;
+        };
+        if !self.is_global(ident) {
+            return None;
+        }
+        match ident.name.as_str() {
+            "Promise" => Some(TypeKind::Promise),
+            "RegExp" => Some(TypeKind::RegExp),
+            "Array" => Some(TypeKind::Array),
+            _ => None,
+        }
+    }
+
+    fn symbol_id(self, ident: &IdentifierReference<'a>) -> Option<SymbolId> {
+        self.semantic.scoping().get_reference(ident.reference_id()).symbol_id()
+    }
+
+    fn is_global(self, ident: &IdentifierReference<'a>) -> bool {
+        ident.is_global_reference(self.semantic.scoping())
+    }
+}
+
+/// An object literal is thenable if it has a `then` property.
+/// Spreads may bring in a `then` property, so they make the type unknown.
+fn object_type(object: &ObjectExpression<'_>) -> Option<TypeKind> {
+    let may_be_thenable = object.properties.iter().any(|property| match property {
+        ObjectPropertyKind::ObjectProperty(property) => {
+            property.computed || property.key.is_specific_static_name("then")
+        }
+        ObjectPropertyKind::SpreadProperty(_) => true,
+    });
+    (!may_be_thenable).then_some(TypeKind::Object)
+}
+
+/// The kind shared by all `kinds`, or `None` if they differ or any is unknown.
+fn same_kind(kinds: impl IntoIterator<Item = Option<TypeKind>>) -> Option<TypeKind> {
+    let mut kinds = kinds.into_iter();
+    let first = kinds.next()??;
+    kinds.all(|kind| kind == Some(first)).then_some(first)
+}
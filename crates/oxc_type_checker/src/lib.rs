@@ -11,6 +11,9 @@
 //! - access to symbol and scope information through [`oxc_semantic::Semantic`], and
 //! - a [`Diagnostics`] collector plus a [`type_error`] helper for reporting problems.
 //!
+//! It also provides [`TypeInference`], a conservative, file-local approximation of the types of
+//! expressions, which Oxlint uses to run some type-aware rules without `tsgolint`.
+//!
 //! ## Usage
 //!
 //! ```rust
@@ -59,11 +62,15 @@ use oxc_semantic::Semantic;
 pub mod compiler;
 mod diagnostics;
 pub mod execute;
+mod infer;
 pub mod tsoptions;
 mod tspath;
 mod vfs;
 
-pub use crate::diagnostics::type_error;
+pub use crate::{
+    diagnostics::type_error,
+    infer::{TypeInference, TypeKind},
+};
 
 /// Options controlling how the [`TypeChecker`] behaves.
 ///
@@ -0,0 +1,179 @@
+use std::fmt::Write;
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{Expression, Statement};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_type_checker::{TypeInference, TypeKind};
+
+/// The inferred kind of the expression in the last statement of `source_text`.
+fn infer(source_text: &str) -> Option<TypeKind> {
+    #[expect(clippy::redundant_closure_for_method_calls, reason = "not general over lifetimes")]
+    infer_with(source_text, |inference, expr| inference.expression_type(expr))
+}
+
+fn infer_with<T>(
+    source_text: &str,
+    f: impl for<'s, 'a> FnOnce(TypeInference<'s, 'a>, &Expression<'a>) -> T,
+) -> T {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::ts()).parse();
+    assert!(ret.diagnostics.is_empty(), "{source_text}: {:?}", ret.diagnostics);
+    let semantic = SemanticBuilder::new().with_build_nodes(true).build(&ret.program).semantic;
+    let Some(Statement::ExpressionStatement(stmt)) = ret.program.body.last() else {
+        panic!("{source_text}: expected an expression statement last");
+    };
+    f(TypeInference::new(&semantic), &stmt.expression)
+}
+
+#[test]
+fn literals() {
+    let cases = [
+        ("true", Some(TypeKind::Boolean)),
+        ("null", Some(TypeKind::Null)),
+        ("1", Some(TypeKind::Number)),
+        ("1n", Some(TypeKind::BigInt)),
+        ("/a/", Some(TypeKind::RegExp)),
+        ("('a')", Some(TypeKind::String)),
+        ("`a${b}`", Some(TypeKind::String)),
+        ("[]", Some(TypeKind::Array)),
+        ("({ a: 1 })", Some(TypeKind::Object)),
+        ("(() => {})", Some(TypeKind::Function)),
+        ("(class {})", Some(TypeKind::Function)),
+        ("undefined", Some(TypeKind::Undefined)),
+        ("NaN", Some(TypeKind::Number)),
+        ("unknownGlobal", None),
+    ];
+    for (source_text, expected) in cases {
+        assert_eq!(infer(source_text), expected, "{source_text}");
+    }
+}
+
+#[test]
+fn thenable_objects() {
+    assert_eq!(infer("({ then() {} })"), None);
+    assert_eq!(infer("({ [key]: 1 })"), None);
+    assert_eq!(infer("({ ...other })"), None);
+    assert_eq!(infer("let x: { then(): void }; x"), None);
+    assert_eq!(infer("let x: { a: number }; x"), Some(TypeKind::Object));
+}
+
+#[test]
+fn operators() {
+    let cases = [
+        ("typeof a", Some(TypeKind::String)),
+        ("!a", Some(TypeKind::Boolean)),
+        ("void a", Some(TypeKind::Undefined)),
+        ("+a", Some(TypeKind::Number)),
+        ("-1n", Some(TypeKind::BigInt)),
+        ("-a", None),
+        ("a === b", Some(TypeKind::Boolean)),
+        ("a in b", Some(TypeKind::Boolean)),
+        ("'a' + b", Some(TypeKind::String)),
+        ("1 + 2", Some(TypeKind::Number)),
+        ("1n * 2n", Some(TypeKind::BigInt)),
+        ("1 + b", None),
+        ("let n: bigint = 1n; n++", Some(TypeKind::BigInt)),
+        // `n++` reassigns `n`, so its initializer does not determine its type
+        ("let n = 1n; n++", Some(TypeKind::Number)),
+        ("a++", Some(TypeKind::Number)),
+        ("a ? 1 : 2", Some(TypeKind::Number)),
+        ("a ? 1 : 'b'", None),
+        ("null ?? 'a'", None),
+        ("1 ?? a", Some(TypeKind::Number)),
+        ("a || 'b'", None),
+        ("(a, 'b')", Some(TypeKind::String)),
+        ("a = 1", Some(TypeKind::Number)),
+        ("(1 satisfies number)", Some(TypeKind::Number)),
+        ("a as string", Some(TypeKind::String)),
+        ("<RegExp>a", Some(TypeKind::RegExp)),
+    ];
+    for (source_text, expected) in cases {
+        assert_eq!(infer(source_text), expected, "{source_text}");
+    }
+}
+
+#[test]
+fn variables() {
+    let cases = [
+        ("const a = 'a'; a", Some(TypeKind::String)),
+        ("const a: number = f(); a", Some(TypeKind::Number)),
+        ("let a = 'a'; a = f(); a", None),
+        ("const { a } = { a: 1 }; a", None),
+        ("const a = 1, b = a; b", Some(TypeKind::Number)),
+        ("function f(a: string) { a }; f", Some(TypeKind::Function)),
+        ("class A {} A", Some(TypeKind::Function)),
+        ("let a: string; a!", Some(TypeKind::String)),
+        ("let a: null; a!", None),
+        // Unions of different kinds are not narrowed
+        ("let a: string | null; a!", None),
+        ("let a: string | number; a", None),
+        ("let a: 'a' | 'b'; a", Some(TypeKind::String)),
+        ("let a: readonly string[]; a", Some(TypeKind::Array)),
+        ("let a: [number]; a", Some(TypeKind::Array)),
+        ("let a: unique symbol; a", Some(TypeKind::Symbol)),
+        ("let a: keyof B; a", None),
+    ];
+    for (source_text, expected) in cases {
+        assert_eq!(infer(source_text), expected, "{source_text}");
+    }
+}
+
+#[test]
+fn depth_limit_stops_cycles() {
+    // Each initializer refers to the next one, which is deeper than the limit
+    let mut source_text = "const a20 = 1;".to_string();
+    for i in 0..20 {
+        write!(source_text, "const a{i} = a{};", i + 1).unwrap();
+    }
+    source_text.push_str("a0");
+    assert_eq!(infer(&source_text), None);
+    assert_eq!(infer("const a20 = 1; const a19 = a20; a19"), Some(TypeKind::Number));
+}
+
+#[test]
+fn promises() {
+    let cases = [
+        ("Promise.resolve(1)", Some(TypeKind::Promise)),
+        ("Promise.all([])", Some(TypeKind::Promise)),
+        ("Promise.withResolvers()", None),
+        ("new Promise(() => {})", Some(TypeKind::Promise)),
+        ("Promise.resolve().then(f)", Some(TypeKind::Promise)),
+        ("p.then(f)", None),
+        ("async function f() {} f()", Some(TypeKind::Promise)),
+        ("async function* f() {} f()", None),
+        ("function f(): Promise<void> {} f()", Some(TypeKind::Promise)),
+        ("const f = async () => {}; f()", Some(TypeKind::Promise)),
+        ("const f = () => Promise.resolve(); f()", Some(TypeKind::Promise)),
+        ("const f: () => PromiseLike<void> = g; f()", Some(TypeKind::Promise)),
+        ("(async () => {})()", Some(TypeKind::Promise)),
+        ("await Promise.resolve()", None),
+        ("await 1", Some(TypeKind::Number)),
+        ("let p: Promise<void>; p", Some(TypeKind::Promise)),
+    ];
+    for (source_text, expected) in cases {
+        assert_eq!(infer(source_text), expected, "{source_text}");
+    }
+}
+
+#[test]
+fn local_declarations_shadow_globals() {
+    assert_eq!(infer("class Promise {} new Promise()"), None);
+    assert_eq!(infer("const Promise = { resolve() {} }; Promise.resolve()"), None);
+    assert_eq!(infer("interface Promise {} let p: Promise; p"), None);
+}
+
+#[test]
+#[expect(clippy::redundant_closure_for_method_calls, reason = "not general over lifetimes")]
+fn promise_returning_functions() {
+    let is_promise_returning = |source_text| {
+        infer_with(source_text, |inference, expr| inference.is_promise_returning_function(expr))
+    };
+    assert!(is_promise_returning("(async () => {})"));
+    assert!(is_promise_returning("async function f() {} f"));
+    assert!(is_promise_returning("const f = async function () {}; (f)"));
+    assert!(!is_promise_returning("(() => {})"));
+    assert!(!is_promise_returning("let f = async () => {}; f = () => {}; f"));
+    assert!(!is_promise_returning("f"));
+}
@@ -72,6 +72,14 @@ export interface CompressOptions {
    * @default true
    */
   sequences?: boolean
+  /**
+   * Inline calls to small functions whose body is a single `return` statement.
+   *
+   * `function f(x) { return g(x) } f(1)` -> `g(1)`
+   *
+   * @default false
+   */
+  inlineFunctions?: boolean
   /**
    * Set of label names to drop from the code.
    *
@@ -148,6 +156,16 @@ export interface MangleOptions {
    * @default []
    */
   reserved?: Array<string>
+  /**
+   * Mangled names from a previous build, keyed by scope path and then by original name.
+   * Bindings that still exist in the same scope keep their cached names. Pass `{}` to start a
+   * cache, and the updated cache is returned in `nameCache`. Equivalent to terser's `nameCache`.
+   *
+   * The root scope's path is `""`. Nested scopes append `/`-separated segments: the name of
+   * the function or class, or of the variable, method or property it is assigned to, or `#n`
+   * for the n-th anonymous scope in its parent.
+   */
+  nameCache?: Record<string, Record<string, string>>
   /** Debug mangled names. */
   debug?: boolean
 }
@@ -235,6 +253,11 @@ export interface MinifyResult {
    * parse without errors.
    */
   mangleCache?: Record<string, string | false>
+  /**
+   * Updated name cache, keyed by scope path and then by original name. Present when
+   * `mangle.nameCache` was given and the parse had no errors.
+   */
+  nameCache?: Record<string, Record<string, string>>
 }
 
 /** Minify synchronously. */
@@ -44,6 +44,10 @@ pub struct MinifyResult {
     /// parse without errors.
     #[napi(ts_type = "Record<string, string | false>")]
     pub mangle_cache: Option<BTreeMap<String, Either<String, bool>>>,
+    /// Updated name cache, keyed by scope path and then by original name. Present when
+    /// `mangle.nameCache` was given and the parse had no errors.
+    #[napi(ts_type = "Record<string, Record<string, string>>")]
+    pub name_cache: Option<BTreeMap<String, BTreeMap<String, String>>>,
 }
 
 fn to_napi_mangle_cache(
@@ -58,6 +62,19 @@ fn to_napi_mangle_cache(
         .collect()
 }
 
+fn to_napi_name_cache(
+    cache: oxc_minifier::MangleNameCache,
+) -> BTreeMap<String, BTreeMap<String, String>> {
+    let mut scopes = BTreeMap::<String, BTreeMap<String, String>>::new();
+    for (scope_path, original, target) in cache.iter() {
+        scopes
+            .entry(scope_path.to_string())
+            .or_default()
+            .insert(original.to_string(), target.to_string());
+    }
+    scopes
+}
+
 fn minify_impl(filename: &str, source_text: &str, options: Option<MinifyOptions>) -> MinifyResult {
     use oxc_codegen::CodegenOptions;
     let options = options.unwrap_or_default();
@@ -98,6 +115,11 @@ fn minify_impl(filename: &str, source_text: &str, options: Option<MinifyOptions>
         .is_empty()
         .then(|| minifier_ret.property_mangle_cache.map(to_napi_mangle_cache))
         .flatten();
+    let name_cache = parser_ret
+        .diagnostics
+        .is_empty()
+        .then(|| minifier_ret.mangle_name_cache.map(to_napi_name_cache))
+        .flatten();
 
     let mut codegen_options = match &options.codegen {
         // Need to remove all comments.
@@ -133,6 +155,7 @@ fn minify_impl(filename: &str, source_text: &str, options: Option<MinifyOptions>
         errors: OxcError::from_diagnostics(filename, source_text, parser_ret.diagnostics),
         legal_comments,
         mangle_cache,
+        name_cache,
     }
 }
 
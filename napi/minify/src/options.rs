@@ -136,6 +136,13 @@ pub struct CompressOptions {
     /// @default true
     pub sequences: Option<bool>,
 
+    /// Inline calls to small functions whose body is a single `return` statement.
+    ///
+    /// `function f(x) { return g(x) } f(1)` -> `g(1)`
+    ///
+    /// @default false
+    pub inline_functions: Option<bool>,
+
     /// Set of label names to drop from the code.
     ///
     /// Labeled statements matching these names will be removed during minification.
@@ -164,6 +171,7 @@ impl TryFrom<&CompressOptions> for oxc_minifier::CompressOptions {
             drop_debugger: o.drop_debugger.unwrap_or(default.drop_debugger),
             join_vars: o.join_vars.unwrap_or(true),
             sequences: o.sequences.unwrap_or(true),
+            inline_functions: o.inline_functions.unwrap_or(default.inline_functions),
             unused: match &o.unused {
                 Some(Either::A(true)) => oxc_minifier::CompressOptionsUnused::Remove,
                 Some(Either::A(false)) => oxc_minifier::CompressOptionsUnused::Keep,
@@ -234,14 +242,45 @@ pub struct MangleOptions {
     /// @default []
     pub reserved: Option<Vec<String>>,
 
+    /// Mangled names from a previous build, keyed by scope path and then by original name.
+    /// Bindings that still exist in the same scope keep their cached names. Pass `{}` to start a
+    /// cache, and the updated cache is returned in `nameCache`. Equivalent to terser's `nameCache`.
+    ///
+    /// The root scope's path is `""`. Nested scopes append `/`-separated segments: the name of
+    /// the function or class, or of the variable, method or property it is assigned to, or `#n`
+    /// for the n-th anonymous scope in its parent.
+    #[napi(ts_type = "Record<string, Record<string, string>>")]
+    pub name_cache: Option<FxHashMap<String, FxHashMap<String, String>>>,
+
     /// Debug mangled names.
     pub debug: Option<bool>,
 }
 
-impl From<&MangleOptions> for oxc_minifier::MangleOptions {
-    fn from(o: &MangleOptions) -> Self {
+impl TryFrom<&MangleOptions> for oxc_minifier::MangleOptions {
+    type Error = String;
+
+    fn try_from(o: &MangleOptions) -> Result<Self, Self::Error> {
         let default = oxc_minifier::MangleOptions::default();
-        Self {
+        let name_cache = o
+            .name_cache
+            .as_ref()
+            .map(|scopes| {
+                let mut cache = oxc_minifier::MangleNameCache::default();
+                for (scope_path, names) in scopes {
+                    for (original, target) in names {
+                        cache
+                            .insert(
+                                CompactStr::from(scope_path.as_str()),
+                                CompactStr::from(original.as_str()),
+                                CompactStr::from(target.as_str()),
+                            )
+                            .map_err(|error| format!("Invalid mangle.nameCache: {error}"))?;
+                    }
+                }
+                Ok::<_, String>(cache)
+            })
+            .transpose()?;
+        Ok(Self {
             top_level: o.toplevel,
             keep_names: match &o.keep_names {
                 Some(Either::A(false)) => oxc_minifier::MangleOptionsKeepNames::all_false(),
@@ -252,8 +291,9 @@ impl From<&MangleOptions> for oxc_minifier::MangleOptions {
             reserved: o.reserved.as_ref().map_or(default.reserved, |names| {
                 names.iter().map(|name| CompactStr::from(name.as_str())).collect()
             }),
+            name_cache,
             debug: o.debug.unwrap_or(default.debug),
-        }
+        })
     }
 }
 
@@ -477,7 +517,7 @@ impl TryFrom<&MinifyOptions> for oxc_minifier::MinifierOptions {
         let mangle = match &o.mangle {
             Some(Either::A(false)) => None,
             None | Some(Either::A(true)) => Some(oxc_minifier::MangleOptions::default()),
-            Some(Either::B(o)) => Some(oxc_minifier::MangleOptions::from(o)),
+            Some(Either::B(o)) => Some(oxc_minifier::MangleOptions::try_from(o)?),
         };
         let mangle_properties = o
             .mangle_props
@@ -311,6 +311,38 @@ describe("property mangling", () => {
   });
 });
 
+describe("name cache", () => {
+  it("reuses cached names across builds", () => {
+    const first = minifySync("test.js", "export function f(a, b) { return a + b }", {
+      module: true,
+      compress: false,
+      mangle: { nameCache: {} },
+    });
+    expect(first.code).toBe("export function f(e,t){return e+t}");
+    expect(first.nameCache).toEqual({ f: { a: "e", b: "t" } });
+
+    const second = minifySync("test.js", "export function f(z, a, b) { return z + a + b }", {
+      module: true,
+      compress: false,
+      mangle: { nameCache: first.nameCache },
+    });
+    expect(second.code).toBe("export function f(n,e,t){return n+e+t}");
+    expect(second.nameCache).toEqual({ f: { a: "e", b: "t", z: "n" } });
+  });
+
+  it("is not returned unless requested", () => {
+    const ret = minifySync("test.js", "export function f(a) { return a }", { module: true });
+    expect(ret.nameCache).toBeUndefined();
+  });
+
+  it("rejects invalid targets", () => {
+    const ret = minifySync("test.js", "const foo = 1;", {
+      mangle: { nameCache: { "": { foo: "class" } } },
+    });
+    expect(ret.errors.length).toBe(1);
+  });
+});
+
 describe("worker", () => {
   it("should run", async () => {
     const code = await new Promise((resolve, reject) => {
@@ -502,6 +502,16 @@ export interface TransformOptions {
    * @see {@link https://oxc.rs/docs/guide/usage/transformer/lowering#target}
    */
   target?: string | Array<string>
+  /**
+   * Import `core-js@3` polyfills for the built-ins used in the code which are not supported by
+   * `target`, like `babel-preset-env`'s `useBuiltIns: "usage"`.
+   *
+   * The value is the installed version of `core-js`, e.g. `'3.38'`. Polyfills which were added
+   * in later versions are not imported.
+   *
+   * @default undefined (No polyfills)
+   */
+  corejs?: string
   /** Behaviour for runtime helpers. */
   helpers?: Helpers
   /**
@@ -19,7 +19,8 @@ use oxc::{
     semantic::{SemanticBuilder, SemanticBuilderReturn},
     span::SourceType,
     transformer::{
-        EnvOptions, HelperLoaderMode, HelperLoaderOptions, JsxRuntime, RewriteExtensionsMode,
+        EnvOptions, HelperLoaderMode, HelperLoaderOptions, JsxRuntime, PolyfillsOptions,
+        RewriteExtensionsMode,
     },
     transformer_plugins::{
         InjectGlobalVariablesConfig, InjectImport, ModuleRunnerTransform,
@@ -140,6 +141,15 @@ pub struct TransformOptions {
     /// @see {@link https://oxc.rs/docs/guide/usage/transformer/lowering#target}
     pub target: Option<Either<String, Vec<String>>>,
 
+    /// Import `core-js@3` polyfills for the built-ins used in the code which are not supported by
+    /// `target`, like `babel-preset-env`'s `useBuiltIns: "usage"`.
+    ///
+    /// The value is the installed version of `core-js`, e.g. `'3.38'`. Polyfills which were added
+    /// in later versions are not imported.
+    ///
+    /// @default undefined (No polyfills)
+    pub corejs: Option<String>,
+
     /// Behaviour for runtime helpers.
     pub helpers: Option<Helpers>,
 
@@ -173,11 +183,14 @@ impl TryFrom<TransformOptions> for oxc::transformer::TransformOptions {
     type Error = String;
 
     fn try_from(options: TransformOptions) -> Result<Self, Self::Error> {
-        let env = match options.target {
+        let mut env = match options.target {
             Some(Either::A(s)) => EnvOptions::from_target(&s)?,
             Some(Either::B(list)) => EnvOptions::from_target_list(&list)?,
             _ => EnvOptions::default(),
         };
+        if let Some(version) = &options.corejs {
+            env.polyfills.core_js = Some(PolyfillsOptions::parse_core_js_version(version)?);
+        }
         Ok(Self {
             cwd: options.cwd.map(PathBuf::from).unwrap_or_default(),
             assumptions: options.assumptions.map(Into::into).unwrap_or_default(),
@@ -126,6 +126,20 @@ describe("target", () => {
   });
 });
 
+describe("corejs", () => {
+  it("imports polyfills for unsupported built-ins", () => {
+    const code = "Object.groupBy(a, f);\n";
+    const ret = transformSync("test.mjs", code, { target: "chrome100", corejs: "3.38" });
+    expect(ret.errors.length).toBe(0);
+    expect(ret.code).toEqual(`import "core-js/modules/es.object.group-by.js";\n${code}`);
+  });
+
+  it("reports invalid versions", () => {
+    const ret = transformSync("test.mjs", "", { corejs: "2" });
+    expect(ret.errors.length).toBe(1);
+  });
+});
+
 describe("helpers", () => {
   const data: Array<[HelperMode, string]> = [
     [HelperMode.External, "babelHelpers.objectSpread2({}, x);\n"],
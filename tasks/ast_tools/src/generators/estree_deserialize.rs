@@ -0,0 +1,516 @@
+//! Generator for deserializing AST from ESTree JSON.
+//!
+//! Generates `FromESTree` impls for all AST types which appear in ESTree AST,
+//! using the same `#[estree]` attributes as the `ESTree` derive (`rename`, `flatten`, `skip`,
+//! `prepend_to`, `append_to` etc), so serialization and deserialization stay in sync.
+//!
+//! Converters (`#[estree(via = ...)]`, and `#[estree(add_fields(...))]` which produce non-constant
+//! values) are one-way, so types which use them are deserialized by hand-written impls in
+//! `oxc_ast/src/deserialize`. So are the few types listed in `CUSTOM_TYPE_NAMES`, whose ESTree
+//! representation depends on data outside the node.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use rustc_hash::FxHashSet;
+
+use crate::{
+    AST_CRATE_PATH, Codegen, Generator,
+    derives::estree::{
+        get_fieldless_variant_value, get_struct_field_name, should_flatten_field,
+        should_skip_enum_variant, should_skip_field,
+    },
+    output::{Output, output_path},
+    schema::{Def, EnumDef, FieldDef, Schema, StructDef, TypeDef, TypeId},
+};
+
+use super::define_generator;
+
+/// Types with hand-written `FromESTree` impls, in addition to those which use converters.
+///
+/// * `Program` stores source text and source type, which are not part of ESTree AST.
+/// * `PropertyKey` depends on `computed` field of the parent node.
+/// * `MemberExpression` variants are only distinguished by `computed` field,
+///   which is an added field with a constant value.
+/// * `NumericLiteral` has a `base` field which is not in ESTree AST, and is derived from `raw`.
+/// * `ExportNamedDeclaration` shares its ESTree type with `ExportFromDeclaration` and
+///   `ExportDeclaration`, which are only distinguished by which fields are `null`.
+const CUSTOM_TYPE_NAMES: &[&str] =
+    &["Program", "PropertyKey", "MemberExpression", "NumericLiteral", "ExportNamedDeclaration"];
+
+/// Generator for `FromESTree` impls.
+pub struct ESTreeDeserializeGenerator;
+
+define_generator!(ESTreeDeserializeGenerator);
+
+impl Generator for ESTreeDeserializeGenerator {
+    fn generate(&self, schema: &Schema, codegen: &Codegen) -> Output {
+        let output = generate(schema, codegen);
+        Output::Rust { path: output_path(AST_CRATE_PATH, "derive_from_estree.rs"), tokens: output }
+    }
+}
+
+/// Generate `FromESTree` impls for all types reachable from `Program`.
+fn generate(schema: &Schema, codegen: &Codegen) -> TokenStream {
+    let estree_derive_id = codegen.get_derive_id_by_name("ESTree");
+    let custom_type_ids =
+        CUSTOM_TYPE_NAMES.iter().map(|&type_name| schema.type_names[type_name]).collect();
+    let g = DeserializerGenerator { schema, custom_type_ids };
+
+    let program_id = schema.type_names["Program"];
+    let type_ids = g.reachable_types(program_id);
+
+    let impls = type_ids.into_iter().filter_map(|type_id| {
+        let type_def = &schema.types[type_id];
+        match type_def {
+            TypeDef::Struct(struct_def) => {
+                if !struct_def.generates_derive(estree_derive_id)
+                    || !is_ast_crate(struct_def.file(schema).krate())
+                    || g.is_custom_struct(struct_def)
+                {
+                    return None;
+                }
+                Some(g.generate_impl_for_struct(struct_def))
+            }
+            TypeDef::Enum(enum_def) => {
+                if !enum_def.generates_derive(estree_derive_id)
+                    || !is_ast_crate(enum_def.file(schema).krate())
+                    || g.is_custom_enum(enum_def)
+                {
+                    return None;
+                }
+                Some(g.generate_impl_for_enum(enum_def))
+            }
+            _ => None,
+        }
+    });
+
+    quote! {
+        #![allow(clippy::match_same_arms, clippy::too_many_lines)]
+
+        ///@@line_break
+        use oxc_allocator::Dummy;
+        use oxc_syntax::operator::*;
+        use serde_json::Value;
+
+        ///@@line_break
+        use crate::ast::js::*;
+        use crate::ast::jsx::*;
+        use crate::ast::literal::*;
+        use crate::ast::ts::*;
+        use crate::deserialize::{EstreeDeserializer, FromESTree, Node, Result, invalid_value};
+
+        #(#impls)*
+    }
+}
+
+struct DeserializerGenerator<'s> {
+    schema: &'s Schema,
+    custom_type_ids: FxHashSet<TypeId>,
+}
+
+impl DeserializerGenerator<'_> {
+    /// Get IDs of all structs and enums reachable from type `root_id` in ESTree AST, in order visited.
+    fn reachable_types(&self, root_id: TypeId) -> Vec<TypeId> {
+        let mut visited = FxHashSet::default();
+        let mut type_ids = vec![];
+        let mut stack = vec![root_id];
+        while let Some(type_id) = stack.pop() {
+            if !visited.insert(type_id) {
+                continue;
+            }
+            match &self.schema.types[type_id] {
+                TypeDef::Struct(struct_def) => {
+                    type_ids.push(type_id);
+                    for field in struct_def.fields.iter().rev() {
+                        if !should_skip_field(field, self.schema) {
+                            stack.push(field.type_id);
+                            // Fields prepended or appended to this one are marked as skipped
+                            let other_index = field
+                                .estree
+                                .prepend_field_index
+                                .or(field.estree.append_field_index);
+                            if let Some(other_index) = other_index {
+                                stack.push(struct_def.fields[other_index].type_id);
+                            }
+                        }
+                    }
+                }
+                TypeDef::Enum(enum_def) => {
+                    type_ids.push(type_id);
+                    for variant in enum_def.variants.iter().rev() {
+                        if !should_skip_enum_variant(variant)
+                            && let Some(field_type) = variant.field_type(self.schema)
+                        {
+                            stack.push(field_type.id());
+                        }
+                    }
+                    stack.extend(enum_def.inherits.iter().rev());
+                }
+                TypeDef::Option(option_def) => stack.push(option_def.inner_type_id),
+                TypeDef::Box(box_def) => stack.push(box_def.inner_type_id),
+                TypeDef::Vec(vec_def) => stack.push(vec_def.inner_type_id),
+                TypeDef::Cell(cell_def) => stack.push(cell_def.inner_type_id),
+                TypeDef::Primitive(_) | TypeDef::Pointer(_) => {}
+            }
+        }
+        type_ids
+    }
+
+    /// Get if a struct has a hand-written `FromESTree` impl.
+    fn is_custom_struct(&self, struct_def: &StructDef) -> bool {
+        self.custom_type_ids.contains(&struct_def.id)
+            || struct_def.estree.via.is_some()
+            || struct_def
+                .fields
+                .iter()
+                .any(|field| !should_skip_field(field, self.schema) && field.estree.via.is_some())
+            || struct_def
+                .estree
+                .add_fields
+                .iter()
+                .any(|(_, converter_name)| !self.is_constant_converter(converter_name))
+            || struct_def.fields.iter().any(|field| {
+                field.name() != "span"
+                    && should_flatten_field(field, self.schema)
+                    && matches!(
+                        field.type_def(self.schema),
+                        TypeDef::Struct(inner) if self.is_custom_struct(inner)
+                    )
+            })
+    }
+
+    /// Get if an enum has a hand-written `FromESTree` impl.
+    fn is_custom_enum(&self, enum_def: &EnumDef) -> bool {
+        self.custom_type_ids.contains(&enum_def.id)
+            || enum_def.estree.via.is_some()
+            || enum_def.variants.iter().any(|variant| variant.estree.via.is_some())
+    }
+
+    /// Get if converter produces a constant value, which deserialization can ignore.
+    fn is_constant_converter(&self, converter_name: &str) -> bool {
+        let converter = self.schema.meta_by_name(converter_name);
+        converter.estree.raw_deser_inline
+            && converter.estree.raw_deser.as_deref().is_some_and(|raw_deser| {
+                matches!(raw_deser, "null" | "true" | "false" | "[]")
+                    || (raw_deser.len() >= 2
+                        && raw_deser.starts_with('\'')
+                        && raw_deser.ends_with('\''))
+            })
+    }
+
+    /// Generate `FromESTree` impl for a struct.
+    fn generate_impl_for_struct(&self, struct_def: &StructDef) -> TokenStream {
+        let has_type_field = struct_def
+            .fields
+            .iter()
+            .any(|field| !should_skip_field(field, self.schema) && self.has_type_field(field));
+
+        let check_type = if struct_def.estree.no_type || has_type_field {
+            quote!()
+        } else {
+            let type_name =
+                struct_def.estree.rename.as_deref().unwrap_or_else(|| struct_def.name());
+            quote!( node.expect_type(#type_name)?; )
+        };
+
+        let mut stmts = quote!();
+        let construct = self.generate_construct_struct(struct_def, &mut stmts);
+
+        let ty = struct_def.ty(self.schema);
+        quote! {
+            ///@@line_break
+            impl<'a> FromESTree<'a> for #ty {
+                fn from_estree(value: &Value, d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+                    let node = Node::object(value)?;
+                    #check_type
+                    #stmts
+                    Ok(#construct)
+                }
+            }
+        }
+    }
+
+    /// Get if a field is the ESTree `type` field, or a flattened struct containing it.
+    fn has_type_field(&self, field: &FieldDef) -> bool {
+        if should_flatten_field(field, self.schema)
+            && let TypeDef::Struct(inner) = field.type_def(self.schema)
+        {
+            return inner
+                .fields
+                .iter()
+                .any(|field| !should_skip_field(field, self.schema) && self.has_type_field(field));
+        }
+        get_struct_field_name(field) == "type"
+    }
+
+    /// Generate struct expression for a struct, reading fields from `node`.
+    ///
+    /// Fields of flattened structs are read from the same `node`.
+    /// Statements which must precede the struct expression are pushed to `stmts`.
+    fn generate_construct_struct(
+        &self,
+        struct_def: &StructDef,
+        stmts: &mut TokenStream,
+    ) -> TokenStream {
+        let prepended_or_appended = struct_def
+            .fields
+            .iter()
+            .filter_map(|field| {
+                field.estree.prepend_field_index.or(field.estree.append_field_index)
+            })
+            .collect::<Vec<_>>();
+
+        let fields = struct_def.fields.iter().enumerate().map(|(field_index, field)| {
+            let field_ident = field.ident();
+
+            // Fields prepended or appended to another field are deserialized along with that field
+            if prepended_or_appended.contains(&field_index) {
+                return quote!( #field_ident );
+            }
+
+            if should_skip_field(field, self.schema) {
+                return quote!( #field_ident: Dummy::dummy(d.allocator()) );
+            }
+
+            if field.name() == "span" {
+                return quote!( span: d.span(node) );
+            }
+
+            if should_flatten_field(field, self.schema) {
+                let value = if let TypeDef::Struct(inner) = field.type_def(self.schema) {
+                    self.generate_construct_struct(inner, stmts)
+                } else {
+                    quote!(FromESTree::from_estree(value, d)?)
+                };
+                return quote!( #field_ident: #value );
+            }
+
+            let field_name = get_struct_field_name(field);
+
+            if let Some(other_index) = field.estree.prepend_field_index {
+                assert!(
+                    field.estree.append_field_index.is_none(),
+                    "Cannot deserialize a field with both prepended and appended fields: `{}::{}`",
+                    struct_def.name(),
+                    field.name(),
+                );
+                let other_ident = struct_def.fields[other_index].ident();
+                stmts.extend(quote! {
+                    let (#other_ident, #field_ident) = d.prepended(node, #field_name)?;
+                });
+                return quote!( #field_ident );
+            }
+
+            if let Some(other_index) = field.estree.append_field_index {
+                let other_ident = struct_def.fields[other_index].ident();
+                stmts.extend(quote! {
+                    let (#field_ident, #other_ident) = d.appended(node, #field_name)?;
+                });
+                return quote!( #field_ident );
+            }
+
+            // Fields which only appear in one of JS and TS ASTs get a default value if absent
+            if field.estree.is_ts || field.estree.is_js {
+                return quote!( #field_ident: d.optional_field(node, #field_name)? );
+            }
+
+            quote!( #field_ident: FromESTree::from_estree_field(node, #field_name, d)? )
+        });
+
+        let struct_ident = struct_def.ident();
+        quote!( #struct_ident { #(#fields),* } )
+    }
+
+    /// Generate `FromESTree` impl for an enum.
+    fn generate_impl_for_enum(&self, enum_def: &EnumDef) -> TokenStream {
+        let (body, d) = if enum_def.is_fieldless() {
+            (Self::generate_body_for_fieldless_enum(enum_def), quote!(_d))
+        } else {
+            (self.generate_body_for_enum(enum_def), quote!(d))
+        };
+
+        let ty = enum_def.ty(self.schema);
+        quote! {
+            ///@@line_break
+            impl<'a> FromESTree<'a> for #ty {
+                fn from_estree(value: &Value, #d: &EstreeDeserializer<'a, '_>) -> Result<Self> {
+                    #body
+                }
+            }
+        }
+    }
+
+    /// Generate body of `from_estree` for a fieldless enum.
+    fn generate_body_for_fieldless_enum(enum_def: &EnumDef) -> TokenStream {
+        let arms =
+            enum_def.variants.iter().filter(|variant| !should_skip_enum_variant(variant)).map(
+                |variant| {
+                    let value = get_fieldless_variant_value(enum_def, variant);
+                    let variant_ident = variant.ident();
+                    quote!( Some(#value) => Ok(Self::#variant_ident), )
+                },
+            );
+
+        let enum_name = enum_def.name();
+        quote! {
+            match value.as_str() {
+                #(#arms)*
+                _ => Err(invalid_value(value, #enum_name)),
+            }
+        }
+    }
+
+    /// Generate body of `from_estree` for an enum with fields.
+    ///
+    /// Dispatch on the node's `type`. Where several variants have the same `type`
+    /// (e.g. all literals are `Literal`), try each variant in turn.
+    fn generate_body_for_enum(&self, enum_def: &EnumDef) -> TokenStream {
+        // `(type name, candidates)`, in order of first appearance
+        let mut types: Vec<(String, Vec<TokenStream>)> = vec![];
+        let mut null_variant = None;
+
+        for variant in &enum_def.variants {
+            if should_skip_enum_variant(variant) {
+                continue;
+            }
+            let variant_ident = variant.ident();
+            let candidate = quote!( Self::#variant_ident(FromESTree::from_estree(value, d)?) );
+            let field_type = variant.field_type(self.schema).unwrap();
+            for type_name in self.type_names(field_type) {
+                if type_name.is_none() {
+                    null_variant = Some(candidate.clone());
+                    continue;
+                }
+                add_candidate(&mut types, type_name.unwrap(), candidate.clone());
+            }
+        }
+
+        for inherited in enum_def.inherits_enums(self.schema) {
+            let inherited_ident = inherited.ident();
+            let candidate = quote!( Self::from(#inherited_ident::from_estree(value, d)?) );
+            for variant in inherited.all_variants(self.schema) {
+                if should_skip_enum_variant(variant) {
+                    continue;
+                }
+                for type_name in self.type_names(variant.field_type(self.schema).unwrap()) {
+                    if let Some(type_name) = type_name {
+                        add_candidate(&mut types, type_name, candidate.clone());
+                    } else {
+                        null_variant = Some(candidate.clone());
+                    }
+                }
+            }
+        }
+
+        // Group type names with the same candidates into one match arm
+        let mut arms: Vec<(Vec<String>, Vec<TokenStream>)> = vec![];
+        for (type_name, candidates) in types {
+            let key = candidates.iter().map(ToString::to_string).collect::<Vec<_>>();
+            if let Some((type_names, _)) = arms.iter_mut().find(|(_, other_candidates)| {
+                other_candidates.iter().map(ToString::to_string).eq(key.iter().cloned())
+            }) {
+                type_names.push(type_name);
+            } else {
+                arms.push((vec![type_name], candidates));
+            }
+        }
+
+        let arms = arms.into_iter().map(|(type_names, candidates)| {
+            let value = if let [candidate] = candidates.as_slice() {
+                quote!( Ok(#candidate) )
+            } else {
+                let candidates =
+                    candidates.iter().map(|candidate| quote!( |value, d| Ok(#candidate) ));
+                quote!( d.first_ok(value, &[#(#candidates),*]) )
+            };
+            quote!( #(#type_names)|* => #value, )
+        });
+
+        let null_check = null_variant.map(|candidate| {
+            quote! {
+                if value.is_null() {
+                    return Ok(#candidate);
+                }
+            }
+        });
+
+        let enum_name = enum_def.name();
+        quote! {
+            #null_check
+            let node = Node::new(value)?;
+            match node.r#type {
+                #(#arms)*
+                _ => Err(node.unexpected(#enum_name)),
+            }
+        }
+    }
+
+    /// Get possible values of `type` field in ESTree AST for a type.
+    ///
+    /// `None` represents `null` (e.g. `Elision`).
+    fn type_names(&self, type_def: &TypeDef) -> Vec<Option<String>> {
+        match type_def {
+            TypeDef::Box(box_def) => self.type_names(box_def.inner_type(self.schema)),
+            TypeDef::Enum(enum_def) => enum_def
+                .all_variants(self.schema)
+                .filter(|variant| !should_skip_enum_variant(variant))
+                .flat_map(|variant| self.type_names(variant.field_type(self.schema).unwrap()))
+                .collect(),
+            TypeDef::Struct(struct_def) => {
+                if let Some(converter_name) = &struct_def.estree.via
+                    && self.schema.meta_by_name(converter_name).estree.raw_deser.as_deref()
+                        == Some("null")
+                {
+                    return vec![None];
+                }
+
+                // Struct with a `type` field e.g. `Function` can have any of that field's values
+                let type_field = struct_def.fields.iter().find(|field| {
+                    !should_skip_field(field, self.schema) && get_struct_field_name(field) == "type"
+                });
+                if let Some(type_field) = type_field {
+                    let TypeDef::Enum(type_enum_def) = type_field.type_def(self.schema) else {
+                        panic!("`type` field must be a fieldless enum: `{}`", struct_def.name());
+                    };
+                    return type_enum_def
+                        .variants
+                        .iter()
+                        .filter(|variant| !should_skip_enum_variant(variant))
+                        .map(|variant| {
+                            Some(get_fieldless_variant_value(type_enum_def, variant).into_owned())
+                        })
+                        .collect();
+                }
+
+                let type_name =
+                    struct_def.estree.rename.as_deref().unwrap_or_else(|| struct_def.name());
+                vec![Some(type_name.to_string())]
+            }
+            _ => panic!("Cannot determine ESTree type of `{}`", type_def.name()),
+        }
+    }
+}
+
+/// Get if types defined in crate `krate` are deserialized.
+///
+/// Types from other crates (e.g. `SourceType` from `oxc_span`, or regexp AST) are either not part
+/// of ESTree AST, or are handled by the hand-written impls for the types which contain them.
+fn is_ast_crate(krate: &str) -> bool {
+    matches!(krate, "oxc_ast" | "oxc_syntax")
+}
+
+/// Add `candidate` for `type_name`, unless it's already present.
+fn add_candidate(
+    types: &mut Vec<(String, Vec<TokenStream>)>,
+    type_name: String,
+    candidate: TokenStream,
+) {
+    if let Some((_, candidates)) = types.iter_mut().find(|(name, _)| *name == type_name) {
+        let candidate_str = candidate.to_string();
+        if !candidates.iter().any(|other| other.to_string() == candidate_str) {
+            candidates.push(candidate);
+        }
+    } else {
+        types.push((type_name, vec![candidate]));
+    }
+}
@@ -0,0 +1,348 @@
+//! Generator for `MatchNode` impls, used by `oxc_codemod` to match patterns against AST.
+//!
+//! Generates a `MatchNode` impl for every AST type which derives `ContentEq`. Fields are compared
+//! the same way as `ContentEq` compares them, except that AST nodes are compared with `MatchNode`,
+//! so metavariables in the pattern can match any node.
+//!
+//! Types where a metavariable can appear in the pattern are "hook" types. They check for
+//! a metavariable before comparing fields, and can be captured. They are:
+//!
+//! * Identifier types, which have hand-written impls in `oxc_codemod`.
+//! * Enums with an identifier variant (e.g. `Expression`, `BindingPattern`, `PropertyKey`).
+//! * The types listed in `IDENTIFIER_FNS`, where a metavariable is wrapped in another node.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{
+    CODEMOD_CRATE_PATH, Codegen, Generator,
+    codegen::DeriveId,
+    output::{Output, output_path},
+    schema::{Def, EnumDef, Schema, StructDef, TypeDef, TypeId},
+    utils::create_ident,
+};
+
+use super::define_generator;
+
+/// Identifier types. Their `MatchNode` impls are hand-written.
+const IDENTIFIER_TYPE_NAMES: [&str; 3] =
+    ["IdentifierReference", "BindingIdentifier", "IdentifierName"];
+
+/// Types which a metavariable can stand in for, other than identifiers, and the function in
+/// `oxc_codemod` which gets the metavariable identifier from them.
+///
+/// * `$STMT;` matches any statement.
+/// * `$$$PARAMS` matches any number of parameters, which are not identifiers.
+const IDENTIFIER_FNS: [(&str, &str); 2] =
+    [("Statement", "statement_identifier"), ("FormalParameter", "formal_parameter_identifier")];
+
+/// Generator for `MatchNode` impls.
+pub struct MatchNodeGenerator;
+
+define_generator!(MatchNodeGenerator);
+
+impl Generator for MatchNodeGenerator {
+    fn generate(&self, schema: &Schema, codegen: &Codegen) -> Output {
+        let output = generate(schema, codegen);
+        Output::Rust { path: output_path(CODEMOD_CRATE_PATH, "match_node.rs"), tokens: output }
+    }
+}
+
+fn generate(schema: &Schema, codegen: &Codegen) -> TokenStream {
+    let g = MatchNodeGen {
+        schema,
+        content_eq_derive_id: codegen.get_derive_id_by_name("ContentEq"),
+        identifier_type_ids: IDENTIFIER_TYPE_NAMES.map(|name| schema.type_names[name]),
+        expression_type_id: schema.type_names["Expression"],
+    };
+
+    let mut hook_types = vec![];
+    let mut impls = quote!();
+    for type_def in &schema.types {
+        match type_def {
+            TypeDef::Struct(struct_def) if g.has_impl(type_def) => {
+                if g.identifier_type_ids.contains(&struct_def.id) {
+                    hook_types.push(type_def);
+                    continue;
+                }
+                let is_hook = identifier_fn(struct_def.name()).is_some();
+                if is_hook {
+                    hook_types.push(type_def);
+                }
+                impls.extend(g.generate_struct(struct_def, is_hook));
+            }
+            TypeDef::Enum(enum_def) if g.has_impl(type_def) => {
+                let is_hook = identifier_fn(enum_def.name()).is_some()
+                    || enum_def.all_variants(schema).any(|variant| {
+                        variant.field_type(schema).is_some_and(|field_type| {
+                            g.identifier_type_ids.contains(&field_type.innermost_type(schema).id())
+                        })
+                    });
+                if is_hook {
+                    hook_types.push(type_def);
+                }
+                impls.extend(g.generate_enum(enum_def, is_hook));
+            }
+            _ => {}
+        }
+    }
+
+    let capture = generate_capture(&hook_types, schema);
+
+    quote! {
+        #![allow(
+            clippy::match_same_arms,
+            clippy::match_wildcard_for_single_variants,
+            clippy::too_many_lines
+        )]
+
+        ///@@line_break
+        use oxc_ast::ast::*;
+        use oxc_span::{ContentEq, GetSpan, Span};
+
+        ///@@line_break
+        use crate::matcher::{Identifier, MatchNode, MatchState};
+
+        ///@@line_break
+        #capture
+
+        #impls
+    }
+}
+
+struct MatchNodeGen<'s> {
+    schema: &'s Schema,
+    content_eq_derive_id: DeriveId,
+    identifier_type_ids: [TypeId; IDENTIFIER_TYPE_NAMES.len()],
+    expression_type_id: TypeId,
+}
+
+impl MatchNodeGen<'_> {
+    /// Whether type implements `MatchNode`, either generated or hand-written.
+    fn has_impl(&self, type_def: &TypeDef) -> bool {
+        match type_def {
+            TypeDef::Struct(struct_def) => {
+                struct_def.file(self.schema).krate() == "oxc_ast"
+                    && struct_def.generates_derive(self.content_eq_derive_id)
+                    && !struct_def.content_eq.skip
+            }
+            TypeDef::Enum(enum_def) => {
+                enum_def.file(self.schema).krate() == "oxc_ast"
+                    && enum_def.generates_derive(self.content_eq_derive_id)
+                    && !enum_def.content_eq.skip
+                    && !enum_def.is_fieldless()
+            }
+            _ => false,
+        }
+    }
+
+    fn generate_struct(&self, struct_def: &StructDef, is_hook: bool) -> TokenStream {
+        let schema = self.schema;
+        let mut content_eq_fields = vec![];
+        let mut match_node_fields = vec![];
+        for field in &struct_def.fields {
+            if field.content_eq.skip {
+                continue;
+            }
+            let innermost_type = field.type_def(schema).innermost_type(schema);
+            let skip = match innermost_type {
+                TypeDef::Struct(struct_def) => struct_def.content_eq.skip,
+                TypeDef::Enum(enum_def) => enum_def.content_eq.skip,
+                _ => false,
+            };
+            if skip {
+                continue;
+            }
+            let ident = field.ident();
+            if self.has_impl(innermost_type) {
+                match_node_fields
+                    .push(quote!( MatchNode::match_node(&self.#ident, &target.#ident, m) ));
+            } else {
+                content_eq_fields
+                    .push(quote!( ContentEq::content_eq(&self.#ident, &target.#ident) ));
+            }
+        }
+
+        // Compare cheap fields first, then AST nodes in source order, so metavariables are bound
+        // in the order they appear
+        let uses_m = !match_node_fields.is_empty();
+        let fields = content_eq_fields.into_iter().chain(match_node_fields).collect::<Vec<_>>();
+        let uses_target = !fields.is_empty();
+        let body = if uses_target { quote!( #(#fields)&&* ) } else { quote!(true) };
+        let hook_methods = is_hook.then(|| {
+            let identifier_fn = create_ident(identifier_fn(struct_def.name()).unwrap());
+            let capture_variant = struct_def.ident();
+            quote! {
+                fn identifier(&'a self) -> Option<Identifier<'a>> {
+                    crate::matcher::#identifier_fn(self)
+                }
+
+                ///@@line_break
+                fn capture(&'a self) -> Option<Capture<'a>> {
+                    Some(Capture::#capture_variant(self))
+                }
+            }
+        });
+
+        generate_impl(&struct_def.ty(schema), &body, uses_target, uses_m, hook_methods.as_ref())
+    }
+
+    fn generate_enum(&self, enum_def: &EnumDef, is_hook: bool) -> TokenStream {
+        let schema = self.schema;
+        let mut identifier_matches = vec![];
+        let mut uses_m = false;
+        let matches = enum_def
+            .all_variants(schema)
+            .map(|variant| {
+                let ident = variant.ident();
+                let Some(field_type) = variant.field_type(schema) else {
+                    return quote!( (Self::#ident, Self::#ident) => true );
+                };
+                let innermost_type = field_type.innermost_type(schema);
+                if self.identifier_type_ids.contains(&innermost_type.id()) {
+                    identifier_matches
+                        .push(quote!( Self::#ident(it) => MatchNode::identifier(it) ));
+                }
+                if self.has_impl(innermost_type) {
+                    uses_m = true;
+                    quote!( (Self::#ident(a), Self::#ident(b)) => MatchNode::match_node(a, b, m) )
+                } else {
+                    quote!( (Self::#ident(a), Self::#ident(b)) => ContentEq::content_eq(a, b) )
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let body = quote! {
+            match (self, target) {
+                #(#matches,)*
+                _ => false,
+            }
+        };
+
+        let hook_methods = is_hook.then(|| {
+            let identifier_body = if let Some(identifier_fn) = identifier_fn(enum_def.name()) {
+                let identifier_fn = create_ident(identifier_fn);
+                quote!( crate::matcher::#identifier_fn(self) )
+            } else {
+                quote! {
+                    match self {
+                        #(#identifier_matches,)*
+                        _ => None,
+                    }
+                }
+            };
+
+            let capture_variant = enum_def.ident();
+            let inherits_expression = enum_def
+                .all_inherits(schema)
+                .any(|inherited| inherited.id == self.expression_type_id);
+            let capture_body = if inherits_expression {
+                // Capture expressions as `Expression`, so they are equivalent to the same
+                // expression in any position
+                quote! {
+                    if let Some(expression) = self.as_expression() {
+                        Some(Capture::Expression(expression))
+                    } else {
+                        Some(Capture::#capture_variant(self))
+                    }
+                }
+            } else {
+                quote!( Some(Capture::#capture_variant(self)) )
+            };
+
+            quote! {
+                fn identifier(&'a self) -> Option<Identifier<'a>> {
+                    #identifier_body
+                }
+
+                ///@@line_break
+                fn capture(&'a self) -> Option<Capture<'a>> {
+                    #capture_body
+                }
+            }
+        });
+
+        generate_impl(&enum_def.ty(schema), &body, true, uses_m, hook_methods.as_ref())
+    }
+}
+
+fn generate_impl(
+    ty: &TokenStream,
+    body: &TokenStream,
+    uses_target: bool,
+    uses_m: bool,
+    hook_methods: Option<&TokenStream>,
+) -> TokenStream {
+    // Hook types check for a metavariable first, which uses both
+    let is_hook = hook_methods.is_some();
+    let target = format_ident!("{}", if uses_target || is_hook { "target" } else { "_" });
+    let m = format_ident!("{}", if uses_m || is_hook { "m" } else { "_" });
+    let metavariable = is_hook.then(|| {
+        quote! {
+            if let Some(result) = m.match_metavariable(self, target) {
+                return result;
+            }
+        }
+    });
+    quote! {
+        ///@@line_break
+        impl<'a> MatchNode<'a> for #ty {
+            fn match_node(&'a self, #target: &'a Self, #m: &mut MatchState<'a>) -> bool {
+                #metavariable
+                #body
+            }
+
+            #hook_methods
+        }
+    }
+}
+
+fn identifier_fn(type_name: &str) -> Option<&'static str> {
+    IDENTIFIER_FNS
+        .iter()
+        .find(|(name, _)| *name == type_name)
+        .map(|(_, identifier_fn)| *identifier_fn)
+}
+
+/// Generate `Capture` enum, which has a variant for each hook type.
+fn generate_capture(hook_types: &[&TypeDef], schema: &Schema) -> TokenStream {
+    let idents =
+        hook_types.iter().map(|type_def| create_ident(type_def.name())).collect::<Vec<_>>();
+    let tys = hook_types.iter().map(|type_def| type_def.ty(schema));
+
+    quote! {
+        /// A node captured by a metavariable.
+        #[derive(Clone, Copy)]
+        pub enum Capture<'a> {
+            #(#idents(&'a #tys),)*
+        }
+
+        ///@@line_break
+        impl<'a> Capture<'a> {
+            /// The identifier which the captured node is, if any.
+            pub fn identifier(self) -> Option<Identifier<'a>> {
+                match self {
+                    #(Self::#idents(it) => MatchNode::identifier(it),)*
+                }
+            }
+
+            ///@@line_break
+            /// Match `self` against `other`, if they are the same type of node.
+            pub fn match_same_type(self, other: Self, m: &mut MatchState<'a>) -> bool {
+                match (self, other) {
+                    #((Self::#idents(a), Self::#idents(b)) => MatchNode::match_node(a, b, m),)*
+                    _ => false,
+                }
+            }
+        }
+
+        ///@@line_break
+        impl GetSpan for Capture<'_> {
+            fn span(&self) -> Span {
+                match self {
+                    #(Self::#idents(it) => GetSpan::span(*it),)*
+                }
+            }
+        }
+    }
+}
@@ -7,11 +7,13 @@ use crate::{
 mod assert_layouts;
 mod ast_builder;
 mod ast_kind;
+mod estree_deserialize;
 #[cfg(feature = "generate-js")]
 mod estree_visit;
 mod formatter;
 mod get_id;
 mod inherit_variants;
+mod match_node;
 mod minifier_traverse;
 #[cfg(feature = "generate-js")]
 mod oxlint_envs;
@@ -30,11 +32,13 @@ mod visit_js;
 pub use assert_layouts::AssertLayoutsGenerator;
 pub use ast_builder::AstBuilderGenerator;
 pub use ast_kind::AstKindGenerator;
+pub use estree_deserialize::ESTreeDeserializeGenerator;
 #[cfg(feature = "generate-js")]
 pub use estree_visit::ESTreeVisitGenerator;
 pub use formatter::{FormatterAstNodesGenerator, FormatterFormatGenerator};
 pub use get_id::GetIdGenerator;
 pub use inherit_variants::InheritVariantsGenerator;
+pub use match_node::MatchNodeGenerator;
 pub use minifier_traverse::MinifierTraverseGenerator;
 #[cfg(feature = "generate-js")]
 pub use oxlint_envs::OxlintEnvsGenerator;
@@ -231,6 +231,9 @@ const TRAVERSE_CRATE_PATH: &str = "crates/oxc_traverse";
 /// Path to `oxc_minifier` crate
 const MINIFIER_CRATE_PATH: &str = "crates/oxc_minifier";
 
+/// Path to `oxc_codemod` crate
+const CODEMOD_CRATE_PATH: &str = "crates/oxc_codemod";
+
 /// Path to write TS type definitions to
 const TYPESCRIPT_DEFINITIONS_PATH: &str = "npm/oxc-types/types.d.ts";
 
@@ -268,6 +271,7 @@ const GENERATORS: &[&(dyn Generator + Sync)] = &[
     &generators::VisitJsGenerator,
     &generators::ScopesCollectorGenerator,
     &generators::Utf8ToUtf16ConverterGenerator,
+    &generators::ESTreeDeserializeGenerator,
     #[cfg(feature = "generate-js")]
     &generators::ESTreeVisitGenerator,
     #[cfg(feature = "generate-js")]
@@ -282,6 +286,7 @@ const GENERATORS: &[&(dyn Generator + Sync)] = &[
     &generators::FormatterAstNodesGenerator,
     &generators::TraverseGenerator,
     &generators::MinifierTraverseGenerator,
+    &generators::MatchNodeGenerator,
 ];
 
 /// Attributes on structs and enums (not including those defined by derives/generators)
@@ -334,6 +334,7 @@ impl AppArgs {
 
     fn run_conformance_suites(&self, data: &TestData) {
         self.run_parser(data);
+        self.run_parser_recovery(data);
         self.run_semantic(data);
         self.run_codegen(data);
         self.run_formatter(data);
@@ -373,6 +374,35 @@ impl AppArgs {
         }
     }
 
+    /// Parse with error recovery enabled, to check that it neither panics nor changes the
+    /// result for valid code.
+    pub fn run_parser_recovery(&self, data: &TestData) {
+        self.run_tool(
+            "parser_recovery_test262",
+            TEST262_PATH,
+            &data.test262,
+            tools::run_parser_recovery_test262,
+        );
+        self.run_tool(
+            "parser_recovery_babel",
+            BABEL_PATH,
+            &data.babel,
+            tools::run_parser_recovery_babel,
+        );
+        self.run_tool(
+            "parser_recovery_typescript",
+            TYPESCRIPT_PATH,
+            &data.typescript,
+            tools::run_parser_recovery_typescript,
+        );
+        self.run_tool(
+            "parser_recovery_misc",
+            MISC_PATH,
+            &data.misc,
+            tools::run_parser_recovery_misc,
+        );
+    }
+
     /// Differential lexer conformance: compare `oxc_lexer`'s significant-token
     /// spans against the parser's token stream over the corpora. Opt-in (kept out
     /// of `run_all`) — requires the `lexer` feature and a static AVX2/BMI2 x86_64
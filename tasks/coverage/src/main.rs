@@ -39,6 +39,7 @@ fn main() {
     let task = command.as_deref().unwrap_or("default");
     match task {
         "parser" => app_args.run_parser(&load()),
+        "parser_recovery" => app_args.run_parser_recovery(&load()),
         #[cfg(all(
             feature = "lexer",
             target_arch = "x86_64",
@@ -13,7 +13,8 @@ use oxc::{
     diagnostics::{GraphicalReportHandler, GraphicalTheme, NamedSource, OxcDiagnostic},
     minifier::CompressOptions,
     parser::{ParseOptions, Parser, ParserReturn, config::RuntimeParserConfig},
-    span::{ModuleKind, SourceType, Span},
+    semantic::SemanticBuilder,
+    span::{ContentEq, ModuleKind, SourceType, Span},
     transformer::{JsxOptions, JsxRuntime, TransformOptions},
 };
 use oxc_estree_tokens::{ESTreeTokenOptions, to_estree_tokens_pretty_json};
@@ -22,6 +23,7 @@ use oxc_formatter::{
     QuoteProperties, QuoteStyle, Semicolons, TrailingCommas,
 };
 use oxc_formatter_core::{IndentStyle, IndentWidth, LineEnding, LineWidth};
+use oxc_tasks_common::normalize_path;
 use rayon::prelude::*;
 
 use crate::{
@@ -261,6 +263,144 @@ pub fn run_parser_misc(files: &[MiscFile]) -> Vec<CoverageResult> {
         .collect()
 }
 
+// ================================
+// Parser Recovery
+// ================================
+
+/// Parse with [`ParseOptions::recover_from_errors`] and check that recovery
+/// * does not panic, neither in the parser nor in semantic analysis of the recovered program,
+/// * does not change the program or diagnostics of code without syntax errors,
+/// * does not hide syntax errors.
+///
+/// Code which the parser still cannot recover from is listed in the snapshot.
+fn run_parser_recovery(
+    path: &Path,
+    code: &str,
+    source_type: SourceType,
+    allow_return_outside_function: bool,
+    should_fail: bool,
+) -> TestResult {
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let options = ParseOptions {
+            parse_regular_expression: true,
+            allow_return_outside_function,
+            ..ParseOptions::default()
+        };
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, code, source_type).with_options(options).parse();
+        let recovered = Parser::new(&allocator, code, source_type)
+            .with_options(ParseOptions { recover_from_errors: true, ..options })
+            .parse();
+        SemanticBuilder::new().build(&recovered.program);
+
+        if ret.diagnostics.is_empty() {
+            if !recovered.diagnostics.is_empty()
+                || !recovered.discarded_spans.is_empty()
+                || !recovered.program.content_eq(&ret.program)
+            {
+                return TestResult::Mismatch(
+                    "Recovery changed valid code",
+                    String::new(),
+                    String::new(),
+                );
+            }
+            // Errors of negative tests without syntax errors are reported by semantic analysis.
+            return if should_fail {
+                TestResult::CorrectError(String::new(), false)
+            } else {
+                TestResult::Passed
+            };
+        }
+        if recovered.diagnostics.is_empty() {
+            return TestResult::Mismatch(
+                "Recovery hid syntax errors",
+                String::new(),
+                String::new(),
+            );
+        }
+        let output = if recovered.panicked {
+            format!("Not recovered: {}\n", normalize_path(Path::new("tasks/coverage").join(path)))
+        } else {
+            String::new()
+        };
+        if should_fail {
+            TestResult::CorrectError(output, recovered.panicked)
+        } else {
+            TestResult::ParseError(output, recovered.panicked)
+        }
+    }));
+    result.unwrap_or_else(|_| {
+        TestResult::GenericError("Recovery panicked", "parser or semantic panicked".to_string())
+    })
+}
+
+pub fn run_parser_recovery_test262(files: &[Test262File]) -> Vec<CoverageResult> {
+    files
+        .par_iter()
+        .map(|f| {
+            let should_fail =
+                f.meta.negative.as_ref().is_some_and(|n| n.phase == crate::test262::Phase::Parse);
+            let source_type = SourceType::cjs().with_script(true);
+            let source_type = if f.meta.flags.contains(&TestFlag::Module) {
+                source_type.with_module(true)
+            } else {
+                source_type
+            };
+            let result = run_parser_recovery(&f.path, &f.code, source_type, false, should_fail);
+            CoverageResult { path: f.path.clone(), should_fail, result }
+        })
+        .collect()
+}
+
+pub fn run_parser_recovery_babel(files: &[BabelFile]) -> Vec<CoverageResult> {
+    files
+        .par_iter()
+        .map(|f| {
+            let result = run_parser_recovery(
+                &f.path,
+                &f.code,
+                f.source_type,
+                f.options.allow_return_outside_function,
+                f.should_fail,
+            );
+            CoverageResult { path: f.path.clone(), should_fail: f.should_fail, result }
+        })
+        .collect()
+}
+
+pub fn run_parser_recovery_typescript(files: &[TypeScriptFile]) -> Vec<CoverageResult> {
+    files
+        .par_iter()
+        .map(|f| {
+            let result = f
+                .units
+                .iter()
+                .map(|unit| {
+                    run_parser_recovery(
+                        &f.path,
+                        &unit.content,
+                        unit.source_type,
+                        false,
+                        f.should_fail,
+                    )
+                })
+                .find(|result| !matches!(result, TestResult::Passed))
+                .unwrap_or(TestResult::Passed);
+            CoverageResult { path: f.path.clone(), should_fail: f.should_fail, result }
+        })
+        .collect()
+}
+
+pub fn run_parser_recovery_misc(files: &[MiscFile]) -> Vec<CoverageResult> {
+    files
+        .par_iter()
+        .map(|f| {
+            let result = run_parser_recovery(&f.path, &f.code, f.source_type, false, f.should_fail);
+            CoverageResult { path: f.path.clone(), should_fail: f.should_fail, result }
+        })
+        .collect()
+}
+
 // ================================
 // Semantic
 // ================================
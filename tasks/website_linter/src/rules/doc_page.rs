@@ -302,6 +302,11 @@ fn upstream_docs_url(plugin: &str, name: &str) -> Option<String> {
         ("react", "only-export-components") => Some(
             "https://github.com/ArnaudBarre/eslint-plugin-react-refresh/blob/main/docs/only-export-components.md",
         ),
+        ("compat", "compat") => Some("https://github.com/amilajack/eslint-plugin-compat"),
+        ("svelte", "valid-rune-placement") => Some("https://svelte.dev/docs/svelte/what-are-runes"),
+        ("svelte", "no-reactive-destructuring") => {
+            Some("https://svelte.dev/docs/svelte/$derived#Destructuring")
+        }
         _ => None,
     };
     if let Some(url) = override_url {
@@ -355,6 +360,30 @@ fn upstream_docs_url(plugin: &str, name: &str) -> Option<String> {
             "https://github.com/vitest-dev/eslint-plugin-vitest/blob/main/docs/rules/{name}.md"
         ),
         "vue" => format!("https://eslint.vuejs.org/rules/{name}.html"),
+        "stylelint" => format!("https://stylelint.io/user-guide/rules/{name}"),
+        "svelte" => format!("https://sveltejs.github.io/eslint-plugin-svelte/rules/{name}/"),
+        "testing_library" => format!(
+            "https://github.com/testing-library/eslint-plugin-testing-library/blob/main/docs/rules/{name}.md"
+        ),
+        "playwright" => format!(
+            "https://github.com/mskelton/eslint-plugin-playwright/blob/main/docs/rules/{name}.md"
+        ),
+        "security" => match name.strip_prefix("no-unsanitized-") {
+            Some(name) => format!(
+                "https://github.com/mozilla/eslint-plugin-no-unsanitized/blob/main/docs/rules/{name}.md"
+            ),
+            None => format!(
+                "https://github.com/eslint-community/eslint-plugin-security/blob/main/docs/rules/{name}.md"
+            ),
+        },
+        "angular" => match name.strip_prefix("template-") {
+            Some(name) => format!(
+                "https://github.com/angular-eslint/angular-eslint/blob/main/packages/eslint-plugin-template/docs/rules/{name}.md"
+            ),
+            None => format!(
+                "https://github.com/angular-eslint/angular-eslint/blob/main/packages/eslint-plugin/docs/rules/{name}.md"
+            ),
+        },
         _ => return None,
     })
 }